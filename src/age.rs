@@ -0,0 +1,266 @@
+// 리소스 나이: 문서의 생성일/시작 시간 행에 "N일 전"을 덧붙이고,
+// 블루프린트에는 나이 구간별 리소스 수와 가장 오래된 리소스를 요약
+use crate::blueprint::BlueprintResource;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SHOW_RESOURCE_AGE: AtomicBool = AtomicBool::new(false);
+
+// 구간별 상한 (일, 미만): 30일, 90일, 1년, 2년, 그 이상
+const BUCKET_LIMITS: [i64; 4] = [30, 90, 365, 730];
+// 나이를 알 수 없는 리소스 구간 (age_bucket_name의 마지막 항목)
+const UNKNOWN_BUCKET: usize = BUCKET_LIMITS.len() + 1;
+const BAR_WIDTH: usize = 20;
+const OLDEST_LIMIT: usize = 5;
+/// Add "created N days ago" to documents and an age summary to blueprints.
+pub fn set_show_resource_age(enabled: bool) {
+    SHOW_RESOURCE_AGE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn show_resource_age_enabled() -> bool {
+    SHOW_RESOURCE_AGE.load(Ordering::Relaxed)
+}
+
+pub fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+/// Append the age to the document's creation (or launch) time row, if it has one.
+/// `created` comes from the detail struct, not from the document.
+pub fn with_age(
+    markdown: String,
+    created: Option<NaiveDate>,
+    lang: Language,
+    today: NaiveDate,
+) -> String {
+    let (Some(day), Some(index)) = (created, created_row(&markdown, lang)) else {
+        return markdown;
+    };
+    let i18n = I18n::new(lang);
+    let mut lines: Vec<String> = markdown.lines().map(str::to_string).collect();
+    let line = &mut lines[index];
+    let end = line.trim_end().len() - 1;
+    line.replace_range(
+        end..,
+        &format!("({}) |", i18n.md_days_ago((today - day).num_days())),
+    );
+    let mut result = lines.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Creation date from a detail's raw AWS timestamp (RFC 3339, or text starting with a date).
+pub fn created_date(timestamp: &str) -> Option<NaiveDate> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(timestamp) {
+        return Some(timestamp.with_timezone(&Utc).date_naive());
+    }
+    NaiveDate::parse_from_str(timestamp.get(..10)?, "%Y-%m-%d").ok()
+}
+
+// 첫 ### 섹션 전의 기본 표에서 생성일/시작 시간 행 위치 (하위 표의 생성일은 다른 리소스 것)
+fn created_row(markdown: &str, lang: Language) -> Option<usize> {
+    let i18n = I18n::new(lang);
+    let labels = [i18n.md_created_at(), i18n.md_launch_time()];
+    markdown
+        .lines()
+        .take_while(|line| !line.starts_with("### "))
+        .position(|line| {
+            let Some(cells) = line
+                .trim()
+                .strip_prefix('|')
+                .and_then(|cells| cells.strip_suffix('|'))
+            else {
+                return false;
+            };
+            cells.split_once('|').is_some_and(|(label, value)| {
+                !value.contains('|') && labels.contains(&label.trim()) && value.trim() != "-"
+            })
+        })
+}
+
+fn bucket(age_days: i64) -> usize {
+    BUCKET_LIMITS
+        .iter()
+        .position(|limit| age_days < *limit)
+        .unwrap_or(BUCKET_LIMITS.len())
+}
+
+/// "Resource Age" section for a blueprint: a histogram of resource ages plus the oldest resources.
+/// `created` holds each resource's creation date in blueprint order.
+pub fn age_summary_markdown(
+    resources: &[BlueprintResource],
+    created: &[Option<NaiveDate>],
+    lang: Language,
+    today: NaiveDate,
+) -> String {
+    let i18n = I18n::new(lang);
+    let ages: Vec<Option<(NaiveDate, i64)>> = created
+        .iter()
+        .map(|created| created.map(|day| (day, (today - day).num_days())))
+        .collect();
+    let mut counts = [0usize; UNKNOWN_BUCKET + 1];
+    for age in &ages {
+        counts[age.map_or(UNKNOWN_BUCKET, |(_, days)| bucket(days))] += 1;
+    }
+    let total = ages.len().max(1);
+
+    let mut lines = vec![
+        format!("## {}\n", i18n.md_resource_age()),
+        format!(
+            "| {} | {} | {} |",
+            i18n.md_age(),
+            i18n.md_resources(),
+            i18n.md_share()
+        ),
+        "|:---|---:|:---|".to_string(),
+    ];
+    for (index, count) in counts.iter().enumerate() {
+        // 나이를 모르는 리소스가 없으면 그 행은 생략
+        if index == UNKNOWN_BUCKET && *count == 0 {
+            continue;
+        }
+        let bar = "█".repeat((count * BAR_WIDTH).div_ceil(total));
+        let share = format!(
+            "{} {}",
+            bar,
+            locale::percent(*count as f64 * 100.0 / total as f64)
+        );
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.age_bucket_name(index),
+            locale::count(*count as i64),
+            share.trim_start()
+        ));
+    }
+
+    let mut oldest: Vec<(&BlueprintResource, NaiveDate, i64)> = resources
+        .iter()
+        .zip(&ages)
+        .filter_map(|(resource, age)| age.map(|(day, days)| (resource, day, days)))
+        .collect();
+    if !oldest.is_empty() {
+        oldest.sort_by_key(|(_, day, _)| *day);
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_oldest_resources()));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.md_resource(),
+            i18n.md_created_at(),
+            i18n.md_age()
+        ));
+        lines.push("|:---|:---|:---|".to_string());
+        for (resource, day, days) in oldest.into_iter().take(OLDEST_LIMIT) {
+            lines.push(format!(
+                "| {} - {} | {} | {} |",
+                resource.resource_type.display(),
+                resource.resource_name,
+                locale::date(&day.format("%Y-%m-%d").to_string()),
+                i18n.md_days_ago(days)
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{age_summary_markdown, bucket, created_date, with_age};
+    use crate::blueprint::{BlueprintResource, ResourceType};
+    use crate::i18n::Language;
+    use chrono::NaiveDate;
+
+    fn day(year: i32, month: u32, date: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, date).expect("valid date")
+    }
+
+    #[test]
+    fn created_date_reads_raw_aws_timestamps() {
+        let expected = Some(day(2024, 3, 5));
+        assert_eq!(created_date("2024-03-05T10:00:00Z"), expected);
+        assert_eq!(created_date("2024-03-05T10:00:00.000+00:00"), expected);
+        // 다른 시간대는 UTC 날짜로
+        assert_eq!(created_date("2024-03-06T01:00:00+09:00"), expected);
+        assert_eq!(created_date("2024-03-05 10:00:00 +0000"), expected);
+        assert_eq!(created_date(""), None);
+        assert_eq!(created_date("running"), None);
+    }
+
+    #[test]
+    fn with_age_annotates_main_table_creation_row_only() {
+        let markdown = "## EC2 (web)\n\n| Item | Value |\n|:---|:---|\n| Launch Time | Oct 5, 2026 08:00 UTC |\n\n### Volumes\n\n| Created At | 2020-01-01 |\n";
+        let today = day(2026, 10, 15);
+
+        let aged = with_age(
+            markdown.to_string(),
+            Some(day(2026, 10, 5)),
+            Language::English,
+            today,
+        );
+        assert!(aged.contains("| Launch Time | Oct 5, 2026 08:00 UTC (10 days ago) |"));
+        assert!(aged.contains("| Created At | 2020-01-01 |\n"));
+
+        let korean = "| 생성일 | 2026년 10월 14일 |\n";
+        assert_eq!(
+            with_age(
+                korean.to_string(),
+                Some(day(2026, 10, 14)),
+                Language::Korean,
+                today
+            ),
+            "| 생성일 | 2026년 10월 14일 (1일 전) |\n"
+        );
+        // 상세에 생성 시각이 없거나 문서에 생성일 행이 없으면 그대로
+        let without_date = "| Created At | - |\n";
+        assert_eq!(
+            with_age(
+                without_date.to_string(),
+                Some(day(2026, 10, 1)),
+                Language::English,
+                today
+            ),
+            without_date
+        );
+        assert_eq!(
+            with_age(korean.to_string(), None, Language::Korean, today),
+            korean
+        );
+    }
+
+    #[test]
+    fn age_summary_counts_buckets_and_lists_oldest_first() {
+        assert_eq!(bucket(0), 0);
+        assert_eq!(bucket(30), 1);
+        assert_eq!(bucket(364), 2);
+        assert_eq!(bucket(365), 3);
+        assert_eq!(bucket(5000), 4);
+
+        let resource = |name: &str| BlueprintResource {
+            resource_type: ResourceType::Rds,
+            region: "ap-northeast-2".to_string(),
+            resource_id: name.to_string(),
+            resource_name: name.to_string(),
+        };
+        let resources = vec![resource("new"), resource("legacy"), resource("unknown")];
+        let created = vec![Some(day(2026, 10, 1)), Some(day(2021, 6, 30)), None];
+
+        let summary =
+            age_summary_markdown(&resources, &created, Language::English, day(2026, 10, 15));
+        assert!(summary.starts_with("## Resource Age\n"));
+        assert!(summary.contains("| Under 30 days | 1 | ███████ 33.3% |"));
+        assert!(summary.contains("| 90 days – 1 year | 0 | 0.0% |"));
+        assert!(summary.contains("| Over 2 years | 1 |"));
+        assert!(summary.contains("| Unknown | 1 |"));
+        let legacy = summary
+            .find("| RDS - legacy | 2021-06-30 |")
+            .expect("legacy");
+        let new = summary.find("| RDS - new | 2026-10-01 | 14 days ago |");
+        assert!(new.is_some_and(|new| legacy < new));
+        assert!(!summary.contains("RDS - unknown"));
+    }
+}
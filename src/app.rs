@@ -1,14 +1,40 @@
+use crate::ascii::resolve_ascii;
 use crate::aws_cli::{
-    self, AsgDetail, AwsAuthError, AwsAuthErrorCode, AwsResource, Ec2Detail, EcrDetail,
+    self, AcmCertificateDetail, AmiDetail, ApiGatewayDetail, AsgDetail, AthenaWorkGroupDetail,
+    AwsAuthError, AwsAuthErrorCode, AwsResource, BackupPlanDetail, CloudFormationStackDetail,
+    CloudFrontDetail, CloudTrailDetail, CloudWatchAlarmDetail, CognitoPoolDetail,
+    DirectConnectDetail, DynamoDbDetail, EbsDetail, Ec2Detail, EcrDetail, EcsDetail, EfsDetail,
+    EksDetail, ElastiCacheDetail, ElasticBeanstalkDetail, EventBusDetail, GlueDetail,
+    IamEntityDetail, KeyPairDetail, KinesisStreamDetail, KmsKeyDetail, LambdaDetail,
+    LaunchTemplateDetail, MskClusterDetail, NetworkInterfaceDetail, OpenSearchDomainDetail,
+    Partition, RdsDetail, RedshiftClusterDetail, Route53Detail, SecretDetail, SnsDetail, SqsDetail,
+    SsmParameterPathDetail, StackResource, StateMachineDetail, TaggedResource,
+    TransitGatewayDetail, VpcEndpointDetail, VpcPeeringDetail, VpnDetail, WafWebAclDetail,
 };
 use crate::blueprint::{
-    Blueprint, BlueprintResource, BlueprintStore, ResourceType, load_blueprints, save_blueprints,
+    Blueprint, BlueprintResource, BlueprintStore, ResourceStatus, ResourceType, load_blueprints,
+    save_blueprints, sync_remote_blueprints,
 };
+use crate::compare::Comparison;
+use crate::doc_index::SearchHit;
 use crate::i18n::{I18n, Language};
-use crate::settings::{AppSettings, load_settings, save_settings};
+use crate::key_macro::KeyMacros;
+use crate::notion::NotionPage;
+use crate::query_playground::QUERY_OPERATIONS;
+use crate::settings::{
+    AppSettings, apply_runtime_settings, load_settings, next_concurrency_limit, save_settings,
+};
+use crate::stats::ResourceFacts;
+use crate::text_width::{align_markdown_tables, display_width};
+use crate::theme::{Theme, resolve_palette};
+use crate::tutorial::Tutorial;
+use crossterm::event::KeyEvent;
+use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
 
 const LOGIN_SESSION_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+// 줄바꿈을 끈 미리보기에서 ←/→ 한 번에 움직이는 칸 수
+const PREVIEW_HSCROLL_STEP: u16 = 8;
 
 fn is_login_required_error(error: &AwsAuthError) -> bool {
     matches!(
@@ -33,13 +59,16 @@ fn auth_error_message(error: &AwsAuthError, i18n: &I18n) -> String {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Screen {
     Login,
     BlueprintSelect,
     BlueprintDetail,
     BlueprintNameInput,
+    BlueprintImportInput,
     BlueprintPreview,
+    DocumentSearch,
+    Compare,
     RegionSelect,
     ServiceSelect,
     Ec2Select,
@@ -48,8 +77,70 @@ pub enum Screen {
     LoadBalancerSelect,
     EcrSelect,
     AsgSelect,
+    RdsSelect,
+    LambdaSelect,
+    DynamoDbSelect,
+    CloudFrontSelect,
+    Route53Select,
+    EksSelect,
+    EcsSelect,
+    SqsSelect,
+    SnsSelect,
+    ElastiCacheSelect,
+    CloudWatchAlarmSelect,
+    CloudTrailSelect,
+    KmsSelect,
+    SecretSelect,
+    SsmParameterSelect,
+    EfsSelect,
+    ApiGatewaySelect,
+    ElasticBeanstalkSelect,
+    StepFunctionsSelect,
+    KinesisSelect,
+    MskSelect,
+    OpenSearchSelect,
+    RedshiftSelect,
+    TransitGatewaySelect,
+    VpcPeeringSelect,
+    VpnSelect,
+    DirectConnectSelect,
+    WafSelect,
+    AcmSelect,
+    CloudFormationSelect,
+    IamSelect,
+    VpcEndpointSelect,
+    NetworkInterfaceSelect,
+    LaunchTemplateSelect,
+    AmiSelect,
+    EbsSelect,
+    KeyPairSelect,
+    EventBridgeSelect,
+    CognitoSelect,
+    GlueSelect,
+    AthenaSelect,
+    BackupSelect,
+    QueryPlayground,
     Preview,
     Settings,
+    NameTagInput,
+    NameTagConfirm,
+}
+
+// 쓰기 모드(--allow-write)에서 Name 태그를 바꿀 리소스
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameTagEdit {
+    pub resource_type: ResourceType,
+    pub resource_id: String,
+    pub current_name: Option<String>,
+    pub return_screen: Screen,
+}
+
+// 블루프린트 가져오기 입력 화면에서 받는 값의 종류
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlueprintImportKind {
+    Terraform,
+    CloudFormation,
+    TagQuery,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -62,20 +153,250 @@ pub enum LoadingTask {
     RefreshLoadBalancer,
     RefreshEcr,
     RefreshAsg,
+    RefreshRds,
+    RefreshLambda,
+    RefreshDynamoDb,
+    RefreshCloudFront,
+    RefreshRoute53,
+    RefreshEks,
+    RefreshEcs,
+    RefreshSqs,
+    RefreshSns,
+    RefreshElastiCache,
+    RefreshCloudWatchAlarm,
+    RefreshCloudTrail,
+    RefreshKms,
+    RefreshSecret,
+    RefreshSsmParameter,
+    RefreshEfs,
+    RefreshApiGateway,
+    RefreshElasticBeanstalk,
+    RefreshStepFunctions,
+    RefreshKinesis,
+    RefreshMsk,
+    RefreshOpenSearch,
+    RefreshRedshift,
+    RefreshTransitGateway,
+    RefreshVpcPeering,
+    RefreshVpn,
+    RefreshDirectConnect,
+    RefreshWaf,
+    RefreshAcm,
+    RefreshCloudFormation,
+    RefreshIam,
+    RefreshVpcEndpoint,
+    RefreshNetworkInterface,
+    RefreshLaunchTemplate,
+    RefreshAmi,
+    RefreshEbs,
+    RefreshKeyPair,
+    RefreshEventBridge,
+    RefreshCognito,
+    RefreshGlue,
+    RefreshAthena,
+    RefreshBackup,
     LoadEc2,
     LoadVpc,
     LoadSecurityGroup,
     LoadLoadBalancer,
     LoadEcr,
     LoadAsg,
+    LoadRds,
+    LoadLambda,
+    LoadDynamoDb,
+    LoadCloudFront,
+    LoadRoute53,
+    LoadEks,
+    LoadEcs,
+    LoadSqs,
+    LoadSns,
+    LoadElastiCache,
+    LoadCloudWatchAlarm,
+    LoadCloudTrail,
+    LoadKms,
+    LoadSecret,
+    LoadSsmParameter,
+    LoadEfs,
+    LoadApiGateway,
+    LoadElasticBeanstalk,
+    LoadStepFunctions,
+    LoadKinesis,
+    LoadMsk,
+    LoadOpenSearch,
+    LoadRedshift,
+    LoadTransitGateway,
+    LoadVpcPeering,
+    LoadVpn,
+    LoadDirectConnect,
+    LoadWaf,
+    LoadAcm,
+    LoadCloudFormation,
+    LoadIam,
+    LoadVpcEndpoint,
+    LoadNetworkInterface,
+    LoadLaunchTemplate,
+    LoadAmi,
+    LoadEbs,
+    LoadKeyPair,
+    LoadEventBridge,
+    LoadCognito,
+    LoadGlue,
+    LoadAthena,
+    LoadBackup,
     LoadEc2Detail(String),
-    LoadVpcDetail(String, u8), // (vpc_id, step: 0-6)
+    LoadVpcDetail(String, u8), // (vpc_id, step: 0-9)
     LoadSecurityGroupDetail(String),
     LoadLoadBalancerDetail(String),
     LoadEcrDetail(String),
     LoadAsgDetail(String),
+    LoadRdsDetail(String),
+    LoadLambdaDetail(String),
+    LoadDynamoDbDetail(String),
+    LoadCloudFrontDetail(String),
+    LoadRoute53Detail(String),
+    LoadEksDetail(String),
+    LoadEcsDetail(String),
+    LoadSqsDetail(String),
+    LoadSnsDetail(String),
+    LoadElastiCacheDetail(String),
+    LoadCloudWatchAlarmDetail(String),
+    LoadCloudTrailDetail(String),
+    LoadKmsDetail(String),
+    LoadSecretDetail(String),
+    LoadSsmParameterDetail(String),
+    LoadEfsDetail(String),
+    LoadApiGatewayDetail(String),
+    LoadElasticBeanstalkDetail(String),
+    LoadStepFunctionsDetail(String),
+    LoadKinesisDetail(String),
+    LoadMskDetail(String),
+    LoadOpenSearchDetail(String),
+    LoadRedshiftDetail(String),
+    LoadTransitGatewayDetail(String),
+    LoadVpcPeeringDetail(String),
+    LoadVpnDetail(String),
+    LoadDirectConnectDetail(String),
+    LoadWafDetail(String),
+    LoadAcmDetail(String),
+    LoadCloudFormationDetail(String),
+    LoadIamDetail(String),
+    LoadVpcEndpointDetail(String),
+    LoadNetworkInterfaceDetail(String),
+    LoadLaunchTemplateDetail(String),
+    LoadAmiDetail(String),
+    LoadEbsDetail(String),
+    LoadKeyPairDetail(String),
+    LoadEventBridgeDetail(String),
+    LoadCognitoDetail(String),
+    LoadGlueDetail(String),
+    LoadAthenaDetail(String),
+    LoadBackupDetail(String),
+
+    DocumentRegion(usize),             // (region_inventory_tasks index)
+    LoadBlueprintResources(usize),     // (current_resource_index)
+    ValidateBlueprintResources(usize), // (current_resource_index)
+    ImportCloudFormationStack(String), // (stack_name)
+    ImportTaggedResources(String),     // (tag_query)
+    LoadCompare,
+    LoadPromotionChecklist(usize, usize), // (source_blueprint_index, target_blueprint_index)
+    RunQueryOperation(usize),             // (QUERY_OPERATIONS index)
+    SetNameTag,                           // (app.name_tag_edit, app.input_buffer)
+}
 
-    LoadBlueprintResources(usize), // (current_resource_index)
+impl LoadingTask {
+    // 목록을 조회하는 작업이면 결과를 보여줄 목록 화면
+    pub fn list_screen(&self) -> Option<Screen> {
+        let screen = match self {
+            LoadingTask::RefreshEc2 | LoadingTask::LoadEc2 => Screen::Ec2Select,
+            LoadingTask::RefreshVpc | LoadingTask::LoadVpc => Screen::VpcSelect,
+            LoadingTask::RefreshSecurityGroup | LoadingTask::LoadSecurityGroup => {
+                Screen::SecurityGroupSelect
+            }
+            LoadingTask::RefreshLoadBalancer | LoadingTask::LoadLoadBalancer => {
+                Screen::LoadBalancerSelect
+            }
+            LoadingTask::RefreshEcr | LoadingTask::LoadEcr => Screen::EcrSelect,
+            LoadingTask::RefreshAsg | LoadingTask::LoadAsg => Screen::AsgSelect,
+            LoadingTask::RefreshRds | LoadingTask::LoadRds => Screen::RdsSelect,
+            LoadingTask::RefreshLambda | LoadingTask::LoadLambda => Screen::LambdaSelect,
+            LoadingTask::RefreshDynamoDb | LoadingTask::LoadDynamoDb => Screen::DynamoDbSelect,
+            LoadingTask::RefreshCloudFront | LoadingTask::LoadCloudFront => {
+                Screen::CloudFrontSelect
+            }
+            LoadingTask::RefreshRoute53 | LoadingTask::LoadRoute53 => Screen::Route53Select,
+            LoadingTask::RefreshEks | LoadingTask::LoadEks => Screen::EksSelect,
+            LoadingTask::RefreshEcs | LoadingTask::LoadEcs => Screen::EcsSelect,
+            LoadingTask::RefreshSqs | LoadingTask::LoadSqs => Screen::SqsSelect,
+            LoadingTask::RefreshSns | LoadingTask::LoadSns => Screen::SnsSelect,
+            LoadingTask::RefreshElastiCache | LoadingTask::LoadElastiCache => {
+                Screen::ElastiCacheSelect
+            }
+            LoadingTask::RefreshCloudWatchAlarm | LoadingTask::LoadCloudWatchAlarm => {
+                Screen::CloudWatchAlarmSelect
+            }
+            LoadingTask::RefreshCloudTrail | LoadingTask::LoadCloudTrail => {
+                Screen::CloudTrailSelect
+            }
+            LoadingTask::RefreshKms | LoadingTask::LoadKms => Screen::KmsSelect,
+            LoadingTask::RefreshSecret | LoadingTask::LoadSecret => Screen::SecretSelect,
+            LoadingTask::RefreshSsmParameter | LoadingTask::LoadSsmParameter => {
+                Screen::SsmParameterSelect
+            }
+            LoadingTask::RefreshEfs | LoadingTask::LoadEfs => Screen::EfsSelect,
+            LoadingTask::RefreshApiGateway | LoadingTask::LoadApiGateway => {
+                Screen::ApiGatewaySelect
+            }
+            LoadingTask::RefreshElasticBeanstalk | LoadingTask::LoadElasticBeanstalk => {
+                Screen::ElasticBeanstalkSelect
+            }
+            LoadingTask::RefreshStepFunctions | LoadingTask::LoadStepFunctions => {
+                Screen::StepFunctionsSelect
+            }
+            LoadingTask::RefreshKinesis | LoadingTask::LoadKinesis => Screen::KinesisSelect,
+            LoadingTask::RefreshMsk | LoadingTask::LoadMsk => Screen::MskSelect,
+            LoadingTask::RefreshOpenSearch | LoadingTask::LoadOpenSearch => {
+                Screen::OpenSearchSelect
+            }
+            LoadingTask::RefreshRedshift | LoadingTask::LoadRedshift => Screen::RedshiftSelect,
+            LoadingTask::RefreshTransitGateway | LoadingTask::LoadTransitGateway => {
+                Screen::TransitGatewaySelect
+            }
+            LoadingTask::RefreshVpcPeering | LoadingTask::LoadVpcPeering => {
+                Screen::VpcPeeringSelect
+            }
+            LoadingTask::RefreshVpn | LoadingTask::LoadVpn => Screen::VpnSelect,
+            LoadingTask::RefreshDirectConnect | LoadingTask::LoadDirectConnect => {
+                Screen::DirectConnectSelect
+            }
+            LoadingTask::RefreshWaf | LoadingTask::LoadWaf => Screen::WafSelect,
+            LoadingTask::RefreshAcm | LoadingTask::LoadAcm => Screen::AcmSelect,
+            LoadingTask::RefreshCloudFormation | LoadingTask::LoadCloudFormation => {
+                Screen::CloudFormationSelect
+            }
+            LoadingTask::RefreshIam | LoadingTask::LoadIam => Screen::IamSelect,
+            LoadingTask::RefreshVpcEndpoint | LoadingTask::LoadVpcEndpoint => {
+                Screen::VpcEndpointSelect
+            }
+            LoadingTask::RefreshNetworkInterface | LoadingTask::LoadNetworkInterface => {
+                Screen::NetworkInterfaceSelect
+            }
+            LoadingTask::RefreshLaunchTemplate | LoadingTask::LoadLaunchTemplate => {
+                Screen::LaunchTemplateSelect
+            }
+            LoadingTask::RefreshAmi | LoadingTask::LoadAmi => Screen::AmiSelect,
+            LoadingTask::RefreshEbs | LoadingTask::LoadEbs => Screen::EbsSelect,
+            LoadingTask::RefreshKeyPair | LoadingTask::LoadKeyPair => Screen::KeyPairSelect,
+            LoadingTask::RefreshEventBridge | LoadingTask::LoadEventBridge => {
+                Screen::EventBridgeSelect
+            }
+            LoadingTask::RefreshCognito | LoadingTask::LoadCognito => Screen::CognitoSelect,
+            LoadingTask::RefreshGlue | LoadingTask::LoadGlue => Screen::GlueSelect,
+            LoadingTask::RefreshAthena | LoadingTask::LoadAthena => Screen::AthenaSelect,
+            LoadingTask::RefreshBackup | LoadingTask::LoadBackup => Screen::BackupSelect,
+            _ => return None,
+        };
+        Some(screen)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -86,6 +407,9 @@ pub struct LoadingProgress {
     pub nats: bool,
     pub route_tables: bool,
     pub eips: bool,
+    pub peerings: bool,
+    pub endpoints: bool,
+    pub nacls: bool,
     pub dns_attrs: bool,
 }
 
@@ -95,6 +419,15 @@ impl LoadingProgress {
     }
 }
 
+// 목록 화면별 마지막 조회 시각 (같은 리전이면 settings.list_cache_seconds 동안 재사용)
+#[derive(Debug, Clone)]
+pub struct ListRefresh {
+    pub refreshed_at: Instant,
+    pub region: usize,
+    // 재조회 없이 이전 목록을 다시 보여주는 중
+    pub cached: bool,
+}
+
 pub struct Region {
     pub code: &'static str,
     pub name_ko: &'static str,
@@ -173,6 +506,41 @@ pub const REGIONS: &[Region] = &[
     },
 ];
 
+pub const GOVCLOUD_REGIONS: &[Region] = &[
+    Region {
+        code: "us-gov-west-1",
+        name_ko: "GovCloud 미국 서부",
+        name_en: "GovCloud (US-West)",
+    },
+    Region {
+        code: "us-gov-east-1",
+        name_ko: "GovCloud 미국 동부",
+        name_en: "GovCloud (US-East)",
+    },
+];
+
+pub const CHINA_REGIONS: &[Region] = &[
+    Region {
+        code: "cn-north-1",
+        name_ko: "베이징",
+        name_en: "Beijing",
+    },
+    Region {
+        code: "cn-northwest-1",
+        name_ko: "닝샤",
+        name_en: "Ningxia",
+    },
+];
+
+/// Regions offered for `partition`; GovCloud and China credentials only work in their own regions.
+pub fn regions_for(partition: Partition) -> &'static [Region] {
+    match partition {
+        Partition::Aws => REGIONS,
+        Partition::AwsUsGov => GOVCLOUD_REGIONS,
+        Partition::AwsCn => CHINA_REGIONS,
+    }
+}
+
 // Service names (excluding exit which is handled separately)
 pub const SERVICE_KEYS: &[&str] = &[
     "EC2",
@@ -181,8 +549,223 @@ pub const SERVICE_KEYS: &[&str] = &[
     "Load Balancer",
     "ECR",
     "ASG",
+    "RDS",
+    "Lambda",
+    "DynamoDB",
+    "CloudFront",
+    "Route53",
+    "EKS",
+    "ECS",
+    "SQS",
+    "SNS",
+    "ElastiCache",
+    "CloudWatch",
+    "CloudTrail",
+    "KMS",
+    "Secrets Manager",
+    "SSM Parameter Store",
+    "EFS",
+    "API Gateway",
+    "Elastic Beanstalk",
+    "Step Functions",
+    "Kinesis",
+    "MSK",
+    "OpenSearch",
+    "Redshift",
+    "Transit Gateway",
+    "VPC Peering",
+    "Site-to-Site VPN",
+    "Direct Connect",
+    "WAF",
+    "ACM",
+    "CloudFormation",
+    "IAM",
+    "VPC Endpoint",
+    "Network Interface",
+    "Launch Template",
+    "AMI",
+    "EBS",
+    "Key Pair",
+    "EventBridge",
+    "Cognito",
+    "Glue",
+    "Athena",
+    "Backup",
+];
+
+// 실험적 서비스: settings.json의 experimental_services에 추가해야 서비스 목록에 표시
+// (Backup 상세는 할당마다, 볼트마다 추가 조회를 해서 호출이 많음)
+pub const EXPERIMENTAL_SERVICE_KEYS: &[&str] = &["Backup"];
+
+// 목록 화면별 서비스 이름(SERVICE_KEYS)과 새로고침 작업 (settings.auto_refresh_seconds에서 사용)
+pub const LIST_REFRESH_TASKS: &[(Screen, &str, LoadingTask)] = &[
+    (Screen::Ec2Select, "EC2", LoadingTask::RefreshEc2),
+    (Screen::VpcSelect, "Network", LoadingTask::RefreshVpc),
+    (
+        Screen::SecurityGroupSelect,
+        "Security Group",
+        LoadingTask::RefreshSecurityGroup,
+    ),
+    (
+        Screen::LoadBalancerSelect,
+        "Load Balancer",
+        LoadingTask::RefreshLoadBalancer,
+    ),
+    (Screen::EcrSelect, "ECR", LoadingTask::RefreshEcr),
+    (Screen::AsgSelect, "ASG", LoadingTask::RefreshAsg),
+    (Screen::RdsSelect, "RDS", LoadingTask::RefreshRds),
+    (Screen::LambdaSelect, "Lambda", LoadingTask::RefreshLambda),
+    (
+        Screen::DynamoDbSelect,
+        "DynamoDB",
+        LoadingTask::RefreshDynamoDb,
+    ),
+    (
+        Screen::CloudFrontSelect,
+        "CloudFront",
+        LoadingTask::RefreshCloudFront,
+    ),
+    (
+        Screen::Route53Select,
+        "Route53",
+        LoadingTask::RefreshRoute53,
+    ),
+    (Screen::EksSelect, "EKS", LoadingTask::RefreshEks),
+    (Screen::EcsSelect, "ECS", LoadingTask::RefreshEcs),
+    (Screen::SqsSelect, "SQS", LoadingTask::RefreshSqs),
+    (Screen::SnsSelect, "SNS", LoadingTask::RefreshSns),
+    (
+        Screen::ElastiCacheSelect,
+        "ElastiCache",
+        LoadingTask::RefreshElastiCache,
+    ),
+    (
+        Screen::CloudWatchAlarmSelect,
+        "CloudWatch",
+        LoadingTask::RefreshCloudWatchAlarm,
+    ),
+    (
+        Screen::CloudTrailSelect,
+        "CloudTrail",
+        LoadingTask::RefreshCloudTrail,
+    ),
+    (Screen::KmsSelect, "KMS", LoadingTask::RefreshKms),
+    (
+        Screen::SecretSelect,
+        "Secrets Manager",
+        LoadingTask::RefreshSecret,
+    ),
+    (
+        Screen::SsmParameterSelect,
+        "SSM Parameter Store",
+        LoadingTask::RefreshSsmParameter,
+    ),
+    (Screen::EfsSelect, "EFS", LoadingTask::RefreshEfs),
+    (
+        Screen::ApiGatewaySelect,
+        "API Gateway",
+        LoadingTask::RefreshApiGateway,
+    ),
+    (
+        Screen::ElasticBeanstalkSelect,
+        "Elastic Beanstalk",
+        LoadingTask::RefreshElasticBeanstalk,
+    ),
+    (
+        Screen::StepFunctionsSelect,
+        "Step Functions",
+        LoadingTask::RefreshStepFunctions,
+    ),
+    (
+        Screen::KinesisSelect,
+        "Kinesis",
+        LoadingTask::RefreshKinesis,
+    ),
+    (Screen::MskSelect, "MSK", LoadingTask::RefreshMsk),
+    (
+        Screen::OpenSearchSelect,
+        "OpenSearch",
+        LoadingTask::RefreshOpenSearch,
+    ),
+    (
+        Screen::RedshiftSelect,
+        "Redshift",
+        LoadingTask::RefreshRedshift,
+    ),
+    (
+        Screen::TransitGatewaySelect,
+        "Transit Gateway",
+        LoadingTask::RefreshTransitGateway,
+    ),
+    (
+        Screen::VpcPeeringSelect,
+        "VPC Peering",
+        LoadingTask::RefreshVpcPeering,
+    ),
+    (
+        Screen::VpnSelect,
+        "Site-to-Site VPN",
+        LoadingTask::RefreshVpn,
+    ),
+    (
+        Screen::DirectConnectSelect,
+        "Direct Connect",
+        LoadingTask::RefreshDirectConnect,
+    ),
+    (Screen::WafSelect, "WAF", LoadingTask::RefreshWaf),
+    (Screen::AcmSelect, "ACM", LoadingTask::RefreshAcm),
+    (
+        Screen::CloudFormationSelect,
+        "CloudFormation",
+        LoadingTask::RefreshCloudFormation,
+    ),
+    (Screen::IamSelect, "IAM", LoadingTask::RefreshIam),
+    (
+        Screen::VpcEndpointSelect,
+        "VPC Endpoint",
+        LoadingTask::RefreshVpcEndpoint,
+    ),
+    (
+        Screen::NetworkInterfaceSelect,
+        "Network Interface",
+        LoadingTask::RefreshNetworkInterface,
+    ),
+    (
+        Screen::LaunchTemplateSelect,
+        "Launch Template",
+        LoadingTask::RefreshLaunchTemplate,
+    ),
+    (Screen::AmiSelect, "AMI", LoadingTask::RefreshAmi),
+    (Screen::EbsSelect, "EBS", LoadingTask::RefreshEbs),
+    (
+        Screen::KeyPairSelect,
+        "Key Pair",
+        LoadingTask::RefreshKeyPair,
+    ),
+    (
+        Screen::EventBridgeSelect,
+        "EventBridge",
+        LoadingTask::RefreshEventBridge,
+    ),
+    (
+        Screen::CognitoSelect,
+        "Cognito",
+        LoadingTask::RefreshCognito,
+    ),
+    (Screen::GlueSelect, "Glue", LoadingTask::RefreshGlue),
+    (Screen::AthenaSelect, "Athena", LoadingTask::RefreshAthena),
+    (Screen::BackupSelect, "Backup", LoadingTask::RefreshBackup),
 ];
 
+/// `SERVICE_KEYS` names hidden by `disabled_services` or not opted into as experimental.
+pub fn hidden_services(settings: &AppSettings) -> Vec<&'static str> {
+    SERVICE_KEYS
+        .iter()
+        .copied()
+        .filter(|name| !settings.service_enabled(name, EXPERIMENTAL_SERVICE_KEYS))
+        .collect()
+}
+
 pub struct App {
     pub screen: Screen,
     pub running: bool,
@@ -202,6 +785,9 @@ pub struct App {
     // Settings & i18n
     pub settings: AppSettings,
     pub i18n: I18n,
+    pub theme: Theme,
+    // 이모지/기호를 ASCII로 바꿔 표시할지 (설정 또는 자동 감지 결과)
+    pub ascii: bool,
     pub selected_setting: usize,
     pub selected_tab: usize, // 0: Main, 1: Settings
     // AWS Resources
@@ -211,6 +797,51 @@ pub struct App {
     pub load_balancers: Vec<AwsResource>,
     pub ecr_repositories: Vec<AwsResource>,
     pub auto_scaling_groups: Vec<AwsResource>,
+    pub rds_databases: Vec<AwsResource>,
+    pub lambda_functions: Vec<AwsResource>,
+    pub dynamodb_tables: Vec<AwsResource>,
+    pub cloudfront_distributions: Vec<AwsResource>,
+    pub route53_zones: Vec<AwsResource>,
+    pub eks_clusters: Vec<AwsResource>,
+    pub ecs_clusters: Vec<AwsResource>,
+    pub sqs_queues: Vec<AwsResource>,
+    pub sns_topics: Vec<AwsResource>,
+    pub elasticache_clusters: Vec<AwsResource>,
+    pub cloudwatch_alarms: Vec<AwsResource>,
+    pub cloudtrail_trails: Vec<AwsResource>,
+    pub kms_keys: Vec<AwsResource>,
+    pub secrets: Vec<AwsResource>,
+    pub ssm_parameter_paths: Vec<AwsResource>,
+    pub efs_file_systems: Vec<AwsResource>,
+    pub api_gateways: Vec<AwsResource>,
+    pub beanstalk_environments: Vec<AwsResource>,
+    pub state_machines: Vec<AwsResource>,
+    pub kinesis_streams: Vec<AwsResource>,
+    pub msk_clusters: Vec<AwsResource>,
+    pub opensearch_domains: Vec<AwsResource>,
+    pub redshift_clusters: Vec<AwsResource>,
+    pub transit_gateways: Vec<AwsResource>,
+    pub vpc_peerings: Vec<AwsResource>,
+    pub vpns: Vec<AwsResource>,
+    pub direct_connect_resources: Vec<AwsResource>,
+    pub list_refreshes: HashMap<Screen, ListRefresh>,
+    // 목록 화면별로 마지막에 선택한 리소스 ID (다른 서비스에 다녀와도 같은 행으로 복원)
+    pub list_selections: HashMap<Screen, String>,
+    pub waf_web_acls: Vec<AwsResource>,
+    pub acm_certificates: Vec<AwsResource>,
+    pub cloudformation_stacks: Vec<AwsResource>,
+    pub iam_entities: Vec<AwsResource>,
+    pub vpc_endpoints: Vec<AwsResource>,
+    pub network_interfaces: Vec<AwsResource>,
+    pub launch_templates: Vec<AwsResource>,
+    pub amis: Vec<AwsResource>,
+    pub ebs_resources: Vec<AwsResource>,
+    pub key_pairs: Vec<AwsResource>,
+    pub event_buses: Vec<AwsResource>,
+    pub cognito_pools: Vec<AwsResource>,
+    pub glue_resources: Vec<AwsResource>,
+    pub athena_workgroups: Vec<AwsResource>,
+    pub backup_plans: Vec<AwsResource>,
 
     // Selected EC2 Detail
     pub ec2_detail: Option<Ec2Detail>,
@@ -224,21 +855,152 @@ pub struct App {
     pub ecr_detail: Option<EcrDetail>,
     // Selected ASG Detail
     pub asg_detail: Option<AsgDetail>,
+    // Selected RDS Detail
+    pub rds_detail: Option<RdsDetail>,
+    // Selected Lambda Detail
+    pub lambda_detail: Option<LambdaDetail>,
+    // Selected DynamoDB Detail
+    pub dynamodb_detail: Option<DynamoDbDetail>,
+    // Selected CloudFront Detail
+    pub cloudfront_detail: Option<CloudFrontDetail>,
+    // Selected Route 53 Detail
+    pub route53_detail: Option<Route53Detail>,
+    // Selected EKS Detail
+    pub eks_detail: Option<EksDetail>,
+    // Selected ECS Detail
+    pub ecs_detail: Option<EcsDetail>,
+    // Selected SQS Detail
+    pub sqs_detail: Option<SqsDetail>,
+    // Selected SNS Detail
+    pub sns_detail: Option<SnsDetail>,
+    // Selected ElastiCache Detail
+    pub elasticache_detail: Option<ElastiCacheDetail>,
+    // Selected CloudWatch Alarm Detail
+    pub cloudwatch_alarm_detail: Option<CloudWatchAlarmDetail>,
+    // Selected CloudTrail Detail
+    pub cloudtrail_detail: Option<CloudTrailDetail>,
+    // Selected KMS Detail
+    pub kms_detail: Option<KmsKeyDetail>,
+    // Selected Secrets Manager Detail
+    pub secret_detail: Option<SecretDetail>,
+    // Selected SSM Parameter Store Detail
+    pub ssm_parameter_detail: Option<SsmParameterPathDetail>,
+    // Selected EFS Detail
+    pub efs_detail: Option<EfsDetail>,
+    // Selected API Gateway Detail
+    pub api_gateway_detail: Option<ApiGatewayDetail>,
+    // Selected Elastic Beanstalk Detail
+    pub elastic_beanstalk_detail: Option<ElasticBeanstalkDetail>,
+    // Selected Step Functions Detail
+    pub state_machine_detail: Option<StateMachineDetail>,
+    // Selected Kinesis Detail
+    pub kinesis_stream_detail: Option<KinesisStreamDetail>,
+    // Selected MSK Detail
+    pub msk_cluster_detail: Option<MskClusterDetail>,
+    // Selected OpenSearch Detail
+    pub opensearch_domain_detail: Option<OpenSearchDomainDetail>,
+    // Selected Redshift Detail
+    pub redshift_cluster_detail: Option<RedshiftClusterDetail>,
+    // Selected Transit Gateway Detail
+    pub transit_gateway_detail: Option<TransitGatewayDetail>,
+    // Selected VPC Peering Detail
+    pub vpc_peering_detail: Option<VpcPeeringDetail>,
+    // Selected Site-to-Site VPN Detail
+    pub vpn_detail: Option<VpnDetail>,
+    // Selected Direct Connect Detail
+    pub direct_connect_detail: Option<DirectConnectDetail>,
+    // Selected WAF Detail
+    pub waf_web_acl_detail: Option<WafWebAclDetail>,
+    // Selected ACM Detail
+    pub acm_certificate_detail: Option<AcmCertificateDetail>,
+    // Selected CloudFormation Detail
+    pub cloudformation_stack_detail: Option<CloudFormationStackDetail>,
+    // Selected IAM Detail
+    pub iam_entity_detail: Option<IamEntityDetail>,
+    // Selected VPC Endpoint Detail
+    pub vpc_endpoint_detail: Option<VpcEndpointDetail>,
+    // Selected Network Interface Detail
+    pub network_interface_detail: Option<NetworkInterfaceDetail>,
+    // Selected Launch Template Detail
+    pub launch_template_detail: Option<LaunchTemplateDetail>,
+    // Selected AMI Detail
+    pub ami_detail: Option<AmiDetail>,
+    // Selected EBS Detail
+    pub ebs_detail: Option<EbsDetail>,
+    // Selected 키 페어 Detail
+    pub key_pair_detail: Option<KeyPairDetail>,
+    // Selected EventBridge Detail
+    pub event_bus_detail: Option<EventBusDetail>,
+    // Selected Cognito Detail
+    pub cognito_detail: Option<CognitoPoolDetail>,
+    // Selected Glue Detail
+    pub glue_detail: Option<GlueDetail>,
+    // Selected Athena Detail
+    pub athena_workgroup_detail: Option<AthenaWorkGroupDetail>,
+    // Selected AWS Backup Detail
+    pub backup_plan_detail: Option<BackupPlanDetail>,
 
     // Preview
     pub preview_content: String,
     pub preview_filename: String,
     pub preview_scroll: u16,
+    // 줄바꿈을 끄면 긴 표 행을 자르지 않고 좌우로 스크롤
+    pub preview_wrap: bool,
+    pub preview_hscroll: u16,
+    // 원본 JSON 보기일 때 직렬화한 상세 정보
+    pub preview_raw: Option<String>,
     pub preview_drag_start: Option<(u16, u16)>, // (x, y) for drag start position
 
     // Blueprint
     pub blueprint_store: BlueprintStore,
     pub selected_blueprint_index: usize,
     pub current_blueprint: Option<Blueprint>,
+    // 리전 전체 문서화 중인 임시 블루프린트 (저장하지 않음)
+    pub region_inventory: Option<Blueprint>,
     pub blueprint_mode: bool,
     pub blueprint_resource_index: usize,
     pub input_buffer: String,
     pub blueprint_markdown_parts: Vec<String>,
+    // 요약에 쓰는 리소스별 구조화된 정보 (blueprint_markdown_parts와 같은 순서)
+    pub blueprint_resource_facts: Vec<ResourceFacts>,
+    // 리소스 검증 결과 (current_blueprint.resources와 같은 순서)
+    pub blueprint_validation: Vec<ResourceStatus>,
+    pub blueprint_import_kind: BlueprintImportKind,
+
+    // 저장한 문서 검색 결과
+    pub search_results: Vec<SearchHit>,
+    pub selected_search_result: usize,
+
+    // 비교 모드: 목록에서 c로 지정한 기준/대상 리소스와 비교 결과
+    pub compare_base: Option<BlueprintResource>,
+    pub compare_target: Option<BlueprintResource>,
+    pub comparison: Option<Comparison>,
+    pub compare_show_table: bool,
+    pub compare_return_screen: Screen,
+
+    // 승격 체크리스트: 블루프린트 목록에서 p로 지정한 원본 블루프린트
+    pub promotion_source: Option<usize>,
+
+    // JMESPath 쿼리 플레이그라운드: 실행한 작업의 JSON 출력과 input_buffer 쿼리 결과
+    pub selected_query_operation: usize,
+    pub query_output: Option<String>,
+    pub query_result: Result<String, String>,
+    pub query_scroll: u16,
+
+    // 키 매크로: Ctrl+R로 기록한 레지스터와 Ctrl+E로 재생 대기 중인 키
+    pub macros: KeyMacros,
+    pub macro_queue: VecDeque<KeyEvent>,
+
+    // vim 스타일 이동: 입력 중인 숫자 접두사와 gg의 첫 g
+    pub motion_count: Option<usize>,
+    pub motion_pending_g: bool,
+
+    // --allow-write로 시작한 경우에만 Name 태그 편집 허용
+    pub allow_write: bool,
+    pub name_tag_edit: Option<NameTagEdit>,
+
+    // 튜토리얼 모드: 진행 중인 단계 (F1 또는 --tutorial로 시작)
+    pub tutorial: Option<Tutorial>,
 }
 
 impl App {
@@ -246,6 +1008,11 @@ impl App {
         let blueprint_store = load_blueprints();
         let settings = load_settings();
         let i18n = I18n::new(settings.language);
+        let theme = Theme::new(resolve_palette(settings.palette));
+        let ascii = resolve_ascii(settings.ascii_mode);
+        let macros = KeyMacros::from_saved(&settings.key_macros);
+        aws_cli::set_max_concurrent_requests(settings.concurrency_limit());
+        apply_runtime_settings(&settings);
         Self {
             screen: Screen::Login,
             running: true,
@@ -264,6 +1031,8 @@ impl App {
 
             settings,
             i18n,
+            theme,
+            ascii,
             selected_setting: 0,
             selected_tab: 0,
 
@@ -273,25 +1042,144 @@ impl App {
             load_balancers: Vec::new(),
             ecr_repositories: Vec::new(),
             auto_scaling_groups: Vec::new(),
+            rds_databases: Vec::new(),
+            lambda_functions: Vec::new(),
+            dynamodb_tables: Vec::new(),
+            cloudfront_distributions: Vec::new(),
+            route53_zones: Vec::new(),
+            eks_clusters: Vec::new(),
+            ecs_clusters: Vec::new(),
+            sqs_queues: Vec::new(),
+            sns_topics: Vec::new(),
+            elasticache_clusters: Vec::new(),
+            cloudwatch_alarms: Vec::new(),
+            cloudtrail_trails: Vec::new(),
+            kms_keys: Vec::new(),
+            secrets: Vec::new(),
+            ssm_parameter_paths: Vec::new(),
+            efs_file_systems: Vec::new(),
+            api_gateways: Vec::new(),
+            beanstalk_environments: Vec::new(),
+            state_machines: Vec::new(),
+            kinesis_streams: Vec::new(),
+            msk_clusters: Vec::new(),
+            opensearch_domains: Vec::new(),
+            redshift_clusters: Vec::new(),
+            transit_gateways: Vec::new(),
+            vpc_peerings: Vec::new(),
+            vpns: Vec::new(),
+            direct_connect_resources: Vec::new(),
+            list_refreshes: HashMap::new(),
+            list_selections: HashMap::new(),
+            waf_web_acls: Vec::new(),
+            acm_certificates: Vec::new(),
+            cloudformation_stacks: Vec::new(),
+            iam_entities: Vec::new(),
+            vpc_endpoints: Vec::new(),
+            network_interfaces: Vec::new(),
+            launch_templates: Vec::new(),
+            amis: Vec::new(),
+            ebs_resources: Vec::new(),
+            key_pairs: Vec::new(),
+            event_buses: Vec::new(),
+            cognito_pools: Vec::new(),
+            glue_resources: Vec::new(),
+            athena_workgroups: Vec::new(),
+            backup_plans: Vec::new(),
             ec2_detail: None,
             network_detail: None,
             sg_detail: None,
             lb_detail: None,
             ecr_detail: None,
             asg_detail: None,
+            rds_detail: None,
+            lambda_detail: None,
+            dynamodb_detail: None,
+            cloudfront_detail: None,
+            route53_detail: None,
+            eks_detail: None,
+            ecs_detail: None,
+            sqs_detail: None,
+            sns_detail: None,
+            elasticache_detail: None,
+            cloudwatch_alarm_detail: None,
+            cloudtrail_detail: None,
+            kms_detail: None,
+            secret_detail: None,
+            ssm_parameter_detail: None,
+            efs_detail: None,
+            api_gateway_detail: None,
+            elastic_beanstalk_detail: None,
+            state_machine_detail: None,
+            kinesis_stream_detail: None,
+            msk_cluster_detail: None,
+            opensearch_domain_detail: None,
+            redshift_cluster_detail: None,
+            transit_gateway_detail: None,
+            vpc_peering_detail: None,
+            vpn_detail: None,
+            direct_connect_detail: None,
+            waf_web_acl_detail: None,
+            acm_certificate_detail: None,
+            cloudformation_stack_detail: None,
+            iam_entity_detail: None,
+            vpc_endpoint_detail: None,
+            network_interface_detail: None,
+            launch_template_detail: None,
+            ami_detail: None,
+            ebs_detail: None,
+            key_pair_detail: None,
+            event_bus_detail: None,
+            cognito_detail: None,
+            glue_detail: None,
+            athena_workgroup_detail: None,
+            backup_plan_detail: None,
 
             preview_content: String::new(),
             preview_filename: String::new(),
             preview_scroll: 0,
+            preview_wrap: true,
+            preview_hscroll: 0,
+            preview_raw: None,
             preview_drag_start: None,
 
             blueprint_store,
             selected_blueprint_index: 0,
             current_blueprint: None,
+            region_inventory: None,
             blueprint_mode: false,
             blueprint_resource_index: 0,
             input_buffer: String::new(),
             blueprint_markdown_parts: Vec::new(),
+            blueprint_resource_facts: Vec::new(),
+            blueprint_validation: Vec::new(),
+            blueprint_import_kind: BlueprintImportKind::Terraform,
+
+            search_results: Vec::new(),
+            selected_search_result: 0,
+
+            compare_base: None,
+            compare_target: None,
+            comparison: None,
+            compare_show_table: false,
+            compare_return_screen: Screen::ServiceSelect,
+
+            promotion_source: None,
+
+            selected_query_operation: 0,
+            query_output: None,
+            query_result: Ok(String::new()),
+            query_scroll: 0,
+
+            macros,
+            macro_queue: VecDeque::new(),
+
+            motion_count: None,
+            motion_pending_g: false,
+            allow_write: false,
+            name_tag_edit: None,
+
+            tutorial: None,
         }
     }
 
@@ -384,6 +1272,8 @@ impl App {
 
         aws_cli::set_aws_profile(&profile);
         self.settings.aws_profile = Some(profile.clone());
+        // 다른 계정의 목록을 재사용하지 않도록 비움
+        self.list_refreshes.clear();
         if let Err(error) = save_settings(&self.settings) {
             tracing::warn!(error = %error, profile = %profile, "Failed to persist selected profile");
         }
@@ -399,6 +1289,10 @@ impl App {
             Ok(info) => {
                 self.login_info = Some(info);
                 self.login_error = None;
+                // 다른 파티션 계정으로 바뀌면 리전 목록도 바뀜
+                if self.selected_region >= self.regions().len() {
+                    self.selected_region = 0;
+                }
                 self.screen = Screen::BlueprintSelect;
                 tracing::info!("Login check passed; screen moved to BlueprintSelect");
             }
@@ -472,6 +1366,33 @@ impl App {
         }
     }
 
+    pub fn start_tutorial(&mut self) {
+        let mut tutorial = Tutorial::new();
+        // 이미 로그인한 상태에서 시작하면 완료한 단계는 건너뜀
+        tutorial.observe(&self.screen);
+        self.tutorial = Some(tutorial);
+        self.message = self.i18n.tutorial_started().to_string();
+    }
+
+    pub fn toggle_tutorial(&mut self) {
+        if self.tutorial.take().is_some() {
+            self.message = self.i18n.tutorial_exited().to_string();
+        } else {
+            self.start_tutorial();
+        }
+    }
+
+    /// Move the tutorial forward when the user reaches the screen of the next step.
+    pub fn advance_tutorial(&mut self) {
+        let Some(tutorial) = self.tutorial.as_mut() else {
+            return;
+        };
+        if tutorial.observe(&self.screen) {
+            self.tutorial = None;
+            self.message = self.i18n.tutorial_finished().to_string();
+        }
+    }
+
     pub fn check_login_if_needed_for_current_screen(&mut self) {
         if self.screen == Screen::Login {
             return;
@@ -480,14 +1401,86 @@ impl App {
     }
 
     pub fn select_region(&mut self) {
-        let region = REGIONS[self.selected_region].code;
+        let region = self.region().code;
         aws_cli::set_region(region);
         self.screen = Screen::ServiceSelect;
     }
 
     pub fn save_file(&mut self) -> Result<(), std::io::Error> {
-        crate::output::save_markdown(&self.preview_filename, &self.preview_content)?;
+        // 원본 JSON 보기에서는 같은 이름의 .json 파일로 저장 (문서 색인 제외)
+        if let Some(raw) = &self.preview_raw {
+            let filename = std::path::Path::new(&self.preview_filename)
+                .with_extension("json")
+                .to_string_lossy()
+                .to_string();
+            crate::output::save_markdown(&filename, raw)?;
+            self.message = format!("{}: {}", self.i18n.save_complete(), filename);
+            return Ok(());
+        }
+        let (content, diagram_error) = crate::diagram::render_if_configured(
+            &self.preview_content,
+            &self.preview_filename,
+            self.settings.diagram_renderer.as_ref(),
+        );
+        crate::output::save_markdown(&self.preview_filename, &content)?;
+        if let Err(error) = crate::doc_index::index_document(
+            &self.preview_filename,
+            &content,
+            self.settings.encrypt_at_rest,
+        ) {
+            tracing::warn!(error = %error, "Failed to index saved document");
+        }
         self.message = format!("{}: {}", self.i18n.save_complete(), self.preview_filename);
+        // 다이어그램 이미지를 만들지 못해도 문서는 Mermaid 그대로 저장됨
+        if let Some(error) = diagram_error {
+            self.message = format!(
+                "{} ({})",
+                self.message,
+                self.i18n.diagram_render_failed(&error)
+            );
+        }
+        Ok(())
+    }
+
+    // 입력한 검색어로 저장한 문서 검색
+    pub fn search_documents(&mut self) {
+        let query = self.input_buffer.trim().to_string();
+        if query.is_empty() {
+            return;
+        }
+        self.search_results =
+            crate::doc_index::search_documents(&query, self.settings.encrypt_at_rest);
+        self.selected_search_result = 0;
+        self.message = if self.search_results.is_empty() {
+            self.i18n.no_search_results().to_string()
+        } else {
+            self.i18n.search_result_count(self.search_results.len())
+        };
+    }
+
+    // 실행한 작업 출력에 현재 쿼리를 다시 적용 (키 입력마다 호출)
+    pub fn apply_query(&mut self) {
+        if let Some(output) = &self.query_output {
+            self.query_result = crate::query_playground::apply_query(output, &self.input_buffer);
+            self.query_scroll = 0;
+        }
+    }
+
+    pub fn query_command(&self) -> String {
+        let (service, operation) = QUERY_OPERATIONS[self.selected_query_operation];
+        crate::query_playground::cli_command(service, operation, &self.input_buffer)
+    }
+
+    // 쿼리 명령과 결과를 마크다운 문서로 저장
+    pub fn save_query_result(&mut self) -> std::io::Result<()> {
+        let Ok(result) = &self.query_result else {
+            return Ok(());
+        };
+        let (service, operation) = QUERY_OPERATIONS[self.selected_query_operation];
+        let filename = format!("query-{}-{}.md", service, operation);
+        let content = crate::query_playground::to_markdown(&self.query_command(), result);
+        crate::output::save_markdown(&filename, &content)?;
+        self.message = format!("{}: {}", self.i18n.save_complete(), filename);
         Ok(())
     }
 
@@ -498,6 +1491,184 @@ impl App {
         self.save_settings();
     }
 
+    // 저장소와 검색 색인을 즉시 다시 저장해 암호화/복호화를 반영
+    pub fn toggle_encrypt_at_rest(&mut self) {
+        self.settings.encrypt_at_rest = !self.settings.encrypt_at_rest;
+        if let Err(error) = save_blueprints(&self.blueprint_store, self.settings.encrypt_at_rest) {
+            tracing::warn!(error = %error, "Failed to re-save blueprint store");
+            self.settings.encrypt_at_rest = !self.settings.encrypt_at_rest;
+            self.message = self.i18n.encryption_toggle_failed(&error.to_string());
+            return;
+        }
+        if let Err(error) = crate::doc_index::resave_index(self.settings.encrypt_at_rest) {
+            tracing::warn!(error = %error, "Failed to re-save search index");
+        }
+        self.save_settings();
+    }
+
+    pub fn toggle_hide_terminated_instances(&mut self) {
+        self.settings.hide_terminated_instances = !self.settings.hide_terminated_instances;
+        // 필터가 바뀌었으므로 EC2 목록은 다시 조회
+        self.list_refreshes.remove(&Screen::Ec2Select);
+        self.save_settings();
+    }
+
+    pub fn toggle_sort_services_by_usage(&mut self) {
+        self.settings.sort_services_by_usage = !self.settings.sort_services_by_usage;
+        self.save_settings();
+    }
+
+    // NO_COLOR가 설정된 동안에는 저장만 하고 화면은 계속 색상 없음으로 표시
+    pub fn cycle_palette(&mut self) {
+        self.settings.palette = self.settings.palette.next();
+        self.theme = Theme::new(resolve_palette(self.settings.palette));
+        self.save_settings();
+    }
+
+    // Auto로 돌아오면 현재 터미널 기준으로 다시 감지
+    pub fn cycle_ascii_mode(&mut self) {
+        self.settings.ascii_mode = self.settings.ascii_mode.next();
+        self.ascii = resolve_ascii(self.settings.ascii_mode);
+        self.save_settings();
+    }
+
+    // 1 → 2 → 4 → 8 → 16 → 1, 다음 조회부터 바로 적용
+    pub fn cycle_max_concurrent_requests(&mut self) {
+        let limit = next_concurrency_limit(self.settings.concurrency_limit());
+        self.settings.max_concurrent_requests = Some(limit);
+        aws_cli::set_max_concurrent_requests(limit);
+        self.save_settings();
+    }
+
+    // 이후에 만드는 문서부터 적용 (열려 있는 미리보기는 다시 조회해야 바뀜)
+    pub fn cycle_number_locale(&mut self) {
+        self.settings.number_locale = self.settings.number_locale.next();
+        apply_runtime_settings(&self.settings);
+        self.save_settings();
+    }
+
+    pub fn toggle_spell_out_schedules(&mut self) {
+        self.settings.spell_out_schedules = !self.settings.spell_out_schedules;
+        apply_runtime_settings(&self.settings);
+        self.save_settings();
+    }
+
+    pub fn toggle_normalize_security_group_rules(&mut self) {
+        self.settings.normalize_security_group_rules =
+            !self.settings.normalize_security_group_rules;
+        apply_runtime_settings(&self.settings);
+        self.save_settings();
+    }
+
+    pub fn toggle_stamp_last_modified(&mut self) {
+        self.settings.stamp_last_modified = !self.settings.stamp_last_modified;
+        apply_runtime_settings(&self.settings);
+        self.save_settings();
+    }
+
+    pub fn toggle_show_resource_age(&mut self) {
+        self.settings.show_resource_age = !self.settings.show_resource_age;
+        apply_runtime_settings(&self.settings);
+        self.save_settings();
+    }
+
+    /// `SERVICE_KEYS` indices in ServiceSelect display order: pinned services first,
+    /// then the rest (most used first when usage sorting is on). Hidden services are left out.
+    pub fn service_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::new();
+        for name in &self.settings.pinned_services {
+            // settings.json을 직접 편집한 경우 없는 이름이나 중복은 무시
+            if let Some(idx) = SERVICE_KEYS.iter().position(|key| key == name)
+                && !order.contains(&idx)
+                && self.is_service_enabled(idx)
+            {
+                order.push(idx);
+            }
+        }
+
+        let mut rest: Vec<usize> = (0..SERVICE_KEYS.len())
+            .filter(|idx| !order.contains(idx) && self.is_service_enabled(*idx))
+            .collect();
+        if self.settings.sort_services_by_usage {
+            // 안정 정렬이므로 선택 횟수가 같으면 기본 순서 유지
+            rest.sort_by_key(|&idx| {
+                std::cmp::Reverse(
+                    self.settings
+                        .service_usage
+                        .get(SERVICE_KEYS[idx])
+                        .copied()
+                        .unwrap_or(0),
+                )
+            });
+        }
+        order.extend(rest);
+        order
+    }
+
+    // 커서 위치의 SERVICE_KEYS 인덱스 (종료 항목은 SERVICE_KEYS.len())
+    pub fn selected_service_key(&self) -> usize {
+        self.service_order()
+            .get(self.selected_service)
+            .copied()
+            .unwrap_or(SERVICE_KEYS.len())
+    }
+
+    pub fn is_service_enabled(&self, service: usize) -> bool {
+        SERVICE_KEYS.get(service).is_some_and(|name| {
+            self.settings
+                .service_enabled(name, EXPERIMENTAL_SERVICE_KEYS)
+        })
+    }
+
+    pub fn is_service_pinned(&self, service: usize) -> bool {
+        SERVICE_KEYS
+            .get(service)
+            .is_some_and(|name| self.settings.pinned_services.iter().any(|p| p == name))
+    }
+
+    // 커서 위치의 서비스를 고정/해제하고, 커서는 같은 서비스를 따라감
+    pub fn toggle_pinned_service(&mut self) {
+        let service = self.selected_service_key();
+        let Some(name) = SERVICE_KEYS.get(service) else {
+            return;
+        };
+        if self.is_service_pinned(service) {
+            self.settings.pinned_services.retain(|p| p != name);
+        } else {
+            self.settings.pinned_services.push(name.to_string());
+        }
+        self.select_service(service);
+        self.save_settings();
+    }
+
+    fn select_service(&mut self, service: usize) {
+        self.selected_service = self
+            .service_order()
+            .iter()
+            .position(|&idx| idx == service)
+            .unwrap_or(0);
+    }
+
+    // 사용 빈도 정렬이 켜져 있을 때만 집계해 저장
+    pub fn record_service_usage(&mut self, service: usize) {
+        if !self.settings.sort_services_by_usage {
+            return;
+        }
+        let Some(name) = SERVICE_KEYS.get(service) else {
+            return;
+        };
+        *self
+            .settings
+            .service_usage
+            .entry(name.to_string())
+            .or_default() += 1;
+        // 순서가 바뀌어도 서비스 목록으로 돌아왔을 때 같은 서비스에 커서 유지
+        self.select_service(service);
+        if let Err(error) = save_settings(&self.settings) {
+            tracing::warn!(error = %error, "Failed to save service usage");
+        }
+    }
+
     pub fn save_settings(&mut self) {
         if save_settings(&self.settings).is_ok() {
             self.message = self.i18n.settings_saved().to_string();
@@ -506,11 +1677,194 @@ impl App {
 
     // Blueprint methods
     pub fn save_blueprints(&mut self) {
-        if save_blueprints(&self.blueprint_store).is_err() {
+        if save_blueprints(&self.blueprint_store, self.settings.encrypt_at_rest).is_err() {
             self.message = self.i18n.blueprint_save_failed().to_string();
         } else {
             self.message = self.i18n.blueprint_saved().to_string();
         }
+        if self.settings.blueprint_remote.is_some() {
+            self.sync_blueprints();
+        }
+    }
+
+    // 원격 저장소와 병합(last-writer-wins) 후 로컬에도 반영
+    pub fn sync_blueprints(&mut self) {
+        let Some(remote) = self.settings.blueprint_remote.clone() else {
+            self.message = self.i18n.remote_store_not_configured().to_string();
+            return;
+        };
+
+        if let Err(error) = sync_remote_blueprints(&mut self.blueprint_store, &remote) {
+            tracing::warn!(error = %error, "Remote blueprint sync failed");
+            self.message = self.i18n.blueprint_sync_failed(&error);
+            return;
+        }
+        if save_blueprints(&self.blueprint_store, self.settings.encrypt_at_rest).is_err() {
+            self.message = self.i18n.blueprint_save_failed().to_string();
+            return;
+        }
+        self.reselect_blueprint_after_sync();
+        self.message = self.i18n.blueprints_synced().to_string();
+    }
+
+    // 미리보기 중인 리소스(블루프린트 미리보기면 모든 리소스)를 Notion 페이지로 내보내기
+    pub fn export_to_notion(&mut self) {
+        let Some(config) = self.settings.notion_export.clone() else {
+            self.message = self.i18n.notion_not_configured().to_string();
+            return;
+        };
+        let pages = self.notion_pages();
+        if pages.is_empty() {
+            self.message = self.i18n.notion_nothing_to_export().to_string();
+            return;
+        }
+
+        match crate::notion::export_pages(&config, &pages) {
+            Ok(count) => self.message = self.i18n.notion_exported(count),
+            Err(error) => {
+                tracing::warn!(error = %error, "Notion export failed");
+                self.message = self.i18n.notion_export_failed(&error);
+            }
+        }
+    }
+
+    fn notion_pages(&self) -> Vec<NotionPage> {
+        if self.screen == Screen::BlueprintPreview {
+            let Some(blueprint) = &self.current_blueprint else {
+                return Vec::new();
+            };
+            return blueprint
+                .resources
+                .iter()
+                .zip(&self.blueprint_markdown_parts)
+                .map(|(resource, markdown)| NotionPage {
+                    title: resource.resource_name.clone(),
+                    resource_type: resource.resource_type.display().to_string(),
+                    region: resource.region.clone(),
+                    resource_id: resource.resource_id.clone(),
+                    markdown: markdown.clone(),
+                })
+                .collect();
+        }
+
+        match (
+            self.get_current_resource_type(),
+            self.get_current_resource_info(),
+        ) {
+            (Some(resource_type), Some((resource_id, name))) => vec![NotionPage {
+                title: name,
+                resource_type: resource_type.display().to_string(),
+                region: self.get_current_region(),
+                resource_id,
+                markdown: self.preview_content.clone(),
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    // 미리보기 중인 리소스(블루프린트 미리보기면 모든 리소스)를 Backstage 엔티티로 저장
+    pub fn export_backstage_catalog(&mut self) -> std::io::Result<()> {
+        let resources = self.catalog_resources();
+        if resources.is_empty() {
+            self.message = self.i18n.backstage_nothing_to_export().to_string();
+            return Ok(());
+        }
+        let content =
+            crate::backstage::catalog_info(&resources, self.settings.backstage_catalog.as_ref());
+        crate::output::save_markdown(crate::backstage::CATALOG_FILENAME, &content)?;
+        self.message = self
+            .i18n
+            .backstage_exported(resources.len(), crate::backstage::CATALOG_FILENAME);
+        Ok(())
+    }
+
+    // 미리보기 중인 리소스(블루프린트 미리보기면 모든 리소스)를 CycloneDX 인벤토리로 저장
+    pub fn export_inventory(&mut self) -> std::io::Result<()> {
+        let resources = self.catalog_resources();
+        if resources.is_empty() {
+            self.message = self.i18n.inventory_nothing_to_export().to_string();
+            return Ok(());
+        }
+        // 문서에 다른 리소스 ID가 나오면 관계로 기록
+        let (title, markdown) = if self.screen == Screen::BlueprintPreview
+            && let Some(blueprint) = &self.current_blueprint
+        {
+            (
+                blueprint.name.clone(),
+                self.blueprint_markdown_parts.clone(),
+            )
+        } else {
+            (
+                resources[0].resource_name.clone(),
+                vec![self.preview_content.clone()],
+            )
+        };
+        let content = crate::inventory::cyclonedx_inventory(&title, &resources, &markdown);
+        crate::output::save_markdown(crate::inventory::INVENTORY_FILENAME, &content)?;
+        self.message = self
+            .i18n
+            .inventory_exported(resources.len(), crate::inventory::INVENTORY_FILENAME);
+        Ok(())
+    }
+
+    fn catalog_resources(&self) -> Vec<BlueprintResource> {
+        if self.screen == Screen::BlueprintPreview {
+            return self
+                .current_blueprint
+                .as_ref()
+                .map(|blueprint| blueprint.resources.clone())
+                .unwrap_or_default();
+        }
+
+        match (
+            self.get_current_resource_type(),
+            self.get_current_resource_info(),
+        ) {
+            (Some(resource_type), Some((resource_id, resource_name))) => vec![BlueprintResource {
+                resource_type,
+                region: self.get_current_region(),
+                resource_id,
+                resource_name,
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    // 병합으로 순서/내용이 바뀔 수 있으므로 현재 블루프린트를 id로 다시 찾음
+    fn reselect_blueprint_after_sync(&mut self) {
+        let len = self.blueprint_store.blueprints.len();
+        if self.screen == Screen::BlueprintSelect {
+            self.selected_blueprint_index = self.selected_blueprint_index.min(len);
+            return;
+        }
+        let Some(current_id) = self.current_blueprint.as_ref().map(|bp| bp.id.clone()) else {
+            return;
+        };
+        match self
+            .blueprint_store
+            .blueprints
+            .iter()
+            .position(|bp| bp.id == current_id)
+        {
+            Some(index) => {
+                let merged = self.blueprint_store.blueprints[index].clone();
+                if merged.resources.len() != self.blueprint_validation.len() {
+                    self.blueprint_validation.clear();
+                }
+                self.blueprint_resource_index = self
+                    .blueprint_resource_index
+                    .min(merged.resources.len().saturating_sub(1));
+                self.selected_blueprint_index = index;
+                self.current_blueprint = Some(merged);
+            }
+            None => {
+                // 다른 사용자가 삭제함
+                self.current_blueprint = None;
+                self.blueprint_validation.clear();
+                self.selected_blueprint_index = 0;
+                self.screen = Screen::BlueprintSelect;
+            }
+        }
     }
 
     pub fn create_blueprint(&mut self, name: String) {
@@ -520,6 +1874,112 @@ impl App {
         self.save_blueprints();
     }
 
+    // Terraform 상태 파일에서 블루프린트를 만들고 상세 화면으로 이동
+    pub fn import_terraform_blueprint(&mut self, path: &str) {
+        let region = self.get_current_region();
+        let result = crate::terraform::import_blueprint(path, &region);
+        if let Err(error) = &result {
+            tracing::warn!(error = %error, path, "Terraform state import failed");
+        }
+        let empty_message = self.i18n.terraform_no_resources();
+        self.finish_blueprint_import(result, empty_message);
+    }
+
+    // CloudFormation 스택 리소스 목록으로 블루프린트를 만들고 상세 화면으로 이동
+    pub fn import_cloudformation_blueprint(
+        &mut self,
+        stack_name: &str,
+        resources: Result<Vec<StackResource>, String>,
+    ) {
+        let region = self.get_current_region();
+        let result = resources.map(|resources| {
+            crate::cloudformation::blueprint_from_stack(stack_name, &resources, &region)
+        });
+        if let Err(error) = &result {
+            tracing::warn!(error = %error, stack_name, "CloudFormation stack import failed");
+        }
+        let empty_message = self.i18n.stack_no_resources();
+        self.finish_blueprint_import(result, empty_message);
+    }
+
+    // 태그 조회 결과로 블루프린트를 만들고 상세 화면으로 이동
+    pub fn import_tag_query_blueprint(
+        &mut self,
+        query: &str,
+        resources: Result<Vec<TaggedResource>, String>,
+    ) {
+        let result = resources
+            .map(|resources| crate::tag_query::blueprint_from_tagged_resources(query, &resources));
+        if let Err(error) = &result {
+            tracing::warn!(error = %error, query, "Tag query import failed");
+        }
+        let empty_message = self.i18n.tag_query_no_resources();
+        self.finish_blueprint_import(result, empty_message);
+    }
+
+    fn finish_blueprint_import(&mut self, result: Result<Blueprint, String>, empty_message: &str) {
+        match result {
+            Ok(blueprint) if blueprint.resources.is_empty() => {
+                self.message = empty_message.to_string();
+            }
+            Ok(blueprint) => {
+                let count = blueprint.resources.len();
+                self.open_imported_blueprint(blueprint);
+                self.message = self.i18n.blueprint_imported(count);
+            }
+            Err(error) => {
+                self.message = self.i18n.blueprint_import_failed(&error);
+            }
+        }
+    }
+
+    fn open_imported_blueprint(&mut self, blueprint: Blueprint) {
+        self.blueprint_store.add_blueprint(blueprint.clone());
+        self.selected_blueprint_index = self.blueprint_store.blueprints.len() - 1;
+        self.current_blueprint = Some(blueprint);
+        self.blueprint_resource_index = 0;
+        self.blueprint_validation.clear();
+        self.save_blueprints();
+        self.screen = Screen::BlueprintDetail;
+    }
+
+    // 리전 문서화 대상: 숨기지 않은 서비스의 목록 새로고침 작업 (서비스 목록 순서)
+    pub fn region_inventory_tasks(&self) -> Vec<(Screen, LoadingTask)> {
+        let hidden = hidden_services(&self.settings);
+        LIST_REFRESH_TASKS
+            .iter()
+            .filter(|(_, service, _)| !hidden.contains(service))
+            .map(|(screen, _, task)| (screen.clone(), task.clone()))
+            .collect()
+    }
+
+    // 조회한 서비스 목록을 리전 문서화 블루프린트에 추가
+    pub fn add_region_inventory_resources(&mut self, screen: &Screen) {
+        let region = self.get_current_region();
+        let Some((resource_type, list)) = self.resource_list(screen) else {
+            return;
+        };
+        let resources: Vec<BlueprintResource> = list
+            .iter()
+            .map(|resource| BlueprintResource {
+                resource_type: resource_type.clone(),
+                region: region.clone(),
+                resource_id: resource.id.clone(),
+                resource_name: resource.name.clone(),
+            })
+            .collect();
+        if let Some(inventory) = self.region_inventory.as_mut() {
+            inventory.resources.extend(resources);
+        }
+    }
+
+    // 마크다운을 생성 중인 블루프린트 (리전 문서화 중이면 임시 블루프린트)
+    pub fn documenting_blueprint(&self) -> Option<&Blueprint> {
+        self.region_inventory
+            .as_ref()
+            .or(self.current_blueprint.as_ref())
+    }
+
     pub fn delete_blueprint(&mut self, index: usize) {
         self.blueprint_store.remove_blueprint(index);
         if self.selected_blueprint_index >= self.blueprint_store.blueprints.len()
@@ -549,6 +2009,9 @@ impl App {
     pub fn remove_resource_from_current_blueprint(&mut self, index: usize) {
         if let Some(ref mut blueprint) = self.current_blueprint {
             blueprint.remove_resource(index);
+            if index < self.blueprint_validation.len() {
+                self.blueprint_validation.remove(index);
+            }
             // Update in store
             if let Some(stored) = self
                 .blueprint_store
@@ -569,6 +2032,9 @@ impl App {
             && index < blueprint.resources.len()
         {
             blueprint.resources.swap(index, index - 1);
+            if index < self.blueprint_validation.len() {
+                self.blueprint_validation.swap(index, index - 1);
+            }
             // Update in store
             if let Some(stored) = self
                 .blueprint_store
@@ -587,6 +2053,9 @@ impl App {
             && index + 1 < blueprint.resources.len()
         {
             blueprint.resources.swap(index, index + 1);
+            if index + 1 < self.blueprint_validation.len() {
+                self.blueprint_validation.swap(index, index + 1);
+            }
             // Update in store
             if let Some(stored) = self
                 .blueprint_store
@@ -600,10 +2069,259 @@ impl App {
         false
     }
 
-    pub fn get_current_resource_type(&self) -> Option<ResourceType> {
-        if self.ec2_detail.is_some() {
-            Some(ResourceType::Ec2)
-        } else if self.network_detail.is_some() {
+    // 이름이나 리전이 바뀐 리소스를 현재 값으로 갱신하고 갱신된 개수를 반환
+    pub fn remap_renamed_resources(&mut self) -> usize {
+        let mut remapped = 0;
+        if let Some(ref mut blueprint) = self.current_blueprint {
+            for (index, status) in self.blueprint_validation.iter_mut().enumerate() {
+                match status {
+                    ResourceStatus::Renamed(name) => blueprint.rename_resource(index, name.clone()),
+                    ResourceStatus::Moved(region) => {
+                        blueprint.relocate_resource(index, region.clone())
+                    }
+                    _ => continue,
+                }
+                *status = ResourceStatus::Valid;
+                remapped += 1;
+            }
+            if remapped > 0 {
+                if let Some(stored) = self
+                    .blueprint_store
+                    .get_blueprint_mut(self.selected_blueprint_index)
+                {
+                    *stored = blueprint.clone();
+                }
+                self.save_blueprints();
+            }
+        }
+        remapped
+    }
+
+    // 더 이상 존재하지 않는 리소스를 제거하고 제거된 개수를 반환
+    pub fn remove_missing_resources(&mut self) -> usize {
+        let missing: Vec<usize> = self
+            .blueprint_validation
+            .iter()
+            .enumerate()
+            .filter(|(_, status)| **status == ResourceStatus::Missing)
+            .map(|(index, _)| index)
+            .collect();
+        for index in missing.iter().rev() {
+            self.remove_resource_from_current_blueprint(*index);
+        }
+        missing.len()
+    }
+
+    pub fn record_list_refresh(&mut self, screen: Screen) {
+        self.list_refreshes.insert(
+            screen,
+            ListRefresh {
+                refreshed_at: Instant::now(),
+                region: self.selected_region,
+                cached: false,
+            },
+        );
+    }
+
+    /// Show a list fetched within `list_cache_seconds` in the current region
+    /// instead of fetching it again. Returns `false` when a fetch is needed.
+    pub fn open_cached_list(&mut self, screen: Screen) -> bool {
+        let ttl = self.settings.list_cache_ttl();
+        let Some(refresh) = self.list_refreshes.get_mut(&screen) else {
+            return false;
+        };
+        if refresh.region != self.selected_region || refresh.refreshed_at.elapsed() >= ttl {
+            return false;
+        }
+        refresh.cached = true;
+        self.screen = screen;
+        self.selected_index = 0;
+        let remembered = self.remembered_list_selection();
+        self.restore_list_cursor(remembered.as_deref());
+        true
+    }
+
+    // 현재 목록 화면의 마지막 조회 정보
+    pub fn current_list_refresh(&self) -> Option<&ListRefresh> {
+        self.list_refreshes.get(&self.screen)
+    }
+
+    /// Refresh task for the current list screen once its `auto_refresh_seconds`
+    /// interval has passed since the list was last fetched.
+    pub fn due_auto_refresh(&self) -> Option<LoadingTask> {
+        let (_, service, task) = LIST_REFRESH_TASKS
+            .iter()
+            .find(|(screen, _, _)| *screen == self.screen)?;
+        let interval = self.settings.auto_refresh_interval(service)?;
+        let due = self
+            .current_list_refresh()
+            .is_none_or(|refresh| refresh.refreshed_at.elapsed() >= interval);
+        due.then(|| task.clone())
+    }
+
+    // 현재 목록 화면에서 커서가 가리키는 리소스 ID
+    pub fn selected_list_id(&self) -> Option<String> {
+        let (_, resources) = self.current_resource_list()?;
+        resources
+            .get(self.selected_index)
+            .map(|resource| resource.id.clone())
+    }
+
+    // 현재 목록 화면에서 선택한 리소스를 서비스별로 기억
+    pub fn remember_list_selection(&mut self) {
+        if let Some(id) = self.selected_list_id() {
+            self.list_selections.insert(self.screen.clone(), id);
+        }
+    }
+
+    // 현재 목록 화면에서 마지막으로 선택했던 리소스 ID
+    pub fn remembered_list_selection(&self) -> Option<String> {
+        self.list_selections.get(&self.screen).cloned()
+    }
+
+    // 새로고침 후 같은 리소스에 커서를 두고, 목록에서 사라졌으면 범위 안으로 맞춤
+    pub fn restore_list_cursor(&mut self, selected_id: Option<&str>) {
+        let Some((_, resources)) = self.current_resource_list() else {
+            return;
+        };
+        self.selected_index = selected_id
+            .and_then(|id| resources.iter().position(|resource| resource.id == id))
+            .unwrap_or_else(|| self.selected_index.min(resources.len().saturating_sub(1)));
+    }
+
+    // 미리보기를 새로 조회한 뒤 이전 스크롤 위치로 돌아가되, 내용이 짧아졌으면 마지막 줄에 맞춤
+    pub fn restore_preview_scroll(&mut self, scroll: u16) {
+        let content_lines = self.preview_text().lines().count() as u16;
+        self.preview_scroll = scroll.min(content_lines.saturating_sub(1));
+    }
+
+    // 현재 목록 화면의 리소스 종류와 목록
+    pub fn current_resource_list(&self) -> Option<(ResourceType, &[AwsResource])> {
+        self.resource_list(&self.screen)
+    }
+
+    // 목록 화면별 리소스 종류와 마지막으로 조회한 목록
+    pub fn resource_list(&self, screen: &Screen) -> Option<(ResourceType, &[AwsResource])> {
+        match screen {
+            Screen::Ec2Select => Some((ResourceType::Ec2, &self.instances)),
+            Screen::VpcSelect => Some((ResourceType::Network, &self.vpcs)),
+            Screen::SecurityGroupSelect => {
+                Some((ResourceType::SecurityGroup, &self.security_groups))
+            }
+            Screen::LoadBalancerSelect => Some((ResourceType::LoadBalancer, &self.load_balancers)),
+            Screen::EcrSelect => Some((ResourceType::Ecr, &self.ecr_repositories)),
+            Screen::AsgSelect => Some((ResourceType::Asg, &self.auto_scaling_groups)),
+            Screen::RdsSelect => Some((ResourceType::Rds, &self.rds_databases)),
+            Screen::LambdaSelect => Some((ResourceType::Lambda, &self.lambda_functions)),
+            Screen::DynamoDbSelect => Some((ResourceType::DynamoDb, &self.dynamodb_tables)),
+            Screen::CloudFrontSelect => {
+                Some((ResourceType::CloudFront, &self.cloudfront_distributions))
+            }
+            Screen::Route53Select => Some((ResourceType::Route53, &self.route53_zones)),
+            Screen::EksSelect => Some((ResourceType::Eks, &self.eks_clusters)),
+            Screen::EcsSelect => Some((ResourceType::Ecs, &self.ecs_clusters)),
+            Screen::SqsSelect => Some((ResourceType::Sqs, &self.sqs_queues)),
+            Screen::SnsSelect => Some((ResourceType::Sns, &self.sns_topics)),
+            Screen::ElastiCacheSelect => {
+                Some((ResourceType::ElastiCache, &self.elasticache_clusters))
+            }
+            Screen::CloudWatchAlarmSelect => {
+                Some((ResourceType::CloudWatchAlarm, &self.cloudwatch_alarms))
+            }
+            Screen::CloudTrailSelect => Some((ResourceType::CloudTrail, &self.cloudtrail_trails)),
+            Screen::KmsSelect => Some((ResourceType::Kms, &self.kms_keys)),
+            Screen::SecretSelect => Some((ResourceType::Secret, &self.secrets)),
+            Screen::SsmParameterSelect => {
+                Some((ResourceType::SsmParameter, &self.ssm_parameter_paths))
+            }
+            Screen::EfsSelect => Some((ResourceType::Efs, &self.efs_file_systems)),
+            Screen::ApiGatewaySelect => Some((ResourceType::ApiGateway, &self.api_gateways)),
+            Screen::ElasticBeanstalkSelect => {
+                Some((ResourceType::ElasticBeanstalk, &self.beanstalk_environments))
+            }
+            Screen::StepFunctionsSelect => {
+                Some((ResourceType::StepFunctions, &self.state_machines))
+            }
+            Screen::KinesisSelect => Some((ResourceType::Kinesis, &self.kinesis_streams)),
+            Screen::MskSelect => Some((ResourceType::Msk, &self.msk_clusters)),
+            Screen::OpenSearchSelect => Some((ResourceType::OpenSearch, &self.opensearch_domains)),
+            Screen::RedshiftSelect => Some((ResourceType::Redshift, &self.redshift_clusters)),
+            Screen::TransitGatewaySelect => {
+                Some((ResourceType::TransitGateway, &self.transit_gateways))
+            }
+            Screen::VpcPeeringSelect => Some((ResourceType::VpcPeering, &self.vpc_peerings)),
+            Screen::VpnSelect => Some((ResourceType::Vpn, &self.vpns)),
+            Screen::DirectConnectSelect => {
+                Some((ResourceType::DirectConnect, &self.direct_connect_resources))
+            }
+            Screen::WafSelect => Some((ResourceType::Waf, &self.waf_web_acls)),
+            Screen::AcmSelect => Some((ResourceType::Acm, &self.acm_certificates)),
+            Screen::CloudFormationSelect => {
+                Some((ResourceType::CloudFormation, &self.cloudformation_stacks))
+            }
+            Screen::IamSelect => Some((ResourceType::Iam, &self.iam_entities)),
+            Screen::VpcEndpointSelect => Some((ResourceType::VpcEndpoint, &self.vpc_endpoints)),
+            Screen::NetworkInterfaceSelect => {
+                Some((ResourceType::NetworkInterface, &self.network_interfaces))
+            }
+            Screen::LaunchTemplateSelect => {
+                Some((ResourceType::LaunchTemplate, &self.launch_templates))
+            }
+            Screen::AmiSelect => Some((ResourceType::Ami, &self.amis)),
+            Screen::EbsSelect => Some((ResourceType::Ebs, &self.ebs_resources)),
+            Screen::KeyPairSelect => Some((ResourceType::KeyPair, &self.key_pairs)),
+            Screen::EventBridgeSelect => Some((ResourceType::EventBridge, &self.event_buses)),
+            Screen::CognitoSelect => Some((ResourceType::Cognito, &self.cognito_pools)),
+            Screen::GlueSelect => Some((ResourceType::Glue, &self.glue_resources)),
+            Screen::AthenaSelect => Some((ResourceType::Athena, &self.athena_workgroups)),
+            Screen::BackupSelect => Some((ResourceType::Backup, &self.backup_plans)),
+            _ => None,
+        }
+    }
+
+    /// Mark the highlighted list entry for comparison. Returns `true` once a
+    /// second resource of the same type is chosen and the diff can be loaded.
+    pub fn mark_for_compare(&mut self) -> bool {
+        let Some((resource_type, resources)) = self.current_resource_list() else {
+            return false;
+        };
+        let Some(selected) = resources.get(self.selected_index) else {
+            return false;
+        };
+        let resource = BlueprintResource {
+            resource_type,
+            region: self.get_current_region(),
+            resource_id: selected.id.clone(),
+            resource_name: selected.name.clone(),
+        };
+
+        match self.compare_base.take() {
+            Some(base)
+                if base.resource_type == resource.resource_type
+                    && base.resource_id != resource.resource_id =>
+            {
+                self.compare_base = Some(base);
+                self.compare_target = Some(resource);
+                true
+            }
+            // 같은 리소스를 다시 고르면 선택 해제
+            Some(base) if base.resource_type == resource.resource_type => {
+                self.message = self.i18n.compare_cleared().to_string();
+                false
+            }
+            // 처음 고르거나 종류가 다르면 새 기준으로 지정
+            _ => {
+                self.message = self.i18n.compare_base_marked(&resource.resource_name);
+                self.compare_base = Some(resource);
+                false
+            }
+        }
+    }
+
+    pub fn get_current_resource_type(&self) -> Option<ResourceType> {
+        if self.ec2_detail.is_some() {
+            Some(ResourceType::Ec2)
+        } else if self.network_detail.is_some() {
             Some(ResourceType::Network)
         } else if self.sg_detail.is_some() {
             Some(ResourceType::SecurityGroup)
@@ -613,6 +2331,90 @@ impl App {
             Some(ResourceType::Ecr)
         } else if self.asg_detail.is_some() {
             Some(ResourceType::Asg)
+        } else if self.rds_detail.is_some() {
+            Some(ResourceType::Rds)
+        } else if self.lambda_detail.is_some() {
+            Some(ResourceType::Lambda)
+        } else if self.dynamodb_detail.is_some() {
+            Some(ResourceType::DynamoDb)
+        } else if self.cloudfront_detail.is_some() {
+            Some(ResourceType::CloudFront)
+        } else if self.route53_detail.is_some() {
+            Some(ResourceType::Route53)
+        } else if self.eks_detail.is_some() {
+            Some(ResourceType::Eks)
+        } else if self.ecs_detail.is_some() {
+            Some(ResourceType::Ecs)
+        } else if self.sqs_detail.is_some() {
+            Some(ResourceType::Sqs)
+        } else if self.sns_detail.is_some() {
+            Some(ResourceType::Sns)
+        } else if self.elasticache_detail.is_some() {
+            Some(ResourceType::ElastiCache)
+        } else if self.cloudwatch_alarm_detail.is_some() {
+            Some(ResourceType::CloudWatchAlarm)
+        } else if self.cloudtrail_detail.is_some() {
+            Some(ResourceType::CloudTrail)
+        } else if self.kms_detail.is_some() {
+            Some(ResourceType::Kms)
+        } else if self.secret_detail.is_some() {
+            Some(ResourceType::Secret)
+        } else if self.ssm_parameter_detail.is_some() {
+            Some(ResourceType::SsmParameter)
+        } else if self.efs_detail.is_some() {
+            Some(ResourceType::Efs)
+        } else if self.api_gateway_detail.is_some() {
+            Some(ResourceType::ApiGateway)
+        } else if self.elastic_beanstalk_detail.is_some() {
+            Some(ResourceType::ElasticBeanstalk)
+        } else if self.state_machine_detail.is_some() {
+            Some(ResourceType::StepFunctions)
+        } else if self.kinesis_stream_detail.is_some() {
+            Some(ResourceType::Kinesis)
+        } else if self.msk_cluster_detail.is_some() {
+            Some(ResourceType::Msk)
+        } else if self.opensearch_domain_detail.is_some() {
+            Some(ResourceType::OpenSearch)
+        } else if self.redshift_cluster_detail.is_some() {
+            Some(ResourceType::Redshift)
+        } else if self.transit_gateway_detail.is_some() {
+            Some(ResourceType::TransitGateway)
+        } else if self.vpc_peering_detail.is_some() {
+            Some(ResourceType::VpcPeering)
+        } else if self.vpn_detail.is_some() {
+            Some(ResourceType::Vpn)
+        } else if self.direct_connect_detail.is_some() {
+            Some(ResourceType::DirectConnect)
+        } else if self.waf_web_acl_detail.is_some() {
+            Some(ResourceType::Waf)
+        } else if self.acm_certificate_detail.is_some() {
+            Some(ResourceType::Acm)
+        } else if self.cloudformation_stack_detail.is_some() {
+            Some(ResourceType::CloudFormation)
+        } else if self.iam_entity_detail.is_some() {
+            Some(ResourceType::Iam)
+        } else if self.vpc_endpoint_detail.is_some() {
+            Some(ResourceType::VpcEndpoint)
+        } else if self.network_interface_detail.is_some() {
+            Some(ResourceType::NetworkInterface)
+        } else if self.launch_template_detail.is_some() {
+            Some(ResourceType::LaunchTemplate)
+        } else if self.ami_detail.is_some() {
+            Some(ResourceType::Ami)
+        } else if self.ebs_detail.is_some() {
+            Some(ResourceType::Ebs)
+        } else if self.key_pair_detail.is_some() {
+            Some(ResourceType::KeyPair)
+        } else if self.event_bus_detail.is_some() {
+            Some(ResourceType::EventBridge)
+        } else if self.cognito_detail.is_some() {
+            Some(ResourceType::Cognito)
+        } else if self.glue_detail.is_some() {
+            Some(ResourceType::Glue)
+        } else if self.athena_workgroup_detail.is_some() {
+            Some(ResourceType::Athena)
+        } else if self.backup_plan_detail.is_some() {
+            Some(ResourceType::Backup)
         } else {
             None
         }
@@ -629,26 +2431,339 @@ impl App {
             Some((detail.arn.clone(), detail.name.clone()))
         } else if let Some(ref detail) = self.ecr_detail {
             Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.asg_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.rds_detail {
+            Some((detail.identifier.clone(), detail.identifier.clone()))
+        } else if let Some(ref detail) = self.lambda_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.dynamodb_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.cloudfront_detail {
+            Some((detail.id.clone(), detail.display_name().to_string()))
+        } else if let Some(ref detail) = self.route53_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.eks_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.ecs_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.sqs_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.sns_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.elasticache_detail {
+            Some((detail.id.clone(), detail.id.clone()))
+        } else if let Some(ref detail) = self.cloudwatch_alarm_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.cloudtrail_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.kms_detail {
+            Some((detail.key_id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.secret_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.ssm_parameter_detail {
+            Some((detail.path.clone(), detail.path.clone()))
+        } else if let Some(ref detail) = self.efs_detail {
+            Some((detail.file_system_id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.api_gateway_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.elastic_beanstalk_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.state_machine_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.kinesis_stream_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.msk_cluster_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.opensearch_domain_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.redshift_cluster_detail {
+            Some((detail.identifier.clone(), detail.identifier.clone()))
+        } else if let Some(ref detail) = self.transit_gateway_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.vpc_peering_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.vpn_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.direct_connect_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.waf_web_acl_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.acm_certificate_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.cloudformation_stack_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.iam_entity_detail {
+            Some((detail.arn.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.vpc_endpoint_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.network_interface_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.launch_template_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.ami_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.ebs_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.key_pair_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.event_bus_detail {
+            Some((detail.name.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.cognito_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.glue_detail {
+            Some((detail.id.clone(), detail.name.clone()))
+        } else if let Some(ref detail) = self.athena_workgroup_detail {
+            Some((detail.name.clone(), detail.name.clone()))
         } else {
-            self.asg_detail
+            self.backup_plan_detail
                 .as_ref()
-                .map(|detail| (detail.name.clone(), detail.name.clone()))
+                .map(|detail| (detail.id.clone(), detail.name.clone()))
+        }
+    }
+
+    // 미리보기 중인 리소스의 생성 (EC2는 시작) 시각 원문, 상세에 없으면 None
+    pub fn current_created_at(&self) -> Option<&str> {
+        let created: &str = if let Some(ref detail) = self.ec2_detail {
+            &detail.launch_time
+        } else if let Some(ref detail) = self.ecr_detail {
+            &detail.created_at
+        } else if let Some(ref detail) = self.kms_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.secret_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.efs_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.api_gateway_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.elastic_beanstalk_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.state_machine_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.kinesis_stream_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.msk_cluster_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.redshift_cluster_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.transit_gateway_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.cloudformation_stack_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.iam_entity_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.vpc_endpoint_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.launch_template_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.ami_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.ebs_detail {
+            detail.created()
+        } else if let Some(ref detail) = self.key_pair_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.event_bus_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.cognito_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.glue_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.athena_workgroup_detail {
+            &detail.created
+        } else if let Some(ref detail) = self.backup_plan_detail {
+            &detail.created
+        } else {
+            return None;
+        };
+        Some(created).filter(|created| !created.is_empty())
+    }
+
+    // 미리보기 중인 리소스의 상세 정보를 JSON으로 직렬화
+    pub fn current_detail_json(&self) -> Option<String> {
+        let json = if let Some(ref detail) = self.ec2_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.network_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.sg_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.lb_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.ecr_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.asg_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.rds_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.lambda_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.dynamodb_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.cloudfront_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.route53_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.eks_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.ecs_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.sqs_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.sns_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.elasticache_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.cloudwatch_alarm_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.cloudtrail_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.kms_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.secret_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.ssm_parameter_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.efs_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.api_gateway_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.elastic_beanstalk_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.state_machine_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.kinesis_stream_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.msk_cluster_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.opensearch_domain_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.redshift_cluster_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.transit_gateway_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.vpc_peering_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.vpn_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.direct_connect_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.waf_web_acl_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.acm_certificate_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.cloudformation_stack_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.iam_entity_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.vpc_endpoint_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.network_interface_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.launch_template_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.ami_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.ebs_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.key_pair_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.event_bus_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.cognito_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.glue_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.athena_workgroup_detail {
+            serde_json::to_string_pretty(detail)
+        } else if let Some(ref detail) = self.backup_plan_detail {
+            serde_json::to_string_pretty(detail)
+        } else {
+            return None;
+        };
+        json.ok()
+    }
+
+    // 미리보기에서 렌더링된 마크다운과 원본 JSON 보기를 전환
+    pub fn toggle_preview_raw(&mut self) {
+        self.preview_scroll = 0;
+        self.preview_hscroll = 0;
+        if self.preview_raw.take().is_some() {
+            return;
+        }
+        self.preview_raw = self.current_detail_json();
+        if self.preview_raw.is_none() {
+            self.message = self.i18n.raw_json_unavailable().to_string();
+        }
+    }
+
+    // 미리보기 줄바꿈 켜기/끄기 (켜면 가로 스크롤 위치는 의미가 없으므로 처음으로)
+    pub fn toggle_preview_wrap(&mut self) {
+        self.preview_wrap = !self.preview_wrap;
+        self.preview_hscroll = 0;
+    }
+
+    pub fn scroll_preview_left(&mut self) {
+        self.preview_hscroll = self.preview_hscroll.saturating_sub(PREVIEW_HSCROLL_STEP);
+    }
+
+    // 가장 긴 줄(표는 정렬된 폭 기준)의 마지막 칸까지만 이동
+    pub fn scroll_preview_right(&mut self) {
+        if self.preview_wrap {
+            return;
         }
+        let widest = align_markdown_tables(self.preview_text())
+            .lines()
+            .map(display_width)
+            .max()
+            .unwrap_or(0)
+            .min(u16::MAX as usize) as u16;
+        self.preview_hscroll = self
+            .preview_hscroll
+            .saturating_add(PREVIEW_HSCROLL_STEP)
+            .min(widest.saturating_sub(1));
+    }
+
+    // 현재 미리보기 화면에 표시할 내용
+    pub fn preview_text(&self) -> &str {
+        self.preview_raw.as_deref().unwrap_or(&self.preview_content)
     }
 
     pub fn get_current_region(&self) -> String {
-        REGIONS[self.selected_region].code.to_string()
+        self.region().code.to_string()
+    }
+
+    /// Region list of the signed-in account's partition.
+    pub fn regions(&self) -> &'static [Region] {
+        regions_for(aws_cli::current_partition())
+    }
+
+    pub fn region(&self) -> &'static Region {
+        let regions = self.regions();
+        regions.get(self.selected_region).unwrap_or(&regions[0])
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{App, LoadingProgress, REGIONS, Region};
+    use super::{
+        App, CHINA_REGIONS, EXPERIMENTAL_SERVICE_KEYS, GOVCLOUD_REGIONS, LIST_REFRESH_TASKS,
+        LoadingProgress, REGIONS, Region, SERVICE_KEYS, Screen, hidden_services, regions_for,
+    };
     use crate::aws_cli::iam::{AttachedPolicy, IamRoleDetail, InlinePolicy};
     use crate::aws_cli::{
-        AsgDetail, AwsAuthError, AwsAuthErrorCode, Ec2Detail, EcrDetail, EipDetail,
-        LoadBalancerDetail, NatDetail, NetworkDetail, RouteTableDetail, ScalingPolicy,
-        SecurityGroupDetail, SecurityRule, TargetGroupInfo,
+        AcmCertificateDetail, AmiDetail, ApiGatewayDetail, AsgDetail, AthenaWorkGroupDetail,
+        AwsAuthError, AwsAuthErrorCode, AwsResource, BackupPlanDetail, CloudFormationStackDetail,
+        CloudFrontDetail, CloudTrailDetail, CloudWatchAlarmDetail, CognitoPool, CognitoPoolDetail,
+        DirectConnectDetail, DirectConnectResource, DirectConnectVirtualInterfaceInfo,
+        DynamoDbDetail, EbsDetail, EbsResource, EbsVolumeInfo, Ec2Detail, EcrDetail, EcsDetail,
+        EfsDetail, EipDetail, EksDetail, ElastiCacheDetail, ElasticBeanstalkDetail, EventBusDetail,
+        GlueDatabaseDetail, GlueDetail, GlueResource, IamEntityDetail, KeyPairDetail,
+        KinesisStreamDetail, KmsKeyDetail, LambdaDetail, LaunchTemplateDetail, LoadBalancerDetail,
+        MskClusterDetail, NatDetail, NetworkDetail, NetworkInterfaceDetail, OpenSearchDomainDetail,
+        Partition, RdsDetail, RedshiftClusterDetail, Route53Detail, RouteTableDetail,
+        ScalingPolicy, SecretDetail, SecurityGroupDetail, SecurityRule, SnsDetail, SqsDetail,
+        SsmParameterPathDetail, StateMachineDetail, TargetGroupInfo, TransitGatewayDetail,
+        UserPoolDetail, VpcEndpointDetail, VpcPeeringDetail, VpcPeeringVpcInfo, VpnConnectionInfo,
+        VpnDetail, VpnResource, WafWebAclDetail,
     };
     use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
     use crate::i18n::{I18n, Language};
@@ -695,6 +2810,8 @@ mod tests {
             name: "web-a".to_string(),
             instance_id: "i-0123456789abcdef0".to_string(),
             instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
             ami: "ubuntu-22.04".to_string(),
             platform: "Linux".to_string(),
             architecture: "x86_64".to_string(),
@@ -734,8 +2851,11 @@ mod tests {
             name: "main-vpc".to_string(),
             id: "vpc-1111aaaa".to_string(),
             cidr: "10.0.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
             state: "available".to_string(),
+            owner_id: String::new(),
             subnets: vec![],
+            subnet_addresses: vec![],
             igws: vec![],
             nats: vec![NatDetail {
                 name: "nat-a".to_string(),
@@ -756,12 +2876,15 @@ mod tests {
                 routes: vec![],
                 associations: vec![],
             }],
+            nacls: vec![],
             eips: vec![EipDetail {
                 name: "eip-a".to_string(),
                 public_ip: "1.1.1.1".to_string(),
                 instance_id: String::new(),
                 private_ip: String::new(),
             }],
+            peerings: vec![],
+            endpoints: vec![],
             dns_support: true,
             dns_hostnames: true,
             tags: vec![("Name".to_string(), "main-vpc".to_string())],
@@ -830,6 +2953,8 @@ mod tests {
             arn: "arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:abcd:autoScalingGroupName/asg-main".to_string(),
             launch_template_name: Some("lt-main".to_string()),
             launch_template_id: Some("lt-1234".to_string()),
+            launch_template_version: None,
+            launch_template: None,
             launch_config_name: None,
             min_size: 1,
             max_size: 3,
@@ -848,72 +2973,1032 @@ mod tests {
                 scaling_adjustment: Some(1),
                 cooldown: Some(60),
             }],
+            scheduled_actions: vec![],
             tags: vec![],
         }
     }
 
-    #[test]
-    fn loading_progress_reset_works() {
-        let mut p = LoadingProgress {
-            vpc_info: true,
-            subnets: true,
-            igws: true,
-            nats: true,
-            route_tables: true,
-            eips: true,
-            dns_attrs: true,
-        };
-        p.reset();
-        assert!(!p.vpc_info);
-        assert!(!p.subnets);
-        assert!(!p.igws);
-        assert!(!p.nats);
-        assert!(!p.route_tables);
-        assert!(!p.eips);
-        assert!(!p.dns_attrs);
+    fn sample_rds_detail() -> RdsDetail {
+        RdsDetail {
+            identifier: "orders-db".to_string(),
+            arn: "arn:aws:rds:ap-northeast-2:123456789012:db:orders-db".to_string(),
+            is_cluster: false,
+            engine: "postgres".to_string(),
+            engine_version: "16.3".to_string(),
+            instance_class: "db.t4g.medium".to_string(),
+            status: "available".to_string(),
+            storage_type: "gp3".to_string(),
+            allocated_storage: 20,
+            storage_encrypted: true,
+            multi_az: false,
+            availability_zones: vec!["ap-northeast-2a".to_string()],
+            endpoint: String::new(),
+            reader_endpoint: None,
+            port: None,
+            subnet_group: String::new(),
+            parameter_group: String::new(),
+            security_groups: vec![],
+            cluster_identifier: None,
+            members: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_lambda_detail() -> LambdaDetail {
+        LambdaDetail {
+            name: "orders-api".to_string(),
+            arn: "arn:aws:lambda:ap-northeast-2:123456789012:function:orders-api".to_string(),
+            runtime: "python3.12".to_string(),
+            handler: "app.handler".to_string(),
+            memory_size: 128,
+            timeout: 3,
+            role: String::new(),
+            description: String::new(),
+            package_type: "Zip".to_string(),
+            architectures: vec![],
+            last_modified: String::new(),
+            code_size: 0,
+            env_var_keys: vec![],
+            vpc_id: None,
+            subnet_ids: vec![],
+            security_group_ids: vec![],
+            layers: vec![],
+            triggers: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_dynamodb_detail() -> DynamoDbDetail {
+        DynamoDbDetail {
+            name: "orders".to_string(),
+            arn: "arn:aws:dynamodb:ap-northeast-2:123456789012:table/orders".to_string(),
+            status: "ACTIVE".to_string(),
+            partition_key: "pk (S)".to_string(),
+            sort_key: None,
+            billing_mode: "PAY_PER_REQUEST".to_string(),
+            read_capacity: 0,
+            write_capacity: 0,
+            table_class: String::new(),
+            item_count: 0,
+            size_bytes: 0,
+            global_indexes: vec![],
+            local_indexes: vec![],
+            ttl_attribute: None,
+            stream_view_type: None,
+            sse_type: None,
+            kms_key_arn: None,
+            deletion_protection: false,
+            tags: vec![],
+        }
+    }
+
+    fn sample_cloudfront_detail() -> CloudFrontDetail {
+        CloudFrontDetail {
+            id: "E2EXAMPLE".to_string(),
+            arn: "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE".to_string(),
+            domain_name: "d111111abcdef8.cloudfront.net".to_string(),
+            status: "Deployed".to_string(),
+            enabled: true,
+            comment: String::new(),
+            aliases: vec!["www.example.com".to_string()],
+            price_class: String::new(),
+            http_version: String::new(),
+            ipv6_enabled: false,
+            default_root_object: String::new(),
+            certificate: None,
+            ssl_support_method: String::new(),
+            minimum_protocol_version: String::new(),
+            web_acl_id: None,
+            origins: vec![],
+            behaviors: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_route53_detail() -> Route53Detail {
+        Route53Detail {
+            id: "Z0123456789ABC".to_string(),
+            name: "example.com".to_string(),
+            private_zone: false,
+            comment: String::new(),
+            record_count: 2,
+            vpcs: vec![],
+            records: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_eks_detail() -> EksDetail {
+        EksDetail {
+            name: "checkout".to_string(),
+            arn: "arn:aws:eks:ap-northeast-2:123456789012:cluster/checkout".to_string(),
+            status: "ACTIVE".to_string(),
+            version: "1.29".to_string(),
+            platform_version: String::new(),
+            endpoint: String::new(),
+            endpoint_public_access: true,
+            endpoint_private_access: false,
+            public_access_cidrs: vec![],
+            vpc_id: String::new(),
+            subnet_ids: vec![],
+            security_group_ids: vec![],
+            role_arn: String::new(),
+            logging: vec![],
+            node_groups: vec![],
+            fargate_profiles: vec![],
+            addons: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_ecs_detail() -> EcsDetail {
+        EcsDetail {
+            name: "checkout".to_string(),
+            arn: "arn:aws:ecs:ap-northeast-2:123456789012:cluster/checkout".to_string(),
+            status: "ACTIVE".to_string(),
+            capacity_providers: vec![],
+            registered_container_instances: 0,
+            active_services_count: 0,
+            running_tasks_count: 0,
+            pending_tasks_count: 0,
+            services: vec![],
+            tasks: vec![],
+            task_definitions: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_sqs_detail() -> SqsDetail {
+        SqsDetail {
+            name: "orders".to_string(),
+            url: "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders".to_string(),
+            arn: "arn:aws:sqs:ap-northeast-2:123456789012:orders".to_string(),
+            fifo: false,
+            content_based_deduplication: false,
+            visibility_timeout: 30,
+            message_retention_period: 345600,
+            delay_seconds: 0,
+            receive_message_wait_time: 0,
+            maximum_message_size: 262144,
+            messages_available: 0,
+            messages_in_flight: 0,
+            messages_delayed: 0,
+            dead_letter_target_arn: None,
+            max_receive_count: None,
+            sqs_managed_sse: true,
+            kms_master_key_id: None,
+            tags: vec![],
+        }
+    }
+
+    fn sample_sns_detail() -> SnsDetail {
+        SnsDetail {
+            name: "alerts".to_string(),
+            arn: "arn:aws:sns:ap-northeast-2:123456789012:alerts".to_string(),
+            display_name: String::new(),
+            owner: "123456789012".to_string(),
+            fifo: false,
+            content_based_deduplication: false,
+            kms_master_key_id: None,
+            subscriptions_confirmed: 0,
+            subscriptions_pending: 0,
+            access_policy: None,
+            subscriptions: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_elasticache_detail() -> ElastiCacheDetail {
+        ElastiCacheDetail {
+            id: "sessions".to_string(),
+            arn: "arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:sessions"
+                .to_string(),
+            is_replication_group: true,
+            description: String::new(),
+            engine: "redis".to_string(),
+            engine_version: "7.1.0".to_string(),
+            node_type: "cache.t4g.small".to_string(),
+            status: "available".to_string(),
+            cluster_mode: Some("disabled".to_string()),
+            automatic_failover: Some("enabled".to_string()),
+            multi_az: Some("enabled".to_string()),
+            configuration_endpoint: None,
+            transit_encryption: true,
+            at_rest_encryption: true,
+            subnet_group: String::new(),
+            parameter_group: String::new(),
+            security_groups: vec![],
+            node_groups: vec![],
+            nodes: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_cloudwatch_alarm_detail() -> CloudWatchAlarmDetail {
+        CloudWatchAlarmDetail {
+            name: "api-high-cpu".to_string(),
+            arn: "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:api-high-cpu".to_string(),
+            description: String::new(),
+            composite: false,
+            state: "OK".to_string(),
+            state_reason: String::new(),
+            state_updated: String::new(),
+            metric: "AWS/EC2 CPUUtilization".to_string(),
+            statistic: "Average".to_string(),
+            dimensions: vec![],
+            period: Some(300),
+            evaluation_periods: Some(3),
+            datapoints_to_alarm: None,
+            comparison_operator: "GreaterThanOrEqualToThreshold".to_string(),
+            threshold: Some(80.0),
+            treat_missing_data: "missing".to_string(),
+            alarm_rule: None,
+            actions_enabled: true,
+            alarm_actions: vec![],
+            ok_actions: vec![],
+            insufficient_data_actions: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_cloudtrail_detail() -> CloudTrailDetail {
+        CloudTrailDetail {
+            name: "org-audit".to_string(),
+            arn: "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/org-audit".to_string(),
+            home_region: "ap-northeast-2".to_string(),
+            multi_region: true,
+            organization_trail: false,
+            include_global_events: true,
+            logging: true,
+            latest_delivery: String::new(),
+            s3_bucket: "audit-logs".to_string(),
+            s3_key_prefix: String::new(),
+            log_file_validation: true,
+            kms_key_id: None,
+            cloudwatch_log_group: None,
+            sns_topic: None,
+            recent_events: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_kms_detail() -> KmsKeyDetail {
+        KmsKeyDetail {
+            name: "app-data".to_string(),
+            key_id: "1234abcd-12ab-34cd-56ef-1234567890ab".to_string(),
+            arn: String::new(),
+            aliases: vec!["alias/app-data".to_string()],
+            description: String::new(),
+            state: "Enabled".to_string(),
+            key_spec: "SYMMETRIC_DEFAULT".to_string(),
+            key_usage: "ENCRYPT_DECRYPT".to_string(),
+            origin: "AWS_KMS".to_string(),
+            multi_region: false,
+            created: String::new(),
+            deletion_date: None,
+            rotation_enabled: Some(true),
+            rotation_period_days: Some(365),
+            next_rotation: None,
+            policy_statements: vec![],
+            grants: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_secret_detail() -> SecretDetail {
+        SecretDetail {
+            name: "prod/checkout/db".to_string(),
+            arn:
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf"
+                    .to_string(),
+            description: String::new(),
+            kms_key_id: None,
+            rotation_enabled: false,
+            rotation_schedule: None,
+            rotation_window: None,
+            rotation_lambda: None,
+            last_rotated: String::new(),
+            next_rotation: String::new(),
+            last_changed: String::new(),
+            last_accessed: String::new(),
+            created: String::new(),
+            deletion_date: None,
+            owning_service: None,
+            primary_region: None,
+            replicas: vec![],
+            tags: vec![],
+        }
     }
 
     #[test]
-    fn login_required_error_detector_matches_auth_codes() {
-        assert!(super::is_login_required_error(&AwsAuthError {
-            code: AwsAuthErrorCode::CredentialsLoadFailed,
-            detail: "fail".to_string(),
-        }));
-        assert!(super::is_login_required_error(&AwsAuthError {
-            code: AwsAuthErrorCode::CallerIdentityFailed,
-            detail: "fail".to_string(),
-        }));
-        assert!(!super::is_login_required_error(&AwsAuthError {
-            code: AwsAuthErrorCode::Network,
-            detail: "network".to_string(),
-        }));
+    fn service_order_puts_pinned_first_then_most_used() {
+        let mut app = App::new();
+        app.settings.pinned_services = vec!["KMS".to_string(), "Unknown".to_string()];
+        app.settings.sort_services_by_usage = false;
+        app.settings.service_usage.clear();
+        app.settings.disabled_services.clear();
+        app.settings.experimental_services = EXPERIMENTAL_SERVICE_KEYS
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let order = app.service_order();
+        assert_eq!(order.len(), SERVICE_KEYS.len());
+        assert_eq!(SERVICE_KEYS[order[0]], "KMS");
+        assert_eq!(SERVICE_KEYS[order[1]], "EC2");
+        assert!(app.is_service_pinned(order[0]));
+
+        app.settings.sort_services_by_usage = true;
+        app.settings.service_usage.insert("RDS".to_string(), 5);
+        app.settings.service_usage.insert("Lambda".to_string(), 2);
+        let order = app.service_order();
+        let names: Vec<&str> = order.iter().take(4).map(|&idx| SERVICE_KEYS[idx]).collect();
+        assert_eq!(names, vec!["KMS", "RDS", "Lambda", "EC2"]);
+
+        // 커서 위치를 SERVICE_KEYS 인덱스로 변환, 마지막 항목은 종료
+        app.selected_service = 1;
+        assert_eq!(SERVICE_KEYS[app.selected_service_key()], "RDS");
+        app.selected_service = SERVICE_KEYS.len();
+        assert_eq!(app.selected_service_key(), SERVICE_KEYS.len());
     }
 
     #[test]
-    fn aws_credential_message_detector_matches_expected_messages() {
-        let i18n = I18n::new(crate::i18n::Language::English);
-        assert!(super::is_aws_credential_message(
-            i18n.auth_network_error(),
-            &i18n
-        ));
-        assert!(super::is_aws_credential_message(
-            i18n.auth_unknown_error(),
-            &i18n
-        ));
-        assert!(!super::is_aws_credential_message(
-            "Save complete: output.md",
-            &i18n
-        ));
+    fn service_order_skips_disabled_services_even_when_pinned() {
+        let mut app = App::new();
+        app.settings.pinned_services = vec!["KMS".to_string()];
+        app.settings.sort_services_by_usage = false;
+        app.settings.disabled_services = vec!["KMS".to_string(), "EC2".to_string()];
+        app.settings.experimental_services = EXPERIMENTAL_SERVICE_KEYS
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let order = app.service_order();
+        assert_eq!(order.len(), SERVICE_KEYS.len() - 2);
+        assert_eq!(SERVICE_KEYS[order[0]], "Network");
+        assert!(!order.iter().any(|&idx| SERVICE_KEYS[idx] == "KMS"));
+
+        // 종료 항목은 숨긴 서비스 수만큼 앞으로 당겨짐
+        app.selected_service = order.len();
+        assert_eq!(app.selected_service_key(), SERVICE_KEYS.len());
+
+        assert_eq!(hidden_services(&app.settings), vec!["EC2", "KMS"]);
     }
 
     #[test]
-    fn auth_error_message_maps_each_auth_code_to_i18n_text() {
-        let i18n = I18n::new(Language::English);
-        assert_eq!(
-            super::auth_error_message(
-                &AwsAuthError {
-                    code: AwsAuthErrorCode::CredentialsProviderMissing,
-                    detail: "x".to_string(),
+    fn experimental_services_are_hidden_until_opted_in() {
+        let mut app = App::new();
+        app.settings.pinned_services.clear();
+        app.settings.disabled_services.clear();
+        app.settings.experimental_services.clear();
+        let backup = SERVICE_KEYS
+            .iter()
+            .position(|name| *name == "Backup")
+            .expect("Backup service");
+
+        assert!(!app.is_service_enabled(backup));
+        assert!(!app.service_order().contains(&backup));
+        assert_eq!(hidden_services(&app.settings), vec!["Backup"]);
+
+        app.settings.experimental_services = vec!["Backup".to_string()];
+        assert!(app.is_service_enabled(backup));
+        assert!(app.service_order().contains(&backup));
+        assert!(hidden_services(&app.settings).is_empty());
+
+        // disabled_services가 우선
+        app.settings.disabled_services = vec!["Backup".to_string()];
+        assert!(!app.service_order().contains(&backup));
+    }
+
+    fn sample_ssm_parameter_detail() -> SsmParameterPathDetail {
+        SsmParameterPathDetail {
+            path: "/prod".to_string(),
+            parameters: vec![],
+        }
+    }
+
+    fn sample_efs_detail() -> EfsDetail {
+        EfsDetail {
+            name: "shared-assets".to_string(),
+            file_system_id: "fs-0123456789abcdef0".to_string(),
+            arn: String::new(),
+            state: "available".to_string(),
+            performance_mode: "generalPurpose".to_string(),
+            throughput_mode: "elastic".to_string(),
+            provisioned_throughput_mibps: None,
+            encrypted: true,
+            kms_key_id: None,
+            availability_zone: None,
+            size_bytes: 0,
+            created: String::new(),
+            transition_to_ia: None,
+            transition_to_archive: None,
+            transition_to_primary: None,
+            mount_targets: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_api_gateway_detail() -> ApiGatewayDetail {
+        ApiGatewayDetail {
+            name: "orders-api".to_string(),
+            id: "a1b2c3d4e5".to_string(),
+            protocol: "HTTP".to_string(),
+            endpoint_type: "REGIONAL".to_string(),
+            endpoint: String::new(),
+            disable_execute_api_endpoint: false,
+            description: String::new(),
+            created: String::new(),
+            stages: vec![],
+            routes: vec![],
+            authorizers: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_elastic_beanstalk_detail() -> ElasticBeanstalkDetail {
+        ElasticBeanstalkDetail {
+            name: "orders-prod".to_string(),
+            id: "e-abc123xyz0".to_string(),
+            arn: String::new(),
+            application: "orders".to_string(),
+            description: String::new(),
+            status: "Ready".to_string(),
+            health: "Green".to_string(),
+            health_status: String::new(),
+            tier: "WebServer/Standard".to_string(),
+            platform: String::new(),
+            version_label: String::new(),
+            cname: String::new(),
+            endpoint_url: String::new(),
+            created: String::new(),
+            updated: String::new(),
+            option_settings: vec![],
+            option_count: 0,
+            env_var_keys: vec![],
+            resources: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_state_machine_detail() -> StateMachineDetail {
+        StateMachineDetail {
+            name: "order-flow".to_string(),
+            arn: "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow".to_string(),
+            machine_type: "STANDARD".to_string(),
+            status: "ACTIVE".to_string(),
+            role_arn: String::new(),
+            description: String::new(),
+            created: String::new(),
+            logging_level: "OFF".to_string(),
+            include_execution_data: false,
+            log_destinations: vec![],
+            tracing_enabled: false,
+            kms_key_id: None,
+            start_at: String::new(),
+            state_count: 0,
+            definition: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_kinesis_stream_detail() -> KinesisStreamDetail {
+        KinesisStreamDetail {
+            name: "clickstream".to_string(),
+            arn: "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream".to_string(),
+            status: "ACTIVE".to_string(),
+            stream_mode: "ON_DEMAND".to_string(),
+            open_shard_count: 4,
+            retention_hours: 24,
+            kms_key_id: None,
+            shard_level_metrics: vec![],
+            created: String::new(),
+            consumers: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_msk_cluster_detail() -> MskClusterDetail {
+        MskClusterDetail {
+            name: "events".to_string(),
+            arn: "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/abcd-1".to_string(),
+            state: "ACTIVE".to_string(),
+            kafka_version: "3.6.0".to_string(),
+            broker_count: 3,
+            instance_type: "kafka.m5.large".to_string(),
+            storage_mode: String::new(),
+            volume_size_gib: None,
+            provisioned_throughput: None,
+            client_broker_encryption: "TLS".to_string(),
+            in_cluster_encryption: true,
+            kms_key_id: None,
+            enhanced_monitoring: String::new(),
+            client_subnets: vec![],
+            security_groups: vec![],
+            created: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_opensearch_domain_detail() -> OpenSearchDomainDetail {
+        OpenSearchDomainDetail {
+            name: "logs".to_string(),
+            arn: "arn:aws:es:ap-northeast-2:123456789012:domain/logs".to_string(),
+            domain_id: "123456789012/logs".to_string(),
+            engine_version: "OpenSearch_2.11".to_string(),
+            state: "Active".to_string(),
+            instance_type: "r6g.large.search".to_string(),
+            instance_count: 3,
+            dedicated_master_type: None,
+            dedicated_master_count: 0,
+            zone_awareness_azs: None,
+            warm_type: None,
+            warm_count: 0,
+            volume_type: None,
+            volume_size_gib: None,
+            iops: None,
+            throughput: None,
+            endpoints: vec![],
+            vpc_id: None,
+            subnets: vec![],
+            security_groups: vec![],
+            vpc_endpoints: vec![],
+            enforce_https: true,
+            tls_security_policy: String::new(),
+            node_to_node_encryption: true,
+            encryption_at_rest: true,
+            kms_key_id: None,
+            access_policy: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_redshift_cluster_detail() -> RedshiftClusterDetail {
+        RedshiftClusterDetail {
+            identifier: "analytics".to_string(),
+            namespace_arn: String::new(),
+            status: "available".to_string(),
+            node_type: "ra3.xlplus".to_string(),
+            node_count: 2,
+            version: "1.0".to_string(),
+            db_name: "dev".to_string(),
+            master_username: "awsuser".to_string(),
+            endpoint: None,
+            vpc_id: None,
+            subnet_group: String::new(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            security_groups: vec![],
+            publicly_accessible: false,
+            enhanced_vpc_routing: false,
+            multi_az: String::new(),
+            encrypted: true,
+            kms_key_id: None,
+            automated_snapshot_retention: 1,
+            manual_snapshot_retention: -1,
+            snapshot_schedule: None,
+            next_snapshot: String::new(),
+            snapshot_copy: None,
+            maintenance_window: String::new(),
+            iam_roles: vec![],
+            created: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_transit_gateway_detail() -> TransitGatewayDetail {
+        TransitGatewayDetail {
+            name: "core-tgw".to_string(),
+            id: "tgw-0123".to_string(),
+            state: "available".to_string(),
+            owner_id: String::new(),
+            description: String::new(),
+            amazon_side_asn: Some(64512),
+            cidr_blocks: vec![],
+            dns_support: "enable".to_string(),
+            vpn_ecmp_support: "enable".to_string(),
+            auto_accept_shared_attachments: "disable".to_string(),
+            association_default_route_table: None,
+            propagation_default_route_table: None,
+            created: String::new(),
+            attachments: vec![],
+            route_tables: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_vpc_peering_detail() -> VpcPeeringDetail {
+        let side = |vpc_id: &str, cidr: &str| VpcPeeringVpcInfo {
+            vpc_id: vpc_id.to_string(),
+            owner_id: String::new(),
+            region: String::new(),
+            cidr_blocks: vec![cidr.to_string()],
+            allow_dns_resolution_from_remote_vpc: Some(false),
+        };
+        VpcPeeringDetail {
+            name: "app-to-shared".to_string(),
+            id: "pcx-0123".to_string(),
+            status: "active".to_string(),
+            status_message: "Active".to_string(),
+            requester: side("vpc-1111", "10.0.0.0/16"),
+            accepter: side("vpc-2222", "10.1.0.0/16"),
+            expiration: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_vpn_detail() -> VpnDetail {
+        VpnDetail {
+            name: "office".to_string(),
+            id: "vpn-0123".to_string(),
+            state: "available".to_string(),
+            tags: vec![],
+            resource: VpnResource::Connection(Box::new(VpnConnectionInfo {
+                connection_type: "ipsec.1".to_string(),
+                customer_gateway_id: "cgw-test".to_string(),
+                vpn_gateway_id: Some("vgw-test".to_string()),
+                transit_gateway_id: None,
+                static_routes_only: true,
+                acceleration: false,
+                local_network_cidr: String::new(),
+                remote_network_cidr: String::new(),
+                tunnels: vec![],
+                routes: vec![],
+                customer_gateway: None,
+                vpn_gateway: None,
+            })),
+        }
+    }
+
+    fn sample_direct_connect_detail() -> DirectConnectDetail {
+        DirectConnectDetail {
+            name: "prod-private".to_string(),
+            id: "dxvif-0123".to_string(),
+            state: "available".to_string(),
+            owner_account: String::new(),
+            tags: vec![],
+            resource: DirectConnectResource::VirtualInterface(DirectConnectVirtualInterfaceInfo {
+                id: "dxvif-0123".to_string(),
+                name: "prod-private".to_string(),
+                state: "available".to_string(),
+                interface_type: "private".to_string(),
+                connection_id: "dxcon-0123".to_string(),
+                location: String::new(),
+                vlan: 101,
+                customer_asn: 65000,
+                amazon_side_asn: None,
+                address_family: "ipv4".to_string(),
+                amazon_address: String::new(),
+                customer_address: String::new(),
+                mtu: None,
+                virtual_gateway_id: None,
+                direct_connect_gateway_id: None,
+                site_link_enabled: false,
+                bgp_peers: vec![],
+                route_filter_prefixes: vec![],
+            }),
+        }
+    }
+
+    fn sample_waf_web_acl_detail() -> WafWebAclDetail {
+        WafWebAclDetail {
+            name: "prod-web".to_string(),
+            id: "a1b2c3d4".to_string(),
+            arn: "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4"
+                .to_string(),
+            scope: "REGIONAL".to_string(),
+            region: "ap-northeast-2".to_string(),
+            description: String::new(),
+            default_action: "Allow".to_string(),
+            capacity: 700,
+            metric_name: "prod-web".to_string(),
+            sampled_requests: true,
+            rules: vec![],
+            associations: vec![],
+        }
+    }
+
+    fn sample_acm_certificate_detail() -> AcmCertificateDetail {
+        AcmCertificateDetail {
+            name: "example.com".to_string(),
+            arn: "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0".to_string(),
+            subject_alternative_names: vec!["example.com".to_string()],
+            status: "ISSUED".to_string(),
+            certificate_type: "AMAZON_ISSUED".to_string(),
+            validation_method: "DNS".to_string(),
+            key_algorithm: "RSA-2048".to_string(),
+            issuer: "Amazon".to_string(),
+            not_before: "2026-01-01T00:00:00Z".to_string(),
+            not_after: "2027-01-30T23:59:59Z".to_string(),
+            days_until_expiry: Some(107),
+            renewal_eligibility: "ELIGIBLE".to_string(),
+            renewal_status: String::new(),
+            in_use_by: vec![],
+            domain_validations: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_cloudformation_stack_detail() -> CloudFormationStackDetail {
+        CloudFormationStackDetail {
+            name: "checkout".to_string(),
+            stack_id: "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout/1a2b3c4d"
+                .to_string(),
+            status: "CREATE_COMPLETE".to_string(),
+            status_reason: String::new(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_updated: String::new(),
+            termination_protection: false,
+            drift_status: "NOT_CHECKED".to_string(),
+            parent_id: String::new(),
+            parameters: vec![],
+            outputs: vec![],
+            resources: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_iam_entity_detail() -> IamEntityDetail {
+        IamEntityDetail {
+            kind: "Role".to_string(),
+            name: "app-role".to_string(),
+            arn: "arn:aws:iam::123456789012:role/app-role".to_string(),
+            path: "/".to_string(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_used: String::new(),
+            permissions_boundary: String::new(),
+            trust_policy: String::new(),
+            attached_policies: vec![],
+            inline_policies: vec![],
+            groups: vec![],
+            members: vec![],
+            access_keys: vec![],
+            default_version: String::new(),
+            policy_document: String::new(),
+            attachment_count: None,
+            tags: vec![],
+        }
+    }
+
+    fn sample_vpc_endpoint_detail() -> VpcEndpointDetail {
+        VpcEndpointDetail {
+            name: "s3-gateway".to_string(),
+            id: "vpce-0a1b2c3d4e5f60718".to_string(),
+            vpc_id: "vpc-1111aaaa".to_string(),
+            service_name: "com.amazonaws.ap-northeast-2.s3".to_string(),
+            endpoint_type: "Gateway".to_string(),
+            state: "available".to_string(),
+            subnet_ids: vec![],
+            security_groups: vec![],
+            route_table_ids: vec!["rtb-1234".to_string()],
+            private_dns_enabled: false,
+            dns_names: vec![],
+            policy: String::new(),
+            created: "2026-03-02T01:02:03.000Z".to_string(),
+            owner_id: "123456789012".to_string(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_network_interface_detail() -> NetworkInterfaceDetail {
+        NetworkInterfaceDetail {
+            name: "old-db".to_string(),
+            id: "eni-0a1b2c3d4e5f60718".to_string(),
+            description: String::new(),
+            interface_type: "interface".to_string(),
+            status: "available".to_string(),
+            vpc_id: "vpc-1111aaaa".to_string(),
+            subnet_id: "subnet-1111aaaa".to_string(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            mac_address: "02:ab:cd:ef:01:02".to_string(),
+            ips: vec![],
+            security_groups: vec![],
+            attachment: None,
+            requester_managed: false,
+            requester_id: String::new(),
+            owner_id: "123456789012".to_string(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_launch_template_detail() -> LaunchTemplateDetail {
+        LaunchTemplateDetail {
+            name: "web".to_string(),
+            id: "lt-0a1b2c3d4e5f60718".to_string(),
+            default_version: 2,
+            latest_version: 3,
+            created: String::new(),
+            created_by: String::new(),
+            versions: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_ami_detail() -> AmiDetail {
+        AmiDetail {
+            id: "ami-0a1b2c3d4e5f60718".to_string(),
+            name: "web-2026-10".to_string(),
+            description: String::new(),
+            state: "available".to_string(),
+            created: String::new(),
+            source_instance_id: String::new(),
+            architecture: String::new(),
+            platform: String::new(),
+            root_device: String::new(),
+            public: false,
+            deprecation_time: String::new(),
+            block_devices: vec![],
+            launch_permissions: None,
+            tags: vec![],
+        }
+    }
+
+    fn sample_ebs_detail() -> EbsDetail {
+        EbsDetail {
+            name: "data".to_string(),
+            id: "vol-0123".to_string(),
+            state: "in-use".to_string(),
+            size_gb: 100,
+            encrypted: true,
+            kms_key_id: String::new(),
+            tags: vec![],
+            resource: EbsResource::Volume(EbsVolumeInfo {
+                volume_type: "gp3".to_string(),
+                iops: Some(3000),
+                throughput: Some(125),
+                availability_zone: "ap-northeast-2a".to_string(),
+                snapshot_id: String::new(),
+                created: String::new(),
+                multi_attach: false,
+                attachments: vec![],
+            }),
+        }
+    }
+
+    fn sample_key_pair_detail() -> KeyPairDetail {
+        KeyPairDetail {
+            id: "key-0123".to_string(),
+            name: "deploy".to_string(),
+            fingerprint: "SHA256:abcd".to_string(),
+            key_type: "ed25519".to_string(),
+            created: String::new(),
+            public_key: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_event_bus_detail() -> EventBusDetail {
+        EventBusDetail {
+            name: "default".to_string(),
+            arn: "arn:aws:events:ap-northeast-2:123456789012:event-bus/default".to_string(),
+            description: String::new(),
+            kms_key_id: None,
+            created: String::new(),
+            rules: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn sample_cognito_detail() -> CognitoPoolDetail {
+        CognitoPoolDetail {
+            id: "ap-northeast-2_AbCdEf123".to_string(),
+            name: "customers".to_string(),
+            arn: String::new(),
+            created: String::new(),
+            pool: CognitoPool::User(UserPoolDetail {
+                tier: String::new(),
+                estimated_users: 0,
+                sign_in_attributes: vec![],
+                deletion_protection: String::new(),
+                mfa: "OFF".to_string(),
+                mfa_methods: vec![],
+                domains: vec![],
+                app_clients: vec![],
+            }),
+            tags: vec![],
+        }
+    }
+
+    fn sample_glue_detail() -> GlueDetail {
+        GlueDetail {
+            id: "database/sales".to_string(),
+            name: "sales".to_string(),
+            arn: String::new(),
+            description: String::new(),
+            created: String::new(),
+            resource: GlueResource::Database(GlueDatabaseDetail {
+                location_uri: String::new(),
+                tables: vec![],
+            }),
+            tags: vec![],
+        }
+    }
+
+    fn sample_athena_workgroup_detail() -> AthenaWorkGroupDetail {
+        AthenaWorkGroupDetail {
+            name: "primary".to_string(),
+            arn: String::new(),
+            state: "ENABLED".to_string(),
+            description: String::new(),
+            created: String::new(),
+            engine_version: String::new(),
+            output_location: String::new(),
+            encryption: String::new(),
+            kms_key: String::new(),
+            enforce_configuration: false,
+            publish_metrics: false,
+            bytes_scanned_cutoff: None,
+            requester_pays: false,
+            execution_role: String::new(),
+            tags: vec![],
+        }
+    }
+
+    fn sample_backup_plan_detail() -> BackupPlanDetail {
+        BackupPlanDetail {
+            id: "8a2f1c4e".to_string(),
+            name: "daily-35d".to_string(),
+            arn: String::new(),
+            version_id: String::new(),
+            created: String::new(),
+            last_execution: String::new(),
+            advanced_settings: vec![],
+            rules: vec![],
+            selections: vec![],
+            protected_resources: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn loading_progress_reset_works() {
+        let mut p = LoadingProgress {
+            vpc_info: true,
+            subnets: true,
+            igws: true,
+            nats: true,
+            route_tables: true,
+            eips: true,
+            peerings: true,
+            endpoints: true,
+            nacls: true,
+            dns_attrs: true,
+        };
+        p.reset();
+        assert!(!p.vpc_info);
+        assert!(!p.subnets);
+        assert!(!p.igws);
+        assert!(!p.nats);
+        assert!(!p.route_tables);
+        assert!(!p.eips);
+        assert!(!p.peerings);
+        assert!(!p.endpoints);
+        assert!(!p.nacls);
+        assert!(!p.dns_attrs);
+    }
+
+    #[test]
+    fn login_required_error_detector_matches_auth_codes() {
+        assert!(super::is_login_required_error(&AwsAuthError {
+            code: AwsAuthErrorCode::CredentialsLoadFailed,
+            detail: "fail".to_string(),
+        }));
+        assert!(super::is_login_required_error(&AwsAuthError {
+            code: AwsAuthErrorCode::CallerIdentityFailed,
+            detail: "fail".to_string(),
+        }));
+        assert!(!super::is_login_required_error(&AwsAuthError {
+            code: AwsAuthErrorCode::Network,
+            detail: "network".to_string(),
+        }));
+    }
+
+    #[test]
+    fn aws_credential_message_detector_matches_expected_messages() {
+        let i18n = I18n::new(crate::i18n::Language::English);
+        assert!(super::is_aws_credential_message(
+            i18n.auth_network_error(),
+            &i18n
+        ));
+        assert!(super::is_aws_credential_message(
+            i18n.auth_unknown_error(),
+            &i18n
+        ));
+        assert!(!super::is_aws_credential_message(
+            "Save complete: output.md",
+            &i18n
+        ));
+    }
+
+    #[test]
+    fn auth_error_message_maps_each_auth_code_to_i18n_text() {
+        let i18n = I18n::new(Language::English);
+        assert_eq!(
+            super::auth_error_message(
+                &AwsAuthError {
+                    code: AwsAuthErrorCode::CredentialsProviderMissing,
+                    detail: "x".to_string(),
                 },
                 &i18n
             ),
@@ -996,87 +4081,777 @@ mod tests {
     }
 
     #[test]
-    fn refresh_profiles_sets_error_when_no_profile_files_exist() {
+    fn save_file_indexes_document_for_search() {
         let _guard = env_lock().lock().expect("env lock poisoned");
         let original_home = env::var_os("HOME");
-        let home = temp_home("no-profiles");
+        let home = temp_home("search");
         fs::create_dir_all(&home).expect("create home");
-
         unsafe {
             env::set_var("HOME", &home);
         }
 
         let mut app = App::new();
-        app.refresh_profiles();
+        app.preview_filename = home.join("network.md").to_string_lossy().to_string();
+        app.preview_content = "## Network\n| CIDR | 10.20.0.0/16 |\n".to_string();
+        app.save_file().expect("save file");
 
-        assert!(app.available_profiles.is_empty());
-        assert!(
-            app.login_error
-                .as_ref()
-                .map(|error| error.detail.as_str())
-                .unwrap_or_default()
-                .contains(app.i18n.profile_not_found())
-        );
+        app.input_buffer = "10.20.0".to_string();
+        app.search_documents();
+        assert_eq!(app.search_results.len(), 1);
+        assert!(app.search_results[0].path.ends_with("network.md"));
+        assert_eq!(app.search_results[0].snippet, "| CIDR | 10.20.0.0/16 |");
+        assert_eq!(app.message, app.i18n.search_result_count(1));
+
+        app.input_buffer = "sg-none".to_string();
+        app.search_documents();
+        assert!(app.search_results.is_empty());
+        assert_eq!(app.message, app.i18n.no_search_results());
 
         restore_var("HOME", original_home);
         let _ = fs::remove_dir_all(home);
     }
 
     #[test]
-    fn region_name_uses_language() {
-        let r = Region {
-            code: "us-east-1",
-            name_ko: "버지니아",
-            name_en: "N. Virginia",
-        };
-        assert_eq!(r.name(crate::i18n::Language::Korean), "버지니아");
-        assert_eq!(r.name(crate::i18n::Language::English), "N. Virginia");
+    fn toggle_preview_raw_switches_between_markdown_and_json() {
+        let mut app = App::new();
+        app.preview_content = "## ECS".to_string();
+        app.preview_scroll = 4;
+        app.toggle_preview_raw();
+        assert!(app.preview_raw.is_none());
+        assert_eq!(app.preview_text(), "## ECS");
+        assert_eq!(app.message, app.i18n.raw_json_unavailable());
+
+        app.ecs_detail = Some(sample_ecs_detail());
+        app.toggle_preview_raw();
+        let raw: serde_json::Value =
+            serde_json::from_str(app.preview_text()).expect("raw preview is json");
+        assert_eq!(raw["name"], "checkout");
+        assert_eq!(raw["status"], "ACTIVE");
+        assert_eq!(app.preview_scroll, 0);
+
+        app.toggle_preview_raw();
+        assert!(app.preview_raw.is_none());
+        assert_eq!(app.preview_text(), "## ECS");
     }
 
     #[test]
-    fn get_current_resource_type_and_info_priority() {
+    fn preview_horizontal_scroll_needs_wrap_off_and_stops_at_widest_line() {
         let mut app = App::new();
+        app.preview_content = format!("## Routes\n{}\nshort", "x".repeat(20));
+        app.scroll_preview_right();
+        assert_eq!(app.preview_hscroll, 0);
 
-        app.ec2_detail = Some(sample_ec2_detail());
-        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ec2));
-        assert_eq!(
-            app.get_current_resource_info(),
-            Some(("i-0123456789abcdef0".to_string(), "web-a".to_string()))
-        );
+        app.toggle_preview_wrap();
+        assert!(!app.preview_wrap);
+        app.scroll_preview_right();
+        assert_eq!(app.preview_hscroll, 8);
+        app.scroll_preview_right();
+        app.scroll_preview_right();
+        assert_eq!(app.preview_hscroll, 19);
+        app.scroll_preview_left();
+        assert_eq!(app.preview_hscroll, 11);
 
-        app.ec2_detail = None;
-        app.network_detail = Some(sample_network_detail());
-        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Network));
+        app.toggle_preview_wrap();
+        assert!(app.preview_wrap);
+        assert_eq!(app.preview_hscroll, 0);
+    }
 
-        app.network_detail = None;
-        app.sg_detail = Some(sample_sg_detail());
-        assert_eq!(
-            app.get_current_resource_type(),
-            Some(ResourceType::SecurityGroup)
-        );
+    #[test]
+    fn save_file_writes_json_in_raw_view() {
+        let home = temp_home("raw-json");
+        fs::create_dir_all(&home).expect("create home");
 
-        app.sg_detail = None;
-        app.lb_detail = Some(sample_lb_detail());
-        assert_eq!(
-            app.get_current_resource_type(),
-            Some(ResourceType::LoadBalancer)
-        );
+        let mut app = App::new();
+        app.preview_filename = home.join("checkout.md").to_string_lossy().to_string();
+        app.preview_raw = Some("{\n  \"name\": \"checkout\"\n}".to_string());
+        app.save_file().expect("save file");
 
-        app.lb_detail = None;
-        app.ecr_detail = Some(sample_ecr_detail());
-        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ecr));
+        let saved = fs::read_to_string(home.join("checkout.json")).expect("read json");
+        assert_eq!(saved, "{\n  \"name\": \"checkout\"\n}");
+        assert!(!home.join("checkout.md").exists());
 
-        app.ecr_detail = None;
-        app.asg_detail = Some(sample_asg_detail());
-        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Asg));
+        let _ = fs::remove_dir_all(home);
     }
 
     #[test]
-    fn get_current_region_returns_selected_code() {
+    fn export_to_notion_requires_configuration() {
         let mut app = App::new();
-        app.selected_region = REGIONS.len().saturating_sub(1);
-        assert_eq!(app.get_current_region(), REGIONS[REGIONS.len() - 1].code);
-    }
+        app.settings.notion_export = None;
+        app.export_to_notion();
+        assert_eq!(app.message, app.i18n.notion_not_configured());
+
+        app.settings.notion_export = Some(crate::settings::NotionExport {
+            database_id: "0123abcd".to_string(),
+        });
+        app.export_to_notion();
+        assert_eq!(app.message, app.i18n.notion_nothing_to_export());
+    }
+
+    #[test]
+    fn notion_pages_follow_preview_or_blueprint_resources() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = "## ECS Cluster (checkout)\n".to_string();
+        app.ecs_detail = Some(sample_ecs_detail());
+        let pages = app.notion_pages();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].title, "checkout");
+        assert_eq!(pages[0].resource_type, "ECS");
+        assert_eq!(pages[0].region, app.get_current_region());
+        assert_eq!(pages[0].markdown, "## ECS Cluster (checkout)\n");
+
+        let mut blueprint = Blueprint::new("checkout".to_string());
+        for (resource_type, id) in [
+            (ResourceType::Ecs, "checkout"),
+            (ResourceType::Sqs, "orders"),
+        ] {
+            blueprint.resources.push(BlueprintResource {
+                resource_type,
+                region: "us-east-1".to_string(),
+                resource_id: id.to_string(),
+                resource_name: id.to_string(),
+            });
+        }
+        app.current_blueprint = Some(blueprint);
+        app.blueprint_markdown_parts = vec!["## ECS\n".to_string(), "## SQS\n".to_string()];
+        app.screen = Screen::BlueprintPreview;
+        let pages = app.notion_pages();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].resource_id, "orders");
+        assert_eq!(pages[1].resource_type, "SQS");
+        assert_eq!(pages[1].region, "us-east-1");
+        assert_eq!(pages[1].markdown, "## SQS\n");
+    }
+
+    #[test]
+    fn catalog_resources_follow_preview_or_blueprint_resources() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        assert!(app.catalog_resources().is_empty());
+        app.export_backstage_catalog().expect("nothing to export");
+        assert_eq!(app.message, app.i18n.backstage_nothing_to_export());
+        app.export_inventory().expect("nothing to export");
+        assert_eq!(app.message, app.i18n.inventory_nothing_to_export());
+
+        app.sns_detail = Some(sample_sns_detail());
+        let resources = app.catalog_resources();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].resource_type, ResourceType::Sns);
+        assert_eq!(
+            resources[0].resource_id,
+            "arn:aws:sns:ap-northeast-2:123456789012:alerts"
+        );
+        assert_eq!(resources[0].resource_name, "alerts");
+        assert_eq!(resources[0].region, app.get_current_region());
+
+        let mut blueprint = Blueprint::new("checkout".to_string());
+        blueprint.resources.push(BlueprintResource {
+            resource_type: ResourceType::Lambda,
+            region: "us-east-1".to_string(),
+            resource_id: "api-handler".to_string(),
+            resource_name: "api-handler".to_string(),
+        });
+        app.current_blueprint = Some(blueprint);
+        app.screen = Screen::BlueprintPreview;
+        let resources = app.catalog_resources();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].resource_type, ResourceType::Lambda);
+        assert_eq!(resources[0].region, "us-east-1");
+    }
+
+    #[test]
+    fn apply_query_updates_result_and_command() {
+        let mut app = App::new();
+        app.selected_query_operation = 1;
+        app.query_output = Some(r#"{"Vpcs":[{"VpcId":"vpc-a"},{"VpcId":"vpc-b"}]}"#.to_string());
+        app.query_scroll = 3;
+
+        app.input_buffer = "Vpcs[].VpcId".to_string();
+        app.apply_query();
+        assert_eq!(
+            app.query_result.as_deref(),
+            Ok("[\n  \"vpc-a\",\n  \"vpc-b\"\n]")
+        );
+        assert_eq!(app.query_scroll, 0);
+        assert_eq!(
+            app.query_command(),
+            "aws ec2 describe-vpcs --query 'Vpcs[].VpcId'"
+        );
+
+        app.input_buffer = "Vpcs[".to_string();
+        app.apply_query();
+        assert!(app.query_result.is_err());
+    }
+
+    #[test]
+    fn refresh_profiles_sets_error_when_no_profile_files_exist() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let original_home = env::var_os("HOME");
+        let home = temp_home("no-profiles");
+        fs::create_dir_all(&home).expect("create home");
+
+        unsafe {
+            env::set_var("HOME", &home);
+        }
+
+        let mut app = App::new();
+        app.refresh_profiles();
+
+        assert!(app.available_profiles.is_empty());
+        assert!(
+            app.login_error
+                .as_ref()
+                .map(|error| error.detail.as_str())
+                .unwrap_or_default()
+                .contains(app.i18n.profile_not_found())
+        );
+
+        restore_var("HOME", original_home);
+        let _ = fs::remove_dir_all(home);
+    }
+
+    #[test]
+    fn region_name_uses_language() {
+        let r = Region {
+            code: "us-east-1",
+            name_ko: "버지니아",
+            name_en: "N. Virginia",
+        };
+        assert_eq!(r.name(crate::i18n::Language::Korean), "버지니아");
+        assert_eq!(r.name(crate::i18n::Language::English), "N. Virginia");
+    }
+
+    #[test]
+    fn get_current_resource_type_and_info_priority() {
+        let mut app = App::new();
+
+        app.ec2_detail = Some(sample_ec2_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ec2));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("i-0123456789abcdef0".to_string(), "web-a".to_string()))
+        );
+
+        app.ec2_detail = None;
+        app.network_detail = Some(sample_network_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Network));
+
+        app.network_detail = None;
+        app.sg_detail = Some(sample_sg_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::SecurityGroup)
+        );
+
+        app.sg_detail = None;
+        app.lb_detail = Some(sample_lb_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::LoadBalancer)
+        );
+
+        app.lb_detail = None;
+        app.ecr_detail = Some(sample_ecr_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ecr));
+
+        app.ecr_detail = None;
+        app.asg_detail = Some(sample_asg_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Asg));
+
+        app.asg_detail = None;
+        app.rds_detail = Some(sample_rds_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Rds));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("orders-db".to_string(), "orders-db".to_string()))
+        );
+
+        app.rds_detail = None;
+        app.lambda_detail = Some(sample_lambda_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Lambda));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("orders-api".to_string(), "orders-api".to_string()))
+        );
+
+        app.lambda_detail = None;
+        app.route53_detail = Some(sample_route53_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Route53));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("Z0123456789ABC".to_string(), "example.com".to_string()))
+        );
+
+        app.route53_detail = None;
+        app.eks_detail = Some(sample_eks_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Eks));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("checkout".to_string(), "checkout".to_string()))
+        );
+
+        app.eks_detail = None;
+        app.ecs_detail = Some(sample_ecs_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ecs));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("checkout".to_string(), "checkout".to_string()))
+        );
+
+        app.ecs_detail = None;
+        app.sqs_detail = Some(sample_sqs_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Sqs));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("orders".to_string(), "orders".to_string()))
+        );
+
+        app.sqs_detail = None;
+        app.sns_detail = Some(sample_sns_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Sns));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:sns:ap-northeast-2:123456789012:alerts".to_string(),
+                "alerts".to_string()
+            ))
+        );
+
+        app.sns_detail = None;
+        app.kms_detail = Some(sample_kms_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Kms));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "1234abcd-12ab-34cd-56ef-1234567890ab".to_string(),
+                "app-data".to_string()
+            ))
+        );
+
+        app.kms_detail = None;
+        app.secret_detail = Some(sample_secret_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Secret));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf"
+                    .to_string(),
+                "prod/checkout/db".to_string()
+            ))
+        );
+
+        app.secret_detail = None;
+        app.efs_detail = Some(sample_efs_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Efs));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "fs-0123456789abcdef0".to_string(),
+                "shared-assets".to_string()
+            ))
+        );
+
+        app.efs_detail = None;
+        app.kinesis_stream_detail = Some(sample_kinesis_stream_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Kinesis));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("clickstream".to_string(), "clickstream".to_string()))
+        );
+
+        app.kinesis_stream_detail = None;
+        app.msk_cluster_detail = Some(sample_msk_cluster_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Msk));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/abcd-1".to_string(),
+                "events".to_string()
+            ))
+        );
+
+        app.msk_cluster_detail = None;
+        app.vpn_detail = Some(sample_vpn_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Vpn));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("vpn-0123".to_string(), "office".to_string()))
+        );
+
+        app.vpn_detail = None;
+        app.waf_web_acl_detail = Some(sample_waf_web_acl_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Waf));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4"
+                    .to_string(),
+                "prod-web".to_string()
+            ))
+        );
+
+        app.waf_web_acl_detail = None;
+        app.acm_certificate_detail = Some(sample_acm_certificate_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Acm));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0".to_string(), "example.com".to_string()))
+        );
+
+        app.acm_certificate_detail = None;
+        app.iam_entity_detail = Some(sample_iam_entity_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Iam));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:iam::123456789012:role/app-role".to_string(),
+                "app-role".to_string()
+            ))
+        );
+
+        app.iam_entity_detail = None;
+        app.ami_detail = Some(sample_ami_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ami));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "ami-0a1b2c3d4e5f60718".to_string(),
+                "web-2026-10".to_string()
+            ))
+        );
+
+        app.ami_detail = None;
+        app.ebs_detail = Some(sample_ebs_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Ebs));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("vol-0123".to_string(), "data".to_string()))
+        );
+
+        app.ebs_detail = None;
+        app.key_pair_detail = Some(sample_key_pair_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::KeyPair));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("key-0123".to_string(), "deploy".to_string()))
+        );
+
+        app.key_pair_detail = None;
+        app.cognito_detail = Some(sample_cognito_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Cognito));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "ap-northeast-2_AbCdEf123".to_string(),
+                "customers".to_string()
+            ))
+        );
+
+        app.cognito_detail = None;
+        app.glue_detail = Some(sample_glue_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Glue));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("database/sales".to_string(), "sales".to_string()))
+        );
+
+        app.glue_detail = None;
+        app.athena_workgroup_detail = Some(sample_athena_workgroup_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Athena));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("primary".to_string(), "primary".to_string()))
+        );
+
+        app.athena_workgroup_detail = None;
+        app.backup_plan_detail = Some(sample_backup_plan_detail());
+        assert_eq!(app.get_current_resource_type(), Some(ResourceType::Backup));
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("8a2f1c4e".to_string(), "daily-35d".to_string()))
+        );
+
+        app.key_pair_detail = None;
+        app.event_bus_detail = Some(sample_event_bus_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::EventBridge)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("default".to_string(), "default".to_string()))
+        );
+
+        app.iam_entity_detail = None;
+        app.launch_template_detail = Some(sample_launch_template_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::LaunchTemplate)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("lt-0a1b2c3d4e5f60718".to_string(), "web".to_string()))
+        );
+
+        app.iam_entity_detail = None;
+        app.network_interface_detail = Some(sample_network_interface_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::NetworkInterface)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("eni-0a1b2c3d4e5f60718".to_string(), "old-db".to_string()))
+        );
+
+        app.iam_entity_detail = None;
+        app.vpc_endpoint_detail = Some(sample_vpc_endpoint_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::VpcEndpoint)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "vpce-0a1b2c3d4e5f60718".to_string(),
+                "s3-gateway".to_string()
+            ))
+        );
+
+        app.acm_certificate_detail = None;
+        app.cloudformation_stack_detail = Some(sample_cloudformation_stack_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::CloudFormation)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("checkout".to_string(), "checkout".to_string()))
+        );
+
+        app.vpn_detail = None;
+        app.direct_connect_detail = Some(sample_direct_connect_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::DirectConnect)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("dxvif-0123".to_string(), "prod-private".to_string()))
+        );
+
+        app.msk_cluster_detail = None;
+        app.vpc_peering_detail = Some(sample_vpc_peering_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::VpcPeering)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("pcx-0123".to_string(), "app-to-shared".to_string()))
+        );
+
+        app.msk_cluster_detail = None;
+        app.transit_gateway_detail = Some(sample_transit_gateway_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::TransitGateway)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("tgw-0123".to_string(), "core-tgw".to_string()))
+        );
+
+        app.msk_cluster_detail = None;
+        app.redshift_cluster_detail = Some(sample_redshift_cluster_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::Redshift)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("analytics".to_string(), "analytics".to_string()))
+        );
+
+        app.msk_cluster_detail = None;
+        app.opensearch_domain_detail = Some(sample_opensearch_domain_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::OpenSearch)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("logs".to_string(), "logs".to_string()))
+        );
+
+        app.efs_detail = None;
+        app.state_machine_detail = Some(sample_state_machine_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::StepFunctions)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow".to_string(),
+                "order-flow".to_string()
+            ))
+        );
+
+        app.efs_detail = None;
+        app.elastic_beanstalk_detail = Some(sample_elastic_beanstalk_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::ElasticBeanstalk)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("e-abc123xyz0".to_string(), "orders-prod".to_string()))
+        );
+
+        app.efs_detail = None;
+        app.api_gateway_detail = Some(sample_api_gateway_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::ApiGateway)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("a1b2c3d4e5".to_string(), "orders-api".to_string()))
+        );
+
+        app.secret_detail = None;
+        app.ssm_parameter_detail = Some(sample_ssm_parameter_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::SsmParameter)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("/prod".to_string(), "/prod".to_string()))
+        );
+
+        app.sns_detail = None;
+        app.cloudtrail_detail = Some(sample_cloudtrail_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::CloudTrail)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some((
+                "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/org-audit".to_string(),
+                "org-audit".to_string()
+            ))
+        );
+
+        app.sns_detail = None;
+        app.cloudwatch_alarm_detail = Some(sample_cloudwatch_alarm_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::CloudWatchAlarm)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("api-high-cpu".to_string(), "api-high-cpu".to_string()))
+        );
+
+        app.sns_detail = None;
+        app.elasticache_detail = Some(sample_elasticache_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::ElastiCache)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("sessions".to_string(), "sessions".to_string()))
+        );
+
+        app.lambda_detail = None;
+        app.cloudfront_detail = Some(sample_cloudfront_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::CloudFront)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("E2EXAMPLE".to_string(), "www.example.com".to_string()))
+        );
+
+        app.lambda_detail = None;
+        app.dynamodb_detail = Some(sample_dynamodb_detail());
+        assert_eq!(
+            app.get_current_resource_type(),
+            Some(ResourceType::DynamoDb)
+        );
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("orders".to_string(), "orders".to_string()))
+        );
+    }
+
+    #[test]
+    fn get_current_resource_info_returns_id_then_name_for_connectivity_resources() {
+        // 블루프린트에는 첫 번째 값이 ID로 저장되므로 리소스마다 따로 확인
+        let mut app = App::new();
+        app.transit_gateway_detail = Some(sample_transit_gateway_detail());
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("tgw-0123".to_string(), "core-tgw".to_string()))
+        );
+
+        let mut app = App::new();
+        app.vpc_peering_detail = Some(sample_vpc_peering_detail());
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("pcx-0123".to_string(), "app-to-shared".to_string()))
+        );
+
+        let mut app = App::new();
+        app.vpn_detail = Some(sample_vpn_detail());
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("vpn-0123".to_string(), "office".to_string()))
+        );
+
+        let mut app = App::new();
+        app.direct_connect_detail = Some(sample_direct_connect_detail());
+        assert_eq!(
+            app.get_current_resource_info(),
+            Some(("dxvif-0123".to_string(), "prod-private".to_string()))
+        );
+    }
+
+    #[test]
+    fn get_current_region_returns_selected_code() {
+        let mut app = App::new();
+        app.selected_region = REGIONS.len().saturating_sub(1);
+        assert_eq!(app.get_current_region(), REGIONS[REGIONS.len() - 1].code);
+    }
+
+    #[test]
+    fn regions_for_partition_lists_only_its_regions() {
+        assert_eq!(regions_for(Partition::Aws)[0].code, "ap-northeast-2");
+        assert!(
+            regions_for(Partition::AwsUsGov)
+                .iter()
+                .all(|region| region.code.starts_with("us-gov-"))
+        );
+        assert!(
+            regions_for(Partition::AwsCn)
+                .iter()
+                .all(|region| region.code.starts_with("cn-"))
+        );
+        assert_eq!(GOVCLOUD_REGIONS.len(), 2);
+        assert_eq!(CHINA_REGIONS[1].name_en, "Ningxia");
+        for partition in [Partition::Aws, Partition::AwsUsGov, Partition::AwsCn] {
+            assert!(
+                regions_for(partition)
+                    .iter()
+                    .all(|region| Partition::from_region(region.code) == partition)
+            );
+        }
+    }
 
     #[test]
     fn move_resource_up_down_respects_bounds() {
@@ -1144,4 +4919,40 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn list_refresh_tasks_cover_every_service_list() {
+        assert_eq!(LIST_REFRESH_TASKS.len(), SERVICE_KEYS.len());
+        for (screen, service, task) in LIST_REFRESH_TASKS {
+            assert!(SERVICE_KEYS.contains(service), "{}", service);
+            assert_eq!(task.list_screen().as_ref(), Some(screen), "{}", service);
+        }
+    }
+
+    #[test]
+    fn restore_list_cursor_follows_selected_resource() {
+        let queue = |id: &str| AwsResource {
+            name: id.to_string(),
+            id: id.to_string(),
+            state: String::new(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        };
+        let mut app = App::new();
+        app.screen = Screen::SqsSelect;
+        app.sqs_queues = vec![queue("a"), queue("b"), queue("c")];
+        app.selected_index = 1;
+        let selected_id = app.selected_list_id();
+
+        // 순서가 바뀌어도 같은 리소스를 가리킴
+        app.sqs_queues = vec![queue("c"), queue("a"), queue("b")];
+        app.restore_list_cursor(selected_id.as_deref());
+        assert_eq!(app.selected_index, 2);
+
+        // 리소스가 사라지면 목록 범위 안으로 맞춤
+        app.sqs_queues = vec![queue("a")];
+        app.restore_list_cursor(selected_id.as_deref());
+        assert_eq!(app.selected_index, 0);
+    }
 }
@@ -0,0 +1,179 @@
+// ASCII 모드: 이모지/기호 글꼴이 없는 터미널에서 두부(□)로 보이는 문자를 ASCII로 바꿔 표시
+// TUI는 그린 뒤 버퍼 전체를, 접근성 모드(--no-tui)는 출력 줄을 변환하므로 화면마다 따로 처리하지 않음
+use ratatui::buffer::Buffer;
+use ratatui::text::Span;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::ffi::OsStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsciiMode {
+    #[default]
+    Auto,
+    On,
+    Off,
+}
+
+impl AsciiMode {
+    /// Next mode in the settings cycle.
+    pub fn next(self) -> Self {
+        match self {
+            AsciiMode::Auto => AsciiMode::On,
+            AsciiMode::On => AsciiMode::Off,
+            AsciiMode::Off => AsciiMode::Auto,
+        }
+    }
+}
+
+/// Whether to render ASCII only; `Auto` checks the terminal type and locale.
+pub fn resolve_ascii(mode: AsciiMode) -> bool {
+    match mode {
+        AsciiMode::On => true,
+        AsciiMode::Off => false,
+        AsciiMode::Auto => {
+            // 로캘은 LC_ALL > LC_CTYPE > LANG 순으로 적용됨
+            let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+                .into_iter()
+                .filter_map(std::env::var_os)
+                .find(|value| !value.is_empty());
+            terminal_lacks_unicode(std::env::var_os("TERM").as_deref(), locale.as_deref())
+        }
+    }
+}
+
+fn terminal_lacks_unicode(term: Option<&OsStr>, locale: Option<&OsStr>) -> bool {
+    // 리눅스 가상 콘솔과 dumb 터미널은 이모지 글꼴이 없음
+    if matches!(term.and_then(OsStr::to_str), Some("linux" | "dumb")) {
+        return true;
+    }
+    // C, POSIX, ko_KR.EUC-KR처럼 UTF-8이 아닌 로캘 (지정하지 않았으면 UTF-8로 간주)
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_string_lossy().to_ascii_lowercase();
+            !(locale.contains("utf-8") || locale.contains("utf8"))
+        }
+        None => false,
+    }
+}
+
+// 화면 폭이 바뀌지 않도록 한 글자로 대응 (이모지처럼 두 칸을 차지하는 문자는 남는 칸을 공백으로 채움)
+fn ascii_glyph(ch: char) -> Option<char> {
+    let replacement = match ch {
+        '✓' | '✔' => 'v',
+        '✗' | '✘' => 'x',
+        '▶' | '►' | '▸' => '>',
+        '◀' | '◄' => '<',
+        '●' | '★' | '≠' => '*',
+        '○' => 'o',
+        '⚠' => '!',
+        '↑' => '^',
+        '↓' => 'v',
+        '→' => '>',
+        '←' => '<',
+        '↔' => '=',
+        '—' | '·' | '─' | '━' | '═' => '-',
+        '…' => '.',
+        '│' | '┃' | '║' => '|',
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮' | '╯' | '╰' => {
+            '+'
+        }
+        '█' | '▓' | '▒' => '#',
+        '░' => '.',
+        '⏳' | '☁' => '*',
+        '\u{1F300}'..='\u{1FAFF}' => '*',
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Replace symbols and emoji with ASCII; text without them is returned as is.
+pub fn to_ascii(text: &str) -> Cow<'_, str> {
+    if !text
+        .chars()
+        .any(|ch| ascii_glyph(ch).is_some() || ch == '\u{FE0F}')
+    {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            // 이모지 표현 선택자(VS16)는 앞 문자와 함께 사라짐
+            .filter(|ch| *ch != '\u{FE0F}')
+            .map(|ch| ascii_glyph(ch).unwrap_or(ch))
+            .collect(),
+    )
+}
+
+/// Rewrite every cell of a drawn frame in place, keeping styles and column positions.
+pub fn fold_buffer(buffer: &mut Buffer) {
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let (replacement, width) = {
+                let symbol = buffer[(x, y)].symbol();
+                if symbol.is_ascii() {
+                    continue;
+                }
+                // 한글처럼 대응이 없는 문자는 그대로 둠
+                match symbol.chars().next().and_then(ascii_glyph) {
+                    Some(replacement) => (replacement, Span::raw(symbol).width()),
+                    None => continue,
+                }
+            };
+            buffer[(x, y)].set_char(replacement);
+            for offset in 1..width as u16 {
+                if x + offset < area.right() {
+                    buffer[(x + offset, y)].set_char(' ');
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsciiMode, fold_buffer, terminal_lacks_unicode, to_ascii};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn to_ascii_replaces_symbols_and_keeps_plain_text_borrowed() {
+        assert_eq!(to_ascii("▶ web-1 ✓"), "> web-1 v");
+        assert_eq!(
+            to_ascii("↑↓/jk: Move | ►: Settings"),
+            "^v/jk: Move | >: Settings"
+        );
+        assert_eq!(to_ascii("☁️ Internet"), "* Internet");
+        assert_eq!(to_ascii("📑 목차"), "* 목차");
+        assert!(matches!(to_ascii("plain text 한글"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn terminal_lacks_unicode_checks_term_and_locale() {
+        let os = |value: &'static str| Some(OsStr::new(value));
+        assert!(terminal_lacks_unicode(os("linux"), os("en_US.UTF-8")));
+        assert!(terminal_lacks_unicode(os("xterm-256color"), os("C")));
+        assert!(terminal_lacks_unicode(None, os("ko_KR.EUC-KR")));
+        assert!(!terminal_lacks_unicode(
+            os("xterm-256color"),
+            os("ko_KR.UTF-8")
+        ));
+        assert!(!terminal_lacks_unicode(os("screen"), os("C.utf8")));
+        assert!(!terminal_lacks_unicode(None, None));
+        assert_eq!(AsciiMode::Auto.next().next().next(), AsciiMode::Auto);
+    }
+
+    #[test]
+    fn fold_buffer_keeps_columns_styles_and_hangul() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        buffer.set_string(0, 0, "▶ ⏳ 목록 →", Style::default().fg(Color::Yellow));
+        fold_buffer(&mut buffer);
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        // 두 칸 문자(⏳, 한글)의 뒤 칸은 공백 셀
+        assert_eq!(text, "> *  목 록  > ");
+        assert_eq!(buffer[(0, 0)].fg, Color::Yellow);
+    }
+}
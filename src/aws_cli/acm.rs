@@ -0,0 +1,281 @@
+pub use crate::aws_cli::acm_sdk::{get_acm_certificate_detail, list_acm_certificates};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+// 만료까지 남은 기간이 이 일수 이하이면 마크다운에 경고 표시
+// (ACM은 만료 60일 전부터 자동 갱신하므로 30일 이하로 남았다면 갱신이 실패했을 가능성이 높음)
+pub const NEAR_EXPIRY_DAYS: i64 = 30;
+
+/// ACM certificate with its domains, validation state and the resources using it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AcmCertificateDetail {
+    // 기본 도메인 이름
+    pub name: String,
+    pub arn: String,
+    pub subject_alternative_names: Vec<String>,
+    // ISSUED, PENDING_VALIDATION, EXPIRED, REVOKED, FAILED ...
+    pub status: String,
+    // AMAZON_ISSUED, IMPORTED, PRIVATE
+    pub certificate_type: String,
+    // DNS, EMAIL, HTTP (가져온 인증서는 빈 문자열)
+    pub validation_method: String,
+    pub key_algorithm: String,
+    pub issuer: String,
+    pub not_before: String,
+    pub not_after: String,
+    // 조회 시점 기준 만료까지 남은 일수 (이미 만료되었으면 음수, 발급 전이면 None)
+    pub days_until_expiry: Option<i64>,
+    pub renewal_eligibility: String,
+    pub renewal_status: String,
+    // 인증서를 사용하는 리소스 ARN (ALB, CloudFront, API Gateway ...)
+    pub in_use_by: Vec<String>,
+    pub domain_validations: Vec<AcmDomainValidation>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AcmDomainValidation {
+    pub domain: String,
+    // SUCCESS, PENDING_VALIDATION, FAILED
+    pub status: String,
+    pub method: String,
+    // DNS 검증용 레코드 "이름 CNAME 값" (DNS 검증이 아니면 빈 문자열)
+    pub record: String,
+}
+
+/// Whole days from `now` until `expiry` (both Unix seconds); negative once expired.
+pub fn days_until(expiry: i64, now: i64) -> i64 {
+    (expiry - now).div_euclid(86_400)
+}
+
+impl AcmCertificateDetail {
+    // 만료되었거나 NEAR_EXPIRY_DAYS 안에 만료되는 인증서
+    pub fn is_near_expiry(&self) -> bool {
+        self.days_until_expiry
+            .is_some_and(|days| days <= NEAR_EXPIRY_DAYS)
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![format!(
+            "## {} ({})\n",
+            i18n.md_acm_certificate(),
+            self.name
+        )];
+        if let Some(days) = self.days_until_expiry.filter(|_| self.is_near_expiry()) {
+            let note = if days < 0 {
+                i18n.md_certificate_expired_note(-days)
+            } else {
+                i18n.md_certificate_expiring_note(days)
+            };
+            lines.push(format!("> ⚠ {}\n", note));
+        }
+        lines.extend([
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_domain_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ]);
+        if !self.subject_alternative_names.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subject_alternative_names(),
+                self.subject_alternative_names.join("<br>")
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.status));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_type(),
+            self.certificate_type
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_validation_method(),
+            or_dash(&self.validation_method)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_key_algorithm(),
+            or_dash(&self.key_algorithm)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_issuer(),
+            or_dash(&self.issuer)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_valid_from(),
+            or_dash(&self.not_before)
+        ));
+        // 만료일 옆에 남은 일수 표시: "2026-11-01T00:00:00Z (12d)"
+        let not_after = match self.days_until_expiry {
+            Some(days) if days >= 0 && !self.not_after.is_empty() => {
+                let remaining = i18n.md_days(i32::try_from(days).unwrap_or(i32::MAX));
+                if self.is_near_expiry() {
+                    format!("{} (⚠ {})", self.not_after, remaining)
+                } else {
+                    format!("{} ({})", self.not_after, remaining)
+                }
+            }
+            _ => or_dash(&self.not_after),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_valid_until(), not_after));
+        if !self.renewal_eligibility.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_renewal_eligibility(),
+                self.renewal_eligibility
+            ));
+        }
+        if !self.renewal_status.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_renewal_status(),
+                self.renewal_status
+            ));
+        }
+
+        // Domain Validation
+        if !self.domain_validations.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_domain_validation()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_domain_name(),
+                i18n.md_state(),
+                i18n.md_validation_method(),
+                i18n.md_dns_record()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for validation in &self.domain_validations {
+                let record = if validation.record.is_empty() {
+                    "-".to_string()
+                } else {
+                    format!("`{}`", validation.record)
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    validation.domain,
+                    or_dash(&validation.status),
+                    or_dash(&validation.method),
+                    record
+                ));
+            }
+        }
+
+        // In Use By
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_in_use_by()));
+        if self.in_use_by.is_empty() {
+            lines.push(format!("- {}", i18n.md_not_in_use()));
+        } else {
+            for arn in &self.in_use_by {
+                lines.push(format!("- {}", arn));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AcmCertificateDetail, AcmDomainValidation, days_until};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> AcmCertificateDetail {
+        AcmCertificateDetail {
+            name: "example.com".to_string(),
+            arn: "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0"
+                .to_string(),
+            subject_alternative_names: vec![
+                "example.com".to_string(),
+                "*.example.com".to_string(),
+            ],
+            status: "ISSUED".to_string(),
+            certificate_type: "AMAZON_ISSUED".to_string(),
+            validation_method: "DNS".to_string(),
+            key_algorithm: "RSA-2048".to_string(),
+            issuer: "Amazon".to_string(),
+            not_before: "2026-01-01T00:00:00Z".to_string(),
+            not_after: "2027-01-30T23:59:59Z".to_string(),
+            days_until_expiry: Some(107),
+            renewal_eligibility: "ELIGIBLE".to_string(),
+            renewal_status: String::new(),
+            in_use_by: vec![
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/web/1234"
+                    .to_string(),
+            ],
+            domain_validations: vec![AcmDomainValidation {
+                domain: "example.com".to_string(),
+                status: "SUCCESS".to_string(),
+                method: "DNS".to_string(),
+                record: "_a1b2.example.com. CNAME _c3d4.acm-validations.aws.".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "web".to_string())],
+        }
+    }
+
+    #[test]
+    fn acm_markdown_lists_domains_validation_and_usage() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## ACM Certificate (example.com)"));
+        assert!(!markdown.contains("> ⚠"));
+        assert!(markdown.contains("| Subject Alternative Names | example.com<br>*.example.com |"));
+        assert!(markdown.contains("| Validation Method | DNS |"));
+        assert!(markdown.contains("| Valid Until | 2027-01-30T23:59:59Z (107d) |"));
+        assert!(markdown.contains(
+            "| example.com | SUCCESS | DNS | `_a1b2.example.com. CNAME _c3d4.acm-validations.aws.` |"
+        ));
+        assert!(markdown.contains("### In Use By"));
+        assert!(markdown.contains("- arn:aws:elasticloadbalancing:"));
+        assert!(markdown.contains("| Team | web |"));
+    }
+
+    #[test]
+    fn acm_markdown_flags_near_expiry_and_expired_certificates() {
+        let mut detail = sample_detail();
+        detail.days_until_expiry = Some(12);
+        detail.in_use_by.clear();
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("> ⚠ This certificate expires in 12 days."));
+        assert!(markdown.contains("| Valid Until | 2027-01-30T23:59:59Z (⚠ 12d) |"));
+        assert!(markdown.contains("- Not in use"));
+
+        detail.status = "EXPIRED".to_string();
+        detail.days_until_expiry = Some(-3);
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("> ⚠ 3일 전에 만료된 인증서입니다."));
+        assert!(markdown.contains("| 유효 기간 종료 | 2027-01-30T23:59:59Z |"));
+    }
+
+    #[test]
+    fn days_until_rounds_down_to_whole_days() {
+        let now = 1_767_225_600; // 2026-01-01T00:00:00Z
+        assert_eq!(days_until(now + 86_400 * 30 + 3_600, now), 30);
+        assert_eq!(days_until(now + 3_600, now), 0);
+        assert_eq!(days_until(now - 3_600, now), -1);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::aws_cli::acm::{
+    AcmCertificateDetail, AcmDomainValidation, NEAR_EXPIRY_DAYS, days_until,
+};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_acm::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_acm::types::{
+    CertificateDetail, CertificateSummary, DomainValidation, Filters, KeyAlgorithm,
+};
+
+/// List ACM certificates of every key algorithm using AWS SDK
+pub fn list_acm_certificates() -> Vec<AwsResource> {
+    get_runtime().block_on(list_acm_certificates_async())
+}
+
+async fn list_acm_certificates_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_acm, &config);
+
+    // 필터가 없으면 RSA_1024/RSA_2048 인증서만 반환하므로 모든 키 알고리즘을 지정
+    let key_types = KeyAlgorithm::values()
+        .iter()
+        .map(|value| KeyAlgorithm::from(*value))
+        .collect();
+    let certificates: Vec<CertificateSummary> = match client
+        .list_certificates()
+        .includes(Filters::builder().set_key_types(Some(key_types)).build())
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(certificates) => certificates,
+        Err(e) => {
+            tracing::error!("Error listing ACM certificates: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let mut resources: Vec<AwsResource> = certificates
+        .iter()
+        .map(|summary| map_certificate_resource(summary, now))
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get ACM certificate detail (domains, validation, expiry and usage) using AWS SDK
+pub fn get_acm_certificate_detail(certificate_arn: &str) -> Option<AcmCertificateDetail> {
+    get_runtime().block_on(get_acm_certificate_detail_async(certificate_arn))
+}
+
+async fn get_acm_certificate_detail_async(certificate_arn: &str) -> Option<AcmCertificateDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_acm, &config);
+
+    let output = match client
+        .describe_certificate()
+        .certificate_arn(certificate_arn)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing certificate {}: {:?}", certificate_arn, e);
+            return None;
+        }
+    };
+    let mut detail = map_certificate_detail(output.certificate()?, chrono::Utc::now().timestamp());
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_tags_for_certificate()
+        .certificate_arn(certificate_arn)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            detail.tags = output
+                .tags()
+                .iter()
+                .map(|tag| {
+                    (
+                        tag.key().to_string(),
+                        tag.value().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect();
+            detail.tags.sort();
+        }
+        Err(e) => tracing::warn!("Error listing tags for {}: {:?}", certificate_arn, e),
+    }
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 상태와 만료일을 표시하고, 곧 만료되는 인증서는 ⚠ 표시
+fn map_certificate_resource(summary: &CertificateSummary, now: i64) -> AwsResource {
+    let expiry = summary.not_after().map(|not_after| {
+        let date = format_timestamp(Some(not_after));
+        let date = date.split('T').next().unwrap_or_default().to_string();
+        if days_until(not_after.secs(), now) <= NEAR_EXPIRY_DAYS {
+            format!("{} ⚠", date)
+        } else {
+            date
+        }
+    });
+    AwsResource {
+        name: summary.domain_name().unwrap_or_default().to_string(),
+        id: summary.certificate_arn().unwrap_or_default().to_string(),
+        state: summary
+            .status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: summary
+            .r#type()
+            .map(|certificate_type| certificate_type.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        cidr: expiry.unwrap_or_default(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_certificate_detail(certificate: &CertificateDetail, now: i64) -> AcmCertificateDetail {
+    let domain_validations: Vec<AcmDomainValidation> = certificate
+        .domain_validation_options()
+        .iter()
+        .map(map_domain_validation)
+        .collect();
+    // 인증서의 검증 방법은 도메인별 검증 방법과 같음 (요청 시 하나만 선택)
+    let validation_method = domain_validations
+        .iter()
+        .map(|validation| validation.method.clone())
+        .find(|method| !method.is_empty())
+        .unwrap_or_default();
+
+    AcmCertificateDetail {
+        name: certificate.domain_name().unwrap_or_default().to_string(),
+        arn: certificate
+            .certificate_arn()
+            .unwrap_or_default()
+            .to_string(),
+        subject_alternative_names: certificate.subject_alternative_names().to_vec(),
+        status: certificate
+            .status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        certificate_type: certificate
+            .r#type()
+            .map(|certificate_type| certificate_type.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        validation_method,
+        key_algorithm: certificate
+            .key_algorithm()
+            .map(|algorithm| algorithm.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        issuer: certificate.issuer().unwrap_or_default().to_string(),
+        not_before: format_timestamp(certificate.not_before()),
+        not_after: format_timestamp(certificate.not_after()),
+        days_until_expiry: certificate
+            .not_after()
+            .map(|not_after| days_until(not_after.secs(), now)),
+        renewal_eligibility: certificate
+            .renewal_eligibility()
+            .map(|eligibility| eligibility.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        renewal_status: certificate
+            .renewal_summary()
+            .map(|summary| summary.renewal_status().as_str())
+            .unwrap_or_default()
+            .to_string(),
+        in_use_by: certificate.in_use_by().to_vec(),
+        domain_validations,
+        tags: Vec::new(),
+    }
+}
+
+fn map_domain_validation(validation: &DomainValidation) -> AcmDomainValidation {
+    AcmDomainValidation {
+        domain: validation.domain_name().to_string(),
+        status: validation
+            .validation_status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        method: validation
+            .validation_method()
+            .map(|method| method.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        record: validation
+            .resource_record()
+            .map(|record| {
+                format!(
+                    "{} {} {}",
+                    record.name(),
+                    record.r#type().as_str(),
+                    record.value()
+                )
+            })
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_certificate_detail, map_certificate_resource};
+    use aws_sdk_acm::primitives::DateTime;
+    use aws_sdk_acm::types::{
+        CertificateDetail, CertificateStatus, CertificateSummary, CertificateType, DomainStatus,
+        DomainValidation, KeyAlgorithm, RecordType, RenewalEligibility, ResourceRecord,
+        ValidationMethod,
+    };
+
+    // 2026-01-01T00:00:00Z
+    const NOW: i64 = 1_767_225_600;
+
+    #[test]
+    fn map_certificate_resource_marks_near_expiry() {
+        let summary = |days: i64| {
+            CertificateSummary::builder()
+                .certificate_arn("arn:aws:acm:ap-northeast-2:123456789012:certificate/abcd")
+                .domain_name("example.com")
+                .status(CertificateStatus::Issued)
+                .r#type(CertificateType::AmazonIssued)
+                .not_after(DateTime::from_secs(NOW + days * 86_400))
+                .build()
+        };
+        let resource = map_certificate_resource(&summary(100), NOW);
+        assert_eq!(resource.name, "example.com");
+        assert_eq!(resource.state, "ISSUED");
+        assert_eq!(resource.az, "AMAZON_ISSUED");
+        assert_eq!(resource.cidr, "2026-04-11");
+
+        let resource = map_certificate_resource(&summary(10), NOW);
+        assert_eq!(resource.cidr, "2026-01-11 ⚠");
+    }
+
+    #[test]
+    fn map_certificate_detail_reads_validation_and_usage() {
+        let validation = DomainValidation::builder()
+            .domain_name("example.com")
+            .validation_status(DomainStatus::Success)
+            .validation_method(ValidationMethod::Dns)
+            .resource_record(
+                ResourceRecord::builder()
+                    .name("_a1b2.example.com.")
+                    .r#type(RecordType::Cname)
+                    .value("_c3d4.acm-validations.aws.")
+                    .build()
+                    .expect("resource record"),
+            )
+            .build()
+            .expect("domain validation");
+        let certificate = CertificateDetail::builder()
+            .certificate_arn("arn:aws:acm:ap-northeast-2:123456789012:certificate/abcd")
+            .domain_name("example.com")
+            .subject_alternative_names("example.com")
+            .subject_alternative_names("*.example.com")
+            .status(CertificateStatus::Issued)
+            .r#type(CertificateType::AmazonIssued)
+            .key_algorithm(KeyAlgorithm::Rsa2048)
+            .issuer("Amazon")
+            .not_after(DateTime::from_secs(NOW + 45 * 86_400))
+            .renewal_eligibility(RenewalEligibility::Eligible)
+            .in_use_by("arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE")
+            .domain_validation_options(validation)
+            .build();
+
+        let detail = map_certificate_detail(&certificate, NOW);
+        assert_eq!(detail.name, "example.com");
+        assert_eq!(detail.subject_alternative_names.len(), 2);
+        assert_eq!(detail.validation_method, "DNS");
+        assert_eq!(detail.key_algorithm, "RSA_2048");
+        assert_eq!(detail.not_after, "2026-02-15T00:00:00Z");
+        assert_eq!(detail.days_until_expiry, Some(45));
+        assert!(!detail.is_near_expiry());
+        assert_eq!(detail.renewal_eligibility, "ELIGIBLE");
+        assert_eq!(
+            detail.domain_validations[0].record,
+            "_a1b2.example.com. CNAME _c3d4.acm-validations.aws."
+        );
+        assert_eq!(detail.in_use_by.len(), 1);
+    }
+}
@@ -0,0 +1,531 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use crate::locale::{self, SizeUnit};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImagesResponse {
+    images: Vec<ImageEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImageEntry {
+    image_id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    creation_date: String,
+    #[serde(default)]
+    source_instance_id: String,
+    #[serde(default)]
+    architecture: String,
+    #[serde(default)]
+    platform_details: String,
+    #[serde(default)]
+    root_device_name: String,
+    #[serde(default)]
+    public: bool,
+    #[serde(default)]
+    deprecation_time: String,
+    #[serde(default)]
+    block_device_mappings: Vec<BlockDeviceMapping>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct BlockDeviceMapping {
+    #[serde(default)]
+    device_name: String,
+    #[serde(default)]
+    virtual_name: String,
+    #[serde(default)]
+    ebs: Option<EbsBlockDevice>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EbsBlockDevice {
+    #[serde(default)]
+    volume_size: Option<i32>,
+    #[serde(default)]
+    volume_type: String,
+    #[serde(default)]
+    iops: Option<i32>,
+    #[serde(default)]
+    encrypted: bool,
+    #[serde(default)]
+    delete_on_termination: bool,
+    #[serde(default)]
+    snapshot_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImageAttributeResponse {
+    #[serde(default)]
+    launch_permissions: Vec<LaunchPermissionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchPermissionEntry {
+    #[serde(default)]
+    user_id: String,
+    #[serde(default)]
+    group: String,
+    #[serde(default)]
+    organization_arn: String,
+    #[serde(default)]
+    organizational_unit_arn: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AmiBlockDevice {
+    pub device_name: String,
+    // 인스턴스 스토어 매핑이면 ephemeral0 같은 가상 이름
+    pub virtual_name: String,
+    pub snapshot_id: String,
+    pub size_gb: Option<i32>,
+    pub volume_type: String,
+    pub iops: Option<i32>,
+    pub encrypted: bool,
+    pub delete_on_termination: bool,
+}
+
+/// Who else may launch the image (`describe-image-attribute launchPermission`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum AmiLaunchPermission {
+    Public,
+    Account(String),
+    Organization(String),
+    OrganizationalUnit(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AmiDetail {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub state: String,
+    pub created: String,
+    pub source_instance_id: String,
+    pub architecture: String,
+    pub platform: String,
+    pub root_device: String,
+    pub public: bool,
+    pub deprecation_time: String,
+    pub block_devices: Vec<AmiBlockDevice>,
+    // 권한 조회에 실패하면 None
+    pub launch_permissions: Option<Vec<AmiLaunchPermission>>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl AmiDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        let source_instance = if self.source_instance_id.is_empty() {
+            "-".to_string()
+        } else {
+            name_index::label(&self.source_instance_id)
+        };
+
+        let mut lines = vec![
+            format!("## AMI ({})\n", display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), or_dash(&self.state)),
+            format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                or_dash(&locale::date(&self.created))
+            ),
+            format!("| {} | {} |", i18n.md_source_instance(), source_instance),
+            format!(
+                "| {} | {} |",
+                i18n.md_architecture(),
+                or_dash(&self.architecture)
+            ),
+            format!("| {} | {} |", i18n.md_platform(), or_dash(&self.platform)),
+            format!(
+                "| {} | {} |",
+                i18n.md_root_device(),
+                or_dash(&self.root_device)
+            ),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.deprecation_time.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_deprecation_time(),
+                locale::date(&self.deprecation_time)
+            ));
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        if !self.block_devices.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_storage()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | IOPS | {} | {} |",
+                i18n.md_device(),
+                i18n.md_snapshot(),
+                i18n.md_size(),
+                i18n.md_type(),
+                i18n.md_encrypted(),
+                i18n.md_delete_on_termination()
+            ));
+            lines.push("|:---|:---|---:|:---|---:|:---:|:---:|".to_string());
+            for device in &self.block_devices {
+                // 인스턴스 스토어는 스냅샷/크기 대신 가상 이름만 표시
+                if !device.virtual_name.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} | - | - | - | - | - |",
+                        device.device_name, device.virtual_name
+                    ));
+                    continue;
+                }
+                let size = device
+                    .size_gb
+                    .map(|size| locale::size(size, SizeUnit::Gb))
+                    .unwrap_or_else(|| "-".to_string());
+                let iops = device
+                    .iops
+                    .map(locale::count)
+                    .unwrap_or_else(|| "-".to_string());
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |",
+                    device.device_name,
+                    or_dash(&device.snapshot_id),
+                    size,
+                    or_dash(&device.volume_type),
+                    iops,
+                    if device.encrypted { "✓" } else { "-" },
+                    if device.delete_on_termination {
+                        "✓"
+                    } else {
+                        "-"
+                    }
+                ));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_launch_permissions()));
+        match &self.launch_permissions {
+            Some(permissions) if !permissions.is_empty() => {
+                lines.push(format!("| {} | {} |", i18n.md_type(), i18n.md_principal()));
+                lines.push("|:---|:---|".to_string());
+                for permission in permissions {
+                    let (kind, principal) = match permission {
+                        AmiLaunchPermission::Public => (i18n.md_public(), "all".to_string()),
+                        AmiLaunchPermission::Account(id) => (i18n.md_account(), id.clone()),
+                        AmiLaunchPermission::Organization(arn) => {
+                            (i18n.md_organization(), arn.clone())
+                        }
+                        AmiLaunchPermission::OrganizationalUnit(arn) => {
+                            (i18n.md_organizational_unit(), arn.clone())
+                        }
+                    };
+                    lines.push(format!("| {} | {} |", kind, principal));
+                }
+            }
+            Some(_) => lines.push(i18n.md_not_shared().to_string()),
+            // 권한 조회 실패 시에도 describe-images의 Public 값은 알 수 있음
+            None if self.public => lines.push(format!("{}: all", i18n.md_public())),
+            None => lines.push("-".to_string()),
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Parse `describe-images` output, newest image first.
+pub(crate) fn parse_images_output(output: &str) -> Option<Vec<AmiDetail>> {
+    let response: ImagesResponse = serde_json::from_str(output).ok()?;
+
+    let mut images: Vec<AmiDetail> = response
+        .images
+        .into_iter()
+        .map(|image| {
+            let mut tags: Vec<(String, String)> = image
+                .tags
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            AmiDetail {
+                id: image.image_id,
+                name: image.name,
+                description: image.description,
+                state: image.state,
+                created: image.creation_date,
+                source_instance_id: image.source_instance_id,
+                architecture: image.architecture,
+                platform: image.platform_details,
+                root_device: image.root_device_name,
+                public: image.public,
+                deprecation_time: image.deprecation_time,
+                block_devices: image
+                    .block_device_mappings
+                    .into_iter()
+                    .map(|mapping| {
+                        let ebs = mapping.ebs;
+                        AmiBlockDevice {
+                            device_name: mapping.device_name,
+                            virtual_name: mapping.virtual_name,
+                            snapshot_id: ebs
+                                .as_ref()
+                                .map(|ebs| ebs.snapshot_id.clone())
+                                .unwrap_or_default(),
+                            size_gb: ebs.as_ref().and_then(|ebs| ebs.volume_size),
+                            volume_type: ebs
+                                .as_ref()
+                                .map(|ebs| ebs.volume_type.clone())
+                                .unwrap_or_default(),
+                            iops: ebs.as_ref().and_then(|ebs| ebs.iops),
+                            encrypted: ebs.as_ref().is_some_and(|ebs| ebs.encrypted),
+                            delete_on_termination: ebs
+                                .as_ref()
+                                .is_some_and(|ebs| ebs.delete_on_termination),
+                        }
+                    })
+                    .collect(),
+                launch_permissions: None,
+                tags,
+            }
+        })
+        .collect();
+    // CreationDate는 ISO 8601이라 문자열 비교로 정렬 가능
+    images.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| a.id.cmp(&b.id)));
+    Some(images)
+}
+
+/// Parse `describe-image-attribute --attribute launchPermission` output.
+pub(crate) fn parse_launch_permissions_output(output: &str) -> Option<Vec<AmiLaunchPermission>> {
+    let response: ImageAttributeResponse = serde_json::from_str(output).ok()?;
+
+    Some(
+        response
+            .launch_permissions
+            .into_iter()
+            .filter_map(|permission| {
+                if permission.group == "all" {
+                    Some(AmiLaunchPermission::Public)
+                } else if !permission.user_id.is_empty() {
+                    Some(AmiLaunchPermission::Account(permission.user_id))
+                } else if !permission.organization_arn.is_empty() {
+                    Some(AmiLaunchPermission::Organization(
+                        permission.organization_arn,
+                    ))
+                } else if !permission.organizational_unit_arn.is_empty() {
+                    Some(AmiLaunchPermission::OrganizationalUnit(
+                        permission.organizational_unit_arn,
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    )
+}
+
+pub fn list_amis() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&[
+        "ec2",
+        "describe-images",
+        "--owners",
+        "self",
+        "--output",
+        "json",
+    ]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let images: Vec<AwsResource> = parse_images_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(image_resource)
+        .collect();
+    name_index::record_resources(&images);
+    images
+}
+
+// 목록에는 생성일과 상태를 함께 표시
+fn image_resource(image: AmiDetail) -> AwsResource {
+    AwsResource {
+        name: if image.name.is_empty() {
+            image.id.clone()
+        } else {
+            image.name.clone()
+        },
+        cidr: image.created.chars().take(10).collect(),
+        az: image.state,
+        id: image.id,
+        state: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+pub fn get_ami_detail(image_id: &str) -> Option<AmiDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-images",
+        "--image-ids",
+        image_id,
+        "--output",
+        "json",
+    ])?;
+    let mut image = parse_images_output(&output)?.into_iter().next()?;
+
+    image.launch_permissions = run_aws_cli(&[
+        "ec2",
+        "describe-image-attribute",
+        "--image-id",
+        image_id,
+        "--attribute",
+        "launchPermission",
+        "--output",
+        "json",
+    ])
+    .and_then(|output| parse_launch_permissions_output(&output));
+    Some(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AmiLaunchPermission, image_resource, parse_images_output, parse_launch_permissions_output,
+    };
+    use crate::i18n::Language;
+
+    const IMAGES: &str = r#"
+    {
+      "Images": [
+        {
+          "ImageId": "ami-0old",
+          "Name": "web-2026-01",
+          "State": "available",
+          "CreationDate": "2026-01-05T00:00:00.000Z"
+        },
+        {
+          "ImageId": "ami-0new",
+          "Name": "web-2026-10",
+          "Description": "golden image",
+          "State": "available",
+          "CreationDate": "2026-10-01T02:03:04.000Z",
+          "SourceInstanceId": "i-0abc",
+          "Architecture": "x86_64",
+          "PlatformDetails": "Linux/UNIX",
+          "RootDeviceName": "/dev/xvda",
+          "Public": false,
+          "BlockDeviceMappings": [
+            {"DeviceName": "/dev/xvda", "Ebs": {"SnapshotId": "snap-1", "VolumeSize": 30, "VolumeType": "gp3", "Iops": 3000, "Encrypted": true, "DeleteOnTermination": true}},
+            {"DeviceName": "/dev/sdb", "VirtualName": "ephemeral0"}
+          ],
+          "Tags": [{"Key": "Name", "Value": "web-2026-10"}, {"Key": "Team", "Value": "platform"}]
+        }
+      ]
+    }
+    "#;
+
+    const PERMISSIONS: &str = r#"
+    {
+      "ImageId": "ami-0new",
+      "LaunchPermissions": [
+        {"UserId": "444455556666"},
+        {"OrganizationalUnitArn": "arn:aws:organizations::111122223333:ou/o-1/ou-1"}
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_image_outputs_read_images_and_permissions() {
+        let images = parse_images_output(IMAGES).expect("images");
+        assert_eq!(images.len(), 2);
+        // 최신 AMI부터
+        assert_eq!(images[0].id, "ami-0new");
+        assert_eq!(images[0].source_instance_id, "i-0abc");
+        assert_eq!(images[0].block_devices.len(), 2);
+        assert_eq!(images[0].block_devices[0].snapshot_id, "snap-1");
+        assert_eq!(images[0].block_devices[0].size_gb, Some(30));
+        assert_eq!(images[0].block_devices[1].virtual_name, "ephemeral0");
+        let resource = image_resource(images[1].clone());
+        assert_eq!(resource.name, "web-2026-01");
+        assert_eq!(resource.cidr, "2026-01-05");
+        assert_eq!(resource.az, "available");
+
+        let permissions = parse_launch_permissions_output(PERMISSIONS).expect("permissions");
+        assert_eq!(
+            permissions,
+            vec![
+                AmiLaunchPermission::Account("444455556666".to_string()),
+                AmiLaunchPermission::OrganizationalUnit(
+                    "arn:aws:organizations::111122223333:ou/o-1/ou-1".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            parse_launch_permissions_output(r#"{"LaunchPermissions":[{"Group":"all"}]}"#),
+            Some(vec![AmiLaunchPermission::Public])
+        );
+    }
+
+    #[test]
+    fn ami_markdown_renders_storage_and_sharing() {
+        let mut images = parse_images_output(IMAGES).expect("images");
+        let mut image = images.remove(0);
+        image.launch_permissions = parse_launch_permissions_output(PERMISSIONS);
+
+        let md = image.to_markdown(Language::English);
+        assert!(md.contains("## AMI (web-2026-10 - ami-0new)"));
+        assert!(md.contains("| Created At | 2026-10-01T02:03:04.000Z |"));
+        assert!(md.contains("| Source Instance | i-0abc |"));
+        assert!(md.contains("| /dev/xvda | snap-1 | 30 GB | gp3 | 3000 | ✓ | ✓ |"));
+        assert!(md.contains("| /dev/sdb | ephemeral0 | - | - | - | - | - |"));
+        assert!(md.contains("| Account | 444455556666 |"));
+        assert!(md.contains("| Organizational Unit | arn:aws:organizations::"));
+        assert!(md.contains("| Tag-Team | platform |"));
+
+        let mut private = images.remove(0);
+        private.launch_permissions = Some(vec![]);
+        let md = private.to_markdown(Language::English);
+        assert!(md.contains("Not shared (owning account only)"));
+    }
+}
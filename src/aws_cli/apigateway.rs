@@ -0,0 +1,337 @@
+pub use crate::aws_cli::apigateway_sdk::{get_api_gateway_detail, list_apis};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiGatewayStage {
+    pub name: String,
+    pub deployment_id: String,
+    pub description: String,
+    // HTTP API 전용 (REST API는 항상 false)
+    pub auto_deploy: bool,
+    pub last_updated: String,
+}
+
+// REST API는 리소스 경로 + 메서드, HTTP/WebSocket API는 라우트 키
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiGatewayRoute {
+    // GET /orders/{id}, $default, $connect
+    pub route_key: String,
+    // NONE, AWS_IAM, CUSTOM, COGNITO_USER_POOLS, JWT
+    pub authorization: String,
+    pub authorizer: Option<String>,
+    pub api_key_required: bool,
+    // AWS_PROXY lambda:orders-api, HTTP_PROXY https://..., MOCK
+    pub integration: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiGatewayAuthorizer {
+    pub id: String,
+    pub name: String,
+    // TOKEN, REQUEST, COGNITO_USER_POOLS, JWT
+    pub authorizer_type: String,
+    pub identity_source: String,
+    // Lambda 함수, Cognito 사용자 풀 또는 JWT 발급자
+    pub target: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct ApiGatewayDetail {
+    pub name: String,
+    pub id: String,
+    // REST, HTTP, WEBSOCKET
+    pub protocol: String,
+    // EDGE, REGIONAL, PRIVATE
+    pub endpoint_type: String,
+    pub endpoint: String,
+    pub disable_execute_api_endpoint: bool,
+    pub description: String,
+    pub created: String,
+    pub stages: Vec<ApiGatewayStage>,
+    pub routes: Vec<ApiGatewayRoute>,
+    pub authorizers: Vec<ApiGatewayAuthorizer>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// Short display for an integration or authorizer URI: Lambda ARNs become `lambda:<function>`.
+pub fn integration_target_display(uri: &str) -> String {
+    // REST API의 Lambda URI는 .../functions/<함수 ARN>/invocations 형식
+    match uri.split_once(":function:") {
+        Some((_, rest)) => {
+            let function = rest.split('/').next().unwrap_or(rest);
+            format!("lambda:{}", function)
+        }
+        None => uri.to_string(),
+    }
+}
+
+impl ApiGatewayDetail {
+    // $default 스테이지는 스테이지 이름 없이 호출
+    fn invoke_url(&self, stage: &str) -> String {
+        if self.disable_execute_api_endpoint || self.endpoint.is_empty() {
+            return "-".to_string();
+        }
+        if stage == "$default" {
+            self.endpoint.clone()
+        } else {
+            format!("{}/{}", self.endpoint, stage)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let endpoint = if self.disable_execute_api_endpoint {
+            i18n.md_disabled().to_string()
+        } else {
+            self.endpoint.clone()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.api_gateway_api(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_protocol(), self.protocol),
+            format!("| {} | {} |", i18n.md_endpoint_type(), self.endpoint_type),
+            format!("| {} | {} |", i18n.md_endpoint(), endpoint),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // Stages
+        if !self.stages.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_stages()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_stage(),
+                i18n.md_deployment(),
+                i18n.md_auto_deploy(),
+                i18n.md_last_updated(),
+                i18n.md_invoke_url()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for stage in &self.stages {
+                let auto_deploy = if stage.auto_deploy {
+                    i18n.md_enabled()
+                } else {
+                    i18n.md_disabled()
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    stage.name,
+                    if stage.deployment_id.is_empty() {
+                        "-"
+                    } else {
+                        stage.deployment_id.as_str()
+                    },
+                    auto_deploy,
+                    if stage.last_updated.is_empty() {
+                        "-"
+                    } else {
+                        stage.last_updated.as_str()
+                    },
+                    self.invoke_url(&stage.name)
+                ));
+            }
+        }
+
+        // Routes
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_routes()));
+        if self.routes.is_empty() {
+            lines.push(format!("- {}", i18n.md_no_routes()));
+        } else {
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_route(),
+                i18n.md_authorization(),
+                i18n.md_integration()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for route in &self.routes {
+                let mut authorization = match route.authorizer {
+                    Some(ref authorizer) => format!("{} ({})", route.authorization, authorizer),
+                    None => route.authorization.clone(),
+                };
+                if route.api_key_required {
+                    authorization.push_str(", API key");
+                }
+                lines.push(format!(
+                    "| `{}` | {} | {} |",
+                    route.route_key, authorization, route.integration
+                ));
+            }
+        }
+
+        // Authorizers
+        if !self.authorizers.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_authorizers()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_type(),
+                i18n.md_identity_source(),
+                i18n.md_target()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for authorizer in &self.authorizers {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    authorizer.name,
+                    authorizer.authorizer_type,
+                    if authorizer.identity_source.is_empty() {
+                        "-"
+                    } else {
+                        authorizer.identity_source.as_str()
+                    },
+                    if authorizer.target.is_empty() {
+                        "-"
+                    } else {
+                        authorizer.target.as_str()
+                    }
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ApiGatewayAuthorizer, ApiGatewayDetail, ApiGatewayRoute, ApiGatewayStage,
+        integration_target_display,
+    };
+    use crate::i18n::Language;
+
+    fn sample_detail() -> ApiGatewayDetail {
+        ApiGatewayDetail {
+            name: "orders-api".to_string(),
+            id: "a1b2c3d4e5".to_string(),
+            protocol: "REST".to_string(),
+            endpoint_type: "REGIONAL".to_string(),
+            endpoint: "https://a1b2c3d4e5.execute-api.ap-northeast-2.amazonaws.com".to_string(),
+            disable_execute_api_endpoint: false,
+            description: "Order service".to_string(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            stages: vec![ApiGatewayStage {
+                name: "prod".to_string(),
+                deployment_id: "dep123".to_string(),
+                description: String::new(),
+                auto_deploy: false,
+                last_updated: "2026-02-01T00:00:00Z".to_string(),
+            }],
+            routes: vec![
+                ApiGatewayRoute {
+                    route_key: "GET /orders/{id}".to_string(),
+                    authorization: "CUSTOM".to_string(),
+                    authorizer: Some("token-auth".to_string()),
+                    api_key_required: true,
+                    integration: "AWS_PROXY lambda:orders-get".to_string(),
+                },
+                ApiGatewayRoute {
+                    route_key: "OPTIONS /orders/{id}".to_string(),
+                    authorization: "NONE".to_string(),
+                    authorizer: None,
+                    api_key_required: false,
+                    integration: "MOCK".to_string(),
+                },
+            ],
+            authorizers: vec![ApiGatewayAuthorizer {
+                id: "auth1".to_string(),
+                name: "token-auth".to_string(),
+                authorizer_type: "TOKEN".to_string(),
+                identity_source: "method.request.header.Authorization".to_string(),
+                target: "lambda:authorizer".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "orders".to_string())],
+        }
+    }
+
+    #[test]
+    fn integration_target_display_shortens_lambda_arns() {
+        assert_eq!(
+            integration_target_display(
+                "arn:aws:apigateway:ap-northeast-2:lambda:path/2015-03-31/functions/arn:aws:lambda:ap-northeast-2:123456789012:function:orders-get/invocations"
+            ),
+            "lambda:orders-get"
+        );
+        assert_eq!(
+            integration_target_display(
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:orders-get:live"
+            ),
+            "lambda:orders-get:live"
+        );
+        assert_eq!(
+            integration_target_display("https://backend.example.com/{proxy}"),
+            "https://backend.example.com/{proxy}"
+        );
+    }
+
+    #[test]
+    fn scenario_rest_api_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## API Gateway API (orders-api)"));
+        assert!(markdown.contains("| Protocol | REST |"));
+        assert!(markdown.contains("| Endpoint Type | REGIONAL |"));
+        assert!(markdown.contains(
+            "| prod | dep123 | Disabled | 2026-02-01T00:00:00Z | https://a1b2c3d4e5.execute-api.ap-northeast-2.amazonaws.com/prod |"
+        ));
+        assert!(markdown.contains(
+            "| `GET /orders/{id}` | CUSTOM (token-auth), API key | AWS_PROXY lambda:orders-get |"
+        ));
+        assert!(markdown.contains("| `OPTIONS /orders/{id}` | NONE | MOCK |"));
+        assert!(markdown.contains(
+            "| token-auth | TOKEN | method.request.header.Authorization | lambda:authorizer |"
+        ));
+        assert!(markdown.contains("| Team | orders |"));
+    }
+
+    #[test]
+    fn scenario_http_api_markdown_uses_default_stage_url() {
+        let mut detail = sample_detail();
+        detail.protocol = "HTTP".to_string();
+        detail.stages[0].name = "$default".to_string();
+        detail.stages[0].auto_deploy = true;
+        detail.routes.clear();
+        detail.authorizers.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains(
+            "| $default | dep123 | 활성화 | 2026-02-01T00:00:00Z | https://a1b2c3d4e5.execute-api.ap-northeast-2.amazonaws.com |"
+        ));
+        assert!(markdown.contains("- 라우트가 없습니다."));
+        assert!(!markdown.contains("### 권한 부여자"));
+
+        detail.disable_execute_api_endpoint = true;
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("| Endpoint | Disabled |"));
+        assert!(markdown.contains("| $default | dep123 | Enabled | 2026-02-01T00:00:00Z | - |"));
+    }
+}
@@ -0,0 +1,699 @@
+use crate::aws_cli::apigateway::{
+    ApiGatewayAuthorizer, ApiGatewayDetail, ApiGatewayRoute, ApiGatewayStage,
+    integration_target_display,
+};
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::partition::Partition;
+use aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput;
+use aws_sdk_apigateway::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_apigateway::types::{
+    Authorizer as RestAuthorizer, Resource as RestResource, RestApi, Stage as RestStage,
+};
+use aws_sdk_apigatewayv2::operation::get_api::GetApiOutput;
+use aws_sdk_apigatewayv2::types::{
+    Api as HttpApi, Authorizer as HttpAuthorizer, Integration as HttpIntegration,
+    Route as HttpRoute, Stage as HttpStage,
+};
+use std::collections::HashMap;
+
+// REST API 목록/리소스/권한 부여자 조회의 최대 페이지 크기
+const REST_PAGE_LIMIT: i32 = 500;
+
+// apigatewayv2 목록 API는 paginator가 없어 next_token으로 직접 반복
+macro_rules! collect_v2_pages {
+    ($request:expr) => {{
+        let mut items = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            match $request.set_next_token(next_token.take()).send().await {
+                Ok(output) => {
+                    items.extend(output.items().iter().cloned());
+                    match output.next_token() {
+                        Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                        _ => break Ok(items),
+                    }
+                }
+                Err(e) => break Err(format!("{:?}", e)),
+            }
+        }
+    }};
+}
+
+/// List REST, HTTP and WebSocket APIs using AWS SDK
+pub fn list_apis() -> Vec<AwsResource> {
+    get_runtime().block_on(list_apis_async())
+}
+
+async fn list_apis_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let rest_client = sdk_client!(aws_sdk_apigateway, &config);
+    let http_client = sdk_client!(aws_sdk_apigatewayv2, &config);
+
+    let mut resources: Vec<AwsResource> = Vec::new();
+    match rest_client
+        .get_rest_apis()
+        .limit(REST_PAGE_LIMIT)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(apis) => resources.extend(apis.iter().map(map_rest_api_resource)),
+        Err(e) => tracing::error!("Error getting REST APIs: {:?}", e),
+    }
+
+    let http_apis: Result<Vec<HttpApi>, String> = collect_v2_pages!(http_client.get_apis());
+    match http_apis {
+        Ok(apis) => resources.extend(apis.iter().map(map_http_api_resource)),
+        Err(e) => tracing::error!("Error getting HTTP/WebSocket APIs: {}", e),
+    }
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get API detail (stages, routes, authorizers, integrations) using AWS SDK.
+/// REST API를 먼저 조회하고 없으면 HTTP/WebSocket API로 조회
+pub fn get_api_gateway_detail(api_id: &str) -> Option<ApiGatewayDetail> {
+    get_runtime().block_on(get_api_gateway_detail_async(api_id))
+}
+
+async fn get_api_gateway_detail_async(api_id: &str) -> Option<ApiGatewayDetail> {
+    let config = get_sdk_config().await;
+    let region = config
+        .region()
+        .map(|region| region.to_string())
+        .unwrap_or_default();
+
+    let rest_client = sdk_client!(aws_sdk_apigateway, &config);
+    if let Ok(output) = rest_client.get_rest_api().rest_api_id(api_id).send().await {
+        let mut detail = map_rest_api_detail(&output, &region);
+        load_rest_api_children(&rest_client, api_id, &mut detail).await;
+        return Some(detail);
+    }
+
+    let http_client = sdk_client!(aws_sdk_apigatewayv2, &config);
+    let output = match http_client.get_api().api_id(api_id).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting API {}: {:?}", api_id, e);
+            return None;
+        }
+    };
+    let mut detail = map_http_api_detail(&output);
+    load_http_api_children(&http_client, api_id, &mut detail).await;
+    Some(detail)
+}
+
+// 스테이지/리소스/권한 부여자 조회 실패는 상세 정보 전체를 실패로 보지 않음
+async fn load_rest_api_children(
+    client: &aws_sdk_apigateway::Client,
+    api_id: &str,
+    detail: &mut ApiGatewayDetail,
+) {
+    let authorizers: Vec<RestAuthorizer> = match client
+        .get_authorizers()
+        .rest_api_id(api_id)
+        .limit(REST_PAGE_LIMIT)
+        .send()
+        .await
+    {
+        Ok(output) => output.items().to_vec(),
+        Err(e) => {
+            tracing::warn!("Error getting authorizers for {}: {:?}", api_id, e);
+            Vec::new()
+        }
+    };
+    detail.authorizers = authorizers.iter().map(map_rest_authorizer).collect();
+
+    match client.get_stages().rest_api_id(api_id).send().await {
+        Ok(output) => detail.stages = output.item().iter().map(map_rest_stage).collect(),
+        Err(e) => tracing::warn!("Error getting stages for {}: {:?}", api_id, e),
+    }
+
+    // embed=methods로 메서드와 통합 정보를 한 번에 받음
+    match client
+        .get_resources()
+        .rest_api_id(api_id)
+        .embed("methods")
+        .limit(REST_PAGE_LIMIT)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(resources) => detail.routes = map_rest_routes(&resources, &detail.authorizers),
+        Err(e) => tracing::warn!("Error getting resources for {}: {:?}", api_id, e),
+    }
+
+    detail.stages.sort_by(|a, b| a.name.cmp(&b.name));
+    detail.authorizers.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+async fn load_http_api_children(
+    client: &aws_sdk_apigatewayv2::Client,
+    api_id: &str,
+    detail: &mut ApiGatewayDetail,
+) {
+    let authorizers: Result<Vec<HttpAuthorizer>, String> =
+        collect_v2_pages!(client.get_authorizers().api_id(api_id));
+    match authorizers {
+        Ok(authorizers) => {
+            detail.authorizers = authorizers.iter().map(map_http_authorizer).collect();
+        }
+        Err(e) => tracing::warn!("Error getting authorizers for {}: {}", api_id, e),
+    }
+
+    let stages: Result<Vec<HttpStage>, String> =
+        collect_v2_pages!(client.get_stages().api_id(api_id));
+    match stages {
+        Ok(stages) => detail.stages = stages.iter().map(map_http_stage).collect(),
+        Err(e) => tracing::warn!("Error getting stages for {}: {}", api_id, e),
+    }
+
+    let integrations: Vec<HttpIntegration> =
+        match collect_v2_pages!(client.get_integrations().api_id(api_id)) {
+            Ok(integrations) => integrations,
+            Err(e) => {
+                tracing::warn!("Error getting integrations for {}: {}", api_id, e);
+                Vec::new()
+            }
+        };
+    let routes: Result<Vec<HttpRoute>, String> =
+        collect_v2_pages!(client.get_routes().api_id(api_id));
+    match routes {
+        Ok(routes) => {
+            detail.routes = map_http_routes(&routes, &integrations, &detail.authorizers);
+        }
+        Err(e) => tracing::warn!("Error getting routes for {}: {}", api_id, e),
+    }
+
+    detail.stages.sort_by(|a, b| a.name.cmp(&b.name));
+    detail.authorizers.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn sorted_tags(tags: Option<&HashMap<String, String>>) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .map(|tags| {
+            tags.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    tags.sort();
+    tags
+}
+
+// 목록에는 프로토콜(state)과 엔드포인트 유형(cidr)을 표시
+fn map_rest_api_resource(api: &RestApi) -> AwsResource {
+    let id = api.id().unwrap_or_default().to_string();
+    AwsResource {
+        name: api
+            .name()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&id)
+            .to_string(),
+        id,
+        state: "REST".to_string(),
+        az: String::new(),
+        cidr: api
+            .endpoint_configuration()
+            .map(|configuration| {
+                configuration
+                    .types()
+                    .iter()
+                    .map(|endpoint_type| endpoint_type.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_http_api_resource(api: &HttpApi) -> AwsResource {
+    let id = api.api_id().unwrap_or_default().to_string();
+    AwsResource {
+        name: api
+            .name()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&id)
+            .to_string(),
+        id,
+        state: api
+            .protocol_type()
+            .map(|protocol| protocol.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: "REGIONAL".to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_rest_api_detail(api: &GetRestApiOutput, region: &str) -> ApiGatewayDetail {
+    let id = api.id().unwrap_or_default().to_string();
+    ApiGatewayDetail {
+        name: api.name().unwrap_or(&id).to_string(),
+        endpoint: format!(
+            "https://{}.execute-api.{}.{}",
+            id,
+            region,
+            Partition::from_region(region).dns_suffix()
+        ),
+        id,
+        protocol: "REST".to_string(),
+        endpoint_type: api
+            .endpoint_configuration()
+            .map(|configuration| {
+                configuration
+                    .types()
+                    .iter()
+                    .map(|endpoint_type| endpoint_type.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default(),
+        disable_execute_api_endpoint: api.disable_execute_api_endpoint(),
+        description: api.description().unwrap_or_default().to_string(),
+        created: format_timestamp(api.created_date()),
+        stages: Vec::new(),
+        routes: Vec::new(),
+        authorizers: Vec::new(),
+        tags: sorted_tags(api.tags()),
+    }
+}
+
+fn map_http_api_detail(api: &GetApiOutput) -> ApiGatewayDetail {
+    let id = api.api_id().unwrap_or_default().to_string();
+    ApiGatewayDetail {
+        name: api.name().unwrap_or(&id).to_string(),
+        id,
+        protocol: api
+            .protocol_type()
+            .map(|protocol| protocol.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        // HTTP/WebSocket API는 리전 엔드포인트만 지원
+        endpoint_type: "REGIONAL".to_string(),
+        endpoint: api.api_endpoint().unwrap_or_default().to_string(),
+        disable_execute_api_endpoint: api.disable_execute_api_endpoint().unwrap_or(false),
+        description: api.description().unwrap_or_default().to_string(),
+        created: format_timestamp(api.created_date()),
+        stages: Vec::new(),
+        routes: Vec::new(),
+        authorizers: Vec::new(),
+        tags: sorted_tags(api.tags()),
+    }
+}
+
+fn map_rest_stage(stage: &RestStage) -> ApiGatewayStage {
+    ApiGatewayStage {
+        name: stage.stage_name().unwrap_or_default().to_string(),
+        deployment_id: stage.deployment_id().unwrap_or_default().to_string(),
+        description: stage.description().unwrap_or_default().to_string(),
+        auto_deploy: false,
+        last_updated: format_timestamp(stage.last_updated_date()),
+    }
+}
+
+fn map_http_stage(stage: &HttpStage) -> ApiGatewayStage {
+    ApiGatewayStage {
+        name: stage.stage_name().unwrap_or_default().to_string(),
+        deployment_id: stage.deployment_id().unwrap_or_default().to_string(),
+        description: stage.description().unwrap_or_default().to_string(),
+        auto_deploy: stage.auto_deploy().unwrap_or(false),
+        last_updated: format_timestamp(stage.last_updated_date()),
+    }
+}
+
+fn map_rest_authorizer(authorizer: &RestAuthorizer) -> ApiGatewayAuthorizer {
+    // Cognito 권한 부여자는 사용자 풀 ARN 목록, Lambda 권한 부여자는 함수 URI
+    let target = if authorizer.provider_arns().is_empty() {
+        authorizer
+            .authorizer_uri()
+            .map(integration_target_display)
+            .unwrap_or_default()
+    } else {
+        authorizer
+            .provider_arns()
+            .iter()
+            .map(|arn| {
+                Arn::parse(arn)
+                    .map_or(arn.as_str(), |parsed| parsed.resource_name())
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    ApiGatewayAuthorizer {
+        id: authorizer.id().unwrap_or_default().to_string(),
+        name: authorizer.name().unwrap_or_default().to_string(),
+        authorizer_type: authorizer
+            .r#type()
+            .map(|authorizer_type| authorizer_type.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        identity_source: authorizer.identity_source().unwrap_or_default().to_string(),
+        target,
+    }
+}
+
+fn map_http_authorizer(authorizer: &HttpAuthorizer) -> ApiGatewayAuthorizer {
+    // JWT 권한 부여자는 발급자, REQUEST 권한 부여자는 Lambda 함수
+    let target = match authorizer.jwt_configuration() {
+        Some(jwt) => jwt.issuer().unwrap_or_default().to_string(),
+        None => authorizer
+            .authorizer_uri()
+            .map(integration_target_display)
+            .unwrap_or_default(),
+    };
+    ApiGatewayAuthorizer {
+        id: authorizer.authorizer_id().unwrap_or_default().to_string(),
+        name: authorizer.name().unwrap_or_default().to_string(),
+        authorizer_type: authorizer
+            .authorizer_type()
+            .map(|authorizer_type| authorizer_type.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        identity_source: authorizer.identity_source().join(", "),
+        target,
+    }
+}
+
+fn authorizer_name(authorizers: &[ApiGatewayAuthorizer], id: Option<&str>) -> Option<String> {
+    let id = id.filter(|id| !id.is_empty())?;
+    Some(
+        authorizers
+            .iter()
+            .find(|authorizer| authorizer.id == id)
+            .map_or_else(|| id.to_string(), |authorizer| authorizer.name.clone()),
+    )
+}
+
+fn integration_display(integration_type: &str, uri: Option<&str>) -> String {
+    match uri.filter(|uri| !uri.is_empty()) {
+        Some(uri) => format!("{} {}", integration_type, integration_target_display(uri)),
+        None if integration_type.is_empty() => "-".to_string(),
+        None => integration_type.to_string(),
+    }
+}
+
+// 리소스 경로 + 메서드를 라우트 한 줄로 펼침 (경로, 메서드 순 정렬)
+fn map_rest_routes(
+    resources: &[RestResource],
+    authorizers: &[ApiGatewayAuthorizer],
+) -> Vec<ApiGatewayRoute> {
+    let mut routes: Vec<(String, String, ApiGatewayRoute)> = Vec::new();
+    for resource in resources {
+        let path = resource.path().unwrap_or("/");
+        let Some(methods) = resource.resource_methods() else {
+            continue;
+        };
+        for (http_method, method) in methods {
+            let integration = method.method_integration().map_or_else(
+                || "-".to_string(),
+                |integration| {
+                    integration_display(
+                        integration
+                            .r#type()
+                            .map(|integration_type| integration_type.as_str())
+                            .unwrap_or_default(),
+                        integration.uri(),
+                    )
+                },
+            );
+            routes.push((
+                path.to_string(),
+                http_method.clone(),
+                ApiGatewayRoute {
+                    route_key: format!("{} {}", http_method, path),
+                    authorization: method.authorization_type().unwrap_or("NONE").to_string(),
+                    authorizer: authorizer_name(authorizers, method.authorizer_id()),
+                    api_key_required: method.api_key_required().unwrap_or(false),
+                    integration,
+                },
+            ));
+        }
+    }
+    routes.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+    routes.into_iter().map(|(_, _, route)| route).collect()
+}
+
+// 라우트 대상은 "integrations/<통합 ID>" 형식
+fn map_http_routes(
+    routes: &[HttpRoute],
+    integrations: &[HttpIntegration],
+    authorizers: &[ApiGatewayAuthorizer],
+) -> Vec<ApiGatewayRoute> {
+    let mut mapped: Vec<ApiGatewayRoute> = routes
+        .iter()
+        .map(|route| {
+            let integration = route
+                .target()
+                .and_then(|target| target.strip_prefix("integrations/"))
+                .and_then(|id| {
+                    integrations
+                        .iter()
+                        .find(|integration| integration.integration_id() == Some(id))
+                })
+                .map_or_else(
+                    || "-".to_string(),
+                    |integration| {
+                        integration_display(
+                            integration
+                                .integration_type()
+                                .map(|integration_type| integration_type.as_str())
+                                .unwrap_or_default(),
+                            integration.integration_uri(),
+                        )
+                    },
+                );
+            ApiGatewayRoute {
+                route_key: route.route_key().unwrap_or_default().to_string(),
+                authorization: route
+                    .authorization_type()
+                    .map(|authorization| authorization.as_str())
+                    .unwrap_or("NONE")
+                    .to_string(),
+                authorizer: authorizer_name(authorizers, route.authorizer_id()),
+                api_key_required: route.api_key_required().unwrap_or(false),
+                integration,
+            }
+        })
+        .collect();
+    mapped.sort_by(|a, b| a.route_key.cmp(&b.route_key));
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_http_authorizer, map_http_routes, map_rest_api_detail, map_rest_api_resource,
+        map_rest_authorizer, map_rest_routes,
+    };
+    use aws_sdk_apigateway::operation::get_rest_api::GetRestApiOutput;
+    use aws_sdk_apigateway::types::{
+        Authorizer as RestAuthorizer, AuthorizerType as RestAuthorizerType, EndpointConfiguration,
+        EndpointType, Integration as RestIntegration, IntegrationType as RestIntegrationType,
+        Method, Resource as RestResource, RestApi,
+    };
+    use aws_sdk_apigatewayv2::types::{
+        AuthorizationType, Authorizer as HttpAuthorizer, AuthorizerType as HttpAuthorizerType,
+        Integration as HttpIntegration, IntegrationType as HttpIntegrationType, JwtConfiguration,
+        Route as HttpRoute,
+    };
+
+    #[test]
+    fn map_rest_api_reads_endpoint_type_and_url() {
+        let endpoint_configuration = EndpointConfiguration::builder()
+            .types(EndpointType::Regional)
+            .build();
+        let resource = map_rest_api_resource(
+            &RestApi::builder()
+                .id("a1b2c3d4e5")
+                .name("orders-api")
+                .endpoint_configuration(endpoint_configuration.clone())
+                .build(),
+        );
+        assert_eq!(resource.state, "REST");
+        assert_eq!(resource.cidr, "REGIONAL");
+
+        let detail = map_rest_api_detail(
+            &GetRestApiOutput::builder()
+                .id("a1b2c3d4e5")
+                .name("orders-api")
+                .endpoint_configuration(endpoint_configuration)
+                .tags("Team", "orders")
+                .build(),
+            "ap-northeast-2",
+        );
+        assert_eq!(
+            detail.endpoint,
+            "https://a1b2c3d4e5.execute-api.ap-northeast-2.amazonaws.com"
+        );
+        assert_eq!(detail.endpoint_type, "REGIONAL");
+        assert_eq!(
+            detail.tags,
+            vec![("Team".to_string(), "orders".to_string())]
+        );
+    }
+
+    #[test]
+    fn map_rest_authorizer_lists_cognito_user_pool_ids() {
+        let authorizer = map_rest_authorizer(
+            &RestAuthorizer::builder()
+                .id("auth2")
+                .name("cognito-auth")
+                .r#type(RestAuthorizerType::CognitoUserPools)
+                .provider_arns(
+                    "arn:aws:cognito-idp:ap-northeast-2:123456789012:userpool/ap-northeast-2_AbC123",
+                )
+                .provider_arns("not-an-arn")
+                .build(),
+        );
+        assert_eq!(authorizer.target, "ap-northeast-2_AbC123, not-an-arn");
+    }
+
+    #[test]
+    fn map_rest_routes_flattens_methods_with_authorizers() {
+        let authorizers = vec![map_rest_authorizer(
+            &RestAuthorizer::builder()
+                .id("auth1")
+                .name("token-auth")
+                .r#type(RestAuthorizerType::Token)
+                .authorizer_uri("arn:aws:apigateway:ap-northeast-2:lambda:path/2015-03-31/functions/arn:aws:lambda:ap-northeast-2:123456789012:function:authorizer/invocations")
+                .identity_source("method.request.header.Authorization")
+                .build(),
+        )];
+        assert_eq!(authorizers[0].target, "lambda:authorizer");
+
+        let get = Method::builder()
+            .http_method("GET")
+            .authorization_type("CUSTOM")
+            .authorizer_id("auth1")
+            .api_key_required(true)
+            .method_integration(
+                RestIntegration::builder()
+                    .r#type(RestIntegrationType::AwsProxy)
+                    .uri("arn:aws:apigateway:ap-northeast-2:lambda:path/2015-03-31/functions/arn:aws:lambda:ap-northeast-2:123456789012:function:orders-get/invocations")
+                    .build(),
+            )
+            .build();
+        let options = Method::builder()
+            .http_method("OPTIONS")
+            .authorization_type("NONE")
+            .method_integration(
+                RestIntegration::builder()
+                    .r#type(RestIntegrationType::Mock)
+                    .build(),
+            )
+            .build();
+        let resources = vec![
+            RestResource::builder()
+                .path("/orders/{id}")
+                .resource_methods("OPTIONS", options)
+                .resource_methods("GET", get)
+                .build(),
+            // 메서드가 없는 루트 리소스는 건너뜀
+            RestResource::builder().path("/").build(),
+        ];
+
+        let routes = map_rest_routes(&resources, &authorizers);
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0].route_key, "GET /orders/{id}");
+        assert_eq!(routes[0].authorizer.as_deref(), Some("token-auth"));
+        assert!(routes[0].api_key_required);
+        assert_eq!(routes[0].integration, "AWS_PROXY lambda:orders-get");
+        assert_eq!(routes[1].route_key, "OPTIONS /orders/{id}");
+        assert_eq!(routes[1].authorizer, None);
+        assert_eq!(routes[1].integration, "MOCK");
+    }
+
+    #[test]
+    fn map_http_routes_resolves_integration_targets() {
+        let authorizers = vec![map_http_authorizer(
+            &HttpAuthorizer::builder()
+                .authorizer_id("jwt1")
+                .name("cognito-jwt")
+                .authorizer_type(HttpAuthorizerType::Jwt)
+                .identity_source("$request.header.Authorization")
+                .jwt_configuration(
+                    JwtConfiguration::builder()
+                        .issuer("https://cognito-idp.ap-northeast-2.amazonaws.com/pool")
+                        .build(),
+                )
+                .build(),
+        )];
+        assert_eq!(
+            authorizers[0].target,
+            "https://cognito-idp.ap-northeast-2.amazonaws.com/pool"
+        );
+
+        let integrations = vec![
+            HttpIntegration::builder()
+                .integration_id("int1")
+                .integration_type(HttpIntegrationType::AwsProxy)
+                .integration_uri("arn:aws:lambda:ap-northeast-2:123456789012:function:orders")
+                .build(),
+            HttpIntegration::builder()
+                .integration_id("int2")
+                .integration_type(HttpIntegrationType::HttpProxy)
+                .integration_uri("https://backend.example.com/{proxy}")
+                .build(),
+        ];
+        let routes = vec![
+            HttpRoute::builder()
+                .route_key("POST /orders")
+                .authorization_type(AuthorizationType::Jwt)
+                .authorizer_id("jwt1")
+                .target("integrations/int1")
+                .build(),
+            HttpRoute::builder()
+                .route_key("$default")
+                .target("integrations/int2")
+                .build(),
+            HttpRoute::builder().route_key("GET /health").build(),
+        ];
+
+        let mapped = map_http_routes(&routes, &integrations, &authorizers);
+        let summary: Vec<(&str, &str, Option<&str>, &str)> = mapped
+            .iter()
+            .map(|route| {
+                (
+                    route.route_key.as_str(),
+                    route.authorization.as_str(),
+                    route.authorizer.as_deref(),
+                    route.integration.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "$default",
+                    "NONE",
+                    None,
+                    "HTTP_PROXY https://backend.example.com/{proxy}"
+                ),
+                ("GET /health", "NONE", None, "-"),
+                (
+                    "POST /orders",
+                    "JWT",
+                    Some("cognito-jwt"),
+                    "AWS_PROXY lambda:orders"
+                ),
+            ]
+        );
+    }
+}
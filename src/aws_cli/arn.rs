@@ -0,0 +1,217 @@
+/// Borrowed view of `arn:partition:service:region:account:resource`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arn<'a> {
+    pub partition: &'a str,
+    pub service: &'a str,
+    pub region: &'a str,
+    pub account: &'a str,
+    pub resource: &'a str,
+}
+
+impl<'a> Arn<'a> {
+    /// Split an ARN into its fields. Returns `None` for anything that is not
+    /// a six-field `arn:` string with a non-empty service and resource.
+    pub fn parse(arn: &'a str) -> Option<Self> {
+        let mut parts = arn.splitn(6, ':');
+        if parts.next()? != "arn" {
+            return None;
+        }
+        let parsed = Self {
+            partition: parts.next()?,
+            service: parts.next()?,
+            region: parts.next()?,
+            account: parts.next()?,
+            resource: parts.next()?,
+        };
+        if parsed.service.is_empty() || parsed.resource.is_empty() {
+            return None;
+        }
+        Some(parsed)
+    }
+
+    /// Leading resource segment, e.g. `loadbalancer`, `targetgroup`, `role`.
+    pub fn resource_type(&self) -> &'a str {
+        self.resource
+            .split(['/', ':'])
+            .next()
+            .unwrap_or(self.resource)
+    }
+
+    /// Resource part after the type, e.g. `app/alb-main/1234` or `vpc-1`.
+    pub fn resource_id(&self) -> &'a str {
+        self.resource
+            .split_once(['/', ':'])
+            .map(|(_, rest)| rest)
+            .unwrap_or(self.resource)
+    }
+
+    /// Last `/`-separated segment (the generated ID for LBs and target groups).
+    pub fn resource_suffix(&self) -> &'a str {
+        self.resource.rsplit('/').next().unwrap_or(self.resource)
+    }
+
+    /// Human-readable resource name, following each service's ARN layout.
+    pub fn resource_name(&self) -> &'a str {
+        match (self.service, self.resource_type()) {
+            // loadbalancer/app/<name>/<id>, loadbalancer/<name> (Classic), targetgroup/<name>/<id>
+            ("elasticloadbalancing", "loadbalancer") => {
+                let id = self.resource_id();
+                match id.split_once('/') {
+                    Some(("app" | "net" | "gwy", rest)) => rest.split('/').next().unwrap_or(rest),
+                    _ => id,
+                }
+            }
+            ("elasticloadbalancing", _) => {
+                let id = self.resource_id();
+                id.split('/').next().unwrap_or(id)
+            }
+            // autoScalingGroup:<uuid>:autoScalingGroupName/<name>
+            ("autoscaling", _) => self
+                .resource
+                .split_once("autoScalingGroupName/")
+                .map(|(_, name)| name)
+                .unwrap_or_else(|| self.resource_suffix()),
+            // repository/<name> (이름에 '/' 포함 가능)
+            ("ecr", "repository") => self.resource_id(),
+            // db:<id>, cluster:<id>, replicationgroup:<id>, alarm:<name>, stateMachine:<name>
+            ("rds" | "elasticache" | "cloudwatch" | "states", _) => self.resource_id(),
+            // function:<name>[:<qualifier>]
+            ("lambda", "function") => {
+                let id = self.resource_id();
+                id.split(':').next().unwrap_or(id)
+            }
+            // cluster/<name>/<uuid>
+            ("kafka", "cluster") => {
+                let id = self.resource_id();
+                id.split('/').next().unwrap_or(id)
+            }
+            // secret:<name>-<6자리 임의 접미사> (이름에 '/' 포함 가능)
+            ("secretsmanager", "secret") => {
+                let id = self.resource_id();
+                id.rsplit_once('-').map_or(id, |(name, _)| name)
+            }
+            // regional/webacl/<name>/<id>, global/webacl/<name>/<id>
+            ("wafv2", _) => self.resource.split('/').nth(2).unwrap_or(self.resource),
+            // stack/<name>/<id>
+            ("cloudformation", "stack") => {
+                let id = self.resource_id();
+                id.split('/').next().unwrap_or(id)
+            }
+            _ => self.resource_suffix(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arn;
+
+    #[test]
+    fn parse_splits_fields_and_rejects_malformed_input() {
+        let arn = Arn::parse("arn:aws:ec2:ap-northeast-2:123456789012:vpc/vpc-1").expect("arn");
+        assert_eq!(arn.partition, "aws");
+        assert_eq!(arn.service, "ec2");
+        assert_eq!(arn.region, "ap-northeast-2");
+        assert_eq!(arn.account, "123456789012");
+        assert_eq!(arn.resource_type(), "vpc");
+        assert_eq!(arn.resource_id(), "vpc-1");
+
+        let bucket = Arn::parse("arn:aws:s3:::checkout-assets").expect("s3 arn");
+        assert_eq!(bucket.region, "");
+        assert_eq!(bucket.resource_type(), "checkout-assets");
+
+        // AWS 관리형 정책은 계정 자리에 "aws"
+        let managed = Arn::parse("arn:aws:iam::aws:policy/ReadOnlyAccess").expect("policy arn");
+        assert_eq!(managed.account, "aws");
+
+        assert!(Arn::parse("arn:.../lb-id").is_none());
+        assert!(Arn::parse("vpc-1").is_none());
+        assert!(Arn::parse("arn:aws:ec2:ap-northeast-2:123456789012:").is_none());
+    }
+
+    #[test]
+    fn resource_name_follows_service_layouts() {
+        let cases = [
+            (
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/alb-main/1234",
+                "alb-main",
+                "1234",
+            ),
+            (
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/classic-lb",
+                "classic-lb",
+                "classic-lb",
+            ),
+            (
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:targetgroup/web-blue/1111",
+                "web-blue",
+                "1111",
+            ),
+            (
+                "arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:abcd:autoScalingGroupName/asg-prod",
+                "asg-prod",
+                "asg-prod",
+            ),
+            (
+                "arn:aws:ecr:ap-northeast-2:123456789012:repository/team/api",
+                "team/api",
+                "api",
+            ),
+            (
+                "arn:aws:iam::123456789012:instance-profile/app/web",
+                "web",
+                "web",
+            ),
+            (
+                "arn:aws:rds:ap-northeast-2:123456789012:cluster:orders-aurora",
+                "orders-aurora",
+                "cluster:orders-aurora",
+            ),
+            (
+                "arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:sessions",
+                "sessions",
+                "replicationgroup:sessions",
+            ),
+            (
+                "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:api-high-cpu",
+                "api-high-cpu",
+                "alarm:api-high-cpu",
+            ),
+            (
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:orders-api:live",
+                "orders-api",
+                "function:orders-api:live",
+            ),
+            (
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf",
+                "prod/checkout/db",
+                "db-AbCdEf",
+            ),
+            (
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow",
+                "order-flow",
+                "stateMachine:order-flow",
+            ),
+            (
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0-2",
+                "events",
+                "0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0-2",
+            ),
+            (
+                "arn:aws:wafv2:us-east-1:123456789012:global/webacl/edge-acl/a1b2c3d4",
+                "edge-acl",
+                "a1b2c3d4",
+            ),
+            (
+                "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout/1a2b3c4d-5e6f",
+                "checkout",
+                "1a2b3c4d-5e6f",
+            ),
+        ];
+        for (raw, name, suffix) in cases {
+            let arn = Arn::parse(raw).expect(raw);
+            assert_eq!(arn.resource_name(), name, "{}", raw);
+            assert_eq!(arn.resource_suffix(), suffix, "{}", raw);
+        }
+    }
+}
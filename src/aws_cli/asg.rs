@@ -1,7 +1,12 @@
+use crate::aws_cli::arn::Arn;
 pub use crate::aws_cli::asg_sdk::{get_asg_detail, list_auto_scaling_groups};
+use crate::aws_cli::launch_template::LaunchTemplateDetail;
+use crate::cron;
 use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ScalingPolicy {
     pub name: String,
     pub policy_type: String,
@@ -10,13 +15,29 @@ pub struct ScalingPolicy {
     pub cooldown: Option<i32>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledAction {
+    pub name: String,
+    // 반복 일정 (Unix cron 5필드), 없으면 start_time에 한 번만 실행
+    pub recurrence: Option<String>,
+    pub start_time: String,
+    // IANA 시간대 (없으면 UTC)
+    pub time_zone: Option<String>,
+    pub min_size: Option<i32>,
+    pub max_size: Option<i32>,
+    pub desired_capacity: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
 #[allow(dead_code)]
 pub struct AsgDetail {
     pub name: String,
     pub arn: String,
     pub launch_template_name: Option<String>,
     pub launch_template_id: Option<String>,
+    // "$Latest", "$Default" 또는 버전 번호 (없으면 기본 버전)
+    pub launch_template_version: Option<String>,
+    pub launch_template: Option<LaunchTemplateDetail>,
     pub launch_config_name: Option<String>,
     pub min_size: i32,
     pub max_size: i32,
@@ -29,6 +50,7 @@ pub struct AsgDetail {
     pub instances: Vec<String>,
     pub created_time: String,
     pub scaling_policies: Vec<ScalingPolicy>,
+    pub scheduled_actions: Vec<ScheduledAction>,
     pub tags: Vec<(String, String)>,
 }
 
@@ -62,12 +84,20 @@ impl AsgDetail {
             ));
         }
 
-        lines.push(format!("| {} | {} |", i18n.asg_min_size(), self.min_size));
-        lines.push(format!("| {} | {} |", i18n.asg_max_size(), self.max_size));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.asg_min_size(),
+            locale::count(self.min_size)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.asg_max_size(),
+            locale::count(self.max_size)
+        ));
         lines.push(format!(
             "| {} | {} |",
             i18n.asg_desired_capacity(),
-            self.desired_capacity
+            locale::count(self.desired_capacity)
         ));
         lines.push(format!(
             "| {} | {} |",
@@ -87,9 +117,22 @@ impl AsgDetail {
         lines.push(format!(
             "| {} | {} |",
             i18n.asg_created_at(),
-            self.created_time
+            locale::date(&self.created_time)
         ));
 
+        // Launch Template 설정 (ASG가 사용하는 버전)
+        if let Some(ref template) = self.launch_template
+            && let Some(version) = template.resolve_version(self.launch_template_version.as_deref())
+        {
+            lines.push(String::new());
+            lines.push(format!(
+                "### {} - {}\n",
+                i18n.asg_launch_template(),
+                version.heading(&i18n, template.latest_version)
+            ));
+            lines.extend(version.markdown_lines(&i18n, "####"));
+        }
+
         // Availability Zones
         if !self.availability_zones.is_empty() {
             lines.push(String::new());
@@ -118,7 +161,7 @@ impl AsgDetail {
             lines.push(String::new());
             lines.push(format!("### {}\n", i18n.asg_target_groups()));
             for tg in &self.target_group_arns {
-                let tg_name = tg.split('/').nth(1).unwrap_or(tg);
+                let tg_name = Arn::parse(tg).map(|arn| arn.resource_name()).unwrap_or(tg);
                 lines.push(format!("- {}", tg_name));
             }
         }
@@ -140,7 +183,7 @@ impl AsgDetail {
                 let adj_type = policy.adjustment_type.as_deref().unwrap_or("-");
                 let adj_val = policy
                     .scaling_adjustment
-                    .map(|v| v.to_string())
+                    .map(locale::count)
                     .unwrap_or("-".to_string());
                 let cooldown = policy
                     .cooldown
@@ -153,6 +196,38 @@ impl AsgDetail {
             }
         }
 
+        // Scheduled Actions
+        if !self.scheduled_actions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_scheduled_actions()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.asg_schedule(),
+                i18n.asg_time_zone(),
+                i18n.asg_min_size(),
+                i18n.asg_max_size(),
+                i18n.asg_desired_capacity()
+            ));
+            lines.push("|:---|:---|:---|---:|---:|---:|".to_string());
+            let size = |value: Option<i32>| value.map(locale::count).unwrap_or("-".to_string());
+            for action in &self.scheduled_actions {
+                let schedule = match action.recurrence {
+                    Some(ref recurrence) => cron::schedule_text(recurrence, &i18n),
+                    None => locale::date(&action.start_time),
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    action.name,
+                    schedule,
+                    action.time_zone.as_deref().unwrap_or("UTC"),
+                    size(action.min_size),
+                    size(action.max_size),
+                    size(action.desired_capacity)
+                ));
+            }
+        }
+
         // Tags
         if !self.tags.is_empty() {
             lines.push(String::new());
@@ -173,7 +248,8 @@ impl AsgDetail {
 
 #[cfg(test)]
 mod tests {
-    use super::{AsgDetail, ScalingPolicy};
+    use super::{AsgDetail, ScalingPolicy, ScheduledAction};
+    use crate::aws_cli::launch_template::{LaunchTemplateDetail, LaunchTemplateVersionDetail};
     use crate::i18n::Language;
 
     fn sample_asg_detail() -> AsgDetail {
@@ -182,6 +258,8 @@ mod tests {
             arn: "arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:abcd:autoScalingGroupName/asg-prod".to_string(),
             launch_template_name: Some("lt-web".to_string()),
             launch_template_id: Some("lt-0123456789abcdef0".to_string()),
+            launch_template_version: None,
+            launch_template: None,
             launch_config_name: None,
             min_size: 1,
             max_size: 4,
@@ -202,6 +280,7 @@ mod tests {
                 scaling_adjustment: Some(1),
                 cooldown: Some(60),
             }],
+            scheduled_actions: vec![],
             tags: vec![
                 ("Name".to_string(), "asg-prod".to_string()),
                 ("Env".to_string(), "prod".to_string()),
@@ -216,6 +295,45 @@ mod tests {
         assert!(markdown.contains("| 시작 템플릿 | lt-web (`lt-0123456789abcdef0`) |"));
     }
 
+    #[test]
+    fn scenario_asg_markdown_launch_template_settings() {
+        let mut detail = sample_asg_detail();
+        detail.launch_template_version = Some("$Latest".to_string());
+        detail.launch_template = Some(LaunchTemplateDetail {
+            name: "lt-web".to_string(),
+            id: "lt-0123456789abcdef0".to_string(),
+            default_version: 1,
+            latest_version: 2,
+            created: String::new(),
+            created_by: String::new(),
+            versions: vec![LaunchTemplateVersionDetail {
+                number: 2,
+                is_default: false,
+                description: String::new(),
+                created: String::new(),
+                created_by: String::new(),
+                image_id: "ami-0abc".to_string(),
+                instance_type: "t3.small".to_string(),
+                key_name: String::new(),
+                iam_instance_profile: "web".to_string(),
+                security_groups: vec![],
+                block_devices: vec![],
+                user_data: Some("#!/bin/bash\n".to_string()),
+            }],
+            tags: vec![],
+        });
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("### Launch Template - Version 2 (Latest)"));
+        assert!(markdown.contains("| AMI | ami-0abc |"));
+        assert!(markdown.contains("| Key Pair | - |"));
+        assert!(markdown.contains("#### User Data\n\n```bash\n#!/bin/bash\n```"));
+
+        // 기본 버전을 쓰는데 조회되지 않았으면 설정을 표시하지 않음
+        detail.launch_template_version = None;
+        let markdown = detail.to_markdown(Language::English);
+        assert!(!markdown.contains("| AMI |"));
+    }
+
     #[test]
     fn scenario_asg_markdown_launch_config_fallback() {
         let mut detail = sample_asg_detail();
@@ -247,6 +365,37 @@ mod tests {
         assert!(markdown.contains("| scale-out | SimpleScaling | ChangeInCapacity | 1 | 60초 |"));
     }
 
+    #[test]
+    fn scenario_asg_markdown_scheduled_actions() {
+        let mut detail = sample_asg_detail();
+        detail.scheduled_actions = vec![
+            ScheduledAction {
+                name: "weekday-morning".to_string(),
+                recurrence: Some("0 9 * * 1-5".to_string()),
+                start_time: "2026-10-01T00:00:00Z".to_string(),
+                time_zone: Some("Asia/Seoul".to_string()),
+                min_size: Some(2),
+                max_size: None,
+                desired_capacity: Some(4),
+            },
+            ScheduledAction {
+                name: "launch-day".to_string(),
+                recurrence: None,
+                start_time: "2026-11-11T00:00:00Z".to_string(),
+                time_zone: None,
+                min_size: None,
+                max_size: Some(10),
+                desired_capacity: None,
+            },
+        ];
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("### Scheduled Actions"));
+        // 풀어 쓰기 설정과 관계없이 원본 표현식은 항상 표시
+        assert!(markdown.contains("`0 9 * * 1-5`"));
+        assert!(markdown.contains("| Asia/Seoul | 2 | - | 4 |"));
+        assert!(markdown.contains("| launch-day | 2026-11-11T00:00:00Z | UTC | - | 10 | - |"));
+    }
+
     #[test]
     fn scenario_asg_markdown_tag_name_filtered() {
         let detail = sample_asg_detail();
@@ -268,6 +417,7 @@ mod tests {
         assert!(!markdown.contains("### 가용 영역"));
         assert!(!markdown.contains("### 대상 그룹"));
         assert!(!markdown.contains("### 조정 정책"));
+        assert!(!markdown.contains("### 예약된 작업"));
         assert!(!markdown.contains("### 태그"));
     }
 }
@@ -1,5 +1,6 @@
-use crate::aws_cli::asg::{AsgDetail, ScalingPolicy};
-use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config};
+use crate::aws_cli::asg::{AsgDetail, ScalingPolicy, ScheduledAction};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::launch_template::get_launch_template_with_version;
 use aws_sdk_autoscaling::Client;
 
 /// List all Auto Scaling Groups using AWS SDK
@@ -9,7 +10,7 @@ pub fn list_auto_scaling_groups() -> Vec<AwsResource> {
 
 async fn list_auto_scaling_groups_async() -> Vec<AwsResource> {
     let config = get_sdk_config().await;
-    let client = Client::new(&config);
+    let client = sdk_client!(aws_sdk_autoscaling, &config);
 
     let result = client
         .describe_auto_scaling_groups()
@@ -31,12 +32,20 @@ async fn list_auto_scaling_groups_async() -> Vec<AwsResource> {
 
 /// Get Auto Scaling Group detail using AWS SDK
 pub fn get_asg_detail(asg_name: &str) -> Option<AsgDetail> {
-    get_runtime().block_on(get_asg_detail_async(asg_name))
+    let mut detail = get_runtime().block_on(get_asg_detail_async(asg_name))?;
+    // 시작 템플릿 조회도 같은 런타임에서 block_on 하므로 ASG 조회가 끝난 뒤 수행
+    detail.launch_template = detail
+        .launch_template_id
+        .as_deref()
+        .and_then(|template_id| {
+            get_launch_template_with_version(template_id, detail.launch_template_version.as_deref())
+        });
+    Some(detail)
 }
 
 async fn get_asg_detail_async(asg_name: &str) -> Option<AsgDetail> {
     let config = get_sdk_config().await;
-    let client = Client::new(&config);
+    let client = sdk_client!(aws_sdk_autoscaling, &config);
 
     let result = client
         .describe_auto_scaling_groups()
@@ -54,7 +63,10 @@ async fn get_asg_detail_async(asg_name: &str) -> Option<AsgDetail> {
     let arn = asg.auto_scaling_group_arn().unwrap_or_default().to_string();
 
     let scaling_policies = get_scaling_policies_async(&client, &name).await;
-    Some(map_asg_detail(asg, scaling_policies, Some((name, arn))))
+    let scheduled_actions = get_scheduled_actions_async(&client, &name).await;
+    let mut detail = map_asg_detail(asg, scaling_policies, Some((name, arn)));
+    detail.scheduled_actions = scheduled_actions;
+    Some(detail)
 }
 
 async fn get_scaling_policies_async(client: &Client, asg_name: &str) -> Vec<ScalingPolicy> {
@@ -70,6 +82,25 @@ async fn get_scaling_policies_async(client: &Client, asg_name: &str) -> Vec<Scal
     }
 }
 
+async fn get_scheduled_actions_async(client: &Client, asg_name: &str) -> Vec<ScheduledAction> {
+    let result = client
+        .describe_scheduled_actions()
+        .auto_scaling_group_name(asg_name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(actions) => map_scheduled_actions(&actions),
+        Err(e) => {
+            tracing::error!("Error listing scheduled actions for {}: {:?}", asg_name, e);
+            Vec::new()
+        }
+    }
+}
+
 fn map_asg_resource(asg: &aws_sdk_autoscaling::types::AutoScalingGroup) -> AwsResource {
     let name = asg
         .auto_scaling_group_name()
@@ -85,6 +116,7 @@ fn map_asg_resource(asg: &aws_sdk_autoscaling::types::AutoScalingGroup) -> AwsRe
         state: format!("Desired: {} (Min: {}, Max: {})", desired, min, max),
         az: String::new(),
         cidr: arn,
+        owner_id: String::new(),
     }
 }
 
@@ -102,26 +134,29 @@ fn map_asg_detail(
         )
     });
 
-    let (launch_template_name, launch_template_id) = if let Some(lt) = asg.launch_template() {
-        (
-            lt.launch_template_name().map(|s| s.to_string()),
-            lt.launch_template_id().map(|s| s.to_string()),
-        )
-    } else if let Some(mip) = asg.mixed_instances_policy() {
-        if let Some(lt_spec) = mip
-            .launch_template()
-            .and_then(|lt| lt.launch_template_specification())
-        {
+    let (launch_template_name, launch_template_id, launch_template_version) =
+        if let Some(lt) = asg.launch_template() {
             (
-                lt_spec.launch_template_name().map(|s| s.to_string()),
-                lt_spec.launch_template_id().map(|s| s.to_string()),
+                lt.launch_template_name().map(|s| s.to_string()),
+                lt.launch_template_id().map(|s| s.to_string()),
+                lt.version().map(|s| s.to_string()),
             )
+        } else if let Some(mip) = asg.mixed_instances_policy() {
+            if let Some(lt_spec) = mip
+                .launch_template()
+                .and_then(|lt| lt.launch_template_specification())
+            {
+                (
+                    lt_spec.launch_template_name().map(|s| s.to_string()),
+                    lt_spec.launch_template_id().map(|s| s.to_string()),
+                    lt_spec.version().map(|s| s.to_string()),
+                )
+            } else {
+                (None, None, None)
+            }
         } else {
-            (None, None)
-        }
-    } else {
-        (None, None)
-    };
+            (None, None, None)
+        };
 
     let launch_config_name = asg.launch_configuration_name().map(|s| s.to_string());
     let min_size = asg.min_size();
@@ -176,6 +211,8 @@ fn map_asg_detail(
         arn,
         launch_template_name,
         launch_template_id,
+        launch_template_version,
+        launch_template: None,
         launch_config_name,
         min_size: min_size.unwrap_or(0),
         max_size: max_size.unwrap_or(0),
@@ -188,6 +225,7 @@ fn map_asg_detail(
         instances,
         created_time,
         scaling_policies,
+        scheduled_actions: Vec::new(),
         tags,
     }
 }
@@ -207,9 +245,42 @@ fn map_scaling_policies(
         .collect()
 }
 
+fn map_scheduled_actions(
+    actions: &[aws_sdk_autoscaling::types::ScheduledUpdateGroupAction],
+) -> Vec<ScheduledAction> {
+    let mut actions: Vec<ScheduledAction> = actions
+        .iter()
+        .map(|a| ScheduledAction {
+            name: a.scheduled_action_name().unwrap_or_default().to_string(),
+            recurrence: a.recurrence().map(|s| s.to_string()),
+            start_time: a
+                .start_time()
+                .or(a.time())
+                .and_then(|dt| {
+                    dt.fmt(aws_sdk_autoscaling::primitives::DateTimeFormat::DateTime)
+                        .ok()
+                })
+                .unwrap_or_default(),
+            time_zone: a.time_zone().map(|s| s.to_string()),
+            min_size: a.min_size(),
+            max_size: a.max_size(),
+            desired_capacity: a.desired_capacity(),
+        })
+        .collect();
+    // 한 번만 실행하는 작업은 시각순, 반복 작업은 그 뒤에 이름순
+    actions.sort_by(|a, b| {
+        a.recurrence
+            .is_some()
+            .cmp(&b.recurrence.is_some())
+            .then_with(|| a.start_time.cmp(&b.start_time))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    actions
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{map_asg_detail, map_asg_resource, map_scaling_policies};
+    use super::{map_asg_detail, map_asg_resource, map_scaling_policies, map_scheduled_actions};
 
     #[test]
     fn map_asg_resource_formats_capacity_state() {
@@ -258,6 +329,7 @@ mod tests {
         let launch_template = aws_sdk_autoscaling::types::LaunchTemplateSpecification::builder()
             .launch_template_name("lt-main")
             .launch_template_id("lt-1234")
+            .version("$Latest")
             .build();
         let instance = aws_sdk_autoscaling::types::Instance::builder()
             .instance_id("i-1234")
@@ -287,6 +359,8 @@ mod tests {
         assert_eq!(detail.name, "asg-main");
         assert_eq!(detail.launch_template_name.as_deref(), Some("lt-main"));
         assert_eq!(detail.launch_template_id.as_deref(), Some("lt-1234"));
+        assert_eq!(detail.launch_template_version.as_deref(), Some("$Latest"));
+        assert!(detail.launch_template.is_none());
         assert_eq!(detail.instances, vec!["i-1234".to_string()]);
         assert_eq!(detail.tags, vec![("Env".to_string(), "prod".to_string())]);
     }
@@ -339,4 +413,30 @@ mod tests {
         assert_eq!(mapped[0].scaling_adjustment, None);
         assert_eq!(mapped[0].cooldown, None);
     }
+
+    #[test]
+    fn map_scheduled_actions_orders_one_time_before_recurring() {
+        let recurring = aws_sdk_autoscaling::types::ScheduledUpdateGroupAction::builder()
+            .scheduled_action_name("weekday-morning")
+            .recurrence("0 9 * * 1-5")
+            .start_time(aws_sdk_autoscaling::primitives::DateTime::from_secs(0))
+            .time_zone("Asia/Seoul")
+            .desired_capacity(4)
+            .build();
+        let one_time = aws_sdk_autoscaling::types::ScheduledUpdateGroupAction::builder()
+            .scheduled_action_name("launch-day")
+            .start_time(aws_sdk_autoscaling::primitives::DateTime::from_secs(86_400))
+            .max_size(10)
+            .build();
+
+        let mapped = map_scheduled_actions(&[recurring, one_time]);
+        assert_eq!(mapped.len(), 2);
+        assert_eq!(mapped[0].name, "launch-day");
+        assert_eq!(mapped[0].recurrence, None);
+        assert_eq!(mapped[0].start_time, "1970-01-02T00:00:00Z");
+        assert_eq!(mapped[0].max_size, Some(10));
+        assert_eq!(mapped[1].recurrence.as_deref(), Some("0 9 * * 1-5"));
+        assert_eq!(mapped[1].time_zone.as_deref(), Some("Asia/Seoul"));
+        assert_eq!(mapped[1].desired_capacity, Some(4));
+    }
 }
@@ -0,0 +1,209 @@
+pub use crate::aws_cli::athena_sdk::{get_athena_workgroup_detail, list_athena_workgroups};
+use crate::aws_cli::partition::Partition;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct AthenaWorkGroupDetail {
+    pub name: String,
+    // 계정 ID를 아직 모르면 빈 문자열
+    pub arn: String,
+    // ENABLED, DISABLED
+    pub state: String,
+    pub description: String,
+    pub created: String,
+    // "Athena engine version 3", 자동 업그레이드면 "(AUTO)"를 덧붙임
+    pub engine_version: String,
+    pub output_location: String,
+    // SSE_S3, SSE_KMS, CSE_KMS, 결과를 암호화하지 않으면 빈 문자열
+    pub encryption: String,
+    pub kms_key: String,
+    // 클라이언트 쪽 설정보다 작업 그룹 설정을 우선 적용
+    pub enforce_configuration: bool,
+    pub publish_metrics: bool,
+    // 쿼리당 스캔 데이터 한도 (바이트)
+    pub bytes_scanned_cutoff: Option<i64>,
+    pub requester_pays: bool,
+    // Spark 작업 그룹만 실행 역할을 가짐
+    pub execution_role: String,
+    pub tags: Vec<(String, String)>,
+}
+
+/// ARN of a workgroup for the Athena tagging API.
+pub fn athena_workgroup_arn(name: &str, region: &str, account: &str) -> String {
+    format!(
+        "arn:{}:athena:{}:{}:workgroup/{}",
+        Partition::from_region(region).as_str(),
+        region,
+        account,
+        name
+    )
+}
+
+/// Engine version in effect; `AUTO` workgroups also show that they upgrade themselves.
+pub fn engine_version_text(selected: &str, effective: &str) -> String {
+    match (selected, effective) {
+        (_, "") => selected.to_string(),
+        ("AUTO", effective) => format!("{} (AUTO)", effective),
+        (_, effective) => effective.to_string(),
+    }
+}
+
+impl AthenaWorkGroupDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.athena_workgroup(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+        ];
+        if !self.arn.is_empty() {
+            lines.push(format!("| ARN | {} |", self.arn));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.state));
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.engine_version.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_engine_version(),
+                self.engine_version
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_query_result_location(),
+            if self.output_location.is_empty() {
+                "-"
+            } else {
+                &self.output_location
+            }
+        ));
+        let encryption = match (self.encryption.as_str(), self.kms_key.as_str()) {
+            ("", _) => i18n.md_disabled().to_string(),
+            (option, "") => option.to_string(),
+            (option, key) => format!("{} ({})", option, key),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_enforce_workgroup_settings(),
+            enabled(self.enforce_configuration)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_cloudwatch_metrics(),
+            enabled(self.publish_metrics)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_bytes_scanned_cutoff(),
+            self.bytes_scanned_cutoff
+                .map(locale::bytes)
+                .unwrap_or_else(|| "-".to_string())
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_requester_pays(),
+            enabled(self.requester_pays)
+        ));
+        if !self.execution_role.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_execution_role(),
+                self.execution_role
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AthenaWorkGroupDetail, athena_workgroup_arn, engine_version_text};
+    use crate::i18n::Language;
+
+    #[test]
+    fn workgroup_arn_and_engine_version_helpers() {
+        assert_eq!(
+            athena_workgroup_arn("analytics", "cn-north-1", "123456789012"),
+            "arn:aws-cn:athena:cn-north-1:123456789012:workgroup/analytics"
+        );
+        assert_eq!(
+            engine_version_text("AUTO", "Athena engine version 3"),
+            "Athena engine version 3 (AUTO)"
+        );
+        assert_eq!(
+            engine_version_text("Athena engine version 3", "Athena engine version 3"),
+            "Athena engine version 3"
+        );
+        assert_eq!(engine_version_text("AUTO", ""), "AUTO");
+    }
+
+    #[test]
+    fn workgroup_markdown_shows_result_location_and_limits() {
+        let detail = AthenaWorkGroupDetail {
+            name: "analytics".to_string(),
+            arn: "arn:aws:athena:ap-northeast-2:123456789012:workgroup/analytics".to_string(),
+            state: "ENABLED".to_string(),
+            description: "BI queries".to_string(),
+            created: String::new(),
+            engine_version: "Athena engine version 3 (AUTO)".to_string(),
+            output_location: "s3://athena-results/analytics/".to_string(),
+            encryption: "SSE_KMS".to_string(),
+            kms_key: "alias/athena".to_string(),
+            enforce_configuration: true,
+            publish_metrics: false,
+            bytes_scanned_cutoff: None,
+            requester_pays: false,
+            execution_role: String::new(),
+            tags: vec![("Team".to_string(), "bi".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Athena Workgroup (analytics)"));
+        assert!(md.contains("| Engine Version | Athena engine version 3 (AUTO) |"));
+        assert!(md.contains("| Query Result Location | s3://athena-results/analytics/ |"));
+        assert!(md.contains("| Encryption | SSE_KMS (alias/athena) |"));
+        assert!(md.contains("| Override Client-side Settings | Enabled |"));
+        assert!(md.contains("| CloudWatch Metrics | Disabled |"));
+        assert!(md.contains("| Data Scanned Limit per Query | - |"));
+        assert!(!md.contains("| Execution Role |"));
+        assert!(md.contains("| Team | bi |"));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::aws_cli::athena::{AthenaWorkGroupDetail, athena_workgroup_arn, engine_version_text};
+use crate::aws_cli::common::{
+    AwsResource, current_account_id, current_region, get_runtime, get_sdk_config, sdk_client,
+};
+use aws_sdk_athena::operation::list_tags_for_resource::ListTagsForResourceOutput;
+use aws_sdk_athena::operation::list_work_groups::ListWorkGroupsOutput;
+use aws_sdk_athena::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_athena::types::{EngineVersion, WorkGroup, WorkGroupSummary};
+
+/// List Athena workgroups using AWS SDK
+pub fn list_athena_workgroups() -> Vec<AwsResource> {
+    get_runtime().block_on(list_athena_workgroups_async())
+}
+
+async fn list_athena_workgroups_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_athena, &config);
+
+    let pages: Vec<ListWorkGroupsOutput> = match client
+        .list_work_groups()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => pages,
+        Err(e) => {
+            tracing::error!("Error listing Athena workgroups: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = pages
+        .iter()
+        .flat_map(|page| page.work_groups())
+        .map(map_workgroup_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get Athena workgroup detail (result location, encryption, limits) using AWS SDK
+pub fn get_athena_workgroup_detail(name: &str) -> Option<AthenaWorkGroupDetail> {
+    get_runtime().block_on(get_athena_workgroup_detail_async(name))
+}
+
+async fn get_athena_workgroup_detail_async(name: &str) -> Option<AthenaWorkGroupDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_athena, &config);
+
+    let output = match client.get_work_group().work_group(name).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting Athena workgroup {}: {:?}", name, e);
+            return None;
+        }
+    };
+    let mut detail = map_workgroup_detail(output.work_group()?);
+
+    // 태그 API는 ARN만 받으므로 계정 ID를 알 때만 조회
+    if let Some(account) = current_account_id() {
+        detail.arn = athena_workgroup_arn(&detail.name, &current_region(), &account);
+        let tags: Result<Vec<ListTagsForResourceOutput>, _> = client
+            .list_tags_for_resource()
+            .resource_arn(&detail.arn)
+            .into_paginator()
+            .send()
+            .try_collect()
+            .await;
+        match tags {
+            Ok(pages) => {
+                let mut tags: Vec<(String, String)> = pages
+                    .iter()
+                    .flat_map(|page| page.tags())
+                    .map(|tag| {
+                        (
+                            tag.key().unwrap_or_default().to_string(),
+                            tag.value().unwrap_or_default().to_string(),
+                        )
+                    })
+                    .collect();
+                tags.sort();
+                detail.tags = tags;
+            }
+            Err(e) => tracing::warn!("Error listing tags for {}: {:?}", detail.arn, e),
+        }
+    }
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn engine_version(version: Option<&EngineVersion>) -> String {
+    version
+        .map(|version| {
+            engine_version_text(
+                version.selected_engine_version().unwrap_or_default(),
+                version.effective_engine_version().unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default()
+}
+
+// 목록에는 상태와 엔진 버전(cidr)을 표시
+fn map_workgroup_resource(workgroup: &WorkGroupSummary) -> AwsResource {
+    let name = workgroup.name().unwrap_or_default();
+    AwsResource {
+        name: name.to_string(),
+        id: name.to_string(),
+        state: workgroup
+            .state()
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_default(),
+        az: String::new(),
+        cidr: engine_version(workgroup.engine_version()),
+        owner_id: String::new(),
+    }
+}
+
+fn map_workgroup_detail(workgroup: &WorkGroup) -> AthenaWorkGroupDetail {
+    let configuration = workgroup.configuration();
+    let result = configuration.and_then(|configuration| configuration.result_configuration());
+    let encryption = result.and_then(|result| result.encryption_configuration());
+    let flag = |value: Option<bool>| value.unwrap_or(false);
+    AthenaWorkGroupDetail {
+        name: workgroup.name().to_string(),
+        arn: String::new(),
+        state: workgroup
+            .state()
+            .map(|state| state.as_str().to_string())
+            .unwrap_or_default(),
+        description: workgroup.description().unwrap_or_default().to_string(),
+        created: format_timestamp(workgroup.creation_time()),
+        engine_version: engine_version(
+            configuration.and_then(|configuration| configuration.engine_version()),
+        ),
+        output_location: result
+            .and_then(|result| result.output_location())
+            .unwrap_or_default()
+            .to_string(),
+        encryption: encryption
+            .map(|encryption| encryption.encryption_option().as_str().to_string())
+            .unwrap_or_default(),
+        kms_key: encryption
+            .and_then(|encryption| encryption.kms_key())
+            .unwrap_or_default()
+            .to_string(),
+        enforce_configuration: flag(
+            configuration
+                .and_then(|configuration| configuration.enforce_work_group_configuration()),
+        ),
+        publish_metrics: flag(
+            configuration
+                .and_then(|configuration| configuration.publish_cloud_watch_metrics_enabled()),
+        ),
+        bytes_scanned_cutoff: configuration
+            .and_then(|configuration| configuration.bytes_scanned_cutoff_per_query()),
+        requester_pays: flag(
+            configuration.and_then(|configuration| configuration.requester_pays_enabled()),
+        ),
+        execution_role: configuration
+            .and_then(|configuration| configuration.execution_role())
+            .unwrap_or_default()
+            .to_string(),
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_workgroup_detail, map_workgroup_resource};
+    use aws_sdk_athena::types::{
+        EncryptionConfiguration, EncryptionOption, EngineVersion, ResultConfiguration, WorkGroup,
+        WorkGroupConfiguration, WorkGroupState, WorkGroupSummary,
+    };
+
+    #[test]
+    fn map_workgroup_resource_shows_state_and_engine() {
+        let resource = map_workgroup_resource(
+            &WorkGroupSummary::builder()
+                .name("primary")
+                .state(WorkGroupState::Enabled)
+                .engine_version(
+                    EngineVersion::builder()
+                        .selected_engine_version("AUTO")
+                        .effective_engine_version("Athena engine version 3")
+                        .build(),
+                )
+                .build(),
+        );
+        assert_eq!(resource.id, "primary");
+        assert_eq!(resource.state, "ENABLED");
+        assert_eq!(resource.cidr, "Athena engine version 3 (AUTO)");
+    }
+
+    #[test]
+    fn map_workgroup_detail_reads_result_configuration() {
+        let workgroup = WorkGroup::builder()
+            .name("analytics")
+            .state(WorkGroupState::Enabled)
+            .configuration(
+                WorkGroupConfiguration::builder()
+                    .result_configuration(
+                        ResultConfiguration::builder()
+                            .output_location("s3://athena-results/analytics/")
+                            .encryption_configuration(
+                                EncryptionConfiguration::builder()
+                                    .encryption_option(EncryptionOption::SseKms)
+                                    .kms_key("alias/athena")
+                                    .build()
+                                    .unwrap(),
+                            )
+                            .build(),
+                    )
+                    .enforce_work_group_configuration(true)
+                    .bytes_scanned_cutoff_per_query(10_737_418_240)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+
+        let detail = map_workgroup_detail(&workgroup);
+        assert_eq!(detail.output_location, "s3://athena-results/analytics/");
+        assert_eq!(detail.encryption, "SSE_KMS");
+        assert_eq!(detail.kms_key, "alias/athena");
+        assert!(detail.enforce_configuration);
+        assert!(!detail.publish_metrics);
+        assert_eq!(detail.bytes_scanned_cutoff, Some(10_737_418_240));
+    }
+}
@@ -0,0 +1,340 @@
+use crate::aws_cli::read_only::GuardRejected;
+use aws_config::Region;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeSerializationInterceptorContextRef, FinalizerInterceptorContextRef,
+};
+use aws_smithy_runtime_api::client::orchestrator::Metadata;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+// 세션 동안 호출한 AWS API 기록 (~/.emd/audit/session-<시각>.jsonl)
+static SESSION: Mutex<Option<AuditSession>> = Mutex::new(None);
+// reset_call_failures 이후 실패한 호출 중 가장 심각한 것 (블루프린트 검증용)
+static CALL_FAILURE: Mutex<CallFailure> = Mutex::new(CallFailure::None);
+
+/// Worst failure among the SDK calls made since [`reset_call_failures`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum CallFailure {
+    #[default]
+    None,
+    // 리소스가 없다는 응답 (NoSuchEntity, InvalidInstanceID.NotFound 등)
+    NotFound,
+    // 권한, 스로틀링, 네트워크 등 리소스 존재 여부를 알 수 없는 실패
+    Other,
+}
+
+pub fn reset_call_failures() {
+    if let Ok(mut failure) = CALL_FAILURE.lock() {
+        *failure = CallFailure::None;
+    }
+}
+
+pub fn call_failure() -> CallFailure {
+    CALL_FAILURE
+        .lock()
+        .map(|f| *f)
+        .unwrap_or(CallFailure::Other)
+}
+
+fn note_failure(kind: CallFailure) {
+    if let Ok(mut failure) = CALL_FAILURE.lock() {
+        *failure = (*failure).max(kind);
+    }
+}
+
+/// Whether a failed call's error says the requested resource does not exist.
+fn is_not_found_error(error_text: &str, status: Option<u16>) -> bool {
+    let lower = error_text.to_ascii_lowercase();
+    const NOT_FOUND_MARKERS: [&str; 5] = [
+        "notfound",
+        "nosuch",
+        "nonexistent",
+        "doesnotexist",
+        "does not exist",
+    ];
+    status == Some(404)
+        || NOT_FOUND_MARKERS
+            .iter()
+            .any(|marker| lower.contains(marker))
+}
+
+struct AuditSession {
+    path: PathBuf,
+    entries: Vec<AuditEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub service: String,
+    pub operation: String,
+    pub region: String,
+    pub duration_ms: u64,
+    // "ok", "error (403)", 응답 없이 실패한 경우 "error", 읽기 전용 가드가 막은 경우 "rejected"
+    pub result: String,
+}
+
+impl AuditEntry {
+    fn failed(&self) -> bool {
+        self.result != "ok"
+    }
+}
+
+/// Start recording AWS API calls for this session. The file is created on the first call.
+pub fn start_session() {
+    let Some(home) = dirs::home_dir() else {
+        return;
+    };
+    let file_name = format!(
+        "session-{}.jsonl",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let path = home.join(".emd").join("audit").join(file_name);
+    if let Ok(mut session) = SESSION.lock() {
+        *session = Some(AuditSession {
+            path,
+            entries: Vec::new(),
+        });
+    }
+}
+
+fn record(entry: AuditEntry) {
+    let Ok(mut session) = SESSION.lock() else {
+        return;
+    };
+    let Some(session) = session.as_mut() else {
+        return;
+    };
+
+    if let Err(e) = append_entry(&session.path, &entry) {
+        tracing::warn!(
+            "Failed to write audit log {}: {}",
+            session.path.display(),
+            e
+        );
+    }
+    session.entries.push(entry);
+}
+
+fn append_entry(path: &PathBuf, entry: &AuditEntry) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+}
+
+fn result_label(failed: bool, status: Option<u16>) -> String {
+    match (failed, status) {
+        (false, _) => "ok".to_string(),
+        (true, Some(status)) => format!("error ({})", status),
+        (true, None) => "error".to_string(),
+    }
+}
+
+/// Summary of the calls recorded so far, or `None` when nothing was called.
+pub fn session_summary() -> Option<String> {
+    let session = SESSION.lock().ok()?;
+    let session = session.as_ref()?;
+    if session.entries.is_empty() {
+        return None;
+    }
+    Some(format_summary(
+        &session.path.display().to_string(),
+        &session.entries,
+    ))
+}
+
+fn format_summary(path: &str, entries: &[AuditEntry]) -> String {
+    // (서비스, 작업)별 호출 수, 실패 수, 누적 시간
+    let mut operations: BTreeMap<(&str, &str), (usize, usize, u64)> = BTreeMap::new();
+    for entry in entries {
+        let stats = operations
+            .entry((entry.service.as_str(), entry.operation.as_str()))
+            .or_default();
+        stats.0 += 1;
+        if entry.failed() {
+            stats.1 += 1;
+        }
+        stats.2 += entry.duration_ms;
+    }
+
+    let failures = entries.iter().filter(|entry| entry.failed()).count();
+    let total_ms: u64 = entries.iter().map(|entry| entry.duration_ms).sum();
+    let mut lines = vec![
+        format!(
+            "AWS API calls: {} ({} failed, {} ms)",
+            entries.len(),
+            failures,
+            total_ms
+        ),
+        format!("Audit log: {}", path),
+    ];
+    for ((service, operation), (count, failed, duration_ms)) in operations {
+        lines.push(format!(
+            "  {}:{}  {} call(s), {} failed, {} ms",
+            service, operation, count, failed, duration_ms
+        ));
+    }
+    lines.join("\n")
+}
+
+#[derive(Debug, Clone)]
+struct CallStarted(Instant);
+
+impl Storable for CallStarted {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Records every SDK operation into the session audit log.
+#[derive(Debug)]
+pub(crate) struct AuditInterceptor;
+
+impl Intercept for AuditInterceptor {
+    fn name(&self) -> &'static str {
+        "AuditInterceptor"
+    }
+
+    fn read_before_execution(
+        &self,
+        _context: &BeforeSerializationInterceptorContextRef<'_>,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        cfg.interceptor_state()
+            .store_put(CallStarted(Instant::now()));
+        Ok(())
+    }
+
+    fn read_after_execution(
+        &self,
+        context: &FinalizerInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let Some(metadata) = cfg.load::<Metadata>() else {
+            return Ok(());
+        };
+        let duration_ms = cfg
+            .load::<CallStarted>()
+            .map(|started| started.0.elapsed().as_millis() as u64)
+            .unwrap_or(0);
+        let failed = !matches!(context.output_or_error(), Some(Ok(_)));
+        let status = context
+            .response()
+            .map(|response| response.status().as_u16());
+        let allowed = cfg.load::<GuardRejected>().is_none();
+        if failed {
+            let error_text = match context.output_or_error() {
+                Some(Err(error)) => format!("{:?}", error),
+                _ => String::new(),
+            };
+            note_failure(if allowed && is_not_found_error(&error_text, status) {
+                CallFailure::NotFound
+            } else {
+                CallFailure::Other
+            });
+        }
+
+        record(AuditEntry {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            service: metadata.service().to_string(),
+            operation: metadata.name().to_string(),
+            region: cfg
+                .load::<Region>()
+                .map(|region| region.to_string())
+                .unwrap_or_default(),
+            duration_ms,
+            // 읽기 전용 가드가 거부한 호출은 전송되지 않음
+            result: if allowed {
+                result_label(failed, status)
+            } else {
+                "rejected".to_string()
+            },
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditEntry, format_summary, is_not_found_error, result_label};
+
+    fn entry(service: &str, operation: &str, duration_ms: u64, result: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: "2026-01-01T00:00:00+09:00".to_string(),
+            service: service.to_string(),
+            operation: operation.to_string(),
+            region: "ap-northeast-2".to_string(),
+            duration_ms,
+            result: result.to_string(),
+        }
+    }
+
+    #[test]
+    fn result_label_includes_http_status_on_failure() {
+        assert_eq!(result_label(false, Some(200)), "ok");
+        assert_eq!(result_label(true, Some(403)), "error (403)");
+        assert_eq!(result_label(true, None), "error");
+    }
+
+    #[test]
+    fn not_found_errors_are_told_apart_from_other_failures() {
+        assert!(is_not_found_error(
+            "DescribeInstancesError { code: Some(\"InvalidInstanceID.NotFound\") }",
+            Some(400)
+        ));
+        assert!(is_not_found_error("NoSuchEntityException", Some(404)));
+        assert!(is_not_found_error("", Some(404)));
+        assert!(is_not_found_error("QueueDoesNotExist", Some(400)));
+        assert!(!is_not_found_error("AccessDeniedException", Some(403)));
+        assert!(!is_not_found_error(
+            "ThrottlingException: Rate exceeded",
+            Some(400)
+        ));
+        assert!(!is_not_found_error(
+            "DispatchFailure: connection refused",
+            None
+        ));
+    }
+
+    #[test]
+    fn format_summary_groups_calls_by_operation() {
+        let entries = vec![
+            entry("EC2", "DescribeInstances", 120, "ok"),
+            entry("Lambda", "ListFunctions", 80, "error (403)"),
+            entry("EC2", "DescribeInstances", 100, "ok"),
+        ];
+
+        let summary = format_summary("/tmp/session.jsonl", &entries);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines[0], "AWS API calls: 3 (1 failed, 300 ms)");
+        assert_eq!(lines[1], "Audit log: /tmp/session.jsonl");
+        assert_eq!(
+            lines[2],
+            "  EC2:DescribeInstances  2 call(s), 0 failed, 220 ms"
+        );
+        assert_eq!(
+            lines[3],
+            "  Lambda:ListFunctions  1 call(s), 1 failed, 80 ms"
+        );
+    }
+
+    #[test]
+    fn audit_entry_serializes_as_json_line() {
+        let line = serde_json::to_string(&entry("STS", "GetCallerIdentity", 42, "ok"))
+            .expect("serialize audit entry");
+        assert!(line.contains("\"service\":\"STS\""));
+        assert!(line.contains("\"operation\":\"GetCallerIdentity\""));
+        assert!(line.contains("\"region\":\"ap-northeast-2\""));
+        assert!(line.contains("\"duration_ms\":42"));
+    }
+}
@@ -0,0 +1,337 @@
+use crate::aws_cli::arn::Arn;
+pub use crate::aws_cli::backup_sdk::{get_backup_plan_detail, list_backup_plans};
+use crate::cron;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct BackupPlanDetail {
+    pub id: String,
+    pub name: String,
+    pub arn: String,
+    pub version_id: String,
+    pub created: String,
+    // 한 번도 실행되지 않았으면 빈 문자열
+    pub last_execution: String,
+    // "EC2: WindowsVSS=enabled"
+    pub advanced_settings: Vec<String>,
+    pub rules: Vec<BackupRuleDetail>,
+    pub selections: Vec<BackupSelectionDetail>,
+    // 계획의 백업 볼트에 복구 시점이 있는 리소스
+    pub protected_resources: Vec<ProtectedResource>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupRuleDetail {
+    pub name: String,
+    pub vault: String,
+    // cron(...) / rate(...), 연속 백업만 하면 빈 문자열
+    pub schedule_expression: String,
+    pub schedule_timezone: String,
+    // None이면 만료 없음
+    pub delete_after_days: Option<i64>,
+    pub cold_storage_after_days: Option<i64>,
+    pub continuous: bool,
+    pub copy_actions: Vec<BackupCopyAction>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupCopyAction {
+    pub destination_vault_arn: String,
+    pub delete_after_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupSelectionDetail {
+    pub name: String,
+    pub iam_role: String,
+    // 리소스 ARN 또는 와일드카드 ("arn:aws:ec2:*:*:volume/*", "*")
+    pub resources: Vec<String>,
+    pub not_resources: Vec<String>,
+    // "backup = daily", "aws:ResourceTag/env like prod*"
+    pub conditions: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProtectedResource {
+    pub arn: String,
+    // EC2, EBS, RDS, DynamoDB, EFS, S3 ...
+    pub resource_type: String,
+    pub name: String,
+    pub last_backup: String,
+}
+
+/// Retention for a markdown cell; recovery points without a delete rule never expire.
+pub fn retention_text(days: Option<i64>, i18n: &I18n) -> String {
+    match days {
+        Some(days) => i18n.md_days(i32::try_from(days).unwrap_or(i32::MAX)),
+        None => i18n.md_never_expires().to_string(),
+    }
+}
+
+// 복사 대상 볼트를 "이름 (리전)"으로 줄여 표시 (ARN이 아니면 원본)
+fn copy_destination(copy: &BackupCopyAction, i18n: &I18n) -> String {
+    let vault = match Arn::parse(&copy.destination_vault_arn) {
+        Some(arn) => format!("{} ({})", arn.resource_id(), arn.region),
+        None => copy.destination_vault_arn.clone(),
+    };
+    format!(
+        "{}, {}",
+        vault,
+        retention_text(copy.delete_after_days, i18n)
+    )
+}
+
+impl BackupRuleDetail {
+    fn schedule(&self, i18n: &I18n) -> String {
+        if self.schedule_expression.is_empty() {
+            return "-".to_string();
+        }
+        let schedule = cron::schedule_text(&self.schedule_expression, i18n);
+        if self.schedule_timezone.is_empty() {
+            schedule
+        } else {
+            format!("{} {}", schedule, self.schedule_timezone)
+        }
+    }
+}
+
+impl BackupPlanDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let or_dash = |values: &[String]| {
+            if values.is_empty() {
+                "-".to_string()
+            } else {
+                values.join(", ")
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.backup_plan(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.version_id.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_version(), self.version_id));
+        }
+        if !self.advanced_settings.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_advanced_backup_settings(),
+                self.advanced_settings.join(", ")
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_last_execution(),
+            if self.last_execution.is_empty() {
+                "-".to_string()
+            } else {
+                locale::date(&self.last_execution)
+            }
+        ));
+
+        // 규칙: 언제 백업하고 얼마나 보관하는지
+        if !self.rules.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_rules()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.asg_schedule(),
+                i18n.md_backup_vault(),
+                i18n.md_retention(),
+                i18n.md_cold_storage_after(),
+                i18n.md_continuous_backup(),
+                i18n.md_copy_to()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|:---|".to_string());
+            for rule in &self.rules {
+                let copies: Vec<String> = rule
+                    .copy_actions
+                    .iter()
+                    .map(|copy| copy_destination(copy, &i18n))
+                    .collect();
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |",
+                    rule.name,
+                    rule.schedule(&i18n),
+                    rule.vault,
+                    retention_text(rule.delete_after_days, &i18n),
+                    rule.cold_storage_after_days
+                        .map(|days| i18n.md_days(i32::try_from(days).unwrap_or(i32::MAX)))
+                        .unwrap_or_else(|| "-".to_string()),
+                    if rule.continuous {
+                        i18n.md_enabled()
+                    } else {
+                        i18n.md_disabled()
+                    },
+                    or_dash(&copies)
+                ));
+            }
+        }
+
+        // 리소스 할당: 어떤 리소스가 이 계획으로 백업되는지
+        if !self.selections.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_resource_assignments()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_iam_role(),
+                i18n.md_resources(),
+                i18n.md_excluded_resources(),
+                i18n.md_tag_conditions()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for selection in &self.selections {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    selection.name,
+                    selection.iam_role,
+                    or_dash(&selection.resources),
+                    or_dash(&selection.not_resources),
+                    or_dash(&selection.conditions)
+                ));
+            }
+        }
+
+        if !self.protected_resources.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_protected_resources()));
+            lines.push(format!(
+                "| {} | {} | {} | ARN |",
+                i18n.md_type(),
+                i18n.md_name(),
+                i18n.md_last_backup()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for resource in &self.protected_resources {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    resource.resource_type,
+                    if resource.name.is_empty() {
+                        "-"
+                    } else {
+                        &resource.name
+                    },
+                    locale::date(&resource.last_backup),
+                    resource.arn
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BackupCopyAction, BackupPlanDetail, BackupRuleDetail, BackupSelectionDetail,
+        ProtectedResource, retention_text,
+    };
+    use crate::i18n::{I18n, Language};
+
+    fn sample_detail() -> BackupPlanDetail {
+        BackupPlanDetail {
+            id: "8a2f1c4e-1111-2222-3333-444455556666".to_string(),
+            name: "daily-35d".to_string(),
+            arn: "arn:aws:backup:ap-northeast-2:123456789012:backup-plan:8a2f1c4e-1111-2222-3333-444455556666".to_string(),
+            version_id: "ZmI3N2Q0".to_string(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_execution: String::new(),
+            advanced_settings: vec!["EC2: WindowsVSS=enabled".to_string()],
+            rules: vec![
+                BackupRuleDetail {
+                    name: "daily".to_string(),
+                    vault: "Default".to_string(),
+                    schedule_expression: "cron(0 17 ? * * *)".to_string(),
+                    schedule_timezone: "Asia/Seoul".to_string(),
+                    delete_after_days: Some(35),
+                    cold_storage_after_days: None,
+                    continuous: true,
+                    copy_actions: vec![BackupCopyAction {
+                        destination_vault_arn:
+                            "arn:aws:backup:us-west-2:123456789012:backup-vault:dr".to_string(),
+                        delete_after_days: Some(90),
+                    }],
+                },
+                BackupRuleDetail {
+                    name: "monthly".to_string(),
+                    vault: "archive".to_string(),
+                    schedule_expression: "cron(0 17 1 * ? *)".to_string(),
+                    schedule_timezone: String::new(),
+                    delete_after_days: None,
+                    cold_storage_after_days: Some(30),
+                    continuous: false,
+                    copy_actions: vec![],
+                },
+            ],
+            selections: vec![BackupSelectionDetail {
+                name: "tagged".to_string(),
+                iam_role: "arn:aws:iam::123456789012:role/service-role/AWSBackupDefaultServiceRole"
+                    .to_string(),
+                resources: vec!["*".to_string()],
+                not_resources: vec![],
+                conditions: vec!["backup = daily".to_string()],
+            }],
+            protected_resources: vec![ProtectedResource {
+                arn: "arn:aws:rds:ap-northeast-2:123456789012:db:orders".to_string(),
+                resource_type: "RDS".to_string(),
+                name: "orders".to_string(),
+                last_backup: "2026-10-14T17:05:00Z".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "platform".to_string())],
+        }
+    }
+
+    #[test]
+    fn retention_text_shows_days_or_never_expires() {
+        let i18n = I18n::new(Language::English);
+        assert_eq!(retention_text(Some(35), &i18n), "35d");
+        assert_eq!(retention_text(None, &i18n), "Never expire");
+    }
+
+    #[test]
+    fn backup_plan_markdown_lists_rules_assignments_and_protected_resources() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## AWS Backup Plan (daily-35d)"));
+        assert!(markdown.contains("| Advanced Backup Settings | EC2: WindowsVSS=enabled |"));
+        assert!(markdown.contains("| Last Run | - |"));
+        assert!(markdown.contains(
+            "| daily | `cron(0 17 ? * * *)` Asia/Seoul | Default | 35d | - | Enabled | dr (us-west-2), 90d |"
+        ));
+        assert!(markdown.contains(
+            "| monthly | `cron(0 17 1 * ? *)` | archive | Never expire | 30d | Disabled | - |"
+        ));
+        assert!(markdown.contains("### Resource Assignments"));
+        assert!(markdown.contains("| tagged | arn:aws:iam::123456789012:role/service-role/AWSBackupDefaultServiceRole | * | - | backup = daily |"));
+        assert!(markdown.contains(
+            "| RDS | orders | 2026-10-14T17:05:00Z | arn:aws:rds:ap-northeast-2:123456789012:db:orders |"
+        ));
+        assert!(markdown.contains("| Team | platform |"));
+    }
+}
@@ -0,0 +1,423 @@
+use crate::aws_cli::backup::{
+    BackupCopyAction, BackupPlanDetail, BackupRuleDetail, BackupSelectionDetail, ProtectedResource,
+};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_backup::Client;
+use aws_sdk_backup::operation::get_backup_plan::GetBackupPlanOutput;
+use aws_sdk_backup::operation::list_tags::ListTagsOutput;
+use aws_sdk_backup::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_backup::types::{
+    AdvancedBackupSetting, BackupPlansListMember, BackupRule, BackupSelection,
+};
+
+/// List AWS Backup plans using AWS SDK
+pub fn list_backup_plans() -> Vec<AwsResource> {
+    get_runtime().block_on(list_backup_plans_async())
+}
+
+async fn list_backup_plans_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_backup, &config);
+
+    let plans: Vec<BackupPlansListMember> = match client
+        .list_backup_plans()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(plans) => plans,
+        Err(e) => {
+            tracing::error!("Error listing backup plans: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = plans.iter().map(map_plan_resource).collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get backup plan detail (rules, resource assignments, protected resources) using AWS SDK
+pub fn get_backup_plan_detail(plan_id: &str) -> Option<BackupPlanDetail> {
+    get_runtime().block_on(get_backup_plan_detail_async(plan_id))
+}
+
+async fn get_backup_plan_detail_async(plan_id: &str) -> Option<BackupPlanDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_backup, &config);
+
+    let output = match client
+        .get_backup_plan()
+        .backup_plan_id(plan_id)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting backup plan {}: {:?}", plan_id, e);
+            return None;
+        }
+    };
+    let mut detail = map_plan_detail(&output);
+    detail.selections = list_selections(&client, plan_id).await;
+
+    // 같은 볼트를 여러 규칙이 쓰면 한 번만 조회
+    let mut vaults: Vec<&str> = detail
+        .rules
+        .iter()
+        .map(|rule| rule.vault.as_str())
+        .collect();
+    vaults.sort();
+    vaults.dedup();
+    let mut protected: Vec<ProtectedResource> = Vec::new();
+    for vault in vaults {
+        for resource in list_protected_resources(&client, vault).await {
+            if !protected
+                .iter()
+                .any(|existing| existing.arn == resource.arn)
+            {
+                protected.push(resource);
+            }
+        }
+    }
+    protected.sort_by(|a, b| {
+        (&a.resource_type, &a.name, &a.arn).cmp(&(&b.resource_type, &b.name, &b.arn))
+    });
+    detail.protected_resources = protected;
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let tags: Result<Vec<ListTagsOutput>, _> = client
+        .list_tags()
+        .resource_arn(&detail.arn)
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await;
+    match tags {
+        Ok(pages) => {
+            let mut tags: Vec<(String, String)> = pages
+                .iter()
+                .filter_map(|page| page.tags())
+                .flatten()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error listing tags for {}: {:?}", detail.arn, e),
+    }
+
+    Some(detail)
+}
+
+// 할당 조회 실패는 해당 할당만 빼고 표시
+async fn list_selections(client: &Client, plan_id: &str) -> Vec<BackupSelectionDetail> {
+    let members = match client
+        .list_backup_selections()
+        .backup_plan_id(plan_id)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(members) => members,
+        Err(e) => {
+            tracing::warn!("Error listing backup selections for {}: {:?}", plan_id, e);
+            return Vec::new();
+        }
+    };
+
+    let mut selections = Vec::new();
+    for member in members {
+        let Some(selection_id) = member.selection_id() else {
+            continue;
+        };
+        match client
+            .get_backup_selection()
+            .backup_plan_id(plan_id)
+            .selection_id(selection_id)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                if let Some(selection) = output.backup_selection() {
+                    selections.push(map_selection(selection));
+                }
+            }
+            Err(e) => tracing::warn!("Error getting backup selection {}: {:?}", selection_id, e),
+        }
+    }
+    selections.sort_by(|a, b| a.name.cmp(&b.name));
+    selections
+}
+
+async fn list_protected_resources(client: &Client, vault: &str) -> Vec<ProtectedResource> {
+    match client
+        .list_protected_resources_by_backup_vault()
+        .backup_vault_name(vault)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(resources) => resources
+            .iter()
+            .map(|resource| ProtectedResource {
+                arn: resource.resource_arn().unwrap_or_default().to_string(),
+                resource_type: resource.resource_type().unwrap_or_default().to_string(),
+                name: resource.resource_name().unwrap_or_default().to_string(),
+                last_backup: format_timestamp(resource.last_backup_time()),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Error listing protected resources in {}: {:?}", vault, e);
+            Vec::new()
+        }
+    }
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 마지막 실행 시각(cidr)을 표시
+fn map_plan_resource(plan: &BackupPlansListMember) -> AwsResource {
+    AwsResource {
+        name: plan.backup_plan_name().unwrap_or_default().to_string(),
+        id: plan.backup_plan_id().unwrap_or_default().to_string(),
+        state: String::new(),
+        az: String::new(),
+        cidr: format_timestamp(plan.last_execution_date()),
+        owner_id: String::new(),
+    }
+}
+
+fn advanced_setting_text(setting: &AdvancedBackupSetting) -> String {
+    let mut options: Vec<String> = setting
+        .backup_options()
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    options.sort();
+    format!(
+        "{}: {}",
+        setting.resource_type().unwrap_or_default(),
+        options.join(", ")
+    )
+}
+
+fn map_rule(rule: &BackupRule) -> BackupRuleDetail {
+    let lifecycle = rule.lifecycle();
+    BackupRuleDetail {
+        name: rule.rule_name().to_string(),
+        vault: rule.target_backup_vault_name().to_string(),
+        schedule_expression: rule.schedule_expression().unwrap_or_default().to_string(),
+        schedule_timezone: rule
+            .schedule_expression_timezone()
+            .unwrap_or_default()
+            .to_string(),
+        delete_after_days: lifecycle.and_then(|lifecycle| lifecycle.delete_after_days()),
+        cold_storage_after_days: lifecycle
+            .and_then(|lifecycle| lifecycle.move_to_cold_storage_after_days()),
+        continuous: rule.enable_continuous_backup().unwrap_or(false),
+        copy_actions: rule
+            .copy_actions()
+            .iter()
+            .map(|copy| BackupCopyAction {
+                destination_vault_arn: copy.destination_backup_vault_arn().to_string(),
+                delete_after_days: copy
+                    .lifecycle()
+                    .and_then(|lifecycle| lifecycle.delete_after_days()),
+            })
+            .collect(),
+    }
+}
+
+fn map_plan_detail(output: &GetBackupPlanOutput) -> BackupPlanDetail {
+    let plan = output.backup_plan();
+    BackupPlanDetail {
+        id: output.backup_plan_id().unwrap_or_default().to_string(),
+        name: plan
+            .map(|plan| plan.backup_plan_name())
+            .unwrap_or_default()
+            .to_string(),
+        arn: output.backup_plan_arn().unwrap_or_default().to_string(),
+        version_id: output.version_id().unwrap_or_default().to_string(),
+        created: format_timestamp(output.creation_date()),
+        last_execution: format_timestamp(output.last_execution_date()),
+        advanced_settings: output
+            .advanced_backup_settings()
+            .iter()
+            .map(advanced_setting_text)
+            .collect(),
+        rules: plan
+            .map(|plan| plan.rules().iter().map(map_rule).collect())
+            .unwrap_or_default(),
+        selections: Vec::new(),
+        protected_resources: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+// 태그 조건: 기본 태그 목록(ListOfTags)과 Conditions 블록을 "키 연산자 값"으로 나열
+fn selection_conditions(selection: &BackupSelection) -> Vec<String> {
+    let mut conditions: Vec<String> = selection
+        .list_of_tags()
+        .iter()
+        .map(|condition| {
+            format!(
+                "{} = {}",
+                condition.condition_key(),
+                condition.condition_value()
+            )
+        })
+        .collect();
+    if let Some(blocks) = selection.conditions() {
+        let groups = [
+            ("=", blocks.string_equals()),
+            ("!=", blocks.string_not_equals()),
+            ("like", blocks.string_like()),
+            ("not like", blocks.string_not_like()),
+        ];
+        for (operator, parameters) in groups {
+            conditions.extend(parameters.iter().map(|parameter| {
+                format!(
+                    "{} {} {}",
+                    parameter.condition_key().unwrap_or_default(),
+                    operator,
+                    parameter.condition_value().unwrap_or_default()
+                )
+            }));
+        }
+    }
+    conditions
+}
+
+fn map_selection(selection: &BackupSelection) -> BackupSelectionDetail {
+    BackupSelectionDetail {
+        name: selection.selection_name().to_string(),
+        iam_role: selection.iam_role_arn().to_string(),
+        resources: selection.resources().to_vec(),
+        not_resources: selection.not_resources().to_vec(),
+        conditions: selection_conditions(selection),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_plan_detail, map_plan_resource, map_selection};
+    use aws_sdk_backup::operation::get_backup_plan::GetBackupPlanOutput;
+    use aws_sdk_backup::primitives::DateTime;
+    use aws_sdk_backup::types::{
+        AdvancedBackupSetting, BackupPlan, BackupPlansListMember, BackupRule, BackupSelection,
+        Condition, ConditionParameter, ConditionType, Conditions, CopyAction, Lifecycle,
+    };
+
+    #[test]
+    fn map_plan_resource_shows_last_execution() {
+        let resource = map_plan_resource(
+            &BackupPlansListMember::builder()
+                .backup_plan_name("daily-35d")
+                .backup_plan_id("8a2f1c4e")
+                .last_execution_date(DateTime::from_secs(1_760_000_000))
+                .build(),
+        );
+        assert_eq!(resource.name, "daily-35d");
+        assert_eq!(resource.id, "8a2f1c4e");
+        assert_eq!(resource.cidr, "2025-10-09T08:53:20Z");
+    }
+
+    #[test]
+    fn map_plan_detail_reads_rules_lifecycle_and_copies() {
+        let rule = BackupRule::builder()
+            .rule_name("daily")
+            .target_backup_vault_name("Default")
+            .schedule_expression("cron(0 17 ? * * *)")
+            .lifecycle(
+                Lifecycle::builder()
+                    .delete_after_days(35)
+                    .move_to_cold_storage_after_days(7)
+                    .build(),
+            )
+            .copy_actions(
+                CopyAction::builder()
+                    .destination_backup_vault_arn(
+                        "arn:aws:backup:us-west-2:123456789012:backup-vault:dr",
+                    )
+                    .lifecycle(Lifecycle::builder().delete_after_days(90).build())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let output = GetBackupPlanOutput::builder()
+            .backup_plan_id("8a2f1c4e")
+            .backup_plan_arn("arn:aws:backup:ap-northeast-2:123456789012:backup-plan:8a2f1c4e")
+            .backup_plan(
+                BackupPlan::builder()
+                    .backup_plan_name("daily-35d")
+                    .rules(rule)
+                    .build()
+                    .unwrap(),
+            )
+            .advanced_backup_settings(
+                AdvancedBackupSetting::builder()
+                    .resource_type("EC2")
+                    .backup_options("WindowsVSS", "enabled")
+                    .build(),
+            )
+            .build();
+
+        let detail = map_plan_detail(&output);
+        assert_eq!(detail.name, "daily-35d");
+        assert_eq!(detail.advanced_settings, vec!["EC2: WindowsVSS=enabled"]);
+        let rule = &detail.rules[0];
+        assert_eq!(rule.vault, "Default");
+        assert_eq!(rule.delete_after_days, Some(35));
+        assert_eq!(rule.cold_storage_after_days, Some(7));
+        assert!(!rule.continuous);
+        assert_eq!(rule.copy_actions[0].delete_after_days, Some(90));
+    }
+
+    #[test]
+    fn map_selection_lists_tag_conditions() {
+        let selection = BackupSelection::builder()
+            .selection_name("tagged")
+            .iam_role_arn("arn:aws:iam::123456789012:role/backup")
+            .resources("*")
+            .list_of_tags(
+                Condition::builder()
+                    .condition_type(ConditionType::Stringequals)
+                    .condition_key("backup")
+                    .condition_value("daily")
+                    .build()
+                    .unwrap(),
+            )
+            .conditions(
+                Conditions::builder()
+                    .string_not_equals(
+                        ConditionParameter::builder()
+                            .condition_key("aws:ResourceTag/env")
+                            .condition_value("dev")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()
+            .unwrap();
+
+        let detail = map_selection(&selection);
+        assert_eq!(detail.resources, vec!["*"]);
+        assert_eq!(
+            detail.conditions,
+            vec!["backup = daily", "aws:ResourceTag/env != dev"]
+        );
+    }
+}
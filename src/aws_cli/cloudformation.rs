@@ -0,0 +1,253 @@
+pub use crate::aws_cli::cloudformation_sdk::{
+    get_cloudformation_stack_detail, list_cloudformation_stacks, list_stack_resources,
+};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StackResource {
+    pub logical_id: String,
+    pub physical_id: String,
+    pub resource_type: String,
+    pub status: String,
+}
+
+/// CloudFormation stack with its parameters, outputs and resources.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CloudFormationStackDetail {
+    pub name: String,
+    // 스택 ARN
+    pub stack_id: String,
+    // CREATE_COMPLETE, UPDATE_ROLLBACK_COMPLETE ...
+    pub status: String,
+    pub status_reason: String,
+    pub description: String,
+    pub created: String,
+    pub last_updated: String,
+    pub termination_protection: bool,
+    // IN_SYNC, DRIFTED, NOT_CHECKED ...
+    pub drift_status: String,
+    // 중첩 스택이면 상위 스택 ARN
+    pub parent_id: String,
+    // (키, 값) - NoEcho 파라미터는 AWS가 "****"로 반환
+    pub parameters: Vec<(String, String)>,
+    pub outputs: Vec<StackOutput>,
+    pub resources: Vec<StackResource>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StackOutput {
+    pub key: String,
+    pub value: String,
+    pub export_name: String,
+    pub description: String,
+}
+
+impl CloudFormationStackDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.md_cloudformation_stack(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| {} | {} |", i18n.md_stack_id(), self.stack_id),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+        ];
+        if !self.status_reason.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_state_reason(),
+                self.status_reason
+            ));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description.replace('\n', "<br>")
+            ));
+        }
+        if !self.parent_id.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_parent_stack(),
+                self.parent_id
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_created_at(),
+            or_dash(&self.created)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_last_updated(),
+            or_dash(&self.last_updated)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_termination_protection(),
+            if self.termination_protection {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_drift_status(),
+            or_dash(&self.drift_status)
+        ));
+
+        // Parameters
+        if !self.parameters.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_parameters()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.parameters {
+                lines.push(format!("| {} | {} |", key, or_dash(value)));
+            }
+        }
+
+        // Outputs
+        if !self.outputs.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_outputs()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.asg_key(),
+                i18n.value(),
+                i18n.md_export_name(),
+                i18n.md_description()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for output in &self.outputs {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    output.key,
+                    or_dash(&output.value),
+                    or_dash(&output.export_name),
+                    or_dash(&output.description)
+                ));
+            }
+        }
+
+        // Resources
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_resources()));
+        if self.resources.is_empty() {
+            lines.push("-".to_string());
+        } else {
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_logical_id(),
+                i18n.md_physical_id(),
+                i18n.md_type(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for resource in &self.resources {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    resource.logical_id,
+                    or_dash(&resource.physical_id),
+                    resource.resource_type,
+                    or_dash(&resource.status)
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CloudFormationStackDetail, StackOutput, StackResource};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> CloudFormationStackDetail {
+        CloudFormationStackDetail {
+            name: "checkout".to_string(),
+            stack_id: "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout/1a2b3c4d"
+                .to_string(),
+            status: "UPDATE_COMPLETE".to_string(),
+            status_reason: String::new(),
+            description: "Checkout service".to_string(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_updated: "2026-03-02T10:00:00Z".to_string(),
+            termination_protection: true,
+            drift_status: "NOT_CHECKED".to_string(),
+            parent_id: String::new(),
+            parameters: vec![
+                ("Env".to_string(), "prod".to_string()),
+                ("DbPassword".to_string(), "****".to_string()),
+            ],
+            outputs: vec![StackOutput {
+                key: "ApiUrl".to_string(),
+                value: "https://api.example.com".to_string(),
+                export_name: "checkout-api-url".to_string(),
+                description: String::new(),
+            }],
+            resources: vec![StackResource {
+                logical_id: "Queue".to_string(),
+                physical_id: "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders"
+                    .to_string(),
+                resource_type: "AWS::SQS::Queue".to_string(),
+                status: "CREATE_COMPLETE".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "payments".to_string())],
+        }
+    }
+
+    #[test]
+    fn stack_markdown_lists_parameters_outputs_and_resources() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## CloudFormation Stack (checkout)"));
+        assert!(markdown.contains("| Termination Protection | Enabled |"));
+        assert!(markdown.contains("| DbPassword | **** |"));
+        assert!(markdown.contains("| ApiUrl | https://api.example.com | checkout-api-url | - |"));
+        assert!(markdown.contains(
+            "| Queue | https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders | AWS::SQS::Queue | CREATE_COMPLETE |"
+        ));
+        assert!(markdown.contains("| Team | payments |"));
+        assert!(!markdown.contains("Parent Stack"));
+    }
+
+    #[test]
+    fn stack_markdown_shows_failure_reason_and_empty_resources() {
+        let mut detail = sample_detail();
+        detail.status = "ROLLBACK_COMPLETE".to_string();
+        detail.status_reason = "The following resource(s) failed to create: [Queue].".to_string();
+        detail.parameters.clear();
+        detail.outputs.clear();
+        detail.resources.clear();
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("The following resource(s) failed to create: [Queue]."));
+        assert!(!markdown.contains("### 파라미터"));
+        assert!(markdown.contains("### 리소스\n\n-"));
+    }
+}
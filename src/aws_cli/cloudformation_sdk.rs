@@ -0,0 +1,297 @@
+use crate::aws_cli::cloudformation::{CloudFormationStackDetail, StackOutput, StackResource};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_cloudformation::error::ProvideErrorMetadata;
+use aws_sdk_cloudformation::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_cloudformation::types::Stack;
+
+/// List CloudFormation stacks (deleted stacks excluded) using AWS SDK
+pub fn list_cloudformation_stacks() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cloudformation_stacks_async())
+}
+
+async fn list_cloudformation_stacks_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudformation, &config);
+
+    // DescribeStacks는 삭제된 스택을 반환하지 않음 (ListStacks는 90일간 삭제된 스택도 포함)
+    let stacks: Vec<Stack> = match client
+        .describe_stacks()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(stacks) => stacks,
+        Err(e) => {
+            tracing::error!("Error listing CloudFormation stacks: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = stacks.iter().map(map_stack_resource_item).collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get CloudFormation stack detail (parameters, outputs and resources) using AWS SDK
+pub fn get_cloudformation_stack_detail(stack_name: &str) -> Option<CloudFormationStackDetail> {
+    get_runtime().block_on(get_cloudformation_stack_detail_async(stack_name))
+}
+
+async fn get_cloudformation_stack_detail_async(
+    stack_name: &str,
+) -> Option<CloudFormationStackDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudformation, &config);
+
+    let output = match client.describe_stacks().stack_name(stack_name).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing stack {}: {:?}", stack_name, e);
+            return None;
+        }
+    };
+    let mut detail = map_stack_detail(output.stacks().first()?);
+
+    // 리소스 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match list_stack_resources_async(stack_name).await {
+        Ok(resources) => detail.resources = resources,
+        Err(e) => tracing::warn!("Error listing resources of {}: {}", stack_name, e),
+    }
+
+    Some(detail)
+}
+
+/// List all resources of a CloudFormation stack using AWS SDK
+pub fn list_stack_resources(stack_name: &str) -> Result<Vec<StackResource>, String> {
+    get_runtime().block_on(list_stack_resources_async(stack_name))
+}
+
+async fn list_stack_resources_async(stack_name: &str) -> Result<Vec<StackResource>, String> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudformation, &config);
+
+    let result = client
+        .list_stack_resources()
+        .stack_name(stack_name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(summaries) => Ok(summaries.iter().map(map_stack_resource).collect()),
+        Err(e) => {
+            tracing::error!("Error listing stack resources: {:?}", e);
+            // 스택이 없으면 ValidationError 메시지가 그대로 전달됨
+            Err(e
+                .message()
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| e.to_string()))
+        }
+    }
+}
+
+fn map_stack_resource(
+    summary: &aws_sdk_cloudformation::types::StackResourceSummary,
+) -> StackResource {
+    StackResource {
+        logical_id: summary
+            .logical_resource_id()
+            .unwrap_or_default()
+            .to_string(),
+        physical_id: summary
+            .physical_resource_id()
+            .unwrap_or_default()
+            .to_string(),
+        resource_type: summary.resource_type().unwrap_or_default().to_string(),
+        status: summary
+            .resource_status()
+            .map(|status| status.as_str().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 상태와 마지막 변경일을 표시하고, 중첩 스택은 nested로 구분
+fn map_stack_resource_item(stack: &Stack) -> AwsResource {
+    let changed = format_timestamp(stack.last_updated_time().or(stack.creation_time()));
+    AwsResource {
+        name: stack.stack_name().unwrap_or_default().to_string(),
+        id: stack.stack_name().unwrap_or_default().to_string(),
+        state: stack
+            .stack_status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: if stack.parent_id().is_some() {
+            "nested".to_string()
+        } else {
+            String::new()
+        },
+        cidr: changed.split('T').next().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_stack_detail(stack: &Stack) -> CloudFormationStackDetail {
+    CloudFormationStackDetail {
+        name: stack.stack_name().unwrap_or_default().to_string(),
+        stack_id: stack.stack_id().unwrap_or_default().to_string(),
+        status: stack
+            .stack_status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        status_reason: stack.stack_status_reason().unwrap_or_default().to_string(),
+        description: stack.description().unwrap_or_default().to_string(),
+        created: format_timestamp(stack.creation_time()),
+        last_updated: format_timestamp(stack.last_updated_time()),
+        termination_protection: stack.enable_termination_protection().unwrap_or(false),
+        drift_status: stack
+            .drift_information()
+            .and_then(|drift| drift.stack_drift_status())
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        parent_id: stack.parent_id().unwrap_or_default().to_string(),
+        // SSM 파라미터 타입은 입력값(파라미터 이름)과 실제로 사용된 값을 함께 표시
+        parameters: stack
+            .parameters()
+            .iter()
+            .map(|parameter| {
+                let value = parameter.parameter_value().unwrap_or_default();
+                let value = match parameter.resolved_value() {
+                    Some(resolved) => format!("{} → {}", value, resolved),
+                    None => value.to_string(),
+                };
+                (
+                    parameter.parameter_key().unwrap_or_default().to_string(),
+                    value,
+                )
+            })
+            .collect(),
+        outputs: stack
+            .outputs()
+            .iter()
+            .map(|output| StackOutput {
+                key: output.output_key().unwrap_or_default().to_string(),
+                value: output.output_value().unwrap_or_default().to_string(),
+                export_name: output.export_name().unwrap_or_default().to_string(),
+                description: output.description().unwrap_or_default().to_string(),
+            })
+            .collect(),
+        resources: Vec::new(),
+        tags: stack
+            .tags()
+            .iter()
+            .map(|tag| {
+                (
+                    tag.key().unwrap_or_default().to_string(),
+                    tag.value().unwrap_or_default().to_string(),
+                )
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_stack_detail, map_stack_resource, map_stack_resource_item};
+    use aws_sdk_cloudformation::primitives::DateTime;
+    use aws_sdk_cloudformation::types::{Output, Parameter, Stack, StackStatus};
+
+    #[test]
+    fn map_stack_resource_copies_identity_and_status() {
+        let summary = aws_sdk_cloudformation::types::StackResourceSummary::builder()
+            .logical_resource_id("WebServer")
+            .physical_resource_id("i-0123")
+            .resource_type("AWS::EC2::Instance")
+            .resource_status(aws_sdk_cloudformation::types::ResourceStatus::CreateComplete)
+            .build();
+
+        let resource = map_stack_resource(&summary);
+        assert_eq!(resource.logical_id, "WebServer");
+        assert_eq!(resource.physical_id, "i-0123");
+        assert_eq!(resource.resource_type, "AWS::EC2::Instance");
+        assert_eq!(resource.status, "CREATE_COMPLETE");
+    }
+
+    #[test]
+    fn map_stack_resource_defaults_missing_fields_to_empty() {
+        let summary = aws_sdk_cloudformation::types::StackResourceSummary::builder().build();
+
+        let resource = map_stack_resource(&summary);
+        assert_eq!(resource.physical_id, "");
+        assert_eq!(resource.status, "");
+    }
+
+    #[test]
+    fn map_stack_resource_item_shows_status_and_last_change() {
+        let stack = Stack::builder()
+            .stack_name("checkout-queue")
+            .stack_id(
+                "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout-queue/1a2b",
+            )
+            .stack_status(StackStatus::UpdateComplete)
+            .creation_time(DateTime::from_secs(1_767_225_600))
+            .last_updated_time(DateTime::from_secs(1_772_445_600))
+            .parent_id("arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout/9f8e")
+            .build();
+
+        let resource = map_stack_resource_item(&stack);
+        assert_eq!(resource.id, "checkout-queue");
+        assert_eq!(resource.state, "UPDATE_COMPLETE");
+        assert_eq!(resource.az, "nested");
+        assert_eq!(resource.cidr, "2026-03-02");
+    }
+
+    #[test]
+    fn map_stack_detail_reads_parameters_and_outputs() {
+        let stack = Stack::builder()
+            .stack_name("checkout")
+            .stack_status(StackStatus::CreateComplete)
+            .enable_termination_protection(true)
+            .parameters(
+                Parameter::builder()
+                    .parameter_key("Env")
+                    .parameter_value("prod")
+                    .build(),
+            )
+            .parameters(
+                Parameter::builder()
+                    .parameter_key("AmiId")
+                    .parameter_value(
+                        "/aws/service/ami-amazon-linux-latest/al2023-ami-kernel-default-x86_64",
+                    )
+                    .resolved_value("ami-0abc")
+                    .build(),
+            )
+            .outputs(
+                Output::builder()
+                    .output_key("ApiUrl")
+                    .output_value("https://api.example.com")
+                    .export_name("checkout-api-url")
+                    .build(),
+            )
+            .build();
+
+        let detail = map_stack_detail(&stack);
+        assert_eq!(detail.status, "CREATE_COMPLETE");
+        assert!(detail.termination_protection);
+        assert_eq!(
+            detail.parameters[0],
+            ("Env".to_string(), "prod".to_string())
+        );
+        assert!(detail.parameters[1].1.ends_with(" → ami-0abc"));
+        assert_eq!(detail.outputs[0].export_name, "checkout-api-url");
+        assert!(detail.resources.is_empty());
+    }
+}
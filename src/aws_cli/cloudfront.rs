@@ -0,0 +1,309 @@
+pub use crate::aws_cli::cloudfront_sdk::{get_cloudfront_detail, list_cloudfront_distributions};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudFrontOrigin {
+    pub id: String,
+    pub domain_name: String,
+    pub path: String,
+    // S3, Custom, VPC
+    pub origin_type: String,
+    pub protocol_policy: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudFrontBehavior {
+    // 기본 동작은 None
+    pub path_pattern: Option<String>,
+    pub target_origin_id: String,
+    pub viewer_protocol_policy: String,
+    pub allowed_methods: Vec<String>,
+    pub cache_policy_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct CloudFrontDetail {
+    pub id: String,
+    pub arn: String,
+    pub domain_name: String,
+    pub status: String,
+    pub enabled: bool,
+    pub comment: String,
+    pub aliases: Vec<String>,
+    pub price_class: String,
+    pub http_version: String,
+    pub ipv6_enabled: bool,
+    pub default_root_object: String,
+    // ACM 인증서 ARN 또는 IAM 인증서 ID (None이면 CloudFront 기본 인증서)
+    pub certificate: Option<String>,
+    pub ssl_support_method: String,
+    pub minimum_protocol_version: String,
+    pub web_acl_id: Option<String>,
+    pub origins: Vec<CloudFrontOrigin>,
+    pub behaviors: Vec<CloudFrontBehavior>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl CloudFrontDetail {
+    /// First alias, or the CloudFront domain name when the distribution has none.
+    pub fn display_name(&self) -> &str {
+        self.aliases
+            .first()
+            .map(|alias| alias.as_str())
+            .unwrap_or(&self.domain_name)
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.cloudfront_distribution(), self.id),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_domain_name(), self.domain_name),
+            format!(
+                "| {} | {} ({}) |",
+                i18n.md_state(),
+                self.status,
+                enabled(self.enabled)
+            ),
+        ];
+        if !self.comment.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_description(), self.comment));
+        }
+        if !self.aliases.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_aliases(),
+                self.aliases.join(", ")
+            ));
+        }
+        if !self.price_class.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_price_class(),
+                self.price_class
+            ));
+        }
+        if !self.http_version.is_empty() {
+            lines.push(format!("| HTTP | {} |", self.http_version));
+        }
+        lines.push(format!("| IPv6 | {} |", enabled(self.ipv6_enabled)));
+        if !self.default_root_object.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_default_root_object(),
+                self.default_root_object
+            ));
+        }
+
+        let certificate = match self.certificate {
+            Some(ref certificate) => {
+                let mut details = Vec::new();
+                if !self.ssl_support_method.is_empty() {
+                    details.push(self.ssl_support_method.as_str());
+                }
+                if !self.minimum_protocol_version.is_empty() {
+                    details.push(self.minimum_protocol_version.as_str());
+                }
+                if details.is_empty() {
+                    certificate.clone()
+                } else {
+                    format!("{} ({})", certificate, details.join(", "))
+                }
+            }
+            None => i18n.md_cloudfront_default_certificate().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_certificate(), certificate));
+        lines.push(format!(
+            "| WAF | {} |",
+            self.web_acl_id.as_deref().unwrap_or("-")
+        ));
+
+        // Origins
+        if !self.origins.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_origins()));
+            lines.push(format!(
+                "| ID | {} | {} | {} | {} |",
+                i18n.md_domain_name(),
+                i18n.md_origin_path(),
+                i18n.md_type(),
+                i18n.md_protocol()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for origin in &self.origins {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    origin.id,
+                    origin.domain_name,
+                    if origin.path.is_empty() {
+                        "-"
+                    } else {
+                        origin.path.as_str()
+                    },
+                    origin.origin_type,
+                    if origin.protocol_policy.is_empty() {
+                        "-"
+                    } else {
+                        origin.protocol_policy.as_str()
+                    }
+                ));
+            }
+        }
+
+        // Behaviors
+        if !self.behaviors.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_behaviors()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_path_pattern(),
+                i18n.md_origin(),
+                i18n.md_viewer_protocol(),
+                i18n.md_allowed_methods(),
+                i18n.md_cache_policy()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for behavior in &self.behaviors {
+                let path_pattern = match behavior.path_pattern {
+                    Some(ref pattern) => pattern.clone(),
+                    None => format!("{} (*)", i18n.md_default()),
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    path_pattern,
+                    behavior.target_origin_id,
+                    behavior.viewer_protocol_policy,
+                    behavior.allowed_methods.join(", "),
+                    behavior.cache_policy_id.as_deref().unwrap_or("-")
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CloudFrontBehavior, CloudFrontDetail, CloudFrontOrigin};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> CloudFrontDetail {
+        CloudFrontDetail {
+            id: "E2EXAMPLE".to_string(),
+            arn: "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE".to_string(),
+            domain_name: "d111111abcdef8.cloudfront.net".to_string(),
+            status: "Deployed".to_string(),
+            enabled: true,
+            comment: "checkout web".to_string(),
+            aliases: vec!["www.example.com".to_string()],
+            price_class: "PriceClass_All".to_string(),
+            http_version: "http2".to_string(),
+            ipv6_enabled: true,
+            default_root_object: "index.html".to_string(),
+            certificate: Some("arn:aws:acm:us-east-1:123456789012:certificate/abcd".to_string()),
+            ssl_support_method: "sni-only".to_string(),
+            minimum_protocol_version: "TLSv1.2_2021".to_string(),
+            web_acl_id: Some(
+                "arn:aws:wafv2:us-east-1:123456789012:global/webacl/checkout/1234".to_string(),
+            ),
+            origins: vec![
+                CloudFrontOrigin {
+                    id: "assets".to_string(),
+                    domain_name: "assets.s3.ap-northeast-2.amazonaws.com".to_string(),
+                    path: String::new(),
+                    origin_type: "S3".to_string(),
+                    protocol_policy: String::new(),
+                },
+                CloudFrontOrigin {
+                    id: "api".to_string(),
+                    domain_name: "api.example.com".to_string(),
+                    path: "/v1".to_string(),
+                    origin_type: "Custom".to_string(),
+                    protocol_policy: "https-only".to_string(),
+                },
+            ],
+            behaviors: vec![
+                CloudFrontBehavior {
+                    path_pattern: None,
+                    target_origin_id: "assets".to_string(),
+                    viewer_protocol_policy: "redirect-to-https".to_string(),
+                    allowed_methods: vec!["GET".to_string(), "HEAD".to_string()],
+                    cache_policy_id: Some("658327ea".to_string()),
+                },
+                CloudFrontBehavior {
+                    path_pattern: Some("/api/*".to_string()),
+                    target_origin_id: "api".to_string(),
+                    viewer_protocol_policy: "https-only".to_string(),
+                    allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+                    cache_policy_id: None,
+                },
+            ],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_cloudfront_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## CloudFront Distribution (E2EXAMPLE)"));
+        assert!(markdown.contains("| State | Deployed (Enabled) |"));
+        assert!(markdown.contains("| Aliases | www.example.com |"));
+        assert!(markdown.contains(
+            "| Certificate | arn:aws:acm:us-east-1:123456789012:certificate/abcd (sni-only, TLSv1.2_2021) |"
+        ));
+        assert!(markdown.contains("| WAF | arn:aws:wafv2:"));
+        assert!(markdown.contains("| api | api.example.com | /v1 | Custom | https-only |"));
+        assert!(
+            markdown
+                .contains("| Default (*) | assets | redirect-to-https | GET, HEAD | 658327ea |")
+        );
+        assert!(markdown.contains("| /api/* | api | https-only | GET, POST | - |"));
+        assert!(markdown.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn display_name_prefers_alias_over_domain_name() {
+        let mut detail = sample_detail();
+        assert_eq!(detail.display_name(), "www.example.com");
+        detail.aliases.clear();
+        assert_eq!(detail.display_name(), "d111111abcdef8.cloudfront.net");
+    }
+
+    #[test]
+    fn scenario_cloudfront_default_certificate_without_waf() {
+        let mut detail = sample_detail();
+        detail.certificate = None;
+        detail.web_acl_id = None;
+        detail.aliases.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 인증서 | CloudFront 기본 인증서 |"));
+        assert!(markdown.contains("| WAF | - |"));
+        assert!(!markdown.contains("| 대체 도메인 |"));
+    }
+}
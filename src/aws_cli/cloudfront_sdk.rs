@@ -0,0 +1,381 @@
+use crate::aws_cli::cloudfront::{CloudFrontBehavior, CloudFrontDetail, CloudFrontOrigin};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_cloudfront::types::{
+    AllowedMethods, CacheBehavior, DefaultCacheBehavior, DistributionConfig, DistributionSummary,
+    Origin,
+};
+
+/// List all CloudFront distributions using AWS SDK
+pub fn list_cloudfront_distributions() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cloudfront_distributions_async())
+}
+
+async fn list_cloudfront_distributions_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudfront, &config);
+
+    let result = client
+        .list_distributions()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(distributions) => distributions
+            .iter()
+            .map(map_distribution_resource)
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing CloudFront distributions: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get CloudFront distribution detail (origins, behaviors, certificate, WAF and tags) using AWS SDK
+pub fn get_cloudfront_detail(id: &str) -> Option<CloudFrontDetail> {
+    get_runtime().block_on(get_cloudfront_detail_async(id))
+}
+
+async fn get_cloudfront_detail_async(id: &str) -> Option<CloudFrontDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudfront, &config);
+
+    let output = client.get_distribution().id(id).send().await.ok()?;
+    let distribution = output.distribution()?;
+    let mut detail = map_distribution_detail(
+        distribution.id(),
+        distribution.arn(),
+        distribution.domain_name(),
+        distribution.status(),
+        distribution.distribution_config()?,
+    );
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_tags_for_resource()
+        .resource(distribution.arn())
+        .send()
+        .await
+    {
+        Ok(tags) => {
+            let mut tags: Vec<(String, String)> = tags
+                .tags()
+                .map(|t| t.items())
+                .unwrap_or_default()
+                .iter()
+                .map(|t| {
+                    (
+                        t.key().to_string(),
+                        t.value().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error listing tags for {}: {:?}", id, e),
+    }
+
+    Some(detail)
+}
+
+fn map_distribution_resource(distribution: &DistributionSummary) -> AwsResource {
+    // 대체 도메인이 있으면 첫 번째 도메인을 이름으로 사용
+    let name = distribution
+        .aliases()
+        .and_then(|a| a.items().first())
+        .map(|alias| alias.to_string())
+        .unwrap_or_else(|| distribution.domain_name().to_string());
+    AwsResource {
+        name,
+        id: distribution.id().to_string(),
+        state: distribution.status().to_string(),
+        az: String::new(),
+        cidr: distribution.domain_name().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_origin(origin: &Origin) -> CloudFrontOrigin {
+    let (origin_type, protocol_policy) = if let Some(custom) = origin.custom_origin_config() {
+        (
+            "Custom",
+            custom.origin_protocol_policy().as_str().to_string(),
+        )
+    } else if origin.vpc_origin_config().is_some() {
+        ("VPC", String::new())
+    } else {
+        ("S3", String::new())
+    };
+    CloudFrontOrigin {
+        id: origin.id().to_string(),
+        domain_name: origin.domain_name().to_string(),
+        path: origin.origin_path().unwrap_or_default().to_string(),
+        origin_type: origin_type.to_string(),
+        protocol_policy,
+    }
+}
+
+fn methods(allowed: Option<&AllowedMethods>) -> Vec<String> {
+    allowed
+        .map(|m| m.items().iter().map(|m| m.as_str().to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn map_default_behavior(behavior: &DefaultCacheBehavior) -> CloudFrontBehavior {
+    CloudFrontBehavior {
+        path_pattern: None,
+        target_origin_id: behavior.target_origin_id().to_string(),
+        viewer_protocol_policy: behavior.viewer_protocol_policy().as_str().to_string(),
+        allowed_methods: methods(behavior.allowed_methods()),
+        cache_policy_id: behavior.cache_policy_id().map(|s| s.to_string()),
+    }
+}
+
+fn map_behavior(behavior: &CacheBehavior) -> CloudFrontBehavior {
+    CloudFrontBehavior {
+        path_pattern: Some(behavior.path_pattern().to_string()),
+        target_origin_id: behavior.target_origin_id().to_string(),
+        viewer_protocol_policy: behavior.viewer_protocol_policy().as_str().to_string(),
+        allowed_methods: methods(behavior.allowed_methods()),
+        cache_policy_id: behavior.cache_policy_id().map(|s| s.to_string()),
+    }
+}
+
+fn map_distribution_detail(
+    id: &str,
+    arn: &str,
+    domain_name: &str,
+    status: &str,
+    config: &DistributionConfig,
+) -> CloudFrontDetail {
+    let certificate = config.viewer_certificate();
+    // 기본 인증서를 쓰는 배포는 ACM/IAM 인증서가 없음
+    let custom_certificate = certificate.and_then(|c| {
+        c.acm_certificate_arn()
+            .or(c.iam_certificate_id())
+            .map(|s| s.to_string())
+    });
+
+    let mut behaviors: Vec<CloudFrontBehavior> = config
+        .default_cache_behavior()
+        .map(map_default_behavior)
+        .into_iter()
+        .collect();
+    behaviors.extend(
+        config
+            .cache_behaviors()
+            .map(|b| b.items())
+            .unwrap_or_default()
+            .iter()
+            .map(map_behavior),
+    );
+
+    CloudFrontDetail {
+        id: id.to_string(),
+        arn: arn.to_string(),
+        domain_name: domain_name.to_string(),
+        status: status.to_string(),
+        enabled: config.enabled(),
+        comment: config.comment().to_string(),
+        aliases: config
+            .aliases()
+            .map(|a| a.items().to_vec())
+            .unwrap_or_default(),
+        price_class: config
+            .price_class()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        http_version: config
+            .http_version()
+            .map(|h| h.as_str().to_string())
+            .unwrap_or_default(),
+        ipv6_enabled: config.is_ipv6_enabled().unwrap_or(false),
+        default_root_object: config.default_root_object().unwrap_or_default().to_string(),
+        ssl_support_method: if custom_certificate.is_some() {
+            certificate
+                .and_then(|c| c.ssl_support_method())
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        },
+        minimum_protocol_version: if custom_certificate.is_some() {
+            certificate
+                .and_then(|c| c.minimum_protocol_version())
+                .map(|v| v.as_str().to_string())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        },
+        certificate: custom_certificate,
+        web_acl_id: config
+            .web_acl_id()
+            .filter(|id| !id.is_empty())
+            .map(|id| id.to_string()),
+        origins: config
+            .origins()
+            .map(|o| o.items())
+            .unwrap_or_default()
+            .iter()
+            .map(map_origin)
+            .collect(),
+        behaviors,
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_distribution_detail, map_origin};
+    use aws_sdk_cloudfront::types::{
+        Aliases, AllowedMethods, CacheBehavior, CacheBehaviors, CustomOriginConfig,
+        DefaultCacheBehavior, DistributionConfig, Method, MinimumProtocolVersion, Origin,
+        OriginProtocolPolicy, Origins, S3OriginConfig, SslSupportMethod, ViewerCertificate,
+        ViewerProtocolPolicy,
+    };
+
+    fn s3_origin() -> Origin {
+        Origin::builder()
+            .id("assets")
+            .domain_name("assets.s3.ap-northeast-2.amazonaws.com")
+            .s3_origin_config(S3OriginConfig::builder().build())
+            .build()
+            .expect("origin")
+    }
+
+    fn custom_origin() -> Origin {
+        Origin::builder()
+            .id("api")
+            .domain_name("api.example.com")
+            .origin_path("/v1")
+            .custom_origin_config(
+                CustomOriginConfig::builder()
+                    .http_port(80)
+                    .https_port(443)
+                    .origin_protocol_policy(OriginProtocolPolicy::HttpsOnly)
+                    .build()
+                    .expect("custom origin config"),
+            )
+            .build()
+            .expect("origin")
+    }
+
+    fn config(viewer_certificate: ViewerCertificate, web_acl_id: &str) -> DistributionConfig {
+        let default_behavior = DefaultCacheBehavior::builder()
+            .target_origin_id("assets")
+            .viewer_protocol_policy(ViewerProtocolPolicy::RedirectToHttps)
+            .allowed_methods(
+                AllowedMethods::builder()
+                    .quantity(2)
+                    .items(Method::Get)
+                    .items(Method::Head)
+                    .build()
+                    .expect("allowed methods"),
+            )
+            .cache_policy_id("658327ea")
+            .build()
+            .expect("default behavior");
+        let api_behavior = CacheBehavior::builder()
+            .path_pattern("/api/*")
+            .target_origin_id("api")
+            .viewer_protocol_policy(ViewerProtocolPolicy::HttpsOnly)
+            .build()
+            .expect("cache behavior");
+
+        DistributionConfig::builder()
+            .caller_reference("checkout")
+            .comment("checkout web")
+            .enabled(true)
+            .aliases(
+                Aliases::builder()
+                    .quantity(1)
+                    .items("www.example.com")
+                    .build()
+                    .expect("aliases"),
+            )
+            .origins(
+                Origins::builder()
+                    .quantity(2)
+                    .items(s3_origin())
+                    .items(custom_origin())
+                    .build()
+                    .expect("origins"),
+            )
+            .default_cache_behavior(default_behavior)
+            .cache_behaviors(
+                CacheBehaviors::builder()
+                    .quantity(1)
+                    .items(api_behavior)
+                    .build()
+                    .expect("cache behaviors"),
+            )
+            .viewer_certificate(viewer_certificate)
+            .web_acl_id(web_acl_id)
+            .build()
+            .expect("distribution config")
+    }
+
+    #[test]
+    fn map_origin_distinguishes_s3_and_custom_origins() {
+        let s3 = map_origin(&s3_origin());
+        assert_eq!(s3.origin_type, "S3");
+        assert!(s3.protocol_policy.is_empty());
+
+        let custom = map_origin(&custom_origin());
+        assert_eq!(custom.origin_type, "Custom");
+        assert_eq!(custom.protocol_policy, "https-only");
+        assert_eq!(custom.path, "/v1");
+    }
+
+    #[test]
+    fn map_distribution_detail_reads_behaviors_certificate_and_waf() {
+        let certificate = ViewerCertificate::builder()
+            .acm_certificate_arn("arn:aws:acm:us-east-1:123456789012:certificate/abcd")
+            .ssl_support_method(SslSupportMethod::SniOnly)
+            .minimum_protocol_version(MinimumProtocolVersion::TlSv122021)
+            .build();
+        let detail = map_distribution_detail(
+            "E2EXAMPLE",
+            "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE",
+            "d111111abcdef8.cloudfront.net",
+            "Deployed",
+            &config(
+                certificate,
+                "arn:aws:wafv2:us-east-1:123456789012:global/webacl/checkout/1234",
+            ),
+        );
+
+        assert_eq!(detail.aliases, vec!["www.example.com".to_string()]);
+        assert_eq!(detail.origins.len(), 2);
+        assert_eq!(detail.behaviors.len(), 2);
+        assert!(detail.behaviors[0].path_pattern.is_none());
+        assert_eq!(detail.behaviors[0].allowed_methods, vec!["GET", "HEAD"]);
+        assert_eq!(detail.behaviors[1].path_pattern.as_deref(), Some("/api/*"));
+        assert_eq!(detail.ssl_support_method, "sni-only");
+        assert_eq!(detail.minimum_protocol_version, "TLSv1.2_2021");
+        assert!(detail.web_acl_id.is_some());
+    }
+
+    #[test]
+    fn map_distribution_detail_handles_default_certificate_without_waf() {
+        let certificate = ViewerCertificate::builder()
+            .cloud_front_default_certificate(true)
+            .minimum_protocol_version(MinimumProtocolVersion::TlSv1)
+            .build();
+        let detail = map_distribution_detail(
+            "E2EXAMPLE",
+            "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE",
+            "d111111abcdef8.cloudfront.net",
+            "InProgress",
+            &config(certificate, ""),
+        );
+
+        assert!(detail.certificate.is_none());
+        assert!(detail.minimum_protocol_version.is_empty());
+        assert!(detail.web_acl_id.is_none());
+    }
+}
@@ -0,0 +1,419 @@
+pub use crate::aws_cli::cloudtrail_sdk::{
+    get_cloudtrail_detail, get_last_modified, list_cloudtrail_trails,
+};
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// 상세 화면/문서에 표시할 최근 관리 이벤트 수
+pub const RECENT_EVENT_LIMIT: i32 = 20;
+
+static STAMP_LAST_MODIFIED: AtomicBool = AtomicBool::new(false);
+
+/// Append the last CloudTrail change to every resource document generated from now on.
+pub fn set_stamp_last_modified(enabled: bool) {
+    STAMP_LAST_MODIFIED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn stamp_last_modified_enabled() -> bool {
+    STAMP_LAST_MODIFIED.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudTrailEvent {
+    pub time: String,
+    pub name: String,
+    pub source: String,
+    pub username: String,
+    pub read_only: bool,
+    // "AWS::EC2::Instance i-0123" 형식
+    pub resources: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct CloudTrailDetail {
+    pub name: String,
+    pub arn: String,
+    pub home_region: String,
+    pub multi_region: bool,
+    pub organization_trail: bool,
+    pub include_global_events: bool,
+    pub logging: bool,
+    pub latest_delivery: String,
+    pub s3_bucket: String,
+    pub s3_key_prefix: String,
+    pub log_file_validation: bool,
+    pub kms_key_id: Option<String>,
+    pub cloudwatch_log_group: Option<String>,
+    pub sns_topic: Option<String>,
+    // 조회 리전의 최근 관리 이벤트 (최신순)
+    pub recent_events: Vec<CloudTrailEvent>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl CloudTrailDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.cloudtrail_trail(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_home_region(), self.home_region),
+            format!("| {} | {} |", i18n.md_logging(), enabled(self.logging)),
+        ];
+        if !self.latest_delivery.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_latest_delivery(),
+                self.latest_delivery
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_multi_region_trail(),
+            enabled(self.multi_region)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_organization_trail(),
+            enabled(self.organization_trail)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_global_service_events(),
+            enabled(self.include_global_events)
+        ));
+        let destination = if self.s3_key_prefix.is_empty() {
+            format!("s3://{}", self.s3_bucket)
+        } else {
+            format!("s3://{}/{}", self.s3_bucket, self.s3_key_prefix)
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_s3_destination(),
+            destination
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_log_file_validation(),
+            enabled(self.log_file_validation)
+        ));
+        let encryption = match self.kms_key_id {
+            Some(ref key) => format!("SSE-KMS ({})", key),
+            None => "SSE-S3".to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+        if let Some(ref log_group) = self.cloudwatch_log_group {
+            lines.push(format!("| {} | {} |", i18n.md_cloudwatch_logs(), log_group));
+        }
+        if let Some(ref topic) = self.sns_topic {
+            lines.push(format!("| {} | {} |", i18n.md_sns_notification(), topic));
+        }
+
+        // Recent Management Events
+        if !self.recent_events.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_recent_events()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_event_time(),
+                i18n.md_event_name(),
+                i18n.md_event_source(),
+                i18n.md_user(),
+                i18n.md_resources()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for event in &self.recent_events {
+                // 읽기 전용이 아닌(변경) 이벤트는 굵게 표시
+                let name = if event.read_only {
+                    event.name.clone()
+                } else {
+                    format!("**{}**", event.name)
+                };
+                let resources = if event.resources.is_empty() {
+                    "-".to_string()
+                } else {
+                    event.resources.join("<br>")
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    event.time, name, event.source, event.username, resources
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+/// How a change reached AWS, read from the event's user agent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ChangeChannel {
+    Console,
+    Cli,
+    Sdk,
+    Terraform,
+    CloudFormation,
+    // 다른 AWS 서비스가 대신 호출 (예: autoscaling.amazonaws.com)
+    Service(String),
+    Other(String),
+}
+
+/// Most recent write event CloudTrail recorded for a resource.
+#[derive(Debug, Clone, Serialize)]
+pub struct LastModified {
+    pub time: String,
+    // IAM 사용자 이름 또는 역할 세션 이름
+    pub user: String,
+    pub event: String,
+    pub source: String,
+    pub via: ChangeChannel,
+}
+
+/// Classify a CloudTrail `userAgent`. Console sessions are also flagged in the event itself.
+pub fn change_channel(user_agent: &str, from_console: bool) -> ChangeChannel {
+    let agent = user_agent.trim();
+    if from_console || agent.starts_with("console.") || agent == "signin.amazonaws.com" {
+        ChangeChannel::Console
+    } else if agent.contains("Terraform/") {
+        ChangeChannel::Terraform
+    } else if agent == "cloudformation.amazonaws.com" {
+        ChangeChannel::CloudFormation
+    } else if agent.starts_with("aws-cli/") {
+        ChangeChannel::Cli
+    } else if agent.ends_with(".amazonaws.com") && !agent.contains(' ') {
+        ChangeChannel::Service(agent.to_string())
+    } else if agent.starts_with("aws-sdk-")
+        || agent.starts_with("Boto3/")
+        || agent.starts_with("botocore/")
+    {
+        ChangeChannel::Sdk
+    } else {
+        // "AWS Internal" 같은 값은 그대로, 그 밖의 클라이언트는 제품 이름만
+        let product = agent.split(['/', ' ']).next().unwrap_or_default();
+        if agent.contains('/') && !product.is_empty() {
+            ChangeChannel::Other(product.to_string())
+        } else if agent.is_empty() {
+            ChangeChannel::Other("-".to_string())
+        } else {
+            ChangeChannel::Other(agent.to_string())
+        }
+    }
+}
+
+impl ChangeChannel {
+    fn label(&self, i18n: &I18n) -> String {
+        match self {
+            ChangeChannel::Console => i18n.md_aws_console().to_string(),
+            ChangeChannel::Cli => "AWS CLI".to_string(),
+            ChangeChannel::Sdk => "AWS SDK".to_string(),
+            ChangeChannel::Terraform => "Terraform".to_string(),
+            ChangeChannel::CloudFormation => "CloudFormation".to_string(),
+            ChangeChannel::Service(service) | ChangeChannel::Other(service) => service.clone(),
+        }
+    }
+}
+
+/// "Last Modified" section appended to a resource document.
+pub fn last_modified_markdown(last_modified: Option<&LastModified>, lang: Language) -> String {
+    let i18n = I18n::new(lang);
+    let mut lines = vec![format!(
+        "### {} (CloudTrail)
+",
+        i18n.md_last_modified()
+    )];
+    match last_modified {
+        Some(change) => {
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| {} | {} |", i18n.md_modified_by(), change.user));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_modified_at(),
+                locale::date(&change.time)
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_modified_via(),
+                change.via.label(&i18n)
+            ));
+            lines.push(format!(
+                "| {} | {} ({}) |",
+                i18n.md_event_name(),
+                change.event,
+                change.source
+            ));
+        }
+        None => lines.push(i18n.md_no_recent_changes().to_string()),
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ChangeChannel, CloudTrailDetail, CloudTrailEvent, LastModified, change_channel,
+        last_modified_markdown,
+    };
+    use crate::i18n::Language;
+
+    fn sample_detail() -> CloudTrailDetail {
+        CloudTrailDetail {
+            name: "org-audit".to_string(),
+            arn: "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/org-audit".to_string(),
+            home_region: "ap-northeast-2".to_string(),
+            multi_region: true,
+            organization_trail: false,
+            include_global_events: true,
+            logging: true,
+            latest_delivery: "2026-01-01T00:05:00Z".to_string(),
+            s3_bucket: "audit-logs".to_string(),
+            s3_key_prefix: "trail".to_string(),
+            log_file_validation: true,
+            kms_key_id: None,
+            cloudwatch_log_group: Some(
+                "arn:aws:logs:ap-northeast-2:123456789012:log-group:trail:*".to_string(),
+            ),
+            sns_topic: None,
+            recent_events: vec![
+                CloudTrailEvent {
+                    time: "2026-01-01T00:04:00Z".to_string(),
+                    name: "StopInstances".to_string(),
+                    source: "ec2.amazonaws.com".to_string(),
+                    username: "alice".to_string(),
+                    read_only: false,
+                    resources: vec!["AWS::EC2::Instance i-0123".to_string()],
+                },
+                CloudTrailEvent {
+                    time: "2026-01-01T00:03:00Z".to_string(),
+                    name: "DescribeInstances".to_string(),
+                    source: "ec2.amazonaws.com".to_string(),
+                    username: "emd".to_string(),
+                    read_only: true,
+                    resources: vec![],
+                },
+            ],
+            tags: vec![("Team".to_string(), "security".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_cloudtrail_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## CloudTrail Trail (org-audit)"));
+        assert!(markdown.contains("| Logging | Enabled |"));
+        assert!(markdown.contains("| Multi-Region Trail | Enabled |"));
+        assert!(markdown.contains("| S3 Destination | s3://audit-logs/trail |"));
+        assert!(markdown.contains("| Encryption | SSE-S3 |"));
+        assert!(markdown.contains("### Recent Management Events"));
+        assert!(markdown.contains(
+            "| 2026-01-01T00:04:00Z | **StopInstances** | ec2.amazonaws.com | alice | AWS::EC2::Instance i-0123 |"
+        ));
+        assert!(markdown.contains(
+            "| 2026-01-01T00:03:00Z | DescribeInstances | ec2.amazonaws.com | emd | - |"
+        ));
+        assert!(markdown.contains("| Team | security |"));
+    }
+
+    #[test]
+    fn scenario_cloudtrail_markdown_without_events() {
+        let mut detail = sample_detail();
+        detail.logging = false;
+        detail.s3_key_prefix = String::new();
+        detail.kms_key_id = Some("alias/trail".to_string());
+        detail.recent_events = vec![];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 로깅 | 비활성화 |"));
+        assert!(markdown.contains("| s3://audit-logs |"));
+        assert!(markdown.contains("| SSE-KMS (alias/trail) |"));
+        assert!(!markdown.contains("최근 관리 이벤트"));
+        assert!(!markdown.contains("StopInstances"));
+    }
+
+    #[test]
+    fn change_channel_reads_user_agent() {
+        assert_eq!(
+            change_channel("aws-cli/2.15.0 Python/3.11.6 Linux/6.1", false),
+            ChangeChannel::Cli
+        );
+        assert_eq!(
+            change_channel(
+                "APN/1.0 HashiCorp/1.0 Terraform/1.7.5 (+https://www.terraform.io) terraform-provider-aws/5.40.0",
+                false
+            ),
+            ChangeChannel::Terraform
+        );
+        assert_eq!(
+            change_channel("cloudformation.amazonaws.com", false),
+            ChangeChannel::CloudFormation
+        );
+        assert_eq!(
+            change_channel("autoscaling.amazonaws.com", false),
+            ChangeChannel::Service("autoscaling.amazonaws.com".to_string())
+        );
+        assert_eq!(
+            change_channel("Boto3/1.34.0 md/Botocore#1.34.0 ua/2.0", false),
+            ChangeChannel::Sdk
+        );
+        assert_eq!(
+            change_channel("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)", true),
+            ChangeChannel::Console
+        );
+        assert_eq!(
+            change_channel("AWS Internal", false),
+            ChangeChannel::Other("AWS Internal".to_string())
+        );
+        assert_eq!(
+            change_channel("Pulumi/3.100.0 (linux; amd64)", false),
+            ChangeChannel::Other("Pulumi".to_string())
+        );
+    }
+
+    #[test]
+    fn last_modified_markdown_renders_change_or_notice() {
+        let change = LastModified {
+            time: "2026-01-01T09:30:00Z".to_string(),
+            user: "alice".to_string(),
+            event: "AuthorizeSecurityGroupIngress".to_string(),
+            source: "ec2.amazonaws.com".to_string(),
+            via: ChangeChannel::Cli,
+        };
+        let markdown = last_modified_markdown(Some(&change), Language::English);
+        assert!(markdown.starts_with("### Last Modified (CloudTrail)\n"));
+        assert!(markdown.contains("| Modified By | alice |"));
+        assert!(markdown.contains("| Via | AWS CLI |"));
+        assert!(
+            markdown.contains("| Event Name | AuthorizeSecurityGroupIngress (ec2.amazonaws.com) |")
+        );
+
+        let markdown = last_modified_markdown(None, Language::Korean);
+        assert!(markdown.contains("### 마지막 수정 (CloudTrail)"));
+        assert!(markdown.contains("최근 90일 동안"));
+    }
+}
@@ -0,0 +1,400 @@
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::cloudtrail::{
+    CloudTrailDetail, CloudTrailEvent, LastModified, RECENT_EVENT_LIMIT, change_channel,
+};
+use crate::aws_cli::common::{
+    AwsResource, current_partition, get_runtime, get_sdk_config, sdk_client,
+};
+use aws_sdk_cloudtrail::Client;
+use aws_sdk_cloudtrail::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_cloudtrail::types::{Event, LookupAttribute, LookupAttributeKey, Trail, TrailInfo};
+
+// 마지막 변경을 찾을 때 살펴볼 최대 페이지 수 (읽기 이벤트가 많은 리소스의 조회 시간 제한)
+const LAST_MODIFIED_PAGES: usize = 3;
+
+/// List all CloudTrail trails (including trails from other regions) using AWS SDK
+pub fn list_cloudtrail_trails() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cloudtrail_trails_async())
+}
+
+async fn list_cloudtrail_trails_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudtrail, &config);
+
+    let trails: Vec<TrailInfo> = match client
+        .list_trails()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(trails) => trails,
+        Err(e) => {
+            tracing::error!("Error listing CloudTrail trails: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = trails.iter().map(map_trail_resource).collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get CloudTrail trail detail with the most recent management events using AWS SDK
+pub fn get_cloudtrail_detail(arn: &str) -> Option<CloudTrailDetail> {
+    get_runtime().block_on(get_cloudtrail_detail_async(arn))
+}
+
+async fn get_cloudtrail_detail_async(arn: &str) -> Option<CloudTrailDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudtrail, &config);
+
+    let output = client
+        .describe_trails()
+        .trail_name_list(arn)
+        .send()
+        .await
+        .ok()?;
+    let trail = output.trail_list().first()?;
+
+    // 상태/이벤트/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let (logging, latest_delivery) = match client.get_trail_status().name(arn).send().await {
+        Ok(status) => (
+            status.is_logging().unwrap_or(false),
+            format_timestamp(status.latest_delivery_time()),
+        ),
+        Err(e) => {
+            tracing::warn!("Error getting trail status for {}: {:?}", arn, e);
+            (false, String::new())
+        }
+    };
+
+    let recent_events = match client
+        .lookup_events()
+        .max_results(RECENT_EVENT_LIMIT)
+        .send()
+        .await
+    {
+        Ok(output) => output.events().iter().map(map_event).collect(),
+        Err(e) => {
+            tracing::warn!("Error looking up CloudTrail events: {:?}", e);
+            Vec::new()
+        }
+    };
+
+    let mut tags: Vec<(String, String)> =
+        match client.list_tags().resource_id_list(arn).send().await {
+            Ok(output) => output
+                .resource_tag_list()
+                .iter()
+                .flat_map(|resource| resource.tags_list())
+                .map(|tag| {
+                    (
+                        tag.key().to_string(),
+                        tag.value().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect(),
+            Err(e) => {
+                tracing::warn!("Error listing tags for {}: {:?}", arn, e);
+                Vec::new()
+            }
+        };
+    tags.sort();
+
+    let mut detail = map_trail_detail(trail, recent_events, tags);
+    detail.logging = logging;
+    detail.latest_delivery = latest_delivery;
+    Some(detail)
+}
+
+/// Find the most recent write event CloudTrail recorded for a resource (last 90 days) using AWS SDK
+pub fn get_last_modified(resource_id: &str, global: bool) -> Option<LastModified> {
+    get_runtime().block_on(get_last_modified_async(resource_id, global))
+}
+
+async fn get_last_modified_async(resource_id: &str, global: bool) -> Option<LastModified> {
+    let mut config = get_sdk_config().await;
+    // IAM, CloudFront, Route 53 같은 글로벌 서비스 이벤트는 파티션의 글로벌 리전에만 기록됨
+    if global {
+        config = config
+            .to_builder()
+            .region(aws_config::Region::new(current_partition().global_region()))
+            .build();
+    }
+    let client = sdk_client!(aws_sdk_cloudtrail, &config);
+
+    // ARN으로 찾지 못하면 리소스 이름으로 다시 조회 (IAM 역할 등은 이벤트에 이름으로 기록됨)
+    let mut names = vec![resource_id];
+    if let Some(arn) = Arn::parse(resource_id) {
+        let name = arn.resource_name();
+        if !name.is_empty() && name != resource_id {
+            names.push(name);
+        }
+    }
+    for name in names {
+        if let Some(event) = latest_write_event(&client, name).await {
+            return Some(map_last_modified(&event));
+        }
+    }
+    None
+}
+
+async fn latest_write_event(client: &Client, resource_name: &str) -> Option<Event> {
+    let attribute = LookupAttribute::builder()
+        .attribute_key(LookupAttributeKey::ResourceName)
+        .attribute_value(resource_name)
+        .build()
+        .ok()?;
+    let mut next_token: Option<String> = None;
+    for _ in 0..LAST_MODIFIED_PAGES {
+        let output = match client
+            .lookup_events()
+            .lookup_attributes(attribute.clone())
+            .set_next_token(next_token.take())
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::warn!(
+                    "Error looking up CloudTrail events for {}: {:?}",
+                    resource_name,
+                    e
+                );
+                return None;
+            }
+        };
+        // 최신순으로 반환되므로 처음 나오는 변경 이벤트가 마지막 변경
+        if let Some(event) = output
+            .events()
+            .iter()
+            .find(|event| event.read_only() != Some("true"))
+        {
+            return Some(event.clone());
+        }
+        next_token = output.next_token().map(str::to_string);
+        if next_token.is_none() {
+            break;
+        }
+    }
+    None
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn map_trail_resource(trail: &TrailInfo) -> AwsResource {
+    AwsResource {
+        name: trail.name().unwrap_or_default().to_string(),
+        id: trail.trail_arn().unwrap_or_default().to_string(),
+        state: trail.home_region().unwrap_or_default().to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_trail_detail(
+    trail: &Trail,
+    recent_events: Vec<CloudTrailEvent>,
+    tags: Vec<(String, String)>,
+) -> CloudTrailDetail {
+    let non_empty = |value: Option<&str>| value.filter(|v| !v.is_empty()).map(str::to_string);
+    CloudTrailDetail {
+        name: trail.name().unwrap_or_default().to_string(),
+        arn: trail.trail_arn().unwrap_or_default().to_string(),
+        home_region: trail.home_region().unwrap_or_default().to_string(),
+        multi_region: trail.is_multi_region_trail().unwrap_or(false),
+        organization_trail: trail.is_organization_trail().unwrap_or(false),
+        include_global_events: trail.include_global_service_events().unwrap_or(false),
+        logging: false,
+        latest_delivery: String::new(),
+        s3_bucket: trail.s3_bucket_name().unwrap_or_default().to_string(),
+        s3_key_prefix: trail.s3_key_prefix().unwrap_or_default().to_string(),
+        log_file_validation: trail.log_file_validation_enabled().unwrap_or(false),
+        kms_key_id: non_empty(trail.kms_key_id()),
+        cloudwatch_log_group: non_empty(trail.cloud_watch_logs_log_group_arn()),
+        sns_topic: non_empty(trail.sns_topic_arn()),
+        recent_events,
+        tags,
+    }
+}
+
+fn map_event(event: &Event) -> CloudTrailEvent {
+    CloudTrailEvent {
+        time: format_timestamp(event.event_time()),
+        name: event.event_name().unwrap_or_default().to_string(),
+        source: event.event_source().unwrap_or_default().to_string(),
+        username: event.username().unwrap_or_default().to_string(),
+        // ReadOnly는 "true"/"false" 문자열
+        read_only: event.read_only() == Some("true"),
+        resources: event
+            .resources()
+            .iter()
+            .map(|resource| {
+                format!(
+                    "{} {}",
+                    resource.resource_type().unwrap_or_default(),
+                    resource.resource_name().unwrap_or_default()
+                )
+                .trim()
+                .to_string()
+            })
+            .filter(|resource| !resource.is_empty())
+            .collect(),
+    }
+}
+
+fn map_last_modified(event: &Event) -> LastModified {
+    // 사용자 에이전트와 콘솔 세션 여부는 원본 이벤트 JSON에만 있음
+    let record: serde_json::Value = event
+        .cloud_trail_event()
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default();
+    let field = |pointer: &str| {
+        record
+            .pointer(pointer)
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let user = match event.username().filter(|username| !username.is_empty()) {
+        Some(username) => username.to_string(),
+        None => field("/userIdentity/arn"),
+    };
+
+    LastModified {
+        time: format_timestamp(event.event_time()),
+        user,
+        event: event.event_name().unwrap_or_default().to_string(),
+        source: event.event_source().unwrap_or_default().to_string(),
+        via: change_channel(
+            &field("/userAgent"),
+            field("/sessionCredentialFromConsole") == "true",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_event, map_last_modified, map_trail_detail, map_trail_resource};
+    use crate::aws_cli::cloudtrail::ChangeChannel;
+    use crate::aws_cli::read_only::is_allowed_operation;
+    use aws_sdk_cloudtrail::operation::lookup_events::LookupEvents;
+    use aws_sdk_cloudtrail::primitives::DateTime;
+    use aws_sdk_cloudtrail::types::{Event, Resource, Trail, TrailInfo};
+    use aws_smithy_runtime_api::client::orchestrator::Metadata;
+    use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugin;
+
+    #[test]
+    fn lookup_events_passes_read_only_guard() {
+        // 최근 이벤트 조회와 latest_write_event 모두 LookupEvents를 호출
+        let config = LookupEvents::new().config().expect("operation config");
+        let metadata = config.load::<Metadata>().expect("operation metadata");
+        assert!(is_allowed_operation(metadata.service(), metadata.name()));
+    }
+
+    #[test]
+    fn map_trail_resource_uses_arn_as_id() {
+        let trail = TrailInfo::builder()
+            .name("org-audit")
+            .trail_arn("arn:aws:cloudtrail:us-east-1:123456789012:trail/org-audit")
+            .home_region("us-east-1")
+            .build();
+        let resource = map_trail_resource(&trail);
+        assert_eq!(resource.name, "org-audit");
+        assert_eq!(
+            resource.id,
+            "arn:aws:cloudtrail:us-east-1:123456789012:trail/org-audit"
+        );
+        assert_eq!(resource.state, "us-east-1");
+    }
+
+    #[test]
+    fn map_trail_detail_drops_empty_optional_fields() {
+        let trail = Trail::builder()
+            .name("org-audit")
+            .s3_bucket_name("audit-logs")
+            .is_multi_region_trail(true)
+            .log_file_validation_enabled(true)
+            .kms_key_id("")
+            .cloud_watch_logs_log_group_arn("arn:aws:logs:us-east-1:123456789012:log-group:trail:*")
+            .build();
+        let detail = map_trail_detail(&trail, vec![], vec![]);
+        assert!(detail.multi_region);
+        assert!(!detail.organization_trail);
+        assert!(detail.log_file_validation);
+        assert_eq!(detail.kms_key_id, None);
+        assert_eq!(detail.sns_topic, None);
+        assert!(detail.cloudwatch_log_group.is_some());
+    }
+
+    #[test]
+    fn map_event_reads_resources_and_read_only_flag() {
+        let event = Event::builder()
+            .event_name("StopInstances")
+            .event_source("ec2.amazonaws.com")
+            .username("alice")
+            .read_only("false")
+            .event_time(DateTime::from_secs(1_767_225_600))
+            .resources(
+                Resource::builder()
+                    .resource_type("AWS::EC2::Instance")
+                    .resource_name("i-0123")
+                    .build(),
+            )
+            .build();
+        let mapped = map_event(&event);
+        assert_eq!(mapped.time, "2026-01-01T00:00:00Z");
+        assert!(!mapped.read_only);
+        assert_eq!(mapped.resources, vec!["AWS::EC2::Instance i-0123"]);
+
+        let read = map_event(
+            &Event::builder()
+                .event_name("DescribeTrails")
+                .read_only("true")
+                .build(),
+        );
+        assert!(read.read_only);
+        assert!(read.resources.is_empty());
+    }
+
+    #[test]
+    fn map_last_modified_reads_user_agent_from_event_record() {
+        let event = Event::builder()
+            .event_name("AuthorizeSecurityGroupIngress")
+            .event_source("ec2.amazonaws.com")
+            .username("alice")
+            .read_only("false")
+            .event_time(DateTime::from_secs(1_767_225_600))
+            .cloud_trail_event(
+                r#"{"userAgent":"aws-cli/2.15.0 Python/3.11.6","userIdentity":{"arn":"arn:aws:iam::123456789012:user/alice"}}"#,
+            )
+            .build();
+        let change = map_last_modified(&event);
+        assert_eq!(change.user, "alice");
+        assert_eq!(change.time, "2026-01-01T00:00:00Z");
+        assert_eq!(change.event, "AuthorizeSecurityGroupIngress");
+        assert_eq!(change.via, ChangeChannel::Cli);
+
+        // 사용자 이름이 없으면 호출자 ARN, 콘솔 세션은 사용자 에이전트와 관계없이 콘솔
+        let console = map_last_modified(
+            &Event::builder()
+                .event_name("ModifyDBInstance")
+                .cloud_trail_event(
+                    r#"{"userAgent":"Mozilla/5.0","sessionCredentialFromConsole":"true","userIdentity":{"arn":"arn:aws:sts::123456789012:assumed-role/Admin/bob"}}"#,
+                )
+                .build(),
+        );
+        assert_eq!(
+            console.user,
+            "arn:aws:sts::123456789012:assumed-role/Admin/bob"
+        );
+        assert_eq!(console.via, ChangeChannel::Console);
+    }
+}
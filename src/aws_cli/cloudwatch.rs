@@ -0,0 +1,273 @@
+pub use crate::aws_cli::cloudwatch_sdk::{get_cloudwatch_alarm_detail, list_cloudwatch_alarms};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct CloudWatchAlarmDetail {
+    pub name: String,
+    pub arn: String,
+    pub description: String,
+    pub composite: bool,
+    // OK, ALARM, INSUFFICIENT_DATA
+    pub state: String,
+    pub state_reason: String,
+    pub state_updated: String,
+    // "AWS/EC2 CPUUtilization" 또는 지표 수식
+    pub metric: String,
+    pub statistic: String,
+    pub dimensions: Vec<(String, String)>,
+    // 초 단위
+    pub period: Option<i32>,
+    pub evaluation_periods: Option<i32>,
+    pub datapoints_to_alarm: Option<i32>,
+    pub comparison_operator: String,
+    pub threshold: Option<f64>,
+    pub treat_missing_data: String,
+    // 복합 경보의 규칙 식
+    pub alarm_rule: Option<String>,
+    pub actions_enabled: bool,
+    pub alarm_actions: Vec<String>,
+    pub ok_actions: Vec<String>,
+    pub insufficient_data_actions: Vec<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+// GreaterThanOrEqualToThreshold -> ">=" (이상 탐지 밴드 비교는 원래 이름 유지)
+pub fn comparison_symbol(operator: &str) -> &str {
+    match operator {
+        "GreaterThanOrEqualToThreshold" => ">=",
+        "GreaterThanThreshold" => ">",
+        "LessThanThreshold" => "<",
+        "LessThanOrEqualToThreshold" => "<=",
+        _ => operator,
+    }
+}
+
+impl CloudWatchAlarmDetail {
+    /// Alarm condition such as `>= 80`, or `None` for composite alarms.
+    pub fn condition(&self) -> Option<String> {
+        if self.comparison_operator.is_empty() {
+            return None;
+        }
+        let symbol = comparison_symbol(&self.comparison_operator);
+        Some(match self.threshold {
+            Some(threshold) => format!("{} {}", symbol, threshold),
+            None => symbol.to_string(),
+        })
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let alarm_type = if self.composite {
+            i18n.md_composite_alarm()
+        } else {
+            i18n.md_metric_alarm()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.cloudwatch_alarm(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_type(), alarm_type));
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.state));
+        if !self.state_reason.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_state_reason(),
+                self.state_reason.replace('|', "\\|")
+            ));
+        }
+        if !self.state_updated.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_state_updated(),
+                self.state_updated
+            ));
+        }
+        if !self.metric.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_metric(), self.metric));
+        }
+        if !self.statistic.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_statistic(), self.statistic));
+        }
+        if let Some(period) = self.period {
+            lines.push(format!("| {} | {}s |", i18n.md_period(), period));
+        }
+        if let Some(condition) = self.condition() {
+            lines.push(format!("| {} | {} |", i18n.md_threshold(), condition));
+        }
+        if let Some(evaluation_periods) = self.evaluation_periods {
+            // 평가 기간 N개 중 M개가 위반하면 경보
+            let datapoints = self.datapoints_to_alarm.unwrap_or(evaluation_periods);
+            lines.push(format!(
+                "| {} | {} / {} |",
+                i18n.md_datapoints_to_alarm(),
+                datapoints,
+                evaluation_periods
+            ));
+        }
+        if !self.treat_missing_data.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_missing_data(),
+                self.treat_missing_data
+            ));
+        }
+        if let Some(ref rule) = self.alarm_rule {
+            lines.push(format!(
+                "| {} | `{}` |",
+                i18n.md_alarm_rule(),
+                rule.replace('|', "\\|")
+            ));
+        }
+        let actions_enabled = if self.actions_enabled {
+            i18n.md_enabled()
+        } else {
+            i18n.md_disabled()
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_actions_enabled(),
+            actions_enabled
+        ));
+
+        // Dimensions
+        if !self.dimensions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_dimensions()));
+            lines.push(format!("| {} | {} |", i18n.md_name(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (name, value) in &self.dimensions {
+                lines.push(format!("| {} | {} |", name, value));
+            }
+        }
+
+        // Actions
+        let actions: Vec<(&str, &String)> = [
+            ("ALARM", &self.alarm_actions),
+            ("OK", &self.ok_actions),
+            ("INSUFFICIENT_DATA", &self.insufficient_data_actions),
+        ]
+        .into_iter()
+        .flat_map(|(state, actions)| actions.iter().map(move |action| (state, action)))
+        .collect();
+        if !actions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_alarm_actions()));
+            lines.push(format!("| {} | {} |", i18n.md_state(), i18n.md_action()));
+            lines.push("|:---|:---|".to_string());
+            for (state, action) in actions {
+                lines.push(format!("| {} | {} |", state, action));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CloudWatchAlarmDetail, comparison_symbol};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> CloudWatchAlarmDetail {
+        CloudWatchAlarmDetail {
+            name: "api-high-cpu".to_string(),
+            arn: "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:api-high-cpu".to_string(),
+            description: "API CPU above 80%".to_string(),
+            composite: false,
+            state: "ALARM".to_string(),
+            state_reason: "Threshold Crossed: 3 datapoints were greater than the threshold"
+                .to_string(),
+            state_updated: "2026-01-01T09:00:00+09:00".to_string(),
+            metric: "AWS/EC2 CPUUtilization".to_string(),
+            statistic: "Average".to_string(),
+            dimensions: vec![("AutoScalingGroupName".to_string(), "api-asg".to_string())],
+            period: Some(300),
+            evaluation_periods: Some(5),
+            datapoints_to_alarm: Some(3),
+            comparison_operator: "GreaterThanOrEqualToThreshold".to_string(),
+            threshold: Some(80.0),
+            treat_missing_data: "missing".to_string(),
+            alarm_rule: None,
+            actions_enabled: true,
+            alarm_actions: vec!["arn:aws:sns:ap-northeast-2:123456789012:alerts".to_string()],
+            ok_actions: vec!["arn:aws:sns:ap-northeast-2:123456789012:alerts".to_string()],
+            insufficient_data_actions: vec![],
+            tags: vec![("Team".to_string(), "platform".to_string())],
+        }
+    }
+
+    #[test]
+    fn comparison_symbol_keeps_unknown_operators() {
+        assert_eq!(comparison_symbol("LessThanThreshold"), "<");
+        assert_eq!(
+            comparison_symbol("LessThanLowerOrGreaterThanUpperThreshold"),
+            "LessThanLowerOrGreaterThanUpperThreshold"
+        );
+    }
+
+    #[test]
+    fn scenario_cloudwatch_alarm_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## CloudWatch Alarm (api-high-cpu)"));
+        assert!(markdown.contains("| Type | Metric Alarm |"));
+        assert!(markdown.contains("| State | ALARM |"));
+        assert!(markdown.contains("| Metric | AWS/EC2 CPUUtilization |"));
+        assert!(markdown.contains("| Period | 300s |"));
+        assert!(markdown.contains("| Threshold | >= 80 |"));
+        assert!(markdown.contains("| Datapoints to Alarm | 3 / 5 |"));
+        assert!(markdown.contains("| AutoScalingGroupName | api-asg |"));
+        assert!(markdown.contains("| ALARM | arn:aws:sns:ap-northeast-2:123456789012:alerts |"));
+        assert!(markdown.contains("| OK | arn:aws:sns:ap-northeast-2:123456789012:alerts |"));
+        assert!(markdown.contains("| Team | platform |"));
+    }
+
+    #[test]
+    fn scenario_cloudwatch_composite_alarm_markdown_render() {
+        let mut detail = sample_detail();
+        detail.composite = true;
+        detail.metric = String::new();
+        detail.statistic = String::new();
+        detail.dimensions = vec![];
+        detail.period = None;
+        detail.evaluation_periods = None;
+        detail.comparison_operator = String::new();
+        detail.threshold = None;
+        detail.treat_missing_data = String::new();
+        detail.alarm_rule = Some("ALARM(api-high-cpu) OR ALARM(api-5xx)".to_string());
+        detail.actions_enabled = false;
+        detail.alarm_actions = vec![];
+        detail.ok_actions = vec![];
+
+        assert_eq!(detail.condition(), None);
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 타입 | 복합 경보 |"));
+        assert!(markdown.contains("| 경보 규칙 | `ALARM(api-high-cpu) OR ALARM(api-5xx)` |"));
+        assert!(markdown.contains("| 작업 활성화 | 비활성화 |"));
+        assert!(!markdown.contains("### 경보 작업"));
+    }
+}
@@ -0,0 +1,336 @@
+use crate::aws_cli::cloudwatch::{CloudWatchAlarmDetail, comparison_symbol};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_cloudwatch::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_cloudwatch::types::{AlarmType, CompositeAlarm, MetricAlarm};
+
+/// List all CloudWatch metric and composite alarms using AWS SDK
+pub fn list_cloudwatch_alarms() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cloudwatch_alarms_async())
+}
+
+async fn list_cloudwatch_alarms_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudwatch, &config);
+
+    let pages: Vec<aws_sdk_cloudwatch::operation::describe_alarms::DescribeAlarmsOutput> =
+        match client
+            .describe_alarms()
+            .alarm_types(AlarmType::MetricAlarm)
+            .alarm_types(AlarmType::CompositeAlarm)
+            .into_paginator()
+            .send()
+            .try_collect()
+            .await
+        {
+            Ok(pages) => pages,
+            Err(e) => {
+                tracing::error!("Error describing CloudWatch alarms: {:?}", e);
+                return Vec::new();
+            }
+        };
+
+    let mut resources: Vec<AwsResource> = pages
+        .iter()
+        .flat_map(|page| {
+            page.metric_alarms()
+                .iter()
+                .map(map_metric_alarm)
+                .chain(page.composite_alarms().iter().map(map_composite_alarm))
+        })
+        .map(|detail| map_alarm_resource(&detail))
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get CloudWatch alarm detail (condition, actions and tags) using AWS SDK
+pub fn get_cloudwatch_alarm_detail(name: &str) -> Option<CloudWatchAlarmDetail> {
+    get_runtime().block_on(get_cloudwatch_alarm_detail_async(name))
+}
+
+async fn get_cloudwatch_alarm_detail_async(name: &str) -> Option<CloudWatchAlarmDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_cloudwatch, &config);
+
+    let output = client
+        .describe_alarms()
+        .alarm_names(name)
+        .alarm_types(AlarmType::MetricAlarm)
+        .alarm_types(AlarmType::CompositeAlarm)
+        .send()
+        .await
+        .ok()?;
+    let mut detail = match output.metric_alarms().first() {
+        Some(alarm) => map_metric_alarm(alarm),
+        None => map_composite_alarm(output.composite_alarms().first()?),
+    };
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    if !detail.arn.is_empty() {
+        match client
+            .list_tags_for_resource()
+            .resource_arn(&detail.arn)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                detail.tags = output
+                    .tags()
+                    .iter()
+                    .map(|tag| {
+                        (
+                            tag.key().unwrap_or_default().to_string(),
+                            tag.value().unwrap_or_default().to_string(),
+                        )
+                    })
+                    .collect();
+                detail.tags.sort();
+            }
+            Err(e) => tracing::warn!("Error listing tags for {}: {:?}", detail.arn, e),
+        }
+    }
+
+    Some(detail)
+}
+
+// 목록에는 상태와 "지표 조건"을 함께 표시 (복합 경보는 규칙)
+fn map_alarm_resource(detail: &CloudWatchAlarmDetail) -> AwsResource {
+    let summary = match (detail.alarm_rule.as_ref(), detail.condition()) {
+        (Some(rule), _) => rule.clone(),
+        (None, Some(condition)) => format!("{} {}", detail.metric, condition),
+        (None, None) => detail.metric.clone(),
+    };
+    AwsResource {
+        name: detail.name.clone(),
+        id: detail.name.clone(),
+        state: detail.state.clone(),
+        az: String::new(),
+        cidr: summary,
+        owner_id: String::new(),
+    }
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 단일 지표는 "네임스페이스 지표명", 지표 수식 경보는 결과로 쓰는 쿼리의 식
+fn alarm_metric(alarm: &MetricAlarm) -> String {
+    if let Some(metric_name) = alarm.metric_name() {
+        return format!("{} {}", alarm.namespace().unwrap_or_default(), metric_name)
+            .trim()
+            .to_string();
+    }
+    alarm
+        .metrics()
+        .iter()
+        .find(|query| query.return_data().unwrap_or(true) && query.expression().is_some())
+        .or_else(|| alarm.metrics().first())
+        .map(|query| match query.expression() {
+            Some(expression) => expression.to_string(),
+            None => query
+                .metric_stat()
+                .and_then(|stat| stat.metric())
+                .map(|metric| {
+                    format!(
+                        "{} {}",
+                        metric.namespace().unwrap_or_default(),
+                        metric.metric_name().unwrap_or_default()
+                    )
+                })
+                .unwrap_or_default(),
+        })
+        .unwrap_or_default()
+}
+
+fn map_metric_alarm(alarm: &MetricAlarm) -> CloudWatchAlarmDetail {
+    let statistic = alarm
+        .statistic()
+        .map(|statistic| statistic.as_str())
+        .or(alarm.extended_statistic())
+        .unwrap_or_default();
+    let comparison_operator = alarm
+        .comparison_operator()
+        .map(|operator| operator.as_str())
+        .unwrap_or_default();
+
+    CloudWatchAlarmDetail {
+        name: alarm.alarm_name().unwrap_or_default().to_string(),
+        arn: alarm.alarm_arn().unwrap_or_default().to_string(),
+        description: alarm.alarm_description().unwrap_or_default().to_string(),
+        composite: false,
+        state: alarm
+            .state_value()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        state_reason: alarm.state_reason().unwrap_or_default().to_string(),
+        state_updated: format_timestamp(alarm.state_updated_timestamp()),
+        metric: alarm_metric(alarm),
+        statistic: statistic.to_string(),
+        dimensions: alarm
+            .dimensions()
+            .iter()
+            .map(|d| {
+                (
+                    d.name().unwrap_or_default().to_string(),
+                    d.value().unwrap_or_default().to_string(),
+                )
+            })
+            .collect(),
+        period: alarm.period(),
+        evaluation_periods: alarm.evaluation_periods(),
+        datapoints_to_alarm: alarm.datapoints_to_alarm(),
+        // 이상 탐지 경보는 고정 임계값 대신 밴드 지표(ThresholdMetricId)와 비교
+        threshold: alarm
+            .threshold()
+            .filter(|_| comparison_symbol(comparison_operator) != comparison_operator),
+        comparison_operator: comparison_operator.to_string(),
+        treat_missing_data: alarm.treat_missing_data().unwrap_or_default().to_string(),
+        alarm_rule: None,
+        actions_enabled: alarm.actions_enabled().unwrap_or(false),
+        alarm_actions: alarm.alarm_actions().to_vec(),
+        ok_actions: alarm.ok_actions().to_vec(),
+        insufficient_data_actions: alarm.insufficient_data_actions().to_vec(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_composite_alarm(alarm: &CompositeAlarm) -> CloudWatchAlarmDetail {
+    CloudWatchAlarmDetail {
+        name: alarm.alarm_name().unwrap_or_default().to_string(),
+        arn: alarm.alarm_arn().unwrap_or_default().to_string(),
+        description: alarm.alarm_description().unwrap_or_default().to_string(),
+        composite: true,
+        state: alarm
+            .state_value()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        state_reason: alarm.state_reason().unwrap_or_default().to_string(),
+        state_updated: format_timestamp(alarm.state_updated_timestamp()),
+        metric: String::new(),
+        statistic: String::new(),
+        dimensions: Vec::new(),
+        period: None,
+        evaluation_periods: None,
+        datapoints_to_alarm: None,
+        comparison_operator: String::new(),
+        threshold: None,
+        treat_missing_data: String::new(),
+        alarm_rule: alarm.alarm_rule().map(str::to_string),
+        actions_enabled: alarm.actions_enabled().unwrap_or(false),
+        alarm_actions: alarm.alarm_actions().to_vec(),
+        ok_actions: alarm.ok_actions().to_vec(),
+        insufficient_data_actions: alarm.insufficient_data_actions().to_vec(),
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_alarm_resource, map_composite_alarm, map_metric_alarm};
+    use aws_sdk_cloudwatch::types::{
+        ComparisonOperator, CompositeAlarm, Dimension, Metric, MetricAlarm, MetricDataQuery,
+        MetricStat, StateValue, Statistic,
+    };
+
+    #[test]
+    fn map_metric_alarm_reads_condition_and_actions() {
+        let alarm = MetricAlarm::builder()
+            .alarm_name("api-high-cpu")
+            .alarm_arn("arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:api-high-cpu")
+            .state_value(StateValue::Alarm)
+            .namespace("AWS/EC2")
+            .metric_name("CPUUtilization")
+            .statistic(Statistic::Average)
+            .dimensions(
+                Dimension::builder()
+                    .name("AutoScalingGroupName")
+                    .value("api-asg")
+                    .build(),
+            )
+            .period(300)
+            .evaluation_periods(3)
+            .comparison_operator(ComparisonOperator::GreaterThanOrEqualToThreshold)
+            .threshold(80.0)
+            .actions_enabled(true)
+            .alarm_actions("arn:aws:sns:ap-northeast-2:123456789012:alerts")
+            .build();
+
+        let detail = map_metric_alarm(&alarm);
+        assert_eq!(detail.state, "ALARM");
+        assert_eq!(detail.metric, "AWS/EC2 CPUUtilization");
+        assert_eq!(detail.statistic, "Average");
+        assert_eq!(
+            detail.dimensions,
+            vec![("AutoScalingGroupName".to_string(), "api-asg".to_string())]
+        );
+        assert_eq!(detail.condition().as_deref(), Some(">= 80"));
+        assert_eq!(detail.alarm_actions.len(), 1);
+
+        let resource = map_alarm_resource(&detail);
+        assert_eq!(resource.id, "api-high-cpu");
+        assert_eq!(resource.state, "ALARM");
+        assert_eq!(resource.cidr, "AWS/EC2 CPUUtilization >= 80");
+    }
+
+    #[test]
+    fn map_metric_alarm_uses_expression_and_skips_band_threshold() {
+        let alarm = MetricAlarm::builder()
+            .alarm_name("api-latency-anomaly")
+            .metrics(
+                MetricDataQuery::builder()
+                    .id("m1")
+                    .return_data(false)
+                    .metric_stat(
+                        MetricStat::builder()
+                            .metric(
+                                Metric::builder()
+                                    .namespace("AWS/ApplicationELB")
+                                    .metric_name("TargetResponseTime")
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .metrics(
+                MetricDataQuery::builder()
+                    .id("ad1")
+                    .expression("ANOMALY_DETECTION_BAND(m1, 2)")
+                    .build(),
+            )
+            .comparison_operator(ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold)
+            .threshold(0.0)
+            .threshold_metric_id("ad1")
+            .build();
+
+        let detail = map_metric_alarm(&alarm);
+        assert_eq!(detail.metric, "ANOMALY_DETECTION_BAND(m1, 2)");
+        assert_eq!(detail.threshold, None);
+        assert_eq!(
+            detail.condition().as_deref(),
+            Some("LessThanLowerOrGreaterThanUpperThreshold")
+        );
+    }
+
+    #[test]
+    fn map_composite_alarm_lists_rule_as_summary() {
+        let alarm = CompositeAlarm::builder()
+            .alarm_name("api-degraded")
+            .alarm_rule("ALARM(api-high-cpu) OR ALARM(api-5xx)")
+            .state_value(StateValue::Ok)
+            .build();
+
+        let detail = map_composite_alarm(&alarm);
+        assert!(detail.composite);
+        assert_eq!(detail.condition(), None);
+
+        let resource = map_alarm_resource(&detail);
+        assert_eq!(resource.state, "OK");
+        assert_eq!(resource.cidr, "ALARM(api-high-cpu) OR ALARM(api-5xx)");
+    }
+}
@@ -0,0 +1,506 @@
+pub use crate::aws_cli::cognito_sdk::{get_cognito_pool_detail, list_cognito_pools};
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct CognitoPoolDetail {
+    pub id: String,
+    pub name: String,
+    // 자격 증명 풀은 API가 ARN을 돌려주지 않으므로 빈 문자열
+    pub arn: String,
+    pub created: String,
+    pub pool: CognitoPool,
+    pub tags: Vec<(String, String)>,
+}
+
+/// User pools sign users in; identity pools hand out AWS credentials.
+#[derive(Debug, Serialize)]
+pub enum CognitoPool {
+    User(UserPoolDetail),
+    Identity(IdentityPoolDetail),
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserPoolDetail {
+    // LITE, ESSENTIALS, PLUS
+    pub tier: String,
+    pub estimated_users: i32,
+    // 사용자 이름 대신 로그인에 쓰는 속성 (email, phone_number), 비어 있으면 사용자 이름
+    pub sign_in_attributes: Vec<String>,
+    pub deletion_protection: String,
+    // OFF, ON, OPTIONAL
+    pub mfa: String,
+    // SMS, TOTP, EMAIL
+    pub mfa_methods: Vec<String>,
+    // 접두사 도메인과 사용자 지정 도메인을 함께 쓸 수 있음
+    pub domains: Vec<UserPoolDomain>,
+    pub app_clients: Vec<AppClient>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserPoolDomain {
+    pub domain: String,
+    // 사용자 지정 도메인이면 ACM 인증서와 CloudFront 배포가 채워짐
+    pub custom: bool,
+    pub certificate_arn: String,
+    pub cloudfront_distribution: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AppClient {
+    pub name: String,
+    pub id: String,
+    // 보안 암호 값은 문서에 남기지 않고 존재 여부만 기록
+    pub has_secret: bool,
+    pub auth_flows: Vec<String>,
+    pub oauth_flows: Vec<String>,
+    pub oauth_scopes: Vec<String>,
+    pub identity_providers: Vec<String>,
+    pub callback_urls: Vec<String>,
+    pub logout_urls: Vec<String>,
+    // "60m", "1h", "30d"처럼 단위를 붙인 토큰 유효 기간
+    pub access_token_validity: String,
+    pub id_token_validity: String,
+    pub refresh_token_validity: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentityPoolDetail {
+    pub allow_unauthenticated: bool,
+    pub allow_classic_flow: bool,
+    pub providers: Vec<IdentityProvider>,
+    pub authenticated_role: String,
+    pub unauthenticated_role: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentityProvider {
+    // Cognito, Social, OIDC, SAML, Developer
+    pub kind: String,
+    pub name: String,
+    pub client_id: String,
+}
+
+/// Identity pool IDs are `region:uuid`; user pool IDs are `region_suffix`.
+pub fn is_identity_pool_id(id: &str) -> bool {
+    id.contains(':')
+}
+
+/// `ALLOW_USER_SRP_AUTH` → `USER_SRP_AUTH`.
+pub fn auth_flow_label(flow: &str) -> String {
+    flow.strip_prefix("ALLOW_").unwrap_or(flow).to_string()
+}
+
+impl CognitoPoolDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let title = match self.pool {
+            CognitoPool::User(_) => i18n.cognito_user_pool(),
+            CognitoPool::Identity(_) => i18n.cognito_identity_pool(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, self.display_name()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+        ];
+        if !self.arn.is_empty() {
+            lines.push(format!("| ARN | {} |", self.arn));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+
+        match &self.pool {
+            CognitoPool::User(user_pool) => user_pool.push_markdown(&mut lines, &i18n),
+            CognitoPool::Identity(identity_pool) => identity_pool.push_markdown(&mut lines, &i18n),
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+fn join_or_dash(values: &[String], separator: &str) -> String {
+    if values.is_empty() {
+        "-".to_string()
+    } else {
+        values.join(separator)
+    }
+}
+
+impl UserPoolDetail {
+    fn push_markdown(&self, lines: &mut Vec<String>, i18n: &I18n) {
+        if !self.tier.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_tier(), self.tier));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_estimated_users(),
+            locale::count(self.estimated_users)
+        ));
+        let sign_in = if self.sign_in_attributes.is_empty() {
+            "username".to_string()
+        } else {
+            self.sign_in_attributes.join(", ")
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_sign_in_attributes(),
+            sign_in
+        ));
+        let mfa = if self.mfa_methods.is_empty() {
+            self.mfa.clone()
+        } else {
+            format!("{} ({})", self.mfa, self.mfa_methods.join(", "))
+        };
+        lines.push(format!("| MFA | {} |", mfa));
+        if !self.deletion_protection.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_deletion_protection(),
+                self.deletion_protection
+            ));
+        }
+
+        if !self.domains.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_user_pool_domain()));
+            lines.push(format!(
+                "| {} | {} | {} | CloudFront | {} |",
+                i18n.md_domain_name(),
+                i18n.md_type(),
+                i18n.md_certificate(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            let or_dash = |value: &str| {
+                if value.is_empty() {
+                    "-".to_string()
+                } else {
+                    value.to_string()
+                }
+            };
+            for domain in &self.domains {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    domain.domain,
+                    if domain.custom {
+                        i18n.md_custom_domain()
+                    } else {
+                        i18n.md_cognito_prefix_domain()
+                    },
+                    or_dash(&domain.certificate_arn),
+                    or_dash(&domain.cloudfront_distribution),
+                    or_dash(&domain.status)
+                ));
+            }
+        }
+
+        if self.app_clients.is_empty() {
+            return;
+        }
+        lines.push(format!("\n### {}\n", i18n.md_app_clients()));
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} |",
+            i18n.md_name(),
+            i18n.md_client_id(),
+            i18n.md_client_secret(),
+            i18n.md_auth_flows(),
+            i18n.md_token_validity()
+        ));
+        lines.push("|:---|:---|:---|:---|:---|".to_string());
+        for client in &self.app_clients {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} / {} / {} |",
+                client.name,
+                client.id,
+                if client.has_secret {
+                    i18n.md_enabled()
+                } else {
+                    i18n.md_disabled()
+                },
+                join_or_dash(&client.auth_flows, ", "),
+                client.access_token_validity,
+                client.id_token_validity,
+                client.refresh_token_validity
+            ));
+        }
+
+        // 호스팅 UI/OAuth를 쓰는 앱 클라이언트만 리디렉션 설정을 따로 표시
+        for client in self
+            .app_clients
+            .iter()
+            .filter(|client| !client.oauth_flows.is_empty())
+        {
+            lines.push(format!("\n#### {}\n", client.name));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_oauth_flows(),
+                client.oauth_flows.join(", ")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_oauth_scopes(),
+                join_or_dash(&client.oauth_scopes, ", ")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_identity_providers(),
+                join_or_dash(&client.identity_providers, ", ")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_callback_urls(),
+                join_or_dash(&client.callback_urls, "<br>")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_sign_out_urls(),
+                join_or_dash(&client.logout_urls, "<br>")
+            ));
+        }
+    }
+}
+
+impl IdentityPoolDetail {
+    fn push_markdown(&self, lines: &mut Vec<String>, i18n: &I18n) {
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_guest_access(),
+            enabled(self.allow_unauthenticated)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_basic_auth_flow(),
+            enabled(self.allow_classic_flow)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_authenticated_role(),
+            or_dash(&self.authenticated_role)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_guest_role(),
+            or_dash(&self.unauthenticated_role)
+        ));
+
+        if !self.providers.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_identity_providers()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_type(),
+                i18n.md_provider(),
+                i18n.md_client_id()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for provider in &self.providers {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    provider.kind,
+                    provider.name,
+                    or_dash(&provider.client_id)
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AppClient, CognitoPool, CognitoPoolDetail, IdentityPoolDetail, IdentityProvider,
+        UserPoolDetail, UserPoolDomain, auth_flow_label, is_identity_pool_id,
+    };
+    use crate::i18n::Language;
+
+    fn app_client(name: &str, oauth: bool) -> AppClient {
+        AppClient {
+            name: name.to_string(),
+            id: format!("{}-client-id", name),
+            has_secret: !oauth,
+            auth_flows: vec![
+                "USER_SRP_AUTH".to_string(),
+                "REFRESH_TOKEN_AUTH".to_string(),
+            ],
+            oauth_flows: if oauth {
+                vec!["code".to_string()]
+            } else {
+                vec![]
+            },
+            oauth_scopes: if oauth {
+                vec!["openid".to_string(), "email".to_string()]
+            } else {
+                vec![]
+            },
+            identity_providers: vec!["COGNITO".to_string()],
+            callback_urls: if oauth {
+                vec![
+                    "https://app.example.com/callback".to_string(),
+                    "http://localhost:3000/callback".to_string(),
+                ]
+            } else {
+                vec![]
+            },
+            logout_urls: vec![],
+            access_token_validity: "1h".to_string(),
+            id_token_validity: "1h".to_string(),
+            refresh_token_validity: "30d".to_string(),
+        }
+    }
+
+    #[test]
+    fn pool_id_and_auth_flow_helpers() {
+        assert!(is_identity_pool_id(
+            "ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666"
+        ));
+        assert!(!is_identity_pool_id("ap-northeast-2_AbCdEf123"));
+        assert_eq!(auth_flow_label("ALLOW_USER_SRP_AUTH"), "USER_SRP_AUTH");
+        assert_eq!(auth_flow_label("ADMIN_NO_SRP_AUTH"), "ADMIN_NO_SRP_AUTH");
+    }
+
+    #[test]
+    fn user_pool_markdown_lists_mfa_domain_and_app_clients() {
+        let detail = CognitoPoolDetail {
+            id: "ap-northeast-2_AbCdEf123".to_string(),
+            name: "customers".to_string(),
+            arn:
+                "arn:aws:cognito-idp:ap-northeast-2:123456789012:userpool/ap-northeast-2_AbCdEf123"
+                    .to_string(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            pool: CognitoPool::User(UserPoolDetail {
+                tier: "ESSENTIALS".to_string(),
+                estimated_users: 1200,
+                sign_in_attributes: vec!["email".to_string()],
+                deletion_protection: "ACTIVE".to_string(),
+                mfa: "OPTIONAL".to_string(),
+                mfa_methods: vec!["TOTP".to_string(), "SMS".to_string()],
+                domains: vec![
+                    UserPoolDomain {
+                        domain: "customers-login".to_string(),
+                        custom: false,
+                        certificate_arn: String::new(),
+                        cloudfront_distribution: String::new(),
+                        status: "ACTIVE".to_string(),
+                    },
+                    UserPoolDomain {
+                        domain: "auth.example.com".to_string(),
+                        custom: true,
+                        certificate_arn: "arn:aws:acm:us-east-1:123456789012:certificate/abc"
+                            .to_string(),
+                        cloudfront_distribution: "d111111abcdef8.cloudfront.net".to_string(),
+                        status: "ACTIVE".to_string(),
+                    },
+                ],
+                app_clients: vec![app_client("backend", false), app_client("web", true)],
+            }),
+            tags: vec![("Team".to_string(), "identity".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Cognito User Pool (customers - ap-northeast-2_AbCdEf123)"));
+        assert!(md.contains("| Sign-in Attributes | email |"));
+        assert!(md.contains("| MFA | OPTIONAL (TOTP, SMS) |"));
+        assert!(md.contains("| customers-login | Cognito domain (prefix) | - | - | ACTIVE |"));
+        assert!(md.contains(
+            "| auth.example.com | Custom domain | arn:aws:acm:us-east-1:123456789012:certificate/abc | d111111abcdef8.cloudfront.net | ACTIVE |"
+        ));
+        assert!(md.contains(
+            "| backend | backend-client-id | Enabled | USER_SRP_AUTH, REFRESH_TOKEN_AUTH | 1h / 1h / 30d |"
+        ));
+        // OAuth를 쓰지 않는 클라이언트는 리디렉션 섹션 없음
+        assert!(!md.contains("#### backend"));
+        assert!(md.contains("#### web"));
+        assert!(md.contains("| OAuth Scopes | openid, email |"));
+        assert!(md.contains(
+            "| Callback URLs | https://app.example.com/callback<br>http://localhost:3000/callback |"
+        ));
+        assert!(md.contains("| Sign-out URLs | - |"));
+        assert!(md.contains("| Team | identity |"));
+    }
+
+    #[test]
+    fn identity_pool_markdown_lists_roles_and_providers() {
+        let detail = CognitoPoolDetail {
+            id: "ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666".to_string(),
+            name: "mobile".to_string(),
+            arn: String::new(),
+            created: String::new(),
+            pool: CognitoPool::Identity(IdentityPoolDetail {
+                allow_unauthenticated: true,
+                allow_classic_flow: false,
+                providers: vec![
+                    IdentityProvider {
+                        kind: "Cognito".to_string(),
+                        name: "cognito-idp.ap-northeast-2.amazonaws.com/ap-northeast-2_AbCdEf123"
+                            .to_string(),
+                        client_id: "web-client-id".to_string(),
+                    },
+                    IdentityProvider {
+                        kind: "Social".to_string(),
+                        name: "accounts.google.com".to_string(),
+                        client_id: "1234.apps.googleusercontent.com".to_string(),
+                    },
+                ],
+                authenticated_role: "arn:aws:iam::123456789012:role/mobile-auth".to_string(),
+                unauthenticated_role: String::new(),
+            }),
+            tags: vec![],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Cognito Identity Pool (mobile - ap-northeast-2:"));
+        assert!(!md.contains("| ARN |"));
+        assert!(md.contains("| Guest Access | Enabled |"));
+        assert!(md.contains("| Basic (Classic) Auth Flow | Disabled |"));
+        assert!(md.contains("| Guest Role | - |"));
+        assert!(md.contains(
+            "| Cognito | cognito-idp.ap-northeast-2.amazonaws.com/ap-northeast-2_AbCdEf123 | web-client-id |"
+        ));
+        assert!(md.contains("| Social | accounts.google.com | 1234.apps.googleusercontent.com |"));
+    }
+}
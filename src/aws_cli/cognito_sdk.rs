@@ -0,0 +1,576 @@
+use crate::aws_cli::cognito::{
+    AppClient, CognitoPool, CognitoPoolDetail, IdentityPoolDetail, IdentityProvider,
+    UserPoolDetail, UserPoolDomain, auth_flow_label, is_identity_pool_id,
+};
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_cognitoidentity::operation::describe_identity_pool::DescribeIdentityPoolOutput;
+use aws_sdk_cognitoidentity::types::IdentityPoolShortDescription;
+use aws_sdk_cognitoidentityprovider::Client;
+use aws_sdk_cognitoidentityprovider::operation::get_user_pool_mfa_config::GetUserPoolMfaConfigOutput;
+use aws_sdk_cognitoidentityprovider::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_cognitoidentityprovider::types::{
+    TimeUnitsType, UserPoolClientType, UserPoolDescriptionType, UserPoolType,
+};
+use std::collections::HashMap;
+
+// 두 API 모두 페이지당 최대 60개
+const PAGE_SIZE: i32 = 60;
+
+/// List Cognito user pools and identity pools using AWS SDK
+pub fn list_cognito_pools() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cognito_pools_async())
+}
+
+async fn list_cognito_pools_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let user_pool_client = sdk_client!(aws_sdk_cognitoidentityprovider, &config);
+    let identity_pool_client = sdk_client!(aws_sdk_cognitoidentity, &config);
+
+    let mut resources = Vec::new();
+    match user_pool_client
+        .list_user_pools()
+        .max_results(PAGE_SIZE)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pools) => resources.extend(pools.iter().map(map_user_pool_resource)),
+        Err(e) => tracing::error!("Error listing Cognito user pools: {:?}", e),
+    }
+    match identity_pool_client
+        .list_identity_pools()
+        .max_results(PAGE_SIZE)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pools) => resources.extend(pools.iter().map(map_identity_pool_resource)),
+        Err(e) => tracing::error!("Error listing Cognito identity pools: {:?}", e),
+    }
+
+    // 사용자 풀 → 자격 증명 풀, 각각 이름 순
+    resources.sort_by(|a, b| b.state.cmp(&a.state).then_with(|| a.name.cmp(&b.name)));
+    resources
+}
+
+/// Get user pool (MFA, domains, app clients) or identity pool (providers, roles) detail using AWS SDK
+pub fn get_cognito_pool_detail(pool_id: &str) -> Option<CognitoPoolDetail> {
+    get_runtime().block_on(get_cognito_pool_detail_async(pool_id))
+}
+
+async fn get_cognito_pool_detail_async(pool_id: &str) -> Option<CognitoPoolDetail> {
+    let config = get_sdk_config().await;
+    if is_identity_pool_id(pool_id) {
+        get_identity_pool_detail(&config, pool_id).await
+    } else {
+        get_user_pool_detail(&config, pool_id).await
+    }
+}
+
+async fn get_user_pool_detail(
+    config: &aws_config::SdkConfig,
+    user_pool_id: &str,
+) -> Option<CognitoPoolDetail> {
+    let client = sdk_client!(aws_sdk_cognitoidentityprovider, config);
+
+    let output = match client
+        .describe_user_pool()
+        .user_pool_id(user_pool_id)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing user pool {}: {:?}", user_pool_id, e);
+            return None;
+        }
+    };
+    let user_pool = output.user_pool()?;
+
+    // MFA, 도메인, 앱 클라이언트 조회 실패는 해당 항목만 비워 둠
+    let mfa_methods = match client
+        .get_user_pool_mfa_config()
+        .user_pool_id(user_pool_id)
+        .send()
+        .await
+    {
+        Ok(output) => map_mfa_methods(&output),
+        Err(e) => {
+            tracing::warn!("Error getting MFA config for {}: {:?}", user_pool_id, e);
+            Vec::new()
+        }
+    };
+
+    let mut domains = Vec::new();
+    if let Some(domain) = user_pool.domain() {
+        domains.push(describe_domain(&client, domain, false).await);
+    }
+    if let Some(domain) = user_pool.custom_domain() {
+        domains.push(describe_domain(&client, domain, true).await);
+    }
+
+    let mut app_clients = Vec::new();
+    match client
+        .list_user_pool_clients()
+        .user_pool_id(user_pool_id)
+        .max_results(PAGE_SIZE)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(clients) => {
+            for description in &clients {
+                let client_id = description.client_id().unwrap_or_default();
+                match client
+                    .describe_user_pool_client()
+                    .user_pool_id(user_pool_id)
+                    .client_id(client_id)
+                    .send()
+                    .await
+                {
+                    Ok(output) => {
+                        if let Some(app_client) = output.user_pool_client() {
+                            app_clients.push(map_app_client(app_client));
+                        }
+                    }
+                    Err(e) => tracing::warn!("Error describing app client {}: {:?}", client_id, e),
+                }
+            }
+        }
+        Err(e) => tracing::warn!("Error listing app clients for {}: {:?}", user_pool_id, e),
+    }
+    app_clients.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Some(map_user_pool_detail(
+        user_pool,
+        mfa_methods,
+        domains,
+        app_clients,
+    ))
+}
+
+async fn describe_domain(client: &Client, domain: &str, custom: bool) -> UserPoolDomain {
+    let mut detail = UserPoolDomain {
+        domain: domain.to_string(),
+        custom,
+        certificate_arn: String::new(),
+        cloudfront_distribution: String::new(),
+        status: String::new(),
+    };
+    match client
+        .describe_user_pool_domain()
+        .domain(domain)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            if let Some(description) = output.domain_description() {
+                detail.status = description
+                    .status()
+                    .map(|status| status.as_str().to_string())
+                    .unwrap_or_default();
+                // 접두사 도메인의 CloudFront 배포는 AWS가 관리하므로 사용자 지정 도메인만 표시
+                if custom {
+                    detail.certificate_arn = description
+                        .custom_domain_config()
+                        .map(|config| config.certificate_arn().to_string())
+                        .unwrap_or_default();
+                    detail.cloudfront_distribution = description
+                        .cloud_front_distribution()
+                        .unwrap_or_default()
+                        .to_string();
+                }
+            }
+        }
+        Err(e) => tracing::warn!("Error describing user pool domain {}: {:?}", domain, e),
+    }
+    detail
+}
+
+async fn get_identity_pool_detail(
+    config: &aws_config::SdkConfig,
+    identity_pool_id: &str,
+) -> Option<CognitoPoolDetail> {
+    let client = sdk_client!(aws_sdk_cognitoidentity, config);
+
+    let output = match client
+        .describe_identity_pool()
+        .identity_pool_id(identity_pool_id)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!(
+                "Error describing identity pool {}: {:?}",
+                identity_pool_id,
+                e
+            );
+            return None;
+        }
+    };
+
+    let roles = match client
+        .get_identity_pool_roles()
+        .identity_pool_id(identity_pool_id)
+        .send()
+        .await
+    {
+        Ok(output) => output.roles().cloned().unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!(
+                "Error getting identity pool roles for {}: {:?}",
+                identity_pool_id,
+                e
+            );
+            HashMap::new()
+        }
+    };
+
+    Some(map_identity_pool_detail(&output, &roles))
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn sorted_tags(tags: Option<&HashMap<String, String>>) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .map(|tags| {
+            tags.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    tags.sort();
+    tags
+}
+
+// 목록에는 풀 종류(state)와 생성일(cidr)을 표시
+fn map_user_pool_resource(pool: &UserPoolDescriptionType) -> AwsResource {
+    AwsResource {
+        name: pool.name().unwrap_or_default().to_string(),
+        id: pool.id().unwrap_or_default().to_string(),
+        state: "User Pool".to_string(),
+        az: String::new(),
+        cidr: format_timestamp(pool.creation_date())
+            .split('T')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_identity_pool_resource(pool: &IdentityPoolShortDescription) -> AwsResource {
+    AwsResource {
+        name: pool.identity_pool_name().unwrap_or_default().to_string(),
+        id: pool.identity_pool_id().unwrap_or_default().to_string(),
+        state: "Identity Pool".to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_mfa_methods(output: &GetUserPoolMfaConfigOutput) -> Vec<String> {
+    let mut methods = Vec::new();
+    if output
+        .software_token_mfa_configuration()
+        .is_some_and(|config| config.enabled())
+    {
+        methods.push("TOTP".to_string());
+    }
+    if output.sms_mfa_configuration().is_some() {
+        methods.push("SMS".to_string());
+    }
+    if output.email_mfa_configuration().is_some() {
+        methods.push("EMAIL".to_string());
+    }
+    methods
+}
+
+fn map_user_pool_detail(
+    user_pool: &UserPoolType,
+    mfa_methods: Vec<String>,
+    domains: Vec<UserPoolDomain>,
+    app_clients: Vec<AppClient>,
+) -> CognitoPoolDetail {
+    let mfa = user_pool
+        .mfa_configuration()
+        .map(|mfa| mfa.as_str())
+        .unwrap_or("OFF")
+        .to_string();
+    CognitoPoolDetail {
+        id: user_pool.id().unwrap_or_default().to_string(),
+        name: user_pool.name().unwrap_or_default().to_string(),
+        arn: user_pool.arn().unwrap_or_default().to_string(),
+        created: format_timestamp(user_pool.creation_date()),
+        pool: CognitoPool::User(UserPoolDetail {
+            tier: user_pool
+                .user_pool_tier()
+                .map(|tier| tier.as_str().to_string())
+                .unwrap_or_default(),
+            estimated_users: user_pool.estimated_number_of_users(),
+            sign_in_attributes: user_pool
+                .username_attributes()
+                .iter()
+                .map(|attribute| attribute.as_str().to_string())
+                .collect(),
+            deletion_protection: user_pool
+                .deletion_protection()
+                .map(|protection| protection.as_str().to_string())
+                .unwrap_or_default(),
+            // MFA를 끈 풀은 설정이 남아 있어도 방식을 표시하지 않음
+            mfa_methods: if mfa == "OFF" {
+                Vec::new()
+            } else {
+                mfa_methods
+            },
+            mfa,
+            domains,
+            app_clients,
+        }),
+        tags: sorted_tags(user_pool.user_pool_tags()),
+    }
+}
+
+// 단위를 지정하지 않은 경우 액세스/ID 토큰은 시간, 새로 고침 토큰은 일 단위
+fn token_validity(value: Option<i32>, unit: Option<&TimeUnitsType>, default_unit: &str) -> String {
+    let Some(value) = value.filter(|value| *value > 0) else {
+        return "-".to_string();
+    };
+    let unit = match unit {
+        Some(TimeUnitsType::Seconds) => "s",
+        Some(TimeUnitsType::Minutes) => "m",
+        Some(TimeUnitsType::Hours) => "h",
+        Some(TimeUnitsType::Days) => "d",
+        _ => default_unit,
+    };
+    format!("{}{}", value, unit)
+}
+
+fn map_app_client(client: &UserPoolClientType) -> AppClient {
+    let units = client.token_validity_units();
+    let strings = |values: &[String]| values.to_vec();
+    AppClient {
+        name: client.client_name().unwrap_or_default().to_string(),
+        id: client.client_id().unwrap_or_default().to_string(),
+        has_secret: client
+            .client_secret()
+            .is_some_and(|secret| !secret.is_empty()),
+        auth_flows: client
+            .explicit_auth_flows()
+            .iter()
+            .map(|flow| auth_flow_label(flow.as_str()))
+            .collect(),
+        oauth_flows: client
+            .allowed_o_auth_flows()
+            .iter()
+            .map(|flow| flow.as_str().to_string())
+            .collect(),
+        oauth_scopes: strings(client.allowed_o_auth_scopes()),
+        identity_providers: strings(client.supported_identity_providers()),
+        callback_urls: strings(client.callback_urls()),
+        logout_urls: strings(client.logout_urls()),
+        access_token_validity: token_validity(
+            client.access_token_validity(),
+            units.and_then(|units| units.access_token()),
+            "h",
+        ),
+        id_token_validity: token_validity(
+            client.id_token_validity(),
+            units.and_then(|units| units.id_token()),
+            "h",
+        ),
+        refresh_token_validity: token_validity(
+            Some(client.refresh_token_validity()),
+            units.and_then(|units| units.refresh_token()),
+            "d",
+        ),
+    }
+}
+
+fn map_identity_pool_detail(
+    output: &DescribeIdentityPoolOutput,
+    roles: &HashMap<String, String>,
+) -> CognitoPoolDetail {
+    let mut providers: Vec<IdentityProvider> = output
+        .cognito_identity_providers()
+        .iter()
+        .map(|provider| IdentityProvider {
+            kind: "Cognito".to_string(),
+            name: provider.provider_name().unwrap_or_default().to_string(),
+            client_id: provider.client_id().unwrap_or_default().to_string(),
+        })
+        .collect();
+    // 소셜 로그인은 공급자 도메인 → 앱 ID
+    let mut social: Vec<(&String, &String)> = output
+        .supported_login_providers()
+        .map(|providers| providers.iter().collect())
+        .unwrap_or_default();
+    social.sort();
+    providers.extend(social.into_iter().map(|(name, app_id)| IdentityProvider {
+        kind: "Social".to_string(),
+        name: name.clone(),
+        client_id: app_id.clone(),
+    }));
+    for (kind, arns) in [
+        ("OIDC", output.open_id_connect_provider_arns()),
+        ("SAML", output.saml_provider_arns()),
+    ] {
+        providers.extend(arns.iter().map(|arn| IdentityProvider {
+            kind: kind.to_string(),
+            name: arn.clone(),
+            client_id: String::new(),
+        }));
+    }
+    if let Some(developer) = output.developer_provider_name() {
+        providers.push(IdentityProvider {
+            kind: "Developer".to_string(),
+            name: developer.to_string(),
+            client_id: String::new(),
+        });
+    }
+
+    let role = |key: &str| roles.get(key).cloned().unwrap_or_default();
+    CognitoPoolDetail {
+        id: output.identity_pool_id().to_string(),
+        name: output.identity_pool_name().to_string(),
+        arn: String::new(),
+        created: String::new(),
+        pool: CognitoPool::Identity(IdentityPoolDetail {
+            allow_unauthenticated: output.allow_unauthenticated_identities(),
+            allow_classic_flow: output.allow_classic_flow().unwrap_or(false),
+            providers,
+            authenticated_role: role("authenticated"),
+            unauthenticated_role: role("unauthenticated"),
+        }),
+        tags: sorted_tags(output.identity_pool_tags()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_app_client, map_identity_pool_resource, map_mfa_methods, map_user_pool_detail,
+        map_user_pool_resource,
+    };
+    use crate::aws_cli::cognito::CognitoPool;
+    use aws_sdk_cognitoidentity::types::IdentityPoolShortDescription;
+    use aws_sdk_cognitoidentityprovider::operation::get_user_pool_mfa_config::GetUserPoolMfaConfigOutput;
+    use aws_sdk_cognitoidentityprovider::primitives::DateTime;
+    use aws_sdk_cognitoidentityprovider::types::{
+        ExplicitAuthFlowsType, OAuthFlowType, SmsMfaConfigType, SoftwareTokenMfaConfigType,
+        TimeUnitsType, TokenValidityUnitsType, UserPoolClientType, UserPoolDescriptionType,
+        UserPoolMfaType, UserPoolType, UsernameAttributeType,
+    };
+
+    #[test]
+    fn map_pool_resources_mark_kind() {
+        let user_pool = map_user_pool_resource(
+            &UserPoolDescriptionType::builder()
+                .id("ap-northeast-2_AbCdEf123")
+                .name("customers")
+                .creation_date(DateTime::from_secs(1_767_225_600))
+                .build(),
+        );
+        assert_eq!(user_pool.id, "ap-northeast-2_AbCdEf123");
+        assert_eq!(user_pool.state, "User Pool");
+        assert_eq!(user_pool.cidr, "2026-01-01");
+
+        let identity_pool = map_identity_pool_resource(
+            &IdentityPoolShortDescription::builder()
+                .identity_pool_id("ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666")
+                .identity_pool_name("mobile")
+                .build(),
+        );
+        assert_eq!(identity_pool.name, "mobile");
+        assert_eq!(identity_pool.state, "Identity Pool");
+    }
+
+    #[test]
+    fn map_user_pool_detail_reads_mfa_and_sign_in_attributes() {
+        let mfa_config = GetUserPoolMfaConfigOutput::builder()
+            .mfa_configuration(UserPoolMfaType::Optional)
+            .software_token_mfa_configuration(
+                SoftwareTokenMfaConfigType::builder().enabled(true).build(),
+            )
+            .sms_mfa_configuration(SmsMfaConfigType::builder().build())
+            .build();
+        let methods = map_mfa_methods(&mfa_config);
+        assert_eq!(methods, ["TOTP", "SMS"]);
+
+        let user_pool = UserPoolType::builder()
+            .id("ap-northeast-2_AbCdEf123")
+            .name("customers")
+            .mfa_configuration(UserPoolMfaType::Optional)
+            .estimated_number_of_users(1200)
+            .username_attributes(UsernameAttributeType::Email)
+            .user_pool_tags("Team", "identity")
+            .build();
+        let detail = map_user_pool_detail(&user_pool, methods.clone(), vec![], vec![]);
+        assert_eq!(detail.tags, [("Team".to_string(), "identity".to_string())]);
+        let CognitoPool::User(pool) = &detail.pool else {
+            panic!("user pool expected");
+        };
+        assert_eq!(pool.mfa, "OPTIONAL");
+        assert_eq!(pool.mfa_methods, ["TOTP", "SMS"]);
+        assert_eq!(pool.sign_in_attributes, ["email"]);
+        assert_eq!(pool.estimated_users, 1200);
+
+        // MFA가 꺼져 있으면 남아 있는 방식 설정은 무시
+        let detail = map_user_pool_detail(
+            &UserPoolType::builder().id("ap-northeast-2_Off").build(),
+            methods,
+            vec![],
+            vec![],
+        );
+        let CognitoPool::User(pool) = &detail.pool else {
+            panic!("user pool expected");
+        };
+        assert_eq!(pool.mfa, "OFF");
+        assert!(pool.mfa_methods.is_empty());
+    }
+
+    #[test]
+    fn map_app_client_hides_secret_and_formats_token_validity() {
+        let client = UserPoolClientType::builder()
+            .client_name("web")
+            .client_id("web-client-id")
+            .client_secret("super-secret")
+            .explicit_auth_flows(ExplicitAuthFlowsType::AllowUserSrpAuth)
+            .explicit_auth_flows(ExplicitAuthFlowsType::AllowRefreshTokenAuth)
+            .allowed_o_auth_flows(OAuthFlowType::Code)
+            .allowed_o_auth_scopes("openid")
+            .callback_urls("https://app.example.com/callback")
+            .access_token_validity(60)
+            .refresh_token_validity(30)
+            .token_validity_units(
+                TokenValidityUnitsType::builder()
+                    .access_token(TimeUnitsType::Minutes)
+                    .build(),
+            )
+            .build();
+
+        let app_client = map_app_client(&client);
+        assert!(app_client.has_secret);
+        assert_eq!(
+            app_client.auth_flows,
+            ["USER_SRP_AUTH", "REFRESH_TOKEN_AUTH"]
+        );
+        assert_eq!(app_client.oauth_flows, ["code"]);
+        assert_eq!(app_client.access_token_validity, "60m");
+        assert_eq!(app_client.id_token_validity, "-");
+        assert_eq!(app_client.refresh_token_validity, "30d");
+        assert!(!format!("{:?}", app_client).contains("super-secret"));
+    }
+}
@@ -1,12 +1,32 @@
+use crate::aws_cli::partition::Partition;
 use aws_credential_types::provider::ProvideCredentials;
-use serde::Deserialize;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 use tokio::runtime::Runtime;
 
+/// `Client::new(&config)` with the read-only guard and session audit interceptors attached.
+macro_rules! sdk_client {
+    ($sdk:ident, $config:expr) => {
+        $sdk::Client::from_conf(
+            $sdk::config::Builder::from($config)
+                .interceptor($crate::aws_cli::read_only::ReadOnlyInterceptor)
+                .interceptor($crate::aws_cli::audit::AuditInterceptor)
+                .build(),
+        )
+    };
+}
+pub(crate) use sdk_client;
+
 static REGION: Mutex<Option<String>> = Mutex::new(None);
+// 로그인 확인 시 조회한 계정 ID (공유 리소스 소유자 구분용)
+static ACCOUNT_ID: Mutex<Option<String>> = Mutex::new(None);
+// 로그인 확인 시 호출자 ARN에서 읽은 파티션 (GovCloud/중국 리전 목록과 콘솔 링크용)
+static PARTITION: Mutex<Option<Partition>> = Mutex::new(None);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AwsAuthErrorCode {
@@ -42,6 +62,54 @@ pub fn set_region(region: &str) {
     }
 }
 
+pub(crate) fn current_region() -> String {
+    REGION
+        .lock()
+        .ok()
+        .and_then(|r| r.clone())
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_account_id(account: &str) {
+    if let Ok(mut a) = ACCOUNT_ID.lock() {
+        *a = Some(account.to_string());
+    }
+}
+
+pub(crate) fn set_partition(partition: Partition) {
+    if let Ok(mut p) = PARTITION.lock() {
+        *p = Some(partition);
+    }
+}
+
+/// Partition of the signed-in account; before the login check, the one of the selected region.
+pub fn current_partition() -> Partition {
+    PARTITION
+        .lock()
+        .ok()
+        .and_then(|p| *p)
+        .unwrap_or_else(|| Partition::from_region(&current_region()))
+}
+
+pub(crate) fn current_account_id() -> Option<String> {
+    ACCOUNT_ID
+        .lock()
+        .ok()
+        .and_then(|a| a.clone())
+        .filter(|account| !account.is_empty())
+}
+
+/// Owner account worth surfacing for a resource: `None` when it belongs to
+/// the signed-in account or when either side is unknown.
+pub(crate) fn foreign_owner(owner_id: &str) -> Option<&str> {
+    let account = ACCOUNT_ID.lock().ok().and_then(|a| a.clone())?;
+    if owner_id.is_empty() || account.is_empty() || owner_id == account {
+        None
+    } else {
+        Some(owner_id)
+    }
+}
+
 pub fn set_aws_profile(profile: &str) {
     let profile = profile.trim();
     if profile.is_empty() {
@@ -122,21 +190,27 @@ fn parse_profile_sections(contents: &str, from_config: bool, profiles: &mut BTre
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AwsResource {
     pub name: String,
     pub id: String,
     pub state: String,
     pub az: String,
     pub cidr: String,
+    // 다른 계정에서 공유된 리소스를 구분하기 위한 소유 계정 ID (모르면 빈 문자열)
+    pub owner_id: String,
 }
 
 impl AwsResource {
     pub fn display(&self) -> String {
-        if self.name.is_empty() {
+        let base = if self.name.is_empty() {
             self.id.clone()
         } else {
             format!("{} ({})", self.name, self.id)
+        };
+        match foreign_owner(&self.owner_id) {
+            Some(owner) => format!("{} [owner: {}]", base, owner),
+            None => base,
         }
     }
 }
@@ -302,16 +376,21 @@ pub fn check_aws_login() -> Result<String, AwsAuthError> {
         }
         tracing::debug!("AWS credential provider returned credentials");
 
-        let client = aws_sdk_sts::Client::new(&config);
+        let client = sdk_client!(aws_sdk_sts, &config);
 
         match client.get_caller_identity().send().await {
             Ok(output) => {
                 let account = output.account().unwrap_or_default();
                 let arn = output.arn().unwrap_or_default();
+                set_account_id(account);
+                if let Some(partition) = Partition::from_arn(arn) {
+                    set_partition(partition);
+                }
                 let elapsed_ms = started_at.elapsed().as_millis();
                 tracing::info!(
                     account = %account,
                     arn = %arn,
+                    partition = %current_partition().as_str(),
                     profile = %profile,
                     elapsed_ms = elapsed_ms,
                     "AWS caller identity verified"
@@ -385,6 +464,20 @@ fn arg_value<'a>(args: &'a [&str], flag: &str) -> Option<&'a str> {
         .find_map(|pair| (pair[0] == flag).then_some(pair[1]))
 }
 
+// `--volume-ids vol-1 vol-2`처럼 다음 플래그 전까지의 값을 모두 수집
+fn arg_values<'a>(args: &'a [&str], flag: &str) -> Vec<&'a str> {
+    args.iter()
+        .position(|arg| *arg == flag)
+        .map(|index| {
+            args[index + 1..]
+                .iter()
+                .take_while(|arg| !arg.starts_with("--"))
+                .copied()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn parse_filter_value(raw: &str, expected_name: &str) -> Option<String> {
     let segments = raw.split(',').collect::<Vec<_>>();
     let mut name = None;
@@ -431,6 +524,15 @@ fn parse_tags_ec2(tags: &[aws_sdk_ec2::types::Tag]) -> Vec<Value> {
         .collect()
 }
 
+// CLI 출력과 같은 ISO 8601 형식 (값이 없으면 빈 문자열)
+fn ec2_timestamp(time: Option<&aws_sdk_ec2::primitives::DateTime>) -> String {
+    time.and_then(|t| {
+        t.fmt(aws_sdk_ec2::primitives::DateTimeFormat::DateTime)
+            .ok()
+    })
+    .unwrap_or_default()
+}
+
 fn parse_tags_iam(tags: &[aws_sdk_iam::types::Tag]) -> Vec<Value> {
     tags.iter()
         .map(|t| {
@@ -453,53 +555,121 @@ async fn run_ec2_request(
 ) -> Option<String> {
     match operation {
         "describe-instances" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_instances(&client, args).await
         }
+        "describe-instance-types" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_instance_types(&client, args).await
+        }
         "describe-volumes" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_volumes(&client, args).await
         }
+        "describe-snapshots" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_snapshots(&client, args).await
+        }
         "describe-instance-attribute" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_instance_attribute(&client, args).await
         }
         "describe-vpcs" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_vpcs(&client, args).await
         }
         "describe-subnets" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_subnets(&client, args).await
         }
         "describe-internet-gateways" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_internet_gateways(&client, args).await
         }
         "describe-nat-gateways" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_nat_gateways(&client, args).await
         }
         "describe-route-tables" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_route_tables(&client, args).await
         }
         "describe-addresses" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_addresses(&client).await
         }
         "describe-vpc-attribute" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_vpc_attribute(&client, args).await
         }
         "describe-security-groups" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_security_groups(&client, args).await
         }
         "describe-images" => {
-            let client = aws_sdk_ec2::Client::new(config);
+            let client = sdk_client!(aws_sdk_ec2, config);
             ec2_describe_images(&client, args).await
         }
+        "describe-image-attribute" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_image_attribute(&client, args).await
+        }
+        "describe-key-pairs" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_key_pairs(&client, args).await
+        }
+        "describe-transit-gateways" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_transit_gateways(&client, args).await
+        }
+        "describe-transit-gateway-attachments" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_transit_gateway_attachments(&client, args).await
+        }
+        "describe-transit-gateway-route-tables" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_transit_gateway_route_tables(&client, args).await
+        }
+        "search-transit-gateway-routes" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_search_transit_gateway_routes(&client, args).await
+        }
+        "describe-vpc-peering-connections" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_vpc_peering_connections(&client, args).await
+        }
+        "describe-launch-templates" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_launch_templates(&client, args).await
+        }
+        "describe-launch-template-versions" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_launch_template_versions(&client, args).await
+        }
+        "describe-network-acls" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_network_acls(&client, args).await
+        }
+        "describe-network-interfaces" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_network_interfaces(&client, args).await
+        }
+        "describe-vpc-endpoints" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_vpc_endpoints(&client, args).await
+        }
+        "describe-vpn-connections" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_vpn_connections(&client, args).await
+        }
+        "describe-customer-gateways" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_customer_gateways(&client, args).await
+        }
+        "describe-vpn-gateways" => {
+            let client = sdk_client!(aws_sdk_ec2, config);
+            ec2_describe_vpn_gateways(&client, args).await
+        }
         _ => None,
     }
 }
@@ -507,7 +677,7 @@ async fn run_ec2_request(
 async fn ec2_describe_instances(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
     let mut req = client.describe_instances();
 
-    if let Some(instance_id) = arg_value(args, "--instance-ids") {
+    for instance_id in arg_values(args, "--instance-ids") {
         req = req.instance_ids(instance_id);
     }
 
@@ -621,6 +791,10 @@ fn ec2_describe_instances_output(
                     json!({
                         "InstanceId": instance.instance_id().unwrap_or_default(),
                         "InstanceType": instance.instance_type().map(|v| v.as_str()).unwrap_or_default(),
+                        "CpuOptions": {
+                            "CoreCount": instance.cpu_options().and_then(|c| c.core_count()),
+                            "ThreadsPerCore": instance.cpu_options().and_then(|c| c.threads_per_core())
+                        },
                         "ImageId": instance.image_id().unwrap_or_default(),
                         "Platform": instance.platform().map(|v| v.as_str()).unwrap_or("Linux"),
                         "Architecture": instance.architecture().map(|v| v.as_str()).unwrap_or("x86_64"),
@@ -685,14 +859,68 @@ fn ec2_describe_instances_output(
     value_to_json_string(json!({ "Reservations": reservations }))
 }
 
+async fn ec2_describe_instance_types(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_instance_types();
+    for instance_type in arg_values(args, "--instance-types") {
+        req = req.instance_types(aws_sdk_ec2::types::InstanceType::from(instance_type));
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-instance-types API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_instance_types_output(output.instance_types())
+}
+
+fn ec2_describe_instance_types_output(
+    instance_types: &[aws_sdk_ec2::types::InstanceTypeInfo],
+) -> Option<String> {
+    let instance_types = instance_types
+        .iter()
+        .map(|info| {
+            json!({
+                "InstanceType": info.instance_type().map(|t| t.as_str()).unwrap_or_default(),
+                "VCpuInfo": {
+                    "DefaultVCpus": info.v_cpu_info().and_then(|v| v.default_v_cpus())
+                },
+                "MemoryInfo": {
+                    "SizeInMiB": info.memory_info().and_then(|m| m.size_in_mib())
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "InstanceTypes": instance_types }))
+}
+
 async fn ec2_describe_volumes(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
-    let volume_id = arg_value(args, "--volume-ids")?;
-    let output = client
-        .describe_volumes()
-        .volume_ids(volume_id)
-        .send()
-        .await
-        .ok()?;
+    // ID 없이 호출하면 계정의 모든 볼륨 (연결되지 않은 볼륨 포함)
+    let mut req = client.describe_volumes();
+    for volume_id in arg_values(args, "--volume-ids") {
+        req = req.volume_ids(volume_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-volumes API call failed"
+            );
+            return None;
+        }
+    };
     ec2_describe_volumes_output(output.volumes())
 }
 
@@ -700,12 +928,35 @@ fn ec2_describe_volumes_output(volumes: &[aws_sdk_ec2::types::Volume]) -> Option
     let volumes = volumes
         .iter()
         .map(|volume| {
+            let attachments = volume
+                .attachments()
+                .iter()
+                .map(|attachment| {
+                    json!({
+                        "InstanceId": attachment.instance_id().unwrap_or_default(),
+                        "Device": attachment.device().unwrap_or_default(),
+                        "State": attachment.state().map(|s| s.as_str()).unwrap_or_default(),
+                        "DeleteOnTermination": attachment.delete_on_termination().unwrap_or(false),
+                        "AttachTime": ec2_timestamp(attachment.attach_time())
+                    })
+                })
+                .collect::<Vec<_>>();
+
             json!({
                 "VolumeId": volume.volume_id().unwrap_or_default(),
                 "Size": volume.size().unwrap_or_default(),
                 "VolumeType": volume.volume_type().map(|v| v.as_str()).unwrap_or("unknown"),
                 "Iops": volume.iops().unwrap_or_default(),
-                "Encrypted": volume.encrypted().unwrap_or(false)
+                "Throughput": volume.throughput(),
+                "Encrypted": volume.encrypted().unwrap_or(false),
+                "KmsKeyId": volume.kms_key_id().unwrap_or_default(),
+                "AvailabilityZone": volume.availability_zone().unwrap_or_default(),
+                "State": volume.state().map(|s| s.as_str()).unwrap_or_default(),
+                "SnapshotId": volume.snapshot_id().unwrap_or_default(),
+                "CreateTime": ec2_timestamp(volume.create_time()),
+                "MultiAttachEnabled": volume.multi_attach_enabled().unwrap_or(false),
+                "Attachments": attachments,
+                "Tags": parse_tags_ec2(volume.tags())
             })
         })
         .collect::<Vec<_>>();
@@ -713,6 +964,60 @@ fn ec2_describe_volumes_output(volumes: &[aws_sdk_ec2::types::Volume]) -> Option
     value_to_json_string(json!({ "Volumes": volumes }))
 }
 
+async fn ec2_describe_snapshots(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
+    let snapshot_ids = arg_values(args, "--snapshot-ids");
+    let owner_ids = arg_values(args, "--owner-ids");
+    // 조건 없이 호출하면 모든 공개 스냅샷이 내려옴
+    if snapshot_ids.is_empty() && owner_ids.is_empty() {
+        return None;
+    }
+
+    let mut req = client.describe_snapshots();
+    for snapshot_id in snapshot_ids {
+        req = req.snapshot_ids(snapshot_id);
+    }
+    for owner_id in owner_ids {
+        req = req.owner_ids(owner_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-snapshots API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_snapshots_output(output.snapshots())
+}
+
+fn ec2_describe_snapshots_output(snapshots: &[aws_sdk_ec2::types::Snapshot]) -> Option<String> {
+    let snapshots = snapshots
+        .iter()
+        .map(|snapshot| {
+            json!({
+                "SnapshotId": snapshot.snapshot_id().unwrap_or_default(),
+                "VolumeId": snapshot.volume_id().unwrap_or_default(),
+                "VolumeSize": snapshot.volume_size().unwrap_or_default(),
+                "State": snapshot.state().map(|s| s.as_str()).unwrap_or_default(),
+                "Progress": snapshot.progress().unwrap_or_default(),
+                "StartTime": ec2_timestamp(snapshot.start_time()),
+                "Description": snapshot.description().unwrap_or_default(),
+                "Encrypted": snapshot.encrypted().unwrap_or(false),
+                "KmsKeyId": snapshot.kms_key_id().unwrap_or_default(),
+                "OwnerId": snapshot.owner_id().unwrap_or_default(),
+                "StorageTier": snapshot.storage_tier().map(|t| t.as_str()).unwrap_or_default(),
+                "Tags": parse_tags_ec2(snapshot.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "Snapshots": snapshots }))
+}
+
 async fn ec2_describe_instance_attribute(
     client: &aws_sdk_ec2::Client,
     args: &[&str],
@@ -764,10 +1069,16 @@ fn ec2_describe_vpcs_output(
     vpcs: &[aws_sdk_ec2::types::Vpc],
     query: Option<&str>,
 ) -> Option<String> {
-    if query == Some("Vpcs[*].[VpcId,Tags]") {
+    if query == Some("Vpcs[*].[VpcId,Tags,OwnerId]") {
         let mut rows = vpcs
             .iter()
-            .map(|vpc| json!([vpc.vpc_id().unwrap_or_default(), parse_tags_ec2(vpc.tags())]))
+            .map(|vpc| {
+                json!([
+                    vpc.vpc_id().unwrap_or_default(),
+                    parse_tags_ec2(vpc.tags()),
+                    vpc.owner_id().unwrap_or_default()
+                ])
+            })
             .collect::<Vec<_>>();
 
         rows.sort_by(|a, b| {
@@ -785,6 +1096,26 @@ fn ec2_describe_vpcs_output(
                 "VpcId": vpc.vpc_id().unwrap_or_default(),
                 "CidrBlock": vpc.cidr_block().unwrap_or_default(),
                 "State": vpc.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "OwnerId": vpc.owner_id().unwrap_or_default(),
+                "Ipv6CidrBlockAssociationSet": vpc
+                    .ipv6_cidr_block_association_set()
+                    .iter()
+                    .map(|association| {
+                        json!({
+                            "AssociationId": association.association_id().unwrap_or_default(),
+                            "Ipv6CidrBlock": association.ipv6_cidr_block().unwrap_or_default(),
+                            "Ipv6CidrBlockState": {
+                                "State": association
+                                    .ipv6_cidr_block_state()
+                                    .and_then(|state| state.state())
+                                    .map(|state| state.as_str())
+                                    .unwrap_or_default()
+                            },
+                            "NetworkBorderGroup": association.network_border_group().unwrap_or_default(),
+                            "Ipv6Pool": association.ipv6_pool().unwrap_or_default()
+                        })
+                    })
+                    .collect::<Vec<_>>(),
                 "Tags": parse_tags_ec2(vpc.tags())
             })
         })
@@ -830,6 +1161,26 @@ fn ec2_describe_subnets_output(subnets: &[aws_sdk_ec2::types::Subnet]) -> Option
                 "State": subnet.state().map(|s| s.as_str()).unwrap_or("unknown"),
                 "MapPublicIpOnLaunch": subnet.map_public_ip_on_launch().unwrap_or(false),
                 "AvailableIpAddressCount": subnet.available_ip_address_count().unwrap_or_default(),
+                "OwnerId": subnet.owner_id().unwrap_or_default(),
+                "Ipv6CidrBlockAssociationSet": subnet
+                    .ipv6_cidr_block_association_set()
+                    .iter()
+                    .map(|association| {
+                        json!({
+                            "AssociationId": association.association_id().unwrap_or_default(),
+                            "Ipv6CidrBlock": association.ipv6_cidr_block().unwrap_or_default(),
+                            "Ipv6CidrBlockState": {
+                                "State": association
+                                    .ipv6_cidr_block_state()
+                                    .and_then(|state| state.state())
+                                    .map(|state| state.as_str())
+                                    .unwrap_or_default()
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+                "AssignIpv6AddressOnCreation": subnet.assign_ipv6_address_on_creation().unwrap_or(false),
+                "Ipv6Native": subnet.ipv6_native().unwrap_or(false),
                 "Tags": parse_tags_ec2(subnet.tags())
             })
         })
@@ -1037,8 +1388,10 @@ fn ec2_describe_route_tables_output(
                     .map(|route| {
                         json!({
                             "DestinationCidrBlock": route.destination_cidr_block().unwrap_or_default(),
+                            "DestinationIpv6CidrBlock": route.destination_ipv6_cidr_block().unwrap_or_default(),
                             "GatewayId": route.gateway_id().unwrap_or_default(),
                             "NatGatewayId": route.nat_gateway_id().unwrap_or_default(),
+                            "EgressOnlyInternetGatewayId": route.egress_only_internet_gateway_id().unwrap_or_default(),
                             "State": route.state().map(|s| s.as_str()).unwrap_or("active")
                         })
                     })
@@ -1075,8 +1428,10 @@ fn ec2_describe_route_tables_output(
                 .map(|route| {
                     json!({
                         "DestinationCidrBlock": route.destination_cidr_block().unwrap_or_default(),
+                        "DestinationIpv6CidrBlock": route.destination_ipv6_cidr_block().unwrap_or_default(),
                         "GatewayId": route.gateway_id().unwrap_or_default(),
                         "NatGatewayId": route.nat_gateway_id().unwrap_or_default(),
+                        "EgressOnlyInternetGatewayId": route.egress_only_internet_gateway_id().unwrap_or_default(),
                         "State": route.state().map(|s| s.as_str()).unwrap_or("active")
                     })
                 })
@@ -1254,13 +1609,32 @@ fn ec2_describe_security_groups_output(
 }
 
 async fn ec2_describe_images(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
-    let image_id = arg_value(args, "--image-ids")?;
-    let output = client
-        .describe_images()
-        .image_ids(image_id)
-        .send()
-        .await
-        .ok()?;
+    let image_ids = arg_values(args, "--image-ids");
+    let owners = arg_values(args, "--owners");
+    // 조건 없이 호출하면 모든 공개 AMI가 내려옴
+    if image_ids.is_empty() && owners.is_empty() {
+        return None;
+    }
+
+    let mut req = client.describe_images();
+    for image_id in image_ids {
+        req = req.image_ids(image_id);
+    }
+    for owner in owners {
+        req = req.owners(owner);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-images API call failed"
+            );
+            return None;
+        }
+    };
     ec2_describe_images_output(output.images())
 }
 
@@ -1280,14 +1654,1049 @@ fn ec2_describe_images_output(images: &[aws_sdk_ec2::types::Image]) -> Option<St
             }
 
             json!({
-                "ImageId": image.image_id().unwrap_or_default(),
-                "Name": image.name().unwrap_or_default(),
-                "Tags": tags
+                "ImageId": image.image_id().unwrap_or_default(),
+                "Name": image.name().unwrap_or_default(),
+                "Description": image.description().unwrap_or_default(),
+                "OwnerId": image.owner_id().unwrap_or_default(),
+                "State": image.state().map(|s| s.as_str()).unwrap_or_default(),
+                "CreationDate": image.creation_date().unwrap_or_default(),
+                "SourceInstanceId": image.source_instance_id().unwrap_or_default(),
+                "Architecture": image.architecture().map(|a| a.as_str()).unwrap_or_default(),
+                "PlatformDetails": image.platform_details().unwrap_or_default(),
+                "RootDeviceName": image.root_device_name().unwrap_or_default(),
+                "Public": image.public().unwrap_or(false),
+                "DeprecationTime": image.deprecation_time().unwrap_or_default(),
+                "BlockDeviceMappings": ec2_block_device_mappings(image.block_device_mappings()),
+                "Tags": tags
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "Images": images }))
+}
+
+fn ec2_block_device_mappings(mappings: &[aws_sdk_ec2::types::BlockDeviceMapping]) -> Vec<Value> {
+    mappings
+        .iter()
+        .map(|mapping| {
+            let ebs = mapping.ebs().map(|ebs| {
+                json!({
+                    "VolumeSize": ebs.volume_size(),
+                    "VolumeType": ebs.volume_type().map(|t| t.as_str()).unwrap_or_default(),
+                    "Iops": ebs.iops(),
+                    "Encrypted": ebs.encrypted().unwrap_or(false),
+                    "DeleteOnTermination": ebs.delete_on_termination().unwrap_or(false),
+                    "SnapshotId": ebs.snapshot_id().unwrap_or_default()
+                })
+            });
+            json!({
+                "DeviceName": mapping.device_name().unwrap_or_default(),
+                "VirtualName": mapping.virtual_name().unwrap_or_default(),
+                "Ebs": ebs
+            })
+        })
+        .collect()
+}
+
+async fn ec2_describe_key_pairs(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
+    let mut req = client.describe_key_pairs();
+    for key_name in arg_values(args, "--key-names") {
+        req = req.key_names(key_name);
+    }
+    for key_pair_id in arg_values(args, "--key-pair-ids") {
+        req = req.key_pair_ids(key_pair_id);
+    }
+    if args.contains(&"--include-public-key") {
+        req = req.include_public_key(true);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-key-pairs API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_key_pairs_output(output.key_pairs())
+}
+
+fn ec2_describe_key_pairs_output(key_pairs: &[aws_sdk_ec2::types::KeyPairInfo]) -> Option<String> {
+    let key_pairs = key_pairs
+        .iter()
+        .map(|key_pair| {
+            let mut entry = json!({
+                "KeyPairId": key_pair.key_pair_id().unwrap_or_default(),
+                "KeyName": key_pair.key_name().unwrap_or_default(),
+                "KeyFingerprint": key_pair.key_fingerprint().unwrap_or_default(),
+                "KeyType": key_pair.key_type().map(|t| t.as_str()).unwrap_or_default(),
+                "CreateTime": ec2_timestamp(key_pair.create_time()),
+                "Tags": parse_tags_ec2(key_pair.tags())
+            });
+            // CLI와 같이 --include-public-key를 줬을 때만 포함
+            if let Some(public_key) = key_pair.public_key() {
+                entry["PublicKey"] = json!(public_key);
+            }
+            entry
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "KeyPairs": key_pairs }))
+}
+
+async fn ec2_describe_image_attribute(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let image_id = arg_value(args, "--image-id")?;
+    // 공유 권한(launchPermission)만 지원
+    if arg_value(args, "--attribute") != Some("launchPermission") {
+        return None;
+    }
+
+    let output = match client
+        .describe_image_attribute()
+        .image_id(image_id)
+        .attribute(aws_sdk_ec2::types::ImageAttributeName::LaunchPermission)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                image_id = image_id,
+                "describe-image-attribute API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_image_attribute_output(
+        output.image_id().unwrap_or(image_id),
+        output.launch_permissions(),
+    )
+}
+
+fn ec2_describe_image_attribute_output(
+    image_id: &str,
+    permissions: &[aws_sdk_ec2::types::LaunchPermission],
+) -> Option<String> {
+    let permissions = permissions
+        .iter()
+        .map(|permission| {
+            let mut entry = serde_json::Map::new();
+            if let Some(user_id) = permission.user_id() {
+                entry.insert("UserId".to_string(), json!(user_id));
+            }
+            if let Some(group) = permission.group() {
+                entry.insert("Group".to_string(), json!(group.as_str()));
+            }
+            if let Some(arn) = permission.organization_arn() {
+                entry.insert("OrganizationArn".to_string(), json!(arn));
+            }
+            if let Some(arn) = permission.organizational_unit_arn() {
+                entry.insert("OrganizationalUnitArn".to_string(), json!(arn));
+            }
+            Value::Object(entry)
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({
+        "ImageId": image_id,
+        "LaunchPermissions": permissions
+    }))
+}
+
+async fn ec2_describe_transit_gateways(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_transit_gateways();
+
+    for transit_gateway_id in arg_values(args, "--transit-gateway-ids") {
+        req = req.transit_gateway_ids(transit_gateway_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-transit-gateways API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_transit_gateways_output(output.transit_gateways())
+}
+
+fn ec2_describe_transit_gateways_output(
+    transit_gateways: &[aws_sdk_ec2::types::TransitGateway],
+) -> Option<String> {
+    let transit_gateways = transit_gateways
+        .iter()
+        .map(|tgw| {
+            let options = tgw.options().map(|options| {
+                json!({
+                    "AmazonSideAsn": options.amazon_side_asn(),
+                    "TransitGatewayCidrBlocks": options.transit_gateway_cidr_blocks(),
+                    "AutoAcceptSharedAttachments": options.auto_accept_shared_attachments().map(|v| v.as_str()).unwrap_or_default(),
+                    "DefaultRouteTableAssociation": options.default_route_table_association().map(|v| v.as_str()).unwrap_or_default(),
+                    "AssociationDefaultRouteTableId": options.association_default_route_table_id(),
+                    "DefaultRouteTablePropagation": options.default_route_table_propagation().map(|v| v.as_str()).unwrap_or_default(),
+                    "PropagationDefaultRouteTableId": options.propagation_default_route_table_id(),
+                    "VpnEcmpSupport": options.vpn_ecmp_support().map(|v| v.as_str()).unwrap_or_default(),
+                    "DnsSupport": options.dns_support().map(|v| v.as_str()).unwrap_or_default()
+                })
+            });
+
+            json!({
+                "TransitGatewayId": tgw.transit_gateway_id().unwrap_or_default(),
+                "TransitGatewayArn": tgw.transit_gateway_arn().unwrap_or_default(),
+                "State": tgw.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "OwnerId": tgw.owner_id().unwrap_or_default(),
+                "Description": tgw.description().unwrap_or_default(),
+                "CreationTime": ec2_timestamp(tgw.creation_time()),
+                "Options": options,
+                "Tags": parse_tags_ec2(tgw.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "TransitGateways": transit_gateways }))
+}
+
+// `--filters Name=transit-gateway-id,Values=tgw-1` 형태의 필터를 SDK 필터로 변환
+fn transit_gateway_filter(args: &[&str], name: &str) -> Option<aws_sdk_ec2::types::Filter> {
+    let values = parse_filter_value(arg_value(args, "--filters")?, name)?;
+    Some(
+        aws_sdk_ec2::types::Filter::builder()
+            .name(name)
+            .set_values(Some(values.split(',').map(str::to_string).collect()))
+            .build(),
+    )
+}
+
+async fn ec2_describe_transit_gateway_attachments(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_transit_gateway_attachments();
+
+    if let Some(filter) = transit_gateway_filter(args, "transit-gateway-id") {
+        req = req.filters(filter);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                filter = arg_value(args, "--filters"),
+                "describe-transit-gateway-attachments API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_transit_gateway_attachments_output(output.transit_gateway_attachments())
+}
+
+fn ec2_describe_transit_gateway_attachments_output(
+    attachments: &[aws_sdk_ec2::types::TransitGatewayAttachment],
+) -> Option<String> {
+    let attachments = attachments
+        .iter()
+        .map(|attachment| {
+            let association = attachment.association().map(|association| {
+                json!({
+                    "TransitGatewayRouteTableId": association.transit_gateway_route_table_id().unwrap_or_default(),
+                    "State": association.state().map(|s| s.as_str()).unwrap_or_default()
+                })
+            });
+
+            json!({
+                "TransitGatewayAttachmentId": attachment.transit_gateway_attachment_id().unwrap_or_default(),
+                "TransitGatewayId": attachment.transit_gateway_id().unwrap_or_default(),
+                "TransitGatewayOwnerId": attachment.transit_gateway_owner_id().unwrap_or_default(),
+                "ResourceOwnerId": attachment.resource_owner_id().unwrap_or_default(),
+                "ResourceType": attachment.resource_type().map(|v| v.as_str()).unwrap_or_default(),
+                "ResourceId": attachment.resource_id().unwrap_or_default(),
+                "State": attachment.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "Association": association,
+                "CreationTime": ec2_timestamp(attachment.creation_time()),
+                "Tags": parse_tags_ec2(attachment.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "TransitGatewayAttachments": attachments }))
+}
+
+async fn ec2_describe_transit_gateway_route_tables(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_transit_gateway_route_tables();
+
+    if let Some(filter) = transit_gateway_filter(args, "transit-gateway-id") {
+        req = req.filters(filter);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                filter = arg_value(args, "--filters"),
+                "describe-transit-gateway-route-tables API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_transit_gateway_route_tables_output(output.transit_gateway_route_tables())
+}
+
+fn ec2_describe_transit_gateway_route_tables_output(
+    route_tables: &[aws_sdk_ec2::types::TransitGatewayRouteTable],
+) -> Option<String> {
+    let route_tables = route_tables
+        .iter()
+        .map(|rt| {
+            json!({
+                "TransitGatewayRouteTableId": rt.transit_gateway_route_table_id().unwrap_or_default(),
+                "TransitGatewayId": rt.transit_gateway_id().unwrap_or_default(),
+                "State": rt.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "DefaultAssociationRouteTable": rt.default_association_route_table().unwrap_or(false),
+                "DefaultPropagationRouteTable": rt.default_propagation_route_table().unwrap_or(false),
+                "CreationTime": ec2_timestamp(rt.creation_time()),
+                "Tags": parse_tags_ec2(rt.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "TransitGatewayRouteTables": route_tables }))
+}
+
+// SearchTransitGatewayRoutes는 필터가 필수이므로 state 필터를 그대로 전달
+async fn ec2_search_transit_gateway_routes(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let route_table_id = arg_value(args, "--transit-gateway-route-table-id")?;
+    let filter = transit_gateway_filter(args, "state")?;
+
+    let output = match client
+        .search_transit_gateway_routes()
+        .transit_gateway_route_table_id(route_table_id)
+        .filters(filter)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                route_table_id = route_table_id,
+                "search-transit-gateway-routes API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_search_transit_gateway_routes_output(
+        output.routes(),
+        output.additional_routes_available().unwrap_or(false),
+    )
+}
+
+fn ec2_search_transit_gateway_routes_output(
+    routes: &[aws_sdk_ec2::types::TransitGatewayRoute],
+    additional_routes_available: bool,
+) -> Option<String> {
+    let routes = routes
+        .iter()
+        .map(|route| {
+            let attachments = route
+                .transit_gateway_attachments()
+                .iter()
+                .map(|attachment| {
+                    json!({
+                        "TransitGatewayAttachmentId": attachment.transit_gateway_attachment_id().unwrap_or_default(),
+                        "ResourceId": attachment.resource_id().unwrap_or_default(),
+                        "ResourceType": attachment.resource_type().map(|v| v.as_str()).unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "DestinationCidrBlock": route.destination_cidr_block().unwrap_or_default(),
+                "PrefixListId": route.prefix_list_id().unwrap_or_default(),
+                "TransitGatewayAttachments": attachments,
+                "Type": route.r#type().map(|v| v.as_str()).unwrap_or_default(),
+                "State": route.state().map(|s| s.as_str()).unwrap_or("active")
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({
+        "Routes": routes,
+        "AdditionalRoutesAvailable": additional_routes_available
+    }))
+}
+
+async fn ec2_describe_vpc_peering_connections(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_vpc_peering_connections();
+
+    for peering_id in arg_values(args, "--vpc-peering-connection-ids") {
+        req = req.vpc_peering_connection_ids(peering_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-vpc-peering-connections API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_vpc_peering_connections_output(output.vpc_peering_connections())
+}
+
+fn ec2_vpc_peering_vpc_info(info: &aws_sdk_ec2::types::VpcPeeringConnectionVpcInfo) -> Value {
+    let cidr_block_set = info
+        .cidr_block_set()
+        .iter()
+        .map(|cidr| json!({ "CidrBlock": cidr.cidr_block().unwrap_or_default() }))
+        .collect::<Vec<_>>();
+    let peering_options = info.peering_options().map(|options| {
+        json!({
+            "AllowDnsResolutionFromRemoteVpc": options.allow_dns_resolution_from_remote_vpc().unwrap_or(false)
+        })
+    });
+
+    json!({
+        "VpcId": info.vpc_id().unwrap_or_default(),
+        "OwnerId": info.owner_id().unwrap_or_default(),
+        "Region": info.region().unwrap_or_default(),
+        "CidrBlock": info.cidr_block().unwrap_or_default(),
+        "CidrBlockSet": cidr_block_set,
+        "PeeringOptions": peering_options
+    })
+}
+
+fn ec2_describe_vpc_peering_connections_output(
+    peerings: &[aws_sdk_ec2::types::VpcPeeringConnection],
+) -> Option<String> {
+    let peerings = peerings
+        .iter()
+        .map(|peering| {
+            let status = peering.status().map(|status| {
+                json!({
+                    "Code": status.code().map(|c| c.as_str()).unwrap_or("unknown"),
+                    "Message": status.message().unwrap_or_default()
+                })
+            });
+
+            json!({
+                "VpcPeeringConnectionId": peering.vpc_peering_connection_id().unwrap_or_default(),
+                "RequesterVpcInfo": peering.requester_vpc_info().map(ec2_vpc_peering_vpc_info),
+                "AccepterVpcInfo": peering.accepter_vpc_info().map(ec2_vpc_peering_vpc_info),
+                "Status": status,
+                "ExpirationTime": ec2_timestamp(peering.expiration_time()),
+                "Tags": parse_tags_ec2(peering.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "VpcPeeringConnections": peerings }))
+}
+
+async fn ec2_describe_launch_templates(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_launch_templates();
+
+    for template_id in arg_values(args, "--launch-template-ids") {
+        req = req.launch_template_ids(template_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-launch-templates API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_launch_templates_output(output.launch_templates())
+}
+
+fn ec2_describe_launch_templates_output(
+    templates: &[aws_sdk_ec2::types::LaunchTemplate],
+) -> Option<String> {
+    let templates = templates
+        .iter()
+        .map(|template| {
+            json!({
+                "LaunchTemplateId": template.launch_template_id().unwrap_or_default(),
+                "LaunchTemplateName": template.launch_template_name().unwrap_or_default(),
+                "CreateTime": ec2_timestamp(template.create_time()),
+                "CreatedBy": template.created_by().unwrap_or_default(),
+                "DefaultVersionNumber": template.default_version_number().unwrap_or_default(),
+                "LatestVersionNumber": template.latest_version_number().unwrap_or_default(),
+                "Tags": parse_tags_ec2(template.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "LaunchTemplates": templates }))
+}
+
+// `--versions $Default $Latest`처럼 버전 별칭도 그대로 전달
+async fn ec2_describe_launch_template_versions(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_launch_template_versions();
+
+    if let Some(template_id) = arg_value(args, "--launch-template-id") {
+        req = req.launch_template_id(template_id);
+    }
+    for version in arg_values(args, "--versions") {
+        req = req.versions(version);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-launch-template-versions API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_launch_template_versions_output(output.launch_template_versions())
+}
+
+fn ec2_describe_launch_template_versions_output(
+    versions: &[aws_sdk_ec2::types::LaunchTemplateVersion],
+) -> Option<String> {
+    let versions = versions
+        .iter()
+        .map(|version| {
+            let data = version.launch_template_data().map(|data| {
+                let block_devices = data
+                    .block_device_mappings()
+                    .iter()
+                    .map(|mapping| {
+                        let ebs = mapping.ebs().map(|ebs| {
+                            json!({
+                                "VolumeSize": ebs.volume_size(),
+                                "VolumeType": ebs.volume_type().map(|t| t.as_str()).unwrap_or_default(),
+                                "Iops": ebs.iops(),
+                                "Encrypted": ebs.encrypted().unwrap_or(false),
+                                "DeleteOnTermination": ebs.delete_on_termination().unwrap_or(false),
+                                "SnapshotId": ebs.snapshot_id().unwrap_or_default()
+                            })
+                        });
+                        json!({
+                            "DeviceName": mapping.device_name().unwrap_or_default(),
+                            "Ebs": ebs
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let iam_instance_profile = data.iam_instance_profile().map(|profile| {
+                    json!({
+                        "Arn": profile.arn().unwrap_or_default(),
+                        "Name": profile.name().unwrap_or_default()
+                    })
+                });
+                // 네트워크 인터페이스에 지정한 보안 그룹도 인스턴스에 적용됨
+                let mut security_group_ids = data.security_group_ids().to_vec();
+                for interface in data.network_interfaces() {
+                    security_group_ids.extend(interface.groups().iter().cloned());
+                }
+                json!({
+                    "ImageId": data.image_id().unwrap_or_default(),
+                    "InstanceType": data.instance_type().map(|t| t.as_str()).unwrap_or_default(),
+                    "KeyName": data.key_name().unwrap_or_default(),
+                    "IamInstanceProfile": iam_instance_profile,
+                    "SecurityGroupIds": security_group_ids,
+                    "SecurityGroups": data.security_groups(),
+                    "UserData": data.user_data().unwrap_or_default(),
+                    "BlockDeviceMappings": block_devices
+                })
+            });
+            json!({
+                "LaunchTemplateId": version.launch_template_id().unwrap_or_default(),
+                "LaunchTemplateName": version.launch_template_name().unwrap_or_default(),
+                "VersionNumber": version.version_number().unwrap_or_default(),
+                "VersionDescription": version.version_description().unwrap_or_default(),
+                "CreateTime": ec2_timestamp(version.create_time()),
+                "CreatedBy": version.created_by().unwrap_or_default(),
+                "DefaultVersion": version.default_version().unwrap_or(false),
+                "LaunchTemplateData": data
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "LaunchTemplateVersions": versions }))
+}
+
+async fn ec2_describe_network_acls(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
+    let mut req = client.describe_network_acls();
+
+    if let Some(raw_filter) = arg_value(args, "--filters")
+        && let Some(vpc_id) = parse_filter_value(raw_filter, "vpc-id")
+    {
+        let filter = aws_sdk_ec2::types::Filter::builder()
+            .name("vpc-id")
+            .values(vpc_id)
+            .build();
+        req = req.filters(filter);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                filter = arg_value(args, "--filters"),
+                "describe-network-acls API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_network_acls_output(output.network_acls())
+}
+
+fn ec2_describe_network_acls_output(acls: &[aws_sdk_ec2::types::NetworkAcl]) -> Option<String> {
+    let acls = acls
+        .iter()
+        .map(|acl| {
+            let entries = acl
+                .entries()
+                .iter()
+                .map(|entry| {
+                    // 모든 프로토콜(-1) 규칙에는 PortRange가 없음
+                    let port_range = entry.port_range().map(|range| {
+                        json!({
+                            "From": range.from().unwrap_or_default(),
+                            "To": range.to().unwrap_or_default()
+                        })
+                    });
+                    json!({
+                        "RuleNumber": entry.rule_number().unwrap_or_default(),
+                        "Protocol": entry.protocol().unwrap_or_default(),
+                        "RuleAction": entry.rule_action().map(|a| a.as_str()).unwrap_or_default(),
+                        "Egress": entry.egress().unwrap_or(false),
+                        "CidrBlock": entry.cidr_block().unwrap_or_default(),
+                        "Ipv6CidrBlock": entry.ipv6_cidr_block().unwrap_or_default(),
+                        "PortRange": port_range
+                    })
+                })
+                .collect::<Vec<_>>();
+            let associations = acl
+                .associations()
+                .iter()
+                .map(|association| {
+                    json!({
+                        "NetworkAclAssociationId": association.network_acl_association_id().unwrap_or_default(),
+                        "NetworkAclId": association.network_acl_id().unwrap_or_default(),
+                        "SubnetId": association.subnet_id().unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "NetworkAclId": acl.network_acl_id().unwrap_or_default(),
+                "VpcId": acl.vpc_id().unwrap_or_default(),
+                "IsDefault": acl.is_default().unwrap_or(false),
+                "Entries": entries,
+                "Associations": associations,
+                "OwnerId": acl.owner_id().unwrap_or_default(),
+                "Tags": parse_tags_ec2(acl.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "NetworkAcls": acls }))
+}
+
+async fn ec2_describe_network_interfaces(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_network_interfaces();
+
+    for interface_id in arg_values(args, "--network-interface-ids") {
+        req = req.network_interface_ids(interface_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-network-interfaces API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_network_interfaces_output(output.network_interfaces())
+}
+
+fn ec2_describe_network_interfaces_output(
+    interfaces: &[aws_sdk_ec2::types::NetworkInterface],
+) -> Option<String> {
+    let interfaces = interfaces
+        .iter()
+        .map(|interface| {
+            let private_ips = interface
+                .private_ip_addresses()
+                .iter()
+                .map(|ip| {
+                    let association = ip.association().map(|association| {
+                        json!({ "PublicIp": association.public_ip().unwrap_or_default() })
+                    });
+                    json!({
+                        "PrivateIpAddress": ip.private_ip_address().unwrap_or_default(),
+                        "Primary": ip.primary().unwrap_or(false),
+                        "Association": association
+                    })
+                })
+                .collect::<Vec<_>>();
+            let groups = interface
+                .groups()
+                .iter()
+                .map(|group| {
+                    json!({
+                        "GroupId": group.group_id().unwrap_or_default(),
+                        "GroupName": group.group_name().unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+            // 관리형 ENI(ELB 등)는 InstanceId 없이 InstanceOwnerId만 있음
+            let attachment = interface.attachment().map(|attachment| {
+                json!({
+                    "AttachmentId": attachment.attachment_id().unwrap_or_default(),
+                    "InstanceId": attachment.instance_id().unwrap_or_default(),
+                    "InstanceOwnerId": attachment.instance_owner_id().unwrap_or_default(),
+                    "DeviceIndex": attachment.device_index(),
+                    "Status": attachment.status().map(|s| s.as_str()).unwrap_or_default(),
+                    "DeleteOnTermination": attachment.delete_on_termination().unwrap_or(false)
+                })
+            });
+
+            json!({
+                "NetworkInterfaceId": interface.network_interface_id().unwrap_or_default(),
+                "Description": interface.description().unwrap_or_default(),
+                "InterfaceType": interface.interface_type().map(|t| t.as_str()).unwrap_or_default(),
+                "Status": interface.status().map(|s| s.as_str()).unwrap_or("unknown"),
+                "VpcId": interface.vpc_id().unwrap_or_default(),
+                "SubnetId": interface.subnet_id().unwrap_or_default(),
+                "AvailabilityZone": interface.availability_zone().unwrap_or_default(),
+                "MacAddress": interface.mac_address().unwrap_or_default(),
+                "PrivateIpAddresses": private_ips,
+                "Groups": groups,
+                "Attachment": attachment,
+                "RequesterManaged": interface.requester_managed().unwrap_or(false),
+                "RequesterId": interface.requester_id().unwrap_or_default(),
+                "OwnerId": interface.owner_id().unwrap_or_default(),
+                "TagSet": parse_tags_ec2(interface.tag_set())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "NetworkInterfaces": interfaces }))
+}
+
+async fn ec2_describe_vpc_endpoints(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
+    let mut req = client.describe_vpc_endpoints();
+
+    for endpoint_id in arg_values(args, "--vpc-endpoint-ids") {
+        req = req.vpc_endpoint_ids(endpoint_id);
+    }
+    if let Some(raw_filter) = arg_value(args, "--filters")
+        && let Some(vpc_id) = parse_filter_value(raw_filter, "vpc-id")
+    {
+        let filter = aws_sdk_ec2::types::Filter::builder()
+            .name("vpc-id")
+            .values(vpc_id)
+            .build();
+        req = req.filters(filter);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-vpc-endpoints API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_vpc_endpoints_output(output.vpc_endpoints())
+}
+
+fn ec2_describe_vpc_endpoints_output(
+    endpoints: &[aws_sdk_ec2::types::VpcEndpoint],
+) -> Option<String> {
+    let endpoints = endpoints
+        .iter()
+        .map(|endpoint| {
+            let groups = endpoint
+                .groups()
+                .iter()
+                .map(|group| {
+                    json!({
+                        "GroupId": group.group_id().unwrap_or_default(),
+                        "GroupName": group.group_name().unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+            let dns_entries = endpoint
+                .dns_entries()
+                .iter()
+                .map(|entry| {
+                    json!({
+                        "DnsName": entry.dns_name().unwrap_or_default(),
+                        "HostedZoneId": entry.hosted_zone_id().unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "VpcEndpointId": endpoint.vpc_endpoint_id().unwrap_or_default(),
+                "VpcEndpointType": endpoint.vpc_endpoint_type().map(|t| t.as_str()).unwrap_or_default(),
+                "VpcId": endpoint.vpc_id().unwrap_or_default(),
+                "ServiceName": endpoint.service_name().unwrap_or_default(),
+                "State": endpoint.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "PolicyDocument": endpoint.policy_document().unwrap_or_default(),
+                "RouteTableIds": endpoint.route_table_ids(),
+                "SubnetIds": endpoint.subnet_ids(),
+                "Groups": groups,
+                "PrivateDnsEnabled": endpoint.private_dns_enabled().unwrap_or(false),
+                "DnsEntries": dns_entries,
+                "CreationTimestamp": ec2_timestamp(endpoint.creation_timestamp()),
+                "OwnerId": endpoint.owner_id().unwrap_or_default(),
+                "Tags": parse_tags_ec2(endpoint.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "VpcEndpoints": endpoints }))
+}
+
+async fn ec2_describe_vpn_connections(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_vpn_connections();
+
+    for vpn_connection_id in arg_values(args, "--vpn-connection-ids") {
+        req = req.vpn_connection_ids(vpn_connection_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-vpn-connections API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_vpn_connections_output(output.vpn_connections())
+}
+
+// 사전 공유 키(PreSharedKey)와 고객 게이트웨이 설정 XML은 문서에 쓰지 않으므로 옮기지 않음
+fn ec2_describe_vpn_connections_output(
+    connections: &[aws_sdk_ec2::types::VpnConnection],
+) -> Option<String> {
+    let connections = connections
+        .iter()
+        .map(|connection| {
+            let options = connection.options().map(|options| {
+                let tunnel_options = options
+                    .tunnel_options()
+                    .iter()
+                    .map(|tunnel| {
+                        json!({
+                            "OutsideIpAddress": tunnel.outside_ip_address().unwrap_or_default(),
+                            "TunnelInsideCidr": tunnel.tunnel_inside_cidr().unwrap_or_default(),
+                            "TunnelInsideIpv6Cidr": tunnel.tunnel_inside_ipv6_cidr().unwrap_or_default()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                json!({
+                    "EnableAcceleration": options.enable_acceleration().unwrap_or(false),
+                    "StaticRoutesOnly": options.static_routes_only().unwrap_or(false),
+                    "LocalIpv4NetworkCidr": options.local_ipv4_network_cidr().unwrap_or_default(),
+                    "RemoteIpv4NetworkCidr": options.remote_ipv4_network_cidr().unwrap_or_default(),
+                    "TunnelInsideIpVersion": options.tunnel_inside_ip_version().map(|v| v.as_str()).unwrap_or_default(),
+                    "TunnelOptions": tunnel_options
+                })
+            });
+            let routes = connection
+                .routes()
+                .iter()
+                .map(|route| {
+                    json!({
+                        "DestinationCidrBlock": route.destination_cidr_block().unwrap_or_default(),
+                        "Source": route.source().map(|s| s.as_str()).unwrap_or_default(),
+                        "State": route.state().map(|s| s.as_str()).unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+            let telemetry = connection
+                .vgw_telemetry()
+                .iter()
+                .map(|tunnel| {
+                    json!({
+                        "OutsideIpAddress": tunnel.outside_ip_address().unwrap_or_default(),
+                        "Status": tunnel.status().map(|s| s.as_str()).unwrap_or_default(),
+                        "StatusMessage": tunnel.status_message().unwrap_or_default(),
+                        "LastStatusChange": ec2_timestamp(tunnel.last_status_change()),
+                        "AcceptedRouteCount": tunnel.accepted_route_count().unwrap_or(0)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "VpnConnectionId": connection.vpn_connection_id().unwrap_or_default(),
+                "State": connection.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "Type": connection.r#type().map(|t| t.as_str()).unwrap_or_default(),
+                "Category": connection.category().unwrap_or_default(),
+                "CustomerGatewayId": connection.customer_gateway_id().unwrap_or_default(),
+                "VpnGatewayId": connection.vpn_gateway_id(),
+                "TransitGatewayId": connection.transit_gateway_id(),
+                "Options": options,
+                "Routes": routes,
+                "VgwTelemetry": telemetry,
+                "Tags": parse_tags_ec2(connection.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "VpnConnections": connections }))
+}
+
+async fn ec2_describe_customer_gateways(
+    client: &aws_sdk_ec2::Client,
+    args: &[&str],
+) -> Option<String> {
+    let mut req = client.describe_customer_gateways();
+
+    for customer_gateway_id in arg_values(args, "--customer-gateway-ids") {
+        req = req.customer_gateway_ids(customer_gateway_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-customer-gateways API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_customer_gateways_output(output.customer_gateways())
+}
+
+fn ec2_describe_customer_gateways_output(
+    gateways: &[aws_sdk_ec2::types::CustomerGateway],
+) -> Option<String> {
+    let gateways = gateways
+        .iter()
+        .map(|gateway| {
+            json!({
+                "CustomerGatewayId": gateway.customer_gateway_id().unwrap_or_default(),
+                "State": gateway.state().unwrap_or("unknown"),
+                "Type": gateway.r#type().unwrap_or_default(),
+                "IpAddress": gateway.ip_address().unwrap_or_default(),
+                "BgpAsn": gateway.bgp_asn().unwrap_or_default(),
+                "DeviceName": gateway.device_name().unwrap_or_default(),
+                "CertificateArn": gateway.certificate_arn().unwrap_or_default(),
+                "Tags": parse_tags_ec2(gateway.tags())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    value_to_json_string(json!({ "CustomerGateways": gateways }))
+}
+
+async fn ec2_describe_vpn_gateways(client: &aws_sdk_ec2::Client, args: &[&str]) -> Option<String> {
+    let mut req = client.describe_vpn_gateways();
+
+    for vpn_gateway_id in arg_values(args, "--vpn-gateway-ids") {
+        req = req.vpn_gateway_ids(vpn_gateway_id);
+    }
+
+    let output = match req.send().await {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::error!(
+                error = %error,
+                args = %args.join(" "),
+                "describe-vpn-gateways API call failed"
+            );
+            return None;
+        }
+    };
+    ec2_describe_vpn_gateways_output(output.vpn_gateways())
+}
+
+fn ec2_describe_vpn_gateways_output(gateways: &[aws_sdk_ec2::types::VpnGateway]) -> Option<String> {
+    let gateways = gateways
+        .iter()
+        .map(|gateway| {
+            let attachments = gateway
+                .vpc_attachments()
+                .iter()
+                .map(|attachment| {
+                    json!({
+                        "VpcId": attachment.vpc_id().unwrap_or_default(),
+                        "State": attachment.state().map(|s| s.as_str()).unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({
+                "VpnGatewayId": gateway.vpn_gateway_id().unwrap_or_default(),
+                "State": gateway.state().map(|s| s.as_str()).unwrap_or("unknown"),
+                "Type": gateway.r#type().map(|t| t.as_str()).unwrap_or_default(),
+                "AvailabilityZone": gateway.availability_zone().unwrap_or_default(),
+                "AmazonSideAsn": gateway.amazon_side_asn(),
+                "VpcAttachments": attachments,
+                "Tags": parse_tags_ec2(gateway.tags())
             })
         })
         .collect::<Vec<_>>();
 
-    value_to_json_string(json!({ "Images": images }))
+    value_to_json_string(json!({ "VpnGateways": gateways }))
 }
 
 async fn run_ecr_request(
@@ -1297,11 +2706,11 @@ async fn run_ecr_request(
 ) -> Option<String> {
     match operation {
         "describe-repositories" => {
-            let client = aws_sdk_ecr::Client::new(config);
+            let client = sdk_client!(aws_sdk_ecr, config);
             ecr_describe_repositories(&client, args).await
         }
         "describe-images" => {
-            let client = aws_sdk_ecr::Client::new(config);
+            let client = sdk_client!(aws_sdk_ecr, config);
             ecr_describe_images(&client, args).await
         }
         _ => None,
@@ -1385,19 +2794,19 @@ async fn run_elbv2_request(
 ) -> Option<String> {
     match operation {
         "describe-load-balancers" => {
-            let client = aws_sdk_elasticloadbalancingv2::Client::new(config);
+            let client = sdk_client!(aws_sdk_elasticloadbalancingv2, config);
             elbv2_describe_load_balancers(&client, args).await
         }
         "describe-listeners" => {
-            let client = aws_sdk_elasticloadbalancingv2::Client::new(config);
+            let client = sdk_client!(aws_sdk_elasticloadbalancingv2, config);
             elbv2_describe_listeners(&client, args).await
         }
         "describe-target-groups" => {
-            let client = aws_sdk_elasticloadbalancingv2::Client::new(config);
+            let client = sdk_client!(aws_sdk_elasticloadbalancingv2, config);
             elbv2_describe_target_groups(&client, args).await
         }
         "describe-target-health" => {
-            let client = aws_sdk_elasticloadbalancingv2::Client::new(config);
+            let client = sdk_client!(aws_sdk_elasticloadbalancingv2, config);
             elbv2_describe_target_health(&client, args).await
         }
         _ => None,
@@ -1538,7 +2947,7 @@ async fn elbv2_describe_target_groups(
         req = req.load_balancer_arn(lb_arn);
     }
 
-    if let Some(tg_arn) = arg_value(args, "--target-group-arns") {
+    for tg_arn in arg_values(args, "--target-group-arns") {
         req = req.target_group_arns(tg_arn);
     }
 
@@ -1629,19 +3038,19 @@ async fn run_iam_request(
 ) -> Option<String> {
     match operation {
         "get-role" => {
-            let client = aws_sdk_iam::Client::new(config);
+            let client = sdk_client!(aws_sdk_iam, config);
             iam_get_role(&client, args).await
         }
         "list-attached-role-policies" => {
-            let client = aws_sdk_iam::Client::new(config);
+            let client = sdk_client!(aws_sdk_iam, config);
             iam_list_attached_role_policies(&client, args).await
         }
         "list-role-policies" => {
-            let client = aws_sdk_iam::Client::new(config);
+            let client = sdk_client!(aws_sdk_iam, config);
             iam_list_role_policies(&client, args).await
         }
         "get-role-policy" => {
-            let client = aws_sdk_iam::Client::new(config);
+            let client = sdk_client!(aws_sdk_iam, config);
             iam_get_role_policy(&client, args).await
         }
         _ => None,
@@ -1767,7 +3176,7 @@ async fn run_sts_request(config: &aws_config::SdkConfig, operation: &str) -> Opt
         return None;
     }
 
-    let client = aws_sdk_sts::Client::new(config);
+    let client = sdk_client!(aws_sdk_sts, config);
     let output = client.get_caller_identity().send().await.ok()?;
 
     value_to_json_string(json!({
@@ -1858,6 +3267,7 @@ pub fn parse_resources_from_json(json: &str, prefix: &str) -> Vec<AwsResource> {
                     state: String::new(),
                     az: String::new(),
                     cidr: String::new(),
+                    owner_id: String::new(),
                 });
             }
             search_start = start + end;
@@ -1874,6 +3284,51 @@ pub fn get_runtime() -> &'static Runtime {
     RUNTIME.get_or_init(|| Runtime::new().expect("Failed to create Tokio runtime"))
 }
 
+/// Default number of AWS requests list/detail/blueprint fetches send at once.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+// 스로틀링을 피하려고 설정값이 커도 이 이상은 보내지 않음
+const MAX_CONCURRENT_REQUESTS_LIMIT: usize = 16;
+
+static MAX_CONCURRENT_REQUESTS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CONCURRENT_REQUESTS);
+
+/// Set how many AWS requests may be in flight at once (1 = one at a time).
+pub fn set_max_concurrent_requests(limit: usize) {
+    MAX_CONCURRENT_REQUESTS.store(
+        limit.clamp(1, MAX_CONCURRENT_REQUESTS_LIMIT),
+        Ordering::Relaxed,
+    );
+}
+
+pub fn max_concurrent_requests() -> usize {
+    MAX_CONCURRENT_REQUESTS.load(Ordering::Relaxed)
+}
+
+/// Run `f` for every item with at most `max_concurrent_requests()` calls in flight.
+/// Results keep the order of `items`.
+pub(crate) async fn map_concurrent<T, R, F, Fut>(items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = R>,
+{
+    map_concurrent_with(max_concurrent_requests(), items, f).await
+}
+
+async fn map_concurrent_with<T, R, F, Fut>(
+    limit: usize,
+    items: impl IntoIterator<Item = T>,
+    f: F,
+) -> Vec<R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = R>,
+{
+    futures_util::stream::iter(items)
+        .map(f)
+        .buffered(limit.max(1))
+        .collect()
+        .await
+}
+
 /// Get AWS SDK config with profile-based credentials and region
 pub async fn get_sdk_config() -> aws_config::SdkConfig {
     let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
@@ -1901,37 +3356,55 @@ pub async fn get_sdk_config() -> aws_config::SdkConfig {
             config_loader = config_loader.region(aws_config::Region::new(region_str.to_string()));
         } else if let Some(region_str) = region_env.as_deref() {
             config_loader = config_loader.region(aws_config::Region::new(region_str.to_string()));
-        } else {
-            config_loader = config_loader.region(aws_config::Region::new("us-east-1"));
         }
     }
 
-    config_loader.load().await
+    // 리전을 고르기 전에는 프로필의 리전을 따르고, 없으면 파티션의 글로벌 리전 사용
+    // (GovCloud/중국 자격 증명은 us-east-1에서 인증되지 않음)
+    let config = config_loader.load().await;
+    if config.region().is_some() {
+        return config;
+    }
+    config
+        .to_builder()
+        .region(aws_config::Region::new(current_partition().global_region()))
+        .build()
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        AwsResource, arg_value, ec2_describe_addresses_output, ec2_describe_images_output,
+        AwsResource, arg_value, arg_values, ec2_describe_addresses_output,
+        ec2_describe_customer_gateways_output, ec2_describe_image_attribute_output,
+        ec2_describe_images_output, ec2_describe_instance_types_output,
         ec2_describe_instances_output, ec2_describe_internet_gateways_output,
-        ec2_describe_nat_gateways_output, ec2_describe_route_tables_output,
-        ec2_describe_security_groups_output, ec2_describe_subnets_output,
-        ec2_describe_volumes_output, ec2_describe_vpcs_output, ecr_describe_images_output,
+        ec2_describe_key_pairs_output, ec2_describe_launch_template_versions_output,
+        ec2_describe_launch_templates_output, ec2_describe_nat_gateways_output,
+        ec2_describe_network_acls_output, ec2_describe_network_interfaces_output,
+        ec2_describe_route_tables_output, ec2_describe_security_groups_output,
+        ec2_describe_snapshots_output, ec2_describe_subnets_output,
+        ec2_describe_transit_gateway_attachments_output, ec2_describe_transit_gateways_output,
+        ec2_describe_volumes_output, ec2_describe_vpc_endpoints_output,
+        ec2_describe_vpc_peering_connections_output, ec2_describe_vpcs_output,
+        ec2_describe_vpn_connections_output, ec2_describe_vpn_gateways_output,
+        ec2_search_transit_gateway_routes_output, ecr_describe_images_output,
         ecr_describe_repositories_output, elbv2_describe_listeners_output,
         elbv2_describe_load_balancers_output, elbv2_describe_target_groups_output,
-        elbv2_describe_target_health_output, extract_json_value, extract_tags,
-        iam_get_role_policy_output, iam_list_attached_role_policies_output,
+        elbv2_describe_target_health_output, extract_json_value, extract_tags, foreign_owner,
+        get_runtime, iam_get_role_policy_output, iam_list_attached_role_policies_output,
         iam_list_role_policies_output, is_auth_failure_error, is_network_error, lb_to_json,
-        list_aws_profiles, parse_filter_value, parse_ip_permissions, parse_name_tag,
-        parse_policy_json, parse_resources_from_json, parse_tags_ec2, parse_tags_iam,
-        run_ec2_request, run_ecr_request, run_elbv2_request, run_iam_request, run_sts_request,
-        set_aws_profile, target_group_to_json, value_to_json_string,
+        list_aws_profiles, map_concurrent_with, parse_filter_value, parse_ip_permissions,
+        parse_name_tag, parse_policy_json, parse_resources_from_json, parse_tags_ec2,
+        parse_tags_iam, run_ec2_request, run_ecr_request, run_elbv2_request, run_iam_request,
+        run_sts_request, set_account_id, set_aws_profile, target_group_to_json,
+        value_to_json_string,
     };
     use serde_json::Value;
     use std::env;
     use std::ffi::OsString;
     use std::fs;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Mutex, OnceLock};
 
     fn env_lock() -> &'static Mutex<()> {
@@ -2135,6 +3608,12 @@ mod tests {
         aws_sdk_ec2::types::Instance::builder()
             .instance_id(instance_id)
             .instance_type(aws_sdk_ec2::types::InstanceType::T3Micro)
+            .cpu_options(
+                aws_sdk_ec2::types::CpuOptions::builder()
+                    .core_count(1)
+                    .threads_per_core(2)
+                    .build(),
+            )
             .image_id("ami-0001")
             .platform(aws_sdk_ec2::types::PlatformValues::Windows)
             .architecture(aws_sdk_ec2::types::ArchitectureValues::X8664)
@@ -2222,6 +3701,36 @@ mod tests {
             default_json["Reservations"][0]["Instances"][0]["SecurityGroups"][0]["GroupId"],
             "sg-1111"
         );
+        assert_eq!(
+            default_json["Reservations"][0]["Instances"][0]["CpuOptions"]["ThreadsPerCore"],
+            2
+        );
+    }
+
+    #[test]
+    fn ec2_instance_types_output_keeps_vcpus_and_memory_numeric() {
+        let instance_types = vec![
+            aws_sdk_ec2::types::InstanceTypeInfo::builder()
+                .instance_type(aws_sdk_ec2::types::InstanceType::C5nLarge)
+                .v_cpu_info(
+                    aws_sdk_ec2::types::VCpuInfo::builder()
+                        .default_v_cpus(2)
+                        .build(),
+                )
+                .memory_info(
+                    aws_sdk_ec2::types::MemoryInfo::builder()
+                        .size_in_mib(5376)
+                        .build(),
+                )
+                .build(),
+        ];
+        let out =
+            ec2_describe_instance_types_output(&instance_types).expect("instance types output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let info = &json["InstanceTypes"][0];
+        assert_eq!(info["InstanceType"], "c5n.large");
+        assert_eq!(info["VCpuInfo"]["DefaultVCpus"], 2);
+        assert_eq!(info["MemoryInfo"]["SizeInMiB"], 5376);
     }
 
     #[test]
@@ -2242,6 +3751,72 @@ mod tests {
         assert_eq!(json["Volumes"][0]["Iops"], 3000);
     }
 
+    #[test]
+    fn ec2_volume_and_snapshot_outputs_match_cli_shape() {
+        let volumes = vec![
+            aws_sdk_ec2::types::Volume::builder()
+                .volume_id("vol-1")
+                .size(100)
+                .volume_type(aws_sdk_ec2::types::VolumeType::Gp3)
+                .throughput(250)
+                .availability_zone("ap-northeast-2a")
+                .state(aws_sdk_ec2::types::VolumeState::InUse)
+                .snapshot_id("snap-1")
+                .attachments(
+                    aws_sdk_ec2::types::VolumeAttachment::builder()
+                        .instance_id("i-0abc")
+                        .device("/dev/xvda")
+                        .state(aws_sdk_ec2::types::VolumeAttachmentState::Attached)
+                        .delete_on_termination(true)
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "data"))
+                .build(),
+            aws_sdk_ec2::types::Volume::builder()
+                .volume_id("vol-2")
+                .size(8)
+                .build(),
+        ];
+        let out = ec2_describe_volumes_output(&volumes).expect("volume output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let volume = &json["Volumes"][0];
+        assert_eq!(volume["Throughput"], 250);
+        assert_eq!(volume["State"], "in-use");
+        assert_eq!(volume["AvailabilityZone"], "ap-northeast-2a");
+        assert_eq!(volume["Attachments"][0]["InstanceId"], "i-0abc");
+        assert_eq!(volume["Attachments"][0]["State"], "attached");
+        assert_eq!(volume["Attachments"][0]["DeleteOnTermination"], true);
+        assert_eq!(volume["Tags"][0]["Value"], "data");
+        assert!(json["Volumes"][1]["Throughput"].is_null());
+        assert_eq!(json["Volumes"][1]["VolumeType"], "unknown");
+        assert_eq!(
+            json["Volumes"][1]["Attachments"].as_array().map(Vec::len),
+            Some(0)
+        );
+
+        let snapshots = vec![
+            aws_sdk_ec2::types::Snapshot::builder()
+                .snapshot_id("snap-1")
+                .volume_id("vol-1")
+                .volume_size(100)
+                .state(aws_sdk_ec2::types::SnapshotState::Completed)
+                .progress("100%")
+                .encrypted(true)
+                .owner_id("111122223333")
+                .storage_tier(aws_sdk_ec2::types::StorageTier::Archive)
+                .build(),
+        ];
+        let out = ec2_describe_snapshots_output(&snapshots).expect("snapshot output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let snapshot = &json["Snapshots"][0];
+        assert_eq!(snapshot["VolumeId"], "vol-1");
+        assert_eq!(snapshot["VolumeSize"], 100);
+        assert_eq!(snapshot["State"], "completed");
+        assert_eq!(snapshot["Progress"], "100%");
+        assert_eq!(snapshot["Encrypted"], true);
+        assert_eq!(snapshot["StorageTier"], "archive");
+    }
+
     #[test]
     fn ec2_vpc_output_supports_query_and_default_shape() {
         let vpcs = vec![
@@ -2255,20 +3830,44 @@ mod tests {
                 .vpc_id("vpc-aaaa")
                 .cidr_block("10.0.0.0/16")
                 .state(aws_sdk_ec2::types::VpcState::Available)
+                .owner_id("111122223333")
+                .ipv6_cidr_block_association_set(
+                    aws_sdk_ec2::types::VpcIpv6CidrBlockAssociation::builder()
+                        .association_id("vpc-cidr-assoc-1")
+                        .ipv6_cidr_block("2600:1f18:abc:de00::/56")
+                        .ipv6_cidr_block_state(
+                            aws_sdk_ec2::types::VpcCidrBlockState::builder()
+                                .state(aws_sdk_ec2::types::VpcCidrBlockStateCode::Associated)
+                                .build(),
+                        )
+                        .network_border_group("ap-northeast-2")
+                        .build(),
+                )
                 .tags(ec2_test_tag("Name", "vpc-a"))
                 .build(),
         ];
 
-        let query_out =
-            ec2_describe_vpcs_output(&vpcs, Some("Vpcs[*].[VpcId,Tags]")).expect("query vpcs");
+        let query_out = ec2_describe_vpcs_output(&vpcs, Some("Vpcs[*].[VpcId,Tags,OwnerId]"))
+            .expect("query vpcs");
         let query_json: Value = serde_json::from_str(&query_out).expect("valid json");
         assert_eq!(query_json[0][0], "vpc-aaaa");
         assert_eq!(query_json[1][0], "vpc-bbbb");
+        assert_eq!(query_json[0][2], "111122223333");
 
         let default_out = ec2_describe_vpcs_output(&vpcs, Some("unsupported")).expect("vpcs");
         let default_json: Value = serde_json::from_str(&default_out).expect("valid json");
         assert_eq!(default_json["Vpcs"][0]["VpcId"], "vpc-aaaa");
         assert_eq!(default_json["Vpcs"][1]["CidrBlock"], "10.1.0.0/16");
+        assert_eq!(default_json["Vpcs"][0]["OwnerId"], "111122223333");
+        let ipv6 = &default_json["Vpcs"][0]["Ipv6CidrBlockAssociationSet"][0];
+        assert_eq!(ipv6["Ipv6CidrBlock"], "2600:1f18:abc:de00::/56");
+        assert_eq!(ipv6["Ipv6CidrBlockState"]["State"], "associated");
+        assert_eq!(
+            default_json["Vpcs"][1]["Ipv6CidrBlockAssociationSet"]
+                .as_array()
+                .map(Vec::len),
+            Some(0)
+        );
     }
 
     #[test]
@@ -2282,6 +3881,17 @@ mod tests {
                 .state(aws_sdk_ec2::types::SubnetState::Available)
                 .map_public_ip_on_launch(true)
                 .available_ip_address_count(251)
+                .ipv6_cidr_block_association_set(
+                    aws_sdk_ec2::types::SubnetIpv6CidrBlockAssociation::builder()
+                        .ipv6_cidr_block("2600:1f18:abc:de01::/64")
+                        .ipv6_cidr_block_state(
+                            aws_sdk_ec2::types::SubnetCidrBlockState::builder()
+                                .state(aws_sdk_ec2::types::SubnetCidrBlockStateCode::Associated)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .assign_ipv6_address_on_creation(true)
                 .tags(ec2_test_tag("Name", "public-a"))
                 .build(),
         ];
@@ -2289,6 +3899,15 @@ mod tests {
         let subnets_json: Value = serde_json::from_str(&subnets_out).expect("valid json");
         assert_eq!(subnets_json["Subnets"][0]["MapPublicIpOnLaunch"], true);
         assert_eq!(subnets_json["Subnets"][0]["AvailableIpAddressCount"], 251);
+        assert_eq!(
+            subnets_json["Subnets"][0]["Ipv6CidrBlockAssociationSet"][0]["Ipv6CidrBlock"],
+            "2600:1f18:abc:de01::/64"
+        );
+        assert_eq!(
+            subnets_json["Subnets"][0]["AssignIpv6AddressOnCreation"],
+            true
+        );
+        assert_eq!(subnets_json["Subnets"][0]["Ipv6Native"], false);
 
         let nat_gateways = vec![
             aws_sdk_ec2::types::NatGateway::builder()
@@ -2344,6 +3963,13 @@ mod tests {
                         .state(aws_sdk_ec2::types::RouteState::Active)
                         .build(),
                 )
+                .routes(
+                    aws_sdk_ec2::types::Route::builder()
+                        .destination_ipv6_cidr_block("::/0")
+                        .egress_only_internet_gateway_id("eigw-1")
+                        .state(aws_sdk_ec2::types::RouteState::Active)
+                        .build(),
+                )
                 .associations(
                     aws_sdk_ec2::types::RouteTableAssociation::builder()
                         .subnet_id("subnet-1")
@@ -2358,6 +3984,14 @@ mod tests {
         .expect("route query");
         let route_query_json: Value = serde_json::from_str(&route_query).expect("valid json");
         assert_eq!(route_query_json[0][0], "rtb-1");
+        assert_eq!(
+            route_query_json[0][2][1]["DestinationIpv6CidrBlock"],
+            "::/0"
+        );
+        assert_eq!(
+            route_query_json[0][2][1]["EgressOnlyInternetGatewayId"],
+            "eigw-1"
+        );
 
         let addresses = vec![
             aws_sdk_ec2::types::Address::builder()
@@ -2422,6 +4056,581 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ec2_transit_gateway_outputs_match_cli_shape() {
+        let transit_gateways = vec![
+            aws_sdk_ec2::types::TransitGateway::builder()
+                .transit_gateway_id("tgw-1")
+                .state(aws_sdk_ec2::types::TransitGatewayState::Available)
+                .owner_id("111122223333")
+                .options(
+                    aws_sdk_ec2::types::TransitGatewayOptions::builder()
+                        .amazon_side_asn(64512)
+                        .association_default_route_table_id("tgw-rtb-1")
+                        .dns_support(aws_sdk_ec2::types::DnsSupportValue::Enable)
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "core-tgw"))
+                .build(),
+        ];
+        let tgw_out = ec2_describe_transit_gateways_output(&transit_gateways).expect("tgw output");
+        let tgw_json: Value = serde_json::from_str(&tgw_out).expect("valid json");
+        let options = &tgw_json["TransitGateways"][0]["Options"];
+        assert_eq!(options["AmazonSideAsn"], 64512);
+        assert_eq!(options["AssociationDefaultRouteTableId"], "tgw-rtb-1");
+        assert_eq!(options["DnsSupport"], "enable");
+        assert!(options["PropagationDefaultRouteTableId"].is_null());
+
+        let attachments = vec![
+            aws_sdk_ec2::types::TransitGatewayAttachment::builder()
+                .transit_gateway_attachment_id("tgw-attach-1")
+                .resource_type(aws_sdk_ec2::types::TransitGatewayAttachmentResourceType::Vpc)
+                .resource_id("vpc-1")
+                .state(aws_sdk_ec2::types::TransitGatewayAttachmentState::Available)
+                .association(
+                    aws_sdk_ec2::types::TransitGatewayAttachmentAssociation::builder()
+                        .transit_gateway_route_table_id("tgw-rtb-1")
+                        .state(aws_sdk_ec2::types::TransitGatewayAssociationState::Associated)
+                        .build(),
+                )
+                .build(),
+        ];
+        let attach_out =
+            ec2_describe_transit_gateway_attachments_output(&attachments).expect("attach output");
+        let attach_json: Value = serde_json::from_str(&attach_out).expect("valid json");
+        let attachment = &attach_json["TransitGatewayAttachments"][0];
+        assert_eq!(attachment["ResourceType"], "vpc");
+        assert_eq!(
+            attachment["Association"]["TransitGatewayRouteTableId"],
+            "tgw-rtb-1"
+        );
+
+        let routes = vec![
+            aws_sdk_ec2::types::TransitGatewayRoute::builder()
+                .destination_cidr_block("10.1.0.0/16")
+                .r#type(aws_sdk_ec2::types::TransitGatewayRouteType::Propagated)
+                .state(aws_sdk_ec2::types::TransitGatewayRouteState::Active)
+                .transit_gateway_attachments(
+                    aws_sdk_ec2::types::TransitGatewayRouteAttachment::builder()
+                        .transit_gateway_attachment_id("tgw-attach-1")
+                        .resource_id("vpc-1")
+                        .resource_type(
+                            aws_sdk_ec2::types::TransitGatewayAttachmentResourceType::Vpc,
+                        )
+                        .build(),
+                )
+                .build(),
+        ];
+        let routes_out =
+            ec2_search_transit_gateway_routes_output(&routes, false).expect("routes output");
+        let routes_json: Value = serde_json::from_str(&routes_out).expect("valid json");
+        assert_eq!(routes_json["Routes"][0]["Type"], "propagated");
+        assert_eq!(
+            routes_json["Routes"][0]["TransitGatewayAttachments"][0]["ResourceId"],
+            "vpc-1"
+        );
+        assert_eq!(routes_json["AdditionalRoutesAvailable"], false);
+    }
+
+    #[test]
+    fn ec2_network_acls_output_matches_cli_shape() {
+        let acls = vec![
+            aws_sdk_ec2::types::NetworkAcl::builder()
+                .network_acl_id("acl-1")
+                .vpc_id("vpc-1")
+                .is_default(true)
+                .entries(
+                    aws_sdk_ec2::types::NetworkAclEntry::builder()
+                        .rule_number(100)
+                        .protocol("6")
+                        .rule_action(aws_sdk_ec2::types::RuleAction::Allow)
+                        .egress(false)
+                        .cidr_block("0.0.0.0/0")
+                        .port_range(
+                            aws_sdk_ec2::types::PortRange::builder()
+                                .from(443)
+                                .to(443)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .entries(
+                    aws_sdk_ec2::types::NetworkAclEntry::builder()
+                        .rule_number(32767)
+                        .protocol("-1")
+                        .rule_action(aws_sdk_ec2::types::RuleAction::Deny)
+                        .egress(true)
+                        .cidr_block("0.0.0.0/0")
+                        .build(),
+                )
+                .associations(
+                    aws_sdk_ec2::types::NetworkAclAssociation::builder()
+                        .network_acl_id("acl-1")
+                        .subnet_id("subnet-a")
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "default-acl"))
+                .build(),
+        ];
+        let out = ec2_describe_network_acls_output(&acls).expect("acl output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let acl = &json["NetworkAcls"][0];
+        assert_eq!(acl["NetworkAclId"], "acl-1");
+        assert_eq!(acl["IsDefault"], true);
+        assert_eq!(acl["Entries"][0]["RuleAction"], "allow");
+        assert_eq!(acl["Entries"][0]["PortRange"]["From"], 443);
+        assert_eq!(acl["Entries"][1]["Egress"], true);
+        assert!(acl["Entries"][1]["PortRange"].is_null());
+        assert_eq!(acl["Associations"][0]["SubnetId"], "subnet-a");
+    }
+
+    #[test]
+    fn ec2_network_interfaces_output_matches_cli_shape() {
+        let interfaces = vec![
+            aws_sdk_ec2::types::NetworkInterface::builder()
+                .network_interface_id("eni-1")
+                .description("ELB app/web/1")
+                .interface_type(aws_sdk_ec2::types::NetworkInterfaceType::Interface)
+                .status(aws_sdk_ec2::types::NetworkInterfaceStatus::InUse)
+                .subnet_id("subnet-a")
+                .private_ip_addresses(
+                    aws_sdk_ec2::types::NetworkInterfacePrivateIpAddress::builder()
+                        .private_ip_address("10.0.1.20")
+                        .primary(true)
+                        .association(
+                            aws_sdk_ec2::types::NetworkInterfaceAssociation::builder()
+                                .public_ip("3.34.1.2")
+                                .build(),
+                        )
+                        .build(),
+                )
+                .attachment(
+                    aws_sdk_ec2::types::NetworkInterfaceAttachment::builder()
+                        .instance_owner_id("amazon-elb")
+                        .device_index(1)
+                        .status(aws_sdk_ec2::types::AttachmentStatus::Attached)
+                        .build(),
+                )
+                .requester_managed(true)
+                .build(),
+            aws_sdk_ec2::types::NetworkInterface::builder()
+                .network_interface_id("eni-2")
+                .status(aws_sdk_ec2::types::NetworkInterfaceStatus::Available)
+                .tag_set(ec2_test_tag("Name", "old-db"))
+                .build(),
+        ];
+        let out = ec2_describe_network_interfaces_output(&interfaces).expect("eni output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let managed = &json["NetworkInterfaces"][0];
+        assert_eq!(managed["InterfaceType"], "interface");
+        assert_eq!(managed["Status"], "in-use");
+        assert_eq!(
+            managed["PrivateIpAddresses"][0]["Association"]["PublicIp"],
+            "3.34.1.2"
+        );
+        assert_eq!(managed["Attachment"]["InstanceOwnerId"], "amazon-elb");
+        assert_eq!(managed["Attachment"]["DeviceIndex"], 1);
+        assert_eq!(managed["Attachment"]["Status"], "attached");
+        // 연결되지 않은 ENI는 Attachment가 없음
+        let orphan = &json["NetworkInterfaces"][1];
+        assert!(orphan["Attachment"].is_null());
+        assert_eq!(orphan["TagSet"][0]["Value"], "old-db");
+    }
+
+    #[test]
+    fn ec2_owned_image_outputs_match_cli_shape() {
+        let images = vec![
+            aws_sdk_ec2::types::Image::builder()
+                .image_id("ami-1")
+                .name("web-2026-10")
+                .owner_id("111122223333")
+                .state(aws_sdk_ec2::types::ImageState::Available)
+                .creation_date("2026-10-01T02:03:04.000Z")
+                .source_instance_id("i-0abc")
+                .architecture(aws_sdk_ec2::types::ArchitectureValues::X8664)
+                .public(false)
+                .block_device_mappings(
+                    aws_sdk_ec2::types::BlockDeviceMapping::builder()
+                        .device_name("/dev/xvda")
+                        .ebs(
+                            aws_sdk_ec2::types::EbsBlockDevice::builder()
+                                .snapshot_id("snap-1")
+                                .volume_size(30)
+                                .volume_type(aws_sdk_ec2::types::VolumeType::Gp3)
+                                .encrypted(true)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        ];
+        let out = ec2_describe_images_output(&images).expect("images output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let image = &json["Images"][0];
+        assert_eq!(image["State"], "available");
+        assert_eq!(image["CreationDate"], "2026-10-01T02:03:04.000Z");
+        assert_eq!(image["SourceInstanceId"], "i-0abc");
+        assert_eq!(image["Architecture"], "x86_64");
+        assert_eq!(image["Public"], false);
+        let ebs = &image["BlockDeviceMappings"][0]["Ebs"];
+        assert_eq!(ebs["SnapshotId"], "snap-1");
+        assert_eq!(ebs["VolumeSize"], 30);
+        assert_eq!(ebs["VolumeType"], "gp3");
+
+        let permissions = vec![
+            aws_sdk_ec2::types::LaunchPermission::builder()
+                .user_id("444455556666")
+                .build(),
+            aws_sdk_ec2::types::LaunchPermission::builder()
+                .group(aws_sdk_ec2::types::PermissionGroup::All)
+                .build(),
+        ];
+        let out =
+            ec2_describe_image_attribute_output("ami-1", &permissions).expect("attribute output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        assert_eq!(json["ImageId"], "ami-1");
+        assert_eq!(json["LaunchPermissions"][0]["UserId"], "444455556666");
+        assert!(json["LaunchPermissions"][0]["Group"].is_null());
+        assert_eq!(json["LaunchPermissions"][1]["Group"], "all");
+    }
+
+    #[test]
+    fn ec2_key_pairs_output_matches_cli_shape() {
+        let key_pairs = vec![
+            aws_sdk_ec2::types::KeyPairInfo::builder()
+                .key_pair_id("key-0abc")
+                .key_name("deploy")
+                .key_fingerprint("SHA256:abcd")
+                .key_type(aws_sdk_ec2::types::KeyType::Ed25519)
+                .public_key("ssh-ed25519 AAAA deploy")
+                .tags(ec2_test_tag("Team", "platform"))
+                .build(),
+            aws_sdk_ec2::types::KeyPairInfo::builder()
+                .key_pair_id("key-0def")
+                .key_name("legacy")
+                .key_type(aws_sdk_ec2::types::KeyType::Rsa)
+                .build(),
+        ];
+        let out = ec2_describe_key_pairs_output(&key_pairs).expect("key pair output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let key_pair = &json["KeyPairs"][0];
+        assert_eq!(key_pair["KeyPairId"], "key-0abc");
+        assert_eq!(key_pair["KeyName"], "deploy");
+        assert_eq!(key_pair["KeyFingerprint"], "SHA256:abcd");
+        assert_eq!(key_pair["KeyType"], "ed25519");
+        assert_eq!(key_pair["PublicKey"], "ssh-ed25519 AAAA deploy");
+        assert_eq!(key_pair["Tags"][0]["Key"], "Team");
+        assert_eq!(json["KeyPairs"][1]["KeyType"], "rsa");
+        assert!(json["KeyPairs"][1]["PublicKey"].is_null());
+    }
+
+    #[test]
+    fn ec2_launch_template_outputs_match_cli_shape() {
+        let templates = vec![
+            aws_sdk_ec2::types::LaunchTemplate::builder()
+                .launch_template_id("lt-1")
+                .launch_template_name("web")
+                .default_version_number(2)
+                .latest_version_number(3)
+                .tags(ec2_test_tag("Team", "platform"))
+                .build(),
+        ];
+        let out = ec2_describe_launch_templates_output(&templates).expect("template output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let template = &json["LaunchTemplates"][0];
+        assert_eq!(template["LaunchTemplateName"], "web");
+        assert_eq!(template["DefaultVersionNumber"], 2);
+        assert_eq!(template["LatestVersionNumber"], 3);
+        assert_eq!(template["Tags"][0]["Key"], "Team");
+
+        let versions = vec![
+            aws_sdk_ec2::types::LaunchTemplateVersion::builder()
+                .launch_template_id("lt-1")
+                .version_number(2)
+                .default_version(true)
+                .launch_template_data(
+                    aws_sdk_ec2::types::ResponseLaunchTemplateData::builder()
+                        .image_id("ami-1")
+                        .instance_type(aws_sdk_ec2::types::InstanceType::T3Micro)
+                        .iam_instance_profile(
+                            aws_sdk_ec2::types::LaunchTemplateIamInstanceProfileSpecification::builder()
+                                .name("web-role")
+                                .build(),
+                        )
+                        .security_group_ids("sg-1")
+                        .network_interfaces(
+                            aws_sdk_ec2::types::LaunchTemplateInstanceNetworkInterfaceSpecification::builder()
+                                .groups("sg-2")
+                                .build(),
+                        )
+                        .block_device_mappings(
+                            aws_sdk_ec2::types::LaunchTemplateBlockDeviceMapping::builder()
+                                .device_name("/dev/xvda")
+                                .ebs(
+                                    aws_sdk_ec2::types::LaunchTemplateEbsBlockDevice::builder()
+                                        .volume_size(20)
+                                        .volume_type(aws_sdk_ec2::types::VolumeType::Gp3)
+                                        .encrypted(true)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .user_data("IyEvYmluL2Jhc2gK")
+                        .build(),
+                )
+                .build(),
+            aws_sdk_ec2::types::LaunchTemplateVersion::builder()
+                .launch_template_id("lt-1")
+                .version_number(3)
+                .build(),
+        ];
+        let out = ec2_describe_launch_template_versions_output(&versions).expect("version output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let default = &json["LaunchTemplateVersions"][0];
+        assert_eq!(default["VersionNumber"], 2);
+        assert_eq!(default["DefaultVersion"], true);
+        let data = &default["LaunchTemplateData"];
+        assert_eq!(data["ImageId"], "ami-1");
+        assert_eq!(data["InstanceType"], "t3.micro");
+        assert_eq!(data["IamInstanceProfile"]["Name"], "web-role");
+        // 네트워크 인터페이스의 보안 그룹도 함께 나열
+        assert_eq!(data["SecurityGroupIds"][0], "sg-1");
+        assert_eq!(data["SecurityGroupIds"][1], "sg-2");
+        assert_eq!(data["BlockDeviceMappings"][0]["DeviceName"], "/dev/xvda");
+        assert_eq!(data["BlockDeviceMappings"][0]["Ebs"]["VolumeSize"], 20);
+        assert_eq!(data["BlockDeviceMappings"][0]["Ebs"]["VolumeType"], "gp3");
+        assert!(data["BlockDeviceMappings"][0]["Ebs"]["Iops"].is_null());
+        assert_eq!(data["UserData"], "IyEvYmluL2Jhc2gK");
+        let latest = &json["LaunchTemplateVersions"][1];
+        assert_eq!(latest["DefaultVersion"], false);
+        assert!(latest["LaunchTemplateData"].is_null());
+    }
+
+    #[test]
+    fn ec2_vpc_endpoints_output_matches_cli_shape() {
+        let endpoints = vec![
+            aws_sdk_ec2::types::VpcEndpoint::builder()
+                .vpc_endpoint_id("vpce-1")
+                .vpc_endpoint_type(aws_sdk_ec2::types::VpcEndpointType::Interface)
+                .vpc_id("vpc-1")
+                .service_name("com.amazonaws.ap-northeast-2.ssm")
+                .state(aws_sdk_ec2::types::State::from("available"))
+                .subnet_ids("subnet-a")
+                .groups(
+                    aws_sdk_ec2::types::SecurityGroupIdentifier::builder()
+                        .group_id("sg-1")
+                        .group_name("endpoints")
+                        .build(),
+                )
+                .private_dns_enabled(true)
+                .dns_entries(
+                    aws_sdk_ec2::types::DnsEntry::builder()
+                        .dns_name("vpce-1.ssm.ap-northeast-2.vpce.amazonaws.com")
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "ssm"))
+                .build(),
+        ];
+        let out = ec2_describe_vpc_endpoints_output(&endpoints).expect("endpoint output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let endpoint = &json["VpcEndpoints"][0];
+        assert_eq!(endpoint["VpcEndpointId"], "vpce-1");
+        assert_eq!(endpoint["VpcEndpointType"], "Interface");
+        assert_eq!(endpoint["State"], "available");
+        assert_eq!(endpoint["SubnetIds"][0], "subnet-a");
+        assert_eq!(endpoint["Groups"][0]["GroupName"], "endpoints");
+        assert_eq!(endpoint["PrivateDnsEnabled"], true);
+        assert_eq!(
+            endpoint["DnsEntries"][0]["DnsName"],
+            "vpce-1.ssm.ap-northeast-2.vpce.amazonaws.com"
+        );
+        // 정책을 지원하지 않는 엔드포인트는 빈 문자열
+        assert_eq!(endpoint["PolicyDocument"], "");
+        assert_eq!(endpoint["RouteTableIds"].as_array().map(Vec::len), Some(0));
+    }
+
+    #[test]
+    fn ec2_vpc_peering_output_matches_cli_shape() {
+        let peerings = vec![
+            aws_sdk_ec2::types::VpcPeeringConnection::builder()
+                .vpc_peering_connection_id("pcx-1")
+                .requester_vpc_info(
+                    aws_sdk_ec2::types::VpcPeeringConnectionVpcInfo::builder()
+                        .vpc_id("vpc-1")
+                        .owner_id("111122223333")
+                        .region("ap-northeast-2")
+                        .cidr_block("10.0.0.0/16")
+                        .cidr_block_set(
+                            aws_sdk_ec2::types::CidrBlock::builder()
+                                .cidr_block("10.0.0.0/16")
+                                .build(),
+                        )
+                        .peering_options(
+                            aws_sdk_ec2::types::VpcPeeringConnectionOptionsDescription::builder()
+                                .allow_dns_resolution_from_remote_vpc(true)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .accepter_vpc_info(
+                    aws_sdk_ec2::types::VpcPeeringConnectionVpcInfo::builder()
+                        .vpc_id("vpc-2")
+                        .owner_id("444455556666")
+                        .region("us-east-1")
+                        .build(),
+                )
+                .status(
+                    aws_sdk_ec2::types::VpcPeeringConnectionStateReason::builder()
+                        .code(aws_sdk_ec2::types::VpcPeeringConnectionStateReasonCode::PendingAcceptance)
+                        .message("Pending Acceptance by 444455556666")
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "app-to-shared"))
+                .build(),
+        ];
+        let out = ec2_describe_vpc_peering_connections_output(&peerings).expect("peering output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let peering = &json["VpcPeeringConnections"][0];
+        assert_eq!(peering["VpcPeeringConnectionId"], "pcx-1");
+        assert_eq!(peering["Status"]["Code"], "pending-acceptance");
+        assert_eq!(
+            peering["RequesterVpcInfo"]["CidrBlockSet"][0]["CidrBlock"],
+            "10.0.0.0/16"
+        );
+        assert_eq!(
+            peering["RequesterVpcInfo"]["PeeringOptions"]["AllowDnsResolutionFromRemoteVpc"],
+            true
+        );
+        // 수락 전에는 수락자 쪽 CIDR과 옵션이 없음
+        assert_eq!(peering["AccepterVpcInfo"]["CidrBlock"], "");
+        assert!(peering["AccepterVpcInfo"]["PeeringOptions"].is_null());
+        assert_eq!(peering["ExpirationTime"], "");
+    }
+
+    #[test]
+    fn ec2_vpn_outputs_match_cli_shape() {
+        let connections = vec![
+            aws_sdk_ec2::types::VpnConnection::builder()
+                .vpn_connection_id("vpn-1")
+                .state(aws_sdk_ec2::types::VpnState::Available)
+                .r#type(aws_sdk_ec2::types::GatewayType::Ipsec1)
+                .category("VPN")
+                .customer_gateway_id("cgw-1")
+                .vpn_gateway_id("vgw-1")
+                .options(
+                    aws_sdk_ec2::types::VpnConnectionOptions::builder()
+                        .static_routes_only(true)
+                        .local_ipv4_network_cidr("0.0.0.0/0")
+                        .tunnel_options(
+                            aws_sdk_ec2::types::TunnelOption::builder()
+                                .outside_ip_address("3.36.0.10")
+                                .tunnel_inside_cidr("169.254.10.0/30")
+                                .pre_shared_key("secret")
+                                .build(),
+                        )
+                        .build(),
+                )
+                .routes(
+                    aws_sdk_ec2::types::VpnStaticRoute::builder()
+                        .destination_cidr_block("192.168.0.0/16")
+                        .source(aws_sdk_ec2::types::VpnStaticRouteSource::Static)
+                        .state(aws_sdk_ec2::types::VpnState::Available)
+                        .build(),
+                )
+                .vgw_telemetry(
+                    aws_sdk_ec2::types::VgwTelemetry::builder()
+                        .outside_ip_address("3.36.0.10")
+                        .status(aws_sdk_ec2::types::TelemetryStatus::Up)
+                        .status_message("1 BGP ROUTES")
+                        .accepted_route_count(1)
+                        .build(),
+                )
+                .tags(ec2_test_tag("Name", "office-vpn"))
+                .build(),
+        ];
+        let out = ec2_describe_vpn_connections_output(&connections).expect("vpn output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        let connection = &json["VpnConnections"][0];
+        assert_eq!(connection["VpnConnectionId"], "vpn-1");
+        assert_eq!(connection["Type"], "ipsec.1");
+        assert_eq!(connection["VpnGatewayId"], "vgw-1");
+        assert!(connection["TransitGatewayId"].is_null());
+        assert_eq!(connection["Options"]["StaticRoutesOnly"], true);
+        let tunnel = &connection["Options"]["TunnelOptions"][0];
+        assert_eq!(tunnel["TunnelInsideCidr"], "169.254.10.0/30");
+        // 사전 공유 키는 출력에 넣지 않음
+        assert!(tunnel.get("PreSharedKey").is_none());
+        assert!(!out.contains("secret"));
+        assert_eq!(connection["Routes"][0]["Source"], "Static");
+        assert_eq!(connection["VgwTelemetry"][0]["Status"], "UP");
+        assert_eq!(connection["VgwTelemetry"][0]["AcceptedRouteCount"], 1);
+
+        let customer_gateways = vec![
+            aws_sdk_ec2::types::CustomerGateway::builder()
+                .customer_gateway_id("cgw-1")
+                .state("available")
+                .r#type("ipsec.1")
+                .ip_address("203.0.113.10")
+                .bgp_asn("65000")
+                .tags(ec2_test_tag("Name", "office-router"))
+                .build(),
+        ];
+        let out = ec2_describe_customer_gateways_output(&customer_gateways).expect("cgw output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        assert_eq!(json["CustomerGateways"][0]["IpAddress"], "203.0.113.10");
+        assert_eq!(json["CustomerGateways"][0]["BgpAsn"], "65000");
+        assert_eq!(json["CustomerGateways"][0]["DeviceName"], "");
+
+        let vpn_gateways = vec![
+            aws_sdk_ec2::types::VpnGateway::builder()
+                .vpn_gateway_id("vgw-1")
+                .state(aws_sdk_ec2::types::VpnState::Available)
+                .r#type(aws_sdk_ec2::types::GatewayType::Ipsec1)
+                .amazon_side_asn(64512)
+                .vpc_attachments(
+                    aws_sdk_ec2::types::VpcAttachment::builder()
+                        .vpc_id("vpc-1")
+                        .state(aws_sdk_ec2::types::AttachmentStatus::Attached)
+                        .build(),
+                )
+                .build(),
+        ];
+        let out = ec2_describe_vpn_gateways_output(&vpn_gateways).expect("vgw output");
+        let json: Value = serde_json::from_str(&out).expect("valid json");
+        assert_eq!(json["VpnGateways"][0]["AmazonSideAsn"], 64512);
+        assert_eq!(
+            json["VpnGateways"][0]["VpcAttachments"][0]["State"],
+            "attached"
+        );
+    }
+
+    #[test]
+    fn map_concurrent_keeps_order_within_the_request_limit() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let results = get_runtime().block_on(map_concurrent_with(2, 1..=5usize, |n| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let running = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(running, Ordering::SeqCst);
+                // 뒤 항목이 먼저 끝나도 결과 순서는 입력 순서를 따름
+                for _ in n..5 {
+                    tokio::task::yield_now().await;
+                }
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                n * 10
+            }
+        }));
+        assert_eq!(results, vec![10, 20, 30, 40, 50]);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+
+        // 0이면 한 번에 하나씩
+        let results = get_runtime().block_on(map_concurrent_with(0, ["a", "b"], |s| async move {
+            s.to_uppercase()
+        }));
+        assert_eq!(results, vec!["A", "B"]);
+    }
+
     #[test]
     fn ecr_outputs_follow_expected_object_shapes() {
         let repositories = vec![
@@ -2622,6 +4831,22 @@ mod tests {
         assert_eq!(arg_value(&args, "--query"), None);
     }
 
+    #[test]
+    fn arg_values_collects_values_until_next_flag() {
+        let args = [
+            "ec2",
+            "describe-volumes",
+            "--volume-ids",
+            "vol-1",
+            "vol-2",
+            "--output",
+            "json",
+        ];
+        assert_eq!(arg_values(&args, "--volume-ids"), vec!["vol-1", "vol-2"]);
+        assert_eq!(arg_values(&args, "--output"), vec!["json"]);
+        assert!(arg_values(&args, "--instance-ids").is_empty());
+    }
+
     #[test]
     fn extract_tags_deduplicates_keys() {
         let payload = r#"
@@ -2657,6 +4882,7 @@ mod tests {
             state: String::new(),
             az: String::new(),
             cidr: String::new(),
+            owner_id: String::new(),
         };
         assert_eq!(named.display(), "web (i-1234)");
 
@@ -2666,10 +4892,32 @@ mod tests {
             state: String::new(),
             az: String::new(),
             cidr: String::new(),
+            owner_id: String::new(),
         };
         assert_eq!(unnamed.display(), "i-5678");
     }
 
+    #[test]
+    fn aws_resource_display_marks_resources_owned_by_other_accounts() {
+        set_account_id("111122223333");
+        assert_eq!(foreign_owner("111122223333"), None);
+        assert_eq!(foreign_owner(""), None);
+        assert_eq!(foreign_owner("444455556666"), Some("444455556666"));
+
+        let shared = AwsResource {
+            name: "shared-vpc".to_string(),
+            id: "vpc-1234".to_string(),
+            state: String::new(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: "444455556666".to_string(),
+        };
+        assert_eq!(
+            shared.display(),
+            "shared-vpc (vpc-1234) [owner: 444455556666]"
+        );
+    }
+
     #[test]
     fn parse_filter_value_returns_none_for_mismatched_name() {
         let raw = "Name=tag:Env,Values=prod,staging";
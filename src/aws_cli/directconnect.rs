@@ -0,0 +1,401 @@
+pub use crate::aws_cli::directconnect_sdk::{
+    get_direct_connect_detail, list_direct_connect_resources,
+};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+/// Direct Connect connection (`dxcon-`) or virtual interface (`dxvif-`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectConnectDetail {
+    pub name: String,
+    pub id: String,
+    // 연결: ordering, requested, pending, available, down, ...
+    // 가상 인터페이스: confirming, verifying, pending, available, down, ...
+    pub state: String,
+    pub owner_account: String,
+    pub tags: Vec<(String, String)>,
+    pub resource: DirectConnectResource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DirectConnectResource {
+    Connection(DirectConnectConnectionInfo),
+    VirtualInterface(DirectConnectVirtualInterfaceInfo),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectConnectConnectionInfo {
+    pub location: String,
+    // 1Gbps, 10Gbps, 50Mbps (호스팅 연결)
+    pub bandwidth: String,
+    // 호스팅 연결만 VLAN이 할당됨
+    pub vlan: Option<i32>,
+    pub partner_name: String,
+    pub provider_name: String,
+    pub aws_device: String,
+    pub lag_id: Option<String>,
+    pub jumbo_frame_capable: bool,
+    // MACsec 지원 포트만 Some (암호화 상태, 암호화 모드)
+    pub mac_sec: Option<(String, String)>,
+    pub virtual_interfaces: Vec<DirectConnectVirtualInterfaceInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectConnectVirtualInterfaceInfo {
+    pub id: String,
+    pub name: String,
+    pub state: String,
+    // private, public, transit
+    pub interface_type: String,
+    pub connection_id: String,
+    pub location: String,
+    pub vlan: i32,
+    pub customer_asn: i64,
+    pub amazon_side_asn: Option<i64>,
+    // ipv4, ipv6
+    pub address_family: String,
+    pub amazon_address: String,
+    pub customer_address: String,
+    pub mtu: Option<i32>,
+    // 프라이빗 VIF는 가상 프라이빗 게이트웨이 또는 Direct Connect 게이트웨이에 연결
+    pub virtual_gateway_id: Option<String>,
+    pub direct_connect_gateway_id: Option<String>,
+    pub site_link_enabled: bool,
+    pub bgp_peers: Vec<DirectConnectBgpPeerInfo>,
+    // 퍼블릭 VIF에서 광고하는 접두사
+    pub route_filter_prefixes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectConnectBgpPeerInfo {
+    pub id: String,
+    pub address_family: String,
+    pub asn: i64,
+    pub amazon_address: String,
+    pub customer_address: String,
+    // pending, available, deleting, ...
+    pub state: String,
+    // up, down, unknown
+    pub status: String,
+}
+
+impl DirectConnectVirtualInterfaceInfo {
+    // 목록과 연결 문서에 쓰는 "1/2 up" 요약
+    pub(crate) fn bgp_summary(&self) -> String {
+        if self.bgp_peers.is_empty() {
+            return "-".to_string();
+        }
+        let up = self
+            .bgp_peers
+            .iter()
+            .filter(|peer| peer.status == "up")
+            .count();
+        format!("{}/{} up", up, self.bgp_peers.len())
+    }
+
+    fn push_markdown(&self, i18n: &I18n, lines: &mut Vec<String>) {
+        lines.push(format!("| {} | {} |", i18n.md_type(), self.interface_type));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_connection(),
+            self.connection_id
+        ));
+        if !self.location.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_location(), self.location));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_vlan(), self.vlan));
+        lines.push(format!("| {} | {} |", i18n.md_bgp_asn(), self.customer_asn));
+        if let Some(asn) = self.amazon_side_asn {
+            lines.push(format!("| {} | {} |", i18n.md_amazon_side_asn(), asn));
+        }
+        if let Some(mtu) = self.mtu {
+            lines.push(format!("| {} | {} |", i18n.md_mtu(), mtu));
+        }
+        if let Some(gateway_id) = &self.virtual_gateway_id {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_virtual_private_gateway(),
+                gateway_id
+            ));
+        }
+        if let Some(gateway_id) = &self.direct_connect_gateway_id {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_direct_connect_gateway(),
+                gateway_id
+            ));
+        }
+        if self.site_link_enabled {
+            lines.push(format!("| SiteLink | {} |", i18n.md_enabled()));
+        }
+    }
+}
+
+impl DirectConnectDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let title = match &self.resource {
+            DirectConnectResource::Connection(_) => i18n.direct_connect(),
+            DirectConnectResource::VirtualInterface(_) => i18n.md_virtual_interface(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+
+        match &self.resource {
+            DirectConnectResource::Connection(connection) => {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_location(),
+                    connection.location
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_bandwidth(),
+                    connection.bandwidth
+                ));
+                if let Some(vlan) = connection.vlan {
+                    lines.push(format!("| {} | {} |", i18n.md_vlan(), vlan));
+                }
+                if !connection.partner_name.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_partner(),
+                        connection.partner_name
+                    ));
+                }
+                if !connection.provider_name.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_provider(),
+                        connection.provider_name
+                    ));
+                }
+                if !connection.aws_device.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_device(),
+                        connection.aws_device
+                    ));
+                }
+                if let Some(lag_id) = &connection.lag_id {
+                    lines.push(format!("| LAG | {} |", lag_id));
+                }
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_jumbo_frames(),
+                    if connection.jumbo_frame_capable {
+                        i18n.md_enabled()
+                    } else {
+                        i18n.md_disabled()
+                    }
+                ));
+                if let Some((status, mode)) = &connection.mac_sec {
+                    let value = match (status.is_empty(), mode.is_empty()) {
+                        (false, false) => format!("{} ({})", status, mode),
+                        (false, true) => status.clone(),
+                        (true, false) => mode.clone(),
+                        (true, true) => "-".to_string(),
+                    };
+                    lines.push(format!("| MACsec | {} |", value));
+                }
+            }
+            DirectConnectResource::VirtualInterface(interface) => {
+                interface.push_markdown(&i18n, &mut lines);
+            }
+        }
+        if !self.owner_account.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_owner_account(),
+                self.owner_account
+            ));
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        match &self.resource {
+            DirectConnectResource::Connection(connection) => {
+                if !connection.virtual_interfaces.is_empty() {
+                    lines.push(format!("\n### {}", i18n.md_virtual_interfaces()));
+                    lines.push(format!(
+                        "| ID | {} | {} | {} | {} | {} |",
+                        i18n.md_name(),
+                        i18n.md_type(),
+                        i18n.md_vlan(),
+                        i18n.md_state(),
+                        i18n.md_bgp_status()
+                    ));
+                    lines.push("|:---|:---|:---|---:|:---|:---|".to_string());
+                    for interface in &connection.virtual_interfaces {
+                        lines.push(format!(
+                            "| {} | {} | {} | {} | {} | {} |",
+                            interface.id,
+                            interface.name,
+                            interface.interface_type,
+                            interface.vlan,
+                            interface.state,
+                            interface.bgp_summary()
+                        ));
+                    }
+                }
+            }
+            DirectConnectResource::VirtualInterface(interface) => {
+                if !interface.bgp_peers.is_empty() {
+                    lines.push(format!("\n### {}", i18n.md_bgp_peers()));
+                    lines.push(format!(
+                        "| ID | {} | {} | {} | {} | {} | {} |",
+                        i18n.md_address_family(),
+                        i18n.md_bgp_asn(),
+                        i18n.md_amazon_address(),
+                        i18n.md_customer_address(),
+                        i18n.md_state(),
+                        i18n.md_bgp_status()
+                    ));
+                    lines.push("|:---|:---|---:|:---|:---|:---|:---|".to_string());
+                    for peer in &interface.bgp_peers {
+                        lines.push(format!(
+                            "| {} | {} | {} | {} | {} | {} | {} |",
+                            peer.id,
+                            peer.address_family,
+                            peer.asn,
+                            or_dash(&peer.amazon_address),
+                            or_dash(&peer.customer_address),
+                            peer.state,
+                            peer.status
+                        ));
+                    }
+                }
+                if !interface.route_filter_prefixes.is_empty() {
+                    lines.push(format!("\n### {}", i18n.md_route_filter_prefixes()));
+                    for prefix in &interface.route_filter_prefixes {
+                        lines.push(format!("- {}", prefix));
+                    }
+                }
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn or_dash(value: &str) -> &str {
+    if value.is_empty() { "-" } else { value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DirectConnectBgpPeerInfo, DirectConnectConnectionInfo, DirectConnectDetail,
+        DirectConnectResource, DirectConnectVirtualInterfaceInfo,
+    };
+    use crate::i18n::Language;
+
+    fn sample_interface() -> DirectConnectVirtualInterfaceInfo {
+        let peer = |id: &str, status: &str| DirectConnectBgpPeerInfo {
+            id: id.to_string(),
+            address_family: "ipv4".to_string(),
+            asn: 65000,
+            amazon_address: "169.254.20.1/30".to_string(),
+            customer_address: "169.254.20.2/30".to_string(),
+            state: "available".to_string(),
+            status: status.to_string(),
+        };
+        DirectConnectVirtualInterfaceInfo {
+            id: "dxvif-md-1".to_string(),
+            name: "prod-private".to_string(),
+            state: "available".to_string(),
+            interface_type: "private".to_string(),
+            connection_id: "dxcon-md-1".to_string(),
+            location: "LGSN1".to_string(),
+            vlan: 101,
+            customer_asn: 65000,
+            amazon_side_asn: Some(64512),
+            address_family: "ipv4".to_string(),
+            amazon_address: "169.254.20.1/30".to_string(),
+            customer_address: "169.254.20.2/30".to_string(),
+            mtu: Some(9001),
+            virtual_gateway_id: None,
+            direct_connect_gateway_id: Some("dxgw-md-1".to_string()),
+            site_link_enabled: false,
+            bgp_peers: vec![peer("dxpeer-1", "up"), peer("dxpeer-2", "down")],
+            route_filter_prefixes: vec![],
+        }
+    }
+
+    #[test]
+    fn direct_connect_connection_markdown_lists_virtual_interfaces() {
+        let detail = DirectConnectDetail {
+            name: "seoul-primary".to_string(),
+            id: "dxcon-md-1".to_string(),
+            state: "available".to_string(),
+            owner_account: "123456789012".to_string(),
+            tags: vec![("Name".to_string(), "seoul-primary".to_string())],
+            resource: DirectConnectResource::Connection(DirectConnectConnectionInfo {
+                location: "LGSN1".to_string(),
+                bandwidth: "10Gbps".to_string(),
+                vlan: None,
+                partner_name: String::new(),
+                provider_name: String::new(),
+                aws_device: "LGSN1-abc123".to_string(),
+                lag_id: None,
+                jumbo_frame_capable: true,
+                mac_sec: Some(("Encryption Up".to_string(), "must_encrypt".to_string())),
+                virtual_interfaces: vec![sample_interface()],
+            }),
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Direct Connect (seoul-primary - dxcon-md-1)"));
+        assert!(md.contains("| Bandwidth | 10Gbps |"));
+        assert!(md.contains("| Jumbo Frames | Enabled |"));
+        assert!(md.contains("| MACsec | Encryption Up (must_encrypt) |"));
+        // 전용 연결은 VLAN 행이 없음 (VIF 표의 VLAN 열만 존재)
+        assert!(!md.lines().any(|line| line.starts_with("| VLAN |")));
+        assert!(md.contains("### Virtual Interfaces"));
+        assert!(md.contains("| dxvif-md-1 | prod-private | private | 101 | available | 1/2 up |"));
+        assert!(!md.contains("Tag-Name"));
+    }
+
+    #[test]
+    fn direct_connect_virtual_interface_markdown_shows_bgp_peers() {
+        let interface = sample_interface();
+        let detail = DirectConnectDetail {
+            name: interface.name.clone(),
+            id: interface.id.clone(),
+            state: interface.state.clone(),
+            owner_account: String::new(),
+            tags: vec![],
+            resource: DirectConnectResource::VirtualInterface(interface),
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Virtual Interface (prod-private - dxvif-md-1)"));
+        assert!(md.contains("| VLAN | 101 |"));
+        assert!(md.contains("| Amazon Side ASN | 64512 |"));
+        assert!(md.contains("| Direct Connect Gateway | dxgw-md-1 |"));
+        assert!(md.contains("### BGP Peers"));
+        assert!(md.contains(
+            "| dxpeer-2 | ipv4 | 65000 | 169.254.20.1/30 | 169.254.20.2/30 | available | down |"
+        ));
+        assert!(!md.contains("Route Filter Prefixes"));
+    }
+}
@@ -0,0 +1,434 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::directconnect::{
+    DirectConnectBgpPeerInfo, DirectConnectConnectionInfo, DirectConnectDetail,
+    DirectConnectResource, DirectConnectVirtualInterfaceInfo,
+};
+use aws_sdk_directconnect::types::{BgpPeer, Connection, Tag, VirtualInterface};
+
+/// List Direct Connect connections and virtual interfaces using AWS SDK
+pub fn list_direct_connect_resources() -> Vec<AwsResource> {
+    get_runtime().block_on(list_direct_connect_resources_async())
+}
+
+// MaxResults를 지정하지 않으면 한 번에 모두 반환
+async fn list_direct_connect_resources_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_directconnect, &config);
+
+    let mut connections: Vec<AwsResource> = match client.describe_connections().send().await {
+        Ok(output) => output
+            .connections()
+            .iter()
+            .filter(|connection| !is_deleted(connection.connection_state().map(|s| s.as_str())))
+            .map(map_connection_resource)
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing Direct Connect connections: {:?}", e);
+            Vec::new()
+        }
+    };
+    let mut interfaces: Vec<AwsResource> = match client.describe_virtual_interfaces().send().await {
+        Ok(output) => output
+            .virtual_interfaces()
+            .iter()
+            .filter(|interface| {
+                !is_deleted(interface.virtual_interface_state().map(|s| s.as_str()))
+            })
+            .map(map_virtual_interface_resource)
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing Direct Connect virtual interfaces: {:?}", e);
+            Vec::new()
+        }
+    };
+
+    // 연결 다음에 가상 인터페이스, 각각 이름순
+    connections.sort_by(|a, b| a.name.cmp(&b.name));
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    connections.extend(interfaces);
+    connections
+}
+
+/// Get Direct Connect connection (with its virtual interfaces) or virtual interface detail using AWS SDK
+pub fn get_direct_connect_detail(resource_id: &str) -> Option<DirectConnectDetail> {
+    get_runtime().block_on(get_direct_connect_detail_async(resource_id))
+}
+
+async fn get_direct_connect_detail_async(resource_id: &str) -> Option<DirectConnectDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_directconnect, &config);
+
+    if resource_id.starts_with("dxvif-") {
+        let output = match client
+            .describe_virtual_interfaces()
+            .virtual_interface_id(resource_id)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::error!(
+                    "Error describing Direct Connect virtual interface {}: {:?}",
+                    resource_id,
+                    e
+                );
+                return None;
+            }
+        };
+        return output
+            .virtual_interfaces()
+            .first()
+            .map(map_virtual_interface_detail);
+    }
+
+    let output = match client
+        .describe_connections()
+        .connection_id(resource_id)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!(
+                "Error describing Direct Connect connection {}: {:?}",
+                resource_id,
+                e
+            );
+            return None;
+        }
+    };
+    let connection = output.connections().first()?;
+
+    // 가상 인터페이스 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let interfaces = match client
+        .describe_virtual_interfaces()
+        .connection_id(resource_id)
+        .send()
+        .await
+    {
+        Ok(output) => output.virtual_interfaces().to_vec(),
+        Err(e) => {
+            tracing::error!(
+                "Error listing virtual interfaces of Direct Connect connection {}: {:?}",
+                resource_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+    Some(map_connection_detail(connection, &interfaces))
+}
+
+fn is_deleted(state: Option<&str>) -> bool {
+    matches!(state, Some("deleted") | Some("rejected"))
+}
+
+fn sorted_tags(tags: &[Tag]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .iter()
+        .map(|tag| {
+            (
+                tag.key().to_string(),
+                tag.value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    tags.sort();
+    tags
+}
+
+fn name_or_id(name: Option<&str>, id: &str) -> String {
+    match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => id.to_string(),
+    }
+}
+
+// 목록에는 "대역폭 @ 위치"를 표시
+fn map_connection_resource(connection: &Connection) -> AwsResource {
+    let id = connection.connection_id().unwrap_or_default().to_string();
+    AwsResource {
+        name: name_or_id(connection.connection_name(), &id),
+        id,
+        state: connection
+            .connection_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: format!(
+            "{} @ {}",
+            connection.bandwidth().unwrap_or_default(),
+            connection.location().unwrap_or_default()
+        ),
+        owner_id: connection.owner_account().unwrap_or_default().to_string(),
+    }
+}
+
+// 목록에는 VLAN과 BGP 피어 상태를 표시
+fn map_virtual_interface_resource(interface: &VirtualInterface) -> AwsResource {
+    let info = map_virtual_interface(interface);
+    AwsResource {
+        cidr: format!("VLAN {}, BGP {}", info.vlan, info.bgp_summary()),
+        name: info.name,
+        id: info.id,
+        state: info.state,
+        az: String::new(),
+        owner_id: interface.owner_account().unwrap_or_default().to_string(),
+    }
+}
+
+fn map_bgp_peer(peer: &BgpPeer) -> DirectConnectBgpPeerInfo {
+    DirectConnectBgpPeerInfo {
+        id: peer.bgp_peer_id().unwrap_or_default().to_string(),
+        address_family: peer
+            .address_family()
+            .map(|family| family.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        asn: peer.asn_long().unwrap_or(peer.asn() as i64),
+        amazon_address: peer.amazon_address().unwrap_or_default().to_string(),
+        customer_address: peer.customer_address().unwrap_or_default().to_string(),
+        state: peer
+            .bgp_peer_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        status: peer
+            .bgp_status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+fn map_virtual_interface(interface: &VirtualInterface) -> DirectConnectVirtualInterfaceInfo {
+    let id = interface
+        .virtual_interface_id()
+        .unwrap_or_default()
+        .to_string();
+    DirectConnectVirtualInterfaceInfo {
+        name: name_or_id(interface.virtual_interface_name(), &id),
+        id,
+        state: interface
+            .virtual_interface_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        interface_type: interface
+            .virtual_interface_type()
+            .unwrap_or_default()
+            .to_string(),
+        connection_id: interface.connection_id().unwrap_or_default().to_string(),
+        location: interface.location().unwrap_or_default().to_string(),
+        vlan: interface.vlan(),
+        // 4바이트 ASN은 asn_long에만 담김
+        customer_asn: interface.asn_long().unwrap_or(interface.asn() as i64),
+        amazon_side_asn: interface.amazon_side_asn(),
+        address_family: interface
+            .address_family()
+            .map(|family| family.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        amazon_address: interface.amazon_address().unwrap_or_default().to_string(),
+        customer_address: interface.customer_address().unwrap_or_default().to_string(),
+        mtu: interface.mtu(),
+        virtual_gateway_id: interface
+            .virtual_gateway_id()
+            .filter(|id| !id.is_empty())
+            .map(str::to_string),
+        direct_connect_gateway_id: interface
+            .direct_connect_gateway_id()
+            .filter(|id| !id.is_empty())
+            .map(str::to_string),
+        site_link_enabled: interface.site_link_enabled().unwrap_or(false),
+        bgp_peers: interface.bgp_peers().iter().map(map_bgp_peer).collect(),
+        route_filter_prefixes: interface
+            .route_filter_prefixes()
+            .iter()
+            .filter_map(|prefix| prefix.cidr())
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+fn map_virtual_interface_detail(interface: &VirtualInterface) -> DirectConnectDetail {
+    let info = map_virtual_interface(interface);
+    DirectConnectDetail {
+        name: info.name.clone(),
+        id: info.id.clone(),
+        state: info.state.clone(),
+        owner_account: interface.owner_account().unwrap_or_default().to_string(),
+        tags: sorted_tags(interface.tags()),
+        resource: DirectConnectResource::VirtualInterface(info),
+    }
+}
+
+fn map_connection_detail(
+    connection: &Connection,
+    interfaces: &[VirtualInterface],
+) -> DirectConnectDetail {
+    let id = connection.connection_id().unwrap_or_default().to_string();
+    let mut virtual_interfaces: Vec<DirectConnectVirtualInterfaceInfo> = interfaces
+        .iter()
+        .filter(|interface| !is_deleted(interface.virtual_interface_state().map(|s| s.as_str())))
+        .map(map_virtual_interface)
+        .collect();
+    virtual_interfaces.sort_by_key(|interface| interface.vlan);
+
+    DirectConnectDetail {
+        name: name_or_id(connection.connection_name(), &id),
+        id,
+        state: connection
+            .connection_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        owner_account: connection.owner_account().unwrap_or_default().to_string(),
+        tags: sorted_tags(connection.tags()),
+        resource: DirectConnectResource::Connection(DirectConnectConnectionInfo {
+            location: connection.location().unwrap_or_default().to_string(),
+            bandwidth: connection.bandwidth().unwrap_or_default().to_string(),
+            // 전용 연결은 VLAN이 0
+            vlan: Some(connection.vlan()).filter(|vlan| *vlan > 0),
+            partner_name: connection.partner_name().unwrap_or_default().to_string(),
+            provider_name: connection.provider_name().unwrap_or_default().to_string(),
+            aws_device: connection
+                .aws_device_v2()
+                .or(connection.aws_device())
+                .unwrap_or_default()
+                .to_string(),
+            lag_id: connection
+                .lag_id()
+                .filter(|id| !id.is_empty())
+                .map(str::to_string),
+            jumbo_frame_capable: connection.jumbo_frame_capable().unwrap_or(false),
+            mac_sec: connection.mac_sec_capable().unwrap_or(false).then(|| {
+                (
+                    connection
+                        .port_encryption_status()
+                        .unwrap_or_default()
+                        .to_string(),
+                    connection.encryption_mode().unwrap_or_default().to_string(),
+                )
+            }),
+            virtual_interfaces,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_connection_detail, map_connection_resource, map_virtual_interface_detail,
+        map_virtual_interface_resource,
+    };
+    use crate::aws_cli::directconnect::DirectConnectResource;
+    use aws_sdk_directconnect::types::{
+        AddressFamily, BgpPeer, BgpPeerState, BgpStatus, Connection, ConnectionState,
+        RouteFilterPrefix, Tag, VirtualInterface, VirtualInterfaceState,
+    };
+
+    fn interface(id: &str, vlan: i32, state: VirtualInterfaceState) -> VirtualInterface {
+        let peer = |id: &str, status: BgpStatus| {
+            BgpPeer::builder()
+                .bgp_peer_id(id)
+                .asn(65000)
+                .address_family(AddressFamily::IPv4)
+                .amazon_address("169.254.20.1/30")
+                .customer_address("169.254.20.2/30")
+                .bgp_peer_state(BgpPeerState::Available)
+                .bgp_status(status)
+                .build()
+        };
+        VirtualInterface::builder()
+            .virtual_interface_id(id)
+            .virtual_interface_name(format!("vif-{}", vlan))
+            .virtual_interface_type("public")
+            .virtual_interface_state(state)
+            .connection_id("dxcon-1")
+            .vlan(vlan)
+            .asn(0)
+            .asn_long(4_200_000_000)
+            .address_family(AddressFamily::IPv4)
+            .bgp_peers(peer("dxpeer-1", BgpStatus::Up))
+            .bgp_peers(peer("dxpeer-2", BgpStatus::Down))
+            .route_filter_prefixes(RouteFilterPrefix::builder().cidr("203.0.113.0/24").build())
+            .build()
+    }
+
+    fn connection() -> Connection {
+        Connection::builder()
+            .connection_id("dxcon-1")
+            .connection_name("seoul-primary")
+            .connection_state(ConnectionState::Available)
+            .location("LGSN1")
+            .bandwidth("10Gbps")
+            .vlan(0)
+            .aws_device("LGSN1-old")
+            .aws_device_v2("LGSN1-abc123")
+            .jumbo_frame_capable(true)
+            .mac_sec_capable(false)
+            .owner_account("123456789012")
+            .tags(
+                Tag::builder()
+                    .key("Team")
+                    .value("net")
+                    .build()
+                    .expect("tag"),
+            )
+            .build()
+    }
+
+    #[test]
+    fn map_resources_summarize_bandwidth_and_bgp_status() {
+        let resource = map_connection_resource(&connection());
+        assert_eq!(resource.name, "seoul-primary");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "10Gbps @ LGSN1");
+
+        let resource =
+            map_virtual_interface_resource(&interface("dxvif-1", 101, VirtualInterfaceState::Down));
+        assert_eq!(resource.name, "vif-101");
+        assert_eq!(resource.state, "down");
+        assert_eq!(resource.cidr, "VLAN 101, BGP 1/2 up");
+    }
+
+    #[test]
+    fn map_details_read_connection_and_virtual_interfaces() {
+        let detail = map_connection_detail(
+            &connection(),
+            &[
+                interface("dxvif-2", 200, VirtualInterfaceState::Available),
+                interface("dxvif-1", 101, VirtualInterfaceState::Available),
+                interface("dxvif-3", 300, VirtualInterfaceState::Deleted),
+            ],
+        );
+        assert_eq!(detail.tags, vec![("Team".to_string(), "net".to_string())]);
+        let DirectConnectResource::Connection(connection) = &detail.resource else {
+            panic!("expected a connection");
+        };
+        // 전용 연결은 VLAN 없음, MACsec 미지원 포트는 None
+        assert_eq!(connection.vlan, None);
+        assert_eq!(connection.mac_sec, None);
+        assert_eq!(connection.aws_device, "LGSN1-abc123");
+        let ids: Vec<&str> = connection
+            .virtual_interfaces
+            .iter()
+            .map(|interface| interface.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["dxvif-1", "dxvif-2"]);
+
+        let detail = map_virtual_interface_detail(&interface(
+            "dxvif-1",
+            101,
+            VirtualInterfaceState::Available,
+        ));
+        let DirectConnectResource::VirtualInterface(interface) = &detail.resource else {
+            panic!("expected a virtual interface");
+        };
+        assert_eq!(interface.customer_asn, 4_200_000_000);
+        assert_eq!(interface.bgp_peers[1].status, "down");
+        assert_eq!(interface.route_filter_prefixes, vec!["203.0.113.0/24"]);
+    }
+}
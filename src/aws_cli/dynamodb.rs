@@ -0,0 +1,240 @@
+pub use crate::aws_cli::dynamodb_sdk::{get_dynamodb_detail, list_dynamodb_tables};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DynamoDbIndex {
+    pub name: String,
+    pub partition_key: String,
+    pub sort_key: Option<String>,
+    pub projection: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct DynamoDbDetail {
+    pub name: String,
+    pub arn: String,
+    pub status: String,
+    // "pk (S)" 형식: 속성 이름과 타입
+    pub partition_key: String,
+    pub sort_key: Option<String>,
+    pub billing_mode: String,
+    pub read_capacity: i64,
+    pub write_capacity: i64,
+    pub table_class: String,
+    pub item_count: i64,
+    pub size_bytes: i64,
+    pub global_indexes: Vec<DynamoDbIndex>,
+    pub local_indexes: Vec<DynamoDbIndex>,
+    // TTL이 활성화된 경우에만 만료 속성 이름
+    pub ttl_attribute: Option<String>,
+    // 스트림이 활성화된 경우에만 뷰 유형
+    pub stream_view_type: Option<String>,
+    // SSE 설명이 없으면 AWS 소유 키로 암호화된 테이블
+    pub sse_type: Option<String>,
+    pub kms_key_arn: Option<String>,
+    pub deletion_protection: bool,
+    pub tags: Vec<(String, String)>,
+}
+
+fn index_table(lines: &mut Vec<String>, title: &str, indexes: &[DynamoDbIndex], i18n: &I18n) {
+    lines.push(String::new());
+    lines.push(format!("### {}\n", title));
+    lines.push(format!(
+        "| {} | {} | {} | {} |",
+        i18n.md_index_name(),
+        i18n.md_partition_key(),
+        i18n.md_sort_key(),
+        i18n.md_projection()
+    ));
+    lines.push("|:---|:---|:---|:---|".to_string());
+    for index in indexes {
+        lines.push(format!(
+            "| {} | {} | {} | {} |",
+            index.name,
+            index.partition_key,
+            index.sort_key.as_deref().unwrap_or("-"),
+            index.projection
+        ));
+    }
+}
+
+impl DynamoDbDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.dynamodb_table(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_partition_key(), self.partition_key),
+        ];
+        if let Some(ref sort_key) = self.sort_key {
+            lines.push(format!("| {} | {} |", i18n.md_sort_key(), sort_key));
+        }
+
+        let capacity = if self.billing_mode == "PAY_PER_REQUEST" {
+            i18n.md_on_demand().to_string()
+        } else {
+            format!(
+                "{} (RCU {} / WCU {})",
+                i18n.md_provisioned(),
+                self.read_capacity,
+                self.write_capacity
+            )
+        };
+        lines.push(format!("| {} | {} |", i18n.md_capacity_mode(), capacity));
+        if !self.table_class.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_table_class(),
+                self.table_class
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_item_count(),
+            self.item_count
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_size(),
+            crate::locale::bytes(self.size_bytes)
+        ));
+
+        let ttl = match self.ttl_attribute {
+            Some(ref attribute) => format!("{} (`{}`)", i18n.md_enabled(), attribute),
+            None => i18n.md_disabled().to_string(),
+        };
+        lines.push(format!("| TTL | {} |", ttl));
+        let stream = match self.stream_view_type {
+            Some(ref view_type) => format!("{} ({})", i18n.md_enabled(), view_type),
+            None => i18n.md_disabled().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_streams(), stream));
+        let encryption = match (&self.sse_type, &self.kms_key_arn) {
+            (Some(sse_type), Some(key)) => format!("{} ({})", sse_type, key),
+            (Some(sse_type), None) => sse_type.clone(),
+            (None, _) => i18n.md_aws_owned_key().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+        let deletion_protection = if self.deletion_protection {
+            i18n.md_enabled()
+        } else {
+            i18n.md_disabled()
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_deletion_protection(),
+            deletion_protection
+        ));
+
+        // Secondary Indexes
+        if !self.global_indexes.is_empty() {
+            index_table(
+                &mut lines,
+                i18n.md_global_secondary_indexes(),
+                &self.global_indexes,
+                &i18n,
+            );
+        }
+        if !self.local_indexes.is_empty() {
+            index_table(
+                &mut lines,
+                i18n.md_local_secondary_indexes(),
+                &self.local_indexes,
+                &i18n,
+            );
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamoDbDetail, DynamoDbIndex};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> DynamoDbDetail {
+        DynamoDbDetail {
+            name: "orders".to_string(),
+            arn: "arn:aws:dynamodb:ap-northeast-2:123456789012:table/orders".to_string(),
+            status: "ACTIVE".to_string(),
+            partition_key: "pk (S)".to_string(),
+            sort_key: Some("sk (S)".to_string()),
+            billing_mode: "PAY_PER_REQUEST".to_string(),
+            read_capacity: 0,
+            write_capacity: 0,
+            table_class: "STANDARD".to_string(),
+            item_count: 42,
+            size_bytes: 2048,
+            global_indexes: vec![DynamoDbIndex {
+                name: "by-customer".to_string(),
+                partition_key: "customer_id (S)".to_string(),
+                sort_key: None,
+                projection: "INCLUDE (status, total)".to_string(),
+            }],
+            local_indexes: vec![],
+            ttl_attribute: Some("expires_at".to_string()),
+            stream_view_type: Some("NEW_AND_OLD_IMAGES".to_string()),
+            sse_type: None,
+            kms_key_arn: None,
+            deletion_protection: true,
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_dynamodb_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## DynamoDB Table (orders)"));
+        assert!(markdown.contains("| Partition Key | pk (S) |"));
+        assert!(markdown.contains("| Sort Key | sk (S) |"));
+        assert!(markdown.contains("| Capacity Mode | On-demand |"));
+        assert!(markdown.contains("| Size | 2.0 KB |"));
+        assert!(markdown.contains("| TTL | Enabled (`expires_at`) |"));
+        assert!(markdown.contains("| Streams | Enabled (NEW_AND_OLD_IMAGES) |"));
+        assert!(markdown.contains("| Encryption | AWS owned key |"));
+        assert!(
+            markdown.contains("| by-customer | customer_id (S) | - | INCLUDE (status, total) |")
+        );
+        assert!(!markdown.contains("### Local Secondary Indexes"));
+        assert!(markdown.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn scenario_dynamodb_provisioned_with_kms() {
+        let mut detail = sample_detail();
+        detail.billing_mode = "PROVISIONED".to_string();
+        detail.read_capacity = 5;
+        detail.write_capacity = 10;
+        detail.ttl_attribute = None;
+        detail.stream_view_type = None;
+        detail.sse_type = Some("KMS".to_string());
+        detail.kms_key_arn = Some("arn:aws:kms:ap-northeast-2:123456789012:key/abcd".to_string());
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 용량 모드 | 프로비저닝 (RCU 5 / WCU 10) |"));
+        assert!(markdown.contains("| TTL | 비활성화 |"));
+        assert!(markdown.contains("| 스트림 | 비활성화 |"));
+        assert!(
+            markdown
+                .contains("| 암호화 | KMS (arn:aws:kms:ap-northeast-2:123456789012:key/abcd) |")
+        );
+    }
+}
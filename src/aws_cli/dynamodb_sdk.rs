@@ -0,0 +1,322 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::dynamodb::{DynamoDbDetail, DynamoDbIndex};
+use aws_sdk_dynamodb::types::{
+    AttributeDefinition, KeySchemaElement, KeyType, Projection, SseStatus, TableDescription,
+    TimeToLiveDescription, TimeToLiveStatus,
+};
+
+/// List all DynamoDB tables using AWS SDK
+pub fn list_dynamodb_tables() -> Vec<AwsResource> {
+    get_runtime().block_on(list_dynamodb_tables_async())
+}
+
+async fn list_dynamodb_tables_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_dynamodb, &config);
+
+    let result = client
+        .list_tables()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(names) => names
+            .into_iter()
+            .map(|name| AwsResource {
+                name: name.clone(),
+                id: name,
+                state: String::new(),
+                az: String::new(),
+                cidr: String::new(),
+                owner_id: String::new(),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing DynamoDB tables: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get DynamoDB table detail (schema, indexes, TTL and tags) using AWS SDK
+pub fn get_dynamodb_detail(name: &str) -> Option<DynamoDbDetail> {
+    get_runtime().block_on(get_dynamodb_detail_async(name))
+}
+
+async fn get_dynamodb_detail_async(name: &str) -> Option<DynamoDbDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_dynamodb, &config);
+
+    let output = client.describe_table().table_name(name).send().await.ok()?;
+    let mut detail = map_table_detail(output.table()?);
+
+    // TTL/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client.describe_time_to_live().table_name(name).send().await {
+        Ok(ttl) => detail.ttl_attribute = ttl.time_to_live_description().and_then(ttl_attribute),
+        Err(e) => tracing::warn!("Error describing TTL for {}: {:?}", name, e),
+    }
+
+    if !detail.arn.is_empty() {
+        match client
+            .list_tags_of_resource()
+            .resource_arn(&detail.arn)
+            .send()
+            .await
+        {
+            Ok(tags) => {
+                let mut tags: Vec<(String, String)> = tags
+                    .tags()
+                    .iter()
+                    .map(|t| (t.key().to_string(), t.value().to_string()))
+                    .collect();
+                tags.sort();
+                detail.tags = tags;
+            }
+            Err(e) => tracing::warn!("Error listing tags for {}: {:?}", name, e),
+        }
+    }
+
+    Some(detail)
+}
+
+fn ttl_attribute(ttl: &TimeToLiveDescription) -> Option<String> {
+    if ttl.time_to_live_status() != Some(&TimeToLiveStatus::Enabled) {
+        return None;
+    }
+    ttl.attribute_name().map(|s| s.to_string())
+}
+
+// 키 속성을 "이름 (타입)" 형식으로 표시 (타입은 속성 정의에서 조회)
+fn key_display(
+    key_schema: &[KeySchemaElement],
+    key_type: KeyType,
+    attributes: &[AttributeDefinition],
+) -> Option<String> {
+    let element = key_schema.iter().find(|k| *k.key_type() == key_type)?;
+    let name = element.attribute_name();
+    Some(
+        match attributes.iter().find(|a| a.attribute_name() == name) {
+            Some(attribute) => format!("{} ({})", name, attribute.attribute_type().as_str()),
+            None => name.to_string(),
+        },
+    )
+}
+
+fn projection_display(projection: Option<&Projection>) -> String {
+    let Some(projection) = projection else {
+        return String::new();
+    };
+    let projection_type = projection
+        .projection_type()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_default();
+    if projection.non_key_attributes().is_empty() {
+        projection_type
+    } else {
+        format!(
+            "{} ({})",
+            projection_type,
+            projection.non_key_attributes().join(", ")
+        )
+    }
+}
+
+fn map_index(
+    name: Option<&str>,
+    key_schema: &[KeySchemaElement],
+    projection: Option<&Projection>,
+    attributes: &[AttributeDefinition],
+) -> DynamoDbIndex {
+    DynamoDbIndex {
+        name: name.unwrap_or_default().to_string(),
+        partition_key: key_display(key_schema, KeyType::Hash, attributes).unwrap_or_default(),
+        sort_key: key_display(key_schema, KeyType::Range, attributes),
+        projection: projection_display(projection),
+    }
+}
+
+fn map_table_detail(table: &TableDescription) -> DynamoDbDetail {
+    let attributes = table.attribute_definitions();
+    let throughput = table.provisioned_throughput();
+    let sse = table
+        .sse_description()
+        .filter(|sse| sse.status() == Some(&SseStatus::Enabled));
+
+    DynamoDbDetail {
+        name: table.table_name().unwrap_or_default().to_string(),
+        arn: table.table_arn().unwrap_or_default().to_string(),
+        status: table
+            .table_status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        partition_key: key_display(table.key_schema(), KeyType::Hash, attributes)
+            .unwrap_or_default(),
+        sort_key: key_display(table.key_schema(), KeyType::Range, attributes),
+        // 결제 모드 요약이 없으면 프로비저닝 모드
+        billing_mode: table
+            .billing_mode_summary()
+            .and_then(|b| b.billing_mode())
+            .map(|b| b.as_str().to_string())
+            .unwrap_or_else(|| "PROVISIONED".to_string()),
+        read_capacity: throughput
+            .and_then(|t| t.read_capacity_units())
+            .unwrap_or(0),
+        write_capacity: throughput
+            .and_then(|t| t.write_capacity_units())
+            .unwrap_or(0),
+        table_class: table
+            .table_class_summary()
+            .and_then(|c| c.table_class())
+            .map(|c| c.as_str().to_string())
+            .unwrap_or_default(),
+        item_count: table.item_count().unwrap_or(0),
+        size_bytes: table.table_size_bytes().unwrap_or(0),
+        global_indexes: table
+            .global_secondary_indexes()
+            .iter()
+            .map(|i| map_index(i.index_name(), i.key_schema(), i.projection(), attributes))
+            .collect(),
+        local_indexes: table
+            .local_secondary_indexes()
+            .iter()
+            .map(|i| map_index(i.index_name(), i.key_schema(), i.projection(), attributes))
+            .collect(),
+        ttl_attribute: None,
+        stream_view_type: table
+            .stream_specification()
+            .filter(|s| s.stream_enabled())
+            .and_then(|s| s.stream_view_type())
+            .map(|v| v.as_str().to_string()),
+        sse_type: sse
+            .and_then(|s| s.sse_type())
+            .map(|t| t.as_str().to_string()),
+        kms_key_arn: sse
+            .and_then(|s| s.kms_master_key_arn())
+            .map(|s| s.to_string()),
+        deletion_protection: table.deletion_protection_enabled().unwrap_or(false),
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_table_detail, ttl_attribute};
+    use aws_sdk_dynamodb::types::{
+        AttributeDefinition, BillingMode, BillingModeSummary, GlobalSecondaryIndexDescription,
+        KeySchemaElement, KeyType, Projection, ProjectionType, ProvisionedThroughputDescription,
+        ScalarAttributeType, SseDescription, SseStatus, SseType, StreamSpecification,
+        StreamViewType, TableDescription, TimeToLiveDescription, TimeToLiveStatus,
+    };
+
+    fn key(name: &str, key_type: KeyType) -> KeySchemaElement {
+        KeySchemaElement::builder()
+            .attribute_name(name)
+            .key_type(key_type)
+            .build()
+            .expect("key schema element")
+    }
+
+    fn attribute(name: &str) -> AttributeDefinition {
+        AttributeDefinition::builder()
+            .attribute_name(name)
+            .attribute_type(ScalarAttributeType::S)
+            .build()
+            .expect("attribute definition")
+    }
+
+    #[test]
+    fn map_table_detail_reads_keys_indexes_and_capacity() {
+        let index = GlobalSecondaryIndexDescription::builder()
+            .index_name("by-customer")
+            .key_schema(key("customer_id", KeyType::Hash))
+            .projection(
+                Projection::builder()
+                    .projection_type(ProjectionType::Include)
+                    .non_key_attributes("status")
+                    .non_key_attributes("total")
+                    .build(),
+            )
+            .build();
+        let table = TableDescription::builder()
+            .table_name("orders")
+            .attribute_definitions(attribute("pk"))
+            .attribute_definitions(attribute("sk"))
+            .attribute_definitions(attribute("customer_id"))
+            .key_schema(key("pk", KeyType::Hash))
+            .key_schema(key("sk", KeyType::Range))
+            .global_secondary_indexes(index)
+            .provisioned_throughput(
+                ProvisionedThroughputDescription::builder()
+                    .read_capacity_units(5)
+                    .write_capacity_units(10)
+                    .build(),
+            )
+            .build();
+
+        let detail = map_table_detail(&table);
+        assert_eq!(detail.partition_key, "pk (S)");
+        assert_eq!(detail.sort_key.as_deref(), Some("sk (S)"));
+        assert_eq!(detail.billing_mode, "PROVISIONED");
+        assert_eq!((detail.read_capacity, detail.write_capacity), (5, 10));
+        assert_eq!(detail.global_indexes[0].partition_key, "customer_id (S)");
+        assert!(detail.global_indexes[0].sort_key.is_none());
+        assert_eq!(
+            detail.global_indexes[0].projection,
+            "INCLUDE (status, total)"
+        );
+        assert!(detail.local_indexes.is_empty());
+    }
+
+    #[test]
+    fn map_table_detail_reads_on_demand_streams_and_encryption() {
+        let table = TableDescription::builder()
+            .table_name("sessions")
+            .billing_mode_summary(
+                BillingModeSummary::builder()
+                    .billing_mode(BillingMode::PayPerRequest)
+                    .build(),
+            )
+            .stream_specification(
+                StreamSpecification::builder()
+                    .stream_enabled(true)
+                    .stream_view_type(StreamViewType::NewAndOldImages)
+                    .build()
+                    .expect("stream specification"),
+            )
+            .sse_description(
+                SseDescription::builder()
+                    .status(SseStatus::Enabled)
+                    .sse_type(SseType::Kms)
+                    .kms_master_key_arn("arn:aws:kms:ap-northeast-2:123456789012:key/abcd")
+                    .build(),
+            )
+            .build();
+
+        let detail = map_table_detail(&table);
+        assert_eq!(detail.billing_mode, "PAY_PER_REQUEST");
+        assert_eq!(
+            detail.stream_view_type.as_deref(),
+            Some("NEW_AND_OLD_IMAGES")
+        );
+        assert_eq!(detail.sse_type.as_deref(), Some("KMS"));
+        assert!(detail.kms_key_arn.is_some());
+    }
+
+    #[test]
+    fn ttl_attribute_requires_enabled_status() {
+        let enabled = TimeToLiveDescription::builder()
+            .time_to_live_status(TimeToLiveStatus::Enabled)
+            .attribute_name("expires_at")
+            .build();
+        assert_eq!(ttl_attribute(&enabled).as_deref(), Some("expires_at"));
+
+        let disabling = TimeToLiveDescription::builder()
+            .time_to_live_status(TimeToLiveStatus::Disabling)
+            .attribute_name("expires_at")
+            .build();
+        assert!(ttl_attribute(&disabling).is_none());
+    }
+}
@@ -0,0 +1,602 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use crate::locale::{self, SizeUnit};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumesResponse {
+    volumes: Vec<VolumeEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeEntry {
+    volume_id: String,
+    #[serde(default)]
+    size: i32,
+    #[serde(default)]
+    volume_type: String,
+    #[serde(default)]
+    iops: Option<i32>,
+    #[serde(default)]
+    throughput: Option<i32>,
+    #[serde(default)]
+    encrypted: bool,
+    #[serde(default)]
+    kms_key_id: String,
+    #[serde(default)]
+    availability_zone: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    snapshot_id: String,
+    #[serde(default)]
+    create_time: String,
+    #[serde(default)]
+    multi_attach_enabled: bool,
+    #[serde(default)]
+    attachments: Vec<AttachmentEntry>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AttachmentEntry {
+    #[serde(default)]
+    instance_id: String,
+    #[serde(default)]
+    device: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    delete_on_termination: bool,
+    #[serde(default)]
+    attach_time: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SnapshotsResponse {
+    snapshots: Vec<SnapshotEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SnapshotEntry {
+    snapshot_id: String,
+    #[serde(default)]
+    volume_id: String,
+    #[serde(default)]
+    volume_size: i32,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    progress: String,
+    #[serde(default)]
+    start_time: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    encrypted: bool,
+    #[serde(default)]
+    kms_key_id: String,
+    #[serde(default)]
+    storage_tier: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+/// EBS volume or snapshot (told apart by the ID prefix).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EbsDetail {
+    pub name: String,
+    pub id: String,
+    // 볼륨: creating, available, in-use, deleting / 스냅샷: pending, completed, error
+    pub state: String,
+    pub size_gb: i32,
+    pub encrypted: bool,
+    pub kms_key_id: String,
+    pub tags: Vec<(String, String)>,
+    pub resource: EbsResource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EbsResource {
+    Volume(EbsVolumeInfo),
+    Snapshot(EbsSnapshotInfo),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EbsVolumeInfo {
+    pub volume_type: String,
+    // gp3/io1/io2만 값이 있음
+    pub iops: Option<i32>,
+    pub throughput: Option<i32>,
+    pub availability_zone: String,
+    pub snapshot_id: String,
+    pub created: String,
+    pub multi_attach: bool,
+    pub attachments: Vec<EbsAttachment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EbsAttachment {
+    pub instance_id: String,
+    pub device: String,
+    pub state: String,
+    pub delete_on_termination: bool,
+    pub attached_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EbsSnapshotInfo {
+    pub volume_id: String,
+    pub progress: String,
+    pub started: String,
+    pub description: String,
+    // standard 또는 archive
+    pub storage_tier: String,
+}
+
+impl EbsDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    /// Volume creation time, or the time a snapshot was started.
+    pub fn created(&self) -> &str {
+        match &self.resource {
+            EbsResource::Volume(volume) => &volume.created,
+            EbsResource::Snapshot(snapshot) => &snapshot.started,
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        let title = match &self.resource {
+            EbsResource::Volume(_) => i18n.md_volume(),
+            EbsResource::Snapshot(_) => i18n.md_snapshot(),
+        };
+        let encryption = if !self.encrypted {
+            i18n.md_disabled().to_string()
+        } else if self.kms_key_id.is_empty() {
+            "aws/ebs".to_string()
+        } else {
+            self.kms_key_id.clone()
+        };
+
+        let mut lines = vec![
+            format!("## EBS {} ({})\n", title, display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), or_dash(&self.state)),
+            format!(
+                "| {} | {} |",
+                i18n.md_size(),
+                locale::size(self.size_gb, SizeUnit::Gb)
+            ),
+            format!("| {} | {} |", i18n.md_encryption(), encryption),
+        ];
+
+        match &self.resource {
+            EbsResource::Volume(volume) => {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_type(),
+                    or_dash(&volume.volume_type)
+                ));
+                if let Some(iops) = volume.iops {
+                    lines.push(format!("| IOPS | {} |", locale::count(iops)));
+                }
+                if let Some(throughput) = volume.throughput {
+                    lines.push(format!(
+                        "| {} | {} MiB/s |",
+                        i18n.md_throughput(),
+                        locale::count(throughput)
+                    ));
+                }
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_availability_zone(),
+                    or_dash(&volume.availability_zone)
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_source_snapshot(),
+                    or_dash(&volume.snapshot_id)
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_multi_attach(),
+                    if volume.multi_attach {
+                        i18n.md_enabled()
+                    } else {
+                        i18n.md_disabled()
+                    }
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_created_at(),
+                    or_dash(&locale::date(&volume.created))
+                ));
+            }
+            EbsResource::Snapshot(snapshot) => {
+                let source_volume = if snapshot.volume_id.is_empty() {
+                    "-".to_string()
+                } else {
+                    name_index::label(&snapshot.volume_id)
+                };
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_source_volume(),
+                    source_volume
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_progress(),
+                    or_dash(&snapshot.progress)
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_tier(),
+                    or_dash(&snapshot.storage_tier)
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_created_at(),
+                    or_dash(&locale::date(&snapshot.started))
+                ));
+                if !snapshot.description.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_description(),
+                        snapshot.description
+                    ));
+                }
+            }
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        if let EbsResource::Volume(volume) = &self.resource {
+            lines.push(format!("\n### {}\n", i18n.md_attachment()));
+            if volume.attachments.is_empty() {
+                lines.push(i18n.md_unattached_volume_note().to_string());
+            } else {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    i18n.md_attached_to(),
+                    i18n.md_device(),
+                    i18n.md_state(),
+                    i18n.md_delete_on_termination(),
+                    i18n.md_attached_at()
+                ));
+                lines.push("|:---|:---|:---|:---:|:---|".to_string());
+                for attachment in &volume.attachments {
+                    lines.push(format!(
+                        "| {} | {} | {} | {} | {} |",
+                        name_index::label(&attachment.instance_id),
+                        or_dash(&attachment.device),
+                        or_dash(&attachment.state),
+                        if attachment.delete_on_termination {
+                            "✓"
+                        } else {
+                            "-"
+                        },
+                        or_dash(&locale::date(&attachment.attached_at))
+                    ));
+                }
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn sorted_tags(tags: &[Tag]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .iter()
+        .map(|t| (t.key.clone(), t.value.clone()))
+        .collect();
+    tags.sort();
+    tags
+}
+
+// Name 태그가 없으면 ID를 이름으로 사용
+fn name_or_id(tags: &[(String, String)], id: &str) -> String {
+    tags.iter()
+        .find(|(key, _)| key == "Name")
+        .map(|(_, value)| value.clone())
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Parse `describe-volumes` output.
+pub(crate) fn parse_volumes_output(output: &str) -> Option<Vec<EbsDetail>> {
+    let response: VolumesResponse = serde_json::from_str(output).ok()?;
+
+    Some(
+        response
+            .volumes
+            .into_iter()
+            .map(|volume| {
+                let tags = sorted_tags(&volume.tags);
+                EbsDetail {
+                    name: name_or_id(&tags, &volume.volume_id),
+                    id: volume.volume_id,
+                    state: volume.state,
+                    size_gb: volume.size,
+                    encrypted: volume.encrypted,
+                    kms_key_id: volume.kms_key_id,
+                    tags,
+                    resource: EbsResource::Volume(EbsVolumeInfo {
+                        volume_type: volume.volume_type,
+                        iops: volume.iops,
+                        throughput: volume.throughput,
+                        availability_zone: volume.availability_zone,
+                        snapshot_id: volume.snapshot_id,
+                        created: volume.create_time,
+                        multi_attach: volume.multi_attach_enabled,
+                        attachments: volume
+                            .attachments
+                            .into_iter()
+                            .map(|attachment| EbsAttachment {
+                                instance_id: attachment.instance_id,
+                                device: attachment.device,
+                                state: attachment.state,
+                                delete_on_termination: attachment.delete_on_termination,
+                                attached_at: attachment.attach_time,
+                            })
+                            .collect(),
+                    }),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Parse `describe-snapshots` output.
+pub(crate) fn parse_snapshots_output(output: &str) -> Option<Vec<EbsDetail>> {
+    let response: SnapshotsResponse = serde_json::from_str(output).ok()?;
+
+    Some(
+        response
+            .snapshots
+            .into_iter()
+            .map(|snapshot| {
+                let tags = sorted_tags(&snapshot.tags);
+                EbsDetail {
+                    name: name_or_id(&tags, &snapshot.snapshot_id),
+                    id: snapshot.snapshot_id,
+                    state: snapshot.state,
+                    size_gb: snapshot.volume_size,
+                    encrypted: snapshot.encrypted,
+                    kms_key_id: snapshot.kms_key_id,
+                    tags,
+                    resource: EbsResource::Snapshot(EbsSnapshotInfo {
+                        volume_id: snapshot.volume_id,
+                        progress: snapshot.progress,
+                        started: snapshot.start_time,
+                        description: snapshot.description,
+                        storage_tier: snapshot.storage_tier,
+                    }),
+                }
+            })
+            .collect(),
+    )
+}
+
+// 볼륨은 이름순, 스냅샷은 최신순으로 볼륨 다음에 표시
+fn ebs_resources(volumes: Vec<EbsDetail>, mut snapshots: Vec<EbsDetail>) -> Vec<AwsResource> {
+    let mut volumes: Vec<AwsResource> = volumes.into_iter().map(ebs_resource).collect();
+    volumes.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+    // StartTime은 ISO 8601이라 문자열 비교로 정렬 가능
+    let started = |detail: &EbsDetail| match &detail.resource {
+        EbsResource::Snapshot(snapshot) => snapshot.started.clone(),
+        EbsResource::Volume(_) => String::new(),
+    };
+    snapshots.sort_by(|a, b| started(b).cmp(&started(a)).then_with(|| a.id.cmp(&b.id)));
+
+    volumes
+        .into_iter()
+        .chain(snapshots.into_iter().map(ebs_resource))
+        .collect()
+}
+
+// 목록에는 용량과 함께 볼륨은 타입/연결 인스턴스, 스냅샷은 생성일/원본 볼륨을 표시
+fn ebs_resource(detail: EbsDetail) -> AwsResource {
+    let size = locale::size(detail.size_gb, SizeUnit::Gb);
+    let summary = match &detail.resource {
+        EbsResource::Volume(volume) => {
+            let attached = volume
+                .attachments
+                .iter()
+                .map(|attachment| name_index::label(&attachment.instance_id))
+                .collect::<Vec<_>>();
+            if attached.is_empty() {
+                format!("{} {}", size, volume.volume_type)
+            } else {
+                format!("{} {} → {}", size, volume.volume_type, attached.join(", "))
+            }
+        }
+        EbsResource::Snapshot(snapshot) => {
+            let started: String = snapshot.started.chars().take(10).collect();
+            format!("{} {} ← {}", size, started, snapshot.volume_id)
+        }
+    };
+    let az = match &detail.resource {
+        EbsResource::Volume(volume) => volume.availability_zone.clone(),
+        EbsResource::Snapshot(_) => String::new(),
+    };
+
+    AwsResource {
+        name: detail.name,
+        id: detail.id,
+        state: detail.state,
+        az,
+        cidr: summary,
+        owner_id: String::new(),
+    }
+}
+
+fn describe_volumes(filter_args: &[&str]) -> Vec<EbsDetail> {
+    let mut args = vec!["ec2", "describe-volumes"];
+    args.extend_from_slice(filter_args);
+    args.extend(["--output", "json"]);
+    run_aws_cli(&args)
+        .and_then(|output| parse_volumes_output(&output))
+        .unwrap_or_default()
+}
+
+fn describe_snapshots(filter_args: &[&str]) -> Vec<EbsDetail> {
+    let mut args = vec!["ec2", "describe-snapshots"];
+    args.extend_from_slice(filter_args);
+    args.extend(["--output", "json"]);
+    run_aws_cli(&args)
+        .and_then(|output| parse_snapshots_output(&output))
+        .unwrap_or_default()
+}
+
+/// List every EBS volume (attached or not) and the account's own snapshots.
+pub fn list_ebs_resources() -> Vec<AwsResource> {
+    let resources = ebs_resources(
+        describe_volumes(&[]),
+        // 다른 계정이 공개한 스냅샷은 제외
+        describe_snapshots(&["--owner-ids", "self"]),
+    );
+    name_index::record_resources(&resources);
+    resources
+}
+
+/// EBS volume (`vol-`) or snapshot (`snap-`) detail.
+pub fn get_ebs_detail(ebs_id: &str) -> Option<EbsDetail> {
+    if ebs_id.starts_with("snap-") {
+        return describe_snapshots(&["--snapshot-ids", ebs_id])
+            .into_iter()
+            .next();
+    }
+    describe_volumes(&["--volume-ids", ebs_id])
+        .into_iter()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EbsResource, ebs_resources, parse_snapshots_output, parse_volumes_output};
+    use crate::i18n::Language;
+
+    const VOLUMES: &str = r#"
+    {
+      "Volumes": [
+        {
+          "VolumeId": "vol-0data",
+          "Size": 100,
+          "VolumeType": "gp3",
+          "Iops": 3000,
+          "Throughput": 250,
+          "Encrypted": true,
+          "KmsKeyId": "arn:aws:kms:ap-northeast-2:111122223333:key/1234",
+          "AvailabilityZone": "ap-northeast-2a",
+          "State": "in-use",
+          "SnapshotId": "snap-0base",
+          "CreateTime": "2026-09-01T00:00:00.000Z",
+          "MultiAttachEnabled": false,
+          "Attachments": [
+            {"InstanceId": "i-0ebsdata", "Device": "/dev/sdf", "State": "attached", "DeleteOnTermination": false, "AttachTime": "2026-09-01T00:01:00.000Z"}
+          ],
+          "Tags": [{"Key": "Name", "Value": "data"}, {"Key": "Team", "Value": "platform"}]
+        },
+        {
+          "VolumeId": "vol-0orphan",
+          "Size": 8,
+          "VolumeType": "gp2",
+          "Iops": 100,
+          "Encrypted": false,
+          "AvailabilityZone": "ap-northeast-2c",
+          "State": "available",
+          "Attachments": []
+        }
+      ]
+    }
+    "#;
+
+    const SNAPSHOTS: &str = r#"
+    {
+      "Snapshots": [
+        {"SnapshotId": "snap-0old", "VolumeId": "vol-0data", "VolumeSize": 100, "State": "completed", "Progress": "100%", "StartTime": "2026-01-01T00:00:00.000Z", "Encrypted": true, "StorageTier": "archive"},
+        {"SnapshotId": "snap-0new", "VolumeId": "vol-0data", "VolumeSize": 100, "State": "pending", "Progress": "42%", "StartTime": "2026-10-01T00:00:00.000Z", "Description": "nightly", "Encrypted": true, "StorageTier": "standard"}
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_ebs_outputs_list_volumes_before_newest_snapshots() {
+        let volumes = parse_volumes_output(VOLUMES).expect("volumes");
+        assert_eq!(volumes[0].name, "data");
+        assert!(matches!(
+            &volumes[0].resource,
+            EbsResource::Volume(volume) if volume.attachments[0].instance_id == "i-0ebsdata"
+                && volume.throughput == Some(250)
+        ));
+        let snapshots = parse_snapshots_output(SNAPSHOTS).expect("snapshots");
+
+        let resources = ebs_resources(volumes, snapshots);
+        let ids: Vec<&str> = resources.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["vol-0data", "vol-0orphan", "snap-0new", "snap-0old"]);
+        assert_eq!(resources[0].cidr, "100 GB gp3 → i-0ebsdata");
+        assert_eq!(resources[0].az, "ap-northeast-2a");
+        assert_eq!(resources[1].name, "vol-0orphan");
+        assert_eq!(resources[1].cidr, "8 GB gp2");
+        assert_eq!(resources[2].cidr, "100 GB 2026-10-01 ← vol-0data");
+        assert_eq!(resources[2].state, "pending");
+    }
+
+    #[test]
+    fn ebs_markdown_renders_volume_attachments_and_snapshot_source() {
+        let volumes = parse_volumes_output(VOLUMES).expect("volumes");
+        let md = volumes[0].to_markdown(Language::English);
+        assert!(md.contains("## EBS Volume (data - vol-0data)"));
+        assert!(md.contains("| Size | 100 GB |"));
+        assert!(md.contains("| Encryption | arn:aws:kms:ap-northeast-2:111122223333:key/1234 |"));
+        assert!(md.contains("| IOPS | 3000 |"));
+        assert!(md.contains("| Throughput | 250 MiB/s |"));
+        assert!(md.contains("| Source Snapshot | snap-0base |"));
+        assert!(md.contains("| i-0ebsdata | /dev/sdf | attached | - | 2026-09-01T00:01:00.000Z |"));
+        assert!(md.contains("| Tag-Team | platform |"));
+
+        let md = volumes[1].to_markdown(Language::English);
+        assert!(md.contains("| Encryption | Disabled |"));
+        assert!(!md.contains("Throughput"));
+        assert!(md.contains("not attached to any instance"));
+
+        let snapshots = parse_snapshots_output(SNAPSHOTS).expect("snapshots");
+        let md = snapshots[1].to_markdown(Language::English);
+        assert!(md.contains("## EBS Snapshot (snap-0new)"));
+        assert!(md.contains("| Source Volume | vol-0data |"));
+        assert!(md.contains("| Progress | 42% |"));
+        assert!(md.contains("| Tier | standard |"));
+        assert!(md.contains("| Description | nightly |"));
+        assert!(md.contains("| Encryption | aws/ebs |"));
+        assert!(!md.contains("### Attachment"));
+    }
+}
@@ -1,8 +1,16 @@
-use crate::aws_cli::common::{AwsResource, extract_json_value, extract_tags, parse_name_tag};
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::common::{
+    AwsResource, current_region, extract_json_value, extract_tags, foreign_owner, parse_name_tag,
+};
 use crate::aws_cli::iam::IamRoleDetail;
+use crate::aws_cli::name_index;
 use crate::i18n::{I18n, Language};
+use crate::locale::{self, SizeUnit};
 use base64::{Engine as _, engine::general_purpose};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
 #[cfg(not(test))]
 mod cli_adapter {
@@ -81,7 +89,7 @@ mod iam_adapter {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VolumeDetail {
     pub device_name: String,
     pub volume_id: String,
@@ -92,12 +100,15 @@ pub struct VolumeDetail {
     pub delete_on_termination: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 #[allow(dead_code)]
 pub struct Ec2Detail {
     pub name: String,
     pub instance_id: String,
     pub instance_type: String,
+    // CpuOptions의 vCPU 수 (없으면 유형 기본값), 메모리는 describe-instance-types 기준
+    pub vcpus: Option<u32>,
+    pub memory_mib: Option<u64>,
     pub ami: String,
     pub platform: String,
     pub architecture: String,
@@ -127,13 +138,20 @@ impl Ec2Detail {
         } else {
             format!("{} - {}", self.name, self.instance_id)
         };
-        let mut lines = vec![
-            format!("## {} ({})\n", i18n.md_ec2_instance(), display_name),
+        let mut lines = vec![format!(
+            "## {} ({})\n",
+            i18n.md_ec2_instance(),
+            display_name
+        )];
+        if is_terminated_state(&self.state) {
+            lines.push(format!("> {}\n", i18n.md_instance_terminated_note()));
+        }
+        lines.extend([
             format!("| {} | {} |", i18n.item(), i18n.value()),
             "|:---|:---|".to_string(),
             format!("| {} | {} |", i18n.md_name(), display_name),
             format!("| {} | {} |", i18n.md_state(), self.state),
-        ];
+        ]);
 
         for (key, value) in &self.tags {
             if key != "Name" {
@@ -154,13 +172,21 @@ impl Ec2Detail {
             self.architecture
         ));
         lines.push(format!("| {} | {} |", i18n.md_key_pair(), self.key_pair));
-        lines.push(format!("| VPC | {} |", self.vpc));
-        lines.push(format!("| {} | {} |", i18n.md_subnet(), self.subnet));
-        lines.push(format!("| {} | {} |", i18n.md_availability_zone(), self.az));
+        lines.push(format!("| VPC | {} |", or_dash(&self.vpc)));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_subnet(),
+            or_dash(&self.subnet)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_availability_zone(),
+            or_dash(&self.az)
+        ));
         lines.push(format!(
             "| {} | {} |",
             i18n.md_private_ip(),
-            self.private_ip
+            or_dash(&self.private_ip)
         ));
 
         if self.public_ip != "-" && !self.public_ip.is_empty() {
@@ -170,7 +196,7 @@ impl Ec2Detail {
         lines.push(format!(
             "| {} | {} |",
             i18n.md_security_groups(),
-            self.security_groups.join(", ")
+            or_dash(&self.security_groups.join(", "))
         ));
 
         let ebs_str = if self.ebs_optimized {
@@ -194,7 +220,7 @@ impl Ec2Detail {
             lines.push(format!(
                 "| {} | {} |",
                 i18n.md_launch_time(),
-                self.launch_time
+                locale::date(&self.launch_time)
             ));
         }
 
@@ -256,7 +282,7 @@ impl Ec2Detail {
             for vol in &self.volumes {
                 let iops_str = vol
                     .iops
-                    .map(|i| i.to_string())
+                    .map(locale::count)
                     .unwrap_or_else(|| "-".to_string());
                 let encrypted_str = if vol.encrypted { "✓" } else { "-" };
                 let delete_str = if vol.delete_on_termination {
@@ -265,10 +291,10 @@ impl Ec2Detail {
                     "-"
                 };
                 lines.push(format!(
-                    "| {} | {} | {} GB | {} | {} | {} | {} |",
+                    "| {} | {} | {} | {} | {} | {} | {} |",
                     vol.device_name,
                     vol.volume_id,
-                    vol.size_gb,
+                    locale::size(vol.size_gb, SizeUnit::Gb),
                     vol.volume_type,
                     iops_str,
                     encrypted_str,
@@ -290,6 +316,16 @@ impl Ec2Detail {
     }
 }
 
+// 종료된 인스턴스는 서브넷/IP 등이 비어 있으므로 "-"로 표시
+fn or_dash(value: &str) -> &str {
+    if value.is_empty() { "-" } else { value }
+}
+
+/// Whether the instance has released (or is releasing) its network and storage.
+pub fn is_terminated_state(state: &str) -> bool {
+    matches!(state, "terminated" | "shutting-down")
+}
+
 pub fn list_instances() -> Vec<AwsResource> {
     let output = match cli_adapter::run(&[
         "ec2",
@@ -328,6 +364,7 @@ fn parse_instance_resources(json: &str) -> Vec<AwsResource> {
 
                 let name = parse_name_tag(section);
                 let state = extract_state(section);
+                name_index::record(id, &name);
 
                 let display_name = if name.is_empty() {
                     id.to_string()
@@ -341,6 +378,7 @@ fn parse_instance_resources(json: &str) -> Vec<AwsResource> {
                     state: state.clone(),
                     az: String::new(),
                     cidr: String::new(),
+                    owner_id: String::new(),
                 });
             }
             search_start = start + end;
@@ -369,15 +407,102 @@ fn extract_state(json: &str) -> String {
     "unknown".to_string()
 }
 
+// prefetch_instances로 미리 받아둔 (리전, 인스턴스 ID)별 describe-instances 응답
+fn prefetched_instances() -> &'static Mutex<HashMap<(String, String), String>> {
+    static PREFETCHED: OnceLock<Mutex<HashMap<(String, String), String>>> = OnceLock::new();
+    PREFETCHED.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Describe many instances in the current region with a single describe-instances call.
+///
+/// Subsequent `get_instance_detail` calls for these ids in the same region reuse
+/// the prefetched response instead of issuing their own request.
+pub fn prefetch_instances(instance_ids: &[String]) {
+    let Ok(mut prefetched) = prefetched_instances().lock() else {
+        return;
+    };
+    let region = current_region();
+    prefetched.retain(|(cached_region, _), _| *cached_region != region);
+    if instance_ids.is_empty() {
+        return;
+    }
+
+    let mut args = vec!["ec2", "describe-instances", "--instance-ids"];
+    args.extend(instance_ids.iter().map(String::as_str));
+    args.extend(["--output", "json"]);
+
+    if let Some(output) = cli_adapter::run(&args) {
+        prefetched.extend(
+            split_instances_output(&output)
+                .into_iter()
+                .map(|(instance_id, json)| ((region.clone(), instance_id), json)),
+        );
+    }
+}
+
+/// Drop prefetched responses that were not used (called when a blueprint run ends).
+pub fn clear_prefetched_instances() {
+    if let Ok(mut prefetched) = prefetched_instances().lock() {
+        prefetched.clear();
+    }
+}
+
+fn take_prefetched_instance(instance_id: &str) -> Option<String> {
+    prefetched_instances()
+        .lock()
+        .ok()?
+        .remove(&(current_region(), instance_id.to_string()))
+}
+
+// 다건 응답을 인스턴스 하나짜리 응답으로 나눠 기존 파서를 그대로 사용
+fn split_instances_output(json: &str) -> Vec<(String, String)> {
+    let Ok(value) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+
+    value
+        .get("Reservations")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .flat_map(|reservation| {
+            reservation
+                .get("Instances")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+        })
+        .filter_map(|instance| {
+            let instance_id = instance.get("InstanceId")?.as_str()?.to_string();
+            let single = json!({ "Reservations": [{ "Instances": [instance] }] });
+            Some((instance_id, serde_json::to_string_pretty(&single).ok()?))
+        })
+        .collect()
+}
+
 pub fn get_instance_detail(instance_id: &str) -> Option<Ec2Detail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-instances",
-        "--instance-ids",
-        instance_id,
-        "--output",
-        "json",
-    ])?;
+    fetch_instance_detail(instance_id, false)
+}
+
+/// Re-describe an instance for a preview refresh.
+///
+/// Volume details fetched by an earlier lookup are reused; only volumes
+/// attached since then are described.
+pub fn refresh_instance_detail(instance_id: &str) -> Option<Ec2Detail> {
+    fetch_instance_detail(instance_id, true)
+}
+
+fn fetch_instance_detail(instance_id: &str, reuse_volumes: bool) -> Option<Ec2Detail> {
+    let output = take_prefetched_instance(instance_id).or_else(|| {
+        cli_adapter::run(&[
+            "ec2",
+            "describe-instances",
+            "--instance-ids",
+            instance_id,
+            "--output",
+            "json",
+        ])
+    })?;
 
     let ami_id = extract_json_value(&output, "ImageId").unwrap_or_default();
     let ami = if !ami_id.is_empty() {
@@ -399,18 +524,70 @@ pub fn get_instance_detail(instance_id: &str) -> Option<Ec2Detail> {
     } else {
         String::new()
     };
-    let volumes = get_instance_volumes(instance_id);
-    let user_data = get_instance_user_data(instance_id);
+    let volumes = get_instance_volumes(&output, reuse_volumes);
+    // 종료된 인스턴스는 describe-instance-attribute가 실패하므로 호출 생략
+    let user_data = if is_terminated_state(&extract_state(&output)) {
+        None
+    } else {
+        get_instance_user_data(instance_id)
+    };
 
-    Some(parse_instance_detail_output(
-        instance_id,
-        &output,
-        ami,
-        vpc,
-        subnet,
-        volumes,
-        user_data,
-    ))
+    let mut detail =
+        parse_instance_detail_output(instance_id, &output, ami, vpc, subnet, volumes, user_data);
+    if let Some((default_vcpus, memory_mib)) = get_instance_type_spec(&detail.instance_type) {
+        detail.vcpus = detail.vcpus.or(Some(default_vcpus));
+        detail.memory_mib = Some(memory_mib);
+    }
+    Some(detail)
+}
+
+// (리전, 인스턴스 유형)별 (기본 vCPU 수, 메모리 MiB), 같은 유형은 한 번만 조회
+type InstanceTypeSpecs = HashMap<(String, String), (u32, u64)>;
+
+fn instance_type_specs() -> &'static Mutex<InstanceTypeSpecs> {
+    static SPECS: OnceLock<Mutex<InstanceTypeSpecs>> = OnceLock::new();
+    SPECS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn get_instance_type_spec(instance_type: &str) -> Option<(u32, u64)> {
+    if instance_type.is_empty() {
+        return None;
+    }
+    let key = (current_region(), instance_type.to_string());
+    if let Some(spec) = instance_type_specs().lock().ok()?.get(&key) {
+        return Some(*spec);
+    }
+    let output = cli_adapter::run(&[
+        "ec2",
+        "describe-instance-types",
+        "--instance-types",
+        instance_type,
+        "--output",
+        "json",
+    ])?;
+    let spec = parse_instance_type_spec(&output)?;
+    // 조회에 실패한 유형은 다음에 다시 조회하도록 성공한 결과만 저장
+    if let Ok(mut specs) = instance_type_specs().lock() {
+        specs.insert(key, spec);
+    }
+    Some(spec)
+}
+
+fn parse_instance_type_spec(json: &str) -> Option<(u32, u64)> {
+    let value = serde_json::from_str::<Value>(json).ok()?;
+    let info = value.get("InstanceTypes")?.get(0)?;
+    let vcpus = info.pointer("/VCpuInfo/DefaultVCpus")?.as_u64()?;
+    let memory_mib = info.pointer("/MemoryInfo/SizeInMiB")?.as_u64()?;
+    Some((u32::try_from(vcpus).ok()?, memory_mib))
+}
+
+// CpuOptions로 정한 vCPU 수 (코어 수 × 코어당 스레드 수)
+fn parse_cpu_options_vcpus(json: &str) -> Option<u32> {
+    let value = serde_json::from_str::<Value>(json).ok()?;
+    let options = value.pointer("/Reservations/0/Instances/0/CpuOptions")?;
+    let cores = options.get("CoreCount")?.as_u64()?;
+    let threads = options.get("ThreadsPerCore")?.as_u64()?;
+    u32::try_from(cores * threads).ok()
 }
 
 fn parse_instance_detail_output(
@@ -423,6 +600,7 @@ fn parse_instance_detail_output(
     user_data: Option<String>,
 ) -> Ec2Detail {
     let instance_type = extract_json_value(json, "InstanceType").unwrap_or_default();
+    let vcpus = parse_cpu_options_vcpus(json);
     let platform = extract_json_value(json, "Platform").unwrap_or_else(|| "Linux".to_string());
     let architecture =
         extract_json_value(json, "Architecture").unwrap_or_else(|| "x86_64".to_string());
@@ -438,7 +616,7 @@ fn parse_instance_detail_output(
         "Disabled".to_string()
     };
     let iam_role = extract_json_value(json, "Arn")
-        .and_then(|arn| arn.split('/').next_back().map(|s| s.to_string()));
+        .and_then(|arn| Arn::parse(&arn).map(|parsed| parsed.resource_name().to_string()));
     let iam_role_detail = iam_role
         .as_ref()
         .and_then(|role_name| iam_adapter::get(role_name));
@@ -455,6 +633,8 @@ fn parse_instance_detail_output(
         name,
         instance_id: instance_id.to_string(),
         instance_type,
+        vcpus,
+        memory_mib: None,
         ami,
         platform,
         architecture,
@@ -477,28 +657,61 @@ fn parse_instance_detail_output(
     }
 }
 
-fn get_instance_volumes(instance_id: &str) -> Vec<VolumeDetail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-instances",
-        "--instance-ids",
-        instance_id,
-        "--query",
-        "Reservations[0].Instances[0].BlockDeviceMappings",
-        "--output",
-        "json",
-    ]);
+// 볼륨 ID별 describe-volumes 응답 (미리보기 새로고침 시 재사용)
+fn volume_cache() -> &'static Mutex<HashMap<String, Value>> {
+    static VOLUMES: OnceLock<Mutex<HashMap<String, Value>>> = OnceLock::new();
+    VOLUMES.get_or_init(|| Mutex::new(HashMap::new()))
+}
 
-    let mut volumes = Vec::new();
+// describe-instances 응답의 BlockDeviceMappings를 재사용하고 볼륨은 한 번에 조회
+fn get_instance_volumes(instance_json: &str, reuse_cached: bool) -> Vec<VolumeDetail> {
+    let mappings = parse_instance_volume_mappings(instance_json);
+    if mappings.is_empty() {
+        return Vec::new();
+    }
 
-    if let Some(json) = output {
-        for (device_name, volume_id, delete_on_term) in parse_instance_volume_mappings(&json) {
-            if let Some(vol_detail) = get_volume_detail(&volume_id, &device_name, delete_on_term) {
-                volumes.push(vol_detail);
+    let Ok(mut cache) = volume_cache().lock() else {
+        return Vec::new();
+    };
+    if !reuse_cached {
+        for (_, volume_id, _) in &mappings {
+            cache.remove(volume_id);
+        }
+    }
+
+    let missing = mappings
+        .iter()
+        .map(|(_, volume_id, _)| volume_id.as_str())
+        .filter(|volume_id| !cache.contains_key(*volume_id))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        let mut args = vec!["ec2", "describe-volumes", "--volume-ids"];
+        args.extend(missing.iter().copied());
+        args.extend(["--output", "json"]);
+
+        match cli_adapter::run(&args) {
+            Some(output) => cache.extend(parse_volumes_output(&output)),
+            // 없는 볼륨이 하나라도 섞이면 묶음 호출 전체가 실패하므로 개별 조회로 나머지는 살림
+            None if missing.len() > 1 => {
+                for volume_id in missing {
+                    let args = [
+                        "ec2",
+                        "describe-volumes",
+                        "--volume-ids",
+                        volume_id,
+                        "--output",
+                        "json",
+                    ];
+                    if let Some(output) = cli_adapter::run(&args) {
+                        cache.extend(parse_volumes_output(&output));
+                    }
+                }
             }
+            None => {}
         }
     }
-    volumes
+
+    volume_details_from(&cache, &mappings)
 }
 
 fn parse_instance_volume_mappings(json: &str) -> Vec<(String, String, bool)> {
@@ -534,26 +747,35 @@ fn parse_instance_volume_mappings(json: &str) -> Vec<(String, String, bool)> {
     mappings
 }
 
-fn get_volume_detail(
-    volume_id: &str,
-    device_name: &str,
-    delete_on_termination: bool,
-) -> Option<VolumeDetail> {
-    let output = cli_adapter::run(&[
-        "ec2",
-        "describe-volumes",
-        "--volume-ids",
-        volume_id,
-        "--output",
-        "json",
-    ])?;
+fn parse_volumes_output(json: &str) -> HashMap<String, Value> {
+    serde_json::from_str::<Value>(json)
+        .ok()
+        .and_then(|value| value.get("Volumes").and_then(Value::as_array).cloned())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|volume| {
+            let volume_id = volume.get("VolumeId")?.as_str()?.to_string();
+            Some((volume_id, volume))
+        })
+        .collect()
+}
 
-    Some(parse_volume_detail_output(
-        &output,
-        volume_id,
-        device_name,
-        delete_on_termination,
-    ))
+fn volume_details_from(
+    volumes: &HashMap<String, Value>,
+    mappings: &[(String, String, bool)],
+) -> Vec<VolumeDetail> {
+    mappings
+        .iter()
+        .filter_map(|(device_name, volume_id, delete_on_term)| {
+            let section = serde_json::to_string_pretty(volumes.get(volume_id)?).ok()?;
+            Some(parse_volume_detail_output(
+                &section,
+                volume_id,
+                device_name,
+                *delete_on_term,
+            ))
+        })
+        .collect()
 }
 
 fn parse_volume_detail_output(
@@ -643,6 +865,10 @@ fn extract_security_groups(json: &str) -> Vec<String> {
 }
 
 fn get_vpc_name(vpc_id: &str) -> String {
+    if let Some(name) = name_index::lookup(vpc_id) {
+        return name;
+    }
+
     let output = cli_adapter::run(&[
         "ec2",
         "describe-vpcs",
@@ -662,6 +888,10 @@ fn get_vpc_name(vpc_id: &str) -> String {
 }
 
 pub fn get_subnet_name(subnet_id: &str) -> String {
+    if let Some(name) = name_index::lookup(subnet_id) {
+        return name;
+    }
+
     let output = cli_adapter::run(&[
         "ec2",
         "describe-subnets",
@@ -680,7 +910,21 @@ pub fn get_subnet_name(subnet_id: &str) -> String {
     subnet_id.to_string()
 }
 
+// AMI ID별 표시 이름 (조회 실패 시의 대체 문구 포함, 느린 describe-images 재호출 방지)
+fn ami_name_cache() -> &'static Mutex<HashMap<String, String>> {
+    static AMI_NAMES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    AMI_NAMES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 fn get_ami_name(ami_id: &str) -> String {
+    if let Some(name) = ami_name_cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(ami_id).cloned())
+    {
+        return name;
+    }
+
     let output = cli_adapter::run(&[
         "ec2",
         "describe-images",
@@ -689,24 +933,48 @@ fn get_ami_name(ami_id: &str) -> String {
         "--output",
         "json",
     ]);
+    let name = ami_display_name(ami_id, output.as_deref());
 
-    if let Some(json) = output {
-        let name = parse_name_tag(&json);
-        if !name.is_empty() {
-            return name;
-        }
+    if let Ok(mut cache) = ami_name_cache().lock() {
+        cache.insert(ami_id.to_string(), name.clone());
+    }
+    name
+}
+
+fn ami_display_name(ami_id: &str, output: Option<&str>) -> String {
+    let Some(json) = output else {
+        // describe-images 권한이 없거나 호출이 실패한 경우
+        return format!("{} (name unknown — insufficient permission)", ami_id);
+    };
+
+    let name = parse_name_tag(json);
+    let name = if !name.is_empty() {
+        name
+    } else if json.contains(ami_id) {
+        ami_id.to_string()
+    } else {
+        // 등록 해제되었거나 공유가 끊긴 AMI
+        return format!("{} (name unknown — image not found)", ami_id);
+    };
+    // 다른 계정에서 공유된 AMI는 소유 계정을 함께 표시
+    let owner_id = extract_json_value(json, "OwnerId").unwrap_or_default();
+    match foreign_owner(&owner_id) {
+        Some(owner) => format!("{} [owner: {}]", name, owner),
+        None => name,
     }
-    ami_id.to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        Ec2Detail, VolumeDetail, cli_adapter, extract_security_groups, extract_state,
-        get_instance_detail, get_subnet_name, iam_adapter, list_instances,
-        parse_instance_detail_output, parse_instance_resources, parse_instance_volume_mappings,
-        parse_user_data_output, parse_volume_detail_output,
+        Ec2Detail, VolumeDetail, ami_display_name, clear_prefetched_instances, cli_adapter,
+        extract_security_groups, extract_state, get_instance_detail, get_subnet_name, iam_adapter,
+        is_terminated_state, list_instances, parse_instance_detail_output,
+        parse_instance_resources, parse_instance_volume_mappings, parse_user_data_output,
+        parse_volume_detail_output, parse_volumes_output, prefetch_instances,
+        refresh_instance_detail, volume_details_from,
     };
+    use crate::aws_cli::common::{set_account_id, set_region};
     use crate::aws_cli::iam::{AttachedPolicy, IamRoleDetail, InlinePolicy};
     use crate::i18n::Language;
     use std::sync::{Mutex, OnceLock};
@@ -724,6 +992,8 @@ mod tests {
             name: "web-a".to_string(),
             instance_id: "i-0123456789abcdef0".to_string(),
             instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
             ami: "ami-12345678".to_string(),
             platform: "Linux".to_string(),
             architecture: "x86_64".to_string(),
@@ -844,6 +1114,8 @@ mod tests {
             name: String::new(),
             instance_id: "i-0abc".to_string(),
             instance_type: "t3.nano".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(512),
             ami: "ami-0abc".to_string(),
             platform: "Linux".to_string(),
             architecture: "x86_64".to_string(),
@@ -873,6 +1145,28 @@ mod tests {
         assert!(!md.contains("Public IP"));
     }
 
+    #[test]
+    fn ec2_markdown_notes_terminated_instances_and_dashes_missing_network() {
+        let mut detail = sample_detail();
+        detail.state = "terminated".to_string();
+        detail.vpc = String::new();
+        detail.subnet = String::new();
+        detail.private_ip = String::new();
+        detail.security_groups = vec![];
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("> This instance is terminated or shutting down."));
+        assert!(md.contains("| VPC | - |"));
+        assert!(md.contains("| Subnet | - |"));
+        assert!(md.contains("| Private IP | - |"));
+        assert!(md.contains("| Security Groups | - |"));
+
+        let running = sample_detail().to_markdown(Language::English);
+        assert!(!running.contains("terminated or shutting down"));
+        assert!(is_terminated_state("shutting-down"));
+        assert!(!is_terminated_state("stopped"));
+    }
+
     #[test]
     fn parse_instance_detail_output_maps_basic_fields_from_fixture_json() {
         let payload = r#"
@@ -933,6 +1227,26 @@ mod tests {
         assert!(volume.delete_on_termination);
     }
 
+    #[test]
+    fn volume_details_from_matches_batched_volumes_to_mappings() {
+        let payload = r#"{"Volumes":[{"VolumeId":"vol-2","Size":"8","VolumeType":"gp2"},{"VolumeId":"vol-1","Size":"30","VolumeType":"gp3","Encrypted":true}]}"#;
+        let mappings = vec![
+            ("/dev/xvda".to_string(), "vol-1".to_string(), true),
+            ("/dev/xvdb".to_string(), "vol-2".to_string(), false),
+            ("/dev/xvdc".to_string(), "vol-missing".to_string(), false),
+        ];
+
+        let volumes = volume_details_from(&parse_volumes_output(payload), &mappings);
+        assert_eq!(volumes.len(), 2);
+        assert_eq!(volumes[0].volume_id, "vol-1");
+        assert_eq!(volumes[0].size_gb, 30);
+        assert!(volumes[0].encrypted);
+        assert_eq!(volumes[1].device_name, "/dev/xvdb");
+        assert_eq!(volumes[1].volume_type, "gp2");
+        assert!(!volumes[1].delete_on_termination);
+        assert!(volume_details_from(&parse_volumes_output("not json"), &mappings).is_empty());
+    }
+
     #[test]
     fn parse_user_data_output_decodes_base64_and_rejects_empty_payloads() {
         let payload = r#"{"UserData":{"Value":"IyEvYmluL2Jhc2gKZWNobyBoaQ=="}}"#;
@@ -999,6 +1313,7 @@ mod tests {
                     "Instances": [{
                       "InstanceId": "i-abc",
                       "InstanceType": "t3.micro",
+                      "CpuOptions": {"CoreCount": 1, "ThreadsPerCore": 1},
                       "ImageId": "ami-123",
                       "Platform": "Linux",
                       "Architecture": "x86_64",
@@ -1019,7 +1334,11 @@ mod tests {
                         {"Key": "Name", "Value": "web-a"},
                         {"Key": "Env", "Value": "prod"}
                       ],
-                      "SecurityGroups": [{"GroupName": "sg-web"}]
+                      "SecurityGroups": [{"GroupName": "sg-web"}],
+                      "BlockDeviceMappings": [
+                        {"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-1", "DeleteOnTermination": true}},
+                        {"DeviceName": "/dev/xvdb", "Ebs": {"VolumeId": "vol-2", "DeleteOnTermination": false}}
+                      ]
                     }]
                   }]
                 }
@@ -1063,36 +1382,18 @@ mod tests {
                 r#"{"Subnets":[{"SubnetId":"subnet-123","Tags":[{"Key": "Name", "Value": "public-a"}]}]}"#,
             ),
         );
-        cli_adapter::set(
-            &[
-                "ec2",
-                "describe-instances",
-                "--instance-ids",
-                "i-abc",
-                "--query",
-                "Reservations[0].Instances[0].BlockDeviceMappings",
-                "--output",
-                "json",
-            ],
-            Some(
-                r#"
-                [
-                  {"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-1", "DeleteOnTermination": true}}
-                ]
-                "#,
-            ),
-        );
         cli_adapter::set(
             &[
                 "ec2",
                 "describe-volumes",
                 "--volume-ids",
                 "vol-1",
+                "vol-2",
                 "--output",
                 "json",
             ],
             Some(
-                r#"{"Volumes":[{"VolumeId": "vol-1", "Size": "30", "VolumeType": "gp3", "Iops": "3000", "Encrypted": true}]}"#,
+                r#"{"Volumes":[{"VolumeId": "vol-2", "Size": "100", "VolumeType": "st1", "Encrypted": false},{"VolumeId": "vol-1", "Size": "30", "VolumeType": "gp3", "Iops": "3000", "Encrypted": true}]}"#,
             ),
         );
         cli_adapter::set(
@@ -1108,6 +1409,19 @@ mod tests {
             ],
             Some(r#"{"UserData":{"Value":"IyEvYmluL2Jhc2gKZWNobyBoaQ=="}}"#),
         );
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-instance-types",
+                "--instance-types",
+                "t3.micro",
+                "--output",
+                "json",
+            ],
+            Some(
+                r#"{"InstanceTypes":[{"InstanceType":"t3.micro","VCpuInfo":{"DefaultVCpus":2},"MemoryInfo":{"SizeInMiB":1024}}]}"#,
+            ),
+        );
 
         iam_adapter::set(
             "role-web",
@@ -1126,8 +1440,10 @@ mod tests {
         assert_eq!(detail.ami, "ubuntu");
         assert_eq!(detail.vpc, "main-vpc");
         assert_eq!(detail.subnet, "public-a");
-        assert_eq!(detail.volumes.len(), 1);
+        assert_eq!(detail.volumes.len(), 2);
         assert_eq!(detail.volumes[0].volume_id, "vol-1");
+        assert_eq!(detail.volumes[1].device_name, "/dev/xvdb");
+        assert_eq!(detail.volumes[1].volume_type, "st1");
         assert!(
             detail
                 .user_data
@@ -1137,6 +1453,139 @@ mod tests {
         );
         assert_eq!(detail.iam_role.as_deref(), Some("role-web"));
         assert!(detail.iam_role_detail.is_some());
+        // vCPU는 유형 기본값(2)보다 CpuOptions로 줄인 값을 따름
+        assert_eq!(detail.vcpus, Some(1));
+        assert_eq!(detail.memory_mib, Some(1024));
+    }
+
+    #[test]
+    fn prefetch_instances_serves_details_from_single_batched_call() {
+        let _guard = test_lock();
+        cli_adapter::clear();
+        iam_adapter::clear();
+
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-instances",
+                "--instance-ids",
+                "i-1",
+                "i-2",
+                "--output",
+                "json",
+            ],
+            Some(
+                r#"{"Reservations":[{"Instances":[{"InstanceId":"i-1","InstanceType":"t3.micro","State":{"Name":"running"}}]},{"Instances":[{"InstanceId":"i-2","InstanceType":"m5.large","State":{"Name":"stopped"},"Tags":[{"Key":"Name","Value":"batch-b"}]}]}]}"#,
+            ),
+        );
+
+        set_region("eu-north-1");
+        prefetch_instances(&["i-1".to_string(), "i-2".to_string()]);
+
+        // 다른 리전에서는 미리 받아둔 응답을 쓰지 않음
+        set_region("sa-east-1");
+        assert!(get_instance_detail("i-2").is_none());
+        set_region("eu-north-1");
+        let second = get_instance_detail("i-2").expect("prefetched detail");
+        assert_eq!(second.instance_type, "m5.large");
+        assert_eq!(second.name, "batch-b");
+        assert_eq!(second.state, "stopped");
+        let first = get_instance_detail("i-1").expect("prefetched detail");
+        assert_eq!(first.instance_type, "t3.micro");
+        assert_eq!(first.state, "running");
+
+        // 한 번 사용한 응답은 소비되어 다음 조회는 개별 호출로 돌아감
+        assert!(get_instance_detail("i-1").is_none());
+
+        // 실행이 끝나면 쓰지 않은 응답도 비움
+        prefetch_instances(&["i-1".to_string(), "i-2".to_string()]);
+        clear_prefetched_instances();
+        assert!(get_instance_detail("i-2").is_none());
+    }
+
+    #[test]
+    fn get_instance_volumes_falls_back_to_single_calls_when_batch_fails() {
+        let _guard = test_lock();
+        cli_adapter::clear();
+        iam_adapter::clear();
+
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-instances",
+                "--instance-ids",
+                "i-vols",
+                "--output",
+                "json",
+            ],
+            Some(
+                r#"{"Reservations": [{"Instances": [{"InstanceId": "i-vols", "BlockDeviceMappings": [{"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-ok"}}, {"DeviceName": "/dev/xvdb", "Ebs": {"VolumeId": "vol-gone"}}]}]}]}"#,
+            ),
+        );
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-volumes",
+                "--volume-ids",
+                "vol-ok",
+                "--output",
+                "json",
+            ],
+            Some(r#"{"Volumes":[{"VolumeId": "vol-ok", "Size": "8", "VolumeType": "gp3"}]}"#),
+        );
+
+        // 묶음 호출은 vol-gone 때문에 실패하지만 vol-ok는 개별 조회로 표시
+        let detail = get_instance_detail("i-vols").expect("instance detail");
+        assert_eq!(detail.volumes.len(), 1);
+        assert_eq!(detail.volumes[0].volume_id, "vol-ok");
+        assert_eq!(detail.volumes[0].size_gb, 8);
+    }
+
+    #[test]
+    fn refresh_instance_detail_reuses_cached_volume_details() {
+        let _guard = test_lock();
+        cli_adapter::clear();
+        iam_adapter::clear();
+
+        let instance_args = [
+            "ec2",
+            "describe-instances",
+            "--instance-ids",
+            "i-refresh",
+            "--output",
+            "json",
+        ];
+        let volume_args = [
+            "ec2",
+            "describe-volumes",
+            "--volume-ids",
+            "vol-refresh",
+            "--output",
+            "json",
+        ];
+        cli_adapter::set(
+            &instance_args,
+            Some(
+                r#"{"Reservations": [{"Instances": [{"InstanceId": "i-refresh", "BlockDeviceMappings": [{"DeviceName": "/dev/xvda", "Ebs": {"VolumeId": "vol-refresh"}}]}]}]}"#,
+            ),
+        );
+        cli_adapter::set(
+            &volume_args,
+            Some(r#"{"Volumes":[{"VolumeId": "vol-refresh", "Size": "20", "VolumeType": "gp3"}]}"#),
+        );
+
+        let detail = get_instance_detail("i-refresh").expect("instance detail");
+        assert_eq!(detail.volumes.len(), 1);
+
+        // 새로고침은 describe-volumes 없이 기존 볼륨 정보를 사용
+        cli_adapter::set(&volume_args, None);
+        let refreshed = refresh_instance_detail("i-refresh").expect("refreshed detail");
+        assert_eq!(refreshed.volumes.len(), 1);
+        assert_eq!(refreshed.volumes[0].size_gb, 20);
+
+        // 일반 조회는 항상 볼륨을 다시 조회
+        let fresh = get_instance_detail("i-refresh").expect("instance detail");
+        assert!(fresh.volumes.is_empty());
     }
 
     #[test]
@@ -1161,4 +1610,48 @@ mod tests {
         assert_eq!(get_subnet_name("subnet-777"), "app-a");
         assert_eq!(get_subnet_name("subnet-999"), "subnet-999");
     }
+
+    #[test]
+    fn ami_display_name_falls_back_when_lookup_is_denied_or_empty() {
+        assert_eq!(
+            ami_display_name(
+                "ami-1",
+                Some(
+                    r#"{"Images":[{"ImageId":"ami-1","Tags":[{"Key": "Name", "Value": "al2023"}]}]}"#
+                )
+            ),
+            "al2023"
+        );
+        assert_eq!(
+            ami_display_name("ami-1", None),
+            "ami-1 (name unknown — insufficient permission)"
+        );
+        assert_eq!(
+            ami_display_name("ami-1", Some(r#"{"Images":[]}"#)),
+            "ami-1 (name unknown — image not found)"
+        );
+        assert_eq!(
+            ami_display_name("ami-1", Some(r#"{"Images":[{"ImageId":"ami-1"}]}"#)),
+            "ami-1"
+        );
+    }
+
+    #[test]
+    fn ami_display_name_appends_owner_for_shared_images() {
+        set_account_id("111122223333");
+        assert_eq!(
+            ami_display_name(
+                "ami-1",
+                Some(r#"{"Images":[{"ImageId": "ami-1", "OwnerId": "444455556666"}]}"#)
+            ),
+            "ami-1 [owner: 444455556666]"
+        );
+        assert_eq!(
+            ami_display_name(
+                "ami-1",
+                Some(r#"{"Images":[{"ImageId": "ami-1", "OwnerId": "111122223333"}]}"#)
+            ),
+            "ami-1"
+        );
+    }
 }
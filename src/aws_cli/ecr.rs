@@ -1,5 +1,6 @@
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
+use crate::locale;
+use serde::{Deserialize, Serialize};
 
 pub use crate::aws_cli::ecr_sdk::{get_ecr_detail, list_ecr_repositories};
 
@@ -42,7 +43,7 @@ pub(super) struct EcrImageDetail {
     pub(super) image_digest: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EcrDetail {
     pub name: String,
     pub uri: String,
@@ -93,8 +94,16 @@ impl EcrDetail {
             format!("| {} | {} |", i18n.md_uri(), self.uri),
             format!("| {} | {} |", i18n.md_tag_mutability(), self.tag_mutability),
             format!("| {} | {} |", i18n.md_encryption(), encryption_display),
-            format!("| {} | {} |", i18n.md_image_count(), self.image_count),
-            format!("| {} | {} |", i18n.md_created_at(), self.created_at),
+            format!(
+                "| {} | {} |",
+                i18n.md_image_count(),
+                locale::count(self.image_count)
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created_at)
+            ),
         ];
 
         lines.join("\n") + "\n"
@@ -50,6 +50,7 @@ fn parse_repository_list_output(output: &str) -> Option<Vec<AwsResource>> {
                     state: repo.image_tag_mutability,
                     az: String::new(),
                     cidr: repo.repository_uri,
+                    owner_id: String::new(),
                 }
             })
             .collect(),
@@ -0,0 +1,381 @@
+pub use crate::aws_cli::ecs_sdk::{get_ecs_detail, list_ecs_clusters};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EcsService {
+    pub name: String,
+    pub status: String,
+    // FARGATE, EC2 또는 용량 공급자 전략 ("FARGATE_SPOT, FARGATE")
+    pub launch_type: String,
+    // "family:revision"
+    pub task_definition: String,
+    pub task_definition_arn: String,
+    pub desired_count: i32,
+    pub running_count: i32,
+    pub pending_count: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EcsTask {
+    // 태스크 ARN의 마지막 부분
+    pub id: String,
+    pub task_definition: String,
+    pub last_status: String,
+    pub launch_type: String,
+    pub availability_zone: String,
+    pub cpu: String,
+    pub memory: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EcsContainer {
+    pub name: String,
+    pub image: String,
+    pub cpu: i32,
+    // 하드 제한 (MiB)
+    pub memory: Option<i32>,
+    // 소프트 제한 (MiB)
+    pub memory_reservation: Option<i32>,
+    // "80/tcp" 또는 "8080→80/tcp"
+    pub ports: Vec<String>,
+    // 환경 변수와 시크릿의 키만 저장 (값은 민감 정보일 수 있음)
+    pub env_var_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EcsTaskDefinition {
+    // "family:revision"
+    pub name: String,
+    pub network_mode: String,
+    pub cpu: String,
+    pub memory: String,
+    pub task_role_arn: String,
+    pub execution_role_arn: String,
+    pub containers: Vec<EcsContainer>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct EcsDetail {
+    pub name: String,
+    pub arn: String,
+    pub status: String,
+    pub capacity_providers: Vec<String>,
+    pub registered_container_instances: i32,
+    pub active_services_count: i32,
+    pub running_tasks_count: i32,
+    pub pending_tasks_count: i32,
+    pub services: Vec<EcsService>,
+    pub tasks: Vec<EcsTask>,
+    pub task_definitions: Vec<EcsTaskDefinition>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl EcsContainer {
+    fn memory_label(&self) -> String {
+        match (self.memory, self.memory_reservation) {
+            (Some(hard), Some(soft)) => format!("{} (soft {})", hard, soft),
+            (Some(hard), None) => hard.to_string(),
+            (None, Some(soft)) => format!("{} (soft)", soft),
+            (None, None) => "-".to_string(),
+        }
+    }
+}
+
+impl EcsDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.ecs_cluster(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+        ];
+        if !self.capacity_providers.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_capacity_providers(),
+                self.capacity_providers.join(", ")
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_container_instances(),
+            self.registered_container_instances
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_active_services(),
+            self.active_services_count
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_running_tasks(),
+            self.running_tasks_count
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_pending_tasks(),
+            self.pending_tasks_count
+        ));
+
+        // Services
+        if !self.services.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_services()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_state(),
+                i18n.md_launch_type(),
+                i18n.md_task_definition(),
+                i18n.md_task_counts()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for service in &self.services {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} / {} / {} |",
+                    service.name,
+                    service.status,
+                    service.launch_type,
+                    service.task_definition,
+                    service.desired_count,
+                    service.running_count,
+                    service.pending_count
+                ));
+            }
+        }
+
+        // Running Tasks
+        if !self.tasks.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_running_tasks()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | CPU / {} |",
+                i18n.md_task_id(),
+                i18n.md_task_definition(),
+                i18n.md_state(),
+                i18n.md_launch_type(),
+                i18n.md_availability_zone(),
+                i18n.md_memory()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|".to_string());
+            for task in &self.tasks {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} / {} |",
+                    task.id,
+                    task.task_definition,
+                    task.last_status,
+                    task.launch_type,
+                    task.availability_zone,
+                    task.cpu,
+                    task.memory
+                ));
+            }
+        }
+
+        // Task Definitions
+        if !self.task_definitions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}", i18n.md_task_definitions()));
+            for definition in &self.task_definitions {
+                lines.push(String::new());
+                lines.push(format!("#### {}\n", definition.name));
+                lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+                lines.push("|:---|:---|".to_string());
+                if !definition.network_mode.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_network_mode(),
+                        definition.network_mode
+                    ));
+                }
+                if !definition.cpu.is_empty() {
+                    lines.push(format!("| CPU | {} |", definition.cpu));
+                }
+                if !definition.memory.is_empty() {
+                    lines.push(format!("| {} | {} |", i18n.md_memory(), definition.memory));
+                }
+                if !definition.task_role_arn.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_task_role(),
+                        definition.task_role_arn
+                    ));
+                }
+                if !definition.execution_role_arn.is_empty() {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        i18n.md_execution_role(),
+                        definition.execution_role_arn
+                    ));
+                }
+
+                if definition.containers.is_empty() {
+                    continue;
+                }
+                lines.push(String::new());
+                lines.push(format!(
+                    "| {} | {} | CPU | {} | {} | {} |",
+                    i18n.md_container(),
+                    i18n.md_image(),
+                    i18n.md_memory(),
+                    i18n.md_port(),
+                    i18n.md_environment_variables()
+                ));
+                lines.push("|:---|:---|:---|:---|:---|:---|".to_string());
+                for container in &definition.containers {
+                    let ports = if container.ports.is_empty() {
+                        "-".to_string()
+                    } else {
+                        container.ports.join(", ")
+                    };
+                    let env_var_keys = if container.env_var_keys.is_empty() {
+                        "-".to_string()
+                    } else {
+                        container
+                            .env_var_keys
+                            .iter()
+                            .map(|key| format!("`{}`", key))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    lines.push(format!(
+                        "| {} | {} | {} | {} | {} | {} |",
+                        container.name,
+                        container.image,
+                        container.cpu,
+                        container.memory_label(),
+                        ports,
+                        env_var_keys
+                    ));
+                }
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EcsContainer, EcsDetail, EcsService, EcsTask, EcsTaskDefinition};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> EcsDetail {
+        EcsDetail {
+            name: "checkout".to_string(),
+            arn: "arn:aws:ecs:ap-northeast-2:123456789012:cluster/checkout".to_string(),
+            status: "ACTIVE".to_string(),
+            capacity_providers: vec!["FARGATE".to_string(), "FARGATE_SPOT".to_string()],
+            registered_container_instances: 0,
+            active_services_count: 1,
+            running_tasks_count: 2,
+            pending_tasks_count: 0,
+            services: vec![EcsService {
+                name: "api".to_string(),
+                status: "ACTIVE".to_string(),
+                launch_type: "FARGATE".to_string(),
+                task_definition: "checkout-api:7".to_string(),
+                task_definition_arn:
+                    "arn:aws:ecs:ap-northeast-2:123456789012:task-definition/checkout-api:7"
+                        .to_string(),
+                desired_count: 2,
+                running_count: 2,
+                pending_count: 0,
+            }],
+            tasks: vec![EcsTask {
+                id: "0a1b2c3d4e5f".to_string(),
+                task_definition: "checkout-api:7".to_string(),
+                last_status: "RUNNING".to_string(),
+                launch_type: "FARGATE".to_string(),
+                availability_zone: "ap-northeast-2a".to_string(),
+                cpu: "256".to_string(),
+                memory: "512".to_string(),
+            }],
+            task_definitions: vec![EcsTaskDefinition {
+                name: "checkout-api:7".to_string(),
+                network_mode: "awsvpc".to_string(),
+                cpu: "256".to_string(),
+                memory: "512".to_string(),
+                task_role_arn: "arn:aws:iam::123456789012:role/checkout-task".to_string(),
+                execution_role_arn: String::new(),
+                containers: vec![
+                    EcsContainer {
+                        name: "app".to_string(),
+                        image: "123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/checkout:1.4"
+                            .to_string(),
+                        cpu: 192,
+                        memory: Some(384),
+                        memory_reservation: Some(256),
+                        ports: vec!["8080/tcp".to_string()],
+                        env_var_keys: vec!["DB_PASSWORD".to_string(), "STAGE".to_string()],
+                    },
+                    EcsContainer {
+                        name: "log-router".to_string(),
+                        image: "amazon/aws-for-fluent-bit:stable".to_string(),
+                        cpu: 0,
+                        memory: None,
+                        memory_reservation: Some(64),
+                        ports: vec![],
+                        env_var_keys: vec![],
+                    },
+                ],
+            }],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_ecs_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## ECS Cluster (checkout)"));
+        assert!(markdown.contains("| Capacity Providers | FARGATE, FARGATE_SPOT |"));
+        assert!(markdown.contains("| api | ACTIVE | FARGATE | checkout-api:7 | 2 / 2 / 0 |"));
+        assert!(markdown.contains(
+            "| 0a1b2c3d4e5f | checkout-api:7 | RUNNING | FARGATE | ap-northeast-2a | 256 / 512 |"
+        ));
+        assert!(markdown.contains("#### checkout-api:7"));
+        assert!(markdown.contains("| Network Mode | awsvpc |"));
+        assert!(markdown.contains(
+            "| app | 123456789012.dkr.ecr.ap-northeast-2.amazonaws.com/checkout:1.4 | 192 | 384 (soft 256) | 8080/tcp | `DB_PASSWORD`, `STAGE` |"
+        ));
+        assert!(
+            markdown.contains(
+                "| log-router | amazon/aws-for-fluent-bit:stable | 0 | 64 (soft) | - | - |"
+            )
+        );
+        assert!(!markdown.contains("Execution Role"));
+        assert!(markdown.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn scenario_ecs_empty_cluster() {
+        let mut detail = sample_detail();
+        detail.capacity_providers.clear();
+        detail.services.clear();
+        detail.tasks.clear();
+        detail.task_definitions.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 활성 서비스 | 1 |"));
+        assert!(!markdown.contains("용량 공급자"));
+        assert!(!markdown.contains("### 서비스"));
+        assert!(!markdown.contains("### 태스크 정의"));
+    }
+}
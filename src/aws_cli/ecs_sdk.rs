@@ -0,0 +1,417 @@
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::ecs::{EcsContainer, EcsDetail, EcsService, EcsTask, EcsTaskDefinition};
+use aws_sdk_ecs::types::{
+    Cluster, ClusterField, ContainerDefinition, DesiredStatus, PortMapping, Service, Task,
+    TaskDefinition,
+};
+
+// DescribeServices는 한 번에 최대 10개, DescribeClusters/DescribeTasks는 100개까지 조회
+const DESCRIBE_SERVICES_BATCH: usize = 10;
+const DESCRIBE_BATCH: usize = 100;
+
+/// List all ECS clusters using AWS SDK
+pub fn list_ecs_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_ecs_clusters_async())
+}
+
+async fn list_ecs_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_ecs, &config);
+
+    let arns: Vec<String> = match client
+        .list_clusters()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(arns) => arns,
+        Err(e) => {
+            tracing::error!("Error listing ECS clusters: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources = Vec::new();
+    for batch in arns.chunks(DESCRIBE_BATCH) {
+        match client
+            .describe_clusters()
+            .set_clusters(Some(batch.to_vec()))
+            .send()
+            .await
+        {
+            Ok(output) => resources.extend(output.clusters().iter().map(map_cluster_resource)),
+            Err(e) => tracing::error!("Error describing ECS clusters: {:?}", e),
+        }
+    }
+    resources
+}
+
+/// Get ECS cluster detail (services, running tasks and task definitions) using AWS SDK
+pub fn get_ecs_detail(name: &str) -> Option<EcsDetail> {
+    get_runtime().block_on(get_ecs_detail_async(name))
+}
+
+async fn get_ecs_detail_async(name: &str) -> Option<EcsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_ecs, &config);
+
+    let output = client
+        .describe_clusters()
+        .clusters(name)
+        .include(ClusterField::Tags)
+        .send()
+        .await
+        .ok()?;
+    let mut detail = map_cluster_detail(output.clusters().first()?);
+
+    // 서비스/태스크/태스크 정의 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_services()
+        .cluster(name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(arns) => {
+            for batch in arns.chunks(DESCRIBE_SERVICES_BATCH) {
+                match client
+                    .describe_services()
+                    .cluster(name)
+                    .set_services(Some(batch.to_vec()))
+                    .send()
+                    .await
+                {
+                    Ok(output) => detail
+                        .services
+                        .extend(output.services().iter().map(map_service)),
+                    Err(e) => tracing::warn!("Error describing services in {}: {:?}", name, e),
+                }
+            }
+        }
+        Err(e) => tracing::warn!("Error listing services for {}: {:?}", name, e),
+    }
+    detail.services.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut task_definition_arns: Vec<String> = Vec::new();
+    match client
+        .list_tasks()
+        .cluster(name)
+        .desired_status(DesiredStatus::Running)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(arns) => {
+            for batch in arns.chunks(DESCRIBE_BATCH) {
+                match client
+                    .describe_tasks()
+                    .cluster(name)
+                    .set_tasks(Some(batch.to_vec()))
+                    .send()
+                    .await
+                {
+                    Ok(output) => {
+                        for task in output.tasks() {
+                            if let Some(arn) = task.task_definition_arn()
+                                && !task_definition_arns.iter().any(|a| a == arn)
+                            {
+                                task_definition_arns.push(arn.to_string());
+                            }
+                            detail.tasks.push(map_task(task));
+                        }
+                    }
+                    Err(e) => tracing::warn!("Error describing tasks in {}: {:?}", name, e),
+                }
+            }
+        }
+        Err(e) => tracing::warn!("Error listing tasks for {}: {:?}", name, e),
+    }
+
+    // 서비스가 사용하는 태스크 정의를 실행 중인 태스크의 태스크 정의보다 먼저 표시
+    let mut ordered_arns: Vec<String> = Vec::new();
+    let service_arns = detail.services.iter().map(|s| &s.task_definition_arn);
+    for arn in service_arns.chain(task_definition_arns.iter()) {
+        if !arn.is_empty() && !ordered_arns.contains(arn) {
+            ordered_arns.push(arn.clone());
+        }
+    }
+    for arn in ordered_arns {
+        match client
+            .describe_task_definition()
+            .task_definition(&arn)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                if let Some(definition) = output.task_definition() {
+                    detail
+                        .task_definitions
+                        .push(map_task_definition(definition));
+                }
+            }
+            Err(e) => tracing::warn!("Error describing task definition {}: {:?}", arn, e),
+        }
+    }
+
+    Some(detail)
+}
+
+// "arn:aws:ecs:...:task-definition/checkout-api:7" -> "checkout-api:7"
+fn arn_suffix(arn: &str) -> String {
+    Arn::parse(arn)
+        .map(|parsed| parsed.resource_name())
+        .unwrap_or(arn)
+        .to_string()
+}
+
+fn map_cluster_resource(cluster: &Cluster) -> AwsResource {
+    let name = cluster.cluster_name().unwrap_or_default().to_string();
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: cluster.status().unwrap_or_default().to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_cluster_detail(cluster: &Cluster) -> EcsDetail {
+    let mut tags: Vec<(String, String)> = cluster
+        .tags()
+        .iter()
+        .filter_map(|t| {
+            Some((
+                t.key()?.to_string(),
+                t.value().unwrap_or_default().to_string(),
+            ))
+        })
+        .collect();
+    tags.sort();
+
+    EcsDetail {
+        name: cluster.cluster_name().unwrap_or_default().to_string(),
+        arn: cluster.cluster_arn().unwrap_or_default().to_string(),
+        status: cluster.status().unwrap_or_default().to_string(),
+        capacity_providers: cluster.capacity_providers().to_vec(),
+        registered_container_instances: cluster.registered_container_instances_count(),
+        active_services_count: cluster.active_services_count(),
+        running_tasks_count: cluster.running_tasks_count(),
+        pending_tasks_count: cluster.pending_tasks_count(),
+        services: Vec::new(),
+        tasks: Vec::new(),
+        task_definitions: Vec::new(),
+        tags,
+    }
+}
+
+fn map_service(service: &Service) -> EcsService {
+    // 시작 유형이 없으면 용량 공급자 전략 사용
+    let launch_type = match service.launch_type() {
+        Some(launch_type) => launch_type.as_str().to_string(),
+        None => service
+            .capacity_provider_strategy()
+            .iter()
+            .map(|item| item.capacity_provider().to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    let task_definition_arn = service.task_definition().unwrap_or_default().to_string();
+
+    EcsService {
+        name: service.service_name().unwrap_or_default().to_string(),
+        status: service.status().unwrap_or_default().to_string(),
+        launch_type,
+        task_definition: arn_suffix(&task_definition_arn),
+        task_definition_arn,
+        desired_count: service.desired_count(),
+        running_count: service.running_count(),
+        pending_count: service.pending_count(),
+    }
+}
+
+fn map_task(task: &Task) -> EcsTask {
+    let launch_type = match task.launch_type() {
+        Some(launch_type) => launch_type.as_str().to_string(),
+        None => task
+            .capacity_provider_name()
+            .unwrap_or_default()
+            .to_string(),
+    };
+    EcsTask {
+        id: arn_suffix(task.task_arn().unwrap_or_default()),
+        task_definition: arn_suffix(task.task_definition_arn().unwrap_or_default()),
+        last_status: task.last_status().unwrap_or_default().to_string(),
+        launch_type,
+        availability_zone: task.availability_zone().unwrap_or_default().to_string(),
+        cpu: task.cpu().unwrap_or_default().to_string(),
+        memory: task.memory().unwrap_or_default().to_string(),
+    }
+}
+
+fn map_task_definition(definition: &TaskDefinition) -> EcsTaskDefinition {
+    EcsTaskDefinition {
+        name: format!(
+            "{}:{}",
+            definition.family().unwrap_or_default(),
+            definition.revision()
+        ),
+        network_mode: definition
+            .network_mode()
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default(),
+        cpu: definition.cpu().unwrap_or_default().to_string(),
+        memory: definition.memory().unwrap_or_default().to_string(),
+        task_role_arn: definition.task_role_arn().unwrap_or_default().to_string(),
+        execution_role_arn: definition
+            .execution_role_arn()
+            .unwrap_or_default()
+            .to_string(),
+        containers: definition
+            .container_definitions()
+            .iter()
+            .map(map_container)
+            .collect(),
+    }
+}
+
+fn map_container(container: &ContainerDefinition) -> EcsContainer {
+    let mut env_var_keys: Vec<String> = container
+        .environment()
+        .iter()
+        .filter_map(|pair| pair.name())
+        .chain(container.secrets().iter().map(|secret| secret.name()))
+        .map(|key| key.to_string())
+        .collect();
+    env_var_keys.sort();
+    env_var_keys.dedup();
+
+    EcsContainer {
+        name: container.name().unwrap_or_default().to_string(),
+        image: container.image().unwrap_or_default().to_string(),
+        cpu: container.cpu(),
+        memory: container.memory(),
+        memory_reservation: container.memory_reservation(),
+        ports: container.port_mappings().iter().map(format_port).collect(),
+        env_var_keys,
+    }
+}
+
+fn format_port(mapping: &PortMapping) -> String {
+    let protocol = mapping
+        .protocol()
+        .map(|p| p.as_str())
+        .unwrap_or("tcp")
+        .to_string();
+    let container_port = match (mapping.container_port(), mapping.container_port_range()) {
+        (Some(port), _) => port.to_string(),
+        (None, Some(range)) => range.to_string(),
+        (None, None) => return String::new(),
+    };
+    // awsvpc 모드는 호스트 포트가 컨테이너 포트와 같으므로 다를 때만 표시
+    match mapping.host_port() {
+        Some(host) if host != 0 && host.to_string() != container_port => {
+            format!("{}→{}/{}", host, container_port, protocol)
+        }
+        _ => format!("{}/{}", container_port, protocol),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_port, map_container, map_service, map_task};
+    use aws_sdk_ecs::types::{
+        CapacityProviderStrategyItem, ContainerDefinition, KeyValuePair, LaunchType, PortMapping,
+        Secret, Service, Task, TransportProtocol,
+    };
+
+    #[test]
+    fn format_port_shows_host_port_only_when_different() {
+        let same = PortMapping::builder()
+            .container_port(8080)
+            .host_port(8080)
+            .build();
+        assert_eq!(format_port(&same), "8080/tcp");
+
+        let mapped = PortMapping::builder()
+            .container_port(53)
+            .host_port(5353)
+            .protocol(TransportProtocol::Udp)
+            .build();
+        assert_eq!(format_port(&mapped), "5353→53/udp");
+
+        let dynamic = PortMapping::builder()
+            .container_port(80)
+            .host_port(0)
+            .build();
+        assert_eq!(format_port(&dynamic), "80/tcp");
+    }
+
+    #[test]
+    fn map_container_keeps_env_and_secret_keys_only() {
+        let container = ContainerDefinition::builder()
+            .name("app")
+            .image("checkout:1.4")
+            .cpu(256)
+            .memory_reservation(256)
+            .environment(KeyValuePair::builder().name("STAGE").value("prod").build())
+            .secrets(
+                Secret::builder()
+                    .name("DB_PASSWORD")
+                    .value_from("arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:db")
+                    .build()
+                    .expect("secret"),
+            )
+            .port_mappings(PortMapping::builder().container_port(8080).build())
+            .build();
+
+        let container = map_container(&container);
+        assert_eq!(container.env_var_keys, vec!["DB_PASSWORD", "STAGE"]);
+        assert_eq!(container.memory, None);
+        assert_eq!(container.memory_reservation, Some(256));
+        assert_eq!(container.ports, vec!["8080/tcp"]);
+    }
+
+    #[test]
+    fn map_service_falls_back_to_capacity_provider_strategy() {
+        let service = Service::builder()
+            .service_name("worker")
+            .task_definition("arn:aws:ecs:ap-northeast-2:123456789012:task-definition/worker:3")
+            .capacity_provider_strategy(
+                CapacityProviderStrategyItem::builder()
+                    .capacity_provider("FARGATE_SPOT")
+                    .build()
+                    .expect("strategy"),
+            )
+            .desired_count(2)
+            .running_count(1)
+            .build();
+
+        let service = map_service(&service);
+        assert_eq!(service.launch_type, "FARGATE_SPOT");
+        assert_eq!(service.task_definition, "worker:3");
+        assert_eq!((service.desired_count, service.running_count), (2, 1));
+    }
+
+    #[test]
+    fn map_task_uses_arn_suffix_for_id() {
+        let task = Task::builder()
+            .task_arn("arn:aws:ecs:ap-northeast-2:123456789012:task/checkout/0a1b2c3d")
+            .task_definition_arn("arn:aws:ecs:ap-northeast-2:123456789012:task-definition/api:7")
+            .launch_type(LaunchType::Fargate)
+            .last_status("RUNNING")
+            .build();
+
+        let task = map_task(&task);
+        assert_eq!(task.id, "0a1b2c3d");
+        assert_eq!(task.task_definition, "api:7");
+        assert_eq!(task.launch_type, "FARGATE");
+    }
+}
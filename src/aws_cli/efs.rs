@@ -0,0 +1,272 @@
+pub use crate::aws_cli::efs_sdk::{get_efs_detail, list_file_systems};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EfsMountTarget {
+    pub mount_target_id: String,
+    pub availability_zone: String,
+    pub subnet_id: String,
+    pub ip_address: String,
+    pub state: String,
+    pub security_groups: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct EfsDetail {
+    pub name: String,
+    pub file_system_id: String,
+    pub arn: String,
+    pub state: String,
+    // generalPurpose, maxIO
+    pub performance_mode: String,
+    // bursting, provisioned, elastic
+    pub throughput_mode: String,
+    pub provisioned_throughput_mibps: Option<f64>,
+    pub encrypted: bool,
+    pub kms_key_id: Option<String>,
+    // One Zone 파일 시스템만 가용 영역이 있음
+    pub availability_zone: Option<String>,
+    pub size_bytes: i64,
+    pub created: String,
+    // 수명 주기 규칙 (AFTER_30_DAYS, AFTER_1_ACCESS 등)
+    pub transition_to_ia: Option<String>,
+    pub transition_to_archive: Option<String>,
+    pub transition_to_primary: Option<String>,
+    pub mount_targets: Vec<EfsMountTarget>,
+    pub tags: Vec<(String, String)>,
+}
+
+// AFTER_30_DAYS → "30일 후", AFTER_1_ACCESS → "첫 액세스 시"
+fn lifecycle_rule_display(rule: &str, i18n: &I18n) -> String {
+    if rule == "AFTER_1_ACCESS" {
+        return i18n.md_on_first_access().to_string();
+    }
+    rule.strip_prefix("AFTER_")
+        .and_then(|rest| rest.split('_').next())
+        .and_then(|days| days.parse::<u32>().ok())
+        .map_or_else(|| rule.to_string(), |days| i18n.md_after_days(days))
+}
+
+impl EfsDetail {
+    fn has_lifecycle_policy(&self) -> bool {
+        self.transition_to_ia.is_some()
+            || self.transition_to_archive.is_some()
+            || self.transition_to_primary.is_some()
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let throughput = match self.provisioned_throughput_mibps {
+            Some(mibps) if self.throughput_mode == "provisioned" => {
+                format!("{} ({} MiB/s)", self.throughput_mode, mibps)
+            }
+            _ => self.throughput_mode.clone(),
+        };
+        let encryption = if self.encrypted {
+            self.kms_key_id
+                .clone()
+                .unwrap_or_else(|| "aws/elasticfilesystem".to_string())
+        } else {
+            i18n.md_disabled().to_string()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.efs_file_system(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.file_system_id),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+            format!(
+                "| {} | {} |",
+                i18n.md_performance_mode(),
+                self.performance_mode
+            ),
+            format!("| {} | {} |", i18n.md_throughput_mode(), throughput),
+            format!("| {} | {} |", i18n.md_encryption(), encryption),
+        ];
+        if let Some(ref az) = self.availability_zone {
+            lines.push(format!("| {} | {} |", i18n.md_availability_zone(), az));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_size(),
+            crate::locale::bytes(self.size_bytes)
+        ));
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+        if !self.has_lifecycle_policy() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_lifecycle_policy(),
+                i18n.md_disabled()
+            ));
+        }
+
+        // Lifecycle policy
+        if self.has_lifecycle_policy() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_lifecycle_policy()));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_transition(),
+                i18n.md_condition()
+            ));
+            lines.push("|:---|:---|".to_string());
+            let transitions = [
+                (i18n.md_transition_to_ia(), &self.transition_to_ia),
+                (i18n.md_transition_to_archive(), &self.transition_to_archive),
+                (i18n.md_transition_to_primary(), &self.transition_to_primary),
+            ];
+            for (label, rule) in transitions {
+                if let Some(rule) = rule {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        label,
+                        lifecycle_rule_display(rule, &i18n)
+                    ));
+                }
+            }
+        }
+
+        // Mount targets
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_mount_targets()));
+        if self.mount_targets.is_empty() {
+            lines.push(format!("- {}", i18n.md_no_mount_targets()));
+        } else {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                i18n.md_availability_zone(),
+                i18n.md_mount_target(),
+                i18n.md_subnet(),
+                i18n.md_ip_address(),
+                i18n.md_state(),
+                i18n.md_security_groups()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|".to_string());
+            for target in &self.mount_targets {
+                let security_groups = if target.security_groups.is_empty() {
+                    "-".to_string()
+                } else {
+                    target.security_groups.join(", ")
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    target.availability_zone,
+                    target.mount_target_id,
+                    target.subnet_id,
+                    target.ip_address,
+                    target.state,
+                    security_groups
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EfsDetail, EfsMountTarget, lifecycle_rule_display};
+    use crate::i18n::{I18n, Language};
+
+    fn sample_detail() -> EfsDetail {
+        EfsDetail {
+            name: "shared-assets".to_string(),
+            file_system_id: "fs-0123456789abcdef0".to_string(),
+            arn: String::new(),
+            state: "available".to_string(),
+            performance_mode: "generalPurpose".to_string(),
+            throughput_mode: "provisioned".to_string(),
+            provisioned_throughput_mibps: Some(128.0),
+            encrypted: true,
+            kms_key_id: None,
+            availability_zone: None,
+            size_bytes: 3 * 1024 * 1024 * 1024,
+            created: "2026-01-01T00:00:00Z".to_string(),
+            transition_to_ia: Some("AFTER_30_DAYS".to_string()),
+            transition_to_archive: None,
+            transition_to_primary: Some("AFTER_1_ACCESS".to_string()),
+            mount_targets: vec![EfsMountTarget {
+                mount_target_id: "fsmt-0a1b2c3d".to_string(),
+                availability_zone: "ap-northeast-2a".to_string(),
+                subnet_id: "subnet-0a1b2c3d".to_string(),
+                ip_address: "10.0.1.25".to_string(),
+                state: "available".to_string(),
+                security_groups: vec!["sg-efs".to_string(), "sg-app".to_string()],
+            }],
+            tags: vec![("Team".to_string(), "media".to_string())],
+        }
+    }
+
+    #[test]
+    fn lifecycle_rule_display_reads_days_and_access() {
+        let i18n = I18n::new(Language::English);
+        assert_eq!(
+            lifecycle_rule_display("AFTER_1_DAY", &i18n),
+            "After 1 day(s)"
+        );
+        assert_eq!(
+            lifecycle_rule_display("AFTER_90_DAYS", &i18n),
+            "After 90 day(s)"
+        );
+        assert_eq!(
+            lifecycle_rule_display("AFTER_1_ACCESS", &i18n),
+            "On first access"
+        );
+        assert_eq!(lifecycle_rule_display("NEW_RULE", &i18n), "NEW_RULE");
+    }
+
+    #[test]
+    fn scenario_efs_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## EFS File System (shared-assets)"));
+        assert!(markdown.contains("| Throughput Mode | provisioned (128 MiB/s) |"));
+        assert!(markdown.contains("| Encryption | aws/elasticfilesystem |"));
+        assert!(markdown.contains("| Size | 3.0 GB |"));
+        assert!(markdown.contains("### Lifecycle Policy"));
+        assert!(markdown.contains("| To Infrequent Access | After 30 day(s) |"));
+        assert!(markdown.contains("| To Primary Storage | On first access |"));
+        assert!(!markdown.contains("| To Archive |"));
+        assert!(markdown.contains(
+            "| ap-northeast-2a | fsmt-0a1b2c3d | subnet-0a1b2c3d | 10.0.1.25 | available | sg-efs, sg-app |"
+        ));
+        assert!(markdown.contains("| Team | media |"));
+    }
+
+    #[test]
+    fn scenario_efs_markdown_without_lifecycle_or_mount_targets() {
+        let mut detail = sample_detail();
+        detail.throughput_mode = "elastic".to_string();
+        detail.encrypted = false;
+        detail.availability_zone = Some("ap-northeast-2c".to_string());
+        detail.transition_to_ia = None;
+        detail.transition_to_primary = None;
+        detail.mount_targets = vec![];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 처리량 모드 | elastic |"));
+        assert!(markdown.contains("| 암호화 | 비활성화 |"));
+        assert!(markdown.contains("| 가용 영역 | ap-northeast-2c |"));
+        assert!(markdown.contains("| 수명 주기 정책 | 비활성화 |"));
+        assert!(!markdown.contains("### 수명 주기 정책"));
+        assert!(markdown.contains("- 마운트 대상이 없습니다."));
+    }
+}
@@ -0,0 +1,307 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::efs::{EfsDetail, EfsMountTarget};
+use aws_sdk_efs::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_efs::types::{FileSystemDescription, LifecyclePolicy, MountTargetDescription};
+
+/// List EFS file systems with their throughput mode using AWS SDK
+pub fn list_file_systems() -> Vec<AwsResource> {
+    get_runtime().block_on(list_file_systems_async())
+}
+
+async fn list_file_systems_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_efs, &config);
+
+    let file_systems: Vec<FileSystemDescription> = match client
+        .describe_file_systems()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(file_systems) => file_systems,
+        Err(e) => {
+            tracing::error!("Error describing EFS file systems: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> =
+        file_systems.iter().map(map_file_system_resource).collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get EFS file system detail (lifecycle policy, mount targets and their security groups) using AWS SDK
+pub fn get_efs_detail(file_system_id: &str) -> Option<EfsDetail> {
+    get_runtime().block_on(get_efs_detail_async(file_system_id))
+}
+
+async fn get_efs_detail_async(file_system_id: &str) -> Option<EfsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_efs, &config);
+
+    let output = client
+        .describe_file_systems()
+        .file_system_id(file_system_id)
+        .send()
+        .await
+        .ok()?;
+    let mut detail = map_file_system_detail(output.file_systems().first()?);
+
+    // 수명 주기/마운트 대상 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .describe_lifecycle_configuration()
+        .file_system_id(file_system_id)
+        .send()
+        .await
+    {
+        Ok(output) => apply_lifecycle_policies(&mut detail, output.lifecycle_policies()),
+        Err(e) => tracing::warn!(
+            "Error describing lifecycle configuration for {}: {:?}",
+            file_system_id,
+            e
+        ),
+    }
+
+    let mount_targets: Vec<MountTargetDescription> = match client
+        .describe_mount_targets()
+        .file_system_id(file_system_id)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(mount_targets) => mount_targets,
+        Err(e) => {
+            tracing::warn!(
+                "Error describing mount targets for {}: {:?}",
+                file_system_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    for mount_target in &mount_targets {
+        let mut target = map_mount_target(mount_target);
+        match client
+            .describe_mount_target_security_groups()
+            .mount_target_id(mount_target.mount_target_id())
+            .send()
+            .await
+        {
+            Ok(output) => target.security_groups = output.security_groups().to_vec(),
+            Err(e) => tracing::warn!(
+                "Error describing security groups for {}: {:?}",
+                target.mount_target_id,
+                e
+            ),
+        }
+        detail.mount_targets.push(target);
+    }
+    detail
+        .mount_targets
+        .sort_by(|a, b| a.availability_zone.cmp(&b.availability_zone));
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// Name 태그가 없으면 파일 시스템 ID로 표시
+fn file_system_name(file_system: &FileSystemDescription) -> String {
+    file_system
+        .name()
+        .filter(|name| !name.is_empty())
+        .unwrap_or(file_system.file_system_id())
+        .to_string()
+}
+
+// 목록에는 수명 주기 상태, One Zone 가용 영역과 처리량 모드를 표시
+fn map_file_system_resource(file_system: &FileSystemDescription) -> AwsResource {
+    AwsResource {
+        name: file_system_name(file_system),
+        id: file_system.file_system_id().to_string(),
+        state: file_system.life_cycle_state().as_str().to_string(),
+        az: file_system
+            .availability_zone_name()
+            .unwrap_or_default()
+            .to_string(),
+        cidr: file_system
+            .throughput_mode()
+            .map(|mode| mode.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        owner_id: file_system.owner_id().to_string(),
+    }
+}
+
+fn map_file_system_detail(file_system: &FileSystemDescription) -> EfsDetail {
+    let mut tags: Vec<(String, String)> = file_system
+        .tags()
+        .iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect();
+    tags.sort();
+
+    EfsDetail {
+        name: file_system_name(file_system),
+        file_system_id: file_system.file_system_id().to_string(),
+        arn: file_system
+            .file_system_arn()
+            .unwrap_or_default()
+            .to_string(),
+        state: file_system.life_cycle_state().as_str().to_string(),
+        performance_mode: file_system.performance_mode().as_str().to_string(),
+        throughput_mode: file_system
+            .throughput_mode()
+            .map(|mode| mode.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        provisioned_throughput_mibps: file_system.provisioned_throughput_in_mibps(),
+        encrypted: file_system.encrypted().unwrap_or(false),
+        kms_key_id: file_system
+            .kms_key_id()
+            .filter(|key_id| !key_id.is_empty())
+            .map(str::to_string),
+        availability_zone: file_system.availability_zone_name().map(str::to_string),
+        size_bytes: file_system
+            .size_in_bytes()
+            .map(|size| size.value())
+            .unwrap_or_default(),
+        created: format_timestamp(Some(file_system.creation_time())),
+        transition_to_ia: None,
+        transition_to_archive: None,
+        transition_to_primary: None,
+        mount_targets: Vec::new(),
+        tags,
+    }
+}
+
+// 수명 주기 정책은 규칙마다 별도 항목으로 반환됨
+fn apply_lifecycle_policies(detail: &mut EfsDetail, policies: &[LifecyclePolicy]) {
+    for policy in policies {
+        if let Some(rule) = policy.transition_to_ia() {
+            detail.transition_to_ia = Some(rule.as_str().to_string());
+        }
+        if let Some(rule) = policy.transition_to_archive() {
+            detail.transition_to_archive = Some(rule.as_str().to_string());
+        }
+        if let Some(rule) = policy.transition_to_primary_storage_class() {
+            detail.transition_to_primary = Some(rule.as_str().to_string());
+        }
+    }
+}
+
+fn map_mount_target(mount_target: &MountTargetDescription) -> EfsMountTarget {
+    EfsMountTarget {
+        mount_target_id: mount_target.mount_target_id().to_string(),
+        availability_zone: mount_target
+            .availability_zone_name()
+            .unwrap_or_default()
+            .to_string(),
+        subnet_id: mount_target.subnet_id().to_string(),
+        ip_address: mount_target.ip_address().unwrap_or_default().to_string(),
+        state: mount_target.life_cycle_state().as_str().to_string(),
+        security_groups: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_lifecycle_policies, map_file_system_detail, map_file_system_resource,
+        map_mount_target,
+    };
+    use aws_sdk_efs::primitives::DateTime;
+    use aws_sdk_efs::types::{
+        FileSystemDescription, FileSystemSize, LifeCycleState, LifecyclePolicy,
+        MountTargetDescription, PerformanceMode, Tag, ThroughputMode, TransitionToIaRules,
+        TransitionToPrimaryStorageClassRules,
+    };
+
+    fn file_system(name: Option<&str>) -> FileSystemDescription {
+        FileSystemDescription::builder()
+            .owner_id("123456789012")
+            .creation_token("shared-assets")
+            .file_system_id("fs-0123456789abcdef0")
+            .creation_time(DateTime::from_secs(1_767_225_600))
+            .life_cycle_state(LifeCycleState::Available)
+            .set_name(name.map(str::to_string))
+            .number_of_mount_targets(2)
+            .size_in_bytes(FileSystemSize::builder().value(4096).build())
+            .performance_mode(PerformanceMode::GeneralPurpose)
+            .encrypted(true)
+            .throughput_mode(ThroughputMode::Provisioned)
+            .provisioned_throughput_in_mibps(128.0)
+            .tags(Tag::builder().key("Team").value("media").build().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn map_file_system_resource_falls_back_to_id() {
+        let resource = map_file_system_resource(&file_system(Some("shared-assets")));
+        assert_eq!(resource.name, "shared-assets");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "provisioned");
+        assert!(resource.az.is_empty());
+
+        let unnamed = map_file_system_resource(&file_system(None));
+        assert_eq!(unnamed.name, "fs-0123456789abcdef0");
+    }
+
+    #[test]
+    fn map_file_system_detail_reads_throughput_lifecycle_and_mount_targets() {
+        let mut detail = map_file_system_detail(&file_system(Some("shared-assets")));
+        assert_eq!(detail.performance_mode, "generalPurpose");
+        assert_eq!(detail.provisioned_throughput_mibps, Some(128.0));
+        assert_eq!(detail.size_bytes, 4096);
+        assert_eq!(detail.created, "2026-01-01T00:00:00Z");
+        assert_eq!(detail.kms_key_id, None);
+        assert_eq!(detail.tags, vec![("Team".to_string(), "media".to_string())]);
+
+        apply_lifecycle_policies(
+            &mut detail,
+            &[
+                LifecyclePolicy::builder()
+                    .transition_to_ia(TransitionToIaRules::After30Days)
+                    .build(),
+                LifecyclePolicy::builder()
+                    .transition_to_primary_storage_class(
+                        TransitionToPrimaryStorageClassRules::After1Access,
+                    )
+                    .build(),
+            ],
+        );
+        assert_eq!(detail.transition_to_ia.as_deref(), Some("AFTER_30_DAYS"));
+        assert_eq!(detail.transition_to_archive, None);
+        assert_eq!(
+            detail.transition_to_primary.as_deref(),
+            Some("AFTER_1_ACCESS")
+        );
+
+        let target = map_mount_target(
+            &MountTargetDescription::builder()
+                .mount_target_id("fsmt-0a1b2c3d")
+                .file_system_id("fs-0123456789abcdef0")
+                .subnet_id("subnet-0a1b2c3d")
+                .life_cycle_state(LifeCycleState::Available)
+                .ip_address("10.0.1.25")
+                .availability_zone_name("ap-northeast-2a")
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(target.availability_zone, "ap-northeast-2a");
+        assert_eq!(target.ip_address, "10.0.1.25");
+        assert_eq!(target.state, "available");
+        assert!(target.security_groups.is_empty());
+    }
+}
@@ -0,0 +1,292 @@
+pub use crate::aws_cli::eks_sdk::{get_eks_detail, list_eks_clusters};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EksNodeGroup {
+    pub name: String,
+    pub status: String,
+    pub instance_types: Vec<String>,
+    // ON_DEMAND, SPOT
+    pub capacity_type: String,
+    pub min_size: i32,
+    pub desired_size: i32,
+    pub max_size: i32,
+    pub ami_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EksFargateProfile {
+    pub name: String,
+    pub status: String,
+    // "namespace" 또는 "namespace (key=value, ...)"
+    pub selectors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EksAddon {
+    pub name: String,
+    pub version: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct EksDetail {
+    pub name: String,
+    pub arn: String,
+    pub status: String,
+    pub version: String,
+    pub platform_version: String,
+    pub endpoint: String,
+    pub endpoint_public_access: bool,
+    pub endpoint_private_access: bool,
+    pub public_access_cidrs: Vec<String>,
+    pub vpc_id: String,
+    pub subnet_ids: Vec<String>,
+    // 클러스터 보안 그룹이 맨 앞, 추가 보안 그룹이 뒤
+    pub security_group_ids: Vec<String>,
+    pub role_arn: String,
+    // 활성화된 컨트롤 플레인 로그 유형 (api, audit, ...)
+    pub logging: Vec<String>,
+    pub node_groups: Vec<EksNodeGroup>,
+    pub fargate_profiles: Vec<EksFargateProfile>,
+    pub addons: Vec<EksAddon>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl EksDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let version = if self.platform_version.is_empty() {
+            self.version.clone()
+        } else {
+            format!("{} ({})", self.version, self.platform_version)
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.eks_cluster(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_kubernetes_version(), version),
+        ];
+        if !self.endpoint.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_endpoint(), self.endpoint));
+        }
+
+        let mut access = Vec::new();
+        if self.endpoint_public_access {
+            if self.public_access_cidrs.is_empty() {
+                access.push(i18n.md_public().to_string());
+            } else {
+                access.push(format!(
+                    "{} ({})",
+                    i18n.md_public(),
+                    self.public_access_cidrs.join(", ")
+                ));
+            }
+        }
+        if self.endpoint_private_access {
+            access.push(i18n.md_private().to_string());
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_endpoint_access(),
+            access.join(", ")
+        ));
+
+        if !self.vpc_id.is_empty() {
+            lines.push(format!("| VPC | {} |", self.vpc_id));
+        }
+        if !self.subnet_ids.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subnets(),
+                self.subnet_ids.join(", ")
+            ));
+        }
+        if !self.security_group_ids.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_security_groups(),
+                self.security_group_ids.join(", ")
+            ));
+        }
+        if !self.role_arn.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_iam_role(), self.role_arn));
+        }
+        let logging = if self.logging.is_empty() {
+            i18n.md_disabled().to_string()
+        } else {
+            self.logging.join(", ")
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_control_plane_logging(),
+            logging
+        ));
+
+        // Node Groups
+        if !self.node_groups.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_node_groups()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_state(),
+                i18n.md_instance_type(),
+                i18n.md_capacity_type(),
+                i18n.md_scaling_size(),
+                i18n.md_ami_type()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|".to_string());
+            for group in &self.node_groups {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} / {} / {} | {} |",
+                    group.name,
+                    group.status,
+                    group.instance_types.join(", "),
+                    group.capacity_type,
+                    group.min_size,
+                    group.desired_size,
+                    group.max_size,
+                    group.ami_type
+                ));
+            }
+        }
+
+        // Fargate Profiles
+        if !self.fargate_profiles.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_fargate_profiles()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_state(),
+                i18n.md_selectors()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for profile in &self.fargate_profiles {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    profile.name,
+                    profile.status,
+                    profile.selectors.join("<br>")
+                ));
+            }
+        }
+
+        // Add-ons
+        if !self.addons.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_addons()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_version(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for addon in &self.addons {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    addon.name, addon.version, addon.status
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EksAddon, EksDetail, EksFargateProfile, EksNodeGroup};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> EksDetail {
+        EksDetail {
+            name: "checkout".to_string(),
+            arn: "arn:aws:eks:ap-northeast-2:123456789012:cluster/checkout".to_string(),
+            status: "ACTIVE".to_string(),
+            version: "1.29".to_string(),
+            platform_version: "eks.5".to_string(),
+            endpoint: "https://ABCD.gr7.ap-northeast-2.eks.amazonaws.com".to_string(),
+            endpoint_public_access: true,
+            endpoint_private_access: true,
+            public_access_cidrs: vec!["203.0.113.0/24".to_string()],
+            vpc_id: "vpc-0abc".to_string(),
+            subnet_ids: vec!["subnet-a".to_string(), "subnet-b".to_string()],
+            security_group_ids: vec!["sg-cluster".to_string()],
+            role_arn: "arn:aws:iam::123456789012:role/eks-cluster".to_string(),
+            logging: vec!["api".to_string(), "audit".to_string()],
+            node_groups: vec![EksNodeGroup {
+                name: "general".to_string(),
+                status: "ACTIVE".to_string(),
+                instance_types: vec!["m6i.large".to_string(), "m5.large".to_string()],
+                capacity_type: "ON_DEMAND".to_string(),
+                min_size: 2,
+                desired_size: 3,
+                max_size: 6,
+                ami_type: "AL2023_x86_64_STANDARD".to_string(),
+            }],
+            fargate_profiles: vec![EksFargateProfile {
+                name: "batch".to_string(),
+                status: "ACTIVE".to_string(),
+                selectors: vec!["jobs (team=data)".to_string(), "cron".to_string()],
+            }],
+            addons: vec![EksAddon {
+                name: "vpc-cni".to_string(),
+                version: "v1.18.0-eksbuild.1".to_string(),
+                status: "ACTIVE".to_string(),
+            }],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_eks_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## EKS Cluster (checkout)"));
+        assert!(markdown.contains("| Kubernetes Version | 1.29 (eks.5) |"));
+        assert!(markdown.contains("| Endpoint Access | Public (203.0.113.0/24), Private |"));
+        assert!(markdown.contains("| Control Plane Logging | api, audit |"));
+        assert!(markdown.contains(
+            "| general | ACTIVE | m6i.large, m5.large | ON_DEMAND | 2 / 3 / 6 | AL2023_x86_64_STANDARD |"
+        ));
+        assert!(markdown.contains("| batch | ACTIVE | jobs (team=data)<br>cron |"));
+        assert!(markdown.contains("| vpc-cni | v1.18.0-eksbuild.1 | ACTIVE |"));
+        assert!(markdown.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn scenario_eks_private_cluster_without_workloads() {
+        let mut detail = sample_detail();
+        detail.endpoint_public_access = false;
+        detail.logging.clear();
+        detail.node_groups.clear();
+        detail.fargate_profiles.clear();
+        detail.addons.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 엔드포인트 액세스 | 프라이빗 |"));
+        assert!(markdown.contains("| 컨트롤 플레인 로깅 | 비활성화 |"));
+        assert!(!markdown.contains("### 노드 그룹"));
+        assert!(!markdown.contains("### Fargate 프로필"));
+        assert!(!markdown.contains("### 추가 기능"));
+    }
+}
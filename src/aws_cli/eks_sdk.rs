@@ -0,0 +1,399 @@
+use crate::aws_cli::common::{
+    AwsResource, get_runtime, get_sdk_config, map_concurrent, sdk_client,
+};
+use crate::aws_cli::eks::{EksAddon, EksDetail, EksFargateProfile, EksNodeGroup};
+use aws_sdk_eks::types::{Addon, Cluster, FargateProfile, Nodegroup};
+
+/// List all EKS clusters using AWS SDK
+pub fn list_eks_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_eks_clusters_async())
+}
+
+async fn list_eks_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_eks, &config);
+
+    let result = client
+        .list_clusters()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(names) => names
+            .into_iter()
+            .map(|name| AwsResource {
+                name: name.clone(),
+                id: name,
+                state: String::new(),
+                az: String::new(),
+                cidr: String::new(),
+                owner_id: String::new(),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing EKS clusters: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get EKS cluster detail (node groups, Fargate profiles and add-ons) using AWS SDK
+pub fn get_eks_detail(name: &str) -> Option<EksDetail> {
+    get_runtime().block_on(get_eks_detail_async(name))
+}
+
+async fn get_eks_detail_async(name: &str) -> Option<EksDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_eks, &config);
+
+    let output = client.describe_cluster().name(name).send().await.ok()?;
+    let mut detail = map_cluster_detail(output.cluster()?);
+
+    // 노드 그룹/Fargate 프로필/추가 기능 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_nodegroups()
+        .cluster_name(name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(names) => {
+            let groups = map_concurrent(names, |nodegroup| {
+                let client = &client;
+                async move {
+                    match client
+                        .describe_nodegroup()
+                        .cluster_name(name)
+                        .nodegroup_name(&nodegroup)
+                        .send()
+                        .await
+                    {
+                        Ok(output) => output.nodegroup().map(map_node_group),
+                        Err(e) => {
+                            tracing::warn!("Error describing node group {}: {:?}", nodegroup, e);
+                            None
+                        }
+                    }
+                }
+            })
+            .await;
+            detail.node_groups.extend(groups.into_iter().flatten());
+        }
+        Err(e) => tracing::warn!("Error listing node groups for {}: {:?}", name, e),
+    }
+
+    match client
+        .list_fargate_profiles()
+        .cluster_name(name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(names) => {
+            let profiles = map_concurrent(names, |profile_name| {
+                let client = &client;
+                async move {
+                    match client
+                        .describe_fargate_profile()
+                        .cluster_name(name)
+                        .fargate_profile_name(&profile_name)
+                        .send()
+                        .await
+                    {
+                        Ok(output) => output.fargate_profile().map(map_fargate_profile),
+                        Err(e) => {
+                            tracing::warn!(
+                                "Error describing Fargate profile {}: {:?}",
+                                profile_name,
+                                e
+                            );
+                            None
+                        }
+                    }
+                }
+            })
+            .await;
+            detail
+                .fargate_profiles
+                .extend(profiles.into_iter().flatten());
+        }
+        Err(e) => tracing::warn!("Error listing Fargate profiles for {}: {:?}", name, e),
+    }
+
+    match client
+        .list_addons()
+        .cluster_name(name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(names) => {
+            let addons = map_concurrent(names, |addon_name| {
+                let client = &client;
+                async move {
+                    match client
+                        .describe_addon()
+                        .cluster_name(name)
+                        .addon_name(&addon_name)
+                        .send()
+                        .await
+                    {
+                        Ok(output) => output.addon().map(map_addon),
+                        Err(e) => {
+                            tracing::warn!("Error describing add-on {}: {:?}", addon_name, e);
+                            None
+                        }
+                    }
+                }
+            })
+            .await;
+            detail.addons.extend(addons.into_iter().flatten());
+        }
+        Err(e) => tracing::warn!("Error listing add-ons for {}: {:?}", name, e),
+    }
+
+    Some(detail)
+}
+
+fn map_cluster_detail(cluster: &Cluster) -> EksDetail {
+    let vpc_config = cluster.resources_vpc_config();
+
+    // 클러스터 보안 그룹을 추가 보안 그룹보다 먼저 표시
+    let mut security_group_ids: Vec<String> = vpc_config
+        .and_then(|v| v.cluster_security_group_id())
+        .map(|id| vec![id.to_string()])
+        .unwrap_or_default();
+    if let Some(vpc_config) = vpc_config {
+        for id in vpc_config.security_group_ids() {
+            if !security_group_ids.contains(id) {
+                security_group_ids.push(id.clone());
+            }
+        }
+    }
+
+    let logging = cluster
+        .logging()
+        .map(|l| l.cluster_logging())
+        .unwrap_or_default()
+        .iter()
+        .filter(|setup| setup.enabled().unwrap_or(false))
+        .flat_map(|setup| setup.types())
+        .map(|log_type| log_type.as_str().to_string())
+        .collect();
+
+    let mut tags: Vec<(String, String)> = cluster
+        .tags()
+        .map(|tags| {
+            tags.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    tags.sort();
+
+    EksDetail {
+        name: cluster.name().unwrap_or_default().to_string(),
+        arn: cluster.arn().unwrap_or_default().to_string(),
+        status: cluster
+            .status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        version: cluster.version().unwrap_or_default().to_string(),
+        platform_version: cluster.platform_version().unwrap_or_default().to_string(),
+        endpoint: cluster.endpoint().unwrap_or_default().to_string(),
+        endpoint_public_access: vpc_config
+            .map(|v| v.endpoint_public_access())
+            .unwrap_or(false),
+        endpoint_private_access: vpc_config
+            .map(|v| v.endpoint_private_access())
+            .unwrap_or(false),
+        public_access_cidrs: vpc_config
+            .map(|v| v.public_access_cidrs().to_vec())
+            .unwrap_or_default(),
+        vpc_id: vpc_config
+            .and_then(|v| v.vpc_id())
+            .unwrap_or_default()
+            .to_string(),
+        subnet_ids: vpc_config
+            .map(|v| v.subnet_ids().to_vec())
+            .unwrap_or_default(),
+        security_group_ids,
+        role_arn: cluster.role_arn().unwrap_or_default().to_string(),
+        logging,
+        node_groups: Vec::new(),
+        fargate_profiles: Vec::new(),
+        addons: Vec::new(),
+        tags,
+    }
+}
+
+fn map_node_group(group: &Nodegroup) -> EksNodeGroup {
+    let scaling = group.scaling_config();
+    EksNodeGroup {
+        name: group.nodegroup_name().unwrap_or_default().to_string(),
+        status: group
+            .status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        instance_types: group.instance_types().to_vec(),
+        capacity_type: group
+            .capacity_type()
+            .map(|c| c.as_str().to_string())
+            .unwrap_or_default(),
+        min_size: scaling.and_then(|s| s.min_size()).unwrap_or(0),
+        desired_size: scaling.and_then(|s| s.desired_size()).unwrap_or(0),
+        max_size: scaling.and_then(|s| s.max_size()).unwrap_or(0),
+        ami_type: group
+            .ami_type()
+            .map(|a| a.as_str().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn map_fargate_profile(profile: &FargateProfile) -> EksFargateProfile {
+    EksFargateProfile {
+        name: profile
+            .fargate_profile_name()
+            .unwrap_or_default()
+            .to_string(),
+        status: profile
+            .status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        selectors: profile
+            .selectors()
+            .iter()
+            .map(|selector| {
+                let namespace = selector.namespace().unwrap_or_default();
+                let mut labels: Vec<String> = selector
+                    .labels()
+                    .map(|labels| labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect())
+                    .unwrap_or_default();
+                if labels.is_empty() {
+                    return namespace.to_string();
+                }
+                labels.sort();
+                format!("{} ({})", namespace, labels.join(", "))
+            })
+            .collect(),
+    }
+}
+
+fn map_addon(addon: &Addon) -> EksAddon {
+    EksAddon {
+        name: addon.addon_name().unwrap_or_default().to_string(),
+        version: addon.addon_version().unwrap_or_default().to_string(),
+        status: addon
+            .status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_cluster_detail, map_fargate_profile, map_node_group};
+    use aws_sdk_eks::types::{
+        CapacityTypes, Cluster, FargateProfile, FargateProfileSelector, LogSetup, LogType, Logging,
+        Nodegroup, NodegroupScalingConfig, VpcConfigResponse,
+    };
+
+    #[test]
+    fn map_cluster_detail_reads_endpoint_access_and_logging() {
+        let cluster = Cluster::builder()
+            .name("checkout")
+            .version("1.29")
+            .platform_version("eks.5")
+            .resources_vpc_config(
+                VpcConfigResponse::builder()
+                    .vpc_id("vpc-0abc")
+                    .subnet_ids("subnet-a")
+                    .security_group_ids("sg-extra")
+                    .cluster_security_group_id("sg-cluster")
+                    .endpoint_public_access(true)
+                    .endpoint_private_access(false)
+                    .public_access_cidrs("0.0.0.0/0")
+                    .build(),
+            )
+            .logging(
+                Logging::builder()
+                    .cluster_logging(
+                        LogSetup::builder()
+                            .types(LogType::Api)
+                            .types(LogType::Audit)
+                            .enabled(true)
+                            .build(),
+                    )
+                    .cluster_logging(
+                        LogSetup::builder()
+                            .types(LogType::Scheduler)
+                            .enabled(false)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .tags("Env", "prod")
+            .build();
+
+        let detail = map_cluster_detail(&cluster);
+        assert_eq!(detail.security_group_ids, vec!["sg-cluster", "sg-extra"]);
+        assert!(detail.endpoint_public_access);
+        assert!(!detail.endpoint_private_access);
+        assert_eq!(detail.public_access_cidrs, vec!["0.0.0.0/0"]);
+        assert_eq!(detail.logging, vec!["api", "audit"]);
+        assert_eq!(detail.tags, vec![("Env".to_string(), "prod".to_string())]);
+    }
+
+    #[test]
+    fn map_node_group_reads_scaling_config() {
+        let group = Nodegroup::builder()
+            .nodegroup_name("general")
+            .instance_types("m6i.large")
+            .capacity_type(CapacityTypes::Spot)
+            .scaling_config(
+                NodegroupScalingConfig::builder()
+                    .min_size(1)
+                    .desired_size(2)
+                    .max_size(4)
+                    .build(),
+            )
+            .build();
+
+        let group = map_node_group(&group);
+        assert_eq!(group.capacity_type, "SPOT");
+        assert_eq!(
+            (group.min_size, group.desired_size, group.max_size),
+            (1, 2, 4)
+        );
+    }
+
+    #[test]
+    fn map_fargate_profile_formats_selectors_with_sorted_labels() {
+        let profile = FargateProfile::builder()
+            .fargate_profile_name("batch")
+            .selectors(
+                FargateProfileSelector::builder()
+                    .namespace("jobs")
+                    .labels("tier", "batch")
+                    .labels("team", "data")
+                    .build(),
+            )
+            .selectors(FargateProfileSelector::builder().namespace("cron").build())
+            .build();
+
+        let profile = map_fargate_profile(&profile);
+        assert_eq!(
+            profile.selectors,
+            vec!["jobs (team=data, tier=batch)", "cron"]
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use crate::aws_cli::common::AwsResource;
+pub use crate::aws_cli::elasticache_sdk::{
+    get_cache_cluster_detail, get_replication_group_detail, list_cache_clusters,
+    list_replication_groups,
+};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElastiCacheNodeGroupMember {
+    pub cluster_id: String,
+    pub node_id: String,
+    // primary/replica (클러스터 모드 활성화 시에는 비어 있을 수 있음)
+    pub role: String,
+    pub availability_zone: String,
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElastiCacheNodeGroup {
+    pub id: String,
+    pub status: String,
+    // 클러스터 모드에서 샤드가 담당하는 해시 슬롯 범위 (예: 0-8191)
+    pub slots: Option<String>,
+    pub primary_endpoint: Option<String>,
+    pub reader_endpoint: Option<String>,
+    pub members: Vec<ElastiCacheNodeGroupMember>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElastiCacheNode {
+    pub id: String,
+    pub status: String,
+    pub availability_zone: String,
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct ElastiCacheDetail {
+    pub id: String,
+    pub arn: String,
+    pub is_replication_group: bool,
+    pub description: String,
+    pub engine: String,
+    pub engine_version: String,
+    pub node_type: String,
+    pub status: String,
+    // 복제 그룹에만 해당
+    pub cluster_mode: Option<String>,
+    pub automatic_failover: Option<String>,
+    pub multi_az: Option<String>,
+    pub configuration_endpoint: Option<String>,
+    pub transit_encryption: bool,
+    pub at_rest_encryption: bool,
+    pub subnet_group: String,
+    pub parameter_group: String,
+    pub security_groups: Vec<String>,
+    pub node_groups: Vec<ElastiCacheNodeGroup>,
+    // 복제 그룹에 속하지 않은 클러스터(Memcached 등)의 노드
+    pub nodes: Vec<ElastiCacheNode>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// List replication groups followed by standalone cache clusters, as shown on the ElastiCache screen
+pub fn list_elasticache_clusters() -> Vec<AwsResource> {
+    let mut resources = list_replication_groups();
+    resources.extend(list_cache_clusters());
+    resources
+}
+
+/// Get ElastiCache detail, trying a replication group first and then a cache cluster
+pub fn get_elasticache_detail(id: &str) -> Option<ElastiCacheDetail> {
+    get_replication_group_detail(id).or_else(|| get_cache_cluster_detail(id))
+}
+
+impl ElastiCacheDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let title = if self.is_replication_group {
+            i18n.md_replication_group()
+        } else {
+            i18n.elasticache_cluster()
+        };
+        let engine = if self.engine_version.is_empty() {
+            self.engine.clone()
+        } else {
+            format!("{} {}", self.engine, self.engine_version)
+        };
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, self.id),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.id),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_engine(), engine));
+        lines.push(format!("| {} | {} |", i18n.md_node_type(), self.node_type));
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.status));
+        if let Some(ref cluster_mode) = self.cluster_mode {
+            lines.push(format!("| {} | {} |", i18n.md_cluster_mode(), cluster_mode));
+        }
+        if let Some(ref failover) = self.automatic_failover {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_automatic_failover(),
+                failover
+            ));
+        }
+        if let Some(ref multi_az) = self.multi_az {
+            lines.push(format!("| {} | {} |", i18n.md_multi_az(), multi_az));
+        }
+        if let Some(ref endpoint) = self.configuration_endpoint {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_configuration_endpoint(),
+                endpoint
+            ));
+        }
+        if !self.subnet_group.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subnet_group(),
+                self.subnet_group
+            ));
+        }
+        if !self.parameter_group.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_parameter_group(),
+                self.parameter_group
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_transit_encryption(),
+            enabled(self.transit_encryption)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_at_rest_encryption(),
+            enabled(self.at_rest_encryption)
+        ));
+
+        // Security Groups
+        if !self.security_groups.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_security_groups()));
+            for sg in &self.security_groups {
+                lines.push(format!("- {}", sg));
+            }
+        }
+
+        // Replication Group Topology
+        if !self.node_groups.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_replication_topology()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_shard(),
+                i18n.md_state(),
+                i18n.md_slots(),
+                i18n.md_primary_endpoint(),
+                i18n.md_reader_endpoint()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for group in &self.node_groups {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    group.id,
+                    group.status,
+                    group.slots.as_deref().unwrap_or("-"),
+                    group.primary_endpoint.as_deref().unwrap_or("-"),
+                    group.reader_endpoint.as_deref().unwrap_or("-")
+                ));
+            }
+
+            lines.push(String::new());
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_shard(),
+                i18n.md_node(),
+                i18n.md_role(),
+                i18n.md_availability_zone(),
+                i18n.md_endpoint()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for group in &self.node_groups {
+                for member in &group.members {
+                    let role = if member.role.is_empty() {
+                        "-"
+                    } else {
+                        member.role.as_str()
+                    };
+                    lines.push(format!(
+                        "| {} | {} ({}) | {} | {} | {} |",
+                        group.id,
+                        member.cluster_id,
+                        member.node_id,
+                        role,
+                        member.availability_zone,
+                        member.endpoint.as_deref().unwrap_or("-")
+                    ));
+                }
+            }
+        }
+
+        // Nodes
+        if !self.nodes.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_nodes()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_node(),
+                i18n.md_state(),
+                i18n.md_availability_zone(),
+                i18n.md_endpoint()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for node in &self.nodes {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    node.id,
+                    node.status,
+                    node.availability_zone,
+                    node.endpoint.as_deref().unwrap_or("-")
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ElastiCacheDetail, ElastiCacheNode, ElastiCacheNodeGroup, ElastiCacheNodeGroupMember,
+    };
+    use crate::i18n::Language;
+
+    fn member(cluster_id: &str, role: &str, az: &str) -> ElastiCacheNodeGroupMember {
+        ElastiCacheNodeGroupMember {
+            cluster_id: cluster_id.to_string(),
+            node_id: "0001".to_string(),
+            role: role.to_string(),
+            availability_zone: az.to_string(),
+            endpoint: Some(format!("{}.abc.apn2.cache.amazonaws.com:6379", cluster_id)),
+        }
+    }
+
+    fn sample_replication_group() -> ElastiCacheDetail {
+        ElastiCacheDetail {
+            id: "sessions".to_string(),
+            arn: "arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:sessions"
+                .to_string(),
+            is_replication_group: true,
+            description: "session store".to_string(),
+            engine: "redis".to_string(),
+            engine_version: "7.1.0".to_string(),
+            node_type: "cache.r7g.large".to_string(),
+            status: "available".to_string(),
+            cluster_mode: Some("disabled".to_string()),
+            automatic_failover: Some("enabled".to_string()),
+            multi_az: Some("enabled".to_string()),
+            configuration_endpoint: None,
+            transit_encryption: true,
+            at_rest_encryption: false,
+            subnet_group: "cache-private".to_string(),
+            parameter_group: "default.redis7".to_string(),
+            security_groups: vec!["sg-0123".to_string()],
+            node_groups: vec![ElastiCacheNodeGroup {
+                id: "0001".to_string(),
+                status: "available".to_string(),
+                slots: None,
+                primary_endpoint: Some(
+                    "master.sessions.abc.apn2.cache.amazonaws.com:6379".to_string(),
+                ),
+                reader_endpoint: Some(
+                    "replica.sessions.abc.apn2.cache.amazonaws.com:6379".to_string(),
+                ),
+                members: vec![
+                    member("sessions-001", "primary", "ap-northeast-2a"),
+                    member("sessions-002", "replica", "ap-northeast-2c"),
+                ],
+            }],
+            nodes: vec![],
+            tags: vec![("Team".to_string(), "platform".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_elasticache_replication_group_markdown_render() {
+        let markdown = sample_replication_group().to_markdown(Language::English);
+        assert!(markdown.contains("## Replication Group (sessions)"));
+        assert!(markdown.contains("| Engine | redis 7.1.0 |"));
+        assert!(markdown.contains("| Node Type | cache.r7g.large |"));
+        assert!(markdown.contains("| Cluster Mode | disabled |"));
+        assert!(markdown.contains("| Automatic Failover | enabled |"));
+        assert!(markdown.contains("| Subnet Group | cache-private |"));
+        assert!(markdown.contains("| Encryption in Transit | Enabled |"));
+        assert!(markdown.contains("| Encryption at Rest | Disabled |"));
+        assert!(markdown.contains("- sg-0123"));
+        assert!(markdown.contains("### Replication Group Topology"));
+        assert!(markdown.contains(
+            "| 0001 | available | - | master.sessions.abc.apn2.cache.amazonaws.com:6379 |"
+        ));
+        assert!(markdown.contains(
+            "| 0001 | sessions-002 (0001) | replica | ap-northeast-2c | sessions-002.abc.apn2.cache.amazonaws.com:6379 |"
+        ));
+        assert!(!markdown.contains("### Nodes"));
+        assert!(markdown.contains("| Team | platform |"));
+    }
+
+    #[test]
+    fn scenario_elasticache_memcached_cluster_lists_nodes() {
+        let mut detail = sample_replication_group();
+        detail.id = "catalog-cache".to_string();
+        detail.is_replication_group = false;
+        detail.description = String::new();
+        detail.engine = "memcached".to_string();
+        detail.engine_version = "1.6.22".to_string();
+        detail.cluster_mode = None;
+        detail.automatic_failover = None;
+        detail.multi_az = None;
+        detail.configuration_endpoint =
+            Some("catalog-cache.abc.cfg.apn2.cache.amazonaws.com:11211".to_string());
+        detail.node_groups = vec![];
+        detail.nodes = vec![ElastiCacheNode {
+            id: "0001".to_string(),
+            status: "available".to_string(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            endpoint: None,
+        }];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("## ElastiCache 클러스터 (catalog-cache)"));
+        assert!(markdown.contains("| 엔진 | memcached 1.6.22 |"));
+        assert!(!markdown.contains("클러스터 모드"));
+        assert!(markdown.contains(
+            "| 구성 엔드포인트 | catalog-cache.abc.cfg.apn2.cache.amazonaws.com:11211 |"
+        ));
+        assert!(markdown.contains("### 노드"));
+        assert!(markdown.contains("| 0001 | available | ap-northeast-2a | - |"));
+    }
+}
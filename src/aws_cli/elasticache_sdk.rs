@@ -0,0 +1,482 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::elasticache::{
+    ElastiCacheDetail, ElastiCacheNode, ElastiCacheNodeGroup, ElastiCacheNodeGroupMember,
+};
+use aws_sdk_elasticache::types::{CacheCluster, Endpoint, ReplicationGroup};
+
+/// List all ElastiCache replication groups using AWS SDK
+pub fn list_replication_groups() -> Vec<AwsResource> {
+    get_runtime().block_on(list_replication_groups_async())
+}
+
+async fn list_replication_groups_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticache, &config);
+
+    let result = client
+        .describe_replication_groups()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(groups) => groups.iter().map(map_replication_group_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing ElastiCache replication groups: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// List ElastiCache cache clusters that are not part of a replication group using AWS SDK
+pub fn list_cache_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_cache_clusters_async())
+}
+
+async fn list_cache_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticache, &config);
+
+    // 복제 그룹의 멤버 클러스터는 복제 그룹 상세에서 토폴로지로 보여줌
+    let result = client
+        .describe_cache_clusters()
+        .show_cache_clusters_not_in_replication_groups(true)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(clusters) => clusters.iter().map(map_cache_cluster_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing ElastiCache clusters: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get ElastiCache replication group detail using AWS SDK
+pub fn get_replication_group_detail(id: &str) -> Option<ElastiCacheDetail> {
+    get_runtime().block_on(get_replication_group_detail_async(id))
+}
+
+async fn get_replication_group_detail_async(id: &str) -> Option<ElastiCacheDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticache, &config);
+
+    let output = client
+        .describe_replication_groups()
+        .replication_group_id(id)
+        .send()
+        .await
+        .ok()?;
+    let group = output.replication_groups().first()?;
+
+    // 엔진 버전, 서브넷/파라미터/보안 그룹은 멤버 클러스터에만 있음
+    let member = match group.member_clusters().first() {
+        Some(cluster_id) => client
+            .describe_cache_clusters()
+            .cache_cluster_id(cluster_id)
+            .send()
+            .await
+            .ok()
+            .and_then(|output| output.cache_clusters().first().cloned()),
+        None => None,
+    };
+
+    let tags = list_tags(&client, group.arn().unwrap_or_default()).await;
+    Some(map_replication_group_detail(group, member.as_ref(), tags))
+}
+
+/// Get standalone ElastiCache cache cluster detail using AWS SDK
+pub fn get_cache_cluster_detail(id: &str) -> Option<ElastiCacheDetail> {
+    get_runtime().block_on(get_cache_cluster_detail_async(id))
+}
+
+async fn get_cache_cluster_detail_async(id: &str) -> Option<ElastiCacheDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticache, &config);
+
+    let output = client
+        .describe_cache_clusters()
+        .cache_cluster_id(id)
+        .show_cache_node_info(true)
+        .send()
+        .await
+        .ok()?;
+    let cluster = output.cache_clusters().first()?;
+
+    let tags = list_tags(&client, cluster.arn().unwrap_or_default()).await;
+    Some(map_cache_cluster_detail(cluster, tags))
+}
+
+// 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+async fn list_tags(client: &aws_sdk_elasticache::Client, arn: &str) -> Vec<(String, String)> {
+    if arn.is_empty() {
+        return Vec::new();
+    }
+    match client
+        .list_tags_for_resource()
+        .resource_name(arn)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            let mut tags: Vec<(String, String)> = output
+                .tag_list()
+                .iter()
+                .map(|tag| {
+                    (
+                        tag.key().unwrap_or_default().to_string(),
+                        tag.value().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect();
+            tags.sort();
+            tags
+        }
+        Err(e) => {
+            tracing::warn!("Error listing tags for {}: {:?}", arn, e);
+            Vec::new()
+        }
+    }
+}
+
+fn format_endpoint(endpoint: Option<&Endpoint>) -> Option<String> {
+    let endpoint = endpoint?;
+    let address = endpoint.address()?;
+    Some(match endpoint.port() {
+        Some(port) => format!("{}:{}", address, port),
+        None => address.to_string(),
+    })
+}
+
+fn map_replication_group_resource(group: &ReplicationGroup) -> AwsResource {
+    let id = group.replication_group_id().unwrap_or_default().to_string();
+    AwsResource {
+        name: id.clone(),
+        id,
+        state: group.status().unwrap_or_default().to_string(),
+        az: String::new(),
+        cidr: format!("{} replication group", group.engine().unwrap_or("redis")),
+        owner_id: String::new(),
+    }
+}
+
+fn map_cache_cluster_resource(cluster: &CacheCluster) -> AwsResource {
+    let id = cluster.cache_cluster_id().unwrap_or_default().to_string();
+    AwsResource {
+        name: id.clone(),
+        id,
+        state: cluster
+            .cache_cluster_status()
+            .unwrap_or_default()
+            .to_string(),
+        az: cluster
+            .preferred_availability_zone()
+            .unwrap_or_default()
+            .to_string(),
+        cidr: cluster.engine().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_security_groups(cluster: &CacheCluster) -> Vec<String> {
+    cluster
+        .security_groups()
+        .iter()
+        .filter_map(|sg| sg.security_group_id().map(|s| s.to_string()))
+        .collect()
+}
+
+fn map_replication_group_detail(
+    group: &ReplicationGroup,
+    member: Option<&CacheCluster>,
+    tags: Vec<(String, String)>,
+) -> ElastiCacheDetail {
+    // cluster_mode가 없는 이전 응답은 cluster_enabled로 판단
+    let cluster_mode = group
+        .cluster_mode()
+        .map(|mode| mode.as_str().to_lowercase())
+        .or_else(|| {
+            group
+                .cluster_enabled()
+                .map(|enabled| if enabled { "enabled" } else { "disabled" }.to_string())
+        });
+    let node_groups = group
+        .node_groups()
+        .iter()
+        .map(|node_group| ElastiCacheNodeGroup {
+            id: node_group.node_group_id().unwrap_or_default().to_string(),
+            status: node_group.status().unwrap_or_default().to_string(),
+            slots: node_group.slots().map(|s| s.to_string()),
+            primary_endpoint: format_endpoint(node_group.primary_endpoint()),
+            reader_endpoint: format_endpoint(node_group.reader_endpoint()),
+            members: node_group
+                .node_group_members()
+                .iter()
+                .map(|m| ElastiCacheNodeGroupMember {
+                    cluster_id: m.cache_cluster_id().unwrap_or_default().to_string(),
+                    node_id: m.cache_node_id().unwrap_or_default().to_string(),
+                    role: m.current_role().unwrap_or_default().to_string(),
+                    availability_zone: m
+                        .preferred_availability_zone()
+                        .unwrap_or_default()
+                        .to_string(),
+                    endpoint: format_endpoint(m.read_endpoint()),
+                })
+                .collect(),
+        })
+        .collect();
+
+    ElastiCacheDetail {
+        id: group.replication_group_id().unwrap_or_default().to_string(),
+        arn: group.arn().unwrap_or_default().to_string(),
+        is_replication_group: true,
+        description: group.description().unwrap_or_default().to_string(),
+        engine: group
+            .engine()
+            .or(member.and_then(|m| m.engine()))
+            .unwrap_or_default()
+            .to_string(),
+        engine_version: member
+            .and_then(|m| m.engine_version())
+            .unwrap_or_default()
+            .to_string(),
+        node_type: group.cache_node_type().unwrap_or_default().to_string(),
+        status: group.status().unwrap_or_default().to_string(),
+        cluster_mode,
+        automatic_failover: group
+            .automatic_failover()
+            .map(|status| status.as_str().to_string()),
+        multi_az: group.multi_az().map(|status| status.as_str().to_string()),
+        configuration_endpoint: format_endpoint(group.configuration_endpoint()),
+        transit_encryption: group.transit_encryption_enabled().unwrap_or(false),
+        at_rest_encryption: group.at_rest_encryption_enabled().unwrap_or(false),
+        subnet_group: member
+            .and_then(|m| m.cache_subnet_group_name())
+            .unwrap_or_default()
+            .to_string(),
+        parameter_group: member
+            .and_then(|m| m.cache_parameter_group())
+            .and_then(|g| g.cache_parameter_group_name())
+            .unwrap_or_default()
+            .to_string(),
+        security_groups: member.map(map_security_groups).unwrap_or_default(),
+        node_groups,
+        nodes: Vec::new(),
+        tags,
+    }
+}
+
+fn map_cache_cluster_detail(
+    cluster: &CacheCluster,
+    tags: Vec<(String, String)>,
+) -> ElastiCacheDetail {
+    let nodes = cluster
+        .cache_nodes()
+        .iter()
+        .map(|node| ElastiCacheNode {
+            id: node.cache_node_id().unwrap_or_default().to_string(),
+            status: node.cache_node_status().unwrap_or_default().to_string(),
+            availability_zone: node
+                .customer_availability_zone()
+                .unwrap_or_default()
+                .to_string(),
+            endpoint: format_endpoint(node.endpoint()),
+        })
+        .collect();
+
+    ElastiCacheDetail {
+        id: cluster.cache_cluster_id().unwrap_or_default().to_string(),
+        arn: cluster.arn().unwrap_or_default().to_string(),
+        is_replication_group: false,
+        description: String::new(),
+        engine: cluster.engine().unwrap_or_default().to_string(),
+        engine_version: cluster.engine_version().unwrap_or_default().to_string(),
+        node_type: cluster.cache_node_type().unwrap_or_default().to_string(),
+        status: cluster
+            .cache_cluster_status()
+            .unwrap_or_default()
+            .to_string(),
+        cluster_mode: None,
+        automatic_failover: None,
+        multi_az: None,
+        configuration_endpoint: format_endpoint(cluster.configuration_endpoint()),
+        transit_encryption: cluster.transit_encryption_enabled().unwrap_or(false),
+        at_rest_encryption: cluster.at_rest_encryption_enabled().unwrap_or(false),
+        subnet_group: cluster
+            .cache_subnet_group_name()
+            .unwrap_or_default()
+            .to_string(),
+        parameter_group: cluster
+            .cache_parameter_group()
+            .and_then(|g| g.cache_parameter_group_name())
+            .unwrap_or_default()
+            .to_string(),
+        security_groups: map_security_groups(cluster),
+        node_groups: Vec::new(),
+        nodes,
+        tags,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_cache_cluster_detail, map_cache_cluster_resource, map_replication_group_detail,
+        map_replication_group_resource,
+    };
+    use aws_sdk_elasticache::types::{
+        AutomaticFailoverStatus, CacheCluster, CacheNode, CacheParameterGroupStatus, Endpoint,
+        NodeGroup, NodeGroupMember, ReplicationGroup, SecurityGroupMembership,
+    };
+
+    fn endpoint(address: &str, port: i32) -> Endpoint {
+        Endpoint::builder().address(address).port(port).build()
+    }
+
+    fn member_cluster() -> CacheCluster {
+        CacheCluster::builder()
+            .cache_cluster_id("sessions-001")
+            .engine("redis")
+            .engine_version("7.1.0")
+            .cache_subnet_group_name("cache-private")
+            .cache_parameter_group(
+                CacheParameterGroupStatus::builder()
+                    .cache_parameter_group_name("default.redis7")
+                    .build(),
+            )
+            .security_groups(
+                SecurityGroupMembership::builder()
+                    .security_group_id("sg-0123")
+                    .status("active")
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn map_resources_use_status_and_engine() {
+        let group = ReplicationGroup::builder()
+            .replication_group_id("sessions")
+            .status("available")
+            .engine("valkey")
+            .build();
+        let resource = map_replication_group_resource(&group);
+        assert_eq!(resource.id, "sessions");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "valkey replication group");
+
+        let cluster = CacheCluster::builder()
+            .cache_cluster_id("catalog-cache")
+            .cache_cluster_status("available")
+            .engine("memcached")
+            .preferred_availability_zone("ap-northeast-2a")
+            .build();
+        let resource = map_cache_cluster_resource(&cluster);
+        assert_eq!(resource.id, "catalog-cache");
+        assert_eq!(resource.cidr, "memcached");
+        assert_eq!(resource.az, "ap-northeast-2a");
+    }
+
+    #[test]
+    fn map_replication_group_detail_builds_topology() {
+        let group = ReplicationGroup::builder()
+            .replication_group_id("sessions")
+            .description("session store")
+            .status("available")
+            .cache_node_type("cache.r7g.large")
+            .cluster_enabled(false)
+            .automatic_failover(AutomaticFailoverStatus::Enabled)
+            .transit_encryption_enabled(true)
+            .member_clusters("sessions-001")
+            .member_clusters("sessions-002")
+            .node_groups(
+                NodeGroup::builder()
+                    .node_group_id("0001")
+                    .status("available")
+                    .primary_endpoint(endpoint("master.sessions.example", 6379))
+                    .reader_endpoint(endpoint("replica.sessions.example", 6379))
+                    .node_group_members(
+                        NodeGroupMember::builder()
+                            .cache_cluster_id("sessions-001")
+                            .cache_node_id("0001")
+                            .current_role("primary")
+                            .preferred_availability_zone("ap-northeast-2a")
+                            .read_endpoint(endpoint("sessions-001.example", 6379))
+                            .build(),
+                    )
+                    .node_group_members(
+                        NodeGroupMember::builder()
+                            .cache_cluster_id("sessions-002")
+                            .cache_node_id("0001")
+                            .current_role("replica")
+                            .preferred_availability_zone("ap-northeast-2c")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        let detail = map_replication_group_detail(&group, Some(&member_cluster()), vec![]);
+        assert!(detail.is_replication_group);
+        assert_eq!(detail.engine, "redis");
+        assert_eq!(detail.engine_version, "7.1.0");
+        assert_eq!(detail.cluster_mode.as_deref(), Some("disabled"));
+        assert_eq!(detail.automatic_failover.as_deref(), Some("enabled"));
+        assert_eq!(detail.multi_az, None);
+        assert!(detail.transit_encryption);
+        assert_eq!(detail.subnet_group, "cache-private");
+        assert_eq!(detail.parameter_group, "default.redis7");
+        assert_eq!(detail.security_groups, vec!["sg-0123".to_string()]);
+
+        let shard = &detail.node_groups[0];
+        assert_eq!(
+            shard.primary_endpoint.as_deref(),
+            Some("master.sessions.example:6379")
+        );
+        assert_eq!(shard.members.len(), 2);
+        assert_eq!(shard.members[0].role, "primary");
+        assert_eq!(
+            shard.members[0].endpoint.as_deref(),
+            Some("sessions-001.example:6379")
+        );
+        assert_eq!(shard.members[1].endpoint, None);
+    }
+
+    #[test]
+    fn map_cache_cluster_detail_lists_nodes() {
+        let cluster = CacheCluster::builder()
+            .cache_cluster_id("catalog-cache")
+            .engine("memcached")
+            .engine_version("1.6.22")
+            .cache_node_type("cache.t4g.small")
+            .configuration_endpoint(endpoint("catalog-cache.cfg.example", 11211))
+            .cache_nodes(
+                CacheNode::builder()
+                    .cache_node_id("0001")
+                    .cache_node_status("available")
+                    .customer_availability_zone("ap-northeast-2a")
+                    .endpoint(endpoint("catalog-cache.0001.example", 11211))
+                    .build(),
+            )
+            .build();
+
+        let detail = map_cache_cluster_detail(&cluster, vec![]);
+        assert!(!detail.is_replication_group);
+        assert_eq!(detail.cluster_mode, None);
+        assert_eq!(
+            detail.configuration_endpoint.as_deref(),
+            Some("catalog-cache.cfg.example:11211")
+        );
+        assert_eq!(detail.nodes.len(), 1);
+        assert_eq!(
+            detail.nodes[0].endpoint.as_deref(),
+            Some("catalog-cache.0001.example:11211")
+        );
+    }
+}
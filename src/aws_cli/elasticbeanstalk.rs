@@ -0,0 +1,344 @@
+pub use crate::aws_cli::elasticbeanstalk_sdk::{
+    get_elastic_beanstalk_detail, list_beanstalk_environments,
+};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+// 환경 속성(환경 변수) 네임스페이스: 값에 비밀이 들어갈 수 있어 키만 기록
+pub const ENVIRONMENT_PROPERTIES_NAMESPACE: &str = "aws:elasticbeanstalk:application:environment";
+
+// 옵션 설정 요약에 포함할 항목 (표시 순서)
+const SUMMARY_OPTIONS: &[(&str, &str)] = &[
+    ("aws:elasticbeanstalk:environment", "EnvironmentType"),
+    ("aws:elasticbeanstalk:environment", "LoadBalancerType"),
+    ("aws:elasticbeanstalk:environment", "ServiceRole"),
+    ("aws:ec2:instances", "InstanceTypes"),
+    ("aws:autoscaling:launchconfiguration", "InstanceType"),
+    ("aws:autoscaling:launchconfiguration", "IamInstanceProfile"),
+    ("aws:autoscaling:launchconfiguration", "EC2KeyName"),
+    ("aws:autoscaling:asg", "MinSize"),
+    ("aws:autoscaling:asg", "MaxSize"),
+    ("aws:ec2:vpc", "VPCId"),
+    ("aws:ec2:vpc", "Subnets"),
+    ("aws:ec2:vpc", "ELBSubnets"),
+    ("aws:elasticbeanstalk:command", "DeploymentPolicy"),
+    ("aws:elasticbeanstalk:healthreporting:system", "SystemType"),
+    (
+        "aws:elasticbeanstalk:managedactions",
+        "ManagedActionsEnabled",
+    ),
+    ("aws:elasticbeanstalk:cloudwatch:logs", "StreamLogs"),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BeanstalkOptionSetting {
+    pub namespace: String,
+    pub option_name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct ElasticBeanstalkDetail {
+    pub name: String,
+    pub id: String,
+    pub arn: String,
+    pub application: String,
+    pub description: String,
+    // Launching, Updating, Ready, Terminating, Terminated
+    pub status: String,
+    // Green, Yellow, Red, Grey
+    pub health: String,
+    // Ok, Info, Warning, Degraded, Severe ... (향상된 상태 보고 사용 시)
+    pub health_status: String,
+    // WebServer/Standard, Worker/SQS/HTTP
+    pub tier: String,
+    pub platform: String,
+    pub version_label: String,
+    pub cname: String,
+    pub endpoint_url: String,
+    pub created: String,
+    pub updated: String,
+    pub option_settings: Vec<BeanstalkOptionSetting>,
+    pub option_count: usize,
+    pub env_var_keys: Vec<String>,
+    // (리소스 유형, ID): AutoScalingGroup, Instance, LoadBalancer ...
+    pub resources: Vec<(String, String)>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// Pick the settings shown in the summary table and the environment property keys.
+/// 기본값이 비어 있는 항목은 요약에서 제외
+pub fn summarize_option_settings(
+    settings: &[BeanstalkOptionSetting],
+) -> (Vec<BeanstalkOptionSetting>, Vec<String>) {
+    let summary = SUMMARY_OPTIONS
+        .iter()
+        .filter_map(|(namespace, option_name)| {
+            settings.iter().find(|setting| {
+                setting.namespace == *namespace
+                    && setting.option_name == *option_name
+                    && !setting.value.is_empty()
+            })
+        })
+        .cloned()
+        .collect();
+
+    let mut env_var_keys: Vec<String> = settings
+        .iter()
+        .filter(|setting| setting.namespace == ENVIRONMENT_PROPERTIES_NAMESPACE)
+        .map(|setting| setting.option_name.clone())
+        .collect();
+    env_var_keys.sort();
+    env_var_keys.dedup();
+    (summary, env_var_keys)
+}
+
+/// Platform name from a platform ARN (`.../platform/Python 3.11 running on 64bit Amazon Linux 2023/4.0.1`).
+pub fn platform_display(platform_arn: &str) -> String {
+    platform_arn
+        .split_once(":platform/")
+        .map_or(platform_arn, |(_, platform)| platform)
+        .to_string()
+}
+
+impl ElasticBeanstalkDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let health = if self.health_status.is_empty() {
+            self.health.clone()
+        } else {
+            format!("{} ({})", self.health, self.health_status)
+        };
+
+        let mut lines = vec![
+            format!(
+                "## {} ({})\n",
+                i18n.elastic_beanstalk_environment(),
+                self.name
+            ),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_application(), self.application),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_health(), health),
+            format!("| {} | {} |", i18n.md_tier(), self.tier),
+            format!("| {} | {} |", i18n.md_platform(), self.platform),
+        ];
+        if !self.version_label.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_version(),
+                self.version_label
+            ));
+        }
+        if !self.cname.is_empty() {
+            lines.push(format!("| CNAME | {} |", self.cname));
+        }
+        if !self.endpoint_url.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_endpoint(),
+                self.endpoint_url
+            ));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+        if !self.updated.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_last_updated(), self.updated));
+        }
+
+        // Option Settings
+        if !self.option_settings.is_empty() {
+            lines.push(String::new());
+            lines.push(format!(
+                "### {} ({}/{})\n",
+                i18n.md_option_settings(),
+                self.option_settings.len(),
+                self.option_count
+            ));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_namespace(),
+                i18n.md_option(),
+                i18n.value()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for setting in &self.option_settings {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    setting.namespace, setting.option_name, setting.value
+                ));
+            }
+        }
+
+        // Environment Properties
+        if !self.env_var_keys.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_environment_variables()));
+            for key in &self.env_var_keys {
+                lines.push(format!("- `{}`", key));
+            }
+        }
+
+        // Associated Resources
+        if !self.resources.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_associated_resources()));
+            lines.push(format!("| {} | ID |", i18n.md_type()));
+            lines.push("|:---|:---|".to_string());
+            for (resource_type, id) in &self.resources {
+                lines.push(format!("| {} | {} |", resource_type, id));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BeanstalkOptionSetting, ElasticBeanstalkDetail, platform_display, summarize_option_settings,
+    };
+    use crate::i18n::Language;
+
+    fn setting(namespace: &str, option_name: &str, value: &str) -> BeanstalkOptionSetting {
+        BeanstalkOptionSetting {
+            namespace: namespace.to_string(),
+            option_name: option_name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn sample_detail() -> ElasticBeanstalkDetail {
+        ElasticBeanstalkDetail {
+            name: "orders-prod".to_string(),
+            id: "e-abc123xyz0".to_string(),
+            arn: "arn:aws:elasticbeanstalk:ap-northeast-2:123456789012:environment/orders/orders-prod"
+                .to_string(),
+            application: "orders".to_string(),
+            description: String::new(),
+            status: "Ready".to_string(),
+            health: "Green".to_string(),
+            health_status: "Ok".to_string(),
+            tier: "WebServer/Standard".to_string(),
+            platform: "Python 3.11 running on 64bit Amazon Linux 2023/4.0.1".to_string(),
+            version_label: "v42".to_string(),
+            cname: "orders-prod.ap-northeast-2.elasticbeanstalk.com".to_string(),
+            endpoint_url: "awseb-e-a-AWSEBLoa-1ABC.ap-northeast-2.elb.amazonaws.com".to_string(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            updated: String::new(),
+            option_settings: vec![
+                setting(
+                    "aws:elasticbeanstalk:environment",
+                    "EnvironmentType",
+                    "LoadBalanced",
+                ),
+                setting("aws:autoscaling:asg", "MinSize", "2"),
+            ],
+            option_count: 180,
+            env_var_keys: vec!["DATABASE_URL".to_string()],
+            resources: vec![
+                ("AutoScalingGroup".to_string(), "awseb-e-abc-AWSEBAutoScalingGroup".to_string()),
+                ("Instance".to_string(), "i-0123456789abcdef0".to_string()),
+            ],
+            tags: vec![("Team".to_string(), "orders".to_string())],
+        }
+    }
+
+    #[test]
+    fn summarize_option_settings_keeps_known_options_and_property_keys() {
+        let settings = vec![
+            setting("aws:autoscaling:asg", "MaxSize", "4"),
+            setting("aws:autoscaling:asg", "MinSize", "2"),
+            setting("aws:autoscaling:launchconfiguration", "EC2KeyName", ""),
+            setting(
+                "aws:elasticbeanstalk:environment",
+                "EnvironmentType",
+                "LoadBalanced",
+            ),
+            setting("aws:elb:policies", "ConnectionDrainingEnabled", "true"),
+            setting(
+                "aws:elasticbeanstalk:application:environment",
+                "SECRET_KEY",
+                "do-not-print",
+            ),
+            setting(
+                "aws:elasticbeanstalk:application:environment",
+                "DATABASE_URL",
+                "postgres://...",
+            ),
+        ];
+
+        let (summary, env_var_keys) = summarize_option_settings(&settings);
+        let names: Vec<&str> = summary
+            .iter()
+            .map(|setting| setting.option_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["EnvironmentType", "MinSize", "MaxSize"]);
+        assert_eq!(env_var_keys, vec!["DATABASE_URL", "SECRET_KEY"]);
+    }
+
+    #[test]
+    fn platform_display_strips_arn_prefix() {
+        assert_eq!(
+            platform_display(
+                "arn:aws:elasticbeanstalk:ap-northeast-2::platform/Python 3.11 running on 64bit Amazon Linux 2023/4.0.1"
+            ),
+            "Python 3.11 running on 64bit Amazon Linux 2023/4.0.1"
+        );
+        assert_eq!(
+            platform_display("64bit Amazon Linux 2023 v4.0.1 running Python 3.11"),
+            "64bit Amazon Linux 2023 v4.0.1 running Python 3.11"
+        );
+    }
+
+    #[test]
+    fn scenario_environment_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Elastic Beanstalk Environment (orders-prod)"));
+        assert!(markdown.contains("| Health | Green (Ok) |"));
+        assert!(
+            markdown
+                .contains("| Platform | Python 3.11 running on 64bit Amazon Linux 2023/4.0.1 |")
+        );
+        assert!(markdown.contains("### Option Settings (2/180)"));
+        assert!(
+            markdown
+                .contains("| aws:elasticbeanstalk:environment | EnvironmentType | LoadBalanced |")
+        );
+        assert!(markdown.contains("- `DATABASE_URL`"));
+        assert!(markdown.contains("| Instance | i-0123456789abcdef0 |"));
+        assert!(markdown.contains("| Team | orders |"));
+
+        let mut detail = sample_detail();
+        detail.health_status.clear();
+        detail.resources.clear();
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 헬스 | Green |"));
+        assert!(!markdown.contains("### 연결된 리소스"));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::elasticbeanstalk::{
+    BeanstalkOptionSetting, ElasticBeanstalkDetail, platform_display, summarize_option_settings,
+};
+use aws_sdk_elasticbeanstalk::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_elasticbeanstalk::types::{
+    ConfigurationOptionSetting, EnvironmentDescription, EnvironmentResourceDescription,
+};
+
+/// List Elastic Beanstalk environments using AWS SDK
+pub fn list_beanstalk_environments() -> Vec<AwsResource> {
+    get_runtime().block_on(list_beanstalk_environments_async())
+}
+
+async fn list_beanstalk_environments_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticbeanstalk, &config);
+
+    // describe_environments는 paginator가 없어 next_token으로 직접 반복
+    let mut resources: Vec<AwsResource> = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        match client
+            .describe_environments()
+            .include_deleted(false)
+            .set_next_token(next_token.take())
+            .send()
+            .await
+        {
+            Ok(output) => {
+                resources.extend(output.environments().iter().map(map_environment_resource));
+                match output.next_token() {
+                    Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                    _ => break,
+                }
+            }
+            Err(e) => {
+                tracing::error!("Error describing Beanstalk environments: {:?}", e);
+                break;
+            }
+        }
+    }
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get environment detail (platform, health, option settings, resources) using AWS SDK.
+/// 환경 ID(e-xxxxxxxxxx) 또는 환경 이름으로 조회 (CloudFormation/태그 ARN은 이름만 제공)
+pub fn get_elastic_beanstalk_detail(environment_id: &str) -> Option<ElasticBeanstalkDetail> {
+    get_runtime().block_on(get_elastic_beanstalk_detail_async(environment_id))
+}
+
+async fn get_elastic_beanstalk_detail_async(
+    environment_id: &str,
+) -> Option<ElasticBeanstalkDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_elasticbeanstalk, &config);
+
+    let request = client.describe_environments().include_deleted(false);
+    let request = if is_environment_id(environment_id) {
+        request.environment_ids(environment_id)
+    } else {
+        request.environment_names(environment_id)
+    };
+    let environment = match request.send().await {
+        Ok(output) => output.environments().first().cloned()?,
+        Err(e) => {
+            tracing::error!(
+                "Error describing Beanstalk environment {}: {:?}",
+                environment_id,
+                e
+            );
+            return None;
+        }
+    };
+    let mut detail = map_environment_detail(&environment);
+
+    // 옵션 설정/리소스/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .describe_configuration_settings()
+        .application_name(&detail.application)
+        .environment_name(&detail.name)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            let settings: Vec<BeanstalkOptionSetting> = output
+                .configuration_settings()
+                .iter()
+                .flat_map(|configuration| configuration.option_settings())
+                .map(map_option_setting)
+                .collect();
+            let (summary, env_var_keys) = summarize_option_settings(&settings);
+            detail.option_count = settings.len();
+            detail.option_settings = summary;
+            detail.env_var_keys = env_var_keys;
+        }
+        Err(e) => tracing::warn!(
+            "Error getting configuration settings for {}: {:?}",
+            environment_id,
+            e
+        ),
+    }
+
+    match client
+        .describe_environment_resources()
+        .environment_id(&detail.id)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            if let Some(resources) = output.environment_resources() {
+                detail.resources = map_environment_resources(resources);
+            }
+        }
+        Err(e) => tracing::warn!(
+            "Error getting environment resources for {}: {:?}",
+            environment_id,
+            e
+        ),
+    }
+
+    if !detail.arn.is_empty() {
+        match client
+            .list_tags_for_resource()
+            .resource_arn(&detail.arn)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                let mut tags: Vec<(String, String)> = output
+                    .resource_tags()
+                    .iter()
+                    .filter_map(|tag| {
+                        Some((
+                            tag.key()?.to_string(),
+                            tag.value().unwrap_or_default().to_string(),
+                        ))
+                    })
+                    .collect();
+                tags.sort();
+                detail.tags = tags;
+            }
+            Err(e) => tracing::warn!("Error getting tags for {}: {:?}", environment_id, e),
+        }
+    }
+
+    Some(detail)
+}
+
+fn is_environment_id(value: &str) -> bool {
+    value.strip_prefix("e-").is_some_and(|suffix| {
+        suffix.len() == 10
+            && suffix
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 헬스(state)와 애플리케이션 이름(cidr)을 표시
+fn map_environment_resource(environment: &EnvironmentDescription) -> AwsResource {
+    AwsResource {
+        name: environment
+            .environment_name()
+            .unwrap_or_default()
+            .to_string(),
+        id: environment.environment_id().unwrap_or_default().to_string(),
+        state: environment
+            .health()
+            .map(|health| health.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: environment
+            .application_name()
+            .unwrap_or_default()
+            .to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_environment_detail(environment: &EnvironmentDescription) -> ElasticBeanstalkDetail {
+    // 솔루션 스택 이름이 없으면 플랫폼 ARN에서 플랫폼 이름 추출
+    let platform = match environment.solution_stack_name() {
+        Some(stack) if !stack.is_empty() => stack.to_string(),
+        _ => environment
+            .platform_arn()
+            .map(platform_display)
+            .unwrap_or_default(),
+    };
+    let tier = environment
+        .tier()
+        .map(|tier| {
+            [tier.name(), tier.r#type()]
+                .into_iter()
+                .flatten()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+
+    ElasticBeanstalkDetail {
+        name: environment
+            .environment_name()
+            .unwrap_or_default()
+            .to_string(),
+        id: environment.environment_id().unwrap_or_default().to_string(),
+        arn: environment
+            .environment_arn()
+            .unwrap_or_default()
+            .to_string(),
+        application: environment
+            .application_name()
+            .unwrap_or_default()
+            .to_string(),
+        description: environment.description().unwrap_or_default().to_string(),
+        status: environment
+            .status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        health: environment
+            .health()
+            .map(|health| health.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        health_status: environment
+            .health_status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        tier,
+        platform,
+        version_label: environment.version_label().unwrap_or_default().to_string(),
+        cname: environment.cname().unwrap_or_default().to_string(),
+        endpoint_url: environment.endpoint_url().unwrap_or_default().to_string(),
+        created: format_timestamp(environment.date_created()),
+        updated: format_timestamp(environment.date_updated()),
+        option_settings: Vec::new(),
+        option_count: 0,
+        env_var_keys: Vec::new(),
+        resources: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_option_setting(setting: &ConfigurationOptionSetting) -> BeanstalkOptionSetting {
+    BeanstalkOptionSetting {
+        namespace: setting.namespace().unwrap_or_default().to_string(),
+        option_name: setting.option_name().unwrap_or_default().to_string(),
+        value: setting.value().unwrap_or_default().to_string(),
+    }
+}
+
+// 유형별로 (리소스 유형, ID) 목록으로 펼침
+fn map_environment_resources(resources: &EnvironmentResourceDescription) -> Vec<(String, String)> {
+    let mut mapped: Vec<(String, String)> = Vec::new();
+    let mut push = |resource_type: &str, id: Option<&str>| {
+        if let Some(id) = id.filter(|id| !id.is_empty()) {
+            mapped.push((resource_type.to_string(), id.to_string()));
+        }
+    };
+    for group in resources.auto_scaling_groups() {
+        push("AutoScalingGroup", group.name());
+    }
+    for instance in resources.instances() {
+        push("Instance", instance.id());
+    }
+    for load_balancer in resources.load_balancers() {
+        push("LoadBalancer", load_balancer.name());
+    }
+    for launch_configuration in resources.launch_configurations() {
+        push("LaunchConfiguration", launch_configuration.name());
+    }
+    for launch_template in resources.launch_templates() {
+        push("LaunchTemplate", launch_template.id());
+    }
+    for trigger in resources.triggers() {
+        push("Trigger", trigger.name());
+    }
+    for queue in resources.queues() {
+        push("Queue", queue.url().or(queue.name()));
+    }
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_environment_id, map_environment_detail, map_environment_resource,
+        map_environment_resources,
+    };
+    use aws_sdk_elasticbeanstalk::types::{
+        AutoScalingGroup, EnvironmentDescription, EnvironmentHealth, EnvironmentHealthStatus,
+        EnvironmentResourceDescription, EnvironmentStatus, EnvironmentTier, Instance, LoadBalancer,
+        Queue,
+    };
+
+    fn sample_environment(solution_stack_name: Option<&str>) -> EnvironmentDescription {
+        EnvironmentDescription::builder()
+            .environment_name("orders-prod")
+            .environment_id("e-abc123xyz0")
+            .application_name("orders")
+            .status(EnvironmentStatus::Ready)
+            .health(EnvironmentHealth::Green)
+            .health_status(EnvironmentHealthStatus::Ok)
+            .tier(
+                EnvironmentTier::builder()
+                    .name("WebServer")
+                    .r#type("Standard")
+                    .version("1.0")
+                    .build(),
+            )
+            .platform_arn("arn:aws:elasticbeanstalk:ap-northeast-2::platform/Python 3.11 running on 64bit Amazon Linux 2023/4.0.1")
+            .set_solution_stack_name(solution_stack_name.map(str::to_string))
+            .build()
+    }
+
+    #[test]
+    fn is_environment_id_distinguishes_ids_from_names() {
+        assert!(is_environment_id("e-abc123xyz0"));
+        assert!(!is_environment_id("e-commerce-prod"));
+        assert!(!is_environment_id("orders-prod"));
+    }
+
+    #[test]
+    fn map_environment_resource_shows_health_and_application() {
+        let resource = map_environment_resource(&sample_environment(None));
+        assert_eq!(resource.name, "orders-prod");
+        assert_eq!(resource.id, "e-abc123xyz0");
+        assert_eq!(resource.state, "Green");
+        assert_eq!(resource.cidr, "orders");
+    }
+
+    #[test]
+    fn map_environment_detail_reads_tier_platform_and_health() {
+        let detail = map_environment_detail(&sample_environment(None));
+        assert_eq!(detail.status, "Ready");
+        assert_eq!(detail.health, "Green");
+        assert_eq!(detail.health_status, "Ok");
+        assert_eq!(detail.tier, "WebServer/Standard");
+        assert_eq!(
+            detail.platform,
+            "Python 3.11 running on 64bit Amazon Linux 2023/4.0.1"
+        );
+
+        // 솔루션 스택 이름이 있으면 우선 사용
+        let detail = map_environment_detail(&sample_environment(Some(
+            "64bit Amazon Linux 2023 v4.0.1 running Python 3.11",
+        )));
+        assert_eq!(
+            detail.platform,
+            "64bit Amazon Linux 2023 v4.0.1 running Python 3.11"
+        );
+    }
+
+    #[test]
+    fn map_environment_resources_flattens_by_type() {
+        let resources = EnvironmentResourceDescription::builder()
+            .auto_scaling_groups(
+                AutoScalingGroup::builder()
+                    .name("awseb-e-abc-AWSEBAutoScalingGroup")
+                    .build(),
+            )
+            .instances(Instance::builder().id("i-0123456789abcdef0").build())
+            .instances(Instance::builder().build())
+            .load_balancers(
+                LoadBalancer::builder()
+                    .name("awseb-e-a-AWSEBLoa-1ABC")
+                    .build(),
+            )
+            .queues(
+                Queue::builder()
+                    .name("WorkerQueue")
+                    .url("https://sqs.ap-northeast-2.amazonaws.com/123456789012/worker")
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            map_environment_resources(&resources),
+            vec![
+                (
+                    "AutoScalingGroup".to_string(),
+                    "awseb-e-abc-AWSEBAutoScalingGroup".to_string()
+                ),
+                ("Instance".to_string(), "i-0123456789abcdef0".to_string()),
+                (
+                    "LoadBalancer".to_string(),
+                    "awseb-e-a-AWSEBLoa-1ABC".to_string()
+                ),
+                (
+                    "Queue".to_string(),
+                    "https://sqs.ap-northeast-2.amazonaws.com/123456789012/worker".to_string()
+                ),
+            ]
+        );
+    }
+}
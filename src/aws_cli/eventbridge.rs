@@ -0,0 +1,329 @@
+use crate::aws_cli::arn::Arn;
+pub use crate::aws_cli::eventbridge_sdk::{get_event_bus_detail, list_event_buses};
+use crate::cron;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct EventBusDetail {
+    pub name: String,
+    pub arn: String,
+    pub description: String,
+    // 고객 관리형 KMS 키를 쓰지 않으면 None (AWS 소유 키)
+    pub kms_key_id: Option<String>,
+    pub created: String,
+    pub rules: Vec<EventRuleDetail>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventRuleDetail {
+    pub name: String,
+    // ENABLED, DISABLED, ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS
+    pub state: String,
+    pub description: String,
+    // rate(...) / cron(...), 이벤트 패턴 규칙이면 빈 문자열
+    pub schedule_expression: String,
+    // 보기 좋게 정리한 이벤트 패턴 (JSON이 아니면 원본 그대로)
+    pub event_pattern: String,
+    pub role_arn: String,
+    // 다른 AWS 서비스가 만든 규칙이면 해당 서비스 주체
+    pub managed_by: String,
+    pub targets: Vec<EventTarget>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventTarget {
+    pub id: String,
+    pub arn: String,
+    pub role_arn: String,
+    pub input: TargetInput,
+}
+
+/// What a target receives when the rule fires.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum TargetInput {
+    MatchedEvent,
+    Constant(String),
+    Path(String),
+    Transformer(String),
+}
+
+/// Pretty-print an event pattern; anything that isn't JSON is kept as is.
+pub fn format_event_pattern(pattern: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(pattern) {
+        Ok(value) => serde_json::to_string_pretty(&value).unwrap_or_else(|_| pattern.to_string()),
+        Err(_) => pattern.to_string(),
+    }
+}
+
+// 대상 ARN을 "이름 (서비스)"로 줄여 표시 (ARN이 아니면 원본)
+fn target_label(arn: &str) -> String {
+    match Arn::parse(arn) {
+        Some(parsed) => format!("{} ({})", parsed.resource_name(), parsed.service),
+        None => arn.to_string(),
+    }
+}
+
+impl EventRuleDetail {
+    fn trigger(&self, i18n: &I18n) -> String {
+        if self.schedule_expression.is_empty() {
+            i18n.md_event_pattern().to_string()
+        } else {
+            cron::schedule_text(&self.schedule_expression, i18n)
+        }
+    }
+}
+
+impl TargetInput {
+    fn label(&self, i18n: &I18n) -> String {
+        match self {
+            TargetInput::MatchedEvent => i18n.md_matched_event().to_string(),
+            TargetInput::Constant(json) => format!("`{}`", json),
+            TargetInput::Path(path) => format!("`{}`", path),
+            TargetInput::Transformer(template) => {
+                format!("{}: `{}`", i18n.md_input_transformer(), template)
+            }
+        }
+    }
+}
+
+impl EventBusDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.eventbridge_event_bus(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_encryption(),
+            self.kms_key_id
+                .as_deref()
+                .unwrap_or_else(|| i18n.md_aws_owned_key())
+        ));
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+
+        // 규칙 요약: 무엇이 어떤 대상을 호출하는지 한눈에
+        if !self.rules.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_rules()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_state(),
+                i18n.md_trigger(),
+                i18n.md_target()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for rule in &self.rules {
+                let targets: Vec<String> = rule
+                    .targets
+                    .iter()
+                    .map(|target| target_label(&target.arn))
+                    .collect();
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    rule.name,
+                    rule.state,
+                    rule.trigger(&i18n),
+                    or_dash(&targets.join(", "))
+                ));
+            }
+        }
+
+        for rule in &self.rules {
+            lines.push(format!("\n#### {}\n", rule.name));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| {} | {} |", i18n.md_state(), rule.state));
+            if !rule.schedule_expression.is_empty() {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.asg_schedule(),
+                    cron::schedule_text(&rule.schedule_expression, &i18n)
+                ));
+            }
+            if !rule.description.is_empty() {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_description(),
+                    rule.description
+                ));
+            }
+            if !rule.role_arn.is_empty() {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_execution_role(),
+                    rule.role_arn
+                ));
+            }
+            if !rule.managed_by.is_empty() {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_managed_by(),
+                    rule.managed_by
+                ));
+            }
+
+            if !rule.event_pattern.is_empty() {
+                lines.push(format!("\n**{}**\n", i18n.md_event_pattern()));
+                lines.push("```json".to_string());
+                lines.push(rule.event_pattern.clone());
+                lines.push("```".to_string());
+            }
+
+            if !rule.targets.is_empty() {
+                lines.push(String::new());
+                lines.push(format!(
+                    "| ID | {} | ARN | {} | {} |",
+                    i18n.md_target(),
+                    i18n.md_execution_role(),
+                    i18n.md_input()
+                ));
+                lines.push("|:---|:---|:---|:---|:---|".to_string());
+                for target in &rule.targets {
+                    lines.push(format!(
+                        "| {} | {} | {} | {} | {} |",
+                        target.id,
+                        target_label(&target.arn),
+                        target.arn,
+                        or_dash(&target.role_arn),
+                        target.input.label(&i18n)
+                    ));
+                }
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventBusDetail, EventRuleDetail, EventTarget, TargetInput, format_event_pattern};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> EventBusDetail {
+        EventBusDetail {
+            name: "orders".to_string(),
+            arn: "arn:aws:events:ap-northeast-2:123456789012:event-bus/orders".to_string(),
+            description: "Order domain events".to_string(),
+            kms_key_id: None,
+            created: "2026-01-01T00:00:00Z".to_string(),
+            rules: vec![
+                EventRuleDetail {
+                    name: "nightly-report".to_string(),
+                    state: "ENABLED".to_string(),
+                    description: String::new(),
+                    schedule_expression: "cron(0 18 * * ? *)".to_string(),
+                    event_pattern: String::new(),
+                    role_arn: String::new(),
+                    managed_by: String::new(),
+                    targets: vec![EventTarget {
+                        id: "report".to_string(),
+                        arn: "arn:aws:lambda:ap-northeast-2:123456789012:function:report"
+                            .to_string(),
+                        role_arn: String::new(),
+                        input: TargetInput::Constant(r#"{"kind":"daily"}"#.to_string()),
+                    }],
+                },
+                EventRuleDetail {
+                    name: "order-created".to_string(),
+                    state: "ENABLED".to_string(),
+                    description: "Fan out new orders".to_string(),
+                    schedule_expression: String::new(),
+                    event_pattern: format_event_pattern(
+                        r#"{"source":["orders"],"detail-type":["OrderCreated"]}"#,
+                    ),
+                    role_arn: String::new(),
+                    managed_by: String::new(),
+                    targets: vec![
+                        EventTarget {
+                            id: "flow".to_string(),
+                            arn:
+                                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow"
+                                    .to_string(),
+                            role_arn: "arn:aws:iam::123456789012:role/events-sfn".to_string(),
+                            input: TargetInput::Path("$.detail".to_string()),
+                        },
+                        EventTarget {
+                            id: "audit".to_string(),
+                            arn: "arn:aws:sqs:ap-northeast-2:123456789012:order-audit".to_string(),
+                            role_arn: String::new(),
+                            input: TargetInput::MatchedEvent,
+                        },
+                    ],
+                },
+            ],
+            tags: vec![("Team".to_string(), "orders".to_string())],
+        }
+    }
+
+    #[test]
+    fn format_event_pattern_pretty_prints_json() {
+        let pattern = format_event_pattern(r#"{"source":["orders"]}"#);
+        assert_eq!(pattern, "{\n  \"source\": [\n    \"orders\"\n  ]\n}");
+        assert_eq!(format_event_pattern("not json"), "not json");
+    }
+
+    #[test]
+    fn event_bus_markdown_lists_rules_triggers_and_targets() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## EventBridge Event Bus (orders)"));
+        assert!(markdown.contains("| Encryption | AWS owned key |"));
+        assert!(markdown.contains("| Name | State | Trigger | Target |"));
+        assert!(
+            markdown
+                .contains("| nightly-report | ENABLED | `cron(0 18 * * ? *)` | report (lambda) |")
+        );
+        assert!(markdown.contains(
+            "| order-created | ENABLED | Event Pattern | order-flow (states), order-audit (sqs) |"
+        ));
+        assert!(markdown.contains("#### order-created"));
+        assert!(markdown.contains("**Event Pattern**\n\n```json\n{\n  \"detail-type\""));
+        assert!(markdown.contains(
+            "| flow | order-flow (states) | arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow | arn:aws:iam::123456789012:role/events-sfn | `$.detail` |"
+        ));
+        assert!(markdown.contains("| Matched event |"));
+        assert!(markdown.contains("| `{\"kind\":\"daily\"}` |"));
+        assert!(markdown.contains("| Team | orders |"));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::eventbridge::{
+    EventBusDetail, EventRuleDetail, EventTarget, TargetInput, format_event_pattern,
+};
+use aws_sdk_eventbridge::Client;
+use aws_sdk_eventbridge::operation::describe_event_bus::DescribeEventBusOutput;
+use aws_sdk_eventbridge::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_eventbridge::types::{EventBus, Rule, Target};
+
+/// List EventBridge event buses using AWS SDK
+pub fn list_event_buses() -> Vec<AwsResource> {
+    get_runtime().block_on(list_event_buses_async())
+}
+
+async fn list_event_buses_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_eventbridge, &config);
+
+    // ListEventBuses는 SDK 페이지네이터가 없으므로 NextToken을 직접 따라감
+    let mut resources = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        match client
+            .list_event_buses()
+            .set_next_token(next_token.take())
+            .send()
+            .await
+        {
+            Ok(output) => {
+                resources.extend(output.event_buses().iter().map(map_event_bus_resource));
+                next_token = output.next_token().map(str::to_string);
+            }
+            Err(e) => {
+                tracing::error!("Error listing event buses: {:?}", e);
+                break;
+            }
+        }
+        if next_token.is_none() {
+            break;
+        }
+    }
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get event bus detail (rules, schedules, event patterns and targets) using AWS SDK
+pub fn get_event_bus_detail(event_bus_name: &str) -> Option<EventBusDetail> {
+    get_runtime().block_on(get_event_bus_detail_async(event_bus_name))
+}
+
+async fn get_event_bus_detail_async(event_bus_name: &str) -> Option<EventBusDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_eventbridge, &config);
+
+    let output = match client
+        .describe_event_bus()
+        .name(event_bus_name)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing event bus {}: {:?}", event_bus_name, e);
+            return None;
+        }
+    };
+    let mut detail = map_event_bus_detail(&output);
+
+    for rule in list_rules(&client, event_bus_name).await {
+        let targets = list_targets(&client, event_bus_name, rule.name().unwrap_or_default()).await;
+        detail.rules.push(map_rule(&rule, &targets));
+    }
+    detail.rules.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_tags_for_resource()
+        .resource_arn(&detail.arn)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            let mut tags: Vec<(String, String)> = output
+                .tags()
+                .iter()
+                .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error getting tags for {}: {:?}", detail.arn, e),
+    }
+
+    Some(detail)
+}
+
+async fn list_rules(client: &Client, event_bus_name: &str) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        match client
+            .list_rules()
+            .event_bus_name(event_bus_name)
+            .set_next_token(next_token.take())
+            .send()
+            .await
+        {
+            Ok(output) => {
+                rules.extend(output.rules().iter().cloned());
+                next_token = output.next_token().map(str::to_string);
+            }
+            Err(e) => {
+                tracing::warn!("Error listing rules for {}: {:?}", event_bus_name, e);
+                break;
+            }
+        }
+        if next_token.is_none() {
+            break;
+        }
+    }
+    rules
+}
+
+// 대상 조회 실패는 해당 규칙만 대상 없이 표시
+async fn list_targets(client: &Client, event_bus_name: &str, rule_name: &str) -> Vec<Target> {
+    let mut targets = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        match client
+            .list_targets_by_rule()
+            .event_bus_name(event_bus_name)
+            .rule(rule_name)
+            .set_next_token(next_token.take())
+            .send()
+            .await
+        {
+            Ok(output) => {
+                targets.extend(output.targets().iter().cloned());
+                next_token = output.next_token().map(str::to_string);
+            }
+            Err(e) => {
+                tracing::warn!("Error listing targets for rule {}: {:?}", rule_name, e);
+                break;
+            }
+        }
+        if next_token.is_none() {
+            break;
+        }
+    }
+    targets
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|timestamp| timestamp.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 설명을 함께 표시하고, 상세 조회는 버스 이름으로 함
+fn map_event_bus_resource(event_bus: &EventBus) -> AwsResource {
+    let name = event_bus.name().unwrap_or_default().to_string();
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: String::new(),
+        az: String::new(),
+        cidr: event_bus.description().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_event_bus_detail(output: &DescribeEventBusOutput) -> EventBusDetail {
+    EventBusDetail {
+        name: output.name().unwrap_or_default().to_string(),
+        arn: output.arn().unwrap_or_default().to_string(),
+        description: output.description().unwrap_or_default().to_string(),
+        kms_key_id: output
+            .kms_key_identifier()
+            .filter(|key| !key.is_empty())
+            .map(str::to_string),
+        created: format_timestamp(output.creation_time()),
+        rules: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_rule(rule: &Rule, targets: &[Target]) -> EventRuleDetail {
+    let mut targets: Vec<EventTarget> = targets.iter().map(map_target).collect();
+    targets.sort_by(|a, b| a.id.cmp(&b.id));
+
+    EventRuleDetail {
+        name: rule.name().unwrap_or_default().to_string(),
+        state: rule
+            .state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        description: rule.description().unwrap_or_default().to_string(),
+        schedule_expression: rule.schedule_expression().unwrap_or_default().to_string(),
+        event_pattern: rule
+            .event_pattern()
+            .map(format_event_pattern)
+            .unwrap_or_default(),
+        role_arn: rule.role_arn().unwrap_or_default().to_string(),
+        managed_by: rule.managed_by().unwrap_or_default().to_string(),
+        targets,
+    }
+}
+
+fn map_target(target: &Target) -> EventTarget {
+    let input = if let Some(transformer) = target.input_transformer() {
+        TargetInput::Transformer(transformer.input_template().to_string())
+    } else if let Some(path) = target.input_path() {
+        TargetInput::Path(path.to_string())
+    } else if let Some(input) = target.input() {
+        TargetInput::Constant(input.to_string())
+    } else {
+        TargetInput::MatchedEvent
+    };
+
+    EventTarget {
+        id: target.id().to_string(),
+        arn: target.arn().to_string(),
+        role_arn: target.role_arn().unwrap_or_default().to_string(),
+        input,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_event_bus_detail, map_event_bus_resource, map_rule};
+    use crate::aws_cli::eventbridge::TargetInput;
+    use aws_sdk_eventbridge::operation::describe_event_bus::DescribeEventBusOutput;
+    use aws_sdk_eventbridge::primitives::DateTime;
+    use aws_sdk_eventbridge::types::{EventBus, InputTransformer, Rule, RuleState, Target};
+
+    const ARN: &str = "arn:aws:events:ap-northeast-2:123456789012:event-bus/orders";
+
+    #[test]
+    fn map_event_bus_resource_and_detail_use_bus_name() {
+        let resource = map_event_bus_resource(
+            &EventBus::builder()
+                .name("orders")
+                .arn(ARN)
+                .description("Order domain events")
+                .build(),
+        );
+        assert_eq!(resource.name, "orders");
+        assert_eq!(resource.id, "orders");
+        assert_eq!(resource.cidr, "Order domain events");
+
+        let detail = map_event_bus_detail(
+            &DescribeEventBusOutput::builder()
+                .name("orders")
+                .arn(ARN)
+                .kms_key_identifier("alias/events")
+                .creation_time(DateTime::from_secs(1_767_225_600))
+                .build(),
+        );
+        assert_eq!(detail.arn, ARN);
+        assert_eq!(detail.kms_key_id.as_deref(), Some("alias/events"));
+        assert_eq!(detail.created, "2026-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn map_rule_reads_pattern_and_target_inputs() {
+        let rule = Rule::builder()
+            .name("order-created")
+            .state(RuleState::Enabled)
+            .event_pattern(r#"{"source":["orders"]}"#)
+            .build();
+        let targets = [
+            Target::builder()
+                .id("b-transform")
+                .arn("arn:aws:sqs:ap-northeast-2:123456789012:order-audit")
+                .input_transformer(
+                    InputTransformer::builder()
+                        .input_template(r#"{"id": <id>}"#)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+            Target::builder()
+                .id("a-path")
+                .arn("arn:aws:lambda:ap-northeast-2:123456789012:function:notify")
+                .input_path("$.detail")
+                .build()
+                .unwrap(),
+            Target::builder()
+                .id("c-event")
+                .arn("arn:aws:sns:ap-northeast-2:123456789012:orders")
+                .build()
+                .unwrap(),
+        ];
+
+        let detail = map_rule(&rule, &targets);
+        assert_eq!(detail.state, "ENABLED");
+        assert!(detail.schedule_expression.is_empty());
+        assert!(detail.event_pattern.contains("\"source\": ["));
+        let ids: Vec<&str> = detail.targets.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["a-path", "b-transform", "c-event"]);
+        assert_eq!(
+            detail.targets[0].input,
+            TargetInput::Path("$.detail".to_string())
+        );
+        assert_eq!(
+            detail.targets[1].input,
+            TargetInput::Transformer(r#"{"id": <id>}"#.to_string())
+        );
+        assert_eq!(detail.targets[2].input, TargetInput::MatchedEvent);
+    }
+}
@@ -0,0 +1,419 @@
+pub use crate::aws_cli::glue_sdk::{get_glue_detail, list_glue_resources};
+use crate::aws_cli::partition::Partition;
+use crate::cron;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct GlueDetail {
+    // ARN의 리소스 부분과 같은 "database/<이름>" 또는 "crawler/<이름>"
+    pub id: String,
+    pub name: String,
+    // 계정 ID를 아직 모르면 빈 문자열
+    pub arn: String,
+    pub description: String,
+    pub created: String,
+    pub resource: GlueResource,
+    pub tags: Vec<(String, String)>,
+}
+
+/// Data Catalog databases hold tables; crawlers keep those tables in sync with the data.
+#[derive(Debug, Serialize)]
+pub enum GlueResource {
+    Database(GlueDatabaseDetail),
+    Crawler(Box<GlueCrawlerDetail>),
+}
+
+#[derive(Debug, Serialize)]
+pub struct GlueDatabaseDetail {
+    pub location_uri: String,
+    pub tables: Vec<GlueTable>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GlueTable {
+    pub name: String,
+    // EXTERNAL_TABLE, VIRTUAL_VIEW, GOVERNED
+    pub table_type: String,
+    // parquet, json, csv, Iceberg ...
+    pub format: String,
+    pub location: String,
+    pub columns: usize,
+    pub partition_keys: Vec<String>,
+    pub updated: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GlueCrawlerDetail {
+    // READY, RUNNING, STOPPING
+    pub state: String,
+    pub role: String,
+    pub database: String,
+    pub table_prefix: String,
+    // cron(...), 온디맨드 크롤러면 빈 문자열
+    pub schedule: String,
+    // SCHEDULED, NOT_SCHEDULED, TRANSITIONING
+    pub schedule_state: String,
+    pub last_crawl_status: String,
+    pub last_crawl_time: String,
+    pub last_crawl_error: String,
+    // UPDATE_IN_DATABASE, LOG ...
+    pub update_behavior: String,
+    // DEPRECATE_IN_DATABASE, DELETE_FROM_DATABASE, LOG
+    pub delete_behavior: String,
+    pub last_updated: String,
+    pub targets: Vec<CrawlerTarget>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CrawlerTarget {
+    // S3, JDBC, DynamoDB, Catalog, Delta, Iceberg, Hudi, MongoDB
+    pub kind: String,
+    pub path: String,
+    pub exclusions: Vec<String>,
+}
+
+/// List and detail IDs carry the resource kind: `database/sales`, `crawler/sales-raw`.
+pub fn glue_resource_id(kind: &str, name: &str) -> String {
+    format!("{}/{}", kind, name)
+}
+
+/// `database/sales` → `("database", "sales")`.
+pub fn split_glue_resource_id(id: &str) -> Option<(&str, &str)> {
+    id.split_once('/')
+        .filter(|(kind, name)| matches!(*kind, "database" | "crawler") && !name.is_empty())
+}
+
+/// ARN of a database or crawler for the Glue tagging API.
+pub fn glue_resource_arn(id: &str, region: &str, account: &str) -> String {
+    format!(
+        "arn:{}:glue:{}:{}:{}",
+        Partition::from_region(region).as_str(),
+        region,
+        account,
+        id
+    )
+}
+
+/// Data format shown for a catalog table, from the crawler classification or the SerDe.
+pub fn table_format(table_type_parameter: &str, classification: &str, serde: &str) -> String {
+    if table_type_parameter.eq_ignore_ascii_case("ICEBERG") {
+        return "Iceberg".to_string();
+    }
+    if !classification.is_empty() {
+        return classification.to_string();
+    }
+    // org.apache.hadoop.hive.ql.io.parquet.serde.ParquetHiveSerDe → ParquetHiveSerDe
+    serde.rsplit('.').next().unwrap_or_default().to_string()
+}
+
+fn or_dash(value: &str) -> String {
+    if value.is_empty() {
+        "-".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+impl GlueDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let title = match self.resource {
+            GlueResource::Database(_) => i18n.glue_database(),
+            GlueResource::Crawler(_) => i18n.glue_crawler(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+        ];
+        if !self.arn.is_empty() {
+            lines.push(format!("| ARN | {} |", self.arn));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+
+        match &self.resource {
+            GlueResource::Database(database) => database.push_markdown(&mut lines, &i18n),
+            GlueResource::Crawler(crawler) => crawler.push_markdown(&mut lines, &i18n),
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+impl GlueDatabaseDetail {
+    fn push_markdown(&self, lines: &mut Vec<String>, i18n: &I18n) {
+        if !self.location_uri.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_location(),
+                self.location_uri
+            ));
+        }
+
+        if self.tables.is_empty() {
+            return;
+        }
+        lines.push(format!(
+            "\n### {} ({})\n",
+            i18n.md_tables(),
+            locale::count(self.tables.len() as i64)
+        ));
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            i18n.md_name(),
+            i18n.md_type(),
+            i18n.md_data_format(),
+            i18n.md_location(),
+            i18n.md_columns(),
+            i18n.md_partition_keys(),
+            i18n.md_last_updated()
+        ));
+        lines.push("|:---|:---|:---|:---|---:|:---|:---|".to_string());
+        for table in &self.tables {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                table.name,
+                or_dash(&table.table_type),
+                or_dash(&table.format),
+                or_dash(&table.location),
+                locale::count(table.columns as i64),
+                or_dash(&table.partition_keys.join(", ")),
+                if table.updated.is_empty() {
+                    "-".to_string()
+                } else {
+                    locale::date(&table.updated)
+                }
+            ));
+        }
+    }
+}
+
+impl GlueCrawlerDetail {
+    fn push_markdown(&self, lines: &mut Vec<String>, i18n: &I18n) {
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_state(),
+            or_dash(&self.state)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_iam_role(),
+            or_dash(&self.role)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_database(),
+            or_dash(&self.database)
+        ));
+        if !self.table_prefix.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_table_prefix(),
+                self.table_prefix
+            ));
+        }
+        // 일정이 없으면 온디맨드 실행
+        let schedule = if self.schedule.is_empty() {
+            "-".to_string()
+        } else if self.schedule_state.is_empty() {
+            cron::schedule_text(&self.schedule, i18n)
+        } else {
+            format!(
+                "{} ({})",
+                cron::schedule_text(&self.schedule, i18n),
+                self.schedule_state
+            )
+        };
+        lines.push(format!("| {} | {} |", i18n.asg_schedule(), schedule));
+        if !self.last_crawl_status.is_empty() {
+            let last_crawl = if self.last_crawl_time.is_empty() {
+                self.last_crawl_status.clone()
+            } else {
+                format!(
+                    "{} ({})",
+                    self.last_crawl_status,
+                    locale::date(&self.last_crawl_time)
+                )
+            };
+            lines.push(format!("| {} | {} |", i18n.md_last_crawl(), last_crawl));
+        }
+        if !self.last_crawl_error.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_crawl_error(),
+                self.last_crawl_error
+            ));
+        }
+        if !self.update_behavior.is_empty() || !self.delete_behavior.is_empty() {
+            lines.push(format!(
+                "| {} | {} / {} |",
+                i18n.md_schema_change_policy(),
+                or_dash(&self.update_behavior),
+                or_dash(&self.delete_behavior)
+            ));
+        }
+        if !self.last_updated.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_updated(),
+                locale::date(&self.last_updated)
+            ));
+        }
+
+        if self.targets.is_empty() {
+            return;
+        }
+        lines.push(format!("\n### {}\n", i18n.md_crawler_targets()));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.md_type(),
+            i18n.md_path(),
+            i18n.md_exclusions()
+        ));
+        lines.push("|:---|:---|:---|".to_string());
+        for target in &self.targets {
+            lines.push(format!(
+                "| {} | {} | {} |",
+                target.kind,
+                or_dash(&target.path),
+                or_dash(&target.exclusions.join(", "))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CrawlerTarget, GlueCrawlerDetail, GlueDatabaseDetail, GlueDetail, GlueResource, GlueTable,
+        glue_resource_arn, glue_resource_id, split_glue_resource_id, table_format,
+    };
+    use crate::i18n::Language;
+
+    #[test]
+    fn resource_id_and_table_format_helpers() {
+        assert_eq!(glue_resource_id("database", "sales"), "database/sales");
+        assert_eq!(
+            split_glue_resource_id("crawler/sales-raw"),
+            Some(("crawler", "sales-raw"))
+        );
+        assert_eq!(split_glue_resource_id("table/sales/orders"), None);
+        assert_eq!(split_glue_resource_id("database/"), None);
+        assert_eq!(
+            glue_resource_arn("database/sales", "ap-northeast-2", "123456789012"),
+            "arn:aws:glue:ap-northeast-2:123456789012:database/sales"
+        );
+
+        assert_eq!(table_format("ICEBERG", "", ""), "Iceberg");
+        assert_eq!(table_format("", "parquet", ""), "parquet");
+        assert_eq!(
+            table_format("", "", "org.openx.data.jsonserde.JsonSerDe"),
+            "JsonSerDe"
+        );
+    }
+
+    #[test]
+    fn database_markdown_lists_tables() {
+        let detail = GlueDetail {
+            id: "database/sales".to_string(),
+            name: "sales".to_string(),
+            arn: "arn:aws:glue:ap-northeast-2:123456789012:database/sales".to_string(),
+            description: "Sales data lake".to_string(),
+            created: String::new(),
+            resource: GlueResource::Database(GlueDatabaseDetail {
+                location_uri: "s3://sales-lake/".to_string(),
+                tables: vec![GlueTable {
+                    name: "orders".to_string(),
+                    table_type: "EXTERNAL_TABLE".to_string(),
+                    format: "parquet".to_string(),
+                    location: "s3://sales-lake/orders/".to_string(),
+                    columns: 12,
+                    partition_keys: vec!["year".to_string(), "month".to_string()],
+                    updated: String::new(),
+                }],
+            }),
+            tags: vec![("Team".to_string(), "data".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Glue Database (sales)"));
+        assert!(md.contains("| Location | s3://sales-lake/ |"));
+        assert!(md.contains("### Tables (1)"));
+        assert!(md.contains(
+            "| orders | EXTERNAL_TABLE | parquet | s3://sales-lake/orders/ | 12 | year, month | - |"
+        ));
+        assert!(md.contains("| Team | data |"));
+    }
+
+    #[test]
+    fn crawler_markdown_shows_schedule_policy_and_targets() {
+        let detail = GlueDetail {
+            id: "crawler/sales-raw".to_string(),
+            name: "sales-raw".to_string(),
+            arn: String::new(),
+            description: String::new(),
+            created: String::new(),
+            resource: GlueResource::Crawler(Box::new(GlueCrawlerDetail {
+                state: "READY".to_string(),
+                role: "arn:aws:iam::123456789012:role/glue-crawler".to_string(),
+                database: "sales".to_string(),
+                table_prefix: "raw_".to_string(),
+                schedule: "cron(0 3 * * ? *)".to_string(),
+                schedule_state: "SCHEDULED".to_string(),
+                last_crawl_status: "FAILED".to_string(),
+                last_crawl_time: String::new(),
+                last_crawl_error: "Access denied".to_string(),
+                update_behavior: "UPDATE_IN_DATABASE".to_string(),
+                delete_behavior: "DEPRECATE_IN_DATABASE".to_string(),
+                last_updated: String::new(),
+                targets: vec![CrawlerTarget {
+                    kind: "S3".to_string(),
+                    path: "s3://sales-lake/raw/".to_string(),
+                    exclusions: vec!["**/_tmp/**".to_string()],
+                }],
+            })),
+            tags: vec![],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Glue Crawler (sales-raw)"));
+        assert!(!md.contains("| ARN |"));
+        assert!(md.contains("| Table Prefix | raw_ |"));
+        assert!(md.contains("| Schedule | `cron(0 3 * * ? *)` (SCHEDULED) |"));
+        assert!(md.contains("| Last Crawl | FAILED |"));
+        assert!(md.contains("| Last Crawl Error | Access denied |"));
+        assert!(
+            md.contains("| Schema Change Policy | UPDATE_IN_DATABASE / DEPRECATE_IN_DATABASE |")
+        );
+        assert!(md.contains("| S3 | s3://sales-lake/raw/ | **/_tmp/** |"));
+    }
+}
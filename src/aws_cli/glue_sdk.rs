@@ -0,0 +1,462 @@
+use crate::aws_cli::common::{
+    AwsResource, current_account_id, current_region, get_runtime, get_sdk_config, sdk_client,
+};
+use crate::aws_cli::glue::{
+    CrawlerTarget, GlueCrawlerDetail, GlueDatabaseDetail, GlueDetail, GlueResource, GlueTable,
+    glue_resource_arn, glue_resource_id, split_glue_resource_id, table_format,
+};
+use aws_sdk_glue::Client;
+use aws_sdk_glue::operation::get_crawlers::GetCrawlersOutput;
+use aws_sdk_glue::operation::get_databases::GetDatabasesOutput;
+use aws_sdk_glue::operation::get_tables::GetTablesOutput;
+use aws_sdk_glue::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_glue::types::{Crawler, CrawlerTargets, Database, Table};
+
+/// List Glue Data Catalog databases and crawlers using AWS SDK
+pub fn list_glue_resources() -> Vec<AwsResource> {
+    get_runtime().block_on(list_glue_resources_async())
+}
+
+async fn list_glue_resources_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_glue, &config);
+
+    let mut resources = Vec::new();
+    let databases: Result<Vec<GetDatabasesOutput>, _> = client
+        .get_databases()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await;
+    match databases {
+        Ok(pages) => resources.extend(
+            pages
+                .iter()
+                .flat_map(|page| page.database_list())
+                .map(map_database_resource),
+        ),
+        Err(e) => tracing::error!("Error listing Glue databases: {:?}", e),
+    }
+    let crawlers: Result<Vec<GetCrawlersOutput>, _> = client
+        .get_crawlers()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await;
+    match crawlers {
+        Ok(pages) => resources.extend(
+            pages
+                .iter()
+                .flat_map(|page| page.crawlers())
+                .map(map_crawler_resource),
+        ),
+        Err(e) => tracing::error!("Error listing Glue crawlers: {:?}", e),
+    }
+
+    // 데이터베이스 → 크롤러, 각각 이름 순
+    resources.sort_by(|a, b| b.state.cmp(&a.state).then_with(|| a.name.cmp(&b.name)));
+    resources
+}
+
+/// Get Glue database (with its tables) or crawler detail using AWS SDK
+pub fn get_glue_detail(id: &str) -> Option<GlueDetail> {
+    get_runtime().block_on(get_glue_detail_async(id))
+}
+
+async fn get_glue_detail_async(id: &str) -> Option<GlueDetail> {
+    let (kind, name) = split_glue_resource_id(id)?;
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_glue, &config);
+
+    let mut detail = if kind == "crawler" {
+        let output = match client.get_crawler().name(name).send().await {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::error!("Error getting Glue crawler {}: {:?}", name, e);
+                return None;
+            }
+        };
+        map_crawler_detail(output.crawler()?)
+    } else {
+        let output = match client.get_database().name(name).send().await {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::error!("Error getting Glue database {}: {:?}", name, e);
+                return None;
+            }
+        };
+        let tables = list_tables(&client, name).await;
+        map_database_detail(output.database()?, tables)
+    };
+
+    // 태그 API는 ARN만 받으므로 계정 ID를 알 때만 조회
+    if let Some(account) = current_account_id() {
+        detail.arn = glue_resource_arn(&detail.id, &current_region(), &account);
+        match client.get_tags().resource_arn(&detail.arn).send().await {
+            Ok(output) => {
+                let mut tags: Vec<(String, String)> = output
+                    .tags()
+                    .map(|tags| {
+                        tags.iter()
+                            .map(|(key, value)| (key.clone(), value.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                tags.sort();
+                detail.tags = tags;
+            }
+            Err(e) => tracing::warn!("Error getting tags for {}: {:?}", detail.arn, e),
+        }
+    }
+
+    Some(detail)
+}
+
+async fn list_tables(client: &Client, database: &str) -> Vec<GlueTable> {
+    let pages: Vec<GetTablesOutput> = match client
+        .get_tables()
+        .database_name(database)
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => pages,
+        Err(e) => {
+            tracing::warn!("Error listing tables of {}: {:?}", database, e);
+            return Vec::new();
+        }
+    };
+    let mut tables: Vec<GlueTable> = pages
+        .iter()
+        .flat_map(|page| page.table_list())
+        .map(map_table)
+        .collect();
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+    tables
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 종류(state)와 설명(cidr)을 표시
+fn map_database_resource(database: &Database) -> AwsResource {
+    AwsResource {
+        name: database.name().to_string(),
+        id: glue_resource_id("database", database.name()),
+        state: "Database".to_string(),
+        az: String::new(),
+        cidr: database.description().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_crawler_resource(crawler: &Crawler) -> AwsResource {
+    let name = crawler.name().unwrap_or_default();
+    AwsResource {
+        name: name.to_string(),
+        id: glue_resource_id("crawler", name),
+        state: "Crawler".to_string(),
+        az: String::new(),
+        cidr: crawler.description().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_table(table: &Table) -> GlueTable {
+    let parameter = |key: &str| {
+        table
+            .parameters()
+            .and_then(|parameters| parameters.get(key))
+            .map(String::as_str)
+            .unwrap_or_default()
+    };
+    let storage = table.storage_descriptor();
+    GlueTable {
+        name: table.name().to_string(),
+        table_type: table.table_type().unwrap_or_default().to_string(),
+        format: table_format(
+            parameter("table_type"),
+            parameter("classification"),
+            storage
+                .and_then(|storage| storage.serde_info())
+                .and_then(|serde| serde.serialization_library())
+                .unwrap_or_default(),
+        ),
+        location: storage
+            .and_then(|storage| storage.location())
+            .unwrap_or_default()
+            .to_string(),
+        columns: storage.map(|storage| storage.columns().len()).unwrap_or(0),
+        partition_keys: table
+            .partition_keys()
+            .iter()
+            .map(|column| column.name().to_string())
+            .collect(),
+        updated: format_timestamp(table.update_time()),
+    }
+}
+
+fn map_database_detail(database: &Database, tables: Vec<GlueTable>) -> GlueDetail {
+    GlueDetail {
+        id: glue_resource_id("database", database.name()),
+        name: database.name().to_string(),
+        arn: String::new(),
+        description: database.description().unwrap_or_default().to_string(),
+        created: format_timestamp(database.create_time()),
+        resource: GlueResource::Database(GlueDatabaseDetail {
+            location_uri: database.location_uri().unwrap_or_default().to_string(),
+            tables,
+        }),
+        tags: Vec::new(),
+    }
+}
+
+fn map_crawler_targets(targets: &CrawlerTargets) -> Vec<CrawlerTarget> {
+    let target = |kind: &str, path: &str, exclusions: &[String]| CrawlerTarget {
+        kind: kind.to_string(),
+        path: path.to_string(),
+        exclusions: exclusions.to_vec(),
+    };
+    let mut result = Vec::new();
+    for s3 in targets.s3_targets() {
+        result.push(target("S3", s3.path().unwrap_or_default(), s3.exclusions()));
+    }
+    // JDBC 경로는 "데이터베이스/스키마/테이블" 형식이라 연결 이름을 앞에 붙임
+    for jdbc in targets.jdbc_targets() {
+        let path = format!(
+            "{}: {}",
+            jdbc.connection_name().unwrap_or_default(),
+            jdbc.path().unwrap_or_default()
+        );
+        result.push(target("JDBC", &path, jdbc.exclusions()));
+    }
+    for dynamodb in targets.dynamo_db_targets() {
+        result.push(target("DynamoDB", dynamodb.path().unwrap_or_default(), &[]));
+    }
+    for catalog in targets.catalog_targets() {
+        let path = format!(
+            "{}: {}",
+            catalog.database_name(),
+            catalog.tables().join(", ")
+        );
+        result.push(target("Catalog", &path, &[]));
+    }
+    for delta in targets.delta_targets() {
+        for path in delta.delta_tables() {
+            result.push(target("Delta", path, &[]));
+        }
+    }
+    for iceberg in targets.iceberg_targets() {
+        for path in iceberg.paths() {
+            result.push(target("Iceberg", path, iceberg.exclusions()));
+        }
+    }
+    for hudi in targets.hudi_targets() {
+        for path in hudi.paths() {
+            result.push(target("Hudi", path, hudi.exclusions()));
+        }
+    }
+    for mongodb in targets.mongo_db_targets() {
+        let path = format!(
+            "{}: {}",
+            mongodb.connection_name().unwrap_or_default(),
+            mongodb.path().unwrap_or_default()
+        );
+        result.push(target("MongoDB", &path, &[]));
+    }
+    result
+}
+
+fn map_crawler_detail(crawler: &Crawler) -> GlueDetail {
+    let name = crawler.name().unwrap_or_default();
+    let schedule = crawler.schedule();
+    let last_crawl = crawler.last_crawl();
+    let policy = crawler.schema_change_policy();
+    GlueDetail {
+        id: glue_resource_id("crawler", name),
+        name: name.to_string(),
+        arn: String::new(),
+        description: crawler.description().unwrap_or_default().to_string(),
+        created: format_timestamp(crawler.creation_time()),
+        resource: GlueResource::Crawler(Box::new(GlueCrawlerDetail {
+            state: crawler
+                .state()
+                .map(|state| state.as_str().to_string())
+                .unwrap_or_default(),
+            role: crawler.role().unwrap_or_default().to_string(),
+            database: crawler.database_name().unwrap_or_default().to_string(),
+            table_prefix: crawler.table_prefix().unwrap_or_default().to_string(),
+            schedule: schedule
+                .and_then(|schedule| schedule.schedule_expression())
+                .unwrap_or_default()
+                .to_string(),
+            schedule_state: schedule
+                .and_then(|schedule| schedule.state())
+                .map(|state| state.as_str().to_string())
+                .unwrap_or_default(),
+            last_crawl_status: last_crawl
+                .and_then(|last_crawl| last_crawl.status())
+                .map(|status| status.as_str().to_string())
+                .unwrap_or_default(),
+            last_crawl_time: format_timestamp(
+                last_crawl.and_then(|last_crawl| last_crawl.start_time()),
+            ),
+            last_crawl_error: last_crawl
+                .and_then(|last_crawl| last_crawl.error_message())
+                .unwrap_or_default()
+                .to_string(),
+            update_behavior: policy
+                .and_then(|policy| policy.update_behavior())
+                .map(|behavior| behavior.as_str().to_string())
+                .unwrap_or_default(),
+            delete_behavior: policy
+                .and_then(|policy| policy.delete_behavior())
+                .map(|behavior| behavior.as_str().to_string())
+                .unwrap_or_default(),
+            last_updated: format_timestamp(crawler.last_updated()),
+            targets: crawler
+                .targets()
+                .map(map_crawler_targets)
+                .unwrap_or_default(),
+        })),
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_crawler_detail, map_crawler_resource, map_database_resource, map_table};
+    use crate::aws_cli::glue::GlueResource;
+    use aws_sdk_glue::types::{
+        CatalogTarget, Column, Crawler, CrawlerState, CrawlerTargets, Database, JdbcTarget,
+        LastCrawlInfo, LastCrawlStatus, S3Target, Schedule, ScheduleState, SerDeInfo,
+        StorageDescriptor, Table,
+    };
+
+    fn column(name: &str) -> Column {
+        Column::builder().name(name).build().unwrap()
+    }
+
+    #[test]
+    fn map_resources_prefix_id_with_kind() {
+        let database = map_database_resource(
+            &Database::builder()
+                .name("sales")
+                .description("Sales data lake")
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(database.id, "database/sales");
+        assert_eq!(database.state, "Database");
+        assert_eq!(database.cidr, "Sales data lake");
+
+        let crawler = map_crawler_resource(&Crawler::builder().name("sales-raw").build());
+        assert_eq!(crawler.id, "crawler/sales-raw");
+        assert_eq!(crawler.state, "Crawler");
+    }
+
+    #[test]
+    fn map_table_reads_format_columns_and_partitions() {
+        let table = Table::builder()
+            .name("orders")
+            .table_type("EXTERNAL_TABLE")
+            .parameters("classification", "parquet")
+            .storage_descriptor(
+                StorageDescriptor::builder()
+                    .location("s3://sales-lake/orders/")
+                    .columns(column("order_id"))
+                    .columns(column("amount"))
+                    .serde_info(
+                        SerDeInfo::builder()
+                            .serialization_library(
+                                "org.apache.hadoop.hive.ql.io.parquet.serde.ParquetHiveSerDe",
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .partition_keys(column("year"))
+            .build()
+            .unwrap();
+
+        let table = map_table(&table);
+        assert_eq!(table.format, "parquet");
+        assert_eq!(table.location, "s3://sales-lake/orders/");
+        assert_eq!(table.columns, 2);
+        assert_eq!(table.partition_keys, ["year"]);
+    }
+
+    #[test]
+    fn map_crawler_detail_reads_schedule_last_crawl_and_targets() {
+        let crawler = Crawler::builder()
+            .name("sales-raw")
+            .role("glue-crawler")
+            .database_name("sales")
+            .state(CrawlerState::Ready)
+            .schedule(
+                Schedule::builder()
+                    .schedule_expression("cron(0 3 * * ? *)")
+                    .state(ScheduleState::Scheduled)
+                    .build(),
+            )
+            .last_crawl(
+                LastCrawlInfo::builder()
+                    .status(LastCrawlStatus::Failed)
+                    .error_message("Access denied")
+                    .build(),
+            )
+            .targets(
+                CrawlerTargets::builder()
+                    .s3_targets(
+                        S3Target::builder()
+                            .path("s3://sales-lake/raw/")
+                            .exclusions("**/_tmp/**")
+                            .build(),
+                    )
+                    .jdbc_targets(
+                        JdbcTarget::builder()
+                            .connection_name("orders-db")
+                            .path("orders/public/%")
+                            .build(),
+                    )
+                    .catalog_targets(
+                        CatalogTarget::builder()
+                            .database_name("sales")
+                            .tables("orders")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        let detail = map_crawler_detail(&crawler);
+        assert_eq!(detail.id, "crawler/sales-raw");
+        let GlueResource::Crawler(crawler) = &detail.resource else {
+            panic!("crawler expected");
+        };
+        assert_eq!(crawler.state, "READY");
+        assert_eq!(crawler.schedule, "cron(0 3 * * ? *)");
+        assert_eq!(crawler.schedule_state, "SCHEDULED");
+        assert_eq!(crawler.last_crawl_status, "FAILED");
+        assert_eq!(crawler.last_crawl_error, "Access denied");
+        let targets: Vec<(&str, &str)> = crawler
+            .targets
+            .iter()
+            .map(|target| (target.kind.as_str(), target.path.as_str()))
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                ("S3", "s3://sales-lake/raw/"),
+                ("JDBC", "orders-db: orders/public/%"),
+                ("Catalog", "sales: orders"),
+            ]
+        );
+        assert_eq!(crawler.targets[0].exclusions, ["**/_tmp/**"]);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::aws_cli::common::{extract_json_value, run_aws_cli};
-use serde::Deserialize;
+pub use crate::aws_cli::iam_sdk::{get_iam_entity_detail, list_iam_entities};
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 #[allow(dead_code)]
 pub struct IamRoleDetail {
     pub name: String,
@@ -11,19 +15,256 @@ pub struct IamRoleDetail {
     pub inline_policies: Vec<InlinePolicy>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AttachedPolicy {
     pub name: String,
     pub arn: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InlinePolicy {
     pub name: String,
     pub document: String,
 }
 
+/// IAM role, user, group or customer managed policy with its permissions.
+#[derive(Debug, Clone, Serialize)]
+pub struct IamEntityDetail {
+    // Role, User, Group, Policy
+    pub kind: String,
+    pub name: String,
+    pub arn: String,
+    pub path: String,
+    pub description: String,
+    pub created: String,
+    // 역할: 마지막 사용 시각, 사용자: 콘솔 비밀번호 마지막 사용 시각
+    pub last_used: String,
+    pub permissions_boundary: String,
+    // 역할의 신뢰 정책 (디코딩된 JSON)
+    pub trust_policy: String,
+    pub attached_policies: Vec<IamManagedPolicy>,
+    pub inline_policies: Vec<InlinePolicy>,
+    // 사용자: 소속 그룹, 그룹: 멤버 사용자
+    pub groups: Vec<String>,
+    pub members: Vec<String>,
+    pub access_keys: Vec<IamAccessKey>,
+    // 관리형 정책: 기본 버전과 그 문서, 연결된 엔터티 수
+    pub default_version: String,
+    pub policy_document: String,
+    pub attachment_count: Option<i32>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IamManagedPolicy {
+    pub name: String,
+    pub arn: String,
+    // 고객 관리형 정책만 문서를 조회 (AWS 관리형 정책은 빈 문자열)
+    pub document: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IamAccessKey {
+    pub id: String,
+    // Active, Inactive
+    pub status: String,
+    pub created: String,
+}
+
+/// URL-decode an IAM policy document and pretty-print it as JSON.
+pub fn decode_policy_document(document: &str) -> String {
+    let decoded = percent_encoding::percent_decode_str(document)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or_else(|_| document.to_string());
+    serde_json::from_str::<serde_json::Value>(&decoded)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or(decoded)
+}
+
+fn push_json_block(lines: &mut Vec<String>, document: &str) {
+    lines.push("```json".to_string());
+    lines.push(document.to_string());
+    lines.push("```".to_string());
+    lines.push(String::new());
+}
+
+impl IamEntityDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let title = match self.kind.as_str() {
+            "User" => i18n.md_iam_user(),
+            "Group" => i18n.md_iam_group(),
+            "Policy" => i18n.md_iam_policy(),
+            _ => i18n.md_iam_role(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_path(), self.path),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+        if !self.last_used.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_accessed(),
+                self.last_used
+            ));
+        }
+        if !self.permissions_boundary.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_permissions_boundary(),
+                self.permissions_boundary
+            ));
+        }
+        if !self.default_version.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_version(),
+                self.default_version
+            ));
+        }
+        if let Some(count) = self.attachment_count {
+            lines.push(format!("| {} | {} |", i18n.md_attachment_count(), count));
+        }
+        lines.push(String::new());
+
+        // Groups / Members
+        for (title, names) in [
+            (i18n.md_groups(), &self.groups),
+            (i18n.md_members(), &self.members),
+        ] {
+            if !names.is_empty() {
+                lines.push(format!("### {}\n", title));
+                for name in names {
+                    lines.push(format!("- {}", name));
+                }
+                lines.push(String::new());
+            }
+        }
+
+        // Access Keys
+        if !self.access_keys.is_empty() {
+            lines.push(format!("### {}\n", i18n.md_access_keys()));
+            lines.push(format!(
+                "| ID | {} | {} |",
+                i18n.md_state(),
+                i18n.md_created_at()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for key in &self.access_keys {
+                lines.push(format!("| {} | {} | {} |", key.id, key.status, key.created));
+            }
+            lines.push(String::new());
+        }
+
+        // Attached Policies
+        if !self.attached_policies.is_empty() {
+            lines.push(format!("### {}\n", i18n.md_attached_policies()));
+            lines.push(format!("| {} | ARN |", i18n.md_policy_name()));
+            lines.push("|:---|:---|".to_string());
+            for policy in &self.attached_policies {
+                lines.push(format!("| {} | {} |", policy.name, policy.arn));
+            }
+            lines.push(String::new());
+            for policy in self
+                .attached_policies
+                .iter()
+                .filter(|policy| !policy.document.is_empty())
+            {
+                lines.push(format!("**{}**\n", policy.name));
+                push_json_block(&mut lines, &policy.document);
+            }
+        }
+
+        // Inline Policies
+        if !self.inline_policies.is_empty() {
+            lines.push(format!("### {}\n", i18n.md_inline_policies()));
+            for policy in &self.inline_policies {
+                lines.push(format!("**{}**\n", policy.name));
+                push_json_block(&mut lines, &policy.document);
+            }
+        }
+
+        // Trust Policy
+        if !self.trust_policy.is_empty() {
+            lines.push(format!("### {}\n", i18n.md_trust_policy()));
+            push_json_block(&mut lines, &self.trust_policy);
+        }
+
+        // Policy Document
+        if !self.policy_document.is_empty() {
+            lines.push(format!("### {}\n", i18n.md_policy_document()));
+            push_json_block(&mut lines, &self.policy_document);
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+            lines.push(String::new());
+        }
+
+        lines.join("\n")
+    }
+}
+
+// 여러 인스턴스가 같은 역할을 공유하므로 생성 실행 동안 조회 결과(실패 포함)를 재사용
+fn role_cache() -> &'static Mutex<HashMap<String, Option<IamRoleDetail>>> {
+    static ROLES: OnceLock<Mutex<HashMap<String, Option<IamRoleDetail>>>> = OnceLock::new();
+    ROLES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Forget cached role lookups so the next generation run sees fresh policies.
+pub fn clear_iam_role_cache() {
+    if let Ok(mut cache) = role_cache().lock() {
+        cache.clear();
+    }
+}
+
 pub fn get_iam_role_detail(role_name: &str) -> Option<IamRoleDetail> {
+    cached_role_detail(role_name, fetch_iam_role_detail)
+}
+
+fn cached_role_detail<F>(role_name: &str, fetch: F) -> Option<IamRoleDetail>
+where
+    F: FnOnce(&str) -> Option<IamRoleDetail>,
+{
+    if let Some(cached) = role_cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(role_name).cloned())
+    {
+        return cached;
+    }
+
+    let detail = fetch(role_name);
+    if let Ok(mut cache) = role_cache().lock() {
+        cache.insert(role_name.to_string(), detail.clone());
+    }
+    detail
+}
+
+fn fetch_iam_role_detail(role_name: &str) -> Option<IamRoleDetail> {
     // 역할 기본 정보
     let output = run_aws_cli(&[
         "iam",
@@ -198,10 +439,45 @@ where
 #[cfg(test)]
 mod tests {
     use super::{
-        build_iam_role_detail_from_output, build_inline_policies_from_names,
-        extract_assume_role_policy, parse_attached_policies_response,
-        parse_inline_policy_document_response, parse_role_policy_names_response,
+        IamAccessKey, IamEntityDetail, IamManagedPolicy, IamRoleDetail, InlinePolicy,
+        build_iam_role_detail_from_output, build_inline_policies_from_names, cached_role_detail,
+        clear_iam_role_cache, decode_policy_document, extract_assume_role_policy,
+        parse_attached_policies_response, parse_inline_policy_document_response,
+        parse_role_policy_names_response,
     };
+    use crate::i18n::Language;
+    use std::cell::Cell;
+
+    #[test]
+    fn cached_role_detail_fetches_each_role_once_until_cleared() {
+        let calls = Cell::new(0);
+        let fetch = |role_name: &str| {
+            calls.set(calls.get() + 1);
+            (role_name != "cache-test-missing").then(|| IamRoleDetail {
+                name: role_name.to_string(),
+                arn: format!("arn:aws:iam::123456789012:role/{}", role_name),
+                assume_role_policy: String::new(),
+                attached_policies: vec![],
+                inline_policies: vec![],
+            })
+        };
+
+        clear_iam_role_cache();
+        for _ in 0..3 {
+            let detail = cached_role_detail("cache-test-web", fetch).expect("role detail");
+            assert_eq!(detail.name, "cache-test-web");
+        }
+        assert_eq!(calls.get(), 1);
+
+        // 조회 실패도 캐시되어 같은 실행에서 반복 호출하지 않음
+        assert!(cached_role_detail("cache-test-missing", fetch).is_none());
+        assert!(cached_role_detail("cache-test-missing", fetch).is_none());
+        assert_eq!(calls.get(), 2);
+
+        clear_iam_role_cache();
+        assert!(cached_role_detail("cache-test-web", fetch).is_some());
+        assert_eq!(calls.get(), 3);
+    }
 
     #[test]
     fn extract_assume_role_policy_returns_pretty_json() {
@@ -299,4 +575,101 @@ mod tests {
         assert_eq!(policies[0].name, "inline-a");
         assert_eq!(policies[1].name, "inline-c");
     }
+
+    #[test]
+    fn decode_policy_document_url_decodes_and_pretty_prints() {
+        let encoded = "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%5D%7D";
+        let document = decode_policy_document(encoded);
+        assert!(document.starts_with("{\n"));
+        assert!(document.contains("\"Version\": \"2012-10-17\""));
+
+        // JSON이 아니면 디코딩한 문자열 그대로
+        assert_eq!(decode_policy_document("not%20json"), "not json");
+    }
+
+    #[test]
+    fn iam_entity_markdown_shows_policies_and_memberships() {
+        let user = IamEntityDetail {
+            kind: "User".to_string(),
+            name: "deploy".to_string(),
+            arn: "arn:aws:iam::123456789012:user/ci/deploy".to_string(),
+            path: "/ci/".to_string(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_used: String::new(),
+            permissions_boundary: String::new(),
+            trust_policy: String::new(),
+            attached_policies: vec![
+                IamManagedPolicy {
+                    name: "ReadOnlyAccess".to_string(),
+                    arn: "arn:aws:iam::aws:policy/ReadOnlyAccess".to_string(),
+                    document: String::new(),
+                },
+                IamManagedPolicy {
+                    name: "deploy-artifacts".to_string(),
+                    arn: "arn:aws:iam::123456789012:policy/deploy-artifacts".to_string(),
+                    document: "{\n  \"Statement\": []\n}".to_string(),
+                },
+            ],
+            inline_policies: vec![InlinePolicy {
+                name: "assume-deploy".to_string(),
+                document: "{}".to_string(),
+            }],
+            groups: vec!["developers".to_string()],
+            members: vec![],
+            access_keys: vec![IamAccessKey {
+                id: "AKIAEXAMPLE".to_string(),
+                status: "Active".to_string(),
+                created: "2026-01-02T00:00:00Z".to_string(),
+            }],
+            default_version: String::new(),
+            policy_document: String::new(),
+            attachment_count: None,
+            tags: vec![],
+        };
+
+        let markdown = user.to_markdown(Language::English);
+        assert!(markdown.contains("## IAM User (deploy)"));
+        assert!(markdown.contains("| Path | /ci/ |"));
+        assert!(markdown.contains("### Groups\n\n- developers"));
+        assert!(markdown.contains("| AKIAEXAMPLE | Active | 2026-01-02T00:00:00Z |"));
+        assert!(markdown.contains("| ReadOnlyAccess | arn:aws:iam::aws:policy/ReadOnlyAccess |"));
+        // 고객 관리형 정책과 인라인 정책만 문서를 표시
+        assert!(!markdown.contains("**ReadOnlyAccess**"));
+        assert!(
+            markdown.contains("**deploy-artifacts**\n\n```json\n{\n  \"Statement\": []\n}\n```")
+        );
+        assert!(markdown.contains("**assume-deploy**"));
+        assert!(!markdown.contains("### Members"));
+    }
+
+    #[test]
+    fn iam_policy_markdown_shows_default_version_document() {
+        let policy = IamEntityDetail {
+            kind: "Policy".to_string(),
+            name: "app-read".to_string(),
+            arn: "arn:aws:iam::123456789012:policy/app-read".to_string(),
+            path: "/".to_string(),
+            description: "Read app bucket".to_string(),
+            created: String::new(),
+            last_used: String::new(),
+            permissions_boundary: String::new(),
+            trust_policy: String::new(),
+            attached_policies: vec![],
+            inline_policies: vec![],
+            groups: vec![],
+            members: vec![],
+            access_keys: vec![],
+            default_version: "v3".to_string(),
+            policy_document: "{}".to_string(),
+            attachment_count: Some(2),
+            tags: vec![],
+        };
+
+        let markdown = policy.to_markdown(Language::Korean);
+        assert!(markdown.contains("## IAM 정책 (app-read)"));
+        assert!(markdown.contains("| 버전 | v3 |"));
+        assert!(markdown.contains("| 연결된 엔터티 수 | 2 |"));
+        assert!(markdown.contains("### 정책 문서\n\n```json\n{}\n```"));
+    }
 }
@@ -0,0 +1,620 @@
+use crate::aws_cli::Arn;
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::iam::{
+    IamAccessKey, IamEntityDetail, IamManagedPolicy, InlinePolicy, decode_policy_document,
+};
+use aws_sdk_iam::Client;
+use aws_sdk_iam::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_iam::types::{
+    AttachedPermissionsBoundary, AttachedPolicy, Group, Policy, PolicyScopeType, Role, Tag, User,
+};
+
+// 목록 정렬 순서 (역할 → 사용자 → 그룹 → 고객 관리형 정책)
+const KIND_ORDER: [&str; 4] = ["Role", "User", "Group", "Policy"];
+
+/// List IAM roles, users, groups and customer managed policies using AWS SDK
+pub fn list_iam_entities() -> Vec<AwsResource> {
+    get_runtime().block_on(list_iam_entities_async())
+}
+
+async fn list_iam_entities_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_iam, &config);
+
+    let mut resources = Vec::new();
+    match client
+        .list_roles()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(roles) => resources.extend(roles.iter().map(map_role_resource)),
+        Err(e) => tracing::error!("Error listing IAM roles: {:?}", e),
+    }
+    match client
+        .list_users()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(users) => resources.extend(users.iter().map(map_user_resource)),
+        Err(e) => tracing::error!("Error listing IAM users: {:?}", e),
+    }
+    match client
+        .list_groups()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(groups) => resources.extend(groups.iter().map(map_group_resource)),
+        Err(e) => tracing::error!("Error listing IAM groups: {:?}", e),
+    }
+    // AWS 관리형 정책은 수백 개이므로 고객 관리형 정책만 표시
+    match client
+        .list_policies()
+        .scope(PolicyScopeType::Local)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(policies) => resources.extend(policies.iter().map(map_policy_resource)),
+        Err(e) => tracing::error!("Error listing IAM policies: {:?}", e),
+    }
+
+    sort_entities(&mut resources);
+    resources
+}
+
+/// Get IAM role, user, group or policy detail (by ARN) with decoded policy documents using AWS SDK
+pub fn get_iam_entity_detail(arn: &str) -> Option<IamEntityDetail> {
+    get_runtime().block_on(get_iam_entity_detail_async(arn))
+}
+
+async fn get_iam_entity_detail_async(arn: &str) -> Option<IamEntityDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_iam, &config);
+
+    let parsed = Arn::parse(arn)?;
+    // IAM 이름에는 '/'가 없으므로 ARN의 마지막 경로가 이름
+    let name = parsed.resource_suffix();
+    match parsed.resource_type() {
+        "role" => get_role_detail(&client, name).await,
+        "user" => get_user_detail(&client, name).await,
+        "group" => get_group_detail(&client, name).await,
+        "policy" => get_policy_detail(&client, arn).await,
+        _ => None,
+    }
+}
+
+async fn get_role_detail(client: &Client, role_name: &str) -> Option<IamEntityDetail> {
+    let output = match client.get_role().role_name(role_name).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting IAM role {}: {:?}", role_name, e);
+            return None;
+        }
+    };
+    let mut detail = map_role_detail(output.role()?);
+    detail.attached_policies = attached_policies(client, "Role", role_name).await;
+    detail.inline_policies = inline_policies(client, "Role", role_name).await;
+    Some(detail)
+}
+
+async fn get_user_detail(client: &Client, user_name: &str) -> Option<IamEntityDetail> {
+    let output = match client.get_user().user_name(user_name).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting IAM user {}: {:?}", user_name, e);
+            return None;
+        }
+    };
+    let mut detail = map_user_detail(output.user()?);
+    detail.attached_policies = attached_policies(client, "User", user_name).await;
+    detail.inline_policies = inline_policies(client, "User", user_name).await;
+
+    match client
+        .list_groups_for_user()
+        .user_name(user_name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(groups) => {
+            detail.groups = groups
+                .iter()
+                .map(|group| group.group_name().to_string())
+                .collect();
+        }
+        Err(e) => tracing::warn!("Error listing groups of {}: {:?}", user_name, e),
+    }
+    match client
+        .list_access_keys()
+        .user_name(user_name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(keys) => {
+            detail.access_keys = keys
+                .iter()
+                .map(|key| IamAccessKey {
+                    id: key.access_key_id().unwrap_or_default().to_string(),
+                    status: key
+                        .status()
+                        .map(|status| status.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    created: format_timestamp(key.create_date()),
+                })
+                .collect();
+        }
+        Err(e) => tracing::warn!("Error listing access keys of {}: {:?}", user_name, e),
+    }
+    Some(detail)
+}
+
+async fn get_group_detail(client: &Client, group_name: &str) -> Option<IamEntityDetail> {
+    let mut group = None;
+    let mut members = Vec::new();
+    let mut pages = client
+        .get_group()
+        .group_name(group_name)
+        .into_paginator()
+        .send();
+    while let Some(page) = pages.next().await {
+        match page {
+            Ok(output) => {
+                members.extend(
+                    output
+                        .users()
+                        .iter()
+                        .map(|user| user.user_name().to_string()),
+                );
+                group = group.or_else(|| output.group().cloned());
+            }
+            Err(e) => {
+                tracing::error!("Error getting IAM group {}: {:?}", group_name, e);
+                return None;
+            }
+        }
+    }
+
+    let mut detail = map_group_detail(&group?);
+    detail.members = members;
+    detail.attached_policies = attached_policies(client, "Group", group_name).await;
+    detail.inline_policies = inline_policies(client, "Group", group_name).await;
+    Some(detail)
+}
+
+async fn get_policy_detail(client: &Client, policy_arn: &str) -> Option<IamEntityDetail> {
+    let output = match client.get_policy().policy_arn(policy_arn).send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting IAM policy {}: {:?}", policy_arn, e);
+            return None;
+        }
+    };
+    let mut detail = map_policy_detail(output.policy()?);
+    detail.policy_document = policy_document(client, policy_arn, &detail.default_version).await;
+    Some(detail)
+}
+
+// 관리형 정책의 기본 버전 문서 (디코딩된 JSON)
+async fn policy_document(client: &Client, policy_arn: &str, version_id: &str) -> String {
+    if version_id.is_empty() {
+        return String::new();
+    }
+    match client
+        .get_policy_version()
+        .policy_arn(policy_arn)
+        .version_id(version_id)
+        .send()
+        .await
+    {
+        Ok(output) => output
+            .policy_version()
+            .and_then(|version| version.document())
+            .map(decode_policy_document)
+            .unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!("Error getting policy version of {}: {:?}", policy_arn, e);
+            String::new()
+        }
+    }
+}
+
+async fn attached_policies(client: &Client, kind: &str, name: &str) -> Vec<IamManagedPolicy> {
+    let result: Result<Vec<AttachedPolicy>, String> = match kind {
+        "Role" => client
+            .list_attached_role_policies()
+            .role_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+        "User" => client
+            .list_attached_user_policies()
+            .user_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+        _ => client
+            .list_attached_group_policies()
+            .group_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+    };
+    let attached = match result {
+        Ok(attached) => attached,
+        Err(e) => {
+            tracing::warn!("Error listing attached policies of {}: {}", name, e);
+            return Vec::new();
+        }
+    };
+
+    let mut policies = Vec::new();
+    for policy in &attached {
+        let arn = policy.policy_arn().unwrap_or_default().to_string();
+        // AWS 관리형 정책(arn:aws:iam::aws:policy/...) 문서는 매우 길 수 있어 이름과 ARN만 표시
+        let document = if Arn::parse(&arn).is_some_and(|parsed| parsed.account == "aws") {
+            String::new()
+        } else {
+            match client.get_policy().policy_arn(&arn).send().await {
+                Ok(output) => {
+                    let version = output
+                        .policy()
+                        .and_then(|policy| policy.default_version_id())
+                        .unwrap_or_default();
+                    policy_document(client, &arn, version).await
+                }
+                Err(e) => {
+                    tracing::warn!("Error getting IAM policy {}: {:?}", arn, e);
+                    String::new()
+                }
+            }
+        };
+        policies.push(IamManagedPolicy {
+            name: policy.policy_name().unwrap_or_default().to_string(),
+            arn,
+            document,
+        });
+    }
+    policies.sort_by(|a, b| a.name.cmp(&b.name));
+    policies
+}
+
+async fn inline_policies(client: &Client, kind: &str, name: &str) -> Vec<InlinePolicy> {
+    let result: Result<Vec<String>, String> = match kind {
+        "Role" => client
+            .list_role_policies()
+            .role_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+        "User" => client
+            .list_user_policies()
+            .user_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+        _ => client
+            .list_group_policies()
+            .group_name(name)
+            .into_paginator()
+            .items()
+            .send()
+            .try_collect()
+            .await
+            .map_err(|e| format!("{:?}", e)),
+    };
+    let mut policy_names = match result {
+        Ok(policy_names) => policy_names,
+        Err(e) => {
+            tracing::warn!("Error listing inline policies of {}: {}", name, e);
+            return Vec::new();
+        }
+    };
+    policy_names.sort();
+
+    let mut policies = Vec::new();
+    for policy_name in policy_names {
+        let document = match kind {
+            "Role" => client
+                .get_role_policy()
+                .role_name(name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .map(|output| output.policy_document().to_string())
+                .ok(),
+            "User" => client
+                .get_user_policy()
+                .user_name(name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .map(|output| output.policy_document().to_string())
+                .ok(),
+            _ => client
+                .get_group_policy()
+                .group_name(name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .map(|output| output.policy_document().to_string())
+                .ok(),
+        };
+        if let Some(document) = document {
+            policies.push(InlinePolicy {
+                name: policy_name,
+                document: decode_policy_document(&document),
+            });
+        }
+    }
+    policies
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn created_date(timestamp: Option<&DateTime>) -> String {
+    let created = format_timestamp(timestamp);
+    created.split('T').next().unwrap_or_default().to_string()
+}
+
+fn entity_resource(kind: &str, name: &str, arn: &str, path: &str, created: &str) -> AwsResource {
+    AwsResource {
+        name: name.to_string(),
+        id: arn.to_string(),
+        state: kind.to_string(),
+        // 기본 경로("/")가 아니면 표시 (서비스 연결 역할: /aws-service-role/...)
+        az: if path == "/" {
+            String::new()
+        } else {
+            path.to_string()
+        },
+        cidr: created.to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_role_resource(role: &Role) -> AwsResource {
+    entity_resource(
+        "Role",
+        role.role_name(),
+        role.arn(),
+        role.path(),
+        &created_date(Some(role.create_date())),
+    )
+}
+
+fn map_user_resource(user: &User) -> AwsResource {
+    entity_resource(
+        "User",
+        user.user_name(),
+        user.arn(),
+        user.path(),
+        &created_date(Some(user.create_date())),
+    )
+}
+
+fn map_group_resource(group: &Group) -> AwsResource {
+    entity_resource(
+        "Group",
+        group.group_name(),
+        group.arn(),
+        group.path(),
+        &created_date(Some(group.create_date())),
+    )
+}
+
+fn map_policy_resource(policy: &Policy) -> AwsResource {
+    entity_resource(
+        "Policy",
+        policy.policy_name().unwrap_or_default(),
+        policy.arn().unwrap_or_default(),
+        policy.path().unwrap_or("/"),
+        &created_date(policy.create_date()),
+    )
+}
+
+fn sort_entities(resources: &mut [AwsResource]) {
+    resources.sort_by(|a, b| {
+        let rank = |kind: &str| KIND_ORDER.iter().position(|k| *k == kind);
+        rank(&a.state)
+            .cmp(&rank(&b.state))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+fn map_tags(tags: &[Tag]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect();
+    tags.sort();
+    tags
+}
+
+fn boundary_arn(boundary: Option<&AttachedPermissionsBoundary>) -> String {
+    boundary
+        .and_then(|boundary| boundary.permissions_boundary_arn())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn empty_detail(kind: &str, name: &str, arn: &str, path: &str) -> IamEntityDetail {
+    IamEntityDetail {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        arn: arn.to_string(),
+        path: path.to_string(),
+        description: String::new(),
+        created: String::new(),
+        last_used: String::new(),
+        permissions_boundary: String::new(),
+        trust_policy: String::new(),
+        attached_policies: Vec::new(),
+        inline_policies: Vec::new(),
+        groups: Vec::new(),
+        members: Vec::new(),
+        access_keys: Vec::new(),
+        default_version: String::new(),
+        policy_document: String::new(),
+        attachment_count: None,
+        tags: Vec::new(),
+    }
+}
+
+fn map_role_detail(role: &Role) -> IamEntityDetail {
+    let mut detail = empty_detail("Role", role.role_name(), role.arn(), role.path());
+    detail.description = role.description().unwrap_or_default().to_string();
+    detail.created = format_timestamp(Some(role.create_date()));
+    detail.last_used = format_timestamp(
+        role.role_last_used()
+            .and_then(|last_used| last_used.last_used_date()),
+    );
+    detail.permissions_boundary = boundary_arn(role.permissions_boundary());
+    detail.trust_policy = role
+        .assume_role_policy_document()
+        .map(decode_policy_document)
+        .unwrap_or_default();
+    detail.tags = map_tags(role.tags());
+    detail
+}
+
+fn map_user_detail(user: &User) -> IamEntityDetail {
+    let mut detail = empty_detail("User", user.user_name(), user.arn(), user.path());
+    detail.created = format_timestamp(Some(user.create_date()));
+    detail.last_used = format_timestamp(user.password_last_used());
+    detail.permissions_boundary = boundary_arn(user.permissions_boundary());
+    detail.tags = map_tags(user.tags());
+    detail
+}
+
+fn map_group_detail(group: &Group) -> IamEntityDetail {
+    let mut detail = empty_detail("Group", group.group_name(), group.arn(), group.path());
+    detail.created = format_timestamp(Some(group.create_date()));
+    detail
+}
+
+fn map_policy_detail(policy: &Policy) -> IamEntityDetail {
+    let mut detail = empty_detail(
+        "Policy",
+        policy.policy_name().unwrap_or_default(),
+        policy.arn().unwrap_or_default(),
+        policy.path().unwrap_or("/"),
+    );
+    detail.description = policy.description().unwrap_or_default().to_string();
+    detail.created = format_timestamp(policy.create_date());
+    detail.default_version = policy.default_version_id().unwrap_or_default().to_string();
+    detail.attachment_count = policy.attachment_count();
+    detail.tags = map_tags(policy.tags());
+    detail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_policy_resource, map_role_detail, map_role_resource, sort_entities};
+    use aws_sdk_iam::primitives::DateTime;
+    use aws_sdk_iam::types::{Policy, Role, RoleLastUsed, Tag};
+
+    fn role(name: &str, path: &str) -> Role {
+        Role::builder()
+            .path(path)
+            .role_name(name)
+            .role_id("AROAEXAMPLE")
+            .arn(format!("arn:aws:iam::123456789012:role{}{}", path, name))
+            .create_date(DateTime::from_secs(1_767_225_600))
+            .assume_role_policy_document(
+                "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%5D%7D",
+            )
+            .build()
+            .expect("role")
+    }
+
+    #[test]
+    fn map_role_resource_shows_kind_path_and_created_date() {
+        let resource = map_role_resource(&role("web", "/"));
+        assert_eq!(resource.id, "arn:aws:iam::123456789012:role/web");
+        assert_eq!(resource.state, "Role");
+        assert_eq!(resource.az, "");
+        assert_eq!(resource.cidr, "2026-01-01");
+
+        let service_role = map_role_resource(&role(
+            "AWSServiceRoleForECS",
+            "/aws-service-role/ecs.amazonaws.com/",
+        ));
+        assert_eq!(service_role.az, "/aws-service-role/ecs.amazonaws.com/");
+    }
+
+    #[test]
+    fn map_role_detail_decodes_trust_policy() {
+        let mut role = role("web", "/");
+        role.role_last_used = Some(
+            RoleLastUsed::builder()
+                .last_used_date(DateTime::from_secs(1_767_312_000))
+                .build(),
+        );
+        role.tags = Some(vec![
+            Tag::builder()
+                .key("Team")
+                .value("web")
+                .build()
+                .expect("tag"),
+        ]);
+
+        let detail = map_role_detail(&role);
+        assert_eq!(detail.kind, "Role");
+        assert!(detail.trust_policy.contains("\"Version\": \"2012-10-17\""));
+        assert_eq!(detail.last_used, "2026-01-02T00:00:00Z");
+        assert_eq!(detail.tags, vec![("Team".to_string(), "web".to_string())]);
+    }
+
+    #[test]
+    fn sort_entities_groups_by_kind_then_name() {
+        let policy = Policy::builder()
+            .policy_name("app-read")
+            .arn("arn:aws:iam::123456789012:policy/app-read")
+            .path("/")
+            .build();
+        let mut resources = vec![
+            map_policy_resource(&policy),
+            map_role_resource(&role("worker", "/")),
+            map_role_resource(&role("api", "/")),
+        ];
+        sort_entities(&mut resources);
+        let names: Vec<&str> = resources.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["api", "worker", "app-read"]);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct KeyPairsResponse {
+    key_pairs: Vec<KeyPairEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct KeyPairEntry {
+    key_pair_id: String,
+    #[serde(default)]
+    key_name: String,
+    #[serde(default)]
+    key_fingerprint: String,
+    #[serde(default)]
+    key_type: String,
+    #[serde(default)]
+    create_time: String,
+    #[serde(default)]
+    public_key: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyPairDetail {
+    pub id: String,
+    pub name: String,
+    pub fingerprint: String,
+    pub key_type: String,
+    pub created: String,
+    // --include-public-key로 조회한 상세에서만 채워짐
+    pub public_key: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl KeyPairDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.md_key_pair(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), or_dash(&self.name)),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_key_type(), or_dash(&self.key_type)),
+            format!(
+                "| {} | {} |",
+                i18n.md_fingerprint(),
+                or_dash(&self.fingerprint)
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                or_dash(&locale::date(&self.created))
+            ),
+        ];
+        for (key, value) in &self.tags {
+            lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+        }
+
+        if !self.public_key.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_public_key()));
+            lines.push("```".to_string());
+            lines.push(self.public_key.trim_end().to_string());
+            lines.push("```".to_string());
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Parse `describe-key-pairs` output, sorted by key name.
+pub(crate) fn parse_key_pairs_output(output: &str) -> Option<Vec<KeyPairDetail>> {
+    let response: KeyPairsResponse = serde_json::from_str(output).ok()?;
+
+    let mut key_pairs: Vec<KeyPairDetail> = response
+        .key_pairs
+        .into_iter()
+        .map(|key_pair| {
+            let mut tags: Vec<(String, String)> = key_pair
+                .tags
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            KeyPairDetail {
+                id: key_pair.key_pair_id,
+                name: key_pair.key_name,
+                fingerprint: key_pair.key_fingerprint,
+                key_type: key_pair.key_type,
+                created: key_pair.create_time,
+                public_key: key_pair.public_key,
+                tags,
+            }
+        })
+        .collect();
+    key_pairs.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    Some(key_pairs)
+}
+
+pub fn list_key_pairs() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&["ec2", "describe-key-pairs", "--output", "json"]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let key_pairs: Vec<AwsResource> = parse_key_pairs_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(key_pair_resource)
+        .collect();
+    name_index::record_resources(&key_pairs);
+    key_pairs
+}
+
+// 목록에는 키 유형, 지문, 생성일을 함께 표시
+fn key_pair_resource(key_pair: KeyPairDetail) -> AwsResource {
+    AwsResource {
+        name: if key_pair.name.is_empty() {
+            key_pair.id.clone()
+        } else {
+            key_pair.name.clone()
+        },
+        state: key_pair.key_type,
+        cidr: key_pair.fingerprint,
+        az: key_pair.created.chars().take(10).collect(),
+        id: key_pair.id,
+        owner_id: String::new(),
+    }
+}
+
+/// Key pair IDs are `key-` plus 17 hex digits; anything else is a key name.
+fn is_key_pair_id(value: &str) -> bool {
+    value
+        .strip_prefix("key-")
+        .is_some_and(|hex| hex.len() == 17 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// 태그 검색과 CloudFormation은 키 이름으로 리소스를 가리키므로 이름 조회도 지원
+pub fn get_key_pair_detail(key_pair_id: &str) -> Option<KeyPairDetail> {
+    let filter = if is_key_pair_id(key_pair_id) {
+        "--key-pair-ids"
+    } else {
+        "--key-names"
+    };
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-key-pairs",
+        filter,
+        key_pair_id,
+        "--include-public-key",
+        "--output",
+        "json",
+    ])?;
+    parse_key_pairs_output(&output)?.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_key_pair_id, key_pair_resource, parse_key_pairs_output};
+    use crate::i18n::Language;
+
+    const KEY_PAIRS: &str = r#"
+    {
+      "KeyPairs": [
+        {
+          "KeyPairId": "key-0web",
+          "KeyName": "web",
+          "KeyFingerprint": "1f:51:ae:28:bf:89:e9:d8:1f:25:5d:37:2d:7d:b8:ca:9f:f5:f1:6f",
+          "KeyType": "rsa",
+          "CreateTime": "2026-01-05T00:00:00.000Z",
+          "Tags": []
+        },
+        {
+          "KeyPairId": "key-0deploy",
+          "KeyName": "deploy",
+          "KeyFingerprint": "SHA256:Yf3bLq7d9hN0v2m4T6xw8PzQ1rKc5sJ0aUeG7iBoXyE=",
+          "KeyType": "ed25519",
+          "CreateTime": "2026-10-01T02:03:04.000Z",
+          "PublicKey": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI deploy\n",
+          "Tags": [{"Key": "Team", "Value": "platform"}]
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_key_pairs_output_sorts_by_name() {
+        let key_pairs = parse_key_pairs_output(KEY_PAIRS).expect("key pairs");
+        assert_eq!(key_pairs.len(), 2);
+        assert_eq!(key_pairs[0].name, "deploy");
+        assert_eq!(key_pairs[0].key_type, "ed25519");
+        assert_eq!(
+            key_pairs[0].tags,
+            vec![("Team".to_string(), "platform".to_string())]
+        );
+        assert!(key_pairs[1].public_key.is_empty());
+
+        let resource = key_pair_resource(key_pairs[1].clone());
+        assert_eq!(resource.name, "web");
+        assert_eq!(resource.id, "key-0web");
+        assert_eq!(resource.state, "rsa");
+        assert_eq!(resource.az, "2026-01-05");
+
+        assert!(is_key_pair_id("key-0a1b2c3d4e5f60718"));
+        assert!(!is_key_pair_id("key-deploy"));
+        assert!(!is_key_pair_id("deploy"));
+    }
+
+    #[test]
+    fn key_pair_markdown_lists_fingerprint_and_public_key() {
+        let key_pairs = parse_key_pairs_output(KEY_PAIRS).expect("key pairs");
+        let md = key_pairs[0].to_markdown(Language::English);
+        assert!(md.contains("## Key Pair (deploy - key-0deploy)"));
+        assert!(md.contains("| Key Type | ed25519 |"));
+        assert!(
+            md.contains("| Fingerprint | SHA256:Yf3bLq7d9hN0v2m4T6xw8PzQ1rKc5sJ0aUeG7iBoXyE= |")
+        );
+        assert!(md.contains("| Tag-Team | platform |"));
+        assert!(md.contains("```\nssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI deploy\n```"));
+
+        // 목록 조회 결과에는 공개 키가 없으므로 섹션도 생략
+        let md = key_pairs[1].to_markdown(Language::English);
+        assert!(!md.contains("```"));
+    }
+}
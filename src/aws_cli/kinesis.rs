@@ -0,0 +1,186 @@
+pub use crate::aws_cli::kinesis_sdk::{get_kinesis_stream_detail, list_kinesis_streams};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KinesisConsumer {
+    pub name: String,
+    pub arn: String,
+    // CREATING, DELETING, ACTIVE
+    pub status: String,
+    pub created: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct KinesisStreamDetail {
+    pub name: String,
+    pub arn: String,
+    // CREATING, DELETING, ACTIVE, UPDATING
+    pub status: String,
+    // PROVISIONED, ON_DEMAND
+    pub stream_mode: String,
+    pub open_shard_count: i32,
+    pub retention_hours: i32,
+    // KMS 암호화를 쓰지 않으면 None
+    pub kms_key_id: Option<String>,
+    // 샤드 수준 확장 모니터링 지표 (ALL, IncomingBytes, ...)
+    pub shard_level_metrics: Vec<String>,
+    pub created: String,
+    // 향상된 팬아웃(EFO) 소비자
+    pub consumers: Vec<KinesisConsumer>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// `168` → `168h (7d)`; whole days are spelled out next to the hours.
+pub fn format_retention(hours: i32, i18n: &I18n) -> String {
+    if hours >= 24 && hours % 24 == 0 {
+        format!("{} ({})", i18n.md_hours(hours), i18n.md_days(hours / 24))
+    } else {
+        i18n.md_hours(hours)
+    }
+}
+
+impl KinesisStreamDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mode = if self.stream_mode == "ON_DEMAND" {
+            i18n.md_on_demand()
+        } else {
+            i18n.md_provisioned()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.kinesis_stream(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_capacity_mode(), mode),
+            format!("| {} | {} |", i18n.md_open_shards(), self.open_shard_count),
+            format!(
+                "| {} | {} |",
+                i18n.md_data_retention(),
+                format_retention(self.retention_hours, &i18n)
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_encryption(),
+                match &self.kms_key_id {
+                    Some(key_id) => format!("KMS ({})", key_id),
+                    None => i18n.md_disabled().to_string(),
+                }
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_enhanced_monitoring(),
+                if self.shard_level_metrics.is_empty() {
+                    i18n.md_disabled().to_string()
+                } else {
+                    self.shard_level_metrics.join(", ")
+                }
+            ),
+        ];
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // Enhanced fan-out consumers
+        if !self.consumers.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_consumers()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_state(),
+                i18n.md_created_at()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for consumer in &self.consumers {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    consumer.name, consumer.status, consumer.created
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KinesisConsumer, KinesisStreamDetail, format_retention};
+    use crate::i18n::{I18n, Language};
+
+    fn sample_detail() -> KinesisStreamDetail {
+        KinesisStreamDetail {
+            name: "clickstream".to_string(),
+            arn: "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream".to_string(),
+            status: "ACTIVE".to_string(),
+            stream_mode: "PROVISIONED".to_string(),
+            open_shard_count: 4,
+            retention_hours: 168,
+            kms_key_id: Some("alias/aws/kinesis".to_string()),
+            shard_level_metrics: vec![
+                "IncomingBytes".to_string(),
+                "OutgoingRecords".to_string(),
+            ],
+            created: "2026-01-01T00:00:00Z".to_string(),
+            consumers: vec![KinesisConsumer {
+                name: "analytics".to_string(),
+                arn: "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream/consumer/analytics:1767225600".to_string(),
+                status: "ACTIVE".to_string(),
+                created: "2026-01-02T00:00:00Z".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "data".to_string())],
+        }
+    }
+
+    #[test]
+    fn format_retention_spells_out_whole_days() {
+        let en = I18n::new(Language::English);
+        assert_eq!(format_retention(24, &en), "24h (1d)");
+        assert_eq!(format_retention(168, &en), "168h (7d)");
+        assert_eq!(format_retention(36, &en), "36h");
+        let ko = I18n::new(Language::Korean);
+        assert_eq!(format_retention(8760, &ko), "8760시간 (365일)");
+    }
+
+    #[test]
+    fn scenario_kinesis_stream_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Kinesis Data Stream (clickstream)"));
+        assert!(markdown.contains("| Capacity Mode | Provisioned |"));
+        assert!(markdown.contains("| Open Shards | 4 |"));
+        assert!(markdown.contains("| Data Retention | 168h (7d) |"));
+        assert!(markdown.contains("| Encryption | KMS (alias/aws/kinesis) |"));
+        assert!(markdown.contains("| Enhanced Monitoring | IncomingBytes, OutgoingRecords |"));
+        assert!(markdown.contains("### Consumers (Enhanced Fan-Out)"));
+        assert!(markdown.contains("| analytics | ACTIVE | 2026-01-02T00:00:00Z |"));
+        assert!(markdown.contains("| Team | data |"));
+
+        let mut detail = sample_detail();
+        detail.stream_mode = "ON_DEMAND".to_string();
+        detail.kms_key_id = None;
+        detail.shard_level_metrics.clear();
+        detail.consumers.clear();
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 용량 모드 | 온디맨드 |"));
+        assert!(markdown.contains("| 암호화 | 비활성화 |"));
+        assert!(!markdown.contains("향상된 팬아웃"));
+    }
+}
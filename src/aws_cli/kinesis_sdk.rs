@@ -0,0 +1,271 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::kinesis::{KinesisConsumer, KinesisStreamDetail};
+use aws_sdk_kinesis::operation::list_stream_consumers::ListStreamConsumersOutput;
+use aws_sdk_kinesis::operation::list_streams::ListStreamsOutput;
+use aws_sdk_kinesis::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_kinesis::types::{
+    Consumer, EncryptionType, StreamDescriptionSummary, StreamModeDetails, StreamSummary,
+};
+
+/// List Kinesis data streams using AWS SDK
+pub fn list_kinesis_streams() -> Vec<AwsResource> {
+    get_runtime().block_on(list_kinesis_streams_async())
+}
+
+async fn list_kinesis_streams_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kinesis, &config);
+
+    let pages: Vec<ListStreamsOutput> = match client
+        .list_streams()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => pages,
+        Err(e) => {
+            tracing::error!("Error listing Kinesis streams: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = pages
+        .iter()
+        .flat_map(|page| page.stream_summaries())
+        .map(map_stream_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get Kinesis stream detail (capacity, retention, encryption, consumers) using AWS SDK
+pub fn get_kinesis_stream_detail(stream: &str) -> Option<KinesisStreamDetail> {
+    get_runtime().block_on(get_kinesis_stream_detail_async(stream))
+}
+
+async fn get_kinesis_stream_detail_async(stream: &str) -> Option<KinesisStreamDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kinesis, &config);
+
+    // 태그 조회(ARN 기반)에서 넘어온 경우도 처리
+    let request = client.describe_stream_summary();
+    let request = if stream.starts_with("arn:") {
+        request.stream_arn(stream)
+    } else {
+        request.stream_name(stream)
+    };
+    let output = match request.send().await {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing Kinesis stream {}: {:?}", stream, e);
+            return None;
+        }
+    };
+    let mut detail = map_stream_detail(output.stream_description_summary()?);
+
+    // 소비자/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_stream_consumers()
+        .stream_arn(&detail.arn)
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => detail.consumers = map_consumers(&pages),
+        Err(e) => tracing::warn!("Error listing consumers for {}: {:?}", detail.arn, e),
+    }
+
+    match client
+        .list_tags_for_resource()
+        .resource_arn(&detail.arn)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            let mut tags: Vec<(String, String)> = output
+                .tags()
+                .iter()
+                .map(|tag| {
+                    (
+                        tag.key().to_string(),
+                        tag.value().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error getting tags for {}: {:?}", detail.arn, e),
+    }
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|timestamp| timestamp.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn stream_mode(details: Option<&StreamModeDetails>) -> String {
+    details
+        .map(|details| details.stream_mode().as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+// 목록에는 상태(state)와 용량 모드를 표시하고, 상세 조회는 스트림 이름으로 함
+fn map_stream_resource(stream: &StreamSummary) -> AwsResource {
+    AwsResource {
+        name: stream.stream_name().to_string(),
+        id: stream.stream_name().to_string(),
+        state: stream.stream_status().as_str().to_string(),
+        az: String::new(),
+        cidr: stream_mode(stream.stream_mode_details()),
+        owner_id: String::new(),
+    }
+}
+
+fn map_stream_detail(summary: &StreamDescriptionSummary) -> KinesisStreamDetail {
+    let kms_key_id = summary
+        .encryption_type()
+        .filter(|encryption| **encryption == EncryptionType::Kms)
+        .map(|_| summary.key_id().unwrap_or_default().to_string());
+
+    KinesisStreamDetail {
+        name: summary.stream_name().to_string(),
+        arn: summary.stream_arn().to_string(),
+        status: summary.stream_status().as_str().to_string(),
+        stream_mode: stream_mode(summary.stream_mode_details()),
+        open_shard_count: summary.open_shard_count(),
+        retention_hours: summary.retention_period_hours(),
+        kms_key_id,
+        shard_level_metrics: summary
+            .enhanced_monitoring()
+            .iter()
+            .flat_map(|monitoring| monitoring.shard_level_metrics())
+            .map(|metric| metric.as_str().to_string())
+            .collect(),
+        created: format_timestamp(Some(summary.stream_creation_timestamp())),
+        consumers: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_consumers(pages: &[ListStreamConsumersOutput]) -> Vec<KinesisConsumer> {
+    let mut consumers: Vec<KinesisConsumer> = pages
+        .iter()
+        .flat_map(|page| page.consumers())
+        .map(map_consumer)
+        .collect();
+    consumers.sort_by(|a, b| a.name.cmp(&b.name));
+    consumers
+}
+
+fn map_consumer(consumer: &Consumer) -> KinesisConsumer {
+    KinesisConsumer {
+        name: consumer.consumer_name().to_string(),
+        arn: consumer.consumer_arn().to_string(),
+        status: consumer.consumer_status().as_str().to_string(),
+        created: format_timestamp(Some(consumer.consumer_creation_timestamp())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_consumers, map_stream_detail, map_stream_resource};
+    use aws_sdk_kinesis::operation::list_stream_consumers::ListStreamConsumersOutput;
+    use aws_sdk_kinesis::primitives::DateTime;
+    use aws_sdk_kinesis::types::{
+        Consumer, ConsumerStatus, EncryptionType, EnhancedMetrics, MetricsName,
+        StreamDescriptionSummary, StreamMode, StreamModeDetails, StreamStatus, StreamSummary,
+    };
+
+    const ARN: &str = "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream";
+
+    fn consumer(name: &str) -> Consumer {
+        Consumer::builder()
+            .consumer_name(name)
+            .consumer_arn(format!("{ARN}/consumer/{name}:1767225600"))
+            .consumer_status(ConsumerStatus::Active)
+            .consumer_creation_timestamp(DateTime::from_secs(1_767_225_600))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn map_stream_resource_shows_status_and_capacity_mode() {
+        let resource = map_stream_resource(
+            &StreamSummary::builder()
+                .stream_name("clickstream")
+                .stream_arn(ARN)
+                .stream_status(StreamStatus::Active)
+                .stream_mode_details(
+                    StreamModeDetails::builder()
+                        .stream_mode(StreamMode::OnDemand)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(resource.name, "clickstream");
+        assert_eq!(resource.id, "clickstream");
+        assert_eq!(resource.state, "ACTIVE");
+        assert_eq!(resource.cidr, "ON_DEMAND");
+    }
+
+    #[test]
+    fn map_stream_detail_reads_encryption_monitoring_and_consumers() {
+        let summary = StreamDescriptionSummary::builder()
+            .stream_name("clickstream")
+            .stream_arn(ARN)
+            .stream_status(StreamStatus::Active)
+            .stream_mode_details(
+                StreamModeDetails::builder()
+                    .stream_mode(StreamMode::Provisioned)
+                    .build()
+                    .unwrap(),
+            )
+            .retention_period_hours(48)
+            .stream_creation_timestamp(DateTime::from_secs(1_767_225_600))
+            .enhanced_monitoring(
+                EnhancedMetrics::builder()
+                    .shard_level_metrics(MetricsName::IncomingBytes)
+                    .shard_level_metrics(MetricsName::IteratorAgeMilliseconds)
+                    .build(),
+            )
+            .encryption_type(EncryptionType::Kms)
+            .key_id("alias/aws/kinesis")
+            .open_shard_count(2)
+            .build()
+            .unwrap();
+
+        let detail = map_stream_detail(&summary);
+        assert_eq!(detail.stream_mode, "PROVISIONED");
+        assert_eq!(detail.open_shard_count, 2);
+        assert_eq!(detail.retention_hours, 48);
+        assert_eq!(detail.kms_key_id.as_deref(), Some("alias/aws/kinesis"));
+        assert_eq!(
+            detail.shard_level_metrics,
+            vec!["IncomingBytes", "IteratorAgeMilliseconds"]
+        );
+        assert_eq!(detail.created, "2026-01-01T00:00:00Z");
+
+        let pages = vec![
+            ListStreamConsumersOutput::builder()
+                .consumers(consumer("search"))
+                .build(),
+            ListStreamConsumersOutput::builder()
+                .consumers(consumer("analytics"))
+                .build(),
+        ];
+        let consumers = map_consumers(&pages);
+        assert_eq!(consumers.len(), 2);
+        assert_eq!(consumers[0].name, "analytics");
+        assert_eq!(consumers[0].status, "ACTIVE");
+        assert_eq!(consumers[1].created, "2026-01-01T00:00:00Z");
+    }
+}
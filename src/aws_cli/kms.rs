@@ -0,0 +1,404 @@
+pub use crate::aws_cli::kms_sdk::{get_kms_key_detail, list_kms_keys};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KmsPolicyStatement {
+    pub sid: String,
+    pub effect: String,
+    // "*" 또는 AWS/Service/Federated 주체
+    pub principals: Vec<String>,
+    pub actions: Vec<String>,
+    pub conditional: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KmsGrant {
+    pub name: String,
+    pub grantee_principal: String,
+    pub retiring_principal: String,
+    pub operations: Vec<String>,
+    pub created: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct KmsKeyDetail {
+    // 첫 번째 별칭(alias/ 제외), 별칭이 없으면 키 ID
+    pub name: String,
+    pub key_id: String,
+    pub arn: String,
+    pub aliases: Vec<String>,
+    pub description: String,
+    pub state: String,
+    pub key_spec: String,
+    pub key_usage: String,
+    pub origin: String,
+    pub multi_region: bool,
+    pub created: String,
+    pub deletion_date: Option<String>,
+    // 자동 교체를 지원하지 않는 키(비대칭, 외부 키 구성 요소 등)는 None
+    pub rotation_enabled: Option<bool>,
+    pub rotation_period_days: Option<i32>,
+    pub next_rotation: Option<String>,
+    pub policy_statements: Vec<KmsPolicyStatement>,
+    pub grants: Vec<KmsGrant>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// Display name for a key: its first alias without the `alias/` prefix, or the key ID.
+pub fn key_display_name(key_id: &str, aliases: &[String]) -> String {
+    aliases
+        .first()
+        .map(|alias| alias.trim_start_matches("alias/").to_string())
+        .unwrap_or_else(|| key_id.to_string())
+}
+
+/// Summarize a key policy document into one entry per statement.
+pub fn summarize_key_policy(policy: &str) -> Vec<KmsPolicyStatement> {
+    let Ok(document) = serde_json::from_str::<Value>(policy) else {
+        return Vec::new();
+    };
+    // Statement는 배열 또는 단일 객체
+    let statements: Vec<&Value> = match document.get("Statement") {
+        Some(Value::Array(statements)) => statements.iter().collect(),
+        Some(statement @ Value::Object(_)) => vec![statement],
+        _ => Vec::new(),
+    };
+
+    statements
+        .into_iter()
+        .map(|statement| KmsPolicyStatement {
+            sid: statement
+                .get("Sid")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            effect: statement
+                .get("Effect")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            principals: match statement.get("Principal") {
+                Some(Value::Object(principals)) => {
+                    principals.values().flat_map(string_list).collect()
+                }
+                Some(principal) => string_list(principal),
+                None => Vec::new(),
+            },
+            actions: statement.get("Action").map(string_list).unwrap_or_default(),
+            conditional: statement.get("Condition").is_some(),
+        })
+        .collect()
+}
+
+// 정책 값은 문자열 또는 문자열 배열
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(value) => vec![value.clone()],
+        Value::Array(values) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl KmsKeyDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+        let or_dash = |values: &[String]| {
+            if values.is_empty() {
+                "-".to_string()
+            } else {
+                values.join("<br>")
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.kms_key(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_key_id(), self.key_id),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.aliases.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_aliases(),
+                self.aliases.join(", ")
+            ));
+        }
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.state));
+        if let Some(ref deletion_date) = self.deletion_date {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_deletion_date(),
+                deletion_date
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_key_spec(), self.key_spec));
+        lines.push(format!("| {} | {} |", i18n.md_key_usage(), self.key_usage));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_key_material_origin(),
+            self.origin
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_multi_region(),
+            enabled(self.multi_region)
+        ));
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // 자동 교체: "활성화 (365일, 다음: ...)"
+        let rotation = match self.rotation_enabled {
+            Some(true) => {
+                let mut details = Vec::new();
+                if let Some(days) = self.rotation_period_days {
+                    details.push(i18n.md_rotation_period(days));
+                }
+                if let Some(ref next) = self.next_rotation {
+                    details.push(format!("{}: {}", i18n.md_next_rotation(), next));
+                }
+                if details.is_empty() {
+                    i18n.md_enabled().to_string()
+                } else {
+                    format!("{} ({})", i18n.md_enabled(), details.join(", "))
+                }
+            }
+            Some(false) => i18n.md_disabled().to_string(),
+            None => i18n.md_not_supported().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_key_rotation(), rotation));
+
+        // Key Policy
+        if !self.policy_statements.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_key_policy()));
+            lines.push(format!(
+                "| Sid | {} | {} | {} | {} |",
+                i18n.md_effect(),
+                i18n.md_principal(),
+                i18n.md_actions(),
+                i18n.md_condition()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for statement in &self.policy_statements {
+                let sid = if statement.sid.is_empty() {
+                    "-"
+                } else {
+                    statement.sid.as_str()
+                };
+                let condition = if statement.conditional { "✓" } else { "-" };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    sid,
+                    statement.effect,
+                    or_dash(&statement.principals),
+                    or_dash(&statement.actions),
+                    condition
+                ));
+            }
+        }
+
+        // Grants
+        if !self.grants.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_grants()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_grantee(),
+                i18n.md_operations(),
+                i18n.md_retiring_principal(),
+                i18n.md_created_at()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for grant in &self.grants {
+                let name = if grant.name.is_empty() {
+                    "-"
+                } else {
+                    grant.name.as_str()
+                };
+                let retiring = if grant.retiring_principal.is_empty() {
+                    "-"
+                } else {
+                    grant.retiring_principal.as_str()
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    name,
+                    grant.grantee_principal,
+                    grant.operations.join(", "),
+                    retiring,
+                    grant.created
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        KmsGrant, KmsKeyDetail, KmsPolicyStatement, key_display_name, summarize_key_policy,
+    };
+    use crate::i18n::Language;
+
+    fn sample_detail() -> KmsKeyDetail {
+        KmsKeyDetail {
+            name: "app-data".to_string(),
+            key_id: "1234abcd-12ab-34cd-56ef-1234567890ab".to_string(),
+            arn: "arn:aws:kms:ap-northeast-2:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+                .to_string(),
+            aliases: vec!["alias/app-data".to_string()],
+            description: "Application data key".to_string(),
+            state: "Enabled".to_string(),
+            key_spec: "SYMMETRIC_DEFAULT".to_string(),
+            key_usage: "ENCRYPT_DECRYPT".to_string(),
+            origin: "AWS_KMS".to_string(),
+            multi_region: false,
+            created: "2025-06-01T00:00:00Z".to_string(),
+            deletion_date: None,
+            rotation_enabled: Some(true),
+            rotation_period_days: Some(365),
+            next_rotation: Some("2026-06-01T00:00:00Z".to_string()),
+            policy_statements: vec![KmsPolicyStatement {
+                sid: "Enable IAM User Permissions".to_string(),
+                effect: "Allow".to_string(),
+                principals: vec!["arn:aws:iam::123456789012:root".to_string()],
+                actions: vec!["kms:*".to_string()],
+                conditional: false,
+            }],
+            grants: vec![KmsGrant {
+                name: String::new(),
+                grantee_principal: "arn:aws:iam::123456789012:role/app".to_string(),
+                retiring_principal: String::new(),
+                operations: vec!["Decrypt".to_string(), "GenerateDataKey".to_string()],
+                created: "2025-06-02T00:00:00Z".to_string(),
+            }],
+            tags: vec![("Team".to_string(), "security".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_kms_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## KMS Key (app-data)"));
+        assert!(markdown.contains("| Aliases | alias/app-data |"));
+        assert!(markdown.contains(
+            "| Automatic Rotation | Enabled (every 365 days, Next Rotation: 2026-06-01T00:00:00Z) |"
+        ));
+        assert!(markdown.contains("### Key Policy"));
+        assert!(markdown.contains(
+            "| Enable IAM User Permissions | Allow | arn:aws:iam::123456789012:root | kms:* | - |"
+        ));
+        assert!(markdown.contains("### Grants"));
+        assert!(markdown.contains(
+            "| - | arn:aws:iam::123456789012:role/app | Decrypt, GenerateDataKey | - | 2025-06-02T00:00:00Z |"
+        ));
+        assert!(markdown.contains("| Team | security |"));
+    }
+
+    #[test]
+    fn scenario_kms_markdown_pending_deletion_without_rotation() {
+        let mut detail = sample_detail();
+        detail.state = "PendingDeletion".to_string();
+        detail.deletion_date = Some("2026-02-01T00:00:00Z".to_string());
+        detail.rotation_enabled = None;
+        detail.grants = vec![];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 삭제 예정일 | 2026-02-01T00:00:00Z |"));
+        assert!(markdown.contains("| 자동 키 교체 | 지원 안 함 |"));
+        assert!(!markdown.contains("### 권한 부여"));
+    }
+
+    #[test]
+    fn key_display_name_prefers_first_alias() {
+        assert_eq!(
+            key_display_name("1234abcd", &["alias/app-data".to_string()]),
+            "app-data"
+        );
+        assert_eq!(key_display_name("1234abcd", &[]), "1234abcd");
+    }
+
+    #[test]
+    fn summarize_key_policy_reads_principals_actions_and_conditions() {
+        let policy = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {
+                    "Sid": "Enable IAM User Permissions",
+                    "Effect": "Allow",
+                    "Principal": {"AWS": "arn:aws:iam::123456789012:root"},
+                    "Action": "kms:*",
+                    "Resource": "*"
+                },
+                {
+                    "Effect": "Allow",
+                    "Principal": {"Service": ["logs.amazonaws.com"], "AWS": ["arn:aws:iam::123456789012:role/app"]},
+                    "Action": ["kms:Encrypt", "kms:Decrypt"],
+                    "Resource": "*",
+                    "Condition": {"StringEquals": {"kms:ViaService": "s3.ap-northeast-2.amazonaws.com"}}
+                },
+                {"Effect": "Deny", "Principal": "*", "Action": "kms:ScheduleKeyDeletion"}
+            ]
+        }"#;
+        let statements = summarize_key_policy(policy);
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].sid, "Enable IAM User Permissions");
+        assert_eq!(
+            statements[0].principals,
+            vec!["arn:aws:iam::123456789012:root"]
+        );
+        assert!(!statements[0].conditional);
+        assert_eq!(
+            statements[1].principals,
+            vec!["arn:aws:iam::123456789012:role/app", "logs.amazonaws.com"]
+        );
+        assert_eq!(statements[1].actions, vec!["kms:Encrypt", "kms:Decrypt"]);
+        assert!(statements[1].conditional);
+        assert_eq!(statements[2].effect, "Deny");
+        assert_eq!(statements[2].principals, vec!["*"]);
+
+        assert!(summarize_key_policy("not json").is_empty());
+        let single =
+            summarize_key_policy(r#"{"Statement": {"Effect": "Allow", "Action": "kms:*"}}"#);
+        assert_eq!(single.len(), 1);
+        assert!(single[0].principals.is_empty());
+    }
+}
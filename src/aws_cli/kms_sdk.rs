@@ -0,0 +1,358 @@
+use crate::aws_cli::common::{
+    AwsResource, get_runtime, get_sdk_config, map_concurrent, sdk_client,
+};
+use crate::aws_cli::kms::{KmsGrant, KmsKeyDetail, key_display_name, summarize_key_policy};
+use aws_sdk_kms::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_kms::types::{
+    AliasListEntry, GrantListEntry, KeyManagerType, KeyMetadata, KeySpec, KeyUsageType, OriginType,
+};
+use std::collections::HashMap;
+
+/// List customer managed KMS keys with their aliases using AWS SDK
+pub fn list_kms_keys() -> Vec<AwsResource> {
+    get_runtime().block_on(list_kms_keys_async())
+}
+
+async fn list_kms_keys_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kms, &config);
+
+    let keys: Vec<aws_sdk_kms::types::KeyListEntry> = match client
+        .list_keys()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(keys) => keys,
+        Err(e) => {
+            tracing::error!("Error listing KMS keys: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    // 별칭 조회 실패 시 키 ID로 표시
+    let aliases: Vec<AliasListEntry> = match client
+        .list_aliases()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(aliases) => aliases,
+        Err(e) => {
+            tracing::warn!("Error listing KMS aliases: {:?}", e);
+            Vec::new()
+        }
+    };
+    let aliases_by_key = group_aliases(&aliases);
+
+    // ListKeys는 AWS 관리형 키도 반환하므로 DescribeKey로 고객 관리형 키만 남김
+    let described = map_concurrent(keys.iter().filter_map(|key| key.key_id()), |key_id| {
+        let client = &client;
+        let aliases_by_key = &aliases_by_key;
+        async move {
+            match client.describe_key().key_id(key_id).send().await {
+                Ok(output) => match output.key_metadata() {
+                    Some(metadata) if metadata.key_manager() == Some(&KeyManagerType::Customer) => {
+                        let aliases = aliases_by_key.get(key_id).cloned().unwrap_or_default();
+                        Some(map_key_resource(metadata, &aliases))
+                    }
+                    _ => None,
+                },
+                Err(e) => {
+                    tracing::warn!("Error describing KMS key {}: {:?}", key_id, e);
+                    None
+                }
+            }
+        }
+    })
+    .await;
+    let mut resources: Vec<AwsResource> = described.into_iter().flatten().collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get KMS key detail (rotation, key policy, grants and tags) using AWS SDK
+pub fn get_kms_key_detail(key_id: &str) -> Option<KmsKeyDetail> {
+    get_runtime().block_on(get_kms_key_detail_async(key_id))
+}
+
+async fn get_kms_key_detail_async(key_id: &str) -> Option<KmsKeyDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kms, &config);
+
+    let output = client.describe_key().key_id(key_id).send().await.ok()?;
+    let metadata = output.key_metadata()?;
+
+    // 별칭/교체/정책/권한 부여/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let aliases: Vec<AliasListEntry> = match client
+        .list_aliases()
+        .key_id(key_id)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(aliases) => aliases,
+        Err(e) => {
+            tracing::warn!("Error listing aliases for {}: {:?}", key_id, e);
+            Vec::new()
+        }
+    };
+    let mut alias_names: Vec<String> = aliases
+        .iter()
+        .filter_map(|alias| alias.alias_name())
+        .map(str::to_string)
+        .collect();
+    alias_names.sort();
+
+    let mut detail = map_key_detail(metadata, alias_names);
+
+    if supports_rotation(metadata) {
+        match client.get_key_rotation_status().key_id(key_id).send().await {
+            Ok(status) => {
+                detail.rotation_enabled = Some(status.key_rotation_enabled());
+                detail.rotation_period_days = status.rotation_period_in_days();
+                detail.next_rotation = status
+                    .next_rotation_date()
+                    .map(|date| format_timestamp(Some(date)));
+            }
+            Err(e) => tracing::warn!("Error getting rotation status for {}: {:?}", key_id, e),
+        }
+    }
+
+    match client
+        .get_key_policy()
+        .key_id(key_id)
+        .policy_name("default")
+        .send()
+        .await
+    {
+        Ok(output) => {
+            detail.policy_statements = summarize_key_policy(output.policy().unwrap_or_default());
+        }
+        Err(e) => tracing::warn!("Error getting key policy for {}: {:?}", key_id, e),
+    }
+
+    match client
+        .list_grants()
+        .key_id(key_id)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(grants) => detail.grants = grants.iter().map(map_grant).collect(),
+        Err(e) => tracing::warn!("Error listing grants for {}: {:?}", key_id, e),
+    }
+
+    match client.list_resource_tags().key_id(key_id).send().await {
+        Ok(output) => {
+            detail.tags = output
+                .tags()
+                .iter()
+                .map(|tag| (tag.tag_key().to_string(), tag.tag_value().to_string()))
+                .collect();
+            detail.tags.sort();
+        }
+        Err(e) => tracing::warn!("Error listing tags for {}: {:?}", key_id, e),
+    }
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 자동 교체는 KMS에서 생성한 대칭 암호화 키만 지원
+fn supports_rotation(metadata: &KeyMetadata) -> bool {
+    metadata.key_spec() == Some(&KeySpec::SymmetricDefault)
+        && metadata.origin() == Some(&OriginType::AwsKms)
+        && metadata.key_usage() == Some(&KeyUsageType::EncryptDecrypt)
+}
+
+// 대상 키 ID별 별칭 이름 (정렬)
+fn group_aliases(aliases: &[AliasListEntry]) -> HashMap<String, Vec<String>> {
+    let mut grouped: HashMap<String, Vec<String>> = HashMap::new();
+    for alias in aliases {
+        if let (Some(key_id), Some(name)) = (alias.target_key_id(), alias.alias_name()) {
+            grouped
+                .entry(key_id.to_string())
+                .or_default()
+                .push(name.to_string());
+        }
+    }
+    for names in grouped.values_mut() {
+        names.sort();
+    }
+    grouped
+}
+
+// 목록에는 키 상태와 키 사양을 함께 표시
+fn map_key_resource(metadata: &KeyMetadata, aliases: &[String]) -> AwsResource {
+    AwsResource {
+        name: key_display_name(metadata.key_id(), aliases),
+        id: metadata.key_id().to_string(),
+        state: metadata
+            .key_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: metadata
+            .key_spec()
+            .map(|spec| spec.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_key_detail(metadata: &KeyMetadata, aliases: Vec<String>) -> KmsKeyDetail {
+    KmsKeyDetail {
+        name: key_display_name(metadata.key_id(), &aliases),
+        key_id: metadata.key_id().to_string(),
+        arn: metadata.arn().unwrap_or_default().to_string(),
+        aliases,
+        description: metadata.description().unwrap_or_default().to_string(),
+        state: metadata
+            .key_state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        key_spec: metadata
+            .key_spec()
+            .map(|spec| spec.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        key_usage: metadata
+            .key_usage()
+            .map(|usage| usage.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        origin: metadata
+            .origin()
+            .map(|origin| origin.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        multi_region: metadata.multi_region().unwrap_or(false),
+        created: format_timestamp(metadata.creation_date()),
+        deletion_date: metadata
+            .deletion_date()
+            .map(|date| format_timestamp(Some(date))),
+        rotation_enabled: None,
+        rotation_period_days: None,
+        next_rotation: None,
+        policy_statements: Vec::new(),
+        grants: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_grant(grant: &GrantListEntry) -> KmsGrant {
+    // 서비스 주체에 부여된 권한은 grantee_service_principal에 담김
+    let grantee = grant
+        .grantee_principal()
+        .or(grant.grantee_service_principal())
+        .unwrap_or_default();
+    let retiring = grant
+        .retiring_principal()
+        .or(grant.retiring_service_principal())
+        .unwrap_or_default();
+    KmsGrant {
+        name: grant.name().unwrap_or_default().to_string(),
+        grantee_principal: grantee.to_string(),
+        retiring_principal: retiring.to_string(),
+        operations: grant
+            .operations()
+            .iter()
+            .map(|operation| operation.as_str().to_string())
+            .collect(),
+        created: format_timestamp(grant.creation_date()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_aliases, map_grant, map_key_detail, map_key_resource, supports_rotation};
+    use aws_sdk_kms::primitives::DateTime;
+    use aws_sdk_kms::types::{
+        AliasListEntry, GrantListEntry, GrantOperation, KeyMetadata, KeySpec, KeyState,
+        KeyUsageType, OriginType,
+    };
+
+    fn metadata(key_spec: KeySpec, origin: OriginType) -> KeyMetadata {
+        KeyMetadata::builder()
+            .key_id("1234abcd-12ab-34cd-56ef-1234567890ab")
+            .arn("arn:aws:kms:ap-northeast-2:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab")
+            .key_state(KeyState::Enabled)
+            .key_spec(key_spec)
+            .key_usage(KeyUsageType::EncryptDecrypt)
+            .origin(origin)
+            .creation_date(DateTime::from_secs(1_748_736_000))
+            .build()
+            .expect("key metadata")
+    }
+
+    #[test]
+    fn map_key_resource_uses_alias_name_and_key_spec() {
+        let aliases = group_aliases(&[
+            AliasListEntry::builder()
+                .alias_name("alias/app-data")
+                .target_key_id("1234abcd-12ab-34cd-56ef-1234567890ab")
+                .build(),
+            AliasListEntry::builder().alias_name("alias/aws/s3").build(),
+        ]);
+        assert_eq!(aliases.len(), 1);
+
+        let key = metadata(KeySpec::SymmetricDefault, OriginType::AwsKms);
+        let resource = map_key_resource(&key, &aliases["1234abcd-12ab-34cd-56ef-1234567890ab"]);
+        assert_eq!(resource.name, "app-data");
+        assert_eq!(resource.id, "1234abcd-12ab-34cd-56ef-1234567890ab");
+        assert_eq!(resource.state, "Enabled");
+        assert_eq!(resource.cidr, "SYMMETRIC_DEFAULT");
+    }
+
+    #[test]
+    fn map_key_detail_reads_metadata_and_rotation_support() {
+        let key = metadata(KeySpec::SymmetricDefault, OriginType::AwsKms);
+        assert!(supports_rotation(&key));
+        let detail = map_key_detail(&key, vec![]);
+        assert_eq!(detail.name, "1234abcd-12ab-34cd-56ef-1234567890ab");
+        assert_eq!(detail.key_usage, "ENCRYPT_DECRYPT");
+        assert_eq!(detail.origin, "AWS_KMS");
+        assert_eq!(detail.created, "2025-06-01T00:00:00Z");
+        assert_eq!(detail.deletion_date, None);
+        assert_eq!(detail.rotation_enabled, None);
+
+        assert!(!supports_rotation(&metadata(
+            KeySpec::Rsa2048,
+            OriginType::AwsKms
+        )));
+        assert!(!supports_rotation(&metadata(
+            KeySpec::SymmetricDefault,
+            OriginType::External
+        )));
+    }
+
+    #[test]
+    fn map_grant_falls_back_to_service_principal() {
+        let grant = GrantListEntry::builder()
+            .grantee_service_principal("logs.amazonaws.com")
+            .operations(GrantOperation::Encrypt)
+            .operations(GrantOperation::Decrypt)
+            .build();
+        let mapped = map_grant(&grant);
+        assert_eq!(mapped.grantee_principal, "logs.amazonaws.com");
+        assert_eq!(mapped.operations, vec!["Encrypt", "Decrypt"]);
+        assert!(mapped.retiring_principal.is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::aws_cli::arn::Arn;
+pub use crate::aws_cli::lambda_sdk::{get_lambda_detail, list_lambda_functions};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LambdaTrigger {
+    pub event_source_arn: String,
+    pub state: String,
+    pub batch_size: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct LambdaDetail {
+    pub name: String,
+    pub arn: String,
+    pub runtime: String,
+    pub handler: String,
+    pub memory_size: i32,
+    pub timeout: i32,
+    pub role: String,
+    pub description: String,
+    pub package_type: String,
+    pub architectures: Vec<String>,
+    pub last_modified: String,
+    pub code_size: i64,
+    // 값은 비밀일 수 있으므로 키만 보관
+    pub env_var_keys: Vec<String>,
+    pub vpc_id: Option<String>,
+    pub subnet_ids: Vec<String>,
+    pub security_group_ids: Vec<String>,
+    pub layers: Vec<String>,
+    pub triggers: Vec<LambdaTrigger>,
+    pub tags: Vec<(String, String)>,
+}
+
+// 레이어 ARN(arn:aws:lambda:...:layer:<name>:<version>)을 "<name>:<version>"으로 표시
+fn layer_display(layer_arn: &str) -> &str {
+    Arn::parse(layer_arn)
+        .map(|arn| arn.resource_id())
+        .unwrap_or(layer_arn)
+}
+
+impl LambdaDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.lambda_function(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if self.runtime.is_empty() {
+            // 컨테이너 이미지 함수는 런타임이 없음
+            lines.push(format!("| {} | {} |", i18n.md_runtime(), self.package_type));
+        } else {
+            lines.push(format!("| {} | {} |", i18n.md_runtime(), self.runtime));
+            lines.push(format!("| {} | {} |", i18n.md_handler(), self.handler));
+        }
+        if !self.architectures.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_architecture(),
+                self.architectures.join(", ")
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} MB |",
+            i18n.md_memory(),
+            self.memory_size
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_timeout(),
+            i18n.asg_seconds(self.timeout)
+        ));
+        if !self.role.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_iam_role(), self.role));
+        }
+        if !self.last_modified.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_last_modified(),
+                self.last_modified
+            ));
+        }
+
+        // Environment Variables
+        if !self.env_var_keys.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_environment_variables()));
+            for key in &self.env_var_keys {
+                lines.push(format!("- `{}`", key));
+            }
+        }
+
+        // VPC
+        if let Some(ref vpc_id) = self.vpc_id {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_vpc_config()));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| VPC | {} |", vpc_id));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subnets(),
+                self.subnet_ids.join(", ")
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_security_groups(),
+                self.security_group_ids.join(", ")
+            ));
+        }
+
+        // Layers
+        if !self.layers.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_layers()));
+            for layer in &self.layers {
+                lines.push(format!("- {}", layer_display(layer)));
+            }
+        }
+
+        // Triggers
+        if !self.triggers.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_triggers()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_source(),
+                i18n.md_state(),
+                i18n.md_batch_size()
+            ));
+            lines.push("|:---|:---|---:|".to_string());
+            for trigger in &self.triggers {
+                let batch_size = trigger
+                    .batch_size
+                    .map(|size| size.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    trigger.event_source_arn, trigger.state, batch_size
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LambdaDetail, LambdaTrigger, layer_display};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> LambdaDetail {
+        LambdaDetail {
+            name: "orders-api".to_string(),
+            arn: "arn:aws:lambda:ap-northeast-2:123456789012:function:orders-api".to_string(),
+            runtime: "python3.12".to_string(),
+            handler: "app.handler".to_string(),
+            memory_size: 512,
+            timeout: 30,
+            role: "arn:aws:iam::123456789012:role/orders-api".to_string(),
+            description: String::new(),
+            package_type: "Zip".to_string(),
+            architectures: vec!["arm64".to_string()],
+            last_modified: "2026-01-01T00:00:00.000+0000".to_string(),
+            code_size: 1024,
+            env_var_keys: vec!["DB_HOST".to_string(), "DB_PASSWORD".to_string()],
+            vpc_id: Some("vpc-1".to_string()),
+            subnet_ids: vec!["subnet-a".to_string(), "subnet-b".to_string()],
+            security_group_ids: vec!["sg-0123".to_string()],
+            layers: vec![
+                "arn:aws:lambda:ap-northeast-2:123456789012:layer:shared-libs:3".to_string(),
+            ],
+            triggers: vec![LambdaTrigger {
+                event_source_arn: "arn:aws:sqs:ap-northeast-2:123456789012:orders".to_string(),
+                state: "Enabled".to_string(),
+                batch_size: Some(10),
+            }],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_lambda_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Lambda Function (orders-api)"));
+        assert!(markdown.contains("| Runtime | python3.12 |"));
+        assert!(markdown.contains("| Handler | app.handler |"));
+        assert!(markdown.contains("| Memory | 512 MB |"));
+        assert!(markdown.contains("| Timeout | 30s |"));
+        assert!(markdown.contains("- `DB_PASSWORD`"));
+        assert!(markdown.contains("| Subnets | subnet-a, subnet-b |"));
+        assert!(markdown.contains("- shared-libs:3"));
+        assert!(
+            markdown.contains("| arn:aws:sqs:ap-northeast-2:123456789012:orders | Enabled | 10 |")
+        );
+        assert!(markdown.contains("| Env | prod |"));
+    }
+
+    #[test]
+    fn scenario_lambda_container_without_vpc() {
+        let mut detail = sample_detail();
+        detail.runtime = String::new();
+        detail.handler = String::new();
+        detail.package_type = "Image".to_string();
+        detail.vpc_id = None;
+        detail.layers.clear();
+        detail.triggers.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 런타임 | Image |"));
+        assert!(!markdown.contains("| 핸들러 |"));
+        assert!(!markdown.contains("### VPC"));
+        assert!(!markdown.contains("### 트리거"));
+    }
+
+    #[test]
+    fn layer_display_strips_arn_prefix() {
+        assert_eq!(
+            layer_display("arn:aws:lambda:ap-northeast-2:123456789012:layer:shared-libs:3"),
+            "shared-libs:3"
+        );
+        assert_eq!(layer_display("not-an-arn"), "not-an-arn");
+    }
+}
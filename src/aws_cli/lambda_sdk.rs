@@ -0,0 +1,239 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::lambda::{LambdaDetail, LambdaTrigger};
+
+/// List all Lambda functions using AWS SDK
+pub fn list_lambda_functions() -> Vec<AwsResource> {
+    get_runtime().block_on(list_lambda_functions_async())
+}
+
+async fn list_lambda_functions_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_lambda, &config);
+
+    let result = client
+        .list_functions()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(functions) => functions.iter().map(map_function_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing Lambda functions: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get Lambda function detail (configuration, tags and event source mappings) using AWS SDK
+pub fn get_lambda_detail(name: &str) -> Option<LambdaDetail> {
+    get_runtime().block_on(get_lambda_detail_async(name))
+}
+
+async fn get_lambda_detail_async(name: &str) -> Option<LambdaDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_lambda, &config);
+
+    let output = client
+        .get_function()
+        .function_name(name)
+        .send()
+        .await
+        .ok()?;
+    let mut detail = map_function_detail(output.configuration()?);
+
+    if let Some(tags) = output.tags() {
+        let mut tags: Vec<(String, String)> = tags
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        tags.sort();
+        detail.tags = tags;
+    }
+
+    // 트리거 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_event_source_mappings()
+        .function_name(name)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(mappings) => detail.triggers = mappings.iter().map(map_trigger).collect(),
+        Err(e) => tracing::warn!("Error listing event source mappings for {}: {:?}", name, e),
+    }
+
+    Some(detail)
+}
+
+fn map_function_resource(function: &aws_sdk_lambda::types::FunctionConfiguration) -> AwsResource {
+    let name = function.function_name().unwrap_or_default().to_string();
+    // 컨테이너 이미지 함수는 런타임 대신 패키지 유형 표시
+    let runtime = function
+        .runtime()
+        .map(|r| r.as_str().to_string())
+        .or_else(|| function.package_type().map(|p| p.as_str().to_string()))
+        .unwrap_or_default();
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: runtime,
+        az: String::new(),
+        cidr: format!("{} MB", function.memory_size().unwrap_or(0)),
+        owner_id: String::new(),
+    }
+}
+
+fn map_trigger(mapping: &aws_sdk_lambda::types::EventSourceMappingConfiguration) -> LambdaTrigger {
+    LambdaTrigger {
+        event_source_arn: mapping.event_source_arn().unwrap_or_default().to_string(),
+        state: mapping.state().unwrap_or_default().to_string(),
+        batch_size: mapping.batch_size(),
+    }
+}
+
+fn map_function_detail(function: &aws_sdk_lambda::types::FunctionConfiguration) -> LambdaDetail {
+    let mut env_var_keys: Vec<String> = function
+        .environment()
+        .and_then(|env| env.variables())
+        .map(|vars| vars.keys().cloned().collect())
+        .unwrap_or_default();
+    env_var_keys.sort();
+
+    let vpc = function
+        .vpc_config()
+        .filter(|vpc| vpc.vpc_id().is_some_and(|id| !id.is_empty()));
+
+    LambdaDetail {
+        name: function.function_name().unwrap_or_default().to_string(),
+        arn: function.function_arn().unwrap_or_default().to_string(),
+        runtime: function
+            .runtime()
+            .map(|r| r.as_str().to_string())
+            .unwrap_or_default(),
+        handler: function.handler().unwrap_or_default().to_string(),
+        memory_size: function.memory_size().unwrap_or(0),
+        timeout: function.timeout().unwrap_or(0),
+        role: function.role().unwrap_or_default().to_string(),
+        description: function.description().unwrap_or_default().to_string(),
+        package_type: function
+            .package_type()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        architectures: function
+            .architectures()
+            .iter()
+            .map(|a| a.as_str().to_string())
+            .collect(),
+        last_modified: function.last_modified().unwrap_or_default().to_string(),
+        code_size: function.code_size(),
+        env_var_keys,
+        vpc_id: vpc.and_then(|v| v.vpc_id()).map(|s| s.to_string()),
+        subnet_ids: vpc.map(|v| v.subnet_ids().to_vec()).unwrap_or_default(),
+        security_group_ids: vpc
+            .map(|v| v.security_group_ids().to_vec())
+            .unwrap_or_default(),
+        layers: function
+            .layers()
+            .iter()
+            .filter_map(|l| l.arn().map(|s| s.to_string()))
+            .collect(),
+        triggers: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_function_detail, map_function_resource, map_trigger};
+    use aws_sdk_lambda::types::{
+        Architecture, EnvironmentResponse, EventSourceMappingConfiguration, FunctionConfiguration,
+        Layer, PackageType, Runtime, VpcConfigResponse,
+    };
+
+    #[test]
+    fn map_function_resource_uses_runtime_or_package_type() {
+        let zip = FunctionConfiguration::builder()
+            .function_name("orders-api")
+            .runtime(Runtime::from("python3.12"))
+            .memory_size(512)
+            .build();
+        let resource = map_function_resource(&zip);
+        assert_eq!(resource.id, "orders-api");
+        assert_eq!(resource.state, "python3.12");
+        assert_eq!(resource.cidr, "512 MB");
+
+        let image = FunctionConfiguration::builder()
+            .function_name("orders-worker")
+            .package_type(PackageType::Image)
+            .build();
+        assert_eq!(map_function_resource(&image).state, "Image");
+    }
+
+    #[test]
+    fn map_function_detail_keeps_env_keys_only_and_reads_vpc() {
+        let env = EnvironmentResponse::builder()
+            .variables("DB_PASSWORD", "secret")
+            .variables("DB_HOST", "db.internal")
+            .build();
+        let vpc = VpcConfigResponse::builder()
+            .vpc_id("vpc-1")
+            .subnet_ids("subnet-a")
+            .subnet_ids("subnet-b")
+            .security_group_ids("sg-0123")
+            .build();
+        let layer = Layer::builder()
+            .arn("arn:aws:lambda:ap-northeast-2:123456789012:layer:shared-libs:3")
+            .build();
+
+        let function = FunctionConfiguration::builder()
+            .function_name("orders-api")
+            .runtime(Runtime::from("python3.12"))
+            .handler("app.handler")
+            .timeout(30)
+            .environment(env)
+            .vpc_config(vpc)
+            .layers(layer)
+            .architectures(Architecture::Arm64)
+            .build();
+
+        let detail = map_function_detail(&function);
+        assert_eq!(
+            detail.env_var_keys,
+            vec!["DB_HOST".to_string(), "DB_PASSWORD".to_string()]
+        );
+        assert_eq!(detail.vpc_id.as_deref(), Some("vpc-1"));
+        assert_eq!(detail.subnet_ids.len(), 2);
+        assert_eq!(detail.security_group_ids, vec!["sg-0123".to_string()]);
+        assert_eq!(detail.layers.len(), 1);
+        assert_eq!(detail.architectures, vec!["arm64".to_string()]);
+        assert_eq!(detail.timeout, 30);
+    }
+
+    #[test]
+    fn map_function_detail_ignores_empty_vpc_config() {
+        let function = FunctionConfiguration::builder()
+            .function_name("orders-api")
+            .vpc_config(VpcConfigResponse::builder().vpc_id("").build())
+            .build();
+        let detail = map_function_detail(&function);
+        assert!(detail.vpc_id.is_none());
+        assert!(detail.subnet_ids.is_empty());
+    }
+
+    #[test]
+    fn map_trigger_reads_source_state_and_batch_size() {
+        let mapping = EventSourceMappingConfiguration::builder()
+            .event_source_arn("arn:aws:sqs:ap-northeast-2:123456789012:orders")
+            .state("Enabled")
+            .batch_size(10)
+            .build();
+        let trigger = map_trigger(&mapping);
+        assert_eq!(trigger.state, "Enabled");
+        assert_eq!(trigger.batch_size, Some(10));
+    }
+}
@@ -0,0 +1,668 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use crate::locale::{self, SizeUnit};
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchTemplatesResponse {
+    launch_templates: Vec<LaunchTemplateEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchTemplateEntry {
+    launch_template_id: String,
+    #[serde(default)]
+    launch_template_name: String,
+    #[serde(default)]
+    create_time: String,
+    #[serde(default)]
+    created_by: String,
+    #[serde(default)]
+    default_version_number: i64,
+    #[serde(default)]
+    latest_version_number: i64,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchTemplateVersionsResponse {
+    launch_template_versions: Vec<LaunchTemplateVersionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchTemplateVersionEntry {
+    version_number: i64,
+    #[serde(default)]
+    version_description: String,
+    #[serde(default)]
+    create_time: String,
+    #[serde(default)]
+    created_by: String,
+    #[serde(default)]
+    default_version: bool,
+    #[serde(default)]
+    launch_template_data: Option<LaunchTemplateData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LaunchTemplateData {
+    #[serde(default)]
+    image_id: String,
+    #[serde(default)]
+    instance_type: String,
+    #[serde(default)]
+    key_name: String,
+    #[serde(default)]
+    iam_instance_profile: Option<IamInstanceProfile>,
+    #[serde(default)]
+    security_group_ids: Vec<String>,
+    #[serde(default)]
+    security_groups: Vec<String>,
+    #[serde(default)]
+    block_device_mappings: Vec<BlockDeviceMapping>,
+    #[serde(default)]
+    user_data: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IamInstanceProfile {
+    #[serde(default)]
+    arn: String,
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct BlockDeviceMapping {
+    #[serde(default)]
+    device_name: String,
+    #[serde(default)]
+    ebs: Option<EbsBlockDevice>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EbsBlockDevice {
+    #[serde(default)]
+    volume_size: Option<i32>,
+    #[serde(default)]
+    volume_type: String,
+    #[serde(default)]
+    iops: Option<i32>,
+    #[serde(default)]
+    encrypted: bool,
+    #[serde(default)]
+    delete_on_termination: bool,
+    #[serde(default)]
+    snapshot_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LaunchTemplateBlockDevice {
+    pub device_name: String,
+    // 스냅샷에서 만드는 볼륨은 크기를 생략할 수 있음
+    pub size_gb: Option<i32>,
+    pub volume_type: String,
+    pub iops: Option<i32>,
+    pub encrypted: bool,
+    pub delete_on_termination: bool,
+    pub snapshot_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LaunchTemplateVersionDetail {
+    pub number: i64,
+    pub is_default: bool,
+    pub description: String,
+    pub created: String,
+    pub created_by: String,
+    pub image_id: String,
+    pub instance_type: String,
+    pub key_name: String,
+    // 이름이 없으면 ARN
+    pub iam_instance_profile: String,
+    // 보안 그룹 ID, 또는 EC2-Classic/기본 VPC용 보안 그룹 이름
+    pub security_groups: Vec<String>,
+    pub block_devices: Vec<LaunchTemplateBlockDevice>,
+    // base64 디코딩한 스크립트 (없으면 None)
+    pub user_data: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LaunchTemplateDetail {
+    pub name: String,
+    pub id: String,
+    pub default_version: i64,
+    pub latest_version: i64,
+    pub created: String,
+    pub created_by: String,
+    // 기본 버전과 최신 버전 (필요하면 ASG가 고정한 버전도), 번호 내림차순
+    pub versions: Vec<LaunchTemplateVersionDetail>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl LaunchTemplateDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    /// The version an Auto Scaling group launches with: `$Latest`, `$Default`
+    /// (also used when no version is set) or a version number.
+    pub fn resolve_version(&self, version: Option<&str>) -> Option<&LaunchTemplateVersionDetail> {
+        let number = match version.unwrap_or("$Default") {
+            "$Latest" => self.latest_version,
+            "$Default" => self.default_version,
+            version => version.parse().ok()?,
+        };
+        self.versions.iter().find(|v| v.number == number)
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.asg_launch_template(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!(
+                "| {} | {} |",
+                i18n.md_default_version(),
+                self.default_version
+            ),
+            format!("| {} | {} |", i18n.md_latest_version(), self.latest_version),
+            format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                or_dash(&locale::date(&self.created))
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_created_by(),
+                or_dash(&self.created_by)
+            ),
+        ];
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        for version in &self.versions {
+            lines.push(String::new());
+            lines.push(format!(
+                "### {}\n",
+                version.heading(&i18n, self.latest_version)
+            ));
+            lines.extend(version.markdown_lines(&i18n, "####"));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+impl LaunchTemplateVersionDetail {
+    /// `Version 3 (Default, Latest)`
+    pub fn heading(&self, i18n: &I18n, latest_version: i64) -> String {
+        let mut markers = Vec::new();
+        if self.is_default {
+            markers.push(i18n.md_default());
+        }
+        if self.number == latest_version {
+            markers.push(i18n.md_latest());
+        }
+        if markers.is_empty() {
+            format!("{} {}", i18n.md_version(), self.number)
+        } else {
+            format!(
+                "{} {} ({})",
+                i18n.md_version(),
+                self.number,
+                markers.join(", ")
+            )
+        }
+    }
+
+    /// Launch settings table, storage and user data; sub-sections use `heading`
+    /// so the block fits both the standalone document and the ASG document.
+    pub fn markdown_lines(&self, i18n: &I18n, heading: &str) -> Vec<String> {
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        let security_groups: Vec<String> = self
+            .security_groups
+            .iter()
+            .map(|group| name_index::label(group))
+            .collect();
+
+        let mut lines = vec![
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| AMI | {} |", or_dash(&self.image_id)),
+            format!(
+                "| {} | {} |",
+                i18n.md_instance_type(),
+                or_dash(&self.instance_type)
+            ),
+            format!("| {} | {} |", i18n.md_key_pair(), or_dash(&self.key_name)),
+            format!(
+                "| {} | {} |",
+                i18n.md_iam_instance_profile(),
+                or_dash(&self.iam_instance_profile)
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_security_groups(),
+                or_dash(&security_groups.join(", "))
+            ),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_created_at(),
+                locale::date(&self.created)
+            ));
+        }
+
+        if !self.block_devices.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("{} {}\n", heading, i18n.md_storage()));
+            lines.push(format!(
+                "| {} | {} | {} | IOPS | {} | {} |",
+                i18n.md_device(),
+                i18n.md_size(),
+                i18n.md_type(),
+                i18n.md_encrypted(),
+                i18n.md_delete_on_termination()
+            ));
+            lines.push("|:---|---:|:---|---:|:---:|:---:|".to_string());
+            for device in &self.block_devices {
+                let size = device
+                    .size_gb
+                    .map(|size| locale::size(size, SizeUnit::Gb))
+                    .unwrap_or_else(|| "-".to_string());
+                let iops = device
+                    .iops
+                    .map(locale::count)
+                    .unwrap_or_else(|| "-".to_string());
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    device.device_name,
+                    size,
+                    or_dash(&device.volume_type),
+                    iops,
+                    if device.encrypted { "✓" } else { "-" },
+                    if device.delete_on_termination {
+                        "✓"
+                    } else {
+                        "-"
+                    }
+                ));
+            }
+        }
+
+        if let Some(ref user_data) = self.user_data {
+            lines.push(String::new());
+            lines.push(format!("{} {}\n", heading, i18n.md_user_data()));
+            lines.push("```bash".to_string());
+            lines.push(user_data.trim_end().to_string());
+            lines.push("```".to_string());
+        }
+
+        lines
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+fn decode_user_data(user_data: &str) -> Option<String> {
+    if user_data.is_empty() {
+        return None;
+    }
+    let decoded = general_purpose::STANDARD.decode(user_data).ok()?;
+    let decoded = String::from_utf8_lossy(&decoded).to_string();
+    if decoded.trim().is_empty() {
+        None
+    } else {
+        Some(decoded)
+    }
+}
+
+/// Parse `describe-launch-templates` output, sorted by name.
+pub(crate) fn parse_launch_templates_output(output: &str) -> Option<Vec<LaunchTemplateDetail>> {
+    let response: LaunchTemplatesResponse = serde_json::from_str(output).ok()?;
+
+    let mut templates: Vec<LaunchTemplateDetail> = response
+        .launch_templates
+        .into_iter()
+        .map(|template| {
+            let mut tags: Vec<(String, String)> = template
+                .tags
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            LaunchTemplateDetail {
+                name: template.launch_template_name,
+                id: template.launch_template_id,
+                default_version: template.default_version_number,
+                latest_version: template.latest_version_number,
+                created: template.create_time,
+                created_by: template.created_by,
+                versions: Vec::new(),
+                tags,
+            }
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    Some(templates)
+}
+
+/// Parse `describe-launch-template-versions` output, newest version first.
+pub(crate) fn parse_launch_template_versions_output(
+    output: &str,
+) -> Option<Vec<LaunchTemplateVersionDetail>> {
+    let response: LaunchTemplateVersionsResponse = serde_json::from_str(output).ok()?;
+
+    let mut versions: Vec<LaunchTemplateVersionDetail> = response
+        .launch_template_versions
+        .into_iter()
+        .map(|version| {
+            let data = version.launch_template_data.unwrap_or_default();
+            let iam_instance_profile = data
+                .iam_instance_profile
+                .map(|profile| {
+                    if profile.name.is_empty() {
+                        profile.arn
+                    } else {
+                        profile.name
+                    }
+                })
+                .unwrap_or_default();
+            let mut security_groups = data.security_group_ids;
+            for group in data.security_groups {
+                if !security_groups.contains(&group) {
+                    security_groups.push(group);
+                }
+            }
+            LaunchTemplateVersionDetail {
+                number: version.version_number,
+                is_default: version.default_version,
+                description: version.version_description,
+                created: version.create_time,
+                created_by: version.created_by,
+                image_id: data.image_id,
+                instance_type: data.instance_type,
+                key_name: data.key_name,
+                iam_instance_profile,
+                security_groups,
+                block_devices: data
+                    .block_device_mappings
+                    .into_iter()
+                    // 인스턴스 스토어 매핑은 EBS 설정이 없음
+                    .filter_map(|mapping| {
+                        let ebs = mapping.ebs?;
+                        Some(LaunchTemplateBlockDevice {
+                            device_name: mapping.device_name,
+                            size_gb: ebs.volume_size,
+                            volume_type: ebs.volume_type,
+                            iops: ebs.iops,
+                            encrypted: ebs.encrypted,
+                            delete_on_termination: ebs.delete_on_termination,
+                            snapshot_id: ebs.snapshot_id,
+                        })
+                    })
+                    .collect(),
+                user_data: decode_user_data(&data.user_data),
+            }
+        })
+        .collect();
+    // $Default와 $Latest가 같은 버전이면 두 번 내려옴
+    versions.sort_by_key(|version| std::cmp::Reverse(version.number));
+    versions.dedup_by_key(|version| version.number);
+    Some(versions)
+}
+
+pub fn list_launch_templates() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&["ec2", "describe-launch-templates", "--output", "json"]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let templates: Vec<AwsResource> = parse_launch_templates_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(template_resource)
+        .collect();
+    name_index::record_resources(&templates);
+    templates
+}
+
+// 목록에는 기본/최신 버전 번호를 함께 표시
+fn template_resource(template: LaunchTemplateDetail) -> AwsResource {
+    AwsResource {
+        name: if template.name.is_empty() {
+            template.id.clone()
+        } else {
+            template.name.clone()
+        },
+        cidr: format!("v{}", template.default_version),
+        az: format!("latest v{}", template.latest_version),
+        id: template.id,
+        state: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+pub fn get_launch_template_detail(template_id: &str) -> Option<LaunchTemplateDetail> {
+    get_launch_template_with_version(template_id, None)
+}
+
+/// Template detail with the default and latest versions, plus `version`
+/// when it pins another version number (as an Auto Scaling group may).
+pub fn get_launch_template_with_version(
+    template_id: &str,
+    version: Option<&str>,
+) -> Option<LaunchTemplateDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-launch-templates",
+        "--launch-template-ids",
+        template_id,
+        "--output",
+        "json",
+    ])?;
+    let mut template = parse_launch_templates_output(&output)?.into_iter().next()?;
+
+    let mut args = vec![
+        "ec2",
+        "describe-launch-template-versions",
+        "--launch-template-id",
+        template_id,
+        "--versions",
+        "$Default",
+        "$Latest",
+    ];
+    if let Some(version) = version.filter(|v| v.parse::<i64>().is_ok()) {
+        args.push(version);
+    }
+    args.extend(["--output", "json"]);
+    template.versions = run_aws_cli(&args)
+        .and_then(|output| parse_launch_template_versions_output(&output))
+        .unwrap_or_default();
+    Some(template)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_launch_template_versions_output, parse_launch_templates_output, template_resource,
+    };
+    use crate::i18n::Language;
+
+    const TEMPLATES: &str = r#"
+    {
+      "LaunchTemplates": [
+        {
+          "LaunchTemplateId": "lt-0b2",
+          "LaunchTemplateName": "worker",
+          "DefaultVersionNumber": 1,
+          "LatestVersionNumber": 1
+        },
+        {
+          "LaunchTemplateId": "lt-0a1",
+          "LaunchTemplateName": "web",
+          "CreateTime": "2026-03-02T01:02:03.000Z",
+          "CreatedBy": "arn:aws:iam::111122223333:user/ops",
+          "DefaultVersionNumber": 2,
+          "LatestVersionNumber": 3,
+          "Tags": [{"Key": "Team", "Value": "platform"}]
+        }
+      ]
+    }
+    "#;
+
+    const VERSIONS: &str = r#"
+    {
+      "LaunchTemplateVersions": [
+        {
+          "LaunchTemplateId": "lt-0a1",
+          "VersionNumber": 2,
+          "VersionDescription": "gp3 root",
+          "DefaultVersion": true,
+          "LaunchTemplateData": {
+            "ImageId": "ami-0abc",
+            "InstanceType": "t3.small",
+            "KeyName": "ops",
+            "IamInstanceProfile": {"Arn": "arn:aws:iam::111122223333:instance-profile/web", "Name": ""},
+            "SecurityGroupIds": ["sg-1"],
+            "BlockDeviceMappings": [
+              {"DeviceName": "/dev/xvda", "Ebs": {"VolumeSize": 20, "VolumeType": "gp3", "Iops": 3000, "Encrypted": true, "DeleteOnTermination": true}},
+              {"DeviceName": "/dev/sdb", "VirtualName": "ephemeral0"}
+            ],
+            "UserData": "IyEvYmluL2Jhc2gKZWNobyBoZWxsbwo="
+          }
+        },
+        {
+          "LaunchTemplateId": "lt-0a1",
+          "VersionNumber": 3,
+          "DefaultVersion": false,
+          "LaunchTemplateData": {
+            "ImageId": "ami-0def",
+            "InstanceType": "t3.medium"
+          }
+        },
+        {
+          "LaunchTemplateId": "lt-0a1",
+          "VersionNumber": 3,
+          "DefaultVersion": false,
+          "LaunchTemplateData": {
+            "ImageId": "ami-0def",
+            "InstanceType": "t3.medium"
+          }
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_launch_template_outputs_read_templates_and_versions() {
+        let templates = parse_launch_templates_output(TEMPLATES).expect("templates");
+        assert_eq!(templates.len(), 2);
+        // 이름 순으로 정렬
+        assert_eq!(templates[0].name, "web");
+        assert_eq!(templates[0].default_version, 2);
+        assert_eq!(templates[0].latest_version, 3);
+        assert_eq!(
+            templates[0].tags,
+            vec![("Team".to_string(), "platform".to_string())]
+        );
+        let resource = template_resource(templates[1].clone());
+        assert_eq!(resource.name, "worker");
+        assert_eq!(resource.cidr, "v1");
+
+        let versions = parse_launch_template_versions_output(VERSIONS).expect("versions");
+        // 최신 버전부터, 중복 제거
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].number, 3);
+        assert!(versions[0].user_data.is_none());
+        let default = &versions[1];
+        assert!(default.is_default);
+        assert_eq!(default.image_id, "ami-0abc");
+        assert_eq!(
+            default.iam_instance_profile,
+            "arn:aws:iam::111122223333:instance-profile/web"
+        );
+        // 인스턴스 스토어는 제외
+        assert_eq!(default.block_devices.len(), 1);
+        assert_eq!(default.block_devices[0].size_gb, Some(20));
+        assert_eq!(
+            default.user_data.as_deref(),
+            Some("#!/bin/bash\necho hello\n")
+        );
+    }
+
+    #[test]
+    fn launch_template_markdown_renders_versions_and_resolves_asg_version() {
+        let mut template = parse_launch_templates_output(TEMPLATES)
+            .expect("templates")
+            .remove(0);
+        template.versions = parse_launch_template_versions_output(VERSIONS).expect("versions");
+
+        assert_eq!(template.resolve_version(None).map(|v| v.number), Some(2));
+        assert_eq!(
+            template.resolve_version(Some("$Latest")).map(|v| v.number),
+            Some(3)
+        );
+        assert_eq!(template.resolve_version(Some("1")), None);
+
+        let md = template.to_markdown(Language::English);
+        assert!(md.contains("## Launch Template (web - lt-0a1)"));
+        assert!(md.contains("| Default Version | 2 |"));
+        assert!(md.contains("### Version 3 (Latest)"));
+        assert!(md.contains("### Version 2 (Default)"));
+        assert!(md.contains("| AMI | ami-0abc |"));
+        assert!(md.contains("| Instance Type | t3.small |"));
+        assert!(md.contains("| /dev/xvda | 20 GB | gp3 | 3000 | ✓ | ✓ |"));
+        assert!(md.contains("#### User Data\n\n```bash\n#!/bin/bash\necho hello\n```"));
+        assert!(md.contains("| Tag-Team | platform |"));
+    }
+}
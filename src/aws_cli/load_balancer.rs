@@ -1,6 +1,8 @@
+use crate::aws_cli::arn::Arn;
 use crate::aws_cli::common::{AwsResource, run_aws_cli};
+use crate::aws_cli::name_index;
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -114,7 +116,7 @@ struct TargetHealth {
     state: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct LoadBalancerDetail {
     pub name: String,
     pub arn: String,
@@ -130,14 +132,14 @@ pub struct LoadBalancerDetail {
     pub target_groups: Vec<TargetGroupInfo>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ListenerInfo {
     pub port: i32,
     pub protocol: String,
     pub default_action: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TargetGroupInfo {
     pub name: String,
     #[allow(dead_code)]
@@ -152,7 +154,7 @@ pub struct TargetGroupInfo {
     pub targets: Vec<TargetInfo>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TargetInfo {
     pub id: String,
     pub port: i32,
@@ -162,8 +164,9 @@ pub struct TargetInfo {
 impl LoadBalancerDetail {
     pub fn to_markdown(&self, lang: Language) -> String {
         let i18n = I18n::new(lang);
-        // Extract LB ID from ARN (last part after the last /)
-        let lb_id = self.arn.rsplit('/').next().unwrap_or(&self.arn);
+        let lb_id = Arn::parse(&self.arn)
+            .map(|arn| arn.resource_suffix())
+            .unwrap_or(&self.arn);
         let display_name = if self.name.is_empty() {
             format!("NULL - {}", lb_id)
         } else {
@@ -259,7 +262,9 @@ impl LoadBalancerDetail {
                     for target in &tg.targets {
                         lines.push(format!(
                             "| {} | {} | {} |",
-                            target.id, target.port, target.health_status
+                            name_index::label(&target.id),
+                            target.port,
+                            target.health_status
                         ));
                     }
                 }
@@ -301,36 +306,38 @@ pub fn get_load_balancer_detail(lb_arn: &str) -> Option<LoadBalancerDetail> {
 
     let (listeners, target_group_arns) = parse_listener_infos(&listeners_output)?;
 
-    let mut target_groups = Vec::new();
-
-    for tg_arn in &target_group_arns {
-        if let Some(tg_info) = get_target_group_info(tg_arn) {
-            target_groups.push(tg_info);
-        }
-    }
+    let target_groups = get_target_group_infos(&target_group_arns);
     parse_load_balancer_detail_output(&output, listeners, target_groups)
 }
 
-fn get_target_group_info(tg_arn: &str) -> Option<TargetGroupInfo> {
-    let output = run_aws_cli(&[
-        "elbv2",
-        "describe-target-groups",
-        "--target-group-arns",
-        tg_arn,
-        "--output",
-        "json",
-    ])?;
+// describe-target-groups는 ARN을 한 번에 조회하고, target-health는 API 특성상 그룹별로 호출
+fn get_target_group_infos(tg_arns: &[String]) -> Vec<TargetGroupInfo> {
+    if tg_arns.is_empty() {
+        return Vec::new();
+    }
 
-    let health_output = run_aws_cli(&[
-        "elbv2",
-        "describe-target-health",
-        "--target-group-arn",
-        tg_arn,
-        "--output",
-        "json",
-    ])?;
+    let mut args = vec!["elbv2", "describe-target-groups", "--target-group-arns"];
+    args.extend(tg_arns.iter().map(String::as_str));
+    args.extend(["--output", "json"]);
+
+    let Some(output) = run_aws_cli(&args) else {
+        return Vec::new();
+    };
 
-    parse_target_group_info_outputs(&output, &health_output)
+    tg_arns
+        .iter()
+        .filter_map(|tg_arn| {
+            let health_output = run_aws_cli(&[
+                "elbv2",
+                "describe-target-health",
+                "--target-group-arn",
+                tg_arn,
+                "--output",
+                "json",
+            ])?;
+            parse_target_group_info_outputs(&output, tg_arn, &health_output)
+        })
+        .collect()
 }
 
 fn parse_load_balancers_output(output: &str, i18n: &I18n) -> Option<Vec<AwsResource>> {
@@ -352,6 +359,7 @@ fn parse_load_balancers_output(output: &str, i18n: &I18n) -> Option<Vec<AwsResou
                     state: lb.lb_type,
                     az: lb.vpc_id,
                     cidr: lb.dns_name,
+                    owner_id: String::new(),
                 }
             })
             .collect(),
@@ -360,10 +368,14 @@ fn parse_load_balancers_output(output: &str, i18n: &I18n) -> Option<Vec<AwsResou
 
 fn parse_target_group_info_outputs(
     target_group_output: &str,
+    tg_arn: &str,
     target_health_output: &str,
 ) -> Option<TargetGroupInfo> {
     let response: TargetGroupsResponse = serde_json::from_str(target_group_output).ok()?;
-    let tg = response.target_groups.first()?;
+    let tg = response
+        .target_groups
+        .iter()
+        .find(|tg| tg.target_group_arn == tg_arn)?;
 
     let health_response: TargetHealthResponse = serde_json::from_str(target_health_output).ok()?;
     let targets: Vec<TargetInfo> = health_response
@@ -557,6 +569,17 @@ mod tests {
                   "HealthCheckPath": "/health",
                   "HealthyThresholdCount": 2,
                   "UnhealthyThresholdCount": 3
+                },
+                {
+                  "TargetGroupName": "tg-other",
+                  "TargetGroupArn": "arn:aws:elasticloadbalancing:...:targetgroup/tg-other/5678",
+                  "Protocol": "HTTP",
+                  "Port": 8080,
+                  "TargetType": "ip",
+                  "HealthCheckProtocol": "HTTP",
+                  "HealthCheckPath": "/",
+                  "HealthyThresholdCount": 2,
+                  "UnhealthyThresholdCount": 3
                 }
               ]
             }
@@ -574,10 +597,19 @@ mod tests {
             }
         "#;
 
-        let info = parse_target_group_info_outputs(tg_payload, health_payload).expect("tg info");
-        assert_eq!(info.name, "tg-main");
+        let info = parse_target_group_info_outputs(
+            tg_payload,
+            "arn:aws:elasticloadbalancing:...:targetgroup/tg-other/5678",
+            health_payload,
+        )
+        .expect("tg info");
+        assert_eq!(info.name, "tg-other");
+        assert_eq!(info.port, 8080);
         assert_eq!(info.targets.len(), 1);
         assert_eq!(info.targets[0].id, "i-1234");
+        assert!(
+            parse_target_group_info_outputs(tg_payload, "arn:missing", health_payload).is_none()
+        );
     }
 
     #[test]
@@ -636,7 +668,9 @@ mod tests {
     fn load_balancer_markdown_omits_listener_and_target_sections_when_empty() {
         let detail = LoadBalancerDetail {
             name: String::new(),
-            arn: "arn:.../lb-id".to_string(),
+            arn:
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/lb/lb-id"
+                    .to_string(),
             dns_name: "lb.example.com".to_string(),
             lb_type: "application".to_string(),
             scheme: "internal".to_string(),
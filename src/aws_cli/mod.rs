@@ -1,38 +1,195 @@
 #![cfg_attr(test, allow(dead_code, unused_imports))]
 
+pub(crate) mod acm;
+mod acm_sdk;
+mod ami;
+pub(crate) mod apigateway;
+mod apigateway_sdk;
+mod arn;
 pub(crate) mod asg;
 mod asg_sdk;
+pub(crate) mod athena;
+mod athena_sdk;
+pub(crate) mod audit;
+pub(crate) mod backup;
+mod backup_sdk;
+pub(crate) mod cloudformation;
+mod cloudformation_sdk;
+pub(crate) mod cloudfront;
+mod cloudfront_sdk;
+pub(crate) mod cloudtrail;
+mod cloudtrail_sdk;
+pub(crate) mod cloudwatch;
+mod cloudwatch_sdk;
+pub(crate) mod cognito;
+mod cognito_sdk;
 mod common;
+pub(crate) mod directconnect;
+mod directconnect_sdk;
+pub(crate) mod dynamodb;
+mod dynamodb_sdk;
+mod ebs;
 mod ec2;
 pub(crate) mod ecr;
 mod ecr_sdk;
+pub(crate) mod ecs;
+mod ecs_sdk;
+pub(crate) mod efs;
+mod efs_sdk;
+pub(crate) mod eks;
+mod eks_sdk;
+pub(crate) mod elasticache;
+mod elasticache_sdk;
+pub(crate) mod elasticbeanstalk;
+mod elasticbeanstalk_sdk;
+pub(crate) mod eventbridge;
+mod eventbridge_sdk;
+pub(crate) mod glue;
+mod glue_sdk;
 pub(crate) mod iam;
+mod iam_sdk;
+mod key_pair;
+pub(crate) mod kinesis;
+mod kinesis_sdk;
+pub(crate) mod kms;
+mod kms_sdk;
+pub(crate) mod lambda;
+mod lambda_sdk;
+mod launch_template;
 mod load_balancer;
+pub(crate) mod msk;
+mod msk_sdk;
+mod name_index;
+mod network_interface;
+pub(crate) mod opensearch;
+mod opensearch_sdk;
+mod partition;
+pub(crate) mod rds;
+mod rds_sdk;
+mod read_only;
+pub(crate) mod redshift;
+mod redshift_sdk;
+pub(crate) mod remote_store;
+pub(crate) mod route53;
+mod route53_sdk;
+pub(crate) mod secretsmanager;
+mod secretsmanager_sdk;
 mod security_group;
+pub(crate) mod sns;
+mod sns_sdk;
+pub(crate) mod sqs;
+mod sqs_sdk;
+pub(crate) mod ssm;
+mod ssm_sdk;
+pub(crate) mod stepfunctions;
+mod stepfunctions_sdk;
+pub(crate) mod tagging;
+mod tagging_sdk;
+mod transit_gateway;
 mod vpc;
+mod vpc_endpoint;
+mod vpc_peering;
+mod vpn;
+pub(crate) mod waf;
+mod waf_sdk;
+
+// Re-export ARN parser
+pub use arn::Arn;
+
+// Re-export partition (aws / aws-us-gov / aws-cn)
+pub use partition::Partition;
+
+// Re-export write mode switch (--allow-write) and blueprint store write target
+pub use read_only::{enable_write_mode, set_blueprint_store};
 
 // Re-export common types
 pub use common::{
-    AwsAuthError, AwsAuthErrorCode, AwsResource, check_aws_login, list_aws_profiles,
-    set_aws_profile, set_region,
+    AwsAuthError, AwsAuthErrorCode, AwsResource, DEFAULT_MAX_CONCURRENT_REQUESTS, check_aws_login,
+    current_partition, list_aws_profiles, max_concurrent_requests, run_aws_cli, set_aws_profile,
+    set_max_concurrent_requests, set_region,
 };
 
 // Re-export EC2 types and functions
 #[allow(unused_imports)]
-pub use ec2::{Ec2Detail, VolumeDetail, get_instance_detail, list_instances};
+pub use ec2::{
+    Ec2Detail, VolumeDetail, clear_prefetched_instances, get_instance_detail, is_terminated_state,
+    list_instances, prefetch_instances, refresh_instance_detail,
+};
+
+// Re-export IAM types and functions
+#[allow(unused_imports)]
+pub use iam::{
+    IamAccessKey, IamEntityDetail, IamManagedPolicy, clear_iam_role_cache, get_iam_entity_detail,
+    list_iam_entities,
+};
 
 // Re-export VPC types and functions
 #[allow(unused_imports)]
 pub use vpc::{
-    EipDetail, NatDetail, NetworkDetail, RouteTableDetail, get_network_detail,
-    get_vpc_dns_hostnames, get_vpc_dns_support, get_vpc_info, list_eips, list_internet_gateways,
-    list_nat_gateways, list_route_tables, list_subnets, list_vpcs,
+    EipDetail, NatDetail, NetworkAclDetail, NetworkAclRule, NetworkDetail, RouteTableDetail,
+    SubnetAddresses, get_network_detail, get_vpc_dns_hostnames, get_vpc_dns_support, get_vpc_info,
+    list_eips, list_internet_gateways, list_nat_gateways, list_network_acls, list_route_tables,
+    list_subnets, list_vpc_endpoints_in, list_vpc_peerings, list_vpcs,
 };
 
 // Re-export Security Group types and functions
 #[allow(unused_imports)]
 pub use security_group::{
     SecurityGroupDetail, SecurityRule, get_security_group_detail, list_security_groups,
+    set_normalize_security_group_rules,
+};
+
+// Re-export Transit Gateway types and functions
+#[allow(unused_imports)]
+pub use transit_gateway::{
+    TransitGatewayAttachmentInfo, TransitGatewayDetail, TransitGatewayRouteInfo,
+    TransitGatewayRouteTableInfo, get_transit_gateway_detail, list_transit_gateways,
+};
+
+// Re-export VPC Peering types and functions
+#[allow(unused_imports)]
+pub use vpc_peering::{
+    VpcPeeringDetail, VpcPeeringVpcInfo, get_vpc_peering_detail, list_vpc_peering_connections,
+};
+
+// Re-export VPC Endpoint types and functions
+#[allow(unused_imports)]
+pub use vpc_endpoint::{VpcEndpointDetail, get_vpc_endpoint_detail, list_vpc_endpoints};
+
+// Re-export Network Interface (ENI) types and functions
+#[allow(unused_imports)]
+pub use network_interface::{
+    NetworkInterfaceAttachment, NetworkInterfaceDetail, NetworkInterfaceIp,
+    get_network_interface_detail, list_network_interfaces,
+};
+
+// Re-export Launch Template types and functions
+#[allow(unused_imports)]
+pub use launch_template::{
+    LaunchTemplateBlockDevice, LaunchTemplateDetail, LaunchTemplateVersionDetail,
+    get_launch_template_detail, get_launch_template_with_version, list_launch_templates,
+};
+
+// Re-export AMI types and functions
+#[allow(unused_imports)]
+pub use ami::{AmiBlockDevice, AmiDetail, AmiLaunchPermission, get_ami_detail, list_amis};
+
+// Re-export EBS types and functions
+#[allow(unused_imports)]
+pub use ebs::{
+    EbsAttachment, EbsDetail, EbsResource, EbsSnapshotInfo, EbsVolumeInfo, get_ebs_detail,
+    list_ebs_resources,
+};
+
+// Re-export key pair types and functions
+#[allow(unused_imports)]
+pub use key_pair::{KeyPairDetail, get_key_pair_detail, list_key_pairs};
+
+// Re-export Site-to-Site VPN types and functions
+#[allow(unused_imports)]
+pub use vpn::{
+    CustomerGatewayInfo, VpnConnectionInfo, VpnDetail, VpnGatewayInfo, VpnResource,
+    VpnStaticRouteInfo, VpnTunnelInfo, get_vpn_detail, list_vpn_resources,
 };
 
 // Re-export Load Balancer types and functions
@@ -48,3 +205,142 @@ pub use ecr::EcrDetail;
 // Re-export ASG types and functions
 #[allow(unused_imports)]
 pub use asg::{AsgDetail, ScalingPolicy};
+
+// Re-export RDS types
+#[allow(unused_imports)]
+pub use rds::{RdsClusterMember, RdsDetail};
+
+// Re-export Lambda types
+#[allow(unused_imports)]
+pub use lambda::{LambdaDetail, LambdaTrigger};
+
+// Re-export DynamoDB types
+#[allow(unused_imports)]
+pub use dynamodb::{DynamoDbDetail, DynamoDbIndex};
+
+// Re-export CloudFront types
+#[allow(unused_imports)]
+pub use cloudfront::{CloudFrontBehavior, CloudFrontDetail, CloudFrontOrigin};
+
+// Re-export Route 53 types
+#[allow(unused_imports)]
+pub use route53::{Route53Detail, Route53Record};
+
+// Re-export EKS types
+#[allow(unused_imports)]
+pub use eks::{EksAddon, EksDetail, EksFargateProfile, EksNodeGroup};
+
+// Re-export ECS types
+#[allow(unused_imports)]
+pub use ecs::{EcsContainer, EcsDetail, EcsService, EcsTask, EcsTaskDefinition};
+
+// Re-export API Gateway types
+#[allow(unused_imports)]
+pub use apigateway::{ApiGatewayAuthorizer, ApiGatewayDetail, ApiGatewayRoute, ApiGatewayStage};
+
+// Re-export CloudTrail types and functions
+#[allow(unused_imports)]
+pub use cloudtrail::{
+    CloudTrailDetail, CloudTrailEvent, LastModified, last_modified_markdown,
+    set_stamp_last_modified, stamp_last_modified_enabled,
+};
+
+// Re-export CloudWatch type
+#[allow(unused_imports)]
+pub use cloudwatch::CloudWatchAlarmDetail;
+
+// Re-export EFS types
+#[allow(unused_imports)]
+pub use efs::{EfsDetail, EfsMountTarget};
+
+// Re-export ElastiCache types
+#[allow(unused_imports)]
+pub use elasticache::{
+    ElastiCacheDetail, ElastiCacheNode, ElastiCacheNodeGroup, ElastiCacheNodeGroupMember,
+};
+
+// Re-export Elastic Beanstalk types
+#[allow(unused_imports)]
+pub use elasticbeanstalk::{BeanstalkOptionSetting, ElasticBeanstalkDetail};
+
+// Re-export Kinesis types
+#[allow(unused_imports)]
+pub use kinesis::{KinesisConsumer, KinesisStreamDetail};
+
+// Re-export KMS types
+#[allow(unused_imports)]
+pub use kms::{KmsGrant, KmsKeyDetail, KmsPolicyStatement};
+
+// Re-export MSK type
+#[allow(unused_imports)]
+pub use msk::MskClusterDetail;
+
+// Re-export OpenSearch types
+#[allow(unused_imports)]
+pub use opensearch::{OpenSearchDomainDetail, OpenSearchVpcEndpoint};
+
+// Re-export Redshift types
+#[allow(unused_imports)]
+pub use redshift::{RedshiftClusterDetail, RedshiftSnapshotSchedule};
+
+// Re-export Direct Connect types
+#[allow(unused_imports)]
+pub use directconnect::{
+    DirectConnectBgpPeerInfo, DirectConnectConnectionInfo, DirectConnectDetail,
+    DirectConnectResource, DirectConnectVirtualInterfaceInfo,
+};
+
+// Re-export Secrets Manager types
+#[allow(unused_imports)]
+pub use secretsmanager::{SecretDetail, SecretReplica};
+
+// Re-export SNS types
+#[allow(unused_imports)]
+pub use sns::{SnsDetail, SnsSubscription};
+
+// Re-export SQS type
+#[allow(unused_imports)]
+pub use sqs::SqsDetail;
+
+// Re-export SSM Parameter Store types
+#[allow(unused_imports)]
+pub use ssm::{SsmParameter, SsmParameterPathDetail};
+
+// Re-export Step Functions type
+#[allow(unused_imports)]
+pub use stepfunctions::StateMachineDetail;
+
+// Re-export EventBridge type
+#[allow(unused_imports)]
+pub use eventbridge::EventBusDetail;
+
+// Re-export Cognito types
+#[allow(unused_imports)]
+pub use cognito::{CognitoPool, CognitoPoolDetail, UserPoolDetail};
+
+// Re-export Glue types
+#[allow(unused_imports)]
+pub use glue::{GlueDatabaseDetail, GlueDetail, GlueResource};
+
+// Re-export Athena type
+#[allow(unused_imports)]
+pub use athena::AthenaWorkGroupDetail;
+
+// Re-export AWS Backup type
+#[allow(unused_imports)]
+pub use backup::BackupPlanDetail;
+
+// Re-export ACM types
+#[allow(unused_imports)]
+pub use acm::{AcmCertificateDetail, AcmDomainValidation};
+
+// Re-export WAF types
+#[allow(unused_imports)]
+pub use waf::{WafAssociationInfo, WafRuleInfo, WafWebAclDetail};
+
+// Re-export CloudFormation types
+#[allow(unused_imports)]
+pub use cloudformation::{CloudFormationStackDetail, StackOutput, StackResource};
+
+// Re-export Tagging type
+pub use tagging::TaggedResource;
@@ -0,0 +1,177 @@
+pub use crate::aws_cli::msk_sdk::{get_msk_cluster_detail, list_msk_clusters};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct MskClusterDetail {
+    pub name: String,
+    pub arn: String,
+    // ACTIVE, CREATING, UPDATING, HEALING, ...
+    pub state: String,
+    pub kafka_version: String,
+    pub broker_count: i32,
+    pub instance_type: String,
+    // LOCAL, TIERED
+    pub storage_mode: String,
+    // 브로커당 EBS 볼륨 크기(GiB)
+    pub volume_size_gib: Option<i32>,
+    // 프로비저닝된 스토리지 처리량(MiB/s), 사용하지 않으면 None
+    pub provisioned_throughput: Option<i32>,
+    // 클라이언트 ↔ 브로커: TLS, TLS_PLAINTEXT, PLAINTEXT
+    pub client_broker_encryption: String,
+    pub in_cluster_encryption: bool,
+    // 저장 데이터 암호화 KMS 키 (AWS 관리형 키도 ARN으로 반환됨)
+    pub kms_key_id: Option<String>,
+    // DEFAULT, PER_BROKER, PER_TOPIC_PER_BROKER, PER_TOPIC_PER_PARTITION
+    pub enhanced_monitoring: String,
+    pub client_subnets: Vec<String>,
+    pub security_groups: Vec<String>,
+    pub created: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl MskClusterDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.msk_cluster(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+            format!("| {} | {} |", i18n.md_kafka_version(), self.kafka_version),
+            format!("| {} | {} |", i18n.md_broker_count(), self.broker_count),
+            format!("| {} | {} |", i18n.md_instance_type(), self.instance_type),
+        ];
+        if let Some(size) = self.volume_size_gib {
+            lines.push(format!("| {} | {} GiB |", i18n.md_broker_storage(), size));
+        }
+        if let Some(throughput) = self.provisioned_throughput {
+            lines.push(format!(
+                "| {} | {} MiB/s |",
+                i18n.md_provisioned_throughput(),
+                throughput
+            ));
+        }
+        if !self.storage_mode.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_storage_mode(),
+                self.storage_mode
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_client_broker_encryption(),
+            self.client_broker_encryption
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_in_cluster_encryption(),
+            enabled(self.in_cluster_encryption)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_at_rest_encryption(),
+            match &self.kms_key_id {
+                Some(key_id) => format!("KMS ({})", key_id),
+                None => i18n.md_aws_owned_key().to_string(),
+            }
+        ));
+        if !self.enhanced_monitoring.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_enhanced_monitoring(),
+                self.enhanced_monitoring
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // Client Subnets
+        if !self.client_subnets.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_client_subnets()));
+            for subnet in &self.client_subnets {
+                lines.push(format!("- {}", subnet));
+            }
+        }
+
+        // Security Groups
+        if !self.security_groups.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_security_groups()));
+            for sg in &self.security_groups {
+                lines.push(format!("- {}", sg));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MskClusterDetail;
+    use crate::i18n::Language;
+
+    #[test]
+    fn scenario_msk_cluster_markdown_render() {
+        let detail = MskClusterDetail {
+            name: "events".to_string(),
+            arn: "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0-2".to_string(),
+            state: "ACTIVE".to_string(),
+            kafka_version: "3.6.0".to_string(),
+            broker_count: 3,
+            instance_type: "kafka.m5.large".to_string(),
+            storage_mode: "LOCAL".to_string(),
+            volume_size_gib: Some(1000),
+            provisioned_throughput: None,
+            client_broker_encryption: "TLS".to_string(),
+            in_cluster_encryption: true,
+            kms_key_id: Some("arn:aws:kms:ap-northeast-2:123456789012:key/abcd".to_string()),
+            enhanced_monitoring: "PER_BROKER".to_string(),
+            client_subnets: vec!["subnet-a".to_string(), "subnet-b".to_string()],
+            security_groups: vec!["sg-0123".to_string()],
+            created: "2026-01-01T00:00:00Z".to_string(),
+            tags: vec![("Team".to_string(), "data".to_string())],
+        };
+
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("## MSK Cluster (events)"));
+        assert!(markdown.contains("| Kafka Version | 3.6.0 |"));
+        assert!(markdown.contains("| Brokers | 3 |"));
+        assert!(markdown.contains("| Instance Type | kafka.m5.large |"));
+        assert!(markdown.contains("| EBS Storage per Broker | 1000 GiB |"));
+        assert!(!markdown.contains("MiB/s"));
+        assert!(markdown.contains("| Encryption in Transit (Client-Broker) | TLS |"));
+        assert!(markdown.contains("| Encryption in Transit (In-Cluster) | Enabled |"));
+        assert!(markdown.contains("| Encryption at Rest | KMS (arn:aws:kms:"));
+        assert!(markdown.contains("### Client Subnets\n\n- subnet-a\n- subnet-b"));
+        assert!(markdown.contains("- sg-0123"));
+        assert!(markdown.contains("| Team | data |"));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::msk::MskClusterDetail;
+use aws_sdk_kafka::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_kafka::types::{BrokerNodeGroupInfo, ClusterInfo, EbsStorageInfo};
+
+/// List provisioned MSK clusters using AWS SDK
+pub fn list_msk_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_msk_clusters_async())
+}
+
+// ListClusters는 프로비저닝된 클러스터만 반환 (서버리스 클러스터는 브로커 정보가 없음)
+async fn list_msk_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kafka, &config);
+
+    let mut resources: Vec<AwsResource> = match client
+        .list_clusters()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(clusters) => clusters.iter().map(map_cluster_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing MSK clusters: {:?}", e);
+            Vec::new()
+        }
+    };
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get MSK cluster detail (brokers, storage, encryption, client subnets) using AWS SDK
+pub fn get_msk_cluster_detail(cluster_arn: &str) -> Option<MskClusterDetail> {
+    get_runtime().block_on(get_msk_cluster_detail_async(cluster_arn))
+}
+
+async fn get_msk_cluster_detail_async(cluster_arn: &str) -> Option<MskClusterDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_kafka, &config);
+
+    let output = match client
+        .describe_cluster()
+        .cluster_arn(cluster_arn)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error describing MSK cluster {}: {:?}", cluster_arn, e);
+            return None;
+        }
+    };
+    output.cluster_info().map(map_cluster_detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|timestamp| timestamp.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn ebs_storage(broker: Option<&BrokerNodeGroupInfo>) -> Option<&EbsStorageInfo> {
+    broker?.storage_info()?.ebs_storage_info()
+}
+
+// 목록에는 상태(state)와 "인스턴스 유형 x 브로커 수"를 표시하고, 상세 조회는 ARN으로 함
+fn map_cluster_resource(cluster: &ClusterInfo) -> AwsResource {
+    let instance_type = cluster
+        .broker_node_group_info()
+        .and_then(|broker| broker.instance_type())
+        .unwrap_or_default();
+
+    AwsResource {
+        name: cluster.cluster_name().unwrap_or_default().to_string(),
+        id: cluster.cluster_arn().unwrap_or_default().to_string(),
+        state: cluster
+            .state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: format!(
+            "{} x {}",
+            instance_type,
+            cluster.number_of_broker_nodes().unwrap_or_default()
+        ),
+        owner_id: String::new(),
+    }
+}
+
+fn map_cluster_detail(cluster: &ClusterInfo) -> MskClusterDetail {
+    let broker = cluster.broker_node_group_info();
+    let ebs = ebs_storage(broker);
+    let encryption = cluster.encryption_info();
+    let in_transit = encryption.and_then(|encryption| encryption.encryption_in_transit());
+
+    let mut tags: Vec<(String, String)> = cluster
+        .tags()
+        .map(|tags| {
+            tags.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    tags.sort();
+
+    MskClusterDetail {
+        name: cluster.cluster_name().unwrap_or_default().to_string(),
+        arn: cluster.cluster_arn().unwrap_or_default().to_string(),
+        state: cluster
+            .state()
+            .map(|state| state.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        kafka_version: cluster
+            .current_broker_software_info()
+            .and_then(|software| software.kafka_version())
+            .unwrap_or_default()
+            .to_string(),
+        broker_count: cluster.number_of_broker_nodes().unwrap_or_default(),
+        instance_type: broker
+            .and_then(|broker| broker.instance_type())
+            .unwrap_or_default()
+            .to_string(),
+        storage_mode: cluster
+            .storage_mode()
+            .map(|mode| mode.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        volume_size_gib: ebs.and_then(|ebs| ebs.volume_size()),
+        provisioned_throughput: ebs
+            .and_then(|ebs| ebs.provisioned_throughput())
+            .filter(|throughput| throughput.enabled().unwrap_or(false))
+            .and_then(|throughput| throughput.volume_throughput()),
+        // 지정하지 않으면 MSK 기본값은 TLS, 클러스터 내부 암호화 사용
+        client_broker_encryption: in_transit
+            .and_then(|in_transit| in_transit.client_broker())
+            .map(|client_broker| client_broker.as_str())
+            .unwrap_or("TLS")
+            .to_string(),
+        in_cluster_encryption: in_transit
+            .and_then(|in_transit| in_transit.in_cluster())
+            .unwrap_or(true),
+        kms_key_id: encryption
+            .and_then(|encryption| encryption.encryption_at_rest())
+            .and_then(|at_rest| at_rest.data_volume_kms_key_id())
+            .map(str::to_string),
+        enhanced_monitoring: cluster
+            .enhanced_monitoring()
+            .map(|monitoring| monitoring.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        client_subnets: broker
+            .map(|broker| broker.client_subnets().to_vec())
+            .unwrap_or_default(),
+        security_groups: broker
+            .map(|broker| broker.security_groups().to_vec())
+            .unwrap_or_default(),
+        created: format_timestamp(cluster.creation_time()),
+        tags,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_cluster_detail, map_cluster_resource};
+    use aws_sdk_kafka::primitives::DateTime;
+    use aws_sdk_kafka::types::{
+        BrokerNodeGroupInfo, BrokerSoftwareInfo, ClientBroker, ClusterInfo, ClusterState,
+        EbsStorageInfo, EncryptionAtRest, EncryptionInTransit, EncryptionInfo, EnhancedMonitoring,
+        ProvisionedThroughput, StorageInfo,
+    };
+
+    const ARN: &str = "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0-2";
+
+    fn cluster(encryption: Option<EncryptionInfo>) -> ClusterInfo {
+        ClusterInfo::builder()
+            .cluster_name("events")
+            .cluster_arn(ARN)
+            .state(ClusterState::Active)
+            .number_of_broker_nodes(3)
+            .creation_time(DateTime::from_secs(1_767_225_600))
+            .current_broker_software_info(
+                BrokerSoftwareInfo::builder().kafka_version("3.6.0").build(),
+            )
+            .enhanced_monitoring(EnhancedMonitoring::PerBroker)
+            .broker_node_group_info(
+                BrokerNodeGroupInfo::builder()
+                    .instance_type("kafka.m5.large")
+                    .client_subnets("subnet-b")
+                    .client_subnets("subnet-a")
+                    .security_groups("sg-0123")
+                    .storage_info(
+                        StorageInfo::builder()
+                            .ebs_storage_info(
+                                EbsStorageInfo::builder()
+                                    .volume_size(1000)
+                                    .provisioned_throughput(
+                                        ProvisionedThroughput::builder()
+                                            .enabled(true)
+                                            .volume_throughput(250)
+                                            .build(),
+                                    )
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .set_encryption_info(encryption)
+            .tags("Team", "data")
+            .build()
+    }
+
+    #[test]
+    fn map_cluster_resource_uses_arn_and_broker_summary() {
+        let resource = map_cluster_resource(&cluster(None));
+        assert_eq!(resource.name, "events");
+        assert_eq!(resource.id, ARN);
+        assert_eq!(resource.state, "ACTIVE");
+        assert_eq!(resource.cidr, "kafka.m5.large x 3");
+    }
+
+    #[test]
+    fn map_cluster_detail_reads_storage_encryption_and_subnets() {
+        let detail = map_cluster_detail(&cluster(None));
+        assert_eq!(detail.kafka_version, "3.6.0");
+        assert_eq!(detail.broker_count, 3);
+        assert_eq!(detail.volume_size_gib, Some(1000));
+        assert_eq!(detail.provisioned_throughput, Some(250));
+        assert_eq!(detail.enhanced_monitoring, "PER_BROKER");
+        // 암호화 설정이 없으면 MSK 기본값
+        assert_eq!(detail.client_broker_encryption, "TLS");
+        assert!(detail.in_cluster_encryption);
+        assert_eq!(detail.kms_key_id, None);
+        assert_eq!(detail.client_subnets, vec!["subnet-b", "subnet-a"]);
+        assert_eq!(detail.security_groups, vec!["sg-0123"]);
+        assert_eq!(detail.tags, vec![("Team".to_string(), "data".to_string())]);
+        assert_eq!(detail.created, "2026-01-01T00:00:00Z");
+
+        let detail = map_cluster_detail(&cluster(Some(
+            EncryptionInfo::builder()
+                .encryption_in_transit(
+                    EncryptionInTransit::builder()
+                        .client_broker(ClientBroker::TlsPlaintext)
+                        .in_cluster(false)
+                        .build(),
+                )
+                .encryption_at_rest(
+                    EncryptionAtRest::builder()
+                        .data_volume_kms_key_id("alias/msk")
+                        .build(),
+                )
+                .build(),
+        )));
+        assert_eq!(detail.client_broker_encryption, "TLS_PLAINTEXT");
+        assert!(!detail.in_cluster_encryption);
+        assert_eq!(detail.kms_key_id.as_deref(), Some("alias/msk"));
+    }
+}
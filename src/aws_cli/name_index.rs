@@ -0,0 +1,69 @@
+use crate::aws_cli::common::{AwsResource, current_region};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+// 목록 조회에서 본 리전별 ID→Name 태그, 상세 렌더링 시 추가 API 호출 없이 재사용
+fn name_index() -> &'static Mutex<HashMap<(String, String), String>> {
+    static NAMES: OnceLock<Mutex<HashMap<(String, String), String>>> = OnceLock::new();
+    NAMES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Remember the Name tag of a resource seen in the current region.
+pub(crate) fn record(id: &str, name: &str) {
+    if id.is_empty() || name.is_empty() || name == id {
+        return;
+    }
+    let key = (current_region(), id.to_string());
+    if let Ok(mut names) = name_index().lock() {
+        names.insert(key, name.to_string());
+    }
+}
+
+pub(crate) fn record_resources(resources: &[AwsResource]) {
+    for resource in resources {
+        record(&resource.id, &resource.name);
+    }
+}
+
+pub(crate) fn lookup(id: &str) -> Option<String> {
+    let key = (current_region(), id.to_string());
+    name_index()
+        .lock()
+        .ok()
+        .and_then(|names| names.get(&key).cloned())
+}
+
+/// `Name - id` when the Name tag is indexed, otherwise the bare id.
+pub(crate) fn label(id: &str) -> String {
+    match lookup(id) {
+        Some(name) => format!("{} - {}", name, id),
+        None => id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label, lookup, record, record_resources};
+    use crate::aws_cli::common::AwsResource;
+
+    #[test]
+    fn record_and_label_resolve_indexed_names() {
+        record("igw-index-1", "main-igw");
+        record("subnet-index-1", "");
+        record("sg-index-1", "sg-index-1");
+        record_resources(&[AwsResource {
+            name: "app-a".to_string(),
+            id: "subnet-index-2".to_string(),
+            state: String::new(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        }]);
+
+        assert_eq!(label("igw-index-1"), "main-igw - igw-index-1");
+        assert_eq!(lookup("subnet-index-2").as_deref(), Some("app-a"));
+        // 이름이 없거나 ID와 같으면 색인하지 않음
+        assert_eq!(label("subnet-index-1"), "subnet-index-1");
+        assert_eq!(lookup("sg-index-1"), None);
+    }
+}
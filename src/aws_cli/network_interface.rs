@@ -0,0 +1,502 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfacesResponse {
+    network_interfaces: Vec<NetworkInterfaceEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfaceEntry {
+    network_interface_id: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    interface_type: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    vpc_id: String,
+    #[serde(default)]
+    subnet_id: String,
+    #[serde(default)]
+    availability_zone: String,
+    #[serde(default)]
+    mac_address: String,
+    #[serde(default)]
+    private_ip_addresses: Vec<NetworkInterfacePrivateIp>,
+    #[serde(default)]
+    groups: Vec<NetworkInterfaceGroup>,
+    #[serde(default)]
+    attachment: Option<NetworkInterfaceAttachmentEntry>,
+    #[serde(default)]
+    requester_managed: bool,
+    #[serde(default)]
+    requester_id: String,
+    #[serde(default)]
+    owner_id: String,
+    #[serde(default)]
+    tag_set: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfacePrivateIp {
+    #[serde(default)]
+    private_ip_address: String,
+    #[serde(default)]
+    primary: bool,
+    #[serde(default)]
+    association: Option<NetworkInterfaceAssociation>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfaceAssociation {
+    #[serde(default)]
+    public_ip: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfaceGroup {
+    #[serde(default)]
+    group_id: String,
+    #[serde(default)]
+    group_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkInterfaceAttachmentEntry {
+    #[serde(default)]
+    instance_id: String,
+    #[serde(default)]
+    instance_owner_id: String,
+    #[serde(default)]
+    device_index: Option<i64>,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    delete_on_termination: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkInterfaceIp {
+    pub private_ip: String,
+    pub public_ip: String,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkInterfaceAttachment {
+    // 인스턴스 ID, 없으면 관리형 서비스 계정(예: amazon-elb)
+    pub target: String,
+    pub device_index: Option<i64>,
+    pub status: String,
+    pub delete_on_termination: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkInterfaceDetail {
+    pub name: String,
+    pub id: String,
+    pub description: String,
+    // interface, nat_gateway, vpc_endpoint, lambda, efa, ...
+    pub interface_type: String,
+    // available이면 어디에도 연결되지 않은 ENI
+    pub status: String,
+    pub vpc_id: String,
+    pub subnet_id: String,
+    pub availability_zone: String,
+    pub mac_address: String,
+    // 기본 IP가 먼저 옴
+    pub ips: Vec<NetworkInterfaceIp>,
+    // "name (sg-xxx)" 형식
+    pub security_groups: Vec<String>,
+    pub attachment: Option<NetworkInterfaceAttachment>,
+    // AWS 서비스가 만들고 관리하는 ENI (예: ELB, Lambda)
+    pub requester_managed: bool,
+    pub requester_id: String,
+    pub owner_id: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl NetworkInterfaceDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    /// An interface that is not attached to anything and only keeps costing addresses.
+    pub fn is_orphaned(&self) -> bool {
+        self.status == "available" && self.attachment.is_none()
+    }
+
+    pub fn primary_private_ip(&self) -> &str {
+        self.ips
+            .iter()
+            .find(|ip| ip.primary)
+            .or_else(|| self.ips.first())
+            .map(|ip| ip.private_ip.as_str())
+            .unwrap_or("")
+    }
+
+    /// What the interface is attached to, `-` when unattached.
+    pub fn attached_to(&self) -> String {
+        match &self.attachment {
+            Some(attachment) if attachment.target.starts_with("i-") => {
+                name_index::label(&attachment.target)
+            }
+            Some(attachment) if !attachment.target.is_empty() => attachment.target.clone(),
+            _ => "-".to_string(),
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.network_interface(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                or_dash(&self.description)
+            ),
+            format!("| {} | {} |", i18n.md_type(), self.interface_type),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| VPC | {} |", name_index::label(&self.vpc_id)),
+            format!(
+                "| {} | {} |",
+                i18n.md_subnet(),
+                name_index::label(&self.subnet_id)
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_availability_zone(),
+                self.availability_zone
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_private_ip(),
+                or_dash(self.primary_private_ip())
+            ),
+            format!(
+                "| {} | {} |",
+                i18n.md_mac_address(),
+                or_dash(&self.mac_address)
+            ),
+        ];
+        if self.requester_managed {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_requester_managed(),
+                or_dash(&self.requester_id)
+            ));
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        lines.push(format!("\n### {}\n", i18n.md_attachment()));
+        match &self.attachment {
+            Some(attachment) => {
+                lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+                lines.push("|:---|:---|".to_string());
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_attached_to(),
+                    self.attached_to()
+                ));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_device_index(),
+                    attachment
+                        .device_index
+                        .map(|index| index.to_string())
+                        .unwrap_or_else(|| "-".to_string())
+                ));
+                lines.push(format!("| {} | {} |", i18n.md_state(), attachment.status));
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_delete_on_termination(),
+                    if attachment.delete_on_termination {
+                        "✓"
+                    } else {
+                        "-"
+                    }
+                ));
+            }
+            None => lines.push(i18n.md_unattached_interface_note().to_string()),
+        }
+
+        if !self.ips.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_ip_addresses()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_private_ip(),
+                i18n.md_public_ip(),
+                i18n.md_primary()
+            ));
+            lines.push("|:---|:---|:---:|".to_string());
+            for ip in &self.ips {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    ip.private_ip,
+                    or_dash(&ip.public_ip),
+                    if ip.primary { "✓" } else { "-" }
+                ));
+            }
+        }
+        if !self.security_groups.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_security_groups()));
+            for group in &self.security_groups {
+                lines.push(format!("- {}", group));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+/// Parse `describe-network-interfaces` output, ordered by subnet then id.
+pub(crate) fn parse_network_interfaces_output(output: &str) -> Option<Vec<NetworkInterfaceDetail>> {
+    let response: NetworkInterfacesResponse = serde_json::from_str(output).ok()?;
+
+    let mut interfaces: Vec<NetworkInterfaceDetail> = response
+        .network_interfaces
+        .into_iter()
+        .map(|interface| {
+            let mut tags: Vec<(String, String)> = interface
+                .tag_set
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            let mut ips: Vec<NetworkInterfaceIp> = interface
+                .private_ip_addresses
+                .into_iter()
+                .map(|ip| NetworkInterfaceIp {
+                    private_ip: ip.private_ip_address,
+                    public_ip: ip
+                        .association
+                        .map(|association| association.public_ip)
+                        .unwrap_or_default(),
+                    primary: ip.primary,
+                })
+                .collect();
+            ips.sort_by_key(|ip| !ip.primary);
+            NetworkInterfaceDetail {
+                name: name_tag(&interface.tag_set),
+                id: interface.network_interface_id,
+                description: interface.description,
+                interface_type: interface.interface_type,
+                status: interface.status,
+                vpc_id: interface.vpc_id,
+                subnet_id: interface.subnet_id,
+                availability_zone: interface.availability_zone,
+                mac_address: interface.mac_address,
+                ips,
+                security_groups: interface
+                    .groups
+                    .into_iter()
+                    .map(|group| {
+                        if group.group_name.is_empty() {
+                            group.group_id
+                        } else {
+                            format!("{} ({})", group.group_name, group.group_id)
+                        }
+                    })
+                    .collect(),
+                // 관리형 ENI는 InstanceId 대신 InstanceOwnerId(예: amazon-elb)만 내려옴
+                attachment: interface
+                    .attachment
+                    .map(|attachment| NetworkInterfaceAttachment {
+                        target: if attachment.instance_id.is_empty() {
+                            attachment.instance_owner_id
+                        } else {
+                            attachment.instance_id
+                        },
+                        device_index: attachment.device_index,
+                        status: attachment.status,
+                        delete_on_termination: attachment.delete_on_termination,
+                    }),
+                requester_managed: interface.requester_managed,
+                requester_id: interface.requester_id,
+                owner_id: interface.owner_id,
+                tags,
+            }
+        })
+        .collect();
+    interfaces.sort_by(|a, b| a.subnet_id.cmp(&b.subnet_id).then_with(|| a.id.cmp(&b.id)));
+    Some(interfaces)
+}
+
+pub fn list_network_interfaces() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&["ec2", "describe-network-interfaces", "--output", "json"]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let interfaces: Vec<AwsResource> = parse_network_interfaces_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(interface_resource)
+        .collect();
+    name_index::record_resources(&interfaces);
+    interfaces
+}
+
+// 이름 태그가 없으면 설명(예: "ELB app/web/...")으로 표시, 목록에는 연결 대상과 기본 IP를 함께 표시
+fn interface_resource(interface: NetworkInterfaceDetail) -> AwsResource {
+    AwsResource {
+        name: if !interface.name.is_empty() {
+            interface.name.clone()
+        } else if !interface.description.is_empty() {
+            interface.description.clone()
+        } else {
+            interface.id.clone()
+        },
+        cidr: interface.primary_private_ip().to_string(),
+        az: interface.attached_to(),
+        id: interface.id,
+        state: interface.status,
+        owner_id: interface.owner_id,
+    }
+}
+
+pub fn get_network_interface_detail(interface_id: &str) -> Option<NetworkInterfaceDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-network-interfaces",
+        "--network-interface-ids",
+        interface_id,
+        "--output",
+        "json",
+    ])?;
+    parse_network_interfaces_output(&output)?.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interface_resource, parse_network_interfaces_output};
+    use crate::i18n::Language;
+
+    const PAYLOAD: &str = r#"
+    {
+      "NetworkInterfaces": [
+        {
+          "NetworkInterfaceId": "eni-0b2",
+          "Description": "ELB app/web/50dc6c495c0c9188",
+          "InterfaceType": "interface",
+          "Status": "in-use",
+          "VpcId": "vpc-1",
+          "SubnetId": "subnet-a",
+          "AvailabilityZone": "ap-northeast-2a",
+          "MacAddress": "02:ab:cd:ef:01:02",
+          "PrivateIpAddresses": [
+            {"PrivateIpAddress": "10.0.1.20", "Primary": true, "Association": {"PublicIp": "3.34.1.2"}}
+          ],
+          "Groups": [{"GroupId": "sg-1", "GroupName": "web-alb"}],
+          "Attachment": {"AttachmentId": "ela-attach-1", "InstanceOwnerId": "amazon-elb", "DeviceIndex": 1, "Status": "attached", "DeleteOnTermination": false},
+          "RequesterManaged": true,
+          "RequesterId": "amazon-elb",
+          "OwnerId": "111122223333",
+          "TagSet": []
+        },
+        {
+          "NetworkInterfaceId": "eni-0a1",
+          "Description": "",
+          "InterfaceType": "interface",
+          "Status": "available",
+          "VpcId": "vpc-1",
+          "SubnetId": "subnet-a",
+          "AvailabilityZone": "ap-northeast-2a",
+          "PrivateIpAddresses": [
+            {"PrivateIpAddress": "10.0.1.31", "Primary": false},
+            {"PrivateIpAddress": "10.0.1.30", "Primary": true}
+          ],
+          "Groups": [{"GroupId": "sg-2", "GroupName": "legacy"}],
+          "OwnerId": "111122223333",
+          "TagSet": [{"Key": "Name", "Value": "old-db"}, {"Key": "Team", "Value": "data"}]
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_network_interfaces_output_reads_attachments_and_ips() {
+        let interfaces = parse_network_interfaces_output(PAYLOAD).expect("interfaces");
+        assert_eq!(interfaces.len(), 2);
+
+        let orphan = &interfaces[0];
+        assert_eq!(orphan.id, "eni-0a1");
+        assert_eq!(orphan.name, "old-db");
+        assert!(orphan.is_orphaned());
+        // 기본 IP가 먼저 정렬됨
+        assert_eq!(orphan.primary_private_ip(), "10.0.1.30");
+        assert_eq!(orphan.ips[0].private_ip, "10.0.1.30");
+        assert_eq!(orphan.attached_to(), "-");
+
+        let managed = &interfaces[1];
+        assert!(!managed.is_orphaned());
+        assert!(managed.requester_managed);
+        assert_eq!(managed.ips[0].public_ip, "3.34.1.2");
+        assert_eq!(managed.security_groups, vec!["web-alb (sg-1)"]);
+        assert_eq!(managed.attached_to(), "amazon-elb");
+
+        let resource = interface_resource(managed.clone());
+        assert_eq!(resource.name, "ELB app/web/50dc6c495c0c9188");
+        assert_eq!(resource.cidr, "10.0.1.20");
+        assert_eq!(resource.az, "amazon-elb");
+        assert_eq!(resource.state, "in-use");
+    }
+
+    #[test]
+    fn network_interface_markdown_shows_attachment_and_orphan_note() {
+        let interfaces = parse_network_interfaces_output(PAYLOAD).expect("interfaces");
+
+        let md = interfaces[1].to_markdown(Language::English);
+        assert!(md.contains("## Network Interface (eni-0b2)"));
+        assert!(md.contains("| Requester Managed | amazon-elb |"));
+        assert!(md.contains("| Attached To | amazon-elb |"));
+        assert!(md.contains("| Device Index | 1 |"));
+        assert!(md.contains("| 10.0.1.20 | 3.34.1.2 | ✓ |"));
+        assert!(md.contains("### Security Groups\n- web-alb (sg-1)"));
+
+        let md = interfaces[0].to_markdown(Language::English);
+        assert!(md.contains("## Network Interface (old-db - eni-0a1)"));
+        assert!(md.contains("| Description | - |"));
+        assert!(md.contains("| Tag-Team | data |"));
+        assert!(md.contains("not attached"));
+        assert!(md.contains("| 10.0.1.31 | - | - |"));
+        assert!(!md.contains("Requester Managed"));
+    }
+}
@@ -0,0 +1,341 @@
+use crate::aws_cli::kms::KmsPolicyStatement;
+pub use crate::aws_cli::opensearch_sdk::{get_opensearch_domain_detail, list_opensearch_domains};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenSearchVpcEndpoint {
+    pub id: String,
+    pub owner: String,
+    // CREATING, ACTIVE, UPDATING, DELETING, ...
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct OpenSearchDomainDetail {
+    pub name: String,
+    pub arn: String,
+    pub domain_id: String,
+    // OpenSearch_2.11, Elasticsearch_7.10
+    pub engine_version: String,
+    pub state: String,
+    pub instance_type: String,
+    pub instance_count: i32,
+    // 전용 마스터 노드를 쓰지 않으면 None
+    pub dedicated_master_type: Option<String>,
+    pub dedicated_master_count: i32,
+    // 영역 인식(zone awareness)을 쓰면 가용 영역 수
+    pub zone_awareness_azs: Option<i32>,
+    // UltraWarm 노드를 쓰지 않으면 None
+    pub warm_type: Option<String>,
+    pub warm_count: i32,
+    // EBS를 쓰지 않으면(인스턴스 스토어) None
+    pub volume_type: Option<String>,
+    pub volume_size_gib: Option<i32>,
+    pub iops: Option<i32>,
+    pub throughput: Option<i32>,
+    // (엔드포인트 종류, 주소): 퍼블릭 도메인은 ("", 주소), VPC 도메인은 ("vpc", 주소)
+    pub endpoints: Vec<(String, String)>,
+    pub vpc_id: Option<String>,
+    pub subnets: Vec<String>,
+    pub security_groups: Vec<String>,
+    // 다른 VPC에서 접근하도록 만든 OpenSearch 관리형 VPC 엔드포인트
+    pub vpc_endpoints: Vec<OpenSearchVpcEndpoint>,
+    pub enforce_https: bool,
+    pub tls_security_policy: String,
+    pub node_to_node_encryption: bool,
+    pub encryption_at_rest: bool,
+    pub kms_key_id: Option<String>,
+    pub access_policy: Vec<KmsPolicyStatement>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl OpenSearchDomainDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+        let or_dash = |values: &[String]| {
+            if values.is_empty() {
+                "-".to_string()
+            } else {
+                values.join(", ")
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.opensearch_domain(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+            format!("| {} | {} |", i18n.md_version(), self.engine_version),
+        ];
+        for (kind, endpoint) in &self.endpoints {
+            if kind.is_empty() {
+                lines.push(format!("| {} | {} |", i18n.md_endpoint(), endpoint));
+            } else {
+                lines.push(format!(
+                    "| {} ({}) | {} |",
+                    i18n.md_endpoint(),
+                    kind,
+                    endpoint
+                ));
+            }
+        }
+
+        // Cluster Configuration
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_cluster_config()));
+        lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+        lines.push("|:---|:---|".to_string());
+        lines.push(format!(
+            "| {} | {} x {} |",
+            i18n.md_data_nodes(),
+            self.instance_type,
+            self.instance_count
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_dedicated_master(),
+            match &self.dedicated_master_type {
+                Some(master_type) => format!("{} x {}", master_type, self.dedicated_master_count),
+                None => i18n.md_disabled().to_string(),
+            }
+        ));
+        if let Some(warm_type) = &self.warm_type {
+            lines.push(format!(
+                "| UltraWarm | {} x {} |",
+                warm_type, self.warm_count
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_zone_awareness(),
+            match self.zone_awareness_azs {
+                Some(count) => format!("{} ({} AZ)", i18n.md_enabled(), count),
+                None => i18n.md_disabled().to_string(),
+            }
+        ));
+
+        // EBS: 볼륨 유형, 노드당 크기, gp3/io1의 IOPS와 처리량
+        let storage = match &self.volume_type {
+            Some(volume_type) => {
+                let mut parts = vec![volume_type.clone()];
+                if let Some(size) = self.volume_size_gib {
+                    parts.push(format!("{} GiB", size));
+                }
+                if let Some(iops) = self.iops {
+                    parts.push(format!("{} IOPS", iops));
+                }
+                if let Some(throughput) = self.throughput {
+                    parts.push(format!("{} MiB/s", throughput));
+                }
+                parts.join(", ")
+            }
+            None => i18n.md_instance_store().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_ebs_per_node(), storage));
+
+        // Security
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_security()));
+        lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+        lines.push("|:---|:---|".to_string());
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_enforce_https(),
+            if self.enforce_https && !self.tls_security_policy.is_empty() {
+                format!("{} ({})", i18n.md_enabled(), self.tls_security_policy)
+            } else {
+                enabled(self.enforce_https).to_string()
+            }
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_node_to_node_encryption(),
+            enabled(self.node_to_node_encryption)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_at_rest_encryption(),
+            match (&self.kms_key_id, self.encryption_at_rest) {
+                (Some(key_id), true) => format!("KMS ({})", key_id),
+                (None, true) => i18n.md_enabled().to_string(),
+                (_, false) => i18n.md_disabled().to_string(),
+            }
+        ));
+
+        // VPC
+        if let Some(vpc_id) = &self.vpc_id {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_vpc_config()));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| VPC | {} |", vpc_id));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_subnets(),
+                or_dash(&self.subnets)
+            ));
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_security_groups(),
+                or_dash(&self.security_groups)
+            ));
+        }
+
+        // OpenSearch-managed VPC endpoints
+        if !self.vpc_endpoints.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_vpc_endpoints()));
+            lines.push(format!(
+                "| ID | {} | {} |",
+                i18n.md_owner_account(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for endpoint in &self.vpc_endpoints {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    endpoint.id, endpoint.owner, endpoint.status
+                ));
+            }
+        }
+
+        // Access Policy
+        if !self.access_policy.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_access_policy()));
+            lines.push(format!(
+                "| Sid | {} | {} | {} | {} |",
+                i18n.md_effect(),
+                i18n.md_principal(),
+                i18n.md_actions(),
+                i18n.md_condition()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for statement in &self.access_policy {
+                let sid = if statement.sid.is_empty() {
+                    "-"
+                } else {
+                    statement.sid.as_str()
+                };
+                let condition = if statement.conditional { "✓" } else { "-" };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    sid,
+                    statement.effect,
+                    or_dash(&statement.principals),
+                    or_dash(&statement.actions),
+                    condition
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpenSearchDomainDetail, OpenSearchVpcEndpoint};
+    use crate::aws_cli::kms::summarize_key_policy;
+    use crate::i18n::Language;
+
+    fn sample_detail() -> OpenSearchDomainDetail {
+        OpenSearchDomainDetail {
+            name: "logs".to_string(),
+            arn: "arn:aws:es:ap-northeast-2:123456789012:domain/logs".to_string(),
+            domain_id: "123456789012/logs".to_string(),
+            engine_version: "OpenSearch_2.11".to_string(),
+            state: "Active".to_string(),
+            instance_type: "r6g.large.search".to_string(),
+            instance_count: 3,
+            dedicated_master_type: Some("m6g.large.search".to_string()),
+            dedicated_master_count: 3,
+            zone_awareness_azs: Some(3),
+            warm_type: None,
+            warm_count: 0,
+            volume_type: Some("gp3".to_string()),
+            volume_size_gib: Some(100),
+            iops: Some(3000),
+            throughput: Some(125),
+            endpoints: vec![(
+                "vpc".to_string(),
+                "vpc-logs-abc.ap-northeast-2.es.amazonaws.com".to_string(),
+            )],
+            vpc_id: Some("vpc-0123".to_string()),
+            subnets: vec!["subnet-a".to_string(), "subnet-b".to_string()],
+            security_groups: vec!["sg-0123".to_string()],
+            vpc_endpoints: vec![OpenSearchVpcEndpoint {
+                id: "aos-0123".to_string(),
+                owner: "210987654321".to_string(),
+                status: "ACTIVE".to_string(),
+            }],
+            enforce_https: true,
+            tls_security_policy: "Policy-Min-TLS-1-2-2019-07".to_string(),
+            node_to_node_encryption: true,
+            encryption_at_rest: true,
+            kms_key_id: Some("arn:aws:kms:ap-northeast-2:123456789012:key/abcd".to_string()),
+            access_policy: summarize_key_policy(
+                r#"{"Statement":[{"Effect":"Allow","Principal":{"AWS":"*"},"Action":"es:ESHttp*","Resource":"arn:aws:es:ap-northeast-2:123456789012:domain/logs/*","Condition":{"IpAddress":{"aws:SourceIp":"10.0.0.0/8"}}}]}"#,
+            ),
+            tags: vec![("Team".to_string(), "search".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_opensearch_domain_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## OpenSearch Domain (logs)"));
+        assert!(markdown.contains("| Version | OpenSearch_2.11 |"));
+        assert!(
+            markdown.contains("| Endpoint (vpc) | vpc-logs-abc.ap-northeast-2.es.amazonaws.com |")
+        );
+        assert!(markdown.contains("| Data Nodes | r6g.large.search x 3 |"));
+        assert!(markdown.contains("| Dedicated Master Nodes | m6g.large.search x 3 |"));
+        assert!(!markdown.contains("UltraWarm"));
+        assert!(markdown.contains("| Zone Awareness | Enabled (3 AZ) |"));
+        assert!(markdown.contains("| EBS per Node | gp3, 100 GiB, 3000 IOPS, 125 MiB/s |"));
+        assert!(markdown.contains("| Require HTTPS | Enabled (Policy-Min-TLS-1-2-2019-07) |"));
+        assert!(markdown.contains("| Subnets | subnet-a, subnet-b |"));
+        assert!(markdown.contains("| aos-0123 | 210987654321 | ACTIVE |"));
+        assert!(markdown.contains("| - | Allow | * | es:ESHttp* | ✓ |"));
+        assert!(markdown.contains("| Team | search |"));
+
+        let mut detail = sample_detail();
+        detail.endpoints = vec![(
+            String::new(),
+            "search-logs-abc.ap-northeast-2.es.amazonaws.com".to_string(),
+        )];
+        detail.dedicated_master_type = None;
+        detail.volume_type = None;
+        detail.vpc_id = None;
+        detail.vpc_endpoints.clear();
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 엔드포인트 | search-logs-abc"));
+        assert!(markdown.contains("| 전용 마스터 노드 | 비활성화 |"));
+        assert!(markdown.contains("| 노드당 EBS | 인스턴스 스토어 |"));
+        assert!(!markdown.contains("### VPC"));
+    }
+}
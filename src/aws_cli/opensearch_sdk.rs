@@ -0,0 +1,426 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::kms::summarize_key_policy;
+use crate::aws_cli::opensearch::{OpenSearchDomainDetail, OpenSearchVpcEndpoint};
+use aws_sdk_opensearch::types::{DomainStatus, Tag, VpcEndpointSummary};
+
+// DescribeDomains는 한 번에 최대 5개 도메인까지 조회
+const DESCRIBE_DOMAINS_BATCH: usize = 5;
+
+/// List OpenSearch domains using AWS SDK
+pub fn list_opensearch_domains() -> Vec<AwsResource> {
+    get_runtime().block_on(list_opensearch_domains_async())
+}
+
+async fn list_opensearch_domains_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_opensearch, &config);
+
+    let names: Vec<String> = match client.list_domain_names().send().await {
+        Ok(output) => output
+            .domain_names()
+            .iter()
+            .filter_map(|domain| domain.domain_name())
+            .map(str::to_string)
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing OpenSearch domains: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    // 목록에 버전과 인스턴스 구성을 보여주기 위해 도메인 상세를 묶어서 조회
+    let mut resources = Vec::new();
+    for batch in names.chunks(DESCRIBE_DOMAINS_BATCH) {
+        match client
+            .describe_domains()
+            .set_domain_names(Some(batch.to_vec()))
+            .send()
+            .await
+        {
+            Ok(output) => {
+                resources.extend(output.domain_status_list().iter().map(map_domain_resource))
+            }
+            Err(e) => tracing::error!("Error describing OpenSearch domains: {:?}", e),
+        }
+    }
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get OpenSearch domain detail (cluster, EBS, network, access policy) using AWS SDK
+pub fn get_opensearch_domain_detail(domain_name: &str) -> Option<OpenSearchDomainDetail> {
+    get_runtime().block_on(get_opensearch_domain_detail_async(domain_name))
+}
+
+async fn get_opensearch_domain_detail_async(domain_name: &str) -> Option<OpenSearchDomainDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_opensearch, &config);
+
+    let output = match client
+        .describe_domain()
+        .domain_name(domain_name)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!(
+                "Error describing OpenSearch domain {}: {:?}",
+                domain_name,
+                e
+            );
+            return None;
+        }
+    };
+    let mut detail = map_domain_detail(output.domain_status()?);
+
+    // VPC 엔드포인트/태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_vpc_endpoints_for_domain()
+        .domain_name(domain_name)
+        .send()
+        .await
+    {
+        Ok(output) => detail.vpc_endpoints = map_vpc_endpoints(output.vpc_endpoint_summary_list()),
+        Err(e) => tracing::warn!("Error listing VPC endpoints for {}: {:?}", domain_name, e),
+    }
+
+    match client.list_tags().arn(&detail.arn).send().await {
+        Ok(output) => detail.tags = map_tags(output.tag_list()),
+        Err(e) => tracing::warn!("Error getting tags for {}: {:?}", detail.arn, e),
+    }
+
+    Some(detail)
+}
+
+// 생성/삭제/변경 중인 상태를 우선 표시하고, 없으면 이전 플래그로 판단
+fn domain_state(domain: &DomainStatus) -> String {
+    if let Some(status) = domain.domain_processing_status() {
+        return status.as_str().to_string();
+    }
+    if domain.deleted().unwrap_or(false) {
+        "Deleting".to_string()
+    } else if domain.processing().unwrap_or(false) || domain.upgrade_processing().unwrap_or(false) {
+        "Modifying".to_string()
+    } else {
+        "Active".to_string()
+    }
+}
+
+fn instance_type(domain: &DomainStatus) -> String {
+    domain
+        .cluster_config()
+        .and_then(|cluster| cluster.instance_type())
+        .map(|instance_type| instance_type.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+// 목록에는 상태와 "엔진 버전 / 인스턴스 유형 x 노드 수"를 표시하고, 상세 조회는 도메인 이름으로 함
+fn map_domain_resource(domain: &DomainStatus) -> AwsResource {
+    AwsResource {
+        name: domain.domain_name().to_string(),
+        id: domain.domain_name().to_string(),
+        state: domain_state(domain),
+        az: String::new(),
+        cidr: format!(
+            "{} / {} x {}",
+            domain.engine_version().unwrap_or_default(),
+            instance_type(domain),
+            domain
+                .cluster_config()
+                .and_then(|cluster| cluster.instance_count())
+                .unwrap_or_default()
+        ),
+        owner_id: String::new(),
+    }
+}
+
+fn map_domain_detail(domain: &DomainStatus) -> OpenSearchDomainDetail {
+    let cluster = domain.cluster_config();
+    let dedicated_master =
+        cluster.filter(|cluster| cluster.dedicated_master_enabled().unwrap_or(false));
+    let warm = cluster.filter(|cluster| cluster.warm_enabled().unwrap_or(false));
+    let ebs = domain
+        .ebs_options()
+        .filter(|ebs| ebs.ebs_enabled().unwrap_or(false));
+    let vpc = domain.vpc_options();
+    let endpoint_options = domain.domain_endpoint_options();
+    let at_rest = domain.encryption_at_rest_options();
+
+    // 퍼블릭 도메인은 endpoint, VPC 도메인은 endpoints 맵(vpc, vpcv2 등)에 주소가 있음
+    let mut endpoints: Vec<(String, String)> = domain
+        .endpoints()
+        .map(|endpoints| {
+            endpoints
+                .iter()
+                .map(|(kind, endpoint)| (kind.clone(), endpoint.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    endpoints.sort();
+    if let Some(endpoint) = domain.endpoint() {
+        endpoints.insert(0, (String::new(), endpoint.to_string()));
+    }
+
+    OpenSearchDomainDetail {
+        name: domain.domain_name().to_string(),
+        arn: domain.arn().to_string(),
+        domain_id: domain.domain_id().to_string(),
+        engine_version: domain.engine_version().unwrap_or_default().to_string(),
+        state: domain_state(domain),
+        instance_type: instance_type(domain),
+        instance_count: cluster
+            .and_then(|cluster| cluster.instance_count())
+            .unwrap_or_default(),
+        dedicated_master_type: dedicated_master
+            .and_then(|cluster| cluster.dedicated_master_type())
+            .map(|master_type| master_type.as_str().to_string()),
+        dedicated_master_count: dedicated_master
+            .and_then(|cluster| cluster.dedicated_master_count())
+            .unwrap_or_default(),
+        // 가용 영역 수를 지정하지 않으면 기본값 2
+        zone_awareness_azs: cluster
+            .filter(|cluster| cluster.zone_awareness_enabled().unwrap_or(false))
+            .map(|cluster| {
+                cluster
+                    .zone_awareness_config()
+                    .and_then(|config| config.availability_zone_count())
+                    .unwrap_or(2)
+            }),
+        warm_type: warm
+            .and_then(|cluster| cluster.warm_type())
+            .map(|warm_type| warm_type.as_str().to_string()),
+        warm_count: warm
+            .and_then(|cluster| cluster.warm_count())
+            .unwrap_or_default(),
+        volume_type: ebs.map(|ebs| {
+            ebs.volume_type()
+                .map(|volume_type| volume_type.as_str())
+                .unwrap_or_default()
+                .to_string()
+        }),
+        volume_size_gib: ebs.and_then(|ebs| ebs.volume_size()),
+        iops: ebs.and_then(|ebs| ebs.iops()),
+        throughput: ebs.and_then(|ebs| ebs.throughput()),
+        endpoints,
+        vpc_id: vpc.and_then(|vpc| vpc.vpc_id()).map(str::to_string),
+        subnets: vpc.map(|vpc| vpc.subnet_ids().to_vec()).unwrap_or_default(),
+        security_groups: vpc
+            .map(|vpc| vpc.security_group_ids().to_vec())
+            .unwrap_or_default(),
+        vpc_endpoints: Vec::new(),
+        enforce_https: endpoint_options
+            .and_then(|options| options.enforce_https())
+            .unwrap_or(false),
+        tls_security_policy: endpoint_options
+            .and_then(|options| options.tls_security_policy())
+            .map(|policy| policy.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        node_to_node_encryption: domain
+            .node_to_node_encryption_options()
+            .and_then(|options| options.enabled())
+            .unwrap_or(false),
+        encryption_at_rest: at_rest
+            .and_then(|options| options.enabled())
+            .unwrap_or(false),
+        kms_key_id: at_rest
+            .and_then(|options| options.kms_key_id())
+            .map(str::to_string),
+        access_policy: domain
+            .access_policies()
+            .map(summarize_key_policy)
+            .unwrap_or_default(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_vpc_endpoints(summaries: &[VpcEndpointSummary]) -> Vec<OpenSearchVpcEndpoint> {
+    summaries
+        .iter()
+        .map(|summary| OpenSearchVpcEndpoint {
+            id: summary.vpc_endpoint_id().unwrap_or_default().to_string(),
+            owner: summary.vpc_endpoint_owner().unwrap_or_default().to_string(),
+            status: summary
+                .status()
+                .map(|status| status.as_str())
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .collect()
+}
+
+fn map_tags(tags: &[Tag]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect();
+    tags.sort();
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_domain_detail, map_domain_resource, map_tags, map_vpc_endpoints};
+    use aws_sdk_opensearch::types::{
+        ClusterConfig, DomainEndpointOptions, DomainProcessingStatusType, DomainStatus, EbsOptions,
+        EncryptionAtRestOptions, NodeToNodeEncryptionOptions, OpenSearchPartitionInstanceType, Tag,
+        TlsSecurityPolicy, VolumeType, VpcDerivedInfo, VpcEndpointStatus, VpcEndpointSummary,
+        ZoneAwarenessConfig,
+    };
+
+    const ARN: &str = "arn:aws:es:ap-northeast-2:123456789012:domain/logs";
+
+    fn domain() -> DomainStatus {
+        DomainStatus::builder()
+            .domain_id("123456789012/logs")
+            .domain_name("logs")
+            .arn(ARN)
+            .engine_version("OpenSearch_2.11")
+            .domain_processing_status(DomainProcessingStatusType::Active)
+            .cluster_config(
+                ClusterConfig::builder()
+                    .instance_type(OpenSearchPartitionInstanceType::R6gLargeSearch)
+                    .instance_count(3)
+                    .dedicated_master_enabled(true)
+                    .dedicated_master_type(OpenSearchPartitionInstanceType::M6gLargeSearch)
+                    .dedicated_master_count(3)
+                    .zone_awareness_enabled(true)
+                    .zone_awareness_config(
+                        ZoneAwarenessConfig::builder()
+                            .availability_zone_count(3)
+                            .build(),
+                    )
+                    .warm_enabled(false)
+                    .build(),
+            )
+            .ebs_options(
+                EbsOptions::builder()
+                    .ebs_enabled(true)
+                    .volume_type(VolumeType::Gp3)
+                    .volume_size(100)
+                    .iops(3000)
+                    .throughput(125)
+                    .build(),
+            )
+            .endpoints("vpc", "vpc-logs-abc.ap-northeast-2.es.amazonaws.com")
+            .vpc_options(
+                VpcDerivedInfo::builder()
+                    .vpc_id("vpc-0123")
+                    .subnet_ids("subnet-a")
+                    .security_group_ids("sg-0123")
+                    .build(),
+            )
+            .domain_endpoint_options(
+                DomainEndpointOptions::builder()
+                    .enforce_https(true)
+                    .tls_security_policy(TlsSecurityPolicy::PolicyMinTls12201907)
+                    .build(),
+            )
+            .node_to_node_encryption_options(
+                NodeToNodeEncryptionOptions::builder().enabled(true).build(),
+            )
+            .encryption_at_rest_options(
+                EncryptionAtRestOptions::builder()
+                    .enabled(true)
+                    .kms_key_id("arn:aws:kms:ap-northeast-2:123456789012:key/abcd")
+                    .build(),
+            )
+            .access_policies(
+                r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"AWS":"arn:aws:iam::123456789012:root"},"Action":"es:*","Resource":"arn:aws:es:ap-northeast-2:123456789012:domain/logs/*"}]}"#,
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn map_domain_resource_shows_version_and_data_nodes() {
+        let resource = map_domain_resource(&domain());
+        assert_eq!(resource.name, "logs");
+        assert_eq!(resource.id, "logs");
+        assert_eq!(resource.state, "Active");
+        assert_eq!(resource.cidr, "OpenSearch_2.11 / r6g.large.search x 3");
+    }
+
+    #[test]
+    fn map_domain_detail_reads_cluster_storage_network_and_policy() {
+        let detail = map_domain_detail(&domain());
+        assert_eq!(detail.instance_type, "r6g.large.search");
+        assert_eq!(
+            detail.dedicated_master_type.as_deref(),
+            Some("m6g.large.search")
+        );
+        assert_eq!(detail.dedicated_master_count, 3);
+        assert_eq!(detail.zone_awareness_azs, Some(3));
+        assert_eq!(detail.warm_type, None);
+        assert_eq!(detail.volume_type.as_deref(), Some("gp3"));
+        assert_eq!(detail.volume_size_gib, Some(100));
+        assert_eq!(detail.iops, Some(3000));
+        assert_eq!(detail.throughput, Some(125));
+        assert_eq!(
+            detail.endpoints,
+            vec![(
+                "vpc".to_string(),
+                "vpc-logs-abc.ap-northeast-2.es.amazonaws.com".to_string()
+            )]
+        );
+        assert_eq!(detail.vpc_id.as_deref(), Some("vpc-0123"));
+        assert_eq!(detail.subnets, vec!["subnet-a"]);
+        assert!(detail.enforce_https);
+        assert_eq!(detail.tls_security_policy, "Policy-Min-TLS-1-2-2019-07");
+        assert!(detail.node_to_node_encryption);
+        assert!(detail.encryption_at_rest);
+        assert_eq!(detail.access_policy.len(), 1);
+        assert_eq!(detail.access_policy[0].actions, vec!["es:*"]);
+
+        // 퍼블릭 도메인, EBS 미사용, 처리 중 플래그만 있는 경우
+        let public = DomainStatus::builder()
+            .domain_id("123456789012/search")
+            .domain_name("search")
+            .arn("arn:aws:es:ap-northeast-2:123456789012:domain/search")
+            .endpoint("search-abc.ap-northeast-2.es.amazonaws.com")
+            .processing(true)
+            .ebs_options(EbsOptions::builder().ebs_enabled(false).build())
+            .build()
+            .unwrap();
+        let detail = map_domain_detail(&public);
+        assert_eq!(detail.state, "Modifying");
+        assert_eq!(
+            detail.endpoints,
+            vec![(
+                String::new(),
+                "search-abc.ap-northeast-2.es.amazonaws.com".to_string()
+            )]
+        );
+        assert_eq!(detail.volume_type, None);
+        assert_eq!(detail.dedicated_master_type, None);
+        assert_eq!(detail.zone_awareness_azs, None);
+        assert!(detail.access_policy.is_empty());
+    }
+
+    #[test]
+    fn map_vpc_endpoints_and_tags_keep_owner_status_and_sort() {
+        let endpoints = map_vpc_endpoints(&[VpcEndpointSummary::builder()
+            .vpc_endpoint_id("aos-0123")
+            .vpc_endpoint_owner("210987654321")
+            .domain_arn(ARN)
+            .status(VpcEndpointStatus::Active)
+            .build()]);
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].owner, "210987654321");
+        assert_eq!(endpoints[0].status, "ACTIVE");
+
+        let tags = map_tags(&[
+            Tag::builder().key("Team").value("search").build().unwrap(),
+            Tag::builder().key("Env").value("prod").build().unwrap(),
+        ]);
+        assert_eq!(
+            tags,
+            vec![
+                ("Env".to_string(), "prod".to_string()),
+                ("Team".to_string(), "search".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::aws_cli::arn::Arn;
+
+/// AWS partition: commercial (`aws`), GovCloud (`aws-us-gov`) or China (`aws-cn`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Partition {
+    #[default]
+    Aws,
+    AwsUsGov,
+    AwsCn,
+}
+
+impl Partition {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "aws" => Some(Self::Aws),
+            "aws-us-gov" => Some(Self::AwsUsGov),
+            "aws-cn" => Some(Self::AwsCn),
+            _ => None,
+        }
+    }
+
+    /// Partition field of an ARN such as the caller identity.
+    pub fn from_arn(arn: &str) -> Option<Self> {
+        Arn::parse(arn).and_then(|arn| Self::parse(arn.partition))
+    }
+
+    /// Partition a region code belongs to (unknown regions are commercial).
+    pub fn from_region(region: &str) -> Self {
+        if region.starts_with("us-gov-") {
+            Self::AwsUsGov
+        } else if region.starts_with("cn-") {
+            Self::AwsCn
+        } else {
+            Self::Aws
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Aws => "aws",
+            Self::AwsUsGov => "aws-us-gov",
+            Self::AwsCn => "aws-cn",
+        }
+    }
+
+    /// Domain suffix of service endpoints, e.g. `execute-api.<region>.amazonaws.com.cn`.
+    pub fn dns_suffix(&self) -> &'static str {
+        match self {
+            Self::AwsCn => "amazonaws.com.cn",
+            _ => "amazonaws.com",
+        }
+    }
+
+    /// Region that hosts global services (IAM, Route53, CloudFront) in this partition.
+    pub fn global_region(&self) -> &'static str {
+        match self {
+            Self::Aws => "us-east-1",
+            Self::AwsUsGov => "us-gov-west-1",
+            Self::AwsCn => "cn-northwest-1",
+        }
+    }
+
+    /// Management console origin for `region`.
+    pub fn console_base(&self, region: &str) -> String {
+        match self {
+            Self::Aws => format!("https://{region}.console.aws.amazon.com"),
+            // GovCloud/중국 콘솔은 리전별 하위 도메인이 없음
+            Self::AwsUsGov => "https://console.amazonaws-us-gov.com".to_string(),
+            Self::AwsCn => "https://console.amazonaws.cn".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Partition;
+
+    #[test]
+    fn partition_is_detected_from_arn_and_region() {
+        assert_eq!(
+            Partition::from_arn("arn:aws-us-gov:iam::123456789012:user/ops"),
+            Some(Partition::AwsUsGov)
+        );
+        assert_eq!(
+            Partition::from_arn("arn:aws-cn:sts::123456789012:assumed-role/ops/session"),
+            Some(Partition::AwsCn)
+        );
+        assert_eq!(
+            Partition::from_arn("arn:aws:iam::123456789012:root"),
+            Some(Partition::Aws)
+        );
+        assert_eq!(Partition::from_arn("not-an-arn"), None);
+
+        assert_eq!(Partition::from_region("us-gov-east-1"), Partition::AwsUsGov);
+        assert_eq!(Partition::from_region("cn-north-1"), Partition::AwsCn);
+        assert_eq!(Partition::from_region("us-east-1"), Partition::Aws);
+    }
+
+    #[test]
+    fn partition_endpoints_and_console() {
+        assert_eq!(Partition::AwsCn.dns_suffix(), "amazonaws.com.cn");
+        assert_eq!(Partition::AwsUsGov.dns_suffix(), "amazonaws.com");
+        assert_eq!(
+            Partition::Aws.console_base("eu-west-1"),
+            "https://eu-west-1.console.aws.amazon.com"
+        );
+        assert_eq!(
+            Partition::AwsUsGov.console_base("us-gov-west-1"),
+            "https://console.amazonaws-us-gov.com"
+        );
+        assert_eq!(Partition::AwsCn.global_region(), "cn-northwest-1");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::aws_cli::common::AwsResource;
+pub use crate::aws_cli::rds_sdk::{
+    get_db_cluster_detail, get_db_instance_detail, list_db_clusters, list_db_instances,
+};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RdsClusterMember {
+    pub instance_id: String,
+    pub is_writer: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct RdsDetail {
+    pub identifier: String,
+    pub arn: String,
+    pub is_cluster: bool,
+    pub engine: String,
+    pub engine_version: String,
+    pub instance_class: String,
+    pub status: String,
+    pub storage_type: String,
+    pub allocated_storage: i32,
+    pub storage_encrypted: bool,
+    pub multi_az: bool,
+    pub availability_zones: Vec<String>,
+    pub endpoint: String,
+    pub reader_endpoint: Option<String>,
+    pub port: Option<i32>,
+    pub subnet_group: String,
+    pub parameter_group: String,
+    pub security_groups: Vec<String>,
+    // 클러스터에 속한 인스턴스라면 상위 클러스터 식별자
+    pub cluster_identifier: Option<String>,
+    pub members: Vec<RdsClusterMember>,
+    pub tags: Vec<(String, String)>,
+}
+
+/// List RDS clusters followed by DB instances, as shown on the RDS screen
+pub fn list_rds_resources() -> Vec<AwsResource> {
+    let mut resources = list_db_clusters();
+    resources.extend(list_db_instances());
+    resources
+}
+
+/// Get RDS detail, trying a DB instance first and then a cluster
+pub fn get_rds_detail(identifier: &str) -> Option<RdsDetail> {
+    get_db_instance_detail(identifier).or_else(|| get_db_cluster_detail(identifier))
+}
+
+pub(super) fn storage_display(
+    storage_type: &str,
+    allocated_storage: i32,
+    encrypted: bool,
+    i18n: &I18n,
+) -> String {
+    let mut parts = Vec::new();
+    if !storage_type.is_empty() {
+        parts.push(storage_type.to_string());
+    }
+    // Aurora 클러스터는 스토리지가 자동 확장되어 할당 크기가 0 또는 1로 보고됨
+    if allocated_storage > 1 {
+        parts.push(format!("{} GiB", allocated_storage));
+    }
+    if parts.is_empty() {
+        parts.push("-".to_string());
+    }
+    if encrypted {
+        parts.push(format!("({})", i18n.md_encrypted()));
+    }
+    parts.join(" ")
+}
+
+impl RdsDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let title = if self.is_cluster {
+            i18n.md_rds_cluster()
+        } else {
+            i18n.md_rds_instance()
+        };
+        let engine = if self.engine_version.is_empty() {
+            self.engine.clone()
+        } else {
+            format!("{} {}", self.engine, self.engine_version)
+        };
+        let endpoint = if self.endpoint.is_empty() {
+            "-".to_string()
+        } else if let Some(port) = self.port {
+            format!("{}:{}", self.endpoint, port)
+        } else {
+            self.endpoint.clone()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, self.identifier),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.identifier),
+            format!("| {} | {} |", i18n.md_engine(), engine),
+        ];
+        if !self.instance_class.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_instance_class(),
+                self.instance_class
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_state(), self.status));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_storage(),
+            storage_display(
+                &self.storage_type,
+                self.allocated_storage,
+                self.storage_encrypted,
+                &i18n
+            )
+        ));
+        lines.push(format!("| {} | {} |", i18n.md_multi_az(), self.multi_az));
+        if !self.availability_zones.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_availability_zone(),
+                self.availability_zones.join(", ")
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_endpoint(), endpoint));
+        if let Some(ref reader) = self.reader_endpoint {
+            lines.push(format!("| {} | {} |", i18n.md_reader_endpoint(), reader));
+        }
+        if !self.subnet_group.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_db_subnet_group(),
+                self.subnet_group
+            ));
+        }
+        if !self.parameter_group.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_parameter_group(),
+                self.parameter_group
+            ));
+        }
+        if let Some(ref cluster) = self.cluster_identifier {
+            lines.push(format!("| {} | {} |", i18n.md_rds_cluster(), cluster));
+        }
+
+        // Security Groups
+        if !self.security_groups.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_security_groups()));
+            for sg in &self.security_groups {
+                lines.push(format!("- {}", sg));
+            }
+        }
+
+        // Cluster Members
+        if !self.members.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_cluster_members()));
+            lines.push(format!("| {} | {} |", i18n.md_name(), i18n.md_role()));
+            lines.push("|:---|:---|".to_string());
+            for member in &self.members {
+                let role = if member.is_writer { "Writer" } else { "Reader" };
+                lines.push(format!("| {} | {} |", member.instance_id, role));
+            }
+        }
+
+        // Tags
+        if self.tags.iter().any(|(key, _)| key != "Name") {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                if key != "Name" {
+                    lines.push(format!("| {} | {} |", key, value));
+                }
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RdsClusterMember, RdsDetail, storage_display};
+    use crate::i18n::{I18n, Language};
+
+    fn sample_instance_detail() -> RdsDetail {
+        RdsDetail {
+            identifier: "orders-db".to_string(),
+            arn: "arn:aws:rds:ap-northeast-2:123456789012:db:orders-db".to_string(),
+            is_cluster: false,
+            engine: "postgres".to_string(),
+            engine_version: "16.3".to_string(),
+            instance_class: "db.t4g.medium".to_string(),
+            status: "available".to_string(),
+            storage_type: "gp3".to_string(),
+            allocated_storage: 100,
+            storage_encrypted: true,
+            multi_az: true,
+            availability_zones: vec!["ap-northeast-2a".to_string()],
+            endpoint: "orders-db.abc.ap-northeast-2.rds.amazonaws.com".to_string(),
+            reader_endpoint: None,
+            port: Some(5432),
+            subnet_group: "db-private".to_string(),
+            parameter_group: "default.postgres16".to_string(),
+            security_groups: vec!["sg-0123".to_string()],
+            cluster_identifier: None,
+            members: Vec::new(),
+            tags: vec![
+                ("Name".to_string(), "orders-db".to_string()),
+                ("Env".to_string(), "prod".to_string()),
+            ],
+        }
+    }
+
+    #[test]
+    fn scenario_rds_instance_markdown_render() {
+        let markdown = sample_instance_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## RDS Instance (orders-db)"));
+        assert!(markdown.contains("| Engine | postgres 16.3 |"));
+        assert!(markdown.contains("| Instance Class | db.t4g.medium |"));
+        assert!(markdown.contains("| Storage | gp3 100 GiB (Encrypted) |"));
+        assert!(markdown.contains("| Multi-AZ | true |"));
+        assert!(
+            markdown.contains("| Endpoint | orders-db.abc.ap-northeast-2.rds.amazonaws.com:5432 |")
+        );
+        assert!(markdown.contains("| DB Subnet Group | db-private |"));
+        assert!(markdown.contains("| Parameter Group | default.postgres16 |"));
+        assert!(markdown.contains("- sg-0123"));
+        assert!(markdown.contains("| Env | prod |"));
+        assert!(!markdown.contains("| Name | orders-db |"));
+    }
+
+    #[test]
+    fn scenario_rds_cluster_markdown_members() {
+        let mut detail = sample_instance_detail();
+        detail.identifier = "orders-aurora".to_string();
+        detail.is_cluster = true;
+        detail.instance_class = String::new();
+        detail.allocated_storage = 1;
+        detail.storage_type = "aurora".to_string();
+        detail.reader_endpoint = Some("orders-aurora.cluster-ro-abc".to_string());
+        detail.members = vec![
+            RdsClusterMember {
+                instance_id: "orders-aurora-1".to_string(),
+                is_writer: true,
+            },
+            RdsClusterMember {
+                instance_id: "orders-aurora-2".to_string(),
+                is_writer: false,
+            },
+        ];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("## RDS 클러스터 (orders-aurora)"));
+        assert!(markdown.contains("| 스토리지 | aurora (암호화) |"));
+        assert!(markdown.contains("| orders-aurora-1 | Writer |"));
+        assert!(markdown.contains("| orders-aurora-2 | Reader |"));
+        assert!(!markdown.contains("| 인스턴스 클래스 |"));
+    }
+
+    #[test]
+    fn scenario_rds_storage_display_fallback() {
+        let i18n = I18n::new(Language::English);
+        assert_eq!(storage_display("", 0, false, &i18n), "-");
+        assert_eq!(storage_display("gp2", 20, false, &i18n), "gp2 20 GiB");
+    }
+}
@@ -0,0 +1,339 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::rds::{RdsClusterMember, RdsDetail};
+
+/// List all RDS DB instances using AWS SDK
+pub fn list_db_instances() -> Vec<AwsResource> {
+    get_runtime().block_on(list_db_instances_async())
+}
+
+async fn list_db_instances_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_rds, &config);
+
+    let result = client
+        .describe_db_instances()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(instances) => instances.iter().map(map_db_instance_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing RDS instances: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// List all RDS DB clusters using AWS SDK
+pub fn list_db_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_db_clusters_async())
+}
+
+async fn list_db_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_rds, &config);
+
+    let result = client
+        .describe_db_clusters()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(clusters) => clusters.iter().map(map_db_cluster_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing RDS clusters: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get RDS DB instance detail using AWS SDK
+pub fn get_db_instance_detail(identifier: &str) -> Option<RdsDetail> {
+    get_runtime().block_on(get_db_instance_detail_async(identifier))
+}
+
+async fn get_db_instance_detail_async(identifier: &str) -> Option<RdsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_rds, &config);
+
+    let result = client
+        .describe_db_instances()
+        .db_instance_identifier(identifier)
+        .send()
+        .await
+        .ok()?;
+
+    result.db_instances().first().map(map_db_instance_detail)
+}
+
+/// Get RDS DB cluster detail using AWS SDK
+pub fn get_db_cluster_detail(identifier: &str) -> Option<RdsDetail> {
+    get_runtime().block_on(get_db_cluster_detail_async(identifier))
+}
+
+async fn get_db_cluster_detail_async(identifier: &str) -> Option<RdsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_rds, &config);
+
+    let result = client
+        .describe_db_clusters()
+        .db_cluster_identifier(identifier)
+        .send()
+        .await
+        .ok()?;
+
+    result.db_clusters().first().map(map_db_cluster_detail)
+}
+
+fn map_db_instance_resource(db: &aws_sdk_rds::types::DbInstance) -> AwsResource {
+    let identifier = db.db_instance_identifier().unwrap_or_default().to_string();
+    AwsResource {
+        name: identifier.clone(),
+        id: identifier,
+        state: db.db_instance_status().unwrap_or_default().to_string(),
+        az: db.availability_zone().unwrap_or_default().to_string(),
+        cidr: db.engine().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_db_cluster_resource(cluster: &aws_sdk_rds::types::DbCluster) -> AwsResource {
+    let identifier = cluster
+        .db_cluster_identifier()
+        .unwrap_or_default()
+        .to_string();
+    AwsResource {
+        name: identifier.clone(),
+        id: identifier,
+        state: cluster.status().unwrap_or_default().to_string(),
+        az: String::new(),
+        cidr: format!("{} cluster", cluster.engine().unwrap_or_default()),
+        owner_id: String::new(),
+    }
+}
+
+fn map_tags(tags: &[aws_sdk_rds::types::Tag]) -> Vec<(String, String)> {
+    tags.iter()
+        .map(|t| {
+            (
+                t.key().unwrap_or_default().to_string(),
+                t.value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn map_security_groups(groups: &[aws_sdk_rds::types::VpcSecurityGroupMembership]) -> Vec<String> {
+    groups
+        .iter()
+        .filter_map(|g| g.vpc_security_group_id().map(|s| s.to_string()))
+        .collect()
+}
+
+fn map_db_instance_detail(db: &aws_sdk_rds::types::DbInstance) -> RdsDetail {
+    let endpoint = db.endpoint();
+    let parameter_group = db
+        .db_parameter_groups()
+        .iter()
+        .filter_map(|g| g.db_parameter_group_name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    RdsDetail {
+        identifier: db.db_instance_identifier().unwrap_or_default().to_string(),
+        arn: db.db_instance_arn().unwrap_or_default().to_string(),
+        is_cluster: false,
+        engine: db.engine().unwrap_or_default().to_string(),
+        engine_version: db.engine_version().unwrap_or_default().to_string(),
+        instance_class: db.db_instance_class().unwrap_or_default().to_string(),
+        status: db.db_instance_status().unwrap_or_default().to_string(),
+        storage_type: db.storage_type().unwrap_or_default().to_string(),
+        allocated_storage: db.allocated_storage().unwrap_or(0),
+        storage_encrypted: db.storage_encrypted().unwrap_or(false),
+        multi_az: db.multi_az().unwrap_or(false),
+        availability_zones: db
+            .availability_zone()
+            .map(|az| vec![az.to_string()])
+            .unwrap_or_default(),
+        endpoint: endpoint
+            .and_then(|e| e.address())
+            .unwrap_or_default()
+            .to_string(),
+        reader_endpoint: None,
+        port: endpoint.and_then(|e| e.port()),
+        subnet_group: db
+            .db_subnet_group()
+            .and_then(|g| g.db_subnet_group_name())
+            .unwrap_or_default()
+            .to_string(),
+        parameter_group,
+        security_groups: map_security_groups(db.vpc_security_groups()),
+        cluster_identifier: db.db_cluster_identifier().map(|s| s.to_string()),
+        members: Vec::new(),
+        tags: map_tags(db.tag_list()),
+    }
+}
+
+fn map_db_cluster_detail(cluster: &aws_sdk_rds::types::DbCluster) -> RdsDetail {
+    let members = cluster
+        .db_cluster_members()
+        .iter()
+        .map(|m| RdsClusterMember {
+            instance_id: m.db_instance_identifier().unwrap_or_default().to_string(),
+            is_writer: m.is_cluster_writer().unwrap_or(false),
+        })
+        .collect();
+
+    RdsDetail {
+        identifier: cluster
+            .db_cluster_identifier()
+            .unwrap_or_default()
+            .to_string(),
+        arn: cluster.db_cluster_arn().unwrap_or_default().to_string(),
+        is_cluster: true,
+        engine: cluster.engine().unwrap_or_default().to_string(),
+        engine_version: cluster.engine_version().unwrap_or_default().to_string(),
+        instance_class: cluster
+            .db_cluster_instance_class()
+            .unwrap_or_default()
+            .to_string(),
+        status: cluster.status().unwrap_or_default().to_string(),
+        storage_type: cluster.storage_type().unwrap_or_default().to_string(),
+        allocated_storage: cluster.allocated_storage().unwrap_or(0),
+        storage_encrypted: cluster.storage_encrypted().unwrap_or(false),
+        multi_az: cluster.multi_az().unwrap_or(false),
+        availability_zones: cluster.availability_zones().to_vec(),
+        endpoint: cluster.endpoint().unwrap_or_default().to_string(),
+        reader_endpoint: cluster.reader_endpoint().map(|s| s.to_string()),
+        port: cluster.port(),
+        subnet_group: cluster.db_subnet_group().unwrap_or_default().to_string(),
+        parameter_group: cluster
+            .db_cluster_parameter_group()
+            .unwrap_or_default()
+            .to_string(),
+        security_groups: map_security_groups(cluster.vpc_security_groups()),
+        cluster_identifier: None,
+        members,
+        tags: map_tags(cluster.tag_list()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_db_cluster_detail, map_db_cluster_resource, map_db_instance_detail,
+        map_db_instance_resource,
+    };
+
+    #[test]
+    fn map_db_instance_resource_uses_status_and_engine() {
+        let db = aws_sdk_rds::types::DbInstance::builder()
+            .db_instance_identifier("orders-db")
+            .db_instance_status("available")
+            .engine("postgres")
+            .availability_zone("ap-northeast-2a")
+            .build();
+
+        let resource = map_db_instance_resource(&db);
+        assert_eq!(resource.id, "orders-db");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "postgres");
+        assert_eq!(resource.az, "ap-northeast-2a");
+    }
+
+    #[test]
+    fn map_db_cluster_resource_marks_cluster_engine() {
+        let cluster = aws_sdk_rds::types::DbCluster::builder()
+            .db_cluster_identifier("orders-aurora")
+            .engine("aurora-postgresql")
+            .status("available")
+            .build();
+
+        let resource = map_db_cluster_resource(&cluster);
+        assert_eq!(resource.id, "orders-aurora");
+        assert_eq!(resource.cidr, "aurora-postgresql cluster");
+    }
+
+    #[test]
+    fn map_db_instance_detail_reads_network_and_groups() {
+        let endpoint = aws_sdk_rds::types::Endpoint::builder()
+            .address("orders-db.abc.ap-northeast-2.rds.amazonaws.com")
+            .port(5432)
+            .build();
+        let subnet_group = aws_sdk_rds::types::DbSubnetGroup::builder()
+            .db_subnet_group_name("db-private")
+            .build();
+        let parameter_group = aws_sdk_rds::types::DbParameterGroupStatus::builder()
+            .db_parameter_group_name("default.postgres16")
+            .build();
+        let sg = aws_sdk_rds::types::VpcSecurityGroupMembership::builder()
+            .vpc_security_group_id("sg-0123")
+            .status("active")
+            .build();
+        let tag = aws_sdk_rds::types::Tag::builder()
+            .key("Env")
+            .value("prod")
+            .build();
+
+        let db = aws_sdk_rds::types::DbInstance::builder()
+            .db_instance_identifier("orders-db")
+            .db_instance_class("db.t4g.medium")
+            .engine("postgres")
+            .engine_version("16.3")
+            .allocated_storage(100)
+            .storage_type("gp3")
+            .multi_az(true)
+            .endpoint(endpoint)
+            .db_subnet_group(subnet_group)
+            .db_parameter_groups(parameter_group)
+            .vpc_security_groups(sg)
+            .tag_list(tag)
+            .build();
+
+        let detail = map_db_instance_detail(&db);
+        assert!(!detail.is_cluster);
+        assert_eq!(detail.instance_class, "db.t4g.medium");
+        assert_eq!(detail.port, Some(5432));
+        assert_eq!(detail.subnet_group, "db-private");
+        assert_eq!(detail.parameter_group, "default.postgres16");
+        assert_eq!(detail.security_groups, vec!["sg-0123".to_string()]);
+        assert_eq!(detail.tags, vec![("Env".to_string(), "prod".to_string())]);
+        assert!(detail.multi_az);
+    }
+
+    #[test]
+    fn map_db_cluster_detail_collects_members() {
+        let writer = aws_sdk_rds::types::DbClusterMember::builder()
+            .db_instance_identifier("orders-aurora-1")
+            .is_cluster_writer(true)
+            .build();
+        let reader = aws_sdk_rds::types::DbClusterMember::builder()
+            .db_instance_identifier("orders-aurora-2")
+            .is_cluster_writer(false)
+            .build();
+
+        let cluster = aws_sdk_rds::types::DbCluster::builder()
+            .db_cluster_identifier("orders-aurora")
+            .engine("aurora-postgresql")
+            .db_subnet_group("db-private")
+            .availability_zones("ap-northeast-2a")
+            .availability_zones("ap-northeast-2c")
+            .db_cluster_members(writer)
+            .db_cluster_members(reader)
+            .build();
+
+        let detail = map_db_cluster_detail(&cluster);
+        assert!(detail.is_cluster);
+        assert_eq!(detail.subnet_group, "db-private");
+        assert_eq!(detail.availability_zones.len(), 2);
+        assert_eq!(detail.members.len(), 2);
+        assert!(detail.members[0].is_writer);
+        assert!(!detail.members[1].is_writer);
+    }
+}
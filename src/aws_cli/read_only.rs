@@ -0,0 +1,430 @@
+use crate::settings::RemoteBlueprintStore;
+use aws_sdk_dynamodb::operation::put_item::PutItemInput;
+use aws_sdk_resourcegroupstagging::operation::tag_resources::TagResourcesInput;
+use aws_sdk_s3::operation::put_object::PutObjectInput;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeSerializationInterceptorContextRef, Input,
+};
+use aws_smithy_runtime_api::client::orchestrator::Metadata;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// 읽기 전용 작업 접두사 (Describe*, List*, Get*)
+const READ_ONLY_PREFIXES: &[&str] = &["Describe", "List", "Get"];
+
+// 접두사는 다르지만 조회만 하는 작업
+const READ_OPERATIONS: &[(&str, &str)] = &[
+    ("CloudTrail", "LookupEvents"),
+    ("EC2", "SearchTransitGatewayRoutes"),
+];
+
+// 공유 블루프린트 저장소(settings.json의 blueprint_remote)에 쓰는 작업 (요청 대상까지 확인)
+const BLUEPRINT_STORE_WRITES: &[(&str, &str)] = &[("S3", "PutObject"), ("DynamoDB", "PutItem")];
+
+// Get* 이지만 비밀/파라미터 값을 반환하므로 읽기 작업이어도 차단 (메타데이터만 문서화)
+const SECRET_VALUE_READS: &[(&str, &str)] = &[
+    ("Secrets Manager", "GetSecretValue"),
+    ("Secrets Manager", "BatchGetSecretValue"),
+    ("SSM", "GetParameter"),
+    ("SSM", "GetParameters"),
+    ("SSM", "GetParametersByPath"),
+    ("SSM", "GetParameterHistory"),
+];
+
+// --allow-write로 시작한 경우에만 허용하는 쓰기 작업 (단일 리소스의 Name 태그 변경)
+const NAME_TAG_WRITES: &[(&str, &str)] = &[("Resource Groups Tagging API", "TagResources")];
+
+static WRITE_MODE: AtomicBool = AtomicBool::new(false);
+// 쓰기를 허용할 블루프린트 저장소 (settings.json의 blueprint_remote)
+static BLUEPRINT_STORE: Mutex<Option<RemoteBlueprintStore>> = Mutex::new(None);
+
+/// Allow the narrowly-scoped Name tag writes for the rest of the session.
+pub fn enable_write_mode() {
+    WRITE_MODE.store(true, Ordering::Relaxed);
+}
+
+fn write_mode_enabled() -> bool {
+    WRITE_MODE.load(Ordering::Relaxed)
+}
+
+/// Whether `service`/`operation` is a write-mode operation allowed in this session.
+fn is_allowed_write_operation(service: &str, operation: &str) -> bool {
+    write_mode_enabled() && NAME_TAG_WRITES.contains(&(service, operation))
+}
+
+/// Only allow blueprint store writes to `store` (`None` rejects them all).
+pub fn set_blueprint_store(store: Option<RemoteBlueprintStore>) {
+    if let Ok(mut current) = BLUEPRINT_STORE.lock() {
+        *current = store;
+    }
+}
+
+/// Whether a `PutObject`/`PutItem` request targets the configured blueprint store.
+fn is_blueprint_store_request(input: &Input, store: Option<&RemoteBlueprintStore>) -> bool {
+    match store {
+        Some(RemoteBlueprintStore::S3 { bucket, prefix }) => {
+            input.downcast_ref::<PutObjectInput>().is_some_and(|put| {
+                put.bucket() == Some(bucket.as_str())
+                    && put
+                        .key()
+                        .is_some_and(|key| key.starts_with(prefix.as_str()))
+            })
+        }
+        Some(RemoteBlueprintStore::DynamoDb { table }) => input
+            .downcast_ref::<PutItemInput>()
+            .is_some_and(|put| put.table_name() == Some(table.as_str())),
+        None => false,
+    }
+}
+
+/// Whether a `TagResources` request only sets the Name tag of a single resource.
+fn is_name_tag_request(input: &TagResourcesInput) -> bool {
+    input.resource_arn_list().len() == 1
+        && input
+            .tags()
+            .is_some_and(|tags| tags.len() == 1 && tags.contains_key("Name"))
+}
+
+/// Whether `service`/`operation` (SDK metadata names) may be called by emd.
+pub fn is_allowed_operation(service: &str, operation: &str) -> bool {
+    if SECRET_VALUE_READS.contains(&(service, operation)) {
+        return false;
+    }
+    READ_ONLY_PREFIXES.iter().any(|prefix| {
+        operation
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+    }) || READ_OPERATIONS.contains(&(service, operation))
+}
+
+/// Whether the request `input` for `service`/`operation` may be sent.
+fn is_allowed_request(
+    service: &str,
+    operation: &str,
+    input: &Input,
+    store: Option<&RemoteBlueprintStore>,
+) -> bool {
+    if is_allowed_operation(service, operation) {
+        return true;
+    }
+    if BLUEPRINT_STORE_WRITES.contains(&(service, operation)) {
+        return is_blueprint_store_request(input, store);
+    }
+    // 쓰기 모드에서도 요청 내용까지 확인해 Name 태그 하나만 바꾸는 경우만 허용
+    is_allowed_write_operation(service, operation)
+        && input
+            .downcast_ref::<TagResourcesInput>()
+            .is_some_and(is_name_tag_request)
+}
+
+/// Left in the config bag when the guard rejects a call, so it is never sent.
+#[derive(Debug)]
+pub(crate) struct GuardRejected;
+
+impl Storable for GuardRejected {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Rejects any SDK operation that is not read-only before it is sent.
+#[derive(Debug)]
+pub(crate) struct ReadOnlyInterceptor;
+
+impl Intercept for ReadOnlyInterceptor {
+    fn name(&self) -> &'static str {
+        "ReadOnlyInterceptor"
+    }
+
+    fn read_before_execution(
+        &self,
+        context: &BeforeSerializationInterceptorContextRef<'_>,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        // 메타데이터가 없으면 작업을 판단할 수 없으므로 거부
+        let Some(metadata) = cfg.load::<Metadata>().cloned() else {
+            return Err("read-only guard: operation metadata is missing".into());
+        };
+        let store = BLUEPRINT_STORE.lock().ok().and_then(|store| store.clone());
+        if is_allowed_request(
+            metadata.service(),
+            metadata.name(),
+            context.input(),
+            store.as_ref(),
+        ) {
+            return Ok(());
+        }
+
+        cfg.interceptor_state().store_put(GuardRejected);
+        tracing::error!(
+            service = %metadata.service(),
+            operation = %metadata.name(),
+            "Rejected non read-only AWS operation"
+        );
+        Err(format!(
+            "read-only guard: {}:{} is not a describe/list/get operation",
+            metadata.service(),
+            metadata.name()
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_allowed_operation, is_allowed_request, is_name_tag_request};
+    use crate::settings::RemoteBlueprintStore;
+    use aws_sdk_cloudtrail::operation::lookup_events::{LookupEvents, LookupEventsInput};
+    use aws_sdk_dynamodb::operation::put_item::{PutItem, PutItemInput};
+    use aws_sdk_ec2::operation::search_transit_gateway_routes::{
+        SearchTransitGatewayRoutes, SearchTransitGatewayRoutesInput,
+    };
+    use aws_sdk_resourcegroupstagging::operation::tag_resources::{
+        TagResources, TagResourcesInput,
+    };
+    use aws_sdk_s3::operation::put_object::{PutObject, PutObjectInput};
+    use aws_smithy_runtime_api::client::interceptors::context::Input;
+    use aws_smithy_runtime_api::client::orchestrator::Metadata;
+    use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugin;
+
+    fn s3_store() -> RemoteBlueprintStore {
+        RemoteBlueprintStore::S3 {
+            bucket: "team-docs".to_string(),
+            prefix: "emd/".to_string(),
+        }
+    }
+
+    fn dynamodb_store() -> RemoteBlueprintStore {
+        RemoteBlueprintStore::DynamoDb {
+            table: "emd-store".to_string(),
+        }
+    }
+
+    fn put_object(bucket: &str, key: &str) -> Input {
+        Input::erase(
+            PutObjectInput::builder()
+                .bucket(bucket)
+                .key(key)
+                .build()
+                .expect("input"),
+        )
+    }
+
+    fn put_item(table: &str) -> Input {
+        Input::erase(
+            PutItemInput::builder()
+                .table_name(table)
+                .build()
+                .expect("input"),
+        )
+    }
+
+    // SDK가 인터셉터에 넘기는 서비스/작업 이름
+    fn operation_metadata(operation: impl RuntimePlugin) -> Metadata {
+        operation
+            .config()
+            .and_then(|config| config.load::<Metadata>().cloned())
+            .expect("operation metadata")
+    }
+
+    #[test]
+    fn allows_describe_list_and_get_operations() {
+        assert!(is_allowed_operation("EC2", "DescribeInstances"));
+        assert!(is_allowed_operation("Lambda", "ListFunctions"));
+        assert!(is_allowed_operation("STS", "GetCallerIdentity"));
+        assert!(is_allowed_operation("Route 53", "ListResourceRecordSets"));
+    }
+
+    #[test]
+    fn rejects_mutating_operations() {
+        assert!(!is_allowed_operation("EC2", "TerminateInstances"));
+        assert!(!is_allowed_operation("EC2", "CreateTags"));
+        assert!(!is_allowed_operation("DynamoDB", "DeleteTable"));
+        assert!(!is_allowed_operation("IAM", "PutRolePolicy"));
+        assert!(!is_allowed_operation("S3", "DeleteObject"));
+        // 접두사만 같은 작업 이름은 읽기 작업이 아님
+        assert!(!is_allowed_operation("Example", "Listen"));
+        assert!(!is_allowed_operation("Example", "Getaway"));
+    }
+
+    #[test]
+    fn allows_listed_read_operations_without_read_prefix() {
+        assert!(is_allowed_operation("CloudTrail", "LookupEvents"));
+        assert!(!is_allowed_operation("CloudTrail", "StopLogging"));
+        assert!(!is_allowed_operation("EC2", "LookupEvents"));
+    }
+
+    #[test]
+    fn allows_transit_gateway_route_search() {
+        assert!(is_allowed_operation("EC2", "SearchTransitGatewayRoutes"));
+        assert!(!is_allowed_operation("EC2", "SearchLocalGatewayRoutes"));
+    }
+
+    #[test]
+    fn allows_only_writes_to_the_configured_blueprint_store() {
+        let s3 = s3_store();
+        let blueprints = put_object("team-docs", "emd/blueprints.json");
+        assert!(is_allowed_request(
+            "S3",
+            "PutObject",
+            &blueprints,
+            Some(&s3)
+        ));
+        assert!(!is_allowed_request(
+            "S3",
+            "PutObject",
+            &put_object("other-bucket", "emd/blueprints.json"),
+            Some(&s3)
+        ));
+        assert!(!is_allowed_request(
+            "S3",
+            "PutObject",
+            &put_object("team-docs", "backup/blueprints.json"),
+            Some(&s3)
+        ));
+        // 저장소를 설정하지 않으면 모두 거부
+        assert!(!is_allowed_request("S3", "PutObject", &blueprints, None));
+
+        let table = dynamodb_store();
+        assert!(is_allowed_request(
+            "DynamoDB",
+            "PutItem",
+            &put_item("emd-store"),
+            Some(&table)
+        ));
+        assert!(!is_allowed_request(
+            "DynamoDB",
+            "PutItem",
+            &put_item("orders"),
+            Some(&table)
+        ));
+        assert!(!is_allowed_request(
+            "DynamoDB",
+            "PutItem",
+            &put_item("emd-store"),
+            Some(&s3)
+        ));
+
+        // 요청 내용을 보지 않는 작업 이름만으로는 허용하지 않음
+        assert!(!is_allowed_operation("S3", "PutObject"));
+        assert!(!is_allowed_operation("DynamoDB", "PutItem"));
+    }
+
+    #[test]
+    fn every_non_read_prefixed_operation_in_use_is_gated() {
+        // src/aws_cli에서 호출하는 Describe/List/Get 이외의 작업 전체와 허용 여부
+        let s3 = s3_store();
+        let table = dynamodb_store();
+        let name_tag = TagResourcesInput::builder()
+            .resource_arn_list("arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123")
+            .tags("Name", "web-1")
+            .build()
+            .expect("input");
+        let cases = [
+            (
+                operation_metadata(LookupEvents::new()),
+                Input::erase(LookupEventsInput::builder().build().expect("input")),
+                None,
+                true,
+            ),
+            (
+                operation_metadata(SearchTransitGatewayRoutes::new()),
+                Input::erase(
+                    SearchTransitGatewayRoutesInput::builder()
+                        .transit_gateway_route_table_id("tgw-rtb-0123")
+                        .build()
+                        .expect("input"),
+                ),
+                None,
+                true,
+            ),
+            (
+                operation_metadata(PutObject::new()),
+                put_object("team-docs", "emd/blueprints.json"),
+                Some(&s3),
+                true,
+            ),
+            (
+                operation_metadata(PutObject::new()),
+                put_object("other-bucket", "emd/blueprints.json"),
+                Some(&s3),
+                false,
+            ),
+            (
+                operation_metadata(PutItem::new()),
+                put_item("emd-store"),
+                Some(&table),
+                true,
+            ),
+            (
+                operation_metadata(PutItem::new()),
+                put_item("orders"),
+                Some(&table),
+                false,
+            ),
+            // --allow-write 없이 시작하면 Name 태그 변경도 거부
+            (
+                operation_metadata(TagResources::new()),
+                Input::erase(name_tag),
+                None,
+                false,
+            ),
+        ];
+        for (metadata, input, store, allowed) in cases {
+            assert_eq!(
+                is_allowed_request(metadata.service(), metadata.name(), &input, store),
+                allowed,
+                "{}:{}",
+                metadata.service(),
+                metadata.name()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_secret_value_reads() {
+        assert!(is_allowed_operation("Secrets Manager", "ListSecrets"));
+        assert!(is_allowed_operation("Secrets Manager", "DescribeSecret"));
+        assert!(!is_allowed_operation("Secrets Manager", "GetSecretValue"));
+        assert!(!is_allowed_operation(
+            "Secrets Manager",
+            "BatchGetSecretValue"
+        ));
+    }
+
+    #[test]
+    fn tag_resources_is_rejected_outside_write_mode() {
+        assert!(!is_allowed_operation(
+            "Resource Groups Tagging API",
+            "TagResources"
+        ));
+    }
+
+    #[test]
+    fn name_tag_request_must_target_one_resource_and_only_name() {
+        let arn = "arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123";
+        let name_only = TagResourcesInput::builder()
+            .resource_arn_list(arn)
+            .tags("Name", "web-1")
+            .build()
+            .expect("input");
+        assert!(is_name_tag_request(&name_only));
+
+        let extra_tag = TagResourcesInput::builder()
+            .resource_arn_list(arn)
+            .tags("Name", "web-1")
+            .tags("Owner", "team")
+            .build()
+            .expect("input");
+        assert!(!is_name_tag_request(&extra_tag));
+
+        let two_resources = TagResourcesInput::builder()
+            .resource_arn_list(arn)
+            .resource_arn_list("arn:aws:ec2:ap-northeast-2:123456789012:vpc/vpc-1")
+            .tags("Name", "web-1")
+            .build()
+            .expect("input");
+        assert!(!is_name_tag_request(&two_resources));
+    }
+}
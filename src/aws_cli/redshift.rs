@@ -0,0 +1,306 @@
+pub use crate::aws_cli::redshift_sdk::{get_redshift_cluster_detail, list_redshift_clusters};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RedshiftSnapshotSchedule {
+    pub identifier: String,
+    pub description: String,
+    // rate(12 hours), cron(0 3 * * ? *)
+    pub definitions: Vec<String>,
+    // ACTIVE, MODIFYING, FAILED
+    pub state: String,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct RedshiftClusterDetail {
+    pub identifier: String,
+    pub namespace_arn: String,
+    // available, creating, paused, resizing, ...
+    pub status: String,
+    pub node_type: String,
+    pub node_count: i32,
+    pub version: String,
+    pub db_name: String,
+    pub master_username: String,
+    // 주소:포트 (생성 중에는 None)
+    pub endpoint: Option<String>,
+    pub vpc_id: Option<String>,
+    pub subnet_group: String,
+    pub availability_zone: String,
+    pub security_groups: Vec<String>,
+    pub publicly_accessible: bool,
+    pub enhanced_vpc_routing: bool,
+    // Enabled, Disabled (RA3 노드만 지원)
+    pub multi_az: String,
+    pub encrypted: bool,
+    pub kms_key_id: Option<String>,
+    pub automated_snapshot_retention: i32,
+    // -1이면 무기한 보관
+    pub manual_snapshot_retention: i32,
+    // 지정하지 않으면 기본 일정(8시간 또는 노드당 5GB 변경마다)
+    pub snapshot_schedule: Option<RedshiftSnapshotSchedule>,
+    pub next_snapshot: String,
+    // 다른 리전으로 스냅샷 복사: (대상 리전, 자동 스냅샷 보관 일수)
+    pub snapshot_copy: Option<(String, i32)>,
+    pub maintenance_window: String,
+    pub iam_roles: Vec<String>,
+    pub created: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl RedshiftClusterDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let enabled = |value: bool| {
+            if value {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        };
+        let or_dash = |values: &[String]| {
+            if values.is_empty() {
+                "-".to_string()
+            } else {
+                values.join(", ")
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.redshift_cluster(), self.identifier),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.identifier),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_node_type(), self.node_type),
+            format!("| {} | {} |", i18n.md_node_count(), self.node_count),
+            format!("| {} | {} |", i18n.md_version(), self.version),
+            format!("| {} | {} |", i18n.md_database(), self.db_name),
+            format!(
+                "| {} | {} |",
+                i18n.md_master_username(),
+                self.master_username
+            ),
+        ];
+        if let Some(endpoint) = &self.endpoint {
+            lines.push(format!("| {} | {} |", i18n.md_endpoint(), endpoint));
+        }
+        if !self.multi_az.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_multi_az(), self.multi_az));
+        }
+        if !self.maintenance_window.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_maintenance_window(),
+                self.maintenance_window
+            ));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // Network
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_vpc_config()));
+        lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+        lines.push("|:---|:---|".to_string());
+        lines.push(format!(
+            "| VPC | {} |",
+            self.vpc_id.as_deref().unwrap_or("-")
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_subnet_group(),
+            if self.subnet_group.is_empty() {
+                "-"
+            } else {
+                self.subnet_group.as_str()
+            }
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_availability_zone(),
+            self.availability_zone
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_security_groups(),
+            or_dash(&self.security_groups)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_publicly_accessible(),
+            enabled(self.publicly_accessible)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_enhanced_vpc_routing(),
+            enabled(self.enhanced_vpc_routing)
+        ));
+
+        // Encryption
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_at_rest_encryption(),
+            match (&self.kms_key_id, self.encrypted) {
+                (Some(key_id), true) => format!("KMS ({})", key_id),
+                (None, true) => i18n.md_enabled().to_string(),
+                (_, false) => i18n.md_disabled().to_string(),
+            }
+        ));
+
+        // Snapshots
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_snapshots()));
+        lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+        lines.push("|:---|:---|".to_string());
+        let schedule = match &self.snapshot_schedule {
+            Some(schedule) if schedule.definitions.is_empty() => schedule.identifier.clone(),
+            Some(schedule) => format!(
+                "{} ({})",
+                schedule.identifier,
+                schedule.definitions.join(", ")
+            ),
+            None => i18n.md_default_snapshot_schedule().to_string(),
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_snapshot_schedule(),
+            schedule
+        ));
+        if !self.next_snapshot.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_next_snapshot(),
+                self.next_snapshot
+            ));
+        }
+        // 보관 기간이 0이면 자동 스냅샷을 만들지 않음
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_automated_snapshot_retention(),
+            if self.automated_snapshot_retention > 0 {
+                i18n.md_days(self.automated_snapshot_retention)
+            } else {
+                i18n.md_disabled().to_string()
+            }
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_manual_snapshot_retention(),
+            if self.manual_snapshot_retention < 0 {
+                i18n.md_indefinitely().to_string()
+            } else {
+                i18n.md_days(self.manual_snapshot_retention)
+            }
+        ));
+        if let Some((region, retention)) = &self.snapshot_copy {
+            lines.push(format!(
+                "| {} | {} ({}) |",
+                i18n.md_cross_region_snapshot_copy(),
+                region,
+                i18n.md_days(*retention)
+            ));
+        }
+
+        // IAM Roles
+        if !self.iam_roles.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_iam_roles()));
+            for role in &self.iam_roles {
+                lines.push(format!("- {}", role));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RedshiftClusterDetail, RedshiftSnapshotSchedule};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> RedshiftClusterDetail {
+        RedshiftClusterDetail {
+            identifier: "analytics".to_string(),
+            namespace_arn: "arn:aws:redshift:ap-northeast-2:123456789012:namespace:0f1e2d3c"
+                .to_string(),
+            status: "available".to_string(),
+            node_type: "ra3.xlplus".to_string(),
+            node_count: 2,
+            version: "1.0".to_string(),
+            db_name: "dev".to_string(),
+            master_username: "awsuser".to_string(),
+            endpoint: Some(
+                "analytics.abc123.ap-northeast-2.redshift.amazonaws.com:5439".to_string(),
+            ),
+            vpc_id: Some("vpc-0123".to_string()),
+            subnet_group: "analytics-subnets".to_string(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            security_groups: vec!["sg-0123".to_string()],
+            publicly_accessible: false,
+            enhanced_vpc_routing: true,
+            multi_az: "Disabled".to_string(),
+            encrypted: true,
+            kms_key_id: Some("arn:aws:kms:ap-northeast-2:123456789012:key/abcd".to_string()),
+            automated_snapshot_retention: 7,
+            manual_snapshot_retention: -1,
+            snapshot_schedule: Some(RedshiftSnapshotSchedule {
+                identifier: "every-12h".to_string(),
+                description: String::new(),
+                definitions: vec!["rate(12 hours)".to_string()],
+                state: "ACTIVE".to_string(),
+            }),
+            next_snapshot: "2026-01-01T12:00:00Z".to_string(),
+            snapshot_copy: Some(("us-west-2".to_string(), 3)),
+            maintenance_window: "sat:17:00-sat:17:30".to_string(),
+            iam_roles: vec!["arn:aws:iam::123456789012:role/redshift-s3".to_string()],
+            created: "2026-01-01T00:00:00Z".to_string(),
+            tags: vec![("Team".to_string(), "data".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_redshift_cluster_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Redshift Cluster (analytics)"));
+        assert!(markdown.contains("| Node Type | ra3.xlplus |"));
+        assert!(markdown.contains("| Node Count | 2 |"));
+        assert!(markdown.contains("| VPC | vpc-0123 |"));
+        assert!(markdown.contains("| Enhanced VPC Routing | Enabled |"));
+        assert!(markdown.contains("| Encryption at Rest | KMS (arn:aws:kms:"));
+        assert!(markdown.contains("| Snapshot Schedule | every-12h (rate(12 hours)) |"));
+        assert!(markdown.contains("| Automated Snapshot Retention | 7d |"));
+        assert!(markdown.contains("| Manual Snapshot Retention | Indefinitely |"));
+        assert!(markdown.contains("| Cross-Region Snapshot Copy | us-west-2 (3d) |"));
+        assert!(markdown.contains("- arn:aws:iam::123456789012:role/redshift-s3"));
+        assert!(markdown.contains("| Team | data |"));
+
+        let mut detail = sample_detail();
+        detail.encrypted = false;
+        detail.kms_key_id = None;
+        detail.snapshot_schedule = None;
+        detail.automated_snapshot_retention = 0;
+        detail.snapshot_copy = None;
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 저장 데이터 암호화 | 비활성화 |"));
+        assert!(markdown.contains("| 스냅샷 일정 | 기본 (8시간 또는 노드당 5GB 변경마다) |"));
+        assert!(markdown.contains("| 자동 스냅샷 보관 기간 | 비활성화 |"));
+        assert!(!markdown.contains("us-west-2"));
+    }
+}
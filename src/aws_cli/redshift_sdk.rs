@@ -0,0 +1,320 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::redshift::{RedshiftClusterDetail, RedshiftSnapshotSchedule};
+use aws_sdk_redshift::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_redshift::types::{Cluster, SnapshotSchedule};
+
+/// List Redshift provisioned clusters using AWS SDK
+pub fn list_redshift_clusters() -> Vec<AwsResource> {
+    get_runtime().block_on(list_redshift_clusters_async())
+}
+
+async fn list_redshift_clusters_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_redshift, &config);
+
+    let mut resources: Vec<AwsResource> = match client
+        .describe_clusters()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(clusters) => clusters.iter().map(map_cluster_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing Redshift clusters: {:?}", e);
+            Vec::new()
+        }
+    };
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get Redshift cluster detail (nodes, network, encryption, snapshot schedule) using AWS SDK
+pub fn get_redshift_cluster_detail(cluster_identifier: &str) -> Option<RedshiftClusterDetail> {
+    get_runtime().block_on(get_redshift_cluster_detail_async(cluster_identifier))
+}
+
+async fn get_redshift_cluster_detail_async(
+    cluster_identifier: &str,
+) -> Option<RedshiftClusterDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_redshift, &config);
+
+    let output = match client
+        .describe_clusters()
+        .cluster_identifier(cluster_identifier)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!(
+                "Error describing Redshift cluster {}: {:?}",
+                cluster_identifier,
+                e
+            );
+            return None;
+        }
+    };
+    let cluster = output.clusters().first()?;
+    let mut detail = map_cluster_detail(cluster);
+
+    // 스냅샷 일정 조회 실패는 상세 정보 전체를 실패로 보지 않음 (클러스터의 일정 ID는 남겨 둠)
+    if let Some(schedule_identifier) = cluster.snapshot_schedule_identifier() {
+        match client
+            .describe_snapshot_schedules()
+            .schedule_identifier(schedule_identifier)
+            .send()
+            .await
+        {
+            Ok(output) => {
+                if let Some(schedule) = output.snapshot_schedules().first() {
+                    detail.snapshot_schedule = Some(map_snapshot_schedule(
+                        schedule,
+                        detail.snapshot_schedule.take(),
+                    ));
+                }
+            }
+            Err(e) => tracing::error!(
+                "Error describing Redshift snapshot schedule {}: {:?}",
+                schedule_identifier,
+                e
+            ),
+        }
+    }
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|timestamp| timestamp.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 목록에는 상태와 "노드 유형 x 노드 수"를 표시
+fn map_cluster_resource(cluster: &Cluster) -> AwsResource {
+    let identifier = cluster.cluster_identifier().unwrap_or_default().to_string();
+    AwsResource {
+        name: identifier.clone(),
+        id: identifier,
+        state: cluster.cluster_status().unwrap_or_default().to_string(),
+        az: cluster.availability_zone().unwrap_or_default().to_string(),
+        cidr: format!(
+            "{} x {}",
+            cluster.node_type().unwrap_or_default(),
+            cluster.number_of_nodes().unwrap_or_default()
+        ),
+        owner_id: String::new(),
+    }
+}
+
+fn map_cluster_detail(cluster: &Cluster) -> RedshiftClusterDetail {
+    let endpoint = cluster.endpoint().and_then(|endpoint| {
+        let address = endpoint.address()?;
+        Some(match endpoint.port() {
+            Some(port) => format!("{}:{}", address, port),
+            None => address.to_string(),
+        })
+    });
+
+    let mut tags: Vec<(String, String)> = cluster
+        .tags()
+        .iter()
+        .map(|tag| {
+            (
+                tag.key().unwrap_or_default().to_string(),
+                tag.value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    tags.sort();
+
+    RedshiftClusterDetail {
+        identifier: cluster.cluster_identifier().unwrap_or_default().to_string(),
+        namespace_arn: cluster
+            .cluster_namespace_arn()
+            .unwrap_or_default()
+            .to_string(),
+        status: cluster.cluster_status().unwrap_or_default().to_string(),
+        node_type: cluster.node_type().unwrap_or_default().to_string(),
+        node_count: cluster.number_of_nodes().unwrap_or_default(),
+        version: cluster.cluster_version().unwrap_or_default().to_string(),
+        db_name: cluster.db_name().unwrap_or_default().to_string(),
+        master_username: cluster.master_username().unwrap_or_default().to_string(),
+        endpoint,
+        vpc_id: cluster.vpc_id().map(str::to_string),
+        subnet_group: cluster
+            .cluster_subnet_group_name()
+            .unwrap_or_default()
+            .to_string(),
+        availability_zone: cluster.availability_zone().unwrap_or_default().to_string(),
+        security_groups: cluster
+            .vpc_security_groups()
+            .iter()
+            .filter_map(|group| group.vpc_security_group_id())
+            .map(str::to_string)
+            .collect(),
+        publicly_accessible: cluster.publicly_accessible().unwrap_or(false),
+        enhanced_vpc_routing: cluster.enhanced_vpc_routing().unwrap_or(false),
+        multi_az: cluster.multi_az().unwrap_or_default().to_string(),
+        encrypted: cluster.encrypted().unwrap_or(false),
+        kms_key_id: cluster.kms_key_id().map(str::to_string),
+        automated_snapshot_retention: cluster
+            .automated_snapshot_retention_period()
+            .unwrap_or_default(),
+        // 지정하지 않으면 무기한 보관(-1)
+        manual_snapshot_retention: cluster.manual_snapshot_retention_period().unwrap_or(-1),
+        // 일정 정의는 DescribeSnapshotSchedules로 채움
+        snapshot_schedule: cluster.snapshot_schedule_identifier().map(|identifier| {
+            RedshiftSnapshotSchedule {
+                identifier: identifier.to_string(),
+                description: String::new(),
+                definitions: Vec::new(),
+                state: cluster
+                    .snapshot_schedule_state()
+                    .map(|state| state.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            }
+        }),
+        next_snapshot: format_timestamp(cluster.expected_next_snapshot_schedule_time()),
+        snapshot_copy: cluster.cluster_snapshot_copy_status().map(|copy| {
+            (
+                copy.destination_region().unwrap_or_default().to_string(),
+                copy.retention_period().unwrap_or_default() as i32,
+            )
+        }),
+        maintenance_window: cluster
+            .preferred_maintenance_window()
+            .unwrap_or_default()
+            .to_string(),
+        iam_roles: cluster
+            .iam_roles()
+            .iter()
+            .filter_map(|role| role.iam_role_arn())
+            .map(str::to_string)
+            .collect(),
+        created: format_timestamp(cluster.cluster_create_time()),
+        tags,
+    }
+}
+
+fn map_snapshot_schedule(
+    schedule: &SnapshotSchedule,
+    current: Option<RedshiftSnapshotSchedule>,
+) -> RedshiftSnapshotSchedule {
+    let state = current.map(|current| current.state).unwrap_or_default();
+    RedshiftSnapshotSchedule {
+        identifier: schedule
+            .schedule_identifier()
+            .unwrap_or_default()
+            .to_string(),
+        description: schedule
+            .schedule_description()
+            .unwrap_or_default()
+            .to_string(),
+        definitions: schedule.schedule_definitions().to_vec(),
+        state,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_cluster_detail, map_cluster_resource, map_snapshot_schedule};
+    use aws_sdk_redshift::primitives::DateTime;
+    use aws_sdk_redshift::types::{
+        Cluster, ClusterIamRole, ClusterSnapshotCopyStatus, Endpoint, ScheduleState,
+        SnapshotSchedule, Tag, VpcSecurityGroupMembership,
+    };
+
+    fn cluster() -> Cluster {
+        Cluster::builder()
+            .cluster_identifier("analytics")
+            .cluster_status("available")
+            .node_type("ra3.xlplus")
+            .number_of_nodes(2)
+            .cluster_version("1.0")
+            .db_name("dev")
+            .master_username("awsuser")
+            .endpoint(
+                Endpoint::builder()
+                    .address("analytics.abc123.ap-northeast-2.redshift.amazonaws.com")
+                    .port(5439)
+                    .build(),
+            )
+            .vpc_id("vpc-0123")
+            .cluster_subnet_group_name("analytics-subnets")
+            .availability_zone("ap-northeast-2a")
+            .vpc_security_groups(
+                VpcSecurityGroupMembership::builder()
+                    .vpc_security_group_id("sg-0123")
+                    .status("active")
+                    .build(),
+            )
+            .encrypted(true)
+            .kms_key_id("arn:aws:kms:ap-northeast-2:123456789012:key/abcd")
+            .automated_snapshot_retention_period(7)
+            .snapshot_schedule_identifier("every-12h")
+            .snapshot_schedule_state(ScheduleState::Active)
+            .expected_next_snapshot_schedule_time(DateTime::from_secs(1_767_268_800))
+            .cluster_snapshot_copy_status(
+                ClusterSnapshotCopyStatus::builder()
+                    .destination_region("us-west-2")
+                    .retention_period(3)
+                    .build(),
+            )
+            .iam_roles(
+                ClusterIamRole::builder()
+                    .iam_role_arn("arn:aws:iam::123456789012:role/redshift-s3")
+                    .build(),
+            )
+            .cluster_create_time(DateTime::from_secs(1_767_225_600))
+            .tags(Tag::builder().key("Team").value("data").build())
+            .build()
+    }
+
+    #[test]
+    fn map_cluster_resource_uses_identifier_and_node_summary() {
+        let resource = map_cluster_resource(&cluster());
+        assert_eq!(resource.name, "analytics");
+        assert_eq!(resource.id, "analytics");
+        assert_eq!(resource.state, "available");
+        assert_eq!(resource.cidr, "ra3.xlplus x 2");
+    }
+
+    #[test]
+    fn map_cluster_detail_reads_network_encryption_and_snapshots() {
+        let detail = map_cluster_detail(&cluster());
+        assert_eq!(
+            detail.endpoint.as_deref(),
+            Some("analytics.abc123.ap-northeast-2.redshift.amazonaws.com:5439")
+        );
+        assert_eq!(detail.security_groups, vec!["sg-0123"]);
+        assert!(detail.encrypted);
+        assert_eq!(detail.automated_snapshot_retention, 7);
+        // 수동 스냅샷 보관 기간이 없으면 무기한
+        assert_eq!(detail.manual_snapshot_retention, -1);
+        assert_eq!(detail.next_snapshot, "2026-01-01T12:00:00Z");
+        assert_eq!(detail.snapshot_copy, Some(("us-west-2".to_string(), 3)));
+        assert_eq!(
+            detail.iam_roles,
+            vec!["arn:aws:iam::123456789012:role/redshift-s3"]
+        );
+        assert_eq!(detail.tags, vec![("Team".to_string(), "data".to_string())]);
+
+        let schedule = detail.snapshot_schedule.expect("schedule id");
+        assert_eq!(schedule.identifier, "every-12h");
+        assert!(schedule.definitions.is_empty());
+
+        let schedule = map_snapshot_schedule(
+            &SnapshotSchedule::builder()
+                .schedule_identifier("every-12h")
+                .schedule_definitions("rate(12 hours)")
+                .build(),
+            Some(schedule),
+        );
+        assert_eq!(schedule.definitions, vec!["rate(12 hours)"]);
+        assert_eq!(schedule.state, "ACTIVE");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::aws_cli::common::{get_runtime, get_sdk_config, sdk_client};
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::primitives::ByteStream;
+
+// 공유 블루프린트 저장소용 단일 문서 읽기/쓰기 (S3 객체 또는 DynamoDB 항목)
+
+fn error_message<E: ProvideErrorMetadata + std::fmt::Display>(e: &E) -> String {
+    e.message()
+        .map(|message| message.to_string())
+        .unwrap_or_else(|| e.to_string())
+}
+
+/// Read an S3 object as UTF-8 text. Returns `Ok(None)` when the key does not exist yet.
+pub fn get_s3_object(bucket: &str, key: &str) -> Result<Option<String>, String> {
+    get_runtime().block_on(async {
+        let config = get_sdk_config().await;
+        let client = sdk_client!(aws_sdk_s3, &config);
+
+        let output = match client.get_object().bucket(bucket).key(key).send().await {
+            Ok(output) => output,
+            Err(e) if e.as_service_error().is_some_and(|err| err.is_no_such_key()) => {
+                return Ok(None);
+            }
+            Err(e) => {
+                tracing::error!("Error reading s3://{}/{}: {:?}", bucket, key, e);
+                return Err(error_message(&e));
+            }
+        };
+
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| e.to_string())?
+            .into_bytes();
+        String::from_utf8(bytes.to_vec())
+            .map(Some)
+            .map_err(|e| e.to_string())
+    })
+}
+
+pub fn put_s3_object(bucket: &str, key: &str, body: &str) -> Result<(), String> {
+    get_runtime().block_on(async {
+        let config = get_sdk_config().await;
+        let client = sdk_client!(aws_sdk_s3, &config);
+
+        client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .content_type("application/json")
+            .body(ByteStream::from(body.as_bytes().to_vec()))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                tracing::error!("Error writing s3://{}/{}: {:?}", bucket, key, e);
+                error_message(&e)
+            })
+    })
+}
+
+/// Read the string attribute `attribute` of the item whose `id` partition key is `id`.
+pub fn get_dynamodb_document(
+    table: &str,
+    id: &str,
+    attribute: &str,
+) -> Result<Option<String>, String> {
+    get_runtime().block_on(async {
+        let config = get_sdk_config().await;
+        let client = sdk_client!(aws_sdk_dynamodb, &config);
+
+        let output = client
+            .get_item()
+            .table_name(table)
+            .key("id", AttributeValue::S(id.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|e| {
+                tracing::error!("Error reading DynamoDB item {}/{}: {:?}", table, id, e);
+                error_message(&e)
+            })?;
+
+        Ok(output
+            .item()
+            .and_then(|item| item.get(attribute))
+            .and_then(|value| value.as_s().ok())
+            .cloned())
+    })
+}
+
+pub fn put_dynamodb_document(
+    table: &str,
+    id: &str,
+    attribute: &str,
+    body: &str,
+) -> Result<(), String> {
+    get_runtime().block_on(async {
+        let config = get_sdk_config().await;
+        let client = sdk_client!(aws_sdk_dynamodb, &config);
+
+        client
+            .put_item()
+            .table_name(table)
+            .item("id", AttributeValue::S(id.to_string()))
+            .item(attribute, AttributeValue::S(body.to_string()))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                tracing::error!("Error writing DynamoDB item {}/{}: {:?}", table, id, e);
+                error_message(&e)
+            })
+    })
+}
@@ -0,0 +1,182 @@
+pub use crate::aws_cli::route53_sdk::{get_route53_detail, list_route53_zones};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Route53Record {
+    pub name: String,
+    pub record_type: String,
+    // 별칭 레코드는 TTL이 없음
+    pub ttl: Option<i64>,
+    pub values: Vec<String>,
+    // 별칭 레코드의 대상 DNS 이름
+    pub alias_target: Option<String>,
+    // 가중치/지연 시간 등 라우팅 정책 레코드 식별자
+    pub set_identifier: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct Route53Detail {
+    pub id: String,
+    pub name: String,
+    pub private_zone: bool,
+    pub comment: String,
+    pub record_count: i64,
+    // 프라이빗 호스팅 영역에 연결된 VPC ("vpc-id (region)")
+    pub vpcs: Vec<String>,
+    pub records: Vec<Route53Record>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl Route53Detail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let zone_type = if self.private_zone {
+            i18n.md_private()
+        } else {
+            i18n.md_public()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.route53_hosted_zone(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_type(), zone_type),
+        ];
+        if !self.comment.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_description(), self.comment));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_record_count(),
+            self.record_count
+        ));
+        if !self.vpcs.is_empty() {
+            lines.push(format!("| VPC | {} |", self.vpcs.join(", ")));
+        }
+
+        // Record Sets
+        if !self.records.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_record_sets()));
+            lines.push(format!(
+                "| {} | {} | TTL | {} |",
+                i18n.md_name(),
+                i18n.md_type(),
+                i18n.md_record_targets()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for record in &self.records {
+                let name = match record.set_identifier {
+                    Some(ref identifier) => format!("{} [{}]", record.name, identifier),
+                    None => record.name.clone(),
+                };
+                let ttl = record
+                    .ttl
+                    .map(|ttl| ttl.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                let targets = match record.alias_target {
+                    Some(ref target) => format!("{} → {}", i18n.md_alias(), target),
+                    // 여러 값은 표가 깨지지 않도록 <br>로 구분
+                    None => record.values.join("<br>"),
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    name, record.record_type, ttl, targets
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Route53Detail, Route53Record};
+    use crate::i18n::Language;
+
+    fn record(name: &str, record_type: &str, ttl: Option<i64>, values: &[&str]) -> Route53Record {
+        Route53Record {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            ttl,
+            values: values.iter().map(|v| v.to_string()).collect(),
+            alias_target: None,
+            set_identifier: None,
+        }
+    }
+
+    fn sample_detail() -> Route53Detail {
+        let mut alias = record("www.example.com", "A", None, &[]);
+        alias.alias_target =
+            Some("dualstack.checkout-123.ap-northeast-2.elb.amazonaws.com".to_string());
+        let mut weighted = record("api.example.com", "CNAME", Some(60), &["blue.example.com"]);
+        weighted.set_identifier = Some("blue".to_string());
+
+        Route53Detail {
+            id: "Z0123456789ABC".to_string(),
+            name: "example.com".to_string(),
+            private_zone: false,
+            comment: "checkout public zone".to_string(),
+            record_count: 5,
+            vpcs: vec![],
+            records: vec![
+                record(
+                    "example.com",
+                    "NS",
+                    Some(172800),
+                    &["ns-1.awsdns-01.org.", "ns-2.awsdns-02.com."],
+                ),
+                alias,
+                weighted,
+            ],
+            tags: vec![("Env".to_string(), "prod".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_route53_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Route 53 Hosted Zone (example.com)"));
+        assert!(markdown.contains("| Type | Public |"));
+        assert!(markdown.contains("| Record Count | 5 |"));
+        assert!(markdown.contains(
+            "| example.com | NS | 172800 | ns-1.awsdns-01.org.<br>ns-2.awsdns-02.com. |"
+        ));
+        assert!(markdown.contains(
+            "| www.example.com | A | - | Alias → dualstack.checkout-123.ap-northeast-2.elb.amazonaws.com |"
+        ));
+        assert!(markdown.contains("| api.example.com [blue] | CNAME | 60 | blue.example.com |"));
+        assert!(markdown.contains("| Env | prod |"));
+        assert!(!markdown.contains("| VPC |"));
+    }
+
+    #[test]
+    fn scenario_route53_private_zone_lists_vpcs() {
+        let mut detail = sample_detail();
+        detail.private_zone = true;
+        detail.vpcs = vec!["vpc-0abc (ap-northeast-2)".to_string()];
+        detail.records.clear();
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 타입 | 프라이빗 |"));
+        assert!(markdown.contains("| VPC | vpc-0abc (ap-northeast-2) |"));
+        assert!(!markdown.contains("### 레코드 세트"));
+    }
+}
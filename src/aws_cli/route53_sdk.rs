@@ -0,0 +1,273 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::route53::{Route53Detail, Route53Record};
+use aws_sdk_route53::types::{HostedZone, ResourceRecordSet, TagResourceType, Vpc};
+
+/// List all Route 53 hosted zones using AWS SDK
+pub fn list_route53_zones() -> Vec<AwsResource> {
+    get_runtime().block_on(list_route53_zones_async())
+}
+
+async fn list_route53_zones_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_route53, &config);
+
+    let result = client
+        .list_hosted_zones()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(zones) => zones.iter().map(map_zone_resource).collect(),
+        Err(e) => {
+            tracing::error!("Error listing Route 53 hosted zones: {:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Get Route 53 hosted zone detail (record sets, VPCs and tags) using AWS SDK
+pub fn get_route53_detail(id: &str) -> Option<Route53Detail> {
+    get_runtime().block_on(get_route53_detail_async(id))
+}
+
+async fn get_route53_detail_async(id: &str) -> Option<Route53Detail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_route53, &config);
+
+    let output = client.get_hosted_zone().id(id).send().await.ok()?;
+    let mut detail = map_zone_detail(output.hosted_zone()?, output.vpcs());
+
+    // ListResourceRecordSets는 다음 레코드 이름/타입/식별자로 이어서 조회
+    let mut start_name: Option<String> = None;
+    let mut start_type = None;
+    let mut start_identifier: Option<String> = None;
+    loop {
+        let page = match client
+            .list_resource_record_sets()
+            .hosted_zone_id(id)
+            .set_start_record_name(start_name.take())
+            .set_start_record_type(start_type.take())
+            .set_start_record_identifier(start_identifier.take())
+            .send()
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                tracing::warn!("Error listing record sets for {}: {:?}", id, e);
+                break;
+            }
+        };
+        detail
+            .records
+            .extend(page.resource_record_sets().iter().map(map_record));
+        if !page.is_truncated() {
+            break;
+        }
+        start_name = page.next_record_name().map(|s| s.to_string());
+        start_type = page.next_record_type().cloned();
+        start_identifier = page.next_record_identifier().map(|s| s.to_string());
+        if start_name.is_none() {
+            break;
+        }
+    }
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_tags_for_resource()
+        .resource_type(TagResourceType::Hostedzone)
+        .resource_id(id)
+        .send()
+        .await
+    {
+        Ok(tags) => {
+            let mut tags: Vec<(String, String)> = tags
+                .resource_tag_set()
+                .map(|set| set.tags())
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|t| {
+                    Some((
+                        t.key()?.to_string(),
+                        t.value().unwrap_or_default().to_string(),
+                    ))
+                })
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error listing tags for {}: {:?}", id, e),
+    }
+
+    Some(detail)
+}
+
+// "/hostedzone/Z0123" -> "Z0123"
+fn zone_id(id: &str) -> String {
+    id.trim_start_matches("/hostedzone/").to_string()
+}
+
+// Route 53은 끝에 점이 붙은 FQDN과 8진수 이스케이프(\052 = *)를 반환
+fn display_name(name: &str) -> String {
+    let name = name.replace("\\052", "*");
+    match name.strip_suffix('.') {
+        Some(trimmed) if !trimmed.is_empty() => trimmed.to_string(),
+        _ => name,
+    }
+}
+
+fn map_zone_resource(zone: &HostedZone) -> AwsResource {
+    let private_zone = zone.config().map(|c| c.private_zone()).unwrap_or(false);
+    AwsResource {
+        name: display_name(zone.name()),
+        id: zone_id(zone.id()),
+        state: if private_zone { "private" } else { "public" }.to_string(),
+        az: String::new(),
+        cidr: zone
+            .resource_record_set_count()
+            .map(|count| count.to_string())
+            .unwrap_or_default(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_zone_detail(zone: &HostedZone, vpcs: &[Vpc]) -> Route53Detail {
+    let config = zone.config();
+    Route53Detail {
+        id: zone_id(zone.id()),
+        name: display_name(zone.name()),
+        private_zone: config.map(|c| c.private_zone()).unwrap_or(false),
+        comment: config
+            .and_then(|c| c.comment())
+            .unwrap_or_default()
+            .to_string(),
+        record_count: zone.resource_record_set_count().unwrap_or(0),
+        vpcs: vpcs
+            .iter()
+            .filter_map(|vpc| {
+                let vpc_id = vpc.vpc_id()?;
+                Some(match vpc.vpc_region() {
+                    Some(region) => format!("{} ({})", vpc_id, region.as_str()),
+                    None => vpc_id.to_string(),
+                })
+            })
+            .collect(),
+        records: Vec::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn map_record(record: &ResourceRecordSet) -> Route53Record {
+    Route53Record {
+        name: display_name(record.name()),
+        record_type: record.r#type().as_str().to_string(),
+        ttl: record.ttl(),
+        values: record
+            .resource_records()
+            .iter()
+            .map(|r| r.value().to_string())
+            .collect(),
+        alias_target: record
+            .alias_target()
+            .map(|alias| display_name(alias.dns_name())),
+        set_identifier: record.set_identifier().map(|s| s.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{display_name, map_record, map_zone_detail, map_zone_resource};
+    use aws_sdk_route53::types::{
+        AliasTarget, HostedZone, HostedZoneConfig, ResourceRecord, ResourceRecordSet, RrType, Vpc,
+        VpcRegion,
+    };
+
+    fn zone(private_zone: bool) -> HostedZone {
+        HostedZone::builder()
+            .id("/hostedzone/Z0123456789ABC")
+            .name("example.com.")
+            .caller_reference("ref")
+            .resource_record_set_count(4)
+            .config(
+                HostedZoneConfig::builder()
+                    .comment("checkout zone")
+                    .private_zone(private_zone)
+                    .build(),
+            )
+            .build()
+            .expect("hosted zone")
+    }
+
+    #[test]
+    fn display_name_trims_root_dot_and_unescapes_wildcard() {
+        assert_eq!(display_name("example.com."), "example.com");
+        assert_eq!(display_name("\\052.example.com."), "*.example.com");
+        assert_eq!(display_name("."), ".");
+    }
+
+    #[test]
+    fn map_zone_resource_strips_hosted_zone_prefix() {
+        let resource = map_zone_resource(&zone(true));
+        assert_eq!(resource.id, "Z0123456789ABC");
+        assert_eq!(resource.name, "example.com");
+        assert_eq!(resource.state, "private");
+        assert_eq!(resource.cidr, "4");
+    }
+
+    #[test]
+    fn map_zone_detail_reads_config_and_vpcs() {
+        let vpc = Vpc::builder()
+            .vpc_id("vpc-0abc")
+            .vpc_region(VpcRegion::ApNortheast2)
+            .build();
+        let detail = map_zone_detail(&zone(true), &[vpc]);
+        assert!(detail.private_zone);
+        assert_eq!(detail.comment, "checkout zone");
+        assert_eq!(detail.record_count, 4);
+        assert_eq!(detail.vpcs, vec!["vpc-0abc (ap-northeast-2)"]);
+    }
+
+    #[test]
+    fn map_record_reads_values_and_alias_targets() {
+        let plain = ResourceRecordSet::builder()
+            .name("api.example.com.")
+            .r#type(RrType::Cname)
+            .ttl(60)
+            .set_identifier("blue")
+            .resource_records(
+                ResourceRecord::builder()
+                    .value("blue.example.com")
+                    .build()
+                    .expect("resource record"),
+            )
+            .build()
+            .expect("record set");
+        let record = map_record(&plain);
+        assert_eq!(record.record_type, "CNAME");
+        assert_eq!(record.ttl, Some(60));
+        assert_eq!(record.values, vec!["blue.example.com"]);
+        assert_eq!(record.set_identifier.as_deref(), Some("blue"));
+
+        let alias = ResourceRecordSet::builder()
+            .name("www.example.com.")
+            .r#type(RrType::A)
+            .alias_target(
+                AliasTarget::builder()
+                    .hosted_zone_id("ZWKZPGTI48KDX")
+                    .dns_name("dualstack.checkout-123.ap-northeast-2.elb.amazonaws.com.")
+                    .evaluate_target_health(true)
+                    .build()
+                    .expect("alias target"),
+            )
+            .build()
+            .expect("record set");
+        let record = map_record(&alias);
+        assert!(record.ttl.is_none());
+        assert_eq!(
+            record.alias_target.as_deref(),
+            Some("dualstack.checkout-123.ap-northeast-2.elb.amazonaws.com")
+        );
+    }
+}
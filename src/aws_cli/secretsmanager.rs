@@ -0,0 +1,247 @@
+pub use crate::aws_cli::secretsmanager_sdk::{get_secret_detail, list_secrets};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SecretReplica {
+    pub region: String,
+    // InSync, Failed, InProgress
+    pub status: String,
+    pub last_accessed: String,
+}
+
+// 비밀 값은 조회하지 않고 메타데이터만 담음
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct SecretDetail {
+    pub name: String,
+    pub arn: String,
+    pub description: String,
+    // None이면 AWS 관리형 키(aws/secretsmanager)
+    pub kms_key_id: Option<String>,
+    pub rotation_enabled: bool,
+    // "rate(30 days)" 또는 cron 식
+    pub rotation_schedule: Option<String>,
+    pub rotation_window: Option<String>,
+    pub rotation_lambda: Option<String>,
+    pub last_rotated: String,
+    pub next_rotation: String,
+    pub last_changed: String,
+    pub last_accessed: String,
+    pub created: String,
+    pub deletion_date: Option<String>,
+    // RDS 등 다른 서비스가 관리하는 비밀
+    pub owning_service: Option<String>,
+    pub primary_region: Option<String>,
+    pub replicas: Vec<SecretReplica>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SecretDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.secret(), self.name),
+            format!("> {}\n", i18n.md_secret_value_note()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        let encryption = self
+            .kms_key_id
+            .clone()
+            .unwrap_or_else(|| "aws/secretsmanager".to_string());
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+        if let Some(ref owning_service) = self.owning_service {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_owning_service(),
+                owning_service
+            ));
+        }
+        if let Some(ref primary_region) = self.primary_region {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_primary_region(),
+                primary_region
+            ));
+        }
+
+        let rotation = if self.rotation_enabled {
+            i18n.md_enabled()
+        } else {
+            i18n.md_disabled()
+        };
+        lines.push(format!("| {} | {} |", i18n.md_rotation(), rotation));
+        if self.rotation_enabled {
+            if let Some(ref schedule) = self.rotation_schedule {
+                lines.push(format!(
+                    "| {} | {} |",
+                    i18n.md_rotation_schedule(),
+                    schedule
+                ));
+            }
+            if let Some(ref window) = self.rotation_window {
+                lines.push(format!("| {} | {} |", i18n.md_rotation_window(), window));
+            }
+            if let Some(ref lambda) = self.rotation_lambda {
+                lines.push(format!("| {} | {} |", i18n.md_rotation_function(), lambda));
+            }
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_next_rotation(),
+                or_dash(&self.next_rotation)
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_last_rotated(),
+            or_dash(&self.last_rotated)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_last_changed(),
+            or_dash(&self.last_changed)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_last_accessed(),
+            or_dash(&self.last_accessed)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_created_at(),
+            or_dash(&self.created)
+        ));
+        if let Some(ref deletion_date) = self.deletion_date {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_deletion_date(),
+                deletion_date
+            ));
+        }
+
+        // Replicas
+        if !self.replicas.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_replicas()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.region(),
+                i18n.md_state(),
+                i18n.md_last_accessed()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for replica in &self.replicas {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    replica.region,
+                    replica.status,
+                    or_dash(&replica.last_accessed)
+                ));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretDetail, SecretReplica};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> SecretDetail {
+        SecretDetail {
+            name: "prod/checkout/db".to_string(),
+            arn:
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf"
+                    .to_string(),
+            description: "Checkout database credentials".to_string(),
+            kms_key_id: None,
+            rotation_enabled: true,
+            rotation_schedule: Some("rate(30 days)".to_string()),
+            rotation_window: Some("2h".to_string()),
+            rotation_lambda: Some(
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:rotate-db".to_string(),
+            ),
+            last_rotated: "2026-01-01T00:00:00Z".to_string(),
+            next_rotation: "2026-01-31T00:00:00Z".to_string(),
+            last_changed: "2026-01-01T00:00:00Z".to_string(),
+            last_accessed: "2026-01-15T00:00:00Z".to_string(),
+            created: "2025-06-01T00:00:00Z".to_string(),
+            deletion_date: None,
+            owning_service: None,
+            primary_region: None,
+            replicas: vec![SecretReplica {
+                region: "us-east-1".to_string(),
+                status: "InSync".to_string(),
+                last_accessed: String::new(),
+            }],
+            tags: vec![("Team".to_string(), "checkout".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_secret_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Secrets Manager Secret (prod/checkout/db)"));
+        assert!(markdown.contains("> Secret values are not retrieved"));
+        assert!(markdown.contains("| Encryption | aws/secretsmanager |"));
+        assert!(markdown.contains("| Rotation | Enabled |"));
+        assert!(markdown.contains("| Rotation Schedule | rate(30 days) |"));
+        assert!(markdown.contains("| Next Rotation | 2026-01-31T00:00:00Z |"));
+        assert!(markdown.contains("| Last Accessed | 2026-01-15T00:00:00Z |"));
+        assert!(markdown.contains("### Replicas"));
+        assert!(markdown.contains("| us-east-1 | InSync | - |"));
+        assert!(markdown.contains("| Team | checkout |"));
+    }
+
+    #[test]
+    fn scenario_secret_markdown_without_rotation() {
+        let mut detail = sample_detail();
+        detail.rotation_enabled = false;
+        detail.last_rotated = String::new();
+        detail.last_accessed = String::new();
+        detail.kms_key_id = Some("alias/checkout".to_string());
+        detail.owning_service = Some("rds".to_string());
+        detail.replicas = vec![];
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 교체 | 비활성화 |"));
+        assert!(!markdown.contains("rate(30 days)"));
+        assert!(markdown.contains("| 마지막 교체 | - |"));
+        assert!(markdown.contains("| 마지막 액세스 | - |"));
+        assert!(markdown.contains("| 암호화 | alias/checkout |"));
+        assert!(markdown.contains("| 관리 서비스 | rds |"));
+        assert!(!markdown.contains("### 복제본"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::secretsmanager::{SecretDetail, SecretReplica};
+use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+use aws_sdk_secretsmanager::operation::list_secrets::ListSecretsOutput;
+use aws_sdk_secretsmanager::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_secretsmanager::types::{RotationRulesType, SecretListEntry};
+
+/// List Secrets Manager secrets (metadata only, never values) using AWS SDK
+pub fn list_secrets() -> Vec<AwsResource> {
+    get_runtime().block_on(list_secrets_async())
+}
+
+async fn list_secrets_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_secretsmanager, &config);
+
+    let pages: Vec<ListSecretsOutput> = match client
+        .list_secrets()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => pages,
+        Err(e) => {
+            tracing::error!("Error listing Secrets Manager secrets: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = pages
+        .iter()
+        .flat_map(|page| page.secret_list())
+        .map(map_secret_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get secret metadata (rotation, access dates, replicas and tags) using AWS SDK
+pub fn get_secret_detail(arn: &str) -> Option<SecretDetail> {
+    get_runtime().block_on(get_secret_detail_async(arn))
+}
+
+async fn get_secret_detail_async(arn: &str) -> Option<SecretDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_secretsmanager, &config);
+
+    // DescribeSecret은 비밀 값 없이 메타데이터와 태그를 함께 반환
+    let output = client.describe_secret().secret_id(arn).send().await.ok()?;
+    Some(map_secret_detail(&output))
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+// 교체 주기: cron/rate 식이 있으면 그대로, 일 단위 설정은 rate 식으로 표시
+fn rotation_schedule(rules: Option<&RotationRulesType>) -> Option<String> {
+    let rules = rules?;
+    rules.schedule_expression().map(str::to_string).or_else(|| {
+        rules
+            .automatically_after_days()
+            .map(|days| format!("rate({} days)", days))
+    })
+}
+
+// 목록에는 마지막 액세스 날짜(일 단위)와 교체 주기를 표시
+fn map_secret_resource(secret: &SecretListEntry) -> AwsResource {
+    let last_accessed = format_timestamp(secret.last_accessed_date());
+    let rotation = if secret.rotation_enabled().unwrap_or(false) {
+        rotation_schedule(secret.rotation_rules()).unwrap_or_default()
+    } else {
+        String::new()
+    };
+    AwsResource {
+        name: secret.name().unwrap_or_default().to_string(),
+        id: secret.arn().unwrap_or_default().to_string(),
+        state: last_accessed
+            .split('T')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        az: String::new(),
+        cidr: rotation,
+        owner_id: String::new(),
+    }
+}
+
+fn map_secret_detail(secret: &DescribeSecretOutput) -> SecretDetail {
+    let non_empty = |value: Option<&str>| value.filter(|v| !v.is_empty()).map(str::to_string);
+    let mut tags: Vec<(String, String)> = secret
+        .tags()
+        .iter()
+        .map(|tag| {
+            (
+                tag.key().unwrap_or_default().to_string(),
+                tag.value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    tags.sort();
+
+    SecretDetail {
+        name: secret.name().unwrap_or_default().to_string(),
+        arn: secret.arn().unwrap_or_default().to_string(),
+        description: secret.description().unwrap_or_default().to_string(),
+        kms_key_id: non_empty(secret.kms_key_id()),
+        rotation_enabled: secret.rotation_enabled().unwrap_or(false),
+        rotation_schedule: rotation_schedule(secret.rotation_rules()),
+        rotation_window: non_empty(secret.rotation_rules().and_then(|rules| rules.duration())),
+        rotation_lambda: non_empty(secret.rotation_lambda_arn()),
+        last_rotated: format_timestamp(secret.last_rotated_date()),
+        next_rotation: format_timestamp(secret.next_rotation_date()),
+        last_changed: format_timestamp(secret.last_changed_date()),
+        last_accessed: format_timestamp(secret.last_accessed_date()),
+        created: format_timestamp(secret.created_date()),
+        deletion_date: secret
+            .deleted_date()
+            .map(|date| format_timestamp(Some(date))),
+        owning_service: non_empty(secret.owning_service()),
+        primary_region: non_empty(secret.primary_region()),
+        replicas: secret
+            .replication_status()
+            .iter()
+            .map(|replica| SecretReplica {
+                region: replica.region().unwrap_or_default().to_string(),
+                status: replica
+                    .status()
+                    .map(|status| status.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                last_accessed: format_timestamp(replica.last_accessed_date()),
+            })
+            .collect(),
+        tags,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_secret_detail, map_secret_resource};
+    use aws_sdk_secretsmanager::operation::describe_secret::DescribeSecretOutput;
+    use aws_sdk_secretsmanager::primitives::DateTime;
+    use aws_sdk_secretsmanager::types::{
+        ReplicationStatusType, RotationRulesType, SecretListEntry, StatusType, Tag,
+    };
+
+    #[test]
+    fn map_secret_resource_shows_last_accessed_day_and_rotation() {
+        let secret = SecretListEntry::builder()
+            .name("prod/checkout/db")
+            .arn(
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf",
+            )
+            .rotation_enabled(true)
+            .rotation_rules(
+                RotationRulesType::builder()
+                    .automatically_after_days(30)
+                    .build(),
+            )
+            .last_accessed_date(DateTime::from_secs(1_768_435_200))
+            .build();
+        let resource = map_secret_resource(&secret);
+        assert_eq!(resource.name, "prod/checkout/db");
+        assert!(resource.id.ends_with(":secret:prod/checkout/db-AbCdEf"));
+        assert_eq!(resource.state, "2026-01-15");
+        assert_eq!(resource.cidr, "rate(30 days)");
+
+        let never_accessed = map_secret_resource(
+            &SecretListEntry::builder()
+                .name("dev/api-key")
+                .rotation_enabled(false)
+                .build(),
+        );
+        assert!(never_accessed.state.is_empty());
+        assert!(never_accessed.cidr.is_empty());
+    }
+
+    #[test]
+    fn map_secret_detail_reads_rotation_replicas_and_tags() {
+        let output = DescribeSecretOutput::builder()
+            .name("prod/checkout/db")
+            .kms_key_id("")
+            .rotation_enabled(true)
+            .rotation_lambda_arn("arn:aws:lambda:ap-northeast-2:123456789012:function:rotate-db")
+            .rotation_rules(
+                RotationRulesType::builder()
+                    .schedule_expression("cron(0 16 1,15 * ? *)")
+                    .duration("2h")
+                    .build(),
+            )
+            .next_rotation_date(DateTime::from_secs(1_767_225_600))
+            .owning_service("rds")
+            .replication_status(
+                ReplicationStatusType::builder()
+                    .region("us-east-1")
+                    .status(StatusType::InSync)
+                    .build(),
+            )
+            .tags(Tag::builder().key("Team").value("checkout").build())
+            .build();
+
+        let detail = map_secret_detail(&output);
+        assert_eq!(detail.kms_key_id, None);
+        assert_eq!(
+            detail.rotation_schedule.as_deref(),
+            Some("cron(0 16 1,15 * ? *)")
+        );
+        assert_eq!(detail.rotation_window.as_deref(), Some("2h"));
+        assert_eq!(detail.next_rotation, "2026-01-01T00:00:00Z");
+        assert!(detail.last_accessed.is_empty());
+        assert_eq!(detail.owning_service.as_deref(), Some("rds"));
+        assert_eq!(detail.replicas.len(), 1);
+        assert_eq!(detail.replicas[0].status, "InSync");
+        assert_eq!(
+            detail.tags,
+            vec![("Team".to_string(), "checkout".to_string())]
+        );
+    }
+}
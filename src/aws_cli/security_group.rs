@@ -1,6 +1,16 @@
 use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::aws_cli::vpc::cidr_label;
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static NORMALIZE_RULES: AtomicBool = AtomicBool::new(false);
+
+/// Merge and sort security group rules in every document rendered from now on.
+pub fn set_normalize_security_group_rules(enabled: bool) {
+    NORMALIZE_RULES.store(enabled, Ordering::Relaxed);
+}
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -63,7 +73,7 @@ struct UserIdGroupPair {
     description: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SecurityGroupDetail {
     pub name: String,
     pub id: String,
@@ -73,7 +83,7 @@ pub struct SecurityGroupDetail {
     pub outbound_rules: Vec<SecurityRule>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SecurityRule {
     pub protocol: String,
     pub port_range: String,
@@ -83,6 +93,10 @@ pub struct SecurityRule {
 
 impl SecurityGroupDetail {
     pub fn to_markdown(&self, lang: Language) -> String {
+        self.to_markdown_in(NORMALIZE_RULES.load(Ordering::Relaxed), lang)
+    }
+
+    fn to_markdown_in(&self, normalize: bool, lang: Language) -> String {
         let i18n = I18n::new(lang);
         let display_name = if self.name.is_empty() || self.name == self.id {
             format!("NULL - {}", self.id)
@@ -108,12 +122,7 @@ impl SecurityGroupDetail {
                 i18n.md_description()
             ));
             lines.push("|:---|:---|:---|:---|".to_string());
-            for rule in &self.inbound_rules {
-                lines.push(format!(
-                    "| {} | {} | {} | {} |",
-                    rule.protocol, rule.port_range, rule.source_dest, rule.description
-                ));
-            }
+            lines.extend(rule_lines(&self.inbound_rules, normalize, &i18n));
         }
 
         if !self.outbound_rules.is_empty() {
@@ -126,18 +135,88 @@ impl SecurityGroupDetail {
                 i18n.md_description()
             ));
             lines.push("|:---|:---|:---|:---|".to_string());
-            for rule in &self.outbound_rules {
-                lines.push(format!(
-                    "| {} | {} | {} | {} |",
-                    rule.protocol, rule.port_range, rule.source_dest, rule.description
-                ));
-            }
+            lines.extend(rule_lines(&self.outbound_rules, normalize, &i18n));
         }
 
         lines.join("\n") + "\n"
     }
 }
 
+struct RuleRow<'a> {
+    protocol: &'a str,
+    port_range: &'a str,
+    sources: Vec<String>,
+    descriptions: Vec<&'a str>,
+}
+
+// 정리 모드: 같은 프로토콜/포트 범위의 규칙을 한 행으로 합치고(출처는 <br>로 나열, 같은 설명은 한 번만),
+// 전체 트래픽 → 포트 번호 순으로 정렬
+fn rule_lines(rules: &[SecurityRule], normalize: bool, i18n: &I18n) -> Vec<String> {
+    let mut rows: Vec<RuleRow> = Vec::new();
+    for rule in rules {
+        let source = source_dest_label(&rule.source_dest, i18n);
+        let merge_into = normalize
+            .then(|| {
+                rows.iter_mut()
+                    .find(|row| row.protocol == rule.protocol && row.port_range == rule.port_range)
+            })
+            .flatten();
+        match merge_into {
+            Some(row) => {
+                if !row.sources.contains(&source) {
+                    row.sources.push(source);
+                }
+                if !row.descriptions.contains(&rule.description.as_str()) {
+                    row.descriptions.push(&rule.description);
+                }
+            }
+            None => rows.push(RuleRow {
+                protocol: &rule.protocol,
+                port_range: &rule.port_range,
+                sources: vec![source],
+                descriptions: vec![&rule.description],
+            }),
+        }
+    }
+
+    if normalize {
+        rows.sort_by(|a, b| {
+            port_start(a.port_range)
+                .cmp(&port_start(b.port_range))
+                .then_with(|| a.protocol.cmp(b.protocol))
+        });
+    }
+
+    rows.into_iter()
+        .map(|mut row| {
+            // 설명이 있는 출처와 없는 출처가 섞이면 "-"는 생략
+            if row.descriptions.len() > 1 {
+                row.descriptions.retain(|description| *description != "-");
+            }
+            format!(
+                "| {} | {} | {} | {} |",
+                row.protocol,
+                row.port_range,
+                row.sources.join("<br>"),
+                row.descriptions.join("<br>")
+            )
+        })
+        .collect()
+}
+
+// "All"(전체 포트)은 None으로 가장 앞에 정렬
+fn port_start(port_range: &str) -> Option<i32> {
+    port_range.split('-').next()?.parse().ok()
+}
+
+// 참조된 보안 그룹은 색인된 이름을 함께 표시, IPv6 범위는 IPv4와 구분되게 표시
+fn source_dest_label(source_dest: &str, i18n: &I18n) -> String {
+    match source_dest.strip_prefix("sg: ") {
+        Some(group_id) => format!("sg: {}", name_index::label(group_id)),
+        None => cidr_label(source_dest, i18n),
+    }
+}
+
 pub fn list_security_groups() -> Vec<AwsResource> {
     let output = match run_aws_cli(&["ec2", "describe-security-groups", "--output", "json"]) {
         Some(o) => o,
@@ -164,6 +243,7 @@ fn parse_security_groups_list_output(output: &str) -> Option<Vec<AwsResource>> {
                     .find(|t| t.key == "Name")
                     .map(|t| t.value.clone())
                     .unwrap_or_else(|| sg.group_name.clone());
+                name_index::record(&sg.group_id, &name);
 
                 AwsResource {
                     name: format!("{} ({})", name, sg.group_name),
@@ -171,6 +251,7 @@ fn parse_security_groups_list_output(output: &str) -> Option<Vec<AwsResource>> {
                     state: sg.vpc_id,
                     az: String::new(),
                     cidr: String::new(),
+                    owner_id: String::new(),
                 }
             })
             .collect(),
@@ -223,6 +304,8 @@ fn parse_security_rules(permissions: &[IpPermission]) -> Vec<SecurityRule> {
             "tcp" => "TCP".to_string(),
             "udp" => "UDP".to_string(),
             "icmp" => "ICMP".to_string(),
+            // IPv6 규칙의 ICMP는 프로토콜 번호 58로 내려옴
+            "58" | "icmpv6" => "ICMPv6".to_string(),
             other => other.to_uppercase(),
         };
 
@@ -337,6 +420,94 @@ mod tests {
         assert!(md.contains("Outbound Rules"));
     }
 
+    #[test]
+    fn security_group_markdown_labels_ipv6_rules() {
+        let json = r#"
+            [
+              {
+                "IpProtocol":"tcp",
+                "FromPort":443,
+                "ToPort":443,
+                "IpRanges":[{"CidrIp":"0.0.0.0/0"}],
+                "Ipv6Ranges":[{"CidrIpv6":"::/0","Description":"https v6"}]
+              },
+              {
+                "IpProtocol":"58",
+                "Ipv6Ranges":[{"CidrIpv6":"2600:1f18:abc:de00::/56"}]
+              }
+            ]
+        "#;
+        let permissions: Vec<IpPermission> =
+            serde_json::from_str(json).expect("deserialize permissions");
+        let detail = SecurityGroupDetail {
+            name: "web".to_string(),
+            id: "sg-dual".to_string(),
+            description: "dual-stack web".to_string(),
+            vpc_id: "vpc-1111".to_string(),
+            inbound_rules: parse_security_rules(&permissions),
+            outbound_rules: vec![],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("| TCP | 443 | 0.0.0.0/0 | - |"));
+        assert!(md.contains("| TCP | 443 | ::/0 (all IPv6 addresses) | https v6 |"));
+        assert!(md.contains("| ICMPv6 | All | 2600:1f18:abc:de00::/56 (IPv6) | - |"));
+    }
+
+    #[test]
+    fn security_group_markdown_normalizes_rules_when_enabled() {
+        let json = r#"
+            [
+              {
+                "IpProtocol":"tcp",
+                "FromPort":443,
+                "ToPort":443,
+                "IpRanges":[
+                  {"CidrIp":"10.0.0.0/8","Description":"https"},
+                  {"CidrIp":"172.16.0.0/12","Description":"https"},
+                  {"CidrIp":"192.168.0.0/16"}
+                ],
+                "Ipv6Ranges":[{"CidrIpv6":"::/0","Description":"https v6"}]
+              },
+              {
+                "IpProtocol":"tcp",
+                "FromPort":22,
+                "ToPort":22,
+                "IpRanges":[{"CidrIp":"10.0.0.0/8","Description":"ssh"}]
+              },
+              {
+                "IpProtocol":"-1",
+                "UserIdGroupPairs":[{"GroupId":"sg-peer"}]
+              }
+            ]
+        "#;
+        let permissions: Vec<IpPermission> =
+            serde_json::from_str(json).expect("deserialize permissions");
+        let detail = SecurityGroupDetail {
+            name: "web".to_string(),
+            id: "sg-web".to_string(),
+            description: "web".to_string(),
+            vpc_id: "vpc-1111".to_string(),
+            inbound_rules: parse_security_rules(&permissions),
+            outbound_rules: vec![],
+        };
+
+        let md = detail.to_markdown_in(true, Language::English);
+        assert!(md.contains(
+            "| All | All | sg: sg-peer | - |\n| TCP | 22 | 10.0.0.0/8 | ssh |\n| TCP | 443 | 10.0.0.0/8<br>172.16.0.0/12<br>192.168.0.0/16<br>::/0 (all IPv6 addresses) | https<br>https v6 |"
+        ));
+
+        // 기본값은 AWS가 내려준 순서대로 출처마다 한 행
+        let md = detail.to_markdown_in(false, Language::English);
+        assert!(
+            md.contains(
+                "| TCP | 443 | 10.0.0.0/8 | https |\n| TCP | 443 | 172.16.0.0/12 | https |"
+            )
+        );
+        assert!(md.contains("| TCP | 443 | 192.168.0.0/16 | - |"));
+        assert!(md.ends_with("| All | All | sg: sg-peer | - |\n"));
+    }
+
     #[test]
     fn parse_security_groups_list_output_prefers_name_tag_or_group_name() {
         let payload = r#"
@@ -0,0 +1,226 @@
+pub use crate::aws_cli::sns_sdk::{get_sns_detail, list_sns_topics};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct SnsSubscription {
+    // 확인 대기 중인 구독은 "PendingConfirmation"
+    pub arn: String,
+    pub protocol: String,
+    pub endpoint: String,
+    // 한 줄 JSON으로 정리된 필터 정책
+    pub filter_policy: Option<String>,
+    pub filter_policy_scope: Option<String>,
+    pub raw_message_delivery: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct SnsDetail {
+    pub name: String,
+    pub arn: String,
+    pub display_name: String,
+    pub owner: String,
+    pub fifo: bool,
+    pub content_based_deduplication: bool,
+    pub kms_master_key_id: Option<String>,
+    pub subscriptions_confirmed: i64,
+    pub subscriptions_pending: i64,
+    // 보기 좋게 정렬된 JSON
+    pub access_policy: Option<String>,
+    pub subscriptions: Vec<SnsSubscription>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SnsDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let topic_type = if self.fifo {
+            "FIFO"
+        } else {
+            i18n.md_standard()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.sns_topic(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+        ];
+        if !self.display_name.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_display_name(),
+                self.display_name
+            ));
+        }
+        lines.push(format!("| {} | {} |", i18n.md_type(), topic_type));
+        if self.fifo {
+            let deduplication = if self.content_based_deduplication {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            };
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_content_based_deduplication(),
+                deduplication
+            ));
+        }
+        if !self.owner.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_owner_account(), self.owner));
+        }
+        let encryption = match self.kms_master_key_id {
+            Some(ref key) => format!("SSE-KMS ({})", key),
+            None => i18n.md_disabled().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_subscriptions_confirmed(),
+            self.subscriptions_confirmed
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_subscriptions_pending(),
+            self.subscriptions_pending
+        ));
+
+        // Subscriptions
+        if !self.subscriptions.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_subscriptions()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_protocol(),
+                i18n.md_endpoint(),
+                i18n.md_filter_policy(),
+                i18n.md_raw_message_delivery()
+            ));
+            lines.push("|:---|:---|:---|:---|".to_string());
+            for subscription in &self.subscriptions {
+                // 필터 정책 JSON의 '|'는 표를 깨뜨리므로 이스케이프
+                let filter = match subscription.filter_policy {
+                    Some(ref policy) => {
+                        let policy = format!("`{}`", policy.replace('|', "\\|"));
+                        match subscription.filter_policy_scope {
+                            Some(ref scope) => format!("{} ({})", policy, scope),
+                            None => policy,
+                        }
+                    }
+                    None => "-".to_string(),
+                };
+                let raw_delivery = if subscription.raw_message_delivery {
+                    i18n.md_enabled()
+                } else {
+                    i18n.md_disabled()
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    subscription.protocol, subscription.endpoint, filter, raw_delivery
+                ));
+            }
+        }
+
+        // Access Policy
+        if let Some(ref policy) = self.access_policy {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_access_policy()));
+            lines.push("```json".to_string());
+            lines.push(policy.clone());
+            lines.push("```".to_string());
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnsDetail, SnsSubscription};
+    use crate::i18n::Language;
+
+    fn sample_detail() -> SnsDetail {
+        SnsDetail {
+            name: "order-events".to_string(),
+            arn: "arn:aws:sns:ap-northeast-2:123456789012:order-events".to_string(),
+            display_name: "Orders".to_string(),
+            owner: "123456789012".to_string(),
+            fifo: false,
+            content_based_deduplication: false,
+            kms_master_key_id: Some("alias/aws/sns".to_string()),
+            subscriptions_confirmed: 2,
+            subscriptions_pending: 1,
+            access_policy: Some(
+                "{\n  \"Version\": \"2012-10-17\",\n  \"Statement\": []\n}".to_string(),
+            ),
+            subscriptions: vec![
+                SnsSubscription {
+                    arn: "arn:aws:sns:ap-northeast-2:123456789012:order-events:1111".to_string(),
+                    protocol: "sqs".to_string(),
+                    endpoint: "arn:aws:sqs:ap-northeast-2:123456789012:orders".to_string(),
+                    filter_policy: Some(r#"{"status":["paid"]}"#.to_string()),
+                    filter_policy_scope: Some("MessageAttributes".to_string()),
+                    raw_message_delivery: true,
+                },
+                SnsSubscription {
+                    arn: "PendingConfirmation".to_string(),
+                    protocol: "email".to_string(),
+                    endpoint: "ops@example.com".to_string(),
+                    filter_policy: None,
+                    filter_policy_scope: None,
+                    raw_message_delivery: false,
+                },
+            ],
+            tags: vec![("Team".to_string(), "commerce".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_sns_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## SNS Topic (order-events)"));
+        assert!(markdown.contains("| Display Name | Orders |"));
+        assert!(markdown.contains("| Type | Standard |"));
+        assert!(!markdown.contains("Content-Based Deduplication"));
+        assert!(markdown.contains("| Encryption | SSE-KMS (alias/aws/sns) |"));
+        assert!(markdown.contains("| Pending Subscriptions | 1 |"));
+        assert!(markdown.contains(
+            "| sqs | arn:aws:sqs:ap-northeast-2:123456789012:orders | `{\"status\":[\"paid\"]}` (MessageAttributes) | Enabled |"
+        ));
+        assert!(markdown.contains("| email | ops@example.com | - | Disabled |"));
+        assert!(markdown.contains("### Access Policy\n\n```json\n{"));
+        assert!(markdown.contains("| Team | commerce |"));
+    }
+
+    #[test]
+    fn scenario_sns_filter_policy_pipe_is_escaped() {
+        let mut detail = sample_detail();
+        detail.fifo = true;
+        detail.content_based_deduplication = true;
+        detail.kms_master_key_id = None;
+        detail.access_policy = None;
+        detail.subscriptions[0].filter_policy = Some(r#"{"kind":["a|b"]}"#.to_string());
+        detail.subscriptions[0].filter_policy_scope = None;
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 타입 | FIFO |"));
+        assert!(markdown.contains("| 콘텐츠 기반 중복 제거 | 활성화 |"));
+        assert!(markdown.contains(r#"`{"kind":["a\|b"]}` |"#));
+        assert!(!markdown.contains("```json"));
+    }
+}
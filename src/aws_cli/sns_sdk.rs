@@ -0,0 +1,327 @@
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::sns::{SnsDetail, SnsSubscription};
+use std::collections::HashMap;
+
+/// List all SNS topics using AWS SDK
+pub fn list_sns_topics() -> Vec<AwsResource> {
+    get_runtime().block_on(list_sns_topics_async())
+}
+
+async fn list_sns_topics_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sns, &config);
+
+    let topics: Vec<aws_sdk_sns::types::Topic> = match client
+        .list_topics()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(topics) => topics,
+        Err(e) => {
+            tracing::error!("Error listing SNS topics: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = topics
+        .iter()
+        .filter_map(|topic| topic.topic_arn())
+        .map(map_topic_resource)
+        .collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get SNS topic detail (attributes, subscriptions and tags) using AWS SDK
+pub fn get_sns_detail(arn: &str) -> Option<SnsDetail> {
+    get_runtime().block_on(get_sns_detail_async(arn))
+}
+
+async fn get_sns_detail_async(arn: &str) -> Option<SnsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sns, &config);
+
+    let output = client
+        .get_topic_attributes()
+        .topic_arn(arn)
+        .send()
+        .await
+        .ok()?;
+    let empty = HashMap::new();
+    let attributes = output.attributes().unwrap_or(&empty);
+
+    let subscriptions: Vec<aws_sdk_sns::types::Subscription> = match client
+        .list_subscriptions_by_topic()
+        .topic_arn(arn)
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(subscriptions) => subscriptions,
+        Err(e) => {
+            tracing::warn!("Error listing subscriptions for {}: {:?}", arn, e);
+            Vec::new()
+        }
+    };
+
+    let mut mapped_subscriptions = Vec::new();
+    for subscription in &subscriptions {
+        let subscription_arn = subscription.subscription_arn().unwrap_or_default();
+        // 확인 대기 중인 구독은 ARN이 없어 속성을 조회할 수 없음
+        let subscription_attributes = if subscription_arn.starts_with("arn:") {
+            match client
+                .get_subscription_attributes()
+                .subscription_arn(subscription_arn)
+                .send()
+                .await
+            {
+                Ok(output) => output.attributes().cloned().unwrap_or_default(),
+                Err(e) => {
+                    tracing::warn!(
+                        "Error getting subscription attributes for {}: {:?}",
+                        subscription_arn,
+                        e
+                    );
+                    HashMap::new()
+                }
+            }
+        } else {
+            HashMap::new()
+        };
+        mapped_subscriptions.push(map_subscription(
+            subscription_arn,
+            subscription.protocol().unwrap_or_default(),
+            subscription.endpoint().unwrap_or_default(),
+            &subscription_attributes,
+        ));
+    }
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let mut tags: Vec<(String, String)> = match client
+        .list_tags_for_resource()
+        .resource_arn(arn)
+        .send()
+        .await
+    {
+        Ok(output) => output
+            .tags()
+            .iter()
+            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Error listing tags for {}: {:?}", arn, e);
+            Vec::new()
+        }
+    };
+    tags.sort();
+
+    Some(map_topic_detail(
+        arn,
+        attributes,
+        mapped_subscriptions,
+        tags,
+    ))
+}
+
+// "arn:aws:sns:ap-northeast-2:123456789012:order-events" -> "order-events"
+fn topic_name(arn: &str) -> &str {
+    Arn::parse(arn)
+        .map(|parsed| parsed.resource_name())
+        .unwrap_or(arn)
+}
+
+fn map_topic_resource(arn: &str) -> AwsResource {
+    let name = topic_name(arn).to_string();
+    let topic_type = if name.ends_with(".fifo") {
+        "FIFO"
+    } else {
+        "Standard"
+    };
+    AwsResource {
+        name,
+        id: arn.to_string(),
+        state: topic_type.to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_topic_detail(
+    arn: &str,
+    attributes: &HashMap<String, String>,
+    subscriptions: Vec<SnsSubscription>,
+    tags: Vec<(String, String)>,
+) -> SnsDetail {
+    let text = |key: &str| attributes.get(key).cloned().unwrap_or_default();
+    let number = |key: &str| {
+        attributes
+            .get(key)
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(0)
+    };
+    let flag = |key: &str| attributes.get(key).is_some_and(|v| v == "true");
+
+    SnsDetail {
+        name: topic_name(arn).to_string(),
+        arn: arn.to_string(),
+        display_name: text("DisplayName"),
+        owner: text("Owner"),
+        fifo: flag("FifoTopic"),
+        content_based_deduplication: flag("ContentBasedDeduplication"),
+        kms_master_key_id: attributes
+            .get("KmsMasterKeyId")
+            .filter(|key| !key.is_empty())
+            .cloned(),
+        subscriptions_confirmed: number("SubscriptionsConfirmed"),
+        subscriptions_pending: number("SubscriptionsPending"),
+        access_policy: attributes
+            .get("Policy")
+            .map(|policy| format_json(policy, true)),
+        subscriptions,
+        tags,
+    }
+}
+
+fn map_subscription(
+    arn: &str,
+    protocol: &str,
+    endpoint: &str,
+    attributes: &HashMap<String, String>,
+) -> SnsSubscription {
+    SnsSubscription {
+        arn: arn.to_string(),
+        protocol: protocol.to_string(),
+        endpoint: endpoint.to_string(),
+        filter_policy: attributes
+            .get("FilterPolicy")
+            .filter(|policy| !policy.is_empty())
+            .map(|policy| format_json(policy, false)),
+        // 필터 정책이 없으면 범위도 의미가 없음
+        filter_policy_scope: attributes
+            .get("FilterPolicy")
+            .filter(|policy| !policy.is_empty())
+            .and(attributes.get("FilterPolicyScope"))
+            .cloned(),
+        raw_message_delivery: attributes
+            .get("RawMessageDelivery")
+            .is_some_and(|v| v == "true"),
+    }
+}
+
+// 정책 JSON을 정리하고, 파싱할 수 없으면 원본을 그대로 사용
+fn format_json(json: &str, pretty: bool) -> String {
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(value) if pretty => serde_json::to_string_pretty(&value).unwrap_or(json.to_string()),
+        Ok(value) => value.to_string(),
+        Err(_) => json.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_json, map_subscription, map_topic_detail, map_topic_resource};
+    use std::collections::HashMap;
+
+    fn attributes(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn map_topic_resource_uses_arn_as_id() {
+        let topic =
+            map_topic_resource("arn:aws:sns:ap-northeast-2:123456789012:checkout-events.fifo");
+        assert_eq!(topic.name, "checkout-events.fifo");
+        assert_eq!(
+            topic.id,
+            "arn:aws:sns:ap-northeast-2:123456789012:checkout-events.fifo"
+        );
+        assert_eq!(topic.state, "FIFO");
+
+        let standard = map_topic_resource("arn:aws:sns:ap-northeast-2:123456789012:alerts");
+        assert_eq!(standard.name, "alerts");
+        assert_eq!(standard.state, "Standard");
+    }
+
+    #[test]
+    fn format_json_pretty_and_compact() {
+        assert_eq!(
+            format_json("{ \"status\": [ \"paid\" ] }", false),
+            r#"{"status":["paid"]}"#
+        );
+        assert_eq!(
+            format_json(r#"{"Version":"2012-10-17"}"#, true),
+            "{\n  \"Version\": \"2012-10-17\"\n}"
+        );
+        assert_eq!(format_json("not json", true), "not json");
+    }
+
+    #[test]
+    fn map_topic_detail_and_subscription_read_attributes() {
+        let topic_attributes = attributes(&[
+            ("DisplayName", "Orders"),
+            ("Owner", "123456789012"),
+            ("KmsMasterKeyId", ""),
+            ("SubscriptionsConfirmed", "3"),
+            ("SubscriptionsPending", "1"),
+            ("Policy", r#"{"Version":"2012-10-17","Statement":[]}"#),
+        ]);
+        let detail = map_topic_detail(
+            "arn:aws:sns:ap-northeast-2:123456789012:order-events",
+            &topic_attributes,
+            vec![],
+            vec![],
+        );
+        assert_eq!(detail.name, "order-events");
+        assert_eq!(detail.display_name, "Orders");
+        assert!(!detail.fifo);
+        assert_eq!(detail.kms_master_key_id, None);
+        assert_eq!(detail.subscriptions_confirmed, 3);
+        assert_eq!(detail.subscriptions_pending, 1);
+        assert!(
+            detail
+                .access_policy
+                .unwrap()
+                .contains("\n  \"Statement\": []")
+        );
+
+        let subscription = map_subscription(
+            "arn:aws:sns:ap-northeast-2:123456789012:order-events:1111",
+            "sqs",
+            "arn:aws:sqs:ap-northeast-2:123456789012:orders",
+            &attributes(&[
+                ("FilterPolicy", "{\n  \"status\": [\"paid\"]\n}"),
+                ("FilterPolicyScope", "MessageBody"),
+                ("RawMessageDelivery", "true"),
+            ]),
+        );
+        assert_eq!(
+            subscription.filter_policy.as_deref(),
+            Some(r#"{"status":["paid"]}"#)
+        );
+        assert_eq!(
+            subscription.filter_policy_scope.as_deref(),
+            Some("MessageBody")
+        );
+        assert!(subscription.raw_message_delivery);
+
+        let pending = map_subscription(
+            "PendingConfirmation",
+            "email",
+            "ops@example.com",
+            &attributes(&[("FilterPolicyScope", "MessageAttributes")]),
+        );
+        assert_eq!(pending.filter_policy, None);
+        assert_eq!(pending.filter_policy_scope, None);
+        assert!(!pending.raw_message_delivery);
+    }
+}
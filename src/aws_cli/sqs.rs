@@ -0,0 +1,205 @@
+pub use crate::aws_cli::sqs_sdk::{get_sqs_detail, list_sqs_queues};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct SqsDetail {
+    pub name: String,
+    pub url: String,
+    pub arn: String,
+    pub fifo: bool,
+    pub content_based_deduplication: bool,
+    // 시간 속성은 모두 초 단위
+    pub visibility_timeout: i32,
+    pub message_retention_period: i32,
+    pub delay_seconds: i32,
+    pub receive_message_wait_time: i32,
+    // 바이트 단위
+    pub maximum_message_size: i32,
+    pub messages_available: i64,
+    pub messages_in_flight: i64,
+    pub messages_delayed: i64,
+    // RedrivePolicy가 있을 때만 설정
+    pub dead_letter_target_arn: Option<String>,
+    pub max_receive_count: Option<i32>,
+    pub sqs_managed_sse: bool,
+    pub kms_master_key_id: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SqsDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let queue_type = if self.fifo {
+            "FIFO"
+        } else {
+            i18n.md_standard()
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.sqs_queue(), self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| {} | {} |", i18n.md_queue_url(), self.url),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_type(), queue_type),
+        ];
+        if self.fifo {
+            let deduplication = if self.content_based_deduplication {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            };
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_content_based_deduplication(),
+                deduplication
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_visibility_timeout(),
+            i18n.asg_seconds(self.visibility_timeout)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_message_retention(),
+            i18n.asg_seconds(self.message_retention_period)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_delivery_delay(),
+            i18n.asg_seconds(self.delay_seconds)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_receive_wait_time(),
+            i18n.asg_seconds(self.receive_message_wait_time)
+        ));
+        lines.push(format!(
+            "| {} | {} KB |",
+            i18n.md_max_message_size(),
+            self.maximum_message_size / 1024
+        ));
+        // KMS 키가 있으면 SSE-KMS, 없으면 SQS 관리형 SSE 여부
+        let encryption = match (&self.kms_master_key_id, self.sqs_managed_sse) {
+            (Some(key), _) => format!("SSE-KMS ({})", key),
+            (None, true) => "SSE-SQS".to_string(),
+            (None, false) => i18n.md_disabled().to_string(),
+        };
+        lines.push(format!("| {} | {} |", i18n.md_encryption(), encryption));
+
+        // Messages
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_messages()));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.md_messages_available(),
+            i18n.md_messages_in_flight(),
+            i18n.md_messages_delayed()
+        ));
+        lines.push("|---:|---:|---:|".to_string());
+        lines.push(format!(
+            "| {} | {} | {} |",
+            self.messages_available, self.messages_in_flight, self.messages_delayed
+        ));
+
+        // Dead-Letter Queue
+        if let Some(ref target_arn) = self.dead_letter_target_arn {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_dead_letter_queue()));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| ARN | {} |", target_arn));
+            if let Some(count) = self.max_receive_count {
+                lines.push(format!("| {} | {} |", i18n.md_max_receive_count(), count));
+            }
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SqsDetail;
+    use crate::i18n::Language;
+
+    fn sample_detail() -> SqsDetail {
+        SqsDetail {
+            name: "orders.fifo".to_string(),
+            url: "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders.fifo".to_string(),
+            arn: "arn:aws:sqs:ap-northeast-2:123456789012:orders.fifo".to_string(),
+            fifo: true,
+            content_based_deduplication: true,
+            visibility_timeout: 30,
+            message_retention_period: 345600,
+            delay_seconds: 0,
+            receive_message_wait_time: 20,
+            maximum_message_size: 262144,
+            messages_available: 12,
+            messages_in_flight: 3,
+            messages_delayed: 0,
+            dead_letter_target_arn: Some(
+                "arn:aws:sqs:ap-northeast-2:123456789012:orders-dlq.fifo".to_string(),
+            ),
+            max_receive_count: Some(5),
+            sqs_managed_sse: false,
+            kms_master_key_id: Some("alias/aws/sqs".to_string()),
+            tags: vec![("Team".to_string(), "commerce".to_string())],
+        }
+    }
+
+    #[test]
+    fn scenario_sqs_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## SQS Queue (orders.fifo)"));
+        assert!(markdown.contains("| Type | FIFO |"));
+        assert!(markdown.contains("| Content-Based Deduplication | Enabled |"));
+        assert!(markdown.contains("| Visibility Timeout | 30s |"));
+        assert!(markdown.contains("| Message Retention | 345600s |"));
+        assert!(markdown.contains("| Maximum Message Size | 256 KB |"));
+        assert!(markdown.contains("| Encryption | SSE-KMS (alias/aws/sqs) |"));
+        assert!(markdown.contains("| 12 | 3 | 0 |"));
+        assert!(
+            markdown.contains("| ARN | arn:aws:sqs:ap-northeast-2:123456789012:orders-dlq.fifo |")
+        );
+        assert!(markdown.contains("| Maximum Receives | 5 |"));
+        assert!(markdown.contains("| Team | commerce |"));
+    }
+
+    #[test]
+    fn scenario_sqs_standard_queue_without_redrive() {
+        let mut detail = sample_detail();
+        detail.name = "events".to_string();
+        detail.fifo = false;
+        detail.dead_letter_target_arn = None;
+        detail.max_receive_count = None;
+        detail.kms_master_key_id = None;
+        detail.sqs_managed_sse = true;
+
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 타입 | 표준 |"));
+        assert!(!markdown.contains("콘텐츠 기반 중복 제거"));
+        assert!(markdown.contains("| 암호화 | SSE-SQS |"));
+        assert!(!markdown.contains("### 배달 못한 편지 대기열"));
+
+        detail.sqs_managed_sse = false;
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("| Encryption | Disabled |"));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::sqs::SqsDetail;
+use aws_sdk_sqs::types::QueueAttributeName;
+use std::collections::HashMap;
+
+/// List all SQS queues using AWS SDK
+pub fn list_sqs_queues() -> Vec<AwsResource> {
+    get_runtime().block_on(list_sqs_queues_async())
+}
+
+async fn list_sqs_queues_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sqs, &config);
+
+    let urls: Vec<String> = match client
+        .list_queues()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(urls) => urls,
+        Err(e) => {
+            tracing::error!("Error listing SQS queues: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut resources: Vec<AwsResource> = urls.iter().map(|url| map_queue_resource(url)).collect();
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get SQS queue detail (attributes, redrive policy and tags) using AWS SDK
+pub fn get_sqs_detail(name: &str) -> Option<SqsDetail> {
+    get_runtime().block_on(get_sqs_detail_async(name))
+}
+
+async fn get_sqs_detail_async(name: &str) -> Option<SqsDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sqs, &config);
+
+    let url = client
+        .get_queue_url()
+        .queue_name(name)
+        .send()
+        .await
+        .ok()?
+        .queue_url()?
+        .to_string();
+
+    let output = client
+        .get_queue_attributes()
+        .queue_url(&url)
+        .attribute_names(QueueAttributeName::All)
+        .send()
+        .await
+        .ok()?;
+    let empty = HashMap::new();
+    let attributes = output.attributes().unwrap_or(&empty);
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let mut tags: Vec<(String, String)> =
+        match client.list_queue_tags().queue_url(&url).send().await {
+            Ok(output) => output
+                .tags()
+                .map(|tags| tags.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("Error listing tags for {}: {:?}", name, e);
+                Vec::new()
+            }
+        };
+    tags.sort();
+
+    Some(map_queue_detail(name, &url, attributes, tags))
+}
+
+// "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders" -> "orders"
+fn queue_name(url: &str) -> &str {
+    url.rsplit('/').next().unwrap_or(url)
+}
+
+fn map_queue_resource(url: &str) -> AwsResource {
+    let name = queue_name(url).to_string();
+    let queue_type = if name.ends_with(".fifo") {
+        "FIFO"
+    } else {
+        "Standard"
+    };
+    AwsResource {
+        name: name.clone(),
+        id: name,
+        state: queue_type.to_string(),
+        az: String::new(),
+        cidr: String::new(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_queue_detail(
+    name: &str,
+    url: &str,
+    attributes: &HashMap<QueueAttributeName, String>,
+    tags: Vec<(String, String)>,
+) -> SqsDetail {
+    let text = |key: QueueAttributeName| attributes.get(&key).cloned().unwrap_or_default();
+    let number = |key: QueueAttributeName| {
+        attributes
+            .get(&key)
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(0)
+    };
+    let flag = |key: QueueAttributeName| attributes.get(&key).is_some_and(|v| v == "true");
+    let (dead_letter_target_arn, max_receive_count) = attributes
+        .get(&QueueAttributeName::RedrivePolicy)
+        .map(|policy| parse_redrive_policy(policy))
+        .unwrap_or((None, None));
+
+    SqsDetail {
+        name: name.to_string(),
+        url: url.to_string(),
+        arn: text(QueueAttributeName::QueueArn),
+        fifo: flag(QueueAttributeName::FifoQueue),
+        content_based_deduplication: flag(QueueAttributeName::ContentBasedDeduplication),
+        visibility_timeout: number(QueueAttributeName::VisibilityTimeout) as i32,
+        message_retention_period: number(QueueAttributeName::MessageRetentionPeriod) as i32,
+        delay_seconds: number(QueueAttributeName::DelaySeconds) as i32,
+        receive_message_wait_time: number(QueueAttributeName::ReceiveMessageWaitTimeSeconds) as i32,
+        maximum_message_size: number(QueueAttributeName::MaximumMessageSize) as i32,
+        messages_available: number(QueueAttributeName::ApproximateNumberOfMessages),
+        messages_in_flight: number(QueueAttributeName::ApproximateNumberOfMessagesNotVisible),
+        messages_delayed: number(QueueAttributeName::ApproximateNumberOfMessagesDelayed),
+        dead_letter_target_arn,
+        max_receive_count,
+        sqs_managed_sse: flag(QueueAttributeName::SqsManagedSseEnabled),
+        kms_master_key_id: attributes
+            .get(&QueueAttributeName::KmsMasterKeyId)
+            .filter(|key| !key.is_empty())
+            .cloned(),
+        tags,
+    }
+}
+
+// RedrivePolicy는 JSON 문자열이며 maxReceiveCount는 숫자 또는 문자열로 올 수 있음
+fn parse_redrive_policy(policy: &str) -> (Option<String>, Option<i32>) {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(policy) else {
+        return (None, None);
+    };
+    let target_arn = value
+        .get("deadLetterTargetArn")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    let max_receive_count = value.get("maxReceiveCount").and_then(|v| {
+        v.as_i64()
+            .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            .map(|n| n as i32)
+    });
+    (target_arn, max_receive_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_queue_detail, map_queue_resource, parse_redrive_policy};
+    use aws_sdk_sqs::types::QueueAttributeName;
+    use std::collections::HashMap;
+
+    #[test]
+    fn map_queue_resource_uses_name_and_queue_type() {
+        let fifo =
+            map_queue_resource("https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders.fifo");
+        assert_eq!(fifo.name, "orders.fifo");
+        assert_eq!(fifo.id, "orders.fifo");
+        assert_eq!(fifo.state, "FIFO");
+
+        let standard =
+            map_queue_resource("https://sqs.ap-northeast-2.amazonaws.com/123456789012/events");
+        assert_eq!(standard.id, "events");
+        assert_eq!(standard.state, "Standard");
+    }
+
+    #[test]
+    fn parse_redrive_policy_accepts_numeric_and_string_counts() {
+        assert_eq!(
+            parse_redrive_policy(
+                r#"{"deadLetterTargetArn":"arn:aws:sqs:ap-northeast-2:123456789012:dlq","maxReceiveCount":5}"#
+            ),
+            (
+                Some("arn:aws:sqs:ap-northeast-2:123456789012:dlq".to_string()),
+                Some(5)
+            )
+        );
+        assert_eq!(
+            parse_redrive_policy(r#"{"deadLetterTargetArn":"arn:dlq","maxReceiveCount":"10"}"#),
+            (Some("arn:dlq".to_string()), Some(10))
+        );
+        assert_eq!(parse_redrive_policy("not json"), (None, None));
+    }
+
+    #[test]
+    fn map_queue_detail_reads_attributes() {
+        let attributes: HashMap<QueueAttributeName, String> = [
+            (
+                QueueAttributeName::QueueArn,
+                "arn:aws:sqs:ap-northeast-2:123456789012:events",
+            ),
+            (QueueAttributeName::VisibilityTimeout, "45"),
+            (QueueAttributeName::MessageRetentionPeriod, "86400"),
+            (QueueAttributeName::MaximumMessageSize, "262144"),
+            (QueueAttributeName::ApproximateNumberOfMessages, "7"),
+            (
+                QueueAttributeName::ApproximateNumberOfMessagesNotVisible,
+                "2",
+            ),
+            (QueueAttributeName::SqsManagedSseEnabled, "true"),
+            (QueueAttributeName::KmsMasterKeyId, ""),
+            (
+                QueueAttributeName::RedrivePolicy,
+                r#"{"deadLetterTargetArn":"arn:dlq","maxReceiveCount":3}"#,
+            ),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k, v.to_string()))
+        .collect();
+
+        let detail = map_queue_detail(
+            "events",
+            "https://sqs.ap-northeast-2.amazonaws.com/123456789012/events",
+            &attributes,
+            vec![],
+        );
+        assert_eq!(detail.arn, "arn:aws:sqs:ap-northeast-2:123456789012:events");
+        assert!(!detail.fifo);
+        assert_eq!(detail.visibility_timeout, 45);
+        assert_eq!(detail.message_retention_period, 86400);
+        assert_eq!(detail.messages_available, 7);
+        assert_eq!(detail.messages_in_flight, 2);
+        assert_eq!(detail.messages_delayed, 0);
+        assert!(detail.sqs_managed_sse);
+        assert_eq!(detail.kms_master_key_id, None);
+        assert_eq!(detail.dead_letter_target_arn.as_deref(), Some("arn:dlq"));
+        assert_eq!(detail.max_receive_count, Some(3));
+    }
+}
@@ -0,0 +1,240 @@
+pub use crate::aws_cli::ssm_sdk::{get_parameter_path_detail, list_parameter_paths};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// 파라미터 메타데이터만 담음 (값은 조회하지 않음)
+#[derive(Debug, Clone, Serialize)]
+pub struct SsmParameter {
+    pub name: String,
+    // String, StringList, SecureString
+    pub parameter_type: String,
+    // Standard, Advanced, Intelligent-Tiering
+    pub tier: String,
+    pub data_type: String,
+    pub version: i64,
+    pub description: String,
+    // SecureString 암호화에 쓰인 KMS 키
+    pub key_id: Option<String>,
+    pub last_modified: String,
+    pub last_modified_user: String,
+}
+
+// 최상위 경로 접두사(/prod 등) 하나에 속한 파라미터
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct SsmParameterPathDetail {
+    pub path: String,
+    pub parameters: Vec<SsmParameter>,
+}
+
+/// Top-level path prefix of a parameter name (`/prod/app/db` → `/prod`, `flag` → `/`).
+pub fn path_prefix(name: &str) -> String {
+    match name.strip_prefix('/').and_then(|rest| rest.split_once('/')) {
+        Some((first, _)) => format!("/{}", first),
+        None => "/".to_string(),
+    }
+}
+
+#[derive(Default)]
+struct PathNode<'a> {
+    parameter: Option<&'a SsmParameter>,
+    children: BTreeMap<&'a str, PathNode<'a>>,
+}
+
+/// Render parameter names below `path` as a tree, one line per path segment.
+pub fn render_parameter_tree(path: &str, parameters: &[SsmParameter]) -> Vec<String> {
+    let mut root = PathNode::default();
+    for parameter in parameters {
+        let relative = parameter.name.strip_prefix(path).unwrap_or(&parameter.name);
+        let mut node = &mut root;
+        for segment in relative.split('/').filter(|segment| !segment.is_empty()) {
+            node = node.children.entry(segment).or_default();
+        }
+        node.parameter = Some(parameter);
+    }
+
+    let mut lines = vec![path.to_string()];
+    push_tree_lines(&root, "", &mut lines);
+    lines
+}
+
+fn push_tree_lines(node: &PathNode, indent: &str, lines: &mut Vec<String>) {
+    let count = node.children.len();
+    for (idx, (segment, child)) in node.children.iter().enumerate() {
+        let last = idx + 1 == count;
+        let branch = if last { "└── " } else { "├── " };
+        // 파라미터이면서 하위 경로도 있는 이름(/app, /app/db)은 둘 다 표시
+        let label = match child.parameter {
+            Some(parameter) => format!(
+                "{} ({}, {})",
+                segment, parameter.parameter_type, parameter.tier
+            ),
+            None => format!("{}/", segment),
+        };
+        lines.push(format!("{}{}{}", indent, branch, label));
+        let child_indent = format!("{}{}", indent, if last { "    " } else { "│   " });
+        push_tree_lines(child, &child_indent, lines);
+    }
+}
+
+impl SsmParameterPathDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let count_of = |parameter_type: &str| {
+            self.parameters
+                .iter()
+                .filter(|parameter| parameter.parameter_type == parameter_type)
+                .count()
+        };
+        let advanced = self
+            .parameters
+            .iter()
+            .filter(|parameter| parameter.tier == "Advanced")
+            .count();
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.ssm_parameter_path(), self.path),
+            format!("> {}\n", i18n.md_parameter_value_note()),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_path(), self.path),
+            format!("| {} | {} |", i18n.md_parameters(), self.parameters.len()),
+            format!("| String | {} |", count_of("String")),
+            format!("| StringList | {} |", count_of("StringList")),
+            format!("| SecureString | {} |", count_of("SecureString")),
+            format!("| {} | {} |", i18n.md_advanced_tier(), advanced),
+        ];
+
+        if self.parameters.is_empty() {
+            lines.push(String::new());
+            return lines.join("\n");
+        }
+
+        // Tree
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_parameter_tree()));
+        lines.push("```text".to_string());
+        lines.extend(render_parameter_tree(&self.path, &self.parameters));
+        lines.push("```".to_string());
+
+        // Parameters
+        lines.push(String::new());
+        lines.push(format!("### {}\n", i18n.md_parameters()));
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            i18n.md_name(),
+            i18n.md_type(),
+            i18n.md_tier(),
+            i18n.md_version(),
+            i18n.md_last_modified(),
+            i18n.md_description()
+        ));
+        lines.push("|:---|:---|:---|---:|:---|:---|".to_string());
+        for parameter in &self.parameters {
+            let parameter_type = match parameter.key_id {
+                Some(ref key_id) => format!("{} ({})", parameter.parameter_type, key_id),
+                None => parameter.parameter_type.clone(),
+            };
+            let last_modified = if parameter.last_modified.is_empty() {
+                "-".to_string()
+            } else {
+                parameter.last_modified.clone()
+            };
+            let description = if parameter.description.is_empty() {
+                "-"
+            } else {
+                parameter.description.as_str()
+            };
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                parameter.name,
+                parameter_type,
+                parameter.tier,
+                parameter.version,
+                last_modified,
+                description
+            ));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SsmParameter, SsmParameterPathDetail, path_prefix, render_parameter_tree};
+    use crate::i18n::Language;
+
+    fn parameter(name: &str, parameter_type: &str, tier: &str) -> SsmParameter {
+        SsmParameter {
+            name: name.to_string(),
+            parameter_type: parameter_type.to_string(),
+            tier: tier.to_string(),
+            data_type: "text".to_string(),
+            version: 1,
+            description: String::new(),
+            key_id: None,
+            last_modified: String::new(),
+            last_modified_user: String::new(),
+        }
+    }
+
+    #[test]
+    fn path_prefix_uses_first_segment() {
+        assert_eq!(path_prefix("/prod/app/db-password"), "/prod");
+        assert_eq!(path_prefix("/prod/url"), "/prod");
+        assert_eq!(path_prefix("/feature-flag"), "/");
+        assert_eq!(path_prefix("legacy-flag"), "/");
+    }
+
+    #[test]
+    fn render_parameter_tree_groups_by_segment() {
+        let parameters = vec![
+            parameter("/prod/app/db-password", "SecureString", "Standard"),
+            parameter("/prod/app/url", "String", "Standard"),
+            parameter("/prod/feature-flags", "StringList", "Advanced"),
+        ];
+        assert_eq!(
+            render_parameter_tree("/prod", &parameters),
+            vec![
+                "/prod",
+                "├── app/",
+                "│   ├── db-password (SecureString, Standard)",
+                "│   └── url (String, Standard)",
+                "└── feature-flags (StringList, Advanced)",
+            ]
+        );
+    }
+
+    #[test]
+    fn scenario_ssm_parameter_markdown_render() {
+        let mut secure = parameter("/prod/app/db-password", "SecureString", "Standard");
+        secure.key_id = Some("alias/aws/ssm".to_string());
+        secure.version = 3;
+        let detail = SsmParameterPathDetail {
+            path: "/prod".to_string(),
+            parameters: vec![
+                secure,
+                parameter("/prod/feature-flags", "StringList", "Advanced"),
+            ],
+        };
+
+        let markdown = detail.to_markdown(Language::English);
+        assert!(markdown.contains("## SSM Parameter Path (/prod)"));
+        assert!(markdown.contains("> Parameter values are not retrieved"));
+        assert!(markdown.contains("| Parameters | 2 |"));
+        assert!(markdown.contains("| SecureString | 1 |"));
+        assert!(markdown.contains("| Advanced Tier | 1 |"));
+        assert!(markdown.contains("### Parameter Tree"));
+        assert!(markdown.contains("└── feature-flags (StringList, Advanced)"));
+        assert!(markdown.contains(
+            "| /prod/app/db-password | SecureString (alias/aws/ssm) | Standard | 3 | - | - |"
+        ));
+
+        let korean = detail.to_markdown(Language::Korean);
+        assert!(korean.contains("| 파라미터 | 2 |"));
+        assert!(korean.contains("### 파라미터 트리"));
+    }
+}
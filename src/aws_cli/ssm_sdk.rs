@@ -0,0 +1,187 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::ssm::{SsmParameter, SsmParameterPathDetail, path_prefix};
+use aws_sdk_ssm::operation::describe_parameters::DescribeParametersOutput;
+use aws_sdk_ssm::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_ssm::types::ParameterMetadata;
+use std::collections::BTreeMap;
+
+/// List Parameter Store path prefixes with their parameter counts using AWS SDK
+pub fn list_parameter_paths() -> Vec<AwsResource> {
+    get_runtime().block_on(list_parameter_paths_async())
+}
+
+async fn list_parameter_paths_async() -> Vec<AwsResource> {
+    let Some(parameters) = describe_all_parameters().await else {
+        return Vec::new();
+    };
+    group_parameter_paths(&parameters)
+}
+
+/// Get parameter metadata under one path prefix using AWS SDK (values are never read)
+pub fn get_parameter_path_detail(path: &str) -> Option<SsmParameterPathDetail> {
+    get_runtime().block_on(get_parameter_path_detail_async(path))
+}
+
+async fn get_parameter_path_detail_async(path: &str) -> Option<SsmParameterPathDetail> {
+    let mut parameters: Vec<SsmParameter> = describe_all_parameters()
+        .await?
+        .into_iter()
+        .filter(|parameter| path_prefix(&parameter.name) == path)
+        .collect();
+    parameters.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(SsmParameterPathDetail {
+        path: path.to_string(),
+        parameters,
+    })
+}
+
+// DescribeParameters는 값 없이 메타데이터만 반환 (GetParameter*는 사용하지 않음)
+async fn describe_all_parameters() -> Option<Vec<SsmParameter>> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_ssm, &config);
+
+    let pages: Vec<DescribeParametersOutput> = match client
+        .describe_parameters()
+        .into_paginator()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(pages) => pages,
+        Err(e) => {
+            tracing::error!("Error describing SSM parameters: {:?}", e);
+            return None;
+        }
+    };
+
+    Some(
+        pages
+            .iter()
+            .flat_map(|page| page.parameters())
+            .map(map_parameter)
+            .collect(),
+    )
+}
+
+fn format_timestamp(timestamp: Option<&DateTime>) -> String {
+    timestamp
+        .and_then(|dt| dt.fmt(DateTimeFormat::DateTime).ok())
+        .unwrap_or_default()
+}
+
+fn map_parameter(parameter: &ParameterMetadata) -> SsmParameter {
+    SsmParameter {
+        name: parameter.name().unwrap_or_default().to_string(),
+        parameter_type: parameter
+            .r#type()
+            .map(|parameter_type| parameter_type.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        tier: parameter
+            .tier()
+            .map(|tier| tier.as_str())
+            .unwrap_or("Standard")
+            .to_string(),
+        data_type: parameter.data_type().unwrap_or_default().to_string(),
+        version: parameter.version(),
+        description: parameter.description().unwrap_or_default().to_string(),
+        key_id: parameter
+            .key_id()
+            .filter(|key_id| !key_id.is_empty())
+            .map(str::to_string),
+        last_modified: format_timestamp(parameter.last_modified_date()),
+        last_modified_user: parameter
+            .last_modified_user()
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+// 경로 접두사별로 묶어 목록에는 파라미터 수(state)와 SecureString 수(cidr)를 표시
+fn group_parameter_paths(parameters: &[SsmParameter]) -> Vec<AwsResource> {
+    let mut groups: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for parameter in parameters {
+        let (total, secure) = groups.entry(path_prefix(&parameter.name)).or_default();
+        *total += 1;
+        if parameter.parameter_type == "SecureString" {
+            *secure += 1;
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(path, (total, secure))| AwsResource {
+            name: path.clone(),
+            id: path,
+            state: total.to_string(),
+            az: String::new(),
+            cidr: secure.to_string(),
+            owner_id: String::new(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_parameter_paths, map_parameter};
+    use aws_sdk_ssm::primitives::DateTime;
+    use aws_sdk_ssm::types::{ParameterMetadata, ParameterTier, ParameterType};
+
+    #[test]
+    fn map_parameter_reads_type_tier_and_key() {
+        let metadata = ParameterMetadata::builder()
+            .name("/prod/app/db-password")
+            .r#type(ParameterType::SecureString)
+            .key_id("alias/aws/ssm")
+            .version(3)
+            .last_modified_date(DateTime::from_secs(1_767_225_600))
+            .build();
+        let parameter = map_parameter(&metadata);
+        assert_eq!(parameter.parameter_type, "SecureString");
+        // 티어가 없으면 Standard
+        assert_eq!(parameter.tier, "Standard");
+        assert_eq!(parameter.key_id.as_deref(), Some("alias/aws/ssm"));
+        assert_eq!(parameter.version, 3);
+        assert_eq!(parameter.last_modified, "2026-01-01T00:00:00Z");
+
+        let advanced = map_parameter(
+            &ParameterMetadata::builder()
+                .name("/prod/feature-flags")
+                .r#type(ParameterType::StringList)
+                .tier(ParameterTier::Advanced)
+                .build(),
+        );
+        assert_eq!(advanced.tier, "Advanced");
+        assert_eq!(advanced.key_id, None);
+    }
+
+    #[test]
+    fn group_parameter_paths_counts_per_prefix() {
+        let parameters: Vec<_> = [
+            ("/prod/app/db-password", ParameterType::SecureString),
+            ("/prod/app/url", ParameterType::String),
+            ("/dev/app/url", ParameterType::String),
+            ("legacy-flag", ParameterType::String),
+        ]
+        .into_iter()
+        .map(|(name, parameter_type)| {
+            map_parameter(
+                &ParameterMetadata::builder()
+                    .name(name)
+                    .r#type(parameter_type)
+                    .build(),
+            )
+        })
+        .collect();
+
+        let groups = group_parameter_paths(&parameters);
+        let summary: Vec<(&str, &str, &str)> = groups
+            .iter()
+            .map(|group| (group.id.as_str(), group.state.as_str(), group.cidr.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("/", "1", "0"), ("/dev", "1", "0"), ("/prod", "2", "1")]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+pub use crate::aws_cli::stepfunctions_sdk::{get_state_machine_detail, list_state_machines};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct StateMachineDetail {
+    pub name: String,
+    pub arn: String,
+    // STANDARD, EXPRESS
+    pub machine_type: String,
+    // ACTIVE, DELETING
+    pub status: String,
+    pub role_arn: String,
+    pub description: String,
+    pub created: String,
+    // OFF, ALL, ERROR, FATAL
+    pub logging_level: String,
+    pub include_execution_data: bool,
+    pub log_destinations: Vec<String>,
+    pub tracing_enabled: bool,
+    // 고객 관리형 KMS 키를 쓰지 않으면 None (AWS 소유 키)
+    pub kms_key_id: Option<String>,
+    pub start_at: String,
+    pub state_count: usize,
+    // 보기 좋게 정리한 ASL 정의 (JSON이 아니면 원본 그대로)
+    pub definition: String,
+    pub tags: Vec<(String, String)>,
+}
+
+/// Pretty-print an Amazon States Language definition and read its `StartAt` and top-level state count.
+pub fn parse_definition(definition: &str) -> (String, String, usize) {
+    match serde_json::from_str::<serde_json::Value>(definition) {
+        Ok(value) => {
+            let start_at = value
+                .get("StartAt")
+                .and_then(|start_at| start_at.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let state_count = value
+                .get("States")
+                .and_then(|states| states.as_object())
+                .map_or(0, |states| states.len());
+            let pretty =
+                serde_json::to_string_pretty(&value).unwrap_or_else(|_| definition.to_string());
+            (pretty, start_at, state_count)
+        }
+        Err(_) => (definition.to_string(), String::new(), 0),
+    }
+}
+
+impl StateMachineDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let logging = if self.logging_level.is_empty() || self.logging_level == "OFF" {
+            i18n.md_disabled().to_string()
+        } else if self.include_execution_data {
+            format!(
+                "{} ({})",
+                self.logging_level,
+                i18n.md_include_execution_data()
+            )
+        } else {
+            self.logging_level.clone()
+        };
+
+        let mut lines = vec![
+            format!(
+                "## {} ({})\n",
+                i18n.step_functions_state_machine(),
+                self.name
+            ),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ARN | {} |", self.arn),
+            format!("| {} | {} |", i18n.md_type(), self.machine_type),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+            format!("| {} | {} |", i18n.md_execution_role(), self.role_arn),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if !self.start_at.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_start_at(), self.start_at));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_state_count(),
+            self.state_count
+        ));
+        lines.push(format!("| {} | {} |", i18n.md_logging(), logging));
+        for destination in &self.log_destinations {
+            lines.push(format!("| {} | {} |", i18n.md_destination(), destination));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_tracing(),
+            if self.tracing_enabled {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_encryption(),
+            self.kms_key_id
+                .as_deref()
+                .unwrap_or_else(|| i18n.md_aws_owned_key())
+        ));
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+
+        // Definition (ASL)
+        if !self.definition.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.md_definition()));
+            lines.push("```json".to_string());
+            lines.push(self.definition.clone());
+            lines.push("```".to_string());
+        }
+
+        // Tags
+        if !self.tags.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("### {}\n", i18n.asg_tags()));
+            lines.push(format!("| {} | {} |", i18n.asg_key(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            for (key, value) in &self.tags {
+                lines.push(format!("| {} | {} |", key, value));
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StateMachineDetail, parse_definition};
+    use crate::i18n::Language;
+
+    const DEFINITION: &str = r#"{"Comment":"Order flow","StartAt":"Validate","States":{"Validate":{"Type":"Task","Resource":"arn:aws:lambda:ap-northeast-2:123456789012:function:validate","Next":"Done"},"Done":{"Type":"Succeed"}}}"#;
+
+    fn sample_detail() -> StateMachineDetail {
+        let (definition, start_at, state_count) = parse_definition(DEFINITION);
+        StateMachineDetail {
+            name: "order-flow".to_string(),
+            arn: "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow".to_string(),
+            machine_type: "STANDARD".to_string(),
+            status: "ACTIVE".to_string(),
+            role_arn: "arn:aws:iam::123456789012:role/order-flow-sfn".to_string(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            logging_level: "ERROR".to_string(),
+            include_execution_data: true,
+            log_destinations: vec![
+                "arn:aws:logs:ap-northeast-2:123456789012:log-group:/aws/states/order-flow:*"
+                    .to_string(),
+            ],
+            tracing_enabled: false,
+            kms_key_id: None,
+            start_at,
+            state_count,
+            definition,
+            tags: vec![("Team".to_string(), "orders".to_string())],
+        }
+    }
+
+    #[test]
+    fn parse_definition_pretty_prints_and_reads_start_state() {
+        let (definition, start_at, state_count) = parse_definition(DEFINITION);
+        assert!(definition.contains("\n  \"StartAt\": \"Validate\""));
+        assert_eq!(start_at, "Validate");
+        assert_eq!(state_count, 2);
+
+        // JSON이 아니면 원본 유지
+        assert_eq!(
+            parse_definition("not json"),
+            ("not json".to_string(), String::new(), 0)
+        );
+    }
+
+    #[test]
+    fn scenario_state_machine_markdown_render() {
+        let markdown = sample_detail().to_markdown(Language::English);
+        assert!(markdown.contains("## Step Functions State Machine (order-flow)"));
+        assert!(markdown.contains("| Type | STANDARD |"));
+        assert!(
+            markdown.contains("| Execution Role | arn:aws:iam::123456789012:role/order-flow-sfn |")
+        );
+        assert!(markdown.contains("| Start State | Validate |"));
+        assert!(markdown.contains("| States | 2 |"));
+        assert!(markdown.contains("| Logging | ERROR (Include Execution Data) |"));
+        assert!(markdown.contains("| X-Ray Tracing | Disabled |"));
+        assert!(markdown.contains("| Encryption | AWS owned key |"));
+        assert!(markdown.contains("### Definition (ASL)\n\n```json\n{\n"));
+        assert!(markdown.contains("| Team | orders |"));
+
+        let mut detail = sample_detail();
+        detail.logging_level = "OFF".to_string();
+        detail.kms_key_id = Some("alias/sfn".to_string());
+        let markdown = detail.to_markdown(Language::Korean);
+        assert!(markdown.contains("| 로깅 | 비활성화 |"));
+        assert!(markdown.contains("| 암호화 | alias/sfn |"));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::aws_cli::common::{AwsResource, get_runtime, get_sdk_config, sdk_client};
+use crate::aws_cli::stepfunctions::{StateMachineDetail, parse_definition};
+use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+use aws_sdk_sfn::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_sfn::types::{EncryptionType, StateMachineListItem};
+
+/// List Step Functions state machines using AWS SDK
+pub fn list_state_machines() -> Vec<AwsResource> {
+    get_runtime().block_on(list_state_machines_async())
+}
+
+async fn list_state_machines_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sfn, &config);
+
+    let mut resources: Vec<AwsResource> = match client
+        .list_state_machines()
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await
+    {
+        Ok(state_machines) => state_machines
+            .iter()
+            .map(map_state_machine_resource)
+            .collect(),
+        Err(e) => {
+            tracing::error!("Error listing state machines: {:?}", e);
+            Vec::new()
+        }
+    };
+    resources.sort_by(|a, b| a.name.cmp(&b.name));
+    resources
+}
+
+/// Get state machine detail (ASL definition, execution role, logging) using AWS SDK
+pub fn get_state_machine_detail(state_machine_arn: &str) -> Option<StateMachineDetail> {
+    get_runtime().block_on(get_state_machine_detail_async(state_machine_arn))
+}
+
+async fn get_state_machine_detail_async(state_machine_arn: &str) -> Option<StateMachineDetail> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_sfn, &config);
+
+    let output = match client
+        .describe_state_machine()
+        .state_machine_arn(state_machine_arn)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!(
+                "Error describing state machine {}: {:?}",
+                state_machine_arn,
+                e
+            );
+            return None;
+        }
+    };
+    let mut detail = map_state_machine_detail(&output);
+
+    // 태그 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    match client
+        .list_tags_for_resource()
+        .resource_arn(state_machine_arn)
+        .send()
+        .await
+    {
+        Ok(output) => {
+            let mut tags: Vec<(String, String)> = output
+                .tags()
+                .iter()
+                .filter_map(|tag| {
+                    Some((
+                        tag.key()?.to_string(),
+                        tag.value().unwrap_or_default().to_string(),
+                    ))
+                })
+                .collect();
+            tags.sort();
+            detail.tags = tags;
+        }
+        Err(e) => tracing::warn!("Error getting tags for {}: {:?}", state_machine_arn, e),
+    }
+
+    Some(detail)
+}
+
+fn format_timestamp(timestamp: &DateTime) -> String {
+    timestamp.fmt(DateTimeFormat::DateTime).unwrap_or_default()
+}
+
+// 목록에는 유형(state)을 표시하고, 상세 조회는 ARN으로 함
+fn map_state_machine_resource(state_machine: &StateMachineListItem) -> AwsResource {
+    AwsResource {
+        name: state_machine.name().to_string(),
+        id: state_machine.state_machine_arn().to_string(),
+        state: state_machine.r#type().as_str().to_string(),
+        az: String::new(),
+        cidr: format_timestamp(state_machine.creation_date()),
+        owner_id: String::new(),
+    }
+}
+
+fn map_state_machine_detail(output: &DescribeStateMachineOutput) -> StateMachineDetail {
+    let (definition, start_at, state_count) = parse_definition(output.definition());
+    let logging = output.logging_configuration();
+    let kms_key_id = output
+        .encryption_configuration()
+        .filter(|encryption| *encryption.r#type() == EncryptionType::CustomerManagedKmsKey)
+        .map(|encryption| encryption.kms_key_id().unwrap_or_default().to_string());
+
+    StateMachineDetail {
+        name: output.name().to_string(),
+        arn: output.state_machine_arn().to_string(),
+        machine_type: output.r#type().as_str().to_string(),
+        status: output
+            .status()
+            .map(|status| status.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        role_arn: output.role_arn().to_string(),
+        description: output.description().unwrap_or_default().to_string(),
+        created: format_timestamp(output.creation_date()),
+        logging_level: logging
+            .and_then(|logging| logging.level())
+            .map(|level| level.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        include_execution_data: logging.is_some_and(|logging| logging.include_execution_data()),
+        log_destinations: logging
+            .map(|logging| {
+                logging
+                    .destinations()
+                    .iter()
+                    .filter_map(|destination| {
+                        destination
+                            .cloud_watch_logs_log_group()?
+                            .log_group_arn()
+                            .map(str::to_string)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        tracing_enabled: output
+            .tracing_configuration()
+            .is_some_and(|tracing| tracing.enabled()),
+        kms_key_id,
+        start_at,
+        state_count,
+        definition,
+        tags: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_state_machine_detail, map_state_machine_resource};
+    use aws_sdk_sfn::operation::describe_state_machine::DescribeStateMachineOutput;
+    use aws_sdk_sfn::primitives::DateTime;
+    use aws_sdk_sfn::types::{
+        CloudWatchLogsLogGroup, EncryptionConfiguration, EncryptionType, LogDestination, LogLevel,
+        LoggingConfiguration, StateMachineListItem, StateMachineStatus, StateMachineType,
+        TracingConfiguration,
+    };
+
+    const ARN: &str = "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow";
+
+    #[test]
+    fn map_state_machine_resource_uses_arn_as_id() {
+        let resource = map_state_machine_resource(
+            &StateMachineListItem::builder()
+                .state_machine_arn(ARN)
+                .name("order-flow")
+                .r#type(StateMachineType::Express)
+                .creation_date(DateTime::from_secs(1_767_225_600))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(resource.name, "order-flow");
+        assert_eq!(resource.id, ARN);
+        assert_eq!(resource.state, "EXPRESS");
+        assert_eq!(resource.cidr, "2026-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn map_state_machine_detail_reads_logging_tracing_and_encryption() {
+        let output = DescribeStateMachineOutput::builder()
+            .state_machine_arn(ARN)
+            .name("order-flow")
+            .status(StateMachineStatus::Active)
+            .definition(r#"{"StartAt":"Validate","States":{"Validate":{"Type":"Pass","End":true}}}"#)
+            .role_arn("arn:aws:iam::123456789012:role/order-flow-sfn")
+            .r#type(StateMachineType::Standard)
+            .creation_date(DateTime::from_secs(1_767_225_600))
+            .logging_configuration(
+                LoggingConfiguration::builder()
+                    .level(LogLevel::Error)
+                    .include_execution_data(true)
+                    .destinations(
+                        LogDestination::builder()
+                            .cloud_watch_logs_log_group(
+                                CloudWatchLogsLogGroup::builder()
+                                    .log_group_arn("arn:aws:logs:ap-northeast-2:123456789012:log-group:/aws/states/order-flow:*")
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            )
+            .tracing_configuration(TracingConfiguration::builder().enabled(true).build())
+            .encryption_configuration(
+                EncryptionConfiguration::builder()
+                    .r#type(EncryptionType::CustomerManagedKmsKey)
+                    .kms_key_id("alias/sfn")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let detail = map_state_machine_detail(&output);
+        assert_eq!(detail.machine_type, "STANDARD");
+        assert_eq!(detail.status, "ACTIVE");
+        assert_eq!(detail.start_at, "Validate");
+        assert_eq!(detail.state_count, 1);
+        assert_eq!(detail.logging_level, "ERROR");
+        assert!(detail.include_execution_data);
+        assert_eq!(detail.log_destinations.len(), 1);
+        assert!(detail.tracing_enabled);
+        assert_eq!(detail.kms_key_id.as_deref(), Some("alias/sfn"));
+        assert_eq!(detail.created, "2026-01-01T00:00:00Z");
+    }
+}
@@ -0,0 +1,25 @@
+use crate::aws_cli::partition::Partition;
+pub use crate::aws_cli::tagging_sdk::{get_resources_by_tags, set_name_tag};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedResource {
+    pub arn: String,
+    pub name: Option<String>,
+}
+
+/// Name tag seen for `id` in the current region's resource lists.
+pub fn indexed_name(id: &str) -> Option<String> {
+    crate::aws_cli::name_index::lookup(id)
+}
+
+/// ARN of an EC2 resource (`instance`, `vpc`, `security-group`) for the Tagging API.
+pub fn ec2_resource_arn(kind: &str, id: &str, region: &str, account: &str) -> String {
+    format!(
+        "arn:{}:ec2:{}:{}:{}/{}",
+        Partition::from_region(region).as_str(),
+        region,
+        account,
+        kind,
+        id
+    )
+}
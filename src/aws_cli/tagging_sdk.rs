@@ -0,0 +1,147 @@
+use crate::aws_cli::common::{
+    current_account_id, current_region, get_runtime, get_sdk_config, sdk_client,
+};
+use crate::aws_cli::name_index;
+use crate::aws_cli::tagging::{TaggedResource, ec2_resource_arn};
+use aws_sdk_resourcegroupstagging::error::ProvideErrorMetadata;
+use aws_sdk_resourcegroupstagging::types::{ResourceTagMapping, TagFilter};
+
+/// Find resources matching all tag filters using the Resource Groups Tagging API
+pub fn get_resources_by_tags(
+    filters: &[(String, Vec<String>)],
+) -> Result<Vec<TaggedResource>, String> {
+    get_runtime().block_on(get_resources_by_tags_async(filters))
+}
+
+async fn get_resources_by_tags_async(
+    filters: &[(String, Vec<String>)],
+) -> Result<Vec<TaggedResource>, String> {
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_resourcegroupstagging, &config);
+
+    let tag_filters: Vec<TagFilter> = filters
+        .iter()
+        .map(|(key, values)| {
+            TagFilter::builder()
+                .key(key)
+                .set_values((!values.is_empty()).then(|| values.clone()))
+                .build()
+        })
+        .collect();
+
+    let result = client
+        .get_resources()
+        .set_tag_filters(Some(tag_filters))
+        .into_paginator()
+        .items()
+        .send()
+        .try_collect()
+        .await;
+
+    match result {
+        Ok(mappings) => Ok(mappings.iter().filter_map(map_tagged_resource).collect()),
+        Err(e) => {
+            tracing::error!("Error querying tagged resources: {:?}", e);
+            Err(e
+                .message()
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| e.to_string()))
+        }
+    }
+}
+
+/// Set the Name tag of one EC2 resource (only allowed when started with `--allow-write`)
+pub fn set_name_tag(kind: &str, id: &str, name: &str) -> Result<(), String> {
+    get_runtime().block_on(set_name_tag_async(kind, id, name))
+}
+
+async fn set_name_tag_async(kind: &str, id: &str, name: &str) -> Result<(), String> {
+    let account =
+        current_account_id().ok_or_else(|| "AWS account ID is not known yet".to_string())?;
+    let arn = ec2_resource_arn(kind, id, &current_region(), &account);
+
+    let config = get_sdk_config().await;
+    let client = sdk_client!(aws_sdk_resourcegroupstagging, &config);
+
+    // 읽기 전용 가드가 단일 리소스의 Name 태그 요청인지 다시 확인함
+    let output = client
+        .tag_resources()
+        .resource_arn_list(&arn)
+        .tags("Name", name)
+        .send()
+        .await
+        .map_err(|e| {
+            tracing::error!("Error setting Name tag on {}: {:?}", arn, e);
+            e.message()
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| e.to_string())
+        })?;
+
+    // 리소스별 실패는 오류 대신 FailedResourcesMap으로 반환됨
+    if let Some(failure) = output
+        .failed_resources_map()
+        .and_then(|failed| failed.get(&arn))
+    {
+        return Err(failure
+            .error_message()
+            .unwrap_or("TagResources failed")
+            .to_string());
+    }
+
+    tracing::info!(arn = %arn, name = %name, "Updated Name tag");
+    name_index::record(id, name);
+    Ok(())
+}
+
+fn map_tagged_resource(mapping: &ResourceTagMapping) -> Option<TaggedResource> {
+    let arn = mapping.resource_arn().filter(|arn| !arn.is_empty())?;
+    let name = mapping
+        .tags()
+        .iter()
+        .find(|tag| tag.key() == "Name")
+        .map(|tag| tag.value().to_string())
+        .filter(|name| !name.is_empty());
+
+    Some(TaggedResource {
+        arn: arn.to_string(),
+        name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ec2_resource_arn, map_tagged_resource};
+    use aws_sdk_resourcegroupstagging::types::ResourceTagMapping;
+
+    #[test]
+    fn map_tagged_resource_keeps_arn_without_name_tag() {
+        let mapping = ResourceTagMapping::builder()
+            .resource_arn("arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123")
+            .build();
+
+        let resource = map_tagged_resource(&mapping).expect("tagged resource");
+        assert_eq!(
+            resource.arn,
+            "arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123"
+        );
+        assert_eq!(resource.name, None);
+    }
+
+    #[test]
+    fn ec2_resource_arn_uses_partition_of_region() {
+        assert_eq!(
+            ec2_resource_arn("instance", "i-0123", "ap-northeast-2", "123456789012"),
+            "arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123"
+        );
+        assert_eq!(
+            ec2_resource_arn("vpc", "vpc-1", "cn-north-1", "123456789012"),
+            "arn:aws-cn:ec2:cn-north-1:123456789012:vpc/vpc-1"
+        );
+    }
+
+    #[test]
+    fn map_tagged_resource_skips_mapping_without_arn() {
+        let mapping = ResourceTagMapping::builder().build();
+        assert!(map_tagged_resource(&mapping).is_none());
+    }
+}
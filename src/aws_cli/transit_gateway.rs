@@ -0,0 +1,794 @@
+use crate::aws_cli::common::{AwsResource, Tag, foreign_owner, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewaysResponse {
+    transit_gateways: Vec<TransitGatewayEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayEntry {
+    transit_gateway_id: String,
+    state: String,
+    #[serde(default)]
+    owner_id: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    creation_time: String,
+    #[serde(default)]
+    options: Option<TransitGatewayOptions>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayOptions {
+    #[serde(default)]
+    amazon_side_asn: Option<i64>,
+    #[serde(default)]
+    transit_gateway_cidr_blocks: Vec<String>,
+    #[serde(default)]
+    auto_accept_shared_attachments: String,
+    #[serde(default)]
+    association_default_route_table_id: Option<String>,
+    #[serde(default)]
+    propagation_default_route_table_id: Option<String>,
+    #[serde(default)]
+    vpn_ecmp_support: String,
+    #[serde(default)]
+    dns_support: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayAttachmentsResponse {
+    transit_gateway_attachments: Vec<TransitGatewayAttachmentEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayAttachmentEntry {
+    transit_gateway_attachment_id: String,
+    #[serde(default)]
+    resource_owner_id: String,
+    #[serde(default)]
+    resource_type: String,
+    #[serde(default)]
+    resource_id: String,
+    state: String,
+    #[serde(default)]
+    association: Option<TransitGatewayAssociation>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayAssociation {
+    transit_gateway_route_table_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayRouteTablesResponse {
+    transit_gateway_route_tables: Vec<TransitGatewayRouteTableEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayRouteTableEntry {
+    transit_gateway_route_table_id: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayRoutesResponse {
+    routes: Vec<TransitGatewayRouteEntry>,
+    #[serde(default)]
+    additional_routes_available: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayRouteEntry {
+    #[serde(default)]
+    destination_cidr_block: String,
+    #[serde(default)]
+    prefix_list_id: String,
+    #[serde(default)]
+    transit_gateway_attachments: Vec<TransitGatewayRouteAttachment>,
+    #[serde(default, rename = "Type")]
+    route_type: String,
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TransitGatewayRouteAttachment {
+    transit_gateway_attachment_id: String,
+    #[serde(default)]
+    resource_id: String,
+    #[serde(default)]
+    resource_type: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransitGatewayDetail {
+    pub name: String,
+    pub id: String,
+    // available, pending, modifying, deleting, deleted
+    pub state: String,
+    pub owner_id: String,
+    pub description: String,
+    pub amazon_side_asn: Option<i64>,
+    pub cidr_blocks: Vec<String>,
+    // enable, disable
+    pub dns_support: String,
+    pub vpn_ecmp_support: String,
+    pub auto_accept_shared_attachments: String,
+    // 기본 연결/전파를 끄면 None
+    pub association_default_route_table: Option<String>,
+    pub propagation_default_route_table: Option<String>,
+    pub created: String,
+    pub attachments: Vec<TransitGatewayAttachmentInfo>,
+    pub route_tables: Vec<TransitGatewayRouteTableInfo>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransitGatewayAttachmentInfo {
+    pub name: String,
+    pub id: String,
+    // vpc, vpn, peering, direct-connect-gateway, connect, ...
+    pub resource_type: String,
+    pub resource_id: String,
+    pub resource_owner_id: String,
+    pub state: String,
+    // 연결(association)된 TGW 라우팅 테이블
+    pub route_table_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransitGatewayRouteTableInfo {
+    pub name: String,
+    pub id: String,
+    pub routes: Vec<TransitGatewayRouteInfo>,
+    // 조회 한도를 넘어 일부 라우트만 받은 경우
+    pub routes_truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransitGatewayRouteInfo {
+    // CIDR 또는 접두사 목록 ID
+    pub destination: String,
+    // (연결 ID, 리소스 유형, 리소스 ID), blackhole이면 비어 있음
+    pub attachments: Vec<(String, String, String)>,
+    // static, propagated
+    pub route_type: String,
+    // active, blackhole
+    pub state: String,
+}
+
+impl TransitGatewayDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        };
+        let default_route_table = |route_table: &Option<String>| match route_table {
+            Some(route_table_id) => self.route_table_label(route_table_id),
+            None => "disable".to_string(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.transit_gateway(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        if let Some(asn) = self.amazon_side_asn {
+            lines.push(format!("| {} | {} |", i18n.md_amazon_side_asn(), asn));
+        }
+        if !self.cidr_blocks.is_empty() {
+            lines.push(format!("| CIDR | {} |", self.cidr_blocks.join(", ")));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_dns_support(),
+            self.dns_support
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_vpn_ecmp_support(),
+            self.vpn_ecmp_support
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_auto_accept_shared_attachments(),
+            self.auto_accept_shared_attachments
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_default_association_route_table(),
+            default_route_table(&self.association_default_route_table)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_default_propagation_route_table(),
+            default_route_table(&self.propagation_default_route_table)
+        ));
+        if let Some(owner) = foreign_owner(&self.owner_id) {
+            lines.push(format!("| {} | {} |", i18n.md_owner_account(), owner));
+        }
+        if !self.created.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_created_at(), self.created));
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        if !self.attachments.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_tgw_attachments()));
+            lines.push(format!(
+                "| {} | ID | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_type(),
+                i18n.md_resources(),
+                i18n.md_state(),
+                i18n.md_route_tables()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|".to_string());
+            for attachment in &self.attachments {
+                // 다른 계정의 VPC를 연결한 경우 소유 계정을 함께 표시
+                let resource = match foreign_owner(&attachment.resource_owner_id) {
+                    Some(owner) => format!(
+                        "{} [owner: {}]",
+                        name_index::label(&attachment.resource_id),
+                        owner
+                    ),
+                    None => name_index::label(&attachment.resource_id),
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    if attachment.name.is_empty() {
+                        "-"
+                    } else {
+                        attachment.name.as_str()
+                    },
+                    attachment.id,
+                    attachment.resource_type,
+                    resource,
+                    attachment.state,
+                    attachment
+                        .route_table_id
+                        .as_deref()
+                        .map(|route_table_id| self.route_table_label(route_table_id))
+                        .unwrap_or_else(|| "-".to_string())
+                ));
+            }
+        }
+
+        if !self.route_tables.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_route_tables()));
+            for route_table in &self.route_tables {
+                lines.push(format!(
+                    "\n#### {}",
+                    self.route_table_label(&route_table.id)
+                ));
+                if route_table.routes.is_empty() {
+                    lines.push("-".to_string());
+                    continue;
+                }
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    i18n.md_destination(),
+                    i18n.md_target(),
+                    i18n.md_type(),
+                    i18n.md_state()
+                ));
+                lines.push("|:---|:---|:---|:---|".to_string());
+                for route in &route_table.routes {
+                    let target = if route.attachments.is_empty() {
+                        "-".to_string()
+                    } else {
+                        route
+                            .attachments
+                            .iter()
+                            .map(|(attachment_id, resource_type, resource_id)| {
+                                format!(
+                                    "{} ({}: {})",
+                                    attachment_id,
+                                    resource_type,
+                                    name_index::label(resource_id)
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    lines.push(format!(
+                        "| {} | {} | {} | {} |",
+                        route.destination, target, route.route_type, route.state
+                    ));
+                }
+                if route_table.routes_truncated {
+                    lines.push(format!("\n> {}", i18n.md_tgw_routes_truncated()));
+                }
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    // 이름 태그가 있는 라우팅 테이블은 `이름 - ID`로 표시
+    fn route_table_label(&self, route_table_id: &str) -> String {
+        self.route_tables
+            .iter()
+            .find(|route_table| route_table.id == route_table_id && !route_table.name.is_empty())
+            .map(|route_table| format!("{} - {}", route_table.name, route_table.id))
+            .unwrap_or_else(|| route_table_id.to_string())
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+pub fn list_transit_gateways() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&["ec2", "describe-transit-gateways", "--output", "json"]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let transit_gateways = parse_transit_gateways_list_output(&output).unwrap_or_default();
+    name_index::record_resources(&transit_gateways);
+    transit_gateways
+}
+
+fn parse_transit_gateways_list_output(output: &str) -> Option<Vec<AwsResource>> {
+    let response: TransitGatewaysResponse = serde_json::from_str(output).ok()?;
+
+    let mut transit_gateways: Vec<AwsResource> = response
+        .transit_gateways
+        .into_iter()
+        // 삭제된 TGW는 한동안 조회 결과에 남음
+        .filter(|tgw| tgw.state != "deleted")
+        .map(|tgw| {
+            let name = name_tag(&tgw.tags);
+            AwsResource {
+                name: if name.is_empty() {
+                    tgw.transit_gateway_id.clone()
+                } else {
+                    name
+                },
+                id: tgw.transit_gateway_id,
+                state: tgw.state,
+                az: String::new(),
+                cidr: tgw
+                    .options
+                    .and_then(|options| options.amazon_side_asn)
+                    .map(|asn| format!("ASN {}", asn))
+                    .unwrap_or_default(),
+                owner_id: tgw.owner_id,
+            }
+        })
+        .collect();
+    transit_gateways.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(transit_gateways)
+}
+
+pub fn get_transit_gateway_detail(tgw_id: &str) -> Option<TransitGatewayDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-transit-gateways",
+        "--transit-gateway-ids",
+        tgw_id,
+        "--output",
+        "json",
+    ])?;
+    let mut detail = parse_transit_gateway_detail_output(&output)?;
+
+    // 연결과 라우팅 테이블 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    let filter = format!("Name=transit-gateway-id,Values={}", tgw_id);
+    detail.attachments = run_aws_cli(&[
+        "ec2",
+        "describe-transit-gateway-attachments",
+        "--filters",
+        &filter,
+        "--output",
+        "json",
+    ])
+    .and_then(|output| parse_transit_gateway_attachments_output(&output))
+    .unwrap_or_default();
+
+    detail.route_tables = run_aws_cli(&[
+        "ec2",
+        "describe-transit-gateway-route-tables",
+        "--filters",
+        &filter,
+        "--output",
+        "json",
+    ])
+    .and_then(|output| parse_transit_gateway_route_tables_output(&output))
+    .unwrap_or_default();
+
+    for route_table in &mut detail.route_tables {
+        if let Some((routes, truncated)) = run_aws_cli(&[
+            "ec2",
+            "search-transit-gateway-routes",
+            "--transit-gateway-route-table-id",
+            &route_table.id,
+            "--filters",
+            "Name=state,Values=active,blackhole",
+            "--output",
+            "json",
+        ])
+        .and_then(|output| parse_transit_gateway_routes_output(&output))
+        {
+            route_table.routes = routes;
+            route_table.routes_truncated = truncated;
+        }
+    }
+
+    Some(detail)
+}
+
+fn parse_transit_gateway_detail_output(output: &str) -> Option<TransitGatewayDetail> {
+    let response: TransitGatewaysResponse = serde_json::from_str(output).ok()?;
+    let tgw = response.transit_gateways.into_iter().next()?;
+    let options = tgw.options.unwrap_or_default();
+
+    let mut tags: Vec<(String, String)> = tgw
+        .tags
+        .iter()
+        .map(|t| (t.key.clone(), t.value.clone()))
+        .collect();
+    tags.sort();
+
+    Some(TransitGatewayDetail {
+        name: name_tag(&tgw.tags),
+        id: tgw.transit_gateway_id,
+        state: tgw.state,
+        owner_id: tgw.owner_id,
+        description: tgw.description,
+        amazon_side_asn: options.amazon_side_asn,
+        cidr_blocks: options.transit_gateway_cidr_blocks,
+        dns_support: options.dns_support,
+        vpn_ecmp_support: options.vpn_ecmp_support,
+        auto_accept_shared_attachments: options.auto_accept_shared_attachments,
+        association_default_route_table: options
+            .association_default_route_table_id
+            .filter(|id| !id.is_empty()),
+        propagation_default_route_table: options
+            .propagation_default_route_table_id
+            .filter(|id| !id.is_empty()),
+        created: tgw.creation_time,
+        attachments: Vec::new(),
+        route_tables: Vec::new(),
+        tags,
+    })
+}
+
+fn parse_transit_gateway_attachments_output(
+    output: &str,
+) -> Option<Vec<TransitGatewayAttachmentInfo>> {
+    let response: TransitGatewayAttachmentsResponse = serde_json::from_str(output).ok()?;
+
+    let mut attachments: Vec<TransitGatewayAttachmentInfo> = response
+        .transit_gateway_attachments
+        .into_iter()
+        .filter(|attachment| attachment.state != "deleted")
+        .map(|attachment| TransitGatewayAttachmentInfo {
+            name: name_tag(&attachment.tags),
+            id: attachment.transit_gateway_attachment_id,
+            resource_type: attachment.resource_type,
+            resource_id: attachment.resource_id,
+            resource_owner_id: attachment.resource_owner_id,
+            state: attachment.state,
+            route_table_id: attachment
+                .association
+                .map(|association| association.transit_gateway_route_table_id)
+                .filter(|id| !id.is_empty()),
+        })
+        .collect();
+    // VPC, VPN, 피어링 순으로 모아 보기 쉽게 정렬
+    attachments.sort_by(|a, b| {
+        (a.resource_type.as_str(), a.id.as_str()).cmp(&(b.resource_type.as_str(), b.id.as_str()))
+    });
+    Some(attachments)
+}
+
+fn parse_transit_gateway_route_tables_output(
+    output: &str,
+) -> Option<Vec<TransitGatewayRouteTableInfo>> {
+    let response: TransitGatewayRouteTablesResponse = serde_json::from_str(output).ok()?;
+
+    Some(
+        response
+            .transit_gateway_route_tables
+            .into_iter()
+            .map(|route_table| TransitGatewayRouteTableInfo {
+                name: name_tag(&route_table.tags),
+                id: route_table.transit_gateway_route_table_id,
+                routes: Vec::new(),
+                routes_truncated: false,
+            })
+            .collect(),
+    )
+}
+
+fn parse_transit_gateway_routes_output(
+    output: &str,
+) -> Option<(Vec<TransitGatewayRouteInfo>, bool)> {
+    let response: TransitGatewayRoutesResponse = serde_json::from_str(output).ok()?;
+
+    let routes = response
+        .routes
+        .into_iter()
+        .map(|route| TransitGatewayRouteInfo {
+            destination: if route.destination_cidr_block.is_empty() {
+                route.prefix_list_id
+            } else {
+                route.destination_cidr_block
+            },
+            attachments: route
+                .transit_gateway_attachments
+                .into_iter()
+                .map(|attachment| {
+                    (
+                        attachment.transit_gateway_attachment_id,
+                        attachment.resource_type,
+                        attachment.resource_id,
+                    )
+                })
+                .collect(),
+            route_type: route.route_type,
+            state: route.state,
+        })
+        .collect();
+    Some((routes, response.additional_routes_available))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TransitGatewayAttachmentInfo, TransitGatewayDetail, TransitGatewayRouteInfo,
+        TransitGatewayRouteTableInfo, parse_transit_gateway_attachments_output,
+        parse_transit_gateway_detail_output, parse_transit_gateway_route_tables_output,
+        parse_transit_gateway_routes_output, parse_transit_gateways_list_output,
+    };
+    use crate::i18n::Language;
+
+    #[test]
+    fn parse_transit_gateways_list_output_uses_name_tag_and_skips_deleted() {
+        let payload = r#"
+        {
+          "TransitGateways": [
+            {
+              "TransitGatewayId": "tgw-2",
+              "State": "available",
+              "OwnerId": "111122223333",
+              "Options": {"AmazonSideAsn": 64512},
+              "Tags": []
+            },
+            {
+              "TransitGatewayId": "tgw-1",
+              "State": "available",
+              "OwnerId": "111122223333",
+              "Options": {"AmazonSideAsn": 64513},
+              "Tags": [{"Key":"Name","Value":"core-tgw"}]
+            },
+            {
+              "TransitGatewayId": "tgw-3",
+              "State": "deleted",
+              "Tags": []
+            }
+          ]
+        }
+        "#;
+        let transit_gateways = parse_transit_gateways_list_output(payload).expect("tgws");
+        assert_eq!(transit_gateways.len(), 2);
+        assert_eq!(transit_gateways[0].name, "core-tgw");
+        assert_eq!(transit_gateways[0].cidr, "ASN 64513");
+        assert_eq!(transit_gateways[1].name, "tgw-2");
+        assert_eq!(transit_gateways[1].owner_id, "111122223333");
+    }
+
+    #[test]
+    fn parse_transit_gateway_outputs_build_attachments_and_routes() {
+        let tgw_payload = r#"
+        {
+          "TransitGateways": [
+            {
+              "TransitGatewayId": "tgw-1",
+              "State": "available",
+              "OwnerId": "111122223333",
+              "Description": "hub",
+              "CreationTime": "2026-01-01T00:00:00Z",
+              "Options": {
+                "AmazonSideAsn": 64512,
+                "TransitGatewayCidrBlocks": [],
+                "AutoAcceptSharedAttachments": "disable",
+                "DefaultRouteTableAssociation": "enable",
+                "AssociationDefaultRouteTableId": "tgw-rtb-1",
+                "DefaultRouteTablePropagation": "disable",
+                "PropagationDefaultRouteTableId": null,
+                "VpnEcmpSupport": "enable",
+                "DnsSupport": "enable"
+              },
+              "Tags": [{"Key":"Name","Value":"core-tgw"}]
+            }
+          ]
+        }
+        "#;
+        let detail = parse_transit_gateway_detail_output(tgw_payload).expect("detail");
+        assert_eq!(detail.name, "core-tgw");
+        assert_eq!(detail.amazon_side_asn, Some(64512));
+        assert_eq!(
+            detail.association_default_route_table.as_deref(),
+            Some("tgw-rtb-1")
+        );
+        assert!(detail.propagation_default_route_table.is_none());
+
+        let attachments_payload = r#"
+        {
+          "TransitGatewayAttachments": [
+            {
+              "TransitGatewayAttachmentId": "tgw-attach-2",
+              "ResourceOwnerId": "111122223333",
+              "ResourceType": "vpn",
+              "ResourceId": "vpn-1",
+              "State": "available",
+              "Association": {"TransitGatewayRouteTableId": "tgw-rtb-1", "State": "associated"}
+            },
+            {
+              "TransitGatewayAttachmentId": "tgw-attach-1",
+              "ResourceOwnerId": "444455556666",
+              "ResourceType": "vpc",
+              "ResourceId": "vpc-1",
+              "State": "available",
+              "Association": null,
+              "Tags": [{"Key":"Name","Value":"app-vpc-attach"}]
+            }
+          ]
+        }
+        "#;
+        let attachments =
+            parse_transit_gateway_attachments_output(attachments_payload).expect("attachments");
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].id, "tgw-attach-1");
+        assert_eq!(attachments[0].name, "app-vpc-attach");
+        assert!(attachments[0].route_table_id.is_none());
+        assert_eq!(attachments[1].route_table_id.as_deref(), Some("tgw-rtb-1"));
+
+        let route_tables = parse_transit_gateway_route_tables_output(
+            r#"{"TransitGatewayRouteTables":[{"TransitGatewayRouteTableId":"tgw-rtb-1","Tags":[{"Key":"Name","Value":"shared"}]}]}"#,
+        )
+        .expect("route tables");
+        assert_eq!(route_tables[0].name, "shared");
+
+        let routes_payload = r#"
+        {
+          "Routes": [
+            {
+              "DestinationCidrBlock": "10.1.0.0/16",
+              "TransitGatewayAttachments": [
+                {"TransitGatewayAttachmentId": "tgw-attach-1", "ResourceId": "vpc-1", "ResourceType": "vpc"}
+              ],
+              "Type": "propagated",
+              "State": "active"
+            },
+            {
+              "PrefixListId": "pl-1",
+              "Type": "static",
+              "State": "blackhole"
+            }
+          ],
+          "AdditionalRoutesAvailable": true
+        }
+        "#;
+        let (routes, truncated) =
+            parse_transit_gateway_routes_output(routes_payload).expect("routes");
+        assert!(truncated);
+        assert_eq!(routes[0].route_type, "propagated");
+        assert_eq!(
+            routes[0].attachments[0],
+            (
+                "tgw-attach-1".to_string(),
+                "vpc".to_string(),
+                "vpc-1".to_string()
+            )
+        );
+        assert_eq!(routes[1].destination, "pl-1");
+        assert!(routes[1].attachments.is_empty());
+    }
+
+    #[test]
+    fn transit_gateway_markdown_lists_attachments_and_route_tables() {
+        let detail = TransitGatewayDetail {
+            name: "core-tgw".to_string(),
+            id: "tgw-1".to_string(),
+            state: "available".to_string(),
+            owner_id: String::new(),
+            description: "hub".to_string(),
+            amazon_side_asn: Some(64512),
+            cidr_blocks: vec![],
+            dns_support: "enable".to_string(),
+            vpn_ecmp_support: "enable".to_string(),
+            auto_accept_shared_attachments: "disable".to_string(),
+            association_default_route_table: Some("tgw-rtb-1".to_string()),
+            propagation_default_route_table: None,
+            created: String::new(),
+            attachments: vec![TransitGatewayAttachmentInfo {
+                name: String::new(),
+                id: "tgw-attach-1".to_string(),
+                resource_type: "vpc".to_string(),
+                resource_id: "vpc-tgw-md-1".to_string(),
+                resource_owner_id: String::new(),
+                state: "available".to_string(),
+                route_table_id: Some("tgw-rtb-1".to_string()),
+            }],
+            route_tables: vec![TransitGatewayRouteTableInfo {
+                name: "shared".to_string(),
+                id: "tgw-rtb-1".to_string(),
+                routes: vec![
+                    TransitGatewayRouteInfo {
+                        destination: "10.1.0.0/16".to_string(),
+                        attachments: vec![(
+                            "tgw-attach-1".to_string(),
+                            "vpc".to_string(),
+                            "vpc-tgw-md-1".to_string(),
+                        )],
+                        route_type: "propagated".to_string(),
+                        state: "active".to_string(),
+                    },
+                    TransitGatewayRouteInfo {
+                        destination: "10.9.0.0/16".to_string(),
+                        attachments: vec![],
+                        route_type: "static".to_string(),
+                        state: "blackhole".to_string(),
+                    },
+                ],
+                routes_truncated: false,
+            }],
+            tags: vec![("Name".to_string(), "core-tgw".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Transit Gateway (core-tgw - tgw-1)"));
+        assert!(md.contains("| Amazon Side ASN | 64512 |"));
+        assert!(md.contains("| Default Association Route Table | shared - tgw-rtb-1 |"));
+        assert!(md.contains("| Default Propagation Route Table | disable |"));
+        assert!(md.contains(
+            "| - | tgw-attach-1 | vpc | vpc-tgw-md-1 | available | shared - tgw-rtb-1 |"
+        ));
+        assert!(md.contains("#### shared - tgw-rtb-1"));
+        assert!(
+            md.contains("| 10.1.0.0/16 | tgw-attach-1 (vpc: vpc-tgw-md-1) | propagated | active |")
+        );
+        assert!(md.contains("| 10.9.0.0/16 | - | static | blackhole |"));
+        assert!(!md.contains("Tag-Name"));
+    }
+}
@@ -1,8 +1,16 @@
 use crate::aws_cli::common::{
-    AwsResource, Tag, extract_json_value, extract_tags, parse_name_tag, parse_resources_from_json,
+    AwsResource, Tag, extract_json_value, extract_tags, foreign_owner, parse_name_tag,
+    parse_resources_from_json,
 };
+use crate::aws_cli::name_index;
+use crate::aws_cli::vpc_endpoint::{
+    VpcEndpointDetail, is_full_access_policy, parse_vpc_endpoints_output,
+};
+use crate::aws_cli::vpc_peering::{VpcPeeringDetail, parse_vpc_peering_connections_output};
+use crate::cidr::Ipv4Cidr;
 use crate::i18n::{I18n, Language};
-use serde::Deserialize;
+use crate::locale;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[cfg(not(test))]
@@ -81,6 +89,36 @@ mod subnet_adapter {
 }
 
 // Serde structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcResponse {
+    vpcs: Vec<Vpc>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Vpc {
+    #[serde(default)]
+    ipv6_cidr_block_association_set: Vec<Ipv6CidrBlockAssociation>,
+}
+
+// VPC와 서브넷의 IPv6 연결 형식이 같음
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Ipv6CidrBlockAssociation {
+    #[serde(default)]
+    ipv6_cidr_block: String,
+    #[serde(default)]
+    ipv6_cidr_block_state: Option<CidrBlockState>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CidrBlockState {
+    #[serde(default)]
+    state: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct SubnetResponse {
@@ -93,13 +131,19 @@ struct SubnetResponse {
 struct Subnet {
     subnet_id: String,
     vpc_id: String,
+    // IPv6 전용 서브넷에는 없음
+    #[serde(default)]
     cidr_block: String,
     availability_zone: String,
     state: String,
     #[serde(default)]
     map_public_ip_on_launch: bool,
     #[serde(default)]
-    available_ip_address_count: i32,
+    available_ip_address_count: Option<i64>,
+    #[serde(default)]
+    owner_id: String,
+    #[serde(default)]
+    ipv6_cidr_block_association_set: Vec<Ipv6CidrBlockAssociation>,
     #[serde(default)]
     tags: Vec<Tag>,
 }
@@ -143,8 +187,71 @@ struct NatGatewayAddress {
     private_ip: String,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct RouteEntry {
+    destination_cidr_block: String,
+    destination_ipv6_cidr_block: String,
+    gateway_id: String,
+    nat_gateway_id: String,
+    egress_only_internet_gateway_id: String,
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkAclResponse {
+    network_acls: Vec<NetworkAcl>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkAcl {
+    network_acl_id: String,
+    #[serde(default)]
+    is_default: bool,
+    #[serde(default)]
+    entries: Vec<NetworkAclEntry>,
+    #[serde(default)]
+    associations: Vec<NetworkAclAssociation>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkAclEntry {
+    rule_number: i64,
+    #[serde(default)]
+    protocol: String,
+    #[serde(default)]
+    rule_action: String,
+    #[serde(default)]
+    egress: bool,
+    #[serde(default)]
+    cidr_block: String,
+    #[serde(default)]
+    ipv6_cidr_block: String,
+    #[serde(default)]
+    port_range: Option<NetworkAclPortRange>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkAclPortRange {
+    from: i64,
+    to: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkAclAssociation {
+    #[serde(default)]
+    subnet_id: String,
+}
+
 // Detail structures
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[allow(dead_code)]
 pub struct NatDetail {
     pub name: String,
@@ -160,7 +267,7 @@ pub struct NatDetail {
     pub tags: Vec<(String, String)>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RouteTableDetail {
     pub name: String,
     #[allow(dead_code)]
@@ -169,7 +276,29 @@ pub struct RouteTableDetail {
     pub associations: Vec<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkAclDetail {
+    pub name: String,
+    pub id: String,
+    pub is_default: bool,
+    // 규칙 번호 순 (마지막은 번호가 없는 기본 거부 규칙)
+    pub inbound: Vec<NetworkAclRule>,
+    pub outbound: Vec<NetworkAclRule>,
+    pub subnet_ids: Vec<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkAclRule {
+    // 기본 규칙(32767)은 None
+    pub rule_number: Option<i64>,
+    pub protocol: String,
+    pub port_range: String,
+    pub cidr: String,
+    pub allow: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EipDetail {
     pub name: String,
     pub public_ip: String,
@@ -177,18 +306,36 @@ pub struct EipDetail {
     pub private_ip: String,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Per-subnet address data that `AwsResource` has no room for.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubnetAddresses {
+    pub subnet_id: String,
+    pub ipv6_cidrs: Vec<String>,
+    // describe-subnets의 AvailableIpAddressCount (IPv4 기준)
+    pub available_ips: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[allow(dead_code)]
 pub struct NetworkDetail {
     pub name: String,
     pub id: String,
     pub cidr: String,
+    // 듀얼 스택 VPC에 연결된 IPv6 CIDR
+    pub ipv6_cidrs: Vec<String>,
     pub state: String,
+    pub owner_id: String,
     pub subnets: Vec<AwsResource>,
+    // AwsResource의 cidr는 IPv4만 담으므로 IPv6 CIDR와 남은 IP 수는 따로 보관
+    pub subnet_addresses: Vec<SubnetAddresses>,
     pub igws: Vec<AwsResource>,
     pub nats: Vec<NatDetail>,
     pub route_tables: Vec<RouteTableDetail>,
+    pub nacls: Vec<NetworkAclDetail>,
     pub eips: Vec<EipDetail>,
+    // 이 VPC가 요청자 또는 수락자인 피어링 연결
+    pub peerings: Vec<VpcPeeringDetail>,
+    pub endpoints: Vec<VpcEndpointDetail>,
     pub dns_support: bool,
     pub dns_hostnames: bool,
     pub tags: Vec<(String, String)>,
@@ -208,6 +355,9 @@ impl NetworkDetail {
         lines.push("|:---|:---|".to_string());
         lines.push(format!("| {} | {} |", i18n.md_name(), vpc_display));
         lines.push(format!("| CIDR | {} |", self.cidr));
+        if !self.ipv6_cidrs.is_empty() {
+            lines.push(format!("| IPv6 CIDR | {} |", self.ipv6_cidrs.join(", ")));
+        }
         lines.push(format!(
             "| {} | {} |",
             i18n.md_dns_support(),
@@ -218,6 +368,9 @@ impl NetworkDetail {
             i18n.md_dns_hostnames(),
             self.dns_hostnames
         ));
+        if let Some(owner) = foreign_owner(&self.owner_id) {
+            lines.push(format!("| {} | {} |", i18n.md_owner_account(), owner));
+        }
 
         for (key, value) in &self.tags {
             if key != "Name" {
@@ -227,17 +380,84 @@ impl NetworkDetail {
 
         if !self.subnets.is_empty() {
             lines.push(format!("\n### {}", i18n.md_subnets()));
-            lines.push(format!(
-                "| {} | CIDR | AZ | {} |",
-                i18n.md_name(),
-                i18n.md_state()
-            ));
-            lines.push("|:---|:---|:---|:---|".to_string());
+            // 다른 계정에서 공유된 서브넷이 있을 때만 소유 계정 열 추가
+            let shared = self
+                .subnets
+                .iter()
+                .any(|s| foreign_owner(&s.owner_id).is_some());
+            // IPv6 CIDR가 연결된 서브넷이 있을 때만 IPv6 열 추가
+            let dual_stack = self
+                .subnet_addresses
+                .iter()
+                .any(|a| !a.ipv6_cidrs.is_empty());
+            let mut header = vec![i18n.md_name(), "CIDR"];
+            if dual_stack {
+                header.push("IPv6 CIDR");
+            }
+            header.extend([
+                "AZ",
+                i18n.md_state(),
+                i18n.md_usable_ips(),
+                i18n.md_ip_utilization(),
+            ]);
+            if shared {
+                header.push(i18n.md_owner_account());
+            }
+            lines.push(format!("| {} |", header.join(" | ")));
+            lines.push(format!("|{}", ":---|".repeat(header.len())));
+            let or_dash = |value: &str| {
+                if value.is_empty() {
+                    "-".to_string()
+                } else {
+                    value.to_string()
+                }
+            };
+            let mut ipv4_subnets = Vec::new();
             for subnet in &self.subnets {
-                lines.push(format!(
-                    "| {} | {} | {} | {} |",
-                    subnet.name, subnet.cidr, subnet.az, subnet.state
-                ));
+                let addresses = self
+                    .subnet_addresses
+                    .iter()
+                    .find(|a| a.subnet_id == subnet.id);
+                let mut row = vec![subnet.name.clone(), or_dash(&subnet.cidr)];
+                if dual_stack {
+                    let ipv6_cidrs = addresses.map(|a| a.ipv6_cidrs.join(", "));
+                    row.push(or_dash(&ipv6_cidrs.unwrap_or_default()));
+                }
+                row.extend([subnet.az.clone(), subnet.state.clone()]);
+                // IPv6 전용 서브넷은 IPv4 주소 계산을 하지 않음
+                let cidr = Ipv4Cidr::parse(&subnet.cidr);
+                let utilization = cidr.zip(addresses.and_then(|a| a.available_ips));
+                row.push(cidr.map_or("-".to_string(), |c| locale::count(c.usable_hosts())));
+                row.push(
+                    utilization
+                        .and_then(|(c, available)| c.utilization(available))
+                        .map_or("-".to_string(), locale::percent),
+                );
+                if shared {
+                    row.push(foreign_owner(&subnet.owner_id).unwrap_or("-").to_string());
+                }
+                lines.push(format!("| {} |", row.join(" | ")));
+                if let Some(cidr) = cidr {
+                    ipv4_subnets.push((subnet, cidr));
+                }
+            }
+
+            let mut overlap_warnings = Vec::new();
+            for (index, (subnet, cidr)) in ipv4_subnets.iter().enumerate() {
+                for (other, other_cidr) in &ipv4_subnets[index + 1..] {
+                    if cidr.overlaps(*other_cidr) {
+                        overlap_warnings.push(i18n.md_subnet_overlap(
+                            &format!("{} ({})", subnet.name, subnet.cidr),
+                            &format!("{} ({})", other.name, other.cidr),
+                        ));
+                    }
+                }
+            }
+            if !overlap_warnings.is_empty() {
+                lines.push(String::new());
+                for warning in overlap_warnings {
+                    lines.push(format!("> ⚠ {}\n", warning));
+                }
             }
         }
 
@@ -377,7 +597,12 @@ impl NetworkDetail {
                     for route in &rt.routes {
                         let parts: Vec<&str> = route.split('|').collect();
                         if parts.len() >= 3 {
-                            lines.push(format!("| {} | {} | {} |", parts[0], parts[1], parts[2]));
+                            lines.push(format!(
+                                "| {} | {} | {} |",
+                                cidr_label(parts[0], &i18n),
+                                name_index::label(parts[1]),
+                                parts[2]
+                            ));
                         }
                     }
                 }
@@ -393,6 +618,136 @@ impl NetworkDetail {
             }
         }
 
+        if !self.nacls.is_empty() {
+            lines.push(format!("\n### {}", i18n.network_acl()));
+            for nacl in &self.nacls {
+                let mut display_name = if nacl.name.is_empty() {
+                    format!("NULL - {}", nacl.id)
+                } else {
+                    format!("{} - {}", nacl.name, nacl.id)
+                };
+                if nacl.is_default {
+                    display_name.push_str(&format!(" ({})", i18n.md_default()));
+                }
+                lines.push(format!("\n#### {}", display_name));
+
+                for (title, cidr_header, rules) in [
+                    (i18n.md_inbound_rules(), i18n.md_source(), &nacl.inbound),
+                    (
+                        i18n.md_outbound_rules(),
+                        i18n.md_destination(),
+                        &nacl.outbound,
+                    ),
+                ] {
+                    if rules.is_empty() {
+                        continue;
+                    }
+                    lines.push(format!("\n**{}**", title));
+                    lines.push(format!(
+                        "| {} | {} | {} | {} | {} |",
+                        i18n.md_rule_number(),
+                        i18n.md_protocol(),
+                        i18n.md_port_range(),
+                        cidr_header,
+                        i18n.md_action()
+                    ));
+                    lines.push("|:---|:---|:---|:---|:---|".to_string());
+                    for rule in rules {
+                        lines.push(format!(
+                            "| {} | {} | {} | {} | {} |",
+                            rule.rule_number
+                                .map(|number| number.to_string())
+                                .unwrap_or_else(|| "*".to_string()),
+                            rule.protocol,
+                            rule.port_range,
+                            cidr_label(&rule.cidr, &i18n),
+                            if rule.allow {
+                                i18n.md_allow()
+                            } else {
+                                i18n.md_deny()
+                            }
+                        ));
+                    }
+                }
+
+                if !nacl.subnet_ids.is_empty() {
+                    lines.push(format!("\n**{}**", i18n.md_associated_subnets()));
+                    lines.push(format!("| {} |", i18n.md_subnet()));
+                    lines.push("|:---|".to_string());
+                    for subnet_id in &nacl.subnet_ids {
+                        lines.push(format!("| {} |", name_index::label(subnet_id)));
+                    }
+                }
+            }
+        }
+
+        if !self.peerings.is_empty() {
+            lines.push(format!("\n### {}", i18n.vpc_peering()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_requester(),
+                i18n.md_accepter(),
+                i18n.md_remote_dns_resolution(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|".to_string());
+            for peering in &self.peerings {
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} |",
+                    peering.display_name(),
+                    peering.requester.summary(),
+                    peering.accepter.summary(),
+                    peering.dns_resolution_summary(&i18n),
+                    peering.status
+                ));
+            }
+        }
+
+        if !self.endpoints.is_empty() {
+            lines.push(format!("\n### {}", i18n.vpc_endpoint()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                i18n.md_name(),
+                i18n.md_service_name(),
+                i18n.md_type(),
+                i18n.md_subnets_or_route_tables(),
+                i18n.md_security_groups(),
+                i18n.md_endpoint_policy(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|:---|:---|".to_string());
+            for endpoint in &self.endpoints {
+                let security_groups = if endpoint.security_groups.is_empty() {
+                    "-".to_string()
+                } else {
+                    endpoint.security_groups.join(", ")
+                };
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |",
+                    endpoint.display_name(),
+                    endpoint.service_name,
+                    endpoint.endpoint_type,
+                    endpoint.placement(),
+                    security_groups,
+                    endpoint.policy_summary(&i18n),
+                    endpoint.state
+                ));
+            }
+            // 기본 정책(전체 허용)이 아닌 엔드포인트만 정책 본문을 표시
+            for endpoint in &self.endpoints {
+                if endpoint.policy.is_empty() || is_full_access_policy(&endpoint.policy) {
+                    continue;
+                }
+                lines.push(format!(
+                    "\n**{} - {}**",
+                    endpoint.display_name(),
+                    i18n.md_endpoint_policy()
+                ));
+                lines.push(format!("```json\n{}\n```", endpoint.policy));
+            }
+        }
+
         if !self.eips.is_empty() {
             lines.push("\n### Elastic IPs".to_string());
             lines.push(format!(
@@ -574,13 +929,50 @@ impl NetworkDetail {
     }
 }
 
+/// CIDR for route and rule tables. IPv6 ranges are marked so dual-stack
+/// entries can't be read as IPv4 ones, and `::/0` is spelled out.
+pub(crate) fn cidr_label(cidr: &str, i18n: &I18n) -> String {
+    if cidr == "::/0" {
+        format!("::/0 ({})", i18n.md_all_ipv6())
+    } else if cidr.contains(':') {
+        format!("{} (IPv6)", cidr)
+    } else {
+        cidr.to_string()
+    }
+}
+
+// 연결 해제 중이거나 실패한 CIDR는 제외
+fn associated_ipv6_cidrs(associations: Vec<Ipv6CidrBlockAssociation>) -> Vec<String> {
+    associations
+        .into_iter()
+        .filter(|association| {
+            association
+                .ipv6_cidr_block_state
+                .as_ref()
+                .is_none_or(|state| {
+                    matches!(state.state.as_str(), "" | "associated" | "associating")
+                })
+        })
+        .map(|association| association.ipv6_cidr_block)
+        .filter(|cidr| !cidr.is_empty())
+        .collect()
+}
+
+fn parse_vpc_ipv6_cidrs(output: &str) -> Vec<String> {
+    serde_json::from_str::<VpcResponse>(output)
+        .ok()
+        .and_then(|response| response.vpcs.into_iter().next())
+        .map(|vpc| associated_ipv6_cidrs(vpc.ipv6_cidr_block_association_set))
+        .unwrap_or_default()
+}
+
 // Public functions
 pub fn list_vpcs() -> Vec<AwsResource> {
     let command = [
         "ec2",
         "describe-vpcs",
         "--query",
-        "Vpcs[*].[VpcId,Tags]",
+        "Vpcs[*].[VpcId,Tags,OwnerId]",
         "--output",
         "json",
     ];
@@ -597,10 +989,34 @@ pub fn list_vpcs() -> Vec<AwsResource> {
         "list_vpcs: aws adapter response"
     );
 
-    parse_resources_from_json(&output, "vpc-")
+    let mut vpcs = parse_resources_from_json(&output, "vpc-");
+    apply_vpc_owners(&mut vpcs, &output);
+    name_index::record_resources(&vpcs);
+    vpcs
+}
+
+// [VpcId, Tags, OwnerId] 행에서 소유 계정 채우기
+fn apply_vpc_owners(vpcs: &mut [AwsResource], output: &str) {
+    let Ok(rows) = serde_json::from_str::<Vec<Vec<serde_json::Value>>>(output) else {
+        return;
+    };
+    for row in rows {
+        let (Some(id), Some(owner)) = (
+            row.first().and_then(|v| v.as_str()),
+            row.get(2).and_then(|v| v.as_str()),
+        ) else {
+            continue;
+        };
+        if let Some(vpc) = vpcs.iter_mut().find(|vpc| vpc.id == id) {
+            vpc.owner_id = owner.to_string();
+        }
+    }
 }
 
-pub fn list_subnets(vpc_id: &str) -> Vec<AwsResource> {
+/// Subnets of the VPC plus their IPv6 CIDRs and available IP counts.
+type SubnetList = (Vec<AwsResource>, Vec<SubnetAddresses>);
+
+pub fn list_subnets(vpc_id: &str) -> SubnetList {
     let command = ["ec2", "describe-subnets", "--output", "json"];
     let output = match cli_adapter::run(&command) {
         Some(o) => o,
@@ -610,7 +1026,7 @@ pub fn list_subnets(vpc_id: &str) -> Vec<AwsResource> {
                 command = %command.join(" "),
                 "list_subnets: no output from aws adapter"
             );
-            return Vec::new();
+            return (Vec::new(), Vec::new());
         }
     };
     tracing::debug!(
@@ -620,16 +1036,19 @@ pub fn list_subnets(vpc_id: &str) -> Vec<AwsResource> {
         "list_subnets: aws adapter response"
     );
 
-    parse_subnets_output(&output, vpc_id)
+    let (subnets, addresses) = parse_subnets_output(&output, vpc_id);
+    name_index::record_resources(&subnets);
+    (subnets, addresses)
 }
 
-fn parse_subnets_output(output: &str, vpc_id: &str) -> Vec<AwsResource> {
+fn parse_subnets_output(output: &str, vpc_id: &str) -> SubnetList {
     let response: SubnetResponse = match serde_json::from_str(output) {
         Ok(r) => r,
-        Err(_) => return Vec::new(),
+        Err(_) => return (Vec::new(), Vec::new()),
     };
 
-    response
+    let mut addresses = Vec::new();
+    let subnets = response
         .subnets
         .into_iter()
         .filter(|s| s.vpc_id == vpc_id)
@@ -640,6 +1059,11 @@ fn parse_subnets_output(output: &str, vpc_id: &str) -> Vec<AwsResource> {
                 .find(|t| t.key == "Name")
                 .map(|t| t.value.clone())
                 .unwrap_or_default();
+            addresses.push(SubnetAddresses {
+                subnet_id: s.subnet_id.clone(),
+                ipv6_cidrs: associated_ipv6_cidrs(s.ipv6_cidr_block_association_set),
+                available_ips: s.available_ip_address_count,
+            });
 
             AwsResource {
                 name,
@@ -647,9 +1071,11 @@ fn parse_subnets_output(output: &str, vpc_id: &str) -> Vec<AwsResource> {
                 state: s.state,
                 az: s.availability_zone,
                 cidr: s.cidr_block,
+                owner_id: s.owner_id,
             }
         })
-        .collect()
+        .collect();
+    (subnets, addresses)
 }
 
 pub fn list_internet_gateways(vpc_id: &str) -> Vec<AwsResource> {
@@ -683,7 +1109,9 @@ pub fn list_internet_gateways(vpc_id: &str) -> Vec<AwsResource> {
         "list_internet_gateways: aws adapter response"
     );
 
-    parse_internet_gateways(&output)
+    let igws = parse_internet_gateways(&output);
+    name_index::record_resources(&igws);
+    igws
 }
 
 fn parse_internet_gateways(json: &str) -> Vec<AwsResource> {
@@ -727,6 +1155,7 @@ fn parse_internet_gateways(json: &str) -> Vec<AwsResource> {
                 state,
                 az: String::new(),
                 cidr: String::new(),
+                owner_id: String::new(),
             });
 
             search_start = att_end;
@@ -766,7 +1195,11 @@ pub fn list_nat_gateways(vpc_id: &str) -> Vec<NatDetail> {
         "list_nat_gateways: aws adapter response"
     );
 
-    parse_nat_gateways_output(&output)
+    let nats = parse_nat_gateways_output(&output);
+    for nat in &nats {
+        name_index::record(&nat.id, &nat.name);
+    }
+    nats
 }
 
 fn parse_nat_gateways_output(output: &str) -> Vec<NatDetail> {
@@ -917,37 +1350,38 @@ fn find_balanced_bracket_end(s: &str) -> Option<usize> {
 }
 
 fn extract_routes(json: &str) -> Vec<String> {
-    let mut routes = Vec::new();
-    let mut search_start = 0;
+    let entries: Vec<RouteEntry> = serde_json::from_str(json).unwrap_or_default();
 
-    while let Some(pos) = json[search_start..].find("\"DestinationCidrBlock\": \"") {
-        let block_start = search_start + pos;
-        let obj_end = if let Some(end) = json[block_start..].find('}') {
-            block_start + end
-        } else {
-            json.len()
-        };
-
-        let section = &json[block_start..obj_end];
-
-        let dest = extract_json_value(section, "DestinationCidrBlock").unwrap_or_default();
-        let gateway = extract_json_value(section, "GatewayId").unwrap_or_default();
-        let nat = extract_json_value(section, "NatGatewayId").unwrap_or_default();
-        let target = if !gateway.is_empty() {
-            gateway
-        } else if !nat.is_empty() {
-            nat
-        } else {
-            "local".to_string()
-        };
-        let state = extract_json_value(section, "State").unwrap_or_else(|| "active".to_string());
-
-        // Format: "dest|target|state"
-        routes.push(format!("{}|{}|{}", dest, target, state));
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            // IPv6 경로는 DestinationIpv6CidrBlock에만 대상이 있음 (접두사 목록 경로는 제외)
+            let dest = if entry.destination_cidr_block.is_empty() {
+                entry.destination_ipv6_cidr_block
+            } else {
+                entry.destination_cidr_block
+            };
+            if dest.is_empty() {
+                return None;
+            }
+            let target = [
+                entry.gateway_id,
+                entry.nat_gateway_id,
+                entry.egress_only_internet_gateway_id,
+            ]
+            .into_iter()
+            .find(|id| !id.is_empty())
+            .unwrap_or_else(|| "local".to_string());
+            let state = if entry.state.is_empty() {
+                "active".to_string()
+            } else {
+                entry.state
+            };
 
-        search_start = obj_end;
-    }
-    routes
+            // Format: "dest|target|state"
+            Some(format!("{}|{}|{}", dest, target, state))
+        })
+        .collect()
 }
 
 fn extract_associations(json: &str) -> Vec<String> {
@@ -1045,6 +1479,201 @@ fn parse_eips_output(json: &str) -> Vec<EipDetail> {
     details
 }
 
+pub fn list_vpc_peerings(vpc_id: &str) -> Vec<VpcPeeringDetail> {
+    // 요청자/수락자 필터는 AND로 묶이므로 전체를 받아 이 VPC가 포함된 연결만 남김
+    let command = [
+        "ec2",
+        "describe-vpc-peering-connections",
+        "--output",
+        "json",
+    ];
+    let output = match cli_adapter::run(&command) {
+        Some(o) => o,
+        None => {
+            tracing::warn!(
+                vpc_id,
+                command = %command.join(" "),
+                "list_vpc_peerings: no output from aws adapter"
+            );
+            return Vec::new();
+        }
+    };
+    tracing::debug!(
+        vpc_id,
+        command = %command.join(" "),
+        bytes = output.len(),
+        "list_vpc_peerings: aws adapter response"
+    );
+
+    let peerings = parse_vpc_peerings_output(&output, vpc_id);
+    // 라우팅 테이블의 pcx- 대상을 이름으로 표시
+    for peering in &peerings {
+        name_index::record(&peering.id, &peering.name);
+    }
+    peerings
+}
+
+fn parse_vpc_peerings_output(output: &str, vpc_id: &str) -> Vec<VpcPeeringDetail> {
+    parse_vpc_peering_connections_output(output)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|peering| peering.requester.vpc_id == vpc_id || peering.accepter.vpc_id == vpc_id)
+        .collect()
+}
+
+pub fn list_network_acls(vpc_id: &str) -> Vec<NetworkAclDetail> {
+    let filter = format!("Name=vpc-id,Values={}", vpc_id);
+    let command = [
+        "ec2",
+        "describe-network-acls",
+        "--filters",
+        &filter,
+        "--output",
+        "json",
+    ];
+    let output = match cli_adapter::run(&command) {
+        Some(o) => o,
+        None => {
+            tracing::warn!(
+                vpc_id,
+                command = %command.join(" "),
+                filter = %filter,
+                "list_network_acls: no output from aws adapter"
+            );
+            return Vec::new();
+        }
+    };
+    tracing::debug!(
+        vpc_id,
+        command = %command.join(" "),
+        bytes = output.len(),
+        "list_network_acls: aws adapter response"
+    );
+
+    let nacls = parse_network_acls_output(&output);
+    for nacl in &nacls {
+        name_index::record(&nacl.id, &nacl.name);
+    }
+    nacls
+}
+
+fn parse_network_acls_output(output: &str) -> Vec<NetworkAclDetail> {
+    let response: NetworkAclResponse = match serde_json::from_str(output) {
+        Ok(r) => r,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut nacls: Vec<NetworkAclDetail> = response
+        .network_acls
+        .into_iter()
+        .map(|nacl| {
+            let name = nacl
+                .tags
+                .iter()
+                .find(|t| t.key == "Name")
+                .map(|t| t.value.clone())
+                .unwrap_or_default();
+            let tags: Vec<(String, String)> = nacl
+                .tags
+                .iter()
+                .filter(|t| t.key != "Name")
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+
+            let (mut outbound, mut inbound): (Vec<_>, Vec<_>) =
+                nacl.entries.into_iter().partition(|entry| entry.egress);
+            inbound.sort_by_key(|entry| entry.rule_number);
+            outbound.sort_by_key(|entry| entry.rule_number);
+
+            NetworkAclDetail {
+                name,
+                id: nacl.network_acl_id,
+                is_default: nacl.is_default,
+                inbound: inbound.into_iter().map(network_acl_rule).collect(),
+                outbound: outbound.into_iter().map(network_acl_rule).collect(),
+                subnet_ids: nacl
+                    .associations
+                    .into_iter()
+                    .map(|association| association.subnet_id)
+                    .filter(|subnet_id| !subnet_id.is_empty())
+                    .collect(),
+                tags,
+            }
+        })
+        .collect();
+    // 기본 ACL을 먼저 표시
+    nacls.sort_by(|a, b| {
+        b.is_default
+            .cmp(&a.is_default)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    nacls
+}
+
+// 프로토콜은 번호로 내려옴 (-1: 전체, 6: TCP, 17: UDP, 1: ICMP, 58: ICMPv6)
+fn network_acl_rule(entry: NetworkAclEntry) -> NetworkAclRule {
+    let protocol = match entry.protocol.as_str() {
+        "-1" => "All".to_string(),
+        "6" => "TCP".to_string(),
+        "17" => "UDP".to_string(),
+        "1" => "ICMP".to_string(),
+        "58" => "ICMPv6".to_string(),
+        other => other.to_string(),
+    };
+    let port_range = match entry.port_range {
+        Some(range) if range.from == range.to => range.from.to_string(),
+        Some(range) => format!("{}-{}", range.from, range.to),
+        None => "All".to_string(),
+    };
+    NetworkAclRule {
+        rule_number: (entry.rule_number != 32767).then_some(entry.rule_number),
+        protocol,
+        port_range,
+        cidr: if entry.cidr_block.is_empty() {
+            entry.ipv6_cidr_block
+        } else {
+            entry.cidr_block
+        },
+        allow: entry.rule_action == "allow",
+    }
+}
+
+pub fn list_vpc_endpoints_in(vpc_id: &str) -> Vec<VpcEndpointDetail> {
+    let filter = format!("Name=vpc-id,Values={}", vpc_id);
+    let command = [
+        "ec2",
+        "describe-vpc-endpoints",
+        "--filters",
+        &filter,
+        "--output",
+        "json",
+    ];
+    let output = match cli_adapter::run(&command) {
+        Some(o) => o,
+        None => {
+            tracing::warn!(
+                vpc_id,
+                command = %command.join(" "),
+                "list_vpc_endpoints_in: no output from aws adapter"
+            );
+            return Vec::new();
+        }
+    };
+    tracing::debug!(
+        vpc_id,
+        command = %command.join(" "),
+        bytes = output.len(),
+        "list_vpc_endpoints_in: aws adapter response"
+    );
+
+    let endpoints = parse_vpc_endpoints_output(&output).unwrap_or_default();
+    // 라우팅 테이블의 vpce- 대상을 이름으로 표시
+    for endpoint in &endpoints {
+        name_index::record(&endpoint.id, &endpoint.name);
+    }
+    endpoints
+}
+
 pub fn get_network_detail(vpc_id: &str) -> Option<NetworkDetail> {
     let command = [
         "ec2",
@@ -1074,7 +1703,9 @@ pub fn get_network_detail(vpc_id: &str) -> Option<NetworkDetail> {
 
     let json = &output;
     let cidr = extract_json_value(json, "CidrBlock").unwrap_or_default();
+    let ipv6_cidrs = parse_vpc_ipv6_cidrs(json);
     let state = extract_state(json);
+    let owner_id = extract_json_value(json, "OwnerId").unwrap_or_default();
     let tags = extract_tags(json);
 
     let name = tags
@@ -1083,11 +1714,14 @@ pub fn get_network_detail(vpc_id: &str) -> Option<NetworkDetail> {
         .map(|(_, v)| v.clone())
         .unwrap_or_else(|| vpc_id.to_string());
 
-    let subnets = list_subnets(vpc_id);
+    let (subnets, subnet_addresses) = list_subnets(vpc_id);
     let igws = list_internet_gateways(vpc_id);
     let nats = list_nat_gateways(vpc_id);
     let route_tables = list_route_tables(vpc_id);
+    let nacls = list_network_acls(vpc_id);
     let eips = list_eips();
+    let peerings = list_vpc_peerings(vpc_id);
+    let endpoints = list_vpc_endpoints_in(vpc_id);
 
     let dns_support = get_vpc_attribute(vpc_id, "enableDnsSupport");
     let dns_hostnames = get_vpc_attribute(vpc_id, "enableDnsHostnames");
@@ -1096,12 +1730,18 @@ pub fn get_network_detail(vpc_id: &str) -> Option<NetworkDetail> {
         name,
         id: vpc_id.to_string(),
         cidr,
+        ipv6_cidrs,
         state,
+        owner_id,
         subnets,
+        subnet_addresses,
         igws,
         nats,
         route_tables,
+        nacls,
         eips,
+        peerings,
+        endpoints,
         dns_support,
         dns_hostnames,
         tags,
@@ -1171,8 +1811,8 @@ fn parse_vpc_attribute_response(json: &str, attribute: &str) -> bool {
     false
 }
 
-/// VPC 기본 정보만 조회 (name, cidr, state, tags)
-type VpcInfo = (String, String, String, Vec<(String, String)>);
+/// VPC 기본 정보만 조회 (name, cidr, state, tags, ipv6_cidrs)
+type VpcInfo = (String, String, String, Vec<(String, String)>, Vec<String>);
 
 pub fn get_vpc_info(vpc_id: &str) -> Option<VpcInfo> {
     let command = [
@@ -1215,7 +1855,7 @@ fn parse_vpc_info_output(json: &str, vpc_id: &str) -> Option<VpcInfo> {
         .map(|(_, v)| v.clone())
         .unwrap_or_else(|| vpc_id.to_string());
 
-    Some((name, cidr, state, tags))
+    Some((name, cidr, state, tags, parse_vpc_ipv6_cidrs(json)))
 }
 
 pub fn get_vpc_dns_support(vpc_id: &str) -> bool {
@@ -1229,11 +1869,12 @@ pub fn get_vpc_dns_hostnames(vpc_id: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        EipDetail, NatDetail, NetworkDetail, RouteTableDetail, cli_adapter, extract_routes,
-        find_balanced_bracket_end, get_network_detail, list_eips, list_nat_gateways,
-        list_route_tables, list_subnets, list_vpcs, parse_eips_output, parse_internet_gateways,
-        parse_nat_gateways_output, parse_route_tables, parse_subnets_output,
-        parse_vpc_attribute_response, parse_vpc_info_output, subnet_adapter,
+        EipDetail, NatDetail, NetworkDetail, RouteTableDetail, SubnetAddresses, cli_adapter,
+        extract_routes, find_balanced_bracket_end, get_network_detail, list_eips,
+        list_nat_gateways, list_route_tables, list_subnets, list_vpcs, parse_eips_output,
+        parse_internet_gateways, parse_nat_gateways_output, parse_network_acls_output,
+        parse_route_tables, parse_subnets_output, parse_vpc_attribute_response,
+        parse_vpc_info_output, subnet_adapter,
     };
     use crate::aws_cli::common::AwsResource;
     use crate::i18n::Language;
@@ -1252,20 +1893,25 @@ mod tests {
             name: "main-vpc".to_string(),
             id: "vpc-1111aaaa".to_string(),
             cidr: "10.0.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
             state: "available".to_string(),
+            owner_id: String::new(),
             subnets: vec![AwsResource {
                 name: "public-a".to_string(),
                 id: "subnet-1111".to_string(),
                 state: "available".to_string(),
                 az: "ap-northeast-2a".to_string(),
                 cidr: "10.0.1.0/24".to_string(),
+                owner_id: String::new(),
             }],
+            subnet_addresses: vec![],
             igws: vec![AwsResource {
                 name: "igw-main".to_string(),
                 id: "igw-1234".to_string(),
                 state: "attached".to_string(),
                 az: String::new(),
                 cidr: String::new(),
+                owner_id: String::new(),
             }],
             nats: vec![NatDetail {
                 name: "nat-main".to_string(),
@@ -1286,18 +1932,81 @@ mod tests {
                 routes: vec!["0.0.0.0/0|igw-1234|active".to_string()],
                 associations: vec!["public-a (subnet-1111)".to_string()],
             }],
+            nacls: vec![],
             eips: vec![EipDetail {
                 name: "eip-main".to_string(),
                 public_ip: "1.1.1.1".to_string(),
                 instance_id: "i-aaaa1111".to_string(),
                 private_ip: String::new(),
             }],
+            peerings: vec![],
+            endpoints: vec![],
             dns_support: true,
             dns_hostnames: true,
             tags: vec![("Name".to_string(), "main-vpc".to_string())],
         }
     }
 
+    #[test]
+    fn parse_network_acls_output_splits_rules_and_renders_markdown() {
+        let payload = r#"
+            {
+              "NetworkAcls": [
+                {
+                  "NetworkAclId": "acl-2222",
+                  "IsDefault": false,
+                  "Entries": [
+                    {"RuleNumber": 32767, "Protocol": "-1", "RuleAction": "deny", "Egress": false, "CidrBlock": "0.0.0.0/0"},
+                    {"RuleNumber": 110, "Protocol": "6", "RuleAction": "allow", "Egress": false, "CidrBlock": "10.0.0.0/16", "PortRange": {"From": 1024, "To": 65535}},
+                    {"RuleNumber": 100, "Protocol": "6", "RuleAction": "allow", "Egress": false, "CidrBlock": "0.0.0.0/0", "PortRange": {"From": 443, "To": 443}},
+                    {"RuleNumber": 100, "Protocol": "-1", "RuleAction": "allow", "Egress": true, "Ipv6CidrBlock": "::/0"}
+                  ],
+                  "Associations": [{"NetworkAclAssociationId": "aclassoc-1", "SubnetId": "subnet-nacl-1"}],
+                  "Tags": [{"Key": "Name", "Value": "public-acl"}, {"Key": "Env", "Value": "prod"}]
+                },
+                {
+                  "NetworkAclId": "acl-1111",
+                  "IsDefault": true,
+                  "Entries": [],
+                  "Associations": [],
+                  "Tags": []
+                }
+              ]
+            }
+        "#;
+
+        let nacls = parse_network_acls_output(payload);
+        assert_eq!(nacls.len(), 2);
+        // 기본 ACL이 먼저
+        assert_eq!(nacls[0].id, "acl-1111");
+        assert!(nacls[0].is_default);
+
+        let public = &nacls[1];
+        assert_eq!(public.name, "public-acl");
+        assert_eq!(public.tags, vec![("Env".to_string(), "prod".to_string())]);
+        assert_eq!(public.inbound.len(), 3);
+        assert_eq!(public.inbound[0].rule_number, Some(100));
+        assert_eq!(public.inbound[0].protocol, "TCP");
+        assert_eq!(public.inbound[0].port_range, "443");
+        assert_eq!(public.inbound[1].port_range, "1024-65535");
+        assert_eq!(public.inbound[2].rule_number, None);
+        assert!(!public.inbound[2].allow);
+        assert_eq!(public.outbound[0].cidr, "::/0");
+        assert_eq!(public.subnet_ids, vec!["subnet-nacl-1"]);
+
+        let mut detail = sample_network_detail();
+        detail.nacls = nacls;
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("### Network ACL"));
+        assert!(md.contains("#### NULL - acl-1111 (Default)"));
+        assert!(md.contains("#### public-acl - acl-2222"));
+        assert!(md.contains("| Rule # | Protocol | Port Range | Source | Action |"));
+        assert!(md.contains("| 100 | TCP | 443 | 0.0.0.0/0 | Allow |"));
+        assert!(md.contains("| * | All | All | 0.0.0.0/0 | Deny |"));
+        assert!(md.contains("| 100 | All | All | ::/0 (all IPv6 addresses) | Allow |"));
+        assert!(md.contains("| subnet-nacl-1 |"));
+    }
+
     #[test]
     fn parse_internet_gateways_extracts_id_name_and_state() {
         let payload = r#"
@@ -1347,6 +2056,16 @@ mod tests {
         assert!(md.contains("```mermaid"));
     }
 
+    #[test]
+    fn network_markdown_labels_route_targets_with_indexed_names() {
+        let md = sample_network_detail().to_markdown(Language::English);
+        assert!(md.contains("| 0.0.0.0/0 | igw-1234 | active |"));
+
+        crate::aws_cli::name_index::record("igw-1234", "igw-main");
+        let md = sample_network_detail().to_markdown(Language::English);
+        assert!(md.contains("| 0.0.0.0/0 | igw-main - igw-1234 | active |"));
+    }
+
     #[test]
     fn parse_internet_gateways_marks_detached_when_attachment_is_missing() {
         let payload = r#"
@@ -1401,14 +2120,18 @@ mod tests {
             name: "vpc-zonal".to_string(),
             id: "vpc-zonal".to_string(),
             cidr: "10.1.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
             state: "available".to_string(),
+            owner_id: String::new(),
             subnets: vec![AwsResource {
                 name: "private-a".to_string(),
                 id: "subnet-z1".to_string(),
                 state: "available".to_string(),
                 az: "ap-northeast-2a".to_string(),
                 cidr: "10.1.1.0/24".to_string(),
+                owner_id: String::new(),
             }],
+            subnet_addresses: vec![],
             igws: vec![],
             nats: vec![NatDetail {
                 name: "nat-zonal".to_string(),
@@ -1424,12 +2147,15 @@ mod tests {
                 tags: vec![],
             }],
             route_tables: vec![],
+            nacls: vec![],
             eips: vec![EipDetail {
                 name: "eip-private".to_string(),
                 public_ip: "52.0.0.9".to_string(),
                 instance_id: String::new(),
                 private_ip: "10.1.1.11".to_string(),
             }],
+            peerings: vec![],
+            endpoints: vec![],
             dns_support: true,
             dns_hostnames: false,
             tags: vec![],
@@ -1455,6 +2181,10 @@ mod tests {
               "State": "available",
               "MapPublicIpOnLaunch": true,
               "AvailableIpAddressCount": 251,
+              "Ipv6CidrBlockAssociationSet": [
+                {"Ipv6CidrBlock": "2600:1f18:abc:de00::/64", "Ipv6CidrBlockState": {"State": "disassociated"}},
+                {"Ipv6CidrBlock": "2600:1f18:abc:de01::/64", "Ipv6CidrBlockState": {"State": "associated"}}
+              ],
               "Tags": [{"Key":"Name","Value":"public-a"}]
             },
             {
@@ -1468,10 +2198,19 @@ mod tests {
           ]
         }
         "#;
-        let subnets = parse_subnets_output(subnets_payload, "vpc-1");
+        let (subnets, addresses) = parse_subnets_output(subnets_payload, "vpc-1");
         assert_eq!(subnets.len(), 1);
         assert_eq!(subnets[0].id, "subnet-a");
         assert_eq!(subnets[0].name, "public-a");
+        // 연결 해제된 CIDR는 제외
+        assert_eq!(
+            addresses,
+            vec![SubnetAddresses {
+                subnet_id: "subnet-a".to_string(),
+                ipv6_cidrs: vec!["2600:1f18:abc:de01::/64".to_string()],
+                available_ips: Some(251),
+            }]
+        );
 
         let nat_payload = r#"
         {
@@ -1549,6 +2288,148 @@ mod tests {
         assert_eq!(info.1, "10.2.0.0/16");
         assert_eq!(info.2, "available");
         assert!(info.3.iter().any(|(k, v)| k == "Env" && v == "prod"));
+        assert!(info.4.is_empty());
+    }
+
+    #[test]
+    fn network_markdown_labels_ipv6_cidrs_routes_and_subnets() {
+        let payload = r#"
+        {
+          "Vpcs": [
+            {
+              "VpcId": "vpc-dual",
+              "CidrBlock": "10.0.0.0/16",
+              "State": "available",
+              "Ipv6CidrBlockAssociationSet": [
+                {
+                  "AssociationId": "vpc-cidr-assoc-1",
+                  "Ipv6CidrBlock": "2600:1f18:abc:de00::/56",
+                  "Ipv6CidrBlockState": {"State": "associated"},
+                  "NetworkBorderGroup": "ap-northeast-2"
+                }
+              ],
+              "Tags": []
+            }
+          ]
+        }
+        "#;
+        let info = parse_vpc_info_output(payload, "vpc-dual").expect("vpc info");
+        assert_eq!(info.1, "10.0.0.0/16");
+        assert_eq!(info.4, vec!["2600:1f18:abc:de00::/56".to_string()]);
+
+        let routes = extract_routes(
+            r#"
+            [
+              {"DestinationCidrBlock": "10.0.0.0/16", "GatewayId": "local", "State": "active"},
+              {"DestinationIpv6CidrBlock": "2600:1f18:abc:de00::/56", "GatewayId": "local", "State": "active"},
+              {"DestinationIpv6CidrBlock": "::/0", "EgressOnlyInternetGatewayId": "eigw-1111", "State": "active"},
+              {"DestinationPrefixListId": "pl-1111", "GatewayId": "vpce-1111", "State": "active"}
+            ]
+            "#,
+        );
+        assert_eq!(
+            routes,
+            vec![
+                "10.0.0.0/16|local|active".to_string(),
+                "2600:1f18:abc:de00::/56|local|active".to_string(),
+                "::/0|eigw-1111|active".to_string(),
+            ]
+        );
+
+        let mut detail = sample_network_detail();
+        detail.ipv6_cidrs = info.4;
+        detail.subnets.push(AwsResource {
+            name: "ipv6-only-b".to_string(),
+            id: "subnet-2222".to_string(),
+            state: "available".to_string(),
+            az: "ap-northeast-2b".to_string(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        });
+        detail.subnet_addresses = vec![
+            SubnetAddresses {
+                subnet_id: "subnet-1111".to_string(),
+                ipv6_cidrs: vec!["2600:1f18:abc:de01::/64".to_string()],
+                available_ips: Some(251),
+            },
+            SubnetAddresses {
+                subnet_id: "subnet-2222".to_string(),
+                ipv6_cidrs: vec!["2600:1f18:abc:de02::/64".to_string()],
+                available_ips: None,
+            },
+        ];
+        detail.route_tables[0].routes.extend(routes);
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("| CIDR | 10.0.0.0/16 |\n| IPv6 CIDR | 2600:1f18:abc:de00::/56 |"));
+        assert!(
+            md.contains("| Name | CIDR | IPv6 CIDR | AZ | State | Usable IPs | IP Utilization |")
+        );
+        assert!(md.contains(
+            "| public-a | 10.0.1.0/24 | 2600:1f18:abc:de01::/64 | ap-northeast-2a | available | 251 | 0.0% |"
+        ));
+        assert!(md.contains(
+            "| ipv6-only-b | - | 2600:1f18:abc:de02::/64 | ap-northeast-2b | available | - | - |"
+        ));
+        assert!(md.contains("| 2600:1f18:abc:de00::/56 (IPv6) | local | active |"));
+        assert!(md.contains("| ::/0 (all IPv6 addresses) | eigw-1111 | active |"));
+
+        // IPv6가 없는 VPC는 기존 표 그대로
+        let md = sample_network_detail().to_markdown(Language::English);
+        assert!(!md.contains("IPv6"));
+        assert!(md.contains("| Name | CIDR | AZ | State | Usable IPs | IP Utilization |"));
+    }
+
+    #[test]
+    fn network_markdown_annotates_subnet_capacity_and_overlaps() {
+        let mut detail = sample_network_detail();
+        detail.subnets.extend([
+            AwsResource {
+                name: "private-a".to_string(),
+                id: "subnet-2222".to_string(),
+                state: "available".to_string(),
+                az: "ap-northeast-2a".to_string(),
+                cidr: "10.0.2.0/26".to_string(),
+                owner_id: String::new(),
+            },
+            AwsResource {
+                name: "legacy".to_string(),
+                id: "subnet-3333".to_string(),
+                state: "available".to_string(),
+                az: "ap-northeast-2c".to_string(),
+                cidr: "10.0.1.128/25".to_string(),
+                owner_id: String::new(),
+            },
+        ]);
+        detail.subnet_addresses = vec![
+            SubnetAddresses {
+                subnet_id: "subnet-1111".to_string(),
+                ipv6_cidrs: vec![],
+                available_ips: Some(200),
+            },
+            SubnetAddresses {
+                subnet_id: "subnet-2222".to_string(),
+                ipv6_cidrs: vec![],
+                available_ips: Some(0),
+            },
+        ];
+
+        let md = detail.to_markdown(Language::English);
+        assert!(
+            md.contains("| public-a | 10.0.1.0/24 | ap-northeast-2a | available | 251 | 20.3% |")
+        );
+        assert!(
+            md.contains("| private-a | 10.0.2.0/26 | ap-northeast-2a | available | 59 | 100.0% |")
+        );
+        // 남은 IP 수를 모르면 사용률은 비워 둠
+        assert!(md.contains("| legacy | 10.0.1.128/25 | ap-northeast-2c | available | 123 | - |"));
+        assert!(md.contains(
+            "> ⚠ public-a (10.0.1.0/24) ↔ legacy (10.0.1.128/25): address ranges overlap"
+        ));
+        assert_eq!(md.matches("> ⚠").count(), 1);
+
+        let md = sample_network_detail().to_markdown(Language::English);
+        assert!(!md.contains("> ⚠"));
     }
 
     #[test]
@@ -1564,7 +2445,7 @@ mod tests {
                 "ec2",
                 "describe-vpcs",
                 "--query",
-                "Vpcs[*].[VpcId,Tags]",
+                "Vpcs[*].[VpcId,Tags,OwnerId]",
                 "--output",
                 "json",
             ],
@@ -1727,11 +2608,89 @@ mod tests {
             Some(r#"{"EnableDnsHostnames":{"Value": true}}"#),
         );
 
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-vpc-peering-connections",
+                "--output",
+                "json",
+            ],
+            Some(
+                r#"
+                {
+                  "VpcPeeringConnections": [
+                    {
+                      "VpcPeeringConnectionId": "pcx-1111",
+                      "RequesterVpcInfo": {
+                        "VpcId": "vpc-2222",
+                        "CidrBlock": "10.1.0.0/16",
+                        "PeeringOptions": {"AllowDnsResolutionFromRemoteVpc": true}
+                      },
+                      "AccepterVpcInfo": {
+                        "VpcId": "vpc-1111",
+                        "CidrBlock": "10.0.0.0/16",
+                        "PeeringOptions": {"AllowDnsResolutionFromRemoteVpc": false}
+                      },
+                      "Status": {"Code": "active", "Message": "Active"},
+                      "Tags": [{"Key": "Name", "Value": "shared-peer"}]
+                    },
+                    {
+                      "VpcPeeringConnectionId": "pcx-3333",
+                      "RequesterVpcInfo": {"VpcId": "vpc-2222"},
+                      "AccepterVpcInfo": {"VpcId": "vpc-3333"},
+                      "Status": {"Code": "active", "Message": "Active"}
+                    }
+                  ]
+                }
+                "#,
+            ),
+        );
+
+        cli_adapter::set(
+            &[
+                "ec2",
+                "describe-vpc-endpoints",
+                "--filters",
+                "Name=vpc-id,Values=vpc-1111",
+                "--output",
+                "json",
+            ],
+            Some(
+                r#"
+                {
+                  "VpcEndpoints": [
+                    {
+                      "VpcEndpointId": "vpce-2222",
+                      "VpcEndpointType": "Interface",
+                      "VpcId": "vpc-1111",
+                      "ServiceName": "com.amazonaws.ap-northeast-2.ssm",
+                      "State": "available",
+                      "PolicyDocument": "{\"Statement\":[{\"Effect\":\"Deny\",\"Principal\":\"*\",\"Action\":\"ssm:DeleteParameter\",\"Resource\":\"*\"}]}",
+                      "SubnetIds": ["subnet-1111"],
+                      "Groups": [{"GroupId": "sg-1111", "GroupName": "endpoints"}],
+                      "PrivateDnsEnabled": true
+                    },
+                    {
+                      "VpcEndpointId": "vpce-1111",
+                      "VpcEndpointType": "Gateway",
+                      "VpcId": "vpc-1111",
+                      "ServiceName": "com.amazonaws.ap-northeast-2.s3",
+                      "State": "available",
+                      "PolicyDocument": "{\"Statement\":[{\"Effect\":\"Allow\",\"Principal\":\"*\",\"Action\":\"*\",\"Resource\":\"*\"}]}",
+                      "RouteTableIds": ["rtb-1234"],
+                      "Tags": [{"Key": "Name", "Value": "s3-gateway"}]
+                    }
+                  ]
+                }
+                "#,
+            ),
+        );
+
         let vpcs = list_vpcs();
         assert_eq!(vpcs.len(), 1);
         assert_eq!(vpcs[0].id, "vpc-1111");
 
-        let subnets = list_subnets("vpc-1111");
+        let (subnets, _) = list_subnets("vpc-1111");
         assert_eq!(subnets.len(), 1);
         assert_eq!(subnets[0].name, "public-a");
 
@@ -1753,5 +2712,24 @@ mod tests {
         assert_eq!(detail.name, "main-vpc");
         assert!(detail.dns_support);
         assert!(detail.dns_hostnames);
+        // 이 VPC가 수락자인 피어링만 포함
+        assert_eq!(detail.peerings.len(), 1);
+        assert_eq!(detail.peerings[0].id, "pcx-1111");
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("### VPC Peering"));
+        assert!(md.contains("| shared-peer - pcx-1111 | vpc-2222 (10.1.0.0/16)"));
+        assert!(md.contains("| Requester: Enabled, Accepter: Disabled | active |"));
+
+        assert_eq!(detail.endpoints.len(), 2);
+        assert!(md.contains("### VPC Endpoint"));
+        assert!(
+            md.contains("| s3-gateway - vpce-1111 | com.amazonaws.ap-northeast-2.s3 | Gateway |")
+        );
+        assert!(md.contains("rtb-1234 | - | Full Access | available |"));
+        assert!(md.contains("subnet-1111 | endpoints (sg-1111) | Custom | available |"));
+        // 사용자 지정 정책만 본문을 표시
+        assert!(md.contains("**vpce-2222 - Endpoint Policy**"));
+        assert!(!md.contains("**s3-gateway - vpce-1111 - Endpoint Policy**"));
     }
 }
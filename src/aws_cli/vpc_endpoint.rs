@@ -0,0 +1,443 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcEndpointsResponse {
+    vpc_endpoints: Vec<VpcEndpointEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcEndpointEntry {
+    vpc_endpoint_id: String,
+    #[serde(default)]
+    vpc_endpoint_type: String,
+    #[serde(default)]
+    vpc_id: String,
+    #[serde(default)]
+    service_name: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    policy_document: String,
+    #[serde(default)]
+    route_table_ids: Vec<String>,
+    #[serde(default)]
+    subnet_ids: Vec<String>,
+    #[serde(default)]
+    groups: Vec<VpcEndpointGroup>,
+    #[serde(default)]
+    private_dns_enabled: bool,
+    #[serde(default)]
+    dns_entries: Vec<VpcEndpointDnsEntry>,
+    #[serde(default)]
+    creation_timestamp: String,
+    #[serde(default)]
+    owner_id: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcEndpointGroup {
+    #[serde(default)]
+    group_id: String,
+    #[serde(default)]
+    group_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcEndpointDnsEntry {
+    #[serde(default)]
+    dns_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpcEndpointDetail {
+    pub name: String,
+    pub id: String,
+    pub vpc_id: String,
+    pub service_name: String,
+    // Interface, Gateway, GatewayLoadBalancer, Resource, ServiceNetwork
+    pub endpoint_type: String,
+    pub state: String,
+    // Interface 엔드포인트의 ENI가 놓인 서브넷
+    pub subnet_ids: Vec<String>,
+    // "name (sg-xxx)" 형식
+    pub security_groups: Vec<String>,
+    // Gateway 엔드포인트가 경로를 추가한 라우팅 테이블
+    pub route_table_ids: Vec<String>,
+    pub private_dns_enabled: bool,
+    pub dns_names: Vec<String>,
+    // 보기 좋게 정렬한 JSON (정책을 지원하지 않는 엔드포인트는 빈 문자열)
+    pub policy: String,
+    pub created: String,
+    pub owner_id: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl VpcEndpointDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    /// Where the endpoint is attached: subnets for interface endpoints, route tables for gateways.
+    pub fn placement(&self) -> String {
+        let placement: Vec<String> = if self.subnet_ids.is_empty() {
+            self.route_table_ids
+                .iter()
+                .map(|id| name_index::label(id))
+                .collect()
+        } else {
+            self.subnet_ids
+                .iter()
+                .map(|id| name_index::label(id))
+                .collect()
+        };
+        if placement.is_empty() {
+            "-".to_string()
+        } else {
+            placement.join(", ")
+        }
+    }
+
+    /// `Full Access` for the default allow-all policy, `Custom` otherwise.
+    pub fn policy_summary(&self, i18n: &I18n) -> &'static str {
+        if self.policy.is_empty() {
+            "-"
+        } else if is_full_access_policy(&self.policy) {
+            i18n.md_full_access()
+        } else {
+            i18n.md_custom()
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.vpc_endpoint(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| VPC | {} |", name_index::label(&self.vpc_id)),
+            format!("| {} | {} |", i18n.md_service_name(), self.service_name),
+            format!("| {} | {} |", i18n.md_type(), self.endpoint_type),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+        if self.endpoint_type == "Interface" {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_private_dns(),
+                if self.private_dns_enabled {
+                    i18n.md_enabled()
+                } else {
+                    i18n.md_disabled()
+                }
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_endpoint_policy(),
+            self.policy_summary(&i18n)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_created_at(),
+            or_dash(&self.created)
+        ));
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        if !self.subnet_ids.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_subnets()));
+            for subnet_id in &self.subnet_ids {
+                lines.push(format!("- {}", name_index::label(subnet_id)));
+            }
+        }
+        if !self.security_groups.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_security_groups()));
+            for group in &self.security_groups {
+                lines.push(format!("- {}", group));
+            }
+        }
+        if !self.route_table_ids.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_route_tables()));
+            for route_table_id in &self.route_table_ids {
+                lines.push(format!("- {}", name_index::label(route_table_id)));
+            }
+        }
+        if !self.dns_names.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_dns_name()));
+            for dns_name in &self.dns_names {
+                lines.push(format!("- `{}`", dns_name));
+            }
+        }
+        if !self.policy.is_empty() {
+            lines.push(format!("\n### {}\n", i18n.md_endpoint_policy()));
+            lines.push(format!("```json\n{}\n```", self.policy));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// The default endpoint policy: a single statement allowing every action on every resource to anyone.
+pub(crate) fn is_full_access_policy(policy: &str) -> bool {
+    let Ok(document) = serde_json::from_str::<serde_json::Value>(policy) else {
+        return false;
+    };
+    let statements = match &document["Statement"] {
+        serde_json::Value::Array(statements) => statements.clone(),
+        statement @ serde_json::Value::Object(_) => vec![statement.clone()],
+        _ => return false,
+    };
+    let is_wildcard = |value: &serde_json::Value| match value {
+        serde_json::Value::String(s) => s == "*",
+        serde_json::Value::Array(items) => items.len() == 1 && items[0] == "*",
+        _ => false,
+    };
+    statements.len() == 1
+        && statements[0]["Effect"] == "Allow"
+        && statements[0].get("Condition").is_none()
+        && (is_wildcard(&statements[0]["Principal"])
+            || is_wildcard(&statements[0]["Principal"]["AWS"]))
+        && is_wildcard(&statements[0]["Action"])
+        && is_wildcard(&statements[0]["Resource"])
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+// PolicyDocument는 JSON 문자열로 내려오므로 다시 들여쓰기해서 보관
+fn pretty_policy(policy: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(policy)
+        .ok()
+        .and_then(|document| serde_json::to_string_pretty(&document).ok())
+        .unwrap_or_else(|| policy.to_string())
+}
+
+/// Parse `describe-vpc-endpoints` output, skipping deleted endpoints.
+pub(crate) fn parse_vpc_endpoints_output(output: &str) -> Option<Vec<VpcEndpointDetail>> {
+    let response: VpcEndpointsResponse = serde_json::from_str(output).ok()?;
+
+    let mut endpoints: Vec<VpcEndpointDetail> = response
+        .vpc_endpoints
+        .into_iter()
+        .map(|endpoint| {
+            let mut tags: Vec<(String, String)> = endpoint
+                .tags
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            VpcEndpointDetail {
+                name: name_tag(&endpoint.tags),
+                id: endpoint.vpc_endpoint_id,
+                vpc_id: endpoint.vpc_id,
+                service_name: endpoint.service_name,
+                endpoint_type: endpoint.vpc_endpoint_type,
+                state: endpoint.state,
+                subnet_ids: endpoint.subnet_ids,
+                security_groups: endpoint
+                    .groups
+                    .into_iter()
+                    .map(|group| {
+                        if group.group_name.is_empty() {
+                            group.group_id
+                        } else {
+                            format!("{} ({})", group.group_name, group.group_id)
+                        }
+                    })
+                    .collect(),
+                route_table_ids: endpoint.route_table_ids,
+                private_dns_enabled: endpoint.private_dns_enabled,
+                dns_names: endpoint
+                    .dns_entries
+                    .into_iter()
+                    .map(|entry| entry.dns_name)
+                    .filter(|dns_name| !dns_name.is_empty())
+                    .collect(),
+                policy: pretty_policy(&endpoint.policy_document),
+                created: endpoint.creation_timestamp,
+                owner_id: endpoint.owner_id,
+                tags,
+            }
+        })
+        // 삭제된 엔드포인트는 한동안 조회 결과에 남음
+        .filter(|endpoint| !endpoint.state.eq_ignore_ascii_case("deleted"))
+        .collect();
+    endpoints.sort_by(|a, b| {
+        a.service_name
+            .cmp(&b.service_name)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Some(endpoints)
+}
+
+pub fn list_vpc_endpoints() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&["ec2", "describe-vpc-endpoints", "--output", "json"]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let endpoints: Vec<AwsResource> = parse_vpc_endpoints_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(endpoint_resource)
+        .collect();
+    name_index::record_resources(&endpoints);
+    endpoints
+}
+
+// 목록에는 서비스 이름과 엔드포인트 타입을 함께 표시
+fn endpoint_resource(endpoint: VpcEndpointDetail) -> AwsResource {
+    AwsResource {
+        name: if endpoint.name.is_empty() {
+            endpoint.id.clone()
+        } else {
+            endpoint.name.clone()
+        },
+        cidr: endpoint.service_name,
+        az: endpoint.endpoint_type,
+        id: endpoint.id,
+        state: endpoint.state,
+        owner_id: endpoint.owner_id,
+    }
+}
+
+pub fn get_vpc_endpoint_detail(endpoint_id: &str) -> Option<VpcEndpointDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-vpc-endpoints",
+        "--vpc-endpoint-ids",
+        endpoint_id,
+        "--output",
+        "json",
+    ])?;
+    parse_vpc_endpoints_output(&output)?.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{endpoint_resource, is_full_access_policy, parse_vpc_endpoints_output};
+    use crate::i18n::Language;
+
+    const PAYLOAD: &str = r#"
+    {
+      "VpcEndpoints": [
+        {
+          "VpcEndpointId": "vpce-0b2",
+          "VpcEndpointType": "Interface",
+          "VpcId": "vpc-1",
+          "ServiceName": "com.amazonaws.ap-northeast-2.ssm",
+          "State": "available",
+          "PolicyDocument": "{\"Statement\":[{\"Effect\":\"Allow\",\"Principal\":\"*\",\"Action\":\"ssm:*\",\"Resource\":\"*\"}]}",
+          "SubnetIds": ["subnet-a", "subnet-c"],
+          "Groups": [{"GroupId": "sg-1", "GroupName": "endpoints"}],
+          "PrivateDnsEnabled": true,
+          "DnsEntries": [{"DnsName": "vpce-0b2-abc.ssm.ap-northeast-2.vpce.amazonaws.com", "HostedZoneId": "Z1"}],
+          "CreationTimestamp": "2026-03-02T01:02:03.000Z",
+          "OwnerId": "111122223333",
+          "Tags": [{"Key": "Name", "Value": "ssm"}, {"Key": "Team", "Value": "net"}]
+        },
+        {
+          "VpcEndpointId": "vpce-0a1",
+          "VpcEndpointType": "Gateway",
+          "VpcId": "vpc-1",
+          "ServiceName": "com.amazonaws.ap-northeast-2.s3",
+          "State": "available",
+          "PolicyDocument": "{\"Version\":\"2008-10-17\",\"Statement\":[{\"Effect\":\"Allow\",\"Principal\":\"*\",\"Action\":\"*\",\"Resource\":\"*\"}]}",
+          "RouteTableIds": ["rtb-1", "rtb-2"],
+          "OwnerId": "111122223333",
+          "Tags": []
+        },
+        {
+          "VpcEndpointId": "vpce-0c3",
+          "VpcEndpointType": "Interface",
+          "ServiceName": "com.amazonaws.ap-northeast-2.ecr.api",
+          "State": "deleted"
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_vpc_endpoints_output_reads_interface_and_gateway_endpoints() {
+        let endpoints = parse_vpc_endpoints_output(PAYLOAD).expect("endpoints");
+        assert_eq!(endpoints.len(), 2);
+
+        // 서비스 이름 순으로 정렬
+        let gateway = &endpoints[0];
+        assert_eq!(gateway.id, "vpce-0a1");
+        assert_eq!(gateway.endpoint_type, "Gateway");
+        assert_eq!(gateway.route_table_ids, vec!["rtb-1", "rtb-2"]);
+        assert!(gateway.subnet_ids.is_empty());
+        assert!(gateway.policy.contains("\n"));
+        assert!(is_full_access_policy(&gateway.policy));
+        assert_eq!(gateway.placement(), "rtb-1, rtb-2");
+
+        let interface = &endpoints[1];
+        assert_eq!(interface.name, "ssm");
+        assert_eq!(interface.subnet_ids, vec!["subnet-a", "subnet-c"]);
+        assert_eq!(interface.security_groups, vec!["endpoints (sg-1)"]);
+        assert!(interface.private_dns_enabled);
+        assert_eq!(
+            interface.dns_names,
+            vec!["vpce-0b2-abc.ssm.ap-northeast-2.vpce.amazonaws.com"]
+        );
+        assert!(!is_full_access_policy(&interface.policy));
+
+        let resource = endpoint_resource(interface.clone());
+        assert_eq!(resource.name, "ssm");
+        assert_eq!(resource.cidr, "com.amazonaws.ap-northeast-2.ssm");
+        assert_eq!(resource.az, "Interface");
+    }
+
+    #[test]
+    fn vpc_endpoint_markdown_shows_placement_and_policy() {
+        let mut endpoints = parse_vpc_endpoints_output(PAYLOAD).expect("endpoints");
+        let interface = endpoints.remove(1);
+
+        let md = interface.to_markdown(Language::English);
+        assert!(md.contains("## VPC Endpoint (ssm - vpce-0b2)"));
+        assert!(md.contains("| Service Name | com.amazonaws.ap-northeast-2.ssm |"));
+        assert!(md.contains("| Private DNS | Enabled |"));
+        assert!(md.contains("| Endpoint Policy | Custom |"));
+        assert!(md.contains("| Tag-Team | net |"));
+        assert!(md.contains("### Security Groups\n- endpoints (sg-1)"));
+        assert!(md.contains("```json\n{"));
+        assert!(md.contains("\"ssm:*\""));
+        assert!(!md.contains("Tag-Name"));
+
+        let gateway = endpoints.remove(0);
+        let md = gateway.to_markdown(Language::English);
+        assert!(md.contains("| Endpoint Policy | Full Access |"));
+        assert!(md.contains("### Route Tables\n- rtb-1\n- rtb-2"));
+        assert!(!md.contains("Private DNS"));
+    }
+}
@@ -0,0 +1,469 @@
+use crate::aws_cli::common::{AwsResource, Tag, current_region, foreign_owner, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringConnectionsResponse {
+    vpc_peering_connections: Vec<VpcPeeringConnectionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringConnectionEntry {
+    vpc_peering_connection_id: String,
+    #[serde(default)]
+    requester_vpc_info: Option<VpcPeeringVpcEntry>,
+    #[serde(default)]
+    accepter_vpc_info: Option<VpcPeeringVpcEntry>,
+    #[serde(default)]
+    status: Option<VpcPeeringStatus>,
+    #[serde(default)]
+    expiration_time: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringVpcEntry {
+    #[serde(default)]
+    vpc_id: String,
+    #[serde(default)]
+    owner_id: String,
+    #[serde(default)]
+    region: String,
+    #[serde(default)]
+    cidr_block: String,
+    #[serde(default)]
+    cidr_block_set: Vec<VpcPeeringCidrBlock>,
+    #[serde(default)]
+    peering_options: Option<VpcPeeringOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringCidrBlock {
+    #[serde(default)]
+    cidr_block: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringOptions {
+    #[serde(default)]
+    allow_dns_resolution_from_remote_vpc: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcPeeringStatus {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpcPeeringDetail {
+    pub name: String,
+    pub id: String,
+    // initiating-request, pending-acceptance, provisioning, active, rejected, expired, failed, deleting, deleted
+    pub status: String,
+    pub status_message: String,
+    pub requester: VpcPeeringVpcInfo,
+    pub accepter: VpcPeeringVpcInfo,
+    // 수락 대기 중인 요청의 만료 시각
+    pub expiration: String,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpcPeeringVpcInfo {
+    pub vpc_id: String,
+    pub owner_id: String,
+    pub region: String,
+    pub cidr_blocks: Vec<String>,
+    // 피어 VPC에서 이 VPC의 퍼블릭 DNS 이름을 프라이빗 IP로 확인할 수 있는지 (수락 전에는 None)
+    pub allow_dns_resolution_from_remote_vpc: Option<bool>,
+}
+
+impl VpcPeeringVpcInfo {
+    /// VPC label with its CIDRs, plus the owner account and region when they differ from ours.
+    pub fn summary(&self) -> String {
+        let mut summary = name_index::label(&self.vpc_id);
+        if !self.cidr_blocks.is_empty() {
+            summary.push_str(&format!(" ({})", self.cidr_blocks.join(", ")));
+        }
+        let mut remote = Vec::new();
+        if let Some(owner) = foreign_owner(&self.owner_id) {
+            remote.push(owner.to_string());
+        }
+        if !self.region.is_empty() && self.region != current_region() {
+            remote.push(self.region.clone());
+        }
+        if !remote.is_empty() {
+            summary.push_str(&format!(" [{}]", remote.join(", ")));
+        }
+        summary
+    }
+}
+
+impl VpcPeeringDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    /// Remote DNS resolution of both sides, e.g. `Requester: Enabled, Accepter: -`.
+    pub fn dns_resolution_summary(&self, i18n: &I18n) -> String {
+        format!(
+            "{}: {}, {}: {}",
+            i18n.md_requester(),
+            dns_resolution_label(i18n, self.requester.allow_dns_resolution_from_remote_vpc),
+            i18n.md_accepter(),
+            dns_resolution_label(i18n, self.accepter.allow_dns_resolution_from_remote_vpc)
+        )
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", i18n.vpc_peering(), display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), self.status),
+        ];
+        // 활성 상태의 메시지("Active")는 상태와 같은 내용이라 생략
+        if self.status != "active" && !self.status_message.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_status_message(),
+                self.status_message
+            ));
+        }
+        if self.status == "pending-acceptance" && !self.expiration.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_expiration_time(),
+                self.expiration
+            ));
+        }
+        for (key, value) in &self.tags {
+            if key != "Name" {
+                lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+            }
+        }
+
+        lines.push("\n### VPC".to_string());
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.item(),
+            i18n.md_requester(),
+            i18n.md_accepter()
+        ));
+        lines.push("|:---|:---|:---|".to_string());
+        lines.push(format!(
+            "| VPC | {} | {} |",
+            name_index::label(&self.requester.vpc_id),
+            name_index::label(&self.accepter.vpc_id)
+        ));
+        lines.push(format!(
+            "| CIDR | {} | {} |",
+            or_dash(&self.requester.cidr_blocks.join(", ")),
+            or_dash(&self.accepter.cidr_blocks.join(", "))
+        ));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.md_owner_account(),
+            or_dash(&self.requester.owner_id),
+            or_dash(&self.accepter.owner_id)
+        ));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.region(),
+            or_dash(&self.requester.region),
+            or_dash(&self.accepter.region)
+        ));
+        lines.push(format!(
+            "| {} | {} | {} |",
+            i18n.md_remote_dns_resolution(),
+            dns_resolution_label(&i18n, self.requester.allow_dns_resolution_from_remote_vpc),
+            dns_resolution_label(&i18n, self.accepter.allow_dns_resolution_from_remote_vpc)
+        ));
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn dns_resolution_label(i18n: &I18n, allowed: Option<bool>) -> &'static str {
+    match allowed {
+        Some(true) => i18n.md_enabled(),
+        Some(false) => i18n.md_disabled(),
+        None => "-",
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+fn vpc_info(entry: Option<VpcPeeringVpcEntry>) -> VpcPeeringVpcInfo {
+    let Some(entry) = entry else {
+        return VpcPeeringVpcInfo {
+            vpc_id: String::new(),
+            owner_id: String::new(),
+            region: String::new(),
+            cidr_blocks: Vec::new(),
+            allow_dns_resolution_from_remote_vpc: None,
+        };
+    };
+    // 보조 CIDR까지 담긴 CidrBlockSet을 우선 사용
+    let mut cidr_blocks: Vec<String> = entry
+        .cidr_block_set
+        .into_iter()
+        .map(|cidr| cidr.cidr_block)
+        .filter(|cidr| !cidr.is_empty())
+        .collect();
+    if cidr_blocks.is_empty() && !entry.cidr_block.is_empty() {
+        cidr_blocks.push(entry.cidr_block);
+    }
+    VpcPeeringVpcInfo {
+        vpc_id: entry.vpc_id,
+        owner_id: entry.owner_id,
+        region: entry.region,
+        cidr_blocks,
+        allow_dns_resolution_from_remote_vpc: entry
+            .peering_options
+            .map(|options| options.allow_dns_resolution_from_remote_vpc),
+    }
+}
+
+/// Parse `describe-vpc-peering-connections` output, skipping deleted connections.
+pub(crate) fn parse_vpc_peering_connections_output(output: &str) -> Option<Vec<VpcPeeringDetail>> {
+    let response: VpcPeeringConnectionsResponse = serde_json::from_str(output).ok()?;
+
+    let mut peerings: Vec<VpcPeeringDetail> = response
+        .vpc_peering_connections
+        .into_iter()
+        .map(|peering| {
+            let mut tags: Vec<(String, String)> = peering
+                .tags
+                .iter()
+                .map(|t| (t.key.clone(), t.value.clone()))
+                .collect();
+            tags.sort();
+            let status = peering.status;
+            VpcPeeringDetail {
+                name: name_tag(&peering.tags),
+                id: peering.vpc_peering_connection_id,
+                status: status
+                    .as_ref()
+                    .map(|status| status.code.clone())
+                    .unwrap_or_default(),
+                status_message: status.map(|status| status.message).unwrap_or_default(),
+                requester: vpc_info(peering.requester_vpc_info),
+                accepter: vpc_info(peering.accepter_vpc_info),
+                expiration: peering.expiration_time,
+                tags,
+            }
+        })
+        // 삭제된 피어링은 한동안 조회 결과에 남음
+        .filter(|peering| peering.status != "deleted")
+        .collect();
+    peerings.sort_by(|a, b| {
+        let a_name = if a.name.is_empty() { &a.id } else { &a.name };
+        let b_name = if b.name.is_empty() { &b.id } else { &b.name };
+        a_name.cmp(b_name)
+    });
+    Some(peerings)
+}
+
+pub fn list_vpc_peering_connections() -> Vec<AwsResource> {
+    let output = match run_aws_cli(&[
+        "ec2",
+        "describe-vpc-peering-connections",
+        "--output",
+        "json",
+    ]) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let peerings: Vec<AwsResource> = parse_vpc_peering_connections_output(&output)
+        .unwrap_or_default()
+        .into_iter()
+        .map(peering_resource)
+        .collect();
+    name_index::record_resources(&peerings);
+    peerings
+}
+
+// 목록에는 요청자/수락자 CIDR을 함께 표시
+fn peering_resource(peering: VpcPeeringDetail) -> AwsResource {
+    let cidr = |info: &VpcPeeringVpcInfo| {
+        info.cidr_blocks
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "-".to_string())
+    };
+    AwsResource {
+        name: if peering.name.is_empty() {
+            peering.id.clone()
+        } else {
+            peering.name.clone()
+        },
+        cidr: format!("{} ↔ {}", cidr(&peering.requester), cidr(&peering.accepter)),
+        id: peering.id,
+        state: peering.status,
+        az: String::new(),
+        owner_id: peering.requester.owner_id,
+    }
+}
+
+pub fn get_vpc_peering_detail(peering_id: &str) -> Option<VpcPeeringDetail> {
+    let output = run_aws_cli(&[
+        "ec2",
+        "describe-vpc-peering-connections",
+        "--vpc-peering-connection-ids",
+        peering_id,
+        "--output",
+        "json",
+    ])?;
+    parse_vpc_peering_connections_output(&output)?
+        .into_iter()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        VpcPeeringDetail, VpcPeeringVpcInfo, parse_vpc_peering_connections_output, peering_resource,
+    };
+    use crate::i18n::Language;
+
+    const PAYLOAD: &str = r#"
+    {
+      "VpcPeeringConnections": [
+        {
+          "VpcPeeringConnectionId": "pcx-2",
+          "RequesterVpcInfo": {
+            "VpcId": "vpc-1",
+            "OwnerId": "111122223333",
+            "Region": "ap-northeast-2",
+            "CidrBlock": "10.0.0.0/16",
+            "CidrBlockSet": [{"CidrBlock": "10.0.0.0/16"}, {"CidrBlock": "10.100.0.0/16"}],
+            "PeeringOptions": {"AllowDnsResolutionFromRemoteVpc": true}
+          },
+          "AccepterVpcInfo": {
+            "VpcId": "vpc-2",
+            "OwnerId": "444455556666",
+            "Region": "us-east-1",
+            "CidrBlock": "10.1.0.0/16",
+            "PeeringOptions": {"AllowDnsResolutionFromRemoteVpc": false}
+          },
+          "Status": {"Code": "active", "Message": "Active"},
+          "Tags": [{"Key": "Name", "Value": "app-to-shared"}, {"Key": "Team", "Value": "net"}]
+        },
+        {
+          "VpcPeeringConnectionId": "pcx-1",
+          "RequesterVpcInfo": {"VpcId": "vpc-1", "OwnerId": "111122223333", "CidrBlock": "10.0.0.0/16"},
+          "AccepterVpcInfo": {"VpcId": "vpc-3", "OwnerId": "111122223333"},
+          "Status": {"Code": "pending-acceptance", "Message": "Pending Acceptance by 111122223333"},
+          "ExpirationTime": "2026-01-08T00:00:00Z",
+          "Tags": []
+        },
+        {
+          "VpcPeeringConnectionId": "pcx-3",
+          "Status": {"Code": "deleted", "Message": "Deleted by 111122223333"}
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_vpc_peering_connections_output_reads_both_sides() {
+        let peerings = parse_vpc_peering_connections_output(PAYLOAD).expect("peerings");
+        assert_eq!(peerings.len(), 2);
+
+        let active = &peerings[0];
+        assert_eq!(active.id, "pcx-2");
+        assert_eq!(active.name, "app-to-shared");
+        assert_eq!(active.status, "active");
+        assert_eq!(
+            active.requester.cidr_blocks,
+            vec!["10.0.0.0/16", "10.100.0.0/16"]
+        );
+        assert_eq!(
+            active.requester.allow_dns_resolution_from_remote_vpc,
+            Some(true)
+        );
+        assert_eq!(active.accepter.cidr_blocks, vec!["10.1.0.0/16"]);
+        assert_eq!(active.accepter.region, "us-east-1");
+
+        // 수락 전에는 수락자 CIDR과 DNS 옵션이 없음
+        let pending = &peerings[1];
+        assert_eq!(pending.id, "pcx-1");
+        assert!(pending.accepter.cidr_blocks.is_empty());
+        assert_eq!(pending.accepter.allow_dns_resolution_from_remote_vpc, None);
+        assert_eq!(pending.expiration, "2026-01-08T00:00:00Z");
+
+        let resource = peering_resource(pending.clone());
+        assert_eq!(resource.name, "pcx-1");
+        assert_eq!(resource.state, "pending-acceptance");
+        assert_eq!(resource.cidr, "10.0.0.0/16 ↔ -");
+    }
+
+    #[test]
+    fn vpc_peering_markdown_shows_requester_and_accepter() {
+        let detail = VpcPeeringDetail {
+            name: "app-to-shared".to_string(),
+            id: "pcx-md-1".to_string(),
+            status: "pending-acceptance".to_string(),
+            status_message: "Pending Acceptance by 444455556666".to_string(),
+            requester: VpcPeeringVpcInfo {
+                vpc_id: "vpc-peer-md-1".to_string(),
+                owner_id: "111122223333".to_string(),
+                region: "ap-northeast-2".to_string(),
+                cidr_blocks: vec!["10.0.0.0/16".to_string()],
+                allow_dns_resolution_from_remote_vpc: Some(false),
+            },
+            accepter: VpcPeeringVpcInfo {
+                vpc_id: "vpc-peer-md-2".to_string(),
+                owner_id: "444455556666".to_string(),
+                region: "us-east-1".to_string(),
+                cidr_blocks: vec![],
+                allow_dns_resolution_from_remote_vpc: None,
+            },
+            expiration: "2026-01-08T00:00:00Z".to_string(),
+            tags: vec![("Name".to_string(), "app-to-shared".to_string())],
+        };
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## VPC Peering (app-to-shared - pcx-md-1)"));
+        assert!(md.contains("| Status Message | Pending Acceptance by 444455556666 |"));
+        assert!(md.contains("| Expiration Time | 2026-01-08T00:00:00Z |"));
+        assert!(md.contains("| VPC | vpc-peer-md-1 | vpc-peer-md-2 |"));
+        assert!(md.contains("| CIDR | 10.0.0.0/16 | - |"));
+        assert!(md.contains("| Region | ap-northeast-2 | us-east-1 |"));
+        assert!(md.contains("| DNS Resolution from Remote VPC | Disabled | - |"));
+        assert!(!md.contains("Tag-Name"));
+    }
+}
@@ -0,0 +1,1008 @@
+use crate::aws_cli::common::{AwsResource, Tag, run_aws_cli};
+use crate::aws_cli::name_index;
+use crate::i18n::{I18n, Language};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnConnectionsResponse {
+    vpn_connections: Vec<VpnConnectionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnConnectionEntry {
+    vpn_connection_id: String,
+    state: String,
+    #[serde(default, rename = "Type")]
+    connection_type: String,
+    #[serde(default)]
+    customer_gateway_id: String,
+    #[serde(default)]
+    vpn_gateway_id: Option<String>,
+    #[serde(default)]
+    transit_gateway_id: Option<String>,
+    #[serde(default)]
+    options: Option<VpnConnectionOptions>,
+    #[serde(default)]
+    routes: Vec<VpnStaticRouteEntry>,
+    #[serde(default)]
+    vgw_telemetry: Vec<VgwTelemetryEntry>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnConnectionOptions {
+    #[serde(default)]
+    enable_acceleration: bool,
+    #[serde(default)]
+    static_routes_only: bool,
+    #[serde(default)]
+    local_ipv4_network_cidr: String,
+    #[serde(default)]
+    remote_ipv4_network_cidr: String,
+    #[serde(default)]
+    tunnel_options: Vec<TunnelOptionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TunnelOptionEntry {
+    #[serde(default)]
+    outside_ip_address: String,
+    #[serde(default)]
+    tunnel_inside_cidr: String,
+    #[serde(default)]
+    tunnel_inside_ipv6_cidr: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnStaticRouteEntry {
+    #[serde(default)]
+    destination_cidr_block: String,
+    #[serde(default)]
+    source: String,
+    #[serde(default)]
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VgwTelemetryEntry {
+    #[serde(default)]
+    outside_ip_address: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    status_message: String,
+    #[serde(default)]
+    last_status_change: String,
+    #[serde(default)]
+    accepted_route_count: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CustomerGatewaysResponse {
+    customer_gateways: Vec<CustomerGatewayEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CustomerGatewayEntry {
+    customer_gateway_id: String,
+    state: String,
+    #[serde(default, rename = "Type")]
+    gateway_type: String,
+    #[serde(default)]
+    ip_address: String,
+    #[serde(default)]
+    bgp_asn: String,
+    #[serde(default)]
+    device_name: String,
+    #[serde(default)]
+    certificate_arn: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnGatewaysResponse {
+    vpn_gateways: Vec<VpnGatewayEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpnGatewayEntry {
+    vpn_gateway_id: String,
+    state: String,
+    #[serde(default, rename = "Type")]
+    gateway_type: String,
+    #[serde(default)]
+    availability_zone: String,
+    #[serde(default)]
+    amazon_side_asn: Option<i64>,
+    #[serde(default)]
+    vpc_attachments: Vec<VpcAttachmentEntry>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VpcAttachmentEntry {
+    #[serde(default)]
+    vpc_id: String,
+    #[serde(default)]
+    state: String,
+}
+
+/// VPN connection, customer gateway or virtual private gateway (told apart by the ID prefix).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpnDetail {
+    pub name: String,
+    pub id: String,
+    // pending, available, deleting, deleted
+    pub state: String,
+    pub tags: Vec<(String, String)>,
+    pub resource: VpnResource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum VpnResource {
+    Connection(Box<VpnConnectionInfo>),
+    // 이 게이트웨이를 쓰는 VPN 연결 ID
+    CustomerGateway {
+        gateway: CustomerGatewayInfo,
+        connections: Vec<String>,
+    },
+    VpnGateway {
+        gateway: VpnGatewayInfo,
+        connections: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpnConnectionInfo {
+    // ipsec.1
+    pub connection_type: String,
+    pub customer_gateway_id: String,
+    // 가상 프라이빗 게이트웨이 또는 Transit Gateway 중 하나에 연결
+    pub vpn_gateway_id: Option<String>,
+    pub transit_gateway_id: Option<String>,
+    pub static_routes_only: bool,
+    pub acceleration: bool,
+    pub local_network_cidr: String,
+    pub remote_network_cidr: String,
+    pub tunnels: Vec<VpnTunnelInfo>,
+    pub routes: Vec<VpnStaticRouteInfo>,
+    // 상세 조회 시 함께 조회한 양쪽 게이트웨이 (조회 실패 시 None)
+    pub customer_gateway: Option<CustomerGatewayInfo>,
+    pub vpn_gateway: Option<VpnGatewayInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpnTunnelInfo {
+    pub outside_ip: String,
+    pub inside_cidr: String,
+    pub inside_ipv6_cidr: String,
+    // UP, DOWN
+    pub status: String,
+    pub status_message: String,
+    pub last_status_change: String,
+    pub accepted_routes: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpnStaticRouteInfo {
+    pub destination: String,
+    // Static
+    pub source: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CustomerGatewayInfo {
+    pub name: String,
+    pub id: String,
+    pub state: String,
+    pub gateway_type: String,
+    pub ip_address: String,
+    pub bgp_asn: String,
+    pub device_name: String,
+    pub certificate_arn: String,
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VpnGatewayInfo {
+    pub name: String,
+    pub id: String,
+    pub state: String,
+    pub gateway_type: String,
+    pub availability_zone: String,
+    pub amazon_side_asn: Option<i64>,
+    // (VPC ID, attaching/attached/detaching/detached)
+    pub vpc_attachments: Vec<(String, String)>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl CustomerGatewayInfo {
+    // 목록과 연결 문서에 쓰는 "IP (ASN 65000)" 요약
+    fn endpoint_summary(&self) -> String {
+        match (self.ip_address.is_empty(), self.bgp_asn.is_empty()) {
+            (false, false) => format!("{} (ASN {})", self.ip_address, self.bgp_asn),
+            (false, true) => self.ip_address.clone(),
+            (true, false) => format!("ASN {}", self.bgp_asn),
+            (true, true) => String::new(),
+        }
+    }
+
+    fn push_rows(&self, i18n: &I18n, lines: &mut Vec<String>) {
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_ip_address(),
+            or_dash(&self.ip_address)
+        ));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_bgp_asn(),
+            or_dash(&self.bgp_asn)
+        ));
+        if !self.device_name.is_empty() {
+            lines.push(format!("| {} | {} |", i18n.md_device(), self.device_name));
+        }
+        if !self.certificate_arn.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_certificate(),
+                self.certificate_arn
+            ));
+        }
+    }
+}
+
+impl VpnGatewayInfo {
+    fn attached_vpcs(&self) -> String {
+        // 분리된 VPC는 한동안 detached 상태로 남음
+        let vpcs: Vec<String> = self
+            .vpc_attachments
+            .iter()
+            .filter(|(_, state)| state != "detached")
+            .map(|(vpc_id, state)| {
+                if state == "attached" {
+                    name_index::label(vpc_id)
+                } else {
+                    format!("{} ({})", name_index::label(vpc_id), state)
+                }
+            })
+            .collect();
+        if vpcs.is_empty() {
+            "-".to_string()
+        } else {
+            vpcs.join(", ")
+        }
+    }
+
+    fn push_rows(&self, i18n: &I18n, lines: &mut Vec<String>) {
+        if let Some(asn) = self.amazon_side_asn {
+            lines.push(format!("| {} | {} |", i18n.md_amazon_side_asn(), asn));
+        }
+        if !self.availability_zone.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_availability_zone(),
+                self.availability_zone
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_attached_vpc(),
+            self.attached_vpcs()
+        ));
+    }
+}
+
+impl VpnDetail {
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() || self.name == self.id {
+            self.id.clone()
+        } else {
+            format!("{} - {}", self.name, self.id)
+        }
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let display_name = self.display_name();
+        let title = match &self.resource {
+            VpnResource::Connection(_) => i18n.site_to_site_vpn(),
+            VpnResource::CustomerGateway { .. } => i18n.md_customer_gateway(),
+            VpnResource::VpnGateway { .. } => i18n.md_virtual_private_gateway(),
+        };
+
+        let mut lines = vec![
+            format!("## {} ({})\n", title, display_name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), display_name),
+            format!("| {} | {} |", i18n.md_state(), self.state),
+        ];
+
+        match &self.resource {
+            VpnResource::Connection(connection) => {
+                connection.push_markdown(&i18n, &self.tags, &mut lines);
+            }
+            VpnResource::CustomerGateway {
+                gateway,
+                connections,
+            } => {
+                lines.push(format!("| {} | {} |", i18n.md_type(), gateway.gateway_type));
+                gateway.push_rows(&i18n, &mut lines);
+                push_tags(&i18n, &self.tags, &mut lines);
+                push_connections(&i18n, connections, &mut lines);
+            }
+            VpnResource::VpnGateway {
+                gateway,
+                connections,
+            } => {
+                lines.push(format!("| {} | {} |", i18n.md_type(), gateway.gateway_type));
+                gateway.push_rows(&i18n, &mut lines);
+                push_tags(&i18n, &self.tags, &mut lines);
+                push_connections(&i18n, connections, &mut lines);
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+impl VpnConnectionInfo {
+    fn push_markdown(&self, i18n: &I18n, tags: &[(String, String)], lines: &mut Vec<String>) {
+        lines.push(format!("| {} | {} |", i18n.md_type(), self.connection_type));
+        let customer_gateway = match &self.customer_gateway {
+            Some(gateway) if !gateway.endpoint_summary().is_empty() => format!(
+                "{} ({})",
+                name_index::label(&self.customer_gateway_id),
+                gateway.endpoint_summary()
+            ),
+            _ => name_index::label(&self.customer_gateway_id),
+        };
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_customer_gateway(),
+            customer_gateway
+        ));
+        if let Some(vpn_gateway_id) = &self.vpn_gateway_id {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_virtual_private_gateway(),
+                name_index::label(vpn_gateway_id)
+            ));
+        }
+        if let Some(transit_gateway_id) = &self.transit_gateway_id {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.transit_gateway(),
+                name_index::label(transit_gateway_id)
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_routing(),
+            if self.static_routes_only {
+                i18n.md_static_routing()
+            } else {
+                i18n.md_dynamic_routing()
+            }
+        ));
+        if self.acceleration {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_acceleration(),
+                i18n.md_enabled()
+            ));
+        }
+        if !self.local_network_cidr.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_local_network_cidr(),
+                self.local_network_cidr
+            ));
+        }
+        if !self.remote_network_cidr.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_remote_network_cidr(),
+                self.remote_network_cidr
+            ));
+        }
+        push_tags(i18n, tags, lines);
+
+        if !self.tunnels.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_tunnels()));
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                i18n.md_outside_ip(),
+                i18n.md_inside_cidr(),
+                i18n.md_state(),
+                i18n.md_status_message(),
+                i18n.md_last_changed(),
+                i18n.md_accepted_routes()
+            ));
+            lines.push("|:---|:---|:---|:---|:---|---:|".to_string());
+            for tunnel in &self.tunnels {
+                let inside_cidr = [&tunnel.inside_cidr, &tunnel.inside_ipv6_cidr]
+                    .into_iter()
+                    .filter(|cidr| !cidr.is_empty())
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                lines.push(format!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    tunnel.outside_ip,
+                    or_dash(&inside_cidr),
+                    or_dash(&tunnel.status),
+                    or_dash(&tunnel.status_message),
+                    or_dash(&tunnel.last_status_change),
+                    tunnel.accepted_routes
+                ));
+            }
+        }
+
+        if !self.routes.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_static_routes()));
+            lines.push(format!(
+                "| {} | {} | {} |",
+                i18n.md_destination(),
+                i18n.md_source(),
+                i18n.md_state()
+            ));
+            lines.push("|:---|:---|:---|".to_string());
+            for route in &self.routes {
+                lines.push(format!(
+                    "| {} | {} | {} |",
+                    route.destination, route.source, route.state
+                ));
+            }
+        }
+
+        if let Some(gateway) = &self.customer_gateway {
+            lines.push(format!("\n### {}", i18n.md_customer_gateway()));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| ID | {} |", name_index::label(&gateway.id)));
+            lines.push(format!("| {} | {} |", i18n.md_state(), gateway.state));
+            gateway.push_rows(i18n, lines);
+        }
+
+        if let Some(gateway) = &self.vpn_gateway {
+            lines.push(format!("\n### {}", i18n.md_virtual_private_gateway()));
+            lines.push(format!("| {} | {} |", i18n.item(), i18n.value()));
+            lines.push("|:---|:---|".to_string());
+            lines.push(format!("| ID | {} |", name_index::label(&gateway.id)));
+            lines.push(format!("| {} | {} |", i18n.md_state(), gateway.state));
+            gateway.push_rows(i18n, lines);
+        }
+    }
+
+    // 목록에 표시할 터널 상태 요약 (예: "Tunnels 1/2 UP")
+    fn tunnel_summary(&self) -> String {
+        if self.tunnels.is_empty() {
+            return String::new();
+        }
+        let up = self
+            .tunnels
+            .iter()
+            .filter(|tunnel| tunnel.status == "UP")
+            .count();
+        format!("Tunnels {}/{} UP", up, self.tunnels.len())
+    }
+}
+
+fn or_dash(value: &str) -> String {
+    if value.is_empty() {
+        "-".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn push_tags(i18n: &I18n, tags: &[(String, String)], lines: &mut Vec<String>) {
+    for (key, value) in tags {
+        if key != "Name" {
+            lines.push(format!("| {}-{} | {} |", i18n.tag(), key, value));
+        }
+    }
+}
+
+fn push_connections(i18n: &I18n, connections: &[String], lines: &mut Vec<String>) {
+    if connections.is_empty() {
+        return;
+    }
+    lines.push(format!("\n### {}", i18n.md_vpn_connections()));
+    for connection_id in connections {
+        lines.push(format!("- {}", name_index::label(connection_id)));
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> String {
+    tags.iter()
+        .find(|t| t.key == "Name")
+        .map(|t| t.value.clone())
+        .unwrap_or_default()
+}
+
+fn sorted_tags(tags: &[Tag]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = tags
+        .iter()
+        .map(|t| (t.key.clone(), t.value.clone()))
+        .collect();
+    tags.sort();
+    tags
+}
+
+/// Parse `describe-vpn-connections` output, skipping deleted connections.
+fn parse_vpn_connections_output(output: &str) -> Option<Vec<VpnDetail>> {
+    let response: VpnConnectionsResponse = serde_json::from_str(output).ok()?;
+
+    let connections = response
+        .vpn_connections
+        .into_iter()
+        // 삭제된 연결은 한동안 조회 결과에 남음
+        .filter(|connection| connection.state != "deleted")
+        .map(|connection| {
+            let options = connection.options.unwrap_or_default();
+            // 터널 내부 CIDR(옵션)과 상태(VgwTelemetry)를 외부 IP로 맞춤
+            let tunnels = options
+                .tunnel_options
+                .iter()
+                .map(|tunnel| {
+                    let telemetry = connection
+                        .vgw_telemetry
+                        .iter()
+                        .find(|t| t.outside_ip_address == tunnel.outside_ip_address);
+                    VpnTunnelInfo {
+                        outside_ip: tunnel.outside_ip_address.clone(),
+                        inside_cidr: tunnel.tunnel_inside_cidr.clone(),
+                        inside_ipv6_cidr: tunnel.tunnel_inside_ipv6_cidr.clone(),
+                        status: telemetry.map(|t| t.status.clone()).unwrap_or_default(),
+                        status_message: telemetry
+                            .map(|t| t.status_message.clone())
+                            .unwrap_or_default(),
+                        last_status_change: telemetry
+                            .map(|t| t.last_status_change.clone())
+                            .unwrap_or_default(),
+                        accepted_routes: telemetry.map(|t| t.accepted_route_count).unwrap_or(0),
+                    }
+                })
+                .collect();
+            let name = name_tag(&connection.tags);
+            VpnDetail {
+                name: if name.is_empty() {
+                    connection.vpn_connection_id.clone()
+                } else {
+                    name
+                },
+                id: connection.vpn_connection_id,
+                state: connection.state,
+                tags: sorted_tags(&connection.tags),
+                resource: VpnResource::Connection(Box::new(VpnConnectionInfo {
+                    connection_type: connection.connection_type,
+                    customer_gateway_id: connection.customer_gateway_id,
+                    vpn_gateway_id: connection.vpn_gateway_id.filter(|id| !id.is_empty()),
+                    transit_gateway_id: connection.transit_gateway_id.filter(|id| !id.is_empty()),
+                    static_routes_only: options.static_routes_only,
+                    acceleration: options.enable_acceleration,
+                    local_network_cidr: options.local_ipv4_network_cidr,
+                    remote_network_cidr: options.remote_ipv4_network_cidr,
+                    tunnels,
+                    routes: connection
+                        .routes
+                        .into_iter()
+                        .map(|route| VpnStaticRouteInfo {
+                            destination: route.destination_cidr_block,
+                            source: route.source,
+                            state: route.state,
+                        })
+                        .collect(),
+                    customer_gateway: None,
+                    vpn_gateway: None,
+                })),
+            }
+        })
+        .collect();
+    Some(connections)
+}
+
+/// Parse `describe-customer-gateways` output, skipping deleted gateways.
+fn parse_customer_gateways_output(output: &str) -> Option<Vec<CustomerGatewayInfo>> {
+    let response: CustomerGatewaysResponse = serde_json::from_str(output).ok()?;
+
+    let gateways = response
+        .customer_gateways
+        .into_iter()
+        .filter(|gateway| gateway.state != "deleted")
+        .map(|gateway| {
+            let name = name_tag(&gateway.tags);
+            CustomerGatewayInfo {
+                name: if name.is_empty() {
+                    gateway.customer_gateway_id.clone()
+                } else {
+                    name
+                },
+                id: gateway.customer_gateway_id,
+                state: gateway.state,
+                gateway_type: gateway.gateway_type,
+                ip_address: gateway.ip_address,
+                bgp_asn: gateway.bgp_asn,
+                device_name: gateway.device_name,
+                certificate_arn: gateway.certificate_arn,
+                tags: sorted_tags(&gateway.tags),
+            }
+        })
+        .collect();
+    Some(gateways)
+}
+
+/// Parse `describe-vpn-gateways` output, skipping deleted gateways.
+fn parse_vpn_gateways_output(output: &str) -> Option<Vec<VpnGatewayInfo>> {
+    let response: VpnGatewaysResponse = serde_json::from_str(output).ok()?;
+
+    let gateways = response
+        .vpn_gateways
+        .into_iter()
+        .filter(|gateway| gateway.state != "deleted")
+        .map(|gateway| {
+            let name = name_tag(&gateway.tags);
+            VpnGatewayInfo {
+                name: if name.is_empty() {
+                    gateway.vpn_gateway_id.clone()
+                } else {
+                    name
+                },
+                id: gateway.vpn_gateway_id,
+                state: gateway.state,
+                gateway_type: gateway.gateway_type,
+                availability_zone: gateway.availability_zone,
+                amazon_side_asn: gateway.amazon_side_asn,
+                vpc_attachments: gateway
+                    .vpc_attachments
+                    .into_iter()
+                    .map(|attachment| (attachment.vpc_id, attachment.state))
+                    .collect(),
+                tags: sorted_tags(&gateway.tags),
+            }
+        })
+        .collect();
+    Some(gateways)
+}
+
+fn describe_vpn_connections(filter_args: &[&str]) -> Vec<VpnDetail> {
+    let mut args = vec!["ec2", "describe-vpn-connections"];
+    args.extend_from_slice(filter_args);
+    args.extend_from_slice(&["--output", "json"]);
+    run_aws_cli(&args)
+        .and_then(|output| parse_vpn_connections_output(&output))
+        .unwrap_or_default()
+}
+
+fn describe_customer_gateways(filter_args: &[&str]) -> Vec<CustomerGatewayInfo> {
+    let mut args = vec!["ec2", "describe-customer-gateways"];
+    args.extend_from_slice(filter_args);
+    args.extend_from_slice(&["--output", "json"]);
+    run_aws_cli(&args)
+        .and_then(|output| parse_customer_gateways_output(&output))
+        .unwrap_or_default()
+}
+
+fn describe_vpn_gateways(filter_args: &[&str]) -> Vec<VpnGatewayInfo> {
+    let mut args = vec!["ec2", "describe-vpn-gateways"];
+    args.extend_from_slice(filter_args);
+    args.extend_from_slice(&["--output", "json"]);
+    run_aws_cli(&args)
+        .and_then(|output| parse_vpn_gateways_output(&output))
+        .unwrap_or_default()
+}
+
+// VPN 연결, 고객 게이트웨이, 가상 프라이빗 게이트웨이 순으로 각각 이름순 정렬
+fn vpn_resources(
+    connections: Vec<VpnDetail>,
+    customer_gateways: Vec<CustomerGatewayInfo>,
+    vpn_gateways: Vec<VpnGatewayInfo>,
+) -> Vec<AwsResource> {
+    let resource = |name: String, id: String, state: String, cidr: String| AwsResource {
+        name,
+        id,
+        state,
+        az: String::new(),
+        cidr,
+        owner_id: String::new(),
+    };
+
+    let mut connections: Vec<AwsResource> = connections
+        .into_iter()
+        .map(|detail| {
+            let summary = match &detail.resource {
+                VpnResource::Connection(connection) => connection.tunnel_summary(),
+                _ => String::new(),
+            };
+            resource(detail.name, detail.id, detail.state, summary)
+        })
+        .collect();
+    let mut customer_gateways: Vec<AwsResource> = customer_gateways
+        .into_iter()
+        .map(|gateway| {
+            let summary = gateway.endpoint_summary();
+            resource(gateway.name, gateway.id, gateway.state, summary)
+        })
+        .collect();
+    let mut vpn_gateways: Vec<AwsResource> = vpn_gateways
+        .into_iter()
+        .map(|gateway| {
+            let summary = gateway
+                .amazon_side_asn
+                .map(|asn| format!("ASN {}", asn))
+                .unwrap_or_default();
+            resource(gateway.name, gateway.id, gateway.state, summary)
+        })
+        .collect();
+    for group in [&mut connections, &mut customer_gateways, &mut vpn_gateways] {
+        group.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    connections
+        .into_iter()
+        .chain(customer_gateways)
+        .chain(vpn_gateways)
+        .collect()
+}
+
+/// List VPN connections, customer gateways and virtual private gateways.
+pub fn list_vpn_resources() -> Vec<AwsResource> {
+    let resources = vpn_resources(
+        describe_vpn_connections(&[]),
+        describe_customer_gateways(&[]),
+        describe_vpn_gateways(&[]),
+    );
+    name_index::record_resources(&resources);
+    resources
+}
+
+/// VPN connection (`vpn-`), customer gateway (`cgw-`) or virtual private gateway (`vgw-`) detail.
+pub fn get_vpn_detail(vpn_id: &str) -> Option<VpnDetail> {
+    if vpn_id.starts_with("cgw-") {
+        let gateway = describe_customer_gateways(&["--customer-gateway-ids", vpn_id])
+            .into_iter()
+            .next()?;
+        let filter = format!("Name=customer-gateway-id,Values={}", vpn_id);
+        return Some(gateway_detail(
+            gateway.name.clone(),
+            gateway.id.clone(),
+            gateway.state.clone(),
+            gateway.tags.clone(),
+            |connections| VpnResource::CustomerGateway {
+                gateway,
+                connections,
+            },
+            &filter,
+        ));
+    }
+    if vpn_id.starts_with("vgw-") {
+        let gateway = describe_vpn_gateways(&["--vpn-gateway-ids", vpn_id])
+            .into_iter()
+            .next()?;
+        let filter = format!("Name=vpn-gateway-id,Values={}", vpn_id);
+        return Some(gateway_detail(
+            gateway.name.clone(),
+            gateway.id.clone(),
+            gateway.state.clone(),
+            gateway.tags.clone(),
+            |connections| VpnResource::VpnGateway {
+                gateway,
+                connections,
+            },
+            &filter,
+        ));
+    }
+
+    let mut detail = describe_vpn_connections(&["--vpn-connection-ids", vpn_id])
+        .into_iter()
+        .next()?;
+    // 게이트웨이 조회 실패는 상세 정보 전체를 실패로 보지 않음
+    if let VpnResource::Connection(connection) = &mut detail.resource {
+        connection.customer_gateway = describe_customer_gateways(&[
+            "--customer-gateway-ids",
+            &connection.customer_gateway_id,
+        ])
+        .into_iter()
+        .next();
+        if let Some(vpn_gateway_id) = &connection.vpn_gateway_id {
+            connection.vpn_gateway = describe_vpn_gateways(&["--vpn-gateway-ids", vpn_gateway_id])
+                .into_iter()
+                .next();
+        }
+    }
+    Some(detail)
+}
+
+// 게이트웨이 문서에는 이 게이트웨이를 쓰는 VPN 연결을 함께 표시
+fn gateway_detail(
+    name: String,
+    id: String,
+    state: String,
+    tags: Vec<(String, String)>,
+    resource: impl FnOnce(Vec<String>) -> VpnResource,
+    connection_filter: &str,
+) -> VpnDetail {
+    let connections = describe_vpn_connections(&["--filters", connection_filter])
+        .into_iter()
+        .map(|connection| connection.id)
+        .collect();
+    VpnDetail {
+        name,
+        id,
+        state,
+        tags,
+        resource: resource(connections),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CustomerGatewayInfo, VpnDetail, VpnResource, parse_customer_gateways_output,
+        parse_vpn_connections_output, parse_vpn_gateways_output, vpn_resources,
+    };
+    use crate::i18n::Language;
+
+    const CONNECTIONS: &str = r#"
+    {
+      "VpnConnections": [
+        {
+          "VpnConnectionId": "vpn-1",
+          "State": "available",
+          "Type": "ipsec.1",
+          "Category": "VPN",
+          "CustomerGatewayId": "cgw-1",
+          "VpnGatewayId": "vgw-1",
+          "TransitGatewayId": null,
+          "Options": {
+            "EnableAcceleration": false,
+            "StaticRoutesOnly": true,
+            "LocalIpv4NetworkCidr": "0.0.0.0/0",
+            "RemoteIpv4NetworkCidr": "0.0.0.0/0",
+            "TunnelInsideIpVersion": "ipv4",
+            "TunnelOptions": [
+              {"OutsideIpAddress": "3.3.3.1", "TunnelInsideCidr": "169.254.10.0/30"},
+              {"OutsideIpAddress": "3.3.3.2", "TunnelInsideCidr": "169.254.11.0/30"}
+            ]
+          },
+          "Routes": [{"DestinationCidrBlock": "192.168.0.0/16", "Source": "Static", "State": "available"}],
+          "VgwTelemetry": [
+            {"OutsideIpAddress": "3.3.3.2", "Status": "DOWN", "StatusMessage": "", "LastStatusChange": "2026-01-01T00:00:00Z", "AcceptedRouteCount": 0},
+            {"OutsideIpAddress": "3.3.3.1", "Status": "UP", "StatusMessage": "1 BGP ROUTES", "LastStatusChange": "2026-01-02T00:00:00Z", "AcceptedRouteCount": 1}
+          ],
+          "Tags": [{"Key": "Name", "Value": "office"}]
+        },
+        {
+          "VpnConnectionId": "vpn-2",
+          "State": "deleted",
+          "Type": "ipsec.1",
+          "CustomerGatewayId": "cgw-1"
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn parse_vpn_outputs_match_tunnel_status_and_order_resources() {
+        let connections = parse_vpn_connections_output(CONNECTIONS).expect("connections");
+        assert_eq!(connections.len(), 1);
+        let office = &connections[0];
+        assert_eq!(office.name, "office");
+        let VpnResource::Connection(connection) = &office.resource else {
+            panic!("expected a VPN connection");
+        };
+        assert_eq!(connection.vpn_gateway_id.as_deref(), Some("vgw-1"));
+        assert_eq!(connection.transit_gateway_id, None);
+        assert!(connection.static_routes_only);
+        // 텔레메트리 순서와 무관하게 외부 IP로 터널 상태를 맞춤
+        assert_eq!(connection.tunnels[0].outside_ip, "3.3.3.1");
+        assert_eq!(connection.tunnels[0].inside_cidr, "169.254.10.0/30");
+        assert_eq!(connection.tunnels[0].status, "UP");
+        assert_eq!(connection.tunnels[0].accepted_routes, 1);
+        assert_eq!(connection.tunnels[1].status, "DOWN");
+        assert_eq!(connection.routes[0].destination, "192.168.0.0/16");
+
+        let customer_gateways = parse_customer_gateways_output(
+            r#"{"CustomerGateways": [
+                {"CustomerGatewayId": "cgw-1", "State": "available", "Type": "ipsec.1", "IpAddress": "203.0.113.10", "BgpAsn": "65000", "Tags": []},
+                {"CustomerGatewayId": "cgw-2", "State": "deleted", "Type": "ipsec.1", "IpAddress": "203.0.113.20", "BgpAsn": "65000"}
+            ]}"#,
+        )
+        .expect("customer gateways");
+        let vpn_gateways = parse_vpn_gateways_output(
+            r#"{"VpnGateways": [
+                {"VpnGatewayId": "vgw-1", "State": "available", "Type": "ipsec.1", "AmazonSideAsn": 64512,
+                 "VpcAttachments": [{"VpcId": "vpc-1", "State": "attached"}], "Tags": [{"Key": "Name", "Value": "main-vgw"}]}
+            ]}"#,
+        )
+        .expect("vpn gateways");
+        assert_eq!(customer_gateways.len(), 1);
+        assert_eq!(customer_gateways[0].name, "cgw-1");
+        assert_eq!(
+            vpn_gateways[0].vpc_attachments,
+            vec![("vpc-1".to_string(), "attached".to_string())]
+        );
+
+        let resources = vpn_resources(connections, customer_gateways, vpn_gateways);
+        let ids: Vec<&str> = resources.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["vpn-1", "cgw-1", "vgw-1"]);
+        assert_eq!(resources[0].cidr, "Tunnels 1/2 UP");
+        assert_eq!(resources[1].cidr, "203.0.113.10 (ASN 65000)");
+        assert_eq!(resources[2].cidr, "ASN 64512");
+    }
+
+    #[test]
+    fn vpn_markdown_shows_tunnels_and_gateway_connections() {
+        let mut connections = parse_vpn_connections_output(CONNECTIONS).expect("connections");
+        let mut detail = connections.remove(0);
+        if let VpnResource::Connection(connection) = &mut detail.resource {
+            connection.customer_gateway = Some(CustomerGatewayInfo {
+                name: "hq-router".to_string(),
+                id: "cgw-md-1".to_string(),
+                state: "available".to_string(),
+                gateway_type: "ipsec.1".to_string(),
+                ip_address: "203.0.113.10".to_string(),
+                bgp_asn: "65000".to_string(),
+                device_name: String::new(),
+                certificate_arn: String::new(),
+                tags: vec![],
+            });
+        }
+
+        let md = detail.to_markdown(Language::English);
+        assert!(md.contains("## Site-to-Site VPN (office - vpn-1)"));
+        assert!(md.contains("| Routing | Static |"));
+        assert!(md.contains("### Tunnels"));
+        assert!(md.contains(
+            "| 3.3.3.1 | 169.254.10.0/30 | UP | 1 BGP ROUTES | 2026-01-02T00:00:00Z | 1 |"
+        ));
+        assert!(md.contains("| 3.3.3.2 | 169.254.11.0/30 | DOWN | - | 2026-01-01T00:00:00Z | 0 |"));
+        assert!(md.contains("| 192.168.0.0/16 | Static | available |"));
+        assert!(md.contains("| IP Address | 203.0.113.10 |"));
+        assert!(!md.contains("Tag-Name"));
+
+        let gateway = VpnDetail {
+            name: "hq-router".to_string(),
+            id: "cgw-md-1".to_string(),
+            state: "available".to_string(),
+            tags: vec![],
+            resource: VpnResource::CustomerGateway {
+                gateway: CustomerGatewayInfo {
+                    name: "hq-router".to_string(),
+                    id: "cgw-md-1".to_string(),
+                    state: "available".to_string(),
+                    gateway_type: "ipsec.1".to_string(),
+                    ip_address: "203.0.113.10".to_string(),
+                    bgp_asn: "65000".to_string(),
+                    device_name: "edge-01".to_string(),
+                    certificate_arn: String::new(),
+                    tags: vec![],
+                },
+                connections: vec!["vpn-md-1".to_string()],
+            },
+        };
+        let md = gateway.to_markdown(Language::English);
+        assert!(md.contains("## Customer Gateway (hq-router - cgw-md-1)"));
+        assert!(md.contains("| BGP ASN | 65000 |"));
+        assert!(md.contains("| Device | edge-01 |"));
+        assert!(md.contains("### VPN Connections\n- vpn-md-1"));
+    }
+}
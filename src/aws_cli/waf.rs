@@ -0,0 +1,190 @@
+pub use crate::aws_cli::waf_sdk::{get_waf_web_acl_detail, list_waf_web_acls};
+use crate::i18n::{I18n, Language};
+use serde::Serialize;
+
+/// WAFv2 WebACL with its rules and the resources it protects.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WafWebAclDetail {
+    pub name: String,
+    pub id: String,
+    pub arn: String,
+    // REGIONAL (ALB, API Gateway 등) 또는 CLOUDFRONT (us-east-1에서 관리)
+    pub scope: String,
+    pub region: String,
+    pub description: String,
+    // Allow, Block
+    pub default_action: String,
+    // WebACL 용량 단위 (WCU)
+    pub capacity: i64,
+    pub metric_name: String,
+    pub sampled_requests: bool,
+    pub rules: Vec<WafRuleInfo>,
+    pub associations: Vec<WafAssociationInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WafRuleInfo {
+    pub priority: i32,
+    pub name: String,
+    // Allow, Block, Count, CAPTCHA, Challenge 또는 규칙 그룹의 Override: None/Count
+    pub action: String,
+    // "Managed: AWS/AWSManagedRulesCommonRuleSet", "Rate: 2000 / 300s (IP)" 같은 조건 요약
+    pub statement: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WafAssociationInfo {
+    // ALB, API Gateway, CloudFront
+    pub resource_type: String,
+    pub resource: String,
+}
+
+impl WafWebAclDetail {
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let scope = if self.scope == "REGIONAL" && !self.region.is_empty() {
+            format!("{} ({})", self.scope, self.region)
+        } else {
+            self.scope.clone()
+        };
+
+        let mut lines = vec![
+            format!("## WAF Web ACL ({})\n", self.name),
+            format!("| {} | {} |", i18n.item(), i18n.value()),
+            "|:---|:---|".to_string(),
+            format!("| {} | {} |", i18n.md_name(), self.name),
+            format!("| ID | {} |", self.id),
+            format!("| {} | {} |", i18n.md_scope(), scope),
+        ];
+        if !self.description.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_description(),
+                self.description
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_default_action(),
+            self.default_action
+        ));
+        lines.push(format!("| {} | {} |", i18n.md_capacity(), self.capacity));
+        if !self.metric_name.is_empty() {
+            lines.push(format!(
+                "| {} | {} |",
+                i18n.md_metric_name(),
+                self.metric_name
+            ));
+        }
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_sampled_requests(),
+            if self.sampled_requests {
+                i18n.md_enabled()
+            } else {
+                i18n.md_disabled()
+            }
+        ));
+
+        if !self.rules.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_rules()));
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                i18n.md_priority(),
+                i18n.md_name(),
+                i18n.md_action(),
+                i18n.md_statement()
+            ));
+            lines.push("|---:|:---|:---|:---|".to_string());
+            for rule in &self.rules {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    rule.priority, rule.name, rule.action, rule.statement
+                ));
+            }
+        }
+
+        if !self.associations.is_empty() {
+            lines.push(format!("\n### {}", i18n.md_associated_resources()));
+            lines.push(format!("| {} | {} |", i18n.md_type(), i18n.md_resource()));
+            lines.push("|:---|:---|".to_string());
+            for association in &self.associations {
+                lines.push(format!(
+                    "| {} | {} |",
+                    association.resource_type, association.resource
+                ));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WafAssociationInfo, WafRuleInfo, WafWebAclDetail};
+    use crate::i18n::Language;
+
+    fn sample_web_acl() -> WafWebAclDetail {
+        WafWebAclDetail {
+            name: "checkout-acl".to_string(),
+            id: "a1b2c3d4".to_string(),
+            arn: "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/checkout-acl/a1b2c3d4"
+                .to_string(),
+            scope: "REGIONAL".to_string(),
+            region: "ap-northeast-2".to_string(),
+            description: String::new(),
+            default_action: "Allow".to_string(),
+            capacity: 725,
+            metric_name: "checkout-acl".to_string(),
+            sampled_requests: true,
+            rules: vec![
+                WafRuleInfo {
+                    priority: 0,
+                    name: "common".to_string(),
+                    action: "Override: None".to_string(),
+                    statement: "Managed: AWS/AWSManagedRulesCommonRuleSet".to_string(),
+                },
+                WafRuleInfo {
+                    priority: 1,
+                    name: "rate-limit".to_string(),
+                    action: "Block".to_string(),
+                    statement: "Rate: 2000 / 300s (IP)".to_string(),
+                },
+            ],
+            associations: vec![WafAssociationInfo {
+                resource_type: "ALB".to_string(),
+                resource: "checkout-alb".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn waf_markdown_lists_rules_and_associations() {
+        let md = sample_web_acl().to_markdown(Language::English);
+        assert!(md.contains("## WAF Web ACL (checkout-acl)"));
+        assert!(md.contains("| Scope | REGIONAL (ap-northeast-2) |"));
+        assert!(md.contains("| Default Action | Allow |"));
+        assert!(md.contains("| Capacity (WCU) | 725 |"));
+        assert!(!md.contains("| Description |"));
+        assert!(md.contains("### Rules"));
+        assert!(md.contains(
+            "| 0 | common | Override: None | Managed: AWS/AWSManagedRulesCommonRuleSet |"
+        ));
+        assert!(md.contains("| 1 | rate-limit | Block | Rate: 2000 / 300s (IP) |"));
+        assert!(md.contains("### Associated Resources"));
+        assert!(md.contains("| ALB | checkout-alb |"));
+    }
+
+    #[test]
+    fn waf_markdown_skips_empty_sections_for_cloudfront_acl() {
+        let mut detail = sample_web_acl();
+        detail.scope = "CLOUDFRONT".to_string();
+        detail.region = "us-east-1".to_string();
+        detail.rules.clear();
+        detail.associations.clear();
+        let md = detail.to_markdown(Language::Korean);
+        assert!(md.contains("| CLOUDFRONT |"));
+        assert!(!md.contains("###"));
+    }
+}
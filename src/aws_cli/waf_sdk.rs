@@ -0,0 +1,592 @@
+use crate::aws_cli::arn::Arn;
+use crate::aws_cli::common::{
+    AwsResource, current_partition, get_runtime, get_sdk_config, sdk_client,
+};
+use crate::aws_cli::partition::Partition;
+use crate::aws_cli::waf::{WafAssociationInfo, WafRuleInfo, WafWebAclDetail};
+use aws_sdk_wafv2::types::{
+    DefaultAction, ResourceType, Rule, Scope, Statement, WebAcl, WebAclSummary,
+};
+
+/// List regional WebACLs of the current region and CloudFront WebACLs using AWS SDK
+pub fn list_waf_web_acls() -> Vec<AwsResource> {
+    get_runtime().block_on(list_waf_web_acls_async())
+}
+
+async fn list_waf_web_acls_async() -> Vec<AwsResource> {
+    let config = get_sdk_config().await;
+    let partition = current_partition();
+
+    let mut web_acls = list_scope(&config, Scope::Regional).await;
+    // CloudFront용 WebACL은 파티션의 글로벌 리전(us-east-1)에서만 조회 가능, GovCloud에는 CloudFront가 없음
+    if partition != Partition::AwsUsGov {
+        let cloudfront_config = config
+            .to_builder()
+            .region(aws_config::Region::new(partition.global_region()))
+            .build();
+        web_acls.extend(list_scope(&cloudfront_config, Scope::Cloudfront).await);
+    }
+    web_acls
+}
+
+async fn list_scope(config: &aws_config::SdkConfig, scope: Scope) -> Vec<AwsResource> {
+    let client = sdk_client!(aws_sdk_wafv2, config);
+    let mut web_acls = Vec::new();
+    let mut next_marker: Option<String> = None;
+
+    loop {
+        let mut request = client.list_web_acls().scope(scope.clone()).limit(100);
+        if let Some(marker) = &next_marker {
+            request = request.next_marker(marker);
+        }
+        match request.send().await {
+            Ok(output) => {
+                web_acls.extend(
+                    output
+                        .web_acls()
+                        .iter()
+                        .map(|summary| map_web_acl_resource(summary, &scope)),
+                );
+                // 마지막 페이지에서도 빈 마커가 아닌 값이 올 수 있어 결과 유무로도 판단
+                next_marker = output
+                    .next_marker()
+                    .filter(|_| !output.web_acls().is_empty())
+                    .map(str::to_string);
+                if next_marker.is_none() {
+                    break;
+                }
+            }
+            Err(e) => {
+                tracing::error!("Error listing {} WAF WebACLs: {:?}", scope.as_str(), e);
+                break;
+            }
+        }
+    }
+
+    web_acls.sort_by(|a, b| a.name.cmp(&b.name));
+    web_acls
+}
+
+/// Get WAF WebACL detail (rules and associated resources) by ARN using AWS SDK
+pub fn get_waf_web_acl_detail(web_acl_arn: &str) -> Option<WafWebAclDetail> {
+    get_runtime().block_on(get_waf_web_acl_detail_async(web_acl_arn))
+}
+
+async fn get_waf_web_acl_detail_async(web_acl_arn: &str) -> Option<WafWebAclDetail> {
+    let (scope, region, name, id) = parse_web_acl_arn(web_acl_arn)?;
+    // ARN의 리전으로 조회 (블루프린트에서 다른 리전의 WebACL을 열 때도 동일)
+    let config = get_sdk_config()
+        .await
+        .to_builder()
+        .region(aws_config::Region::new(region.to_string()))
+        .build();
+    let client = sdk_client!(aws_sdk_wafv2, &config);
+
+    let output = match client
+        .get_web_acl()
+        .name(name)
+        .scope(scope.clone())
+        .id(id)
+        .send()
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::error!("Error getting WAF WebACL {}: {:?}", web_acl_arn, e);
+            return None;
+        }
+    };
+    let web_acl = output.web_acl()?;
+
+    let associations = if scope == Scope::Cloudfront {
+        list_cloudfront_associations(&config, web_acl_arn).await
+    } else {
+        let mut associations = Vec::new();
+        for resource_type in [
+            ResourceType::ApplicationLoadBalancer,
+            ResourceType::ApiGateway,
+        ] {
+            match client
+                .list_resources_for_web_acl()
+                .web_acl_arn(web_acl_arn)
+                .resource_type(resource_type.clone())
+                .send()
+                .await
+            {
+                Ok(output) => associations.extend(
+                    output
+                        .resource_arns()
+                        .iter()
+                        .map(|arn| map_regional_association(&resource_type, arn)),
+                ),
+                // 연결 조회 실패는 상세 정보 전체를 실패로 보지 않음
+                Err(e) => tracing::error!(
+                    "Error listing {} resources for WAF WebACL {}: {:?}",
+                    resource_type.as_str(),
+                    web_acl_arn,
+                    e
+                ),
+            }
+        }
+        associations
+    };
+
+    Some(map_web_acl_detail(web_acl, &scope, region, associations))
+}
+
+// WebACL을 연결한 CloudFront 배포는 CloudFront API에서 조회
+async fn list_cloudfront_associations(
+    config: &aws_config::SdkConfig,
+    web_acl_arn: &str,
+) -> Vec<WafAssociationInfo> {
+    let client = sdk_client!(aws_sdk_cloudfront, config);
+    match client
+        .list_distributions_by_web_acl_id()
+        .web_acl_id(web_acl_arn)
+        .send()
+        .await
+    {
+        Ok(output) => output
+            .distribution_list()
+            .map(|list| list.items())
+            .unwrap_or_default()
+            .iter()
+            .map(|distribution| WafAssociationInfo {
+                resource_type: "CloudFront".to_string(),
+                resource: format!("{} ({})", distribution.id(), distribution.domain_name()),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::error!(
+                "Error listing CloudFront distributions for WAF WebACL {}: {:?}",
+                web_acl_arn,
+                e
+            );
+            Vec::new()
+        }
+    }
+}
+
+// arn:aws:wafv2:<region>:<account>:regional/webacl/<name>/<id> 또는 global/webacl/<name>/<id>
+fn parse_web_acl_arn(web_acl_arn: &str) -> Option<(Scope, &str, &str, &str)> {
+    let arn = Arn::parse(web_acl_arn)?;
+    let mut parts = arn.resource.split('/');
+    let scope = match parts.next()? {
+        "regional" => Scope::Regional,
+        "global" => Scope::Cloudfront,
+        _ => return None,
+    };
+    if parts.next()? != "webacl" {
+        return None;
+    }
+    let name = parts.next()?;
+    let id = parts.next()?;
+    let region = if scope == Scope::Cloudfront {
+        Partition::parse(arn.partition)
+            .unwrap_or_default()
+            .global_region()
+    } else {
+        arn.region
+    };
+    Some((scope, region, name, id))
+}
+
+// 목록에는 범위와 설명을 표시 (리소스 ID는 ARN)
+fn map_web_acl_resource(summary: &WebAclSummary, scope: &Scope) -> AwsResource {
+    let name = summary.name().unwrap_or_default().to_string();
+    AwsResource {
+        id: summary
+            .arn()
+            .map(str::to_string)
+            .unwrap_or_else(|| name.clone()),
+        name,
+        state: scope.as_str().to_string(),
+        az: String::new(),
+        cidr: summary.description().unwrap_or_default().to_string(),
+        owner_id: String::new(),
+    }
+}
+
+fn map_regional_association(
+    resource_type: &ResourceType,
+    resource_arn: &str,
+) -> WafAssociationInfo {
+    let label = match resource_type {
+        ResourceType::ApplicationLoadBalancer => "ALB",
+        ResourceType::ApiGateway => "API Gateway",
+        other => other.as_str(),
+    };
+    // ALB는 이름, API Gateway는 "<api-id>/<stage>"로 표시
+    let resource = Arn::parse(resource_arn)
+        .map(|arn| match resource_type {
+            ResourceType::ApplicationLoadBalancer => arn.resource_name().to_string(),
+            _ => arn
+                .resource
+                .trim_start_matches("/restapis/")
+                .replace("/stages/", "/"),
+        })
+        .unwrap_or_else(|| resource_arn.to_string());
+    WafAssociationInfo {
+        resource_type: label.to_string(),
+        resource,
+    }
+}
+
+fn default_action_label(action: Option<&DefaultAction>) -> String {
+    match action {
+        Some(action) if action.block().is_some() => "Block".to_string(),
+        Some(action) if action.allow().is_some() => "Allow".to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+fn rule_action_label(rule: &Rule) -> String {
+    if let Some(action) = rule.action() {
+        let label = if action.block().is_some() {
+            "Block"
+        } else if action.allow().is_some() {
+            "Allow"
+        } else if action.count().is_some() {
+            "Count"
+        } else if action.captcha().is_some() {
+            "CAPTCHA"
+        } else if action.challenge().is_some() {
+            "Challenge"
+        } else {
+            "-"
+        };
+        return label.to_string();
+    }
+    // 규칙 그룹 참조 규칙은 action 대신 override_action을 가짐
+    match rule.override_action() {
+        Some(action) if action.count().is_some() => "Override: Count".to_string(),
+        Some(_) => "Override: None".to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn statement_summary(statement: &Statement) -> String {
+    if let Some(managed) = statement.managed_rule_group_statement() {
+        let mut summary = format!("Managed: {}/{}", managed.vendor_name(), managed.name());
+        if let Some(version) = managed.version() {
+            summary.push_str(&format!(" ({})", version));
+        }
+        summary
+    } else if let Some(reference) = statement.rule_group_reference_statement() {
+        format!(
+            "Rule group: {}",
+            Arn::parse(reference.arn())
+                .map(|arn| arn.resource_name())
+                .unwrap_or(reference.arn())
+        )
+    } else if let Some(rate) = statement.rate_based_statement() {
+        format!(
+            "Rate: {} / {}s ({})",
+            rate.limit(),
+            // 평가 기간을 지정하지 않으면 기본 5분
+            if rate.evaluation_window_sec() > 0 {
+                rate.evaluation_window_sec()
+            } else {
+                300
+            },
+            rate.aggregate_key_type().as_str()
+        )
+    } else if let Some(ip_set) = statement.ip_set_reference_statement() {
+        format!(
+            "IP set: {}",
+            Arn::parse(ip_set.arn())
+                .map(|arn| arn.resource_name())
+                .unwrap_or(ip_set.arn())
+        )
+    } else if let Some(geo) = statement.geo_match_statement() {
+        let countries: Vec<&str> = geo
+            .country_codes()
+            .iter()
+            .map(|code| code.as_str())
+            .collect();
+        format!("Geo: {}", countries.join(", "))
+    } else if let Some(label) = statement.label_match_statement() {
+        format!("Label: {}", label.key())
+    } else if let Some(and) = statement.and_statement() {
+        join_statements(and.statements(), " AND ")
+    } else if let Some(or) = statement.or_statement() {
+        join_statements(or.statements(), " OR ")
+    } else if let Some(not) = statement.not_statement() {
+        format!(
+            "NOT {}",
+            not.statement()
+                .map(statement_summary)
+                .unwrap_or_else(|| "-".to_string())
+        )
+    } else if statement.byte_match_statement().is_some() {
+        "Byte match".to_string()
+    } else if statement.sqli_match_statement().is_some() {
+        "SQL injection".to_string()
+    } else if statement.xss_match_statement().is_some() {
+        "XSS".to_string()
+    } else if statement.size_constraint_statement().is_some() {
+        "Size constraint".to_string()
+    } else if statement.regex_pattern_set_reference_statement().is_some() {
+        "Regex pattern set".to_string()
+    } else if statement.regex_match_statement().is_some() {
+        "Regex match".to_string()
+    } else if statement.asn_match_statement().is_some() {
+        "ASN match".to_string()
+    } else {
+        "-".to_string()
+    }
+}
+
+fn join_statements(statements: &[Statement], separator: &str) -> String {
+    let parts: Vec<String> = statements.iter().map(statement_summary).collect();
+    format!("({})", parts.join(separator))
+}
+
+fn map_web_acl_detail(
+    web_acl: &WebAcl,
+    scope: &Scope,
+    region: &str,
+    associations: Vec<WafAssociationInfo>,
+) -> WafWebAclDetail {
+    let mut rules: Vec<WafRuleInfo> = web_acl
+        .rules()
+        .iter()
+        .map(|rule| WafRuleInfo {
+            priority: rule.priority(),
+            name: rule.name().to_string(),
+            action: rule_action_label(rule),
+            statement: rule
+                .statement()
+                .map(statement_summary)
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect();
+    rules.sort_by_key(|rule| rule.priority);
+
+    WafWebAclDetail {
+        name: web_acl.name().to_string(),
+        id: web_acl.id().to_string(),
+        arn: web_acl.arn().to_string(),
+        scope: scope.as_str().to_string(),
+        region: region.to_string(),
+        description: web_acl.description().unwrap_or_default().to_string(),
+        default_action: default_action_label(web_acl.default_action()),
+        capacity: web_acl.capacity(),
+        metric_name: web_acl
+            .visibility_config()
+            .map(|config| config.metric_name().to_string())
+            .unwrap_or_default(),
+        sampled_requests: web_acl
+            .visibility_config()
+            .is_some_and(|config| config.sampled_requests_enabled()),
+        rules,
+        associations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_regional_association, map_web_acl_detail, map_web_acl_resource, parse_web_acl_arn,
+        statement_summary,
+    };
+    use aws_sdk_wafv2::types::{
+        AllowAction, AndStatement, BlockAction, CountryCode, DefaultAction, GeoMatchStatement,
+        IpSetReferenceStatement, ManagedRuleGroupStatement, NoneAction, OverrideAction,
+        RateBasedStatement, RateBasedStatementAggregateKeyType, ResourceType, Rule, RuleAction,
+        RuleGroupReferenceStatement, Scope, Statement, VisibilityConfig, WebAcl, WebAclSummary,
+    };
+
+    fn visibility(metric: &str) -> VisibilityConfig {
+        VisibilityConfig::builder()
+            .metric_name(metric)
+            .sampled_requests_enabled(true)
+            .cloud_watch_metrics_enabled(true)
+            .build()
+            .expect("visibility config")
+    }
+
+    fn web_acl() -> WebAcl {
+        let managed = Rule::builder()
+            .name("common")
+            .priority(1)
+            .statement(
+                Statement::builder()
+                    .managed_rule_group_statement(
+                        ManagedRuleGroupStatement::builder()
+                            .vendor_name("AWS")
+                            .name("AWSManagedRulesCommonRuleSet")
+                            .build()
+                            .expect("managed rule group"),
+                    )
+                    .build(),
+            )
+            .override_action(
+                OverrideAction::builder()
+                    .none(NoneAction::builder().build())
+                    .build(),
+            )
+            .visibility_config(visibility("common"))
+            .build()
+            .expect("managed rule");
+        let rate_limit = Rule::builder()
+            .name("rate-limit")
+            .priority(0)
+            .statement(
+                Statement::builder()
+                    .and_statement(
+                        AndStatement::builder()
+                            .statements(
+                                Statement::builder()
+                                    .rate_based_statement(
+                                        RateBasedStatement::builder()
+                                            .limit(2000)
+                                            .aggregate_key_type(
+                                                RateBasedStatementAggregateKeyType::Ip,
+                                            )
+                                            .build()
+                                            .expect("rate statement"),
+                                    )
+                                    .build(),
+                            )
+                            .statements(
+                                Statement::builder()
+                                    .geo_match_statement(
+                                        GeoMatchStatement::builder()
+                                            .country_codes(CountryCode::Kr)
+                                            .country_codes(CountryCode::Jp)
+                                            .build(),
+                                    )
+                                    .build(),
+                            )
+                            .build()
+                            .expect("and statement"),
+                    )
+                    .build(),
+            )
+            .action(
+                RuleAction::builder()
+                    .block(BlockAction::builder().build())
+                    .build(),
+            )
+            .visibility_config(visibility("rate-limit"))
+            .build()
+            .expect("rate rule");
+
+        WebAcl::builder()
+            .name("checkout-acl")
+            .id("a1b2c3d4")
+            .arn("arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/checkout-acl/a1b2c3d4")
+            .default_action(
+                DefaultAction::builder()
+                    .allow(AllowAction::builder().build())
+                    .build(),
+            )
+            .capacity(725)
+            .rules(managed)
+            .rules(rate_limit)
+            .visibility_config(visibility("checkout-acl"))
+            .build()
+            .expect("web acl")
+    }
+
+    #[test]
+    fn parse_web_acl_arn_reads_scope_region_name_and_id() {
+        let (scope, region, name, id) = parse_web_acl_arn(
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/checkout-acl/a1b2c3d4",
+        )
+        .expect("regional arn");
+        assert_eq!(scope, Scope::Regional);
+        assert_eq!(
+            (region, name, id),
+            ("ap-northeast-2", "checkout-acl", "a1b2c3d4")
+        );
+
+        let (scope, region, _, _) =
+            parse_web_acl_arn("arn:aws:wafv2:us-east-1:123456789012:global/webacl/cdn-acl/e5f6")
+                .expect("global arn");
+        assert_eq!(scope, Scope::Cloudfront);
+        assert_eq!(region, "us-east-1");
+        let (_, region, _, _) = parse_web_acl_arn(
+            "arn:aws-cn:wafv2:cn-northwest-1:123456789012:global/webacl/cdn-acl/e5f6",
+        )
+        .expect("china global arn");
+        assert_eq!(region, "cn-northwest-1");
+
+        assert!(
+            parse_web_acl_arn("arn:aws:wafv2:us-east-1:123456789012:global/ipset/office/e5f6")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn map_web_acl_summarizes_rules_in_priority_order() {
+        let resource = map_web_acl_resource(
+            &WebAclSummary::builder()
+                .name("checkout-acl")
+                .arn("arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/checkout-acl/a1b2c3d4")
+                .description("checkout ALB")
+                .build(),
+            &Scope::Regional,
+        );
+        assert_eq!(resource.state, "REGIONAL");
+        assert_eq!(resource.cidr, "checkout ALB");
+        assert!(resource.id.ends_with("/checkout-acl/a1b2c3d4"));
+
+        let association = map_regional_association(
+            &ResourceType::ApplicationLoadBalancer,
+            "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/checkout-alb/50dc6c495c0c9188",
+        );
+        assert_eq!(
+            (
+                association.resource_type.as_str(),
+                association.resource.as_str()
+            ),
+            ("ALB", "checkout-alb")
+        );
+        let association = map_regional_association(
+            &ResourceType::ApiGateway,
+            "arn:aws:apigateway:ap-northeast-2::/restapis/a1b2c3d4e5/stages/prod",
+        );
+        assert_eq!(association.resource, "a1b2c3d4e5/prod");
+
+        let detail = map_web_acl_detail(&web_acl(), &Scope::Regional, "ap-northeast-2", vec![]);
+        assert_eq!(detail.default_action, "Allow");
+        assert_eq!(detail.metric_name, "checkout-acl");
+        assert!(detail.sampled_requests);
+        assert_eq!(detail.rules[0].name, "rate-limit");
+        assert_eq!(detail.rules[0].action, "Block");
+        assert_eq!(
+            detail.rules[0].statement,
+            "(Rate: 2000 / 300s (IP) AND Geo: KR, JP)"
+        );
+        assert_eq!(detail.rules[1].action, "Override: None");
+        assert_eq!(
+            detail.rules[1].statement,
+            "Managed: AWS/AWSManagedRulesCommonRuleSet"
+        );
+    }
+
+    #[test]
+    fn statement_summary_names_referenced_ip_sets_and_rule_groups() {
+        let ip_set = Statement::builder()
+            .ip_set_reference_statement(
+                IpSetReferenceStatement::builder()
+                    .arn("arn:aws:wafv2:ap-northeast-2:123456789012:regional/ipset/office-ips/a1b2c3d4")
+                    .build()
+                    .expect("ip set reference"),
+            )
+            .build();
+        assert_eq!(statement_summary(&ip_set), "IP set: office-ips");
+
+        let rule_group = Statement::builder()
+            .rule_group_reference_statement(
+                RuleGroupReferenceStatement::builder()
+                    .arn("arn:aws:wafv2:us-east-1:123456789012:global/rulegroup/bot-control/e5f6a7b8")
+                    .build()
+                    .expect("rule group reference"),
+            )
+            .build();
+        assert_eq!(statement_summary(&rule_group), "Rule group: bot-control");
+    }
+}
@@ -0,0 +1,501 @@
+// Backstage catalog-info.yaml 내보내기: 리소스마다 Component/Resource 엔티티 하나
+use crate::aws_cli::{Arn, Partition};
+use crate::blueprint::{BlueprintResource, ResourceType};
+use crate::settings::BackstageCatalog;
+
+pub const CATALOG_FILENAME: &str = "catalog-info.yaml";
+// owner를 설정하지 않았을 때 사용 (Backstage에서 spec.owner는 필수)
+const DEFAULT_OWNER: &str = "unknown";
+// metadata.name 최대 길이
+const MAX_NAME_LEN: usize = 63;
+
+/// Render one catalog entity per resource as a multi-document `catalog-info.yaml`.
+pub fn catalog_info(resources: &[BlueprintResource], config: Option<&BackstageCatalog>) -> String {
+    resources
+        .iter()
+        .map(|resource| entity_yaml(resource, config))
+        .collect::<Vec<_>>()
+        .join("---\n")
+}
+
+// 애플리케이션 코드가 배포되는 Lambda/CloudFront는 Component, 나머지 인프라는 Resource
+pub(crate) fn entity_kind(resource_type: &ResourceType) -> (&'static str, &'static str) {
+    match resource_type {
+        ResourceType::Lambda => ("Component", "service"),
+        ResourceType::CloudFront => ("Component", "website"),
+        ResourceType::Ec2 => ("Resource", "compute-instance"),
+        ResourceType::Network => ("Resource", "network"),
+        ResourceType::SecurityGroup => ("Resource", "security-group"),
+        ResourceType::LoadBalancer => ("Resource", "load-balancer"),
+        ResourceType::Ecr => ("Resource", "container-registry"),
+        ResourceType::Asg => ("Resource", "autoscaling-group"),
+        ResourceType::Rds | ResourceType::DynamoDb | ResourceType::Redshift => {
+            ("Resource", "database")
+        }
+        ResourceType::Route53 => ("Resource", "dns-zone"),
+        ResourceType::Eks => ("Resource", "kubernetes-cluster"),
+        ResourceType::Ecs => ("Resource", "container-cluster"),
+        ResourceType::Sqs => ("Resource", "queue"),
+        ResourceType::Sns => ("Resource", "topic"),
+        ResourceType::ElastiCache => ("Resource", "cache"),
+        ResourceType::CloudWatchAlarm => ("Resource", "alarm"),
+        ResourceType::CloudTrail => ("Resource", "audit-trail"),
+        ResourceType::Kms => ("Resource", "encryption-key"),
+        ResourceType::Secret => ("Resource", "secret"),
+        ResourceType::SsmParameter => ("Resource", "configuration"),
+        ResourceType::Efs => ("Resource", "file-system"),
+        ResourceType::ApiGateway => ("Resource", "api-gateway"),
+        ResourceType::ElasticBeanstalk => ("Resource", "elastic-beanstalk-environment"),
+        ResourceType::StepFunctions => ("Resource", "step-functions-state-machine"),
+        ResourceType::Kinesis => ("Resource", "kinesis-stream"),
+        ResourceType::Msk => ("Resource", "msk-cluster"),
+        ResourceType::OpenSearch => ("Resource", "opensearch-domain"),
+        ResourceType::TransitGateway => ("Resource", "network"),
+        ResourceType::VpcPeering => ("Resource", "network"),
+        ResourceType::Vpn => ("Resource", "network"),
+        ResourceType::DirectConnect => ("Resource", "network"),
+        ResourceType::Waf => ("Resource", "web-acl"),
+        ResourceType::Acm => ("Resource", "certificate"),
+        ResourceType::CloudFormation => ("Resource", "cloudformation-stack"),
+        ResourceType::Iam => ("Resource", "iam-entity"),
+        ResourceType::VpcEndpoint => ("Resource", "network"),
+        ResourceType::NetworkInterface => ("Resource", "network"),
+        ResourceType::LaunchTemplate => ("Resource", "launch-template"),
+        ResourceType::Ami => ("Resource", "machine-image"),
+        ResourceType::Ebs => ("Resource", "block-storage"),
+        ResourceType::KeyPair => ("Resource", "ssh-key"),
+        ResourceType::EventBridge => ("Resource", "event-bus"),
+        ResourceType::Cognito => ("Resource", "identity-pool"),
+        ResourceType::Glue => ("Resource", "data-catalog"),
+        ResourceType::Athena => ("Resource", "query-workgroup"),
+        ResourceType::Backup => ("Resource", "backup-plan"),
+    }
+}
+
+// 태그와 엔티티 이름 접두사로 쓰는 서비스 이름
+pub(crate) fn service_slug(resource_type: &ResourceType) -> &'static str {
+    match resource_type {
+        ResourceType::Ec2 => "ec2",
+        ResourceType::Network => "vpc",
+        ResourceType::SecurityGroup => "security-group",
+        ResourceType::LoadBalancer => "elb",
+        ResourceType::Ecr => "ecr",
+        ResourceType::Asg => "autoscaling",
+        ResourceType::Rds => "rds",
+        ResourceType::Lambda => "lambda",
+        ResourceType::DynamoDb => "dynamodb",
+        ResourceType::CloudFront => "cloudfront",
+        ResourceType::Route53 => "route53",
+        ResourceType::Eks => "eks",
+        ResourceType::Ecs => "ecs",
+        ResourceType::Sqs => "sqs",
+        ResourceType::Sns => "sns",
+        ResourceType::ElastiCache => "elasticache",
+        ResourceType::CloudWatchAlarm => "cloudwatch",
+        ResourceType::CloudTrail => "cloudtrail",
+        ResourceType::Kms => "kms",
+        ResourceType::Secret => "secretsmanager",
+        ResourceType::SsmParameter => "ssm-parameter-store",
+        ResourceType::Efs => "efs",
+        ResourceType::ApiGateway => "apigateway",
+        ResourceType::ElasticBeanstalk => "elasticbeanstalk",
+        ResourceType::StepFunctions => "stepfunctions",
+        ResourceType::Kinesis => "kinesis",
+        ResourceType::Msk => "msk",
+        ResourceType::OpenSearch => "opensearch",
+        ResourceType::Redshift => "redshift",
+        ResourceType::TransitGateway => "transit-gateway",
+        ResourceType::VpcPeering => "vpc-peering",
+        ResourceType::Vpn => "vpn",
+        ResourceType::DirectConnect => "direct-connect",
+        ResourceType::Waf => "waf",
+        ResourceType::Acm => "acm",
+        ResourceType::CloudFormation => "cloudformation",
+        ResourceType::Iam => "iam",
+        ResourceType::VpcEndpoint => "vpc-endpoint",
+        ResourceType::NetworkInterface => "network-interface",
+        ResourceType::LaunchTemplate => "launch-template",
+        ResourceType::Ami => "ami",
+        ResourceType::Ebs => "ebs",
+        ResourceType::KeyPair => "key-pair",
+        ResourceType::EventBridge => "eventbridge",
+        ResourceType::Cognito => "cognito",
+        ResourceType::Glue => "glue",
+        ResourceType::Athena => "athena",
+        ResourceType::Backup => "backup",
+    }
+}
+
+// Backstage 이름 규칙: [a-zA-Z0-9]로 시작/끝, 중간 구분자는 - _ . 하나씩, 최대 63자
+// 서비스가 다르면 같은 이름이 겹칠 수 있어 서비스 접두사를 붙임
+pub fn entity_name(resource: &BlueprintResource) -> String {
+    let raw = format!(
+        "{}-{}",
+        service_slug(&resource.resource_type),
+        resource.resource_name
+    );
+    let mut name = String::new();
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if !name.is_empty() && !name.ends_with(['-', '_', '.']) {
+            name.push(if matches!(c, '_' | '.') { c } else { '-' });
+        }
+    }
+    name.truncate(MAX_NAME_LEN);
+    name.trim_end_matches(['-', '_', '.']).to_string()
+}
+
+/// AWS console deep link for a resource (falls back to the service home page).
+pub fn console_url(resource: &BlueprintResource) -> String {
+    let region = &resource.region;
+    let id = &resource.resource_id;
+    // ARN이 아닌 ID는 리전으로 파티션(GovCloud/중국 콘솔 도메인) 판단
+    let partition = Partition::from_arn(id).unwrap_or_else(|| Partition::from_region(region));
+    let base = partition.console_base(region);
+    let global = partition.console_base(partition.global_region());
+    match resource.resource_type {
+        ResourceType::Ec2 => {
+            format!("{base}/ec2/home?region={region}#InstanceDetails:instanceId={id}")
+        }
+        ResourceType::Network => {
+            format!("{base}/vpcconsole/home?region={region}#VpcDetails:VpcId={id}")
+        }
+        ResourceType::SecurityGroup => {
+            format!("{base}/ec2/home?region={region}#SecurityGroup:groupId={id}")
+        }
+        ResourceType::LoadBalancer => {
+            format!("{base}/ec2/home?region={region}#LoadBalancer:loadBalancerArn={id}")
+        }
+        ResourceType::Ecr => format!("{base}/ecr/private-registry/repositories?region={region}"),
+        ResourceType::Asg => {
+            format!("{base}/ec2/home?region={region}#AutoScalingGroupDetails:id={id}")
+        }
+        ResourceType::Rds => format!("{base}/rds/home?region={region}#database:id={id}"),
+        ResourceType::Lambda => format!("{base}/lambda/home?region={region}#/functions/{id}"),
+        ResourceType::DynamoDb => {
+            format!("{base}/dynamodbv2/home?region={region}#table?name={id}")
+        }
+        // 글로벌 서비스는 파티션의 글로벌 리전(us-east-1) 콘솔 사용
+        ResourceType::CloudFront => format!("{global}/cloudfront/v4/home#/distributions/{id}"),
+        ResourceType::Route53 => {
+            format!("{global}/route53/v2/hostedzones#ListRecordSets/{id}")
+        }
+        ResourceType::Eks => format!("{base}/eks/home?region={region}#/clusters/{id}"),
+        ResourceType::Ecs => format!("{base}/ecs/v2/clusters/{id}?region={region}"),
+        ResourceType::Sqs => format!("{base}/sqs/v3/home?region={region}#/queues"),
+        ResourceType::Sns => format!("{base}/sns/v3/home?region={region}#/topic/{id}"),
+        ResourceType::ElastiCache => format!("{base}/elasticache/home?region={region}#/redis/{id}"),
+        ResourceType::CloudWatchAlarm => {
+            format!("{base}/cloudwatch/home?region={region}#alarmsV2:alarm/{id}")
+        }
+        ResourceType::CloudTrail => format!("{base}/cloudtrail/home?region={region}#/trails/{id}"),
+        ResourceType::Kms => format!("{base}/kms/home?region={region}#/kms/keys/{id}"),
+        // 콘솔은 ARN이 아닌 비밀 이름으로 조회
+        ResourceType::Secret => format!(
+            "{base}/secretsmanager/secret?name={}&region={region}",
+            resource.resource_name
+        ),
+        ResourceType::SsmParameter => format!("{base}/systems-manager/parameters?region={region}"),
+        ResourceType::Efs => format!("{base}/efs/home?region={region}#/file-systems/{id}"),
+        ResourceType::ApiGateway => {
+            format!("{base}/apigateway/main/apis/{id}/resources?api={id}&region={region}")
+        }
+        ResourceType::ElasticBeanstalk => format!(
+            "{base}/elasticbeanstalk/home?region={region}#/environment/dashboard?environmentId={id}"
+        ),
+        ResourceType::StepFunctions => {
+            format!("{base}/states/home?region={region}#/statemachines/view/{id}")
+        }
+        ResourceType::Kinesis => {
+            format!("{base}/kinesis/home?region={region}#/streams/details/{id}/monitoring")
+        }
+        ResourceType::Msk => format!("{base}/msk/home?region={region}#/cluster/{id}/view"),
+        ResourceType::OpenSearch => {
+            format!("{base}/aos/home?region={region}#opensearch/domains/{id}")
+        }
+        ResourceType::Redshift => {
+            format!("{base}/redshiftv2/home?region={region}#cluster-details?cluster={id}")
+        }
+        ResourceType::TransitGateway => format!(
+            "{base}/vpcconsole/home?region={region}#TransitGatewayDetails:transitGatewayId={id}"
+        ),
+        ResourceType::VpcPeering => format!(
+            "{base}/vpcconsole/home?region={region}#PeeringConnectionDetails:VpcPeeringConnectionId={id}"
+        ),
+        ResourceType::Vpn => {
+            if id.starts_with("cgw-") {
+                format!(
+                    "{base}/vpcconsole/home?region={region}#CustomerGatewayDetails:customerGatewayId={id}"
+                )
+            } else if id.starts_with("vgw-") {
+                format!(
+                    "{base}/vpcconsole/home?region={region}#VpnGatewayDetails:VpnGatewayId={id}"
+                )
+            } else {
+                format!(
+                    "{base}/vpcconsole/home?region={region}#VpnConnectionDetails:VpnConnectionId={id}"
+                )
+            }
+        }
+        ResourceType::DirectConnect => {
+            if id.starts_with("dxvif-") {
+                format!("{base}/directconnect/v2/home?region={region}#/virtual-interfaces/{id}")
+            } else {
+                format!("{base}/directconnect/v2/home?region={region}#/connections/{id}")
+            }
+        }
+        ResourceType::Waf => {
+            // ARN 끝의 <name>/<id>로 조회하며 CloudFront용은 region=global
+            let scope_region = if id.contains(":global/") {
+                "global"
+            } else {
+                region.as_str()
+            };
+            let path = id.rsplit_once("/webacl/").map(|(_, p)| p).unwrap_or(id);
+            format!("{base}/wafv2/homev2/web-acl/{path}/overview?region={scope_region}")
+        }
+        ResourceType::Acm => {
+            let certificate_id = id.rsplit('/').next().unwrap_or(id);
+            format!("{base}/acm/home?region={region}#/certificates/{certificate_id}")
+        }
+        ResourceType::CloudFormation => {
+            format!("{base}/cloudformation/home?region={region}#/stacks/stackinfo?stackId={id}")
+        }
+        ResourceType::Iam => {
+            // 글로벌 서비스이며 정책은 ARN, 나머지는 이름으로 조회
+            let iam = format!("{global}/iam/home");
+            match Arn::parse(id).map(|arn| arn.resource_type()) {
+                Some("policy") => format!(
+                    "{iam}#/policies/details/{}",
+                    id.replace(':', "%3A").replace('/', "%2F")
+                ),
+                Some(kind) => format!("{iam}#/{kind}s/details/{}", resource.resource_name),
+                None => format!("{iam}#/home"),
+            }
+        }
+        ResourceType::VpcEndpoint => {
+            format!("{base}/vpcconsole/home?region={region}#EndpointDetails:vpcEndpointId={id}")
+        }
+        ResourceType::NetworkInterface => {
+            format!("{base}/ec2/home?region={region}#NetworkInterface:networkInterfaceId={id}")
+        }
+        ResourceType::LaunchTemplate => {
+            format!("{base}/ec2/home?region={region}#LaunchTemplateDetails:launchTemplateId={id}")
+        }
+        ResourceType::Ami => format!("{base}/ec2/home?region={region}#ImageDetails:imageId={id}"),
+        ResourceType::Ebs => {
+            if id.starts_with("snap-") {
+                format!("{base}/ec2/home?region={region}#SnapshotDetails:snapshotId={id}")
+            } else {
+                format!("{base}/ec2/home?region={region}#VolumeDetails:volumeId={id}")
+            }
+        }
+        ResourceType::KeyPair => format!("{base}/ec2/home?region={region}#KeyPairs:search={id}"),
+        ResourceType::EventBridge => format!("{base}/events/home?region={region}#/eventbus/{id}"),
+        ResourceType::Cognito => {
+            if id.contains(':') {
+                format!("{base}/cognito/v2/identity/identity-pools/{id}?region={region}")
+            } else {
+                format!("{base}/cognito/v2/idp/user-pools/{id}/overview?region={region}")
+            }
+        }
+        ResourceType::Glue => match id.split_once('/') {
+            Some(("crawler", name)) => {
+                format!("{base}/glue/home?region={region}#/v2/data-catalog/crawlers/view/{name}")
+            }
+            Some((_, name)) => {
+                format!("{base}/glue/home?region={region}#/v2/data-catalog/databases/view/{name}")
+            }
+            None => {
+                format!("{base}/glue/home?region={region}#/v2/data-catalog/databases/view/{id}")
+            }
+        },
+        ResourceType::Athena => {
+            format!("{base}/athena/home?region={region}#/workgroups/details/{id}")
+        }
+        ResourceType::Backup => {
+            format!("{base}/backup/home?region={region}#/backupplan/details/{id}")
+        }
+    }
+}
+
+// JSON 문자열은 그대로 유효한 YAML 스칼라이므로 따옴표/이스케이프를 serde_json에 맡김
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn entity_yaml(resource: &BlueprintResource, config: Option<&BackstageCatalog>) -> String {
+    let (kind, entity_type) = entity_kind(&resource.resource_type);
+    let slug = service_slug(&resource.resource_type);
+    let owner = config
+        .map(|c| c.owner.trim())
+        .filter(|owner| !owner.is_empty())
+        .unwrap_or(DEFAULT_OWNER);
+    let system = config
+        .and_then(|c| c.system.as_deref())
+        .map(str::trim)
+        .filter(|system| !system.is_empty());
+
+    let mut lines = vec![
+        "apiVersion: backstage.io/v1alpha1".to_string(),
+        format!("kind: {}", kind),
+        "metadata:".to_string(),
+        format!("  name: {}", quote(&entity_name(resource))),
+        format!("  title: {}", quote(&resource.resource_name)),
+        format!(
+            "  description: {}",
+            quote(&format!(
+                "{} {} in {}",
+                resource.resource_type.display(),
+                resource.resource_id,
+                resource.region
+            ))
+        ),
+        "  annotations:".to_string(),
+        format!("    aws.amazon.com/region: {}", quote(&resource.region)),
+        format!(
+            "    aws.amazon.com/resource-type: {}",
+            quote(resource.resource_type.display())
+        ),
+        format!(
+            "    aws.amazon.com/resource-id: {}",
+            quote(&resource.resource_id)
+        ),
+    ];
+    if resource.resource_id.starts_with("arn:") {
+        lines.push(format!(
+            "    aws.amazon.com/arn: {}",
+            quote(&resource.resource_id)
+        ));
+    }
+    lines.extend([
+        "  tags:".to_string(),
+        "    - aws".to_string(),
+        format!("    - {}", slug),
+        "  links:".to_string(),
+        format!("    - url: {}", quote(&console_url(resource))),
+        "      title: AWS Console".to_string(),
+        "spec:".to_string(),
+        format!("  type: {}", entity_type),
+        format!("  owner: {}", quote(owner)),
+    ]);
+    // Component는 lifecycle이 필수
+    if kind == "Component" {
+        lines.push("  lifecycle: production".to_string());
+    }
+    if let Some(system) = system {
+        lines.push(format!("  system: {}", quote(system)));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{catalog_info, console_url, entity_name};
+    use crate::blueprint::{BlueprintResource, ResourceType};
+    use crate::settings::BackstageCatalog;
+
+    fn resource(resource_type: ResourceType, id: &str, name: &str) -> BlueprintResource {
+        BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn entity_name_follows_backstage_rules() {
+        assert_eq!(
+            entity_name(&resource(ResourceType::Sqs, "orders.fifo", "orders.fifo")),
+            "sqs-orders.fifo"
+        );
+        assert_eq!(
+            entity_name(&resource(
+                ResourceType::Ec2,
+                "i-0123",
+                "web server #1 (blue)"
+            )),
+            "ec2-web-server-1-blue"
+        );
+        assert_eq!(
+            entity_name(&resource(ResourceType::Route53, "Z1", "example.com.")),
+            "route53-example.com"
+        );
+        let long = entity_name(&resource(ResourceType::Lambda, "fn", &"a".repeat(80)));
+        assert_eq!(long.len(), 63);
+    }
+
+    #[test]
+    fn console_url_links_back_to_resource() {
+        assert_eq!(
+            console_url(&resource(
+                ResourceType::Lambda,
+                "api-handler",
+                "api-handler"
+            )),
+            "https://ap-northeast-2.console.aws.amazon.com/lambda/home?region=ap-northeast-2#/functions/api-handler"
+        );
+        assert!(
+            console_url(&resource(ResourceType::CloudFront, "E2EXAMPLE", "cdn"))
+                .starts_with("https://us-east-1.console.aws.amazon.com/cloudfront/")
+        );
+
+        // GovCloud/중국 리소스는 해당 파티션 콘솔로 연결
+        let mut gov = resource(ResourceType::Ec2, "i-0123", "web");
+        gov.region = "us-gov-west-1".to_string();
+        assert_eq!(
+            console_url(&gov),
+            "https://console.amazonaws-us-gov.com/ec2/home?region=us-gov-west-1#InstanceDetails:instanceId=i-0123"
+        );
+        assert!(
+            console_url(&resource(
+                ResourceType::Iam,
+                "arn:aws-cn:iam::123456789012:role/app",
+                "app"
+            ))
+            .starts_with("https://console.amazonaws.cn/iam/home#/roles/details/app")
+        );
+    }
+
+    #[test]
+    fn catalog_info_renders_component_and_resource_entities() {
+        let resources = vec![
+            resource(ResourceType::Lambda, "api-handler", "api-handler"),
+            resource(
+                ResourceType::Sns,
+                "arn:aws:sns:ap-northeast-2:123456789012:alerts",
+                "alerts",
+            ),
+        ];
+        let config = BackstageCatalog {
+            owner: "team-platform".to_string(),
+            system: Some("checkout".to_string()),
+        };
+
+        let yaml = catalog_info(&resources, Some(&config));
+        let documents: Vec<&str> = yaml.split("---\n").collect();
+        assert_eq!(documents.len(), 2);
+
+        assert!(documents[0].starts_with("apiVersion: backstage.io/v1alpha1\nkind: Component\n"));
+        assert!(documents[0].contains("  name: \"lambda-api-handler\"\n"));
+        assert!(documents[0].contains("  type: service\n  owner: \"team-platform\"\n"));
+        assert!(documents[0].contains("  lifecycle: production\n  system: \"checkout\"\n"));
+        assert!(!documents[0].contains("aws.amazon.com/arn"));
+
+        assert!(documents[1].contains("kind: Resource\n"));
+        assert!(documents[1].contains(
+            "    aws.amazon.com/arn: \"arn:aws:sns:ap-northeast-2:123456789012:alerts\"\n"
+        ));
+        assert!(documents[1].contains("    - aws\n    - sns\n"));
+        assert!(documents[1].contains("      title: AWS Console\n"));
+        assert!(!documents[1].contains("lifecycle"));
+
+        let unconfigured = catalog_info(&resources[..1], None);
+        assert!(unconfigured.contains("  owner: \"unknown\"\n"));
+        assert!(!unconfigured.contains("system:"));
+    }
+}
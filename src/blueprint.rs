@@ -1,7 +1,15 @@
+use crate::aws_cli::remote_store;
+use crate::crypto::{self, KeySource};
+use crate::settings::RemoteBlueprintStore;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// blueprints.json 스키마 버전 (구조가 바뀌면 올리고 parse_store에 마이그레이션 추가)
+pub const BLUEPRINT_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ResourceType {
@@ -11,6 +19,48 @@ pub enum ResourceType {
     LoadBalancer,
     Ecr,
     Asg,
+    Rds,
+    Lambda,
+    DynamoDb,
+    CloudFront,
+    Route53,
+    Eks,
+    Ecs,
+    Sqs,
+    Sns,
+    ElastiCache,
+    CloudWatchAlarm,
+    CloudTrail,
+    Kms,
+    Secret,
+    SsmParameter,
+    Efs,
+    ApiGateway,
+    ElasticBeanstalk,
+    StepFunctions,
+    Kinesis,
+    Msk,
+    OpenSearch,
+    Redshift,
+    TransitGateway,
+    VpcPeering,
+    Vpn,
+    DirectConnect,
+    Waf,
+    Acm,
+    CloudFormation,
+    Iam,
+    VpcEndpoint,
+    NetworkInterface,
+    LaunchTemplate,
+    Ami,
+    Ebs,
+    KeyPair,
+    EventBridge,
+    Cognito,
+    Glue,
+    Athena,
+    Backup,
 }
 
 impl ResourceType {
@@ -22,6 +72,48 @@ impl ResourceType {
             ResourceType::LoadBalancer => "Load Balancer",
             ResourceType::Ecr => "ECR",
             ResourceType::Asg => "Auto Scaling Group",
+            ResourceType::Rds => "RDS",
+            ResourceType::Lambda => "Lambda",
+            ResourceType::DynamoDb => "DynamoDB",
+            ResourceType::CloudFront => "CloudFront",
+            ResourceType::Route53 => "Route 53",
+            ResourceType::Eks => "EKS",
+            ResourceType::Ecs => "ECS",
+            ResourceType::Sqs => "SQS",
+            ResourceType::Sns => "SNS",
+            ResourceType::ElastiCache => "ElastiCache",
+            ResourceType::CloudWatchAlarm => "CloudWatch Alarm",
+            ResourceType::CloudTrail => "CloudTrail",
+            ResourceType::Kms => "KMS",
+            ResourceType::Secret => "Secrets Manager",
+            ResourceType::SsmParameter => "SSM Parameter Store",
+            ResourceType::Efs => "EFS",
+            ResourceType::ApiGateway => "API Gateway",
+            ResourceType::ElasticBeanstalk => "Elastic Beanstalk",
+            ResourceType::StepFunctions => "Step Functions",
+            ResourceType::Kinesis => "Kinesis",
+            ResourceType::Msk => "MSK",
+            ResourceType::OpenSearch => "OpenSearch",
+            ResourceType::Redshift => "Redshift",
+            ResourceType::TransitGateway => "Transit Gateway",
+            ResourceType::VpcPeering => "VPC Peering",
+            ResourceType::Vpn => "Site-to-Site VPN",
+            ResourceType::DirectConnect => "Direct Connect",
+            ResourceType::Waf => "WAF",
+            ResourceType::Acm => "ACM",
+            ResourceType::CloudFormation => "CloudFormation",
+            ResourceType::Iam => "IAM",
+            ResourceType::VpcEndpoint => "VPC Endpoint",
+            ResourceType::NetworkInterface => "Network Interface",
+            ResourceType::LaunchTemplate => "Launch Template",
+            ResourceType::Ami => "AMI",
+            ResourceType::Ebs => "EBS",
+            ResourceType::KeyPair => "Key Pair",
+            ResourceType::EventBridge => "EventBridge",
+            ResourceType::Cognito => "Cognito",
+            ResourceType::Glue => "Glue",
+            ResourceType::Athena => "Athena",
+            ResourceType::Backup => "Backup",
         }
     }
 }
@@ -46,6 +138,27 @@ impl BlueprintResource {
     }
 }
 
+/// Result of checking a blueprint resource against the live AWS account.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceStatus {
+    Valid,
+    Renamed(String),
+    // 리소스 ID(ARN)의 리전이 저장된 리전과 다름
+    Moved(String),
+    Missing,
+    // 권한, 스로틀링, 네트워크 오류 등으로 확인하지 못함 (제거 대상 아님)
+    Unknown,
+}
+
+impl ResourceStatus {
+    pub fn is_stale(&self) -> bool {
+        matches!(
+            self,
+            ResourceStatus::Renamed(_) | ResourceStatus::Moved(_) | ResourceStatus::Missing
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Blueprint {
     pub id: String,
@@ -85,17 +198,38 @@ impl Blueprint {
             self.updated_at = Utc::now();
         }
     }
+
+    pub fn rename_resource(&mut self, index: usize, name: String) {
+        if let Some(resource) = self.resources.get_mut(index) {
+            resource.resource_name = name;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn relocate_resource(&mut self, index: usize, region: String) {
+        if let Some(resource) = self.resources.get_mut(index) {
+            resource.region = region;
+            self.updated_at = Utc::now();
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct BlueprintStore {
+    #[serde(default)]
+    pub version: u32,
     pub blueprints: Vec<Blueprint>,
+    // 삭제 기록 (id -> 삭제 시각), 원격 저장소 병합 시 삭제가 되살아나지 않도록 유지
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub deleted: HashMap<String, DateTime<Utc>>,
 }
 
 impl BlueprintStore {
     pub fn new() -> Self {
         Self {
+            version: BLUEPRINT_SCHEMA_VERSION,
             blueprints: Vec::new(),
+            deleted: HashMap::new(),
         }
     }
 
@@ -105,8 +239,37 @@ impl BlueprintStore {
 
     pub fn remove_blueprint(&mut self, index: usize) {
         if index < self.blueprints.len() {
-            self.blueprints.remove(index);
+            let removed = self.blueprints.remove(index);
+            self.deleted.insert(removed.id, Utc::now());
+        }
+    }
+
+    /// Merge another copy of the store using last-writer-wins per blueprint.
+    ///
+    /// Local order is kept and blueprints only known to `other` are appended.
+    pub fn merge(&mut self, other: BlueprintStore) {
+        for (id, deleted_at) in other.deleted {
+            let entry = self.deleted.entry(id).or_insert(deleted_at);
+            if deleted_at > *entry {
+                *entry = deleted_at;
+            }
+        }
+
+        for theirs in other.blueprints {
+            match self.blueprints.iter_mut().find(|bp| bp.id == theirs.id) {
+                Some(ours) if theirs.updated_at > ours.updated_at => *ours = theirs,
+                Some(_) => {}
+                None => self.blueprints.push(theirs),
+            }
         }
+
+        // 삭제 이후에 수정된 블루프린트는 유지
+        let deleted = &self.deleted;
+        self.blueprints.retain(|bp| {
+            deleted
+                .get(&bp.id)
+                .is_none_or(|deleted_at| bp.updated_at > *deleted_at)
+        });
     }
 
     pub fn get_blueprint(&self, index: usize) -> Option<&Blueprint> {
@@ -130,35 +293,235 @@ fn get_blueprint_path() -> Option<PathBuf> {
     Some(emd_dir.join("blueprints.json"))
 }
 
-pub fn load_blueprints() -> BlueprintStore {
-    let path = match get_blueprint_path() {
-        Some(p) => p,
-        None => return BlueprintStore::new(),
-    };
+// blueprints.json -> blueprints.json.<suffix>
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+// 동시에 실행된 emd끼리 저장 파일을 덮어쓰지 않도록 별도 lock 파일 사용 (drop 시 해제)
+fn lock_store(path: &Path, exclusive: bool) -> std::io::Result<File> {
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sibling_path(path, "lock"))?;
+    if exclusive {
+        lock.lock()?;
+    } else {
+        lock.lock_shared()?;
+    }
+    Ok(lock)
+}
+
+fn parse_store(content: &str) -> Result<BlueprintStore, String> {
+    let mut value: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let version = stored_version(&value);
+    if version > u64::from(BLUEPRINT_SCHEMA_VERSION) {
+        return Err(format!(
+            "blueprint store version {} is newer than supported version {}",
+            version, BLUEPRINT_SCHEMA_VERSION
+        ));
+    }
+
+    // 버전별 마이그레이션 (순서대로 적용)
+    if version < 1
+        && let Some(object) = value.as_object_mut()
+    {
+        // v0 -> v1: version 필드 도입, 구조 변경 없음
+        object.insert("version".to_string(), serde_json::Value::from(1));
+    }
+
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+fn stored_version(value: &serde_json::Value) -> u64 {
+    value
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0)
+}
+
+// 암호화된 파일이면 키로 복호화 (설정을 꺼도 기존 파일은 계속 읽을 수 있음)
+fn read_store_file(path: &Path, key: &KeySource) -> Result<BlueprintStore, String> {
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    parse_store(&crypto::decode_text(&bytes, key)?)
+}
+
+fn load_blueprints_from(path: &Path, key: &KeySource) -> BlueprintStore {
+    let _lock = lock_store(path, false);
 
     if !path.exists() {
         return BlueprintStore::new();
     }
+    let error = match read_store_file(path, key) {
+        Ok(store) => return store,
+        Err(error) => error,
+    };
+
+    // 손상된 파일은 덮어쓰기 전에 따로 보관하고 백업에서 복구
+    tracing::warn!(error = %error, path = %path.display(), "Blueprint store unreadable, trying backup");
+    let corrupt = sibling_path(
+        path,
+        &format!("corrupt-{}", Utc::now().format("%Y%m%d%H%M%S")),
+    );
+    if let Err(e) = fs::copy(path, &corrupt) {
+        tracing::warn!(error = %e, "Failed to preserve unreadable blueprint store");
+    }
+
+    match read_store_file(&sibling_path(path, "bak"), key) {
+        Ok(store) => store,
+        Err(e) => {
+            tracing::warn!(error = %e, "Blueprint backup unreadable, starting empty");
+            BlueprintStore::new()
+        }
+    }
+}
+
+fn save_blueprints_to(
+    store: &BlueprintStore,
+    path: &Path,
+    encrypt: bool,
+    key: &KeySource,
+) -> Result<(), std::io::Error> {
+    let invalid = |e: String| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+    let _lock = lock_store(path, true)?;
+
+    // 기존 파일이 정상일 때만 백업으로 보관, 더 새로운 버전의 emd가 쓴 파일은 덮어쓰지 않음
+    let existing_is_valid = match fs::read(path) {
+        Ok(bytes) => match crypto::decode_text(&bytes, key)
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(value) if stored_version(&value) > u64::from(BLUEPRINT_SCHEMA_VERSION) => {
+                return Err(invalid(
+                    "blueprint store was written by a newer emd version".to_string(),
+                ));
+            }
+            Ok(value) => serde_json::from_value::<BlueprintStore>(value).is_ok(),
+            // 키를 못 읽어 복호화에 실패한 경우 빈 저장소로 덮어쓰지 않도록 키가 돌아올 때까지 저장 거부
+            Err(e) if crypto::is_encrypted(&bytes) => {
+                return Err(invalid(format!(
+                    "encrypted blueprint store could not be decrypted, refusing to overwrite it: {}",
+                    e
+                )));
+            }
+            Err(_) => false,
+        },
+        Err(_) => false,
+    };
+
+    let mut store = store.clone();
+    store.version = BLUEPRINT_SCHEMA_VERSION;
+    let content = serde_json::to_string_pretty(&store)?;
+    let bytes = crypto::encode_text(content, encrypt, key).map_err(invalid)?;
+
+    // 임시 파일에 기록 후 rename으로 교체 (저장 중 종료돼도 기존 파일 유지)
+    let tmp = sibling_path(path, "tmp");
+    {
+        let mut file = File::create(&tmp)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+    }
+    if existing_is_valid {
+        fs::copy(path, sibling_path(path, "bak"))?;
+    }
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
 
-    match fs::read_to_string(&path) {
-        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| BlueprintStore::new()),
-        Err(_) => BlueprintStore::new(),
+pub fn load_blueprints() -> BlueprintStore {
+    match get_blueprint_path() {
+        Some(path) => load_blueprints_from(&path, &crypto::data_key),
+        None => BlueprintStore::new(),
     }
 }
 
-pub fn save_blueprints(store: &BlueprintStore) -> Result<(), std::io::Error> {
+/// Save the store, encrypting it with the keychain data key when `encrypt` is set.
+pub fn save_blueprints(store: &BlueprintStore, encrypt: bool) -> Result<(), std::io::Error> {
     let path = get_blueprint_path().ok_or_else(|| {
         std::io::Error::new(std::io::ErrorKind::NotFound, "Home directory not found")
     })?;
+    save_blueprints_to(store, &path, encrypt, &crypto::data_key)
+}
 
-    let content = serde_json::to_string_pretty(store)?;
-    fs::write(&path, content)?;
-    Ok(())
+const REMOTE_DOCUMENT_ID: &str = "emd-blueprints";
+const REMOTE_DOCUMENT_ATTRIBUTE: &str = "store";
+
+fn remote_s3_key(prefix: &str) -> String {
+    if prefix.is_empty() || prefix.ends_with('/') {
+        format!("{}blueprints.json", prefix)
+    } else {
+        format!("{}/blueprints.json", prefix)
+    }
+}
+
+/// Pull the shared store, merge it into `store`, and push the merged result back.
+pub fn sync_remote_blueprints(
+    store: &mut BlueprintStore,
+    remote: &RemoteBlueprintStore,
+) -> Result<(), String> {
+    let fetched = match remote {
+        RemoteBlueprintStore::S3 { bucket, prefix } => {
+            remote_store::get_s3_object(bucket, &remote_s3_key(prefix))?
+        }
+        RemoteBlueprintStore::DynamoDb { table } => remote_store::get_dynamodb_document(
+            table,
+            REMOTE_DOCUMENT_ID,
+            REMOTE_DOCUMENT_ATTRIBUTE,
+        )?,
+    };
+
+    if let Some(content) = fetched {
+        let theirs: BlueprintStore = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        store.merge(theirs);
+    }
+
+    let content = serde_json::to_string_pretty(store).map_err(|e| e.to_string())?;
+    match remote {
+        RemoteBlueprintStore::S3 { bucket, prefix } => {
+            remote_store::put_s3_object(bucket, &remote_s3_key(prefix), &content)
+        }
+        RemoteBlueprintStore::DynamoDb { table } => remote_store::put_dynamodb_document(
+            table,
+            REMOTE_DOCUMENT_ID,
+            REMOTE_DOCUMENT_ATTRIBUTE,
+            &content,
+        ),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Blueprint, BlueprintResource, BlueprintStore, ResourceType};
+    use super::{
+        BLUEPRINT_SCHEMA_VERSION, Blueprint, BlueprintResource, BlueprintStore, ResourceStatus,
+        ResourceType, load_blueprints_from, parse_store, remote_s3_key, save_blueprints_to,
+        sibling_path,
+    };
+    use crate::crypto::{DataKey, is_encrypted};
+    use chrono::{Duration, Utc};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn no_key() -> Result<DataKey, String> {
+        Err("no key in tests".to_string())
+    }
+
+    fn test_key() -> Result<DataKey, String> {
+        Ok([9u8; 32])
+    }
+
+    fn temp_store_dir(prefix: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let dir =
+            std::env::temp_dir().join(format!("emd-{}-{}-{}", prefix, std::process::id(), nanos));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        dir
+    }
 
     fn sample_resource(resource_type: ResourceType, suffix: &str) -> BlueprintResource {
         BlueprintResource {
@@ -177,6 +540,54 @@ mod tests {
         assert_eq!(ResourceType::LoadBalancer.display(), "Load Balancer");
         assert_eq!(ResourceType::Ecr.display(), "ECR");
         assert_eq!(ResourceType::Asg.display(), "Auto Scaling Group");
+        assert_eq!(ResourceType::Rds.display(), "RDS");
+        assert_eq!(ResourceType::Lambda.display(), "Lambda");
+        assert_eq!(ResourceType::DynamoDb.display(), "DynamoDB");
+        assert_eq!(ResourceType::CloudFront.display(), "CloudFront");
+        assert_eq!(ResourceType::Route53.display(), "Route 53");
+        assert_eq!(ResourceType::Eks.display(), "EKS");
+        assert_eq!(ResourceType::Ecs.display(), "ECS");
+        assert_eq!(ResourceType::Sqs.display(), "SQS");
+        assert_eq!(ResourceType::Sns.display(), "SNS");
+        assert_eq!(ResourceType::ElastiCache.display(), "ElastiCache");
+        assert_eq!(ResourceType::CloudWatchAlarm.display(), "CloudWatch Alarm");
+        assert_eq!(ResourceType::CloudTrail.display(), "CloudTrail");
+        assert_eq!(ResourceType::Kms.display(), "KMS");
+        assert_eq!(ResourceType::Secret.display(), "Secrets Manager");
+        assert_eq!(ResourceType::SsmParameter.display(), "SSM Parameter Store");
+        assert_eq!(ResourceType::Efs.display(), "EFS");
+        assert_eq!(ResourceType::ApiGateway.display(), "API Gateway");
+        assert_eq!(ResourceType::StepFunctions.display(), "Step Functions");
+        assert_eq!(ResourceType::Kinesis.display(), "Kinesis");
+        assert_eq!(ResourceType::Msk.display(), "MSK");
+        assert_eq!(ResourceType::OpenSearch.display(), "OpenSearch");
+        assert_eq!(ResourceType::Redshift.display(), "Redshift");
+        assert_eq!(ResourceType::TransitGateway.display(), "Transit Gateway");
+        assert_eq!(ResourceType::VpcPeering.display(), "VPC Peering");
+        assert_eq!(ResourceType::Vpn.display(), "Site-to-Site VPN");
+        assert_eq!(ResourceType::DirectConnect.display(), "Direct Connect");
+        assert_eq!(ResourceType::Waf.display(), "WAF");
+        assert_eq!(ResourceType::Acm.display(), "ACM");
+        assert_eq!(ResourceType::CloudFormation.display(), "CloudFormation");
+        assert_eq!(ResourceType::Iam.display(), "IAM");
+        assert_eq!(ResourceType::VpcEndpoint.display(), "VPC Endpoint");
+        assert_eq!(ResourceType::LaunchTemplate.display(), "Launch Template");
+        assert_eq!(ResourceType::Ami.display(), "AMI");
+        assert_eq!(ResourceType::Ebs.display(), "EBS");
+        assert_eq!(ResourceType::KeyPair.display(), "Key Pair");
+        assert_eq!(ResourceType::EventBridge.display(), "EventBridge");
+        assert_eq!(ResourceType::Cognito.display(), "Cognito");
+        assert_eq!(ResourceType::Glue.display(), "Glue");
+        assert_eq!(ResourceType::Athena.display(), "Athena");
+        assert_eq!(ResourceType::Backup.display(), "Backup");
+        assert_eq!(
+            ResourceType::NetworkInterface.display(),
+            "Network Interface"
+        );
+        assert_eq!(
+            ResourceType::ElasticBeanstalk.display(),
+            "Elastic Beanstalk"
+        );
     }
 
     #[test]
@@ -189,6 +600,15 @@ mod tests {
         assert!(text.contains("ap-northeast-2"));
     }
 
+    #[test]
+    fn resource_status_only_valid_is_not_stale() {
+        assert!(!ResourceStatus::Valid.is_stale());
+        assert!(ResourceStatus::Renamed("new-name".to_string()).is_stale());
+        assert!(ResourceStatus::Missing.is_stale());
+        assert!(ResourceStatus::Moved("us-east-1".to_string()).is_stale());
+        assert!(!ResourceStatus::Unknown.is_stale());
+    }
+
     #[test]
     fn blueprint_add_resource_deduplicates_same_id_and_region() {
         let mut blueprint = Blueprint::new("bp".to_string());
@@ -235,4 +655,193 @@ mod tests {
             Some("second-updated")
         );
     }
+
+    #[test]
+    fn blueprint_store_merge_keeps_newest_copy_and_appends_unknown() {
+        let mut ours = BlueprintStore::new();
+        let mut shared = Blueprint::new("shared".to_string());
+        shared.updated_at = Utc::now() - Duration::minutes(5);
+        ours.add_blueprint(shared.clone());
+        ours.add_blueprint(Blueprint::new("local-only".to_string()));
+
+        let mut theirs = BlueprintStore::new();
+        let mut edited = shared.clone();
+        edited.name = "shared-edited".to_string();
+        edited.updated_at = Utc::now();
+        theirs.add_blueprint(edited);
+        theirs.add_blueprint(Blueprint::new("remote-only".to_string()));
+
+        ours.merge(theirs);
+        let names: Vec<&str> = ours.blueprints.iter().map(|bp| bp.name.as_str()).collect();
+        assert_eq!(names, vec!["shared-edited", "local-only", "remote-only"]);
+
+        let mut stale = BlueprintStore::new();
+        let mut older = shared;
+        older.name = "shared-stale".to_string();
+        stale.add_blueprint(older);
+        ours.merge(stale);
+        assert_eq!(ours.blueprints[0].name, "shared-edited");
+    }
+
+    #[test]
+    fn blueprint_store_merge_applies_deletions_unless_edited_later() {
+        let mut ours = BlueprintStore::new();
+        ours.add_blueprint(Blueprint::new("deleted-here".to_string()));
+        let deleted_here = ours.blueprints[0].clone();
+        ours.remove_blueprint(0);
+        assert!(ours.deleted.contains_key(&deleted_here.id));
+
+        let mut theirs = BlueprintStore::new();
+        let mut stale = deleted_here;
+        stale.updated_at = Utc::now() - Duration::minutes(1);
+        theirs.add_blueprint(stale);
+        let mut revived = Blueprint::new("revived".to_string());
+        revived.updated_at = Utc::now() + Duration::minutes(1);
+        theirs
+            .deleted
+            .insert(revived.id.clone(), Utc::now() - Duration::minutes(1));
+        theirs.add_blueprint(revived);
+        let removed_remotely = Blueprint::new("removed-remotely".to_string());
+        theirs.deleted.insert(
+            removed_remotely.id.clone(),
+            Utc::now() + Duration::minutes(1),
+        );
+        ours.add_blueprint(removed_remotely);
+
+        ours.merge(theirs);
+        let names: Vec<&str> = ours.blueprints.iter().map(|bp| bp.name.as_str()).collect();
+        assert_eq!(names, vec!["revived"]);
+        assert_eq!(ours.deleted.len(), 3);
+    }
+
+    #[test]
+    fn remote_s3_key_joins_prefix_with_single_separator() {
+        assert_eq!(remote_s3_key(""), "blueprints.json");
+        assert_eq!(remote_s3_key("team/"), "team/blueprints.json");
+        assert_eq!(remote_s3_key("team"), "team/blueprints.json");
+    }
+
+    #[test]
+    fn save_blueprints_to_writes_atomically_and_keeps_previous_copy_as_backup() {
+        let dir = temp_store_dir("bp-save");
+        let path = dir.join("blueprints.json");
+
+        let mut store = BlueprintStore::new();
+        store.add_blueprint(Blueprint::new("first".to_string()));
+        save_blueprints_to(&store, &path, false, &no_key).expect("first save");
+        assert!(!sibling_path(&path, "bak").exists());
+
+        store.add_blueprint(Blueprint::new("second".to_string()));
+        save_blueprints_to(&store, &path, false, &no_key).expect("second save");
+
+        assert!(!sibling_path(&path, "tmp").exists());
+        let backup = fs::read_to_string(sibling_path(&path, "bak")).expect("backup");
+        assert_eq!(
+            parse_store(&backup)
+                .expect("backup parses")
+                .blueprints
+                .len(),
+            1
+        );
+        let loaded = load_blueprints_from(&path, &no_key);
+        assert_eq!(loaded.version, BLUEPRINT_SCHEMA_VERSION);
+        assert_eq!(loaded.blueprints.len(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn load_blueprints_from_recovers_backup_and_preserves_corrupt_file() {
+        let dir = temp_store_dir("bp-corrupt");
+        let path = dir.join("blueprints.json");
+
+        let mut store = BlueprintStore::new();
+        store.add_blueprint(Blueprint::new("kept".to_string()));
+        save_blueprints_to(&store, &path, false, &no_key).expect("save");
+        save_blueprints_to(&store, &path, false, &no_key).expect("save with backup");
+        fs::write(&path, "{\"blueprints\": [").expect("truncate store");
+
+        let loaded = load_blueprints_from(&path, &no_key);
+        assert_eq!(loaded.blueprints.len(), 1);
+        assert_eq!(loaded.blueprints[0].name, "kept");
+        let preserved = fs::read_dir(&dir)
+            .expect("read dir")
+            .filter_map(Result::ok)
+            .any(|entry| entry.file_name().to_string_lossy().contains(".corrupt-"));
+        assert!(preserved);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn parse_store_migrates_unversioned_files_and_rejects_newer_versions() {
+        let legacy = r#"{"blueprints":[{"id":"bp-1","name":"legacy","resources":[],"created_at":"2026-01-01T00:00:00Z","updated_at":"2026-01-01T00:00:00Z"}]}"#;
+        let migrated = parse_store(legacy).expect("legacy store");
+        assert_eq!(migrated.version, 1);
+        assert_eq!(migrated.blueprints[0].name, "legacy");
+
+        assert!(parse_store(r#"{"version":99,"blueprints":[]}"#).is_err());
+    }
+
+    #[test]
+    fn save_blueprints_to_refuses_to_overwrite_newer_schema() {
+        let dir = temp_store_dir("bp-newer");
+        let path = dir.join("blueprints.json");
+        let newer = r#"{"version":99,"blueprints":[]}"#;
+        fs::write(&path, newer).expect("write newer store");
+
+        assert!(save_blueprints_to(&BlueprintStore::new(), &path, false, &no_key).is_err());
+        assert_eq!(fs::read_to_string(&path).expect("read"), newer);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn encrypted_store_round_trips_and_stays_readable_after_disabling() {
+        let dir = temp_store_dir("bp-encrypted");
+        let path = dir.join("blueprints.json");
+
+        let mut store = BlueprintStore::new();
+        store.add_blueprint(Blueprint::new("secret-stack".to_string()));
+        save_blueprints_to(&store, &path, true, &test_key).expect("encrypted save");
+
+        let raw = fs::read(&path).expect("read raw");
+        assert!(is_encrypted(&raw));
+        assert!(!String::from_utf8_lossy(&raw).contains("secret-stack"));
+        assert_eq!(load_blueprints_from(&path, &test_key).blueprints.len(), 1);
+
+        // 암호화를 끄면 평문으로 다시 저장되고, 암호화된 이전 파일은 백업으로 남음
+        save_blueprints_to(&store, &path, false, &test_key).expect("plain save");
+        assert!(!is_encrypted(&fs::read(&path).expect("read plain")));
+        assert!(is_encrypted(
+            &fs::read(sibling_path(&path, "bak")).expect("read backup")
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_blueprints_to_refuses_to_overwrite_store_it_cannot_decrypt() {
+        let dir = temp_store_dir("bp-locked");
+        let path = dir.join("blueprints.json");
+
+        let mut store = BlueprintStore::new();
+        store.add_blueprint(Blueprint::new("secret-stack".to_string()));
+        save_blueprints_to(&store, &path, true, &test_key).expect("encrypted save");
+        let encrypted = fs::read(&path).expect("read encrypted");
+
+        // 키를 읽지 못하면 빈 저장소로 로드되지만 기존 파일은 덮어쓰지 않음
+        let loaded = load_blueprints_from(&path, &no_key);
+        assert!(loaded.blueprints.is_empty());
+        assert!(save_blueprints_to(&loaded, &path, false, &no_key).is_err());
+        assert!(save_blueprints_to(&loaded, &path, true, &no_key).is_err());
+        assert_eq!(fs::read(&path).expect("read unchanged"), encrypted);
+
+        // 키를 다시 읽을 수 있으면 정상적으로 로드/저장
+        let reloaded = load_blueprints_from(&path, &test_key);
+        assert_eq!(reloaded.blueprints.len(), 1);
+        save_blueprints_to(&reloaded, &path, true, &test_key).expect("save with key");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
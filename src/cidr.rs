@@ -0,0 +1,107 @@
+// 서브넷 표에 붙이는 IPv4 CIDR 계산: 주소 수, AWS 예약분을 뺀 사용 가능 호스트 수, 범위 겹침
+use std::net::Ipv4Addr;
+
+/// AWS reserves the first four addresses and the last address of every subnet.
+const AWS_RESERVED_ADDRESSES: i64 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv4Cidr {
+    network: u32,
+    prefix: u8,
+}
+
+impl Ipv4Cidr {
+    /// Parse `a.b.c.d/n`; host bits are masked off, so `10.0.1.7/24` reads as `10.0.1.0/24`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (address, prefix) = raw.trim().split_once('/')?;
+        let address: Ipv4Addr = address.parse().ok()?;
+        let prefix: u8 = prefix.parse().ok()?;
+        if prefix > 32 {
+            return None;
+        }
+        Some(Self {
+            network: u32::from(address) & Self::mask(prefix),
+            prefix,
+        })
+    }
+
+    fn mask(prefix: u8) -> u32 {
+        u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0)
+    }
+
+    fn last_address(self) -> u32 {
+        self.network | !Self::mask(self.prefix)
+    }
+
+    pub fn total_addresses(self) -> i64 {
+        1i64 << (32 - u32::from(self.prefix))
+    }
+
+    /// Addresses left for instances and ENIs once the AWS reserved ones are taken out.
+    pub fn usable_hosts(self) -> i64 {
+        (self.total_addresses() - AWS_RESERVED_ADDRESSES).max(0)
+    }
+
+    /// Percentage of usable hosts in use, from describe-subnets' `AvailableIpAddressCount`.
+    pub fn utilization(self, available_ips: i64) -> Option<f64> {
+        let usable = self.usable_hosts();
+        if usable == 0 {
+            return None;
+        }
+        let used = (usable - available_ips).clamp(0, usable);
+        Some(used as f64 * 100.0 / usable as f64)
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.network <= other.last_address() && other.network <= self.last_address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ipv4Cidr;
+
+    #[test]
+    fn usable_hosts_and_utilization_exclude_aws_reserved_addresses() {
+        let subnet = Ipv4Cidr::parse("10.0.1.0/24").expect("cidr");
+        assert_eq!(subnet.total_addresses(), 256);
+        assert_eq!(subnet.usable_hosts(), 251);
+        assert_eq!(subnet.utilization(251), Some(0.0));
+        assert_eq!(subnet.utilization(0), Some(100.0));
+        let half = subnet.utilization(125).expect("utilization");
+        assert!((half - 50.199).abs() < 0.01);
+
+        assert_eq!(
+            Ipv4Cidr::parse("10.0.0.0/16").unwrap().usable_hosts(),
+            65531
+        );
+        assert_eq!(
+            Ipv4Cidr::parse("0.0.0.0/0").unwrap().total_addresses(),
+            1 << 32
+        );
+        assert_eq!(Ipv4Cidr::parse("10.0.0.1/32").unwrap().usable_hosts(), 0);
+        assert_eq!(Ipv4Cidr::parse("10.0.0.1/32").unwrap().utilization(0), None);
+    }
+
+    #[test]
+    fn parse_masks_host_bits_and_rejects_non_ipv4() {
+        assert_eq!(
+            Ipv4Cidr::parse("10.0.1.7/24"),
+            Ipv4Cidr::parse("10.0.1.0/24")
+        );
+        assert_eq!(Ipv4Cidr::parse("2600:1f18:abc:de01::/64"), None);
+        assert_eq!(Ipv4Cidr::parse("10.0.1.0/33"), None);
+        assert_eq!(Ipv4Cidr::parse("10.0.1.0"), None);
+        assert_eq!(Ipv4Cidr::parse(""), None);
+    }
+
+    #[test]
+    fn overlaps_detects_nested_and_shared_ranges() {
+        let cidr = |raw| Ipv4Cidr::parse(raw).expect("cidr");
+        assert!(cidr("10.0.0.0/16").overlaps(cidr("10.0.1.0/24")));
+        assert!(cidr("10.0.1.0/24").overlaps(cidr("10.0.0.0/16")));
+        assert!(cidr("10.0.1.0/24").overlaps(cidr("10.0.1.128/25")));
+        assert!(!cidr("10.0.1.0/24").overlaps(cidr("10.0.2.0/24")));
+        assert!(!cidr("10.0.1.0/25").overlaps(cidr("10.0.1.128/25")));
+    }
+}
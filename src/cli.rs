@@ -1,4 +1,5 @@
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "emd")]
@@ -7,24 +8,105 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Command>,
+
+    /// Print a summary of the AWS API calls made during the session on exit
+    #[arg(long)]
+    pub audit_summary: bool,
+
+    /// Allow editing Name tags from the TUI (every change asks for confirmation)
+    #[arg(long)]
+    pub allow_write: bool,
+
+    /// Start the TUI with the guided tutorial (toggle any time with F1)
+    #[arg(long)]
+    pub tutorial: bool,
+
+    /// Accessible mode: numbered prompts and plain text output instead of the TUI (for screen readers)
+    #[arg(long)]
+    pub no_tui: bool,
 }
 
 #[derive(Subcommand)]
 pub enum Command {
     /// Update to the latest version
     Update,
+    /// Print the minimal read-only IAM policy emd needs
+    IamPolicy {
+        /// Limit the policy to these services (repeatable, default: all enabled in settings)
+        #[arg(long = "service", value_name = "SERVICE")]
+        services: Vec<String>,
+    },
+    /// Regenerate blueprints on cron schedules and send webhook/Slack notifications
+    Daemon {
+        /// Schedule config file (TOML)
+        #[arg(long, value_name = "FILE")]
+        config: PathBuf,
+    },
+    /// Serve on-demand resource exports over HTTP (GET /export/<service>/<id>?format=md|json)
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+        /// Address to bind (use 0.0.0.0 to accept remote connections)
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+        /// Default region when a request has no `region` parameter
+        #[arg(long)]
+        region: Option<String>,
+    },
 }
 
-pub fn run() -> Option<()> {
-    let cli = Cli::parse();
+/// Run the given subcommand, or return the parsed options when the TUI should start.
+pub fn run() -> Option<Cli> {
+    let mut cli = Cli::parse();
 
-    match cli.command? {
+    let Some(command) = cli.command.take() else {
+        return Some(cli);
+    };
+    match command {
         Command::Update => {
             if let Err(e) = crate::update::perform_update() {
                 eprintln!("Update failed: {}", e);
                 std::process::exit(1);
             }
-            Some(())
+            None
+        }
+        Command::IamPolicy { mut services } => {
+            // 서비스를 지정하지 않으면 설정에서 숨긴 서비스는 제외
+            if services.is_empty() {
+                let settings = crate::settings::load_settings();
+                services =
+                    crate::iam_policy::default_services(&crate::app::hidden_services(&settings));
+            }
+            match crate::iam_policy::policy_document(&services) {
+                Ok(policy) => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&policy).unwrap_or_default()
+                ),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+            None
+        }
+        Command::Daemon { config } => {
+            let _telemetry =
+                crate::telemetry::init(crate::settings::load_settings().otlp_tracing.as_ref());
+            if let Err(e) = crate::daemon::run(&config) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+            None
+        }
+        Command::Serve { port, bind, region } => {
+            let _telemetry =
+                crate::telemetry::init(crate::settings::load_settings().otlp_tracing.as_ref());
+            if let Err(e) = crate::server::run(&bind, port, region) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+            None
         }
     }
 }
@@ -38,6 +120,38 @@ mod tests {
     fn parse_without_subcommand() {
         let cli = Cli::parse_from(["emd"]);
         assert!(cli.command.is_none());
+        assert!(!cli.audit_summary);
+        assert!(!cli.allow_write);
+        assert!(!cli.tutorial);
+        assert!(!cli.no_tui);
+    }
+
+    #[test]
+    fn parse_audit_summary_flag() {
+        let cli = Cli::parse_from(["emd", "--audit-summary"]);
+        assert!(cli.command.is_none());
+        assert!(cli.audit_summary);
+    }
+
+    #[test]
+    fn parse_allow_write_flag() {
+        let cli = Cli::parse_from(["emd", "--allow-write"]);
+        assert!(cli.command.is_none());
+        assert!(cli.allow_write);
+    }
+
+    #[test]
+    fn parse_tutorial_flag() {
+        let cli = Cli::parse_from(["emd", "--tutorial"]);
+        assert!(cli.command.is_none());
+        assert!(cli.tutorial);
+    }
+
+    #[test]
+    fn parse_no_tui_flag() {
+        let cli = Cli::parse_from(["emd", "--no-tui"]);
+        assert!(cli.command.is_none());
+        assert!(cli.no_tui);
     }
 
     #[test]
@@ -45,4 +159,44 @@ mod tests {
         let cli = Cli::parse_from(["emd", "update"]);
         assert!(matches!(cli.command, Some(Command::Update)));
     }
+
+    #[test]
+    fn parse_iam_policy_subcommand_with_services() {
+        let cli = Cli::parse_from(["emd", "iam-policy", "--service", "ec2", "--service", "ecr"]);
+        match cli.command {
+            Some(Command::IamPolicy { services }) => assert_eq!(services, vec!["ec2", "ecr"]),
+            _ => panic!("expected iam-policy subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_daemon_subcommand_with_config() {
+        let cli = Cli::parse_from(["emd", "daemon", "--config", "schedule.toml"]);
+        match cli.command {
+            Some(Command::Daemon { config }) => {
+                assert_eq!(config, std::path::PathBuf::from("schedule.toml"))
+            }
+            _ => panic!("expected daemon subcommand"),
+        }
+        assert!(Cli::try_parse_from(["emd", "daemon"]).is_err());
+    }
+
+    #[test]
+    fn parse_serve_subcommand_defaults_and_port() {
+        match Cli::parse_from(["emd", "serve"]).command {
+            Some(Command::Serve { port, bind, region }) => {
+                assert_eq!(port, 8080);
+                assert_eq!(bind, "127.0.0.1");
+                assert_eq!(region, None);
+            }
+            _ => panic!("expected serve subcommand"),
+        }
+        match Cli::parse_from(["emd", "serve", "--port", "9000", "--region", "eu-west-1"]).command {
+            Some(Command::Serve { port, region, .. }) => {
+                assert_eq!(port, 9000);
+                assert_eq!(region.as_deref(), Some("eu-west-1"));
+            }
+            _ => panic!("expected serve subcommand"),
+        }
+    }
 }
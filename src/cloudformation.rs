@@ -0,0 +1,209 @@
+use crate::aws_cli::{Arn, StackResource};
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+
+fn resource_type_for(cfn_type: &str) -> Option<ResourceType> {
+    match cfn_type {
+        "AWS::EC2::Instance" => Some(ResourceType::Ec2),
+        "AWS::EC2::VPC" => Some(ResourceType::Network),
+        "AWS::EC2::SecurityGroup" => Some(ResourceType::SecurityGroup),
+        "AWS::ElasticLoadBalancingV2::LoadBalancer" => Some(ResourceType::LoadBalancer),
+        "AWS::ECR::Repository" => Some(ResourceType::Ecr),
+        "AWS::AutoScaling::AutoScalingGroup" => Some(ResourceType::Asg),
+        "AWS::RDS::DBInstance" | "AWS::RDS::DBCluster" => Some(ResourceType::Rds),
+        "AWS::Lambda::Function" => Some(ResourceType::Lambda),
+        "AWS::DynamoDB::Table" => Some(ResourceType::DynamoDb),
+        "AWS::CloudFront::Distribution" => Some(ResourceType::CloudFront),
+        "AWS::Route53::HostedZone" => Some(ResourceType::Route53),
+        "AWS::EKS::Cluster" => Some(ResourceType::Eks),
+        "AWS::ECS::Cluster" => Some(ResourceType::Ecs),
+        "AWS::SQS::Queue" => Some(ResourceType::Sqs),
+        "AWS::SNS::Topic" => Some(ResourceType::Sns),
+        "AWS::ElastiCache::ReplicationGroup" | "AWS::ElastiCache::CacheCluster" => {
+            Some(ResourceType::ElastiCache)
+        }
+        "AWS::CloudWatch::Alarm" | "AWS::CloudWatch::CompositeAlarm" => {
+            Some(ResourceType::CloudWatchAlarm)
+        }
+        // Physical ID는 추적 이름이며, 상세 조회는 이름으로도 가능
+        "AWS::CloudTrail::Trail" => Some(ResourceType::CloudTrail),
+        "AWS::KMS::Key" => Some(ResourceType::Kms),
+        "AWS::SecretsManager::Secret" => Some(ResourceType::Secret),
+        "AWS::EFS::FileSystem" => Some(ResourceType::Efs),
+        "AWS::ApiGateway::RestApi" | "AWS::ApiGatewayV2::Api" => Some(ResourceType::ApiGateway),
+        "AWS::ElasticBeanstalk::Environment" => Some(ResourceType::ElasticBeanstalk),
+        "AWS::StepFunctions::StateMachine" => Some(ResourceType::StepFunctions),
+        // Physical ID는 이벤트 버스 이름
+        "AWS::Events::EventBus" => Some(ResourceType::EventBridge),
+        // Physical ID는 사용자 풀 ID / 자격 증명 풀 ID
+        "AWS::Cognito::UserPool" | "AWS::Cognito::IdentityPool" => Some(ResourceType::Cognito),
+        // Physical ID는 데이터베이스/크롤러/작업 그룹 이름
+        "AWS::Glue::Database" | "AWS::Glue::Crawler" => Some(ResourceType::Glue),
+        "AWS::Athena::WorkGroup" => Some(ResourceType::Athena),
+        // Physical ID는 백업 계획 ID
+        "AWS::Backup::BackupPlan" => Some(ResourceType::Backup),
+        "AWS::Kinesis::Stream" => Some(ResourceType::Kinesis),
+        "AWS::MSK::Cluster" => Some(ResourceType::Msk),
+        "AWS::OpenSearchService::Domain" | "AWS::Elasticsearch::Domain" => {
+            Some(ResourceType::OpenSearch)
+        }
+        "AWS::Redshift::Cluster" => Some(ResourceType::Redshift),
+        "AWS::EC2::TransitGateway" => Some(ResourceType::TransitGateway),
+        "AWS::EC2::VPCPeeringConnection" => Some(ResourceType::VpcPeering),
+        "AWS::EC2::VPCEndpoint" => Some(ResourceType::VpcEndpoint),
+        "AWS::EC2::NetworkInterface" => Some(ResourceType::NetworkInterface),
+        "AWS::EC2::LaunchTemplate" => Some(ResourceType::LaunchTemplate),
+        "AWS::EC2::Volume" => Some(ResourceType::Ebs),
+        // Physical ID는 키 이름
+        "AWS::EC2::KeyPair" => Some(ResourceType::KeyPair),
+        "AWS::EC2::VPNConnection" | "AWS::EC2::CustomerGateway" | "AWS::EC2::VPNGateway" => {
+            Some(ResourceType::Vpn)
+        }
+        // Physical ID는 인증서 ARN
+        "AWS::CertificateManager::Certificate" => Some(ResourceType::Acm),
+        // 중첩 스택: Physical ID는 스택 ARN
+        "AWS::CloudFormation::Stack" => Some(ResourceType::CloudFormation),
+        // 관리형 정책의 Physical ID는 정책 ARN (역할/사용자/그룹은 이름뿐이라 제외)
+        "AWS::IAM::ManagedPolicy" => Some(ResourceType::Iam),
+        _ => None,
+    }
+}
+
+// Physical ID는 서비스별로 EMD 리소스 ID와 같음 (LB/SNS 주제: ARN, ECR/ASG/Lambda/DynamoDB/EKS/ECS/CloudWatch 경보: 이름, RDS/ElastiCache: 식별자, CloudFront: 배포 ID, Route 53: 호스팅 영역 ID, KMS: 키 ID, Secrets Manager: ARN, EFS: 파일 시스템 ID, API Gateway: API ID, Elastic Beanstalk: 환경 이름, Step Functions: 상태 머신 ARN, Kinesis: 스트림 이름, MSK: 클러스터 ARN, OpenSearch: 도메인 이름, Redshift: 클러스터 식별자)
+// 단, SQS 대기열의 Physical ID는 URL이므로 마지막 경로인 대기열 이름, 중첩 스택은 ARN에서 읽은 스택 이름 사용
+fn to_blueprint_resource(resource: &StackResource, region: &str) -> Option<BlueprintResource> {
+    let resource_type = resource_type_for(&resource.resource_type)?;
+    if resource.physical_id.is_empty() || resource.status == "DELETE_COMPLETE" {
+        return None;
+    }
+    let resource_id = match resource_type {
+        ResourceType::Sqs => resource
+            .physical_id
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        ResourceType::CloudFormation => Arn::parse(&resource.physical_id)
+            .map(|arn| arn.resource_name())
+            .unwrap_or(&resource.physical_id)
+            .to_string(),
+        // Glue는 이름 앞에 리소스 종류를 붙인 ID (database/<이름>, crawler/<이름>)
+        ResourceType::Glue if resource.resource_type == "AWS::Glue::Crawler" => {
+            format!("crawler/{}", resource.physical_id)
+        }
+        ResourceType::Glue => format!("database/{}", resource.physical_id),
+        _ => resource.physical_id.clone(),
+    };
+
+    Some(BlueprintResource {
+        resource_type,
+        region: region.to_string(),
+        resource_id,
+        resource_name: resource.logical_id.clone(),
+    })
+}
+
+/// Build a blueprint named after the stack from its `list-stack-resources` output.
+pub fn blueprint_from_stack(
+    stack_name: &str,
+    resources: &[StackResource],
+    region: &str,
+) -> Blueprint {
+    let mut blueprint = Blueprint::new(stack_name.to_string());
+    for resource in resources
+        .iter()
+        .filter_map(|resource| to_blueprint_resource(resource, region))
+    {
+        blueprint.add_resource(resource);
+    }
+    blueprint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blueprint_from_stack;
+    use crate::aws_cli::StackResource;
+    use crate::blueprint::ResourceType;
+
+    fn stack_resource(logical_id: &str, physical_id: &str, resource_type: &str) -> StackResource {
+        StackResource {
+            logical_id: logical_id.to_string(),
+            physical_id: physical_id.to_string(),
+            resource_type: resource_type.to_string(),
+            status: "CREATE_COMPLETE".to_string(),
+        }
+    }
+
+    #[test]
+    fn blueprint_from_stack_keeps_supported_resources_in_stack_order() {
+        let resources = vec![
+            stack_resource("AppVpc", "vpc-1", "AWS::EC2::VPC"),
+            stack_resource("LogsBucket", "logs-bucket", "AWS::S3::Bucket"),
+            stack_resource(
+                "PublicAlb",
+                "arn:aws:elasticloadbalancing:ap-northeast-2:123456789012:loadbalancer/app/public/abcd",
+                "AWS::ElasticLoadBalancingV2::LoadBalancer",
+            ),
+            stack_resource("ApiRepo", "api", "AWS::ECR::Repository"),
+        ];
+
+        let blueprint = blueprint_from_stack("app-stack", &resources, "ap-northeast-2");
+        assert_eq!(blueprint.name, "app-stack");
+        assert_eq!(blueprint.resources.len(), 3);
+        assert_eq!(blueprint.resources[0].resource_type, ResourceType::Network);
+        assert_eq!(blueprint.resources[0].resource_name, "AppVpc");
+        assert_eq!(
+            blueprint.resources[1].resource_type,
+            ResourceType::LoadBalancer
+        );
+        assert!(blueprint.resources[1].resource_id.starts_with("arn:aws:"));
+        assert_eq!(blueprint.resources[2].resource_id, "api");
+        assert_eq!(blueprint.resources[2].region, "ap-northeast-2");
+    }
+
+    #[test]
+    fn blueprint_from_stack_skips_deleted_or_unprovisioned_resources() {
+        let mut deleted = stack_resource("OldWeb", "i-old", "AWS::EC2::Instance");
+        deleted.status = "DELETE_COMPLETE".to_string();
+        let resources = vec![
+            deleted,
+            stack_resource("PendingAsg", "", "AWS::AutoScaling::AutoScalingGroup"),
+            stack_resource("WebSg", "sg-1", "AWS::EC2::SecurityGroup"),
+        ];
+
+        let blueprint = blueprint_from_stack("web", &resources, "us-east-1");
+        assert_eq!(blueprint.resources.len(), 1);
+        assert_eq!(blueprint.resources[0].resource_id, "sg-1");
+    }
+
+    #[test]
+    fn blueprint_from_stack_uses_queue_name_for_sqs_url() {
+        let resources = vec![stack_resource(
+            "OrdersQueue",
+            "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders",
+            "AWS::SQS::Queue",
+        )];
+
+        let blueprint = blueprint_from_stack("queues", &resources, "ap-northeast-2");
+        assert_eq!(blueprint.resources.len(), 1);
+        assert_eq!(blueprint.resources[0].resource_type, ResourceType::Sqs);
+        assert_eq!(blueprint.resources[0].resource_id, "orders");
+        assert_eq!(blueprint.resources[0].resource_name, "OrdersQueue");
+    }
+
+    #[test]
+    fn blueprint_from_stack_uses_stack_name_for_nested_stack() {
+        let resources = vec![stack_resource(
+            "NetworkStack",
+            "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/app-NetworkStack-1A2B/0f1e2d3c",
+            "AWS::CloudFormation::Stack",
+        )];
+
+        let blueprint = blueprint_from_stack("app", &resources, "ap-northeast-2");
+        assert_eq!(blueprint.resources.len(), 1);
+        assert_eq!(
+            blueprint.resources[0].resource_type,
+            ResourceType::CloudFormation
+        );
+        assert_eq!(blueprint.resources[0].resource_id, "app-NetworkStack-1A2B");
+    }
+}
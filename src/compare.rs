@@ -0,0 +1,301 @@
+use crate::blueprint::BlueprintResource;
+use crate::i18n::{I18n, Language};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl FieldDiff {
+    pub fn is_different(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+// 같은 종류의 두 리소스를 나란히 비교한 결과
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub left: BlueprintResource,
+    pub right: BlueprintResource,
+    pub left_markdown: String,
+    pub right_markdown: String,
+    pub fields: Vec<FieldDiff>,
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+fn is_separator_row(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with('|') && line.chars().all(|c| matches!(c, '|' | ':' | '-' | ' '))
+}
+
+// 같은 필드가 여러 번 나오면 (규칙 표, 목록) 값을 이어 붙임
+fn push_field(fields: &mut Vec<(String, String)>, field: String, value: String) {
+    if let Some(existing) = fields.iter_mut().find(|(name, _)| *name == field) {
+        existing.1.push_str("; ");
+        existing.1.push_str(&value);
+    } else {
+        fields.push((field, value));
+    }
+}
+
+/// Flatten a detail markdown document into `(field, value)` pairs.
+///
+/// Two-column table rows become `section / key` fields; wider table rows and
+/// list items are joined into a single value per section. The top-level `##`
+/// heading carries the resource name, so it does not prefix field names.
+pub fn markdown_fields(markdown: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut section = String::new();
+    let mut in_code_block = false;
+    let mut header_cells = 0;
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        if let Some(heading) = trimmed.strip_prefix("### ") {
+            section = heading.trim().to_string();
+            header_cells = 0;
+        } else if trimmed.starts_with("## ") || trimmed.starts_with("# ") {
+            section.clear();
+            header_cells = 0;
+        } else if trimmed.starts_with('|') {
+            if is_separator_row(trimmed) {
+                continue;
+            }
+            // 다음 줄이 구분선이면 표 머리글
+            if lines
+                .get(idx + 1)
+                .is_some_and(|next| is_separator_row(next))
+            {
+                header_cells = table_cells(trimmed).len();
+                continue;
+            }
+            let cells = table_cells(trimmed);
+            if header_cells == 2 && cells.len() == 2 {
+                let field = if section.is_empty() {
+                    cells[0].clone()
+                } else {
+                    format!("{} / {}", section, cells[0])
+                };
+                push_field(&mut fields, field, cells[1].clone());
+            } else {
+                push_field(&mut fields, section.clone(), cells.join(" | "));
+            }
+        } else if let Some(item) = trimmed.strip_prefix("- ") {
+            push_field(&mut fields, section.clone(), item.to_string());
+        }
+    }
+
+    fields
+}
+
+/// Pair up fields from both documents, keeping the left document's order and
+/// appending fields that only exist on the right.
+pub fn diff_fields(left_markdown: &str, right_markdown: &str) -> Vec<FieldDiff> {
+    let left = markdown_fields(left_markdown);
+    let right = markdown_fields(right_markdown);
+
+    let mut diffs: Vec<FieldDiff> = left
+        .iter()
+        .map(|(field, value)| FieldDiff {
+            field: field.clone(),
+            left: value.clone(),
+            right: right
+                .iter()
+                .find(|(name, _)| name == field)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default(),
+        })
+        .collect();
+    for (field, value) in &right {
+        if !left.iter().any(|(name, _)| name == field) {
+            diffs.push(FieldDiff {
+                field: field.clone(),
+                left: String::new(),
+                right: value.clone(),
+            });
+        }
+    }
+    diffs
+}
+
+fn cell(value: &str) -> &str {
+    if value.is_empty() { "-" } else { value }
+}
+
+impl Comparison {
+    pub fn new(
+        left: BlueprintResource,
+        left_markdown: String,
+        right: BlueprintResource,
+        right_markdown: String,
+    ) -> Self {
+        let fields = diff_fields(&left_markdown, &right_markdown);
+        Self {
+            left,
+            right,
+            left_markdown,
+            right_markdown,
+            fields,
+        }
+    }
+
+    pub fn difference_count(&self) -> usize {
+        self.fields.iter().filter(|f| f.is_different()).count()
+    }
+
+    pub fn filename(&self) -> String {
+        format!(
+            "compare-{}-{}.md",
+            self.left.resource_name, self.right.resource_name
+        )
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let mut lines = vec![
+            format!(
+                "## {}: {} ↔ {}\n",
+                self.left.resource_type.display(),
+                self.left.resource_name,
+                self.right.resource_name
+            ),
+            format!(
+                "{}\n",
+                i18n.compare_difference_count(self.difference_count())
+            ),
+            format!(
+                "| {} | {} | {} | |",
+                i18n.compare_field(),
+                self.left.resource_name,
+                self.right.resource_name
+            ),
+            "|:---|:---|:---|:---:|".to_string(),
+        ];
+        for field in &self.fields {
+            let marker = if field.is_different() { "≠" } else { "" };
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                cell(&field.field),
+                cell(&field.left),
+                cell(&field.right),
+                marker
+            ));
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparison, diff_fields, markdown_fields};
+    use crate::blueprint::{BlueprintResource, ResourceType};
+    use crate::i18n::Language;
+
+    const PROD_SG: &str = "## Security Group (sg-prod)\n\n\
+        | Item | Value |\n\
+        |:---|:---|\n\
+        | Name | sg-prod |\n\
+        | VPC | vpc-1 |\n\n\
+        ### Inbound Rules\n\n\
+        | Protocol | Port Range | Source |\n\
+        |:---|:---|:---|\n\
+        | TCP | 443 | 0.0.0.0/0 |\n\
+        | TCP | 22 | 10.0.0.0/8 |\n";
+    const STAGING_SG: &str = "## Security Group (sg-staging)\n\n\
+        | Item | Value |\n\
+        |:---|:---|\n\
+        | Name | sg-staging |\n\
+        | VPC | vpc-1 |\n\n\
+        ### Inbound Rules\n\n\
+        | Protocol | Port Range | Source |\n\
+        |:---|:---|:---|\n\
+        | TCP | 443 | 0.0.0.0/0 |\n\n\
+        ### Tags\n\n\
+        - Env=staging\n";
+
+    fn resource(id: &str) -> BlueprintResource {
+        BlueprintResource {
+            resource_type: ResourceType::SecurityGroup,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn markdown_fields_flattens_tables_and_lists() {
+        let fields = markdown_fields(STAGING_SG);
+        assert_eq!(fields[0], ("Name".to_string(), "sg-staging".to_string()));
+        assert_eq!(fields[1], ("VPC".to_string(), "vpc-1".to_string()));
+        assert_eq!(
+            fields[2],
+            (
+                "Inbound Rules".to_string(),
+                "TCP | 443 | 0.0.0.0/0".to_string()
+            )
+        );
+        assert_eq!(fields[3], ("Tags".to_string(), "Env=staging".to_string()));
+    }
+
+    #[test]
+    fn markdown_fields_skips_code_blocks() {
+        let fields = markdown_fields("## EC2 (web)\n\n```bash\n- not a list item\n```\n- real\n");
+        assert_eq!(fields, vec![(String::new(), "real".to_string())]);
+    }
+
+    #[test]
+    fn diff_fields_marks_changed_and_one_sided_fields() {
+        let diffs = diff_fields(PROD_SG, STAGING_SG);
+        let name = diffs.iter().find(|d| d.field == "Name").expect("name");
+        assert!(name.is_different());
+        let vpc = diffs.iter().find(|d| d.field == "VPC").expect("vpc");
+        assert!(!vpc.is_different());
+        let rules = diffs
+            .iter()
+            .find(|d| d.field == "Inbound Rules")
+            .expect("rules");
+        assert_eq!(rules.left, "TCP | 443 | 0.0.0.0/0; TCP | 22 | 10.0.0.0/8");
+        assert!(rules.is_different());
+        let tags = diffs.last().expect("tags");
+        assert_eq!(tags.field, "Tags");
+        assert_eq!(tags.left, "");
+    }
+
+    #[test]
+    fn comparison_markdown_renders_side_by_side_table() {
+        let comparison = Comparison::new(
+            resource("sg-prod"),
+            PROD_SG.to_string(),
+            resource("sg-staging"),
+            STAGING_SG.to_string(),
+        );
+        assert_eq!(comparison.difference_count(), 3);
+        assert_eq!(comparison.filename(), "compare-sg-prod-sg-staging.md");
+
+        let markdown = comparison.to_markdown(Language::English);
+        assert!(markdown.contains("## Security Group: sg-prod ↔ sg-staging"));
+        assert!(markdown.contains("| Field | sg-prod | sg-staging | |"));
+        assert!(markdown.contains("| VPC | vpc-1 | vpc-1 |  |"));
+        assert!(markdown.contains("| Tags | - | Env=staging | ≠ |"));
+    }
+}
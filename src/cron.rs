@@ -0,0 +1,499 @@
+// 5필드 cron 표현식 (분 시 일 월 요일) 파서와 다음 실행 시각 계산
+// 문서에 쓰는 AWS 일정 표현식(cron(...), rate(...))을 문장으로 풀어 쓰기
+use crate::i18n::I18n;
+use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// 다음 실행 시각을 찾을 때 살펴볼 최대 기간 (2월 29일 같은 드문 일정 포함)
+const MAX_LOOKAHEAD_DAYS: i64 = 366 * 5;
+
+/// A parsed five-field cron expression (`minute hour day-of-month month day-of-week`).
+///
+/// Supports `*`, lists (`1,15`), ranges (`1-5`), steps (`*/15`, `0-30/10`) and the
+/// `@hourly`, `@daily`, `@weekly`, `@monthly` shortcuts. Day of week is 0-7 (0 and 7 are Sunday).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    // 일/요일이 둘 다 제한되면 둘 중 하나만 맞아도 실행 (표준 cron 동작)
+    // `*`로 시작하는 필드(`*`, `*/2`)는 제한하지 않은 것으로 봄
+    day_of_month_any: bool,
+    day_of_week_any: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(format!(
+                "Expected 5 cron fields (minute hour day month weekday), got {}",
+                fields.len()
+            ));
+        };
+
+        // 요일 7은 일요일(0)과 같음
+        let weekdays = parse_field(day_of_week, 0, 7)?;
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)? as u32,
+            days_of_month: parse_field(day_of_month, 1, 31)? as u32,
+            months: parse_field(month, 1, 12)? as u16,
+            days_of_week: weekdays as u8,
+            day_of_month_any: day_of_month.starts_with('*'),
+            day_of_week_any: day_of_week.starts_with('*'),
+        })
+    }
+
+    pub fn matches(&self, time: NaiveDateTime) -> bool {
+        self.matches_day(time)
+            && self.hours & (1 << time.hour()) != 0
+            && self.minutes & (1 << time.minute()) != 0
+    }
+
+    fn matches_day(&self, time: NaiveDateTime) -> bool {
+        if self.months & (1 << time.month()) == 0 {
+            return false;
+        }
+        let day_of_month = self.days_of_month & (1 << time.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << time.weekday().num_days_from_sunday()) != 0;
+        if self.day_of_month_any || self.day_of_week_any {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+
+    /// First matching minute strictly after `after`, or `None` if nothing matches (e.g. `0 0 31 2 *`).
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut time = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = after + Duration::days(MAX_LOOKAHEAD_DAYS);
+        while time <= limit {
+            if !self.matches_day(time) {
+                // 다음 날 00:00으로 건너뜀
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if self.hours & (1 << time.hour()) == 0 {
+                time = time.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << time.minute()) != 0 {
+                return Some(time);
+            }
+            time += Duration::minutes(1);
+        }
+        None
+    }
+}
+
+// 필드 하나를 비트마스크로 변환 (비트 n = 값 n)
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("Invalid cron step: {}", part))?;
+                if step == 0 {
+                    return Err(format!("Invalid cron step: {}", part));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, min, max)?, parse_value(end, min, max)?)
+        } else {
+            let value = parse_value(range, min, max)?;
+            // "5/10"은 5부터 최대값까지 10 간격
+            (value, if step > 1 { max } else { value })
+        };
+        if start > end {
+            return Err(format!("Invalid cron range: {}", part));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, max: u32) -> Result<u32, String> {
+    match value.parse::<u32>() {
+        Ok(value) if (min..=max).contains(&value) => Ok(value),
+        _ => Err(format!(
+            "Invalid cron value: {} (expected {}-{})",
+            value, min, max
+        )),
+    }
+}
+
+static SPELL_OUT_SCHEDULES: AtomicBool = AtomicBool::new(false);
+
+// 풀어 쓸 실행 시각 목록의 최대 개수 (더 많으면 원본 표현식만 표시)
+const MAX_SPELLED_TIMES: usize = 6;
+
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Spell out schedule expressions in every document rendered from now on.
+pub fn set_spell_out_schedules(enabled: bool) {
+    SPELL_OUT_SCHEDULES.store(enabled, Ordering::Relaxed);
+}
+
+/// Schedule expression for a markdown cell: `` `cron(0 9 * * ? *)` ``, or with spell-out on,
+/// `` Every day at 09:00 (`cron(0 9 * * ? *)`) ``.
+pub fn schedule_text(expression: &str, i18n: &I18n) -> String {
+    schedule_text_in(
+        SPELL_OUT_SCHEDULES.load(Ordering::Relaxed),
+        expression,
+        i18n,
+    )
+}
+
+fn schedule_text_in(spell_out: bool, expression: &str, i18n: &I18n) -> String {
+    let raw = format!("`{}`", expression.trim());
+    match spell_out.then(|| describe(expression, i18n)).flatten() {
+        Some(sentence) => format!("{} ({})", sentence, raw),
+        None => raw,
+    }
+}
+
+/// Sentence for `rate(...)`, AWS `cron(...)` (six fields, `1` = Sunday) or a five-field
+/// Unix cron expression (`0` = Sunday). `None` when the expression uses something we
+/// don't spell out (`#`, `W`, steps inside ranges, specific years, ...).
+pub fn describe(expression: &str, i18n: &I18n) -> Option<String> {
+    let expression = expression.trim();
+    if let Some(rate) = unwrap_call(expression, "rate") {
+        return describe_rate(rate, i18n);
+    }
+
+    let (fields, aws) = match unwrap_call(expression, "cron") {
+        Some(inner) => (inner.split_whitespace().collect::<Vec<_>>(), true),
+        None => {
+            let expression = match expression {
+                "@hourly" => "0 * * * *",
+                "@daily" | "@midnight" => "0 0 * * *",
+                "@weekly" => "0 0 * * 0",
+                "@monthly" => "0 0 1 * *",
+                other => other,
+            };
+            (expression.split_whitespace().collect(), false)
+        }
+    };
+    let (minute, hour, day_of_month, month, day_of_week) = match (aws, &fields[..]) {
+        (true, [minute, hour, dom, month, dow, year]) if *year == "*" => {
+            (*minute, *hour, *dom, *month, *dow)
+        }
+        (false, [minute, hour, dom, month, dow]) => (*minute, *hour, *dom, *month, *dow),
+        _ => return None,
+    };
+
+    let days = describe_days(day_of_month, day_of_week, aws, i18n)?;
+    let sentence = match (expand(minute, 0, 59, &[]), expand(hour, 0, 23, &[])) {
+        (Some(minutes), Some(hours)) if minutes.len() * hours.len() <= MAX_SPELLED_TIMES => {
+            let times: Vec<String> = hours
+                .iter()
+                .flat_map(|h| minutes.iter().map(move |m| format!("{:02}:{:02}", h, m)))
+                .collect();
+            i18n.schedule_at(days.as_deref(), &i18n.join_list(&times))
+        }
+        _ => {
+            let interval = describe_interval(minute, hour, i18n)?;
+            i18n.schedule_repeating(&interval, days.as_deref())
+        }
+    };
+
+    if month == "*" {
+        return Some(sentence);
+    }
+    let months: Vec<String> = expand(month, 1, 12, &MONTH_NAMES)?
+        .into_iter()
+        .map(|m| i18n.month_name(m).to_string())
+        .collect();
+    Some(i18n.schedule_in_months(&sentence, &i18n.join_list(&months)))
+}
+
+// "rate(5 minutes)" -> Some("5 minutes")
+fn unwrap_call<'a>(expression: &'a str, name: &str) -> Option<&'a str> {
+    expression
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+        .map(str::trim)
+}
+
+fn describe_rate(rate: &str, i18n: &I18n) -> Option<String> {
+    let (value, unit) = rate.split_once(char::is_whitespace)?;
+    let value: u32 = value.parse().ok().filter(|v| *v > 0)?;
+    let interval = match unit.trim() {
+        "minute" | "minutes" => i18n.schedule_every_minutes(value),
+        "hour" | "hours" => i18n.schedule_every_hours(value),
+        "day" | "days" => i18n.schedule_every_days(value),
+        _ => return None,
+    };
+    Some(i18n.schedule_repeating(&interval, None))
+}
+
+// 시각 목록으로 풀 수 없는 반복 일정 ("*/15 *", "0 */2" 등)
+fn describe_interval(minute: &str, hour: &str, i18n: &I18n) -> Option<String> {
+    let step = |field: &str| -> Option<u32> {
+        let (start, step) = field.split_once('/')?;
+        if start != "*" && start != "0" {
+            return None;
+        }
+        step.parse().ok().filter(|s| *s > 0)
+    };
+    match (minute, hour) {
+        ("*", "*") => Some(i18n.schedule_every_minutes(1)),
+        (minute, "*") => match step(minute) {
+            Some(step) => Some(i18n.schedule_every_minutes(step)),
+            None => {
+                let minute: u32 = minute.parse().ok().filter(|m| *m <= 59)?;
+                Some(i18n.schedule_hourly_at(minute))
+            }
+        },
+        ("0", hour) => step(hour).map(|step| i18n.schedule_every_hours(step)),
+        _ => None,
+    }
+}
+
+// None은 표현 불가, Some(None)은 매일
+fn describe_days(
+    day_of_month: &str,
+    day_of_week: &str,
+    aws: bool,
+    i18n: &I18n,
+) -> Option<Option<String>> {
+    let any = |field: &str| field == "*" || field == "?";
+    match (any(day_of_month), any(day_of_week)) {
+        (true, true) => Some(None),
+        (false, true) if day_of_month == "L" => {
+            Some(Some(i18n.schedule_last_day_of_month().to_string()))
+        }
+        (false, true) => {
+            let days = expand(day_of_month, 1, 31, &[])?;
+            Some(Some(i18n.schedule_days_of_month(&days)))
+        }
+        (true, false) => {
+            // AWS는 1-7 (1 = 일요일), Unix cron은 0-7 (0과 7 = 일요일)
+            let (min, max) = if aws { (1, 7) } else { (0, 7) };
+            let to_index = |value: u32| if aws { value - 1 } else { value % 7 };
+            let names: &[&str] = &WEEKDAY_NAMES;
+            let weekday = |value: &str| -> Option<u32> {
+                let values = expand(value, min, max, names)?;
+                match values[..] {
+                    [value] => Some(value),
+                    _ => None,
+                }
+            };
+            if let Some((from, to)) = day_of_week.split_once('-')
+                && !day_of_week.contains([',', '/'])
+            {
+                let (from, to) = (to_index(weekday(from)?), to_index(weekday(to)?));
+                return Some(Some(i18n.schedule_weekday_range(
+                    i18n.weekday_name(from),
+                    i18n.weekday_name(to),
+                )));
+            }
+            let mut days: Vec<u32> = expand(day_of_week, min, max, names)?
+                .into_iter()
+                .map(to_index)
+                .collect();
+            days.sort_unstable();
+            days.dedup();
+            let days: Vec<String> = days
+                .into_iter()
+                .map(|day| i18n.weekday_name(day).to_string())
+                .collect();
+            Some(Some(i18n.schedule_weekdays(&i18n.join_list(&days))))
+        }
+        (false, false) => None,
+    }
+}
+
+// 목록/범위를 값 목록으로 펼침 ("1,15" -> [1, 15], "MON-WED" -> [2, 3, 4]); 간격(/)은 지원하지 않음
+fn expand(field: &str, min: u32, max: u32, names: &[&str]) -> Option<Vec<u32>> {
+    // 이름은 최소값부터 차례로 대응 (AWS 요일은 SUN = 1, Unix 요일은 SUN = 0, 월은 JAN = 1)
+    let value = |text: &str| -> Option<u32> {
+        let value = match names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(text))
+        {
+            Some(index) => min + index as u32,
+            None => text.parse().ok()?,
+        };
+        (min..=max).contains(&value).then_some(value)
+    };
+    let mut values = Vec::new();
+    for part in field.split(',') {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (value(start)?, value(end)?);
+                if start > end {
+                    return None;
+                }
+                values.extend(start..=end);
+            }
+            None => values.push(value(part)?),
+        }
+    }
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CronSchedule, describe, schedule_text_in};
+    use crate::i18n::{I18n, Language};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        // 2026-03-02는 월요일
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_rejects_invalid_expressions() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 5-1 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 0 * *").is_err());
+        assert!(CronSchedule::parse("0 6 * * mon").is_err());
+        assert_eq!(
+            CronSchedule::parse("@daily"),
+            CronSchedule::parse("0 0 * * *")
+        );
+    }
+
+    #[test]
+    fn next_after_handles_steps_lists_and_ranges() {
+        let every_15 = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(every_15.next_after(at(2, 10, 7)), Some(at(2, 10, 15)));
+        assert_eq!(every_15.next_after(at(2, 10, 45)), Some(at(2, 11, 0)));
+
+        // 평일 06:30, 18:30
+        let weekdays = CronSchedule::parse("30 6,18 * * 1-5").unwrap();
+        assert_eq!(weekdays.next_after(at(2, 6, 30)), Some(at(2, 18, 30)));
+        // 3월 6일(금) 18:30 이후는 3월 9일(월) 06:30
+        assert_eq!(weekdays.next_after(at(6, 18, 30)), Some(at(9, 6, 30)));
+        assert!(weekdays.matches(at(3, 6, 30)));
+        assert!(!weekdays.matches(at(7, 6, 30)));
+    }
+
+    #[test]
+    fn next_after_ors_day_of_month_and_weekday() {
+        // 매월 15일 또는 일요일(7) 자정
+        let schedule = CronSchedule::parse("0 0 15 * 7").unwrap();
+        assert_eq!(schedule.next_after(at(2, 12, 0)), Some(at(8, 0, 0)));
+        assert_eq!(schedule.next_after(at(14, 0, 0)), Some(at(15, 0, 0)));
+
+        assert_eq!(
+            CronSchedule::parse("0 0 31 2 *")
+                .unwrap()
+                .next_after(at(2, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn starred_day_of_month_step_is_not_ored_with_weekday() {
+        // 홀수 날이면서 월요일인 날 자정 (*/2는 제한 없는 필드라 OR가 아님)
+        let schedule = CronSchedule::parse("0 0 */2 * 1").unwrap();
+        assert!(!schedule.matches(at(2, 0, 0)));
+        assert!(!schedule.matches(at(3, 0, 0)));
+        assert!(schedule.matches(at(9, 0, 0)));
+        assert_eq!(schedule.next_after(at(2, 0, 0)), Some(at(9, 0, 0)));
+
+        // 요일 쪽이 */2여도 마찬가지로 둘 다 맞아야 실행
+        let schedule = CronSchedule::parse("0 0 15 * */2").unwrap();
+        assert_eq!(schedule.next_after(at(2, 0, 0)), Some(at(15, 0, 0)));
+        assert!(!schedule.matches(at(3, 0, 0)));
+    }
+
+    #[test]
+    fn describe_spells_out_aws_and_unix_expressions() {
+        let en = I18n::new(Language::English);
+        let spell = |expression: &str| describe(expression, &en);
+        assert_eq!(spell("rate(1 day)").as_deref(), Some("Every day"));
+        assert_eq!(
+            spell("rate(15 minutes)").as_deref(),
+            Some("Every 15 minutes")
+        );
+        assert_eq!(
+            spell("cron(0 9 * * ? *)").as_deref(),
+            Some("Every day at 09:00")
+        );
+        assert_eq!(
+            spell("cron(30 6,18 ? * MON-FRI *)").as_deref(),
+            Some("Monday through Friday at 06:30 and 18:30")
+        );
+        // AWS 요일 1 = 일요일, Unix 요일 0 = 일요일
+        assert_eq!(
+            spell("cron(0 2 ? * 1,7 *)").as_deref(),
+            Some("Every Sunday and Saturday at 02:00")
+        );
+        assert_eq!(
+            spell("0 2 * * 0,6").as_deref(),
+            Some("Every Sunday and Saturday at 02:00")
+        );
+        assert_eq!(
+            spell("cron(0 3 L * ? *)").as_deref(),
+            Some("The last day of every month at 03:00")
+        );
+        assert_eq!(
+            spell("cron(0 0 1,15 JAN,JUL ? *)").as_deref(),
+            Some("Days 1 and 15 of every month at 00:00 in January and July")
+        );
+        assert_eq!(
+            spell("*/15 * * * 1-5").as_deref(),
+            Some("Every 15 minutes, Monday through Friday")
+        );
+        assert_eq!(spell("@hourly").as_deref(), Some("Every hour at minute 0"));
+
+        // 풀어 쓰지 않는 표현식
+        assert_eq!(spell("cron(0 10 ? * 6#3 *)"), None);
+        assert_eq!(spell("cron(0 9 * * ? 2027)"), None);
+        assert_eq!(spell("0 9-17 * * *"), None);
+        assert_eq!(spell("at(2026-10-01T09:00:00)"), None);
+        assert_eq!(spell("rate(1 week)"), None);
+    }
+
+    #[test]
+    fn schedule_text_keeps_raw_expression_alongside() {
+        let ko = I18n::new(Language::Korean);
+        assert_eq!(
+            schedule_text_in(true, "cron(30 6 ? * MON-FRI *)", &ko),
+            "월요일부터 금요일까지 06:30 (`cron(30 6 ? * MON-FRI *)`)"
+        );
+        assert_eq!(
+            schedule_text_in(true, "rate(2 hours)", &ko),
+            "2시간마다 (`rate(2 hours)`)"
+        );
+        assert_eq!(
+            schedule_text_in(true, "cron(0 10 ? * 6#3 *)", &ko),
+            "`cron(0 10 ? * 6#3 *)`"
+        );
+        assert_eq!(schedule_text_in(false, "0 9 * * *", &ko), "`0 9 * * *`");
+    }
+}
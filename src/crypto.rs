@@ -0,0 +1,146 @@
+use aws_lc_rs::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use base64::{Engine as _, engine::general_purpose};
+use std::sync::OnceLock;
+
+// 암호화 파일 형식: MAGIC | nonce(12) | AES-256-GCM ciphertext+tag
+const MAGIC: &[u8] = b"EMDENC1\n";
+const KEY_LEN: usize = 32;
+const KEYCHAIN_ACCOUNT: &str = "data-key";
+const KEY_ENV: &str = "EMD_DATA_KEY";
+
+pub type DataKey = [u8; KEY_LEN];
+
+/// Where a file's data key comes from ([`data_key`], or a fixed key in tests).
+pub type KeySource = dyn Fn() -> Result<DataKey, String>;
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+fn sealing_key(key: &DataKey) -> Result<LessSafeKey, String> {
+    UnboundKey::new(&AES_256_GCM, key)
+        .map(LessSafeKey::new)
+        .map_err(|_| "invalid data key".to_string())
+}
+
+pub fn encrypt(key: &DataKey, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    aws_lc_rs::rand::fill(&mut nonce).map_err(|_| "failed to generate nonce".to_string())?;
+
+    let mut in_out = plaintext.to_vec();
+    sealing_key(key)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut in_out,
+        )
+        .map_err(|_| "encryption failed".to_string())?;
+
+    let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + in_out.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&in_out);
+    Ok(out)
+}
+
+pub fn decrypt(key: &DataKey, data: &[u8]) -> Result<Vec<u8>, String> {
+    let body = data
+        .strip_prefix(MAGIC)
+        .filter(|body| body.len() > NONCE_LEN)
+        .ok_or_else(|| "not an encrypted emd file".to_string())?;
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| "invalid nonce".to_string())?;
+
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = sealing_key(key)?
+        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .map_err(|_| "decryption failed (wrong key or corrupted file)".to_string())?;
+    Ok(plaintext.to_vec())
+}
+
+/// Serialized `content` as it is written to disk: encrypted when `encrypt` is set.
+pub fn encode_text(content: String, encrypt: bool, key: &KeySource) -> Result<Vec<u8>, String> {
+    if encrypt {
+        self::encrypt(&key()?, content.as_bytes())
+    } else {
+        Ok(content.into_bytes())
+    }
+}
+
+/// Text of a file written by [`encode_text`], decrypting it if it is encrypted.
+pub fn decode_text(bytes: &[u8], key: &KeySource) -> Result<String, String> {
+    let plaintext = if is_encrypted(bytes) {
+        decrypt(&key()?, bytes)?
+    } else {
+        bytes.to_vec()
+    };
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+fn decode_key(encoded: &str) -> Result<DataKey, String> {
+    general_purpose::STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| DataKey::try_from(bytes.as_slice()).ok())
+        .ok_or_else(|| "data key must be 32 bytes of base64".to_string())
+}
+
+/// Data key from `EMD_DATA_KEY` (base64) or the OS keychain, generated on first use.
+pub fn data_key() -> Result<DataKey, String> {
+    // 저장할 때마다 키체인 접근 프롬프트가 뜨지 않도록 프로세스 내에서 캐시
+    static CACHED: OnceLock<DataKey> = OnceLock::new();
+    if let Some(key) = CACHED.get() {
+        return Ok(*key);
+    }
+
+    let key = match std::env::var(KEY_ENV) {
+        Ok(encoded) if !encoded.trim().is_empty() => decode_key(&encoded)?,
+        _ => {
+            let encoded = crate::keychain::get_or_create_secret(KEYCHAIN_ACCOUNT, || {
+                let mut key = [0u8; KEY_LEN];
+                aws_lc_rs::rand::fill(&mut key)
+                    .map_err(|_| "failed to generate data key".to_string())?;
+                Ok(general_purpose::STANDARD.encode(key))
+            })?;
+            decode_key(&encoded)?
+        }
+    };
+    Ok(*CACHED.get_or_init(|| key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_key, decrypt, encrypt, is_encrypted};
+
+    #[test]
+    fn encrypt_round_trips_and_marks_output() {
+        let key = [7u8; 32];
+        let sealed = encrypt(&key, b"{\"blueprints\":[]}").expect("encrypt");
+
+        assert!(is_encrypted(&sealed));
+        assert!(!is_encrypted(b"{\"blueprints\":[]}"));
+        assert_eq!(
+            decrypt(&key, &sealed).expect("decrypt"),
+            b"{\"blueprints\":[]}"
+        );
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_key_and_tampering() {
+        let sealed = encrypt(&[1u8; 32], b"secret").expect("encrypt");
+        assert!(decrypt(&[2u8; 32], &sealed).is_err());
+
+        let mut tampered = sealed.clone();
+        if let Some(last) = tampered.last_mut() {
+            *last ^= 0xff;
+        }
+        assert!(decrypt(&[1u8; 32], &tampered).is_err());
+        assert!(decrypt(&[1u8; 32], b"plain").is_err());
+    }
+
+    #[test]
+    fn decode_key_requires_32_bytes() {
+        assert!(decode_key("AAAA").is_err());
+        assert!(decode_key("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=").is_ok());
+    }
+}
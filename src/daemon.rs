@@ -0,0 +1,501 @@
+// 예약 문서화 데몬: 설정 파일의 cron 일정에 따라 블루프린트 문서를 다시 생성하고 웹훅/Slack으로 알림
+use crate::cron::CronSchedule;
+use crate::metrics::{self, DaemonMetrics};
+use chrono::NaiveDateTime;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use toml_edit::{DocumentMut, Item, Table};
+
+// 대기 중에도 시계 변경(절전 복귀 등)을 반영하도록 최대 1분씩 잠듦
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One blueprint to regenerate on a schedule (`[[job]]` in the config file).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaemonJob {
+    pub blueprint: String,
+    pub schedule: CronSchedule,
+    pub schedule_expression: String,
+    // 출력 파일 이름 (기본: <blueprint>.md, split이면 디렉터리 이름 <blueprint>)
+    pub output: Option<String>,
+    // index.md와 리소스별 파일로 나눠 저장 (git 기반 위키용)
+    pub split: bool,
+}
+
+/// Where to report job results (`[notify]` in the config file).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NotifyConfig {
+    pub webhook_url: Option<String>,
+    pub slack_webhook_url: Option<String>,
+    pub only_failures: bool,
+}
+
+/// Parsed `emd daemon --config` file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaemonConfig {
+    pub profile: Option<String>,
+    pub output_dir: PathBuf,
+    // Prometheus 메트릭 엔드포인트 주소 (예: 127.0.0.1:9464)
+    pub metrics_addr: Option<String>,
+    pub notify: NotifyConfig,
+    pub jobs: Vec<DaemonJob>,
+}
+
+/// Result of one scheduled run, sent to the configured notification targets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobOutcome {
+    pub blueprint: String,
+    pub output: Option<String>,
+    pub resources: usize,
+    pub error: Option<String>,
+    pub duration_secs: f64,
+    pub finished_at: String,
+}
+
+/// Parse the TOML daemon config.
+pub fn parse_config(content: &str) -> Result<DaemonConfig, String> {
+    let document: DocumentMut = content
+        .parse()
+        .map_err(|e| format!("Invalid daemon config: {}", e))?;
+
+    let notify = match document.get("notify") {
+        Some(item) => {
+            let table = item
+                .as_table()
+                .ok_or_else(|| "[notify] must be a table".to_string())?;
+            NotifyConfig {
+                webhook_url: optional_str(table, "webhook_url")?,
+                slack_webhook_url: optional_str(table, "slack_webhook_url")?,
+                only_failures: match table.get("only_failures") {
+                    Some(item) => item
+                        .as_bool()
+                        .ok_or_else(|| "notify.only_failures must be a boolean".to_string())?,
+                    None => false,
+                },
+            }
+        }
+        None => NotifyConfig::default(),
+    };
+
+    let job_tables = match document.get("job") {
+        Some(item) => item
+            .as_array_of_tables()
+            .ok_or_else(|| "job must be an array of tables ([[job]])".to_string())?,
+        None => return Err("No [[job]] entries in daemon config".to_string()),
+    };
+    let mut jobs = Vec::new();
+    for (index, table) in job_tables.iter().enumerate() {
+        let blueprint = optional_str(table, "blueprint")?
+            .ok_or_else(|| format!("job #{}: blueprint is required", index + 1))?;
+        let schedule_expression = optional_str(table, "schedule")?
+            .ok_or_else(|| format!("job #{}: schedule is required", index + 1))?;
+        let schedule = CronSchedule::parse(&schedule_expression)
+            .map_err(|e| format!("job #{} ({}): {}", index + 1, blueprint, e))?;
+        jobs.push(DaemonJob {
+            blueprint,
+            schedule,
+            schedule_expression,
+            output: optional_str(table, "output")?,
+            split: match table.get("split") {
+                Some(item) => item
+                    .as_bool()
+                    .ok_or_else(|| format!("job #{}: split must be a boolean", index + 1))?,
+                None => false,
+            },
+        });
+    }
+    if jobs.is_empty() {
+        return Err("No [[job]] entries in daemon config".to_string());
+    }
+
+    let root = document.as_table();
+    Ok(DaemonConfig {
+        profile: optional_str(root, "profile")?,
+        output_dir: PathBuf::from(optional_str(root, "output_dir")?.unwrap_or(".".to_string())),
+        metrics_addr: optional_str(root, "metrics_addr")?,
+        notify,
+        jobs,
+    })
+}
+
+fn optional_str(table: &Table, key: &str) -> Result<Option<String>, String> {
+    match table.get(key).map(Item::as_str) {
+        Some(Some(value)) if !value.trim().is_empty() => Ok(Some(value.trim().to_string())),
+        Some(Some(_)) | None => Ok(None),
+        Some(None) => Err(format!("{} must be a string", key)),
+    }
+}
+
+/// Output file path of a job inside the configured output directory
+/// (a directory for `split` jobs).
+pub fn output_path(config: &DaemonConfig, job: &DaemonJob) -> PathBuf {
+    let filename = job.output.clone().unwrap_or_else(|| {
+        if job.split {
+            job.blueprint.clone()
+        } else {
+            format!("{}.md", job.blueprint)
+        }
+    });
+    config.output_dir.join(filename)
+}
+
+/// Load the config and regenerate blueprints on their schedules until the process is stopped.
+pub fn run(config_path: &Path) -> Result<(), String> {
+    let content = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+    let config = parse_config(&content)?;
+    if let Some(ref profile) = config.profile {
+        crate::aws_cli::set_aws_profile(profile);
+    }
+    crate::aws_cli::set_max_concurrent_requests(
+        crate::settings::load_settings().concurrency_limit(),
+    );
+    std::fs::create_dir_all(&config.output_dir)
+        .map_err(|e| format!("Failed to create {}: {}", config.output_dir.display(), e))?;
+    let notifier = Notifier::new(&config.notify)?;
+    let metrics = Arc::new(DaemonMetrics::new(
+        config.jobs.iter().map(|job| job.blueprint.as_str()),
+    ));
+    if let Some(ref addr) = config.metrics_addr {
+        metrics::serve(addr, Arc::clone(&metrics))?;
+        println!("Serving Prometheus metrics on http://{}/metrics", addr);
+    }
+
+    let started = now();
+    let mut next_runs: Vec<Option<NaiveDateTime>> = config
+        .jobs
+        .iter()
+        .map(|job| job.schedule.next_after(started))
+        .collect();
+    println!(
+        "emd daemon started with {} job(s), writing to {}",
+        config.jobs.len(),
+        config.output_dir.display()
+    );
+    for (job, next) in config.jobs.iter().zip(&next_runs) {
+        println!(
+            "  {} [{}] next run: {}",
+            job.blueprint,
+            job.schedule_expression,
+            next.map_or_else(|| "never".to_string(), |time| time.to_string())
+        );
+    }
+    tracing::info!(jobs = config.jobs.len(), "Daemon started");
+
+    loop {
+        let Some(due) = next_runs.iter().flatten().min().copied() else {
+            return Err("No job has an upcoming run".to_string());
+        };
+        wait_until(due);
+
+        for (job, next) in config.jobs.iter().zip(next_runs.iter_mut()) {
+            if *next != Some(due) {
+                continue;
+            }
+            let outcome = run_job(&config, job);
+            match outcome.error {
+                Some(ref error) => eprintln!(
+                    "[{}] {} failed: {}",
+                    outcome.finished_at, job.blueprint, error
+                ),
+                None => println!(
+                    "[{}] {} → {}",
+                    outcome.finished_at,
+                    job.blueprint,
+                    outcome.output.as_deref().unwrap_or_default()
+                ),
+            }
+            metrics.record(&outcome, chrono::Utc::now().timestamp());
+            notifier.send(&outcome);
+            *next = job.schedule.next_after(due);
+        }
+    }
+}
+
+fn now() -> NaiveDateTime {
+    chrono::Local::now().naive_local()
+}
+
+fn wait_until(target: NaiveDateTime) {
+    loop {
+        let remaining = target - now();
+        let Ok(remaining) = remaining.to_std() else {
+            return;
+        };
+        if remaining.is_zero() {
+            return;
+        }
+        std::thread::sleep(remaining.min(MAX_SLEEP));
+    }
+}
+
+// 블루프린트는 실행할 때마다 다시 읽어 TUI에서 수정한 내용을 반영
+fn run_job(config: &DaemonConfig, job: &DaemonJob) -> JobOutcome {
+    let _span = tracing::info_span!("daemon_job", blueprint = %job.blueprint).entered();
+    let started_at = Instant::now();
+    let mut outcome = JobOutcome {
+        blueprint: job.blueprint.clone(),
+        output: None,
+        resources: 0,
+        error: None,
+        duration_secs: 0.0,
+        finished_at: String::new(),
+    };
+    if let Err(error) = generate_job_output(config, job, &mut outcome) {
+        tracing::error!("Daemon job failed: {}", error);
+        outcome.error = Some(error);
+    }
+    outcome.duration_secs = started_at.elapsed().as_secs_f64();
+    outcome.finished_at = chrono::Local::now().to_rfc3339();
+    outcome
+}
+
+fn generate_job_output(
+    config: &DaemonConfig,
+    job: &DaemonJob,
+    outcome: &mut JobOutcome,
+) -> Result<(), String> {
+    let store = crate::blueprint::load_blueprints();
+    let blueprint = store
+        .blueprints
+        .iter()
+        .find(|blueprint| blueprint.name == job.blueprint)
+        .ok_or_else(|| format!("Blueprint not found: {}", job.blueprint))?;
+    outcome.resources = blueprint.resources.len();
+
+    // 자격 증명이 만료되면 모든 섹션이 조회 실패로 채워지므로 먼저 확인
+    crate::aws_cli::check_aws_login().map_err(|e| e.as_str().to_string())?;
+
+    let settings = crate::settings::load_settings();
+    crate::settings::apply_runtime_settings(&settings);
+    let language = settings.language;
+    let renderer = settings.diagram_renderer.as_ref();
+    let path = output_path(config, job);
+    let filename = path.to_string_lossy().to_string();
+    if job.split {
+        let mut files = crate::handler::generate_blueprint_files(blueprint, language);
+        crate::handler::render_file_diagrams(&filename, &mut files, renderer);
+        let paths = crate::output::save_markdown_files(&filename, &files)
+            .map_err(|e| format!("Failed to write {}: {}", filename, e))?;
+        for (path, (_, markdown)) in paths.iter().zip(&files) {
+            if let Err(e) =
+                crate::doc_index::index_document(path, markdown, settings.encrypt_at_rest)
+            {
+                tracing::warn!("Failed to index {}: {}", path, e);
+            }
+        }
+        outcome.output = Some(filename);
+        return Ok(());
+    }
+
+    let markdown = crate::handler::generate_blueprint_document(blueprint, language);
+    let (markdown, _) = crate::diagram::render_if_configured(&markdown, &filename, renderer);
+    crate::output::save_markdown(&filename, &markdown)
+        .map_err(|e| format!("Failed to write {}: {}", filename, e))?;
+    if let Err(e) = crate::doc_index::index_document(&filename, &markdown, settings.encrypt_at_rest)
+    {
+        tracing::warn!("Failed to index {}: {}", filename, e);
+    }
+    outcome.output = Some(filename);
+    Ok(())
+}
+
+/// Generic webhook body (one JSON object per run).
+pub fn webhook_payload(outcome: &JobOutcome) -> Value {
+    json!({
+        "source": "emd",
+        "blueprint": outcome.blueprint,
+        "status": if outcome.error.is_some() { "failure" } else { "success" },
+        "output": outcome.output,
+        "resources": outcome.resources,
+        "error": outcome.error,
+        "duration_seconds": outcome.duration_secs,
+        "finished_at": outcome.finished_at,
+    })
+}
+
+/// Slack incoming webhook body.
+pub fn slack_payload(outcome: &JobOutcome) -> Value {
+    let text = match outcome.error {
+        Some(ref error) => format!(
+            ":x: emd: blueprint `{}` failed: {}",
+            outcome.blueprint, error
+        ),
+        None => format!(
+            ":white_check_mark: emd: blueprint `{}` regenerated ({} resources) → `{}`",
+            outcome.blueprint,
+            outcome.resources,
+            outcome.output.as_deref().unwrap_or_default()
+        ),
+    };
+    json!({ "text": text })
+}
+
+struct Notifier {
+    http: reqwest::blocking::Client,
+    config: NotifyConfig,
+}
+
+impl Notifier {
+    fn new(config: &NotifyConfig) -> Result<Self, String> {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(NOTIFY_TIMEOUT)
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            http,
+            config: config.clone(),
+        })
+    }
+
+    // 알림 실패는 로그만 남기고 데몬은 계속 실행
+    fn send(&self, outcome: &JobOutcome) {
+        if self.config.only_failures && outcome.error.is_none() {
+            return;
+        }
+        let targets = [
+            (&self.config.webhook_url, webhook_payload(outcome)),
+            (&self.config.slack_webhook_url, slack_payload(outcome)),
+        ];
+        for (url, payload) in targets {
+            let Some(url) = url else {
+                continue;
+            };
+            let result = self
+                .http
+                .post(url)
+                .json(&payload)
+                .send()
+                .and_then(|response| response.error_for_status());
+            if let Err(e) = result {
+                tracing::warn!("Daemon notification failed: {}", e);
+                eprintln!("Notification failed: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JobOutcome, output_path, parse_config, slack_payload, webhook_payload};
+    use std::path::PathBuf;
+
+    const CONFIG: &str = r#"
+profile = "prod"
+output_dir = "docs/aws"
+metrics_addr = "127.0.0.1:9464"
+
+[notify]
+webhook_url = "https://hooks.example.com/emd"
+slack_webhook_url = "https://hooks.slack.com/services/T000/B000/XXXX"
+only_failures = true
+
+[[job]]
+blueprint = "prod-network"
+schedule = "0 6 * * 1-5"
+
+[[job]]
+blueprint = "prod-data"
+schedule = "@daily"
+output = "data.md"
+
+[[job]]
+blueprint = "prod-wiki"
+schedule = "@weekly"
+split = true
+"#;
+
+    fn outcome(error: Option<&str>) -> JobOutcome {
+        JobOutcome {
+            blueprint: "prod-network".to_string(),
+            output: error
+                .is_none()
+                .then(|| "docs/aws/prod-network.md".to_string()),
+            resources: 4,
+            error: error.map(str::to_string),
+            duration_secs: 1.25,
+            finished_at: "2026-03-02T06:00:05+09:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_config_reads_jobs_and_notify_targets() {
+        let config = parse_config(CONFIG).unwrap();
+        assert_eq!(config.profile.as_deref(), Some("prod"));
+        assert_eq!(config.output_dir, PathBuf::from("docs/aws"));
+        assert_eq!(config.metrics_addr.as_deref(), Some("127.0.0.1:9464"));
+        assert_eq!(
+            config.notify.slack_webhook_url.as_deref(),
+            Some("https://hooks.slack.com/services/T000/B000/XXXX")
+        );
+        assert!(config.notify.only_failures);
+        assert_eq!(config.jobs.len(), 3);
+        assert_eq!(config.jobs[0].schedule_expression, "0 6 * * 1-5");
+
+        assert_eq!(
+            output_path(&config, &config.jobs[0]),
+            PathBuf::from("docs/aws/prod-network.md")
+        );
+        assert_eq!(
+            output_path(&config, &config.jobs[1]),
+            PathBuf::from("docs/aws/data.md")
+        );
+        assert!(!config.jobs[1].split);
+        // 나눠 저장하는 작업은 블루프린트 이름의 디렉터리에 씀
+        assert!(config.jobs[2].split);
+        assert_eq!(
+            output_path(&config, &config.jobs[2]),
+            PathBuf::from("docs/aws/prod-wiki")
+        );
+    }
+
+    #[test]
+    fn parse_config_defaults_and_errors() {
+        let config =
+            parse_config("[[job]]\nblueprint = \"a\"\nschedule = \"*/30 * * * *\"\n").unwrap();
+        assert_eq!(config.output_dir, PathBuf::from("."));
+        assert_eq!(config.profile, None);
+        assert_eq!(config.metrics_addr, None);
+        assert_eq!(config.notify.webhook_url, None);
+        assert!(!config.notify.only_failures);
+
+        assert!(parse_config("output_dir = \"docs\"\n").is_err());
+        assert!(parse_config("[[job]]\nschedule = \"@daily\"\n").is_err());
+        assert!(
+            parse_config("[[job]]\nblueprint = \"a\"\nschedule = \"@daily\"\nsplit = \"yes\"\n")
+                .is_err()
+        );
+        let error =
+            parse_config("[[job]]\nblueprint = \"a\"\nschedule = \"0 25 * * *\"\n").unwrap_err();
+        assert!(error.starts_with("job #1 (a):"));
+        assert!(
+            parse_config("output_dir = 3\n[[job]]\nblueprint = \"a\"\nschedule = \"@daily\"\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn payloads_report_success_and_failure() {
+        let success = webhook_payload(&outcome(None));
+        assert_eq!(success["status"], "success");
+        assert_eq!(success["output"], "docs/aws/prod-network.md");
+        assert_eq!(success["resources"], 4);
+        assert_eq!(success["duration_seconds"], 1.25);
+        assert!(success["error"].is_null());
+
+        let failure = webhook_payload(&outcome(Some("Blueprint not found: prod-network")));
+        assert_eq!(failure["status"], "failure");
+        assert!(failure["output"].is_null());
+
+        assert_eq!(
+            slack_payload(&outcome(None))["text"],
+            ":white_check_mark: emd: blueprint `prod-network` regenerated (4 resources) → `docs/aws/prod-network.md`"
+        );
+        assert_eq!(
+            slack_payload(&outcome(Some("ExpiredToken")))["text"],
+            ":x: emd: blueprint `prod-network` failed: ExpiredToken"
+        );
+    }
+}
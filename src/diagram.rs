@@ -0,0 +1,224 @@
+use crate::settings::DiagramRenderer;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const MERMAID_FENCE: &str = "```mermaid";
+const FENCE: &str = "```";
+
+/// Render every Mermaid block of `markdown` (to be saved at `path`) to an image file next to
+/// it with the configured command, and reference the image in place of the block.
+/// The Mermaid source is kept in a collapsed `<details>` below the image.
+pub fn render_diagrams(
+    markdown: &str,
+    path: &str,
+    renderer: &DiagramRenderer,
+) -> Result<String, String> {
+    let document = Path::new(path);
+    let dir = document.parent().unwrap_or_else(|| Path::new(""));
+    let stem = document
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "diagram".to_string());
+    let extension = renderer.format.extension();
+
+    replace_mermaid_blocks(markdown, |index, source| {
+        let image = format!("{}-diagram-{}.{}", stem, index, extension);
+        // 분할 저장처럼 문서 디렉터리가 아직 없을 수 있음
+        if !dir.as_os_str().is_empty() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        run_renderer(renderer, source, &dir.join(&image))?;
+        Ok(image)
+    })
+}
+
+/// `render_diagrams` when a renderer is configured; on failure the warning is logged
+/// and returned with the original markdown so saving still succeeds.
+pub fn render_if_configured(
+    markdown: &str,
+    path: &str,
+    renderer: Option<&DiagramRenderer>,
+) -> (String, Option<String>) {
+    let Some(renderer) = renderer else {
+        return (markdown.to_string(), None);
+    };
+    match render_diagrams(markdown, path, renderer) {
+        Ok(rendered) => (rendered, None),
+        Err(error) => {
+            tracing::warn!(error = %error, path, "Failed to render diagrams");
+            (markdown.to_string(), Some(error))
+        }
+    }
+}
+
+// ```mermaid 블록마다 render(1부터 시작하는 번호, 소스)가 돌려준 이미지 파일로 교체
+fn replace_mermaid_blocks(
+    markdown: &str,
+    mut render: impl FnMut(usize, &str) -> Result<String, String>,
+) -> Result<String, String> {
+    let mut output = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    let mut index = 0;
+
+    while let Some(start) = find_fence_line(rest, MERMAID_FENCE) {
+        let body_start = line_end(rest, start);
+        // 닫는 펜스가 없으면 그대로 둠
+        let Some(close) = find_fence_line(&rest[body_start..], FENCE) else {
+            break;
+        };
+        let close = body_start + close;
+        let block_end = line_end(rest, close);
+
+        index += 1;
+        let image = render(index, &rest[body_start..close])?;
+        output.push_str(&rest[..start]);
+        output.push_str(&format!(
+            "![diagram {}]({})\n\n<details><summary>Mermaid</summary>\n\n{}\n</details>\n",
+            index,
+            image,
+            rest[start..block_end].trim_end()
+        ));
+        rest = &rest[block_end..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+// 줄 전체가 fence인 첫 줄의 시작 위치
+fn find_fence_line(text: &str, fence: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim() == fence {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+// start가 속한 줄의 다음 줄 시작 위치 (마지막 줄이면 문자열 끝)
+fn line_end(text: &str, start: usize) -> usize {
+    text[start..]
+        .find('\n')
+        .map(|pos| start + pos + 1)
+        .unwrap_or(text.len())
+}
+
+// 소스를 임시 .mmd 파일에 쓰고 {input}/{output}을 채운 명령 실행 (셸을 거치지 않음)
+fn run_renderer(renderer: &DiagramRenderer, source: &str, image: &Path) -> Result<(), String> {
+    let input = std::env::temp_dir().join(format!(
+        "emd-diagram-{}-{}.mmd",
+        std::process::id(),
+        image
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default()
+    ));
+    fs::write(&input, source).map_err(|e| format!("Failed to write {}: {}", input.display(), e))?;
+
+    let input_arg = input.to_string_lossy().to_string();
+    let output_arg = image.to_string_lossy().to_string();
+    let mut args = renderer.command.split_whitespace().map(|arg| {
+        arg.replace("{input}", &input_arg)
+            .replace("{output}", &output_arg)
+    });
+    let result = match args.next() {
+        Some(program) => Command::new(&program)
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", program, e)),
+        None => Err("diagram_renderer.command is empty".to_string()),
+    };
+    let _ = fs::remove_file(&input);
+
+    let output = result?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} ({})",
+            String::from_utf8_lossy(&output.stderr).trim(),
+            output.status
+        ));
+    }
+    if !image.exists() {
+        return Err(format!("{} was not created", image.display()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_diagrams, render_if_configured, replace_mermaid_blocks};
+    use crate::settings::{DiagramFormat, DiagramRenderer};
+    use std::fs;
+
+    const MARKDOWN: &str =
+        "## Network\n\n### Diagram\n```mermaid\ngraph TB\n    A --> B\n```\n\n### Subnets\n| a |\n";
+
+    #[test]
+    fn replace_mermaid_blocks_links_images_and_keeps_source() {
+        let mut sources = Vec::new();
+        let rendered = replace_mermaid_blocks(MARKDOWN, |index, source| {
+            sources.push(source.to_string());
+            Ok(format!("network-diagram-{}.svg", index))
+        })
+        .expect("replace blocks");
+
+        assert_eq!(sources, vec!["graph TB\n    A --> B\n".to_string()]);
+        assert!(
+            rendered
+                .starts_with("## Network\n\n### Diagram\n![diagram 1](network-diagram-1.svg)\n")
+        );
+        assert!(rendered.contains(
+            "<details><summary>Mermaid</summary>\n\n```mermaid\ngraph TB\n    A --> B\n```\n</details>\n\n### Subnets\n| a |\n"
+        ));
+
+        // 다이어그램이 없거나 닫히지 않은 블록은 그대로 둠
+        let plain = "## EC2\n```json\n{}\n```\n";
+        assert_eq!(
+            replace_mermaid_blocks(plain, |_, _| Err("unused".to_string())).expect("plain"),
+            plain
+        );
+        let open = "```mermaid\ngraph TB\n";
+        assert_eq!(
+            replace_mermaid_blocks(open, |_, _| Err("unused".to_string())).expect("open"),
+            open
+        );
+    }
+
+    #[test]
+    fn render_diagrams_runs_command_and_falls_back_on_failure() {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("emd-diagram-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("create dir");
+        let path = dir.join("network.md").to_string_lossy().to_string();
+
+        // 입력 파일을 그대로 복사하는 명령으로 렌더러를 흉내냄
+        let renderer = DiagramRenderer {
+            command: "cp {input} {output}".to_string(),
+            format: DiagramFormat::Png,
+        };
+        let rendered = render_diagrams(MARKDOWN, &path, &renderer).expect("render");
+        assert!(rendered.contains("![diagram 1](network-diagram-1.png)"));
+        assert_eq!(
+            fs::read_to_string(dir.join("network-diagram-1.png")).expect("image"),
+            "graph TB\n    A --> B\n"
+        );
+
+        let broken = DiagramRenderer {
+            command: "emd-missing-renderer {input} {output}".to_string(),
+            format: DiagramFormat::Svg,
+        };
+        let (markdown, warning) = render_if_configured(MARKDOWN, &path, Some(&broken));
+        assert_eq!(markdown, MARKDOWN);
+        assert!(warning.is_some());
+        assert_eq!(
+            render_if_configured(MARKDOWN, &path, None),
+            (MARKDOWN.to_string(), None)
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
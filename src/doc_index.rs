@@ -0,0 +1,353 @@
+use crate::crypto::{self, KeySource};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// 저장한 마크다운 문서의 역색인 (~/.emd/search_index.json, encrypt_at_rest면 blueprints.json처럼 암호화)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DocIndex {
+    // 문서 경로 -> 색인 시각 (RFC 3339)
+    #[serde(default)]
+    documents: BTreeMap<String, String>,
+    // 토큰 -> 해당 토큰이 등장하는 문서 경로
+    #[serde(default)]
+    terms: BTreeMap<String, BTreeSet<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub path: String,
+    pub indexed_at: String,
+    // 검색어가 처음 등장하는 줄 (파일을 읽지 못하면 빈 문자열)
+    pub snippet: String,
+}
+
+const SNIPPET_MAX_CHARS: usize = 120;
+
+/// Split markdown into lowercase tokens, keeping `.`, `/`, `:`, `-` and `_`
+/// inside tokens so CIDRs, resource IDs and hostnames stay whole.
+pub fn tokenize(content: &str) -> BTreeSet<String> {
+    content
+        .split(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '/' | ':' | '-' | '_')))
+        .map(|token| token.trim_matches(|c: char| matches!(c, '.' | ':' | '-' | '/')))
+        .filter(|token| token.chars().count() > 1)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+impl DocIndex {
+    pub fn add_document(&mut self, path: &str, content: &str, indexed_at: &str) {
+        self.remove_document(path);
+        for token in tokenize(content) {
+            self.terms
+                .entry(token)
+                .or_default()
+                .insert(path.to_string());
+        }
+        self.documents
+            .insert(path.to_string(), indexed_at.to_string());
+    }
+
+    pub fn remove_document(&mut self, path: &str) {
+        if self.documents.remove(path).is_none() {
+            return;
+        }
+        self.terms.retain(|_, paths| {
+            paths.remove(path);
+            !paths.is_empty()
+        });
+    }
+
+    /// Paths of documents containing every query term (substring match on
+    /// tokens, so `10.0.1` finds `10.0.1.0/24`), newest first.
+    pub fn search(&self, query: &str) -> Vec<(String, String)> {
+        let query_terms = tokenize(query);
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matched: Option<BTreeSet<&String>> = None;
+        for term in &query_terms {
+            let paths: BTreeSet<&String> = self
+                .terms
+                .iter()
+                .filter(|(token, _)| token.contains(term.as_str()))
+                .flat_map(|(_, paths)| paths.iter())
+                .collect();
+            matched = Some(match matched {
+                Some(previous) => previous.intersection(&paths).copied().collect(),
+                None => paths,
+            });
+        }
+
+        let mut hits: Vec<(String, String)> = matched
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|path| {
+                self.documents
+                    .get(path)
+                    .map(|indexed_at| (path.clone(), indexed_at.clone()))
+            })
+            .collect();
+        hits.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        hits
+    }
+}
+
+fn get_index_path() -> Option<PathBuf> {
+    let home = dirs::home_dir()?;
+    let emd_dir = home.join(".emd");
+
+    if !emd_dir.exists() {
+        fs::create_dir_all(&emd_dir).ok()?;
+    }
+
+    Some(emd_dir.join("search_index.json"))
+}
+
+fn load_index_from(path: &Path, key: &KeySource) -> Result<DocIndex, String> {
+    let Ok(bytes) = fs::read(path) else {
+        return Ok(DocIndex::default());
+    };
+    match crypto::decode_text(&bytes, key) {
+        Ok(content) => Ok(serde_json::from_str(&content).unwrap_or_default()),
+        // 키를 못 읽어 복호화에 실패하면 빈 색인으로 덮어쓰지 않도록 오류로 반환
+        Err(e) if crypto::is_encrypted(&bytes) => Err(e),
+        Err(_) => Ok(DocIndex::default()),
+    }
+}
+
+fn save_index_to(
+    path: &Path,
+    index: &DocIndex,
+    encrypt: bool,
+    key: &KeySource,
+) -> std::io::Result<()> {
+    let content = serde_json::to_string(index)?;
+    let bytes = crypto::encode_text(content, encrypt, key)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    fs::write(path, bytes)
+}
+
+fn index_path() -> std::io::Result<PathBuf> {
+    get_index_path().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "Home directory not found")
+    })
+}
+
+fn load_index(path: &Path) -> std::io::Result<DocIndex> {
+    load_index_from(path, &crypto::data_key)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+// 검색어가 처음 등장하는 줄을 잘라서 반환
+fn snippet_for(content: &str, query: &str) -> String {
+    let terms = tokenize(query);
+    content
+        .lines()
+        .find(|line| {
+            let line = line.to_lowercase();
+            terms.iter().any(|term| line.contains(term.as_str()))
+        })
+        .map(|line| {
+            let line = line.trim();
+            if line.chars().count() > SNIPPET_MAX_CHARS {
+                let cut: String = line.chars().take(SNIPPET_MAX_CHARS).collect();
+                format!("{}…", cut)
+            } else {
+                line.to_string()
+            }
+        })
+        .unwrap_or_default()
+}
+
+/// Add a saved markdown file to the on-disk search index, encrypting the index when `encrypt` is set.
+pub fn index_document(filename: &str, content: &str, encrypt: bool) -> std::io::Result<()> {
+    let index_path = index_path()?;
+    // 실행 위치와 무관하게 찾을 수 있도록 절대 경로로 저장
+    let path = fs::canonicalize(filename)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| filename.to_string());
+
+    let mut index = load_index(&index_path)?;
+    index.add_document(&path, content, &chrono::Utc::now().to_rfc3339());
+    save_index_to(&index_path, &index, encrypt, &crypto::data_key)
+}
+
+/// Re-save the index so it matches `encrypt` (after `encrypt_at_rest` is toggled).
+pub fn resave_index(encrypt: bool) -> std::io::Result<()> {
+    let index_path = index_path()?;
+    if !index_path.exists() {
+        return Ok(());
+    }
+    let index = load_index(&index_path)?;
+    save_index_to(&index_path, &index, encrypt, &crypto::data_key)
+}
+
+/// Search the index and read a matching line from each document.
+/// Documents that no longer exist on disk are dropped from the index.
+pub fn search_documents(query: &str, encrypt: bool) -> Vec<SearchHit> {
+    let Some(index_path) = get_index_path() else {
+        return Vec::new();
+    };
+    let mut index = match load_index(&index_path) {
+        Ok(index) => index,
+        Err(error) => {
+            tracing::warn!(error = %error, "Failed to read search index");
+            return Vec::new();
+        }
+    };
+
+    let mut hits = Vec::new();
+    let mut stale = Vec::new();
+    for (path, indexed_at) in index.search(query) {
+        match fs::read_to_string(&path) {
+            Ok(content) => hits.push(SearchHit {
+                snippet: snippet_for(&content, query),
+                path,
+                indexed_at,
+            }),
+            Err(_) => stale.push(path),
+        }
+    }
+
+    if !stale.is_empty() {
+        for path in &stale {
+            index.remove_document(path);
+        }
+        if let Err(error) = save_index_to(&index_path, &index, encrypt, &crypto::data_key) {
+            tracing::warn!(error = %error, "Failed to prune search index");
+        }
+    }
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocIndex, load_index_from, save_index_to, snippet_for, tokenize};
+    use crate::crypto::{DataKey, is_encrypted};
+    use std::fs;
+    use std::path::PathBuf;
+
+    const NETWORK_DOC: &str = "## Network (vpc-main)\n\
+        | CIDR | 10.0.0.0/16 |\n\
+        | Subnet | `subnet-0a1b` 10.0.1.0/24 |\n";
+    const LB_DOC: &str = "## Load Balancer (alb-main)\n\
+        | DNS Name | alb-main-123.ap-northeast-2.elb.amazonaws.com |\n\
+        | Security Groups | sg-0123abcd |\n";
+
+    fn sample_index() -> DocIndex {
+        let mut index = DocIndex::default();
+        index.add_document("/docs/network.md", NETWORK_DOC, "2026-01-01T00:00:00Z");
+        index.add_document("/docs/lb.md", LB_DOC, "2026-01-02T00:00:00Z");
+        index
+    }
+
+    #[test]
+    fn tokenize_keeps_cidrs_ids_and_hostnames_whole() {
+        let tokens = tokenize("| 10.0.1.0/24 | `sg-0123abcd` (db.example.com:5432). |");
+        assert!(tokens.contains("10.0.1.0/24"));
+        assert!(tokens.contains("sg-0123abcd"));
+        assert!(tokens.contains("db.example.com:5432"));
+    }
+
+    #[test]
+    fn search_matches_token_substrings_and_requires_all_terms() {
+        let index = sample_index();
+
+        let hits = index.search("10.0.1");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, "/docs/network.md");
+
+        assert_eq!(index.search("SG-0123ABCD")[0].0, "/docs/lb.md");
+        assert_eq!(index.search("elb.amazonaws.com")[0].0, "/docs/lb.md");
+        assert!(index.search("sg-0123abcd 10.0.0.0/16").is_empty());
+
+        // 최근에 색인한 문서가 먼저
+        let both = index.search("main");
+        assert_eq!(both[0].0, "/docs/lb.md");
+        assert_eq!(both[1].0, "/docs/network.md");
+    }
+
+    #[test]
+    fn add_document_replaces_previous_tokens_for_same_path() {
+        let mut index = sample_index();
+        index.add_document(
+            "/docs/lb.md",
+            "## Load Balancer (nlb-edge)\n",
+            "2026-01-03T00:00:00Z",
+        );
+
+        assert_eq!(index.documents.len(), 2);
+        assert!(index.search("sg-0123abcd").is_empty());
+        assert_eq!(index.search("nlb-edge")[0].0, "/docs/lb.md");
+
+        index.remove_document("/docs/lb.md");
+        assert_eq!(index.documents.len(), 1);
+        assert!(index.search("nlb-edge").is_empty());
+    }
+
+    fn no_key() -> Result<DataKey, String> {
+        Err("no data key".to_string())
+    }
+
+    fn test_key() -> Result<DataKey, String> {
+        Ok([9u8; 32])
+    }
+
+    fn temp_index_path(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        std::env::temp_dir().join(format!(
+            "emd-search-index-{}-{}-{}.json",
+            label,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    #[test]
+    fn index_round_trips_through_json_file() {
+        let path = temp_index_path("plain");
+
+        save_index_to(&path, &sample_index(), false, &no_key).expect("save index");
+        let loaded = load_index_from(&path, &no_key).expect("load index");
+        assert_eq!(loaded.documents.len(), 2);
+        assert_eq!(loaded.search("subnet-0a1b")[0].0, "/docs/network.md");
+
+        let _ = fs::remove_file(&path);
+        let missing = load_index_from(&path, &no_key).expect("missing index");
+        assert_eq!(missing.documents.len(), 0);
+    }
+
+    #[test]
+    fn encrypted_index_is_not_plaintext_on_disk() {
+        let path = temp_index_path("encrypted");
+
+        save_index_to(&path, &sample_index(), true, &test_key).expect("save index");
+        let raw = fs::read(&path).expect("read raw");
+        assert!(is_encrypted(&raw));
+        let text = String::from_utf8_lossy(&raw);
+        assert!(!text.contains("subnet-0a1b"));
+        assert!(!text.contains("/docs/network.md"));
+
+        let loaded = load_index_from(&path, &test_key).expect("load index");
+        assert_eq!(loaded.search("subnet-0a1b")[0].0, "/docs/network.md");
+        // 키를 읽지 못하면 빈 색인으로 덮어쓰지 않도록 오류
+        assert!(load_index_from(&path, &no_key).is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn snippet_returns_first_matching_line() {
+        assert_eq!(
+            snippet_for(NETWORK_DOC, "10.0.1.0"),
+            "| Subnet | `subnet-0a1b` 10.0.1.0/24 |"
+        );
+        assert_eq!(snippet_for(NETWORK_DOC, "missing"), "");
+    }
+}
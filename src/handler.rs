@@ -1,14 +1,29 @@
-use crate::app::{App, LoadingTask, REGIONS, SERVICE_KEYS, Screen};
-use crate::aws_cli::NetworkDetail;
-use crate::blueprint::{BlueprintResource, ResourceType};
+use crate::age;
+use crate::app::{App, BlueprintImportKind, LoadingTask, NameTagEdit, Region, Screen};
+use crate::aws_cli::audit::CallFailure;
+use crate::aws_cli::{Arn, NetworkDetail, is_terminated_state};
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceStatus, ResourceType};
+use crate::compare::Comparison;
+use crate::i18n::{I18n, Language};
+use crate::key_macro::{self, PendingMacro};
+use crate::promotion::PromotionChecklist;
+use crate::query_playground::QUERY_OPERATIONS;
+use crate::stats::{self, ResourceFacts};
+use crate::tutorial;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
-type VpcInfoTuple = (String, String, String, Vec<(String, String)>);
+type VpcInfoTuple = (String, String, String, Vec<(String, String)>, Vec<String>);
+// 서브넷 목록과 서브넷별 IPv6 CIDR/남은 IP 수
+type SubnetListTuple = (
+    Vec<crate::aws_cli::AwsResource>,
+    Vec<crate::aws_cli::SubnetAddresses>,
+);
 
 #[cfg(not(test))]
 mod aws_adapter {
-    use super::VpcInfoTuple;
+    use super::{SubnetListTuple, VpcInfoTuple};
     use crate::aws_cli;
+    use crate::i18n::Language;
 
     pub fn set_region(region: &str) {
         aws_cli::set_region(region);
@@ -22,6 +37,34 @@ mod aws_adapter {
         aws_cli::get_instance_detail(id)
     }
 
+    pub fn refresh_instance_detail(id: &str) -> Option<aws_cli::Ec2Detail> {
+        aws_cli::refresh_instance_detail(id)
+    }
+
+    pub fn prefetch_instances(ids: &[String]) {
+        aws_cli::prefetch_instances(ids);
+    }
+
+    pub fn clear_prefetched_instances() {
+        aws_cli::clear_prefetched_instances();
+    }
+
+    pub fn clear_iam_role_cache() {
+        aws_cli::clear_iam_role_cache();
+    }
+
+    pub fn reset_call_failures() {
+        aws_cli::audit::reset_call_failures();
+    }
+
+    pub fn call_failure() -> aws_cli::audit::CallFailure {
+        aws_cli::audit::call_failure()
+    }
+
+    pub fn max_concurrent_requests() -> usize {
+        aws_cli::max_concurrent_requests()
+    }
+
     pub fn list_vpcs() -> Vec<aws_cli::AwsResource> {
         aws_cli::list_vpcs()
     }
@@ -34,7 +77,7 @@ mod aws_adapter {
         aws_cli::get_vpc_info(vpc_id)
     }
 
-    pub fn list_subnets(vpc_id: &str) -> Vec<aws_cli::AwsResource> {
+    pub fn list_subnets(vpc_id: &str) -> SubnetListTuple {
         aws_cli::list_subnets(vpc_id)
     }
 
@@ -54,6 +97,18 @@ mod aws_adapter {
         aws_cli::list_eips()
     }
 
+    pub fn list_vpc_peerings(vpc_id: &str) -> Vec<aws_cli::VpcPeeringDetail> {
+        aws_cli::list_vpc_peerings(vpc_id)
+    }
+
+    pub fn list_vpc_endpoints_in(vpc_id: &str) -> Vec<aws_cli::VpcEndpointDetail> {
+        aws_cli::list_vpc_endpoints_in(vpc_id)
+    }
+
+    pub fn list_network_acls(vpc_id: &str) -> Vec<aws_cli::NetworkAclDetail> {
+        aws_cli::list_network_acls(vpc_id)
+    }
+
     pub fn get_vpc_dns_support(vpc_id: &str) -> bool {
         aws_cli::get_vpc_dns_support(vpc_id)
     }
@@ -93,12 +148,400 @@ mod aws_adapter {
     pub fn get_asg_detail(name: &str) -> Option<aws_cli::AsgDetail> {
         aws_cli::asg::get_asg_detail(name)
     }
+
+    pub fn list_rds_databases() -> Vec<aws_cli::AwsResource> {
+        aws_cli::rds::list_rds_resources()
+    }
+
+    pub fn get_rds_detail(identifier: &str) -> Option<aws_cli::RdsDetail> {
+        aws_cli::rds::get_rds_detail(identifier)
+    }
+
+    pub fn list_lambda_functions() -> Vec<aws_cli::AwsResource> {
+        aws_cli::lambda::list_lambda_functions()
+    }
+
+    pub fn get_lambda_detail(name: &str) -> Option<aws_cli::LambdaDetail> {
+        aws_cli::lambda::get_lambda_detail(name)
+    }
+
+    pub fn list_dynamodb_tables() -> Vec<aws_cli::AwsResource> {
+        aws_cli::dynamodb::list_dynamodb_tables()
+    }
+
+    pub fn get_dynamodb_detail(name: &str) -> Option<aws_cli::DynamoDbDetail> {
+        aws_cli::dynamodb::get_dynamodb_detail(name)
+    }
+
+    pub fn list_cloudfront_distributions() -> Vec<aws_cli::AwsResource> {
+        aws_cli::cloudfront::list_cloudfront_distributions()
+    }
+
+    pub fn get_cloudfront_detail(id: &str) -> Option<aws_cli::CloudFrontDetail> {
+        aws_cli::cloudfront::get_cloudfront_detail(id)
+    }
+
+    pub fn list_route53_zones() -> Vec<aws_cli::AwsResource> {
+        aws_cli::route53::list_route53_zones()
+    }
+
+    pub fn get_route53_detail(id: &str) -> Option<aws_cli::Route53Detail> {
+        aws_cli::route53::get_route53_detail(id)
+    }
+
+    pub fn list_eks_clusters() -> Vec<aws_cli::AwsResource> {
+        aws_cli::eks::list_eks_clusters()
+    }
+
+    pub fn get_eks_detail(name: &str) -> Option<aws_cli::EksDetail> {
+        aws_cli::eks::get_eks_detail(name)
+    }
+
+    pub fn list_ecs_clusters() -> Vec<aws_cli::AwsResource> {
+        aws_cli::ecs::list_ecs_clusters()
+    }
+
+    pub fn get_ecs_detail(name: &str) -> Option<aws_cli::EcsDetail> {
+        aws_cli::ecs::get_ecs_detail(name)
+    }
+
+    pub fn list_sqs_queues() -> Vec<aws_cli::AwsResource> {
+        aws_cli::sqs::list_sqs_queues()
+    }
+
+    pub fn get_sqs_detail(name: &str) -> Option<aws_cli::SqsDetail> {
+        aws_cli::sqs::get_sqs_detail(name)
+    }
+
+    pub fn list_sns_topics() -> Vec<aws_cli::AwsResource> {
+        aws_cli::sns::list_sns_topics()
+    }
+
+    pub fn get_sns_detail(arn: &str) -> Option<aws_cli::SnsDetail> {
+        aws_cli::sns::get_sns_detail(arn)
+    }
+
+    pub fn list_elasticache_clusters() -> Vec<aws_cli::AwsResource> {
+        aws_cli::elasticache::list_elasticache_clusters()
+    }
+
+    pub fn get_elasticache_detail(id: &str) -> Option<aws_cli::ElastiCacheDetail> {
+        aws_cli::elasticache::get_elasticache_detail(id)
+    }
+
+    pub fn list_cloudwatch_alarms() -> Vec<aws_cli::AwsResource> {
+        aws_cli::cloudwatch::list_cloudwatch_alarms()
+    }
+
+    pub fn get_cloudwatch_alarm_detail(name: &str) -> Option<aws_cli::CloudWatchAlarmDetail> {
+        aws_cli::cloudwatch::get_cloudwatch_alarm_detail(name)
+    }
+
+    pub fn list_cloudtrail_trails() -> Vec<aws_cli::AwsResource> {
+        aws_cli::cloudtrail::list_cloudtrail_trails()
+    }
+
+    pub fn get_cloudtrail_detail(arn: &str) -> Option<aws_cli::CloudTrailDetail> {
+        aws_cli::cloudtrail::get_cloudtrail_detail(arn)
+    }
+
+    pub fn get_last_modified(resource_id: &str, global: bool) -> Option<aws_cli::LastModified> {
+        aws_cli::cloudtrail::get_last_modified(resource_id, global)
+    }
+
+    pub fn stamp_last_modified_enabled() -> bool {
+        aws_cli::stamp_last_modified_enabled()
+    }
+
+    pub fn last_modified_markdown(
+        last_modified: Option<&aws_cli::LastModified>,
+        lang: Language,
+    ) -> String {
+        aws_cli::last_modified_markdown(last_modified, lang)
+    }
+
+    pub fn list_kms_keys() -> Vec<aws_cli::AwsResource> {
+        aws_cli::kms::list_kms_keys()
+    }
+
+    pub fn get_kms_key_detail(key_id: &str) -> Option<aws_cli::KmsKeyDetail> {
+        aws_cli::kms::get_kms_key_detail(key_id)
+    }
+
+    pub fn list_secrets() -> Vec<aws_cli::AwsResource> {
+        aws_cli::secretsmanager::list_secrets()
+    }
+
+    pub fn get_secret_detail(arn: &str) -> Option<aws_cli::SecretDetail> {
+        aws_cli::secretsmanager::get_secret_detail(arn)
+    }
+
+    pub fn list_parameter_paths() -> Vec<aws_cli::AwsResource> {
+        aws_cli::ssm::list_parameter_paths()
+    }
+
+    pub fn get_parameter_path_detail(path: &str) -> Option<aws_cli::SsmParameterPathDetail> {
+        aws_cli::ssm::get_parameter_path_detail(path)
+    }
+
+    pub fn list_file_systems() -> Vec<aws_cli::AwsResource> {
+        aws_cli::efs::list_file_systems()
+    }
+
+    pub fn get_efs_detail(file_system_id: &str) -> Option<aws_cli::EfsDetail> {
+        aws_cli::efs::get_efs_detail(file_system_id)
+    }
+
+    pub fn list_apis() -> Vec<aws_cli::AwsResource> {
+        aws_cli::apigateway::list_apis()
+    }
+
+    pub fn get_api_gateway_detail(api_id: &str) -> Option<aws_cli::ApiGatewayDetail> {
+        aws_cli::apigateway::get_api_gateway_detail(api_id)
+    }
+
+    pub fn list_beanstalk_environments() -> Vec<aws_cli::AwsResource> {
+        aws_cli::elasticbeanstalk::list_beanstalk_environments()
+    }
+
+    pub fn get_elastic_beanstalk_detail(
+        environment_id: &str,
+    ) -> Option<aws_cli::ElasticBeanstalkDetail> {
+        aws_cli::elasticbeanstalk::get_elastic_beanstalk_detail(environment_id)
+    }
+
+    pub fn list_state_machines() -> Vec<aws_cli::AwsResource> {
+        aws_cli::stepfunctions::list_state_machines()
+    }
+
+    pub fn get_state_machine_detail(
+        state_machine_arn: &str,
+    ) -> Option<aws_cli::StateMachineDetail> {
+        aws_cli::stepfunctions::get_state_machine_detail(state_machine_arn)
+    }
+
+    pub fn list_kinesis_streams() -> Vec<aws_cli::AwsResource> {
+        aws_cli::kinesis::list_kinesis_streams()
+    }
+
+    pub fn get_kinesis_stream_detail(stream_name: &str) -> Option<aws_cli::KinesisStreamDetail> {
+        aws_cli::kinesis::get_kinesis_stream_detail(stream_name)
+    }
+
+    pub fn list_msk_clusters() -> Vec<aws_cli::AwsResource> {
+        aws_cli::msk::list_msk_clusters()
+    }
+
+    pub fn get_msk_cluster_detail(cluster_arn: &str) -> Option<aws_cli::MskClusterDetail> {
+        aws_cli::msk::get_msk_cluster_detail(cluster_arn)
+    }
+
+    pub fn list_opensearch_domains() -> Vec<aws_cli::AwsResource> {
+        aws_cli::opensearch::list_opensearch_domains()
+    }
+
+    pub fn get_opensearch_domain_detail(
+        domain_name: &str,
+    ) -> Option<aws_cli::OpenSearchDomainDetail> {
+        aws_cli::opensearch::get_opensearch_domain_detail(domain_name)
+    }
+
+    pub fn list_redshift_clusters() -> Vec<aws_cli::AwsResource> {
+        aws_cli::redshift::list_redshift_clusters()
+    }
+
+    pub fn get_redshift_cluster_detail(
+        cluster_identifier: &str,
+    ) -> Option<aws_cli::RedshiftClusterDetail> {
+        aws_cli::redshift::get_redshift_cluster_detail(cluster_identifier)
+    }
+
+    pub fn list_transit_gateways() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_transit_gateways()
+    }
+
+    pub fn get_transit_gateway_detail(tgw_id: &str) -> Option<aws_cli::TransitGatewayDetail> {
+        aws_cli::get_transit_gateway_detail(tgw_id)
+    }
+
+    pub fn list_vpc_peering_connections() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_vpc_peering_connections()
+    }
+
+    pub fn get_vpc_peering_detail(peering_id: &str) -> Option<aws_cli::VpcPeeringDetail> {
+        aws_cli::get_vpc_peering_detail(peering_id)
+    }
+
+    pub fn list_vpn_resources() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_vpn_resources()
+    }
+
+    pub fn get_vpn_detail(vpn_id: &str) -> Option<aws_cli::VpnDetail> {
+        aws_cli::get_vpn_detail(vpn_id)
+    }
+
+    pub fn list_direct_connect_resources() -> Vec<aws_cli::AwsResource> {
+        aws_cli::directconnect::list_direct_connect_resources()
+    }
+
+    pub fn get_direct_connect_detail(resource_id: &str) -> Option<aws_cli::DirectConnectDetail> {
+        aws_cli::directconnect::get_direct_connect_detail(resource_id)
+    }
+
+    pub fn list_waf_web_acls() -> Vec<aws_cli::AwsResource> {
+        aws_cli::waf::list_waf_web_acls()
+    }
+
+    pub fn get_waf_web_acl_detail(web_acl_arn: &str) -> Option<aws_cli::WafWebAclDetail> {
+        aws_cli::waf::get_waf_web_acl_detail(web_acl_arn)
+    }
+
+    pub fn list_acm_certificates() -> Vec<aws_cli::AwsResource> {
+        aws_cli::acm::list_acm_certificates()
+    }
+
+    pub fn get_acm_certificate_detail(
+        certificate_arn: &str,
+    ) -> Option<aws_cli::AcmCertificateDetail> {
+        aws_cli::acm::get_acm_certificate_detail(certificate_arn)
+    }
+
+    pub fn list_cloudformation_stacks() -> Vec<aws_cli::AwsResource> {
+        aws_cli::cloudformation::list_cloudformation_stacks()
+    }
+
+    pub fn get_cloudformation_stack_detail(
+        stack_name: &str,
+    ) -> Option<aws_cli::CloudFormationStackDetail> {
+        aws_cli::cloudformation::get_cloudformation_stack_detail(stack_name)
+    }
+
+    pub fn list_iam_entities() -> Vec<aws_cli::AwsResource> {
+        aws_cli::iam::list_iam_entities()
+    }
+
+    pub fn get_iam_entity_detail(arn: &str) -> Option<aws_cli::IamEntityDetail> {
+        aws_cli::iam::get_iam_entity_detail(arn)
+    }
+
+    pub fn list_vpc_endpoints() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_vpc_endpoints()
+    }
+
+    pub fn get_vpc_endpoint_detail(endpoint_id: &str) -> Option<aws_cli::VpcEndpointDetail> {
+        aws_cli::get_vpc_endpoint_detail(endpoint_id)
+    }
+
+    pub fn list_network_interfaces() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_network_interfaces()
+    }
+
+    pub fn get_network_interface_detail(
+        interface_id: &str,
+    ) -> Option<aws_cli::NetworkInterfaceDetail> {
+        aws_cli::get_network_interface_detail(interface_id)
+    }
+
+    pub fn list_launch_templates() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_launch_templates()
+    }
+
+    pub fn get_launch_template_detail(template_id: &str) -> Option<aws_cli::LaunchTemplateDetail> {
+        aws_cli::get_launch_template_detail(template_id)
+    }
+
+    pub fn list_amis() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_amis()
+    }
+
+    pub fn get_ami_detail(image_id: &str) -> Option<aws_cli::AmiDetail> {
+        aws_cli::get_ami_detail(image_id)
+    }
+
+    pub fn list_ebs_resources() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_ebs_resources()
+    }
+
+    pub fn get_ebs_detail(ebs_id: &str) -> Option<aws_cli::EbsDetail> {
+        aws_cli::get_ebs_detail(ebs_id)
+    }
+
+    pub fn list_key_pairs() -> Vec<aws_cli::AwsResource> {
+        aws_cli::list_key_pairs()
+    }
+
+    pub fn get_key_pair_detail(key_pair_id: &str) -> Option<aws_cli::KeyPairDetail> {
+        aws_cli::get_key_pair_detail(key_pair_id)
+    }
+
+    pub fn list_event_buses() -> Vec<aws_cli::AwsResource> {
+        aws_cli::eventbridge::list_event_buses()
+    }
+
+    pub fn get_event_bus_detail(event_bus_name: &str) -> Option<aws_cli::EventBusDetail> {
+        aws_cli::eventbridge::get_event_bus_detail(event_bus_name)
+    }
+
+    pub fn list_cognito_pools() -> Vec<aws_cli::AwsResource> {
+        aws_cli::cognito::list_cognito_pools()
+    }
+
+    pub fn get_cognito_pool_detail(pool_id: &str) -> Option<aws_cli::CognitoPoolDetail> {
+        aws_cli::cognito::get_cognito_pool_detail(pool_id)
+    }
+
+    pub fn list_glue_resources() -> Vec<aws_cli::AwsResource> {
+        aws_cli::glue::list_glue_resources()
+    }
+
+    pub fn get_glue_detail(id: &str) -> Option<aws_cli::GlueDetail> {
+        aws_cli::glue::get_glue_detail(id)
+    }
+
+    pub fn list_athena_workgroups() -> Vec<aws_cli::AwsResource> {
+        aws_cli::athena::list_athena_workgroups()
+    }
+
+    pub fn get_athena_workgroup_detail(name: &str) -> Option<aws_cli::AthenaWorkGroupDetail> {
+        aws_cli::athena::get_athena_workgroup_detail(name)
+    }
+
+    pub fn list_backup_plans() -> Vec<aws_cli::AwsResource> {
+        aws_cli::backup::list_backup_plans()
+    }
+
+    pub fn get_backup_plan_detail(plan_id: &str) -> Option<aws_cli::BackupPlanDetail> {
+        aws_cli::backup::get_backup_plan_detail(plan_id)
+    }
+
+    pub fn list_stack_resources(stack_name: &str) -> Result<Vec<aws_cli::StackResource>, String> {
+        aws_cli::cloudformation::list_stack_resources(stack_name)
+    }
+
+    pub fn get_resources_by_tags(
+        filters: &[(String, Vec<String>)],
+    ) -> Result<Vec<aws_cli::TaggedResource>, String> {
+        aws_cli::tagging::get_resources_by_tags(filters)
+    }
+
+    pub fn indexed_name(id: &str) -> Option<String> {
+        aws_cli::tagging::indexed_name(id)
+    }
+
+    pub fn set_name_tag(kind: &str, id: &str, name: &str) -> Result<(), String> {
+        aws_cli::tagging::set_name_tag(kind, id, name)
+    }
+
+    pub fn run_aws_cli(args: &[&str]) -> Option<String> {
+        aws_cli::run_aws_cli(args)
+    }
 }
 
 #[cfg(test)]
 mod aws_adapter {
-    use super::VpcInfoTuple;
-    use crate::aws_cli;
+    use super::{SubnetListTuple, VpcInfoTuple};
+    use crate::aws_cli::{self, Arn};
+    use crate::i18n::Language;
 
     fn resource(id: &str, name: &str) -> aws_cli::AwsResource {
         aws_cli::AwsResource {
@@ -107,20 +550,75 @@ mod aws_adapter {
             state: "available".to_string(),
             az: "ap-northeast-2a".to_string(),
             cidr: "10.0.0.0/24".to_string(),
+            owner_id: String::new(),
         }
     }
 
     pub fn set_region(_region: &str) {}
 
+    pub fn prefetch_instances(ids: &[String]) {
+        PREFETCHED.with(|prefetched| prefetched.borrow_mut().extend_from_slice(ids));
+    }
+
+    pub fn clear_prefetched_instances() {
+        PREFETCHED.with(|prefetched| prefetched.borrow_mut().clear());
+    }
+
+    pub fn prefetched_instance_ids() -> Vec<String> {
+        PREFETCHED.with(|prefetched| prefetched.borrow().clone())
+    }
+
+    pub fn clear_iam_role_cache() {}
+
+    thread_local! {
+        // 마지막 조회의 실패 종류 (블루프린트 검증 테스트용)
+        static CALL_FAILURE: std::cell::Cell<aws_cli::audit::CallFailure> =
+            const { std::cell::Cell::new(aws_cli::audit::CallFailure::None) };
+        // 미리 조회해 둔 인스턴스 ID (실행이 끝나면 비워지는지 확인용)
+        static PREFETCHED: std::cell::RefCell<Vec<String>> =
+            const { std::cell::RefCell::new(Vec::new()) };
+        // 마지막 변경 스탬프 설정 (전역 설정 대신 테스트마다 따로 둠)
+        static STAMP_LAST_MODIFIED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    }
+
+    pub fn reset_call_failures() {
+        CALL_FAILURE.with(|failure| failure.set(aws_cli::audit::CallFailure::None));
+    }
+
+    pub fn call_failure() -> aws_cli::audit::CallFailure {
+        CALL_FAILURE.with(|failure| failure.get())
+    }
+
+    // 세 개짜리 블루프린트가 두 묶음으로 나뉘도록 작게 둠
+    pub fn max_concurrent_requests() -> usize {
+        2
+    }
+
+    pub fn refresh_instance_detail(id: &str) -> Option<aws_cli::Ec2Detail> {
+        get_instance_detail(id)
+    }
+
     pub fn list_instances() -> Vec<aws_cli::AwsResource> {
         vec![resource("i-test", "ec2-test")]
     }
 
     pub fn get_instance_detail(id: &str) -> Option<aws_cli::Ec2Detail> {
+        // "i-gone"은 없는 인스턴스, "i-denied"는 권한이 없어 조회 실패
+        let failure = match id {
+            "i-gone" => Some(aws_cli::audit::CallFailure::NotFound),
+            "i-denied" => Some(aws_cli::audit::CallFailure::Other),
+            _ => None,
+        };
+        if let Some(failure) = failure {
+            CALL_FAILURE.with(|cell| cell.set(failure));
+            return None;
+        }
         Some(aws_cli::Ec2Detail {
             name: format!("ec2-{}", id),
             instance_id: id.to_string(),
             instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
             ami: "ami-test".to_string(),
             platform: "Linux".to_string(),
             architecture: "x86_64".to_string(),
@@ -152,12 +650,18 @@ mod aws_adapter {
             name: format!("network-{}", vpc_id),
             id: vpc_id.to_string(),
             cidr: "10.0.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
             state: "available".to_string(),
+            owner_id: String::new(),
             subnets: vec![],
+            subnet_addresses: vec![],
             igws: vec![],
             nats: vec![],
             route_tables: vec![],
+            nacls: vec![],
             eips: vec![],
+            peerings: vec![],
+            endpoints: vec![],
             dns_support: true,
             dns_hostnames: true,
             tags: vec![],
@@ -170,11 +674,12 @@ mod aws_adapter {
             "10.0.0.0/16".to_string(),
             "available".to_string(),
             vec![],
+            vec![],
         ))
     }
 
-    pub fn list_subnets(_vpc_id: &str) -> Vec<aws_cli::AwsResource> {
-        vec![resource("subnet-test", "subnet-test")]
+    pub fn list_subnets(_vpc_id: &str) -> SubnetListTuple {
+        (vec![resource("subnet-test", "subnet-test")], vec![])
     }
 
     pub fn list_internet_gateways(_vpc_id: &str) -> Vec<aws_cli::AwsResource> {
@@ -193,6 +698,18 @@ mod aws_adapter {
         vec![]
     }
 
+    pub fn list_vpc_peerings(_vpc_id: &str) -> Vec<aws_cli::VpcPeeringDetail> {
+        vec![]
+    }
+
+    pub fn list_vpc_endpoints_in(_vpc_id: &str) -> Vec<aws_cli::VpcEndpointDetail> {
+        vec![]
+    }
+
+    pub fn list_network_acls(_vpc_id: &str) -> Vec<aws_cli::NetworkAclDetail> {
+        vec![]
+    }
+
     pub fn get_vpc_dns_support(_vpc_id: &str) -> bool {
         true
     }
@@ -263,6 +780,8 @@ mod aws_adapter {
             arn: format!("arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:{name}"),
             launch_template_name: None,
             launch_template_id: None,
+            launch_template_version: None,
+            launch_template: None,
             launch_config_name: None,
             min_size: 1,
             max_size: 1,
@@ -275,1389 +794,8887 @@ mod aws_adapter {
             instances: vec![],
             created_time: "2026-01-01T00:00:00Z".to_string(),
             scaling_policies: vec![],
+            scheduled_actions: vec![],
             tags: vec![],
         })
     }
-}
 
-pub fn handle_key(app: &mut App, key: KeyEvent) {
-    match &app.screen {
-        Screen::Login => handle_login(app, key),
-        Screen::BlueprintSelect => handle_blueprint_select(app, key),
-        Screen::BlueprintDetail => handle_blueprint_detail(app, key),
-        Screen::BlueprintNameInput => handle_blueprint_name_input(app, key),
-        Screen::BlueprintPreview => handle_blueprint_preview(app, key),
-        Screen::RegionSelect => handle_region_select(app, key),
-        Screen::ServiceSelect => handle_service_select(app, key),
-        Screen::Ec2Select => handle_ec2_select(app, key),
-        Screen::VpcSelect => handle_vpc_select(app, key),
-        Screen::SecurityGroupSelect => handle_security_group_select(app, key),
-        Screen::LoadBalancerSelect => handle_load_balancer_select(app, key),
-        Screen::EcrSelect => handle_ecr_select(app, key),
-        Screen::AsgSelect => handle_asg_select(app, key),
-        Screen::Preview => handle_preview(app, key),
-        Screen::Settings => handle_settings(app, key),
+    pub fn list_rds_databases() -> Vec<aws_cli::AwsResource> {
+        vec![resource("rds-test", "rds-test")]
     }
-}
 
-pub fn handle_mouse(app: &mut App, mouse: MouseEvent) {
-    // 미리보기 화면에서만 마우스 스크롤/드래그 처리
-    match &app.screen {
-        Screen::Preview | Screen::BlueprintPreview => {
-            handle_preview_mouse(app, mouse);
-        }
-        _ => {}
+    pub fn get_rds_detail(identifier: &str) -> Option<aws_cli::RdsDetail> {
+        Some(aws_cli::RdsDetail {
+            identifier: identifier.to_string(),
+            arn: format!("arn:aws:rds:ap-northeast-2:123456789012:db:{identifier}"),
+            is_cluster: false,
+            engine: "postgres".to_string(),
+            engine_version: "16.3".to_string(),
+            instance_class: "db.t4g.micro".to_string(),
+            status: "available".to_string(),
+            storage_type: "gp3".to_string(),
+            allocated_storage: 20,
+            storage_encrypted: false,
+            multi_az: false,
+            availability_zones: vec![],
+            endpoint: String::new(),
+            reader_endpoint: None,
+            port: None,
+            subnet_group: String::new(),
+            parameter_group: String::new(),
+            security_groups: vec![],
+            cluster_identifier: None,
+            members: vec![],
+            tags: vec![],
+        })
     }
-}
 
-fn handle_preview_mouse(app: &mut App, mouse: MouseEvent) {
-    let content_lines = app.preview_content.lines().count() as u16;
+    pub fn list_lambda_functions() -> Vec<aws_cli::AwsResource> {
+        vec![resource("lambda-test", "lambda-test")]
+    }
 
-    match mouse.kind {
-        MouseEventKind::ScrollUp => {
-            app.preview_scroll = app.preview_scroll.saturating_sub(3);
-        }
-        MouseEventKind::ScrollDown => {
-            app.preview_scroll = app
-                .preview_scroll
-                .saturating_add(3)
-                .min(content_lines.saturating_sub(1));
-        }
-        MouseEventKind::Down(_) => {
-            app.preview_drag_start = Some((mouse.column, mouse.row));
-        }
-        MouseEventKind::Up(_) => {
-            app.preview_drag_start = None;
-        }
-        MouseEventKind::Drag(_) => {
-            if let Some((_, start_y)) = app.preview_drag_start {
-                let current_y = mouse.row;
-                if current_y < start_y {
-                    // 드래그 위로 -> 스크롤 다운
-                    let diff = start_y - current_y;
-                    app.preview_scroll = app
-                        .preview_scroll
-                        .saturating_add(diff)
-                        .min(content_lines.saturating_sub(1));
-                } else if current_y > start_y {
-                    // 드래그 아래로 -> 스크롤 업
-                    let diff = current_y - start_y;
-                    app.preview_scroll = app.preview_scroll.saturating_sub(diff);
-                }
-                app.preview_drag_start = Some((mouse.column, mouse.row));
-            }
-        }
-        _ => {}
+    pub fn get_lambda_detail(name: &str) -> Option<aws_cli::LambdaDetail> {
+        Some(aws_cli::LambdaDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:lambda:ap-northeast-2:123456789012:function:{name}"),
+            runtime: "python3.12".to_string(),
+            handler: "app.handler".to_string(),
+            memory_size: 128,
+            timeout: 3,
+            role: String::new(),
+            description: String::new(),
+            package_type: "Zip".to_string(),
+            architectures: vec![],
+            last_modified: String::new(),
+            code_size: 0,
+            env_var_keys: vec![],
+            vpc_id: None,
+            subnet_ids: vec![],
+            security_group_ids: vec![],
+            layers: vec![],
+            triggers: vec![],
+            tags: vec![],
+        })
     }
-}
 
-pub fn process_loading(app: &mut App) {
-    match app.loading_task.clone() {
-        LoadingTask::RefreshEc2 => {
-            app.instances = aws_adapter::list_instances();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::RefreshVpc => {
-            app.vpcs = aws_adapter::list_vpcs();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::RefreshPreview => {
-            if app.ec2_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_instance_detail(
-                    app.instances
-                        .get(app.selected_index)
-                        .map(|i| i.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.ec2_detail = Some(new_detail);
-                }
-            } else if app.network_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_network_detail(
-                    app.vpcs
-                        .get(app.selected_index)
-                        .map(|v| v.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.network_detail = Some(new_detail);
-                }
-            } else if app.sg_detail.is_some()
-                && let Some(new_detail) = aws_adapter::get_security_group_detail(
-                    app.security_groups
-                        .get(app.selected_index)
-                        .map(|sg| sg.id.as_str())
-                        .unwrap_or(""),
-                )
-            {
-                app.preview_content = new_detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", new_detail.name);
-                app.sg_detail = Some(new_detail);
-            } else if app.lb_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_load_balancer_detail(
-                    app.load_balancers
-                        .get(app.selected_index)
-                        .map(|lb| lb.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.lb_detail = Some(new_detail);
-                }
-            } else if app.ecr_detail.is_some() {
-                if let Some(new_detail) = aws_adapter::get_ecr_detail(
-                    app.ecr_repositories
-                        .get(app.selected_index)
-                        .map(|repo| repo.id.as_str())
-                        .unwrap_or(""),
-                ) {
-                    app.preview_content = new_detail.to_markdown(app.settings.language);
-                    app.preview_filename = format!("{}.md", new_detail.name);
-                    app.ecr_detail = Some(new_detail);
-                }
-            } else if app.asg_detail.is_some()
-                && let Some(new_detail) = aws_adapter::get_asg_detail(
-                    app.auto_scaling_groups
-                        .get(app.selected_index)
-                        .map(|asg| asg.id.as_str())
-                        .unwrap_or(""),
-                )
-            {
-                app.preview_content = new_detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", new_detail.name);
-                app.asg_detail = Some(new_detail);
-            }
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::LoadEc2 => {
-            app.instances = aws_adapter::list_instances();
-            app.selected_index = 0;
-            app.screen = Screen::Ec2Select;
-            finish_loading(app);
-        }
-        LoadingTask::LoadEc2Detail(id) => {
-            if let Some(detail) = aws_adapter::get_instance_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.ec2_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
-        }
-        LoadingTask::LoadVpc => {
-            app.vpcs = aws_adapter::list_vpcs();
-            app.selected_index = 0;
-            app.screen = Screen::VpcSelect;
-            finish_loading(app);
-        }
+    pub fn list_dynamodb_tables() -> Vec<aws_cli::AwsResource> {
+        vec![resource("dynamodb-test", "dynamodb-test")]
+    }
 
-        LoadingTask::LoadVpcDetail(id, step) => {
-            process_vpc_detail_step(app, &id, step);
-        }
-        LoadingTask::RefreshSecurityGroup => {
-            app.security_groups = aws_adapter::list_security_groups();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::LoadSecurityGroup => {
-            app.security_groups = aws_adapter::list_security_groups();
-            app.selected_index = 0;
-            app.screen = Screen::SecurityGroupSelect;
-            finish_loading(app);
-        }
-        LoadingTask::LoadSecurityGroupDetail(id) => {
-            if let Some(detail) = aws_adapter::get_security_group_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.sg_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
-        }
+    pub fn get_dynamodb_detail(name: &str) -> Option<aws_cli::DynamoDbDetail> {
+        Some(aws_cli::DynamoDbDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:dynamodb:ap-northeast-2:123456789012:table/{name}"),
+            status: "ACTIVE".to_string(),
+            partition_key: "pk (S)".to_string(),
+            sort_key: None,
+            billing_mode: "PAY_PER_REQUEST".to_string(),
+            read_capacity: 0,
+            write_capacity: 0,
+            table_class: String::new(),
+            item_count: 0,
+            size_bytes: 0,
+            global_indexes: vec![],
+            local_indexes: vec![],
+            ttl_attribute: None,
+            stream_view_type: None,
+            sse_type: None,
+            kms_key_arn: None,
+            deletion_protection: false,
+            tags: vec![],
+        })
+    }
 
-        LoadingTask::RefreshLoadBalancer => {
-            app.load_balancers = aws_adapter::list_load_balancers();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::LoadLoadBalancer => {
-            app.load_balancers = aws_adapter::list_load_balancers();
-            app.selected_index = 0;
-            app.screen = Screen::LoadBalancerSelect;
-            finish_loading(app);
-        }
-        LoadingTask::LoadLoadBalancerDetail(id) => {
-            if let Some(detail) = aws_adapter::get_load_balancer_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.lb_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
-        }
+    pub fn list_cloudfront_distributions() -> Vec<aws_cli::AwsResource> {
+        vec![resource("E2EXAMPLE", "cdn-test")]
+    }
 
-        LoadingTask::RefreshEcr => {
-            app.ecr_repositories = aws_adapter::list_ecr_repositories();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::LoadEcr => {
-            app.ecr_repositories = aws_adapter::list_ecr_repositories();
-            app.selected_index = 0;
-            app.screen = Screen::EcrSelect;
-            finish_loading(app);
-        }
-        LoadingTask::LoadEcrDetail(id) => {
-            if let Some(detail) = aws_adapter::get_ecr_detail(&id) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.ecr_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
-        }
+    pub fn get_cloudfront_detail(id: &str) -> Option<aws_cli::CloudFrontDetail> {
+        Some(aws_cli::CloudFrontDetail {
+            id: id.to_string(),
+            arn: format!("arn:aws:cloudfront::123456789012:distribution/{id}"),
+            domain_name: "d111111abcdef8.cloudfront.net".to_string(),
+            status: "Deployed".to_string(),
+            enabled: true,
+            comment: String::new(),
+            aliases: vec![],
+            price_class: String::new(),
+            http_version: String::new(),
+            ipv6_enabled: false,
+            default_root_object: String::new(),
+            certificate: None,
+            ssl_support_method: String::new(),
+            minimum_protocol_version: String::new(),
+            web_acl_id: None,
+            origins: vec![],
+            behaviors: vec![],
+            tags: vec![],
+        })
+    }
 
-        LoadingTask::RefreshAsg => {
-            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
-            app.message = app.i18n.refresh_complete().to_string();
-            finish_loading(app);
-        }
-        LoadingTask::LoadAsg => {
-            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
-            app.selected_index = 0;
-            app.screen = Screen::AsgSelect;
-            finish_loading(app);
-        }
-        LoadingTask::LoadAsgDetail(name) => {
-            if let Some(detail) = aws_adapter::get_asg_detail(&name) {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-                app.asg_detail = Some(detail);
-                app.screen = Screen::Preview;
-            }
-            finish_loading(app);
-        }
+    pub fn list_route53_zones() -> Vec<aws_cli::AwsResource> {
+        vec![resource("Z0123456789ABC", "route53-test.example")]
+    }
 
-        LoadingTask::LoadBlueprintResources(current_index) => {
-            process_blueprint_resources(app, current_index);
-        }
-        LoadingTask::None => {}
+    pub fn get_route53_detail(id: &str) -> Option<aws_cli::Route53Detail> {
+        Some(aws_cli::Route53Detail {
+            id: id.to_string(),
+            name: "route53-test.example".to_string(),
+            private_zone: false,
+            comment: String::new(),
+            record_count: 2,
+            vpcs: vec![],
+            records: vec![],
+            tags: vec![],
+        })
     }
-}
 
-fn process_blueprint_resources(app: &mut App, current_index: usize) {
-    let blueprint = match &app.current_blueprint {
-        Some(bp) => bp.clone(),
-        None => {
-            finish_loading(app);
-            return;
-        }
-    };
+    pub fn list_eks_clusters() -> Vec<aws_cli::AwsResource> {
+        vec![resource("eks-test", "eks-test")]
+    }
 
-    if current_index >= blueprint.resources.len() {
-        // All resources loaded, generate table of contents and combine markdown
-        let mut toc = vec![format!("## {}\n", app.i18n.toc())];
-        for (i, (res, markdown)) in blueprint
-            .resources
-            .iter()
-            .zip(app.blueprint_markdown_parts.iter())
-            .enumerate()
-        {
-            let anchor = format!(
-                "{}-{}",
-                res.resource_type.display().to_lowercase().replace(" ", "-"),
-                res.resource_name.to_lowercase().replace(" ", "-")
-            );
-            toc.push(format!(
-                "- [{}. {} - {}](#{})",
-                i + 1,
-                res.resource_type.display(),
-                res.resource_name,
-                anchor
-            ));
-
-            // ### 헤더들을 서브 목차로 추가
-            for line in markdown.lines() {
-                if line.starts_with("### ") {
-                    let header = line.trim_start_matches("### ").trim();
-                    let sub_anchor = header
-                        .to_lowercase()
-                        .replace(" ", "-")
-                        .replace("(", "")
-                        .replace(")", "");
-                    toc.push(format!("  - [{}](#{})", header, sub_anchor));
-                }
-            }
-        }
-        toc.push("\n".to_string());
-
-        let combined = app.blueprint_markdown_parts.join("\n---\n\n");
-        let toc_str = toc.join("\n");
-        app.preview_content = format!("# Blueprint: {}\n\n{}{}", blueprint.name, toc_str, combined);
-        app.preview_filename = format!("{}.md", blueprint.name);
-        app.preview_scroll = 0;
-        app.screen = Screen::BlueprintPreview;
-        finish_loading(app);
-        return;
+    pub fn get_eks_detail(name: &str) -> Option<aws_cli::EksDetail> {
+        Some(aws_cli::EksDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:eks:ap-northeast-2:123456789012:cluster/{name}"),
+            status: "ACTIVE".to_string(),
+            version: "1.29".to_string(),
+            platform_version: String::new(),
+            endpoint: String::new(),
+            endpoint_public_access: true,
+            endpoint_private_access: false,
+            public_access_cidrs: vec![],
+            vpc_id: String::new(),
+            subnet_ids: vec![],
+            security_group_ids: vec![],
+            role_arn: String::new(),
+            logging: vec![],
+            node_groups: vec![],
+            fargate_profiles: vec![],
+            addons: vec![],
+            tags: vec![],
+        })
     }
 
-    let resource = &blueprint.resources[current_index];
+    pub fn list_ecs_clusters() -> Vec<aws_cli::AwsResource> {
+        vec![resource("ecs-test", "ecs-test")]
+    }
 
-    // Set region for this resource
-    aws_adapter::set_region(&resource.region);
+    pub fn get_ecs_detail(name: &str) -> Option<aws_cli::EcsDetail> {
+        Some(aws_cli::EcsDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:ecs:ap-northeast-2:123456789012:cluster/{name}"),
+            status: "ACTIVE".to_string(),
+            capacity_providers: vec![],
+            registered_container_instances: 0,
+            active_services_count: 0,
+            running_tasks_count: 0,
+            pending_tasks_count: 0,
+            services: vec![],
+            tasks: vec![],
+            task_definitions: vec![],
+            tags: vec![],
+        })
+    }
 
-    // Fetch resource detail and generate markdown
-    let failed = app.i18n.query_failed();
-    let markdown = match resource.resource_type {
-        ResourceType::Ec2 => aws_adapter::get_instance_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.ec2(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Network => aws_adapter::get_network_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.network(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::SecurityGroup => {
-            aws_adapter::get_security_group_detail(&resource.resource_id)
-                .map(|d| d.to_markdown(app.settings.language))
-                .unwrap_or_else(|| {
-                    format!(
-                        "## {}: {} ({})\n",
-                        app.i18n.security_group(),
-                        resource.resource_name,
-                        failed
-                    )
-                })
-        }
-        ResourceType::LoadBalancer => aws_adapter::get_load_balancer_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.load_balancer(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Ecr => aws_adapter::get_ecr_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.md_ecr_repository(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-        ResourceType::Asg => aws_adapter::get_asg_detail(&resource.resource_id)
-            .map(|d| d.to_markdown(app.settings.language))
-            .unwrap_or_else(|| {
-                format!(
-                    "## {}: {} ({})\n",
-                    app.i18n.auto_scaling_group(),
-                    resource.resource_name,
-                    failed
-                )
-            }),
-    };
+    pub fn list_sqs_queues() -> Vec<aws_cli::AwsResource> {
+        vec![resource("sqs-test", "sqs-test")]
+    }
 
-    app.blueprint_markdown_parts.push(markdown);
+    pub fn get_sqs_detail(name: &str) -> Option<aws_cli::SqsDetail> {
+        Some(aws_cli::SqsDetail {
+            name: name.to_string(),
+            url: format!("https://sqs.ap-northeast-2.amazonaws.com/123456789012/{name}"),
+            arn: format!("arn:aws:sqs:ap-northeast-2:123456789012:{name}"),
+            fifo: false,
+            content_based_deduplication: false,
+            visibility_timeout: 30,
+            message_retention_period: 345600,
+            delay_seconds: 0,
+            receive_message_wait_time: 0,
+            maximum_message_size: 262144,
+            messages_available: 0,
+            messages_in_flight: 0,
+            messages_delayed: 0,
+            dead_letter_target_arn: None,
+            max_receive_count: None,
+            sqs_managed_sse: true,
+            kms_master_key_id: None,
+            tags: vec![],
+        })
+    }
 
-    // Move to next resource
-    app.loading_task = LoadingTask::LoadBlueprintResources(current_index + 1);
-}
+    pub fn list_sns_topics() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:sns:ap-northeast-2:123456789012:sns-test",
+            "sns-test",
+        )]
+    }
 
-fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
-    if step > 0 && app.network_detail.is_none() {
-        tracing::warn!(
-            vpc_id,
-            step,
-            "Network detail aborting because VPC info is missing"
-        );
-        app.message = app.i18n.network_detail_unavailable(vpc_id);
-        finish_loading(app);
-        return;
+    pub fn get_sns_detail(arn: &str) -> Option<aws_cli::SnsDetail> {
+        let name = Arn::parse(arn).map_or(arn, |parsed| parsed.resource_name());
+        Some(aws_cli::SnsDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:sns:ap-northeast-2:123456789012:{name}"),
+            display_name: String::new(),
+            owner: "123456789012".to_string(),
+            fifo: false,
+            content_based_deduplication: false,
+            kms_master_key_id: None,
+            subscriptions_confirmed: 0,
+            subscriptions_pending: 0,
+            access_policy: None,
+            subscriptions: vec![],
+            tags: vec![],
+        })
     }
 
-    match step {
-        0 => {
-            // Step 0: VPC 기본 정보
-            if let Some(info) = aws_adapter::get_vpc_info(vpc_id) {
-                tracing::info!(
-                    vpc_id,
-                    cidr = %info.1,
-                    state = %info.2,
-                    tag_count = info.3.len(),
-                    "Network detail step 0 loaded VPC info"
-                );
-                app.network_detail = Some(NetworkDetail {
-                    name: info.0,
-                    id: vpc_id.to_string(),
-                    cidr: info.1,
-                    state: info.2,
-                    tags: info.3,
-                    subnets: Vec::new(),
-                    igws: Vec::new(),
-                    nats: Vec::new(),
-                    route_tables: Vec::new(),
-                    eips: Vec::new(),
-                    dns_support: false,
-                    dns_hostnames: false,
-                });
-                app.loading_progress.vpc_info = true;
-            } else {
-                tracing::warn!(vpc_id, "Network detail step 0 failed to load VPC info");
-                app.message = app.i18n.network_detail_unavailable(vpc_id);
-                app.network_detail = None;
-                finish_loading(app);
-                return;
-            }
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 1);
-        }
-        1 => {
-            // Step 1: Subnets
-            let subnets = aws_adapter::list_subnets(vpc_id);
-            tracing::info!(
-                vpc_id,
-                subnet_count = subnets.len(),
-                "Network detail step 1 loaded subnets"
-            );
-            if subnets.is_empty() {
-                tracing::warn!(vpc_id, "Network detail step 1 returned empty subnet list");
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.subnets = subnets;
-            }
-            app.loading_progress.subnets = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 2);
-        }
-        2 => {
-            // Step 2: Internet Gateways
-            let igws = aws_adapter::list_internet_gateways(vpc_id);
-            tracing::info!(
-                vpc_id,
-                igw_count = igws.len(),
-                "Network detail step 2 loaded internet gateways"
-            );
-            if igws.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 2 returned empty internet gateway list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.igws = igws;
-            }
-            app.loading_progress.igws = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 3);
-        }
-        3 => {
-            // Step 3: NAT Gateways
-            let nats = aws_adapter::list_nat_gateways(vpc_id);
-            tracing::info!(
-                vpc_id,
-                nat_count = nats.len(),
-                "Network detail step 3 loaded NAT gateways"
-            );
-            if nats.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 3 returned empty NAT gateway list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.nats = nats;
-            }
-            app.loading_progress.nats = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 4);
-        }
-        4 => {
-            // Step 4: Route Tables
-            let route_tables = aws_adapter::list_route_tables(vpc_id);
-            tracing::info!(
-                vpc_id,
-                route_table_count = route_tables.len(),
-                "Network detail step 4 loaded route tables"
-            );
-            if route_tables.is_empty() {
-                tracing::warn!(
-                    vpc_id,
-                    "Network detail step 4 returned empty route table list"
-                );
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.route_tables = route_tables;
-            }
-            app.loading_progress.route_tables = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 5);
-        }
-        5 => {
-            // Step 5: Elastic IPs
-            let eips = aws_adapter::list_eips();
-            tracing::info!(
-                vpc_id,
-                eip_count = eips.len(),
-                "Network detail step 5 loaded EIPs"
-            );
-            if eips.is_empty() {
-                tracing::warn!(vpc_id, "Network detail step 5 returned empty EIP list");
-            }
-            if let Some(ref mut detail) = app.network_detail {
-                detail.eips = eips;
-            }
-            app.loading_progress.eips = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 6);
-        }
-        6 => {
-            // Step 6: DNS Attributes
-            tracing::debug!(vpc_id, "Network detail step 6 loading DNS attributes");
-            if let Some(ref mut detail) = app.network_detail {
-                detail.dns_support = aws_adapter::get_vpc_dns_support(vpc_id);
-                detail.dns_hostnames = aws_adapter::get_vpc_dns_hostnames(vpc_id);
-                tracing::info!(
-                    vpc_id,
-                    dns_support = detail.dns_support,
-                    dns_hostnames = detail.dns_hostnames,
-                    "Network detail step 6 loaded DNS attributes"
-                );
-            }
-            app.loading_progress.dns_attrs = true;
-            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 7);
-        }
-        _ => {
-            // 완료: Preview 화면으로 전환
-            if let Some(ref detail) = app.network_detail {
-                app.preview_content = detail.to_markdown(app.settings.language);
-                app.preview_filename = format!("{}.md", detail.name);
-            }
-            app.screen = Screen::Preview;
-            finish_loading(app);
-        }
+    pub fn list_elasticache_clusters() -> Vec<aws_cli::AwsResource> {
+        vec![resource("elasticache-test", "elasticache-test")]
     }
-}
 
-fn finish_loading(app: &mut App) {
-    app.loading = false;
-    app.loading_task = LoadingTask::None;
-    app.loading_progress.reset();
-}
+    pub fn get_elasticache_detail(id: &str) -> Option<aws_cli::ElastiCacheDetail> {
+        Some(aws_cli::ElastiCacheDetail {
+            id: id.to_string(),
+            arn: format!("arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:{id}"),
+            is_replication_group: true,
+            description: String::new(),
+            engine: "redis".to_string(),
+            engine_version: "7.1.0".to_string(),
+            node_type: "cache.t4g.small".to_string(),
+            status: "available".to_string(),
+            cluster_mode: Some("disabled".to_string()),
+            automatic_failover: Some("enabled".to_string()),
+            multi_az: Some("enabled".to_string()),
+            configuration_endpoint: None,
+            transit_encryption: true,
+            at_rest_encryption: true,
+            subnet_group: String::new(),
+            parameter_group: String::new(),
+            security_groups: vec![],
+            node_groups: vec![],
+            nodes: vec![],
+            tags: vec![],
+        })
+    }
 
-fn start_loading(app: &mut App, task: LoadingTask) {
-    app.loading = true;
-    app.loading_progress.reset();
-    app.loading_task = task;
-    app.message = app.i18n.loading_msg().to_string();
-}
+    pub fn list_cloudwatch_alarms() -> Vec<aws_cli::AwsResource> {
+        vec![resource("alarm-test", "alarm-test")]
+    }
 
-fn handle_login(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_profile_index > 0 {
-                app.selected_profile_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_profile_index + 1 < app.available_profiles.len() {
-                app.selected_profile_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            app.select_current_profile_and_login();
-        }
-        KeyCode::Char('r') => {
-            app.refresh_profiles();
-        }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn get_cloudwatch_alarm_detail(name: &str) -> Option<aws_cli::CloudWatchAlarmDetail> {
+        Some(aws_cli::CloudWatchAlarmDetail {
+            name: name.to_string(),
+            arn: format!("arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:{name}"),
+            description: String::new(),
+            composite: false,
+            state: "OK".to_string(),
+            state_reason: String::new(),
+            state_updated: String::new(),
+            metric: "AWS/EC2 CPUUtilization".to_string(),
+            statistic: "Average".to_string(),
+            dimensions: vec![],
+            period: Some(300),
+            evaluation_periods: Some(3),
+            datapoints_to_alarm: None,
+            comparison_operator: "GreaterThanOrEqualToThreshold".to_string(),
+            threshold: Some(80.0),
+            treat_missing_data: "missing".to_string(),
+            alarm_rule: None,
+            actions_enabled: true,
+            alarm_actions: vec![],
+            ok_actions: vec![],
+            insufficient_data_actions: vec![],
+            tags: vec![],
+        })
     }
-}
 
-fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
-    let list_len = app.blueprint_store.blueprints.len() + 1; // +1 for "새 블루프린터"
+    pub fn list_cloudtrail_trails() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/trail-test",
+            "trail-test",
+        )]
+    }
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_blueprint_index > 0 {
-                app.selected_blueprint_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_blueprint_index < list_len - 1 {
-                app.selected_blueprint_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_blueprint_index == 0 {
-                // "새 블루프린터" 선택
-                app.input_buffer.clear();
-                app.screen = Screen::BlueprintNameInput;
-            } else {
-                // 기존 블루프린터 선택
-                let bp_index = app.selected_blueprint_index - 1;
-                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
-                    app.current_blueprint = Some(bp.clone());
-                    app.selected_blueprint_index = bp_index;
-                    app.blueprint_resource_index = 0;
-                    app.screen = Screen::BlueprintDetail;
-                }
-            }
-        }
-        KeyCode::Char('g') => {
-            // 선택된 블루프린터의 마크다운 생성
-            if app.selected_blueprint_index > 0 {
-                let bp_index = app.selected_blueprint_index - 1;
-                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
-                    if !bp.resources.is_empty() {
-                        app.current_blueprint = Some(bp.clone());
-                        app.selected_blueprint_index = bp_index;
-                        app.blueprint_markdown_parts.clear();
-                        start_loading(app, LoadingTask::LoadBlueprintResources(0));
-                    } else {
-                        app.message = app.i18n.no_resources().to_string();
-                    }
-                }
-            }
-        }
-        KeyCode::Char('d') => {
-            // 블루프린터 삭제
-            if app.selected_blueprint_index > 0 {
-                let bp_index = app.selected_blueprint_index - 1;
-                app.delete_blueprint(bp_index);
-                if app.selected_blueprint_index > 0 {
-                    app.selected_blueprint_index = 1.min(app.blueprint_store.blueprints.len());
-                }
-            }
-        }
-        KeyCode::Char('s') => {
-            // 단일 리소스 모드로 전환 (리전 선택)
-            app.blueprint_mode = false;
-            app.screen = Screen::RegionSelect;
-        }
-        KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-            // Switch to Settings tab
-            app.selected_tab = 1;
-            app.selected_setting = 0;
-            app.screen = Screen::Settings;
-        }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn get_cloudtrail_detail(arn: &str) -> Option<aws_cli::CloudTrailDetail> {
+        let name = Arn::parse(arn).map_or(arn, |parsed| parsed.resource_name());
+        Some(aws_cli::CloudTrailDetail {
+            name: name.to_string(),
+            arn: arn.to_string(),
+            home_region: "ap-northeast-2".to_string(),
+            multi_region: true,
+            organization_trail: false,
+            include_global_events: true,
+            logging: true,
+            latest_delivery: String::new(),
+            s3_bucket: "audit-logs".to_string(),
+            s3_key_prefix: String::new(),
+            log_file_validation: true,
+            kms_key_id: None,
+            cloudwatch_log_group: None,
+            sns_topic: None,
+            recent_events: vec![],
+            tags: vec![],
+        })
     }
-}
-
-fn handle_blueprint_detail(app: &mut App, key: KeyEvent) {
-    let resource_len = app
-        .current_blueprint
-        .as_ref()
-        .map(|bp| bp.resources.len())
-        .unwrap_or(0);
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-            // Shift+Up/K: 리소스 위로 이동
-            if app.move_resource_up(app.blueprint_resource_index) {
-                app.blueprint_resource_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-            // Shift+Down/J: 리소스 아래로 이동
-            if app.move_resource_down(app.blueprint_resource_index) {
-                app.blueprint_resource_index += 1;
-            }
-        }
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.blueprint_resource_index > 0 {
-                app.blueprint_resource_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.blueprint_resource_index < resource_len.saturating_sub(1) {
-                app.blueprint_resource_index += 1;
-            }
-        }
-        KeyCode::Char('a') => {
-            // 리소스 추가 (리전 선택으로 이동)
-            app.blueprint_mode = true;
-            app.screen = Screen::RegionSelect;
-        }
-        KeyCode::Char('d') => {
-            // 리소스 삭제
-            if resource_len > 0 {
-                app.remove_resource_from_current_blueprint(app.blueprint_resource_index);
-                if app.blueprint_resource_index >= resource_len.saturating_sub(1)
-                    && app.blueprint_resource_index > 0
-                {
-                    app.blueprint_resource_index -= 1;
-                }
-            }
-        }
-        KeyCode::Enter | KeyCode::Char('g') => {
-            // 마크다운 생성
-            if let Some(ref bp) = app.current_blueprint {
-                if !bp.resources.is_empty() {
-                    app.blueprint_markdown_parts.clear();
-                    start_loading(app, LoadingTask::LoadBlueprintResources(0));
-                } else {
-                    app.message = app.i18n.no_resources().to_string();
-                }
-            }
-        }
-        KeyCode::Esc => {
-            app.current_blueprint = None;
-            app.screen = Screen::BlueprintSelect;
-        }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn get_last_modified(resource_id: &str, _global: bool) -> Option<aws_cli::LastModified> {
+        Some(aws_cli::LastModified {
+            time: "2026-01-01T00:00:00Z".to_string(),
+            user: "alice".to_string(),
+            event: format!("Update {}", resource_id),
+            source: "ec2.amazonaws.com".to_string(),
+            via: aws_cli::cloudtrail::ChangeChannel::Cli,
+        })
     }
-}
 
-fn handle_blueprint_name_input(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Enter => {
-            if !app.input_buffer.trim().is_empty() {
-                let name = app.input_buffer.trim().to_string();
-                app.create_blueprint(name);
-                // 새로 생성된 블루프린터 선택
-                let bp_index = app.blueprint_store.blueprints.len() - 1;
-                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
-                    app.current_blueprint = Some(bp.clone());
-                    app.selected_blueprint_index = bp_index;
-                    app.blueprint_resource_index = 0;
-                    app.screen = Screen::BlueprintDetail;
-                }
-            }
-            app.input_buffer.clear();
-        }
-        KeyCode::Esc => {
-            app.input_buffer.clear();
-            app.screen = Screen::BlueprintSelect;
-        }
-        KeyCode::Backspace => {
-            app.input_buffer.pop();
-        }
-        KeyCode::Char(c) => {
-            app.input_buffer.push(c);
-        }
-        _ => {}
+    pub fn set_stamp_last_modified(enabled: bool) {
+        STAMP_LAST_MODIFIED.with(|stamp| stamp.set(enabled));
     }
-}
 
-fn handle_blueprint_preview(app: &mut App, key: KeyEvent) {
-    let content_lines = app.preview_content.lines().count() as u16;
+    pub fn stamp_last_modified_enabled() -> bool {
+        STAMP_LAST_MODIFIED.with(|stamp| stamp.get())
+    }
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.preview_scroll = app.preview_scroll.saturating_sub(1);
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.preview_scroll = app
-                .preview_scroll
-                .saturating_add(1)
-                .min(content_lines.saturating_sub(1));
-        }
-        KeyCode::PageUp => {
-            app.preview_scroll = app.preview_scroll.saturating_sub(20);
-        }
-        KeyCode::PageDown => {
-            app.preview_scroll = app
-                .preview_scroll
-                .saturating_add(20)
-                .min(content_lines.saturating_sub(1));
-        }
-        KeyCode::Home => {
-            app.preview_scroll = 0;
-        }
-        KeyCode::End => {
-            app.preview_scroll = content_lines.saturating_sub(1);
-        }
-        KeyCode::Enter | KeyCode::Char('s') => {
-            let _ = app.save_file();
-        }
-        KeyCode::Esc => {
-            app.preview_scroll = 0;
-            if app.current_blueprint.is_some() {
-                // 블루프린터 상세로 돌아가기
-                app.screen = Screen::BlueprintDetail;
-            } else {
-                app.screen = Screen::BlueprintSelect;
-            }
+    pub fn last_modified_markdown(
+        last_modified: Option<&aws_cli::LastModified>,
+        _lang: Language,
+    ) -> String {
+        match last_modified {
+            Some(change) => format!("### Last Modified\n{} by {}\n", change.event, change.user),
+            None => "### Last Modified\n-\n".to_string(),
         }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
     }
-}
 
-fn handle_region_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_region > 0 {
-                app.selected_region -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_region < REGIONS.len() - 1 {
-                app.selected_region += 1;
-            }
-        }
-        KeyCode::Enter => app.select_region(),
-        KeyCode::Esc => {
-            if app.blueprint_mode {
-                app.screen = Screen::BlueprintDetail;
-            } else {
-                app.screen = Screen::BlueprintSelect;
-            }
-        }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn list_kms_keys() -> Vec<aws_cli::AwsResource> {
+        vec![resource("1234abcd-12ab-34cd-56ef-1234567890ab", "kms-test")]
     }
-}
 
-fn handle_service_select(app: &mut App, key: KeyEvent) {
-    let total_items = SERVICE_KEYS.len() + 1; // +1 for exit
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_service > 0 {
-                app.selected_service -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_service < total_items - 1 {
-                app.selected_service += 1;
-            }
-        }
-        KeyCode::Enter => match app.selected_service {
-            0 => start_loading(app, LoadingTask::LoadEc2),
-            1 => start_loading(app, LoadingTask::LoadVpc),
-            2 => start_loading(app, LoadingTask::LoadSecurityGroup),
-            3 => start_loading(app, LoadingTask::LoadLoadBalancer),
-            4 => start_loading(app, LoadingTask::LoadEcr),
-            5 => start_loading(app, LoadingTask::LoadAsg),
-            6 => {
-                // Exit
-                if app.blueprint_mode {
-                    app.screen = Screen::BlueprintDetail;
-                } else {
-                    app.running = false;
-                }
-            }
-            _ => {}
-        },
-        KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-            // Switch to Settings tab
-            app.selected_tab = 1;
-            app.selected_setting = 0;
-            app.screen = Screen::Settings;
-        }
-        KeyCode::Char('q') => app.running = false,
-        KeyCode::Esc => app.screen = Screen::RegionSelect,
-        _ => {}
+    pub fn get_kms_key_detail(key_id: &str) -> Option<aws_cli::KmsKeyDetail> {
+        Some(aws_cli::KmsKeyDetail {
+            name: key_id.to_string(),
+            key_id: key_id.to_string(),
+            arn: format!("arn:aws:kms:ap-northeast-2:123456789012:key/{}", key_id),
+            aliases: vec![],
+            description: String::new(),
+            state: "Enabled".to_string(),
+            key_spec: "SYMMETRIC_DEFAULT".to_string(),
+            key_usage: "ENCRYPT_DECRYPT".to_string(),
+            origin: "AWS_KMS".to_string(),
+            multi_region: false,
+            created: String::new(),
+            deletion_date: None,
+            rotation_enabled: Some(true),
+            rotation_period_days: Some(365),
+            next_rotation: None,
+            policy_statements: vec![],
+            grants: vec![],
+            tags: vec![],
+        })
     }
-}
 
-fn handle_ec2_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.instances.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.instances.len() {
-                let inst = &app.instances[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::Ec2,
-                        inst.id.clone(),
-                        inst.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadEc2Detail(inst.id.clone()));
-                }
-            }
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshEc2);
-        }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn list_secrets() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf",
+            "secret-test",
+        )]
     }
-}
 
-fn handle_vpc_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.vpcs.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.vpcs.len() {
-                let vpc = &app.vpcs[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::Network,
-                        vpc.id.clone(),
-                        vpc.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadVpcDetail(vpc.id.clone(), 0));
-                }
-            }
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshVpc);
-        }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn get_secret_detail(arn: &str) -> Option<aws_cli::SecretDetail> {
+        let name = Arn::parse(arn).map_or(arn, |parsed| parsed.resource_name());
+        Some(aws_cli::SecretDetail {
+            name: name.to_string(),
+            arn: arn.to_string(),
+            description: String::new(),
+            kms_key_id: None,
+            rotation_enabled: false,
+            rotation_schedule: None,
+            rotation_window: None,
+            rotation_lambda: None,
+            last_rotated: String::new(),
+            next_rotation: String::new(),
+            last_changed: String::new(),
+            last_accessed: String::new(),
+            created: String::new(),
+            deletion_date: None,
+            owning_service: None,
+            primary_region: None,
+            replicas: vec![],
+            tags: vec![],
+        })
     }
-}
 
-fn handle_security_group_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.security_groups.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.security_groups.len() {
-                let sg = &app.security_groups[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::SecurityGroup,
-                        sg.id.clone(),
-                        sg.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadSecurityGroupDetail(sg.id.clone()));
-                }
-            }
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshSecurityGroup);
-        }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn list_parameter_paths() -> Vec<aws_cli::AwsResource> {
+        vec![resource("/ssm-test", "/ssm-test")]
     }
-}
 
-fn handle_load_balancer_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.load_balancers.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.load_balancers.len() {
-                let lb = &app.load_balancers[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::LoadBalancer,
-                        lb.id.clone(),
-                        lb.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadLoadBalancerDetail(lb.id.clone()));
-                }
-            }
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshLoadBalancer);
-        }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn get_parameter_path_detail(path: &str) -> Option<aws_cli::SsmParameterPathDetail> {
+        Some(aws_cli::SsmParameterPathDetail {
+            path: path.to_string(),
+            parameters: vec![],
+        })
     }
-}
 
-fn handle_ecr_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.ecr_repositories.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.ecr_repositories.len() {
-                let repo = &app.ecr_repositories[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::Ecr,
-                        repo.id.clone(),
-                        repo.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadEcrDetail(repo.id.clone()));
-                }
-            }
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshEcr);
-        }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+    pub fn list_file_systems() -> Vec<aws_cli::AwsResource> {
+        vec![resource("fs-test", "efs-test")]
     }
-}
 
-fn handle_preview(app: &mut App, key: KeyEvent) {
-    let content_lines = app.preview_content.lines().count() as u16;
+    pub fn get_efs_detail(file_system_id: &str) -> Option<aws_cli::EfsDetail> {
+        Some(aws_cli::EfsDetail {
+            name: file_system_id.to_string(),
+            file_system_id: file_system_id.to_string(),
+            arn: String::new(),
+            state: "available".to_string(),
+            performance_mode: "generalPurpose".to_string(),
+            throughput_mode: "elastic".to_string(),
+            provisioned_throughput_mibps: None,
+            encrypted: true,
+            kms_key_id: None,
+            availability_zone: None,
+            size_bytes: 0,
+            created: String::new(),
+            transition_to_ia: None,
+            transition_to_archive: None,
+            transition_to_primary: None,
+            mount_targets: vec![],
+            tags: vec![],
+        })
+    }
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.preview_scroll = app.preview_scroll.saturating_sub(1);
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.preview_scroll = app
-                .preview_scroll
-                .saturating_add(1)
-                .min(content_lines.saturating_sub(1));
-        }
-        KeyCode::PageUp => {
-            app.preview_scroll = app.preview_scroll.saturating_sub(20);
-        }
-        KeyCode::PageDown => {
-            app.preview_scroll = app
-                .preview_scroll
-                .saturating_add(20)
-                .min(content_lines.saturating_sub(1));
-        }
-        KeyCode::Home => {
-            app.preview_scroll = 0;
-        }
-        KeyCode::End => {
-            app.preview_scroll = content_lines.saturating_sub(1);
-        }
-        KeyCode::Enter | KeyCode::Char('s') => {
-            let _ = app.save_file();
-        }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshPreview);
-        }
-        KeyCode::Char('a') => {
-            // 블루프린트 모드일 때만 리소스 추가
-            if app.blueprint_mode
-                && let (Some(resource_type), Some((resource_id, resource_name))) = (
-                    app.get_current_resource_type(),
-                    app.get_current_resource_info(),
-                )
-            {
-                let region = app.get_current_region();
-                let resource = BlueprintResource {
-                    resource_type,
-                    region,
-                    resource_id,
-                    resource_name,
-                };
-                app.add_resource_to_current_blueprint(resource);
+    pub fn list_apis() -> Vec<aws_cli::AwsResource> {
+        vec![resource("api-test", "api-gateway-test")]
+    }
 
-                // 리소스 정리 후 블루프린트 상세로 돌아가기
-                app.ec2_detail = None;
-                app.network_detail = None;
-                app.sg_detail = None;
-                app.lb_detail = None;
-                app.ecr_detail = None;
-                app.asg_detail = None;
-                app.preview_scroll = 0;
-                app.screen = Screen::BlueprintDetail;
-            }
-        }
-        KeyCode::Esc => {
-            app.preview_scroll = 0;
-            if app.blueprint_mode {
-                // 블루프린트 모드: 블루프린트 상세로 돌아가기
-                app.ec2_detail = None;
-                app.network_detail = None;
-                app.sg_detail = None;
-                app.lb_detail = None;
-                app.ecr_detail = None;
-                app.asg_detail = None;
-                app.screen = Screen::BlueprintDetail;
-            } else if app.ec2_detail.is_some() {
-                app.ec2_detail = None;
-                app.screen = Screen::Ec2Select;
-            } else if app.network_detail.is_some() {
-                app.network_detail = None;
-                app.screen = Screen::VpcSelect;
-            } else if app.sg_detail.is_some() {
-                app.sg_detail = None;
-                app.screen = Screen::SecurityGroupSelect;
-            } else if app.lb_detail.is_some() {
-                app.lb_detail = None;
-                app.screen = Screen::LoadBalancerSelect;
-            } else if app.ecr_detail.is_some() {
-                app.ecr_detail = None;
-                app.screen = Screen::EcrSelect;
-            } else if app.asg_detail.is_some() {
-                app.asg_detail = None;
-                app.screen = Screen::AsgSelect;
-            } else {
-                app.screen = Screen::ServiceSelect;
-            }
+    pub fn get_api_gateway_detail(api_id: &str) -> Option<aws_cli::ApiGatewayDetail> {
+        Some(aws_cli::ApiGatewayDetail {
+            name: api_id.to_string(),
+            id: api_id.to_string(),
+            protocol: "HTTP".to_string(),
+            endpoint_type: "REGIONAL".to_string(),
+            endpoint: String::new(),
+            disable_execute_api_endpoint: false,
+            description: String::new(),
+            created: String::new(),
+            stages: vec![],
+            routes: vec![],
+            authorizers: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_beanstalk_environments() -> Vec<aws_cli::AwsResource> {
+        vec![resource("e-test", "beanstalk-env-test")]
+    }
+
+    pub fn get_elastic_beanstalk_detail(
+        environment_id: &str,
+    ) -> Option<aws_cli::ElasticBeanstalkDetail> {
+        Some(aws_cli::ElasticBeanstalkDetail {
+            name: environment_id.to_string(),
+            id: environment_id.to_string(),
+            arn: String::new(),
+            application: "test-app".to_string(),
+            description: String::new(),
+            status: "Ready".to_string(),
+            health: "Green".to_string(),
+            health_status: String::new(),
+            tier: "WebServer/Standard".to_string(),
+            platform: String::new(),
+            version_label: String::new(),
+            cname: String::new(),
+            endpoint_url: String::new(),
+            created: String::new(),
+            updated: String::new(),
+            option_settings: vec![],
+            option_count: 0,
+            env_var_keys: vec![],
+            resources: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_state_machines() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test",
+            "sfn-test",
+        )]
+    }
+
+    pub fn get_state_machine_detail(
+        state_machine_arn: &str,
+    ) -> Option<aws_cli::StateMachineDetail> {
+        Some(aws_cli::StateMachineDetail {
+            name: "sfn-test".to_string(),
+            arn: state_machine_arn.to_string(),
+            machine_type: "STANDARD".to_string(),
+            status: "ACTIVE".to_string(),
+            role_arn: String::new(),
+            description: String::new(),
+            created: String::new(),
+            logging_level: "OFF".to_string(),
+            include_execution_data: false,
+            log_destinations: vec![],
+            tracing_enabled: false,
+            kms_key_id: None,
+            start_at: String::new(),
+            state_count: 0,
+            definition: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_kinesis_streams() -> Vec<aws_cli::AwsResource> {
+        vec![resource("kinesis-test", "kinesis-test")]
+    }
+
+    pub fn get_kinesis_stream_detail(stream_name: &str) -> Option<aws_cli::KinesisStreamDetail> {
+        Some(aws_cli::KinesisStreamDetail {
+            name: stream_name.to_string(),
+            arn: "arn:aws:kinesis:ap-northeast-2:123456789012:stream/kinesis-test".to_string(),
+            status: "ACTIVE".to_string(),
+            stream_mode: "ON_DEMAND".to_string(),
+            open_shard_count: 4,
+            retention_hours: 24,
+            kms_key_id: None,
+            shard_level_metrics: vec![],
+            created: String::new(),
+            consumers: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_msk_clusters() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1",
+            "msk-test",
+        )]
+    }
+
+    pub fn get_msk_cluster_detail(cluster_arn: &str) -> Option<aws_cli::MskClusterDetail> {
+        Some(aws_cli::MskClusterDetail {
+            name: "msk-test".to_string(),
+            arn: cluster_arn.to_string(),
+            state: "ACTIVE".to_string(),
+            kafka_version: "3.6.0".to_string(),
+            broker_count: 3,
+            instance_type: "kafka.m5.large".to_string(),
+            storage_mode: String::new(),
+            volume_size_gib: None,
+            provisioned_throughput: None,
+            client_broker_encryption: "TLS".to_string(),
+            in_cluster_encryption: true,
+            kms_key_id: None,
+            enhanced_monitoring: String::new(),
+            client_subnets: vec![],
+            security_groups: vec![],
+            created: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_opensearch_domains() -> Vec<aws_cli::AwsResource> {
+        vec![resource("opensearch-test", "opensearch-test")]
+    }
+
+    pub fn get_opensearch_domain_detail(
+        domain_name: &str,
+    ) -> Option<aws_cli::OpenSearchDomainDetail> {
+        Some(aws_cli::OpenSearchDomainDetail {
+            name: domain_name.to_string(),
+            arn: format!(
+                "arn:aws:es:ap-northeast-2:123456789012:domain/{}",
+                domain_name
+            ),
+            domain_id: format!("123456789012/{}", domain_name),
+            engine_version: "OpenSearch_2.11".to_string(),
+            state: "Active".to_string(),
+            instance_type: "r6g.large.search".to_string(),
+            instance_count: 3,
+            dedicated_master_type: None,
+            dedicated_master_count: 0,
+            zone_awareness_azs: None,
+            warm_type: None,
+            warm_count: 0,
+            volume_type: None,
+            volume_size_gib: None,
+            iops: None,
+            throughput: None,
+            endpoints: vec![],
+            vpc_id: None,
+            subnets: vec![],
+            security_groups: vec![],
+            vpc_endpoints: vec![],
+            enforce_https: true,
+            tls_security_policy: String::new(),
+            node_to_node_encryption: true,
+            encryption_at_rest: true,
+            kms_key_id: None,
+            access_policy: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_redshift_clusters() -> Vec<aws_cli::AwsResource> {
+        vec![resource("redshift-test", "redshift-test")]
+    }
+
+    pub fn get_redshift_cluster_detail(
+        cluster_identifier: &str,
+    ) -> Option<aws_cli::RedshiftClusterDetail> {
+        Some(aws_cli::RedshiftClusterDetail {
+            identifier: cluster_identifier.to_string(),
+            namespace_arn: String::new(),
+            status: "available".to_string(),
+            node_type: "ra3.xlplus".to_string(),
+            node_count: 2,
+            version: "1.0".to_string(),
+            db_name: "dev".to_string(),
+            master_username: "awsuser".to_string(),
+            endpoint: None,
+            vpc_id: None,
+            subnet_group: String::new(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            security_groups: vec![],
+            publicly_accessible: false,
+            enhanced_vpc_routing: false,
+            multi_az: String::new(),
+            encrypted: true,
+            kms_key_id: None,
+            automated_snapshot_retention: 1,
+            manual_snapshot_retention: -1,
+            snapshot_schedule: None,
+            next_snapshot: String::new(),
+            snapshot_copy: None,
+            maintenance_window: String::new(),
+            iam_roles: vec![],
+            created: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_transit_gateways() -> Vec<aws_cli::AwsResource> {
+        vec![resource("tgw-test", "tgw-test")]
+    }
+
+    pub fn get_transit_gateway_detail(tgw_id: &str) -> Option<aws_cli::TransitGatewayDetail> {
+        Some(aws_cli::TransitGatewayDetail {
+            name: "tgw-test".to_string(),
+            id: tgw_id.to_string(),
+            state: "available".to_string(),
+            owner_id: String::new(),
+            description: String::new(),
+            amazon_side_asn: Some(64512),
+            cidr_blocks: vec![],
+            dns_support: "enable".to_string(),
+            vpn_ecmp_support: "enable".to_string(),
+            auto_accept_shared_attachments: "disable".to_string(),
+            association_default_route_table: None,
+            propagation_default_route_table: None,
+            created: String::new(),
+            attachments: vec![],
+            route_tables: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_vpc_peering_connections() -> Vec<aws_cli::AwsResource> {
+        vec![resource("pcx-test", "pcx-test")]
+    }
+
+    pub fn get_vpc_peering_detail(peering_id: &str) -> Option<aws_cli::VpcPeeringDetail> {
+        Some(aws_cli::VpcPeeringDetail {
+            name: "pcx-test".to_string(),
+            id: peering_id.to_string(),
+            status: "active".to_string(),
+            status_message: String::new(),
+            requester: aws_cli::VpcPeeringVpcInfo {
+                vpc_id: "vpc-test".to_string(),
+                owner_id: String::new(),
+                region: String::new(),
+                cidr_blocks: vec!["10.0.0.0/16".to_string()],
+                allow_dns_resolution_from_remote_vpc: Some(false),
+            },
+            accepter: aws_cli::VpcPeeringVpcInfo {
+                vpc_id: "vpc-peer".to_string(),
+                owner_id: String::new(),
+                region: String::new(),
+                cidr_blocks: vec!["10.1.0.0/16".to_string()],
+                allow_dns_resolution_from_remote_vpc: Some(false),
+            },
+            expiration: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_vpn_resources() -> Vec<aws_cli::AwsResource> {
+        vec![resource("vpn-test", "vpn-test")]
+    }
+
+    pub fn get_vpn_detail(vpn_id: &str) -> Option<aws_cli::VpnDetail> {
+        Some(aws_cli::VpnDetail {
+            name: "vpn-test".to_string(),
+            id: vpn_id.to_string(),
+            state: "available".to_string(),
+            tags: vec![],
+            resource: aws_cli::VpnResource::Connection(Box::new(aws_cli::VpnConnectionInfo {
+                connection_type: "ipsec.1".to_string(),
+                customer_gateway_id: "cgw-test".to_string(),
+                vpn_gateway_id: Some("vgw-test".to_string()),
+                transit_gateway_id: None,
+                static_routes_only: true,
+                acceleration: false,
+                local_network_cidr: String::new(),
+                remote_network_cidr: String::new(),
+                tunnels: vec![],
+                routes: vec![],
+                customer_gateway: None,
+                vpn_gateway: None,
+            })),
+        })
+    }
+
+    pub fn list_direct_connect_resources() -> Vec<aws_cli::AwsResource> {
+        vec![resource("dxcon-test", "dxcon-test")]
+    }
+
+    pub fn get_direct_connect_detail(resource_id: &str) -> Option<aws_cli::DirectConnectDetail> {
+        Some(aws_cli::DirectConnectDetail {
+            name: "dxcon-test".to_string(),
+            id: resource_id.to_string(),
+            state: "available".to_string(),
+            owner_account: String::new(),
+            tags: vec![],
+            resource: aws_cli::DirectConnectResource::Connection(
+                aws_cli::DirectConnectConnectionInfo {
+                    location: "LGSN1".to_string(),
+                    bandwidth: "10Gbps".to_string(),
+                    vlan: None,
+                    partner_name: String::new(),
+                    provider_name: String::new(),
+                    aws_device: String::new(),
+                    lag_id: None,
+                    jumbo_frame_capable: false,
+                    mac_sec: None,
+                    virtual_interfaces: vec![],
+                },
+            ),
+        })
+    }
+
+    pub fn list_waf_web_acls() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4",
+            "prod-web",
+        )]
+    }
+
+    pub fn get_waf_web_acl_detail(web_acl_arn: &str) -> Option<aws_cli::WafWebAclDetail> {
+        Some(aws_cli::WafWebAclDetail {
+            name: "prod-web".to_string(),
+            id: "a1b2c3d4".to_string(),
+            arn: web_acl_arn.to_string(),
+            scope: "REGIONAL".to_string(),
+            region: "ap-northeast-2".to_string(),
+            description: String::new(),
+            default_action: "Allow".to_string(),
+            capacity: 700,
+            metric_name: "prod-web".to_string(),
+            sampled_requests: true,
+            rules: vec![],
+            associations: vec![],
+        })
+    }
+
+    pub fn list_acm_certificates() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "example.com",
+        )]
+    }
+
+    pub fn get_acm_certificate_detail(
+        certificate_arn: &str,
+    ) -> Option<aws_cli::AcmCertificateDetail> {
+        Some(aws_cli::AcmCertificateDetail {
+            name: "example.com".to_string(),
+            arn: certificate_arn.to_string(),
+            subject_alternative_names: vec!["example.com".to_string()],
+            status: "ISSUED".to_string(),
+            certificate_type: "AMAZON_ISSUED".to_string(),
+            validation_method: "DNS".to_string(),
+            key_algorithm: "RSA-2048".to_string(),
+            issuer: "Amazon".to_string(),
+            not_before: "2026-01-01T00:00:00Z".to_string(),
+            not_after: "2027-01-30T23:59:59Z".to_string(),
+            days_until_expiry: Some(107),
+            renewal_eligibility: "ELIGIBLE".to_string(),
+            renewal_status: String::new(),
+            in_use_by: vec![],
+            domain_validations: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_cloudformation_stacks() -> Vec<aws_cli::AwsResource> {
+        vec![resource("checkout", "checkout")]
+    }
+
+    pub fn get_cloudformation_stack_detail(
+        stack_name: &str,
+    ) -> Option<aws_cli::CloudFormationStackDetail> {
+        Some(aws_cli::CloudFormationStackDetail {
+            name: stack_name.to_string(),
+            stack_id: format!(
+                "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/{}/1a2b3c4d",
+                stack_name
+            ),
+            status: "CREATE_COMPLETE".to_string(),
+            status_reason: String::new(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_updated: String::new(),
+            termination_protection: false,
+            drift_status: "NOT_CHECKED".to_string(),
+            parent_id: String::new(),
+            parameters: vec![],
+            outputs: vec![],
+            resources: list_stack_resources(stack_name).unwrap_or_default(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_iam_entities() -> Vec<aws_cli::AwsResource> {
+        vec![resource(
+            "arn:aws:iam::123456789012:role/app-role",
+            "app-role",
+        )]
+    }
+
+    pub fn get_iam_entity_detail(arn: &str) -> Option<aws_cli::IamEntityDetail> {
+        Some(aws_cli::IamEntityDetail {
+            kind: "Role".to_string(),
+            name: Arn::parse(arn)
+                .map_or(arn, |parsed| parsed.resource_name())
+                .to_string(),
+            arn: arn.to_string(),
+            path: "/".to_string(),
+            description: String::new(),
+            created: "2026-01-01T00:00:00Z".to_string(),
+            last_used: String::new(),
+            permissions_boundary: String::new(),
+            trust_policy: String::new(),
+            attached_policies: vec![],
+            inline_policies: vec![],
+            groups: vec![],
+            members: vec![],
+            access_keys: vec![],
+            default_version: String::new(),
+            policy_document: String::new(),
+            attachment_count: None,
+            tags: vec![],
+        })
+    }
+
+    pub fn list_vpc_endpoints() -> Vec<aws_cli::AwsResource> {
+        vec![resource("vpce-0a1b2c3d4e5f60718", "s3-gateway")]
+    }
+
+    pub fn get_vpc_endpoint_detail(endpoint_id: &str) -> Option<aws_cli::VpcEndpointDetail> {
+        Some(aws_cli::VpcEndpointDetail {
+            name: "s3-gateway".to_string(),
+            id: endpoint_id.to_string(),
+            vpc_id: "vpc-1111aaaa".to_string(),
+            service_name: "com.amazonaws.ap-northeast-2.s3".to_string(),
+            endpoint_type: "Gateway".to_string(),
+            state: "available".to_string(),
+            subnet_ids: vec![],
+            security_groups: vec![],
+            route_table_ids: vec!["rtb-1234".to_string()],
+            private_dns_enabled: false,
+            dns_names: vec![],
+            policy: String::new(),
+            created: "2026-03-02T01:02:03.000Z".to_string(),
+            owner_id: "123456789012".to_string(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_network_interfaces() -> Vec<aws_cli::AwsResource> {
+        vec![resource("eni-0a1b2c3d4e5f60718", "old-db")]
+    }
+
+    pub fn get_network_interface_detail(
+        interface_id: &str,
+    ) -> Option<aws_cli::NetworkInterfaceDetail> {
+        Some(aws_cli::NetworkInterfaceDetail {
+            name: "old-db".to_string(),
+            id: interface_id.to_string(),
+            description: String::new(),
+            interface_type: "interface".to_string(),
+            status: "available".to_string(),
+            vpc_id: "vpc-1111aaaa".to_string(),
+            subnet_id: "subnet-1111aaaa".to_string(),
+            availability_zone: "ap-northeast-2a".to_string(),
+            mac_address: "02:ab:cd:ef:01:02".to_string(),
+            ips: vec![],
+            security_groups: vec![],
+            attachment: None,
+            requester_managed: false,
+            requester_id: String::new(),
+            owner_id: "123456789012".to_string(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_launch_templates() -> Vec<aws_cli::AwsResource> {
+        vec![resource("lt-0a1b2c3d4e5f60718", "web")]
+    }
+
+    pub fn get_launch_template_detail(template_id: &str) -> Option<aws_cli::LaunchTemplateDetail> {
+        Some(aws_cli::LaunchTemplateDetail {
+            name: "web".to_string(),
+            id: template_id.to_string(),
+            default_version: 2,
+            latest_version: 3,
+            created: String::new(),
+            created_by: String::new(),
+            versions: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_amis() -> Vec<aws_cli::AwsResource> {
+        vec![resource("ami-0a1b2c3d4e5f60718", "web-2026-10")]
+    }
+
+    pub fn get_ami_detail(image_id: &str) -> Option<aws_cli::AmiDetail> {
+        Some(aws_cli::AmiDetail {
+            id: image_id.to_string(),
+            name: "web-2026-10".to_string(),
+            description: String::new(),
+            state: "available".to_string(),
+            created: String::new(),
+            source_instance_id: String::new(),
+            architecture: String::new(),
+            platform: String::new(),
+            root_device: String::new(),
+            public: false,
+            deprecation_time: String::new(),
+            block_devices: vec![],
+            launch_permissions: None,
+            tags: vec![],
+        })
+    }
+
+    pub fn list_ebs_resources() -> Vec<aws_cli::AwsResource> {
+        vec![resource("vol-0123", "data")]
+    }
+
+    pub fn get_ebs_detail(ebs_id: &str) -> Option<aws_cli::EbsDetail> {
+        Some(aws_cli::EbsDetail {
+            name: "data".to_string(),
+            id: ebs_id.to_string(),
+            state: "in-use".to_string(),
+            size_gb: 100,
+            encrypted: true,
+            kms_key_id: String::new(),
+            tags: vec![],
+            resource: aws_cli::EbsResource::Volume(aws_cli::EbsVolumeInfo {
+                volume_type: "gp3".to_string(),
+                iops: Some(3000),
+                throughput: Some(125),
+                availability_zone: "ap-northeast-2a".to_string(),
+                snapshot_id: String::new(),
+                created: String::new(),
+                multi_attach: false,
+                attachments: vec![],
+            }),
+        })
+    }
+
+    pub fn list_key_pairs() -> Vec<aws_cli::AwsResource> {
+        vec![resource("key-0123", "deploy")]
+    }
+
+    pub fn get_key_pair_detail(key_pair_id: &str) -> Option<aws_cli::KeyPairDetail> {
+        Some(aws_cli::KeyPairDetail {
+            id: key_pair_id.to_string(),
+            name: "deploy".to_string(),
+            fingerprint: "SHA256:abcd".to_string(),
+            key_type: "ed25519".to_string(),
+            created: String::new(),
+            public_key: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_event_buses() -> Vec<aws_cli::AwsResource> {
+        vec![resource("default", "default")]
+    }
+
+    pub fn get_event_bus_detail(event_bus_name: &str) -> Option<aws_cli::EventBusDetail> {
+        Some(aws_cli::EventBusDetail {
+            name: event_bus_name.to_string(),
+            arn: format!(
+                "arn:aws:events:ap-northeast-2:123456789012:event-bus/{}",
+                event_bus_name
+            ),
+            description: String::new(),
+            kms_key_id: None,
+            created: String::new(),
+            rules: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_cognito_pools() -> Vec<aws_cli::AwsResource> {
+        vec![resource("customers", "ap-northeast-2_AbCdEf123")]
+    }
+
+    pub fn get_cognito_pool_detail(pool_id: &str) -> Option<aws_cli::CognitoPoolDetail> {
+        Some(aws_cli::CognitoPoolDetail {
+            id: pool_id.to_string(),
+            name: "customers".to_string(),
+            arn: String::new(),
+            created: String::new(),
+            pool: aws_cli::CognitoPool::User(aws_cli::UserPoolDetail {
+                tier: String::new(),
+                estimated_users: 0,
+                sign_in_attributes: vec![],
+                deletion_protection: String::new(),
+                mfa: "OFF".to_string(),
+                mfa_methods: vec![],
+                domains: vec![],
+                app_clients: vec![],
+            }),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_glue_resources() -> Vec<aws_cli::AwsResource> {
+        vec![resource("sales", "database/sales")]
+    }
+
+    pub fn get_glue_detail(id: &str) -> Option<aws_cli::GlueDetail> {
+        Some(aws_cli::GlueDetail {
+            id: id.to_string(),
+            name: id.rsplit('/').next().unwrap_or(id).to_string(),
+            arn: String::new(),
+            description: String::new(),
+            created: String::new(),
+            resource: aws_cli::GlueResource::Database(aws_cli::GlueDatabaseDetail {
+                location_uri: String::new(),
+                tables: vec![],
+            }),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_athena_workgroups() -> Vec<aws_cli::AwsResource> {
+        vec![resource("primary", "primary")]
+    }
+
+    pub fn get_athena_workgroup_detail(name: &str) -> Option<aws_cli::AthenaWorkGroupDetail> {
+        Some(aws_cli::AthenaWorkGroupDetail {
+            name: name.to_string(),
+            arn: String::new(),
+            state: "ENABLED".to_string(),
+            description: String::new(),
+            created: String::new(),
+            engine_version: String::new(),
+            output_location: String::new(),
+            encryption: String::new(),
+            kms_key: String::new(),
+            enforce_configuration: false,
+            publish_metrics: false,
+            bytes_scanned_cutoff: None,
+            requester_pays: false,
+            execution_role: String::new(),
+            tags: vec![],
+        })
+    }
+
+    pub fn list_backup_plans() -> Vec<aws_cli::AwsResource> {
+        vec![resource("daily-35d", "8a2f1c4e")]
+    }
+
+    pub fn get_backup_plan_detail(plan_id: &str) -> Option<aws_cli::BackupPlanDetail> {
+        Some(aws_cli::BackupPlanDetail {
+            id: plan_id.to_string(),
+            name: "daily-35d".to_string(),
+            arn: String::new(),
+            version_id: String::new(),
+            created: String::new(),
+            last_execution: String::new(),
+            advanced_settings: vec![],
+            rules: vec![],
+            selections: vec![],
+            protected_resources: vec![],
+            tags: vec![],
+        })
+    }
+
+    pub fn list_stack_resources(stack_name: &str) -> Result<Vec<aws_cli::StackResource>, String> {
+        if stack_name == "missing-stack" {
+            return Err(format!("Stack with id {} does not exist", stack_name));
         }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+        Ok(vec![
+            aws_cli::StackResource {
+                logical_id: "WebServer".to_string(),
+                physical_id: "i-cfn".to_string(),
+                resource_type: "AWS::EC2::Instance".to_string(),
+                status: "CREATE_COMPLETE".to_string(),
+            },
+            aws_cli::StackResource {
+                logical_id: "Queue".to_string(),
+                physical_id: "https://sqs.example.com/queue".to_string(),
+                resource_type: "AWS::SQS::Queue".to_string(),
+                status: "CREATE_COMPLETE".to_string(),
+            },
+        ])
     }
-}
 
-fn handle_settings(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_setting > 0 {
-                app.selected_setting -= 1;
-            }
+    pub fn get_resources_by_tags(
+        filters: &[(String, Vec<String>)],
+    ) -> Result<Vec<aws_cli::TaggedResource>, String> {
+        let matches_checkout = filters
+            .iter()
+            .any(|(key, values)| key == "Project" && values.iter().any(|v| v == "checkout"));
+        if !matches_checkout {
+            return Ok(Vec::new());
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            // Currently only 1 setting (language), so max index is 0
-            // Will be expanded when more settings are added
+        Ok(vec![
+            aws_cli::TaggedResource {
+                arn: "arn:aws:ec2:ap-northeast-2:123456789012:instance/i-tagged".to_string(),
+                name: Some("checkout-web".to_string()),
+            },
+            aws_cli::TaggedResource {
+                arn: "arn:aws:ecr:ap-northeast-2:123456789012:repository/checkout".to_string(),
+                name: None,
+            },
+        ])
+    }
+
+    pub fn indexed_name(id: &str) -> Option<String> {
+        (id == "i-named").then(|| "web-1".to_string())
+    }
+
+    pub fn set_name_tag(_kind: &str, id: &str, _name: &str) -> Result<(), String> {
+        if id == "i-denied" {
+            return Err("AccessDenied".to_string());
         }
-        KeyCode::Enter | KeyCode::Char(' ') => {
-            // Toggle current setting
-            if app.selected_setting == 0 {
-                app.toggle_language();
+        Ok(())
+    }
+
+    pub fn run_aws_cli(args: &[&str]) -> Option<String> {
+        match args {
+            ["ec2", "describe-vpcs"] => {
+                Some(r#"{"Vpcs":[{"VpcId":"vpc-a","CidrBlock":"10.0.0.0/16"}]}"#.to_string())
             }
+            _ => None,
         }
-        KeyCode::Left | KeyCode::Char('h') | KeyCode::Tab | KeyCode::Esc => {
-            // Switch back to Main tab
-            app.selected_tab = 0;
-            app.screen = Screen::BlueprintSelect;
-        }
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
     }
 }
 
-fn handle_asg_select(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.selected_index < app.auto_scaling_groups.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
-        }
-        KeyCode::Enter => {
-            if app.selected_index < app.auto_scaling_groups.len() {
-                let asg = &app.auto_scaling_groups[app.selected_index];
-                if app.blueprint_mode {
-                    add_resource_to_blueprint(
-                        app,
-                        ResourceType::Asg,
-                        asg.id.clone(),
-                        asg.name.clone(),
-                    );
-                } else {
-                    start_loading(app, LoadingTask::LoadAsgDetail(asg.name.clone()));
-                }
-            }
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    // 목록 화면을 떠나기 전에 선택한 행을 서비스별로 기억
+    app.remember_list_selection();
+
+    // 모든 화면 공통: F1로 튜토리얼 시작/종료 (매크로에 기록하지 않음)
+    if key.code == tutorial::TOGGLE_KEY {
+        app.toggle_tutorial();
+        return;
+    }
+
+    // 모든 화면 공통: Ctrl+R/Ctrl+E 키 매크로 기록/재생
+    if handle_macro_key(app, key) {
+        return;
+    }
+
+    // 목록/미리보기 공통: 숫자 접두사, gg/G, Ctrl-d/Ctrl-u 이동
+    if handle_motion_key(app, key) {
+        return;
+    }
+
+    // 목록 화면 공통: c로 비교할 리소스 두 개를 차례로 지정
+    if key.code == KeyCode::Char('c') && app.current_resource_list().is_some() {
+        if app.mark_for_compare() {
+            app.compare_return_screen = app.screen.clone();
+            start_loading(app, LoadingTask::LoadCompare);
         }
-        KeyCode::Char('r') => {
-            start_loading(app, LoadingTask::RefreshAsg);
+        return;
+    }
+
+    match &app.screen {
+        Screen::Login => handle_login(app, key),
+        Screen::BlueprintSelect => handle_blueprint_select(app, key),
+        Screen::BlueprintDetail => handle_blueprint_detail(app, key),
+        Screen::BlueprintNameInput => handle_blueprint_name_input(app, key),
+        Screen::BlueprintImportInput => handle_blueprint_import_input(app, key),
+        Screen::BlueprintPreview => handle_blueprint_preview(app, key),
+        Screen::DocumentSearch => handle_document_search(app, key),
+        Screen::Compare => handle_compare(app, key),
+        Screen::RegionSelect => handle_region_select(app, key),
+        Screen::ServiceSelect => handle_service_select(app, key),
+        Screen::Ec2Select => handle_ec2_select(app, key),
+        Screen::VpcSelect => handle_vpc_select(app, key),
+        Screen::SecurityGroupSelect => handle_security_group_select(app, key),
+        Screen::LoadBalancerSelect => handle_load_balancer_select(app, key),
+        Screen::EcrSelect => handle_ecr_select(app, key),
+        Screen::AsgSelect => handle_asg_select(app, key),
+        Screen::RdsSelect => handle_rds_select(app, key),
+        Screen::LambdaSelect => handle_lambda_select(app, key),
+        Screen::DynamoDbSelect => handle_dynamodb_select(app, key),
+        Screen::CloudFrontSelect => handle_cloudfront_select(app, key),
+        Screen::Route53Select => handle_route53_select(app, key),
+        Screen::EksSelect => handle_eks_select(app, key),
+        Screen::EcsSelect => handle_ecs_select(app, key),
+        Screen::SqsSelect => handle_sqs_select(app, key),
+        Screen::SnsSelect => handle_sns_select(app, key),
+        Screen::ElastiCacheSelect => handle_elasticache_select(app, key),
+        Screen::CloudWatchAlarmSelect => handle_cloudwatch_alarm_select(app, key),
+        Screen::CloudTrailSelect => handle_cloudtrail_select(app, key),
+        Screen::KmsSelect => handle_kms_select(app, key),
+        Screen::SecretSelect => handle_secret_select(app, key),
+        Screen::SsmParameterSelect => handle_ssm_parameter_select(app, key),
+        Screen::EfsSelect => handle_efs_select(app, key),
+        Screen::ApiGatewaySelect => handle_apigateway_select(app, key),
+        Screen::ElasticBeanstalkSelect => handle_elasticbeanstalk_select(app, key),
+        Screen::StepFunctionsSelect => handle_stepfunctions_select(app, key),
+        Screen::KinesisSelect => handle_kinesis_select(app, key),
+        Screen::MskSelect => handle_msk_select(app, key),
+        Screen::OpenSearchSelect => handle_opensearch_select(app, key),
+        Screen::RedshiftSelect => handle_redshift_select(app, key),
+        Screen::TransitGatewaySelect => handle_transit_gateway_select(app, key),
+        Screen::VpcPeeringSelect => handle_vpc_peering_select(app, key),
+        Screen::VpnSelect => handle_vpn_select(app, key),
+        Screen::DirectConnectSelect => handle_direct_connect_select(app, key),
+        Screen::WafSelect => handle_waf_select(app, key),
+        Screen::AcmSelect => handle_acm_select(app, key),
+        Screen::CloudFormationSelect => handle_cloudformation_select(app, key),
+        Screen::IamSelect => handle_iam_select(app, key),
+        Screen::VpcEndpointSelect => handle_vpc_endpoint_select(app, key),
+        Screen::NetworkInterfaceSelect => handle_network_interface_select(app, key),
+        Screen::LaunchTemplateSelect => handle_launch_template_select(app, key),
+        Screen::AmiSelect => handle_ami_select(app, key),
+        Screen::EbsSelect => handle_ebs_select(app, key),
+        Screen::KeyPairSelect => handle_key_pair_select(app, key),
+        Screen::EventBridgeSelect => handle_eventbridge_select(app, key),
+        Screen::CognitoSelect => handle_cognito_select(app, key),
+        Screen::GlueSelect => handle_glue_select(app, key),
+        Screen::AthenaSelect => handle_athena_select(app, key),
+        Screen::BackupSelect => handle_backup_select(app, key),
+        Screen::QueryPlayground => handle_query_playground(app, key),
+        Screen::NameTagInput => handle_name_tag_input(app, key),
+        Screen::NameTagConfirm => handle_name_tag_confirm(app, key),
+        Screen::Preview => handle_preview(app, key),
+        Screen::Settings => handle_settings(app, key),
+    }
+}
+
+// Ctrl-d/Ctrl-u로 이동하는 줄 수 (PageUp/PageDown의 절반)
+const HALF_PAGE_LINES: usize = 10;
+
+// vim 이동 대상: 리소스 목록의 선택 위치 또는 미리보기 스크롤 (현재 위치, 전체 줄 수)
+fn motion_position(app: &App) -> Option<(usize, usize)> {
+    if let Some((_, resources)) = app.current_resource_list() {
+        return Some((app.selected_index, resources.len()));
+    }
+    if app.screen == Screen::Preview {
+        return Some((
+            app.preview_scroll as usize,
+            app.preview_text().lines().count(),
+        ));
+    }
+    None
+}
+
+// 이동 키를 처리했으면 true, 그 외 키는 숫자 접두사를 지우고 화면별 처리로 넘김
+fn handle_motion_key(app: &mut App, key: KeyEvent) -> bool {
+    let count = app.motion_count.take();
+    let pending_g = std::mem::take(&mut app.motion_pending_g);
+    let Some((position, len)) = motion_position(app) else {
+        return false;
+    };
+    let last = len.saturating_sub(1);
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    let target = match key.code {
+        // 0은 접두사가 이미 있을 때만 숫자로 취급
+        KeyCode::Char(c @ '0'..='9') if !ctrl && (c != '0' || count.is_some()) => {
+            let digit = c as usize - '0' as usize;
+            app.motion_count = Some(
+                count
+                    .unwrap_or(0)
+                    .saturating_mul(10)
+                    .saturating_add(digit)
+                    .min(99_999),
+            );
+            return true;
+        }
+        KeyCode::Char('g') if !ctrl => {
+            if !pending_g {
+                app.motion_count = count;
+                app.motion_pending_g = true;
+                return true;
+            }
+            // {count}gg: count번째 줄로 이동
+            count.map_or(0, |n| n - 1)
+        }
+        KeyCode::Char('G') if !ctrl => count.map_or(last, |n| n - 1),
+        KeyCode::Char('d') if ctrl => position.saturating_add(HALF_PAGE_LINES),
+        KeyCode::Char('u') if ctrl => position.saturating_sub(HALF_PAGE_LINES),
+        // 숫자 접두사가 없는 ↑↓/jk는 화면별 처리 그대로 사용
+        KeyCode::Down | KeyCode::Char('j') if !ctrl && count.is_some() => {
+            position.saturating_add(count.unwrap_or(1))
+        }
+        KeyCode::Up | KeyCode::Char('k') if !ctrl && count.is_some() => {
+            position.saturating_sub(count.unwrap_or(1))
+        }
+        _ => return false,
+    };
+
+    let target = target.min(last);
+    if app.screen == Screen::Preview {
+        app.preview_scroll = target.min(u16::MAX as usize) as u16;
+    } else {
+        app.selected_index = target;
+    }
+    true
+}
+
+// 매크로 키를 처리했으면 true, 아니면 기록 중인 매크로에 키를 추가하고 false
+fn handle_macro_key(app: &mut App, key: KeyEvent) -> bool {
+    if let Some(pending) = app.macros.pending.take() {
+        match (pending, key_macro::register_for(&key)) {
+            (PendingMacro::Record, Some(register)) => {
+                app.macros.start_recording(register);
+                app.message = app.i18n.macro_recording(register);
+            }
+            (PendingMacro::Replay, Some(register)) => match app.macros.register(register) {
+                Some(keys) => {
+                    app.macro_queue.extend(keys.iter().copied());
+                    app.message = app.i18n.macro_replaying(register, keys.len());
+                }
+                None => app.message = app.i18n.macro_empty(register),
+            },
+            (_, None) => app.message = app.i18n.macro_cancelled().to_string(),
+        }
+        return true;
+    }
+
+    if key_macro::is_ctrl(&key, key_macro::RECORD_KEY) {
+        match app.macros.stop_recording() {
+            Some((register, count)) => {
+                app.settings.key_macros = app.macros.to_saved();
+                app.save_settings();
+                app.message = app.i18n.macro_saved(register, count);
+            }
+            None => {
+                app.macros.pending = Some(PendingMacro::Record);
+                app.message = app.i18n.macro_register_prompt().to_string();
+            }
+        }
+        return true;
+    }
+
+    if key_macro::is_ctrl(&key, key_macro::REPLAY_KEY) {
+        // 재생한 키가 다시 기록되지 않도록 기록 중에는 재생 불가
+        if app.macros.recording_register().is_some() {
+            app.message = app.i18n.macro_replay_while_recording().to_string();
+        } else {
+            app.macros.pending = Some(PendingMacro::Replay);
+            app.message = app.i18n.macro_register_prompt().to_string();
+        }
+        return true;
+    }
+
+    app.macros.record(key);
+    false
+}
+
+pub fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    // 미리보기 화면에서만 마우스 스크롤/드래그 처리
+    match &app.screen {
+        Screen::Preview | Screen::BlueprintPreview => {
+            handle_preview_mouse(app, mouse);
+        }
+        _ => {}
+    }
+}
+
+fn handle_preview_mouse(app: &mut App, mouse: MouseEvent) {
+    let content_lines = app.preview_text().lines().count() as u16;
+
+    match mouse.kind {
+        MouseEventKind::ScrollUp => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(3);
+        }
+        MouseEventKind::ScrollDown => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(3)
+                .min(content_lines.saturating_sub(1));
+        }
+        MouseEventKind::ScrollLeft => app.scroll_preview_left(),
+        MouseEventKind::ScrollRight => app.scroll_preview_right(),
+        MouseEventKind::Down(_) => {
+            app.preview_drag_start = Some((mouse.column, mouse.row));
+        }
+        MouseEventKind::Up(_) => {
+            app.preview_drag_start = None;
+        }
+        MouseEventKind::Drag(_) => {
+            if let Some((_, start_y)) = app.preview_drag_start {
+                let current_y = mouse.row;
+                if current_y < start_y {
+                    // 드래그 위로 -> 스크롤 다운
+                    let diff = start_y - current_y;
+                    app.preview_scroll = app
+                        .preview_scroll
+                        .saturating_add(diff)
+                        .min(content_lines.saturating_sub(1));
+                } else if current_y > start_y {
+                    // 드래그 아래로 -> 스크롤 업
+                    let diff = current_y - start_y;
+                    app.preview_scroll = app.preview_scroll.saturating_sub(diff);
+                }
+                app.preview_drag_start = Some((mouse.column, mouse.row));
+            }
+        }
+        _ => {}
+    }
+}
+
+// 설정에 따라 terminated/shutting-down 인스턴스는 목록에서 제외
+fn load_instances(app: &mut App) {
+    app.instances = aws_adapter::list_instances();
+    if app.settings.hide_terminated_instances {
+        app.instances
+            .retain(|instance| !is_terminated_state(&instance.state));
+    }
+}
+
+pub fn process_loading(app: &mut App) {
+    // 작업 단위 스팬 (OTLP 내보내기 시 느린 단계 분석용)
+    let _span = tracing::info_span!("loading_task", task = ?app.loading_task).entered();
+    let list_screen = app.loading_task.list_screen();
+    // 보고 있는 목록을 새로고침하면 커서가 가리키던 리소스를 기억해 둠
+    let selected_id = list_screen
+        .as_ref()
+        .filter(|screen| **screen == app.screen)
+        .and_then(|_| app.selected_list_id());
+    let stamps_preview =
+        aws_adapter::stamp_last_modified_enabled() || age::show_resource_age_enabled();
+    let preview_before = stamps_preview.then(|| app.preview_content.clone());
+    match app.loading_task.clone() {
+        LoadingTask::RefreshEc2 => {
+            load_instances(app);
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::RefreshVpc => {
+            app.vpcs = aws_adapter::list_vpcs();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::RefreshPreview => {
+            let scroll = app.preview_scroll;
+            if app.ec2_detail.is_some() {
+                aws_adapter::clear_iam_role_cache();
+                if let Some(new_detail) = aws_adapter::refresh_instance_detail(
+                    app.instances
+                        .get(app.selected_index)
+                        .map(|i| i.id.as_str())
+                        .unwrap_or(""),
+                ) {
+                    app.preview_content = new_detail.to_markdown(app.settings.language);
+                    app.preview_filename = format!("{}.md", new_detail.name);
+                    app.ec2_detail = Some(new_detail);
+                }
+            } else if app.network_detail.is_some() {
+                if let Some(new_detail) = aws_adapter::get_network_detail(
+                    app.vpcs
+                        .get(app.selected_index)
+                        .map(|v| v.id.as_str())
+                        .unwrap_or(""),
+                ) {
+                    app.preview_content = new_detail.to_markdown(app.settings.language);
+                    app.preview_filename = format!("{}.md", new_detail.name);
+                    app.network_detail = Some(new_detail);
+                }
+            } else if app.sg_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_security_group_detail(
+                    app.security_groups
+                        .get(app.selected_index)
+                        .map(|sg| sg.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.sg_detail = Some(new_detail);
+            } else if app.lb_detail.is_some() {
+                if let Some(new_detail) = aws_adapter::get_load_balancer_detail(
+                    app.load_balancers
+                        .get(app.selected_index)
+                        .map(|lb| lb.id.as_str())
+                        .unwrap_or(""),
+                ) {
+                    app.preview_content = new_detail.to_markdown(app.settings.language);
+                    app.preview_filename = format!("{}.md", new_detail.name);
+                    app.lb_detail = Some(new_detail);
+                }
+            } else if app.ecr_detail.is_some() {
+                if let Some(new_detail) = aws_adapter::get_ecr_detail(
+                    app.ecr_repositories
+                        .get(app.selected_index)
+                        .map(|repo| repo.id.as_str())
+                        .unwrap_or(""),
+                ) {
+                    app.preview_content = new_detail.to_markdown(app.settings.language);
+                    app.preview_filename = format!("{}.md", new_detail.name);
+                    app.ecr_detail = Some(new_detail);
+                }
+            } else if app.asg_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_asg_detail(
+                    app.auto_scaling_groups
+                        .get(app.selected_index)
+                        .map(|asg| asg.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.asg_detail = Some(new_detail);
+            } else if app.rds_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_rds_detail(
+                    app.rds_databases
+                        .get(app.selected_index)
+                        .map(|db| db.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.identifier);
+                app.rds_detail = Some(new_detail);
+            } else if app.lambda_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_lambda_detail(
+                    app.lambda_functions
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.lambda_detail = Some(new_detail);
+            } else if app.dynamodb_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_dynamodb_detail(
+                    app.dynamodb_tables
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.dynamodb_detail = Some(new_detail);
+            } else if app.cloudfront_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_cloudfront_detail(
+                    app.cloudfront_distributions
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.id);
+                app.cloudfront_detail = Some(new_detail);
+            } else if app.route53_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_route53_detail(
+                    app.route53_zones
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.route53_detail = Some(new_detail);
+            } else if app.eks_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_eks_detail(
+                    app.eks_clusters
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.eks_detail = Some(new_detail);
+            } else if app.ecs_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_ecs_detail(
+                    app.ecs_clusters
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.ecs_detail = Some(new_detail);
+            } else if app.sqs_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_sqs_detail(
+                    app.sqs_queues
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.sqs_detail = Some(new_detail);
+            } else if app.sns_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_sns_detail(
+                    app.sns_topics
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.sns_detail = Some(new_detail);
+            } else if app.elasticache_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_elasticache_detail(
+                    app.elasticache_clusters
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.id);
+                app.elasticache_detail = Some(new_detail);
+            } else if app.cloudwatch_alarm_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_cloudwatch_alarm_detail(
+                    app.cloudwatch_alarms
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.cloudwatch_alarm_detail = Some(new_detail);
+            } else if app.cloudtrail_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_cloudtrail_detail(
+                    app.cloudtrail_trails
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.cloudtrail_detail = Some(new_detail);
+            } else if app.kms_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_kms_key_detail(
+                    app.kms_keys
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.kms_detail = Some(new_detail);
+            } else if app.secret_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_secret_detail(
+                    app.secrets
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name.replace('/', "_"));
+                app.secret_detail = Some(new_detail);
+            } else if app.ssm_parameter_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_parameter_path_detail(
+                    app.ssm_parameter_paths
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = ssm_parameter_filename(&new_detail.path);
+                app.ssm_parameter_detail = Some(new_detail);
+            } else if app.efs_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_efs_detail(
+                    app.efs_file_systems
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.efs_detail = Some(new_detail);
+            } else if app.api_gateway_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_api_gateway_detail(
+                    app.api_gateways
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.api_gateway_detail = Some(new_detail);
+            } else if app.elastic_beanstalk_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_elastic_beanstalk_detail(
+                    app.beanstalk_environments
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.elastic_beanstalk_detail = Some(new_detail);
+            } else if app.state_machine_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_state_machine_detail(
+                    app.state_machines
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.state_machine_detail = Some(new_detail);
+            } else if app.kinesis_stream_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_kinesis_stream_detail(
+                    app.kinesis_streams
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.kinesis_stream_detail = Some(new_detail);
+            } else if app.msk_cluster_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_msk_cluster_detail(
+                    app.msk_clusters
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.msk_cluster_detail = Some(new_detail);
+            } else if app.opensearch_domain_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_opensearch_domain_detail(
+                    app.opensearch_domains
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.opensearch_domain_detail = Some(new_detail);
+            } else if app.redshift_cluster_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_redshift_cluster_detail(
+                    app.redshift_clusters
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.identifier);
+                app.redshift_cluster_detail = Some(new_detail);
+            } else if app.transit_gateway_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_transit_gateway_detail(
+                    app.transit_gateways
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.transit_gateway_detail = Some(new_detail);
+            } else if app.vpc_peering_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_vpc_peering_detail(
+                    app.vpc_peerings
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.vpc_peering_detail = Some(new_detail);
+            } else if app.vpn_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_vpn_detail(
+                    app.vpns
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.vpn_detail = Some(new_detail);
+            } else if app.direct_connect_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_direct_connect_detail(
+                    app.direct_connect_resources
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.direct_connect_detail = Some(new_detail);
+            } else if app.waf_web_acl_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_waf_web_acl_detail(
+                    app.waf_web_acls
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.waf_web_acl_detail = Some(new_detail);
+            } else if app.acm_certificate_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_acm_certificate_detail(
+                    app.acm_certificates
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.acm_certificate_detail = Some(new_detail);
+            } else if app.cloudformation_stack_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_cloudformation_stack_detail(
+                    app.cloudformation_stacks
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.cloudformation_stack_detail = Some(new_detail);
+            } else if app.iam_entity_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_iam_entity_detail(
+                    app.iam_entities
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.iam_entity_detail = Some(new_detail);
+            } else if app.vpc_endpoint_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_vpc_endpoint_detail(
+                    app.vpc_endpoints
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.vpc_endpoint_detail = Some(new_detail);
+            } else if app.network_interface_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_network_interface_detail(
+                    app.network_interfaces
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.network_interface_detail = Some(new_detail);
+            } else if app.launch_template_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_launch_template_detail(
+                    app.launch_templates
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.launch_template_detail = Some(new_detail);
+            } else if app.ami_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_ami_detail(
+                    app.amis
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.ami_detail = Some(new_detail);
+            } else if app.ebs_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_ebs_detail(
+                    app.ebs_resources
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.ebs_detail = Some(new_detail);
+            } else if app.key_pair_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_key_pair_detail(
+                    app.key_pairs
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.key_pair_detail = Some(new_detail);
+            } else if app.event_bus_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_event_bus_detail(
+                    app.event_buses
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.event_bus_detail = Some(new_detail);
+            } else if app.cognito_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_cognito_pool_detail(
+                    app.cognito_pools
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.cognito_detail = Some(new_detail);
+            } else if app.glue_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_glue_detail(
+                    app.glue_resources
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.glue_detail = Some(new_detail);
+            } else if app.athena_workgroup_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_athena_workgroup_detail(
+                    app.athena_workgroups
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.athena_workgroup_detail = Some(new_detail);
+            } else if app.backup_plan_detail.is_some()
+                && let Some(new_detail) = aws_adapter::get_backup_plan_detail(
+                    app.backup_plans
+                        .get(app.selected_index)
+                        .map(|r| r.id.as_str())
+                        .unwrap_or(""),
+                )
+            {
+                app.preview_content = new_detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", new_detail.name);
+                app.backup_plan_detail = Some(new_detail);
+            }
+            // 원본 JSON 보기 중이면 새로 조회한 상세 정보로 다시 직렬화
+            if app.preview_raw.is_some() {
+                app.preview_raw = app.current_detail_json();
+            }
+            app.restore_preview_scroll(scroll);
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEc2 => {
+            load_instances(app);
+            app.selected_index = 0;
+            app.screen = Screen::Ec2Select;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEc2Detail(id) => {
+            aws_adapter::clear_iam_role_cache();
+            if let Some(detail) = aws_adapter::get_instance_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.ec2_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpc => {
+            app.vpcs = aws_adapter::list_vpcs();
+            app.selected_index = 0;
+            app.screen = Screen::VpcSelect;
+            finish_loading(app);
+        }
+
+        LoadingTask::LoadVpcDetail(id, step) => {
+            process_vpc_detail_step(app, &id, step);
+        }
+        LoadingTask::RefreshSecurityGroup => {
+            app.security_groups = aws_adapter::list_security_groups();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadSecurityGroup => {
+            app.security_groups = aws_adapter::list_security_groups();
+            app.selected_index = 0;
+            app.screen = Screen::SecurityGroupSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadSecurityGroupDetail(id) => {
+            if let Some(detail) = aws_adapter::get_security_group_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.sg_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshLoadBalancer => {
+            app.load_balancers = aws_adapter::list_load_balancers();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadLoadBalancer => {
+            app.load_balancers = aws_adapter::list_load_balancers();
+            app.selected_index = 0;
+            app.screen = Screen::LoadBalancerSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadLoadBalancerDetail(id) => {
+            if let Some(detail) = aws_adapter::get_load_balancer_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.lb_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEcr => {
+            app.ecr_repositories = aws_adapter::list_ecr_repositories();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEcr => {
+            app.ecr_repositories = aws_adapter::list_ecr_repositories();
+            app.selected_index = 0;
+            app.screen = Screen::EcrSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEcrDetail(id) => {
+            if let Some(detail) = aws_adapter::get_ecr_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.ecr_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshAsg => {
+            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadAsg => {
+            app.auto_scaling_groups = aws_adapter::list_auto_scaling_groups();
+            app.selected_index = 0;
+            app.screen = Screen::AsgSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadAsgDetail(name) => {
+            if let Some(detail) = aws_adapter::get_asg_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.asg_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshRds => {
+            app.rds_databases = aws_adapter::list_rds_databases();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadRds => {
+            app.rds_databases = aws_adapter::list_rds_databases();
+            app.selected_index = 0;
+            app.screen = Screen::RdsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadRdsDetail(identifier) => {
+            if let Some(detail) = aws_adapter::get_rds_detail(&identifier) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.identifier);
+                app.rds_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshLambda => {
+            app.lambda_functions = aws_adapter::list_lambda_functions();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadLambda => {
+            app.lambda_functions = aws_adapter::list_lambda_functions();
+            app.selected_index = 0;
+            app.screen = Screen::LambdaSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadLambdaDetail(name) => {
+            if let Some(detail) = aws_adapter::get_lambda_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.lambda_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::LoadCompare => {
+            let lang = app.settings.language;
+            if let (Some(base), Some(target)) = (app.compare_base.take(), app.compare_target.take())
+            {
+                match (
+                    fetch_resource_markdown(&base, lang),
+                    fetch_resource_markdown(&target, lang),
+                ) {
+                    (Some(left), Some(right)) => {
+                        let comparison = Comparison::new(base, left, target, right);
+                        app.preview_content = comparison.to_markdown(lang);
+                        app.preview_filename = comparison.filename();
+                        app.preview_scroll = 0;
+                        app.compare_show_table = false;
+                        app.comparison = Some(comparison);
+                        app.screen = Screen::Compare;
+                    }
+                    _ => app.message = app.i18n.query_failed().to_string(),
+                }
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshDynamoDb => {
+            app.dynamodb_tables = aws_adapter::list_dynamodb_tables();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadDynamoDb => {
+            app.dynamodb_tables = aws_adapter::list_dynamodb_tables();
+            app.selected_index = 0;
+            app.screen = Screen::DynamoDbSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadDynamoDbDetail(name) => {
+            if let Some(detail) = aws_adapter::get_dynamodb_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.dynamodb_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::LoadPromotionChecklist(source, target) => {
+            let lang = app.settings.language;
+            if let (Some(source), Some(target)) = (
+                app.blueprint_store.get_blueprint(source).cloned(),
+                app.blueprint_store.get_blueprint(target).cloned(),
+            ) {
+                aws_adapter::clear_iam_role_cache();
+                let checklist = PromotionChecklist::new(&source, &target, |resource| {
+                    aws_adapter::set_region(&resource.region);
+                    fetch_resource_markdown(resource, lang)
+                });
+                app.preview_content = checklist.to_markdown(lang);
+                app.preview_filename = checklist.filename();
+                app.preview_scroll = 0;
+                app.screen = Screen::BlueprintPreview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshCloudFront => {
+            app.cloudfront_distributions = aws_adapter::list_cloudfront_distributions();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudFront => {
+            app.cloudfront_distributions = aws_adapter::list_cloudfront_distributions();
+            app.selected_index = 0;
+            app.screen = Screen::CloudFrontSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudFrontDetail(id) => {
+            if let Some(detail) = aws_adapter::get_cloudfront_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.id);
+                app.cloudfront_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshRoute53 => {
+            app.route53_zones = aws_adapter::list_route53_zones();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadRoute53 => {
+            app.route53_zones = aws_adapter::list_route53_zones();
+            app.selected_index = 0;
+            app.screen = Screen::Route53Select;
+            finish_loading(app);
+        }
+        LoadingTask::LoadRoute53Detail(id) => {
+            if let Some(detail) = aws_adapter::get_route53_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.route53_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEks => {
+            app.eks_clusters = aws_adapter::list_eks_clusters();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEks => {
+            app.eks_clusters = aws_adapter::list_eks_clusters();
+            app.selected_index = 0;
+            app.screen = Screen::EksSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEksDetail(name) => {
+            if let Some(detail) = aws_adapter::get_eks_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.eks_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEcs => {
+            app.ecs_clusters = aws_adapter::list_ecs_clusters();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEcs => {
+            app.ecs_clusters = aws_adapter::list_ecs_clusters();
+            app.selected_index = 0;
+            app.screen = Screen::EcsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEcsDetail(name) => {
+            if let Some(detail) = aws_adapter::get_ecs_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.ecs_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshSqs => {
+            app.sqs_queues = aws_adapter::list_sqs_queues();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadSqs => {
+            app.sqs_queues = aws_adapter::list_sqs_queues();
+            app.selected_index = 0;
+            app.screen = Screen::SqsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadSqsDetail(name) => {
+            if let Some(detail) = aws_adapter::get_sqs_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.sqs_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshSns => {
+            app.sns_topics = aws_adapter::list_sns_topics();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadSns => {
+            app.sns_topics = aws_adapter::list_sns_topics();
+            app.selected_index = 0;
+            app.screen = Screen::SnsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadSnsDetail(arn) => {
+            if let Some(detail) = aws_adapter::get_sns_detail(&arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.sns_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshElastiCache => {
+            app.elasticache_clusters = aws_adapter::list_elasticache_clusters();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadElastiCache => {
+            app.elasticache_clusters = aws_adapter::list_elasticache_clusters();
+            app.selected_index = 0;
+            app.screen = Screen::ElastiCacheSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadElastiCacheDetail(id) => {
+            if let Some(detail) = aws_adapter::get_elasticache_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.id);
+                app.elasticache_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshCloudWatchAlarm => {
+            app.cloudwatch_alarms = aws_adapter::list_cloudwatch_alarms();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudWatchAlarm => {
+            app.cloudwatch_alarms = aws_adapter::list_cloudwatch_alarms();
+            app.selected_index = 0;
+            app.screen = Screen::CloudWatchAlarmSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudWatchAlarmDetail(name) => {
+            if let Some(detail) = aws_adapter::get_cloudwatch_alarm_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.cloudwatch_alarm_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshCloudTrail => {
+            app.cloudtrail_trails = aws_adapter::list_cloudtrail_trails();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudTrail => {
+            app.cloudtrail_trails = aws_adapter::list_cloudtrail_trails();
+            app.selected_index = 0;
+            app.screen = Screen::CloudTrailSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudTrailDetail(arn) => {
+            if let Some(detail) = aws_adapter::get_cloudtrail_detail(&arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.cloudtrail_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshKms => {
+            app.kms_keys = aws_adapter::list_kms_keys();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadKms => {
+            app.kms_keys = aws_adapter::list_kms_keys();
+            app.selected_index = 0;
+            app.screen = Screen::KmsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadKmsDetail(key_id) => {
+            if let Some(detail) = aws_adapter::get_kms_key_detail(&key_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.kms_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshSecret => {
+            app.secrets = aws_adapter::list_secrets();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadSecret => {
+            app.secrets = aws_adapter::list_secrets();
+            app.selected_index = 0;
+            app.screen = Screen::SecretSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadSecretDetail(arn) => {
+            if let Some(detail) = aws_adapter::get_secret_detail(&arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                // 비밀 이름의 경로 구분자(prod/db)는 파일명에 쓸 수 없음
+                app.preview_filename = format!("{}.md", detail.name.replace('/', "_"));
+                app.secret_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshSsmParameter => {
+            app.ssm_parameter_paths = aws_adapter::list_parameter_paths();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadSsmParameter => {
+            app.ssm_parameter_paths = aws_adapter::list_parameter_paths();
+            app.selected_index = 0;
+            app.screen = Screen::SsmParameterSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadSsmParameterDetail(path) => {
+            if let Some(detail) = aws_adapter::get_parameter_path_detail(&path) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = ssm_parameter_filename(&detail.path);
+                app.ssm_parameter_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEfs => {
+            app.efs_file_systems = aws_adapter::list_file_systems();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEfs => {
+            app.efs_file_systems = aws_adapter::list_file_systems();
+            app.selected_index = 0;
+            app.screen = Screen::EfsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEfsDetail(file_system_id) => {
+            if let Some(detail) = aws_adapter::get_efs_detail(&file_system_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.efs_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshApiGateway => {
+            app.api_gateways = aws_adapter::list_apis();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadApiGateway => {
+            app.api_gateways = aws_adapter::list_apis();
+            app.selected_index = 0;
+            app.screen = Screen::ApiGatewaySelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadApiGatewayDetail(api_id) => {
+            if let Some(detail) = aws_adapter::get_api_gateway_detail(&api_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.api_gateway_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshElasticBeanstalk => {
+            app.beanstalk_environments = aws_adapter::list_beanstalk_environments();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadElasticBeanstalk => {
+            app.beanstalk_environments = aws_adapter::list_beanstalk_environments();
+            app.selected_index = 0;
+            app.screen = Screen::ElasticBeanstalkSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadElasticBeanstalkDetail(environment_id) => {
+            if let Some(detail) = aws_adapter::get_elastic_beanstalk_detail(&environment_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.elastic_beanstalk_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshStepFunctions => {
+            app.state_machines = aws_adapter::list_state_machines();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadStepFunctions => {
+            app.state_machines = aws_adapter::list_state_machines();
+            app.selected_index = 0;
+            app.screen = Screen::StepFunctionsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadStepFunctionsDetail(state_machine_arn) => {
+            if let Some(detail) = aws_adapter::get_state_machine_detail(&state_machine_arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.state_machine_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshKinesis => {
+            app.kinesis_streams = aws_adapter::list_kinesis_streams();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadKinesis => {
+            app.kinesis_streams = aws_adapter::list_kinesis_streams();
+            app.selected_index = 0;
+            app.screen = Screen::KinesisSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadKinesisDetail(stream_name) => {
+            if let Some(detail) = aws_adapter::get_kinesis_stream_detail(&stream_name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.kinesis_stream_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshMsk => {
+            app.msk_clusters = aws_adapter::list_msk_clusters();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadMsk => {
+            app.msk_clusters = aws_adapter::list_msk_clusters();
+            app.selected_index = 0;
+            app.screen = Screen::MskSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadMskDetail(cluster_arn) => {
+            if let Some(detail) = aws_adapter::get_msk_cluster_detail(&cluster_arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.msk_cluster_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshOpenSearch => {
+            app.opensearch_domains = aws_adapter::list_opensearch_domains();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadOpenSearch => {
+            app.opensearch_domains = aws_adapter::list_opensearch_domains();
+            app.selected_index = 0;
+            app.screen = Screen::OpenSearchSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadOpenSearchDetail(domain_name) => {
+            if let Some(detail) = aws_adapter::get_opensearch_domain_detail(&domain_name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.opensearch_domain_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshRedshift => {
+            app.redshift_clusters = aws_adapter::list_redshift_clusters();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadRedshift => {
+            app.redshift_clusters = aws_adapter::list_redshift_clusters();
+            app.selected_index = 0;
+            app.screen = Screen::RedshiftSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadRedshiftDetail(cluster_identifier) => {
+            if let Some(detail) = aws_adapter::get_redshift_cluster_detail(&cluster_identifier) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.identifier);
+                app.redshift_cluster_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshTransitGateway => {
+            app.transit_gateways = aws_adapter::list_transit_gateways();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadTransitGateway => {
+            app.transit_gateways = aws_adapter::list_transit_gateways();
+            app.selected_index = 0;
+            app.screen = Screen::TransitGatewaySelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadTransitGatewayDetail(tgw_id) => {
+            if let Some(detail) = aws_adapter::get_transit_gateway_detail(&tgw_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.transit_gateway_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshVpcPeering => {
+            app.vpc_peerings = aws_adapter::list_vpc_peering_connections();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpcPeering => {
+            app.vpc_peerings = aws_adapter::list_vpc_peering_connections();
+            app.selected_index = 0;
+            app.screen = Screen::VpcPeeringSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpcPeeringDetail(peering_id) => {
+            if let Some(detail) = aws_adapter::get_vpc_peering_detail(&peering_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.vpc_peering_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshVpn => {
+            app.vpns = aws_adapter::list_vpn_resources();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpn => {
+            app.vpns = aws_adapter::list_vpn_resources();
+            app.selected_index = 0;
+            app.screen = Screen::VpnSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpnDetail(vpn_id) => {
+            if let Some(detail) = aws_adapter::get_vpn_detail(&vpn_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.vpn_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshDirectConnect => {
+            app.direct_connect_resources = aws_adapter::list_direct_connect_resources();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadDirectConnect => {
+            app.direct_connect_resources = aws_adapter::list_direct_connect_resources();
+            app.selected_index = 0;
+            app.screen = Screen::DirectConnectSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadDirectConnectDetail(resource_id) => {
+            if let Some(detail) = aws_adapter::get_direct_connect_detail(&resource_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.direct_connect_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshWaf => {
+            app.waf_web_acls = aws_adapter::list_waf_web_acls();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadWaf => {
+            app.waf_web_acls = aws_adapter::list_waf_web_acls();
+            app.selected_index = 0;
+            app.screen = Screen::WafSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadWafDetail(web_acl_arn) => {
+            if let Some(detail) = aws_adapter::get_waf_web_acl_detail(&web_acl_arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.waf_web_acl_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshAcm => {
+            app.acm_certificates = aws_adapter::list_acm_certificates();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadAcm => {
+            app.acm_certificates = aws_adapter::list_acm_certificates();
+            app.selected_index = 0;
+            app.screen = Screen::AcmSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadAcmDetail(certificate_arn) => {
+            if let Some(detail) = aws_adapter::get_acm_certificate_detail(&certificate_arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.acm_certificate_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshCloudFormation => {
+            app.cloudformation_stacks = aws_adapter::list_cloudformation_stacks();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudFormation => {
+            app.cloudformation_stacks = aws_adapter::list_cloudformation_stacks();
+            app.selected_index = 0;
+            app.screen = Screen::CloudFormationSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadCloudFormationDetail(stack_name) => {
+            if let Some(detail) = aws_adapter::get_cloudformation_stack_detail(&stack_name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.cloudformation_stack_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshIam => {
+            app.iam_entities = aws_adapter::list_iam_entities();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadIam => {
+            app.iam_entities = aws_adapter::list_iam_entities();
+            app.selected_index = 0;
+            app.screen = Screen::IamSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadIamDetail(arn) => {
+            if let Some(detail) = aws_adapter::get_iam_entity_detail(&arn) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.iam_entity_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::DocumentRegion(index) => {
+            process_region_inventory(app, index);
+        }
+        LoadingTask::RefreshVpcEndpoint => {
+            app.vpc_endpoints = aws_adapter::list_vpc_endpoints();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpcEndpoint => {
+            app.vpc_endpoints = aws_adapter::list_vpc_endpoints();
+            app.selected_index = 0;
+            app.screen = Screen::VpcEndpointSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadVpcEndpointDetail(endpoint_id) => {
+            if let Some(detail) = aws_adapter::get_vpc_endpoint_detail(&endpoint_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.vpc_endpoint_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshNetworkInterface => {
+            app.network_interfaces = aws_adapter::list_network_interfaces();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadNetworkInterface => {
+            app.network_interfaces = aws_adapter::list_network_interfaces();
+            app.selected_index = 0;
+            app.screen = Screen::NetworkInterfaceSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadNetworkInterfaceDetail(interface_id) => {
+            if let Some(detail) = aws_adapter::get_network_interface_detail(&interface_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.network_interface_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshLaunchTemplate => {
+            app.launch_templates = aws_adapter::list_launch_templates();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadLaunchTemplate => {
+            app.launch_templates = aws_adapter::list_launch_templates();
+            app.selected_index = 0;
+            app.screen = Screen::LaunchTemplateSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadLaunchTemplateDetail(template_id) => {
+            if let Some(detail) = aws_adapter::get_launch_template_detail(&template_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.launch_template_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshAmi => {
+            app.amis = aws_adapter::list_amis();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadAmi => {
+            app.amis = aws_adapter::list_amis();
+            app.selected_index = 0;
+            app.screen = Screen::AmiSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadAmiDetail(image_id) => {
+            if let Some(detail) = aws_adapter::get_ami_detail(&image_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.ami_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEbs => {
+            app.ebs_resources = aws_adapter::list_ebs_resources();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEbs => {
+            app.ebs_resources = aws_adapter::list_ebs_resources();
+            app.selected_index = 0;
+            app.screen = Screen::EbsSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEbsDetail(ebs_id) => {
+            if let Some(detail) = aws_adapter::get_ebs_detail(&ebs_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.ebs_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshKeyPair => {
+            app.key_pairs = aws_adapter::list_key_pairs();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadKeyPair => {
+            app.key_pairs = aws_adapter::list_key_pairs();
+            app.selected_index = 0;
+            app.screen = Screen::KeyPairSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadKeyPairDetail(key_pair_id) => {
+            if let Some(detail) = aws_adapter::get_key_pair_detail(&key_pair_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.key_pair_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshEventBridge => {
+            app.event_buses = aws_adapter::list_event_buses();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadEventBridge => {
+            app.event_buses = aws_adapter::list_event_buses();
+            app.selected_index = 0;
+            app.screen = Screen::EventBridgeSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadEventBridgeDetail(event_bus_name) => {
+            if let Some(detail) = aws_adapter::get_event_bus_detail(&event_bus_name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.event_bus_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshCognito => {
+            app.cognito_pools = aws_adapter::list_cognito_pools();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadCognito => {
+            app.cognito_pools = aws_adapter::list_cognito_pools();
+            app.selected_index = 0;
+            app.screen = Screen::CognitoSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadCognitoDetail(pool_id) => {
+            if let Some(detail) = aws_adapter::get_cognito_pool_detail(&pool_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.cognito_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshGlue => {
+            app.glue_resources = aws_adapter::list_glue_resources();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadGlue => {
+            app.glue_resources = aws_adapter::list_glue_resources();
+            app.selected_index = 0;
+            app.screen = Screen::GlueSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadGlueDetail(id) => {
+            if let Some(detail) = aws_adapter::get_glue_detail(&id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.glue_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshAthena => {
+            app.athena_workgroups = aws_adapter::list_athena_workgroups();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadAthena => {
+            app.athena_workgroups = aws_adapter::list_athena_workgroups();
+            app.selected_index = 0;
+            app.screen = Screen::AthenaSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadAthenaDetail(name) => {
+            if let Some(detail) = aws_adapter::get_athena_workgroup_detail(&name) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.athena_workgroup_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::RefreshBackup => {
+            app.backup_plans = aws_adapter::list_backup_plans();
+            app.message = app.i18n.refresh_complete().to_string();
+            finish_loading(app);
+        }
+        LoadingTask::LoadBackup => {
+            app.backup_plans = aws_adapter::list_backup_plans();
+            app.selected_index = 0;
+            app.screen = Screen::BackupSelect;
+            finish_loading(app);
+        }
+        LoadingTask::LoadBackupDetail(plan_id) => {
+            if let Some(detail) = aws_adapter::get_backup_plan_detail(&plan_id) {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+                app.backup_plan_detail = Some(detail);
+                app.screen = Screen::Preview;
+            }
+            finish_loading(app);
+        }
+
+        LoadingTask::LoadBlueprintResources(current_index) => {
+            process_blueprint_resources(app, current_index);
+        }
+        LoadingTask::ValidateBlueprintResources(current_index) => {
+            process_blueprint_validation(app, current_index);
+        }
+        LoadingTask::ImportCloudFormationStack(stack_name) => {
+            let resources = aws_adapter::list_stack_resources(&stack_name);
+            app.import_cloudformation_blueprint(&stack_name, resources);
+            finish_loading(app);
+        }
+        LoadingTask::ImportTaggedResources(query) => {
+            let resources = crate::tag_query::parse_tag_query(&query)
+                .and_then(|filters| aws_adapter::get_resources_by_tags(&filters));
+            app.import_tag_query_blueprint(&query, resources);
+            finish_loading(app);
+        }
+        LoadingTask::SetNameTag => {
+            let name = app.input_buffer.trim().to_string();
+            app.input_buffer.clear();
+            if let Some(edit) = app.name_tag_edit.take() {
+                let kind = name_tag_resource_kind(&edit.resource_type).unwrap_or_default();
+                match aws_adapter::set_name_tag(kind, &edit.resource_id, &name) {
+                    Ok(()) => {
+                        // 목록 표시 이름에 바로 반영
+                        match edit.resource_type {
+                            ResourceType::Network => app.vpcs = aws_adapter::list_vpcs(),
+                            ResourceType::SecurityGroup => {
+                                app.security_groups = aws_adapter::list_security_groups();
+                            }
+                            _ => load_instances(app),
+                        }
+                        app.message = app.i18n.name_tag_updated(&edit.resource_id, &name);
+                    }
+                    Err(error) => app.message = app.i18n.name_tag_update_failed(&error),
+                }
+                app.screen = edit.return_screen;
+            }
+            finish_loading(app);
+        }
+        LoadingTask::RunQueryOperation(index) => {
+            let (service, operation) = QUERY_OPERATIONS[index];
+            match aws_adapter::run_aws_cli(&[service, operation]) {
+                Some(output) => {
+                    app.query_output = Some(output);
+                    app.input_buffer.clear();
+                    app.apply_query();
+                }
+                None => app.message = app.i18n.query_failed().to_string(),
+            }
+            finish_loading(app);
+        }
+        LoadingTask::None => {}
+    }
+    // 리소스 문서를 새로 만들었으면 경과 기간과 CloudTrail 마지막 변경 정보 추가
+    if let Some(preview_before) = preview_before
+        && app.screen == Screen::Preview
+        && app.preview_content != preview_before
+        && let (Some(resource_type), Some((id, _))) = (
+            app.get_current_resource_type(),
+            app.get_current_resource_info(),
+        )
+    {
+        let mut markdown = std::mem::take(&mut app.preview_content);
+        if age::show_resource_age_enabled() {
+            let created = app.current_created_at().and_then(age::created_date);
+            markdown = age::with_age(markdown, created, app.settings.language, age::today());
+        }
+        if aws_adapter::stamp_last_modified_enabled() {
+            markdown = with_last_modified(&resource_type, &id, markdown, app.settings.language);
+        }
+        app.preview_content = markdown;
+    }
+    if let Some(screen) = list_screen {
+        if screen == app.screen {
+            // 목록을 새로 열었으면 이 서비스에서 마지막으로 선택했던 행으로 복원
+            let selected_id = selected_id.or_else(|| app.remembered_list_selection());
+            app.restore_list_cursor(selected_id.as_deref());
+        }
+        app.record_list_refresh(screen);
+    }
+}
+
+// 숨기지 않은 서비스 목록을 하나씩 조회해 리전 문서화용 임시 블루프린트를 채운 뒤
+// 블루프린트와 같은 방식으로 리소스별 마크다운 생성
+fn process_region_inventory(app: &mut App, index: usize) {
+    if index == 0 {
+        let name = format!(
+            "region-{}-{}",
+            app.get_current_region(),
+            chrono::Utc::now().format("%Y%m%d")
+        );
+        app.region_inventory = Some(Blueprint::new(name));
+    }
+
+    let tasks = app.region_inventory_tasks();
+    if let Some((screen, task)) = tasks.get(index) {
+        // 목록 새로고침 작업을 그대로 실행 (화면은 바뀌지 않음)
+        app.loading_task = task.clone();
+        process_loading(app);
+        app.add_region_inventory_resources(screen);
+        app.loading = true;
+        app.loading_task = LoadingTask::DocumentRegion(index + 1);
+        return;
+    }
+
+    if app
+        .region_inventory
+        .as_ref()
+        .is_none_or(|inventory| inventory.resources.is_empty())
+    {
+        app.region_inventory = None;
+        finish_loading(app);
+        app.message = app.i18n.region_inventory_empty().to_string();
+        return;
+    }
+    app.blueprint_markdown_parts.clear();
+    app.blueprint_resource_facts.clear();
+    app.loading_task = LoadingTask::LoadBlueprintResources(0);
+}
+
+fn process_blueprint_resources(app: &mut App, current_index: usize) {
+    let blueprint = match app.documenting_blueprint() {
+        Some(bp) => bp.clone(),
+        None => {
+            finish_loading(app);
+            return;
+        }
+    };
+
+    if current_index >= blueprint.resources.len() {
+        // 쓰이지 않은 미리 조회 응답이 다음 실행에 남지 않도록 비움
+        aws_adapter::clear_prefetched_instances();
+    }
+    if current_index >= blueprint.resources.len() && app.region_inventory.take().is_some() {
+        app.preview_content =
+            region_inventory_document(&app.i18n, &blueprint, &app.blueprint_markdown_parts);
+        app.preview_filename = format!("{}.md", blueprint.name);
+        app.preview_scroll = 0;
+        app.preview_hscroll = 0;
+        app.screen = Screen::Preview;
+        finish_loading(app);
+        app.message = app.i18n.region_inventory_summary(
+            region_inventory_chapters(&blueprint, &app.blueprint_markdown_parts).len(),
+            blueprint.resources.len(),
+        );
+        return;
+    }
+    if current_index >= blueprint.resources.len() {
+        // All resources loaded, generate table of contents and combine markdown
+        app.preview_content = blueprint_document(
+            &app.i18n,
+            &blueprint,
+            &app.blueprint_markdown_parts,
+            &app.blueprint_resource_facts,
+        );
+        app.preview_filename = format!("{}.md", blueprint.name);
+        app.preview_scroll = 0;
+        app.preview_hscroll = 0;
+        app.screen = Screen::BlueprintPreview;
+        finish_loading(app);
+        return;
+    }
+
+    let documents = blueprint_batch_documents(
+        &blueprint.resources,
+        current_index,
+        &app.i18n,
+        app.settings.language,
+    );
+    let next_index = current_index + documents.len();
+    for (markdown, facts) in documents {
+        app.blueprint_markdown_parts.push(markdown);
+        app.blueprint_resource_facts.push(facts);
+    }
+
+    // Move to next batch
+    app.loading_task = LoadingTask::LoadBlueprintResources(next_index);
+}
+
+/// Generate a blueprint's Markdown document without the TUI (used by `emd daemon`).
+pub fn generate_blueprint_document(blueprint: &Blueprint, lang: Language) -> String {
+    let i18n = I18n::new(lang);
+    let (parts, facts) = generate_blueprint_parts(blueprint, &i18n, lang);
+    blueprint_document(&i18n, blueprint, &parts, &facts)
+}
+
+/// Like [`generate_blueprint_document`], but split into `index.md` plus one file per resource.
+pub fn generate_blueprint_files(blueprint: &Blueprint, lang: Language) -> Vec<(String, String)> {
+    let i18n = I18n::new(lang);
+    let (parts, facts) = generate_blueprint_parts(blueprint, &i18n, lang);
+    blueprint_files(&i18n, blueprint, &parts, &facts)
+}
+
+// 리소스별 마크다운과 요약용 정보 (블루프린트 순서)
+fn generate_blueprint_parts(
+    blueprint: &Blueprint,
+    i18n: &I18n,
+    lang: Language,
+) -> (Vec<String>, Vec<ResourceFacts>) {
+    let mut parts: Vec<String> = Vec::with_capacity(blueprint.resources.len());
+    let mut facts = Vec::with_capacity(blueprint.resources.len());
+    while parts.len() < blueprint.resources.len() {
+        let documents = blueprint_batch_documents(&blueprint.resources, parts.len(), i18n, lang);
+        for (markdown, resource_facts) in documents {
+            parts.push(markdown);
+            facts.push(resource_facts);
+        }
+    }
+    aws_adapter::clear_prefetched_instances();
+    (parts, facts)
+}
+
+// start부터 같은 리전의 연속된 리소스를 최대 limit개까지 한 묶음으로 봄
+// (리전 설정이 프로세스 전역이므로 리전이 바뀌는 곳에서 묶음을 끊음)
+fn blueprint_batch_end(resources: &[BlueprintResource], start: usize, limit: usize) -> usize {
+    let Some(first) = resources.get(start) else {
+        return start;
+    };
+    start
+        + resources[start..]
+            .iter()
+            .take(limit.max(1))
+            .take_while(|resource| resource.region == first.region)
+            .count()
+}
+
+// 한 묶음의 리소스를 동시에 조회해 블루프린트 순서대로 마크다운과 요약용 정보 반환
+// (조회 실패 시 실패 표시 섹션과 빈 정보)
+fn blueprint_batch_documents(
+    resources: &[BlueprintResource],
+    start: usize,
+    i18n: &I18n,
+    lang: Language,
+) -> Vec<(String, ResourceFacts)> {
+    let end = blueprint_batch_end(resources, start, aws_adapter::max_concurrent_requests());
+    let Some(first) = resources.get(start) else {
+        return Vec::new();
+    };
+
+    // Set region for this batch
+    aws_adapter::set_region(&first.region);
+    if start == 0 {
+        aws_adapter::clear_iam_role_cache();
+    }
+    for index in start..end {
+        prefetch_blueprint_instances(resources, index);
+    }
+
+    let document = |resource: &BlueprintResource| {
+        document_resource(resource, lang).unwrap_or_else(|| {
+            let markdown = format!(
+                "## {}: {} ({})\n",
+                resource_type_label(i18n, &resource.resource_type),
+                resource.resource_name,
+                i18n.query_failed()
+            );
+            (markdown, ResourceFacts::default())
+        })
+    };
+    if end - start == 1 {
+        return vec![document(first)];
+    }
+
+    // 작업 스레드에서도 호출한 쪽 스팬(예: daemon_job) 아래에 기록
+    let parent = tracing::Span::current();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = resources[start..end]
+            .iter()
+            .map(|resource| {
+                let parent = &parent;
+                let document = &document;
+                scope.spawn(move || parent.in_scope(|| document(resource)))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+// 요약, 목차와 리소스별 마크다운을 합쳐 블루프린트 문서 생성
+fn blueprint_document(
+    i18n: &I18n,
+    blueprint: &Blueprint,
+    parts: &[String],
+    facts: &[ResourceFacts],
+) -> String {
+    let mut toc = vec![format!("## {}\n", i18n.toc())];
+    for (i, (res, markdown)) in blueprint.resources.iter().zip(parts.iter()).enumerate() {
+        let anchor = format!(
+            "{}-{}",
+            res.resource_type.display().to_lowercase().replace(" ", "-"),
+            res.resource_name.to_lowercase().replace(" ", "-")
+        );
+        toc.push(format!(
+            "- [{}. {} - {}](#{})",
+            i + 1,
+            res.resource_type.display(),
+            res.resource_name,
+            anchor
+        ));
+
+        // ### 헤더들을 서브 목차로 추가
+        for line in markdown.lines() {
+            if line.starts_with("### ") {
+                let header = line.trim_start_matches("### ").trim();
+                let sub_anchor = header
+                    .to_lowercase()
+                    .replace(" ", "-")
+                    .replace("(", "")
+                    .replace(")", "");
+                toc.push(format!("  - [{}](#{})", header, sub_anchor));
+            }
+        }
+    }
+    toc.push("\n".to_string());
+
+    let combined = parts.join("\n---\n\n");
+    let toc_str = toc.join("\n");
+    let summary = stats::summary_markdown(&blueprint.resources, facts, i18n.lang);
+    let age_summary = blueprint_age_summary(i18n, blueprint, facts)
+        .map(|summary| summary + "\n")
+        .unwrap_or_default();
+    format!(
+        "# Blueprint: {}\n\n{}\n{}{}{}",
+        blueprint.name, summary, age_summary, toc_str, combined
+    )
+}
+
+// 경과 기간 표시를 켰으면 목차 앞에 넣을 리소스 경과 기간 분포
+fn blueprint_age_summary(
+    i18n: &I18n,
+    blueprint: &Blueprint,
+    facts: &[ResourceFacts],
+) -> Option<String> {
+    age::show_resource_age_enabled().then(|| {
+        let created: Vec<_> = facts.iter().map(|facts| facts.created).collect();
+        age::age_summary_markdown(&blueprint.resources, &created, i18n.lang, age::today())
+    })
+}
+
+// 블루프린트를 index.md와 리소스별 파일로 나눔 (git 기반 위키용, (파일 이름, 내용) 목록)
+// 파일 이름은 순번-서비스-이름.md로 블루프린트 순서대로 정렬됨
+fn blueprint_files(
+    i18n: &I18n,
+    blueprint: &Blueprint,
+    parts: &[String],
+    facts: &[ResourceFacts],
+) -> Vec<(String, String)> {
+    let width = blueprint.resources.len().to_string().len().max(2);
+    let mut index = vec![
+        format!("# Blueprint: {}\n", blueprint.name),
+        stats::summary_markdown(&blueprint.resources, facts, i18n.lang),
+    ];
+    index.extend(blueprint_age_summary(i18n, blueprint, facts));
+    index.push(format!("## {}\n", i18n.toc()));
+    let mut files = Vec::with_capacity(parts.len() + 1);
+    for (i, (res, markdown)) in blueprint.resources.iter().zip(parts).enumerate() {
+        let mut slug = heading_anchor(&res.resource_name);
+        if slug.is_empty() {
+            slug = heading_anchor(&res.resource_id);
+        }
+        let filename = format!(
+            "{:0width$}-{}-{}.md",
+            i + 1,
+            heading_anchor(res.resource_type.display()),
+            slug
+        );
+        index.push(format!(
+            "- [{}. {} - {}]({})",
+            i + 1,
+            res.resource_type.display(),
+            res.resource_name,
+            filename
+        ));
+        for line in markdown.lines() {
+            if let Some(header) = line.strip_prefix("### ") {
+                index.push(format!(
+                    "  - [{}]({}#{})",
+                    header.trim(),
+                    filename,
+                    heading_anchor(header)
+                ));
+            }
+        }
+        files.push((
+            filename,
+            format!("[{}](index.md)\n\n{}", i18n.toc(), markdown),
+        ));
+    }
+    files.insert(0, ("index.md".to_string(), index.join("\n") + "\n"));
+    files
+}
+
+// 블루프린트 미리보기를 <블루프린트 이름>/ 디렉터리에 index.md와 리소스별 파일로 저장
+fn save_blueprint_files(app: &mut App) {
+    let Some(blueprint) = app.current_blueprint.clone() else {
+        return;
+    };
+    let mut files = blueprint_files(
+        &app.i18n,
+        &blueprint,
+        &app.blueprint_markdown_parts,
+        &app.blueprint_resource_facts,
+    );
+    let diagram_error = render_file_diagrams(
+        &blueprint.name,
+        &mut files,
+        app.settings.diagram_renderer.as_ref(),
+    );
+    match crate::output::save_markdown_files(&blueprint.name, &files) {
+        Ok(paths) => {
+            for (path, (_, content)) in paths.iter().zip(&files) {
+                if let Err(error) =
+                    crate::doc_index::index_document(path, content, app.settings.encrypt_at_rest)
+                {
+                    tracing::warn!(error = %error, "Failed to index saved document");
+                }
+            }
+            app.message = app.i18n.blueprint_files_saved(files.len(), &blueprint.name);
+            if let Some(error) = diagram_error {
+                app.message = format!(
+                    "{} ({})",
+                    app.message,
+                    app.i18n.diagram_render_failed(&error)
+                );
+            }
+        }
+        Err(error) => {
+            tracing::warn!(error = %error, "Failed to save blueprint files");
+            app.message = app.i18n.blueprint_files_save_failed(&error.to_string());
+        }
+    }
+}
+
+/// Render the Mermaid diagrams of split blueprint files saved under `dir`.
+/// Returns the first rendering error; files that failed keep their Mermaid source.
+pub fn render_file_diagrams(
+    dir: &str,
+    files: &mut [(String, String)],
+    renderer: Option<&crate::settings::DiagramRenderer>,
+) -> Option<String> {
+    let mut first_error = None;
+    for (filename, content) in files.iter_mut() {
+        let path = std::path::Path::new(dir).join(filename.as_str());
+        let (rendered, error) =
+            crate::diagram::render_if_configured(content, &path.to_string_lossy(), renderer);
+        *content = rendered;
+        if first_error.is_none() {
+            first_error = error;
+        }
+    }
+    first_error
+}
+
+// 서비스 목록 순서대로 연속된 리소스를 한 장(chapter)으로 묶음
+fn region_inventory_chapters<'a>(
+    blueprint: &'a Blueprint,
+    parts: &'a [String],
+) -> Vec<(&'a ResourceType, Vec<(&'a BlueprintResource, &'a str)>)> {
+    let mut chapters: Vec<(&ResourceType, Vec<(&BlueprintResource, &str)>)> = Vec::new();
+    for (resource, markdown) in blueprint.resources.iter().zip(parts) {
+        match chapters.last_mut() {
+            Some((resource_type, items)) if **resource_type == resource.resource_type => {
+                items.push((resource, markdown));
+            }
+            _ => chapters.push((&resource.resource_type, vec![(resource, markdown)])),
+        }
+    }
+    chapters
+}
+
+// GitHub 방식 헤더 앵커: 소문자, 공백은 '-', 문자/숫자/'-'/'_' 외에는 제거
+fn heading_anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+// 리전 문서화 결과: 서비스별 장과 전체 색인(장 → 리소스)을 갖춘 한 권의 문서
+fn region_inventory_document(i18n: &I18n, blueprint: &Blueprint, parts: &[String]) -> String {
+    let region = blueprint
+        .resources
+        .first()
+        .map(|resource| resource.region.as_str())
+        .unwrap_or_default();
+    let chapters = region_inventory_chapters(blueprint, parts);
+
+    let mut lines = vec![
+        format!(
+            "# {}: {}
+",
+            i18n.region_inventory(),
+            region
+        ),
+        format!(
+            "> {} · {}
+",
+            i18n.region_inventory_summary(chapters.len(), blueprint.resources.len()),
+            blueprint.created_at.format("%Y-%m-%d %H:%M UTC")
+        ),
+        format!(
+            "## {}
+",
+            i18n.toc()
+        ),
+    ];
+    for (number, (resource_type, items)) in chapters.iter().enumerate() {
+        let title = format!(
+            "{}. {}",
+            number + 1,
+            resource_type_label(i18n, resource_type)
+        );
+        lines.push(format!(
+            "- [{} ({})](#{})",
+            title,
+            items.len(),
+            heading_anchor(&title)
+        ));
+        // 리소스 섹션의 첫 "## " 헤더로 연결
+        for (resource, markdown) in items {
+            let anchor = markdown
+                .lines()
+                .find_map(|line| line.strip_prefix("## "))
+                .map(heading_anchor)
+                .unwrap_or_default();
+            lines.push(format!("  - [{}](#{})", resource.resource_name, anchor));
+        }
+    }
+
+    for (number, (resource_type, items)) in chapters.iter().enumerate() {
+        lines.push(String::new());
+        lines.push(format!(
+            "# {}. {}
+",
+            number + 1,
+            resource_type_label(i18n, resource_type)
+        ));
+        let sections: Vec<&str> = items.iter().map(|(_, markdown)| *markdown).collect();
+        lines.push(sections.join("\n---\n\n"));
+    }
+    lines.join("\n")
+}
+
+// 리소스 상세를 조회해 마크다운과 요약용 구조화된 정보로 변환 (조회 실패 시 None)
+fn fetch_resource_document(
+    resource: &BlueprintResource,
+    lang: Language,
+) -> Option<(String, ResourceFacts)> {
+    let id = resource.resource_id.as_str();
+    let _span = tracing::info_span!(
+        "generate_markdown",
+        resource_type = resource.resource_type.display(),
+        resource_id = id,
+        region = resource.region.as_str()
+    )
+    .entered();
+    match resource.resource_type {
+        ResourceType::Ec2 => aws_adapter::get_instance_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::ec2(&d))),
+        ResourceType::Network => aws_adapter::get_network_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::SecurityGroup => aws_adapter::get_security_group_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::LoadBalancer => aws_adapter::get_load_balancer_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::load_balancer(&d))),
+        ResourceType::Ecr => aws_adapter::get_ecr_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created_at))),
+        ResourceType::Asg => {
+            aws_adapter::get_asg_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::Rds => {
+            aws_adapter::get_rds_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::Lambda => aws_adapter::get_lambda_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::DynamoDb => aws_adapter::get_dynamodb_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::CloudFront => aws_adapter::get_cloudfront_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::cloudfront(&d))),
+        ResourceType::Route53 => aws_adapter::get_route53_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Eks => {
+            aws_adapter::get_eks_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::eks(&d)))
+        }
+        ResourceType::Ecs => {
+            aws_adapter::get_ecs_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::Sqs => {
+            aws_adapter::get_sqs_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::Sns => {
+            aws_adapter::get_sns_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::ElastiCache => aws_adapter::get_elasticache_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::CloudWatchAlarm => aws_adapter::get_cloudwatch_alarm_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::CloudTrail => aws_adapter::get_cloudtrail_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Kms => aws_adapter::get_kms_key_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Secret => aws_adapter::get_secret_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::SsmParameter => aws_adapter::get_parameter_path_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Efs => aws_adapter::get_efs_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::ApiGateway => aws_adapter::get_api_gateway_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::api_gateway(&d))),
+        ResourceType::ElasticBeanstalk => aws_adapter::get_elastic_beanstalk_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::StepFunctions => aws_adapter::get_state_machine_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Kinesis => aws_adapter::get_kinesis_stream_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Msk => aws_adapter::get_msk_cluster_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::OpenSearch => aws_adapter::get_opensearch_domain_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Redshift => aws_adapter::get_redshift_cluster_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::redshift(&d))),
+        ResourceType::TransitGateway => aws_adapter::get_transit_gateway_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::VpcPeering => aws_adapter::get_vpc_peering_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Vpn => {
+            aws_adapter::get_vpn_detail(id).map(|d| (d.to_markdown(lang), ResourceFacts::default()))
+        }
+        ResourceType::DirectConnect => aws_adapter::get_direct_connect_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Waf => aws_adapter::get_waf_web_acl_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::Acm => aws_adapter::get_acm_certificate_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::CloudFormation => aws_adapter::get_cloudformation_stack_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Iam => aws_adapter::get_iam_entity_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::VpcEndpoint => aws_adapter::get_vpc_endpoint_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::NetworkInterface => aws_adapter::get_network_interface_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::default())),
+        ResourceType::LaunchTemplate => aws_adapter::get_launch_template_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Ami => aws_adapter::get_ami_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Ebs => aws_adapter::get_ebs_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(d.created()))),
+        ResourceType::KeyPair => aws_adapter::get_key_pair_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::EventBridge => aws_adapter::get_event_bus_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Cognito => aws_adapter::get_cognito_pool_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Glue => aws_adapter::get_glue_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Athena => aws_adapter::get_athena_workgroup_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+        ResourceType::Backup => aws_adapter::get_backup_plan_detail(id)
+            .map(|d| (d.to_markdown(lang), ResourceFacts::created(&d.created))),
+    }
+}
+
+// 리소스 상세를 조회해 마크다운으로 변환 (조회 실패 시 None)
+fn fetch_resource_markdown(resource: &BlueprintResource, lang: Language) -> Option<String> {
+    fetch_resource_document(resource, lang).map(|(markdown, _)| markdown)
+}
+
+// 문서로 내보내는 마크다운과 요약용 정보 (비교/승격 점검은 설정 값만 비교하도록 fetch_resource_markdown 사용)
+fn document_resource(
+    resource: &BlueprintResource,
+    lang: Language,
+) -> Option<(String, ResourceFacts)> {
+    fetch_resource_document(resource, lang).map(|(mut markdown, facts)| {
+        if age::show_resource_age_enabled() {
+            markdown = age::with_age(markdown, facts.created, lang, age::today());
+        }
+        if aws_adapter::stamp_last_modified_enabled() {
+            markdown = with_last_modified(
+                &resource.resource_type,
+                &resource.resource_id,
+                markdown,
+                lang,
+            );
+        }
+        (markdown, facts)
+    })
+}
+
+fn document_resource_markdown(resource: &BlueprintResource, lang: Language) -> Option<String> {
+    document_resource(resource, lang).map(|(markdown, _)| markdown)
+}
+
+// CloudTrail에서 찾은 마지막 변경 섹션을 문서 끝에 붙임
+fn with_last_modified(
+    resource_type: &ResourceType,
+    id: &str,
+    mut markdown: String,
+    lang: Language,
+) -> String {
+    // 글로벌 서비스 이벤트는 글로벌 리전의 CloudTrail에서 조회
+    let global = matches!(
+        resource_type,
+        ResourceType::Iam | ResourceType::CloudFront | ResourceType::Route53
+    );
+    let last_modified = aws_adapter::get_last_modified(id, global);
+    markdown.push('\n');
+    markdown.push_str(&aws_adapter::last_modified_markdown(
+        last_modified.as_ref(),
+        lang,
+    ));
+    markdown
+}
+
+/// Fetch a single resource's Markdown without the TUI (used by `emd serve`).
+pub fn export_resource_markdown(resource: &BlueprintResource, lang: Language) -> Option<String> {
+    aws_adapter::set_region(&resource.region);
+    document_resource_markdown(resource, lang)
+}
+
+/// Fetch a single resource's detail as pretty-printed JSON (used by `emd serve`).
+pub fn export_resource_json(resource: &BlueprintResource) -> Option<String> {
+    aws_adapter::set_region(&resource.region);
+    fetch_resource_json(resource)
+}
+
+// 미리보기의 JSON 보기와 같은 상세 구조체를 그대로 직렬화
+fn fetch_resource_json(resource: &BlueprintResource) -> Option<String> {
+    fn to_json(detail: &impl serde::Serialize) -> Option<String> {
+        serde_json::to_string_pretty(detail).ok()
+    }
+
+    let id = resource.resource_id.as_str();
+    match resource.resource_type {
+        ResourceType::Ec2 => aws_adapter::get_instance_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Network => aws_adapter::get_network_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::SecurityGroup => {
+            aws_adapter::get_security_group_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::LoadBalancer => {
+            aws_adapter::get_load_balancer_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Ecr => aws_adapter::get_ecr_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Asg => aws_adapter::get_asg_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Rds => aws_adapter::get_rds_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Lambda => aws_adapter::get_lambda_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::DynamoDb => aws_adapter::get_dynamodb_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::CloudFront => {
+            aws_adapter::get_cloudfront_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Route53 => aws_adapter::get_route53_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Eks => aws_adapter::get_eks_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Ecs => aws_adapter::get_ecs_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Sqs => aws_adapter::get_sqs_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Sns => aws_adapter::get_sns_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::ElastiCache => {
+            aws_adapter::get_elasticache_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::CloudWatchAlarm => {
+            aws_adapter::get_cloudwatch_alarm_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::CloudTrail => {
+            aws_adapter::get_cloudtrail_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Kms => aws_adapter::get_kms_key_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Secret => aws_adapter::get_secret_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::SsmParameter => {
+            aws_adapter::get_parameter_path_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Efs => aws_adapter::get_efs_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::ApiGateway => {
+            aws_adapter::get_api_gateway_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::ElasticBeanstalk => {
+            aws_adapter::get_elastic_beanstalk_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::StepFunctions => {
+            aws_adapter::get_state_machine_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Kinesis => {
+            aws_adapter::get_kinesis_stream_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Msk => aws_adapter::get_msk_cluster_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::OpenSearch => {
+            aws_adapter::get_opensearch_domain_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Redshift => {
+            aws_adapter::get_redshift_cluster_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::TransitGateway => {
+            aws_adapter::get_transit_gateway_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::VpcPeering => {
+            aws_adapter::get_vpc_peering_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Vpn => aws_adapter::get_vpn_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::DirectConnect => {
+            aws_adapter::get_direct_connect_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Waf => aws_adapter::get_waf_web_acl_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Acm => aws_adapter::get_acm_certificate_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::CloudFormation => {
+            aws_adapter::get_cloudformation_stack_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Iam => aws_adapter::get_iam_entity_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::VpcEndpoint => {
+            aws_adapter::get_vpc_endpoint_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::NetworkInterface => {
+            aws_adapter::get_network_interface_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::LaunchTemplate => {
+            aws_adapter::get_launch_template_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Ami => aws_adapter::get_ami_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Ebs => aws_adapter::get_ebs_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::KeyPair => aws_adapter::get_key_pair_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::EventBridge => {
+            aws_adapter::get_event_bus_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Cognito => aws_adapter::get_cognito_pool_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Glue => aws_adapter::get_glue_detail(id).and_then(|d| to_json(&d)),
+        ResourceType::Athena => {
+            aws_adapter::get_athena_workgroup_detail(id).and_then(|d| to_json(&d))
+        }
+        ResourceType::Backup => aws_adapter::get_backup_plan_detail(id).and_then(|d| to_json(&d)),
+    }
+}
+
+fn resource_type_label(i18n: &I18n, resource_type: &ResourceType) -> &'static str {
+    match resource_type {
+        ResourceType::Ec2 => i18n.ec2(),
+        ResourceType::Network => i18n.network(),
+        ResourceType::SecurityGroup => i18n.security_group(),
+        ResourceType::LoadBalancer => i18n.load_balancer(),
+        ResourceType::Ecr => i18n.md_ecr_repository(),
+        ResourceType::Asg => i18n.auto_scaling_group(),
+        ResourceType::Rds => i18n.rds_database(),
+        ResourceType::Lambda => i18n.lambda_function(),
+        ResourceType::DynamoDb => i18n.dynamodb_table(),
+        ResourceType::CloudFront => i18n.cloudfront_distribution(),
+        ResourceType::Route53 => i18n.route53_hosted_zone(),
+        ResourceType::Eks => i18n.eks_cluster(),
+        ResourceType::Ecs => i18n.ecs_cluster(),
+        ResourceType::Sqs => i18n.sqs_queue(),
+        ResourceType::Sns => i18n.sns_topic(),
+        ResourceType::ElastiCache => i18n.elasticache_cluster(),
+        ResourceType::CloudWatchAlarm => i18n.cloudwatch_alarm(),
+        ResourceType::CloudTrail => i18n.cloudtrail_trail(),
+        ResourceType::Kms => i18n.kms_key(),
+        ResourceType::Secret => i18n.secret(),
+        ResourceType::SsmParameter => i18n.ssm_parameter_path(),
+        ResourceType::Efs => i18n.efs_file_system(),
+        ResourceType::ApiGateway => i18n.api_gateway_api(),
+        ResourceType::ElasticBeanstalk => i18n.elastic_beanstalk_environment(),
+        ResourceType::StepFunctions => i18n.step_functions_state_machine(),
+        ResourceType::Kinesis => i18n.kinesis_stream(),
+        ResourceType::Msk => i18n.msk_cluster(),
+        ResourceType::OpenSearch => i18n.opensearch_domain(),
+        ResourceType::Redshift => i18n.redshift_cluster(),
+        ResourceType::TransitGateway => i18n.transit_gateway(),
+        ResourceType::VpcPeering => i18n.vpc_peering(),
+        ResourceType::Vpn => i18n.site_to_site_vpn(),
+        ResourceType::DirectConnect => i18n.direct_connect(),
+        ResourceType::Waf => i18n.waf(),
+        ResourceType::Acm => i18n.acm(),
+        ResourceType::CloudFormation => i18n.cloudformation(),
+        ResourceType::Iam => i18n.iam(),
+        ResourceType::VpcEndpoint => i18n.vpc_endpoint(),
+        ResourceType::NetworkInterface => i18n.network_interface(),
+        ResourceType::LaunchTemplate => i18n.asg_launch_template(),
+        ResourceType::Ami => i18n.ami(),
+        ResourceType::Ebs => i18n.ebs(),
+        ResourceType::KeyPair => i18n.key_pairs(),
+        ResourceType::EventBridge => i18n.eventbridge_event_bus(),
+        ResourceType::Cognito => i18n.cognito(),
+        ResourceType::Glue => i18n.glue(),
+        ResourceType::Athena => i18n.athena_workgroup(),
+        ResourceType::Backup => i18n.backup_plan(),
+    }
+}
+
+fn process_blueprint_validation(app: &mut App, current_index: usize) {
+    let resource = match app
+        .current_blueprint
+        .as_ref()
+        .and_then(|bp| bp.resources.get(current_index))
+    {
+        Some(resource) => resource.clone(),
+        None => {
+            let stale = app
+                .blueprint_validation
+                .iter()
+                .filter(|status| status.is_stale())
+                .count();
+            let unverified = app
+                .blueprint_validation
+                .iter()
+                .filter(|status| **status == ResourceStatus::Unknown)
+                .count();
+            aws_adapter::clear_prefetched_instances();
+            finish_loading(app);
+            app.message = app.i18n.validation_complete(stale, unverified);
+            return;
+        }
+    };
+
+    aws_adapter::set_region(&resource.region);
+    if current_index == 0 {
+        aws_adapter::clear_iam_role_cache();
+    }
+    if let Some(blueprint) = &app.current_blueprint {
+        prefetch_blueprint_instances(&blueprint.resources, current_index);
+    }
+
+    let status = validate_resource(&resource, app.regions());
+    app.blueprint_validation.push(status);
+
+    app.loading_task = LoadingTask::ValidateBlueprintResources(current_index + 1);
+}
+
+// 리전별 첫 EC2 리소스 차례에 같은 리전의 나머지 EC2를 한 번에 조회
+fn prefetch_blueprint_instances(resources: &[BlueprintResource], current_index: usize) {
+    let Some(resource) = resources.get(current_index) else {
+        return;
+    };
+    let same_region_ec2 =
+        |r: &BlueprintResource| r.resource_type == ResourceType::Ec2 && r.region == resource.region;
+
+    if !same_region_ec2(resource) || resources[..current_index].iter().any(same_region_ec2) {
+        return;
+    }
+
+    let ids = resources[current_index..]
+        .iter()
+        .filter(|r| same_region_ec2(r))
+        .map(|r| r.resource_id.clone())
+        .collect::<Vec<_>>();
+    aws_adapter::prefetch_instances(&ids);
+}
+
+// 리전을 확인한 뒤 이름 조회; 조회 실패는 "없음" 응답일 때만 Missing으로 봄
+fn validate_resource(resource: &BlueprintResource, regions: &[Region]) -> ResourceStatus {
+    // ARN으로 저장된 리소스는 ARN의 리전이 실제 리전
+    let region = Arn::parse(&resource.resource_id)
+        .map(|arn| arn.region)
+        .filter(|region| !region.is_empty())
+        .unwrap_or(&resource.region)
+        .to_string();
+    // 현재 파티션에 없는 리전은 지금 자격 증명으로 확인할 수 없음
+    if !regions.iter().any(|known| known.code == region) {
+        return ResourceStatus::Unknown;
+    }
+
+    aws_adapter::set_region(&region);
+    aws_adapter::reset_call_failures();
+    match lookup_resource_name(resource) {
+        Some(_) if region != resource.region => ResourceStatus::Moved(region),
+        Some(name) if name == resource.resource_name => ResourceStatus::Valid,
+        Some(name) => ResourceStatus::Renamed(name),
+        None if aws_adapter::call_failure() == CallFailure::Other => ResourceStatus::Unknown,
+        None => ResourceStatus::Missing,
+    }
+}
+
+// 리소스의 현재 이름 조회 (존재하지 않으면 None)
+fn lookup_resource_name(resource: &BlueprintResource) -> Option<String> {
+    let id = resource.resource_id.as_str();
+    match resource.resource_type {
+        ResourceType::Ec2 => aws_adapter::get_instance_detail(id)
+            .filter(|d| d.state != "terminated")
+            .map(|d| d.name),
+        ResourceType::Network => aws_adapter::get_vpc_info(id).map(|info| info.0),
+        ResourceType::SecurityGroup => aws_adapter::get_security_group_detail(id).map(|d| d.name),
+        ResourceType::LoadBalancer => aws_adapter::get_load_balancer_detail(id).map(|d| d.name),
+        ResourceType::Ecr => aws_adapter::get_ecr_detail(id).map(|d| d.name),
+        ResourceType::Asg => aws_adapter::get_asg_detail(id).map(|d| d.name),
+        ResourceType::Rds => aws_adapter::get_rds_detail(id).map(|d| d.identifier),
+        ResourceType::Lambda => aws_adapter::get_lambda_detail(id).map(|d| d.name),
+        ResourceType::DynamoDb => aws_adapter::get_dynamodb_detail(id).map(|d| d.name),
+        ResourceType::CloudFront => {
+            aws_adapter::get_cloudfront_detail(id).map(|d| d.display_name().to_string())
+        }
+        ResourceType::Route53 => aws_adapter::get_route53_detail(id).map(|d| d.name),
+        ResourceType::Eks => aws_adapter::get_eks_detail(id).map(|d| d.name),
+        ResourceType::Ecs => aws_adapter::get_ecs_detail(id).map(|d| d.name),
+        ResourceType::Sqs => aws_adapter::get_sqs_detail(id).map(|d| d.name),
+        ResourceType::Sns => aws_adapter::get_sns_detail(id).map(|d| d.name),
+        ResourceType::ElastiCache => aws_adapter::get_elasticache_detail(id).map(|d| d.id),
+        ResourceType::CloudWatchAlarm => {
+            aws_adapter::get_cloudwatch_alarm_detail(id).map(|d| d.name)
+        }
+        ResourceType::CloudTrail => aws_adapter::get_cloudtrail_detail(id).map(|d| d.name),
+        ResourceType::Kms => aws_adapter::get_kms_key_detail(id).map(|d| d.name),
+        ResourceType::Secret => aws_adapter::get_secret_detail(id).map(|d| d.name),
+        ResourceType::SsmParameter => aws_adapter::get_parameter_path_detail(id).map(|d| d.path),
+        ResourceType::Efs => aws_adapter::get_efs_detail(id).map(|d| d.name),
+        ResourceType::ApiGateway => aws_adapter::get_api_gateway_detail(id).map(|d| d.name),
+        ResourceType::ElasticBeanstalk => {
+            aws_adapter::get_elastic_beanstalk_detail(id).map(|d| d.name)
+        }
+        ResourceType::StepFunctions => aws_adapter::get_state_machine_detail(id).map(|d| d.name),
+        ResourceType::Kinesis => aws_adapter::get_kinesis_stream_detail(id).map(|d| d.name),
+        ResourceType::Msk => aws_adapter::get_msk_cluster_detail(id).map(|d| d.name),
+        ResourceType::OpenSearch => aws_adapter::get_opensearch_domain_detail(id).map(|d| d.name),
+        ResourceType::Redshift => {
+            aws_adapter::get_redshift_cluster_detail(id).map(|d| d.identifier)
+        }
+        ResourceType::TransitGateway => aws_adapter::get_transit_gateway_detail(id).map(|d| d.name),
+        ResourceType::VpcPeering => aws_adapter::get_vpc_peering_detail(id).map(|d| d.name),
+        ResourceType::Vpn => aws_adapter::get_vpn_detail(id).map(|d| d.name),
+        ResourceType::DirectConnect => aws_adapter::get_direct_connect_detail(id).map(|d| d.name),
+        ResourceType::Waf => aws_adapter::get_waf_web_acl_detail(id).map(|d| d.name),
+        ResourceType::Acm => aws_adapter::get_acm_certificate_detail(id).map(|d| d.name),
+        ResourceType::CloudFormation => {
+            aws_adapter::get_cloudformation_stack_detail(id).map(|d| d.name)
+        }
+        ResourceType::Iam => aws_adapter::get_iam_entity_detail(id).map(|d| d.name),
+        ResourceType::VpcEndpoint => aws_adapter::get_vpc_endpoint_detail(id).map(|d| d.name),
+        ResourceType::NetworkInterface => {
+            aws_adapter::get_network_interface_detail(id).map(|d| d.name)
+        }
+        ResourceType::LaunchTemplate => aws_adapter::get_launch_template_detail(id).map(|d| d.name),
+        ResourceType::Ami => aws_adapter::get_ami_detail(id).map(|d| d.name),
+        ResourceType::Ebs => aws_adapter::get_ebs_detail(id).map(|d| d.name),
+        ResourceType::KeyPair => aws_adapter::get_key_pair_detail(id).map(|d| d.name),
+        ResourceType::EventBridge => aws_adapter::get_event_bus_detail(id).map(|d| d.name),
+        ResourceType::Cognito => aws_adapter::get_cognito_pool_detail(id).map(|d| d.name),
+        ResourceType::Glue => aws_adapter::get_glue_detail(id).map(|d| d.name),
+        ResourceType::Athena => aws_adapter::get_athena_workgroup_detail(id).map(|d| d.name),
+        ResourceType::Backup => aws_adapter::get_backup_plan_detail(id).map(|d| d.name),
+    }
+}
+
+fn process_vpc_detail_step(app: &mut App, vpc_id: &str, step: u8) {
+    if step > 0 && app.network_detail.is_none() {
+        tracing::warn!(
+            vpc_id,
+            step,
+            "Network detail aborting because VPC info is missing"
+        );
+        app.message = app.i18n.network_detail_unavailable(vpc_id);
+        finish_loading(app);
+        return;
+    }
+
+    match step {
+        0 => {
+            // Step 0: VPC 기본 정보
+            if let Some(info) = aws_adapter::get_vpc_info(vpc_id) {
+                tracing::info!(
+                    vpc_id,
+                    cidr = %info.1,
+                    state = %info.2,
+                    tag_count = info.3.len(),
+                    "Network detail step 0 loaded VPC info"
+                );
+                app.network_detail = Some(NetworkDetail {
+                    name: info.0,
+                    id: vpc_id.to_string(),
+                    cidr: info.1,
+                    ipv6_cidrs: info.4,
+                    state: info.2,
+                    owner_id: app
+                        .vpcs
+                        .iter()
+                        .find(|vpc| vpc.id == vpc_id)
+                        .map(|vpc| vpc.owner_id.clone())
+                        .unwrap_or_default(),
+                    tags: info.3,
+                    subnets: Vec::new(),
+                    subnet_addresses: Vec::new(),
+                    igws: Vec::new(),
+                    nats: Vec::new(),
+                    route_tables: Vec::new(),
+                    nacls: Vec::new(),
+                    eips: Vec::new(),
+                    peerings: Vec::new(),
+                    endpoints: Vec::new(),
+                    dns_support: false,
+                    dns_hostnames: false,
+                });
+                app.loading_progress.vpc_info = true;
+            } else {
+                tracing::warn!(vpc_id, "Network detail step 0 failed to load VPC info");
+                app.message = app.i18n.network_detail_unavailable(vpc_id);
+                app.network_detail = None;
+                finish_loading(app);
+                return;
+            }
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 1);
+        }
+        1 => {
+            // Step 1: Subnets
+            let (subnets, subnet_addresses) = aws_adapter::list_subnets(vpc_id);
+            tracing::info!(
+                vpc_id,
+                subnet_count = subnets.len(),
+                "Network detail step 1 loaded subnets"
+            );
+            if subnets.is_empty() {
+                tracing::warn!(vpc_id, "Network detail step 1 returned empty subnet list");
+            }
+            if let Some(ref mut detail) = app.network_detail {
+                detail.subnets = subnets;
+                detail.subnet_addresses = subnet_addresses;
+            }
+            app.loading_progress.subnets = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 2);
+        }
+        2 => {
+            // Step 2: Internet Gateways
+            let igws = aws_adapter::list_internet_gateways(vpc_id);
+            tracing::info!(
+                vpc_id,
+                igw_count = igws.len(),
+                "Network detail step 2 loaded internet gateways"
+            );
+            if igws.is_empty() {
+                tracing::warn!(
+                    vpc_id,
+                    "Network detail step 2 returned empty internet gateway list"
+                );
+            }
+            if let Some(ref mut detail) = app.network_detail {
+                detail.igws = igws;
+            }
+            app.loading_progress.igws = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 3);
+        }
+        3 => {
+            // Step 3: NAT Gateways
+            let nats = aws_adapter::list_nat_gateways(vpc_id);
+            tracing::info!(
+                vpc_id,
+                nat_count = nats.len(),
+                "Network detail step 3 loaded NAT gateways"
+            );
+            if nats.is_empty() {
+                tracing::warn!(
+                    vpc_id,
+                    "Network detail step 3 returned empty NAT gateway list"
+                );
+            }
+            if let Some(ref mut detail) = app.network_detail {
+                detail.nats = nats;
+            }
+            app.loading_progress.nats = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 4);
+        }
+        4 => {
+            // Step 4: Route Tables
+            let route_tables = aws_adapter::list_route_tables(vpc_id);
+            tracing::info!(
+                vpc_id,
+                route_table_count = route_tables.len(),
+                "Network detail step 4 loaded route tables"
+            );
+            if route_tables.is_empty() {
+                tracing::warn!(
+                    vpc_id,
+                    "Network detail step 4 returned empty route table list"
+                );
+            }
+            if let Some(ref mut detail) = app.network_detail {
+                detail.route_tables = route_tables;
+            }
+            app.loading_progress.route_tables = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 5);
+        }
+        5 => {
+            // Step 5: Elastic IPs
+            let eips = aws_adapter::list_eips();
+            tracing::info!(
+                vpc_id,
+                eip_count = eips.len(),
+                "Network detail step 5 loaded EIPs"
+            );
+            if eips.is_empty() {
+                tracing::warn!(vpc_id, "Network detail step 5 returned empty EIP list");
+            }
+            if let Some(ref mut detail) = app.network_detail {
+                detail.eips = eips;
+            }
+            app.loading_progress.eips = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 6);
+        }
+        6 => {
+            // Step 6: VPC Peering
+            let peerings = aws_adapter::list_vpc_peerings(vpc_id);
+            tracing::info!(
+                vpc_id,
+                peering_count = peerings.len(),
+                "Network detail step 6 loaded VPC peering connections"
+            );
+            if let Some(ref mut detail) = app.network_detail {
+                detail.peerings = peerings;
+            }
+            app.loading_progress.peerings = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 7);
+        }
+        7 => {
+            // Step 7: VPC Endpoints
+            let endpoints = aws_adapter::list_vpc_endpoints_in(vpc_id);
+            tracing::info!(
+                vpc_id,
+                endpoint_count = endpoints.len(),
+                "Network detail step 7 loaded VPC endpoints"
+            );
+            if let Some(ref mut detail) = app.network_detail {
+                detail.endpoints = endpoints;
+            }
+            app.loading_progress.endpoints = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 8);
+        }
+        8 => {
+            // Step 8: Network ACLs
+            let nacls = aws_adapter::list_network_acls(vpc_id);
+            tracing::info!(
+                vpc_id,
+                nacl_count = nacls.len(),
+                "Network detail step 8 loaded network ACLs"
+            );
+            if let Some(ref mut detail) = app.network_detail {
+                detail.nacls = nacls;
+            }
+            app.loading_progress.nacls = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 9);
+        }
+        9 => {
+            // Step 9: DNS Attributes
+            tracing::debug!(vpc_id, "Network detail step 9 loading DNS attributes");
+            if let Some(ref mut detail) = app.network_detail {
+                detail.dns_support = aws_adapter::get_vpc_dns_support(vpc_id);
+                detail.dns_hostnames = aws_adapter::get_vpc_dns_hostnames(vpc_id);
+                tracing::info!(
+                    vpc_id,
+                    dns_support = detail.dns_support,
+                    dns_hostnames = detail.dns_hostnames,
+                    "Network detail step 9 loaded DNS attributes"
+                );
+            }
+            app.loading_progress.dns_attrs = true;
+            app.loading_task = LoadingTask::LoadVpcDetail(vpc_id.to_string(), 10);
+        }
+        _ => {
+            // 완료: Preview 화면으로 전환
+            if let Some(ref detail) = app.network_detail {
+                app.preview_content = detail.to_markdown(app.settings.language);
+                app.preview_filename = format!("{}.md", detail.name);
+            }
+            app.screen = Screen::Preview;
+            finish_loading(app);
+        }
+    }
+}
+
+fn finish_loading(app: &mut App) {
+    app.loading = false;
+    app.loading_task = LoadingTask::None;
+    app.loading_progress.reset();
+}
+
+/// Re-run the current list screen's Refresh task when its `auto_refresh_seconds`
+/// interval has passed. The cursor stays on the same resource after the refresh.
+pub fn auto_refresh_list(app: &mut App) {
+    if app.loading {
+        return;
+    }
+    if let Some(task) = app.due_auto_refresh() {
+        start_loading(app, task);
+    }
+}
+
+// 최근에 조회한 목록이 있으면 바로 보여주고, 없으면 조회 시작
+fn open_service_list(app: &mut App, task: LoadingTask) {
+    if let Some(screen) = task.list_screen()
+        && app.open_cached_list(screen)
+    {
+        return;
+    }
+    start_loading(app, task);
+}
+
+fn start_loading(app: &mut App, task: LoadingTask) {
+    app.loading = true;
+    app.loading_progress.reset();
+    app.loading_task = task;
+    app.message = app.i18n.loading_msg().to_string();
+}
+
+fn handle_login(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_profile_index > 0 {
+                app.selected_profile_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_profile_index + 1 < app.available_profiles.len() {
+                app.selected_profile_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            app.select_current_profile_and_login();
+        }
+        KeyCode::Char('r') => {
+            app.refresh_profiles();
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_blueprint_select(app: &mut App, key: KeyEvent) {
+    let list_len = app.blueprint_store.blueprints.len() + 1; // +1 for "새 블루프린터"
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_blueprint_index > 0 {
+                app.selected_blueprint_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_blueprint_index < list_len - 1 {
+                app.selected_blueprint_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_blueprint_index == 0 {
+                // "새 블루프린터" 선택
+                app.input_buffer.clear();
+                app.screen = Screen::BlueprintNameInput;
+            } else {
+                // 기존 블루프린터 선택
+                let bp_index = app.selected_blueprint_index - 1;
+                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
+                    app.current_blueprint = Some(bp.clone());
+                    app.selected_blueprint_index = bp_index;
+                    app.blueprint_resource_index = 0;
+                    app.blueprint_validation.clear();
+                    app.screen = Screen::BlueprintDetail;
+                }
+            }
+        }
+        KeyCode::Char('g') => {
+            // 선택된 블루프린터의 마크다운 생성
+            if app.selected_blueprint_index > 0 {
+                let bp_index = app.selected_blueprint_index - 1;
+                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
+                    if !bp.resources.is_empty() {
+                        app.current_blueprint = Some(bp.clone());
+                        app.selected_blueprint_index = bp_index;
+                        app.blueprint_markdown_parts.clear();
+                        app.blueprint_resource_facts.clear();
+                        start_loading(app, LoadingTask::LoadBlueprintResources(0));
+                    } else {
+                        app.message = app.i18n.no_resources().to_string();
+                    }
+                }
+            }
+        }
+        KeyCode::Char('d') => {
+            // 블루프린터 삭제
+            if app.selected_blueprint_index > 0 {
+                let bp_index = app.selected_blueprint_index - 1;
+                app.promotion_source = None;
+                app.delete_blueprint(bp_index);
+                if app.selected_blueprint_index > 0 {
+                    app.selected_blueprint_index = 1.min(app.blueprint_store.blueprints.len());
+                }
+            }
+        }
+        KeyCode::Char('s') => {
+            // 단일 리소스 모드로 전환 (리전 선택)
+            app.blueprint_mode = false;
+            app.screen = Screen::RegionSelect;
+        }
+        KeyCode::Char('t') => {
+            // Terraform 상태 파일에서 블루프린트 가져오기
+            app.input_buffer.clear();
+            app.blueprint_import_kind = BlueprintImportKind::Terraform;
+            app.screen = Screen::BlueprintImportInput;
+        }
+        KeyCode::Char('c') => {
+            // CloudFormation 스택에서 블루프린트 가져오기
+            app.input_buffer.clear();
+            app.blueprint_import_kind = BlueprintImportKind::CloudFormation;
+            app.screen = Screen::BlueprintImportInput;
+        }
+        KeyCode::Char('r') => {
+            // 원격 저장소와 동기화
+            app.sync_blueprints();
+        }
+        KeyCode::Char('f') => {
+            // 태그 조건(Resource Groups Tagging API)으로 블루프린트 만들기
+            app.input_buffer.clear();
+            app.blueprint_import_kind = BlueprintImportKind::TagQuery;
+            app.screen = Screen::BlueprintImportInput;
+        }
+        KeyCode::Char('p') => {
+            // 승격 체크리스트: 원본(예: staging)에서 p, 대상(예: prod)에서 다시 p
+            if app.selected_blueprint_index > 0 {
+                let bp_index = app.selected_blueprint_index - 1;
+                match app.promotion_source.take() {
+                    Some(source) if source != bp_index => {
+                        app.current_blueprint = None;
+                        start_loading(app, LoadingTask::LoadPromotionChecklist(source, bp_index));
+                    }
+                    Some(_) => app.message = app.i18n.promotion_cleared().to_string(),
+                    None => {
+                        if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
+                            app.message = app.i18n.promotion_source_marked(&bp.name);
+                            app.promotion_source = Some(bp_index);
+                        }
+                    }
+                }
+            }
+        }
+        KeyCode::Char('/') => {
+            // 저장한 문서 전문 검색
+            app.input_buffer.clear();
+            app.search_results.clear();
+            app.selected_search_result = 0;
+            app.screen = Screen::DocumentSearch;
+        }
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+            // Switch to Settings tab
+            app.selected_tab = 1;
+            app.selected_setting = 0;
+            app.screen = Screen::Settings;
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_blueprint_detail(app: &mut App, key: KeyEvent) {
+    let resource_len = app
+        .current_blueprint
+        .as_ref()
+        .map(|bp| bp.resources.len())
+        .unwrap_or(0);
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            // Shift+Up/K: 리소스 위로 이동
+            if app.move_resource_up(app.blueprint_resource_index) {
+                app.blueprint_resource_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            // Shift+Down/J: 리소스 아래로 이동
+            if app.move_resource_down(app.blueprint_resource_index) {
+                app.blueprint_resource_index += 1;
+            }
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.blueprint_resource_index > 0 {
+                app.blueprint_resource_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.blueprint_resource_index < resource_len.saturating_sub(1) {
+                app.blueprint_resource_index += 1;
+            }
+        }
+        KeyCode::Char('a') => {
+            // 리소스 추가 (리전 선택으로 이동)
+            app.blueprint_mode = true;
+            app.screen = Screen::RegionSelect;
+        }
+        KeyCode::Char('d') => {
+            // 리소스 삭제
+            if resource_len > 0 {
+                app.remove_resource_from_current_blueprint(app.blueprint_resource_index);
+                if app.blueprint_resource_index >= resource_len.saturating_sub(1)
+                    && app.blueprint_resource_index > 0
+                {
+                    app.blueprint_resource_index -= 1;
+                }
+            }
+        }
+        KeyCode::Enter | KeyCode::Char('g') => {
+            // 마크다운 생성
+            if let Some(ref bp) = app.current_blueprint {
+                if !bp.resources.is_empty() {
+                    app.blueprint_markdown_parts.clear();
+                    app.blueprint_resource_facts.clear();
+                    start_loading(app, LoadingTask::LoadBlueprintResources(0));
+                } else {
+                    app.message = app.i18n.no_resources().to_string();
+                }
+            }
+        }
+        KeyCode::Char('v') => {
+            // 리소스 존재 여부 검증
+            if resource_len > 0 {
+                app.blueprint_validation.clear();
+                start_loading(app, LoadingTask::ValidateBlueprintResources(0));
+            } else {
+                app.message = app.i18n.no_resources().to_string();
+            }
+        }
+        KeyCode::Char('m') => {
+            // 이름이나 리전이 바뀐 리소스를 현재 값으로 갱신
+            let remapped = app.remap_renamed_resources();
+            app.message = app.i18n.resources_remapped(remapped);
+        }
+        KeyCode::Char('x') => {
+            // 존재하지 않는 리소스 일괄 제거
+            let removed = app.remove_missing_resources();
+            app.message = app.i18n.missing_resources_removed(removed);
+            let remaining = app
+                .current_blueprint
+                .as_ref()
+                .map(|bp| bp.resources.len())
+                .unwrap_or(0);
+            app.blueprint_resource_index = app
+                .blueprint_resource_index
+                .min(remaining.saturating_sub(1));
+        }
+        KeyCode::Esc => {
+            app.current_blueprint = None;
+            app.blueprint_validation.clear();
+            app.screen = Screen::BlueprintSelect;
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_blueprint_name_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            if !app.input_buffer.trim().is_empty() {
+                let name = app.input_buffer.trim().to_string();
+                app.create_blueprint(name);
+                // 새로 생성된 블루프린터 선택
+                let bp_index = app.blueprint_store.blueprints.len() - 1;
+                if let Some(bp) = app.blueprint_store.get_blueprint(bp_index) {
+                    app.current_blueprint = Some(bp.clone());
+                    app.selected_blueprint_index = bp_index;
+                    app.blueprint_resource_index = 0;
+                    app.screen = Screen::BlueprintDetail;
+                }
+            }
+            app.input_buffer.clear();
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.screen = Screen::BlueprintSelect;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+fn handle_blueprint_import_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            let input = app.input_buffer.trim().to_string();
+            if !input.is_empty() {
+                match app.blueprint_import_kind {
+                    BlueprintImportKind::Terraform => app.import_terraform_blueprint(&input),
+                    BlueprintImportKind::CloudFormation => {
+                        start_loading(app, LoadingTask::ImportCloudFormationStack(input));
+                    }
+                    BlueprintImportKind::TagQuery => {
+                        start_loading(app, LoadingTask::ImportTaggedResources(input));
+                    }
+                }
+            }
+            app.input_buffer.clear();
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.screen = Screen::BlueprintSelect;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+fn handle_document_search(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.search_documents(),
+        KeyCode::Up => {
+            app.selected_search_result = app.selected_search_result.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if app.selected_search_result + 1 < app.search_results.len() {
+                app.selected_search_result += 1;
+            }
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            app.search_results.clear();
+            app.screen = Screen::BlueprintSelect;
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+fn handle_blueprint_preview(app: &mut App, key: KeyEvent) {
+    let content_lines = app.preview_content.lines().count() as u16;
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(1)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::PageUp => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(20);
+        }
+        KeyCode::PageDown => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(20)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::Home => {
+            app.preview_scroll = 0;
+        }
+        KeyCode::End => {
+            app.preview_scroll = content_lines.saturating_sub(1);
+        }
+        KeyCode::Left | KeyCode::Char('h') => app.scroll_preview_left(),
+        KeyCode::Right | KeyCode::Char('l') => app.scroll_preview_right(),
+        KeyCode::Char('w') => app.toggle_preview_wrap(),
+        KeyCode::Enter | KeyCode::Char('s') => {
+            let _ = app.save_file();
+        }
+        KeyCode::Char('f') => save_blueprint_files(app),
+        KeyCode::Char('n') => app.export_to_notion(),
+        KeyCode::Char('b') => {
+            let _ = app.export_backstage_catalog();
+        }
+        KeyCode::Char('i') => {
+            let _ = app.export_inventory();
+        }
+        KeyCode::Esc => {
+            app.preview_scroll = 0;
+            app.preview_hscroll = 0;
+            if app.current_blueprint.is_some() {
+                // 블루프린터 상세로 돌아가기
+                app.screen = Screen::BlueprintDetail;
+            } else {
+                app.screen = Screen::BlueprintSelect;
+            }
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_region_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_region > 0 {
+                app.selected_region -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_region < app.regions().len() - 1 {
+                app.selected_region += 1;
+            }
+        }
+        KeyCode::Enter => app.select_region(),
+        KeyCode::Esc => {
+            if app.blueprint_mode {
+                app.screen = Screen::BlueprintDetail;
+            } else {
+                app.screen = Screen::BlueprintSelect;
+            }
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_service_select(app: &mut App, key: KeyEvent) {
+    let total_items = app.service_order().len() + 1; // +1 for exit
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_service > 0 {
+                app.selected_service -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_service < total_items - 1 {
+                app.selected_service += 1;
+            }
+        }
+        KeyCode::Enter => {
+            let service = app.selected_service_key();
+            app.record_service_usage(service);
+            let task = match service {
+                0 => LoadingTask::LoadEc2,
+                1 => LoadingTask::LoadVpc,
+                2 => LoadingTask::LoadSecurityGroup,
+                3 => LoadingTask::LoadLoadBalancer,
+                4 => LoadingTask::LoadEcr,
+                5 => LoadingTask::LoadAsg,
+                6 => LoadingTask::LoadRds,
+                7 => LoadingTask::LoadLambda,
+                8 => LoadingTask::LoadDynamoDb,
+                9 => LoadingTask::LoadCloudFront,
+                10 => LoadingTask::LoadRoute53,
+                11 => LoadingTask::LoadEks,
+                12 => LoadingTask::LoadEcs,
+                13 => LoadingTask::LoadSqs,
+                14 => LoadingTask::LoadSns,
+                15 => LoadingTask::LoadElastiCache,
+                16 => LoadingTask::LoadCloudWatchAlarm,
+                17 => LoadingTask::LoadCloudTrail,
+                18 => LoadingTask::LoadKms,
+                19 => LoadingTask::LoadSecret,
+                20 => LoadingTask::LoadSsmParameter,
+                21 => LoadingTask::LoadEfs,
+                22 => LoadingTask::LoadApiGateway,
+                23 => LoadingTask::LoadElasticBeanstalk,
+                24 => LoadingTask::LoadStepFunctions,
+                25 => LoadingTask::LoadKinesis,
+                26 => LoadingTask::LoadMsk,
+                27 => LoadingTask::LoadOpenSearch,
+                28 => LoadingTask::LoadRedshift,
+                29 => LoadingTask::LoadTransitGateway,
+                30 => LoadingTask::LoadVpcPeering,
+                31 => LoadingTask::LoadVpn,
+                32 => LoadingTask::LoadDirectConnect,
+                33 => LoadingTask::LoadWaf,
+                34 => LoadingTask::LoadAcm,
+                35 => LoadingTask::LoadCloudFormation,
+                36 => LoadingTask::LoadIam,
+                37 => LoadingTask::LoadVpcEndpoint,
+                38 => LoadingTask::LoadNetworkInterface,
+                39 => LoadingTask::LoadLaunchTemplate,
+                40 => LoadingTask::LoadAmi,
+                41 => LoadingTask::LoadEbs,
+                42 => LoadingTask::LoadKeyPair,
+                43 => LoadingTask::LoadEventBridge,
+                44 => LoadingTask::LoadCognito,
+                45 => LoadingTask::LoadGlue,
+                46 => LoadingTask::LoadAthena,
+                47 => LoadingTask::LoadBackup,
+                48 => {
+                    // Exit
+                    if app.blueprint_mode {
+                        app.screen = Screen::BlueprintDetail;
+                    } else {
+                        app.running = false;
+                    }
+                    return;
+                }
+                _ => return,
+            };
+            open_service_list(app, task);
+        }
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+            // Switch to Settings tab
+            app.selected_tab = 1;
+            app.selected_setting = 0;
+            app.screen = Screen::Settings;
+        }
+        KeyCode::Char('f') => app.toggle_pinned_service(),
+        // 숨기지 않은 모든 서비스의 목록과 상세를 한 문서로
+        KeyCode::Char('d') => start_loading(app, LoadingTask::DocumentRegion(0)),
+        KeyCode::Char('p') => {
+            // JMESPath 쿼리 플레이그라운드
+            app.selected_query_operation = 0;
+            app.query_output = None;
+            app.input_buffer.clear();
+            app.screen = Screen::QueryPlayground;
+        }
+        KeyCode::Char('q') => app.running = false,
+        KeyCode::Esc => app.screen = Screen::RegionSelect,
+        _ => {}
+    }
+}
+
+fn handle_query_playground(app: &mut App, key: KeyEvent) {
+    // 작업 실행 전에는 작업 선택, 실행 후에는 쿼리 입력
+    if app.query_output.is_none() {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.selected_query_operation = app.selected_query_operation.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if app.selected_query_operation + 1 < QUERY_OPERATIONS.len() {
+                    app.selected_query_operation += 1;
+                }
+            }
+            KeyCode::Enter => {
+                start_loading(
+                    app,
+                    LoadingTask::RunQueryOperation(app.selected_query_operation),
+                );
+            }
+            KeyCode::Esc => app.screen = Screen::ServiceSelect,
+            KeyCode::Char('q') => app.running = false,
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Up => app.query_scroll = app.query_scroll.saturating_sub(1),
+        KeyCode::Down => app.query_scroll = app.query_scroll.saturating_add(1),
+        KeyCode::PageUp => app.query_scroll = app.query_scroll.saturating_sub(20),
+        KeyCode::PageDown => app.query_scroll = app.query_scroll.saturating_add(20),
+        KeyCode::Enter => {
+            let _ = app.save_query_result();
+        }
+        KeyCode::Esc => {
+            app.query_output = None;
+            app.input_buffer.clear();
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+            app.apply_query();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+            app.apply_query();
+        }
+        _ => {}
+    }
+}
+
+fn handle_ec2_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.instances.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.instances.len() {
+                let inst = &app.instances[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Ec2,
+                        inst.id.clone(),
+                        inst.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEc2Detail(inst.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEc2);
+        }
+        KeyCode::Char('N') => start_name_tag_edit(app, ResourceType::Ec2),
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+// Name 태그를 편집할 수 있는 EC2 리소스와 Tagging API ARN의 리소스 종류
+fn name_tag_resource_kind(resource_type: &ResourceType) -> Option<&'static str> {
+    match resource_type {
+        ResourceType::Ec2 => Some("instance"),
+        ResourceType::Network => Some("vpc"),
+        ResourceType::SecurityGroup => Some("security-group"),
+        _ => None,
+    }
+}
+
+fn start_name_tag_edit(app: &mut App, resource_type: ResourceType) {
+    if !app.allow_write {
+        app.message = app.i18n.write_mode_disabled().to_string();
+        return;
+    }
+    let resources = match resource_type {
+        ResourceType::Ec2 => &app.instances,
+        ResourceType::Network => &app.vpcs,
+        ResourceType::SecurityGroup => &app.security_groups,
+        _ => return,
+    };
+    let Some(resource_id) = resources.get(app.selected_index).map(|r| r.id.clone()) else {
+        return;
+    };
+
+    let current_name = aws_adapter::indexed_name(&resource_id);
+    app.input_buffer = current_name.clone().unwrap_or_default();
+    app.name_tag_edit = Some(NameTagEdit {
+        resource_type,
+        resource_id,
+        current_name,
+        return_screen: app.screen.clone(),
+    });
+    app.screen = Screen::NameTagInput;
+}
+
+fn handle_name_tag_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            // 빈 이름은 태그 삭제와 같으므로 확인 단계로 넘기지 않음
+            if !app.input_buffer.trim().is_empty() {
+                app.screen = Screen::NameTagConfirm;
+            }
+        }
+        KeyCode::Esc => {
+            app.input_buffer.clear();
+            if let Some(edit) = app.name_tag_edit.take() {
+                app.screen = edit.return_screen;
+            }
+        }
+        KeyCode::Backspace => {
+            app.input_buffer.pop();
+        }
+        KeyCode::Char(c) => {
+            app.input_buffer.push(c);
+        }
+        _ => {}
+    }
+}
+
+fn handle_name_tag_confirm(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => start_loading(app, LoadingTask::SetNameTag),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            app.screen = Screen::NameTagInput;
+        }
+        _ => {}
+    }
+}
+
+fn handle_vpc_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.vpcs.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.vpcs.len() {
+                let vpc = &app.vpcs[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Network,
+                        vpc.id.clone(),
+                        vpc.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadVpcDetail(vpc.id.clone(), 0));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshVpc);
+        }
+        KeyCode::Char('N') => start_name_tag_edit(app, ResourceType::Network),
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_security_group_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.security_groups.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.security_groups.len() {
+                let sg = &app.security_groups[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::SecurityGroup,
+                        sg.id.clone(),
+                        sg.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadSecurityGroupDetail(sg.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshSecurityGroup);
+        }
+        KeyCode::Char('N') => start_name_tag_edit(app, ResourceType::SecurityGroup),
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_load_balancer_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.load_balancers.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.load_balancers.len() {
+                let lb = &app.load_balancers[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::LoadBalancer,
+                        lb.id.clone(),
+                        lb.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadLoadBalancerDetail(lb.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshLoadBalancer);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_ecr_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.ecr_repositories.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.ecr_repositories.len() {
+                let repo = &app.ecr_repositories[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Ecr,
+                        repo.id.clone(),
+                        repo.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEcrDetail(repo.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEcr);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_preview(app: &mut App, key: KeyEvent) {
+    let content_lines = app.preview_text().lines().count() as u16;
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(1)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::PageUp => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(20);
+        }
+        KeyCode::PageDown => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(20)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::Home => {
+            app.preview_scroll = 0;
+        }
+        KeyCode::End => {
+            app.preview_scroll = content_lines.saturating_sub(1);
+        }
+        KeyCode::Left | KeyCode::Char('h') => app.scroll_preview_left(),
+        KeyCode::Right | KeyCode::Char('l') => app.scroll_preview_right(),
+        KeyCode::Char('w') => app.toggle_preview_wrap(),
+        KeyCode::Enter | KeyCode::Char('s') => {
+            let _ = app.save_file();
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshPreview);
+        }
+        KeyCode::Char('v') => app.toggle_preview_raw(),
+        KeyCode::Char('n') => app.export_to_notion(),
+        KeyCode::Char('b') => {
+            let _ = app.export_backstage_catalog();
+        }
+        KeyCode::Char('i') => {
+            let _ = app.export_inventory();
+        }
+        KeyCode::Char('a') => {
+            // 블루프린트 모드일 때만 리소스 추가
+            if app.blueprint_mode
+                && let (Some(resource_type), Some((resource_id, resource_name))) = (
+                    app.get_current_resource_type(),
+                    app.get_current_resource_info(),
+                )
+            {
+                let region = app.get_current_region();
+                let resource = BlueprintResource {
+                    resource_type,
+                    region,
+                    resource_id,
+                    resource_name,
+                };
+                app.add_resource_to_current_blueprint(resource);
+
+                // 리소스 정리 후 블루프린트 상세로 돌아가기
+                app.ec2_detail = None;
+                app.network_detail = None;
+                app.sg_detail = None;
+                app.lb_detail = None;
+                app.ecr_detail = None;
+                app.asg_detail = None;
+                app.rds_detail = None;
+                app.lambda_detail = None;
+                app.dynamodb_detail = None;
+                app.cloudfront_detail = None;
+                app.route53_detail = None;
+                app.eks_detail = None;
+                app.ecs_detail = None;
+                app.sqs_detail = None;
+                app.sns_detail = None;
+                app.elasticache_detail = None;
+                app.cloudwatch_alarm_detail = None;
+                app.cloudtrail_detail = None;
+                app.kms_detail = None;
+                app.secret_detail = None;
+                app.ssm_parameter_detail = None;
+                app.efs_detail = None;
+                app.api_gateway_detail = None;
+                app.elastic_beanstalk_detail = None;
+                app.state_machine_detail = None;
+                app.kinesis_stream_detail = None;
+                app.msk_cluster_detail = None;
+                app.opensearch_domain_detail = None;
+                app.redshift_cluster_detail = None;
+                app.transit_gateway_detail = None;
+                app.vpc_peering_detail = None;
+                app.vpn_detail = None;
+                app.direct_connect_detail = None;
+                app.waf_web_acl_detail = None;
+                app.acm_certificate_detail = None;
+                app.cloudformation_stack_detail = None;
+                app.iam_entity_detail = None;
+                app.vpc_endpoint_detail = None;
+                app.network_interface_detail = None;
+                app.launch_template_detail = None;
+                app.ami_detail = None;
+                app.ebs_detail = None;
+                app.key_pair_detail = None;
+                app.event_bus_detail = None;
+                app.cognito_detail = None;
+                app.glue_detail = None;
+                app.athena_workgroup_detail = None;
+                app.backup_plan_detail = None;
+                app.preview_scroll = 0;
+                app.preview_raw = None;
+                app.screen = Screen::BlueprintDetail;
+            }
+        }
+        KeyCode::Esc => {
+            app.preview_scroll = 0;
+            app.preview_hscroll = 0;
+            app.preview_raw = None;
+            if app.blueprint_mode {
+                // 블루프린트 모드: 블루프린트 상세로 돌아가기
+                app.ec2_detail = None;
+                app.network_detail = None;
+                app.sg_detail = None;
+                app.lb_detail = None;
+                app.ecr_detail = None;
+                app.asg_detail = None;
+                app.rds_detail = None;
+                app.lambda_detail = None;
+                app.dynamodb_detail = None;
+                app.cloudfront_detail = None;
+                app.route53_detail = None;
+                app.eks_detail = None;
+                app.ecs_detail = None;
+                app.sqs_detail = None;
+                app.sns_detail = None;
+                app.elasticache_detail = None;
+                app.cloudwatch_alarm_detail = None;
+                app.cloudtrail_detail = None;
+                app.kms_detail = None;
+                app.secret_detail = None;
+                app.ssm_parameter_detail = None;
+                app.efs_detail = None;
+                app.api_gateway_detail = None;
+                app.elastic_beanstalk_detail = None;
+                app.state_machine_detail = None;
+                app.kinesis_stream_detail = None;
+                app.msk_cluster_detail = None;
+                app.opensearch_domain_detail = None;
+                app.redshift_cluster_detail = None;
+                app.transit_gateway_detail = None;
+                app.vpc_peering_detail = None;
+                app.vpn_detail = None;
+                app.direct_connect_detail = None;
+                app.waf_web_acl_detail = None;
+                app.acm_certificate_detail = None;
+                app.cloudformation_stack_detail = None;
+                app.iam_entity_detail = None;
+                app.vpc_endpoint_detail = None;
+                app.network_interface_detail = None;
+                app.launch_template_detail = None;
+                app.ami_detail = None;
+                app.ebs_detail = None;
+                app.key_pair_detail = None;
+                app.event_bus_detail = None;
+                app.cognito_detail = None;
+                app.glue_detail = None;
+                app.athena_workgroup_detail = None;
+                app.backup_plan_detail = None;
+                app.screen = Screen::BlueprintDetail;
+            } else if app.ec2_detail.is_some() {
+                app.ec2_detail = None;
+                app.screen = Screen::Ec2Select;
+            } else if app.network_detail.is_some() {
+                app.network_detail = None;
+                app.screen = Screen::VpcSelect;
+            } else if app.sg_detail.is_some() {
+                app.sg_detail = None;
+                app.screen = Screen::SecurityGroupSelect;
+            } else if app.lb_detail.is_some() {
+                app.lb_detail = None;
+                app.screen = Screen::LoadBalancerSelect;
+            } else if app.ecr_detail.is_some() {
+                app.ecr_detail = None;
+                app.screen = Screen::EcrSelect;
+            } else if app.asg_detail.is_some() {
+                app.asg_detail = None;
+                app.screen = Screen::AsgSelect;
+            } else if app.rds_detail.is_some() {
+                app.rds_detail = None;
+                app.screen = Screen::RdsSelect;
+            } else if app.lambda_detail.is_some() {
+                app.lambda_detail = None;
+                app.screen = Screen::LambdaSelect;
+            } else if app.dynamodb_detail.is_some() {
+                app.dynamodb_detail = None;
+                app.screen = Screen::DynamoDbSelect;
+            } else if app.cloudfront_detail.is_some() {
+                app.cloudfront_detail = None;
+                app.screen = Screen::CloudFrontSelect;
+            } else if app.route53_detail.is_some() {
+                app.route53_detail = None;
+                app.screen = Screen::Route53Select;
+            } else if app.eks_detail.is_some() {
+                app.eks_detail = None;
+                app.screen = Screen::EksSelect;
+            } else if app.ecs_detail.is_some() {
+                app.ecs_detail = None;
+                app.screen = Screen::EcsSelect;
+            } else if app.sqs_detail.is_some() {
+                app.sqs_detail = None;
+                app.screen = Screen::SqsSelect;
+            } else if app.sns_detail.is_some() {
+                app.sns_detail = None;
+                app.screen = Screen::SnsSelect;
+            } else if app.elasticache_detail.is_some() {
+                app.elasticache_detail = None;
+                app.screen = Screen::ElastiCacheSelect;
+            } else if app.cloudwatch_alarm_detail.is_some() {
+                app.cloudwatch_alarm_detail = None;
+                app.screen = Screen::CloudWatchAlarmSelect;
+            } else if app.cloudtrail_detail.is_some() {
+                app.cloudtrail_detail = None;
+                app.screen = Screen::CloudTrailSelect;
+            } else if app.kms_detail.is_some() {
+                app.kms_detail = None;
+                app.screen = Screen::KmsSelect;
+            } else if app.secret_detail.is_some() {
+                app.secret_detail = None;
+                app.screen = Screen::SecretSelect;
+            } else if app.ssm_parameter_detail.is_some() {
+                app.ssm_parameter_detail = None;
+                app.screen = Screen::SsmParameterSelect;
+            } else if app.efs_detail.is_some() {
+                app.efs_detail = None;
+                app.screen = Screen::EfsSelect;
+            } else if app.api_gateway_detail.is_some() {
+                app.api_gateway_detail = None;
+                app.screen = Screen::ApiGatewaySelect;
+            } else if app.elastic_beanstalk_detail.is_some() {
+                app.elastic_beanstalk_detail = None;
+                app.screen = Screen::ElasticBeanstalkSelect;
+            } else if app.state_machine_detail.is_some() {
+                app.state_machine_detail = None;
+                app.screen = Screen::StepFunctionsSelect;
+            } else if app.kinesis_stream_detail.is_some() {
+                app.kinesis_stream_detail = None;
+                app.screen = Screen::KinesisSelect;
+            } else if app.msk_cluster_detail.is_some() {
+                app.msk_cluster_detail = None;
+                app.screen = Screen::MskSelect;
+            } else if app.opensearch_domain_detail.is_some() {
+                app.opensearch_domain_detail = None;
+                app.screen = Screen::OpenSearchSelect;
+            } else if app.redshift_cluster_detail.is_some() {
+                app.redshift_cluster_detail = None;
+                app.screen = Screen::RedshiftSelect;
+            } else if app.transit_gateway_detail.is_some() {
+                app.transit_gateway_detail = None;
+                app.screen = Screen::TransitGatewaySelect;
+            } else if app.vpc_peering_detail.is_some() {
+                app.vpc_peering_detail = None;
+                app.screen = Screen::VpcPeeringSelect;
+            } else if app.vpn_detail.is_some() {
+                app.vpn_detail = None;
+                app.screen = Screen::VpnSelect;
+            } else if app.direct_connect_detail.is_some() {
+                app.direct_connect_detail = None;
+                app.screen = Screen::DirectConnectSelect;
+            } else if app.waf_web_acl_detail.is_some() {
+                app.waf_web_acl_detail = None;
+                app.screen = Screen::WafSelect;
+            } else if app.acm_certificate_detail.is_some() {
+                app.acm_certificate_detail = None;
+                app.screen = Screen::AcmSelect;
+            } else if app.cloudformation_stack_detail.is_some() {
+                app.cloudformation_stack_detail = None;
+                app.screen = Screen::CloudFormationSelect;
+            } else if app.iam_entity_detail.is_some() {
+                app.iam_entity_detail = None;
+                app.screen = Screen::IamSelect;
+            } else if app.vpc_endpoint_detail.is_some() {
+                app.vpc_endpoint_detail = None;
+                app.screen = Screen::VpcEndpointSelect;
+            } else if app.network_interface_detail.is_some() {
+                app.network_interface_detail = None;
+                app.screen = Screen::NetworkInterfaceSelect;
+            } else if app.launch_template_detail.is_some() {
+                app.launch_template_detail = None;
+                app.screen = Screen::LaunchTemplateSelect;
+            } else if app.ami_detail.is_some() {
+                app.ami_detail = None;
+                app.screen = Screen::AmiSelect;
+            } else if app.ebs_detail.is_some() {
+                app.ebs_detail = None;
+                app.screen = Screen::EbsSelect;
+            } else if app.key_pair_detail.is_some() {
+                app.key_pair_detail = None;
+                app.screen = Screen::KeyPairSelect;
+            } else if app.event_bus_detail.is_some() {
+                app.event_bus_detail = None;
+                app.screen = Screen::EventBridgeSelect;
+            } else if app.cognito_detail.is_some() {
+                app.cognito_detail = None;
+                app.screen = Screen::CognitoSelect;
+            } else if app.glue_detail.is_some() {
+                app.glue_detail = None;
+                app.screen = Screen::GlueSelect;
+            } else if app.athena_workgroup_detail.is_some() {
+                app.athena_workgroup_detail = None;
+                app.screen = Screen::AthenaSelect;
+            } else if app.backup_plan_detail.is_some() {
+                app.backup_plan_detail = None;
+                app.screen = Screen::BackupSelect;
+            } else {
+                app.screen = Screen::ServiceSelect;
+            }
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+// 언어, 로컬 데이터 암호화, 종료된 인스턴스 숨기기, 서비스 사용 빈도 정렬, 색상 팔레트, ASCII 모드, 최대 동시 요청 수, 숫자/날짜 표기, 일정 표현식 풀어 쓰기, 보안 그룹 규칙 정리, CloudTrail 마지막 변경 표시
+const SETTINGS_COUNT: usize = 12;
+
+fn handle_settings(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_setting > 0 {
+                app.selected_setting -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_setting + 1 < SETTINGS_COUNT {
+                app.selected_setting += 1;
+            }
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            // Toggle current setting
+            match app.selected_setting {
+                0 => app.toggle_language(),
+                1 => app.toggle_encrypt_at_rest(),
+                2 => app.toggle_hide_terminated_instances(),
+                3 => app.toggle_sort_services_by_usage(),
+                4 => app.cycle_palette(),
+                5 => app.cycle_ascii_mode(),
+                6 => app.cycle_max_concurrent_requests(),
+                7 => app.cycle_number_locale(),
+                8 => app.toggle_spell_out_schedules(),
+                9 => app.toggle_normalize_security_group_rules(),
+                10 => app.toggle_stamp_last_modified(),
+                11 => app.toggle_show_resource_age(),
+                _ => {}
+            }
+        }
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Tab | KeyCode::Esc => {
+            // Switch back to Main tab
+            app.selected_tab = 0;
+            app.screen = Screen::BlueprintSelect;
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_asg_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.auto_scaling_groups.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.auto_scaling_groups.len() {
+                let asg = &app.auto_scaling_groups[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Asg,
+                        asg.id.clone(),
+                        asg.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadAsgDetail(asg.name.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshAsg);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_rds_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.rds_databases.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.rds_databases.len() {
+                let db = &app.rds_databases[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Rds,
+                        db.id.clone(),
+                        db.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadRdsDetail(db.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshRds);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_lambda_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.lambda_functions.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.lambda_functions.len() {
+                let function = &app.lambda_functions[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Lambda,
+                        function.id.clone(),
+                        function.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadLambdaDetail(function.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshLambda);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_compare(app: &mut App, key: KeyEvent) {
+    let content_lines = if app.compare_show_table {
+        app.preview_content.lines().count()
+    } else {
+        app.comparison
+            .as_ref()
+            .map(|c| {
+                c.left_markdown
+                    .lines()
+                    .count()
+                    .max(c.right_markdown.lines().count())
+            })
+            .unwrap_or(0)
+    } as u16;
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(1)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::PageUp => {
+            app.preview_scroll = app.preview_scroll.saturating_sub(20);
+        }
+        KeyCode::PageDown => {
+            app.preview_scroll = app
+                .preview_scroll
+                .saturating_add(20)
+                .min(content_lines.saturating_sub(1));
+        }
+        KeyCode::Tab | KeyCode::Char('t') => {
+            app.compare_show_table = !app.compare_show_table;
+            app.preview_scroll = 0;
+        }
+        KeyCode::Enter | KeyCode::Char('s') => {
+            let _ = app.save_file();
+        }
+        KeyCode::Esc => {
+            app.comparison = None;
+            app.preview_scroll = 0;
+            app.screen = app.compare_return_screen.clone();
+        }
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_dynamodb_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.dynamodb_tables.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.dynamodb_tables.len() {
+                let table = &app.dynamodb_tables[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::DynamoDb,
+                        table.id.clone(),
+                        table.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadDynamoDbDetail(table.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshDynamoDb);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_cloudfront_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.cloudfront_distributions.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.cloudfront_distributions.len() {
+                let distribution = &app.cloudfront_distributions[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::CloudFront,
+                        distribution.id.clone(),
+                        distribution.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadCloudFrontDetail(distribution.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshCloudFront);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_route53_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.route53_zones.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.route53_zones.len() {
+                let zone = &app.route53_zones[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Route53,
+                        zone.id.clone(),
+                        zone.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadRoute53Detail(zone.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshRoute53);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_eks_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.eks_clusters.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.eks_clusters.len() {
+                let cluster = &app.eks_clusters[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Eks,
+                        cluster.id.clone(),
+                        cluster.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEksDetail(cluster.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEks);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_ecs_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.ecs_clusters.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.ecs_clusters.len() {
+                let cluster = &app.ecs_clusters[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Ecs,
+                        cluster.id.clone(),
+                        cluster.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEcsDetail(cluster.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEcs);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_sqs_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.sqs_queues.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.sqs_queues.len() {
+                let queue = &app.sqs_queues[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Sqs,
+                        queue.id.clone(),
+                        queue.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadSqsDetail(queue.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshSqs);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_sns_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.sns_topics.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.sns_topics.len() {
+                let topic = &app.sns_topics[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Sns,
+                        topic.id.clone(),
+                        topic.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadSnsDetail(topic.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshSns);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_elasticache_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.elasticache_clusters.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.elasticache_clusters.len() {
+                let cache = &app.elasticache_clusters[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::ElastiCache,
+                        cache.id.clone(),
+                        cache.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadElastiCacheDetail(cache.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshElastiCache);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_cloudwatch_alarm_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.cloudwatch_alarms.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.cloudwatch_alarms.len() {
+                let alarm = &app.cloudwatch_alarms[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::CloudWatchAlarm,
+                        alarm.id.clone(),
+                        alarm.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadCloudWatchAlarmDetail(alarm.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshCloudWatchAlarm);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_cloudtrail_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.cloudtrail_trails.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.cloudtrail_trails.len() {
+                let trail = &app.cloudtrail_trails[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::CloudTrail,
+                        trail.id.clone(),
+                        trail.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadCloudTrailDetail(trail.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshCloudTrail);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_kms_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.kms_keys.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.kms_keys.len() {
+                let key = &app.kms_keys[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Kms,
+                        key.id.clone(),
+                        key.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadKmsDetail(key.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshKms);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_secret_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.secrets.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.secrets.len() {
+                let secret = &app.secrets[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Secret,
+                        secret.id.clone(),
+                        secret.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadSecretDetail(secret.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshSecret);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+// 경로(/prod, /)는 파일명에 쓸 수 없으므로 ssm-prod.md, ssm.md로 저장
+fn ssm_parameter_filename(path: &str) -> String {
+    format!("ssm{}.md", path.trim_end_matches('/').replace('/', "-"))
+}
+
+fn handle_ssm_parameter_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.ssm_parameter_paths.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.ssm_parameter_paths.len() {
+                let path = &app.ssm_parameter_paths[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::SsmParameter,
+                        path.id.clone(),
+                        path.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadSsmParameterDetail(path.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshSsmParameter);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_efs_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.efs_file_systems.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.efs_file_systems.len() {
+                let fs = &app.efs_file_systems[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Efs,
+                        fs.id.clone(),
+                        fs.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEfsDetail(fs.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEfs);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_apigateway_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.api_gateways.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.api_gateways.len() {
+                let api = &app.api_gateways[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::ApiGateway,
+                        api.id.clone(),
+                        api.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadApiGatewayDetail(api.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshApiGateway);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_elasticbeanstalk_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.beanstalk_environments.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.beanstalk_environments.len() {
+                let env = &app.beanstalk_environments[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::ElasticBeanstalk,
+                        env.id.clone(),
+                        env.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadElasticBeanstalkDetail(env.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshElasticBeanstalk);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_stepfunctions_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.state_machines.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.state_machines.len() {
+                let machine = &app.state_machines[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::StepFunctions,
+                        machine.id.clone(),
+                        machine.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadStepFunctionsDetail(machine.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshStepFunctions);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_kinesis_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.kinesis_streams.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.kinesis_streams.len() {
+                let stream = &app.kinesis_streams[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Kinesis,
+                        stream.id.clone(),
+                        stream.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadKinesisDetail(stream.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshKinesis);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_msk_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.msk_clusters.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.msk_clusters.len() {
+                let cluster = &app.msk_clusters[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Msk,
+                        cluster.id.clone(),
+                        cluster.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadMskDetail(cluster.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshMsk);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_opensearch_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.opensearch_domains.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.opensearch_domains.len() {
+                let domain = &app.opensearch_domains[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::OpenSearch,
+                        domain.id.clone(),
+                        domain.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadOpenSearchDetail(domain.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshOpenSearch);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_redshift_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.redshift_clusters.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.redshift_clusters.len() {
+                let cluster = &app.redshift_clusters[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Redshift,
+                        cluster.id.clone(),
+                        cluster.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadRedshiftDetail(cluster.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshRedshift);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_transit_gateway_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.transit_gateways.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.transit_gateways.len() {
+                let tgw = &app.transit_gateways[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::TransitGateway,
+                        tgw.id.clone(),
+                        tgw.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadTransitGatewayDetail(tgw.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshTransitGateway);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_vpc_peering_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.vpc_peerings.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.vpc_peerings.len() {
+                let peering = &app.vpc_peerings[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::VpcPeering,
+                        peering.id.clone(),
+                        peering.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadVpcPeeringDetail(peering.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshVpcPeering);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_vpn_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.vpns.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.vpns.len() {
+                let vpn = &app.vpns[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Vpn,
+                        vpn.id.clone(),
+                        vpn.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadVpnDetail(vpn.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshVpn);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_direct_connect_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.direct_connect_resources.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.direct_connect_resources.len() {
+                let resource = &app.direct_connect_resources[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::DirectConnect,
+                        resource.id.clone(),
+                        resource.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadDirectConnectDetail(resource.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshDirectConnect);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_waf_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.waf_web_acls.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.waf_web_acls.len() {
+                let acl = &app.waf_web_acls[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Waf,
+                        acl.id.clone(),
+                        acl.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadWafDetail(acl.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshWaf);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_acm_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.acm_certificates.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.acm_certificates.len() {
+                let certificate = &app.acm_certificates[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Acm,
+                        certificate.id.clone(),
+                        certificate.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadAcmDetail(certificate.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshAcm);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_cloudformation_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.cloudformation_stacks.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.cloudformation_stacks.len() {
+                let stack = &app.cloudformation_stacks[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::CloudFormation,
+                        stack.id.clone(),
+                        stack.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadCloudFormationDetail(stack.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshCloudFormation);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_iam_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.iam_entities.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.iam_entities.len() {
+                let entity = &app.iam_entities[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Iam,
+                        entity.id.clone(),
+                        entity.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadIamDetail(entity.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshIam);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_vpc_endpoint_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.vpc_endpoints.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.vpc_endpoints.len() {
+                let endpoint = &app.vpc_endpoints[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::VpcEndpoint,
+                        endpoint.id.clone(),
+                        endpoint.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadVpcEndpointDetail(endpoint.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshVpcEndpoint);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_network_interface_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.network_interfaces.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.network_interfaces.len() {
+                let interface = &app.network_interfaces[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::NetworkInterface,
+                        interface.id.clone(),
+                        interface.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadNetworkInterfaceDetail(interface.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshNetworkInterface);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_launch_template_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.launch_templates.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.launch_templates.len() {
+                let template = &app.launch_templates[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::LaunchTemplate,
+                        template.id.clone(),
+                        template.name.clone(),
+                    );
+                } else {
+                    start_loading(
+                        app,
+                        LoadingTask::LoadLaunchTemplateDetail(template.id.clone()),
+                    );
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshLaunchTemplate);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_ami_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.amis.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.amis.len() {
+                let image = &app.amis[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Ami,
+                        image.id.clone(),
+                        image.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadAmiDetail(image.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshAmi);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_ebs_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.ebs_resources.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.ebs_resources.len() {
+                let ebs = &app.ebs_resources[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Ebs,
+                        ebs.id.clone(),
+                        ebs.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEbsDetail(ebs.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEbs);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_key_pair_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.key_pairs.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.key_pairs.len() {
+                let key_pair = &app.key_pairs[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::KeyPair,
+                        key_pair.id.clone(),
+                        key_pair.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadKeyPairDetail(key_pair.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshKeyPair);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_eventbridge_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.event_buses.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.event_buses.len() {
+                let bus = &app.event_buses[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::EventBridge,
+                        bus.id.clone(),
+                        bus.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadEventBridgeDetail(bus.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshEventBridge);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_cognito_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.cognito_pools.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.cognito_pools.len() {
+                let pool = &app.cognito_pools[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Cognito,
+                        pool.id.clone(),
+                        pool.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadCognitoDetail(pool.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshCognito);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_glue_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.glue_resources.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.glue_resources.len() {
+                let resource = &app.glue_resources[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Glue,
+                        resource.id.clone(),
+                        resource.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadGlueDetail(resource.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshGlue);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_athena_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.athena_workgroups.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.athena_workgroups.len() {
+                let workgroup = &app.athena_workgroups[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Athena,
+                        workgroup.id.clone(),
+                        workgroup.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadAthenaDetail(workgroup.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshAthena);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn handle_backup_select(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if app.selected_index < app.backup_plans.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if app.selected_index < app.backup_plans.len() {
+                let plan = &app.backup_plans[app.selected_index];
+                if app.blueprint_mode {
+                    add_resource_to_blueprint(
+                        app,
+                        ResourceType::Backup,
+                        plan.id.clone(),
+                        plan.name.clone(),
+                    );
+                } else {
+                    start_loading(app, LoadingTask::LoadBackupDetail(plan.id.clone()));
+                }
+            }
+        }
+        KeyCode::Char('r') => {
+            start_loading(app, LoadingTask::RefreshBackup);
+        }
+        KeyCode::Esc => app.screen = Screen::ServiceSelect,
+        KeyCode::Char('q') => app.running = false,
+        _ => {}
+    }
+}
+
+fn add_resource_to_blueprint(
+    app: &mut App,
+    resource_type: ResourceType,
+    resource_id: String,
+    resource_name: String,
+) {
+    if app.current_blueprint.is_some() {
+        let region = app.get_current_region();
+        let resource = BlueprintResource {
+            resource_type,
+            region,
+            resource_id,
+            resource_name,
+        };
+        app.add_resource_to_current_blueprint(resource);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SETTINGS_COUNT, auto_refresh_list, aws_adapter, blueprint_batch_end, blueprint_files,
+        document_resource_markdown, generate_blueprint_document, handle_key, handle_mouse,
+        heading_anchor, process_loading, with_last_modified,
+    };
+    use crate::app::{
+        App, BlueprintImportKind, LIST_REFRESH_TASKS, LoadingTask, SERVICE_KEYS, Screen,
+    };
+    use crate::aws_cli::{
+        AsgDetail, AwsResource, Ec2Detail, EcrDetail, LoadBalancerDetail, NetworkDetail,
+        SecurityGroupDetail,
+    };
+    use crate::blueprint::{BlueprintResource, ResourceStatus, ResourceType};
+    use crate::stats::ResourceFacts;
+    use crate::tutorial::TutorialStep;
+    use crossterm::event::{
+        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    };
+    use std::time::Duration;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn key_with_mod(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn sample_resource(id: &str, name: &str) -> AwsResource {
+        AwsResource {
+            name: name.to_string(),
+            id: id.to_string(),
+            state: "running".to_string(),
+            az: "ap-northeast-2a".to_string(),
+            cidr: "10.0.0.0/24".to_string(),
+            owner_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn service_select_enter_sets_loading_tasks() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        // 저장된 고정/사용 빈도 설정과 무관하게 기본 순서로 검증 (실험적 서비스 포함)
+        app.settings.pinned_services.clear();
+        app.settings.sort_services_by_usage = false;
+        app.settings.disabled_services.clear();
+        app.settings.experimental_services = crate::app::EXPERIMENTAL_SERVICE_KEYS
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        app.selected_service = 0;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEc2);
+
+        app.selected_service = 1;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpc);
+
+        app.selected_service = 5;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadAsg);
+
+        app.selected_service = 6;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadRds);
+
+        app.selected_service = 7;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadLambda);
+
+        app.selected_service = 8;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadDynamoDb);
+
+        app.selected_service = 9;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadCloudFront);
+
+        app.selected_service = 10;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadRoute53);
+
+        app.selected_service = 11;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEks);
+
+        app.selected_service = 12;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEcs);
+
+        app.selected_service = 13;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSqs);
+
+        app.selected_service = 14;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSns);
+
+        app.selected_service = 15;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadElastiCache);
+
+        app.selected_service = 16;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadCloudWatchAlarm);
+
+        app.selected_service = 17;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadCloudTrail);
+
+        app.selected_service = 18;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadKms);
+
+        app.selected_service = 19;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSecret);
+
+        app.selected_service = 20;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadSsmParameter);
+
+        app.selected_service = 21;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEfs);
+
+        app.selected_service = 22;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadApiGateway);
+
+        app.selected_service = 23;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadElasticBeanstalk);
+
+        app.selected_service = 24;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadStepFunctions);
+
+        app.selected_service = 25;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadKinesis);
+
+        app.selected_service = 26;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadMsk);
+
+        app.selected_service = 27;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadOpenSearch);
+
+        app.selected_service = 28;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadRedshift);
+
+        app.selected_service = 29;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadTransitGateway);
+
+        app.selected_service = 30;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpcPeering);
+
+        app.selected_service = 31;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpn);
+
+        app.selected_service = 32;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadDirectConnect);
+
+        app.selected_service = 33;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadWaf);
+
+        app.selected_service = 34;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadAcm);
+
+        app.selected_service = 35;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadCloudFormation);
+
+        app.selected_service = 36;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadIam);
+
+        app.selected_service = 37;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpcEndpoint);
+
+        app.selected_service = 38;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadNetworkInterface);
+
+        app.selected_service = 39;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadLaunchTemplate);
+
+        app.selected_service = 40;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadAmi);
+
+        app.selected_service = 41;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEbs);
+
+        app.selected_service = 42;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadKeyPair);
+
+        app.selected_service = 43;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadEventBridge);
+
+        app.selected_service = 44;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadCognito);
+
+        app.selected_service = 45;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadGlue);
+
+        app.selected_service = 46;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadAthena);
+
+        app.selected_service = 47;
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadBackup);
+    }
+
+    #[test]
+    fn service_select_reuses_recent_list_until_region_changes() {
+        let mut app = App::new();
+        app.settings.pinned_services.clear();
+        app.settings.sort_services_by_usage = false;
+        app.settings.disabled_services.clear();
+        app.settings.list_cache_seconds = None;
+        app.selected_region = 0;
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpn;
+        process_loading(&mut app);
+        assert!(!app.current_list_refresh().expect("vpn refresh").cached);
+
+        // 방금 조회한 목록은 다시 조회하지 않음
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = 31;
+        app.selected_index = 2;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert!(!app.loading);
+        assert_eq!(app.screen, Screen::VpnSelect);
+        assert_eq!(app.selected_index, 0);
+        assert!(app.current_list_refresh().expect("vpn refresh").cached);
+
+        // 'r'로 새로 조회하면 캐시 표시가 사라짐
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        process_loading(&mut app);
+        assert!(!app.current_list_refresh().expect("vpn refresh").cached);
+
+        // 리전이 바뀌면 다시 조회
+        app.screen = Screen::ServiceSelect;
+        app.selected_region = 1;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpn);
+
+        // list_cache_seconds가 0이면 항상 조회
+        app.loading = false;
+        app.loading_task = LoadingTask::None;
+        app.selected_region = 0;
+        app.settings.list_cache_seconds = Some(0);
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadVpn);
+    }
+
+    #[test]
+    fn auto_refresh_reruns_refresh_task_and_keeps_cursor() {
+        let mut app = App::new();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpn;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpnSelect);
+
+        // 설정하지 않은 목록은 자동 새로고침하지 않음
+        auto_refresh_list(&mut app);
+        assert!(!app.loading);
+
+        app.settings
+            .auto_refresh_seconds
+            .insert("Site-to-Site VPN".to_string(), 60);
+        auto_refresh_list(&mut app);
+        assert!(!app.loading);
+
+        // 간격이 지나면 Refresh 작업을 실행하고 커서는 같은 리소스에 남음
+        app.list_refreshes
+            .get_mut(&Screen::VpnSelect)
+            .expect("vpn refresh")
+            .refreshed_at -= Duration::from_secs(61);
+        app.vpns.insert(0, sample_resource("vpn-old", "vpn-old"));
+        app.selected_index = 1;
+        auto_refresh_list(&mut app);
+        assert_eq!(app.loading_task, LoadingTask::RefreshVpn);
+        process_loading(&mut app);
+        assert!(!app.loading);
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.selected_list_id().as_deref(), Some("vpn-test"));
+        auto_refresh_list(&mut app);
+        assert!(!app.loading);
+    }
+
+    #[test]
+    fn list_selection_is_restored_per_service() {
+        let mut app = App::new();
+        app.screen = Screen::SqsSelect;
+        app.sqs_queues = vec![
+            sample_resource("queue-a", "queue-a"),
+            sample_resource("queue-b", "queue-b"),
+            sample_resource("queue-c", "queue-c"),
+        ];
+        app.record_list_refresh(Screen::SqsSelect);
+        app.selected_index = 2;
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::ServiceSelect);
+
+        // 다른 서비스 목록은 처음 열면 맨 위에서 시작
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpn;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpnSelect);
+        assert_eq!(app.selected_index, 0);
+        handle_key(&mut app, key(KeyCode::Esc));
+
+        // 돌아오면 SQS에서 마지막으로 선택한 행 유지
+        assert!(app.open_cached_list(Screen::SqsSelect));
+        assert_eq!(app.selected_list_id().as_deref(), Some("queue-c"));
+    }
+
+    #[test]
+    fn list_refresh_keeps_cursor_in_range_for_every_service() {
+        let mut app = App::new();
+        for (screen, service, task) in LIST_REFRESH_TASKS {
+            app.screen = screen.clone();
+            app.selected_index = usize::MAX;
+            app.loading = true;
+            app.loading_task = task.clone();
+            process_loading(&mut app);
+            assert_eq!(&app.screen, screen, "{}", service);
+            let len = app
+                .current_resource_list()
+                .map_or(0, |(_, resources)| resources.len());
+            assert!(
+                app.selected_index < len.max(1),
+                "{}: {} / {}",
+                service,
+                app.selected_index,
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn refresh_preview_keeps_scroll_within_content() {
+        let mut app = App::new();
+        app.instances = vec![sample_resource("i-test", "ec2-test")];
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2Detail("i-test".to_string());
+        process_loading(&mut app);
+        let content_lines = app.preview_text().lines().count() as u16;
+        assert!(content_lines > 3);
+
+        app.preview_scroll = 3;
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshPreview;
+        process_loading(&mut app);
+        assert_eq!(app.preview_scroll, 3);
+
+        // 새 내용이 짧아졌으면 마지막 줄로 맞춤
+        app.preview_scroll = u16::MAX;
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshPreview;
+        process_loading(&mut app);
+        assert_eq!(
+            app.preview_scroll,
+            app.preview_text().lines().count() as u16 - 1
+        );
+    }
+
+    #[test]
+    fn region_select_navigation_and_escape_work() {
+        let mut app = App::new();
+        app.screen = Screen::RegionSelect;
+        app.selected_region = 0;
+
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_region, 1);
+
+        handle_key(&mut app, key(KeyCode::Up));
+        assert_eq!(app.selected_region, 0);
+
+        app.blueprint_mode = false;
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+    }
+
+    #[test]
+    fn resource_select_enter_starts_detail_loading() {
+        let mut app = App::new();
+
+        app.screen = Screen::Ec2Select;
+        app.instances = vec![sample_resource("i-1111", "web-1")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEc2Detail("i-1111".to_string())
+        );
+
+        app.screen = Screen::EcrSelect;
+        app.loading = false;
+        app.ecr_repositories = vec![sample_resource("repo-a", "repo-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEcrDetail("repo-a".to_string())
+        );
+
+        app.screen = Screen::AsgSelect;
+        app.loading = false;
+        app.auto_scaling_groups = vec![sample_resource("asg-a", "asg-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadAsgDetail("asg-a".to_string())
+        );
+
+        app.screen = Screen::RdsSelect;
+        app.loading = false;
+        app.rds_databases = vec![sample_resource("orders-db", "orders-db")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadRdsDetail("orders-db".to_string())
+        );
+
+        app.screen = Screen::LambdaSelect;
+        app.loading = false;
+        app.lambda_functions = vec![sample_resource("orders-api", "orders-api")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadLambdaDetail("orders-api".to_string())
+        );
+
+        app.screen = Screen::DynamoDbSelect;
+        app.loading = false;
+        app.dynamodb_tables = vec![sample_resource("orders", "orders")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadDynamoDbDetail("orders".to_string())
+        );
+
+        app.screen = Screen::CloudFrontSelect;
+        app.loading = false;
+        app.cloudfront_distributions = vec![sample_resource("E2EXAMPLE", "E2EXAMPLE")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadCloudFrontDetail("E2EXAMPLE".to_string())
+        );
+
+        app.screen = Screen::Route53Select;
+        app.loading = false;
+        app.route53_zones = vec![sample_resource("Z0123456789ABC", "Z0123456789ABC")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadRoute53Detail("Z0123456789ABC".to_string())
+        );
+
+        app.screen = Screen::EksSelect;
+        app.loading = false;
+        app.eks_clusters = vec![sample_resource("checkout", "checkout")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEksDetail("checkout".to_string())
+        );
+
+        app.screen = Screen::EcsSelect;
+        app.loading = false;
+        app.ecs_clusters = vec![sample_resource("checkout", "checkout")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEcsDetail("checkout".to_string())
+        );
+
+        app.screen = Screen::SqsSelect;
+        app.loading = false;
+        app.sqs_queues = vec![sample_resource("orders", "orders")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadSqsDetail("orders".to_string())
+        );
+
+        app.screen = Screen::SnsSelect;
+        app.loading = false;
+        app.sns_topics = vec![sample_resource("alerts", "alerts")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadSnsDetail("alerts".to_string())
+        );
+
+        app.screen = Screen::ElastiCacheSelect;
+        app.loading = false;
+        app.elasticache_clusters = vec![sample_resource("sessions", "sessions")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadElastiCacheDetail("sessions".to_string())
+        );
+
+        app.screen = Screen::CloudWatchAlarmSelect;
+        app.loading = false;
+        app.cloudwatch_alarms = vec![sample_resource("api-high-cpu", "api-high-cpu")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadCloudWatchAlarmDetail("api-high-cpu".to_string())
+        );
+
+        app.screen = Screen::CloudTrailSelect;
+        app.loading = false;
+        app.cloudtrail_trails = vec![sample_resource("org-audit", "org-audit")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadCloudTrailDetail("org-audit".to_string())
+        );
+
+        app.screen = Screen::KmsSelect;
+        app.loading = false;
+        app.kms_keys = vec![sample_resource(
+            "1234abcd-12ab-34cd-56ef-1234567890ab",
+            "1234abcd-12ab-34cd-56ef-1234567890ab",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadKmsDetail("1234abcd-12ab-34cd-56ef-1234567890ab".to_string())
+        );
+
+        app.screen = Screen::SecretSelect;
+        app.loading = false;
+        app.secrets = vec![sample_resource(
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf",
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadSecretDetail(
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf"
+                    .to_string()
+            )
+        );
+
+        app.screen = Screen::SsmParameterSelect;
+        app.loading = false;
+        app.ssm_parameter_paths = vec![sample_resource("/ssm-test", "/ssm-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadSsmParameterDetail("/ssm-test".to_string())
+        );
+
+        app.screen = Screen::EfsSelect;
+        app.loading = false;
+        app.efs_file_systems = vec![sample_resource("fs-test", "fs-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEfsDetail("fs-test".to_string())
+        );
+
+        app.screen = Screen::ApiGatewaySelect;
+        app.loading = false;
+        app.api_gateways = vec![sample_resource("api-test", "api-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadApiGatewayDetail("api-test".to_string())
+        );
+
+        app.screen = Screen::ElasticBeanstalkSelect;
+        app.loading = false;
+        app.beanstalk_environments = vec![sample_resource("e-test", "e-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadElasticBeanstalkDetail("e-test".to_string())
+        );
+
+        app.screen = Screen::StepFunctionsSelect;
+        app.loading = false;
+        app.state_machines = vec![sample_resource(
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test",
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadStepFunctionsDetail(
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test".to_string()
+            )
+        );
+
+        app.screen = Screen::KinesisSelect;
+        app.loading = false;
+        app.kinesis_streams = vec![sample_resource("kinesis-test", "kinesis-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadKinesisDetail("kinesis-test".to_string())
+        );
+
+        app.screen = Screen::MskSelect;
+        app.loading = false;
+        app.msk_clusters = vec![sample_resource(
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1",
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadMskDetail(
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1".to_string()
+            )
+        );
+
+        app.screen = Screen::OpenSearchSelect;
+        app.loading = false;
+        app.opensearch_domains = vec![sample_resource("opensearch-test", "opensearch-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadOpenSearchDetail("opensearch-test".to_string())
+        );
+
+        app.screen = Screen::RedshiftSelect;
+        app.loading = false;
+        app.redshift_clusters = vec![sample_resource("redshift-test", "redshift-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadRedshiftDetail("redshift-test".to_string())
+        );
+
+        app.screen = Screen::TransitGatewaySelect;
+        app.loading = false;
+        app.transit_gateways = vec![sample_resource("tgw-test", "tgw-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadTransitGatewayDetail("tgw-test".to_string())
+        );
+
+        app.screen = Screen::VpcPeeringSelect;
+        app.loading = false;
+        app.vpc_peerings = vec![sample_resource("pcx-test", "pcx-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadVpcPeeringDetail("pcx-test".to_string())
+        );
+
+        app.screen = Screen::VpnSelect;
+        app.loading = false;
+        app.vpns = vec![sample_resource("vpn-test", "vpn-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadVpnDetail("vpn-test".to_string())
+        );
+
+        app.screen = Screen::DirectConnectSelect;
+        app.loading = false;
+        app.direct_connect_resources = vec![sample_resource("dxcon-test", "dxcon-test")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadDirectConnectDetail("dxcon-test".to_string())
+        );
+
+        app.screen = Screen::WafSelect;
+        app.loading = false;
+        app.waf_web_acls = vec![sample_resource(
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4",
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadWafDetail(
+                "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4"
+                    .to_string()
+            )
+        );
+
+        app.screen = Screen::AcmSelect;
+        app.loading = false;
+        app.acm_certificates = vec![sample_resource(
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadAcmDetail("arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0".to_string())
+        );
+
+        app.screen = Screen::CloudFormationSelect;
+        app.loading = false;
+        app.cloudformation_stacks = vec![sample_resource("checkout", "checkout")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadCloudFormationDetail("checkout".to_string())
+        );
+
+        app.screen = Screen::IamSelect;
+        app.loading = false;
+        app.iam_entities = vec![sample_resource(
+            "arn:aws:iam::123456789012:role/app-role",
+            "arn:aws:iam::123456789012:role/app-role",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadIamDetail("arn:aws:iam::123456789012:role/app-role".to_string())
+        );
+
+        app.screen = Screen::VpcEndpointSelect;
+        app.loading = false;
+        app.vpc_endpoints = vec![sample_resource(
+            "vpce-0a1b2c3d4e5f60718",
+            "vpce-0a1b2c3d4e5f60718",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadVpcEndpointDetail("vpce-0a1b2c3d4e5f60718".to_string())
+        );
+
+        app.screen = Screen::NetworkInterfaceSelect;
+        app.loading = false;
+        app.network_interfaces = vec![sample_resource(
+            "eni-0a1b2c3d4e5f60718",
+            "eni-0a1b2c3d4e5f60718",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadNetworkInterfaceDetail("eni-0a1b2c3d4e5f60718".to_string())
+        );
+
+        app.screen = Screen::LaunchTemplateSelect;
+        app.loading = false;
+        app.launch_templates = vec![sample_resource(
+            "lt-0a1b2c3d4e5f60718",
+            "lt-0a1b2c3d4e5f60718",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadLaunchTemplateDetail("lt-0a1b2c3d4e5f60718".to_string())
+        );
+
+        app.screen = Screen::AmiSelect;
+        app.loading = false;
+        app.amis = vec![sample_resource(
+            "ami-0a1b2c3d4e5f60718",
+            "ami-0a1b2c3d4e5f60718",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadAmiDetail("ami-0a1b2c3d4e5f60718".to_string())
+        );
+
+        app.screen = Screen::EbsSelect;
+        app.loading = false;
+        app.ebs_resources = vec![sample_resource("vol-0123", "vol-0123")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEbsDetail("vol-0123".to_string())
+        );
+
+        app.screen = Screen::KeyPairSelect;
+        app.loading = false;
+        app.key_pairs = vec![sample_resource("key-0123", "key-0123")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadKeyPairDetail("key-0123".to_string())
+        );
+
+        app.screen = Screen::EventBridgeSelect;
+        app.loading = false;
+        app.event_buses = vec![sample_resource("default", "default")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadEventBridgeDetail("default".to_string())
+        );
+
+        app.screen = Screen::CognitoSelect;
+        app.loading = false;
+        app.cognito_pools = vec![sample_resource(
+            "ap-northeast-2_AbCdEf123",
+            "ap-northeast-2_AbCdEf123",
+        )];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadCognitoDetail("ap-northeast-2_AbCdEf123".to_string())
+        );
+
+        app.screen = Screen::GlueSelect;
+        app.loading = false;
+        app.glue_resources = vec![sample_resource("database/sales", "database/sales")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadGlueDetail("database/sales".to_string())
+        );
+
+        app.screen = Screen::AthenaSelect;
+        app.loading = false;
+        app.athena_workgroups = vec![sample_resource("primary", "primary")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadAthenaDetail("primary".to_string())
+        );
+
+        app.screen = Screen::BackupSelect;
+        app.loading = false;
+        app.backup_plans = vec![sample_resource("8a2f1c4e", "8a2f1c4e")];
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::LoadBackupDetail("8a2f1c4e".to_string())
+        );
+    }
+
+    #[test]
+    fn preview_keys_update_scroll_and_escape_route() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = (0..100)
+            .map(|n| format!("line {}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.preview_scroll, 1);
+        handle_key(&mut app, key(KeyCode::PageDown));
+        assert!(app.preview_scroll >= 20);
+        handle_key(&mut app, key(KeyCode::Home));
+        assert_eq!(app.preview_scroll, 0);
+        handle_key(&mut app, key(KeyCode::End));
+        assert_eq!(app.preview_scroll, 99);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::ServiceSelect);
+    }
+
+    #[test]
+    fn preview_raw_json_toggle_scrolls_json_and_resets_on_escape() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = "## checkout".to_string();
+        app.ecs_clusters = vec![sample_resource("checkout", "checkout")];
+        app.ecs_detail = aws_adapter::get_ecs_detail("checkout");
+
+        handle_key(&mut app, key(KeyCode::Char('v')));
+        assert!(
+            app.preview_text()
+                .starts_with("{\n  \"name\": \"checkout\"")
+        );
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.preview_scroll, 1);
+
+        app.loading = false;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        process_loading(&mut app);
+        assert!(app.preview_raw.is_some());
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(app.preview_raw.is_none());
+        assert_eq!(app.screen, Screen::EcsSelect);
+    }
+
+    #[test]
+    fn preview_mouse_scroll_and_drag_work() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = (0..30)
+            .map(|n| format!("line {}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        handle_mouse(
+            &mut app,
+            MouseEvent {
+                kind: MouseEventKind::ScrollDown,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+        assert!(app.preview_scroll > 0);
+
+        handle_mouse(
+            &mut app,
+            MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 10,
+                row: 10,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+        handle_mouse(
+            &mut app,
+            MouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column: 10,
+                row: 8,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+        handle_mouse(
+            &mut app,
+            MouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                column: 10,
+                row: 8,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+        assert!(app.preview_drag_start.is_none());
+    }
+
+    #[test]
+    fn blueprint_name_input_and_settings_paths_work() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintNameInput;
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        handle_key(&mut app, key(KeyCode::Char('b')));
+        assert_eq!(app.input_buffer, "ab");
+        handle_key(&mut app, key(KeyCode::Backspace));
+        assert_eq!(app.input_buffer, "a");
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+
+        app.screen = Screen::ServiceSelect;
+        handle_key(&mut app, key(KeyCode::Tab));
+        assert_eq!(app.screen, Screen::Settings);
+
+        app.screen = Screen::Settings;
+        let before = app.settings.language;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_ne!(app.settings.language, before);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+    }
+
+    #[test]
+    fn blueprint_detail_shift_reorder_shortcuts_are_handled() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::Ec2,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "i-1".to_string(),
+                    resource_name: "one".to_string(),
+                },
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::Ec2,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "i-2".to_string(),
+                    resource_name: "two".to_string(),
+                },
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.blueprint_resource_index = 1;
+
+        handle_key(&mut app, key_with_mod(KeyCode::Up, KeyModifiers::SHIFT));
+        assert_eq!(app.blueprint_resource_index, 0);
+    }
+
+    #[test]
+    fn process_loading_none_is_noop() {
+        let mut app = App::new();
+        app.loading = true;
+        app.loading_task = LoadingTask::None;
+        process_loading(&mut app);
+        assert!(app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+    }
+
+    #[test]
+    fn preview_add_path_in_blueprint_mode_returns_to_blueprint_detail() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.blueprint_mode = true;
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.blueprint_store.blueprints = vec![app.current_blueprint.clone().expect("bp")];
+        app.selected_blueprint_index = 0;
+        app.ec2_detail = Some(Ec2Detail {
+            name: "web".to_string(),
+            instance_id: "i-1234".to_string(),
+            instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
+            ami: "ami".to_string(),
+            platform: "Linux".to_string(),
+            architecture: "x86_64".to_string(),
+            key_pair: "key".to_string(),
+            vpc: "vpc".to_string(),
+            subnet: "subnet".to_string(),
+            az: "az".to_string(),
+            public_ip: "-".to_string(),
+            private_ip: "10.0.0.1".to_string(),
+            security_groups: vec!["sg".to_string()],
+            state: "running".to_string(),
+            ebs_optimized: false,
+            monitoring: "Disabled".to_string(),
+            iam_role: None,
+            iam_role_detail: None,
+            launch_time: String::new(),
+            tags: vec![("Name".to_string(), "web".to_string())],
+            volumes: Vec::new(),
+            user_data: None,
+        });
+
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+    }
+
+    #[test]
+    fn preview_escape_selects_resource_screen_for_each_detail_type() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+
+        app.ec2_detail = Some(Ec2Detail {
+            name: "web".to_string(),
+            instance_id: "i-1234".to_string(),
+            instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
+            ami: "ami".to_string(),
+            platform: "Linux".to_string(),
+            architecture: "x86_64".to_string(),
+            key_pair: "key".to_string(),
+            vpc: "vpc".to_string(),
+            subnet: "subnet".to_string(),
+            az: "az".to_string(),
+            public_ip: "-".to_string(),
+            private_ip: "10.0.0.1".to_string(),
+            security_groups: vec!["sg".to_string()],
+            state: "running".to_string(),
+            ebs_optimized: false,
+            monitoring: "Disabled".to_string(),
+            iam_role: None,
+            iam_role_detail: None,
+            launch_time: String::new(),
+            tags: vec![("Name".to_string(), "web".to_string())],
+            volumes: Vec::new(),
+            user_data: None,
+        });
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::Ec2Select);
+
+        app.screen = Screen::Preview;
+        app.ecr_detail = Some(EcrDetail {
+            name: "repo".to_string(),
+            uri: "uri".to_string(),
+            tag_mutability: "MUTABLE".to_string(),
+            encryption_type: "AES256".to_string(),
+            kms_key: None,
+            created_at: "2026-01-01".to_string(),
+            image_count: 0,
+        });
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::EcrSelect);
+
+        app.screen = Screen::Preview;
+        app.asg_detail = Some(AsgDetail {
+            name: "asg".to_string(),
+            arn: "arn".to_string(),
+            launch_template_name: None,
+            launch_template_id: None,
+            launch_template_version: None,
+            launch_template: None,
+            launch_config_name: None,
+            min_size: 1,
+            max_size: 1,
+            desired_capacity: 1,
+            default_cooldown: 0,
+            availability_zones: Vec::new(),
+            target_group_arns: Vec::new(),
+            health_check_type: "EC2".to_string(),
+            health_check_grace_period: 0,
+            instances: Vec::new(),
+            created_time: "2026-01-01".to_string(),
+            scaling_policies: Vec::new(),
+            scheduled_actions: vec![],
+            tags: Vec::new(),
+        });
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::AsgSelect);
+    }
+
+    #[test]
+    fn blueprint_select_shortcuts_change_mode_and_tab() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+        app.blueprint_store.blueprints = vec![crate::blueprint::Blueprint {
+            id: "bp-1".to_string(),
+            name: "bp-1".to_string(),
+            resources: vec![crate::blueprint::BlueprintResource {
+                resource_type: crate::blueprint::ResourceType::Ec2,
+                region: "ap-northeast-2".to_string(),
+                resource_id: "i-1234".to_string(),
+                resource_name: "web".to_string(),
+            }],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }];
+
+        app.selected_blueprint_index = 1;
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        assert_eq!(app.loading_task, LoadingTask::LoadBlueprintResources(0));
+
+        app.loading = false;
+        app.selected_blueprint_index = 1;
+        handle_key(&mut app, key(KeyCode::Char('d')));
+        assert!(app.selected_blueprint_index <= 1);
+
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        assert_eq!(app.screen, Screen::RegionSelect);
+
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Tab));
+        assert_eq!(app.screen, Screen::Settings);
+    }
+
+    #[test]
+    fn blueprint_detail_actions_cover_add_delete_generate_and_escape() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![crate::blueprint::BlueprintResource {
+                resource_type: crate::blueprint::ResourceType::Ec2,
+                region: "ap-northeast-2".to_string(),
+                resource_id: "i-1234".to_string(),
+                resource_name: "web".to_string(),
+            }],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        assert_eq!(app.screen, Screen::RegionSelect);
+        assert!(app.blueprint_mode);
+
+        app.screen = Screen::BlueprintDetail;
+        app.blueprint_mode = false;
+        app.blueprint_resource_index = 0;
+        handle_key(&mut app, key(KeyCode::Char('d')));
+        assert_eq!(
+            app.current_blueprint
+                .as_ref()
+                .map(|bp| bp.resources.len())
+                .unwrap_or_default(),
+            0
+        );
+
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![crate::blueprint::BlueprintResource {
+                resource_type: crate::blueprint::ResourceType::Ec2,
+                region: "ap-northeast-2".to_string(),
+                resource_id: "i-1234".to_string(),
+                resource_name: "web".to_string(),
+            }],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::LoadBlueprintResources(0));
+
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.loading_task = LoadingTask::None;
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        assert_eq!(app.message, app.i18n.no_resources());
+        assert_eq!(app.loading_task, LoadingTask::None);
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+    }
+
+    #[test]
+    fn blueprint_detail_validate_marks_renamed_resources_and_remaps_them() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::Ec2,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "i-1234".to_string(),
+                    resource_name: "ec2-i-1234".to_string(),
+                },
+                crate::blueprint::BlueprintResource {
+                    resource_type: crate::blueprint::ResourceType::SecurityGroup,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "sg-1234".to_string(),
+                    resource_name: "old-name".to_string(),
+                },
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+
+        handle_key(&mut app, key(KeyCode::Char('v')));
+        assert_eq!(app.loading_task, LoadingTask::ValidateBlueprintResources(0));
+        while app.loading {
+            process_loading(&mut app);
+        }
+        assert_eq!(
+            app.blueprint_validation,
+            vec![
+                ResourceStatus::Valid,
+                ResourceStatus::Renamed("sg-sg-1234".to_string()),
+            ]
+        );
+        assert_eq!(app.message, app.i18n.validation_complete(1, 0));
+
+        handle_key(&mut app, key(KeyCode::Char('m')));
+        assert_eq!(app.message, app.i18n.resources_remapped(1));
+        assert_eq!(
+            app.current_blueprint
+                .as_ref()
+                .map(|bp| bp.resources[1].resource_name.clone())
+                .unwrap_or_default(),
+            "sg-sg-1234"
+        );
+        assert!(app.blueprint_validation.iter().all(|s| !s.is_stale()));
+    }
+
+    #[test]
+    fn blueprint_validation_separates_not_found_from_failed_lookups_and_checks_region() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        let resource =
+            |resource_type: ResourceType, region: &str, id: &str, name: &str| BlueprintResource {
+                resource_type,
+                region: region.to_string(),
+                resource_id: id.to_string(),
+                resource_name: name.to_string(),
+            };
+        let lb_arn = "arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/app/web/1";
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![
+                resource(ResourceType::Ec2, "ap-northeast-2", "i-gone", "gone"),
+                resource(ResourceType::Ec2, "ap-northeast-2", "i-denied", "denied"),
+                resource(
+                    ResourceType::LoadBalancer,
+                    "ap-northeast-2",
+                    lb_arn,
+                    &format!("lb-{}", lb_arn),
+                ),
+                resource(ResourceType::Ec2, "us-gov-west-1", "i-gov", "ec2-i-gov"),
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+
+        handle_key(&mut app, key(KeyCode::Char('v')));
+        while app.loading {
+            process_loading(&mut app);
         }
-        KeyCode::Esc => app.screen = Screen::ServiceSelect,
-        KeyCode::Char('q') => app.running = false,
-        _ => {}
+        assert_eq!(
+            app.blueprint_validation,
+            vec![
+                ResourceStatus::Missing,
+                ResourceStatus::Unknown,
+                ResourceStatus::Moved("us-east-1".to_string()),
+                ResourceStatus::Unknown,
+            ]
+        );
+        assert_eq!(app.message, app.i18n.validation_complete(2, 2));
+        assert!(aws_adapter::prefetched_instance_ids().is_empty());
+
+        // 조회에 실패한 리소스는 제거하지 않음
+        handle_key(&mut app, key(KeyCode::Char('x')));
+        assert_eq!(app.message, app.i18n.missing_resources_removed(1));
+        handle_key(&mut app, key(KeyCode::Char('m')));
+        assert_eq!(app.message, app.i18n.resources_remapped(1));
+        let resources: Vec<(String, String)> = app
+            .current_blueprint
+            .as_ref()
+            .map(|bp| {
+                bp.resources
+                    .iter()
+                    .map(|r| (r.resource_id.clone(), r.region.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        assert_eq!(
+            resources,
+            vec![
+                ("i-denied".to_string(), "ap-northeast-2".to_string()),
+                (lb_arn.to_string(), "us-east-1".to_string()),
+                ("i-gov".to_string(), "us-gov-west-1".to_string()),
+            ]
+        );
     }
-}
 
-fn add_resource_to_blueprint(
-    app: &mut App,
-    resource_type: ResourceType,
-    resource_id: String,
-    resource_name: String,
-) {
-    if app.current_blueprint.is_some() {
-        let region = app.get_current_region();
-        let resource = BlueprintResource {
-            resource_type,
-            region,
-            resource_id,
-            resource_name,
+    #[test]
+    fn blueprint_detail_remove_missing_drops_only_missing_resources() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        let resource = |id: &str| crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::Ec2,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: id.to_string(),
         };
-        app.add_resource_to_current_blueprint(resource);
-    }
-}
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: vec![resource("i-1"), resource("i-2"), resource("i-3")],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.blueprint_validation = vec![
+            ResourceStatus::Missing,
+            ResourceStatus::Valid,
+            ResourceStatus::Missing,
+        ];
+        app.blueprint_resource_index = 2;
 
-#[cfg(test)]
-mod tests {
-    use super::{handle_key, handle_mouse, process_loading};
-    use crate::app::{App, LoadingTask, Screen};
-    use crate::aws_cli::{
-        AsgDetail, AwsResource, Ec2Detail, EcrDetail, LoadBalancerDetail, NetworkDetail,
-        SecurityGroupDetail,
-    };
-    use crossterm::event::{
-        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
-    };
+        handle_key(&mut app, key(KeyCode::Char('x')));
 
-    fn key(code: KeyCode) -> KeyEvent {
-        KeyEvent::new(code, KeyModifiers::NONE)
+        let ids: Vec<String> = app
+            .current_blueprint
+            .as_ref()
+            .map(|bp| bp.resources.iter().map(|r| r.resource_id.clone()).collect())
+            .unwrap_or_default();
+        assert_eq!(ids, vec!["i-2".to_string()]);
+        assert_eq!(app.blueprint_validation, vec![ResourceStatus::Valid]);
+        assert_eq!(app.blueprint_resource_index, 0);
+        assert_eq!(app.message, app.i18n.missing_resources_removed(2));
+
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp".to_string(),
+            name: "bp".to_string(),
+            resources: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        handle_key(&mut app, key(KeyCode::Char('v')));
+        assert_eq!(app.loading_task, LoadingTask::None);
+        assert_eq!(app.message, app.i18n.no_resources());
     }
 
-    fn key_with_mod(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
-        KeyEvent::new(code, modifiers)
+    #[test]
+    fn service_select_exit_paths_cover_blueprint_and_running_flags() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = crate::app::SERVICE_KEYS.len();
+        app.blueprint_mode = true;
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = crate::app::SERVICE_KEYS.len();
+        app.blueprint_mode = false;
+        app.running = true;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert!(!app.running);
     }
 
-    fn sample_resource(id: &str, name: &str) -> AwsResource {
-        AwsResource {
+    fn sample_blueprint(name: &str) -> crate::blueprint::Blueprint {
+        crate::blueprint::Blueprint {
+            id: format!("id-{}", name),
             name: name.to_string(),
-            id: id.to_string(),
-            state: "running".to_string(),
-            az: "ap-northeast-2a".to_string(),
-            cidr: "10.0.0.0/24".to_string(),
+            resources: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
         }
     }
 
     #[test]
-    fn service_select_enter_sets_loading_tasks() {
+    fn blueprint_name_input_enter_creates_blueprint_and_opens_detail() {
         let mut app = App::new();
-        app.screen = Screen::ServiceSelect;
-
-        app.selected_service = 0;
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.loading_task, LoadingTask::LoadEc2);
+        app.screen = Screen::BlueprintNameInput;
+        app.input_buffer = "new-bp".to_string();
 
-        app.selected_service = 1;
-        app.loading = false;
         handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.loading_task, LoadingTask::LoadVpc);
 
-        app.selected_service = 5;
-        app.loading = false;
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.loading_task, LoadingTask::LoadAsg);
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        assert_eq!(
+            app.current_blueprint
+                .as_ref()
+                .map(|bp| bp.name.clone())
+                .unwrap_or_default(),
+            "new-bp"
+        );
+        assert!(app.input_buffer.is_empty());
     }
 
     #[test]
-    fn region_select_navigation_and_escape_work() {
+    fn blueprint_preview_keys_cover_navigation_save_and_escape_paths() {
         let mut app = App::new();
-        app.screen = Screen::RegionSelect;
-        app.selected_region = 0;
+        app.screen = Screen::BlueprintPreview;
+        app.current_blueprint = Some(sample_blueprint("bp-a"));
+        app.preview_content = (0..50)
+            .map(|n| format!("line {}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
 
         handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.selected_region, 1);
+        assert_eq!(app.preview_scroll, 1);
+        handle_key(&mut app, key(KeyCode::PageDown));
+        assert!(app.preview_scroll >= 20);
+        handle_key(&mut app, key(KeyCode::Home));
+        assert_eq!(app.preview_scroll, 0);
+        handle_key(&mut app, key(KeyCode::End));
+        assert_eq!(app.preview_scroll, 49);
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        app.settings.notion_export = None;
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert_eq!(app.message, app.i18n.notion_not_configured());
 
-        handle_key(&mut app, key(KeyCode::Up));
-        assert_eq!(app.selected_region, 0);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintDetail);
 
-        app.blueprint_mode = false;
+        app.screen = Screen::BlueprintPreview;
+        app.current_blueprint = None;
+        handle_key(&mut app, key(KeyCode::Char('b')));
+        assert_eq!(app.message, app.i18n.backstage_nothing_to_export());
         handle_key(&mut app, key(KeyCode::Esc));
         assert_eq!(app.screen, Screen::BlueprintSelect);
     }
 
     #[test]
-    fn resource_select_enter_starts_detail_loading() {
+    fn service_select_navigation_shortcuts_and_escape_work() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = 1;
+
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_service, 2);
+        handle_key(&mut app, key(KeyCode::Up));
+        assert_eq!(app.selected_service, 1);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::RegionSelect);
+    }
+
+    #[test]
+    fn resource_select_refresh_shortcuts_set_loading_tasks() {
         let mut app = App::new();
 
         app.screen = Screen::Ec2Select;
-        app.instances = vec![sample_resource("i-1111", "web-1")];
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshEc2);
+
+        app.screen = Screen::VpcSelect;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshVpc);
+
+        app.screen = Screen::SecurityGroupSelect;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshSecurityGroup);
+
+        app.screen = Screen::LoadBalancerSelect;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshLoadBalancer);
+
+        app.screen = Screen::EcrSelect;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshEcr);
+
+        app.screen = Screen::AsgSelect;
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.loading_task, LoadingTask::RefreshAsg);
+    }
+
+    #[test]
+    fn blueprint_mode_enter_adds_resource_from_each_select_screen() {
+        let mut app = App::new();
+        app.blueprint_mode = true;
+        app.current_blueprint = Some(sample_blueprint("bp-r"));
+        app.selected_index = 0;
+
+        app.screen = Screen::Ec2Select;
+        app.instances = vec![sample_resource("i-1", "ec2-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        app.screen = Screen::VpcSelect;
+        app.vpcs = vec![sample_resource("vpc-1", "vpc-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        app.screen = Screen::SecurityGroupSelect;
+        app.security_groups = vec![sample_resource("sg-1", "sg-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        app.screen = Screen::LoadBalancerSelect;
+        app.load_balancers = vec![sample_resource("lb-1", "lb-a")];
         handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(
-            app.loading_task,
-            LoadingTask::LoadEc2Detail("i-1111".to_string())
-        );
 
         app.screen = Screen::EcrSelect;
-        app.loading = false;
         app.ecr_repositories = vec![sample_resource("repo-a", "repo-a")];
         handle_key(&mut app, key(KeyCode::Enter));
+
+        app.screen = Screen::AsgSelect;
+        app.auto_scaling_groups = vec![sample_resource("asg-a", "asg-a")];
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        let resources = app
+            .current_blueprint
+            .as_ref()
+            .map(|bp| bp.resources.clone())
+            .unwrap_or_default();
+        assert_eq!(resources.len(), 6);
+        assert_eq!(
+            resources[0].resource_type,
+            crate::blueprint::ResourceType::Ec2
+        );
+        assert_eq!(
+            resources[1].resource_type,
+            crate::blueprint::ResourceType::Network
+        );
+        assert_eq!(
+            resources[2].resource_type,
+            crate::blueprint::ResourceType::SecurityGroup
+        );
+        assert_eq!(
+            resources[3].resource_type,
+            crate::blueprint::ResourceType::LoadBalancer
+        );
         assert_eq!(
-            app.loading_task,
-            LoadingTask::LoadEcrDetail("repo-a".to_string())
+            resources[4].resource_type,
+            crate::blueprint::ResourceType::Ecr
         );
-
-        app.screen = Screen::AsgSelect;
-        app.loading = false;
-        app.auto_scaling_groups = vec![sample_resource("asg-a", "asg-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
         assert_eq!(
-            app.loading_task,
-            LoadingTask::LoadAsgDetail("asg-a".to_string())
+            resources[5].resource_type,
+            crate::blueprint::ResourceType::Asg
         );
     }
 
     #[test]
-    fn preview_keys_update_scroll_and_escape_route() {
+    fn settings_screen_left_returns_to_blueprint_select() {
+        let mut app = App::new();
+        app.screen = Screen::Settings;
+
+        handle_key(&mut app, key(KeyCode::Left));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert_eq!(app.selected_tab, 0);
+    }
+
+    #[test]
+    fn preview_blueprint_mode_escape_clears_details_and_returns_to_detail_screen() {
         let mut app = App::new();
         app.screen = Screen::Preview;
-        app.preview_content = (0..100)
-            .map(|n| format!("line {}", n))
-            .collect::<Vec<_>>()
-            .join("\n");
+        app.blueprint_mode = true;
+        app.ec2_detail = Some(Ec2Detail {
+            name: "web".to_string(),
+            instance_id: "i-1".to_string(),
+            instance_type: "t3.micro".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
+            ami: "ami".to_string(),
+            platform: "Linux".to_string(),
+            architecture: "x86_64".to_string(),
+            key_pair: "kp".to_string(),
+            vpc: "vpc".to_string(),
+            subnet: "subnet".to_string(),
+            az: "az".to_string(),
+            public_ip: "-".to_string(),
+            private_ip: "10.0.0.1".to_string(),
+            security_groups: vec![],
+            state: "running".to_string(),
+            ebs_optimized: false,
+            monitoring: "disabled".to_string(),
+            iam_role: None,
+            iam_role_detail: None,
+            launch_time: String::new(),
+            tags: vec![],
+            volumes: vec![],
+            user_data: None,
+        });
 
-        handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.preview_scroll, 1);
-        handle_key(&mut app, key(KeyCode::PageDown));
-        assert!(app.preview_scroll >= 20);
-        handle_key(&mut app, key(KeyCode::Home));
-        assert_eq!(app.preview_scroll, 0);
-        handle_key(&mut app, key(KeyCode::End));
-        assert_eq!(app.preview_scroll, 99);
         handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        assert!(app.ec2_detail.is_none());
     }
 
     #[test]
-    fn preview_mouse_scroll_and_drag_work() {
+    fn handle_mouse_outside_preview_is_noop() {
         let mut app = App::new();
-        app.screen = Screen::Preview;
-        app.preview_content = (0..30)
-            .map(|n| format!("line {}", n))
-            .collect::<Vec<_>>()
-            .join("\n");
+        app.screen = Screen::ServiceSelect;
+        app.preview_scroll = 7;
 
         handle_mouse(
             &mut app,
@@ -1668,915 +9685,1253 @@ mod tests {
                 modifiers: KeyModifiers::NONE,
             },
         );
-        assert!(app.preview_scroll > 0);
 
-        handle_mouse(
-            &mut app,
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column: 10,
-                row: 10,
-                modifiers: KeyModifiers::NONE,
-            },
-        );
-        handle_mouse(
-            &mut app,
-            MouseEvent {
-                kind: MouseEventKind::Drag(MouseButton::Left),
-                column: 10,
-                row: 8,
-                modifiers: KeyModifiers::NONE,
-            },
-        );
-        handle_mouse(
-            &mut app,
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left),
-                column: 10,
-                row: 8,
-                modifiers: KeyModifiers::NONE,
-            },
-        );
-        assert!(app.preview_drag_start.is_none());
+        assert_eq!(app.preview_scroll, 7);
     }
 
     #[test]
-    fn blueprint_name_input_and_settings_paths_work() {
+    fn blueprint_select_enter_covers_new_and_existing_paths() {
         let mut app = App::new();
-        app.screen = Screen::BlueprintNameInput;
-        handle_key(&mut app, key(KeyCode::Char('a')));
-        handle_key(&mut app, key(KeyCode::Char('b')));
-        assert_eq!(app.input_buffer, "ab");
-        handle_key(&mut app, key(KeyCode::Backspace));
-        assert_eq!(app.input_buffer, "a");
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
+        app.screen = Screen::BlueprintSelect;
+        app.input_buffer = "temp".to_string();
 
-        app.screen = Screen::ServiceSelect;
-        handle_key(&mut app, key(KeyCode::Tab));
-        assert_eq!(app.screen, Screen::Settings);
+        app.selected_blueprint_index = 0;
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::BlueprintNameInput);
+        assert!(app.input_buffer.is_empty());
 
-        app.screen = Screen::Settings;
-        let before = app.settings.language;
+        app.blueprint_store.blueprints = vec![sample_blueprint("bp-existing")];
+        app.screen = Screen::BlueprintSelect;
+        app.selected_blueprint_index = 1;
         handle_key(&mut app, key(KeyCode::Enter));
-        assert_ne!(app.settings.language, before);
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        assert_eq!(
+            app.current_blueprint
+                .as_ref()
+                .map(|bp| bp.name.clone())
+                .unwrap_or_default(),
+            "bp-existing"
+        );
     }
 
     #[test]
-    fn blueprint_detail_shift_reorder_shortcuts_are_handled() {
+    fn process_loading_blueprint_resources_handles_none_and_empty_blueprint_paths() {
         let mut app = App::new();
-        app.screen = Screen::BlueprintDetail;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        app.current_blueprint = None;
+
+        process_loading(&mut app);
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
         app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp".to_string(),
-            name: "bp".to_string(),
-            resources: vec![
-                crate::blueprint::BlueprintResource {
-                    resource_type: crate::blueprint::ResourceType::Ec2,
-                    region: "ap-northeast-2".to_string(),
-                    resource_id: "i-1".to_string(),
-                    resource_name: "one".to_string(),
-                },
-                crate::blueprint::BlueprintResource {
-                    resource_type: crate::blueprint::ResourceType::Ec2,
-                    region: "ap-northeast-2".to_string(),
-                    resource_id: "i-2".to_string(),
-                    resource_name: "two".to_string(),
-                },
-            ],
+            id: "bp-empty".to_string(),
+            name: "bp-empty".to_string(),
+            resources: Vec::new(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         });
-        app.blueprint_resource_index = 1;
+        app.blueprint_markdown_parts = Vec::new();
+        app.blueprint_resource_facts = Vec::new();
 
-        handle_key(&mut app, key_with_mod(KeyCode::Up, KeyModifiers::SHIFT));
-        assert_eq!(app.blueprint_resource_index, 0);
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::BlueprintPreview);
+        assert!(app.preview_content.contains("# Blueprint: bp-empty"));
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
     }
 
     #[test]
-    fn process_loading_none_is_noop() {
+    fn process_loading_vpc_detail_steps_require_step_zero_init() {
         let mut app = App::new();
         app.loading = true;
-        app.loading_task = LoadingTask::None;
+        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1".to_string(), 1);
+
         process_loading(&mut app);
-        assert!(app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
+        assert!(!app.loading);
+        assert_eq!(app.network_detail, None);
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::None,
+            "step 1 should not proceed without step 0 initialization"
+        );
+        assert_eq!(app.message, app.i18n.network_detail_unavailable("vpc-1"));
     }
 
     #[test]
-    fn preview_add_path_in_blueprint_mode_returns_to_blueprint_detail() {
+    fn process_loading_refresh_preview_without_detail_finishes_cleanly() {
         let mut app = App::new();
-        app.screen = Screen::Preview;
-        app.blueprint_mode = true;
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp".to_string(),
-            name: "bp".to_string(),
-            resources: Vec::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
-        app.blueprint_store.blueprints = vec![app.current_blueprint.clone().expect("bp")];
-        app.selected_blueprint_index = 0;
-        app.ec2_detail = Some(Ec2Detail {
-            name: "web".to_string(),
-            instance_id: "i-1234".to_string(),
-            instance_type: "t3.micro".to_string(),
-            ami: "ami".to_string(),
-            platform: "Linux".to_string(),
-            architecture: "x86_64".to_string(),
-            key_pair: "key".to_string(),
-            vpc: "vpc".to_string(),
-            subnet: "subnet".to_string(),
-            az: "az".to_string(),
-            public_ip: "-".to_string(),
-            private_ip: "10.0.0.1".to_string(),
-            security_groups: vec!["sg".to_string()],
-            state: "running".to_string(),
-            ebs_optimized: false,
-            monitoring: "Disabled".to_string(),
-            iam_role: None,
-            iam_role_detail: None,
-            launch_time: String::new(),
-            tags: vec![("Name".to_string(), "web".to_string())],
-            volumes: Vec::new(),
-            user_data: None,
-        });
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshPreview;
 
-        handle_key(&mut app, key(KeyCode::Char('a')));
-        assert_eq!(app.screen, Screen::BlueprintDetail);
+        process_loading(&mut app);
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+        assert_eq!(app.message, app.i18n.refresh_complete());
     }
 
     #[test]
-    fn preview_escape_selects_resource_screen_for_each_detail_type() {
+    fn process_loading_refresh_preview_updates_all_detail_types() {
         let mut app = App::new();
-        app.screen = Screen::Preview;
+        app.selected_index = 0;
+
+        let reset_refresh = |app: &mut App| {
+            app.loading = true;
+            app.loading_task = LoadingTask::RefreshPreview;
+            app.message.clear();
+        };
+        let clear_details = |app: &mut App| {
+            app.ec2_detail = None;
+            app.network_detail = None;
+            app.sg_detail = None;
+            app.lb_detail = None;
+            app.ecr_detail = None;
+            app.asg_detail = None;
+            app.lambda_detail = None;
+            app.dynamodb_detail = None;
+            app.cloudfront_detail = None;
+            app.route53_detail = None;
+            app.eks_detail = None;
+            app.ecs_detail = None;
+            app.sqs_detail = None;
+            app.sns_detail = None;
+            app.elasticache_detail = None;
+            app.cloudwatch_alarm_detail = None;
+            app.cloudtrail_detail = None;
+            app.kms_detail = None;
+            app.secret_detail = None;
+            app.ssm_parameter_detail = None;
+            app.efs_detail = None;
+            app.api_gateway_detail = None;
+            app.elastic_beanstalk_detail = None;
+            app.state_machine_detail = None;
+            app.kinesis_stream_detail = None;
+            app.msk_cluster_detail = None;
+            app.opensearch_domain_detail = None;
+            app.redshift_cluster_detail = None;
+            app.transit_gateway_detail = None;
+            app.vpc_peering_detail = None;
+            app.vpn_detail = None;
+            app.direct_connect_detail = None;
+            app.waf_web_acl_detail = None;
+            app.acm_certificate_detail = None;
+            app.cloudformation_stack_detail = None;
+            app.iam_entity_detail = None;
+            app.vpc_endpoint_detail = None;
+            app.network_interface_detail = None;
+            app.launch_template_detail = None;
+            app.ami_detail = None;
+            app.ebs_detail = None;
+            app.key_pair_detail = None;
+            app.event_bus_detail = None;
+            app.cognito_detail = None;
+            app.glue_detail = None;
+            app.athena_workgroup_detail = None;
+            app.backup_plan_detail = None;
+        };
 
+        clear_details(&mut app);
+        app.instances = vec![sample_resource("i-test", "ec2-test")];
         app.ec2_detail = Some(Ec2Detail {
-            name: "web".to_string(),
-            instance_id: "i-1234".to_string(),
+            name: "seed-ec2".to_string(),
+            instance_id: "i-seed".to_string(),
             instance_type: "t3.micro".to_string(),
-            ami: "ami".to_string(),
+            vcpus: Some(2),
+            memory_mib: Some(1024),
+            ami: "ami-seed".to_string(),
             platform: "Linux".to_string(),
             architecture: "x86_64".to_string(),
-            key_pair: "key".to_string(),
-            vpc: "vpc".to_string(),
-            subnet: "subnet".to_string(),
-            az: "az".to_string(),
+            key_pair: "kp".to_string(),
+            vpc: "vpc-seed".to_string(),
+            subnet: "subnet-seed".to_string(),
+            az: "ap-northeast-2a".to_string(),
             public_ip: "-".to_string(),
-            private_ip: "10.0.0.1".to_string(),
-            security_groups: vec!["sg".to_string()],
+            private_ip: "10.0.0.10".to_string(),
+            security_groups: vec!["sg-seed".to_string()],
             state: "running".to_string(),
             ebs_optimized: false,
             monitoring: "Disabled".to_string(),
             iam_role: None,
             iam_role_detail: None,
             launch_time: String::new(),
-            tags: vec![("Name".to_string(), "web".to_string())],
-            volumes: Vec::new(),
+            tags: vec![],
+            volumes: vec![],
             user_data: None,
         });
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::Ec2Select);
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "ec2-i-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
 
-        app.screen = Screen::Preview;
+        clear_details(&mut app);
+        app.vpcs = vec![sample_resource("vpc-test", "vpc-test")];
+        app.network_detail = Some(NetworkDetail {
+            name: "seed-network".to_string(),
+            id: "vpc-seed".to_string(),
+            cidr: "10.0.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
+            state: "available".to_string(),
+            owner_id: String::new(),
+            subnets: vec![],
+            subnet_addresses: vec![],
+            igws: vec![],
+            nats: vec![],
+            route_tables: vec![],
+            nacls: vec![],
+            eips: vec![],
+            peerings: vec![],
+            endpoints: vec![],
+            dns_support: true,
+            dns_hostnames: true,
+            tags: vec![],
+        });
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "network-vpc-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
+
+        clear_details(&mut app);
+        app.security_groups = vec![sample_resource("sg-test", "sg-test")];
+        app.sg_detail = Some(SecurityGroupDetail {
+            name: "seed-sg".to_string(),
+            id: "sg-seed".to_string(),
+            description: "seed".to_string(),
+            vpc_id: "vpc-seed".to_string(),
+            inbound_rules: vec![],
+            outbound_rules: vec![],
+        });
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "sg-sg-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
+
+        clear_details(&mut app);
+        app.load_balancers = vec![sample_resource("lb-test", "lb-test")];
+        app.lb_detail = Some(LoadBalancerDetail {
+            name: "seed-lb".to_string(),
+            arn: "arn:seed".to_string(),
+            dns_name: "seed.example.com".to_string(),
+            lb_type: "application".to_string(),
+            scheme: "internal".to_string(),
+            vpc_id: "vpc-seed".to_string(),
+            ip_address_type: "ipv4".to_string(),
+            state: "active".to_string(),
+            availability_zones: vec![],
+            security_groups: vec![],
+            listeners: vec![],
+            target_groups: vec![],
+        });
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "lb-lb-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
+
+        clear_details(&mut app);
+        app.ecr_repositories = vec![sample_resource("repo-test", "repo-test")];
         app.ecr_detail = Some(EcrDetail {
-            name: "repo".to_string(),
-            uri: "uri".to_string(),
+            name: "seed-repo".to_string(),
+            uri: "seed-uri".to_string(),
             tag_mutability: "MUTABLE".to_string(),
             encryption_type: "AES256".to_string(),
             kms_key: None,
             created_at: "2026-01-01".to_string(),
             image_count: 0,
         });
-        handle_key(&mut app, key(KeyCode::Esc));
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "repo-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
+
+        clear_details(&mut app);
+        app.auto_scaling_groups = vec![sample_resource("asg-test", "asg-test")];
+        app.asg_detail = Some(AsgDetail {
+            name: "seed-asg".to_string(),
+            arn: "arn:seed-asg".to_string(),
+            launch_template_name: None,
+            launch_template_id: None,
+            launch_template_version: None,
+            launch_template: None,
+            launch_config_name: None,
+            min_size: 1,
+            max_size: 1,
+            desired_capacity: 1,
+            default_cooldown: 0,
+            availability_zones: vec![],
+            target_group_arns: vec![],
+            health_check_type: "EC2".to_string(),
+            health_check_grace_period: 0,
+            instances: vec![],
+            created_time: "2026-01-01".to_string(),
+            scaling_policies: vec![],
+            scheduled_actions: vec![],
+            tags: vec![],
+        });
+        reset_refresh(&mut app);
+        process_loading(&mut app);
+        assert_eq!(app.preview_filename, "asg-test.md");
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+        assert_eq!(app.loading_task, LoadingTask::None);
+    }
+
+    #[test]
+    fn login_screen_quit_shortcut_stops_app() {
+        let mut app = App::new();
+        app.screen = Screen::Login;
+        app.running = true;
+        handle_key(&mut app, key(KeyCode::Char('q')));
+        assert!(!app.running);
+    }
+
+    #[test]
+    fn process_loading_load_list_tasks_open_each_select_screen() {
+        let mut app = App::new();
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert!(!app.instances.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpc;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpcSelect);
+        assert!(!app.vpcs.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSecurityGroup;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SecurityGroupSelect);
+        assert!(!app.security_groups.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadLoadBalancer;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::LoadBalancerSelect);
+        assert!(!app.load_balancers.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEcr;
+        process_loading(&mut app);
         assert_eq!(app.screen, Screen::EcrSelect);
+        assert!(!app.ecr_repositories.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAsg;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::AsgSelect);
+        assert!(!app.auto_scaling_groups.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRds;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::RdsSelect);
+        assert!(!app.rds_databases.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadLambda;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::LambdaSelect);
+        assert!(!app.lambda_functions.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadDynamoDb;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::DynamoDbSelect);
+        assert!(!app.dynamodb_tables.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudFront;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::CloudFrontSelect);
+        assert!(!app.cloudfront_distributions.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRoute53;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Route53Select);
+        assert!(!app.route53_zones.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEks;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EksSelect);
+        assert!(!app.eks_clusters.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEcs;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EcsSelect);
+        assert!(!app.ecs_clusters.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSqs;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SqsSelect);
+        assert!(!app.sqs_queues.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSns;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SnsSelect);
+        assert!(!app.sns_topics.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadElastiCache;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::ElastiCacheSelect);
+        assert!(!app.elasticache_clusters.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudWatchAlarm;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::CloudWatchAlarmSelect);
+        assert!(!app.cloudwatch_alarms.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudTrail;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::CloudTrailSelect);
+        assert!(!app.cloudtrail_trails.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadKms;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::KmsSelect);
+        assert!(!app.kms_keys.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSecret;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SecretSelect);
+        assert!(!app.secrets.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSsmParameter;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SsmParameterSelect);
+        assert!(!app.ssm_parameter_paths.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEfs;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EfsSelect);
+        assert!(!app.efs_file_systems.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadApiGateway;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::ApiGatewaySelect);
+        assert!(!app.api_gateways.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadElasticBeanstalk;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::ElasticBeanstalkSelect);
+        assert!(!app.beanstalk_environments.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadStepFunctions;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::StepFunctionsSelect);
+        assert!(!app.state_machines.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadKinesis;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::KinesisSelect);
+        assert!(!app.kinesis_streams.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadMsk;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::MskSelect);
+        assert!(!app.msk_clusters.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadOpenSearch;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::OpenSearchSelect);
+        assert!(!app.opensearch_domains.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRedshift;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::RedshiftSelect);
+        assert!(!app.redshift_clusters.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadTransitGateway;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::TransitGatewaySelect);
+        assert!(!app.transit_gateways.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcPeering;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpcPeeringSelect);
+        assert!(!app.vpc_peerings.is_empty());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpn;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpnSelect);
+        assert!(!app.vpns.is_empty());
 
-        app.screen = Screen::Preview;
-        app.asg_detail = Some(AsgDetail {
-            name: "asg".to_string(),
-            arn: "arn".to_string(),
-            launch_template_name: None,
-            launch_template_id: None,
-            launch_config_name: None,
-            min_size: 1,
-            max_size: 1,
-            desired_capacity: 1,
-            default_cooldown: 0,
-            availability_zones: Vec::new(),
-            target_group_arns: Vec::new(),
-            health_check_type: "EC2".to_string(),
-            health_check_grace_period: 0,
-            instances: Vec::new(),
-            created_time: "2026-01-01".to_string(),
-            scaling_policies: Vec::new(),
-            tags: Vec::new(),
-        });
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::AsgSelect);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadDirectConnect;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::DirectConnectSelect);
+        assert!(!app.direct_connect_resources.is_empty());
 
-    #[test]
-    fn blueprint_select_shortcuts_change_mode_and_tab() {
-        let mut app = App::new();
-        app.screen = Screen::BlueprintSelect;
-        app.blueprint_store.blueprints = vec![crate::blueprint::Blueprint {
-            id: "bp-1".to_string(),
-            name: "bp-1".to_string(),
-            resources: vec![crate::blueprint::BlueprintResource {
-                resource_type: crate::blueprint::ResourceType::Ec2,
-                region: "ap-northeast-2".to_string(),
-                resource_id: "i-1234".to_string(),
-                resource_name: "web".to_string(),
-            }],
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        }];
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadWaf;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::WafSelect);
+        assert!(!app.waf_web_acls.is_empty());
 
-        app.selected_blueprint_index = 1;
-        handle_key(&mut app, key(KeyCode::Char('g')));
-        assert_eq!(app.loading_task, LoadingTask::LoadBlueprintResources(0));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAcm;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::AcmSelect);
+        assert!(!app.acm_certificates.is_empty());
 
-        app.loading = false;
-        app.selected_blueprint_index = 1;
-        handle_key(&mut app, key(KeyCode::Char('d')));
-        assert!(app.selected_blueprint_index <= 1);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudFormation;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::CloudFormationSelect);
+        assert!(!app.cloudformation_stacks.is_empty());
 
-        handle_key(&mut app, key(KeyCode::Char('s')));
-        assert_eq!(app.screen, Screen::RegionSelect);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadIam;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::IamSelect);
+        assert!(!app.iam_entities.is_empty());
 
-        app.screen = Screen::BlueprintSelect;
-        handle_key(&mut app, key(KeyCode::Tab));
-        assert_eq!(app.screen, Screen::Settings);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcEndpoint;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::VpcEndpointSelect);
+        assert!(!app.vpc_endpoints.is_empty());
 
-    #[test]
-    fn blueprint_detail_actions_cover_add_delete_generate_and_escape() {
-        let mut app = App::new();
-        app.screen = Screen::BlueprintDetail;
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp".to_string(),
-            name: "bp".to_string(),
-            resources: vec![crate::blueprint::BlueprintResource {
-                resource_type: crate::blueprint::ResourceType::Ec2,
-                region: "ap-northeast-2".to_string(),
-                resource_id: "i-1234".to_string(),
-                resource_name: "web".to_string(),
-            }],
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadNetworkInterface;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::NetworkInterfaceSelect);
+        assert!(!app.network_interfaces.is_empty());
 
-        handle_key(&mut app, key(KeyCode::Char('a')));
-        assert_eq!(app.screen, Screen::RegionSelect);
-        assert!(app.blueprint_mode);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadLaunchTemplate;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::LaunchTemplateSelect);
+        assert!(!app.launch_templates.is_empty());
 
-        app.screen = Screen::BlueprintDetail;
-        app.blueprint_mode = false;
-        app.blueprint_resource_index = 0;
-        handle_key(&mut app, key(KeyCode::Char('d')));
-        assert_eq!(
-            app.current_blueprint
-                .as_ref()
-                .map(|bp| bp.resources.len())
-                .unwrap_or_default(),
-            0
-        );
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAmi;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::AmiSelect);
+        assert!(!app.amis.is_empty());
 
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp".to_string(),
-            name: "bp".to_string(),
-            resources: vec![crate::blueprint::BlueprintResource {
-                resource_type: crate::blueprint::ResourceType::Ec2,
-                region: "ap-northeast-2".to_string(),
-                resource_id: "i-1234".to_string(),
-                resource_name: "web".to_string(),
-            }],
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.loading_task, LoadingTask::LoadBlueprintResources(0));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEbs;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EbsSelect);
+        assert!(!app.ebs_resources.is_empty());
 
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp".to_string(),
-            name: "bp".to_string(),
-            resources: Vec::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
-        app.loading_task = LoadingTask::None;
-        handle_key(&mut app, key(KeyCode::Char('g')));
-        assert_eq!(app.message, app.i18n.no_resources());
-        assert_eq!(app.loading_task, LoadingTask::None);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadKeyPair;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::KeyPairSelect);
+        assert!(!app.key_pairs.is_empty());
 
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEventBridge;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::EventBridgeSelect);
+        assert!(!app.event_buses.is_empty());
 
-    #[test]
-    fn service_select_exit_paths_cover_blueprint_and_running_flags() {
-        let mut app = App::new();
-        app.screen = Screen::ServiceSelect;
-        app.selected_service = crate::app::SERVICE_KEYS.len();
-        app.blueprint_mode = true;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCognito;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::CognitoSelect);
+        assert!(!app.cognito_pools.is_empty());
 
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.screen, Screen::BlueprintDetail);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadGlue;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::GlueSelect);
+        assert!(!app.glue_resources.is_empty());
 
-        app.screen = Screen::ServiceSelect;
-        app.selected_service = crate::app::SERVICE_KEYS.len();
-        app.blueprint_mode = false;
-        app.running = true;
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert!(!app.running);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAthena;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::AthenaSelect);
+        assert!(!app.athena_workgroups.is_empty());
 
-    fn sample_blueprint(name: &str) -> crate::blueprint::Blueprint {
-        crate::blueprint::Blueprint {
-            id: format!("id-{}", name),
-            name: name.to_string(),
-            resources: Vec::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBackup;
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::BackupSelect);
+        assert!(!app.backup_plans.is_empty());
     }
 
     #[test]
-    fn blueprint_name_input_enter_creates_blueprint_and_opens_detail() {
+    fn process_loading_detail_tasks_open_preview_screen() {
         let mut app = App::new();
-        app.screen = Screen::BlueprintNameInput;
-        app.input_buffer = "new-bp".to_string();
+        app.settings.language = crate::i18n::Language::English;
 
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEc2Detail("i-1234".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.ec2_detail.is_some());
 
-        assert_eq!(app.screen, Screen::BlueprintDetail);
-        assert_eq!(
-            app.current_blueprint
-                .as_ref()
-                .map(|bp| bp.name.clone())
-                .unwrap_or_default(),
-            "new-bp"
-        );
-        assert!(app.input_buffer.is_empty());
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSecurityGroupDetail("sg-1234".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.sg_detail.is_some());
 
-    #[test]
-    fn blueprint_preview_keys_cover_navigation_save_and_escape_paths() {
-        let mut app = App::new();
-        app.screen = Screen::BlueprintPreview;
-        app.current_blueprint = Some(sample_blueprint("bp-a"));
-        app.preview_content = (0..50)
-            .map(|n| format!("line {}", n))
-            .collect::<Vec<_>>()
-            .join("\n");
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadLoadBalancerDetail("lb-1234".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.lb_detail.is_some());
 
-        handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.preview_scroll, 1);
-        handle_key(&mut app, key(KeyCode::PageDown));
-        assert!(app.preview_scroll >= 20);
-        handle_key(&mut app, key(KeyCode::Home));
-        assert_eq!(app.preview_scroll, 0);
-        handle_key(&mut app, key(KeyCode::End));
-        assert_eq!(app.preview_scroll, 49);
-        handle_key(&mut app, key(KeyCode::Char('s')));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEcrDetail("repo-a".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.ecr_detail.is_some());
 
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintDetail);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAsgDetail("asg-a".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.asg_detail.is_some());
 
-        app.screen = Screen::BlueprintPreview;
-        app.current_blueprint = None;
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRdsDetail("orders-db".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "orders-db.md");
+        assert!(app.rds_detail.is_some());
 
-    #[test]
-    fn service_select_navigation_shortcuts_and_escape_work() {
-        let mut app = App::new();
-        app.screen = Screen::ServiceSelect;
-        app.selected_service = 1;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadLambdaDetail("orders-api".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "orders-api.md");
+        assert!(app.lambda_detail.is_some());
 
-        handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.selected_service, 2);
-        handle_key(&mut app, key(KeyCode::Up));
-        assert_eq!(app.selected_service, 1);
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::RegionSelect);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadDynamoDbDetail("orders".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "orders.md");
+        assert!(app.dynamodb_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudFrontDetail("E2EXAMPLE".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "E2EXAMPLE.md");
+        assert!(app.cloudfront_detail.is_some());
 
-    #[test]
-    fn resource_select_refresh_shortcuts_set_loading_tasks() {
-        let mut app = App::new();
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRoute53Detail("Z0123456789ABC".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "Z0123456789ABC.md");
+        assert!(app.route53_detail.is_some());
 
-        app.screen = Screen::Ec2Select;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshEc2);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEksDetail("checkout".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "checkout.md");
+        assert!(app.eks_detail.is_some());
 
-        app.screen = Screen::VpcSelect;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshVpc);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEcsDetail("checkout".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "checkout.md");
+        assert!(app.ecs_detail.is_some());
 
-        app.screen = Screen::SecurityGroupSelect;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshSecurityGroup);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSqsDetail("orders".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "orders.md");
+        assert!(app.sqs_detail.is_some());
 
-        app.screen = Screen::LoadBalancerSelect;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshLoadBalancer);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSnsDetail("alerts".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "alerts.md");
+        assert!(app.sns_detail.is_some());
 
-        app.screen = Screen::EcrSelect;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshEcr);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadElastiCacheDetail("sessions".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "sessions.md");
+        assert!(app.elasticache_detail.is_some());
 
-        app.screen = Screen::AsgSelect;
-        handle_key(&mut app, key(KeyCode::Char('r')));
-        assert_eq!(app.loading_task, LoadingTask::RefreshAsg);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudWatchAlarmDetail("api-high-cpu".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "api-high-cpu.md");
+        assert!(app.cloudwatch_alarm_detail.is_some());
 
-    #[test]
-    fn blueprint_mode_enter_adds_resource_from_each_select_screen() {
-        let mut app = App::new();
-        app.blueprint_mode = true;
-        app.current_blueprint = Some(sample_blueprint("bp-r"));
-        app.selected_index = 0;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudTrailDetail("org-audit".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "org-audit.md");
+        assert!(app.cloudtrail_detail.is_some());
 
-        app.screen = Screen::Ec2Select;
-        app.instances = vec![sample_resource("i-1", "ec2-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task =
+            LoadingTask::LoadKmsDetail("1234abcd-12ab-34cd-56ef-1234567890ab".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(
+            app.preview_filename,
+            "1234abcd-12ab-34cd-56ef-1234567890ab.md"
+        );
+        assert!(app.kms_detail.is_some());
 
-        app.screen = Screen::VpcSelect;
-        app.vpcs = vec![sample_resource("vpc-1", "vpc-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSecretDetail(
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf"
+                .to_string(),
+        );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(
+            app.preview_filename,
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf.md"
+        );
+        assert!(app.secret_detail.is_some());
 
-        app.screen = Screen::SecurityGroupSelect;
-        app.security_groups = vec![sample_resource("sg-1", "sg-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadSsmParameterDetail("/ssm-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "ssm-ssm-test.md");
+        assert!(app.ssm_parameter_detail.is_some());
 
-        app.screen = Screen::LoadBalancerSelect;
-        app.load_balancers = vec![sample_resource("lb-1", "lb-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEfsDetail("fs-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "fs-test.md");
+        assert!(app.efs_detail.is_some());
 
-        app.screen = Screen::EcrSelect;
-        app.ecr_repositories = vec![sample_resource("repo-a", "repo-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadApiGatewayDetail("api-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "api-test.md");
+        assert!(app.api_gateway_detail.is_some());
 
-        app.screen = Screen::AsgSelect;
-        app.auto_scaling_groups = vec![sample_resource("asg-a", "asg-a")];
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadElasticBeanstalkDetail("e-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "e-test.md");
+        assert!(app.elastic_beanstalk_detail.is_some());
 
-        let resources = app
-            .current_blueprint
-            .as_ref()
-            .map(|bp| bp.resources.clone())
-            .unwrap_or_default();
-        assert_eq!(resources.len(), 6);
-        assert_eq!(
-            resources[0].resource_type,
-            crate::blueprint::ResourceType::Ec2
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadStepFunctionsDetail(
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test".to_string(),
         );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
         assert_eq!(
-            resources[1].resource_type,
-            crate::blueprint::ResourceType::Network
+            app.preview_filename,
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test.md"
+        );
+        assert!(app.state_machine_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadKinesisDetail("kinesis-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "kinesis-test.md");
+        assert!(app.kinesis_stream_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadMskDetail(
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1".to_string(),
         );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
         assert_eq!(
-            resources[2].resource_type,
-            crate::blueprint::ResourceType::SecurityGroup
+            app.preview_filename,
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1.md"
+        );
+        assert!(app.msk_cluster_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadOpenSearchDetail("opensearch-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "opensearch-test.md");
+        assert!(app.opensearch_domain_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadRedshiftDetail("redshift-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "redshift-test.md");
+        assert!(app.redshift_cluster_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadTransitGatewayDetail("tgw-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "tgw-test.md");
+        assert!(app.transit_gateway_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcPeeringDetail("pcx-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "pcx-test.md");
+        assert!(app.vpc_peering_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpnDetail("vpn-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "vpn-test.md");
+        assert!(app.vpn_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadDirectConnectDetail("dxcon-test".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "dxcon-test.md");
+        assert!(app.direct_connect_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadWafDetail(
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4"
+                .to_string(),
         );
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
         assert_eq!(
-            resources[3].resource_type,
-            crate::blueprint::ResourceType::LoadBalancer
+            app.preview_filename,
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4.md"
         );
+        assert!(app.waf_web_acl_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAcmDetail("arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
         assert_eq!(
-            resources[4].resource_type,
-            crate::blueprint::ResourceType::Ecr
+            app.preview_filename,
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0.md"
         );
+        assert!(app.acm_certificate_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCloudFormationDetail("checkout".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "checkout.md");
+        assert!(app.cloudformation_stack_detail.is_some());
+
+        app.loading = true;
+        app.loading_task =
+            LoadingTask::LoadIamDetail("arn:aws:iam::123456789012:role/app-role".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
         assert_eq!(
-            resources[5].resource_type,
-            crate::blueprint::ResourceType::Asg
+            app.preview_filename,
+            "arn:aws:iam::123456789012:role/app-role.md"
         );
-    }
+        assert!(app.iam_entity_detail.is_some());
 
-    #[test]
-    fn settings_screen_left_returns_to_blueprint_select() {
-        let mut app = App::new();
-        app.screen = Screen::Settings;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadVpcEndpointDetail("vpce-0a1b2c3d4e5f60718".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "vpce-0a1b2c3d4e5f60718.md");
+        assert!(app.vpc_endpoint_detail.is_some());
 
-        handle_key(&mut app, key(KeyCode::Left));
-        assert_eq!(app.screen, Screen::BlueprintSelect);
-        assert_eq!(app.selected_tab, 0);
-    }
+        app.loading = true;
+        app.loading_task =
+            LoadingTask::LoadNetworkInterfaceDetail("eni-0a1b2c3d4e5f60718".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "eni-0a1b2c3d4e5f60718.md");
+        assert!(app.network_interface_detail.is_some());
 
-    #[test]
-    fn preview_blueprint_mode_escape_clears_details_and_returns_to_detail_screen() {
-        let mut app = App::new();
-        app.screen = Screen::Preview;
-        app.blueprint_mode = true;
-        app.ec2_detail = Some(Ec2Detail {
-            name: "web".to_string(),
-            instance_id: "i-1".to_string(),
-            instance_type: "t3.micro".to_string(),
-            ami: "ami".to_string(),
-            platform: "Linux".to_string(),
-            architecture: "x86_64".to_string(),
-            key_pair: "kp".to_string(),
-            vpc: "vpc".to_string(),
-            subnet: "subnet".to_string(),
-            az: "az".to_string(),
-            public_ip: "-".to_string(),
-            private_ip: "10.0.0.1".to_string(),
-            security_groups: vec![],
-            state: "running".to_string(),
-            ebs_optimized: false,
-            monitoring: "disabled".to_string(),
-            iam_role: None,
-            iam_role_detail: None,
-            launch_time: String::new(),
-            tags: vec![],
-            volumes: vec![],
-            user_data: None,
-        });
+        app.loading = true;
+        app.loading_task =
+            LoadingTask::LoadLaunchTemplateDetail("lt-0a1b2c3d4e5f60718".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "lt-0a1b2c3d4e5f60718.md");
+        assert!(app.launch_template_detail.is_some());
 
-        handle_key(&mut app, key(KeyCode::Esc));
-        assert_eq!(app.screen, Screen::BlueprintDetail);
-        assert!(app.ec2_detail.is_none());
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAmiDetail("ami-0a1b2c3d4e5f60718".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "ami-0a1b2c3d4e5f60718.md");
+        assert!(app.ami_detail.is_some());
 
-    #[test]
-    fn handle_mouse_outside_preview_is_noop() {
-        let mut app = App::new();
-        app.screen = Screen::ServiceSelect;
-        app.preview_scroll = 7;
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEbsDetail("vol-0123".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "vol-0123.md");
+        assert!(app.ebs_detail.is_some());
 
-        handle_mouse(
-            &mut app,
-            MouseEvent {
-                kind: MouseEventKind::ScrollDown,
-                column: 0,
-                row: 0,
-                modifiers: KeyModifiers::NONE,
-            },
-        );
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadKeyPairDetail("key-0123".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "key-0123.md");
+        assert!(app.key_pair_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadEventBridgeDetail("default".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "default.md");
+        assert!(app.event_bus_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadCognitoDetail("ap-northeast-2_AbCdEf123".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "ap-northeast-2_AbCdEf123.md");
+        assert!(app.cognito_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadGlueDetail("database/sales".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "database/sales.md");
+        assert!(app.glue_detail.is_some());
+
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadAthenaDetail("primary".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "primary.md");
+        assert!(app.athena_workgroup_detail.is_some());
 
-        assert_eq!(app.preview_scroll, 7);
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBackupDetail("8a2f1c4e".to_string());
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Preview);
+        assert_eq!(app.preview_filename, "8a2f1c4e.md");
+        assert!(app.backup_plan_detail.is_some());
     }
 
     #[test]
-    fn blueprint_select_enter_covers_new_and_existing_paths() {
+    fn process_loading_refresh_tasks_update_message_and_finish() {
         let mut app = App::new();
-        app.screen = Screen::BlueprintSelect;
-        app.input_buffer = "temp".to_string();
 
-        app.selected_blueprint_index = 0;
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.screen, Screen::BlueprintNameInput);
-        assert!(app.input_buffer.is_empty());
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEc2;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        app.blueprint_store.blueprints = vec![sample_blueprint("bp-existing")];
-        app.screen = Screen::BlueprintSelect;
-        app.selected_blueprint_index = 1;
-        handle_key(&mut app, key(KeyCode::Enter));
-        assert_eq!(app.screen, Screen::BlueprintDetail);
-        assert_eq!(
-            app.current_blueprint
-                .as_ref()
-                .map(|bp| bp.name.clone())
-                .unwrap_or_default(),
-            "bp-existing"
-        );
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshVpc;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-    #[test]
-    fn process_loading_blueprint_resources_handles_none_and_empty_blueprint_paths() {
-        let mut app = App::new();
         app.loading = true;
-        app.loading_task = LoadingTask::LoadBlueprintResources(0);
-        app.current_blueprint = None;
+        app.loading_task = LoadingTask::RefreshSecurityGroup;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshLoadBalancer;
         process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadBlueprintResources(0);
-        app.current_blueprint = Some(crate::blueprint::Blueprint {
-            id: "bp-empty".to_string(),
-            name: "bp-empty".to_string(),
-            resources: Vec::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        });
-        app.blueprint_markdown_parts = Vec::new();
+        app.loading_task = LoadingTask::RefreshEcr;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshAsg;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::BlueprintPreview);
-        assert!(app.preview_content.contains("# Blueprint: bp-empty"));
+        assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
-    }
 
-    #[test]
-    fn process_loading_vpc_detail_steps_require_step_zero_init() {
-        let mut app = App::new();
         app.loading = true;
-        app.loading_task = LoadingTask::LoadVpcDetail("vpc-1".to_string(), 1);
+        app.loading_task = LoadingTask::RefreshRds;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshLambda;
         process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
-        assert_eq!(app.network_detail, None);
-        assert_eq!(
-            app.loading_task,
-            LoadingTask::None,
-            "step 1 should not proceed without step 0 initialization"
-        );
-        assert_eq!(app.message, app.i18n.network_detail_unavailable("vpc-1"));
-    }
 
-    #[test]
-    fn process_loading_refresh_preview_without_detail_finishes_cleanly() {
-        let mut app = App::new();
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshPreview;
+        app.loading_task = LoadingTask::RefreshDynamoDb;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshCloudFront;
         process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshRoute53;
+        process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
-    }
+        assert!(!app.loading);
 
-    #[test]
-    fn process_loading_refresh_preview_updates_all_detail_types() {
-        let mut app = App::new();
-        app.selected_index = 0;
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEks;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        let reset_refresh = |app: &mut App| {
-            app.loading = true;
-            app.loading_task = LoadingTask::RefreshPreview;
-            app.message.clear();
-        };
-        let clear_details = |app: &mut App| {
-            app.ec2_detail = None;
-            app.network_detail = None;
-            app.sg_detail = None;
-            app.lb_detail = None;
-            app.ecr_detail = None;
-            app.asg_detail = None;
-        };
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEcs;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.instances = vec![sample_resource("i-test", "ec2-test")];
-        app.ec2_detail = Some(Ec2Detail {
-            name: "seed-ec2".to_string(),
-            instance_id: "i-seed".to_string(),
-            instance_type: "t3.micro".to_string(),
-            ami: "ami-seed".to_string(),
-            platform: "Linux".to_string(),
-            architecture: "x86_64".to_string(),
-            key_pair: "kp".to_string(),
-            vpc: "vpc-seed".to_string(),
-            subnet: "subnet-seed".to_string(),
-            az: "ap-northeast-2a".to_string(),
-            public_ip: "-".to_string(),
-            private_ip: "10.0.0.10".to_string(),
-            security_groups: vec!["sg-seed".to_string()],
-            state: "running".to_string(),
-            ebs_optimized: false,
-            monitoring: "Disabled".to_string(),
-            iam_role: None,
-            iam_role_detail: None,
-            launch_time: String::new(),
-            tags: vec![],
-            volumes: vec![],
-            user_data: None,
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshSqs;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "ec2-i-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.vpcs = vec![sample_resource("vpc-test", "vpc-test")];
-        app.network_detail = Some(NetworkDetail {
-            name: "seed-network".to_string(),
-            id: "vpc-seed".to_string(),
-            cidr: "10.0.0.0/16".to_string(),
-            state: "available".to_string(),
-            subnets: vec![],
-            igws: vec![],
-            nats: vec![],
-            route_tables: vec![],
-            eips: vec![],
-            dns_support: true,
-            dns_hostnames: true,
-            tags: vec![],
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshSns;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "network-vpc-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.security_groups = vec![sample_resource("sg-test", "sg-test")];
-        app.sg_detail = Some(SecurityGroupDetail {
-            name: "seed-sg".to_string(),
-            id: "sg-seed".to_string(),
-            description: "seed".to_string(),
-            vpc_id: "vpc-seed".to_string(),
-            inbound_rules: vec![],
-            outbound_rules: vec![],
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshElastiCache;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "sg-sg-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.load_balancers = vec![sample_resource("lb-test", "lb-test")];
-        app.lb_detail = Some(LoadBalancerDetail {
-            name: "seed-lb".to_string(),
-            arn: "arn:seed".to_string(),
-            dns_name: "seed.example.com".to_string(),
-            lb_type: "application".to_string(),
-            scheme: "internal".to_string(),
-            vpc_id: "vpc-seed".to_string(),
-            ip_address_type: "ipv4".to_string(),
-            state: "active".to_string(),
-            availability_zones: vec![],
-            security_groups: vec![],
-            listeners: vec![],
-            target_groups: vec![],
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshCloudWatchAlarm;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "lb-lb-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.ecr_repositories = vec![sample_resource("repo-test", "repo-test")];
-        app.ecr_detail = Some(EcrDetail {
-            name: "seed-repo".to_string(),
-            uri: "seed-uri".to_string(),
-            tag_mutability: "MUTABLE".to_string(),
-            encryption_type: "AES256".to_string(),
-            kms_key: None,
-            created_at: "2026-01-01".to_string(),
-            image_count: 0,
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshCloudTrail;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshKms;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshSecret;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshSsmParameter;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshEfs;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshApiGateway;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshElasticBeanstalk;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshStepFunctions;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "repo-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-        clear_details(&mut app);
-        app.auto_scaling_groups = vec![sample_resource("asg-test", "asg-test")];
-        app.asg_detail = Some(AsgDetail {
-            name: "seed-asg".to_string(),
-            arn: "arn:seed-asg".to_string(),
-            launch_template_name: None,
-            launch_template_id: None,
-            launch_config_name: None,
-            min_size: 1,
-            max_size: 1,
-            desired_capacity: 1,
-            default_cooldown: 0,
-            availability_zones: vec![],
-            target_group_arns: vec![],
-            health_check_type: "EC2".to_string(),
-            health_check_grace_period: 0,
-            instances: vec![],
-            created_time: "2026-01-01".to_string(),
-            scaling_policies: vec![],
-            tags: vec![],
-        });
-        reset_refresh(&mut app);
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshKinesis;
         process_loading(&mut app);
-        assert_eq!(app.preview_filename, "asg-test.md");
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
-        assert_eq!(app.loading_task, LoadingTask::None);
-    }
 
-    #[test]
-    fn login_screen_quit_shortcut_stops_app() {
-        let mut app = App::new();
-        app.screen = Screen::Login;
-        app.running = true;
-        handle_key(&mut app, key(KeyCode::Char('q')));
-        assert!(!app.running);
-    }
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshMsk;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-    #[test]
-    fn process_loading_load_list_tasks_open_each_select_screen() {
-        let mut app = App::new();
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshOpenSearch;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadEc2;
+        app.loading_task = LoadingTask::RefreshRedshift;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Ec2Select);
-        assert!(!app.instances.is_empty());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadVpc;
+        app.loading_task = LoadingTask::RefreshTransitGateway;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::VpcSelect);
-        assert!(!app.vpcs.is_empty());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadSecurityGroup;
+        app.loading_task = LoadingTask::RefreshVpcPeering;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::SecurityGroupSelect);
-        assert!(!app.security_groups.is_empty());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadLoadBalancer;
+        app.loading_task = LoadingTask::RefreshVpn;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::LoadBalancerSelect);
-        assert!(!app.load_balancers.is_empty());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadEcr;
+        app.loading_task = LoadingTask::RefreshDirectConnect;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::EcrSelect);
-        assert!(!app.ecr_repositories.is_empty());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadAsg;
+        app.loading_task = LoadingTask::RefreshWaf;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::AsgSelect);
-        assert!(!app.auto_scaling_groups.is_empty());
-    }
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-    #[test]
-    fn process_loading_detail_tasks_open_preview_screen() {
-        let mut app = App::new();
-        app.settings.language = crate::i18n::Language::English;
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshAcm;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadEc2Detail("i-1234".to_string());
+        app.loading_task = LoadingTask::RefreshCloudFormation;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Preview);
-        assert!(app.ec2_detail.is_some());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadSecurityGroupDetail("sg-1234".to_string());
+        app.loading_task = LoadingTask::RefreshIam;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Preview);
-        assert!(app.sg_detail.is_some());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadLoadBalancerDetail("lb-1234".to_string());
+        app.loading_task = LoadingTask::RefreshVpcEndpoint;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Preview);
-        assert!(app.lb_detail.is_some());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadEcrDetail("repo-a".to_string());
+        app.loading_task = LoadingTask::RefreshNetworkInterface;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Preview);
-        assert!(app.ecr_detail.is_some());
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::LoadAsgDetail("asg-a".to_string());
+        app.loading_task = LoadingTask::RefreshLaunchTemplate;
         process_loading(&mut app);
-        assert_eq!(app.screen, Screen::Preview);
-        assert!(app.asg_detail.is_some());
-    }
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
-    #[test]
-    fn process_loading_refresh_tasks_update_message_and_finish() {
-        let mut app = App::new();
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshAmi;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshEc2;
+        app.loading_task = LoadingTask::RefreshEbs;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshVpc;
+        app.loading_task = LoadingTask::RefreshKeyPair;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshSecurityGroup;
+        app.loading_task = LoadingTask::RefreshEventBridge;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshLoadBalancer;
+        app.loading_task = LoadingTask::RefreshCognito;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshEcr;
+        app.loading_task = LoadingTask::RefreshGlue;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
 
         app.loading = true;
-        app.loading_task = LoadingTask::RefreshAsg;
+        app.loading_task = LoadingTask::RefreshAthena;
+        process_loading(&mut app);
+        assert_eq!(app.message, app.i18n.refresh_complete());
+        assert!(!app.loading);
+
+        app.loading = true;
+        app.loading_task = LoadingTask::RefreshBackup;
         process_loading(&mut app);
         assert_eq!(app.message, app.i18n.refresh_complete());
         assert!(!app.loading);
@@ -2645,6 +11000,209 @@ mod tests {
         assert!(app.preview_content.contains("## Load Balancer"));
         assert!(!app.loading);
         assert_eq!(app.loading_task, LoadingTask::None);
+        assert!(aws_adapter::prefetched_instance_ids().is_empty());
+    }
+
+    #[test]
+    fn blueprint_batches_stop_at_limit_and_region_change() {
+        let resource = |id: &str, region: &str| crate::blueprint::BlueprintResource {
+            resource_type: crate::blueprint::ResourceType::SecurityGroup,
+            region: region.to_string(),
+            resource_id: id.to_string(),
+            resource_name: id.to_string(),
+        };
+        let resources = vec![
+            resource("sg-1", "ap-northeast-2"),
+            resource("sg-2", "ap-northeast-2"),
+            resource("sg-3", "ap-northeast-2"),
+            resource("sg-4", "us-east-1"),
+        ];
+
+        assert_eq!(blueprint_batch_end(&resources, 0, 2), 2);
+        // 리전이 바뀌면 한도보다 적어도 끊음
+        assert_eq!(blueprint_batch_end(&resources, 2, 2), 3);
+        assert_eq!(blueprint_batch_end(&resources, 3, 4), 4);
+        // 한도 0은 1로 취급
+        assert_eq!(blueprint_batch_end(&resources, 0, 0), 1);
+        assert_eq!(blueprint_batch_end(&resources, 4, 2), 4);
+
+        let mut app = App::new();
+        app.current_blueprint = Some(crate::blueprint::Blueprint {
+            id: "bp-batch".to_string(),
+            name: "bp-batch".to_string(),
+            resources,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        });
+        app.loading = true;
+        app.loading_task = LoadingTask::LoadBlueprintResources(0);
+        process_loading(&mut app);
+        assert_eq!(app.loading_task, LoadingTask::LoadBlueprintResources(2));
+        assert_eq!(app.blueprint_markdown_parts.len(), 2);
+        assert!(app.blueprint_markdown_parts[0].contains("sg-1"));
+        assert!(app.blueprint_markdown_parts[1].contains("sg-2"));
+    }
+
+    #[test]
+    fn document_region_builds_one_chapter_per_enabled_service() {
+        let mut app = App::new();
+        app.settings.disabled_services = SERVICE_KEYS
+            .iter()
+            .filter(|name| !matches!(**name, "SQS" | "ACM"))
+            .map(|name| name.to_string())
+            .collect();
+        app.screen = Screen::ServiceSelect;
+        handle_key(&mut app, key(KeyCode::Char('d')));
+        assert_eq!(app.loading_task, LoadingTask::DocumentRegion(0));
+
+        for _ in 0..10 {
+            if !app.loading {
+                break;
+            }
+            process_loading(&mut app);
+        }
+
+        assert!(!app.loading);
+        assert!(app.region_inventory.is_none());
+        assert_eq!(app.screen, Screen::Preview);
+        assert!(app.preview_filename.starts_with("region-"));
+        let document = &app.preview_content;
+        assert!(document.starts_with(&format!("# {}: ", app.i18n.region_inventory())));
+        assert!(document.contains(&app.i18n.region_inventory_summary(2, 2)));
+        let sqs = document
+            .find(&format!("# 1. {}", app.i18n.sqs_queue()))
+            .expect("SQS chapter");
+        let acm = document
+            .find(&format!("# 2. {}", app.i18n.acm()))
+            .expect("ACM chapter");
+        assert!(sqs < acm);
+        assert!(document.contains("  - [example.com](#"));
+    }
+
+    #[test]
+    fn document_region_without_resources_reports_empty_region() {
+        let mut app = App::new();
+        app.settings.disabled_services = SERVICE_KEYS.iter().map(|name| name.to_string()).collect();
+        app.screen = Screen::ServiceSelect;
+        handle_key(&mut app, key(KeyCode::Char('d')));
+        process_loading(&mut app);
+
+        assert!(!app.loading);
+        assert!(app.region_inventory.is_none());
+        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert_eq!(app.message, app.i18n.region_inventory_empty());
+    }
+
+    #[test]
+    fn heading_anchor_follows_github_rules() {
+        assert_eq!(heading_anchor("1. SQS Queue"), "1-sqs-queue");
+        assert_eq!(
+            heading_anchor("ACM Certificate (example.com)"),
+            "acm-certificate-examplecom"
+        );
+        assert_eq!(heading_anchor("IAM 역할 (app_role)"), "iam-역할-app_role");
+    }
+
+    #[test]
+    fn generate_blueprint_document_matches_tui_output_without_app() {
+        let blueprint = crate::blueprint::Blueprint {
+            id: "bp-daemon".to_string(),
+            name: "bp-daemon".to_string(),
+            resources: vec![crate::blueprint::BlueprintResource {
+                resource_type: crate::blueprint::ResourceType::SecurityGroup,
+                region: "ap-northeast-2".to_string(),
+                resource_id: "sg-1".to_string(),
+                resource_name: "sg-a".to_string(),
+            }],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+
+        let document = generate_blueprint_document(&blueprint, crate::i18n::Language::English);
+        assert!(document.starts_with("# Blueprint: bp-daemon\n\n## Summary\n"));
+        assert!(document.contains("| Security Group | 1 |"));
+        assert!(document.contains("- [1. Security Group - sg-a](#security-group-sg-a)"));
+        assert!(document.contains("## Security Group"));
+    }
+
+    #[test]
+    fn with_last_modified_appends_cloudtrail_section() {
+        let markdown = with_last_modified(
+            &crate::blueprint::ResourceType::SecurityGroup,
+            "sg-1",
+            "## Security Group (sg-a)\n".to_string(),
+            crate::i18n::Language::English,
+        );
+        assert_eq!(
+            markdown,
+            "## Security Group (sg-a)\n\n### Last Modified\nUpdate sg-1 by alice\n"
+        );
+
+        // 설정이 켜져 있을 때만 문서에 붙임
+        let resource = BlueprintResource {
+            resource_type: ResourceType::SecurityGroup,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "sg-1".to_string(),
+            resource_name: "sg-a".to_string(),
+        };
+        let plain = document_resource_markdown(&resource, crate::i18n::Language::English)
+            .expect("document");
+        assert!(!plain.contains("### Last Modified"));
+        aws_adapter::set_stamp_last_modified(true);
+        let stamped = document_resource_markdown(&resource, crate::i18n::Language::English)
+            .expect("document");
+        aws_adapter::set_stamp_last_modified(false);
+        assert!(stamped.ends_with("### Last Modified\nUpdate sg-1 by alice\n"));
+    }
+
+    #[test]
+    fn blueprint_files_split_resources_and_link_them_from_index() {
+        let blueprint = crate::blueprint::Blueprint {
+            id: "bp-wiki".to_string(),
+            name: "bp-wiki".to_string(),
+            resources: vec![
+                BlueprintResource {
+                    resource_type: ResourceType::SecurityGroup,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "sg-1".to_string(),
+                    resource_name: "sg-a".to_string(),
+                },
+                BlueprintResource {
+                    resource_type: ResourceType::Ec2,
+                    region: "ap-northeast-2".to_string(),
+                    resource_id: "i-1".to_string(),
+                    resource_name: String::new(),
+                },
+            ],
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        let parts = vec![
+            "## Security Group (sg-a)\n\n### Inbound Rules\n".to_string(),
+            "## EC2 (i-1)\n".to_string(),
+        ];
+        let i18n = crate::i18n::I18n::new(crate::i18n::Language::English);
+
+        let facts = vec![ResourceFacts::default(); parts.len()];
+
+        let files = blueprint_files(&i18n, &blueprint, &parts, &facts);
+        let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+        // 이름이 없으면 리소스 ID로 파일 이름을 만듦
+        assert_eq!(
+            names,
+            vec!["index.md", "01-security-group-sg-a.md", "02-ec2-i-1.md"]
+        );
+
+        let index = &files[0].1;
+        assert!(index.starts_with("# Blueprint: bp-wiki"));
+        assert!(index.contains("| Regions | ap-northeast-2 |"));
+        assert!(index.contains("- [1. Security Group - sg-a](01-security-group-sg-a.md)"));
+        assert!(index.contains("  - [Inbound Rules](01-security-group-sg-a.md#inbound-rules)"));
+        assert!(
+            files[2]
+                .1
+                .starts_with("[📑 Table of Contents](index.md)\n\n## EC2 (i-1)")
+        );
     }
 
     #[test]
@@ -2660,22 +11218,108 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Char('l')));
         assert_eq!(app.screen, Screen::Settings);
 
-        app.screen = Screen::BlueprintSelect;
-        app.running = true;
-        handle_key(&mut app, key(KeyCode::Char('q')));
-        assert!(!app.running);
+        app.screen = Screen::BlueprintSelect;
+        app.running = true;
+        handle_key(&mut app, key(KeyCode::Char('q')));
+        assert!(!app.running);
+    }
+
+    #[test]
+    fn blueprint_name_input_enter_with_blank_keeps_screen_and_clears_buffer() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintNameInput;
+        app.input_buffer = "   ".to_string();
+
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        assert_eq!(app.screen, Screen::BlueprintNameInput);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    #[test]
+    fn name_tag_edit_requires_write_mode() {
+        let mut app = App::new();
+        app.screen = Screen::Ec2Select;
+        app.instances = vec![aws_cli::AwsResource {
+            name: "web-1".to_string(),
+            id: "i-named".to_string(),
+            state: "running".to_string(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        }];
+
+        handle_key(&mut app, key(KeyCode::Char('N')));
+
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert!(app.name_tag_edit.is_none());
+        assert_eq!(app.message, app.i18n.write_mode_disabled());
+    }
+
+    #[test]
+    fn name_tag_edit_flow_confirms_before_tagging() {
+        let mut app = App::new();
+        app.allow_write = true;
+        app.screen = Screen::Ec2Select;
+        app.instances = vec![aws_cli::AwsResource {
+            name: "web-1".to_string(),
+            id: "i-named".to_string(),
+            state: "running".to_string(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        }];
+
+        // 현재 Name 태그가 입력란에 미리 채워짐
+        handle_key(&mut app, key(KeyCode::Char('N')));
+        assert_eq!(app.screen, Screen::NameTagInput);
+        assert_eq!(app.input_buffer, "web-1");
+
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::NameTagConfirm);
+
+        // 확인 화면에서 n이면 입력 화면으로 돌아감
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert_eq!(app.screen, Screen::NameTagInput);
+        handle_key(&mut app, key(KeyCode::Enter));
+        handle_key(&mut app, key(KeyCode::Char('y')));
+        assert_eq!(app.loading_task, LoadingTask::SetNameTag);
+
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Ec2Select);
+        assert!(app.name_tag_edit.is_none());
+        assert!(app.input_buffer.is_empty());
+        assert_eq!(app.message, app.i18n.name_tag_updated("i-named", "web-1a"));
     }
 
     #[test]
-    fn blueprint_name_input_enter_with_blank_keeps_screen_and_clears_buffer() {
+    fn name_tag_edit_reports_failure_and_cancels_with_esc() {
         let mut app = App::new();
-        app.screen = Screen::BlueprintNameInput;
-        app.input_buffer = "   ".to_string();
-
-        handle_key(&mut app, key(KeyCode::Enter));
+        app.allow_write = true;
+        app.screen = Screen::SecurityGroupSelect;
+        app.security_groups = vec![aws_cli::AwsResource {
+            name: String::new(),
+            id: "i-denied".to_string(),
+            state: String::new(),
+            az: String::new(),
+            cidr: String::new(),
+            owner_id: String::new(),
+        }];
 
-        assert_eq!(app.screen, Screen::BlueprintNameInput);
+        handle_key(&mut app, key(KeyCode::Char('N')));
         assert!(app.input_buffer.is_empty());
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::SecurityGroupSelect);
+        assert!(app.name_tag_edit.is_none());
+
+        handle_key(&mut app, key(KeyCode::Char('N')));
+        handle_key(&mut app, key(KeyCode::Char('x')));
+        handle_key(&mut app, key(KeyCode::Enter));
+        handle_key(&mut app, key(KeyCode::Char('y')));
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::SecurityGroupSelect);
+        assert_eq!(app.message, app.i18n.name_tag_update_failed("AccessDenied"));
     }
 
     #[test]
@@ -2869,12 +11513,18 @@ mod tests {
             name: "vpc-main".to_string(),
             id: "vpc-1".to_string(),
             cidr: "10.0.0.0/16".to_string(),
+            ipv6_cidrs: vec![],
             state: "available".to_string(),
+            owner_id: String::new(),
             subnets: vec![],
+            subnet_addresses: vec![],
             igws: vec![],
             nats: vec![],
             route_tables: vec![],
+            nacls: vec![],
             eips: vec![],
+            peerings: vec![],
+            endpoints: vec![],
             dns_support: true,
             dns_hostnames: true,
             tags: vec![],
@@ -2921,7 +11571,13 @@ mod tests {
         app.selected_tab = 1;
 
         handle_key(&mut app, key(KeyCode::Down));
-        assert_eq!(app.selected_setting, 0);
+        assert_eq!(app.selected_setting, 1);
+        for _ in 0..SETTINGS_COUNT {
+            handle_key(&mut app, key(KeyCode::Down));
+        }
+        assert_eq!(app.selected_setting, SETTINGS_COUNT - 1);
+        handle_key(&mut app, key(KeyCode::Up));
+        assert_eq!(app.selected_setting, SETTINGS_COUNT - 2);
 
         app.screen = Screen::Settings;
         app.selected_tab = 1;
@@ -2940,4 +11596,481 @@ mod tests {
         handle_key(&mut app, key(KeyCode::Char('q')));
         assert!(!app.running);
     }
+
+    #[test]
+    fn blueprint_select_terraform_import_opens_imported_blueprint() {
+        let mut app = App::new();
+        app.current_blueprint = None;
+        app.screen = Screen::BlueprintSelect;
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let path = std::env::temp_dir().join(format!(
+            "emd-handler-tfstate-{}-{}.json",
+            std::process::id(),
+            nanos
+        ));
+        std::fs::write(
+            &path,
+            r#"{"resources":[{"mode":"managed","type":"aws_instance","name":"web","instances":[{"attributes":{"id":"i-tf","tags":{"Name":"tf-web"}}}]}]}"#,
+        )
+        .expect("write tfstate");
+
+        handle_key(&mut app, key(KeyCode::Char('t')));
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+
+        for c in path.to_string_lossy().chars() {
+            handle_key(&mut app, key(KeyCode::Char(c)));
+        }
+        handle_key(&mut app, key(KeyCode::Enter));
+
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        assert_eq!(app.message, app.i18n.blueprint_imported(1));
+        let resource_ids: Vec<String> = app
+            .current_blueprint
+            .as_ref()
+            .map(|bp| bp.resources.iter().map(|r| r.resource_id.clone()).collect())
+            .unwrap_or_default();
+        assert_eq!(resource_ids, vec!["i-tf".to_string()]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn blueprint_import_input_reports_failures_and_escape_returns() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintImportInput;
+        app.input_buffer = "/nonexistent/emd/terraform.tfstate".to_string();
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+        assert!(app.input_buffer.is_empty());
+        assert!(!app.message.is_empty());
+
+        handle_key(&mut app, key(KeyCode::Char('x')));
+        handle_key(&mut app, key(KeyCode::Backspace));
+        assert!(app.input_buffer.is_empty());
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+    }
+
+    #[test]
+    fn compare_key_marks_base_then_opens_comparison() {
+        let mut app = App::new();
+        app.screen = Screen::SecurityGroupSelect;
+        app.security_groups = vec![
+            sample_resource("sg-prod", "sg-prod"),
+            sample_resource("sg-staging", "sg-staging"),
+        ];
+
+        // 같은 리소스를 두 번 고르면 선택 해제
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert!(app.compare_base.is_some());
+        assert!(!app.loading);
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert!(app.compare_base.is_none());
+        assert_eq!(app.message, app.i18n.compare_cleared());
+
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        app.selected_index = 1;
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert_eq!(app.loading_task, LoadingTask::LoadCompare);
+
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::Compare);
+        assert!(!app.loading);
+        assert!(app.compare_base.is_none());
+        assert_eq!(app.preview_filename, "compare-sg-prod-sg-staging.md");
+        let comparison = app.comparison.as_ref().expect("comparison");
+        assert!(comparison.difference_count() > 0);
+
+        handle_key(&mut app, key(KeyCode::Char('t')));
+        assert!(app.compare_show_table);
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::SecurityGroupSelect);
+        assert!(app.comparison.is_none());
+    }
+
+    #[test]
+    fn motion_keys_support_counts_and_jumps_in_lists() {
+        let mut app = App::new();
+        app.screen = Screen::Ec2Select;
+        app.instances = (0..30)
+            .map(|i| sample_resource(&format!("i-{}", i), &format!("web-{}", i)))
+            .collect();
+
+        handle_key(&mut app, key(KeyCode::Char('5')));
+        handle_key(&mut app, key(KeyCode::Char('j')));
+        assert_eq!(app.selected_index, 5);
+        assert_eq!(app.motion_count, None);
+
+        // 접두사가 없으면 기존 한 칸 이동
+        handle_key(&mut app, key(KeyCode::Char('k')));
+        assert_eq!(app.selected_index, 4);
+
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('G'), KeyModifiers::SHIFT),
+        );
+        assert_eq!(app.selected_index, 29);
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        assert!(app.motion_pending_g);
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        assert_eq!(app.selected_index, 0);
+
+        handle_key(&mut app, key(KeyCode::Char('1')));
+        handle_key(&mut app, key(KeyCode::Char('0')));
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('G'), KeyModifiers::SHIFT),
+        );
+        assert_eq!(app.selected_index, 9);
+        handle_key(&mut app, key(KeyCode::Char('9')));
+        handle_key(&mut app, key(KeyCode::Char('9')));
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_index, 29);
+
+        let ctrl = |c: char| key_with_mod(KeyCode::Char(c), KeyModifiers::CONTROL);
+        handle_key(&mut app, ctrl('u'));
+        assert_eq!(app.selected_index, 19);
+        handle_key(&mut app, ctrl('d'));
+        handle_key(&mut app, ctrl('d'));
+        assert_eq!(app.selected_index, 29);
+
+        // 다른 키는 접두사를 지우고 원래 처리로 넘김
+        handle_key(&mut app, key(KeyCode::Char('3')));
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert_eq!(app.motion_count, None);
+    }
+
+    #[test]
+    fn motion_keys_scroll_preview() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = (0..40)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        );
+        assert_eq!(app.preview_scroll, 10);
+        handle_key(&mut app, key(KeyCode::Char('3')));
+        handle_key(&mut app, key(KeyCode::Char('k')));
+        assert_eq!(app.preview_scroll, 7);
+        handle_key(
+            &mut app,
+            key_with_mod(KeyCode::Char('G'), KeyModifiers::SHIFT),
+        );
+        assert_eq!(app.preview_scroll, 39);
+        handle_key(&mut app, key(KeyCode::Char('2')));
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        handle_key(&mut app, key(KeyCode::Char('g')));
+        assert_eq!(app.preview_scroll, 1);
+    }
+
+    #[test]
+    fn motion_keys_ignored_outside_lists_and_preview() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        handle_key(&mut app, key(KeyCode::Char('5')));
+        assert_eq!(app.motion_count, None);
+    }
+
+    #[test]
+    fn macro_keys_record_then_queue_replay() {
+        let mut app = App::new();
+        app.screen = Screen::SecurityGroupSelect;
+        app.security_groups = vec![
+            sample_resource("sg-prod", "sg-prod"),
+            sample_resource("sg-staging", "sg-staging"),
+        ];
+        let ctrl = |c: char| key_with_mod(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        // 레지스터가 아닌 키는 대기 중인 매크로 명령을 취소
+        handle_key(&mut app, ctrl('r'));
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.message, app.i18n.macro_cancelled());
+        assert_eq!(app.macros.recording_register(), None);
+
+        handle_key(&mut app, ctrl('r'));
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        assert_eq!(app.macros.recording_register(), Some('a'));
+
+        // 기록 중에도 키는 그대로 처리
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_index, 1);
+        handle_key(&mut app, ctrl('e'));
+        assert_eq!(app.message, app.i18n.macro_replay_while_recording());
+        assert_eq!(app.macros.stop_recording(), Some(('a', 1)));
+
+        handle_key(&mut app, ctrl('e'));
+        handle_key(&mut app, key(KeyCode::Char('b')));
+        assert_eq!(app.message, app.i18n.macro_empty('b'));
+        assert!(app.macro_queue.is_empty());
+
+        handle_key(&mut app, ctrl('e'));
+        handle_key(&mut app, key(KeyCode::Char('a')));
+        assert_eq!(app.macro_queue, vec![key(KeyCode::Down)]);
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn tutorial_key_toggles_and_real_navigation_advances_steps() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+
+        // 로그인한 뒤 시작하면 로그인 단계는 건너뜀
+        handle_key(&mut app, key(KeyCode::F(1)));
+        assert_eq!(app.message, app.i18n.tutorial_started());
+        assert_eq!(
+            app.tutorial.as_ref().map(|tutorial| tutorial.step),
+            Some(TutorialStep::Region)
+        );
+
+        handle_key(&mut app, key(KeyCode::Char('s')));
+        assert_eq!(app.screen, Screen::RegionSelect);
+        handle_key(&mut app, key(KeyCode::Enter));
+        app.advance_tutorial();
+        assert_eq!(app.screen, Screen::ServiceSelect);
+        assert_eq!(
+            app.tutorial.as_ref().map(|tutorial| tutorial.step),
+            Some(TutorialStep::Service)
+        );
+
+        handle_key(&mut app, key(KeyCode::F(1)));
+        assert!(app.tutorial.is_none());
+        assert_eq!(app.message, app.i18n.tutorial_exited());
+
+        app.tutorial = Some(crate::tutorial::Tutorial {
+            step: TutorialStep::Blueprint,
+        });
+        app.screen = Screen::BlueprintPreview;
+        app.advance_tutorial();
+        assert!(app.tutorial.is_none());
+        assert_eq!(app.message, app.i18n.tutorial_finished());
+    }
+
+    #[test]
+    fn compare_key_ignored_outside_list_screens() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert!(app.compare_base.is_none());
+    }
+
+    #[test]
+    fn promotion_key_marks_source_then_builds_checklist() {
+        let sg = |id: &str, name: &str| BlueprintResource {
+            resource_type: ResourceType::SecurityGroup,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+        };
+        let mut staging = sample_blueprint("staging");
+        staging.resources.push(sg("sg-staging", "staging-web"));
+        let mut prod = sample_blueprint("prod");
+        prod.resources.push(sg("sg-prod", "prod-web"));
+        prod.resources.push(sg("sg-prod-db", "prod-db"));
+
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+        app.blueprint_store.blueprints = vec![staging, prod];
+        app.selected_blueprint_index = 1;
+
+        handle_key(&mut app, key(KeyCode::Char('p')));
+        assert_eq!(app.promotion_source, Some(0));
+        assert!(!app.loading);
+
+        app.selected_blueprint_index = 2;
+        handle_key(&mut app, key(KeyCode::Char('p')));
+        assert_eq!(app.loading_task, LoadingTask::LoadPromotionChecklist(0, 1));
+        assert!(app.promotion_source.is_none());
+
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::BlueprintPreview);
+        assert!(!app.loading);
+        assert_eq!(app.preview_filename, "promotion-staging-prod.md");
+        assert!(
+            app.preview_content
+                .contains(&app.i18n.promotion_missing_in("staging"))
+        );
+        assert!(app.preview_content.contains("prod-db"));
+        assert!(
+            !app.preview_content
+                .contains(app.i18n.promotion_unreadable())
+        );
+
+        // 같은 블루프린트에서 다시 p를 누르면 선택 해제
+        app.screen = Screen::BlueprintSelect;
+        handle_key(&mut app, key(KeyCode::Char('p')));
+        handle_key(&mut app, key(KeyCode::Char('p')));
+        assert!(app.promotion_source.is_none());
+        assert_eq!(app.message, app.i18n.promotion_cleared());
+    }
+
+    #[test]
+    fn document_search_screen_edits_query_and_moves_selection() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+        app.input_buffer = "stale".to_string();
+
+        handle_key(&mut app, key(KeyCode::Char('/')));
+        assert_eq!(app.screen, Screen::DocumentSearch);
+        assert!(app.input_buffer.is_empty());
+
+        for c in "sg-1".chars() {
+            handle_key(&mut app, key(KeyCode::Char(c)));
+        }
+        handle_key(&mut app, key(KeyCode::Backspace));
+        assert_eq!(app.input_buffer, "sg-");
+
+        app.search_results = ["a.md", "b.md"]
+            .iter()
+            .map(|path| crate::doc_index::SearchHit {
+                path: path.to_string(),
+                indexed_at: "2026-01-01T00:00:00Z".to_string(),
+                snippet: String::new(),
+            })
+            .collect();
+        handle_key(&mut app, key(KeyCode::Down));
+        handle_key(&mut app, key(KeyCode::Down));
+        assert_eq!(app.selected_search_result, 1);
+        handle_key(&mut app, key(KeyCode::Up));
+        assert_eq!(app.selected_search_result, 0);
+
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert!(app.search_results.is_empty());
+    }
+
+    #[test]
+    fn query_playground_runs_operation_and_applies_query_live() {
+        let mut app = App::new();
+        app.screen = Screen::ServiceSelect;
+
+        handle_key(&mut app, key(KeyCode::Char('p')));
+        assert_eq!(app.screen, Screen::QueryPlayground);
+        handle_key(&mut app, key(KeyCode::Down));
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.loading_task, LoadingTask::RunQueryOperation(1));
+        process_loading(&mut app);
+        assert!(!app.loading);
+        assert!(app.query_output.is_some());
+
+        for c in "Vpcs[0].CidrBlock".chars() {
+            handle_key(&mut app, key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.query_result.as_deref(), Ok("\"10.0.0.0/16\""));
+        handle_key(&mut app, key(KeyCode::Backspace));
+        assert_eq!(app.query_result.as_deref(), Ok("null"));
+        handle_key(&mut app, key(KeyCode::Char('[')));
+        assert!(app.query_result.is_err());
+
+        // Esc는 작업 선택으로, 한 번 더 누르면 서비스 선택으로 돌아감
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(app.query_output.is_none());
+        assert!(app.input_buffer.is_empty());
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert_eq!(app.screen, Screen::ServiceSelect);
+    }
+
+    #[test]
+    fn query_playground_reports_failed_operation() {
+        let mut app = App::new();
+        app.screen = Screen::QueryPlayground;
+        app.selected_query_operation = 0;
+
+        handle_key(&mut app, key(KeyCode::Enter));
+        process_loading(&mut app);
+        assert!(app.query_output.is_none());
+        assert_eq!(app.message, app.i18n.query_failed());
+    }
+
+    #[test]
+    fn blueprint_select_cloudformation_import_loads_stack_resources() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+
+        handle_key(&mut app, key(KeyCode::Char('c')));
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+        assert_eq!(
+            app.blueprint_import_kind,
+            BlueprintImportKind::CloudFormation
+        );
+
+        app.input_buffer = "missing-stack".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::ImportCloudFormationStack("missing-stack".to_string())
+        );
+        process_loading(&mut app);
+        assert!(!app.loading);
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+        assert!(app.message.contains("does not exist"));
+
+        app.input_buffer = "app-stack".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        process_loading(&mut app);
+
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        assert_eq!(app.message, app.i18n.blueprint_imported(1));
+        let blueprint = app.current_blueprint.as_ref().expect("imported blueprint");
+        assert_eq!(blueprint.name, "app-stack");
+        assert_eq!(blueprint.resources[0].resource_id, "i-cfn");
+        assert_eq!(blueprint.resources[0].resource_name, "WebServer");
+    }
+
+    #[test]
+    fn blueprint_select_tag_query_import_builds_cross_service_blueprint() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+
+        handle_key(&mut app, key(KeyCode::Char('f')));
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+        assert_eq!(app.blueprint_import_kind, BlueprintImportKind::TagQuery);
+
+        app.input_buffer = "Project=unknown".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        process_loading(&mut app);
+        assert_eq!(app.screen, Screen::BlueprintImportInput);
+        assert_eq!(app.message, app.i18n.tag_query_no_resources());
+
+        app.input_buffer = "Project=checkout".to_string();
+        handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(
+            app.loading_task,
+            LoadingTask::ImportTaggedResources("Project=checkout".to_string())
+        );
+        process_loading(&mut app);
+
+        assert_eq!(app.screen, Screen::BlueprintDetail);
+        let blueprint = app.current_blueprint.as_ref().expect("imported blueprint");
+        assert_eq!(blueprint.name, "Project=checkout");
+        let types: Vec<ResourceType> = blueprint
+            .resources
+            .iter()
+            .map(|r| r.resource_type.clone())
+            .collect();
+        assert_eq!(types, vec![ResourceType::Ec2, ResourceType::Ecr]);
+        assert_eq!(blueprint.resources[0].resource_name, "checkout-web");
+    }
+
+    #[test]
+    fn blueprint_select_sync_without_remote_store_reports_not_configured() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+        app.settings.blueprint_remote = None;
+
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert_eq!(app.screen, Screen::BlueprintSelect);
+        assert_eq!(app.message, app.i18n.remote_store_not_configured());
+    }
 }
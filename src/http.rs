@@ -0,0 +1,111 @@
+// emd serve와 데몬 메트릭 서버가 함께 쓰는 최소한의 HTTP/1.1 요청 읽기/응답 쓰기
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+// 요청 줄/헤더 한 줄의 최대 길이와 헤더 개수 (끝없이 이어지는 요청으로 메모리를 쓰지 않도록)
+const MAX_LINE_BYTES: u64 = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+
+/// Status for requests rejected by [`read_request_line`] for being too large.
+pub const TOO_LARGE_STATUS: &str = "431 Request Header Fields Too Large";
+
+/// Read the request line from `stream` and discard its headers.
+///
+/// Oversized requests fail with [`ErrorKind::InvalidData`]; see [`is_too_large`].
+pub fn read_request(stream: &TcpStream) -> std::io::Result<String> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    read_request_line(stream)
+}
+
+fn read_request_line(stream: impl Read) -> std::io::Result<String> {
+    let mut reader = BufReader::new(stream);
+    let request_line = read_limited_line(&mut reader)?;
+    // 나머지 헤더는 빈 줄까지 읽고 버림
+    for _ in 0..MAX_HEADERS {
+        if read_limited_line(&mut reader)?.trim().is_empty() {
+            return Ok(request_line);
+        }
+    }
+    Err(std::io::Error::new(
+        ErrorKind::InvalidData,
+        "too many request headers",
+    ))
+}
+
+fn read_limited_line(reader: &mut impl BufRead) -> std::io::Result<String> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_LINE_BYTES + 1)
+        .read_until(b'\n', &mut line)?;
+    if line.len() as u64 > MAX_LINE_BYTES {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "request line or header too long",
+        ));
+    }
+    Ok(String::from_utf8_lossy(&line).into_owned())
+}
+
+pub fn is_too_large(error: &std::io::Error) -> bool {
+    error.kind() == ErrorKind::InvalidData
+}
+
+pub fn write_response(
+    mut stream: &TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_HEADERS, MAX_LINE_BYTES, is_too_large, read_request_line};
+
+    #[test]
+    fn read_request_line_skips_headers() {
+        let request = b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\nbody";
+        assert_eq!(
+            read_request_line(&request[..]).expect("request"),
+            "GET /metrics HTTP/1.1\r\n"
+        );
+        // 헤더 없이 연결이 끊겨도 요청 줄은 사용
+        assert_eq!(
+            read_request_line(&b"GET / HTTP/1.1"[..]).expect("request"),
+            "GET / HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn read_request_line_rejects_oversized_requests() {
+        let long_target = format!(
+            "GET /{} HTTP/1.1\r\n\r\n",
+            "a".repeat(MAX_LINE_BYTES as usize)
+        );
+        let error = read_request_line(long_target.as_bytes()).unwrap_err();
+        assert!(is_too_large(&error));
+
+        let long_header = format!(
+            "GET / HTTP/1.1\r\nX: {}\r\n\r\n",
+            "a".repeat(MAX_LINE_BYTES as usize)
+        );
+        assert!(is_too_large(
+            &read_request_line(long_header.as_bytes()).unwrap_err()
+        ));
+
+        let many_headers = format!("GET / HTTP/1.1\r\n{}\r\n", "X: 1\r\n".repeat(MAX_HEADERS));
+        assert!(is_too_large(
+            &read_request_line(many_headers.as_bytes()).unwrap_err()
+        ));
+    }
+}
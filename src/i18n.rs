@@ -256,6 +256,25 @@ impl I18n {
         }
     }
 
+    pub fn refreshed_ago(&self, elapsed_secs: u64) -> String {
+        let (minutes, hours) = (elapsed_secs / 60, elapsed_secs / 3600);
+        match self.lang {
+            Language::Korean if elapsed_secs < 60 => "방금 조회".to_string(),
+            Language::Korean if hours == 0 => format!("{}분 전 조회", minutes),
+            Language::Korean => format!("{}시간 전 조회", hours),
+            Language::English if elapsed_secs < 60 => "refreshed just now".to_string(),
+            Language::English if hours == 0 => format!("refreshed {}m ago", minutes),
+            Language::English => format!("refreshed {}h ago", hours),
+        }
+    }
+
+    pub fn cached(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "캐시",
+            Language::English => "cached",
+        }
+    }
+
     pub fn save_complete(&self) -> &'static str {
         match self.lang {
             Language::Korean => "저장 완료",
@@ -565,919 +584,6703 @@ impl I18n {
         }
     }
 
-    pub fn auto_scaling_group(&self) -> &'static str {
+    pub fn loading_rds_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "Auto Scaling Group",
-            Language::English => "Auto Scaling Group",
+            Language::Korean => "RDS 목록 조회 중",
+            Language::English => "Loading RDS databases",
         }
     }
 
-    pub fn asg_launch_template(&self) -> &'static str {
+    pub fn loading_rds_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "시작 템플릿",
-            Language::English => "Launch Template",
+            Language::Korean => "RDS 상세 정보 조회 중",
+            Language::English => "Loading RDS details",
         }
     }
 
-    pub fn asg_launch_configuration(&self) -> &'static str {
+    pub fn no_rds_databases(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "시작 구성",
-            Language::English => "Launch Configuration",
+            Language::Korean => "RDS 데이터베이스가 없습니다.",
+            Language::English => "No RDS databases found.",
         }
     }
 
-    pub fn asg_min_size(&self) -> &'static str {
+    pub fn auto_scaling_group(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "최소 크기",
-            Language::English => "Min Size",
+            Language::Korean => "Auto Scaling Group",
+            Language::English => "Auto Scaling Group",
         }
     }
 
-    pub fn asg_max_size(&self) -> &'static str {
+    pub fn rds_database(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "최대 크기",
-            Language::English => "Max Size",
+            Language::Korean => "RDS 데이터베이스",
+            Language::English => "RDS Database",
         }
     }
 
-    pub fn asg_desired_capacity(&self) -> &'static str {
+    pub fn loading_lambda_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "원하는 용량",
-            Language::English => "Desired Capacity",
+            Language::Korean => "Lambda 함수 목록 조회 중",
+            Language::English => "Loading Lambda functions",
         }
     }
 
-    pub fn asg_default_cooldown(&self) -> &'static str {
+    pub fn loading_lambda_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "기본 쿨다운",
-            Language::English => "Default Cooldown",
+            Language::Korean => "Lambda 함수 상세 정보 조회 중",
+            Language::English => "Loading Lambda function details",
         }
     }
 
-    pub fn asg_health_check_type(&self) -> &'static str {
+    pub fn no_lambda_functions(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "헬스 체크 유형",
-            Language::English => "Health Check Type",
+            Language::Korean => "Lambda 함수가 없습니다.",
+            Language::English => "No Lambda functions found.",
         }
     }
 
-    pub fn asg_health_check_grace_period(&self) -> &'static str {
+    pub fn lambda_function(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "헬스 체크 유예 기간",
-            Language::English => "Health Check Grace Period",
+            Language::Korean => "Lambda 함수",
+            Language::English => "Lambda Function",
         }
     }
 
-    pub fn asg_created_at(&self) -> &'static str {
+    pub fn loading_dynamodb_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "생성일",
-            Language::English => "Created At",
+            Language::Korean => "DynamoDB 테이블 목록 조회 중",
+            Language::English => "Loading DynamoDB tables",
         }
     }
 
-    pub fn asg_availability_zones(&self) -> &'static str {
+    pub fn loading_dynamodb_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "가용 영역",
-            Language::English => "Availability Zones",
+            Language::Korean => "DynamoDB 테이블 상세 정보 조회 중",
+            Language::English => "Loading DynamoDB table details",
         }
     }
 
-    pub fn asg_instances(&self) -> &'static str {
+    pub fn no_dynamodb_tables(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인스턴스",
-            Language::English => "Instances",
+            Language::Korean => "DynamoDB 테이블이 없습니다.",
+            Language::English => "No DynamoDB tables found.",
         }
     }
 
-    pub fn asg_instance_id(&self) -> &'static str {
+    pub fn dynamodb_table(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인스턴스 ID",
-            Language::English => "Instance ID",
+            Language::Korean => "DynamoDB 테이블",
+            Language::English => "DynamoDB Table",
         }
     }
 
-    pub fn asg_target_groups(&self) -> &'static str {
+    pub fn loading_cloudfront_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "대상 그룹",
-            Language::English => "Target Groups",
+            Language::Korean => "CloudFront 배포 목록 조회 중",
+            Language::English => "Loading CloudFront distributions",
         }
     }
 
-    pub fn asg_scaling_policies(&self) -> &'static str {
+    pub fn loading_cloudfront_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "조정 정책",
-            Language::English => "Scaling Policies",
+            Language::Korean => "CloudFront 배포 상세 정보 조회 중",
+            Language::English => "Loading CloudFront distribution details",
         }
     }
 
-    pub fn asg_policy_type(&self) -> &'static str {
+    pub fn no_cloudfront_distributions(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "유형",
-            Language::English => "Type",
+            Language::Korean => "CloudFront 배포가 없습니다.",
+            Language::English => "No CloudFront distributions found.",
         }
     }
 
-    pub fn asg_adjustment_type(&self) -> &'static str {
+    pub fn cloudfront_distribution(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "조정 유형",
-            Language::English => "Adjustment Type",
+            Language::Korean => "CloudFront 배포",
+            Language::English => "CloudFront Distribution",
         }
     }
 
-    pub fn asg_adjustment_value(&self) -> &'static str {
+    pub fn loading_route53_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "조정 값",
-            Language::English => "Adjustment Value",
+            Language::Korean => "Route 53 호스팅 영역 목록 조회 중",
+            Language::English => "Loading Route 53 hosted zones",
         }
     }
 
-    pub fn asg_cooldown(&self) -> &'static str {
+    pub fn loading_route53_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "쿨다운",
-            Language::English => "Cooldown",
+            Language::Korean => "Route 53 호스팅 영역 상세 정보 조회 중",
+            Language::English => "Loading Route 53 hosted zone details",
         }
     }
 
-    pub fn asg_tags(&self) -> &'static str {
+    pub fn no_route53_zones(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "태그",
-            Language::English => "Tags",
+            Language::Korean => "Route 53 호스팅 영역이 없습니다.",
+            Language::English => "No Route 53 hosted zones found.",
         }
     }
 
-    pub fn asg_key(&self) -> &'static str {
+    pub fn route53_hosted_zone(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "키",
-            Language::English => "Key",
+            Language::Korean => "Route 53 호스팅 영역",
+            Language::English => "Route 53 Hosted Zone",
         }
     }
 
-    pub fn asg_seconds(&self, value: i32) -> String {
+    pub fn loading_eks_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => format!("{value}초"),
-            Language::English => format!("{value}s"),
+            Language::Korean => "EKS 클러스터 목록 조회 중",
+            Language::English => "Loading EKS clusters",
         }
     }
 
-    pub fn asg_instances_with_count(&self, count: usize) -> String {
+    pub fn loading_eks_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => format!("{} ({} 개)", self.asg_instances(), count),
-            Language::English => format!("{} ({count})", self.asg_instances()),
+            Language::Korean => "EKS 클러스터 상세 정보 조회 중",
+            Language::English => "Loading EKS cluster details",
         }
     }
 
-    pub fn loading_blueprint_resources(&self) -> &'static str {
+    pub fn no_eks_clusters(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "블루프린트 리소스 조회 중",
-            Language::English => "Loading Blueprint resources",
+            Language::Korean => "EKS 클러스터가 없습니다.",
+            Language::English => "No EKS clusters found.",
         }
     }
 
-    // VPC Loading steps
-    pub fn vpc_basic_info(&self) -> &'static str {
+    pub fn eks_cluster(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "VPC 기본 정보",
-            Language::English => "VPC Basic Info",
+            Language::Korean => "EKS 클러스터",
+            Language::English => "EKS Cluster",
         }
     }
 
-    pub fn subnets(&self) -> &'static str {
+    pub fn loading_ecs_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "서브넷",
-            Language::English => "Subnets",
+            Language::Korean => "ECS 클러스터 목록 조회 중",
+            Language::English => "Loading ECS clusters",
         }
     }
 
-    pub fn internet_gateway(&self) -> &'static str {
+    pub fn loading_ecs_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인터넷 게이트웨이",
-            Language::English => "Internet Gateway",
+            Language::Korean => "ECS 클러스터 상세 정보 조회 중",
+            Language::English => "Loading ECS cluster details",
         }
     }
 
-    pub fn nat_gateway(&self) -> &'static str {
+    pub fn no_ecs_clusters(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "NAT 게이트웨이",
-            Language::English => "NAT Gateway",
+            Language::Korean => "ECS 클러스터가 없습니다.",
+            Language::English => "No ECS clusters found.",
         }
     }
 
-    pub fn route_tables(&self) -> &'static str {
+    pub fn ecs_cluster(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "라우팅 테이블",
-            Language::English => "Route Tables",
+            Language::Korean => "ECS 클러스터",
+            Language::English => "ECS Cluster",
         }
     }
 
-    pub fn elastic_ip(&self) -> &'static str {
+    pub fn loading_sqs_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "Elastic IP",
-            Language::English => "Elastic IP",
+            Language::Korean => "SQS 대기열 목록 조회 중",
+            Language::English => "Loading SQS queues",
         }
     }
 
-    pub fn dns_settings(&self) -> &'static str {
+    pub fn loading_sqs_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "DNS 설정",
-            Language::English => "DNS Settings",
+            Language::Korean => "SQS 대기열 상세 정보 조회 중",
+            Language::English => "Loading SQS queue details",
         }
     }
 
-    pub fn completing(&self) -> &'static str {
+    pub fn no_sqs_queues(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "완료 중",
-            Language::English => "Completing",
+            Language::Korean => "SQS 대기열이 없습니다.",
+            Language::English => "No SQS queues found.",
         }
     }
 
-    pub fn current_loading(&self, task: &str) -> String {
+    pub fn sqs_queue(&self) -> &'static str {
         match self.lang {
-            Language::Korean => format!("현재: {} 조회 중...", task),
-            Language::English => format!("Current: Loading {}...", task),
+            Language::Korean => "SQS 대기열",
+            Language::English => "SQS Queue",
         }
     }
 
-    // Blueprint
-    pub fn new_blueprint(&self) -> &'static str {
+    pub fn loading_sns_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "+ 새 블루프린터",
-            Language::English => "+ New Blueprint",
+            Language::Korean => "SNS 주제 목록 조회 중",
+            Language::English => "Loading SNS topics",
         }
     }
 
-    pub fn blueprint_load_failed(&self) -> &'static str {
+    pub fn loading_sns_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "블루프린터 로드 실패",
-            Language::English => "Blueprint load failed",
+            Language::Korean => "SNS 주제 상세 정보 조회 중",
+            Language::English => "Loading SNS topic details",
         }
     }
 
-    pub fn enter_blueprint_name(&self) -> &'static str {
+    pub fn no_sns_topics(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "블루프린터 이름을 입력하세요:",
-            Language::English => "Enter blueprint name:",
+            Language::Korean => "SNS 주제가 없습니다.",
+            Language::English => "No SNS topics found.",
         }
     }
 
-    pub fn press_a_to_add(&self) -> &'static str {
+    pub fn sns_topic(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "'a' 키를 눌러 리소스를 추가하세요.",
-            Language::English => "Press 'a' to add resources.",
+            Language::Korean => "SNS 주제",
+            Language::English => "SNS Topic",
         }
     }
 
-    pub fn resources(&self) -> &'static str {
+    pub fn loading_elasticache_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "리소스",
-            Language::English => "resources",
+            Language::Korean => "ElastiCache 클러스터 목록 조회 중",
+            Language::English => "Loading ElastiCache clusters",
         }
     }
 
-    // Settings
-    pub fn language(&self) -> &'static str {
+    pub fn loading_elasticache_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "언어",
-            Language::English => "Language",
+            Language::Korean => "ElastiCache 클러스터 상세 정보 조회 중",
+            Language::English => "Loading ElastiCache cluster details",
         }
     }
 
-    #[allow(dead_code)]
-    pub fn language_setting(&self) -> &'static str {
+    pub fn no_elasticache_clusters(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "언어 설정",
-            Language::English => "Language Setting",
+            Language::Korean => "ElastiCache 클러스터가 없습니다.",
+            Language::English => "No ElastiCache clusters found.",
         }
     }
 
-    pub fn settings_saved(&self) -> &'static str {
+    pub fn elasticache_cluster(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "설정 저장 완료",
-            Language::English => "Settings saved",
+            Language::Korean => "ElastiCache 클러스터",
+            Language::English => "ElastiCache Cluster",
         }
     }
 
-    pub fn blueprint_save_failed(&self) -> &'static str {
+    pub fn loading_cloudwatch_alarm_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "블루프린터 저장 실패",
-            Language::English => "Blueprint save failed",
+            Language::Korean => "CloudWatch 경보 목록 조회 중",
+            Language::English => "Loading CloudWatch alarms",
         }
     }
 
-    pub fn change(&self) -> &'static str {
+    pub fn loading_cloudwatch_alarm_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "변경",
-            Language::English => "Change",
+            Language::Korean => "CloudWatch 경보 상세 정보 조회 중",
+            Language::English => "Loading CloudWatch alarm details",
         }
     }
 
-    // Table headers for markdown
-    pub fn item(&self) -> &'static str {
+    pub fn no_cloudwatch_alarms(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "항목",
-            Language::English => "Item",
+            Language::Korean => "CloudWatch 경보가 없습니다.",
+            Language::English => "No CloudWatch alarms found.",
         }
     }
 
-    pub fn value(&self) -> &'static str {
+    pub fn cloudwatch_alarm(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "값",
-            Language::English => "Value",
+            Language::Korean => "CloudWatch 경보",
+            Language::English => "CloudWatch Alarm",
         }
     }
 
-    pub fn md_name(&self) -> &'static str {
+    pub fn loading_cloudtrail_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "이름",
-            Language::English => "Name",
+            Language::Korean => "CloudTrail 추적 목록 조회 중",
+            Language::English => "Loading CloudTrail trails",
         }
     }
 
-    pub fn md_state(&self) -> &'static str {
+    pub fn loading_cloudtrail_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "상태",
-            Language::English => "State",
+            Language::Korean => "CloudTrail 추적 상세 정보와 최근 이벤트 조회 중",
+            Language::English => "Loading CloudTrail trail details and recent events",
         }
     }
 
-    pub fn tag(&self) -> &'static str {
+    pub fn no_cloudtrail_trails(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "태그",
-            Language::English => "Tag",
+            Language::Korean => "CloudTrail 추적이 없습니다.",
+            Language::English => "No CloudTrail trails found.",
         }
     }
 
-    // Toc
-    pub fn toc(&self) -> &'static str {
+    pub fn cloudtrail_trail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "📑 목차",
-            Language::English => "📑 Table of Contents",
+            Language::Korean => "CloudTrail 추적",
+            Language::English => "CloudTrail Trail",
         }
     }
 
-    // Query failed
-    pub fn query_failed(&self) -> &'static str {
+    pub fn loading_kms_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "조회 실패",
-            Language::English => "Query failed",
+            Language::Korean => "KMS 키 목록 조회 중",
+            Language::English => "Loading KMS keys",
         }
     }
 
-    pub fn network_detail_unavailable(&self, vpc_id: &str) -> String {
+    pub fn loading_kms_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => {
-                format!(
-                    "{} 네트워크 상세 정보를 불러올 수 없습니다. 로그인 상태를 확인하세요.",
-                    vpc_id
-                )
-            }
-            Language::English => {
-                format!(
-                    "Network detail unavailable for {}. Please check AWS login status.",
-                    vpc_id
-                )
-            }
+            Language::Korean => "KMS 키 상세 정보와 키 정책 조회 중",
+            Language::English => "Loading KMS key details and key policy",
         }
     }
 
-    // VPC/Network markdown labels
-    pub fn md_dns_support(&self) -> &'static str {
+    pub fn no_kms_keys(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "DNS 지원",
-            Language::English => "DNS Support",
+            Language::Korean => "고객 관리형 KMS 키가 없습니다.",
+            Language::English => "No customer managed KMS keys found.",
         }
     }
 
-    pub fn md_dns_hostnames(&self) -> &'static str {
+    pub fn kms_key(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "DNS 호스트 이름",
-            Language::English => "DNS Hostnames",
+            Language::Korean => "KMS 키",
+            Language::English => "KMS Key",
         }
     }
 
-    pub fn md_subnets(&self) -> &'static str {
+    pub fn loading_secret_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "서브넷",
-            Language::English => "Subnets",
+            Language::Korean => "Secrets Manager 비밀 목록 조회 중",
+            Language::English => "Loading Secrets Manager secrets",
         }
     }
 
-    pub fn md_internet_gateway(&self) -> &'static str {
+    pub fn loading_secret_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인터넷 게이트웨이",
-            Language::English => "Internet Gateway",
+            Language::Korean => "비밀 메타데이터 조회 중",
+            Language::English => "Loading secret metadata",
         }
     }
 
-    pub fn md_attached_vpc(&self) -> &'static str {
+    pub fn no_secrets(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "연결된 VPC",
-            Language::English => "Attached VPC",
+            Language::Korean => "Secrets Manager 비밀이 없습니다.",
+            Language::English => "No Secrets Manager secrets found.",
         }
     }
 
-    pub fn md_nat_gateway(&self) -> &'static str {
+    pub fn secret(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "NAT 게이트웨이",
-            Language::English => "NAT Gateway",
+            Language::Korean => "Secrets Manager 비밀",
+            Language::English => "Secrets Manager Secret",
         }
     }
 
-    pub fn md_availability_mode(&self) -> &'static str {
+    pub fn loading_ssm_parameter_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "가용성 모드",
-            Language::English => "Availability Mode",
+            Language::Korean => "Parameter Store 경로 목록 조회 중",
+            Language::English => "Loading Parameter Store paths",
         }
     }
 
-    pub fn md_zonal(&self) -> &'static str {
+    pub fn loading_ssm_parameter_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "영역",
-            Language::English => "Zonal",
+            Language::Korean => "파라미터 메타데이터 조회 중",
+            Language::English => "Loading parameter metadata",
         }
     }
 
-    pub fn md_regional(&self) -> &'static str {
+    pub fn no_ssm_parameters(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "리전별",
-            Language::English => "Regional",
+            Language::Korean => "Parameter Store 파라미터가 없습니다.",
+            Language::English => "No Parameter Store parameters found.",
         }
     }
 
-    pub fn md_ip_auto_scaling(&self) -> &'static str {
+    pub fn ssm_parameter_path(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "IP 자동 확장",
-            Language::English => "IP Auto Scaling",
+            Language::Korean => "SSM 파라미터 경로",
+            Language::English => "SSM Parameter Path",
         }
     }
 
-    pub fn md_zone_auto_provisioning(&self) -> &'static str {
+    pub fn loading_efs_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "영역 자동 프로비저닝",
-            Language::English => "Zone Auto Provisioning",
+            Language::Korean => "EFS 파일 시스템 목록 조회 중",
+            Language::English => "Loading EFS file systems",
         }
     }
 
-    pub fn md_enabled(&self) -> &'static str {
+    pub fn loading_efs_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "활성화",
-            Language::English => "Enabled",
+            Language::Korean => "EFS 파일 시스템 상세 정보와 마운트 대상 조회 중",
+            Language::English => "Loading EFS file system details and mount targets",
         }
     }
 
-    pub fn md_disabled(&self) -> &'static str {
+    pub fn no_efs_file_systems(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "비활성화",
-            Language::English => "Disabled",
+            Language::Korean => "EFS 파일 시스템이 없습니다.",
+            Language::English => "No EFS file systems found.",
         }
     }
 
-    pub fn md_subnet(&self) -> &'static str {
+    pub fn efs_file_system(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "서브넷",
-            Language::English => "Subnet",
+            Language::Korean => "EFS 파일 시스템",
+            Language::English => "EFS File System",
         }
     }
 
-    pub fn md_connectivity_type(&self) -> &'static str {
+    pub fn loading_api_gateway_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "연결 유형",
-            Language::English => "Connectivity Type",
+            Language::Korean => "API Gateway API 목록 조회 중",
+            Language::English => "Loading API Gateway APIs",
         }
     }
 
-    pub fn md_public(&self) -> &'static str {
+    pub fn loading_api_gateway_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "퍼블릭",
-            Language::English => "Public",
+            Language::Korean => "API 스테이지, 라우트, 권한 부여자 조회 중",
+            Language::English => "Loading API stages, routes and authorizers",
         }
     }
 
-    pub fn md_private(&self) -> &'static str {
+    pub fn no_api_gateway_apis(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "프라이빗",
-            Language::English => "Private",
+            Language::Korean => "API Gateway API가 없습니다.",
+            Language::English => "No API Gateway APIs found.",
         }
     }
 
-    pub fn md_elastic_ip_allocation_id(&self) -> &'static str {
+    pub fn api_gateway_api(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "탄력적 IP 할당 ID",
-            Language::English => "Elastic IP Allocation ID",
+            Language::Korean => "API Gateway API",
+            Language::English => "API Gateway API",
         }
     }
 
-    pub fn md_route_tables(&self) -> &'static str {
+    pub fn loading_beanstalk_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "라우팅 테이블",
-            Language::English => "Route Tables",
+            Language::Korean => "Elastic Beanstalk 환경 목록 조회 중",
+            Language::English => "Loading Elastic Beanstalk environments",
         }
     }
 
-    pub fn md_destination(&self) -> &'static str {
+    pub fn loading_beanstalk_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "대상",
-            Language::English => "Destination",
+            Language::Korean => "환경 옵션 설정 및 연결된 리소스 조회 중",
+            Language::English => "Loading environment option settings and resources",
         }
     }
 
-    pub fn md_target(&self) -> &'static str {
+    pub fn no_beanstalk_environments(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "대상",
-            Language::English => "Target",
+            Language::Korean => "Elastic Beanstalk 환경이 없습니다.",
+            Language::English => "No Elastic Beanstalk environments found.",
         }
     }
 
-    pub fn md_associated_subnets(&self) -> &'static str {
+    pub fn elastic_beanstalk_environment(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "연결된 서브넷:",
-            Language::English => "Associated Subnets:",
+            Language::Korean => "Elastic Beanstalk 환경",
+            Language::English => "Elastic Beanstalk Environment",
         }
     }
 
-    pub fn md_association(&self) -> &'static str {
+    pub fn loading_state_machine_list(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "연결",
-            Language::English => "Association",
+            Language::Korean => "Step Functions 상태 머신 목록 조회 중",
+            Language::English => "Loading Step Functions state machines",
         }
     }
 
-    pub fn md_network_diagram(&self) -> &'static str {
+    pub fn loading_state_machine_detail(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "네트워크 구성도",
-            Language::English => "Network Diagram",
+            Language::Korean => "상태 머신 정의 및 로깅 설정 조회 중",
+            Language::English => "Loading state machine definition and logging",
         }
     }
 
-    // Security Group markdown labels
-    pub fn md_description(&self) -> &'static str {
+    pub fn no_state_machines(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "설명",
-            Language::English => "Description",
+            Language::Korean => "Step Functions 상태 머신이 없습니다.",
+            Language::English => "No Step Functions state machines found.",
         }
     }
 
-    pub fn md_inbound_rules(&self) -> &'static str {
+    pub fn step_functions_state_machine(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인바운드 규칙",
+            Language::Korean => "Step Functions 상태 머신",
+            Language::English => "Step Functions State Machine",
+        }
+    }
+
+    pub fn loading_kinesis_stream_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Kinesis 데이터 스트림 목록 조회 중",
+            Language::English => "Loading Kinesis data streams",
+        }
+    }
+
+    pub fn loading_kinesis_stream_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스트림 샤드, 보존 기간 및 소비자 조회 중",
+            Language::English => "Loading stream shards, retention and consumers",
+        }
+    }
+
+    pub fn no_kinesis_streams(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Kinesis 데이터 스트림이 없습니다.",
+            Language::English => "No Kinesis data streams found.",
+        }
+    }
+
+    pub fn kinesis_stream(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Kinesis 데이터 스트림",
+            Language::English => "Kinesis Data Stream",
+        }
+    }
+
+    pub fn loading_msk_cluster_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MSK 클러스터 목록 조회 중",
+            Language::English => "Loading MSK clusters",
+        }
+    }
+
+    pub fn loading_msk_cluster_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "브로커, 스토리지 및 암호화 설정 조회 중",
+            Language::English => "Loading brokers, storage and encryption settings",
+        }
+    }
+
+    pub fn no_msk_clusters(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MSK 클러스터가 없습니다.",
+            Language::English => "No MSK clusters found.",
+        }
+    }
+
+    pub fn msk_cluster(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MSK 클러스터",
+            Language::English => "MSK Cluster",
+        }
+    }
+
+    pub fn loading_opensearch_domain_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "OpenSearch 도메인 목록 조회 중",
+            Language::English => "Loading OpenSearch domains",
+        }
+    }
+
+    pub fn loading_opensearch_domain_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클러스터, 스토리지 및 액세스 정책 조회 중",
+            Language::English => "Loading cluster, storage and access policy",
+        }
+    }
+
+    pub fn no_opensearch_domains(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "OpenSearch 도메인이 없습니다.",
+            Language::English => "No OpenSearch domains found.",
+        }
+    }
+
+    pub fn opensearch_domain(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "OpenSearch 도메인",
+            Language::English => "OpenSearch Domain",
+        }
+    }
+
+    pub fn loading_redshift_cluster_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Redshift 클러스터 목록 조회 중",
+            Language::English => "Loading Redshift clusters",
+        }
+    }
+
+    pub fn loading_redshift_cluster_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드, 네트워크 및 스냅샷 일정 조회 중",
+            Language::English => "Loading nodes, network and snapshot schedule",
+        }
+    }
+
+    pub fn no_redshift_clusters(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Redshift 클러스터가 없습니다.",
+            Language::English => "No Redshift clusters found.",
+        }
+    }
+
+    pub fn redshift_cluster(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Redshift 클러스터",
+            Language::English => "Redshift Cluster",
+        }
+    }
+
+    pub fn loading_transit_gateway_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Transit Gateway 목록 조회 중",
+            Language::English => "Loading transit gateways",
+        }
+    }
+
+    pub fn loading_transit_gateway_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결 및 라우팅 테이블 조회 중",
+            Language::English => "Loading attachments and route tables",
+        }
+    }
+
+    pub fn no_transit_gateways(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Transit Gateway가 없습니다.",
+            Language::English => "No transit gateways found.",
+        }
+    }
+
+    pub fn transit_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Transit Gateway",
+            Language::English => "Transit Gateway",
+        }
+    }
+
+    pub fn loading_vpc_peering_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 피어링 목록 조회 중",
+            Language::English => "Loading VPC peering connections",
+        }
+    }
+
+    pub fn loading_vpc_peering_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 피어링 정보 조회 중",
+            Language::English => "Loading VPC peering connection",
+        }
+    }
+
+    pub fn no_vpc_peerings(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 피어링 연결이 없습니다.",
+            Language::English => "No VPC peering connections found.",
+        }
+    }
+
+    pub fn vpc_peering(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 피어링",
+            Language::English => "VPC Peering",
+        }
+    }
+
+    pub fn network_acl(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 ACL",
+            Language::English => "Network ACL",
+        }
+    }
+
+    pub fn loading_vpn_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPN 목록 조회 중",
+            Language::English => "Loading VPN connections and gateways",
+        }
+    }
+
+    pub fn loading_vpn_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPN 정보 조회 중",
+            Language::English => "Loading VPN details",
+        }
+    }
+
+    pub fn no_vpns(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPN 연결과 게이트웨이가 없습니다.",
+            Language::English => "No VPN connections or gateways found.",
+        }
+    }
+
+    pub fn site_to_site_vpn(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사이트 간 VPN",
+            Language::English => "Site-to-Site VPN",
+        }
+    }
+
+    pub fn loading_direct_connect_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Direct Connect 목록 조회 중",
+            Language::English => "Loading Direct Connect connections and virtual interfaces",
+        }
+    }
+
+    pub fn loading_direct_connect_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Direct Connect 정보 조회 중",
+            Language::English => "Loading Direct Connect details",
+        }
+    }
+
+    pub fn no_direct_connect_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Direct Connect 연결과 가상 인터페이스가 없습니다.",
+            Language::English => "No Direct Connect connections or virtual interfaces found.",
+        }
+    }
+
+    pub fn direct_connect(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Direct Connect",
+            Language::English => "Direct Connect",
+        }
+    }
+
+    pub fn loading_waf_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "WAF Web ACL 목록 조회 중",
+            Language::English => "Loading WAF web ACLs",
+        }
+    }
+
+    pub fn loading_waf_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "WAF Web ACL 정보 조회 중",
+            Language::English => "Loading WAF web ACL details",
+        }
+    }
+
+    pub fn no_waf_web_acls(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "WAF Web ACL이 없습니다.",
+            Language::English => "No WAF web ACLs found.",
+        }
+    }
+
+    pub fn waf(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "WAF",
+            Language::English => "WAF",
+        }
+    }
+
+    pub fn loading_acm_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ACM 인증서 목록 조회 중",
+            Language::English => "Loading ACM certificates",
+        }
+    }
+
+    pub fn loading_acm_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ACM 인증서 정보 조회 중",
+            Language::English => "Loading ACM certificate details",
+        }
+    }
+
+    pub fn no_acm_certificates(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ACM 인증서가 없습니다.",
+            Language::English => "No ACM certificates found.",
+        }
+    }
+
+    pub fn acm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ACM",
+            Language::English => "ACM",
+        }
+    }
+
+    pub fn loading_cloudformation_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 스택 목록 조회 중",
+            Language::English => "Loading CloudFormation stacks",
+        }
+    }
+
+    pub fn loading_cloudformation_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 스택 정보 조회 중",
+            Language::English => "Loading CloudFormation stack details",
+        }
+    }
+
+    pub fn no_cloudformation_stacks(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 스택이 없습니다.",
+            Language::English => "No CloudFormation stacks found.",
+        }
+    }
+
+    pub fn cloudformation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation",
+            Language::English => "CloudFormation",
+        }
+    }
+
+    pub fn loading_iam_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 엔터티 목록 조회 중",
+            Language::English => "Loading IAM entities",
+        }
+    }
+
+    pub fn loading_iam_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 엔터티 정보 조회 중",
+            Language::English => "Loading IAM entity details",
+        }
+    }
+
+    pub fn no_iam_entities(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 역할, 사용자, 그룹, 정책이 없습니다.",
+            Language::English => "No IAM roles, users, groups or policies found.",
+        }
+    }
+
+    pub fn iam(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM",
+            Language::English => "IAM",
+        }
+    }
+
+    pub fn loading_vpc_endpoint_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 엔드포인트 목록 조회 중",
+            Language::English => "Loading VPC endpoints",
+        }
+    }
+
+    pub fn loading_vpc_endpoint_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 엔드포인트 정보 조회 중",
+            Language::English => "Loading VPC endpoint details",
+        }
+    }
+
+    pub fn no_vpc_endpoints(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 엔드포인트가 없습니다.",
+            Language::English => "No VPC endpoints found.",
+        }
+    }
+
+    pub fn vpc_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 엔드포인트",
+            Language::English => "VPC Endpoint",
+        }
+    }
+
+    pub fn loading_network_interface_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 인터페이스 목록 조회 중",
+            Language::English => "Loading network interfaces",
+        }
+    }
+
+    pub fn loading_network_interface_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 인터페이스 정보 조회 중",
+            Language::English => "Loading network interface details",
+        }
+    }
+
+    pub fn no_network_interfaces(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 인터페이스가 없습니다.",
+            Language::English => "No network interfaces found.",
+        }
+    }
+
+    pub fn network_interface(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 인터페이스",
+            Language::English => "Network Interface",
+        }
+    }
+
+    pub fn loading_launch_template_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 템플릿 목록 조회 중",
+            Language::English => "Loading launch templates",
+        }
+    }
+
+    pub fn loading_launch_template_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 템플릿 정보 조회 중",
+            Language::English => "Loading launch template details",
+        }
+    }
+
+    pub fn no_launch_templates(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 템플릿이 없습니다.",
+            Language::English => "No launch templates found.",
+        }
+    }
+
+    pub fn loading_ami_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AMI 목록 조회 중",
+            Language::English => "Loading AMIs",
+        }
+    }
+
+    pub fn loading_ami_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AMI 정보 조회 중",
+            Language::English => "Loading AMI details",
+        }
+    }
+
+    pub fn no_amis(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "소유한 AMI가 없습니다.",
+            Language::English => "No owned AMIs found.",
+        }
+    }
+
+    pub fn ami(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AMI",
+            Language::English => "AMI",
+        }
+    }
+
+    pub fn loading_ebs_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EBS 볼륨/스냅샷 목록 조회 중",
+            Language::English => "Loading EBS volumes and snapshots",
+        }
+    }
+
+    pub fn loading_ebs_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EBS 정보 조회 중",
+            Language::English => "Loading EBS details",
+        }
+    }
+
+    pub fn no_ebs_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EBS 볼륨과 스냅샷이 없습니다.",
+            Language::English => "No EBS volumes or snapshots found.",
+        }
+    }
+
+    pub fn ebs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EBS",
+            Language::English => "EBS",
+        }
+    }
+
+    pub fn loading_key_pair_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 페어 목록 조회 중",
+            Language::English => "Loading key pairs",
+        }
+    }
+
+    pub fn loading_key_pair_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 페어 정보 조회 중",
+            Language::English => "Loading key pair details",
+        }
+    }
+
+    pub fn no_key_pairs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 페어가 없습니다.",
+            Language::English => "No key pairs found.",
+        }
+    }
+
+    pub fn key_pairs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 페어",
+            Language::English => "Key Pairs",
+        }
+    }
+
+    pub fn loading_event_bus_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 이벤트 버스 목록 조회 중",
+            Language::English => "Loading EventBridge event buses",
+        }
+    }
+
+    pub fn loading_event_bus_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 버스 규칙 및 대상 조회 중",
+            Language::English => "Loading event bus rules and targets",
+        }
+    }
+
+    pub fn no_event_buses(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 이벤트 버스가 없습니다.",
+            Language::English => "No EventBridge event buses found.",
+        }
+    }
+
+    pub fn eventbridge_event_bus(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EventBridge 이벤트 버스",
+            Language::English => "EventBridge Event Bus",
+        }
+    }
+
+    pub fn loading_cognito_pool_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Cognito 사용자 풀/자격 증명 풀 목록 조회 중",
+            Language::English => "Loading Cognito user pools and identity pools",
+        }
+    }
+
+    pub fn loading_cognito_pool_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Cognito 풀 설정 및 앱 클라이언트 조회 중",
+            Language::English => "Loading Cognito pool settings and app clients",
+        }
+    }
+
+    pub fn no_cognito_pools(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Cognito 사용자 풀이나 자격 증명 풀이 없습니다.",
+            Language::English => "No Cognito user pools or identity pools found.",
+        }
+    }
+
+    pub fn cognito(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Cognito",
+            Language::English => "Cognito",
+        }
+    }
+
+    pub fn loading_glue_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Glue 데이터베이스/크롤러 목록 조회 중",
+            Language::English => "Loading Glue databases and crawlers",
+        }
+    }
+
+    pub fn loading_glue_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Glue 테이블 및 크롤러 설정 조회 중",
+            Language::English => "Loading Glue tables and crawler settings",
+        }
+    }
+
+    pub fn no_glue_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Glue 데이터베이스나 크롤러가 없습니다.",
+            Language::English => "No Glue databases or crawlers found.",
+        }
+    }
+
+    pub fn glue(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Glue 데이터 카탈로그",
+            Language::English => "Glue Data Catalog",
+        }
+    }
+
+    pub fn loading_athena_workgroup_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Athena 작업 그룹 목록 조회 중",
+            Language::English => "Loading Athena workgroups",
+        }
+    }
+
+    pub fn loading_athena_workgroup_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Athena 작업 그룹 설정 조회 중",
+            Language::English => "Loading Athena workgroup settings",
+        }
+    }
+
+    pub fn no_athena_workgroups(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Athena 작업 그룹이 없습니다.",
+            Language::English => "No Athena workgroups found.",
+        }
+    }
+
+    pub fn athena_workgroup(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Athena 작업 그룹",
+            Language::English => "Athena Workgroup",
+        }
+    }
+
+    pub fn loading_backup_plan_list(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "백업 계획 목록 조회 중",
+            Language::English => "Loading backup plans",
+        }
+    }
+
+    pub fn loading_backup_plan_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "백업 규칙 및 보호된 리소스 조회 중",
+            Language::English => "Loading backup rules and protected resources",
+        }
+    }
+
+    pub fn no_backup_plans(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "백업 계획이 없습니다.",
+            Language::English => "No backup plans found.",
+        }
+    }
+
+    pub fn backup_plan(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AWS Backup 백업 계획",
+            Language::English => "AWS Backup Plan",
+        }
+    }
+
+    pub fn asg_launch_template(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 템플릿",
+            Language::English => "Launch Template",
+        }
+    }
+
+    pub fn asg_launch_configuration(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 구성",
+            Language::English => "Launch Configuration",
+        }
+    }
+
+    pub fn asg_min_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최소 크기",
+            Language::English => "Min Size",
+        }
+    }
+
+    pub fn asg_max_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최대 크기",
+            Language::English => "Max Size",
+        }
+    }
+
+    pub fn asg_desired_capacity(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원하는 용량",
+            Language::English => "Desired Capacity",
+        }
+    }
+
+    pub fn asg_default_cooldown(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 쿨다운",
+            Language::English => "Default Cooldown",
+        }
+    }
+
+    pub fn asg_health_check_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "헬스 체크 유형",
+            Language::English => "Health Check Type",
+        }
+    }
+
+    pub fn asg_health_check_grace_period(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "헬스 체크 유예 기간",
+            Language::English => "Health Check Grace Period",
+        }
+    }
+
+    pub fn asg_created_at(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "생성일",
+            Language::English => "Created At",
+        }
+    }
+
+    pub fn asg_availability_zones(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가용 영역",
+            Language::English => "Availability Zones",
+        }
+    }
+
+    pub fn asg_instances(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인스턴스",
+            Language::English => "Instances",
+        }
+    }
+
+    pub fn asg_instance_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인스턴스 ID",
+            Language::English => "Instance ID",
+        }
+    }
+
+    pub fn asg_target_groups(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상 그룹",
+            Language::English => "Target Groups",
+        }
+    }
+
+    pub fn asg_scaling_policies(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조정 정책",
+            Language::English => "Scaling Policies",
+        }
+    }
+
+    pub fn asg_policy_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "유형",
+            Language::English => "Type",
+        }
+    }
+
+    pub fn asg_adjustment_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조정 유형",
+            Language::English => "Adjustment Type",
+        }
+    }
+
+    pub fn asg_adjustment_value(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조정 값",
+            Language::English => "Adjustment Value",
+        }
+    }
+
+    pub fn asg_cooldown(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "쿨다운",
+            Language::English => "Cooldown",
+        }
+    }
+
+    pub fn asg_scheduled_actions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "예약된 작업",
+            Language::English => "Scheduled Actions",
+        }
+    }
+
+    pub fn asg_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일정",
+            Language::English => "Schedule",
+        }
+    }
+
+    pub fn asg_time_zone(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시간대",
+            Language::English => "Time Zone",
+        }
+    }
+
+    pub fn asg_tags(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그",
+            Language::English => "Tags",
+        }
+    }
+
+    pub fn asg_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키",
+            Language::English => "Key",
+        }
+    }
+
+    pub fn asg_seconds(&self, value: i32) -> String {
+        match self.lang {
+            Language::Korean => format!("{}초", crate::locale::count(value)),
+            Language::English => format!("{}s", crate::locale::count(value)),
+        }
+    }
+
+    pub fn asg_instances_with_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!(
+                "{} ({} 개)",
+                self.asg_instances(),
+                crate::locale::count(count as i64)
+            ),
+            Language::English => format!(
+                "{} ({})",
+                self.asg_instances(),
+                crate::locale::count(count as i64)
+            ),
+        }
+    }
+
+    pub fn loading_blueprint_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린트 리소스 조회 중",
+            Language::English => "Loading Blueprint resources",
+        }
+    }
+
+    // VPC Loading steps
+    pub fn vpc_basic_info(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 기본 정보",
+            Language::English => "VPC Basic Info",
+        }
+    }
+
+    pub fn subnets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서브넷",
+            Language::English => "Subnets",
+        }
+    }
+
+    pub fn internet_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인터넷 게이트웨이",
+            Language::English => "Internet Gateway",
+        }
+    }
+
+    pub fn nat_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "NAT 게이트웨이",
+            Language::English => "NAT Gateway",
+        }
+    }
+
+    pub fn route_tables(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우팅 테이블",
+            Language::English => "Route Tables",
+        }
+    }
+
+    pub fn elastic_ip(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Elastic IP",
+            Language::English => "Elastic IP",
+        }
+    }
+
+    pub fn dns_settings(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DNS 설정",
+            Language::English => "DNS Settings",
+        }
+    }
+
+    pub fn completing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "완료 중",
+            Language::English => "Completing",
+        }
+    }
+
+    pub fn current_loading(&self, task: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("현재: {} 조회 중...", task),
+            Language::English => format!("Current: Loading {}...", task),
+        }
+    }
+
+    // Blueprint
+    pub fn new_blueprint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "+ 새 블루프린터",
+            Language::English => "+ New Blueprint",
+        }
+    }
+
+    pub fn blueprint_load_failed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린터 로드 실패",
+            Language::English => "Blueprint load failed",
+        }
+    }
+
+    pub fn enter_blueprint_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린터 이름을 입력하세요:",
+            Language::English => "Enter blueprint name:",
+        }
+    }
+
+    pub fn press_a_to_add(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "'a' 키를 눌러 리소스를 추가하세요.",
+            Language::English => "Press 'a' to add resources.",
+        }
+    }
+
+    pub fn resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스",
+            Language::English => "resources",
+        }
+    }
+
+    pub fn validate(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "검증",
+            Language::English => "Validate",
+        }
+    }
+
+    pub fn remap(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이름 갱신",
+            Language::English => "Remap",
+        }
+    }
+
+    pub fn remove_missing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "누락 제거",
+            Language::English => "Remove missing",
+        }
+    }
+
+    pub fn validating_blueprint_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린트 리소스 검증 중",
+            Language::English => "Validating Blueprint resources",
+        }
+    }
+
+    pub fn resource_missing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "존재하지 않음",
+            Language::English => "not found",
+        }
+    }
+
+    pub fn resource_renamed(&self, name: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("현재 이름: {}", name),
+            Language::English => format!("now: {}", name),
+        }
+    }
+
+    pub fn resource_moved(&self, region: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("현재 리전: {}", region),
+            Language::English => format!("now in: {}", region),
+        }
+    }
+
+    pub fn resource_unverified(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "확인 실패",
+            Language::English => "could not verify",
+        }
+    }
+
+    pub fn validation_complete(&self, stale: usize, unverified: usize) -> String {
+        match (self.lang, unverified) {
+            (Language::Korean, 0) => format!("검증 완료: 변경/누락 {}개", stale),
+            (Language::Korean, _) => format!(
+                "검증 완료: 변경/누락 {}개, 확인 실패 {}개",
+                stale, unverified
+            ),
+            (Language::English, 0) => format!("Validation complete: {} stale", stale),
+            (Language::English, _) => format!(
+                "Validation complete: {} stale, {} could not be verified",
+                stale, unverified
+            ),
+        }
+    }
+
+    pub fn resources_remapped(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("리소스 {}개 갱신", count),
+            Language::English => format!("{} resource(s) remapped", count),
+        }
+    }
+
+    pub fn missing_resources_removed(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("누락 리소스 {}개 제거", count),
+            Language::English => format!("{} missing resource(s) removed", count),
+        }
+    }
+
+    pub fn sync(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "동기화",
+            Language::English => "Sync",
+        }
+    }
+
+    pub fn blueprints_synced(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원격 저장소와 동기화 완료",
+            Language::English => "Synced with remote store",
+        }
+    }
+
+    pub fn remote_store_not_configured(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "원격 저장소가 설정되지 않았습니다 (settings.json의 blueprint_remote)"
+            }
+            Language::English => "Remote store not configured (blueprint_remote in settings.json)",
+        }
+    }
+
+    pub fn blueprint_sync_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("원격 동기화 실패: {}", error),
+            Language::English => format!("Remote sync failed: {}", error),
+        }
+    }
+
+    pub fn export_notion(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Notion 내보내기",
+            Language::English => "Export to Notion",
+        }
+    }
+
+    pub fn notion_not_configured(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Notion이 설정되지 않았습니다 (settings.json의 notion_export)",
+            Language::English => "Notion not configured (notion_export in settings.json)",
+        }
+    }
+
+    pub fn notion_nothing_to_export(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Notion으로 내보낼 리소스가 없습니다",
+            Language::English => "No resources to export to Notion",
+        }
+    }
+
+    pub fn notion_exported(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("Notion 페이지 {}개 내보냄", count),
+            Language::English => format!("Exported {} page(s) to Notion", count),
+        }
+    }
+
+    pub fn notion_export_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("Notion 내보내기 실패: {}", error),
+            Language::English => format!("Notion export failed: {}", error),
+        }
+    }
+
+    pub fn export_backstage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Backstage 카탈로그",
+            Language::English => "Backstage catalog",
+        }
+    }
+
+    pub fn backstage_nothing_to_export(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Backstage 카탈로그로 내보낼 리소스가 없습니다",
+            Language::English => "No resources to export to the Backstage catalog",
+        }
+    }
+
+    pub fn backstage_exported(&self, count: usize, filename: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("Backstage 엔티티 {}개 저장: {}", count, filename),
+            Language::English => format!("Saved {} Backstage entities: {}", count, filename),
+        }
+    }
+
+    pub fn export_inventory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인벤토리(CycloneDX)",
+            Language::English => "Inventory (CycloneDX)",
+        }
+    }
+
+    pub fn inventory_nothing_to_export(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인벤토리로 내보낼 리소스가 없습니다",
+            Language::English => "No resources to export to the inventory",
+        }
+    }
+
+    pub fn inventory_exported(&self, count: usize, filename: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("인벤토리 컴포넌트 {}개 저장: {}", count, filename),
+            Language::English => format!("Saved {} inventory components: {}", count, filename),
+        }
+    }
+
+    pub fn save_per_resource(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스별 파일 저장",
+            Language::English => "Save per resource",
+        }
+    }
+
+    pub fn blueprint_files_saved(&self, count: usize, dir: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("파일 {}개 저장 (index.md 포함): {}/", count, dir),
+            Language::English => format!("Saved {} files (including index.md): {}/", count, dir),
+        }
+    }
+
+    pub fn blueprint_files_save_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("파일 저장 실패: {}", error),
+            Language::English => format!("Failed to save files: {}", error),
+        }
+    }
+
+    pub fn diagram_render_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("다이어그램 이미지 생성 실패: {}", error),
+            Language::English => format!("Failed to render diagram images: {}", error),
+        }
+    }
+
+    pub fn import_terraform(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Terraform 가져오기",
+            Language::English => "Import Terraform",
+        }
+    }
+
+    pub fn import_blueprint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린트 가져오기",
+            Language::English => "Import Blueprint",
+        }
+    }
+
+    pub fn enter_terraform_state_path(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "terraform.tfstate 또는 terraform show -json 파일 경로를 입력하세요:"
+            }
+            Language::English => "Enter path to terraform.tfstate or terraform show -json output:",
+        }
+    }
+
+    pub fn terraform_no_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지원되는 AWS 리소스가 상태 파일에 없습니다",
+            Language::English => "No supported AWS resources found in state file",
+        }
+    }
+
+    pub fn import_cloudformation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 가져오기",
+            Language::English => "Import CloudFormation",
+        }
+    }
+
+    pub fn enter_stack_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 스택 이름을 입력하세요:",
+            Language::English => "Enter CloudFormation stack name:",
+        }
+    }
+
+    pub fn stack_no_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지원되는 AWS 리소스가 스택에 없습니다",
+            Language::English => "No supported AWS resources found in stack",
+        }
+    }
+
+    pub fn loading_stack_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스택 리소스 조회 중",
+            Language::English => "Loading stack resources",
+        }
+    }
+
+    pub fn import_tag_query(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그로 가져오기",
+            Language::English => "Import by Tag",
+        }
+    }
+
+    pub fn enter_tag_query(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그 조건을 입력하세요 (예: Project=checkout,Env=prod|stage):",
+            Language::English => "Enter tag query (e.g. Project=checkout,Env=prod|stage):",
+        }
+    }
+
+    pub fn tag_query_no_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그 조건에 맞는 지원 리소스가 없습니다",
+            Language::English => "No supported resources match the tag query",
+        }
+    }
+
+    pub fn querying_tagged_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그 리소스 조회 중",
+            Language::English => "Querying tagged resources",
+        }
+    }
+
+    pub fn blueprint_imported(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("블루프린트 가져오기 완료: 리소스 {}개", count),
+            Language::English => format!("Blueprint imported: {} resource(s)", count),
+        }
+    }
+
+    pub fn blueprint_import_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("블루프린트 가져오기 실패: {}", error),
+            Language::English => format!("Blueprint import failed: {}", error),
+        }
+    }
+
+    // Document search
+    pub fn search_documents(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "문서 검색",
+            Language::English => "Search Documents",
+        }
+    }
+
+    pub fn enter_search_query(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "저장한 문서에서 찾을 CIDR, SG ID 또는 호스트 이름을 입력하세요:",
+            Language::English => "Enter a CIDR, SG ID or hostname to find in saved documents:",
+        }
+    }
+
+    pub fn no_search_results(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일치하는 문서가 없습니다",
+            Language::English => "No matching documents",
+        }
+    }
+
+    pub fn search_result_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("문서 {}개에서 찾음", count),
+            Language::English => format!("Found in {} document(s)", count),
+        }
+    }
+
+    // Compare mode
+    pub fn compare(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비교",
+            Language::English => "Compare",
+        }
+    }
+
+    pub fn compare_toggle_view(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "표/분할 보기",
+            Language::English => "Table/Split view",
+        }
+    }
+
+    pub fn compare_field(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "필드",
+            Language::English => "Field",
+        }
+    }
+
+    pub fn compare_cleared(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비교 선택 해제",
+            Language::English => "Compare selection cleared",
+        }
+    }
+
+    pub fn loading_comparison(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 비교 중",
+            Language::English => "Comparing resources",
+        }
+    }
+
+    pub fn compare_base_marked(&self, name: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("비교 기준: {} (비교할 리소스에서 c)", name),
+            Language::English => format!("Compare base: {} (press c on another resource)", name),
+        }
+    }
+
+    pub fn compare_difference_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("다른 항목 {}개", count),
+            Language::English => format!("{} field(s) differ", count),
+        }
+    }
+
+    // Keyboard macros
+    pub fn macro_register_prompt(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "매크로 레지스터 입력 (a-z)",
+            Language::English => "Press a macro register (a-z)",
+        }
+    }
+
+    pub fn macro_cancelled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "매크로 취소",
+            Language::English => "Macro cancelled",
+        }
+    }
+
+    pub fn macro_replay_while_recording(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "매크로 기록 중에는 재생할 수 없음 (Ctrl+R로 종료)",
+            Language::English => "Cannot replay while recording (Ctrl+R to stop)",
+        }
+    }
+
+    pub fn macro_recording(&self, register: char) -> String {
+        match self.lang {
+            Language::Korean => format!("@{} 매크로 기록 중 (Ctrl+R로 종료)", register),
+            Language::English => format!("Recording macro @{} (Ctrl+R to stop)", register),
+        }
+    }
+
+    pub fn macro_saved(&self, register: char, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("@{} 매크로 저장: 키 {}개", register, count),
+            Language::English => format!("Saved macro @{}: {} key(s)", register, count),
+        }
+    }
+
+    pub fn macro_empty(&self, register: char) -> String {
+        match self.lang {
+            Language::Korean => format!("@{} 매크로가 비어 있음", register),
+            Language::English => format!("Macro @{} is empty", register),
+        }
+    }
+
+    pub fn macro_replaying(&self, register: char, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("@{} 매크로 재생: 키 {}개", register, count),
+            Language::English => format!("Replaying macro @{}: {} key(s)", register, count),
+        }
+    }
+
+    // Tutorial
+    pub fn tutorial(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "튜토리얼",
+            Language::English => "Tutorial",
+        }
+    }
+
+    pub fn tutorial_started(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "튜토리얼 시작 (F1로 종료)",
+            Language::English => "Tutorial started (F1 to exit)",
+        }
+    }
+
+    pub fn tutorial_exited(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "튜토리얼 종료",
+            Language::English => "Tutorial closed",
+        }
+    }
+
+    pub fn tutorial_finished(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "튜토리얼 완료! 언제든 F1로 다시 볼 수 있음",
+            Language::English => "Tutorial complete! Press F1 any time to run it again",
+        }
+    }
+
+    pub fn tutorial_login(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로그인",
+            Language::English => "Log in",
+        }
+    }
+
+    pub fn tutorial_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 선택",
+            Language::English => "Choose a region",
+        }
+    }
+
+    pub fn tutorial_service(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서비스 선택",
+            Language::English => "Choose a service",
+        }
+    }
+
+    pub fn tutorial_preview(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "문서 미리보기",
+            Language::English => "Preview the document",
+        }
+    }
+
+    pub fn tutorial_blueprint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린트 만들기",
+            Language::English => "Build a blueprint",
+        }
+    }
+
+    pub fn tutorial_login_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "↑↓로 AWS 프로필을 고르고 Enter로 로그인하세요. 프로필이 없으면 `aws configure` 후 r로 새로고침합니다."
+            }
+            Language::English => {
+                "Pick an AWS profile with ↑↓ and press Enter to log in. No profiles? Run `aws configure`, then press r to refresh."
+            }
+        }
+    }
+
+    pub fn tutorial_single_mode_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "블루프린트 목록입니다. 먼저 리소스 하나를 문서화해 봅니다: s를 눌러 단일 모드로 시작하세요."
+            }
+            Language::English => {
+                "This is the blueprint list. Start by documenting one resource: press s for single mode."
+            }
+        }
+    }
+
+    pub fn tutorial_region_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스가 있는 리전을 고르고 Enter를 누르세요.",
+            Language::English => "Choose the region your resources live in and press Enter.",
+        }
+    }
+
+    pub fn tutorial_service_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "문서화할 AWS 서비스를 고르고 Enter로 리소스 목록을 불러오세요.",
+            Language::English => "Choose an AWS service and press Enter to load its resources.",
+        }
+    }
+
+    pub fn tutorial_resource_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "리소스를 고르고 Enter로 Markdown 문서를 만드세요. c로 두 리소스를 비교할 수도 있습니다."
+            }
+            Language::English => {
+                "Pick a resource and press Enter to generate its Markdown. Press c on two resources to compare them."
+            }
+        }
+    }
+
+    pub fn tutorial_preview_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "생성된 문서입니다. j/k로 스크롤하고 s로 .md 파일을 저장하세요. 다 봤으면 Esc를 눌러 블루프린트 목록으로 돌아갑니다."
+            }
+            Language::English => {
+                "This is the generated document. Scroll with j/k and press s to save it as .md. When done, press Esc until you are back at the blueprint list."
+            }
+        }
+    }
+
+    pub fn tutorial_new_blueprint_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "블루프린트는 여러 리소스를 한 문서로 묶습니다. 맨 위의 새 블루프린트에서 Enter를 누르세요."
+            }
+            Language::English => {
+                "Blueprints bundle several resources into one document. Press Enter on New Blueprint at the top."
+            }
+        }
+    }
+
+    pub fn tutorial_blueprint_name_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린트 이름(예: checkout-prod)을 입력하고 Enter를 누르세요.",
+            Language::English => "Type a blueprint name (e.g. checkout-prod) and press Enter.",
+        }
+    }
+
+    pub fn tutorial_blueprint_detail_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "a로 리소스를 추가하고, 다 모았으면 g로 전체 문서를 생성하세요.",
+            Language::English => {
+                "Press a to add resources, then g to generate the combined document."
+            }
+        }
+    }
+
+    pub fn tutorial_add_resource_hint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "앞에서처럼 리전 → 서비스 → 리소스를 고른 뒤, 미리보기에서 a로 블루프린트에 추가하세요."
+            }
+            Language::English => {
+                "Choose region → service → resource as before, then press a in the preview to add it to the blueprint."
+            }
+        }
+    }
+
+    // Accessible plain mode (--no-tui)
+    pub fn plain_mode_title(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "emd 접근성 모드 (--no-tui): 번호를 입력하고 Enter를 누르세요.",
+            Language::English => "emd accessible mode (--no-tui): type a number and press Enter.",
+        }
+    }
+
+    pub fn plain_choice_prompt(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "번호:",
+            Language::English => "Number:",
+        }
+    }
+
+    pub fn plain_main_menu(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "무엇을 할까요?",
+            Language::English => "What would you like to do?",
+        }
+    }
+
+    pub fn plain_document_resource(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 하나 문서화",
+            Language::English => "Document a single resource",
+        }
+    }
+
+    pub fn plain_document_blueprint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린터 문서 생성",
+            Language::English => "Generate a blueprint document",
+        }
+    }
+
+    pub fn plain_no_blueprints(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "저장된 블루프린터가 없습니다. TUI에서 먼저 만드세요.",
+            Language::English => "No saved blueprints. Create one in the TUI first.",
+        }
+    }
+
+    pub fn plain_invalid_choice(&self, answer: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("잘못된 선택: {}", answer),
+            Language::English => format!("Invalid choice: {}", answer),
+        }
+    }
+
+    pub fn plain_resource_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("리소스 {}개", count),
+            Language::English => format!("{} resource(s)", count),
+        }
+    }
+
+    // Promotion checklist
+    pub fn promotion(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "승격 점검",
+            Language::English => "Promotion check",
+        }
+    }
+
+    pub fn promotion_checklist(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "승격 체크리스트",
+            Language::English => "Promotion Checklist",
+        }
+    }
+
+    pub fn promotion_cleared(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "승격 원본 선택 해제",
+            Language::English => "Promotion source cleared",
+        }
+    }
+
+    pub fn promotion_config_differences(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "설정 차이",
+            Language::English => "Configuration Differences",
+        }
+    }
+
+    pub fn promotion_unreadable(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조회 실패",
+            Language::English => "Could Not Be Read",
+        }
+    }
+
+    pub fn promotion_no_differences(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "차이가 없습니다",
+            Language::English => "No differences found",
+        }
+    }
+
+    pub fn loading_promotion_checklist(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "승격 체크리스트 생성 중",
+            Language::English => "Building promotion checklist",
+        }
+    }
+
+    pub fn promotion_source_marked(&self, name: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("승격 원본: {} (대상 블루프린트에서 p)", name),
+            Language::English => format!("Promotion source: {} (press p on target)", name),
+        }
+    }
+
+    // JMESPath query playground
+    pub fn query_playground(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "쿼리 플레이그라운드",
+            Language::English => "Query Playground",
+        }
+    }
+
+    pub fn select_query_operation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행할 읽기 전용 작업을 선택하세요:",
+            Language::English => "Select a read-only operation to run:",
+        }
+    }
+
+    pub fn enter_jmespath_query(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "JMESPath 쿼리 (입력하는 대로 결과에 반영):",
+            Language::English => "JMESPath query (results update as you type):",
+        }
+    }
+
+    pub fn run_operation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행",
+            Language::English => "Run",
+        }
+    }
+
+    pub fn query_result(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "결과",
+            Language::English => "Result",
+        }
+    }
+
+    pub fn query_error(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "쿼리 오류",
+            Language::English => "Query error",
+        }
+    }
+
+    pub fn running_query_operation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "작업 실행 중",
+            Language::English => "Running operation",
+        }
+    }
+
+    pub fn raw_json(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원본 JSON",
+            Language::English => "Raw JSON",
+        }
+    }
+
+    pub fn word_wrap(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "줄바꿈",
+            Language::English => "Wrap",
+        }
+    }
+
+    pub fn scroll_sideways(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "좌우 스크롤",
+            Language::English => "Scroll sideways",
+        }
+    }
+
+    pub fn raw_json_unavailable(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이 미리보기에는 원본 데이터가 없습니다",
+            Language::English => "No raw data for this preview",
+        }
+    }
+
+    pub fn promotion_missing_in(&self, name: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{}에 없는 리소스", name),
+            Language::English => format!("Missing in {}", name),
+        }
+    }
+
+    pub fn promotion_item_count(&self, count: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("검토 항목 {}개", count),
+            Language::English => format!("{} item(s) to review", count),
+        }
+    }
+
+    // Settings
+    pub fn language(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "언어",
+            Language::English => "Language",
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn language_setting(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "언어 설정",
+            Language::English => "Language Setting",
+        }
+    }
+
+    pub fn encrypt_at_rest(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로컬 데이터 암호화",
+            Language::English => "Encrypt Local Data",
+        }
+    }
+
+    pub fn hide_terminated_instances(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "종료된 인스턴스 숨기기",
+            Language::English => "Hide Terminated Instances",
+        }
+    }
+
+    pub fn sort_services_by_usage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서비스 사용 빈도순 정렬",
+            Language::English => "Sort Services by Usage",
+        }
+    }
+
+    pub fn color_palette(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "색상 팔레트",
+            Language::English => "Color Palette",
+        }
+    }
+
+    pub fn palette_high_contrast(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고대비",
+            Language::English => "High Contrast",
+        }
+    }
+
+    pub fn palette_deuteranopia(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "색약 (녹색약)",
+            Language::English => "Colorblind (Deuteranopia)",
+        }
+    }
+
+    pub fn palette_protanopia(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "색약 (적색약)",
+            Language::English => "Colorblind (Protanopia)",
+        }
+    }
+
+    pub fn palette_no_color(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "색상 없음",
+            Language::English => "No Color",
+        }
+    }
+
+    pub fn ascii_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ASCII 모드 (이모지 대신 기호)",
+            Language::English => "ASCII Mode (no emoji)",
+        }
+    }
+
+    pub fn max_concurrent_requests(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최대 동시 요청 수",
+            Language::English => "Max Concurrent Requests",
+        }
+    }
+
+    pub fn ascii_auto(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동",
+            Language::English => "Auto",
+        }
+    }
+
+    pub fn number_locale(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "숫자/날짜 표기",
+            Language::English => "Number & Date Format",
+        }
+    }
+
+    pub fn number_locale_plain(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본",
+            Language::English => "Plain",
+        }
+    }
+
+    pub fn spell_out_schedules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "일정 표현식 풀어 쓰기",
+            Language::English => "Spell Out Schedules",
+        }
+    }
+
+    pub fn normalize_security_group_rules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "보안 그룹 규칙 정리",
+            Language::English => "Normalize Security Group Rules",
+        }
+    }
+
+    pub fn stamp_last_modified(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudTrail 마지막 변경 표시",
+            Language::English => "Stamp Last Modified from CloudTrail",
+        }
+    }
+
+    pub fn show_resource_age(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 생성 경과 기간 표시",
+            Language::English => "Show Resource Age",
+        }
+    }
+
+    /// "a, b and c" (Korean: "a, b, c").
+    pub fn join_list(&self, items: &[String]) -> String {
+        match (self.lang, items) {
+            (Language::English, [rest @ .., last]) if !rest.is_empty() => {
+                format!("{} and {}", rest.join(", "), last)
+            }
+            _ => items.join(", "),
+        }
+    }
+
+    /// 0 = Sunday.
+    pub fn weekday_name(&self, day: u32) -> &'static str {
+        const KOREAN: [&str; 7] = [
+            "일요일",
+            "월요일",
+            "화요일",
+            "수요일",
+            "목요일",
+            "금요일",
+            "토요일",
+        ];
+        const ENGLISH: [&str; 7] = [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ];
+        let day = day as usize % 7;
+        match self.lang {
+            Language::Korean => KOREAN[day],
+            Language::English => ENGLISH[day],
+        }
+    }
+
+    /// 1 = January.
+    pub fn month_name(&self, month: u32) -> &'static str {
+        const KOREAN: [&str; 12] = [
+            "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+        ];
+        const ENGLISH: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let month = (month.clamp(1, 12) - 1) as usize;
+        match self.lang {
+            Language::Korean => KOREAN[month],
+            Language::English => ENGLISH[month],
+        }
+    }
+
+    pub fn schedule_every_minutes(&self, minutes: u32) -> String {
+        match (self.lang, minutes) {
+            (Language::Korean, 1) => "매분".to_string(),
+            (Language::Korean, n) => format!("{}분마다", n),
+            (Language::English, 1) => "every minute".to_string(),
+            (Language::English, n) => format!("every {} minutes", n),
+        }
+    }
+
+    pub fn schedule_every_hours(&self, hours: u32) -> String {
+        match (self.lang, hours) {
+            (Language::Korean, 1) => "매시간".to_string(),
+            (Language::Korean, n) => format!("{}시간마다", n),
+            (Language::English, 1) => "every hour".to_string(),
+            (Language::English, n) => format!("every {} hours", n),
+        }
+    }
+
+    pub fn schedule_every_days(&self, days: u32) -> String {
+        match (self.lang, days) {
+            (Language::Korean, 1) => "매일".to_string(),
+            (Language::Korean, n) => format!("{}일마다", n),
+            (Language::English, 1) => "every day".to_string(),
+            (Language::English, n) => format!("every {} days", n),
+        }
+    }
+
+    pub fn schedule_hourly_at(&self, minute: u32) -> String {
+        match self.lang {
+            Language::Korean => format!("매시 {}분", minute),
+            Language::English => format!("every hour at minute {}", minute),
+        }
+    }
+
+    pub fn schedule_weekdays(&self, days: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("매주 {}", days),
+            Language::English => format!("every {}", days),
+        }
+    }
+
+    pub fn schedule_weekday_range(&self, from: &str, to: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{}부터 {}까지", from, to),
+            Language::English => format!("{} through {}", from, to),
+        }
+    }
+
+    pub fn schedule_days_of_month(&self, days: &[u32]) -> String {
+        let list = |suffix: &str| {
+            let days: Vec<String> = days.iter().map(|d| format!("{}{}", d, suffix)).collect();
+            self.join_list(&days)
+        };
+        match self.lang {
+            Language::Korean => format!("매월 {}", list("일")),
+            Language::English if days.len() == 1 => format!("day {} of every month", list("")),
+            Language::English => format!("days {} of every month", list("")),
+        }
+    }
+
+    pub fn schedule_last_day_of_month(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "매월 마지막 날",
+            Language::English => "the last day of every month",
+        }
+    }
+
+    /// Fixed times of day; `days` is `None` for every day.
+    pub fn schedule_at(&self, days: Option<&str>, times: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{} {}", days.unwrap_or("매일"), times),
+            Language::English => {
+                capitalize_first(&format!("{} at {}", days.unwrap_or("every day"), times))
+            }
+        }
+    }
+
+    /// Repeating interval such as "every 15 minutes"; `days` is `None` for every day.
+    pub fn schedule_repeating(&self, interval: &str, days: Option<&str>) -> String {
+        match (self.lang, days) {
+            (Language::Korean, Some(days)) => format!("{} {}", days, interval),
+            (Language::Korean, None) => interval.to_string(),
+            (Language::English, Some(days)) => capitalize_first(&format!("{}, {}", interval, days)),
+            (Language::English, None) => capitalize_first(interval),
+        }
+    }
+
+    pub fn schedule_in_months(&self, schedule: &str, months: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{} {}", months, schedule),
+            Language::English => format!("{} in {}", schedule, months),
+        }
+    }
+
+    pub fn document_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 문서화",
+            Language::English => "Document region",
+        }
+    }
+
+    pub fn documenting_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 전체 서비스 목록 조회 중",
+            Language::English => "Listing every service in the region",
+        }
+    }
+
+    pub fn region_inventory_empty(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이 리전에 문서화할 리소스가 없습니다",
+            Language::English => "No resources to document in this region",
+        }
+    }
+
+    pub fn region_inventory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 인벤토리",
+            Language::English => "Region Inventory",
+        }
+    }
+
+    pub fn region_inventory_summary(&self, services: usize, resources: usize) -> String {
+        match self.lang {
+            Language::Korean => format!("서비스 {}개, 리소스 {}개", services, resources),
+            Language::English => format!("{} service(s), {} resource(s)", services, resources),
+        }
+    }
+
+    pub fn pin_service(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고정/해제",
+            Language::English => "Pin/Unpin",
+        }
+    }
+
+    // Write mode (--allow-write)
+    pub fn edit_name_tag(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Name 태그 편집",
+            Language::English => "Edit Name Tag",
+        }
+    }
+
+    pub fn enter_name_tag(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "새 Name 태그 값을 입력하세요:",
+            Language::English => "Enter the new Name tag value:",
+        }
+    }
+
+    pub fn write_mode_disabled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "읽기 전용 모드입니다. --allow-write로 실행하면 Name 태그를 편집할 수 있습니다"
+            }
+            Language::English => "Read-only mode. Start with --allow-write to edit Name tags",
+        }
+    }
+
+    pub fn setting_name_tag(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Name 태그 변경 중",
+            Language::English => "Updating Name tag",
+        }
+    }
+
+    pub fn confirm_name_tag(&self, resource_id: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{}의 Name 태그를 변경할까요?", resource_id),
+            Language::English => format!("Change the Name tag of {}?", resource_id),
+        }
+    }
+
+    pub fn name_tag_updated(&self, resource_id: &str, name: &str) -> String {
+        match self.lang {
+            Language::Korean => format!(
+                "{}의 Name 태그를 '{}'(으)로 변경했습니다",
+                resource_id, name
+            ),
+            Language::English => format!("Updated Name tag of {} to '{}'", resource_id, name),
+        }
+    }
+
+    pub fn name_tag_update_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("Name 태그 변경 실패: {}", error),
+            Language::English => format!("Failed to update Name tag: {}", error),
+        }
+    }
+
+    pub fn on(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "켜짐",
+            Language::English => "On",
+        }
+    }
+
+    pub fn off(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "꺼짐",
+            Language::English => "Off",
+        }
+    }
+
+    pub fn encryption_toggle_failed(&self, error: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("암호화 설정 변경 실패: {}", error),
+            Language::English => format!("Failed to change encryption: {}", error),
+        }
+    }
+
+    pub fn settings_saved(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "설정 저장 완료",
+            Language::English => "Settings saved",
+        }
+    }
+
+    pub fn blueprint_save_failed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "블루프린터 저장 실패",
+            Language::English => "Blueprint save failed",
+        }
+    }
+
+    pub fn change(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "변경",
+            Language::English => "Change",
+        }
+    }
+
+    // Table headers for markdown
+    pub fn item(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "항목",
+            Language::English => "Item",
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "값",
+            Language::English => "Value",
+        }
+    }
+
+    pub fn md_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이름",
+            Language::English => "Name",
+        }
+    }
+
+    pub fn md_state(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태",
+            Language::English => "State",
+        }
+    }
+
+    pub fn tag(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그",
+            Language::English => "Tag",
+        }
+    }
+
+    // Toc
+    pub fn toc(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "📑 목차",
+            Language::English => "📑 Table of Contents",
+        }
+    }
+
+    // Query failed
+    pub fn query_failed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조회 실패",
+            Language::English => "Query failed",
+        }
+    }
+
+    pub fn network_detail_unavailable(&self, vpc_id: &str) -> String {
+        match self.lang {
+            Language::Korean => {
+                format!(
+                    "{} 네트워크 상세 정보를 불러올 수 없습니다. 로그인 상태를 확인하세요.",
+                    vpc_id
+                )
+            }
+            Language::English => {
+                format!(
+                    "Network detail unavailable for {}. Please check AWS login status.",
+                    vpc_id
+                )
+            }
+        }
+    }
+
+    // VPC/Network markdown labels
+    pub fn md_dns_support(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DNS 지원",
+            Language::English => "DNS Support",
+        }
+    }
+
+    pub fn md_dns_hostnames(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DNS 호스트 이름",
+            Language::English => "DNS Hostnames",
+        }
+    }
+
+    pub fn md_owner_account(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "소유 계정",
+            Language::English => "Owner Account",
+        }
+    }
+
+    pub fn md_subnets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서브넷",
+            Language::English => "Subnets",
+        }
+    }
+
+    pub fn md_internet_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인터넷 게이트웨이",
+            Language::English => "Internet Gateway",
+        }
+    }
+
+    pub fn md_attached_vpc(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결된 VPC",
+            Language::English => "Attached VPC",
+        }
+    }
+
+    pub fn md_nat_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "NAT 게이트웨이",
+            Language::English => "NAT Gateway",
+        }
+    }
+
+    pub fn md_availability_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가용성 모드",
+            Language::English => "Availability Mode",
+        }
+    }
+
+    pub fn md_zonal(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "영역",
+            Language::English => "Zonal",
+        }
+    }
+
+    pub fn md_regional(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전별",
+            Language::English => "Regional",
+        }
+    }
+
+    pub fn md_ip_auto_scaling(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 자동 확장",
+            Language::English => "IP Auto Scaling",
+        }
+    }
+
+    pub fn md_zone_auto_provisioning(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "영역 자동 프로비저닝",
+            Language::English => "Zone Auto Provisioning",
+        }
+    }
+
+    pub fn md_enabled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "활성화",
+            Language::English => "Enabled",
+        }
+    }
+
+    pub fn md_disabled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비활성화",
+            Language::English => "Disabled",
+        }
+    }
+
+    pub fn md_subnet(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서브넷",
+            Language::English => "Subnet",
+        }
+    }
+
+    pub fn md_connectivity_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결 유형",
+            Language::English => "Connectivity Type",
+        }
+    }
+
+    pub fn md_public(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "퍼블릭",
+            Language::English => "Public",
+        }
+    }
+
+    pub fn md_private(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프라이빗",
+            Language::English => "Private",
+        }
+    }
+
+    pub fn md_elastic_ip_allocation_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "탄력적 IP 할당 ID",
+            Language::English => "Elastic IP Allocation ID",
+        }
+    }
+
+    pub fn md_route_tables(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우팅 테이블",
+            Language::English => "Route Tables",
+        }
+    }
+
+    pub fn md_destination(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상",
+            Language::English => "Destination",
+        }
+    }
+
+    pub fn md_target(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상",
+            Language::English => "Target",
+        }
+    }
+
+    pub fn md_all_ipv6(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "모든 IPv6 주소",
+            Language::English => "all IPv6 addresses",
+        }
+    }
+
+    pub fn md_usable_ips(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용 가능 IP",
+            Language::English => "Usable IPs",
+        }
+    }
+
+    pub fn md_ip_utilization(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 사용률",
+            Language::English => "IP Utilization",
+        }
+    }
+
+    pub fn md_subnet_overlap(&self, first: &str, second: &str) -> String {
+        match self.lang {
+            Language::Korean => format!("{} ↔ {}: 주소 범위가 겹칩니다", first, second),
+            Language::English => format!("{} ↔ {}: address ranges overlap", first, second),
+        }
+    }
+
+    pub fn md_associated_subnets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결된 서브넷:",
+            Language::English => "Associated Subnets:",
+        }
+    }
+
+    pub fn md_association(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결",
+            Language::English => "Association",
+        }
+    }
+
+    pub fn md_network_diagram(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 구성도",
+            Language::English => "Network Diagram",
+        }
+    }
+
+    // Security Group markdown labels
+    pub fn md_description(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "설명",
+            Language::English => "Description",
+        }
+    }
+
+    pub fn md_inbound_rules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인바운드 규칙",
             Language::English => "Inbound Rules",
         }
     }
 
-    pub fn md_outbound_rules(&self) -> &'static str {
+    pub fn md_outbound_rules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "아웃바운드 규칙",
+            Language::English => "Outbound Rules",
+        }
+    }
+
+    pub fn md_rule_number(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "규칙 번호",
+            Language::English => "Rule #",
+        }
+    }
+
+    pub fn md_allow(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "허용",
+            Language::English => "Allow",
+        }
+    }
+
+    pub fn md_deny(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "거부",
+            Language::English => "Deny",
+        }
+    }
+
+    pub fn md_protocol(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로토콜",
+            Language::English => "Protocol",
+        }
+    }
+
+    pub fn md_port_range(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "포트 범위",
+            Language::English => "Port Range",
+        }
+    }
+
+    pub fn md_source(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "소스",
+            Language::English => "Source",
+        }
+    }
+
+    // Load Balancer markdown labels
+    pub fn md_dns_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DNS 이름",
+            Language::English => "DNS Name",
+        }
+    }
+
+    pub fn md_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "타입",
+            Language::English => "Type",
+        }
+    }
+
+    pub fn md_ip_address_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 주소 유형",
+            Language::English => "IP Address Type",
+        }
+    }
+
+    pub fn md_port(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "포트",
+            Language::English => "Port",
+        }
+    }
+
+    pub fn md_default_action(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 액션",
+            Language::English => "Default Action",
+        }
+    }
+
+    pub fn md_basic_info(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 정보:",
+            Language::English => "Basic Info:",
+        }
+    }
+
+    // EC2 markdown labels
+    pub fn md_ec2_instance(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EC2 인스턴스",
+            Language::English => "EC2 Instance",
+        }
+    }
+
+    pub fn md_instance_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인스턴스 유형",
+            Language::English => "Instance Type",
+        }
+    }
+
+    pub fn md_platform(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "플랫폼",
+            Language::English => "Platform",
+        }
+    }
+
+    pub fn md_architecture(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "아키텍처",
+            Language::English => "Architecture",
+        }
+    }
+
+    pub fn md_key_pair(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 페어",
+            Language::English => "Key Pair",
+        }
+    }
+
+    pub fn md_availability_zone(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가용 영역",
+            Language::English => "Availability Zone",
+        }
+    }
+
+    pub fn md_availability_zones(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가용 영역",
+            Language::English => "Availability Zones",
+        }
+    }
+
+    pub fn md_private_ip(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프라이빗 IP",
+            Language::English => "Private IP",
+        }
+    }
+
+    pub fn md_public_ip(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "퍼블릭 IP",
+            Language::English => "Public IP",
+        }
+    }
+
+    pub fn md_security_groups(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "보안 그룹",
+            Language::English => "Security Groups",
+        }
+    }
+
+    pub fn md_ebs_optimized(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "EBS 최적화",
+            Language::English => "EBS Optimized",
+        }
+    }
+
+    pub fn md_monitoring(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "모니터링",
+            Language::English => "Monitoring",
+        }
+    }
+
+    pub fn md_iam_role(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 역할",
+            Language::English => "IAM Role",
+        }
+    }
+
+    pub fn md_iam_role_detail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 역할 상세",
+            Language::English => "IAM Role Detail",
+        }
+    }
+
+    pub fn md_attached_policies(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결된 정책 (Attached Policies)",
+            Language::English => "Attached Policies",
+        }
+    }
+
+    pub fn md_inline_policies(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인라인 정책 (Inline Policies)",
+            Language::English => "Inline Policies",
+        }
+    }
+
+    pub fn md_trust_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "신뢰 관계 (Trust Policy)",
+            Language::English => "Trust Policy",
+        }
+    }
+
+    pub fn md_policy_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정책 이름",
+            Language::English => "Policy Name",
+        }
+    }
+
+    pub fn md_launch_time(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 시간",
+            Language::English => "Launch Time",
+        }
+    }
+
+    pub fn md_storage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스토리지",
+            Language::English => "Storage",
+        }
+    }
+
+    pub fn md_device(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "디바이스",
+            Language::English => "Device",
+        }
+    }
+
+    pub fn md_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "크기",
+            Language::English => "Size",
+        }
+    }
+
+    pub fn md_encrypted(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "암호화",
+            Language::English => "Encrypted",
+        }
+    }
+
+    pub fn md_delete_on_termination(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "종료 시 삭제",
+            Language::English => "Delete on Termination",
+        }
+    }
+
+    pub fn md_instance_terminated_note(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "종료되었거나 종료 중인 인스턴스입니다. 네트워크, 스토리지, 사용자 데이터 정보가 없을 수 있습니다."
+            }
+            Language::English => {
+                "This instance is terminated or shutting down. Network, storage and user data details may be unavailable."
+            }
+        }
+    }
+
+    pub fn md_user_data(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용자 데이터",
+            Language::English => "User Data",
+        }
+    }
+
+    // ECR markdown labels
+    pub fn md_ecr_repository(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ECR 레포지토리",
+            Language::English => "ECR Repository",
+        }
+    }
+
+    pub fn md_tag_mutability(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그 변경 가능",
+            Language::English => "Tag Mutability",
+        }
+    }
+
+    pub fn md_uri(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "URI",
+            Language::English => "URI",
+        }
+    }
+
+    pub fn md_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "암호화",
+            Language::English => "Encryption",
+        }
+    }
+
+    pub fn md_image_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이미지 수",
+            Language::English => "Image Count",
+        }
+    }
+
+    pub fn md_created_at(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "생성일",
+            Language::English => "Created At",
+        }
+    }
+
+    pub fn ecr_encryption_kms(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AWS KMS",
+            Language::English => "AWS KMS",
+        }
+    }
+
+    pub fn ecr_encryption_aes256(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AES-256",
+            Language::English => "AES-256",
+        }
+    }
+
+    // Load Balancer extra labels
+    pub fn md_scheme(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스키마",
+            Language::English => "Scheme",
+        }
+    }
+
+    pub fn md_target_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상 유형",
+            Language::English => "Target Type",
+        }
+    }
+
+    pub fn md_health_check(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "헬스 체크",
+            Language::English => "Health Check",
+        }
+    }
+
+    pub fn md_threshold(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "임계값",
+            Language::English => "Threshold",
+        }
+    }
+
+    pub fn md_healthy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정상",
+            Language::English => "Healthy",
+        }
+    }
+
+    pub fn md_unhealthy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비정상",
+            Language::English => "Unhealthy",
+        }
+    }
+
+    pub fn md_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상:",
+            Language::English => "Targets:",
+        }
+    }
+
+    // RDS markdown labels
+    pub fn md_rds_instance(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "RDS 인스턴스",
+            Language::English => "RDS Instance",
+        }
+    }
+
+    pub fn md_rds_cluster(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "RDS 클러스터",
+            Language::English => "RDS Cluster",
+        }
+    }
+
+    pub fn md_engine(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔진",
+            Language::English => "Engine",
+        }
+    }
+
+    pub fn md_instance_class(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인스턴스 클래스",
+            Language::English => "Instance Class",
+        }
+    }
+
+    pub fn md_multi_az(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다중 AZ",
+            Language::English => "Multi-AZ",
+        }
+    }
+
+    pub fn md_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔드포인트",
+            Language::English => "Endpoint",
+        }
+    }
+
+    pub fn md_reader_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "읽기 엔드포인트",
+            Language::English => "Reader Endpoint",
+        }
+    }
+
+    pub fn md_db_subnet_group(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DB 서브넷 그룹",
+            Language::English => "DB Subnet Group",
+        }
+    }
+
+    pub fn md_parameter_group(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파라미터 그룹",
+            Language::English => "Parameter Group",
+        }
+    }
+
+    pub fn md_cluster_members(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클러스터 멤버",
+            Language::English => "Cluster Members",
+        }
+    }
+
+    pub fn md_role(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "역할",
+            Language::English => "Role",
+        }
+    }
+
+    // Lambda markdown labels
+    pub fn md_runtime(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "런타임",
+            Language::English => "Runtime",
+        }
+    }
+
+    pub fn md_handler(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "핸들러",
+            Language::English => "Handler",
+        }
+    }
+
+    pub fn md_memory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메모리",
+            Language::English => "Memory",
+        }
+    }
+
+    pub fn md_timeout(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "제한 시간",
+            Language::English => "Timeout",
+        }
+    }
+
+    pub fn md_last_modified(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 수정",
+            Language::English => "Last Modified",
+        }
+    }
+
+    pub fn md_environment_variables(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "환경 변수",
+            Language::English => "Environment Variables",
+        }
+    }
+
+    pub fn md_vpc_config(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 구성",
+            Language::English => "VPC Configuration",
+        }
+    }
+
+    pub fn md_layers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "레이어",
+            Language::English => "Layers",
+        }
+    }
+
+    pub fn md_triggers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "트리거",
+            Language::English => "Triggers",
+        }
+    }
+
+    pub fn md_batch_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "배치 크기",
+            Language::English => "Batch Size",
+        }
+    }
+
+    // DynamoDB markdown labels
+    pub fn md_partition_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파티션 키",
+            Language::English => "Partition Key",
+        }
+    }
+
+    pub fn md_sort_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정렬 키",
+            Language::English => "Sort Key",
+        }
+    }
+
+    pub fn md_capacity_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "용량 모드",
+            Language::English => "Capacity Mode",
+        }
+    }
+
+    pub fn md_on_demand(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "온디맨드",
+            Language::English => "On-demand",
+        }
+    }
+
+    pub fn md_provisioned(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로비저닝",
+            Language::English => "Provisioned",
+        }
+    }
+
+    pub fn md_table_class(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "테이블 클래스",
+            Language::English => "Table Class",
+        }
+    }
+
+    pub fn md_item_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "항목 수",
+            Language::English => "Item Count",
+        }
+    }
+
+    pub fn md_streams(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스트림",
+            Language::English => "Streams",
+        }
+    }
+
+    pub fn md_aws_owned_key(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AWS 소유 키",
+            Language::English => "AWS owned key",
+        }
+    }
+
+    pub fn md_deletion_protection(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "삭제 방지",
+            Language::English => "Deletion Protection",
+        }
+    }
+
+    pub fn md_global_secondary_indexes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "글로벌 보조 인덱스",
+            Language::English => "Global Secondary Indexes",
+        }
+    }
+
+    pub fn md_local_secondary_indexes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로컬 보조 인덱스",
+            Language::English => "Local Secondary Indexes",
+        }
+    }
+
+    pub fn md_index_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인덱스 이름",
+            Language::English => "Index Name",
+        }
+    }
+
+    pub fn md_projection(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로젝션",
+            Language::English => "Projection",
+        }
+    }
+
+    // CloudFront markdown labels
+    pub fn md_domain_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "도메인 이름",
+            Language::English => "Domain Name",
+        }
+    }
+
+    pub fn md_aliases(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대체 도메인",
+            Language::English => "Aliases",
+        }
+    }
+
+    pub fn md_price_class(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가격 등급",
+            Language::English => "Price Class",
+        }
+    }
+
+    pub fn md_default_root_object(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 루트 객체",
+            Language::English => "Default Root Object",
+        }
+    }
+
+    pub fn md_certificate(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인증서",
+            Language::English => "Certificate",
+        }
+    }
+
+    pub fn md_cloudfront_default_certificate(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFront 기본 인증서",
+            Language::English => "CloudFront default certificate",
+        }
+    }
+
+    pub fn md_origins(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "오리진",
+            Language::English => "Origins",
+        }
+    }
+
+    pub fn md_origin(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "오리진",
+            Language::English => "Origin",
+        }
+    }
+
+    pub fn md_origin_path(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "오리진 경로",
+            Language::English => "Origin Path",
+        }
+    }
+
+    pub fn md_behaviors(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "동작",
+            Language::English => "Behaviors",
+        }
+    }
+
+    pub fn md_path_pattern(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경로 패턴",
+            Language::English => "Path Pattern",
+        }
+    }
+
+    pub fn md_viewer_protocol(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "뷰어 프로토콜",
+            Language::English => "Viewer Protocol",
+        }
+    }
+
+    pub fn md_allowed_methods(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "허용 메서드",
+            Language::English => "Allowed Methods",
+        }
+    }
+
+    pub fn md_cache_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "캐시 정책",
+            Language::English => "Cache Policy",
+        }
+    }
+
+    pub fn md_default(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본",
+            Language::English => "Default",
+        }
+    }
+
+    // Route 53 markdown labels
+    pub fn md_record_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "레코드 수",
+            Language::English => "Record Count",
+        }
+    }
+
+    pub fn md_record_sets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "레코드 세트",
+            Language::English => "Record Sets",
+        }
+    }
+
+    pub fn md_record_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대상",
+            Language::English => "Targets",
+        }
+    }
+
+    pub fn md_alias(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "별칭",
+            Language::English => "Alias",
+        }
+    }
+
+    // EKS markdown labels
+    pub fn md_kubernetes_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Kubernetes 버전",
+            Language::English => "Kubernetes Version",
+        }
+    }
+
+    pub fn md_endpoint_access(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔드포인트 액세스",
+            Language::English => "Endpoint Access",
+        }
+    }
+
+    pub fn md_control_plane_logging(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "컨트롤 플레인 로깅",
+            Language::English => "Control Plane Logging",
+        }
+    }
+
+    pub fn md_node_groups(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드 그룹",
+            Language::English => "Node Groups",
+        }
+    }
+
+    pub fn md_capacity_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "용량 유형",
+            Language::English => "Capacity Type",
+        }
+    }
+
+    pub fn md_scaling_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최소 / 희망 / 최대",
+            Language::English => "Min / Desired / Max",
+        }
+    }
+
+    pub fn md_ami_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AMI 유형",
+            Language::English => "AMI Type",
+        }
+    }
+
+    pub fn md_fargate_profiles(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Fargate 프로필",
+            Language::English => "Fargate Profiles",
+        }
+    }
+
+    pub fn md_selectors(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "셀렉터",
+            Language::English => "Selectors",
+        }
+    }
+
+    pub fn md_addons(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "추가 기능",
+            Language::English => "Add-ons",
+        }
+    }
+
+    pub fn md_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "버전",
+            Language::English => "Version",
+        }
+    }
+
+    // ECS markdown labels
+    pub fn md_capacity_providers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "용량 공급자",
+            Language::English => "Capacity Providers",
+        }
+    }
+
+    pub fn md_container_instances(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "컨테이너 인스턴스",
+            Language::English => "Container Instances",
+        }
+    }
+
+    pub fn md_active_services(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "활성 서비스",
+            Language::English => "Active Services",
+        }
+    }
+
+    pub fn md_running_tasks(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행 중인 태스크",
+            Language::English => "Running Tasks",
+        }
+    }
+
+    pub fn md_pending_tasks(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대기 중인 태스크",
+            Language::English => "Pending Tasks",
+        }
+    }
+
+    pub fn md_services(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서비스",
+            Language::English => "Services",
+        }
+    }
+
+    pub fn md_launch_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 유형",
+            Language::English => "Launch Type",
+        }
+    }
+
+    pub fn md_task_definition(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태스크 정의",
+            Language::English => "Task Definition",
+        }
+    }
+
+    pub fn md_task_definitions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태스크 정의",
+            Language::English => "Task Definitions",
+        }
+    }
+
+    pub fn md_task_counts(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "희망 / 실행 / 대기",
+            Language::English => "Desired / Running / Pending",
+        }
+    }
+
+    pub fn md_task_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태스크 ID",
+            Language::English => "Task ID",
+        }
+    }
+
+    pub fn md_network_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네트워크 모드",
+            Language::English => "Network Mode",
+        }
+    }
+
+    pub fn md_task_role(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태스크 역할",
+            Language::English => "Task Role",
+        }
+    }
+
+    pub fn md_execution_role(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행 역할",
+            Language::English => "Execution Role",
+        }
+    }
+
+    pub fn md_container(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "컨테이너",
+            Language::English => "Container",
+        }
+    }
+
+    pub fn md_image(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이미지",
+            Language::English => "Image",
+        }
+    }
+
+    // SQS markdown labels
+    pub fn md_queue_url(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대기열 URL",
+            Language::English => "Queue URL",
+        }
+    }
+
+    pub fn md_standard(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "표준",
+            Language::English => "Standard",
+        }
+    }
+
+    pub fn md_content_based_deduplication(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "콘텐츠 기반 중복 제거",
+            Language::English => "Content-Based Deduplication",
+        }
+    }
+
+    pub fn md_visibility_timeout(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "표시 제한 시간",
+            Language::English => "Visibility Timeout",
+        }
+    }
+
+    pub fn md_message_retention(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메시지 보존 기간",
+            Language::English => "Message Retention",
+        }
+    }
+
+    pub fn md_delivery_delay(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전송 지연",
+            Language::English => "Delivery Delay",
+        }
+    }
+
+    pub fn md_receive_wait_time(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메시지 수신 대기 시간",
+            Language::English => "Receive Wait Time",
+        }
+    }
+
+    pub fn md_max_message_size(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최대 메시지 크기",
+            Language::English => "Maximum Message Size",
+        }
+    }
+
+    pub fn md_messages(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "메시지",
+            Language::English => "Messages",
+        }
+    }
+
+    pub fn md_messages_available(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용 가능",
+            Language::English => "Available",
+        }
+    }
+
+    pub fn md_messages_in_flight(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "처리 중",
+            Language::English => "In Flight",
+        }
+    }
+
+    pub fn md_messages_delayed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지연됨",
+            Language::English => "Delayed",
+        }
+    }
+
+    pub fn md_dead_letter_queue(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "배달 못한 편지 대기열",
+            Language::English => "Dead-Letter Queue",
+        }
+    }
+
+    pub fn md_max_receive_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최대 수신 수",
+            Language::English => "Maximum Receives",
+        }
+    }
+
+    // SNS markdown labels
+    pub fn md_display_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "표시 이름",
+            Language::English => "Display Name",
+        }
+    }
+
+    pub fn md_subscriptions_confirmed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "확인된 구독",
+            Language::English => "Confirmed Subscriptions",
+        }
+    }
+
+    pub fn md_subscriptions_pending(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대기 중인 구독",
+            Language::English => "Pending Subscriptions",
+        }
+    }
+
+    pub fn md_subscriptions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "구독",
+            Language::English => "Subscriptions",
+        }
+    }
+
+    pub fn md_filter_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "필터 정책",
+            Language::English => "Filter Policy",
+        }
+    }
+
+    pub fn md_raw_message_delivery(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원시 메시지 전송",
+            Language::English => "Raw Message Delivery",
+        }
+    }
+
+    pub fn md_access_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "액세스 정책",
+            Language::English => "Access Policy",
+        }
+    }
+
+    // ElastiCache markdown labels
+    pub fn md_replication_group(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "복제 그룹",
+            Language::English => "Replication Group",
+        }
+    }
+
+    pub fn md_node_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드 유형",
+            Language::English => "Node Type",
+        }
+    }
+
+    pub fn md_cluster_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클러스터 모드",
+            Language::English => "Cluster Mode",
+        }
+    }
+
+    pub fn md_automatic_failover(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 장애 조치",
+            Language::English => "Automatic Failover",
+        }
+    }
+
+    pub fn md_configuration_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "구성 엔드포인트",
+            Language::English => "Configuration Endpoint",
+        }
+    }
+
+    pub fn md_subnet_group(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서브넷 그룹",
+            Language::English => "Subnet Group",
+        }
+    }
+
+    pub fn md_transit_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전송 중 암호화",
+            Language::English => "Encryption in Transit",
+        }
+    }
+
+    pub fn md_at_rest_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "저장 데이터 암호화",
+            Language::English => "Encryption at Rest",
+        }
+    }
+
+    pub fn md_replication_topology(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "복제 그룹 토폴로지",
+            Language::English => "Replication Group Topology",
+        }
+    }
+
+    pub fn md_shard(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "샤드",
+            Language::English => "Shard",
+        }
+    }
+
+    pub fn md_slots(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "슬롯",
+            Language::English => "Slots",
+        }
+    }
+
+    pub fn md_primary_endpoint(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 엔드포인트",
+            Language::English => "Primary Endpoint",
+        }
+    }
+
+    pub fn md_node(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드",
+            Language::English => "Node",
+        }
+    }
+
+    pub fn md_nodes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드",
+            Language::English => "Nodes",
+        }
+    }
+
+    // CloudWatch Alarm markdown labels
+    pub fn md_metric_alarm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지표 경보",
+            Language::English => "Metric Alarm",
+        }
+    }
+
+    pub fn md_composite_alarm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "복합 경보",
+            Language::English => "Composite Alarm",
+        }
+    }
+
+    pub fn md_state_reason(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 사유",
+            Language::English => "State Reason",
+        }
+    }
+
+    pub fn md_state_updated(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 변경 시각",
+            Language::English => "State Updated",
+        }
+    }
+
+    pub fn md_metric(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지표",
+            Language::English => "Metric",
+        }
+    }
+
+    pub fn md_statistic(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "통계",
+            Language::English => "Statistic",
+        }
+    }
+
+    pub fn md_period(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기간",
+            Language::English => "Period",
+        }
+    }
+
+    pub fn md_datapoints_to_alarm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보 데이터 포인트",
+            Language::English => "Datapoints to Alarm",
+        }
+    }
+
+    pub fn md_missing_data(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "누락 데이터 처리",
+            Language::English => "Missing Data Treatment",
+        }
+    }
+
+    pub fn md_alarm_rule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보 규칙",
+            Language::English => "Alarm Rule",
+        }
+    }
+
+    pub fn md_actions_enabled(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "작업 활성화",
+            Language::English => "Actions Enabled",
+        }
+    }
+
+    pub fn md_dimensions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "차원",
+            Language::English => "Dimensions",
+        }
+    }
+
+    pub fn md_alarm_actions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경보 작업",
+            Language::English => "Alarm Actions",
+        }
+    }
+
+    pub fn md_action(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "작업",
+            Language::English => "Action",
+        }
+    }
+
+    // CloudTrail markdown labels
+    pub fn md_home_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "홈 리전",
+            Language::English => "Home Region",
+        }
+    }
+
+    pub fn md_logging(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로깅",
+            Language::English => "Logging",
+        }
+    }
+
+    pub fn md_latest_delivery(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최근 로그 전달",
+            Language::English => "Latest Delivery",
+        }
+    }
+
+    pub fn md_multi_region_trail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다중 리전 추적",
+            Language::English => "Multi-Region Trail",
+        }
+    }
+
+    pub fn md_organization_trail(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조직 추적",
+            Language::English => "Organization Trail",
+        }
+    }
+
+    pub fn md_global_service_events(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "글로벌 서비스 이벤트",
+            Language::English => "Global Service Events",
+        }
+    }
+
+    pub fn md_s3_destination(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "S3 대상",
+            Language::English => "S3 Destination",
+        }
+    }
+
+    pub fn md_log_file_validation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로그 파일 검증",
+            Language::English => "Log File Validation",
+        }
+    }
+
+    pub fn md_cloudwatch_logs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudWatch Logs",
+            Language::English => "CloudWatch Logs",
+        }
+    }
+
+    pub fn md_sns_notification(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "SNS 알림",
+            Language::English => "SNS Notification",
+        }
+    }
+
+    pub fn md_recent_events(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최근 관리 이벤트",
+            Language::English => "Recent Management Events",
+        }
+    }
+
+    pub fn md_event_time(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 시각",
+            Language::English => "Event Time",
+        }
+    }
+
+    pub fn md_event_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 이름",
+            Language::English => "Event Name",
+        }
+    }
+
+    pub fn md_event_source(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "이벤트 소스",
+            Language::English => "Event Source",
+        }
+    }
+
+    pub fn md_user(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용자",
+            Language::English => "User",
+        }
+    }
+
+    pub fn md_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스",
+            Language::English => "Resources",
+        }
+    }
+
+    pub fn md_modified_by(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "변경한 사용자",
+            Language::English => "Modified By",
+        }
+    }
+
+    pub fn md_modified_at(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "변경 시각",
+            Language::English => "Modified At",
+        }
+    }
+
+    pub fn md_modified_via(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "변경 경로",
+            Language::English => "Via",
+        }
+    }
+
+    pub fn md_aws_console(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "AWS 콘솔",
+            Language::English => "AWS Console",
+        }
+    }
+
+    pub fn md_no_recent_changes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최근 90일 동안 CloudTrail에 기록된 변경 이벤트가 없습니다.",
+            Language::English => "No change events recorded in CloudTrail in the last 90 days.",
+        }
+    }
+
+    // KMS markdown labels
+    pub fn md_key_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 ID",
+            Language::English => "Key ID",
+        }
+    }
+
+    pub fn md_key_spec(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 사양",
+            Language::English => "Key Spec",
+        }
+    }
+
+    pub fn md_key_usage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 용도",
+            Language::English => "Key Usage",
+        }
+    }
+
+    pub fn md_key_material_origin(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 구성 요소 출처",
+            Language::English => "Key Material Origin",
+        }
+    }
+
+    pub fn md_multi_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다중 리전",
+            Language::English => "Multi-Region",
+        }
+    }
+
+    pub fn md_deletion_date(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "삭제 예정일",
+            Language::English => "Scheduled Deletion",
+        }
+    }
+
+    pub fn md_key_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 키 교체",
+            Language::English => "Automatic Rotation",
+        }
+    }
+
+    pub fn md_rotation_period(&self, days: i32) -> String {
+        match self.lang {
+            Language::Korean => format!("{}일 주기", days),
+            Language::English => format!("every {} days", days),
+        }
+    }
+
+    pub fn md_next_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다음 교체",
+            Language::English => "Next Rotation",
+        }
+    }
+
+    pub fn md_not_supported(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지원 안 함",
+            Language::English => "Not supported",
+        }
+    }
+
+    pub fn md_key_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 정책",
+            Language::English => "Key Policy",
+        }
+    }
+
+    pub fn md_effect(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "효과",
+            Language::English => "Effect",
+        }
+    }
+
+    pub fn md_principal(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "주체",
+            Language::English => "Principal",
+        }
+    }
+
+    pub fn md_actions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "작업",
+            Language::English => "Actions",
+        }
+    }
+
+    pub fn md_condition(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조건",
+            Language::English => "Condition",
+        }
+    }
+
+    pub fn md_grants(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여",
+            Language::English => "Grants",
+        }
+    }
+
+    pub fn md_grantee(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "피부여자",
+            Language::English => "Grantee",
+        }
+    }
+
+    pub fn md_operations(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "허용 작업",
+            Language::English => "Operations",
+        }
+    }
+
+    pub fn md_retiring_principal(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "폐기 주체",
+            Language::English => "Retiring Principal",
+        }
+    }
+
+    // Secrets Manager markdown labels
+    pub fn md_secret_value_note(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비밀 값은 조회하지 않으며 메타데이터만 기록합니다.",
+            Language::English => "Secret values are not retrieved; only metadata is documented.",
+        }
+    }
+
+    pub fn md_owning_service(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "관리 서비스",
+            Language::English => "Managed By",
+        }
+    }
+
+    pub fn md_primary_region(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 리전",
+            Language::English => "Primary Region",
+        }
+    }
+
+    pub fn md_rotation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체",
+            Language::English => "Rotation",
+        }
+    }
+
+    pub fn md_rotation_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 일정",
+            Language::English => "Rotation Schedule",
+        }
+    }
+
+    pub fn md_rotation_window(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 기간",
+            Language::English => "Rotation Window",
+        }
+    }
+
+    pub fn md_rotation_function(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "교체 함수",
+            Language::English => "Rotation Function",
+        }
+    }
+
+    pub fn md_last_rotated(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 교체",
+            Language::English => "Last Rotated",
+        }
+    }
+
+    pub fn md_last_changed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 변경",
+            Language::English => "Last Changed",
+        }
+    }
+
+    pub fn md_last_accessed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 액세스",
+            Language::English => "Last Accessed",
+        }
+    }
+
+    pub fn md_replicas(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "복제본",
+            Language::English => "Replicas",
+        }
+    }
+
+    // SSM Parameter Store markdown labels
+    pub fn md_parameter_value_note(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파라미터 값은 조회하지 않으며 SecureString도 복호화하지 않습니다.",
+            Language::English => {
+                "Parameter values are not retrieved and SecureString values are never decrypted."
+            }
+        }
+    }
+
+    pub fn md_path(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경로",
+            Language::English => "Path",
+        }
+    }
+
+    pub fn md_parameters(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파라미터",
+            Language::English => "Parameters",
+        }
+    }
+
+    pub fn md_parameter_tree(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파라미터 트리",
+            Language::English => "Parameter Tree",
+        }
+    }
+
+    pub fn md_tier(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "티어",
+            Language::English => "Tier",
+        }
+    }
+
+    pub fn md_advanced_tier(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Advanced 티어",
+            Language::English => "Advanced Tier",
+        }
+    }
+
+    // EFS markdown labels
+    pub fn md_performance_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "성능 모드",
+            Language::English => "Performance Mode",
+        }
+    }
+
+    pub fn md_throughput_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "처리량 모드",
+            Language::English => "Throughput Mode",
+        }
+    }
+
+    pub fn md_lifecycle_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수명 주기 정책",
+            Language::English => "Lifecycle Policy",
+        }
+    }
+
+    pub fn md_transition(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전환",
+            Language::English => "Transition",
+        }
+    }
+
+    pub fn md_transition_to_ia(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IA로 전환",
+            Language::English => "To Infrequent Access",
+        }
+    }
+
+    pub fn md_transition_to_archive(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Archive로 전환",
+            Language::English => "To Archive",
+        }
+    }
+
+    pub fn md_transition_to_primary(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 스토리지로 전환",
+            Language::English => "To Primary Storage",
+        }
+    }
+
+    pub fn md_on_first_access(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "첫 액세스 시",
+            Language::English => "On first access",
+        }
+    }
+
+    pub fn md_after_days(&self, days: u32) -> String {
+        match self.lang {
+            Language::Korean => format!("{}일 후", days),
+            Language::English => format!("After {} day(s)", days),
+        }
+    }
+
+    pub fn md_mount_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마운트 대상",
+            Language::English => "Mount Targets",
+        }
+    }
+
+    pub fn md_mount_target(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마운트 대상 ID",
+            Language::English => "Mount Target",
+        }
+    }
+
+    pub fn md_ip_address(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 주소",
+            Language::English => "IP Address",
+        }
+    }
+
+    pub fn md_no_mount_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마운트 대상이 없습니다.",
+            Language::English => "No mount targets.",
+        }
+    }
+
+    // API Gateway markdown labels
+    pub fn md_endpoint_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔드포인트 유형",
+            Language::English => "Endpoint Type",
+        }
+    }
+
+    pub fn md_stages(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스테이지",
+            Language::English => "Stages",
+        }
+    }
+
+    pub fn md_stage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스테이지",
+            Language::English => "Stage",
+        }
+    }
+
+    pub fn md_deployment(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "배포 ID",
+            Language::English => "Deployment",
+        }
+    }
+
+    pub fn md_auto_deploy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 배포",
+            Language::English => "Auto Deploy",
+        }
+    }
+
+    pub fn md_last_updated(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 업데이트",
+            Language::English => "Last Updated",
+        }
+    }
+
+    pub fn md_invoke_url(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "호출 URL",
+            Language::English => "Invoke URL",
+        }
+    }
+
+    pub fn md_routes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우트",
+            Language::English => "Routes",
+        }
+    }
+
+    pub fn md_route(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우트",
+            Language::English => "Route",
+        }
+    }
+
+    pub fn md_authorization(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여",
+            Language::English => "Authorization",
+        }
+    }
+
+    pub fn md_integration(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "통합",
+            Language::English => "Integration",
+        }
+    }
+
+    pub fn md_no_routes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우트가 없습니다.",
+            Language::English => "No routes.",
+        }
+    }
+
+    pub fn md_authorizers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 부여자",
+            Language::English => "Authorizers",
+        }
+    }
+
+    pub fn md_identity_source(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자격 증명 소스",
+            Language::English => "Identity Source",
+        }
+    }
+
+    // Elastic Beanstalk markdown labels
+    pub fn md_application(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "애플리케이션",
+            Language::English => "Application",
+        }
+    }
+
+    pub fn md_health(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "헬스",
+            Language::English => "Health",
+        }
+    }
+
+    pub fn md_option_settings(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "옵션 설정",
+            Language::English => "Option Settings",
+        }
+    }
+
+    pub fn md_namespace(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "네임스페이스",
+            Language::English => "Namespace",
+        }
+    }
+
+    pub fn md_option(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "옵션",
+            Language::English => "Option",
+        }
+    }
+
+    pub fn md_associated_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결된 리소스",
+            Language::English => "Associated Resources",
+        }
+    }
+
+    // Step Functions markdown labels
+    pub fn md_include_execution_data(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "실행 데이터 포함",
+            Language::English => "Include Execution Data",
+        }
+    }
+
+    pub fn md_start_at(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "시작 상태",
+            Language::English => "Start State",
+        }
+    }
+
+    pub fn md_state_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 수",
+            Language::English => "States",
+        }
+    }
+
+    pub fn md_tracing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "X-Ray 추적",
+            Language::English => "X-Ray Tracing",
+        }
+    }
+
+    pub fn md_definition(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정의 (ASL)",
+            Language::English => "Definition (ASL)",
+        }
+    }
+
+    // Kinesis markdown labels
+    pub fn md_open_shards(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "열린 샤드",
+            Language::English => "Open Shards",
+        }
+    }
+
+    pub fn md_data_retention(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "데이터 보존 기간",
+            Language::English => "Data Retention",
+        }
+    }
+
+    pub fn md_enhanced_monitoring(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "확장 모니터링",
+            Language::English => "Enhanced Monitoring",
+        }
+    }
+
+    pub fn md_consumers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "소비자 (향상된 팬아웃)",
+            Language::English => "Consumers (Enhanced Fan-Out)",
+        }
+    }
+
+    pub fn md_hours(&self, hours: i32) -> String {
+        match self.lang {
+            Language::Korean => format!("{hours}시간"),
+            Language::English => format!("{hours}h"),
+        }
+    }
+
+    pub fn md_days(&self, days: i32) -> String {
+        match self.lang {
+            Language::Korean => format!("{days}일"),
+            Language::English => format!("{days}d"),
+        }
+    }
+
+    pub fn md_days_ago(&self, days: i64) -> String {
+        match (self.lang, days) {
+            (Language::Korean, ..=0) => "오늘".to_string(),
+            (Language::Korean, days) => format!("{}일 전", crate::locale::count(days)),
+            (Language::English, ..=0) => "today".to_string(),
+            (Language::English, 1) => "1 day ago".to_string(),
+            (Language::English, days) => format!("{} days ago", crate::locale::count(days)),
+        }
+    }
+
+    pub fn md_resource_age(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 경과 기간",
+            Language::English => "Resource Age",
+        }
+    }
+
+    pub fn md_age(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경과 기간",
+            Language::English => "Age",
+        }
+    }
+
+    pub fn md_share(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "비율",
+            Language::English => "Share",
+        }
+    }
+
+    pub fn md_oldest_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가장 오래된 리소스",
+            Language::English => "Oldest Resources",
+        }
+    }
+
+    pub fn md_blueprint_summary(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요약",
+            Language::English => "Summary",
+        }
+    }
+
+    pub fn md_regions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전",
+            Language::English => "Regions",
+        }
+    }
+
+    pub fn md_total_vcpu(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "총 vCPU (EC2)",
+            Language::English => "Total vCPU (EC2)",
+        }
+    }
+
+    pub fn md_total_memory(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "총 메모리 (EC2)",
+            Language::English => "Total Memory (EC2)",
+        }
+    }
+
+    pub fn md_unknown_instance_types(&self, count: usize) -> String {
+        match (self.lang, count) {
+            (Language::Korean, count) => format!("인스턴스 {count}개 유형 미확인"),
+            (Language::English, 1) => "1 instance of unknown type".to_string(),
+            (Language::English, count) => format!("{count} instances of unknown type"),
+        }
+    }
+
+    pub fn md_public_endpoints(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "퍼블릭 엔드포인트",
+            Language::English => "Public Endpoints",
+        }
+    }
+
+    pub fn md_resources_by_type(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "유형별 리소스",
+            Language::English => "Resources by Type",
+        }
+    }
+
+    pub fn md_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "개수",
+            Language::English => "Count",
+        }
+    }
+
+    /// Resource age histogram bucket; the last one holds resources without a creation date.
+    pub fn age_bucket_name(&self, bucket: usize) -> &'static str {
+        const KOREAN: [&str; 6] = [
+            "30일 미만",
+            "30~90일",
+            "90일~1년",
+            "1~2년",
+            "2년 이상",
+            "알 수 없음",
+        ];
+        const ENGLISH: [&str; 6] = [
+            "Under 30 days",
+            "30–90 days",
+            "90 days – 1 year",
+            "1–2 years",
+            "Over 2 years",
+            "Unknown",
+        ];
+        let bucket = bucket.min(5);
+        match self.lang {
+            Language::Korean => KOREAN[bucket],
+            Language::English => ENGLISH[bucket],
+        }
+    }
+
+    pub fn md_certificate_expiring_note(&self, days: i64) -> String {
+        match self.lang {
+            Language::Korean => format!("{days}일 후 만료되는 인증서입니다."),
+            Language::English => format!("This certificate expires in {days} days."),
+        }
+    }
+
+    pub fn md_certificate_expired_note(&self, days: i64) -> String {
+        match self.lang {
+            Language::Korean => format!("{days}일 전에 만료된 인증서입니다."),
+            Language::English => format!("This certificate expired {days} days ago."),
+        }
+    }
+
+    // MSK markdown labels
+    pub fn md_kafka_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Kafka 버전",
+            Language::English => "Kafka Version",
+        }
+    }
+
+    pub fn md_broker_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "브로커 수",
+            Language::English => "Brokers",
+        }
+    }
+
+    pub fn md_broker_storage(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "브로커당 EBS 스토리지",
+            Language::English => "EBS Storage per Broker",
+        }
+    }
+
+    pub fn md_provisioned_throughput(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프로비저닝된 스토리지 처리량",
+            Language::English => "Provisioned Storage Throughput",
+        }
+    }
+
+    pub fn md_storage_mode(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스토리지 모드",
+            Language::English => "Storage Mode",
+        }
+    }
+
+    pub fn md_client_broker_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전송 중 암호화 (클라이언트-브로커)",
+            Language::English => "Encryption in Transit (Client-Broker)",
+        }
+    }
+
+    pub fn md_in_cluster_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전송 중 암호화 (클러스터 내부)",
+            Language::English => "Encryption in Transit (In-Cluster)",
+        }
+    }
+
+    pub fn md_client_subnets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클라이언트 서브넷",
+            Language::English => "Client Subnets",
+        }
+    }
+
+    // OpenSearch markdown labels
+    pub fn md_cluster_config(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클러스터 구성",
+            Language::English => "Cluster Configuration",
+        }
+    }
+
+    pub fn md_data_nodes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "데이터 노드",
+            Language::English => "Data Nodes",
+        }
+    }
+
+    pub fn md_dedicated_master(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전용 마스터 노드",
+            Language::English => "Dedicated Master Nodes",
+        }
+    }
+
+    pub fn md_zone_awareness(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "영역 인식",
+            Language::English => "Zone Awareness",
+        }
+    }
+
+    pub fn md_ebs_per_node(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드당 EBS",
+            Language::English => "EBS per Node",
+        }
+    }
+
+    pub fn md_instance_store(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "인스턴스 스토어",
+            Language::English => "Instance Store",
+        }
+    }
+
+    pub fn md_security(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "보안",
+            Language::English => "Security",
+        }
+    }
+
+    pub fn md_enforce_https(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "HTTPS 필수",
+            Language::English => "Require HTTPS",
+        }
+    }
+
+    pub fn md_node_to_node_encryption(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드 간 암호화",
+            Language::English => "Node-to-Node Encryption",
+        }
+    }
+
+    pub fn md_vpc_endpoints(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPC 엔드포인트 (OpenSearch 관리형)",
+            Language::English => "VPC Endpoints (OpenSearch-managed)",
+        }
+    }
+
+    // Redshift markdown labels
+    pub fn md_node_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "노드 수",
+            Language::English => "Node Count",
+        }
+    }
+
+    pub fn md_database(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "데이터베이스",
+            Language::English => "Database",
+        }
+    }
+
+    pub fn md_master_username(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "관리자 사용자 이름",
+            Language::English => "Admin Username",
+        }
+    }
+
+    pub fn md_maintenance_window(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "유지 관리 기간",
+            Language::English => "Maintenance Window",
+        }
+    }
+
+    pub fn md_publicly_accessible(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "퍼블릭 액세스",
+            Language::English => "Publicly Accessible",
+        }
+    }
+
+    pub fn md_enhanced_vpc_routing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "향상된 VPC 라우팅",
+            Language::English => "Enhanced VPC Routing",
+        }
+    }
+
+    pub fn md_snapshots(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스냅샷",
+            Language::English => "Snapshots",
+        }
+    }
+
+    pub fn md_snapshot_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스냅샷 일정",
+            Language::English => "Snapshot Schedule",
+        }
+    }
+
+    pub fn md_default_snapshot_schedule(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 (8시간 또는 노드당 5GB 변경마다)",
+            Language::English => "Default (every 8 hours or 5 GB per node of changes)",
+        }
+    }
+
+    pub fn md_next_snapshot(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다음 스냅샷 예정",
+            Language::English => "Next Scheduled Snapshot",
+        }
+    }
+
+    pub fn md_automated_snapshot_retention(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "자동 스냅샷 보관 기간",
+            Language::English => "Automated Snapshot Retention",
+        }
+    }
+
+    pub fn md_manual_snapshot_retention(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수동 스냅샷 보관 기간",
+            Language::English => "Manual Snapshot Retention",
+        }
+    }
+
+    pub fn md_indefinitely(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "무기한",
+            Language::English => "Indefinitely",
+        }
+    }
+
+    pub fn md_cross_region_snapshot_copy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리전 간 스냅샷 복사",
+            Language::English => "Cross-Region Snapshot Copy",
+        }
+    }
+
+    pub fn md_iam_roles(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 역할",
+            Language::English => "IAM Roles",
+        }
+    }
+
+    // Transit Gateway markdown labels
+    pub fn md_amazon_side_asn(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Amazon 측 ASN",
+            Language::English => "Amazon Side ASN",
+        }
+    }
+
+    pub fn md_vpn_ecmp_support(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPN ECMP 지원",
+            Language::English => "VPN ECMP Support",
+        }
+    }
+
+    pub fn md_auto_accept_shared_attachments(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "공유 연결 자동 수락",
+            Language::English => "Auto Accept Shared Attachments",
+        }
+    }
+
+    pub fn md_default_association_route_table(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 연결 라우팅 테이블",
+            Language::English => "Default Association Route Table",
+        }
+    }
+
+    pub fn md_default_propagation_route_table(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 전파 라우팅 테이블",
+            Language::English => "Default Propagation Route Table",
+        }
+    }
+
+    pub fn md_tgw_attachments(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Transit Gateway 연결",
+            Language::English => "Transit Gateway Attachments",
+        }
+    }
+
+    pub fn md_tgw_routes_truncated(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우트가 더 있어 일부만 표시합니다.",
+            Language::English => "More routes exist; only the first page is shown.",
+        }
+    }
+
+    // VPC Peering markdown labels
+    pub fn md_requester(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요청자",
+            Language::English => "Requester",
+        }
+    }
+
+    pub fn md_accepter(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수락자",
+            Language::English => "Accepter",
+        }
+    }
+
+    pub fn md_remote_dns_resolution(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원격 VPC DNS 확인",
+            Language::English => "DNS Resolution from Remote VPC",
+        }
+    }
+
+    pub fn md_status_message(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상태 메시지",
+            Language::English => "Status Message",
+        }
+    }
+
+    pub fn md_expiration_time(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "만료 시각",
+            Language::English => "Expiration Time",
+        }
+    }
+
+    // Site-to-Site VPN markdown labels
+    pub fn md_customer_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고객 게이트웨이",
+            Language::English => "Customer Gateway",
+        }
+    }
+
+    pub fn md_virtual_private_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가상 프라이빗 게이트웨이",
+            Language::English => "Virtual Private Gateway",
+        }
+    }
+
+    pub fn md_tunnels(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "터널",
+            Language::English => "Tunnels",
+        }
+    }
+
+    pub fn md_outside_ip(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "외부 IP",
+            Language::English => "Outside IP",
+        }
+    }
+
+    pub fn md_inside_cidr(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "내부 CIDR",
+            Language::English => "Inside CIDR",
+        }
+    }
+
+    pub fn md_accepted_routes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "수신 경로 수",
+            Language::English => "Accepted Routes",
+        }
+    }
+
+    pub fn md_static_routes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정적 경로",
+            Language::English => "Static Routes",
+        }
+    }
+
+    pub fn md_routing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "라우팅",
+            Language::English => "Routing",
+        }
+    }
+
+    pub fn md_static_routing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정적",
+            Language::English => "Static",
+        }
+    }
+
+    pub fn md_dynamic_routing(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "동적 (BGP)",
+            Language::English => "Dynamic (BGP)",
+        }
+    }
+
+    pub fn md_bgp_asn(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "BGP ASN",
+            Language::English => "BGP ASN",
+        }
+    }
+
+    pub fn md_vpn_connections(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VPN 연결",
+            Language::English => "VPN Connections",
+        }
+    }
+
+    pub fn md_acceleration(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가속",
+            Language::English => "Acceleration",
+        }
+    }
+
+    pub fn md_local_network_cidr(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "로컬 네트워크 CIDR",
+            Language::English => "Local Network CIDR",
+        }
+    }
+
+    pub fn md_remote_network_cidr(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "원격 네트워크 CIDR",
+            Language::English => "Remote Network CIDR",
+        }
+    }
+
+    // Direct Connect markdown labels
+    pub fn md_location(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "위치",
+            Language::English => "Location",
+        }
+    }
+
+    pub fn md_bandwidth(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대역폭",
+            Language::English => "Bandwidth",
+        }
+    }
+
+    pub fn md_vlan(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "VLAN",
+            Language::English => "VLAN",
+        }
+    }
+
+    pub fn md_partner(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "파트너",
+            Language::English => "Partner",
+        }
+    }
+
+    pub fn md_provider(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "공급자",
+            Language::English => "Provider",
+        }
+    }
+
+    pub fn md_jumbo_frames(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "점보 프레임",
+            Language::English => "Jumbo Frames",
+        }
+    }
+
+    pub fn md_connection(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결",
+            Language::English => "Connection",
+        }
+    }
+
+    pub fn md_virtual_interface(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가상 인터페이스",
+            Language::English => "Virtual Interface",
+        }
+    }
+
+    pub fn md_virtual_interfaces(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "가상 인터페이스",
+            Language::English => "Virtual Interfaces",
+        }
+    }
+
+    pub fn md_mtu(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MTU",
+            Language::English => "MTU",
+        }
+    }
+
+    pub fn md_direct_connect_gateway(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Direct Connect 게이트웨이",
+            Language::English => "Direct Connect Gateway",
+        }
+    }
+
+    pub fn md_bgp_peers(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "BGP 피어",
+            Language::English => "BGP Peers",
+        }
+    }
+
+    pub fn md_bgp_status(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "BGP 상태",
+            Language::English => "BGP Status",
+        }
+    }
+
+    pub fn md_address_family(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "주소 패밀리",
+            Language::English => "Address Family",
+        }
+    }
+
+    pub fn md_amazon_address(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "Amazon 주소",
+            Language::English => "Amazon Address",
+        }
+    }
+
+    pub fn md_customer_address(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고객 주소",
+            Language::English => "Customer Address",
+        }
+    }
+
+    pub fn md_route_filter_prefixes(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "경로 필터 접두사",
+            Language::English => "Route Filter Prefixes",
+        }
+    }
+
+    // WAF markdown labels
+    pub fn md_scope(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "범위",
+            Language::English => "Scope",
+        }
+    }
+
+    pub fn md_capacity(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "용량 (WCU)",
+            Language::English => "Capacity (WCU)",
+        }
+    }
+
+    pub fn md_metric_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "지표 이름",
+            Language::English => "Metric Name",
+        }
+    }
+
+    pub fn md_sampled_requests(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "샘플 요청",
+            Language::English => "Sampled Requests",
+        }
+    }
+
+    pub fn md_rules(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "규칙",
+            Language::English => "Rules",
+        }
+    }
+
+    pub fn md_priority(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "우선순위",
+            Language::English => "Priority",
+        }
+    }
+
+    pub fn md_statement(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "조건",
+            Language::English => "Statement",
+        }
+    }
+
+    pub fn md_resource(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스",
+            Language::English => "Resource",
+        }
+    }
+
+    // ACM markdown labels
+    pub fn md_acm_certificate(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "ACM 인증서",
+            Language::English => "ACM Certificate",
+        }
+    }
+
+    pub fn md_subject_alternative_names(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "대체 도메인 이름 (SAN)",
+            Language::English => "Subject Alternative Names",
+        }
+    }
+
+    pub fn md_validation_method(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "검증 방법",
+            Language::English => "Validation Method",
+        }
+    }
+
+    pub fn md_key_algorithm(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "키 알고리즘",
+            Language::English => "Key Algorithm",
+        }
+    }
+
+    pub fn md_issuer(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "발급자",
+            Language::English => "Issuer",
+        }
+    }
+
+    pub fn md_valid_from(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "유효 기간 시작",
+            Language::English => "Valid From",
+        }
+    }
+
+    pub fn md_valid_until(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "유효 기간 종료",
+            Language::English => "Valid Until",
+        }
+    }
+
+    pub fn md_renewal_eligibility(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "갱신 자격",
+            Language::English => "Renewal Eligibility",
+        }
+    }
+
+    pub fn md_renewal_status(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "갱신 상태",
+            Language::English => "Renewal Status",
+        }
+    }
+
+    pub fn md_domain_validation(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "도메인 검증",
+            Language::English => "Domain Validation",
+        }
+    }
+
+    pub fn md_dns_record(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "DNS 레코드",
+            Language::English => "DNS Record",
+        }
+    }
+
+    pub fn md_in_use_by(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용 중인 리소스",
+            Language::English => "In Use By",
+        }
+    }
+
+    pub fn md_not_in_use(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용 중이 아님",
+            Language::English => "Not in use",
+        }
+    }
+
+    // CloudFormation markdown labels
+    pub fn md_cloudformation_stack(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudFormation 스택",
+            Language::English => "CloudFormation Stack",
+        }
+    }
+
+    pub fn md_stack_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스택 ID",
+            Language::English => "Stack ID",
+        }
+    }
+
+    pub fn md_parent_stack(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "상위 스택",
+            Language::English => "Parent Stack",
+        }
+    }
+
+    pub fn md_termination_protection(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "종료 방지",
+            Language::English => "Termination Protection",
+        }
+    }
+
+    pub fn md_drift_status(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "드리프트 상태",
+            Language::English => "Drift Status",
+        }
+    }
+
+    pub fn md_outputs(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "출력",
+            Language::English => "Outputs",
+        }
+    }
+
+    pub fn md_export_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "내보내기 이름",
+            Language::English => "Export Name",
+        }
+    }
+
+    pub fn md_logical_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "논리적 ID",
+            Language::English => "Logical ID",
+        }
+    }
+
+    pub fn md_physical_id(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "물리적 ID",
+            Language::English => "Physical ID",
+        }
+    }
+
+    // IAM markdown labels
+    pub fn md_iam_user(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 사용자",
+            Language::English => "IAM User",
+        }
+    }
+
+    pub fn md_iam_group(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 그룹",
+            Language::English => "IAM Group",
+        }
+    }
+
+    pub fn md_iam_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 정책",
+            Language::English => "IAM Policy",
+        }
+    }
+
+    pub fn md_permissions_boundary(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "권한 경계",
+            Language::English => "Permissions Boundary",
+        }
+    }
+
+    pub fn md_attachment_count(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결된 엔터티 수",
+            Language::English => "Attachment Count",
+        }
+    }
+
+    pub fn md_groups(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "그룹",
+            Language::English => "Groups",
+        }
+    }
+
+    pub fn md_members(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "멤버",
+            Language::English => "Members",
+        }
+    }
+
+    pub fn md_access_keys(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "액세스 키",
+            Language::English => "Access Keys",
+        }
+    }
+
+    pub fn md_policy_document(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "정책 문서",
+            Language::English => "Policy Document",
+        }
+    }
+
+    // VPC Endpoint markdown labels
+    pub fn md_service_name(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서비스 이름",
+            Language::English => "Service Name",
+        }
+    }
+
+    pub fn md_private_dns(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "프라이빗 DNS",
+            Language::English => "Private DNS",
+        }
+    }
+
+    pub fn md_endpoint_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔드포인트 정책",
+            Language::English => "Endpoint Policy",
+        }
+    }
+
+    pub fn md_full_access(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "전체 액세스",
+            Language::English => "Full Access",
+        }
+    }
+
+    pub fn md_custom(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용자 지정",
+            Language::English => "Custom",
+        }
+    }
+
+    pub fn md_subnets_or_route_tables(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "서브넷 / 라우팅 테이블",
+            Language::English => "Subnets / Route Tables",
+        }
+    }
+
+    // Network Interface markdown labels
+    pub fn md_mac_address(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "MAC 주소",
+            Language::English => "MAC Address",
+        }
+    }
+
+    pub fn md_attachment(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결",
+            Language::English => "Attachment",
+        }
+    }
+
+    pub fn md_attached_to(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연결 대상",
+            Language::English => "Attached To",
+        }
+    }
+
+    pub fn md_device_index(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "디바이스 인덱스",
+            Language::English => "Device Index",
+        }
+    }
+
+    pub fn md_ip_addresses(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IP 주소",
+            Language::English => "IP Addresses",
+        }
+    }
+
+    pub fn md_primary(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본",
+            Language::English => "Primary",
+        }
+    }
+
+    pub fn md_requester_managed(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요청자 관리",
+            Language::English => "Requester Managed",
+        }
+    }
+
+    pub fn md_unattached_interface_note(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => {
+                "어디에도 연결되지 않은 인터페이스입니다. 사용하지 않으면 삭제를 검토하세요."
+            }
+            Language::English => {
+                "This interface is not attached to any resource. Consider deleting it if it is unused."
+            }
+        }
+    }
+
+    // Launch Template markdown labels
+    pub fn md_latest(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최신",
+            Language::English => "Latest",
+        }
+    }
+
+    pub fn md_default_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "기본 버전",
+            Language::English => "Default Version",
+        }
+    }
+
+    pub fn md_latest_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "최신 버전",
+            Language::English => "Latest Version",
+        }
+    }
+
+    pub fn md_created_by(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "생성자",
+            Language::English => "Created By",
+        }
+    }
+
+    pub fn md_iam_instance_profile(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "IAM 인스턴스 프로파일",
+            Language::English => "IAM Instance Profile",
+        }
+    }
+
+    // AMI markdown labels
+    pub fn md_source_instance(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "소스 인스턴스",
+            Language::English => "Source Instance",
+        }
+    }
+
+    pub fn md_root_device(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "루트 디바이스",
+            Language::English => "Root Device",
+        }
+    }
+
+    pub fn md_deprecation_time(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "사용 중단 시각",
+            Language::English => "Deprecation Time",
+        }
+    }
+
+    pub fn md_snapshot(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스냅샷",
+            Language::English => "Snapshot",
+        }
+    }
+
+    pub fn md_launch_permissions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "공유 권한",
+            Language::English => "Sharing Permissions",
+        }
+    }
+
+    pub fn md_account(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "아웃바운드 규칙",
-            Language::English => "Outbound Rules",
+            Language::Korean => "계정",
+            Language::English => "Account",
         }
     }
 
-    pub fn md_protocol(&self) -> &'static str {
+    pub fn md_organization(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "프로토콜",
-            Language::English => "Protocol",
+            Language::Korean => "조직",
+            Language::English => "Organization",
         }
     }
 
-    pub fn md_port_range(&self) -> &'static str {
+    pub fn md_organizational_unit(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "포트 범위",
-            Language::English => "Port Range",
+            Language::Korean => "조직 단위",
+            Language::English => "Organizational Unit",
         }
     }
 
-    pub fn md_source(&self) -> &'static str {
+    pub fn md_not_shared(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "소스",
-            Language::English => "Source",
+            Language::Korean => "공유되지 않음 (소유 계정만 사용 가능)",
+            Language::English => "Not shared (owning account only)",
         }
     }
 
-    // Load Balancer markdown labels
-    pub fn md_dns_name(&self) -> &'static str {
+    // EBS markdown labels
+    pub fn md_volume(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "DNS 이름",
-            Language::English => "DNS Name",
+            Language::Korean => "볼륨",
+            Language::English => "Volume",
         }
     }
 
-    pub fn md_type(&self) -> &'static str {
+    pub fn md_throughput(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "타입",
-            Language::English => "Type",
+            Language::Korean => "처리량",
+            Language::English => "Throughput",
         }
     }
 
-    pub fn md_ip_address_type(&self) -> &'static str {
+    pub fn md_multi_attach(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "IP 주소 유형",
-            Language::English => "IP Address Type",
+            Language::Korean => "다중 연결",
+            Language::English => "Multi-Attach",
         }
     }
 
-    pub fn md_port(&self) -> &'static str {
+    pub fn md_source_snapshot(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "포트",
-            Language::English => "Port",
+            Language::Korean => "원본 스냅샷",
+            Language::English => "Source Snapshot",
         }
     }
 
-    pub fn md_default_action(&self) -> &'static str {
+    pub fn md_source_volume(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "기본 액션",
-            Language::English => "Default Action",
+            Language::Korean => "원본 볼륨",
+            Language::English => "Source Volume",
         }
     }
 
-    pub fn md_basic_info(&self) -> &'static str {
+    pub fn md_progress(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "기본 정보:",
-            Language::English => "Basic Info:",
+            Language::Korean => "진행률",
+            Language::English => "Progress",
         }
     }
 
-    // EC2 markdown labels
-    pub fn md_ec2_instance(&self) -> &'static str {
+    pub fn md_attached_at(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "EC2 인스턴스",
-            Language::English => "EC2 Instance",
+            Language::Korean => "연결 시각",
+            Language::English => "Attached At",
         }
     }
 
-    pub fn md_instance_type(&self) -> &'static str {
+    pub fn md_unattached_volume_note(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인스턴스 유형",
-            Language::English => "Instance Type",
+            Language::Korean => {
+                "어떤 인스턴스에도 연결되지 않은 볼륨입니다. 사용하지 않으면 스냅샷을 남기고 삭제를 검토하세요."
+            }
+            Language::English => {
+                "This volume is not attached to any instance. Consider snapshotting and deleting it if it is unused."
+            }
         }
     }
 
-    pub fn md_platform(&self) -> &'static str {
+    // 키 페어 markdown labels
+    pub fn md_key_type(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "플랫폼",
-            Language::English => "Platform",
+            Language::Korean => "키 유형",
+            Language::English => "Key Type",
         }
     }
 
-    pub fn md_architecture(&self) -> &'static str {
+    pub fn md_fingerprint(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "아키텍처",
-            Language::English => "Architecture",
+            Language::Korean => "지문",
+            Language::English => "Fingerprint",
         }
     }
 
-    pub fn md_key_pair(&self) -> &'static str {
+    pub fn md_public_key(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "키 페어",
-            Language::English => "Key Pair",
+            Language::Korean => "공개 키",
+            Language::English => "Public Key",
         }
     }
 
-    pub fn md_availability_zone(&self) -> &'static str {
+    // EventBridge markdown labels
+    pub fn md_trigger(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "가용 영역",
-            Language::English => "Availability Zone",
+            Language::Korean => "트리거",
+            Language::English => "Trigger",
         }
     }
 
-    pub fn md_availability_zones(&self) -> &'static str {
+    pub fn md_event_pattern(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "가용 영역",
-            Language::English => "Availability Zones",
+            Language::Korean => "이벤트 패턴",
+            Language::English => "Event Pattern",
         }
     }
 
-    pub fn md_private_ip(&self) -> &'static str {
+    pub fn md_managed_by(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "프라이빗 IP",
-            Language::English => "Private IP",
+            Language::Korean => "관리 주체",
+            Language::English => "Managed By",
         }
     }
 
-    pub fn md_public_ip(&self) -> &'static str {
+    pub fn md_input(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "퍼블릭 IP",
-            Language::English => "Public IP",
+            Language::Korean => "입력",
+            Language::English => "Input",
         }
     }
 
-    pub fn md_security_groups(&self) -> &'static str {
+    pub fn md_matched_event(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "보안 그룹",
-            Language::English => "Security Groups",
+            Language::Korean => "일치한 이벤트",
+            Language::English => "Matched event",
         }
     }
 
-    pub fn md_ebs_optimized(&self) -> &'static str {
+    pub fn md_input_transformer(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "EBS 최적화",
-            Language::English => "EBS Optimized",
+            Language::Korean => "입력 변환기",
+            Language::English => "Input Transformer",
         }
     }
 
-    pub fn md_monitoring(&self) -> &'static str {
+    // Cognito markdown labels
+    pub fn cognito_user_pool(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "모니터링",
-            Language::English => "Monitoring",
+            Language::Korean => "Cognito 사용자 풀",
+            Language::English => "Cognito User Pool",
         }
     }
 
-    pub fn md_iam_role(&self) -> &'static str {
+    pub fn cognito_identity_pool(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "IAM 역할",
-            Language::English => "IAM Role",
+            Language::Korean => "Cognito 자격 증명 풀",
+            Language::English => "Cognito Identity Pool",
         }
     }
 
-    pub fn md_iam_role_detail(&self) -> &'static str {
+    pub fn md_estimated_users(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "IAM 역할 상세",
-            Language::English => "IAM Role Detail",
+            Language::Korean => "예상 사용자 수",
+            Language::English => "Estimated Users",
         }
     }
 
-    pub fn md_attached_policies(&self) -> &'static str {
+    pub fn md_sign_in_attributes(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "연결된 정책 (Attached Policies)",
-            Language::English => "Attached Policies",
+            Language::Korean => "로그인 속성",
+            Language::English => "Sign-in Attributes",
         }
     }
 
-    pub fn md_inline_policies(&self) -> &'static str {
+    pub fn md_user_pool_domain(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "인라인 정책 (Inline Policies)",
-            Language::English => "Inline Policies",
+            Language::Korean => "도메인",
+            Language::English => "Domains",
         }
     }
 
-    pub fn md_trust_policy(&self) -> &'static str {
+    pub fn md_custom_domain(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "신뢰 관계 (Trust Policy)",
-            Language::English => "Trust Policy",
+            Language::Korean => "사용자 지정 도메인",
+            Language::English => "Custom domain",
         }
     }
 
-    pub fn md_policy_name(&self) -> &'static str {
+    pub fn md_cognito_prefix_domain(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "정책 이름",
-            Language::English => "Policy Name",
+            Language::Korean => "Cognito 도메인 (접두사)",
+            Language::English => "Cognito domain (prefix)",
         }
     }
 
-    pub fn md_launch_time(&self) -> &'static str {
+    pub fn md_app_clients(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "시작 시간",
-            Language::English => "Launch Time",
+            Language::Korean => "앱 클라이언트",
+            Language::English => "App Clients",
         }
     }
 
-    pub fn md_storage(&self) -> &'static str {
+    pub fn md_client_id(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "스토리지",
-            Language::English => "Storage",
+            Language::Korean => "클라이언트 ID",
+            Language::English => "Client ID",
         }
     }
 
-    pub fn md_device(&self) -> &'static str {
+    pub fn md_client_secret(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "디바이스",
-            Language::English => "Device",
+            Language::Korean => "클라이언트 보안 암호",
+            Language::English => "Client Secret",
         }
     }
 
-    pub fn md_size(&self) -> &'static str {
+    pub fn md_auth_flows(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "크기",
-            Language::English => "Size",
+            Language::Korean => "인증 흐름",
+            Language::English => "Auth Flows",
         }
     }
 
-    pub fn md_encrypted(&self) -> &'static str {
+    pub fn md_token_validity(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "암호화",
-            Language::English => "Encrypted",
+            Language::Korean => "토큰 유효 기간 (액세스 / ID / 새로 고침)",
+            Language::English => "Token Validity (Access / ID / Refresh)",
         }
     }
 
-    pub fn md_delete_on_termination(&self) -> &'static str {
+    pub fn md_oauth_flows(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "종료 시 삭제",
-            Language::English => "Delete on Termination",
+            Language::Korean => "OAuth 권한 부여 유형",
+            Language::English => "OAuth Flows",
         }
     }
 
-    pub fn md_user_data(&self) -> &'static str {
+    pub fn md_oauth_scopes(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "사용자 데이터",
-            Language::English => "User Data",
+            Language::Korean => "OAuth 범위",
+            Language::English => "OAuth Scopes",
         }
     }
 
-    // ECR markdown labels
-    pub fn md_ecr_repository(&self) -> &'static str {
+    pub fn md_identity_providers(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "ECR 레포지토리",
-            Language::English => "ECR Repository",
+            Language::Korean => "자격 증명 공급자",
+            Language::English => "Identity Providers",
         }
     }
 
-    pub fn md_tag_mutability(&self) -> &'static str {
+    pub fn md_callback_urls(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "태그 변경 가능",
-            Language::English => "Tag Mutability",
+            Language::Korean => "콜백 URL",
+            Language::English => "Callback URLs",
         }
     }
 
-    pub fn md_uri(&self) -> &'static str {
+    pub fn md_sign_out_urls(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "URI",
-            Language::English => "URI",
+            Language::Korean => "로그아웃 URL",
+            Language::English => "Sign-out URLs",
         }
     }
 
-    pub fn md_encryption(&self) -> &'static str {
+    pub fn md_guest_access(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "암호화",
-            Language::English => "Encryption",
+            Language::Korean => "게스트 액세스",
+            Language::English => "Guest Access",
         }
     }
 
-    pub fn md_image_count(&self) -> &'static str {
+    pub fn md_basic_auth_flow(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "이미지 수",
-            Language::English => "Image Count",
+            Language::Korean => "기본(클래식) 인증 흐름",
+            Language::English => "Basic (Classic) Auth Flow",
         }
     }
 
-    pub fn md_created_at(&self) -> &'static str {
+    pub fn md_authenticated_role(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "생성일",
-            Language::English => "Created At",
+            Language::Korean => "인증된 역할",
+            Language::English => "Authenticated Role",
         }
     }
 
-    pub fn ecr_encryption_kms(&self) -> &'static str {
+    pub fn md_guest_role(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "AWS KMS",
-            Language::English => "AWS KMS",
+            Language::Korean => "게스트 역할",
+            Language::English => "Guest Role",
         }
     }
 
-    pub fn ecr_encryption_aes256(&self) -> &'static str {
+    // Glue markdown labels
+    pub fn glue_database(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "AES-256",
-            Language::English => "AES-256",
+            Language::Korean => "Glue 데이터베이스",
+            Language::English => "Glue Database",
         }
     }
 
-    // Load Balancer extra labels
-    pub fn md_scheme(&self) -> &'static str {
+    pub fn glue_crawler(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "스키마",
-            Language::English => "Scheme",
+            Language::Korean => "Glue 크롤러",
+            Language::English => "Glue Crawler",
         }
     }
 
-    pub fn md_target_type(&self) -> &'static str {
+    pub fn md_tables(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "대상 유형",
-            Language::English => "Target Type",
+            Language::Korean => "테이블",
+            Language::English => "Tables",
         }
     }
 
-    pub fn md_health_check(&self) -> &'static str {
+    pub fn md_data_format(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "헬스 체크",
-            Language::English => "Health Check",
+            Language::Korean => "데이터 형식",
+            Language::English => "Format",
         }
     }
 
-    pub fn md_threshold(&self) -> &'static str {
+    pub fn md_columns(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "임계값",
-            Language::English => "Threshold",
+            Language::Korean => "열",
+            Language::English => "Columns",
         }
     }
 
-    pub fn md_healthy(&self) -> &'static str {
+    pub fn md_partition_keys(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "정상",
-            Language::English => "Healthy",
+            Language::Korean => "파티션 키",
+            Language::English => "Partition Keys",
         }
     }
 
-    pub fn md_unhealthy(&self) -> &'static str {
+    pub fn md_table_prefix(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "비정상",
-            Language::English => "Unhealthy",
+            Language::Korean => "테이블 접두사",
+            Language::English => "Table Prefix",
         }
     }
 
-    pub fn md_targets(&self) -> &'static str {
+    pub fn md_last_crawl(&self) -> &'static str {
         match self.lang {
-            Language::Korean => "대상:",
-            Language::English => "Targets:",
+            Language::Korean => "마지막 크롤링",
+            Language::English => "Last Crawl",
+        }
+    }
+
+    pub fn md_last_crawl_error(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 크롤링 오류",
+            Language::English => "Last Crawl Error",
+        }
+    }
+
+    pub fn md_schema_change_policy(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "스키마 변경 정책 (업데이트 / 삭제)",
+            Language::English => "Schema Change Policy",
+        }
+    }
+
+    pub fn md_crawler_targets(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "크롤러 데이터 소스",
+            Language::English => "Data Sources",
+        }
+    }
+
+    pub fn md_exclusions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "제외 패턴",
+            Language::English => "Exclusions",
+        }
+    }
+
+    // Athena markdown labels
+    pub fn md_engine_version(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "엔진 버전",
+            Language::English => "Engine Version",
+        }
+    }
+
+    pub fn md_query_result_location(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "쿼리 결과 위치",
+            Language::English => "Query Result Location",
+        }
+    }
+
+    pub fn md_enforce_workgroup_settings(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "클라이언트 측 설정 재정의",
+            Language::English => "Override Client-side Settings",
+        }
+    }
+
+    pub fn md_cloudwatch_metrics(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "CloudWatch 지표",
+            Language::English => "CloudWatch Metrics",
+        }
+    }
+
+    pub fn md_bytes_scanned_cutoff(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "쿼리당 스캔 데이터 한도",
+            Language::English => "Data Scanned Limit per Query",
+        }
+    }
+
+    pub fn md_requester_pays(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "요청자 지불",
+            Language::English => "Requester Pays",
+        }
+    }
+
+    // AWS Backup markdown labels
+    pub fn md_advanced_backup_settings(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "고급 백업 설정",
+            Language::English => "Advanced Backup Settings",
+        }
+    }
+
+    pub fn md_last_execution(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 실행",
+            Language::English => "Last Run",
+        }
+    }
+
+    pub fn md_backup_vault(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "백업 볼트",
+            Language::English => "Backup Vault",
+        }
+    }
+
+    pub fn md_retention(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "보존 기간",
+            Language::English => "Retention",
+        }
+    }
+
+    pub fn md_never_expires(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "만료 없음",
+            Language::English => "Never expire",
+        }
+    }
+
+    pub fn md_cold_storage_after(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "콜드 스토리지 전환",
+            Language::English => "Move to Cold Storage",
+        }
+    }
+
+    pub fn md_continuous_backup(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "연속 백업 (PITR)",
+            Language::English => "Continuous Backup (PITR)",
+        }
+    }
+
+    pub fn md_copy_to(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "다른 볼트로 복사",
+            Language::English => "Copy To",
+        }
+    }
+
+    pub fn md_resource_assignments(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "리소스 할당",
+            Language::English => "Resource Assignments",
+        }
+    }
+
+    pub fn md_excluded_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "제외 리소스",
+            Language::English => "Excluded Resources",
+        }
+    }
+
+    pub fn md_tag_conditions(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "태그 조건",
+            Language::English => "Tag Conditions",
+        }
+    }
+
+    pub fn md_protected_resources(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "보호된 리소스",
+            Language::English => "Protected Resources",
+        }
+    }
+
+    pub fn md_last_backup(&self) -> &'static str {
+        match self.lang {
+            Language::Korean => "마지막 백업",
+            Language::English => "Last Backup",
         }
     }
 }
 
+// 영어 문장 첫 글자를 대문자로 ("every day at 09:00" -> "Every day at 09:00")
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{I18n, Language};
@@ -1531,6 +7334,7 @@ mod tests {
             loading_msg,
             aws_cli_waiting,
             refresh_complete,
+            cached,
             save_complete,
             resource_added,
             resource_deleted,
@@ -1566,7 +7370,174 @@ mod tests {
             loading_asg_list,
             loading_asg_detail,
             no_asgs,
+            loading_rds_list,
+            loading_rds_detail,
+            no_rds_databases,
             auto_scaling_group,
+            rds_database,
+            loading_lambda_list,
+            loading_lambda_detail,
+            no_lambda_functions,
+            lambda_function,
+            loading_dynamodb_list,
+            loading_dynamodb_detail,
+            no_dynamodb_tables,
+            dynamodb_table,
+            loading_cloudfront_list,
+            loading_cloudfront_detail,
+            no_cloudfront_distributions,
+            cloudfront_distribution,
+            loading_route53_list,
+            loading_route53_detail,
+            no_route53_zones,
+            route53_hosted_zone,
+            loading_eks_list,
+            loading_eks_detail,
+            no_eks_clusters,
+            eks_cluster,
+            loading_ecs_list,
+            loading_ecs_detail,
+            no_ecs_clusters,
+            ecs_cluster,
+            loading_sqs_list,
+            loading_sqs_detail,
+            no_sqs_queues,
+            sqs_queue,
+            loading_sns_list,
+            loading_sns_detail,
+            no_sns_topics,
+            sns_topic,
+            loading_elasticache_list,
+            loading_elasticache_detail,
+            no_elasticache_clusters,
+            elasticache_cluster,
+            loading_cloudwatch_alarm_list,
+            loading_cloudwatch_alarm_detail,
+            no_cloudwatch_alarms,
+            cloudwatch_alarm,
+            loading_cloudtrail_list,
+            loading_cloudtrail_detail,
+            no_cloudtrail_trails,
+            cloudtrail_trail,
+            loading_kms_list,
+            loading_kms_detail,
+            no_kms_keys,
+            kms_key,
+            loading_secret_list,
+            loading_secret_detail,
+            no_secrets,
+            secret,
+            loading_ssm_parameter_list,
+            loading_ssm_parameter_detail,
+            no_ssm_parameters,
+            ssm_parameter_path,
+            loading_efs_list,
+            loading_efs_detail,
+            no_efs_file_systems,
+            efs_file_system,
+            loading_api_gateway_list,
+            loading_api_gateway_detail,
+            no_api_gateway_apis,
+            api_gateway_api,
+            loading_beanstalk_list,
+            loading_beanstalk_detail,
+            no_beanstalk_environments,
+            elastic_beanstalk_environment,
+            loading_state_machine_list,
+            loading_state_machine_detail,
+            no_state_machines,
+            step_functions_state_machine,
+            loading_kinesis_stream_list,
+            loading_kinesis_stream_detail,
+            no_kinesis_streams,
+            kinesis_stream,
+            loading_msk_cluster_list,
+            loading_msk_cluster_detail,
+            no_msk_clusters,
+            msk_cluster,
+            loading_opensearch_domain_list,
+            loading_opensearch_domain_detail,
+            no_opensearch_domains,
+            opensearch_domain,
+            loading_redshift_cluster_list,
+            loading_redshift_cluster_detail,
+            no_redshift_clusters,
+            redshift_cluster,
+            loading_transit_gateway_list,
+            loading_transit_gateway_detail,
+            no_transit_gateways,
+            transit_gateway,
+            loading_vpc_peering_list,
+            loading_vpc_peering_detail,
+            no_vpc_peerings,
+            vpc_peering,
+            loading_vpn_list,
+            loading_vpn_detail,
+            no_vpns,
+            site_to_site_vpn,
+            loading_direct_connect_list,
+            loading_direct_connect_detail,
+            no_direct_connect_resources,
+            direct_connect,
+            loading_waf_list,
+            loading_waf_detail,
+            no_waf_web_acls,
+            waf,
+            loading_acm_list,
+            loading_acm_detail,
+            no_acm_certificates,
+            acm,
+            loading_cloudformation_list,
+            loading_cloudformation_detail,
+            no_cloudformation_stacks,
+            cloudformation,
+            loading_iam_list,
+            loading_iam_detail,
+            no_iam_entities,
+            iam,
+            loading_vpc_endpoint_list,
+            loading_vpc_endpoint_detail,
+            no_vpc_endpoints,
+            vpc_endpoint,
+            loading_network_interface_list,
+            loading_network_interface_detail,
+            no_network_interfaces,
+            network_interface,
+            loading_launch_template_list,
+            loading_launch_template_detail,
+            no_launch_templates,
+            loading_ami_list,
+            loading_ami_detail,
+            no_amis,
+            ami,
+            loading_ebs_list,
+            loading_ebs_detail,
+            no_ebs_resources,
+            ebs,
+            loading_key_pair_list,
+            loading_key_pair_detail,
+            no_key_pairs,
+            key_pairs,
+            loading_event_bus_list,
+            loading_event_bus_detail,
+            no_event_buses,
+            eventbridge_event_bus,
+            loading_cognito_pool_list,
+            loading_cognito_pool_detail,
+            no_cognito_pools,
+            cognito,
+            loading_glue_list,
+            loading_glue_detail,
+            no_glue_resources,
+            glue,
+            loading_athena_workgroup_list,
+            loading_athena_workgroup_detail,
+            no_athena_workgroups,
+            athena_workgroup,
+            loading_backup_plan_list,
+            loading_backup_plan_detail,
+            no_backup_plans,
+            backup_plan,
             loading_blueprint_resources,
             ec2,
             network,
@@ -1604,6 +7575,9 @@ mod tests {
             asg_adjustment_type,
             asg_adjustment_value,
             asg_cooldown,
+            asg_scheduled_actions,
+            asg_schedule,
+            asg_time_zone,
             asg_tags,
             asg_key,
             subnets,
@@ -1618,8 +7592,125 @@ mod tests {
             enter_blueprint_name,
             press_a_to_add,
             resources,
+            validate,
+            remap,
+            remove_missing,
+            validating_blueprint_resources,
+            resource_missing,
+            resource_unverified,
+            sync,
+            blueprints_synced,
+            remote_store_not_configured,
+            export_notion,
+            notion_not_configured,
+            notion_nothing_to_export,
+            export_backstage,
+            backstage_nothing_to_export,
+            export_inventory,
+            inventory_nothing_to_export,
+            import_terraform,
+            import_blueprint,
+            enter_terraform_state_path,
+            terraform_no_resources,
+            import_cloudformation,
+            enter_stack_name,
+            stack_no_resources,
+            loading_stack_resources,
+            import_tag_query,
+            enter_tag_query,
+            tag_query_no_resources,
+            querying_tagged_resources,
+            search_documents,
+            enter_search_query,
+            no_search_results,
+            compare,
+            compare_toggle_view,
+            compare_field,
+            compare_cleared,
+            loading_comparison,
+            macro_register_prompt,
+            macro_cancelled,
+            macro_replay_while_recording,
+            tutorial,
+            tutorial_started,
+            tutorial_exited,
+            tutorial_finished,
+            tutorial_login,
+            tutorial_region,
+            tutorial_service,
+            tutorial_preview,
+            tutorial_blueprint,
+            tutorial_login_hint,
+            tutorial_single_mode_hint,
+            tutorial_region_hint,
+            tutorial_service_hint,
+            tutorial_resource_hint,
+            tutorial_preview_hint,
+            tutorial_new_blueprint_hint,
+            tutorial_blueprint_name_hint,
+            tutorial_blueprint_detail_hint,
+            tutorial_add_resource_hint,
+            plain_mode_title,
+            plain_choice_prompt,
+            plain_main_menu,
+            plain_document_resource,
+            plain_document_blueprint,
+            plain_no_blueprints,
+            promotion,
+            promotion_checklist,
+            promotion_cleared,
+            promotion_config_differences,
+            promotion_unreadable,
+            promotion_no_differences,
+            loading_promotion_checklist,
+            query_playground,
+            select_query_operation,
+            enter_jmespath_query,
+            run_operation,
+            query_result,
+            query_error,
+            running_query_operation,
+            raw_json,
+            word_wrap,
+            scroll_sideways,
+            raw_json_unavailable,
             language,
             language_setting,
+            encrypt_at_rest,
+            hide_terminated_instances,
+            sort_services_by_usage,
+            color_palette,
+            palette_high_contrast,
+            palette_deuteranopia,
+            palette_protanopia,
+            palette_no_color,
+            ascii_mode,
+            max_concurrent_requests,
+            ascii_auto,
+            number_locale,
+            number_locale_plain,
+            spell_out_schedules,
+            stamp_last_modified,
+            show_resource_age,
+            md_resource_age,
+            md_age,
+            md_share,
+            md_oldest_resources,
+            md_blueprint_summary,
+            md_regions,
+            md_total_vcpu,
+            md_total_memory,
+            md_public_endpoints,
+            md_resources_by_type,
+            md_count,
+            schedule_last_day_of_month,
+            pin_service,
+            edit_name_tag,
+            enter_name_tag,
+            write_mode_disabled,
+            setting_name_tag,
+            on,
+            off,
             settings_saved,
             change,
             item,
@@ -1631,6 +7722,7 @@ mod tests {
             query_failed,
             md_dns_support,
             md_dns_hostnames,
+            md_owner_account,
             md_subnets,
             md_internet_gateway,
             md_attached_vpc,
@@ -1650,6 +7742,9 @@ mod tests {
             md_route_tables,
             md_destination,
             md_target,
+            md_all_ipv6,
+            md_usable_ips,
+            md_ip_utilization,
             md_associated_subnets,
             md_association,
             md_network_diagram,
@@ -1689,6 +7784,7 @@ mod tests {
             md_size,
             md_encrypted,
             md_delete_on_termination,
+            md_instance_terminated_note,
             md_user_data,
             md_ecr_repository,
             md_tag_mutability,
@@ -1701,7 +7797,456 @@ mod tests {
             md_threshold,
             md_healthy,
             md_unhealthy,
-            md_targets
+            md_targets,
+            md_rds_instance,
+            md_rds_cluster,
+            md_engine,
+            md_instance_class,
+            md_multi_az,
+            md_endpoint,
+            md_reader_endpoint,
+            md_db_subnet_group,
+            md_parameter_group,
+            md_cluster_members,
+            md_role,
+            md_runtime,
+            md_handler,
+            md_memory,
+            md_timeout,
+            md_last_modified,
+            md_environment_variables,
+            md_vpc_config,
+            md_layers,
+            md_triggers,
+            md_batch_size,
+            md_partition_key,
+            md_sort_key,
+            md_capacity_mode,
+            md_on_demand,
+            md_provisioned,
+            md_table_class,
+            md_item_count,
+            md_streams,
+            md_aws_owned_key,
+            md_deletion_protection,
+            md_global_secondary_indexes,
+            md_local_secondary_indexes,
+            md_index_name,
+            md_projection,
+            md_domain_name,
+            md_aliases,
+            md_price_class,
+            md_default_root_object,
+            md_certificate,
+            md_cloudfront_default_certificate,
+            md_origins,
+            md_origin,
+            md_origin_path,
+            md_behaviors,
+            md_path_pattern,
+            md_viewer_protocol,
+            md_allowed_methods,
+            md_cache_policy,
+            md_default,
+            md_record_count,
+            md_record_sets,
+            md_record_targets,
+            md_alias,
+            md_kubernetes_version,
+            md_endpoint_access,
+            md_control_plane_logging,
+            md_node_groups,
+            md_capacity_type,
+            md_scaling_size,
+            md_ami_type,
+            md_fargate_profiles,
+            md_selectors,
+            md_addons,
+            md_version,
+            md_capacity_providers,
+            md_container_instances,
+            md_active_services,
+            md_running_tasks,
+            md_pending_tasks,
+            md_services,
+            md_launch_type,
+            md_task_definition,
+            md_task_definitions,
+            md_task_counts,
+            md_task_id,
+            md_network_mode,
+            md_task_role,
+            md_execution_role,
+            md_container,
+            md_image,
+            md_queue_url,
+            md_standard,
+            md_content_based_deduplication,
+            md_visibility_timeout,
+            md_message_retention,
+            md_delivery_delay,
+            md_receive_wait_time,
+            md_max_message_size,
+            md_messages,
+            md_messages_available,
+            md_messages_in_flight,
+            md_messages_delayed,
+            md_dead_letter_queue,
+            md_max_receive_count,
+            md_display_name,
+            md_subscriptions_confirmed,
+            md_subscriptions_pending,
+            md_subscriptions,
+            md_filter_policy,
+            md_raw_message_delivery,
+            md_access_policy,
+            md_replication_group,
+            md_node_type,
+            md_cluster_mode,
+            md_automatic_failover,
+            md_configuration_endpoint,
+            md_subnet_group,
+            md_transit_encryption,
+            md_at_rest_encryption,
+            md_replication_topology,
+            md_shard,
+            md_slots,
+            md_primary_endpoint,
+            md_node,
+            md_nodes,
+            md_metric_alarm,
+            md_composite_alarm,
+            md_state_reason,
+            md_state_updated,
+            md_metric,
+            md_statistic,
+            md_period,
+            md_datapoints_to_alarm,
+            md_missing_data,
+            md_alarm_rule,
+            md_actions_enabled,
+            md_dimensions,
+            md_alarm_actions,
+            md_action,
+            md_home_region,
+            md_logging,
+            md_latest_delivery,
+            md_multi_region_trail,
+            md_organization_trail,
+            md_global_service_events,
+            md_s3_destination,
+            md_log_file_validation,
+            md_cloudwatch_logs,
+            md_sns_notification,
+            md_recent_events,
+            md_event_time,
+            md_event_name,
+            md_event_source,
+            md_user,
+            md_resources,
+            md_modified_by,
+            md_modified_at,
+            md_modified_via,
+            md_aws_console,
+            md_no_recent_changes,
+            md_key_id,
+            md_key_spec,
+            md_key_usage,
+            md_key_material_origin,
+            md_multi_region,
+            md_deletion_date,
+            md_key_rotation,
+            md_next_rotation,
+            md_not_supported,
+            md_key_policy,
+            md_effect,
+            md_principal,
+            md_actions,
+            md_condition,
+            md_grants,
+            md_grantee,
+            md_operations,
+            md_retiring_principal,
+            md_secret_value_note,
+            md_owning_service,
+            md_primary_region,
+            md_rotation,
+            md_rotation_schedule,
+            md_rotation_window,
+            md_rotation_function,
+            md_last_rotated,
+            md_last_changed,
+            md_last_accessed,
+            md_replicas,
+            md_parameter_value_note,
+            md_path,
+            md_parameters,
+            md_parameter_tree,
+            md_tier,
+            md_advanced_tier,
+            md_performance_mode,
+            md_throughput_mode,
+            md_lifecycle_policy,
+            md_transition,
+            md_transition_to_ia,
+            md_transition_to_archive,
+            md_transition_to_primary,
+            md_on_first_access,
+            md_mount_targets,
+            md_mount_target,
+            md_ip_address,
+            md_no_mount_targets,
+            md_endpoint_type,
+            md_stages,
+            md_stage,
+            md_deployment,
+            md_auto_deploy,
+            md_last_updated,
+            md_invoke_url,
+            md_routes,
+            md_route,
+            md_authorization,
+            md_integration,
+            md_no_routes,
+            md_authorizers,
+            md_identity_source,
+            md_application,
+            md_health,
+            md_option_settings,
+            md_namespace,
+            md_option,
+            md_associated_resources,
+            md_include_execution_data,
+            md_start_at,
+            md_state_count,
+            md_tracing,
+            md_definition,
+            md_open_shards,
+            md_data_retention,
+            md_enhanced_monitoring,
+            md_consumers,
+            md_kafka_version,
+            md_broker_count,
+            md_broker_storage,
+            md_provisioned_throughput,
+            md_storage_mode,
+            md_client_broker_encryption,
+            md_in_cluster_encryption,
+            md_client_subnets,
+            md_cluster_config,
+            md_data_nodes,
+            md_dedicated_master,
+            md_zone_awareness,
+            md_ebs_per_node,
+            md_instance_store,
+            md_security,
+            md_enforce_https,
+            md_node_to_node_encryption,
+            md_vpc_endpoints,
+            md_node_count,
+            md_database,
+            md_master_username,
+            md_maintenance_window,
+            md_publicly_accessible,
+            md_enhanced_vpc_routing,
+            md_snapshots,
+            md_snapshot_schedule,
+            md_default_snapshot_schedule,
+            md_next_snapshot,
+            md_automated_snapshot_retention,
+            md_manual_snapshot_retention,
+            md_indefinitely,
+            md_cross_region_snapshot_copy,
+            md_iam_roles,
+            md_amazon_side_asn,
+            md_vpn_ecmp_support,
+            md_auto_accept_shared_attachments,
+            md_default_association_route_table,
+            md_default_propagation_route_table,
+            md_tgw_attachments,
+            md_tgw_routes_truncated,
+            md_requester,
+            md_accepter,
+            md_remote_dns_resolution,
+            md_status_message,
+            md_expiration_time,
+            md_customer_gateway,
+            md_virtual_private_gateway,
+            md_tunnels,
+            md_outside_ip,
+            md_inside_cidr,
+            md_accepted_routes,
+            md_static_routes,
+            md_routing,
+            md_static_routing,
+            md_dynamic_routing,
+            md_bgp_asn,
+            md_vpn_connections,
+            md_acceleration,
+            md_local_network_cidr,
+            md_remote_network_cidr,
+            md_location,
+            md_bandwidth,
+            md_vlan,
+            md_partner,
+            md_provider,
+            md_jumbo_frames,
+            md_connection,
+            md_virtual_interface,
+            md_virtual_interfaces,
+            md_mtu,
+            md_direct_connect_gateway,
+            md_bgp_peers,
+            md_bgp_status,
+            md_address_family,
+            md_amazon_address,
+            md_customer_address,
+            md_route_filter_prefixes,
+            md_scope,
+            md_capacity,
+            md_metric_name,
+            md_sampled_requests,
+            md_rules,
+            md_priority,
+            md_statement,
+            md_resource,
+            md_acm_certificate,
+            md_subject_alternative_names,
+            md_validation_method,
+            md_key_algorithm,
+            md_issuer,
+            md_valid_from,
+            md_valid_until,
+            md_renewal_eligibility,
+            md_renewal_status,
+            md_domain_validation,
+            md_dns_record,
+            md_in_use_by,
+            md_not_in_use,
+            md_cloudformation_stack,
+            md_stack_id,
+            md_parent_stack,
+            md_termination_protection,
+            md_drift_status,
+            md_outputs,
+            md_export_name,
+            md_logical_id,
+            md_physical_id,
+            md_iam_user,
+            md_iam_group,
+            md_iam_policy,
+            md_permissions_boundary,
+            md_attachment_count,
+            md_groups,
+            md_members,
+            md_access_keys,
+            md_policy_document,
+            document_region,
+            documenting_region,
+            region_inventory_empty,
+            region_inventory,
+            md_service_name,
+            md_private_dns,
+            md_endpoint_policy,
+            md_full_access,
+            md_custom,
+            md_subnets_or_route_tables,
+            save_per_resource,
+            network_acl,
+            md_rule_number,
+            md_allow,
+            md_deny,
+            md_mac_address,
+            md_attachment,
+            md_attached_to,
+            md_device_index,
+            md_ip_addresses,
+            md_primary,
+            md_requester_managed,
+            md_unattached_interface_note,
+            md_latest,
+            md_default_version,
+            md_latest_version,
+            md_created_by,
+            md_iam_instance_profile,
+            md_source_instance,
+            md_root_device,
+            md_deprecation_time,
+            md_snapshot,
+            md_launch_permissions,
+            md_account,
+            md_organization,
+            md_organizational_unit,
+            md_not_shared,
+            md_volume,
+            md_throughput,
+            md_multi_attach,
+            md_source_snapshot,
+            md_source_volume,
+            md_progress,
+            md_attached_at,
+            md_unattached_volume_note,
+            md_key_type,
+            md_fingerprint,
+            md_public_key,
+            md_trigger,
+            md_event_pattern,
+            md_managed_by,
+            md_input,
+            md_matched_event,
+            md_input_transformer,
+            cognito_user_pool,
+            cognito_identity_pool,
+            md_estimated_users,
+            md_sign_in_attributes,
+            md_user_pool_domain,
+            md_custom_domain,
+            md_cognito_prefix_domain,
+            md_app_clients,
+            md_client_id,
+            md_client_secret,
+            md_auth_flows,
+            md_token_validity,
+            md_oauth_flows,
+            md_oauth_scopes,
+            md_identity_providers,
+            md_callback_urls,
+            md_sign_out_urls,
+            md_guest_access,
+            md_basic_auth_flow,
+            md_authenticated_role,
+            md_guest_role,
+            glue_database,
+            glue_crawler,
+            md_tables,
+            md_data_format,
+            md_columns,
+            md_partition_keys,
+            md_table_prefix,
+            md_last_crawl,
+            md_last_crawl_error,
+            md_schema_change_policy,
+            md_crawler_targets,
+            md_exclusions,
+            md_engine_version,
+            md_query_result_location,
+            md_enforce_workgroup_settings,
+            md_cloudwatch_metrics,
+            md_bytes_scanned_cutoff,
+            md_requester_pays,
+            md_advanced_backup_settings,
+            md_last_execution,
+            md_backup_vault,
+            md_retention,
+            md_never_expires,
+            md_cold_storage_after,
+            md_continuous_backup,
+            md_copy_to,
+            md_resource_assignments,
+            md_excluded_resources,
+            md_tag_conditions,
+            md_protected_resources,
+            md_last_backup
         );
     }
 
@@ -1730,5 +8275,96 @@ mod tests {
         assert_non_empty!(en.asg_seconds(10));
         assert_non_empty!(en.asg_instances_with_count(3));
         assert_non_empty!(en.network_detail_unavailable("vpc-1234"));
+
+        assert!(ko.resource_renamed("web-b").contains("web-b"));
+        assert!(en.resource_renamed("web-b").contains("web-b"));
+        assert!(ko.md_subnet_overlap("a", "b").contains(" ↔ b"));
+        assert!(en.md_subnet_overlap("a", "b").contains(" ↔ b"));
+        assert_eq!(ko.md_days_ago(12), "12일 전");
+        assert_eq!(en.md_days_ago(1), "1 day ago");
+        assert_eq!(en.md_days_ago(0), "today");
+        assert_eq!(en.age_bucket_name(5), "Unknown");
+        assert_eq!(ko.md_unknown_instance_types(2), "인스턴스 2개 유형 미확인");
+        assert_eq!(
+            en.md_unknown_instance_types(1),
+            "1 instance of unknown type"
+        );
+        assert!(ko.validation_complete(2, 0).contains('2'));
+        assert!(en.validation_complete(2, 0).contains('2'));
+        assert!(
+            en.validation_complete(0, 3)
+                .contains("3 could not be verified")
+        );
+        assert!(ko.resource_moved("us-east-1").contains("us-east-1"));
+        assert_non_empty!(ko.resources_remapped(1));
+        assert_non_empty!(ko.missing_resources_removed(1));
+        assert_non_empty!(en.resources_remapped(1));
+        assert_non_empty!(en.missing_resources_removed(1));
+
+        assert!(ko.blueprint_imported(3).contains('3'));
+        assert!(ko.region_inventory_summary(2, 5).contains('5'));
+        assert!(en.region_inventory_summary(2, 5).contains('2'));
+        assert!(en.blueprint_imported(3).contains('3'));
+        assert!(ko.blueprint_import_failed("denied").contains("denied"));
+        assert!(en.blueprint_sync_failed("denied").contains("denied"));
+        assert!(ko.encryption_toggle_failed("locked").contains("locked"));
+        assert!(en.blueprint_import_failed("denied").contains("denied"));
+        assert!(ko.search_result_count(4).contains('4'));
+        assert!(en.search_result_count(4).contains('4'));
+        assert_eq!(en.refreshed_ago(5), "refreshed just now");
+        assert_eq!(en.refreshed_ago(150), "refreshed 2m ago");
+        assert_eq!(ko.refreshed_ago(7300), "2시간 전 조회");
+        assert!(ko.notion_exported(2).contains('2'));
+        assert!(en.notion_exported(2).contains('2'));
+        assert!(ko.notion_export_failed("denied").contains("denied"));
+        assert!(en.notion_export_failed("denied").contains("denied"));
+        assert!(ko.backstage_exported(3, "c.yaml").contains('3'));
+        assert!(en.backstage_exported(3, "c.yaml").contains("c.yaml"));
+        assert!(ko.inventory_exported(2, "i.json").contains('2'));
+        assert!(en.inventory_exported(2, "i.json").contains("i.json"));
+        assert!(ko.blueprint_files_saved(4, "prod").contains("prod/"));
+        assert!(en.blueprint_files_saved(4, "prod").contains('4'));
+        assert!(ko.blueprint_files_save_failed("denied").contains("denied"));
+        assert!(en.blueprint_files_save_failed("denied").contains("denied"));
+        assert!(ko.diagram_render_failed("mmdc").contains("mmdc"));
+        assert!(en.diagram_render_failed("mmdc").contains("mmdc"));
+        assert!(ko.compare_base_marked("sg-prod").contains("sg-prod"));
+        assert!(en.compare_base_marked("sg-prod").contains("sg-prod"));
+        assert!(ko.compare_difference_count(3).contains('3'));
+        assert!(en.compare_difference_count(3).contains('3'));
+        assert!(ko.macro_recording('a').contains("@a"));
+        assert!(en.macro_recording('a').contains("@a"));
+        assert!(ko.macro_saved('a', 5).contains('5'));
+        assert!(en.macro_saved('a', 5).contains('5'));
+        assert_eq!(en.md_hours(24), "24h");
+        assert_eq!(ko.md_days(7), "7일");
+        assert!(ko.plain_invalid_choice("9").contains('9'));
+        assert!(en.plain_invalid_choice("9").contains('9'));
+        assert!(ko.plain_resource_count(3).contains('3'));
+        assert!(en.plain_resource_count(3).contains('3'));
+        assert!(ko.macro_empty('b').contains("@b"));
+        assert!(en.macro_empty('b').contains("@b"));
+        assert!(ko.macro_replaying('a', 5).contains('5'));
+        assert!(en.macro_replaying('a', 5).contains("@a"));
+        assert!(ko.md_rotation_period(365).contains("365"));
+        assert!(en.md_rotation_period(365).contains("365"));
+        assert!(ko.md_certificate_expiring_note(12).contains("12"));
+        assert!(en.md_certificate_expiring_note(12).contains("12"));
+        assert!(ko.md_certificate_expired_note(3).contains('3'));
+        assert!(en.md_certificate_expired_note(3).contains('3'));
+        assert!(ko.promotion_source_marked("staging").contains("staging"));
+        assert!(en.promotion_source_marked("staging").contains("staging"));
+        assert!(ko.promotion_missing_in("prod").contains("prod"));
+        assert!(en.promotion_missing_in("prod").contains("prod"));
+        assert!(ko.promotion_item_count(4).contains('4'));
+        assert!(en.promotion_item_count(4).contains('4'));
+        assert!(ko.confirm_name_tag("i-0abc").contains("i-0abc"));
+        assert!(en.confirm_name_tag("i-0abc").contains("i-0abc"));
+        assert!(ko.name_tag_updated("i-0abc", "web-1").contains("web-1"));
+        assert!(en.name_tag_updated("i-0abc", "web-1").contains("i-0abc"));
+        assert!(ko.name_tag_update_failed("denied").contains("denied"));
+        assert!(en.name_tag_update_failed("denied").contains("denied"));
+        assert!(ko.md_after_days(30).contains("30"));
+        assert!(en.md_after_days(30).contains("30"));
     }
 }
@@ -0,0 +1,578 @@
+use serde_json::json;
+use std::collections::BTreeSet;
+
+// 서비스별로 emd가 실제 호출하는 읽기 전용 API (aws_cli 모듈 변경 시 함께 갱신)
+pub const SERVICE_PERMISSIONS: &[(&str, &[&str])] = &[
+    (
+        "ec2",
+        &[
+            "ec2:DescribeInstances",
+            "ec2:DescribeInstanceAttribute",
+            "ec2:DescribeInstanceTypes",
+            "ec2:DescribeImages",
+            "ec2:DescribeVolumes",
+            "iam:GetRole",
+            "iam:GetRolePolicy",
+            "iam:ListAttachedRolePolicies",
+            "iam:ListRolePolicies",
+        ],
+    ),
+    (
+        "network",
+        &[
+            "ec2:DescribeVpcs",
+            "ec2:DescribeVpcAttribute",
+            "ec2:DescribeSubnets",
+            "ec2:DescribeInternetGateways",
+            "ec2:DescribeNatGateways",
+            "ec2:DescribeRouteTables",
+            "ec2:DescribeAddresses",
+            "ec2:DescribeVpcPeeringConnections",
+            "ec2:DescribeVpcEndpoints",
+            "ec2:DescribeNetworkAcls",
+        ],
+    ),
+    ("security-group", &["ec2:DescribeSecurityGroups"]),
+    (
+        "load-balancer",
+        &[
+            "elasticloadbalancing:DescribeLoadBalancers",
+            "elasticloadbalancing:DescribeListeners",
+            "elasticloadbalancing:DescribeTargetGroups",
+            "elasticloadbalancing:DescribeTargetHealth",
+        ],
+    ),
+    ("ecr", &["ecr:DescribeRepositories", "ecr:DescribeImages"]),
+    (
+        "asg",
+        &[
+            "autoscaling:DescribeAutoScalingGroups",
+            "autoscaling:DescribePolicies",
+            "autoscaling:DescribeScheduledActions",
+            // ASG 문서에 시작 템플릿 설정을 함께 표시
+            "ec2:DescribeLaunchTemplates",
+            "ec2:DescribeLaunchTemplateVersions",
+        ],
+    ),
+    (
+        "rds",
+        &["rds:DescribeDBInstances", "rds:DescribeDBClusters"],
+    ),
+    (
+        "lambda",
+        &[
+            "lambda:ListFunctions",
+            "lambda:GetFunction",
+            "lambda:ListEventSourceMappings",
+        ],
+    ),
+    (
+        "dynamodb",
+        &[
+            "dynamodb:ListTables",
+            "dynamodb:DescribeTable",
+            "dynamodb:DescribeTimeToLive",
+            "dynamodb:ListTagsOfResource",
+        ],
+    ),
+    (
+        "cloudfront",
+        &[
+            "cloudfront:ListDistributions",
+            "cloudfront:GetDistribution",
+            "cloudfront:ListTagsForResource",
+        ],
+    ),
+    (
+        "route53",
+        &[
+            "route53:ListHostedZones",
+            "route53:GetHostedZone",
+            "route53:ListResourceRecordSets",
+            "route53:ListTagsForResource",
+        ],
+    ),
+    (
+        "eks",
+        &[
+            "eks:ListClusters",
+            "eks:DescribeCluster",
+            "eks:ListNodegroups",
+            "eks:DescribeNodegroup",
+            "eks:ListFargateProfiles",
+            "eks:DescribeFargateProfile",
+            "eks:ListAddons",
+            "eks:DescribeAddon",
+        ],
+    ),
+    (
+        "ecs",
+        &[
+            "ecs:ListClusters",
+            "ecs:DescribeClusters",
+            "ecs:ListServices",
+            "ecs:DescribeServices",
+            "ecs:ListTasks",
+            "ecs:DescribeTasks",
+            "ecs:DescribeTaskDefinition",
+        ],
+    ),
+    (
+        "cloudtrail",
+        &[
+            "cloudtrail:ListTrails",
+            "cloudtrail:DescribeTrails",
+            "cloudtrail:GetTrailStatus",
+            "cloudtrail:LookupEvents",
+            "cloudtrail:ListTags",
+        ],
+    ),
+    (
+        "cloudwatch",
+        &[
+            "cloudwatch:DescribeAlarms",
+            "cloudwatch:ListTagsForResource",
+        ],
+    ),
+    (
+        "elasticache",
+        &[
+            "elasticache:DescribeReplicationGroups",
+            "elasticache:DescribeCacheClusters",
+            "elasticache:ListTagsForResource",
+        ],
+    ),
+    (
+        "kms",
+        &[
+            "kms:ListKeys",
+            "kms:ListAliases",
+            "kms:DescribeKey",
+            "kms:GetKeyRotationStatus",
+            "kms:GetKeyPolicy",
+            "kms:ListGrants",
+            "kms:ListResourceTags",
+        ],
+    ),
+    (
+        "secretsmanager",
+        &[
+            "secretsmanager:ListSecrets",
+            "secretsmanager:DescribeSecret",
+        ],
+    ),
+    (
+        "sns",
+        &[
+            "sns:ListTopics",
+            "sns:GetTopicAttributes",
+            "sns:ListSubscriptionsByTopic",
+            "sns:GetSubscriptionAttributes",
+            "sns:ListTagsForResource",
+        ],
+    ),
+    (
+        "sqs",
+        &[
+            "sqs:ListQueues",
+            "sqs:GetQueueUrl",
+            "sqs:GetQueueAttributes",
+            "sqs:ListQueueTags",
+        ],
+    ),
+    ("ssm", &["ssm:DescribeParameters"]),
+    (
+        "efs",
+        &[
+            "elasticfilesystem:DescribeFileSystems",
+            "elasticfilesystem:DescribeLifecycleConfiguration",
+            "elasticfilesystem:DescribeMountTargets",
+            "elasticfilesystem:DescribeMountTargetSecurityGroups",
+            // DescribeMountTargetSecurityGroups가 마운트 대상 ENI를 조회할 때 필요
+            "ec2:DescribeNetworkInterfaceAttribute",
+        ],
+    ),
+    // REST API(apigateway)와 HTTP/WebSocket API(apigatewayv2) 모두 apigateway:GET 하나로 조회
+    ("apigateway", &["apigateway:GET"]),
+    (
+        "elasticbeanstalk",
+        &[
+            "elasticbeanstalk:DescribeEnvironments",
+            "elasticbeanstalk:DescribeConfigurationSettings",
+            "elasticbeanstalk:DescribeEnvironmentResources",
+            "elasticbeanstalk:ListTagsForResource",
+        ],
+    ),
+    (
+        "stepfunctions",
+        &[
+            "states:ListStateMachines",
+            "states:DescribeStateMachine",
+            "states:ListTagsForResource",
+        ],
+    ),
+    (
+        "eventbridge",
+        &[
+            "events:ListEventBuses",
+            "events:DescribeEventBus",
+            "events:ListRules",
+            "events:ListTargetsByRule",
+            "events:ListTagsForResource",
+        ],
+    ),
+    (
+        "cognito",
+        &[
+            "cognito-idp:ListUserPools",
+            "cognito-idp:DescribeUserPool",
+            "cognito-idp:GetUserPoolMfaConfig",
+            "cognito-idp:DescribeUserPoolDomain",
+            "cognito-idp:ListUserPoolClients",
+            "cognito-idp:DescribeUserPoolClient",
+            "cognito-identity:ListIdentityPools",
+            "cognito-identity:DescribeIdentityPool",
+            "cognito-identity:GetIdentityPoolRoles",
+        ],
+    ),
+    (
+        "glue",
+        &[
+            "glue:GetDatabases",
+            "glue:GetDatabase",
+            "glue:GetTables",
+            "glue:GetCrawlers",
+            "glue:GetCrawler",
+            "glue:GetTags",
+        ],
+    ),
+    (
+        "athena",
+        &[
+            "athena:ListWorkGroups",
+            "athena:GetWorkGroup",
+            "athena:ListTagsForResource",
+        ],
+    ),
+    (
+        "backup",
+        &[
+            "backup:ListBackupPlans",
+            "backup:GetBackupPlan",
+            "backup:ListBackupSelections",
+            "backup:GetBackupSelection",
+            "backup:ListProtectedResourcesByBackupVault",
+            "backup:ListTags",
+        ],
+    ),
+    (
+        "kinesis",
+        &[
+            "kinesis:ListStreams",
+            "kinesis:DescribeStreamSummary",
+            "kinesis:ListStreamConsumers",
+            "kinesis:ListTagsForResource",
+        ],
+    ),
+    ("msk", &["kafka:ListClusters", "kafka:DescribeCluster"]),
+    (
+        "opensearch",
+        &[
+            "es:ListDomainNames",
+            "es:DescribeDomains",
+            "es:DescribeDomain",
+            "es:ListVpcEndpointsForDomain",
+            "es:ListTags",
+        ],
+    ),
+    (
+        "redshift",
+        &[
+            "redshift:DescribeClusters",
+            "redshift:DescribeSnapshotSchedules",
+        ],
+    ),
+    (
+        "transit-gateway",
+        &[
+            "ec2:DescribeTransitGateways",
+            "ec2:DescribeTransitGatewayAttachments",
+            "ec2:DescribeTransitGatewayRouteTables",
+            "ec2:SearchTransitGatewayRoutes",
+        ],
+    ),
+    ("vpc-peering", &["ec2:DescribeVpcPeeringConnections"]),
+    ("vpc-endpoint", &["ec2:DescribeVpcEndpoints"]),
+    ("network-interface", &["ec2:DescribeNetworkInterfaces"]),
+    (
+        "launch-template",
+        &[
+            "ec2:DescribeLaunchTemplates",
+            "ec2:DescribeLaunchTemplateVersions",
+        ],
+    ),
+    ("ami", &["ec2:DescribeImages", "ec2:DescribeImageAttribute"]),
+    ("ebs", &["ec2:DescribeVolumes", "ec2:DescribeSnapshots"]),
+    ("key-pair", &["ec2:DescribeKeyPairs"]),
+    (
+        "vpn",
+        &[
+            "ec2:DescribeVpnConnections",
+            "ec2:DescribeCustomerGateways",
+            "ec2:DescribeVpnGateways",
+        ],
+    ),
+    (
+        "directconnect",
+        &[
+            "directconnect:DescribeConnections",
+            "directconnect:DescribeVirtualInterfaces",
+        ],
+    ),
+    (
+        "waf",
+        &[
+            "wafv2:ListWebACLs",
+            "wafv2:GetWebACL",
+            "wafv2:ListResourcesForWebACL",
+            "cloudfront:ListDistributionsByWebACLId",
+        ],
+    ),
+    (
+        "acm",
+        &[
+            "acm:ListCertificates",
+            "acm:DescribeCertificate",
+            "acm:ListTagsForCertificate",
+        ],
+    ),
+    (
+        "cloudformation",
+        &[
+            "cloudformation:DescribeStacks",
+            "cloudformation:ListStackResources",
+        ],
+    ),
+    (
+        "iam",
+        &[
+            "iam:ListRoles",
+            "iam:ListUsers",
+            "iam:ListGroups",
+            "iam:ListPolicies",
+            "iam:GetRole",
+            "iam:GetUser",
+            "iam:GetGroup",
+            "iam:GetPolicy",
+            "iam:GetPolicyVersion",
+            "iam:ListAttachedRolePolicies",
+            "iam:ListAttachedUserPolicies",
+            "iam:ListAttachedGroupPolicies",
+            "iam:ListRolePolicies",
+            "iam:ListUserPolicies",
+            "iam:ListGroupPolicies",
+            "iam:GetRolePolicy",
+            "iam:GetUserPolicy",
+            "iam:GetGroupPolicy",
+            "iam:ListGroupsForUser",
+            "iam:ListAccessKeys",
+        ],
+    ),
+    ("tagging", &["tag:GetResources"]),
+];
+
+// 로그인 확인에 항상 필요
+const BASE_ACTIONS: &[&str] = &["sts:GetCallerIdentity"];
+
+pub fn service_keys() -> Vec<&'static str> {
+    SERVICE_PERMISSIONS.iter().map(|(key, _)| *key).collect()
+}
+
+// 서비스 목록(SERVICE_KEYS) 이름 → SERVICE_PERMISSIONS 키
+fn permission_key(service: &str) -> Option<&'static str> {
+    let key = match service {
+        "EC2" => "ec2",
+        "Network" => "network",
+        "Security Group" => "security-group",
+        "Load Balancer" => "load-balancer",
+        "ECR" => "ecr",
+        "ASG" => "asg",
+        "RDS" => "rds",
+        "Lambda" => "lambda",
+        "DynamoDB" => "dynamodb",
+        "CloudFront" => "cloudfront",
+        "Route53" => "route53",
+        "EKS" => "eks",
+        "ECS" => "ecs",
+        "SQS" => "sqs",
+        "SNS" => "sns",
+        "ElastiCache" => "elasticache",
+        "CloudWatch" => "cloudwatch",
+        "CloudTrail" => "cloudtrail",
+        "KMS" => "kms",
+        "Secrets Manager" => "secretsmanager",
+        "SSM Parameter Store" => "ssm",
+        "EFS" => "efs",
+        "API Gateway" => "apigateway",
+        "Elastic Beanstalk" => "elasticbeanstalk",
+        "Step Functions" => "stepfunctions",
+        "EventBridge" => "eventbridge",
+        "Cognito" => "cognito",
+        "Glue" => "glue",
+        "Athena" => "athena",
+        "Backup" => "backup",
+        "Kinesis" => "kinesis",
+        "MSK" => "msk",
+        "OpenSearch" => "opensearch",
+        "Redshift" => "redshift",
+        "Transit Gateway" => "transit-gateway",
+        "VPC Peering" => "vpc-peering",
+        "Site-to-Site VPN" => "vpn",
+        "Direct Connect" => "directconnect",
+        "WAF" => "waf",
+        "ACM" => "acm",
+        "CloudFormation" => "cloudformation",
+        "IAM" => "iam",
+        "VPC Endpoint" => "vpc-endpoint",
+        "Network Interface" => "network-interface",
+        "Launch Template" => "launch-template",
+        "AMI" => "ami",
+        "EBS" => "ebs",
+        "Key Pair" => "key-pair",
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Permission keys for the default policy, leaving out services hidden from the service list.
+///
+/// The tag query import (`tagging`) is not a service in the list and is always kept;
+/// CloudFormation stack imports share the `cloudformation` permissions of the service.
+pub fn default_services(hidden: &[&str]) -> Vec<String> {
+    let hidden: Vec<&str> = hidden
+        .iter()
+        .filter_map(|&name| permission_key(name))
+        .collect();
+    SERVICE_PERMISSIONS
+        .iter()
+        .map(|(key, _)| *key)
+        .filter(|key| !hidden.contains(key))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Build the least-privilege read-only policy for `services` (all services when empty).
+///
+/// The optional shared blueprint store needs S3/DynamoDB write access and is not included.
+pub fn policy_document(services: &[String]) -> Result<serde_json::Value, String> {
+    let mut actions: BTreeSet<&str> = BASE_ACTIONS.iter().copied().collect();
+
+    for service in services {
+        let service = service.trim().to_ascii_lowercase();
+        let (_, service_actions) = SERVICE_PERMISSIONS
+            .iter()
+            .find(|(key, _)| *key == service)
+            .ok_or_else(|| {
+                format!(
+                    "unknown service '{}' (expected one of: {})",
+                    service,
+                    service_keys().join(", ")
+                )
+            })?;
+        actions.extend(service_actions.iter().copied());
+    }
+    if services.is_empty() {
+        for (_, service_actions) in SERVICE_PERMISSIONS {
+            actions.extend(service_actions.iter().copied());
+        }
+    }
+
+    Ok(json!({
+        "Version": "2012-10-17",
+        "Statement": [{
+            "Sid": "EmdReadOnly",
+            "Effect": "Allow",
+            "Action": actions.into_iter().collect::<Vec<_>>(),
+            "Resource": "*",
+        }],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SERVICE_PERMISSIONS, default_services, permission_key, policy_document};
+    use crate::app::SERVICE_KEYS;
+
+    fn actions(policy: &serde_json::Value) -> Vec<String> {
+        policy["Statement"][0]["Action"]
+            .as_array()
+            .map(|actions| {
+                actions
+                    .iter()
+                    .filter_map(|a| a.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn policy_document_covers_all_services_by_default() {
+        let policy = policy_document(&[]).expect("policy");
+        let actions = actions(&policy);
+
+        assert_eq!(policy["Version"], "2012-10-17");
+        assert!(actions.contains(&"sts:GetCallerIdentity".to_string()));
+        for (_, service_actions) in SERVICE_PERMISSIONS {
+            for action in *service_actions {
+                assert!(actions.contains(&action.to_string()), "missing {}", action);
+            }
+        }
+        let mut sorted = actions.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(actions, sorted);
+    }
+
+    #[test]
+    fn policy_document_limits_actions_to_selected_services() {
+        let policy = policy_document(&["ECR".to_string()]).expect("policy");
+        assert_eq!(
+            actions(&policy),
+            vec![
+                "ecr:DescribeImages".to_string(),
+                "ecr:DescribeRepositories".to_string(),
+                "sts:GetCallerIdentity".to_string(),
+            ]
+        );
+        assert!(
+            actions(&policy)
+                .iter()
+                .all(|action| !action.contains(":Put") && !action.contains(":Create"))
+        );
+    }
+
+    #[test]
+    fn default_services_leave_out_hidden_services() {
+        for name in SERVICE_KEYS {
+            let key = permission_key(name).unwrap_or_else(|| panic!("no permissions for {}", name));
+            assert!(SERVICE_PERMISSIONS.iter().any(|(k, _)| *k == key));
+        }
+
+        let services = default_services(&["VPC Peering", "KMS"]);
+        assert_eq!(services.len(), SERVICE_PERMISSIONS.len() - 2);
+        assert!(!services.contains(&"kms".to_string()));
+        assert!(services.contains(&"tagging".to_string()));
+
+        // 피어링을 숨겨도 Network 서비스가 쓰는 같은 API는 정책에 남음
+        let actions = actions(&policy_document(&services).expect("policy"));
+        assert!(actions.contains(&"ec2:DescribeVpcPeeringConnections".to_string()));
+        assert!(!actions.contains(&"kms:ListKeys".to_string()));
+    }
+
+    #[test]
+    fn policy_document_rejects_unknown_service() {
+        let error = policy_document(&["braket".to_string()]).expect_err("unknown service");
+        assert!(error.contains("braket"));
+        assert!(error.contains("ec2"));
+    }
+}
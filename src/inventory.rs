@@ -0,0 +1,215 @@
+// CycloneDX 1.5 JSON 인벤토리 내보내기: 리소스마다 component 하나,
+// 리소스 문서에 다른 리소스 ID가 나오면 dependency로 연결
+use crate::aws_cli::Arn;
+use crate::backstage::{console_url, entity_kind, service_slug};
+use crate::blueprint::BlueprintResource;
+use serde_json::{Value, json};
+
+pub const INVENTORY_FILENAME: &str = "inventory.cdx.json";
+const SPEC_VERSION: &str = "1.5";
+// 짧은 ID(예: 이름이 "app"인 큐)는 다른 문서에 우연히 섞여 나올 수 있어 관계에서 제외
+const MIN_REFERENCE_LEN: usize = 6;
+
+/// Render `resources` (with the markdown generated for each) as a CycloneDX BOM.
+/// `markdown` may be shorter than `resources`; missing documents add no relationships.
+pub fn cyclonedx_inventory(
+    title: &str,
+    resources: &[BlueprintResource],
+    markdown: &[String],
+) -> String {
+    let bom = inventory_bom(
+        title,
+        resources,
+        markdown,
+        &uuid::Uuid::new_v4().to_string(),
+        &chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    );
+    serde_json::to_string_pretty(&bom).unwrap_or_default() + "\n"
+}
+
+fn inventory_bom(
+    title: &str,
+    resources: &[BlueprintResource],
+    markdown: &[String],
+    serial: &str,
+    timestamp: &str,
+) -> Value {
+    let refs: Vec<String> = resources.iter().map(bom_ref).collect();
+    let components: Vec<Value> = resources.iter().zip(&refs).map(component).collect();
+    let dependencies: Vec<Value> = refs
+        .iter()
+        .enumerate()
+        .map(|(index, bom_ref)| {
+            let depends_on: Vec<&String> = markdown
+                .get(index)
+                .map(|document| {
+                    resources
+                        .iter()
+                        .zip(&refs)
+                        .enumerate()
+                        .filter(|(other, (resource, _))| {
+                            *other != index && mentions(document, &resource.resource_id)
+                        })
+                        .map(|(_, (_, other_ref))| other_ref)
+                        .collect()
+                })
+                .unwrap_or_default();
+            json!({ "ref": bom_ref, "dependsOn": depends_on })
+        })
+        .collect();
+
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": SPEC_VERSION,
+        "serialNumber": format!("urn:uuid:{}", serial),
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                }]
+            },
+            "component": { "type": "platform", "name": title },
+        },
+        "components": components,
+        "dependencies": dependencies,
+    })
+}
+
+// 서비스/리전이 달라도 겹치지 않도록 "서비스:리전:ID"
+fn bom_ref(resource: &BlueprintResource) -> String {
+    format!(
+        "{}:{}:{}",
+        service_slug(&resource.resource_type),
+        resource.region,
+        resource.resource_id
+    )
+}
+
+fn component((resource, bom_ref): (&BlueprintResource, &String)) -> Value {
+    let (kind, _) = entity_kind(&resource.resource_type);
+    let mut properties = vec![
+        json!({ "name": "aws:resourceType", "value": resource.resource_type.display() }),
+        json!({ "name": "aws:resourceId", "value": resource.resource_id }),
+        json!({ "name": "aws:region", "value": resource.region }),
+    ];
+    if Arn::parse(&resource.resource_id).is_some() {
+        properties.push(json!({ "name": "aws:arn", "value": resource.resource_id }));
+    }
+
+    json!({
+        // Backstage에서 Component인 리소스(Lambda/CloudFront)는 application, 나머지 인프라는 platform
+        "type": if kind == "Component" { "application" } else { "platform" },
+        "bom-ref": bom_ref,
+        "group": format!("aws/{}", service_slug(&resource.resource_type)),
+        "name": resource.resource_name,
+        "description": resource.resource_type.display(),
+        "externalReferences": [{ "type": "website", "url": console_url(resource) }],
+        "properties": properties,
+    })
+}
+
+// ID 앞뒤가 다른 ID 문자로 이어지면(예: vpc-1 과 vpc-12) 같은 리소스가 아님
+fn mentions(document: &str, id: &str) -> bool {
+    if id.len() < MIN_REFERENCE_LEN {
+        return false;
+    }
+    let is_id_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_');
+    document.match_indices(id).any(|(start, _)| {
+        let before = document[..start].chars().next_back();
+        let after = document[start + id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inventory_bom, mentions};
+    use crate::blueprint::{BlueprintResource, ResourceType};
+
+    fn resource(resource_type: ResourceType, id: &str, name: &str) -> BlueprintResource {
+        BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn inventory_bom_lists_components_and_relationships_from_markdown() {
+        let resources = vec![
+            resource(ResourceType::Network, "vpc-0a1b2c3d", "prod"),
+            resource(ResourceType::Ec2, "i-0123456789", "web"),
+            resource(
+                ResourceType::Lambda,
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:api",
+                "api",
+            ),
+        ];
+        let markdown = vec![
+            "## VPC\n| ID | vpc-0a1b2c3d |\n".to_string(),
+            "## EC2\n| VPC | prod - vpc-0a1b2c3d |\n| Other | vpc-0a1b2c3d9 |\n".to_string(),
+        ];
+
+        let bom = inventory_bom(
+            "checkout",
+            &resources,
+            &markdown,
+            "1234",
+            "2026-10-15T00:00:00Z",
+        );
+        assert_eq!(bom["bomFormat"], "CycloneDX");
+        assert_eq!(bom["specVersion"], "1.5");
+        assert_eq!(bom["serialNumber"], "urn:uuid:1234");
+        assert_eq!(bom["metadata"]["component"]["name"], "checkout");
+
+        let components = bom["components"].as_array().expect("components");
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0]["bom-ref"], "vpc:ap-northeast-2:vpc-0a1b2c3d");
+        assert_eq!(components[0]["type"], "platform");
+        assert_eq!(components[0]["group"], "aws/vpc");
+        assert_eq!(components[1]["name"], "web");
+        assert_eq!(components[2]["type"], "application");
+        let properties = components[2]["properties"].as_array().expect("properties");
+        assert!(properties.iter().any(|p| p["name"] == "aws:arn"));
+        assert!(
+            components[1]["externalReferences"][0]["url"]
+                .as_str()
+                .expect("url")
+                .contains("instanceId=i-0123456789")
+        );
+
+        let dependencies = bom["dependencies"].as_array().expect("dependencies");
+        assert_eq!(dependencies.len(), 3);
+        // 자기 자신은 관계에서 제외
+        assert_eq!(
+            dependencies[0]["dependsOn"].as_array().map(Vec::len),
+            Some(0)
+        );
+        assert_eq!(
+            dependencies[1]["dependsOn"][0],
+            "vpc:ap-northeast-2:vpc-0a1b2c3d"
+        );
+        assert_eq!(
+            dependencies[1]["dependsOn"].as_array().map(Vec::len),
+            Some(1)
+        );
+        assert_eq!(
+            dependencies[2]["dependsOn"].as_array().map(Vec::len),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn mentions_matches_whole_ids_only() {
+        assert!(mentions("| VPC | prod - vpc-0a1b |", "vpc-0a1b"));
+        assert!(mentions("(sg-123456)", "sg-123456"));
+        assert!(!mentions("| VPC | vpc-0a1b2 |", "vpc-0a1b"));
+        assert!(!mentions("subnet-vpc-0a1b", "vpc-0a1b"));
+        assert!(!mentions("queue app is used", "app"));
+    }
+}
@@ -0,0 +1,291 @@
+// 키 매크로: 레지스터(a-z)에 키 입력을 기록해 두고 한 번에 재생
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+
+// Ctrl+R <레지스터>: 기록 시작, 기록 중 Ctrl+R: 기록 종료
+pub const RECORD_KEY: char = 'r';
+// Ctrl+E <레지스터>: 재생
+pub const REPLAY_KEY: char = 'e';
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PendingMacro {
+    Record,
+    Replay,
+}
+
+#[derive(Debug, Default)]
+pub struct KeyMacros {
+    registers: BTreeMap<char, Vec<KeyEvent>>,
+    recording: Option<(char, Vec<KeyEvent>)>,
+    pub pending: Option<PendingMacro>,
+}
+
+impl KeyMacros {
+    /// Restore registers saved in `settings.json` (unknown keys are dropped).
+    pub fn from_saved(saved: &BTreeMap<String, Vec<String>>) -> Self {
+        let registers = saved
+            .iter()
+            .filter_map(|(register, keys)| {
+                let register = parse_register(register)?;
+                let keys: Vec<KeyEvent> = keys.iter().filter_map(|key| decode_key(key)).collect();
+                Some((register, keys))
+            })
+            .collect();
+        Self {
+            registers,
+            ..Self::default()
+        }
+    }
+
+    pub fn to_saved(&self) -> BTreeMap<String, Vec<String>> {
+        self.registers
+            .iter()
+            .map(|(register, keys)| (register.to_string(), keys.iter().map(encode_key).collect()))
+            .collect()
+    }
+
+    pub fn recording_register(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    pub fn start_recording(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    /// Stop recording and store the keys; returns the register and key count.
+    pub fn stop_recording(&mut self) -> Option<(char, usize)> {
+        let (register, keys) = self.recording.take()?;
+        let count = keys.len();
+        self.registers.insert(register, keys);
+        Some((register, count))
+    }
+
+    pub fn record(&mut self, key: KeyEvent) {
+        if let Some((_, keys)) = self.recording.as_mut() {
+            keys.push(key);
+        }
+    }
+
+    pub fn register(&self, register: char) -> Option<&[KeyEvent]> {
+        self.registers
+            .get(&register)
+            .map(Vec::as_slice)
+            .filter(|keys| !keys.is_empty())
+    }
+}
+
+/// Ctrl+<c> without other modifiers.
+pub fn is_ctrl(key: &KeyEvent, c: char) -> bool {
+    key.code == KeyCode::Char(c) && key.modifiers == KeyModifiers::CONTROL
+}
+
+// 레지스터는 영문 소문자 한 글자
+pub fn register_for(key: &KeyEvent) -> Option<char> {
+    match key.code {
+        KeyCode::Char(c)
+            if c.is_ascii_lowercase() && !key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            Some(c)
+        }
+        _ => None,
+    }
+}
+
+fn parse_register(register: &str) -> Option<char> {
+    let mut chars = register.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_lowercase() => Some(c),
+        _ => None,
+    }
+}
+
+// vim 표기법: "j", "<Enter>", "<C-x>", "<S-Up>" ("<"는 "<lt>")
+pub fn encode_key(key: &KeyEvent) -> String {
+    let name = match key.code {
+        KeyCode::Char('<') => "lt".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Backspace => "BS".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        _ => "Nop".to_string(),
+    };
+    // 대문자 등 문자 키의 Shift는 문자 자체에 반영되어 있음
+    let mut prefix = String::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        prefix.push_str("C-");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        prefix.push_str("A-");
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) && !matches!(key.code, KeyCode::Char(_)) {
+        prefix.push_str("S-");
+    }
+    match key.code {
+        KeyCode::Char(c) if prefix.is_empty() && c != '<' && c != ' ' => name,
+        _ => format!("<{}{}>", prefix, name),
+    }
+}
+
+pub fn decode_key(token: &str) -> Option<KeyEvent> {
+    let Some(inner) = token.strip_prefix('<').and_then(|t| t.strip_suffix('>')) else {
+        let mut chars = token.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(KeyEvent::new(KeyCode::Char(c), char_modifiers(c))),
+            _ => None,
+        };
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    let mut name = inner;
+    loop {
+        if let Some(rest) = name.strip_prefix("C-") {
+            modifiers |= KeyModifiers::CONTROL;
+            name = rest;
+        } else if let Some(rest) = name.strip_prefix("A-") {
+            modifiers |= KeyModifiers::ALT;
+            name = rest;
+        } else if let Some(rest) = name.strip_prefix("S-") {
+            modifiers |= KeyModifiers::SHIFT;
+            name = rest;
+        } else {
+            break;
+        }
+    }
+
+    let code = match name {
+        "lt" => KeyCode::Char('<'),
+        "Space" => KeyCode::Char(' '),
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "BackTab" => KeyCode::BackTab,
+        "BS" => KeyCode::Backspace,
+        "Del" => KeyCode::Delete,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        _ => match name.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+            Some(n) => KeyCode::F(n),
+            None => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => {
+                        modifiers |= char_modifiers(c);
+                        KeyCode::Char(c)
+                    }
+                    _ => return None,
+                }
+            }
+        },
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+// 터미널은 대문자 입력을 Shift와 함께 보냄 (J/K 재정렬 단축키 등)
+fn char_modifiers(c: char) -> KeyModifiers {
+    if c.is_ascii_uppercase() {
+        KeyModifiers::SHIFT
+    } else {
+        KeyModifiers::NONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyMacros, decode_key, encode_key, is_ctrl, register_for};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::collections::BTreeMap;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        let keys = vec![
+            key(KeyCode::Char('j')),
+            KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT),
+            key(KeyCode::Char('<')),
+            key(KeyCode::Char(' ')),
+            key(KeyCode::Enter),
+            key(KeyCode::Esc),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL),
+            key(KeyCode::F(5)),
+        ];
+        let encoded: Vec<String> = keys.iter().map(encode_key).collect();
+        assert_eq!(
+            encoded,
+            vec![
+                "j", "K", "<lt>", "<Space>", "<Enter>", "<Esc>", "<S-Up>", "<C-x>", "<F5>"
+            ]
+        );
+        let decoded: Vec<KeyEvent> = encoded.iter().filter_map(|k| decode_key(k)).collect();
+        assert_eq!(decoded, keys);
+        assert_eq!(decode_key("<Bogus>"), None);
+        assert_eq!(decode_key("jk"), None);
+    }
+
+    #[test]
+    fn register_keys_are_plain_lowercase_letters() {
+        assert_eq!(register_for(&key(KeyCode::Char('a'))), Some('a'));
+        assert_eq!(register_for(&key(KeyCode::Char('A'))), None);
+        assert_eq!(register_for(&key(KeyCode::Enter)), None);
+        assert!(is_ctrl(
+            &KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+            'r'
+        ));
+        assert!(!is_ctrl(&key(KeyCode::Char('r')), 'r'));
+    }
+
+    #[test]
+    fn recording_stores_register_and_survives_save() {
+        let mut macros = KeyMacros::default();
+        assert_eq!(macros.stop_recording(), None);
+
+        macros.start_recording('a');
+        assert_eq!(macros.recording_register(), Some('a'));
+        macros.record(key(KeyCode::Down));
+        macros.record(key(KeyCode::Enter));
+        assert_eq!(macros.stop_recording(), Some(('a', 2)));
+        assert_eq!(macros.recording_register(), None);
+
+        // 기록 중이 아닐 때의 입력은 무시
+        macros.record(key(KeyCode::Esc));
+        assert_eq!(macros.register('a').map(<[KeyEvent]>::len), Some(2));
+        assert!(macros.register('b').is_none());
+
+        let saved = macros.to_saved();
+        assert_eq!(
+            saved.get("a"),
+            Some(&vec!["<Down>".to_string(), "<Enter>".to_string()])
+        );
+
+        let mut with_invalid = saved.clone();
+        with_invalid.insert("AB".to_string(), vec!["j".to_string()]);
+        let restored = KeyMacros::from_saved(&with_invalid);
+        assert_eq!(restored.to_saved(), saved);
+        assert_eq!(
+            KeyMacros::from_saved(&BTreeMap::new()).to_saved(),
+            BTreeMap::new()
+        );
+    }
+}
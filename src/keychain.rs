@@ -0,0 +1,30 @@
+// OS 키체인(macOS Keychain, Windows Credential Manager, Secret Service) 접근
+
+const SERVICE: &str = "emd";
+
+/// Read a secret from the OS keychain, or `None` when it was never stored.
+pub fn get_secret(account: &str) -> Result<Option<String>, String> {
+    let entry = keyring::Entry::new(SERVICE, account).map_err(|e| e.to_string())?;
+    match entry.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Read a secret from the OS keychain, storing the value from `create` on first use.
+pub fn get_or_create_secret(
+    account: &str,
+    create: impl FnOnce() -> Result<String, String>,
+) -> Result<String, String> {
+    let entry = keyring::Entry::new(SERVICE, account).map_err(|e| e.to_string())?;
+    match entry.get_password() {
+        Ok(secret) => Ok(secret),
+        Err(keyring::Error::NoEntry) => {
+            let secret = create()?;
+            entry.set_password(&secret).map_err(|e| e.to_string())?;
+            Ok(secret)
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,294 @@
+// 문서의 숫자/용량/날짜 표기: 설정의 number_locale을 따르며, 렌더러는 format! 대신 이 함수들을 사용
+// 화면 언어(Language)와는 따로 설정 (예: 영어 화면에 독일식 숫자 표기)
+use crate::i18n::I18n;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberLocale {
+    /// AWS CLI와 같은 표기: 구분 기호 없음, 날짜는 받은 그대로
+    #[default]
+    Plain,
+    EnUs,
+    KoKr,
+    DeDe,
+    FrFr,
+}
+
+impl NumberLocale {
+    const ALL: [NumberLocale; 5] = [
+        NumberLocale::Plain,
+        NumberLocale::EnUs,
+        NumberLocale::KoKr,
+        NumberLocale::DeDe,
+        NumberLocale::FrFr,
+    ];
+
+    /// Next locale in the settings cycle.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Settings label with a sample of the style.
+    pub fn label(self, i18n: &I18n) -> String {
+        match self {
+            NumberLocale::Plain => format!("{} (1234.5 GB)", i18n.number_locale_plain()),
+            NumberLocale::EnUs => "English (1,234.5 GB)".to_string(),
+            NumberLocale::KoKr => "한국어 (1,234.5 GB)".to_string(),
+            NumberLocale::DeDe => "Deutsch (1.234,5 GB)".to_string(),
+            NumberLocale::FrFr => "Français (1 234,5 Go)".to_string(),
+        }
+    }
+
+    fn group_separator(self) -> Option<char> {
+        match self {
+            NumberLocale::Plain => None,
+            NumberLocale::EnUs | NumberLocale::KoKr => Some(','),
+            NumberLocale::DeDe => Some('.'),
+            // 줄바꿈되지 않도록 NBSP
+            NumberLocale::FrFr => Some('\u{a0}'),
+        }
+    }
+
+    fn decimal_separator(self) -> char {
+        match self {
+            NumberLocale::DeDe | NumberLocale::FrFr => ',',
+            _ => '.',
+        }
+    }
+}
+
+/// Storage units; AWS reports volume sizes in GiB but labels them GB, and so do we.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeUnit {
+    Byte,
+    Kb,
+    Mb,
+    Gb,
+    Tb,
+}
+
+impl SizeUnit {
+    const SCALE: [SizeUnit; 5] = [
+        SizeUnit::Byte,
+        SizeUnit::Kb,
+        SizeUnit::Mb,
+        SizeUnit::Gb,
+        SizeUnit::Tb,
+    ];
+
+    fn label(self, locale: NumberLocale) -> &'static str {
+        // 프랑스어권은 바이트 대신 옥텟(o)
+        let french = locale == NumberLocale::FrFr;
+        match (self, french) {
+            (SizeUnit::Byte, false) => "B",
+            (SizeUnit::Byte, true) => "o",
+            (SizeUnit::Kb, false) => "KB",
+            (SizeUnit::Kb, true) => "Ko",
+            (SizeUnit::Mb, false) => "MB",
+            (SizeUnit::Mb, true) => "Mo",
+            (SizeUnit::Gb, false) => "GB",
+            (SizeUnit::Gb, true) => "Go",
+            (SizeUnit::Tb, false) => "TB",
+            (SizeUnit::Tb, true) => "To",
+        }
+    }
+}
+
+static NUMBER_LOCALE: Mutex<NumberLocale> = Mutex::new(NumberLocale::Plain);
+
+/// Apply `locale` to every document rendered from now on.
+pub fn set_number_locale(locale: NumberLocale) {
+    if let Ok(mut current) = NUMBER_LOCALE.lock() {
+        *current = locale;
+    }
+}
+
+pub fn number_locale() -> NumberLocale {
+    NUMBER_LOCALE.lock().map(|l| *l).unwrap_or_default()
+}
+
+/// Integer with thousands separators, e.g. `16,384`.
+pub fn count(value: impl Into<i64>) -> String {
+    count_in(number_locale(), value.into())
+}
+
+/// `value` in `unit`, e.g. `30 GB`.
+pub fn size(value: impl Into<i64>, unit: SizeUnit) -> String {
+    size_in(number_locale(), value.into(), unit)
+}
+
+/// Byte count scaled to the largest unit below 1024, e.g. `1.5 GB`.
+pub fn bytes(value: i64) -> String {
+    bytes_in(number_locale(), value)
+}
+
+/// Percentage with one decimal place, e.g. `12.5%`.
+pub fn percent(value: f64) -> String {
+    percent_in(number_locale(), value)
+}
+
+/// RFC 3339 timestamp or `YYYY-MM-DD` date; anything else is returned unchanged.
+pub fn date(raw: &str) -> String {
+    date_in(number_locale(), raw)
+}
+
+fn count_in(locale: NumberLocale, value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    let Some(separator) = locale.group_separator() else {
+        return format!("{}{}", sign, digits);
+    };
+
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    format!("{}{}", sign, grouped)
+}
+
+fn decimal_in(locale: NumberLocale, value: f64, places: usize) -> String {
+    let rounded = format!("{:.*}", places, value.abs());
+    let (whole, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+    let whole = count_in(locale, whole.parse().unwrap_or(0));
+    let sign = if value < 0.0 && rounded.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    if fraction.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!(
+            "{}{}{}{}",
+            sign,
+            whole,
+            locale.decimal_separator(),
+            fraction
+        )
+    }
+}
+
+fn percent_in(locale: NumberLocale, value: f64) -> String {
+    match locale {
+        // 독일어와 프랑스어는 숫자와 % 사이를 띄움
+        NumberLocale::DeDe | NumberLocale::FrFr => {
+            format!("{}\u{a0}%", decimal_in(locale, value, 1))
+        }
+        _ => format!("{}%", decimal_in(locale, value, 1)),
+    }
+}
+
+fn size_in(locale: NumberLocale, value: i64, unit: SizeUnit) -> String {
+    format!("{} {}", count_in(locale, value), unit.label(locale))
+}
+
+fn bytes_in(locale: NumberLocale, value: i64) -> String {
+    if value < 1024 {
+        return size_in(locale, value, SizeUnit::Byte);
+    }
+    let mut scaled = value as f64 / 1024.0;
+    let mut unit = 1;
+    while scaled >= 1024.0 && unit < SizeUnit::SCALE.len() - 1 {
+        scaled /= 1024.0;
+        unit += 1;
+    }
+    format!(
+        "{} {}",
+        decimal_in(locale, scaled, 1),
+        SizeUnit::SCALE[unit].label(locale)
+    )
+}
+
+fn date_in(locale: NumberLocale, raw: &str) -> String {
+    if locale == NumberLocale::Plain {
+        return raw.to_string();
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(raw) {
+        let timestamp = timestamp.with_timezone(&Utc);
+        return format!(
+            "{} {} UTC",
+            date_only(locale, timestamp.date_naive()),
+            timestamp.format("%H:%M")
+        );
+    }
+    match NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        Ok(day) => date_only(locale, day),
+        Err(_) => raw.to_string(),
+    }
+}
+
+fn date_only(locale: NumberLocale, day: NaiveDate) -> String {
+    match locale {
+        NumberLocale::Plain => day.format("%Y-%m-%d").to_string(),
+        NumberLocale::EnUs => day.format("%b %-d, %Y").to_string(),
+        NumberLocale::KoKr => day.format("%Y년 %-m월 %-d일").to_string(),
+        NumberLocale::DeDe => day.format("%d.%m.%Y").to_string(),
+        NumberLocale::FrFr => day.format("%d/%m/%Y").to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        NumberLocale, SizeUnit, bytes_in, count_in, date_in, decimal_in, percent_in, size_in,
+    };
+
+    #[test]
+    fn numbers_and_sizes_follow_locale_separators_and_units() {
+        assert_eq!(count_in(NumberLocale::Plain, 16384), "16384");
+        assert_eq!(count_in(NumberLocale::EnUs, 16384), "16,384");
+        assert_eq!(count_in(NumberLocale::DeDe, 1234567), "1.234.567");
+        assert_eq!(count_in(NumberLocale::FrFr, -1234), "-1\u{a0}234");
+        assert_eq!(count_in(NumberLocale::KoKr, 999), "999");
+
+        assert_eq!(decimal_in(NumberLocale::DeDe, 1234.56, 1), "1.234,6");
+        assert_eq!(decimal_in(NumberLocale::EnUs, -0.04, 1), "0.0");
+        assert_eq!(percent_in(NumberLocale::Plain, 12.54), "12.5%");
+        assert_eq!(percent_in(NumberLocale::DeDe, 100.0), "100,0\u{a0}%");
+
+        assert_eq!(size_in(NumberLocale::EnUs, 3000, SizeUnit::Gb), "3,000 GB");
+        assert_eq!(size_in(NumberLocale::FrFr, 30, SizeUnit::Gb), "30 Go");
+        assert_eq!(bytes_in(NumberLocale::Plain, 512), "512 B");
+        assert_eq!(bytes_in(NumberLocale::Plain, 1536), "1.5 KB");
+        assert_eq!(
+            bytes_in(NumberLocale::Plain, 5 * 1024 * 1024 * 1024),
+            "5.0 GB"
+        );
+        assert_eq!(bytes_in(NumberLocale::DeDe, 1536 * 1024), "1,5 MB");
+    }
+
+    #[test]
+    fn dates_follow_locale_and_keep_unknown_values() {
+        let launched = "2026-10-01T11:03:04+09:00";
+        assert_eq!(date_in(NumberLocale::Plain, launched), launched);
+        assert_eq!(
+            date_in(NumberLocale::EnUs, launched),
+            "Oct 1, 2026 02:03 UTC"
+        );
+        assert_eq!(
+            date_in(NumberLocale::KoKr, "2026-10-01T02:03:04.000Z"),
+            "2026년 10월 1일 02:03 UTC"
+        );
+        assert_eq!(date_in(NumberLocale::DeDe, "2026-10-01"), "01.10.2026");
+        assert_eq!(date_in(NumberLocale::FrFr, "2026-10-01"), "01/10/2026");
+        assert_eq!(date_in(NumberLocale::DeDe, "-"), "-");
+        assert_eq!(date_in(NumberLocale::DeDe, ""), "");
+    }
+
+    #[test]
+    fn number_locale_cycles_through_all_styles() {
+        let mut locale = NumberLocale::Plain;
+        for _ in 0..5 {
+            locale = locale.next();
+        }
+        assert_eq!(locale, NumberLocale::Plain);
+        assert_eq!(NumberLocale::EnUs.next(), NumberLocale::KoKr);
+    }
+}
@@ -1,11 +1,41 @@
+mod age;
 mod app;
+mod ascii;
 mod aws_cli;
+mod backstage;
 mod blueprint;
+mod cidr;
 mod cli;
+mod cloudformation;
+mod compare;
+mod cron;
+mod crypto;
+mod daemon;
+mod diagram;
+mod doc_index;
 mod handler;
+mod http;
 mod i18n;
+mod iam_policy;
+mod inventory;
+mod key_macro;
+mod keychain;
+mod locale;
+mod metrics;
+mod notion;
 mod output;
+mod plain;
+mod promotion;
+mod query_playground;
+mod server;
 mod settings;
+mod stats;
+mod tag_query;
+mod telemetry;
+mod terraform;
+mod text_width;
+mod theme;
+mod tutorial;
 mod ui;
 mod update;
 
@@ -21,20 +51,43 @@ use std::time::Duration;
 use app::App;
 
 fn main() -> io::Result<()> {
-    if cli::run().is_some() {
+    let Some(options) = cli::run() else {
         return Ok(());
-    }
+    };
 
-    // Setup logging
-    let file_appender = tracing_appender::rolling::daily(".", "emd.log");
-    let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
-    tracing_subscriber::fmt()
-        .with_writer(non_blocking)
-        .with_ansi(false)
-        .init();
+    // Setup logging (and OTLP trace export when configured)
+    let _telemetry = telemetry::init(settings::load_settings().otlp_tracing.as_ref());
 
     tracing::info!("Application started");
+    aws_cli::audit::start_session();
+    if options.allow_write {
+        tracing::warn!("Write mode enabled: Name tag edits are allowed");
+        aws_cli::enable_write_mode();
+    }
+
+    // 접근성 모드: raw 모드/대체 화면 없이 표준 입출력으로 진행
+    let res = if options.no_tui {
+        plain::run()
+    } else {
+        run_tui(&options)
+    };
+
+    if let Err(err) = res {
+        tracing::error!("Application error: {}", err);
+        eprintln!("Error: {}", err);
+    }
+
+    if options.audit_summary {
+        match aws_cli::audit::session_summary() {
+            Some(summary) => println!("{}", summary),
+            None => println!("No AWS API calls were made."),
+        }
+    }
+
+    Ok(())
+}
 
+fn run_tui(options: &cli::Cli) -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -42,7 +95,11 @@ fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new();
+    app.allow_write = options.allow_write;
     app.init_auth_flow();
+    if options.tutorial {
+        app.start_tutorial();
+    }
 
     let res = run_app(&mut terminal, &mut app);
 
@@ -54,16 +111,13 @@ fn main() -> io::Result<()> {
     )?;
     terminal.show_cursor()?;
 
-    if let Err(err) = res {
-        tracing::error!("Application error: {}", err);
-        eprintln!("Error: {}", err);
-    }
-
-    Ok(())
+    res
 }
 
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()> {
     while app.running {
+        // 튜토리얼은 실제 화면 이동을 보고 다음 단계로 진행
+        app.advance_tutorial();
         terminal.draw(|f| ui::draw(f, app))?;
 
         // 로딩 중이면 실제 작업 수행
@@ -74,6 +128,18 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
 
         app.check_login_if_needed_for_current_screen();
 
+        // 매크로 재생: 로딩 작업이 끝난 뒤 다음 키를 하나씩 처리
+        if let Some(key) = app.macro_queue.pop_front() {
+            handler::handle_key(app, key);
+            continue;
+        }
+
+        // 목록 화면 자동 새로고침 (settings.auto_refresh_seconds)
+        handler::auto_refresh_list(app);
+        if app.loading {
+            continue;
+        }
+
         // 100ms 타임아웃으로 이벤트 폴링
         if event::poll(Duration::from_millis(100))? {
             match event::read()? {
@@ -0,0 +1,288 @@
+// 데몬 모드 Prometheus 메트릭: 블루프린트별 생성 시간, 실패 수, 문서화한 리소스 수
+use crate::daemon::JobOutcome;
+use crate::http;
+use std::collections::BTreeMap;
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct JobStats {
+    successes: u64,
+    failures: u64,
+    duration_sum: f64,
+    last_duration: f64,
+    resources: usize,
+    last_success: Option<i64>,
+}
+
+/// Per-blueprint counters scraped from `GET /metrics`.
+#[derive(Debug, Default)]
+pub struct DaemonMetrics {
+    jobs: Mutex<BTreeMap<String, JobStats>>,
+}
+
+impl DaemonMetrics {
+    /// Register the configured blueprints so they show up with zero values before the first run.
+    pub fn new<'a>(blueprints: impl IntoIterator<Item = &'a str>) -> Self {
+        let jobs = blueprints
+            .into_iter()
+            .map(|blueprint| (blueprint.to_string(), JobStats::default()))
+            .collect();
+        Self {
+            jobs: Mutex::new(jobs),
+        }
+    }
+
+    pub fn record(&self, outcome: &JobOutcome, finished_at_unix: i64) {
+        let Ok(mut jobs) = self.jobs.lock() else {
+            return;
+        };
+        let stats = jobs.entry(outcome.blueprint.clone()).or_default();
+        stats.duration_sum += outcome.duration_secs;
+        stats.last_duration = outcome.duration_secs;
+        if outcome.error.is_some() {
+            stats.failures += 1;
+        } else {
+            stats.successes += 1;
+            stats.resources = outcome.resources;
+            stats.last_success = Some(finished_at_unix);
+        }
+    }
+
+    /// Prometheus text exposition format (version 0.0.4).
+    pub fn render(&self) -> String {
+        let jobs = match self.jobs.lock() {
+            Ok(jobs) => jobs.clone(),
+            Err(_) => return String::new(),
+        };
+        let mut lines = Vec::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: Vec<String>| {
+            lines.push(format!("# HELP {} {}", name, help));
+            lines.push(format!("# TYPE {} {}", name, kind));
+            lines.extend(samples);
+        };
+
+        metric(
+            "emd_daemon_runs_total",
+            "counter",
+            "Scheduled blueprint generations by result.",
+            jobs.iter()
+                .flat_map(|(blueprint, stats)| {
+                    [
+                        format!(
+                            "emd_daemon_runs_total{{blueprint=\"{}\",status=\"success\"}} {}",
+                            label_value(blueprint),
+                            stats.successes
+                        ),
+                        format!(
+                            "emd_daemon_runs_total{{blueprint=\"{}\",status=\"failure\"}} {}",
+                            label_value(blueprint),
+                            stats.failures
+                        ),
+                    ]
+                })
+                .collect(),
+        );
+        metric(
+            "emd_daemon_failures_total",
+            "counter",
+            "Failed scheduled blueprint generations.",
+            samples(&jobs, "emd_daemon_failures_total", |stats| {
+                stats.failures.to_string()
+            }),
+        );
+        metric(
+            "emd_daemon_generation_duration_seconds",
+            "summary",
+            "Time spent generating a blueprint document.",
+            jobs.iter()
+                .flat_map(|(blueprint, stats)| {
+                    [
+                        format!(
+                            "emd_daemon_generation_duration_seconds_sum{{blueprint=\"{}\"}} {}",
+                            label_value(blueprint),
+                            stats.duration_sum
+                        ),
+                        format!(
+                            "emd_daemon_generation_duration_seconds_count{{blueprint=\"{}\"}} {}",
+                            label_value(blueprint),
+                            stats.successes + stats.failures
+                        ),
+                    ]
+                })
+                .collect(),
+        );
+        metric(
+            "emd_daemon_last_generation_duration_seconds",
+            "gauge",
+            "Duration of the most recent generation.",
+            samples(
+                &jobs,
+                "emd_daemon_last_generation_duration_seconds",
+                |stats| stats.last_duration.to_string(),
+            ),
+        );
+        metric(
+            "emd_daemon_resources_documented",
+            "gauge",
+            "Resources in the most recent successful document.",
+            samples(&jobs, "emd_daemon_resources_documented", |stats| {
+                stats.resources.to_string()
+            }),
+        );
+        metric(
+            "emd_daemon_last_success_timestamp_seconds",
+            "gauge",
+            "Unix time of the most recent successful generation (0 if none).",
+            samples(
+                &jobs,
+                "emd_daemon_last_success_timestamp_seconds",
+                |stats| stats.last_success.unwrap_or_default().to_string(),
+            ),
+        );
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+fn samples(
+    jobs: &BTreeMap<String, JobStats>,
+    name: &str,
+    value: impl Fn(&JobStats) -> String,
+) -> Vec<String> {
+    jobs.iter()
+        .map(|(blueprint, stats)| {
+            format!(
+                "{}{{blueprint=\"{}\"}} {}",
+                name,
+                label_value(blueprint),
+                value(stats)
+            )
+        })
+        .collect()
+}
+
+// 레이블 값의 \, ", 줄바꿈은 이스케이프
+fn label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Serve `GET /metrics` on `addr` from a background thread.
+pub fn serve(addr: &str, metrics: Arc<DaemonMetrics>) -> Result<(), String> {
+    let listener =
+        TcpListener::bind(addr).map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if let Err(e) = handle_connection(stream, &metrics) {
+                        tracing::warn!("Metrics request failed: {}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Metrics connection failed: {}", e),
+            }
+        }
+    });
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, metrics: &DaemonMetrics) -> std::io::Result<()> {
+    let (status, body) = match http::read_request(&stream) {
+        Ok(request_line) => route(&request_line, metrics),
+        Err(e) if http::is_too_large(&e) => (http::TOO_LARGE_STATUS, format!("{}\n", e)),
+        Err(e) => return Err(e),
+    };
+    http::write_response(&stream, status, CONTENT_TYPE, &body)
+}
+
+fn route(request_line: &str, metrics: &DaemonMetrics) -> (&'static str, String) {
+    let mut parts = request_line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", metrics.render()),
+        (Some("GET"), _) => ("404 Not Found", "Not Found\n".to_string()),
+        _ => ("405 Method Not Allowed", "Method Not Allowed\n".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DaemonMetrics, route};
+    use crate::daemon::JobOutcome;
+
+    fn outcome(blueprint: &str, error: Option<&str>, duration_secs: f64) -> JobOutcome {
+        JobOutcome {
+            blueprint: blueprint.to_string(),
+            output: None,
+            resources: 4,
+            error: error.map(str::to_string),
+            duration_secs,
+            finished_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn render_reports_runs_durations_and_resources() {
+        let metrics = DaemonMetrics::new(["prod-network", "idle"]);
+        metrics.record(&outcome("prod-network", None, 1.5), 1_772_400_000);
+        metrics.record(
+            &outcome("prod-network", Some("ExpiredToken"), 0.5),
+            1_772_403_600,
+        );
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE emd_daemon_runs_total counter"));
+        assert!(
+            text.contains("emd_daemon_runs_total{blueprint=\"prod-network\",status=\"success\"} 1")
+        );
+        assert!(
+            text.contains("emd_daemon_runs_total{blueprint=\"prod-network\",status=\"failure\"} 1")
+        );
+        assert!(text.contains("emd_daemon_failures_total{blueprint=\"prod-network\"} 1"));
+        assert!(
+            text.contains(
+                "emd_daemon_generation_duration_seconds_sum{blueprint=\"prod-network\"} 2"
+            )
+        );
+        assert!(text.contains(
+            "emd_daemon_generation_duration_seconds_count{blueprint=\"prod-network\"} 2"
+        ));
+        assert!(text.contains(
+            "emd_daemon_last_generation_duration_seconds{blueprint=\"prod-network\"} 0.5"
+        ));
+        assert!(text.contains("emd_daemon_resources_documented{blueprint=\"prod-network\"} 4"));
+        assert!(text.contains(
+            "emd_daemon_last_success_timestamp_seconds{blueprint=\"prod-network\"} 1772400000"
+        ));
+        // 아직 실행되지 않은 작업도 0으로 노출
+        assert!(text.contains("emd_daemon_failures_total{blueprint=\"idle\"} 0"));
+    }
+
+    #[test]
+    fn render_escapes_label_values() {
+        let metrics = DaemonMetrics::new(["team \"a\"\\b"]);
+        assert!(
+            metrics
+                .render()
+                .contains("emd_daemon_failures_total{blueprint=\"team \\\"a\\\"\\\\b\"} 0")
+        );
+    }
+
+    #[test]
+    fn route_serves_metrics_only() {
+        let metrics = DaemonMetrics::new(["prod-network"]);
+        let (status, body) = route("GET /metrics HTTP/1.1\r\n", &metrics);
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("emd_daemon_runs_total"));
+        assert_eq!(route("GET / HTTP/1.1\r\n", &metrics).0, "404 Not Found");
+        assert_eq!(
+            route("POST /metrics HTTP/1.1\r\n", &metrics).0,
+            "405 Method Not Allowed"
+        );
+    }
+}
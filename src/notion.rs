@@ -0,0 +1,395 @@
+// Notion 데이터베이스 내보내기: 리소스마다 페이지 하나 (리소스 ID + 리전으로 기존 페이지를 찾아 갱신)
+use crate::settings::NotionExport;
+use serde_json::{Value, json};
+
+const API_BASE: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+const TOKEN_ENV: &str = "NOTION_TOKEN";
+const KEYCHAIN_ACCOUNT: &str = "notion-token";
+// Notion API 제한: 요청당 블록 100개, 텍스트 객체당 2000자
+const MAX_BLOCKS_PER_REQUEST: usize = 100;
+const MAX_TEXT_LEN: usize = 2000;
+// 코드 블록에 그대로 넘길 수 있는 언어 (그 외는 plain text)
+const CODE_LANGUAGES: &[&str] = &["bash", "json", "mermaid", "markdown", "shell", "yaml"];
+
+/// One resource document to create or update in the Notion database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotionPage {
+    pub title: String,
+    pub resource_type: String,
+    pub region: String,
+    pub resource_id: String,
+    pub markdown: String,
+}
+
+/// Create or update a page per resource and return how many pages were written.
+pub fn export_pages(config: &NotionExport, pages: &[NotionPage]) -> Result<usize, String> {
+    let client = NotionClient::new(&notion_token()?)?;
+    for page in pages {
+        client.upsert_page(&config.database_id, page)?;
+    }
+    Ok(pages.len())
+}
+
+// NOTION_TOKEN 환경 변수 우선, 없으면 OS 키체인의 notion-token 항목
+fn notion_token() -> Result<String, String> {
+    if let Ok(token) = std::env::var(TOKEN_ENV)
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+    crate::keychain::get_secret(KEYCHAIN_ACCOUNT)?
+        .ok_or_else(|| format!("{} is not set", TOKEN_ENV))
+}
+
+struct NotionClient {
+    http: reqwest::blocking::Client,
+    token: String,
+}
+
+impl NotionClient {
+    fn new(token: &str) -> Result<Self, String> {
+        let http = reqwest::blocking::Client::builder()
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            http,
+            token: token.to_string(),
+        })
+    }
+
+    fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, String> {
+        let mut request = self
+            .http
+            .request(method, format!("{}{}", API_BASE, path))
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_VERSION);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = request.send().map_err(|e| e.to_string())?;
+        let status = response.status();
+        let value: Value = response.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = value["message"].as_str().unwrap_or_default();
+            return Err(format!("Notion API {}: {}", status.as_u16(), message));
+        }
+        Ok(value)
+    }
+
+    fn upsert_page(&self, database_id: &str, page: &NotionPage) -> Result<(), String> {
+        let found = self.request(
+            reqwest::Method::POST,
+            &format!("/databases/{}/query", database_id),
+            Some(&find_page_query(page)),
+        )?;
+        let existing = found["results"]
+            .as_array()
+            .and_then(|results| results.first())
+            .and_then(|result| result["id"].as_str())
+            .map(|id| id.to_string());
+
+        let blocks = markdown_to_blocks(&page.markdown);
+        let mut chunks = blocks.chunks(MAX_BLOCKS_PER_REQUEST);
+        let page_id = match existing {
+            Some(page_id) => {
+                let body = json!({ "properties": page_properties(page) });
+                self.request(
+                    reqwest::Method::PATCH,
+                    &format!("/pages/{}", page_id),
+                    Some(&body),
+                )?;
+                self.clear_children(&page_id)?;
+                page_id
+            }
+            None => {
+                let body = json!({
+                    "parent": { "database_id": database_id },
+                    "properties": page_properties(page),
+                    "children": chunks.next().unwrap_or_default(),
+                });
+                let created = self.request(reqwest::Method::POST, "/pages", Some(&body))?;
+                created["id"].as_str().unwrap_or_default().to_string()
+            }
+        };
+
+        for chunk in chunks {
+            self.request(
+                reqwest::Method::PATCH,
+                &format!("/blocks/{}/children", page_id),
+                Some(&json!({ "children": chunk })),
+            )?;
+        }
+        Ok(())
+    }
+
+    // 기존 본문 블록을 모두 지운 뒤 새 마크다운으로 다시 채움
+    fn clear_children(&self, page_id: &str) -> Result<(), String> {
+        let mut block_ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut path = format!("/blocks/{}/children?page_size=100", page_id);
+            if let Some(ref cursor) = cursor {
+                path.push_str(&format!("&start_cursor={}", cursor));
+            }
+            let listed = self.request(reqwest::Method::GET, &path, None)?;
+            if let Some(results) = listed["results"].as_array() {
+                block_ids.extend(
+                    results
+                        .iter()
+                        .filter_map(|block| block["id"].as_str().map(|id| id.to_string())),
+                );
+            }
+            cursor = listed["next_cursor"].as_str().map(|c| c.to_string());
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        for block_id in block_ids {
+            self.request(
+                reqwest::Method::DELETE,
+                &format!("/blocks/{}", block_id),
+                None,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// 같은 리소스 ID라도 리전이 다르면 별도 페이지
+fn find_page_query(page: &NotionPage) -> Value {
+    json!({
+        "filter": {
+            "and": [
+                { "property": "Resource ID", "rich_text": { "equals": page.resource_id } },
+                { "property": "Region", "rich_text": { "equals": page.region } },
+            ]
+        },
+        "page_size": 1,
+    })
+}
+
+fn page_properties(page: &NotionPage) -> Value {
+    json!({
+        "Name": { "title": rich_text(&page.title) },
+        "Type": { "select": { "name": page.resource_type } },
+        "Region": { "rich_text": rich_text(&page.region) },
+        "Resource ID": { "rich_text": rich_text(&page.resource_id) },
+    })
+}
+
+fn rich_text(text: &str) -> Value {
+    let chars: Vec<char> = text.chars().collect();
+    let parts: Vec<Value> = chars
+        .chunks(MAX_TEXT_LEN)
+        .map(|chunk| {
+            json!({ "type": "text", "text": { "content": chunk.iter().collect::<String>() } })
+        })
+        .collect();
+    Value::Array(parts)
+}
+
+fn text_block(block_type: &str, text: &str) -> Value {
+    json!({
+        "object": "block",
+        "type": block_type,
+        block_type: { "rich_text": rich_text(text) },
+    })
+}
+
+fn code_block(code: &str, language: &str) -> Value {
+    let language = if CODE_LANGUAGES.contains(&language) {
+        language
+    } else {
+        "plain text"
+    };
+    json!({
+        "object": "block",
+        "type": "code",
+        "code": { "rich_text": rich_text(code), "language": language },
+    })
+}
+
+fn table_cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    row.split('|')
+        .map(|cell| cell.trim().replace("<br>", "\n"))
+        .collect()
+}
+
+// |:---|---:| 같은 정렬 행
+fn is_separator_row(cells: &[String]) -> bool {
+    cells
+        .iter()
+        .all(|cell| !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':')))
+}
+
+fn table_block(rows: &[&str]) -> Value {
+    let mut parsed: Vec<Vec<String>> = rows.iter().map(|row| table_cells(row)).collect();
+    let has_header = parsed.len() > 1 && is_separator_row(&parsed[1]);
+    if has_header {
+        parsed.remove(1);
+    }
+    let width = parsed.iter().map(|cells| cells.len()).max().unwrap_or(1);
+
+    let children: Vec<Value> = parsed
+        .into_iter()
+        .map(|mut cells| {
+            cells.resize(width, String::new());
+            let cells: Vec<Value> = cells.iter().map(|cell| rich_text(cell)).collect();
+            json!({ "object": "block", "type": "table_row", "table_row": { "cells": cells } })
+        })
+        .collect();
+    json!({
+        "object": "block",
+        "type": "table",
+        "table": {
+            "table_width": width,
+            "has_column_header": has_header,
+            "has_row_header": false,
+            "children": children,
+        },
+    })
+}
+
+/// Convert the generated markdown into Notion blocks (headings, lists, tables, code).
+pub fn markdown_to_blocks(markdown: &str) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines().peekable();
+    while let Some(line) = lines.next() {
+        let line = line.trim_end();
+        if let Some(language) = line.strip_prefix("```") {
+            let mut code = Vec::new();
+            for next in lines.by_ref() {
+                if next.trim_end() == "```" {
+                    break;
+                }
+                code.push(next);
+            }
+            blocks.push(code_block(&code.join("\n"), language.trim()));
+        } else if line.starts_with('|') {
+            let mut rows = vec![line];
+            while let Some(next) = lines.next_if(|next| next.starts_with('|')) {
+                rows.push(next.trim_end());
+            }
+            blocks.push(table_block(&rows));
+        } else if let Some(text) = line.strip_prefix("### ") {
+            blocks.push(text_block("heading_3", text));
+        } else if let Some(text) = line.strip_prefix("## ") {
+            blocks.push(text_block("heading_2", text));
+        } else if let Some(text) = line.strip_prefix("# ") {
+            blocks.push(text_block("heading_1", text));
+        } else if let Some(text) = line.trim_start().strip_prefix("- ") {
+            blocks.push(text_block("bulleted_list_item", text));
+        } else if line == "---" {
+            blocks.push(json!({ "object": "block", "type": "divider", "divider": {} }));
+        } else if !line.trim().is_empty() {
+            blocks.push(text_block("paragraph", line));
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NotionPage, find_page_query, markdown_to_blocks, page_properties, rich_text};
+
+    fn sample_page() -> NotionPage {
+        NotionPage {
+            title: "orders".to_string(),
+            resource_type: "SQS".to_string(),
+            region: "ap-northeast-2".to_string(),
+            resource_id: "orders".to_string(),
+            markdown: "## SQS Queue (orders)\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn markdown_to_blocks_converts_headings_tables_and_code() {
+        let markdown = "## SQS Queue (orders)\n\n| Item | Value |\n|:---|:---|\n| Name | orders |\n| Tags | a<br>b |\n\n### Diagram\n\n```mermaid\ngraph TD\n  A --> B\n```\n- note\n---\nplain text\n";
+        let blocks = markdown_to_blocks(markdown);
+        let types: Vec<&str> = blocks
+            .iter()
+            .map(|block| block["type"].as_str().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                "heading_2",
+                "table",
+                "heading_3",
+                "code",
+                "bulleted_list_item",
+                "divider",
+                "paragraph"
+            ]
+        );
+
+        let table = &blocks[1]["table"];
+        assert_eq!(table["table_width"], 2);
+        assert_eq!(table["has_column_header"], true);
+        let rows = table["children"].as_array().expect("table rows");
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[2]["table_row"]["cells"][1][0]["text"]["content"],
+            "a\nb"
+        );
+
+        let code = &blocks[3]["code"];
+        assert_eq!(code["language"], "mermaid");
+        assert_eq!(
+            code["rich_text"][0]["text"]["content"],
+            "graph TD\n  A --> B"
+        );
+    }
+
+    #[test]
+    fn markdown_to_blocks_pads_rows_and_falls_back_to_plain_text_code() {
+        let blocks = markdown_to_blocks("| a | b | c |\n| 1 |\n```hcl\nx = 1\n```\n");
+        let table = &blocks[0]["table"];
+        assert_eq!(table["has_column_header"], false);
+        assert_eq!(
+            table["children"][1]["table_row"]["cells"]
+                .as_array()
+                .map(|c| c.len()),
+            Some(3)
+        );
+        assert_eq!(blocks[1]["code"]["language"], "plain text");
+    }
+
+    #[test]
+    fn rich_text_splits_long_text() {
+        let long = "x".repeat(4500);
+        let parts = rich_text(&long);
+        let parts = parts.as_array().expect("parts");
+        assert_eq!(parts.len(), 3);
+        assert_eq!(
+            parts[2]["text"]["content"].as_str().map(|s| s.len()),
+            Some(500)
+        );
+    }
+
+    #[test]
+    fn page_properties_and_query_use_resource_fields() {
+        let page = sample_page();
+        let properties = page_properties(&page);
+        assert_eq!(properties["Name"]["title"][0]["text"]["content"], "orders");
+        assert_eq!(properties["Type"]["select"]["name"], "SQS");
+        assert_eq!(
+            properties["Region"]["rich_text"][0]["text"]["content"],
+            "ap-northeast-2"
+        );
+
+        let query = find_page_query(&page);
+        assert_eq!(query["filter"]["and"][0]["rich_text"]["equals"], "orders");
+        assert_eq!(query["filter"]["and"][1]["property"], "Region");
+    }
+}
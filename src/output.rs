@@ -1,13 +1,27 @@
 use std::fs;
 use std::io;
+use std::path::Path;
 
 pub fn save_markdown(filename: &str, content: &str) -> io::Result<()> {
     fs::write(filename, content)
 }
 
+/// Write `(file name, content)` pairs into `dir`, creating it if needed.
+/// Returns the written paths in the same order.
+pub fn save_markdown_files(dir: &str, files: &[(String, String)]) -> io::Result<Vec<String>> {
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::with_capacity(files.len());
+    for (filename, content) in files {
+        let path = Path::new(dir).join(filename).to_string_lossy().to_string();
+        save_markdown(&path, content)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::save_markdown;
+    use super::{save_markdown, save_markdown_files};
     use std::fs;
 
     #[test]
@@ -30,4 +44,25 @@ mod tests {
 
         let _ = fs::remove_file(&path);
     }
+
+    #[test]
+    fn save_markdown_files_creates_directory_and_writes_each_file() {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("emd-output-files-test-{}", std::process::id()));
+        let dir = dir.to_string_lossy().to_string();
+        let files = vec![
+            ("index.md".to_string(), "# index\n".to_string()),
+            ("01-ec2-web.md".to_string(), "## EC2\n".to_string()),
+        ];
+
+        let paths = save_markdown_files(&dir, &files).expect("save markdown files");
+        assert_eq!(paths.len(), 2);
+        assert!(paths[1].ends_with("01-ec2-web.md"));
+        assert_eq!(
+            fs::read_to_string(&paths[0]).expect("read index"),
+            "# index\n"
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
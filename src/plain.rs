@@ -0,0 +1,344 @@
+// 접근성 모드(--no-tui): 대체 화면과 색상 없이 TUI와 같은 흐름을 번호 선택 프롬프트로 진행 (화면 낭독기용)
+// 화면 전환과 조회는 TUI의 키 처리/로딩 로직을 그대로 사용
+use crate::app::{App, SERVICE_KEYS, Screen};
+use crate::ascii::to_ascii;
+use crate::handler;
+use crate::i18n::I18n;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::borrow::Cow;
+use std::io::{self, BufRead, Write};
+
+struct Prompt<R, W> {
+    input: R,
+    output: W,
+    i18n: I18n,
+    // ASCII 모드면 목록/문서의 기호와 이모지를 ASCII로 바꿔 출력
+    ascii: bool,
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    fn line(&mut self, text: &str) -> io::Result<()> {
+        let text = self.text(text);
+        writeln!(self.output, "{}", text)
+    }
+
+    fn text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.ascii {
+            to_ascii(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Print a numbered list and read a choice. `None` means `0` (back) or end of input.
+    fn choose(&mut self, title: &str, items: &[String], back: &str) -> io::Result<Option<usize>> {
+        writeln!(self.output)?;
+        writeln!(self.output, "{}", self.text(title))?;
+        for (index, item) in items.iter().enumerate() {
+            writeln!(self.output, "  {}. {}", index + 1, self.text(item))?;
+        }
+        writeln!(self.output, "  0. {}", self.text(back))?;
+
+        loop {
+            write!(self.output, "{} ", self.i18n.plain_choice_prompt())?;
+            self.output.flush()?;
+            let mut answer = String::new();
+            if self.input.read_line(&mut answer)? == 0 {
+                writeln!(self.output)?;
+                return Ok(None);
+            }
+            match answer.trim().parse::<usize>() {
+                Ok(0) => return Ok(None),
+                Ok(choice) if choice <= items.len() => return Ok(Some(choice - 1)),
+                _ => {
+                    let message = self.i18n.plain_invalid_choice(answer.trim());
+                    self.line(&message)?;
+                }
+            }
+        }
+    }
+}
+
+/// Run the accessible mode on stdin/stdout until the user exits.
+pub fn run() -> io::Result<()> {
+    let mut app = App::new();
+    app.init_auth_flow();
+    let stdin = io::stdin();
+    let mut prompt = Prompt {
+        input: stdin.lock(),
+        output: io::stdout(),
+        i18n: I18n::new(app.settings.language),
+        ascii: app.ascii,
+    };
+
+    prompt.line(app.i18n.plain_mode_title())?;
+    if login(&mut app, &mut prompt)? {
+        main_menu(&mut app, &mut prompt)?;
+    }
+    Ok(())
+}
+
+// 키 하나를 TUI 핸들러로 보내고 로딩 작업이 끝날 때까지 처리
+fn press<R: BufRead, W: Write>(
+    app: &mut App,
+    prompt: &mut Prompt<R, W>,
+    code: KeyCode,
+) -> io::Result<()> {
+    app.message.clear();
+    handler::handle_key(app, KeyEvent::new(code, KeyModifiers::NONE));
+    if app.loading {
+        prompt.line(app.i18n.loading_msg())?;
+    }
+    while app.loading {
+        handler::process_loading(app);
+    }
+    // 로딩 안내는 이미 출력했으므로 결과 메시지만 남김
+    if app.message == app.i18n.loading_msg() {
+        app.message.clear();
+    }
+    Ok(())
+}
+
+fn print_message<R: BufRead, W: Write>(app: &App, prompt: &mut Prompt<R, W>) -> io::Result<()> {
+    if app.message.is_empty() {
+        return Ok(());
+    }
+    prompt.line(&app.message)
+}
+
+fn login<R: BufRead, W: Write>(app: &mut App, prompt: &mut Prompt<R, W>) -> io::Result<bool> {
+    loop {
+        if app.available_profiles.is_empty() {
+            prompt.line(app.i18n.profile_not_found())?;
+            prompt.line(app.i18n.aws_configure_hint())?;
+            return Ok(false);
+        }
+
+        let Some(index) = prompt.choose(
+            app.i18n.profile_select_prompt(),
+            &app.available_profiles,
+            app.i18n.exit(),
+        )?
+        else {
+            return Ok(false);
+        };
+        app.selected_profile_index = index;
+        app.select_current_profile_and_login();
+
+        if app.screen != Screen::Login {
+            if let Some(info) = &app.login_info {
+                prompt.line(&format!("{} ({})", app.i18n.aws_login_verified(), info))?;
+            }
+            print_message(app, prompt)?;
+            return Ok(true);
+        }
+        prompt.line(app.i18n.aws_login_required())?;
+        if let Some(error) = &app.login_error {
+            prompt.line(error.as_str())?;
+        }
+    }
+}
+
+fn main_menu<R: BufRead, W: Write>(app: &mut App, prompt: &mut Prompt<R, W>) -> io::Result<()> {
+    loop {
+        let items = vec![
+            app.i18n.plain_document_resource().to_string(),
+            app.i18n.plain_document_blueprint().to_string(),
+        ];
+        match prompt.choose(app.i18n.plain_main_menu(), &items, app.i18n.exit())? {
+            Some(0) => document_resource(app, prompt)?,
+            Some(1) => document_blueprint(app, prompt)?,
+            _ => return Ok(()),
+        }
+    }
+}
+
+// 리전 → 서비스 → 리소스 → 미리보기 (단일 모드)
+fn document_resource<R: BufRead, W: Write>(
+    app: &mut App,
+    prompt: &mut Prompt<R, W>,
+) -> io::Result<()> {
+    app.blueprint_mode = false;
+    let lang = app.settings.language;
+    let regions: Vec<String> = app
+        .regions()
+        .iter()
+        .map(|region| format!("{} ({})", region.code, region.name(lang)))
+        .collect();
+    let Some(region) = prompt.choose(app.i18n.region(), &regions, app.i18n.back())? else {
+        return Ok(());
+    };
+    app.selected_region = region;
+    app.select_region();
+
+    loop {
+        let services: Vec<String> = app
+            .service_order()
+            .iter()
+            .map(|&service| SERVICE_KEYS[service].to_string())
+            .collect();
+        let Some(position) = prompt.choose(app.i18n.service(), &services, app.i18n.back())? else {
+            return Ok(());
+        };
+        app.screen = Screen::ServiceSelect;
+        app.selected_service = position;
+        press(app, prompt, KeyCode::Enter)?;
+
+        let Some((resource_type, resources)) = app.current_resource_list() else {
+            print_message(app, prompt)?;
+            continue;
+        };
+        let title = resource_type.display();
+        let items: Vec<String> = resources
+            .iter()
+            .map(|resource| {
+                if resource.state.is_empty() {
+                    format!("{} ({})", resource.name, resource.id)
+                } else {
+                    format!("{} ({}) [{}]", resource.name, resource.id, resource.state)
+                }
+            })
+            .collect();
+        if items.is_empty() {
+            prompt.line(app.i18n.no_resources())?;
+            continue;
+        }
+        let Some(index) = prompt.choose(title, &items, app.i18n.back())? else {
+            continue;
+        };
+        app.selected_index = index;
+        press(app, prompt, KeyCode::Enter)?;
+
+        if app.screen == Screen::Preview {
+            show_preview(app, prompt)?;
+        } else {
+            print_message(app, prompt)?;
+        }
+    }
+}
+
+fn document_blueprint<R: BufRead, W: Write>(
+    app: &mut App,
+    prompt: &mut Prompt<R, W>,
+) -> io::Result<()> {
+    let blueprints: Vec<String> = app
+        .blueprint_store
+        .blueprints
+        .iter()
+        .map(|blueprint| {
+            format!(
+                "{} ({})",
+                blueprint.name,
+                app.i18n.plain_resource_count(blueprint.resources.len())
+            )
+        })
+        .collect();
+    if blueprints.is_empty() {
+        return prompt.line(app.i18n.plain_no_blueprints());
+    }
+    let Some(index) = prompt.choose(app.i18n.blueprint(), &blueprints, app.i18n.back())? else {
+        return Ok(());
+    };
+
+    // 블루프린트 목록의 0번은 "새 블루프린트" 항목
+    app.screen = Screen::BlueprintSelect;
+    app.selected_blueprint_index = index + 1;
+    press(app, prompt, KeyCode::Char('g'))?;
+
+    if app.screen == Screen::BlueprintPreview {
+        show_preview(app, prompt)?;
+    } else {
+        print_message(app, prompt)?;
+    }
+    app.screen = Screen::BlueprintSelect;
+    Ok(())
+}
+
+// 문서 본문을 그대로 출력하고 저장 여부 확인
+fn show_preview<R: BufRead, W: Write>(app: &mut App, prompt: &mut Prompt<R, W>) -> io::Result<()> {
+    prompt.line("")?;
+    prompt.line(app.preview_text())?;
+
+    let items = vec![format!("{} ({})", app.i18n.save(), app.preview_filename)];
+    if prompt
+        .choose(app.i18n.preview(), &items, app.i18n.back())?
+        .is_some()
+    {
+        press(app, prompt, KeyCode::Char('s'))?;
+        print_message(app, prompt)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prompt, main_menu};
+    use crate::app::{App, Screen};
+    use crate::i18n::{I18n, Language};
+
+    fn run_main_menu(app: &mut App, input: &str) -> String {
+        let mut output = Vec::new();
+        let mut prompt = Prompt {
+            input: input.as_bytes(),
+            output: &mut output,
+            i18n: I18n::new(Language::English),
+            ascii: false,
+        };
+        main_menu(app, &mut prompt).expect("plain mode");
+        String::from_utf8(output).expect("utf-8 output")
+    }
+
+    #[test]
+    fn choose_rejects_out_of_range_and_treats_eof_as_back() {
+        let mut output = Vec::new();
+        let mut prompt = Prompt {
+            input: "7\nabc\n2\n".as_bytes(),
+            output: &mut output,
+            i18n: I18n::new(Language::English),
+            ascii: false,
+        };
+        let items = vec!["first".to_string(), "second".to_string()];
+        assert_eq!(prompt.choose("Pick", &items, "Back").unwrap(), Some(1));
+        assert_eq!(prompt.choose("Pick", &items, "Back").unwrap(), None);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Pick\n  1. first\n  2. second\n  0. Back\n"));
+        assert!(output.contains("Invalid choice: 7"));
+        assert!(output.contains("Invalid choice: abc"));
+    }
+
+    #[test]
+    fn single_resource_flow_prints_document_without_escape_codes() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintSelect;
+        app.settings.pinned_services.clear();
+        app.settings.sort_services_by_usage = false;
+
+        // 단일 모드 → 첫 리전 → EC2 → 첫 인스턴스 → 저장 안 함 → 뒤로 → 종료
+        let output = run_main_menu(&mut app, "1\n1\n1\n1\n0\n0\n0\n");
+        assert!(output.contains("  1. EC2\n"));
+        assert!(output.contains("ec2-test (i-test) [available]"));
+        assert!(output.contains(&app.preview_content));
+        assert!(!app.preview_content.is_empty());
+        assert!(!output.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn ascii_prompt_replaces_symbols_in_titles_and_items() {
+        let mut output = Vec::new();
+        let mut prompt = Prompt {
+            input: "1\n".as_bytes(),
+            output: &mut output,
+            i18n: I18n::new(Language::English),
+            ascii: true,
+        };
+        let items = vec!["✓ web-1".to_string(), "☁️ Internet".to_string()];
+        assert_eq!(prompt.choose("▶ Pick", &items, "Back").unwrap(), Some(0));
+        prompt.line("Saved → out.md").unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("> Pick\n  1. v web-1\n  2. * Internet\n"));
+        assert!(output.contains("Saved > out.md"));
+        assert!(output.is_ascii());
+    }
+}
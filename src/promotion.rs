@@ -0,0 +1,413 @@
+use crate::blueprint::{Blueprint, BlueprintResource};
+use crate::compare::{FieldDiff, diff_fields};
+use crate::i18n::{I18n, Language};
+use std::collections::BTreeSet;
+
+// 환경을 나타내는 이름 토큰 (리소스 짝짓기와 값 비교 시 무시)
+const ENVIRONMENT_TOKENS: &[&str] = &[
+    "prod",
+    "production",
+    "prd",
+    "staging",
+    "stage",
+    "stg",
+    "dev",
+    "development",
+    "qa",
+];
+
+#[derive(Debug, Clone)]
+pub struct ResourceDifference {
+    pub source: BlueprintResource,
+    pub target: BlueprintResource,
+    pub fields: Vec<FieldDiff>,
+}
+
+/// Differences to review before promoting one blueprint's environment to another's.
+#[derive(Debug, Clone)]
+pub struct PromotionChecklist {
+    pub source_name: String,
+    pub target_name: String,
+    pub missing_in_target: Vec<BlueprintResource>,
+    pub missing_in_source: Vec<BlueprintResource>,
+    pub differences: Vec<ResourceDifference>,
+    // 상세 조회에 실패해 비교하지 못한 리소스 쌍 (원본 기준)
+    pub unreadable: Vec<BlueprintResource>,
+}
+
+fn tokens(value: &str) -> Vec<String> {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+// 환경 토큰을 뺀 나머지로 이름/값을 비교 ("staging-web" == "prod-web")
+fn without_environment(value: &str, environment: &BTreeSet<String>) -> Vec<String> {
+    tokens(value)
+        .into_iter()
+        .filter(|token| !environment.contains(token))
+        .collect()
+}
+
+fn is_identifier(token: &str) -> bool {
+    if token.starts_with("arn:") || token.contains(".amazonaws.com") {
+        return true;
+    }
+    // IPv4 주소/CIDR
+    if token
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.' || c == '/')
+    {
+        return token.contains('.');
+    }
+    // i-0123abcd, sg-0123abcd, vpc-0123abcd ...
+    token.split_once('-').is_some_and(|(prefix, id)| {
+        !prefix.is_empty()
+            && prefix.chars().all(|c| c.is_ascii_lowercase())
+            && id.len() >= 8
+            && id.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+// 환경마다 당연히 다른 값 (ID, ARN, IP, 엔드포인트)으로만 이루어진 필드인지
+fn is_identifier_only(value: &str) -> bool {
+    let parts: Vec<&str> = value
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '|' | '(' | ')' | '`'))
+        .filter(|part| !part.is_empty())
+        .collect();
+    !parts.is_empty() && parts.iter().all(|part| is_identifier(part))
+}
+
+fn needs_review(diff: &FieldDiff, environment: &BTreeSet<String>) -> bool {
+    diff.is_different()
+        && !(is_identifier_only(&diff.left) && is_identifier_only(&diff.right))
+        && without_environment(&diff.left, environment)
+            != without_environment(&diff.right, environment)
+}
+
+impl PromotionChecklist {
+    /// Pair resources of the same type whose names match once environment
+    /// tokens are removed, then diff each pair's detail markdown from `fetch`.
+    pub fn new(
+        source: &Blueprint,
+        target: &Blueprint,
+        mut fetch: impl FnMut(&BlueprintResource) -> Option<String>,
+    ) -> Self {
+        // 두 블루프린트 이름에서 서로 다른 토큰 (예: checkout-blue / checkout-green)도 환경 토큰으로 취급
+        let source_tokens: BTreeSet<String> = tokens(&source.name).into_iter().collect();
+        let target_tokens: BTreeSet<String> = tokens(&target.name).into_iter().collect();
+        let mut environment: BTreeSet<String> = ENVIRONMENT_TOKENS
+            .iter()
+            .map(|token| token.to_string())
+            .collect();
+        environment.extend(source_tokens.symmetric_difference(&target_tokens).cloned());
+
+        let mut checklist = Self {
+            source_name: source.name.clone(),
+            target_name: target.name.clone(),
+            missing_in_target: Vec::new(),
+            missing_in_source: Vec::new(),
+            differences: Vec::new(),
+            unreadable: Vec::new(),
+        };
+
+        let mut matched = vec![false; target.resources.len()];
+        for resource in &source.resources {
+            let key = without_environment(&resource.resource_name, &environment);
+            let counterpart = target
+                .resources
+                .iter()
+                .zip(&matched)
+                .position(|(other, used)| {
+                    !used
+                        && other.resource_type == resource.resource_type
+                        && without_environment(&other.resource_name, &environment) == key
+                });
+            let Some(index) = counterpart else {
+                checklist.missing_in_target.push(resource.clone());
+                continue;
+            };
+            matched[index] = true;
+            let other = &target.resources[index];
+
+            match (fetch(resource), fetch(other)) {
+                (Some(left), Some(right)) => {
+                    let fields: Vec<FieldDiff> = diff_fields(&left, &right)
+                        .into_iter()
+                        .filter(|diff| needs_review(diff, &environment))
+                        .collect();
+                    if !fields.is_empty() {
+                        checklist.differences.push(ResourceDifference {
+                            source: resource.clone(),
+                            target: other.clone(),
+                            fields,
+                        });
+                    }
+                }
+                _ => checklist.unreadable.push(resource.clone()),
+            }
+        }
+        checklist.missing_in_source = target
+            .resources
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(resource, _)| resource.clone())
+            .collect();
+
+        checklist
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.missing_in_target.len()
+            + self.missing_in_source.len()
+            + self
+                .differences
+                .iter()
+                .map(|d| d.fields.len())
+                .sum::<usize>()
+            + self.unreadable.len()
+    }
+
+    pub fn filename(&self) -> String {
+        format!("promotion-{}-{}.md", self.source_name, self.target_name)
+    }
+
+    pub fn to_markdown(&self, lang: Language) -> String {
+        let i18n = I18n::new(lang);
+        let value = |v: &str| {
+            if v.is_empty() {
+                "-".to_string()
+            } else {
+                format!("`{}`", v)
+            }
+        };
+        let mut lines = vec![
+            format!(
+                "# {}: {} → {}\n",
+                i18n.promotion_checklist(),
+                self.source_name,
+                self.target_name
+            ),
+            format!("{}\n", i18n.promotion_item_count(self.item_count())),
+        ];
+
+        if self.item_count() == 0 {
+            lines.push(format!("- [x] {}", i18n.promotion_no_differences()));
+        }
+
+        if !self.missing_in_target.is_empty() {
+            lines.push(format!(
+                "## {}\n",
+                i18n.promotion_missing_in(&self.target_name)
+            ));
+            for resource in &self.missing_in_target {
+                lines.push(format!("- [ ] {}", resource.display()));
+            }
+            lines.push(String::new());
+        }
+
+        if !self.missing_in_source.is_empty() {
+            lines.push(format!(
+                "## {}\n",
+                i18n.promotion_missing_in(&self.source_name)
+            ));
+            for resource in &self.missing_in_source {
+                lines.push(format!("- [ ] {}", resource.display()));
+            }
+            lines.push(String::new());
+        }
+
+        if !self.differences.is_empty() {
+            lines.push(format!("## {}\n", i18n.promotion_config_differences()));
+            for difference in &self.differences {
+                lines.push(format!(
+                    "### {}: {} ↔ {}\n",
+                    difference.source.resource_type.display(),
+                    difference.source.resource_name,
+                    difference.target.resource_name
+                ));
+                for field in &difference.fields {
+                    lines.push(format!(
+                        "- [ ] {}: {} → {}",
+                        field.field,
+                        value(&field.left),
+                        value(&field.right)
+                    ));
+                }
+                lines.push(String::new());
+            }
+        }
+
+        if !self.unreadable.is_empty() {
+            lines.push(format!("## {}\n", i18n.promotion_unreadable()));
+            for resource in &self.unreadable {
+                lines.push(format!("- [ ] {}", resource.display()));
+            }
+            lines.push(String::new());
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PromotionChecklist, is_identifier_only};
+    use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+    use crate::i18n::Language;
+
+    fn resource(resource_type: ResourceType, id: &str, name: &str) -> BlueprintResource {
+        BlueprintResource {
+            resource_type,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: name.to_string(),
+        }
+    }
+
+    fn blueprint(name: &str, resources: Vec<BlueprintResource>) -> Blueprint {
+        let mut blueprint = Blueprint::new(name.to_string());
+        for resource in resources {
+            blueprint.add_resource(resource);
+        }
+        blueprint
+    }
+
+    fn ec2_markdown(id: &str, name: &str, instance_type: &str) -> String {
+        format!(
+            "## EC2 Instance ({name})\n\n| Item | Value |\n|:---|:---|\n\
+             | Name | {name} |\n| Instance ID | {id} |\n| Instance Type | {instance_type} |\n"
+        )
+    }
+
+    fn sg_markdown(name: &str, rules: &[&str]) -> String {
+        let mut markdown = format!(
+            "## Security Group ({name})\n\n### Inbound Rules\n\n\
+             | Protocol | Port Range | Source |\n|:---|:---|:---|\n"
+        );
+        for rule in rules {
+            markdown.push_str(&format!("| {} |\n", rule));
+        }
+        markdown
+    }
+
+    fn fetch(resource: &BlueprintResource) -> Option<String> {
+        match resource.resource_id.as_str() {
+            "i-0aaaaaaaaaaaaaaa1" => Some(ec2_markdown(
+                "i-0aaaaaaaaaaaaaaa1",
+                "staging-web",
+                "t3.small",
+            )),
+            "i-0bbbbbbbbbbbbbbb2" => {
+                Some(ec2_markdown("i-0bbbbbbbbbbbbbbb2", "prod-web", "t3.large"))
+            }
+            "sg-0aaaaaaaaaaaaaaa1" => Some(sg_markdown(
+                "staging-web-sg",
+                &["TCP | 443 | 0.0.0.0/0", "TCP | 22 | 10.0.0.0/8"],
+            )),
+            "sg-0bbbbbbbbbbbbbbb2" => Some(sg_markdown("prod-web-sg", &["TCP | 443 | 0.0.0.0/0"])),
+            _ => None,
+        }
+    }
+
+    fn sample_checklist() -> PromotionChecklist {
+        let staging = blueprint(
+            "checkout-staging",
+            vec![
+                resource(ResourceType::Ec2, "i-0aaaaaaaaaaaaaaa1", "staging-web"),
+                resource(
+                    ResourceType::SecurityGroup,
+                    "sg-0aaaaaaaaaaaaaaa1",
+                    "staging-web-sg",
+                ),
+                resource(ResourceType::Ecr, "checkout-debug", "checkout-debug"),
+            ],
+        );
+        let prod = blueprint(
+            "checkout-prod",
+            vec![
+                resource(ResourceType::Ec2, "i-0bbbbbbbbbbbbbbb2", "prod-web"),
+                resource(
+                    ResourceType::SecurityGroup,
+                    "sg-0bbbbbbbbbbbbbbb2",
+                    "prod-web-sg",
+                ),
+                resource(ResourceType::Rds, "checkout-db", "checkout-db"),
+            ],
+        );
+        PromotionChecklist::new(&staging, &prod, fetch)
+    }
+
+    #[test]
+    fn checklist_pairs_resources_by_name_without_environment() {
+        let checklist = sample_checklist();
+        assert_eq!(checklist.missing_in_target.len(), 1);
+        assert_eq!(checklist.missing_in_target[0].resource_id, "checkout-debug");
+        assert_eq!(checklist.missing_in_source.len(), 1);
+        assert_eq!(checklist.missing_in_source[0].resource_id, "checkout-db");
+        assert!(checklist.unreadable.is_empty());
+        assert_eq!(checklist.differences.len(), 2);
+    }
+
+    #[test]
+    fn checklist_ignores_identifiers_and_environment_names() {
+        let checklist = sample_checklist();
+        let ec2 = &checklist.differences[0];
+        assert_eq!(ec2.fields.len(), 1);
+        assert_eq!(ec2.fields[0].field, "Instance Type");
+
+        let sg = &checklist.differences[1];
+        assert_eq!(sg.fields.len(), 1);
+        assert_eq!(sg.fields[0].field, "Inbound Rules");
+        assert_eq!(checklist.item_count(), 4);
+    }
+
+    #[test]
+    fn checklist_markdown_renders_review_items() {
+        let checklist = sample_checklist();
+        assert_eq!(
+            checklist.filename(),
+            "promotion-checkout-staging-checkout-prod.md"
+        );
+        let markdown = checklist.to_markdown(Language::English);
+        assert!(markdown.contains("# Promotion Checklist: checkout-staging → checkout-prod"));
+        assert!(markdown.contains("## Missing in checkout-prod"));
+        assert!(markdown.contains("- [ ] [ECR] checkout-debug - checkout-debug (ap-northeast-2)"));
+        assert!(markdown.contains("### EC2: staging-web ↔ prod-web"));
+        assert!(markdown.contains("- [ ] Instance Type: `t3.small` → `t3.large`"));
+    }
+
+    #[test]
+    fn checklist_reports_unreadable_pairs_and_empty_result() {
+        let staging = blueprint(
+            "staging",
+            vec![resource(ResourceType::Lambda, "api", "api")],
+        );
+        let prod = blueprint("prod", vec![resource(ResourceType::Lambda, "api", "api")]);
+
+        let unreadable = PromotionChecklist::new(&staging, &prod, |_| None);
+        assert_eq!(unreadable.unreadable.len(), 1);
+
+        let same = PromotionChecklist::new(&staging, &prod, |_| Some("| Name | api |".to_string()));
+        assert_eq!(same.item_count(), 0);
+        assert!(
+            same.to_markdown(Language::English)
+                .contains("- [x] No differences found")
+        );
+    }
+
+    #[test]
+    fn identifier_only_values_are_detected() {
+        assert!(is_identifier_only("i-0123456789abcdef0"));
+        assert!(is_identifier_only("10.0.1.15, 10.0.2.0/24"));
+        assert!(is_identifier_only(
+            "arn:aws:iam::123456789012:role/web (sg-0123abcd)"
+        ));
+        assert!(!is_identifier_only("t3.large"));
+        assert!(!is_identifier_only("TCP | 22 | 10.0.0.0/8"));
+    }
+}
@@ -0,0 +1,96 @@
+// JMESPath 쿼리 플레이그라운드: 읽기 전용 CLI 작업 결과에 --query 식을 적용
+
+/// Read-only operations that `run_aws_cli` can run without extra arguments.
+pub const QUERY_OPERATIONS: &[(&str, &str)] = &[
+    ("ec2", "describe-instances"),
+    ("ec2", "describe-vpcs"),
+    ("ec2", "describe-subnets"),
+    ("ec2", "describe-security-groups"),
+    ("ec2", "describe-internet-gateways"),
+    ("ec2", "describe-nat-gateways"),
+    ("ec2", "describe-route-tables"),
+    ("ec2", "describe-addresses"),
+    ("ecr", "describe-repositories"),
+    ("elbv2", "describe-load-balancers"),
+    ("elbv2", "describe-target-groups"),
+    ("sts", "get-caller-identity"),
+];
+
+/// Apply a JMESPath expression to CLI JSON output and pretty-print the result.
+/// An empty expression returns the whole output.
+pub fn apply_query(json: &str, expression: &str) -> Result<String, String> {
+    let data: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let expression = expression.trim();
+    if expression.is_empty() {
+        return serde_json::to_string_pretty(&data).map_err(|e| e.to_string());
+    }
+
+    let compiled = jmespath::compile(expression).map_err(|e| e.to_string())?;
+    let result = compiled.search(data).map_err(|e| e.to_string())?;
+    serde_json::to_string_pretty(&result).map_err(|e| e.to_string())
+}
+
+/// AWS CLI command line that reproduces the playground query.
+pub fn cli_command(service: &str, operation: &str, expression: &str) -> String {
+    let expression = expression.trim();
+    if expression.is_empty() {
+        return format!("aws {} {}", service, operation);
+    }
+    // 셸 작은따옴표 안의 작은따옴표는 '\''로 이스케이프
+    format!(
+        "aws {} {} --query '{}'",
+        service,
+        operation,
+        expression.replace('\'', "'\\''")
+    )
+}
+
+/// Markdown document with the command line and the query result.
+pub fn to_markdown(command: &str, result: &str) -> String {
+    format!("```bash\n{}\n```\n\n```json\n{}\n```\n", command, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_query, cli_command, to_markdown};
+
+    const OUTPUT: &str = r#"{"Vpcs":[{"VpcId":"vpc-a","CidrBlock":"10.0.0.0/16","IsDefault":true},{"VpcId":"vpc-b","CidrBlock":"10.1.0.0/16","IsDefault":false}]}"#;
+
+    #[test]
+    fn apply_query_projects_fields() {
+        let result = apply_query(OUTPUT, "Vpcs[?IsDefault==`false`].VpcId").expect("query");
+        assert_eq!(result, "[\n  \"vpc-b\"\n]");
+    }
+
+    #[test]
+    fn apply_query_without_expression_returns_whole_output() {
+        let result = apply_query(OUTPUT, "  ").expect("query");
+        assert!(result.starts_with("{\n  \"Vpcs\": ["));
+        assert!(result.contains("\"CidrBlock\": \"10.1.0.0/16\""));
+    }
+
+    #[test]
+    fn apply_query_reports_invalid_expression() {
+        assert!(apply_query(OUTPUT, "Vpcs[?").is_err());
+        assert!(apply_query("not json", "Vpcs").is_err());
+    }
+
+    #[test]
+    fn cli_command_quotes_query_for_shell() {
+        assert_eq!(
+            cli_command("ec2", "describe-vpcs", ""),
+            "aws ec2 describe-vpcs"
+        );
+        assert_eq!(
+            cli_command("ec2", "describe-vpcs", "Vpcs[?VpcId=='vpc-a']"),
+            "aws ec2 describe-vpcs --query 'Vpcs[?VpcId=='\\''vpc-a'\\'']'"
+        );
+    }
+
+    #[test]
+    fn to_markdown_wraps_command_and_result() {
+        let markdown = to_markdown("aws sts get-caller-identity --query 'Account'", "\"123\"");
+        assert!(markdown.contains("```bash\naws sts get-caller-identity --query 'Account'\n```"));
+        assert!(markdown.contains("```json\n\"123\"\n```"));
+    }
+}
@@ -0,0 +1,361 @@
+// HTTP API 서버 모드: GET /export/<service>/<id>?format=md|json 요청마다 aws_cli 계층으로 문서 생성
+use crate::blueprint::{BlueprintResource, ResourceType};
+use crate::http;
+use crate::i18n::Language;
+use percent_encoding::percent_decode_str;
+use serde_json::json;
+use std::net::{TcpListener, TcpStream};
+
+const JSON_CONTENT_TYPE: &str = "application/json; charset=utf-8";
+const MARKDOWN_CONTENT_TYPE: &str = "text/markdown; charset=utf-8";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+}
+
+/// A parsed `GET /export/<service>/<id>` request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportRequest {
+    pub resource_type: ResourceType,
+    pub id: String,
+    pub format: ExportFormat,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn ok(format: ExportFormat, body: String) -> Self {
+        let content_type = match format {
+            ExportFormat::Markdown => MARKDOWN_CONTENT_TYPE,
+            ExportFormat::Json => JSON_CONTENT_TYPE,
+        };
+        Self {
+            status: "200 OK",
+            content_type,
+            body,
+        }
+    }
+
+    // 오류는 형식과 관계없이 JSON으로 응답
+    fn error(status: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: JSON_CONTENT_TYPE,
+            body: json!({ "error": message.into() }).to_string(),
+        }
+    }
+}
+
+// 서비스 키는 `emd iam-policy --service`와 동일
+fn resource_type_for(service: &str) -> Option<ResourceType> {
+    match service {
+        "ec2" => Some(ResourceType::Ec2),
+        "network" => Some(ResourceType::Network),
+        "security-group" => Some(ResourceType::SecurityGroup),
+        "load-balancer" => Some(ResourceType::LoadBalancer),
+        "ecr" => Some(ResourceType::Ecr),
+        "asg" => Some(ResourceType::Asg),
+        "rds" => Some(ResourceType::Rds),
+        "lambda" => Some(ResourceType::Lambda),
+        "dynamodb" => Some(ResourceType::DynamoDb),
+        "cloudfront" => Some(ResourceType::CloudFront),
+        "route53" => Some(ResourceType::Route53),
+        "eks" => Some(ResourceType::Eks),
+        "ecs" => Some(ResourceType::Ecs),
+        "sqs" => Some(ResourceType::Sqs),
+        "sns" => Some(ResourceType::Sns),
+        "elasticache" => Some(ResourceType::ElastiCache),
+        "cloudwatch" => Some(ResourceType::CloudWatchAlarm),
+        "cloudtrail" => Some(ResourceType::CloudTrail),
+        "kms" => Some(ResourceType::Kms),
+        "secretsmanager" => Some(ResourceType::Secret),
+        "ssm" => Some(ResourceType::SsmParameter),
+        "efs" => Some(ResourceType::Efs),
+        "apigateway" => Some(ResourceType::ApiGateway),
+        "elasticbeanstalk" => Some(ResourceType::ElasticBeanstalk),
+        "stepfunctions" => Some(ResourceType::StepFunctions),
+        "kinesis" => Some(ResourceType::Kinesis),
+        "msk" => Some(ResourceType::Msk),
+        "opensearch" => Some(ResourceType::OpenSearch),
+        "redshift" => Some(ResourceType::Redshift),
+        "transit-gateway" => Some(ResourceType::TransitGateway),
+        "vpc-peering" => Some(ResourceType::VpcPeering),
+        "vpn" => Some(ResourceType::Vpn),
+        "directconnect" => Some(ResourceType::DirectConnect),
+        "waf" => Some(ResourceType::Waf),
+        "acm" => Some(ResourceType::Acm),
+        "cloudformation" => Some(ResourceType::CloudFormation),
+        "iam" => Some(ResourceType::Iam),
+        "vpc-endpoint" => Some(ResourceType::VpcEndpoint),
+        "network-interface" => Some(ResourceType::NetworkInterface),
+        "launch-template" => Some(ResourceType::LaunchTemplate),
+        "ami" => Some(ResourceType::Ami),
+        "ebs" => Some(ResourceType::Ebs),
+        "key-pair" => Some(ResourceType::KeyPair),
+        "eventbridge" => Some(ResourceType::EventBridge),
+        "cognito" => Some(ResourceType::Cognito),
+        "glue" => Some(ResourceType::Glue),
+        "athena" => Some(ResourceType::Athena),
+        "backup" => Some(ResourceType::Backup),
+        _ => None,
+    }
+}
+
+fn decode(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().to_string()
+}
+
+/// Parse the request target of `GET /export/<service>/<id>?format=md|json&region=<region>`.
+/// ID에 포함된 `/`(ARN, SSM 경로)는 그대로 허용하고 퍼센트 인코딩은 디코딩
+fn parse_export(target: &str) -> Result<ExportRequest, Response> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let Some(rest) = path.strip_prefix("/export/") else {
+        return Err(Response::error("404 Not Found", "Not found"));
+    };
+    let (service, id) = rest.split_once('/').unwrap_or((rest, ""));
+    let resource_type = resource_type_for(service).ok_or_else(|| {
+        Response::error(
+            "400 Bad Request",
+            format!("Unknown service: {}", decode(service)),
+        )
+    })?;
+    let id = decode(id);
+    if id.is_empty() {
+        return Err(Response::error("400 Bad Request", "Missing resource ID"));
+    }
+
+    let mut format = ExportFormat::Markdown;
+    let mut region = None;
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match key {
+            "format" => {
+                format = match value {
+                    "md" | "markdown" => ExportFormat::Markdown,
+                    "json" => ExportFormat::Json,
+                    other => {
+                        return Err(Response::error(
+                            "400 Bad Request",
+                            format!(
+                                "Unsupported format: {} (expected md or json)",
+                                decode(other)
+                            ),
+                        ));
+                    }
+                }
+            }
+            "region" => region = Some(decode(value)).filter(|region| !region.is_empty()),
+            _ => {}
+        }
+    }
+
+    Ok(ExportRequest {
+        resource_type,
+        id,
+        format,
+        region,
+    })
+}
+
+// CLI/환경 변수로 지정한 리전이 없으면 로그인한 계정 파티션의 글로벌 리전 (보통 us-east-1)
+fn default_region(region: Option<String>) -> String {
+    region
+        .or_else(|| std::env::var("AWS_REGION").ok())
+        .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+        .filter(|region| !region.trim().is_empty())
+        .unwrap_or_else(|| {
+            crate::aws_cli::current_partition()
+                .global_region()
+                .to_string()
+        })
+}
+
+/// Serve export requests on `bind:port` until the process is stopped.
+/// 리전 설정이 프로세스 전역이므로 요청은 한 번에 하나씩 처리
+pub fn run(bind: &str, port: u16, region: Option<String>) -> Result<(), String> {
+    crate::aws_cli::check_aws_login().map_err(|e| e.as_str().to_string())?;
+    let default_region = default_region(region);
+    let settings = crate::settings::load_settings();
+    crate::settings::apply_runtime_settings(&settings);
+    let language = settings.language;
+
+    let addr = format!("{}:{}", bind, port);
+    let listener =
+        TcpListener::bind(&addr).map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+    println!(
+        "emd serve listening on http://{} (default region: {})",
+        addr, default_region
+    );
+    println!("  GET /export/<service>/<id>?format=md|json&region=<region>");
+    tracing::info!(%addr, region = %default_region, "Export server started");
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(e) = handle_connection(stream, &default_region, language) {
+                    tracing::warn!("Export request failed: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Export connection failed: {}", e),
+        }
+    }
+    Ok(())
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    default_region: &str,
+    language: Language,
+) -> std::io::Result<()> {
+    let response = match http::read_request(&stream) {
+        Ok(request_line) => route(&request_line, default_region, language),
+        Err(e) if http::is_too_large(&e) => Response::error(http::TOO_LARGE_STATUS, e.to_string()),
+        Err(e) => return Err(e),
+    };
+    http::write_response(
+        &stream,
+        response.status,
+        response.content_type,
+        &response.body,
+    )
+}
+
+fn route(request_line: &str, default_region: &str, language: Language) -> Response {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Response::error("400 Bad Request", "Malformed request");
+    };
+    if method != "GET" {
+        return Response::error("405 Method Not Allowed", "Method not allowed");
+    }
+    let request = match parse_export(target) {
+        Ok(request) => request,
+        Err(response) => return response,
+    };
+    export(&request, default_region, language)
+}
+
+fn export(request: &ExportRequest, default_region: &str, language: Language) -> Response {
+    let resource = BlueprintResource {
+        resource_type: request.resource_type.clone(),
+        region: request
+            .region
+            .clone()
+            .unwrap_or_else(|| default_region.to_string()),
+        resource_id: request.id.clone(),
+        resource_name: request.id.clone(),
+    };
+    let _span = tracing::info_span!(
+        "export_request",
+        resource_type = resource.resource_type.display(),
+        resource_id = resource.resource_id.as_str(),
+        region = resource.region.as_str()
+    )
+    .entered();
+
+    let body = match request.format {
+        ExportFormat::Markdown => crate::handler::export_resource_markdown(&resource, language),
+        ExportFormat::Json => crate::handler::export_resource_json(&resource),
+    };
+    match body {
+        Some(body) => Response::ok(request.format, body),
+        // 조회 실패가 자격 증명 만료 때문인지 구분
+        None => match crate::aws_cli::check_aws_login() {
+            Err(e) => Response::error("503 Service Unavailable", e.as_str()),
+            Ok(_) => Response::error(
+                "404 Not Found",
+                format!(
+                    "{} not found: {} ({})",
+                    resource.resource_type.display(),
+                    resource.resource_id,
+                    resource.region
+                ),
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportFormat, ExportRequest, Response, parse_export, route};
+    use crate::blueprint::ResourceType;
+    use crate::i18n::Language;
+
+    fn status(result: Result<ExportRequest, Response>) -> &'static str {
+        result.expect_err("expected an error response").status
+    }
+
+    #[test]
+    fn parse_export_reads_service_id_format_and_region() {
+        assert_eq!(
+            parse_export("/export/ec2/i-0123456789abcdef0?format=json&region=ap-northeast-2"),
+            Ok(ExportRequest {
+                resource_type: ResourceType::Ec2,
+                id: "i-0123456789abcdef0".to_string(),
+                format: ExportFormat::Json,
+                region: Some("ap-northeast-2".to_string()),
+            })
+        );
+
+        // 기본 형식은 Markdown, ID의 `/`와 퍼센트 인코딩 허용
+        let request = parse_export("/export/ssm/%2Fcheckout/db").unwrap();
+        assert_eq!(request.resource_type, ResourceType::SsmParameter);
+        assert_eq!(request.id, "/checkout/db");
+        assert_eq!(request.format, ExportFormat::Markdown);
+        assert_eq!(request.region, None);
+
+        let request =
+            parse_export("/export/sns/arn:aws:sns:ap-northeast-2:123456789012:alerts?format=md")
+                .unwrap();
+        assert_eq!(request.id, "arn:aws:sns:ap-northeast-2:123456789012:alerts");
+    }
+
+    #[test]
+    fn parse_export_rejects_bad_requests() {
+        assert_eq!(status(parse_export("/metrics")), "404 Not Found");
+        assert_eq!(status(parse_export("/export/s3/bucket")), "400 Bad Request");
+        assert_eq!(status(parse_export("/export/ec2/")), "400 Bad Request");
+        let response = parse_export("/export/ec2/i-0123?format=html").unwrap_err();
+        assert_eq!(response.status, "400 Bad Request");
+        assert!(response.body.contains("Unsupported format: html"));
+    }
+
+    #[test]
+    fn route_exports_markdown_and_json() {
+        let response = route(
+            "GET /export/ec2/i-0123?format=json HTTP/1.1\r\n",
+            "ap-northeast-2",
+            Language::English,
+        );
+        assert_eq!(response.status, "200 OK");
+        assert_eq!(response.content_type, "application/json; charset=utf-8");
+        assert!(response.body.contains("\"instance_id\": \"i-0123\""));
+
+        let response = route(
+            "GET /export/ec2/i-0123 HTTP/1.1\r\n",
+            "ap-northeast-2",
+            Language::English,
+        );
+        assert_eq!(response.content_type, "text/markdown; charset=utf-8");
+        assert!(response.body.contains("ec2-i-0123"));
+
+        let response = route(
+            "POST /export/ec2/i-0123 HTTP/1.1\r\n",
+            "ap-northeast-2",
+            Language::English,
+        );
+        assert_eq!(response.status, "405 Method Not Allowed");
+        assert_eq!(
+            route("\r\n", "ap-northeast-2", Language::English).status,
+            "400 Bad Request"
+        );
+    }
+}
@@ -1,13 +1,199 @@
+use crate::ascii::AsciiMode;
+use crate::aws_cli::DEFAULT_MAX_CONCURRENT_REQUESTS;
 use crate::i18n::Language;
+use crate::locale::NumberLocale;
+use crate::theme::Palette;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppSettings {
     pub language: Language,
     #[serde(default)]
     pub aws_profile: Option<String>,
+    #[serde(default)]
+    pub blueprint_remote: Option<RemoteBlueprintStore>,
+    // blueprints.json을 키체인 키로 암호화해 저장
+    #[serde(default)]
+    pub encrypt_at_rest: bool,
+    // EC2 목록에서 terminated/shutting-down 인스턴스 제외
+    #[serde(default)]
+    pub hide_terminated_instances: bool,
+    // 리소스 문서를 내보낼 Notion 데이터베이스 (토큰은 NOTION_TOKEN 또는 키체인)
+    #[serde(default)]
+    pub notion_export: Option<NotionExport>,
+    // Backstage catalog-info.yaml 엔티티의 owner/system
+    #[serde(default)]
+    pub backstage_catalog: Option<BackstageCatalog>,
+    // 스팬을 내보낼 OTLP/HTTP 수집기 (예: Jaeger)
+    #[serde(default)]
+    pub otlp_tracing: Option<OtlpTracing>,
+    // 키 매크로 레지스터: "a" -> ["<Down>", "<Enter>", ...]
+    #[serde(default)]
+    pub key_macros: BTreeMap<String, Vec<String>>,
+    // 서비스 목록 상단에 고정한 서비스 이름 (고정한 순서대로)
+    #[serde(default)]
+    pub pinned_services: Vec<String>,
+    // 고정하지 않은 서비스를 선택 횟수가 많은 순으로 정렬
+    #[serde(default)]
+    pub sort_services_by_usage: bool,
+    // 서비스별 선택 횟수 (sort_services_by_usage가 켜져 있을 때만 집계)
+    #[serde(default)]
+    pub service_usage: BTreeMap<String, u32>,
+    // 화면 색상 팔레트 (NO_COLOR 환경 변수가 설정되면 무시하고 색상 없음으로 표시)
+    #[serde(default)]
+    pub palette: Palette,
+    // 이모지/기호 대신 ASCII로 표시 (auto: 터미널 종류와 로캘로 판단)
+    #[serde(default)]
+    pub ascii_mode: AsciiMode,
+    // 목록/상세/블루프린트 조회 시 동시에 보내는 AWS 요청 수 (없으면 기본값 4, 스로틀링이 잦으면 낮춤)
+    #[serde(default)]
+    pub max_concurrent_requests: Option<usize>,
+    // 서비스 목록에서 숨길 서비스 이름 (iam-policy 기본 정책에서도 제외)
+    #[serde(default)]
+    pub disabled_services: Vec<String>,
+    // 기본으로 숨겨진 실험적 서비스 중 사용할 서비스 이름
+    #[serde(default)]
+    pub experimental_services: Vec<String>,
+    // 서비스 목록을 다시 열 때 이 시간(초) 안에 조회한 목록은 재조회 없이 표시 (0이면 항상 조회)
+    #[serde(default)]
+    pub list_cache_seconds: Option<u64>,
+    // 목록 화면을 보고 있는 동안 자동으로 새로고침하는 간격(초), 서비스 이름 -> 초 (예: "EC2": 60)
+    #[serde(default)]
+    pub auto_refresh_seconds: BTreeMap<String, u64>,
+    // 문서를 저장할 때 Mermaid 다이어그램을 이미지로 렌더링할 외부 명령 (예: mermaid-cli의 mmdc)
+    #[serde(default)]
+    pub diagram_renderer: Option<DiagramRenderer>,
+    // 문서의 천 단위 구분 기호, 용량 단위, 날짜 표기 (plain: AWS CLI와 같은 표기)
+    #[serde(default)]
+    pub number_locale: NumberLocale,
+    // 예약 조정/EventBridge 규칙/백업 계획의 cron·rate 표현식을 문장으로 풀어 씀 (원본 표현식도 함께 표시)
+    #[serde(default)]
+    pub spell_out_schedules: bool,
+    // 보안 그룹 문서에서 같은 프로토콜/포트 규칙을 한 행으로 합치고 포트 순으로 정렬
+    #[serde(default)]
+    pub normalize_security_group_rules: bool,
+    // 리소스 문서 끝에 CloudTrail에서 찾은 마지막 변경(사용자/시각/경로) 추가
+    #[serde(default)]
+    pub stamp_last_modified: bool,
+    // 생성일/시작 시간 옆에 경과 일수, 블루프린트 첫머리에 경과 기간 분포 표시
+    #[serde(default)]
+    pub show_resource_age: bool,
+}
+
+// 설정 화면에서 순환하는 동시 요청 수
+const CONCURRENCY_STEPS: [usize; 5] = [1, 2, 4, 8, 16];
+
+// list_cache_seconds를 지정하지 않았을 때 목록을 재사용하는 시간
+const DEFAULT_LIST_CACHE_SECONDS: u64 = 300;
+
+impl AppSettings {
+    /// Number of AWS requests sent at once, falling back to the default when unset.
+    pub fn concurrency_limit(&self) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_REQUESTS)
+            .max(1)
+    }
+
+    /// How long a fetched resource list is reused when its service is opened again.
+    pub fn list_cache_ttl(&self) -> Duration {
+        Duration::from_secs(
+            self.list_cache_seconds
+                .unwrap_or(DEFAULT_LIST_CACHE_SECONDS),
+        )
+    }
+
+    /// Auto-refresh interval of `service`'s list screen; `None` when unset or 0.
+    pub fn auto_refresh_interval(&self, service: &str) -> Option<Duration> {
+        self.auto_refresh_seconds
+            .get(service)
+            .filter(|seconds| **seconds > 0)
+            .map(|seconds| Duration::from_secs(*seconds))
+    }
+
+    /// Whether `service` is shown in ServiceSelect. Services in `experimental` are
+    /// hidden until listed in `experimental_services`; `disabled_services` always wins.
+    pub fn service_enabled(&self, service: &str, experimental: &[&str]) -> bool {
+        if self.disabled_services.iter().any(|name| name == service) {
+            return false;
+        }
+        !experimental.contains(&service)
+            || self
+                .experimental_services
+                .iter()
+                .any(|name| name == service)
+    }
+}
+
+/// Next value in the 1 → 2 → 4 → 8 → 16 → 1 cycle of the Settings screen.
+pub fn next_concurrency_limit(current: usize) -> usize {
+    CONCURRENCY_STEPS
+        .into_iter()
+        .find(|&step| step > current)
+        .unwrap_or(CONCURRENCY_STEPS[0])
+}
+
+// 팀 공유 블루프린트 저장소 (settings.json에서 설정)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RemoteBlueprintStore {
+    S3 {
+        bucket: String,
+        #[serde(default)]
+        prefix: String,
+    },
+    #[serde(rename = "dynamodb")]
+    DynamoDb { table: String },
+}
+
+// Notion 내보내기 대상 (settings.json에서 설정)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotionExport {
+    pub database_id: String,
+}
+
+// Backstage 카탈로그 내보내기 기본값 (settings.json에서 설정)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackstageCatalog {
+    pub owner: String,
+    #[serde(default)]
+    pub system: Option<String>,
+}
+
+// OpenTelemetry 트레이스 내보내기 대상 (settings.json에서 설정)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtlpTracing {
+    pub endpoint: String,
+}
+
+// Mermaid 다이어그램 이미지 렌더러 (settings.json에서 설정)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiagramRenderer {
+    // {input}은 .mmd 파일, {output}은 이미지 파일 경로로 바뀜 (예: "mmdc -i {input} -o {output}")
+    pub command: String,
+    #[serde(default)]
+    pub format: DiagramFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagramFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+impl DiagramFormat {
+    /// File extension of the rendered image.
+    pub fn extension(self) -> &'static str {
+        match self {
+            DiagramFormat::Svg => "svg",
+            DiagramFormat::Png => "png",
+        }
+    }
 }
 
 fn get_settings_path() -> Option<PathBuf> {
@@ -37,6 +223,18 @@ pub fn load_settings() -> AppSettings {
     }
 }
 
+/// Apply the process-wide settings: document rendering and the blueprint store write target.
+///
+/// Called wherever settings are loaded or changed (TUI, `emd serve`, `emd daemon`).
+pub fn apply_runtime_settings(settings: &AppSettings) {
+    crate::locale::set_number_locale(settings.number_locale);
+    crate::cron::set_spell_out_schedules(settings.spell_out_schedules);
+    crate::aws_cli::set_normalize_security_group_rules(settings.normalize_security_group_rules);
+    crate::aws_cli::set_stamp_last_modified(settings.stamp_last_modified);
+    crate::age::set_show_resource_age(settings.show_resource_age);
+    crate::aws_cli::set_blueprint_store(settings.blueprint_remote.clone());
+}
+
 pub fn save_settings(settings: &AppSettings) -> Result<(), std::io::Error> {
     let path = get_settings_path().ok_or_else(|| {
         std::io::Error::new(std::io::ErrorKind::NotFound, "Home directory not found")
@@ -49,12 +247,20 @@ pub fn save_settings(settings: &AppSettings) -> Result<(), std::io::Error> {
 
 #[cfg(test)]
 mod tests {
-    use super::{AppSettings, load_settings, save_settings};
+    use super::{
+        AppSettings, BackstageCatalog, DiagramFormat, DiagramRenderer, NotionExport, OtlpTracing,
+        RemoteBlueprintStore, load_settings, next_concurrency_limit, save_settings,
+    };
+    use crate::ascii::AsciiMode;
     use crate::i18n::Language;
+    use crate::locale::NumberLocale;
+    use crate::theme::Palette;
+    use std::collections::BTreeMap;
     use std::env;
     use std::fs;
     use std::path::PathBuf;
     use std::sync::{Mutex, OnceLock};
+    use std::time::Duration;
 
     fn env_lock() -> &'static Mutex<()> {
         static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
@@ -117,11 +323,152 @@ mod tests {
         let to_save = AppSettings {
             language: Language::Korean,
             aws_profile: Some("default".to_string()),
+            blueprint_remote: Some(RemoteBlueprintStore::S3 {
+                bucket: "team-docs".to_string(),
+                prefix: "emd/".to_string(),
+            }),
+            encrypt_at_rest: true,
+            hide_terminated_instances: true,
+            notion_export: Some(NotionExport {
+                database_id: "0123abcd".to_string(),
+            }),
+            backstage_catalog: Some(BackstageCatalog {
+                owner: "team-platform".to_string(),
+                system: None,
+            }),
+            otlp_tracing: Some(OtlpTracing {
+                endpoint: "http://localhost:4318".to_string(),
+            }),
+            key_macros: BTreeMap::from([(
+                "a".to_string(),
+                vec!["<Down>".to_string(), "<Enter>".to_string()],
+            )]),
+            pinned_services: vec!["KMS".to_string(), "EC2".to_string()],
+            sort_services_by_usage: true,
+            service_usage: BTreeMap::from([("RDS".to_string(), 3)]),
+            palette: Palette::Deuteranopia,
+            ascii_mode: AsciiMode::On,
+            max_concurrent_requests: Some(8),
+            disabled_services: vec!["Kinesis".to_string()],
+            experimental_services: vec!["Site-to-Site VPN".to_string()],
+            list_cache_seconds: Some(0),
+            auto_refresh_seconds: BTreeMap::from([("EC2".to_string(), 60), ("RDS".to_string(), 0)]),
+            diagram_renderer: Some(DiagramRenderer {
+                command: "mmdc -i {input} -o {output}".to_string(),
+                format: DiagramFormat::Png,
+            }),
+            number_locale: NumberLocale::DeDe,
+            spell_out_schedules: true,
+            normalize_security_group_rules: true,
+            stamp_last_modified: true,
+            show_resource_age: true,
         };
         save_settings(&to_save).expect("save settings");
 
         let loaded = load_settings();
         assert_eq!(loaded.language, Language::Korean);
         assert_eq!(loaded.aws_profile.as_deref(), Some("default"));
+        assert_eq!(loaded.blueprint_remote, to_save.blueprint_remote);
+        assert!(loaded.encrypt_at_rest);
+        assert!(loaded.hide_terminated_instances);
+        assert_eq!(loaded.notion_export, to_save.notion_export);
+        assert_eq!(loaded.backstage_catalog, to_save.backstage_catalog);
+        assert_eq!(loaded.otlp_tracing, to_save.otlp_tracing);
+        assert_eq!(loaded.key_macros, to_save.key_macros);
+        assert_eq!(loaded.pinned_services, to_save.pinned_services);
+        assert!(loaded.sort_services_by_usage);
+        assert_eq!(loaded.service_usage, to_save.service_usage);
+        assert_eq!(loaded.palette, Palette::Deuteranopia);
+        assert_eq!(loaded.ascii_mode, AsciiMode::On);
+        assert_eq!(loaded.concurrency_limit(), 8);
+        assert_eq!(loaded.disabled_services, to_save.disabled_services);
+        assert_eq!(loaded.experimental_services, to_save.experimental_services);
+        assert!(loaded.list_cache_ttl().is_zero());
+        assert_eq!(
+            loaded.auto_refresh_interval("EC2"),
+            Some(Duration::from_secs(60))
+        );
+        // 0이거나 지정하지 않은 서비스는 자동 새로고침하지 않음
+        assert_eq!(loaded.auto_refresh_interval("RDS"), None);
+        assert_eq!(loaded.auto_refresh_interval("Lambda"), None);
+        assert_eq!(loaded.diagram_renderer, to_save.diagram_renderer);
+        assert_eq!(loaded.number_locale, NumberLocale::DeDe);
+        assert!(loaded.spell_out_schedules);
+        assert!(loaded.normalize_security_group_rules);
+        assert!(loaded.stamp_last_modified);
+        assert!(loaded.show_resource_age);
+    }
+
+    #[test]
+    fn concurrency_limit_defaults_when_unset_and_cycles_through_steps() {
+        let settings: AppSettings = serde_json::from_str(r#"{"language":"English"}"#)
+            .expect("settings without max_concurrent_requests");
+        assert_eq!(settings.max_concurrent_requests, None);
+        assert_eq!(settings.concurrency_limit(), 4);
+        assert_eq!(settings.list_cache_ttl().as_secs(), 300);
+
+        assert_eq!(next_concurrency_limit(1), 2);
+        assert_eq!(next_concurrency_limit(4), 8);
+        assert_eq!(next_concurrency_limit(16), 1);
+        // 설정 파일에 직접 적은 값(예: 3)도 다음 단계로 넘어감
+        assert_eq!(next_concurrency_limit(3), 4);
+    }
+
+    #[test]
+    fn service_enabled_hides_disabled_and_unlisted_experimental_services() {
+        let experimental = ["Glue"];
+        let mut settings = AppSettings::default();
+        assert!(settings.service_enabled("EC2", &experimental));
+        assert!(!settings.service_enabled("Glue", &experimental));
+
+        settings.experimental_services = vec!["Glue".to_string()];
+        assert!(settings.service_enabled("Glue", &experimental));
+
+        // 실험적 서비스로 켜도 끈 목록에 있으면 숨김
+        settings.disabled_services = vec!["EC2".to_string(), "Glue".to_string()];
+        assert!(!settings.service_enabled("EC2", &experimental));
+        assert!(!settings.service_enabled("Glue", &experimental));
+        assert!(settings.service_enabled("RDS", &experimental));
+    }
+
+    #[test]
+    fn remote_blueprint_store_parses_both_kinds() {
+        let s3: RemoteBlueprintStore =
+            serde_json::from_str(r#"{"kind":"s3","bucket":"team-docs"}"#).expect("s3 config");
+        assert_eq!(
+            s3,
+            RemoteBlueprintStore::S3 {
+                bucket: "team-docs".to_string(),
+                prefix: String::new(),
+            }
+        );
+
+        let dynamodb: RemoteBlueprintStore =
+            serde_json::from_str(r#"{"kind":"dynamodb","table":"emd-blueprints"}"#)
+                .expect("dynamodb config");
+        assert_eq!(
+            dynamodb,
+            RemoteBlueprintStore::DynamoDb {
+                table: "emd-blueprints".to_string(),
+            }
+        );
+
+        let legacy: AppSettings =
+            serde_json::from_str(r#"{"language":"English"}"#).expect("legacy settings");
+        assert!(legacy.blueprint_remote.is_none());
+        assert!(legacy.notion_export.is_none());
+        assert!(legacy.backstage_catalog.is_none());
+        assert!(legacy.otlp_tracing.is_none());
+        assert!(legacy.diagram_renderer.is_none());
+        assert!(legacy.key_macros.is_empty());
+        assert!(legacy.pinned_services.is_empty());
+        assert!(!legacy.sort_services_by_usage);
+        assert_eq!(legacy.palette, Palette::Default);
+        assert_eq!(legacy.ascii_mode, AsciiMode::Auto);
+        assert_eq!(legacy.number_locale, NumberLocale::Plain);
+        assert!(!legacy.spell_out_schedules);
+        assert!(!legacy.normalize_security_group_rules);
+        assert!(!legacy.stamp_last_modified);
+        assert!(!legacy.show_resource_age);
     }
 }
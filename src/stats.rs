@@ -0,0 +1,335 @@
+// 블루프린트 요약: 유형별 리소스 수, 리전, EC2 vCPU/메모리 합계, 퍼블릭 엔드포인트를
+// 각 리소스를 문서화할 때 상세 구조체에서 모은 정보로 계산
+use crate::age;
+use crate::aws_cli::{
+    ApiGatewayDetail, CloudFrontDetail, Ec2Detail, EksDetail, LoadBalancerDetail,
+    RedshiftClusterDetail, is_terminated_state,
+};
+use crate::blueprint::BlueprintResource;
+use crate::i18n::{I18n, Language};
+use crate::locale;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What the blueprint summaries need from a resource, taken from its detail struct while documenting it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceFacts {
+    // 생성 (EC2는 시작) 날짜, 상세에 없으면 None
+    pub created: Option<NaiveDate>,
+    // EC2 인스턴스일 때만 값이 있음
+    pub instance: Option<InstanceUsage>,
+    // 인터넷에서 접근할 수 있으면 그 근거
+    pub public_endpoint: Option<PublicEndpoint>,
+}
+
+/// Capacity of an EC2 instance; `None` when its instance type could not be described.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstanceUsage {
+    pub terminated: bool,
+    pub vcpus: Option<u32>,
+    pub memory_mib: Option<u64>,
+}
+
+/// Why a resource is reachable from the internet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PublicEndpoint {
+    PublicIp(String),
+    InternetFacing,
+    // PRIVATE가 아닌 API Gateway 엔드포인트 유형 (EDGE, REGIONAL)
+    ApiEndpointType(String),
+    DomainName(String),
+    // EKS 퍼블릭 API 엔드포인트에 접근을 허용한 CIDR (비어 있으면 모두 허용)
+    EksPublicAccess(Vec<String>),
+    PubliclyAccessible,
+}
+
+impl PublicEndpoint {
+    // 요약 표에 쓰는 "항목: 값"
+    fn describe(&self, i18n: &I18n) -> String {
+        match self {
+            Self::PublicIp(ip) => format!("{}: {}", i18n.md_public_ip(), ip),
+            Self::InternetFacing => format!("{}: internet-facing", i18n.md_scheme()),
+            Self::ApiEndpointType(endpoint_type) => {
+                format!("{}: {}", i18n.md_endpoint_type(), endpoint_type)
+            }
+            Self::DomainName(domain) => format!("{}: {}", i18n.md_domain_name(), domain),
+            Self::EksPublicAccess(cidrs) if cidrs.is_empty() => {
+                format!("{}: {}", i18n.md_endpoint_access(), i18n.md_public())
+            }
+            Self::EksPublicAccess(cidrs) => format!(
+                "{}: {} ({})",
+                i18n.md_endpoint_access(),
+                i18n.md_public(),
+                cidrs.join(", ")
+            ),
+            Self::PubliclyAccessible => {
+                format!("{}: {}", i18n.md_publicly_accessible(), i18n.md_enabled())
+            }
+        }
+    }
+}
+
+impl ResourceFacts {
+    /// Facts for a detail whose only summarized field is its raw creation timestamp.
+    pub fn created(timestamp: &str) -> Self {
+        Self {
+            created: age::created_date(timestamp),
+            ..Self::default()
+        }
+    }
+
+    pub fn ec2(detail: &Ec2Detail) -> Self {
+        let has_public_ip = !detail.public_ip.is_empty() && detail.public_ip != "-";
+        Self {
+            instance: Some(InstanceUsage {
+                terminated: is_terminated_state(&detail.state),
+                vcpus: detail.vcpus,
+                memory_mib: detail.memory_mib,
+            }),
+            public_endpoint: has_public_ip
+                .then(|| PublicEndpoint::PublicIp(detail.public_ip.clone())),
+            ..Self::created(&detail.launch_time)
+        }
+    }
+
+    pub fn load_balancer(detail: &LoadBalancerDetail) -> Self {
+        Self {
+            public_endpoint: (detail.scheme == "internet-facing")
+                .then_some(PublicEndpoint::InternetFacing),
+            ..Self::default()
+        }
+    }
+
+    pub fn api_gateway(detail: &ApiGatewayDetail) -> Self {
+        let endpoint_type = &detail.endpoint_type;
+        Self {
+            public_endpoint: (!endpoint_type.is_empty() && !endpoint_type.contains("PRIVATE"))
+                .then(|| PublicEndpoint::ApiEndpointType(endpoint_type.clone())),
+            ..Self::created(&detail.created)
+        }
+    }
+
+    // CloudFront 배포는 항상 퍼블릭
+    pub fn cloudfront(detail: &CloudFrontDetail) -> Self {
+        Self {
+            public_endpoint: (!detail.domain_name.is_empty())
+                .then(|| PublicEndpoint::DomainName(detail.domain_name.clone())),
+            ..Self::default()
+        }
+    }
+
+    pub fn eks(detail: &EksDetail) -> Self {
+        Self {
+            public_endpoint: detail
+                .endpoint_public_access
+                .then(|| PublicEndpoint::EksPublicAccess(detail.public_access_cidrs.clone())),
+            ..Self::default()
+        }
+    }
+
+    pub fn redshift(detail: &RedshiftClusterDetail) -> Self {
+        Self {
+            public_endpoint: detail
+                .publicly_accessible
+                .then_some(PublicEndpoint::PubliclyAccessible),
+            ..Self::created(&detail.created)
+        }
+    }
+}
+
+/// "Summary" section at the top of a blueprint.
+/// `facts` holds each resource's facts in blueprint order.
+pub fn summary_markdown(
+    resources: &[BlueprintResource],
+    facts: &[ResourceFacts],
+    lang: Language,
+) -> String {
+    let i18n = I18n::new(lang);
+    let mut by_type: BTreeMap<&str, usize> = BTreeMap::new();
+    for resource in resources {
+        *by_type.entry(resource.resource_type.display()).or_default() += 1;
+    }
+    let regions: BTreeSet<&str> = resources
+        .iter()
+        .map(|resource| resource.region.as_str())
+        .filter(|region| !region.is_empty())
+        .collect();
+
+    let mut instances = 0;
+    let mut vcpus = 0u32;
+    let mut memory_mib = 0u64;
+    let mut unknown = 0usize;
+    let mut endpoints = Vec::new();
+    for (resource, facts) in resources.iter().zip(facts) {
+        if let Some(endpoint) = &facts.public_endpoint {
+            endpoints.push((resource, endpoint.describe(&i18n)));
+        }
+        // 종료된 인스턴스는 자원을 쓰지 않으므로 합계에서 제외
+        let Some(usage) = facts.instance.as_ref().filter(|usage| !usage.terminated) else {
+            continue;
+        };
+        instances += 1;
+        match (usage.vcpus, usage.memory_mib) {
+            (Some(cpu), Some(mib)) => {
+                vcpus += cpu;
+                memory_mib += mib;
+            }
+            _ => unknown += 1,
+        }
+    }
+
+    let mut lines = vec![
+        format!("## {}\n", i18n.md_blueprint_summary()),
+        format!("| {} | {} |", i18n.item(), i18n.value()),
+        "|:---|:---|".to_string(),
+        format!(
+            "| {} | {} |",
+            i18n.md_resources(),
+            locale::count(resources.len() as i64)
+        ),
+        format!(
+            "| {} | {} |",
+            i18n.md_regions(),
+            if regions.is_empty() {
+                "-".to_string()
+            } else {
+                regions.into_iter().collect::<Vec<_>>().join(", ")
+            }
+        ),
+    ];
+    if instances > 0 {
+        let note = if unknown > 0 {
+            format!(" ({})", i18n.md_unknown_instance_types(unknown))
+        } else {
+            String::new()
+        };
+        lines.push(format!(
+            "| {} | {}{} |",
+            i18n.md_total_vcpu(),
+            locale::count(vcpus),
+            note
+        ));
+        lines.push(format!(
+            "| {} | {}{} |",
+            i18n.md_total_memory(),
+            locale::bytes(memory_mib as i64 * 1024 * 1024),
+            note
+        ));
+    }
+    lines.push(format!(
+        "| {} | {} |",
+        i18n.md_public_endpoints(),
+        locale::count(endpoints.len() as i64)
+    ));
+
+    // 많은 유형부터, 같으면 이름순
+    let mut by_type: Vec<(&str, usize)> = by_type.into_iter().collect();
+    by_type.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    if !by_type.is_empty() {
+        lines.push(format!("\n### {}\n", i18n.md_resources_by_type()));
+        lines.push(format!("| {} | {} |", i18n.md_type(), i18n.md_count()));
+        lines.push("|:---|---:|".to_string());
+        for (name, count) in by_type {
+            lines.push(format!("| {} | {} |", name, locale::count(count as i64)));
+        }
+    }
+
+    if !endpoints.is_empty() {
+        lines.push(format!("\n### {}\n", i18n.md_public_endpoints()));
+        lines.push(format!(
+            "| {} | {} |",
+            i18n.md_resource(),
+            i18n.md_endpoint()
+        ));
+        lines.push("|:---|:---|".to_string());
+        for (resource, endpoint) in endpoints {
+            let name = if resource.resource_name.is_empty() {
+                &resource.resource_id
+            } else {
+                &resource.resource_name
+            };
+            lines.push(format!(
+                "| {} - {} | {} |",
+                resource.resource_type.display(),
+                name,
+                endpoint
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InstanceUsage, PublicEndpoint, ResourceFacts, summary_markdown};
+    use crate::blueprint::{BlueprintResource, ResourceType};
+    use crate::i18n::Language;
+
+    #[test]
+    fn summary_counts_types_regions_capacity_and_public_endpoints() {
+        let resource = |resource_type: ResourceType, region: &str, name: &str| BlueprintResource {
+            resource_type,
+            region: region.to_string(),
+            resource_id: format!("{}-id", name),
+            resource_name: name.to_string(),
+        };
+        let resources = vec![
+            resource(ResourceType::Ec2, "ap-northeast-2", "web"),
+            resource(ResourceType::Ec2, "ap-northeast-2", "batch"),
+            resource(ResourceType::Ec2, "us-east-1", "old"),
+            resource(ResourceType::Ec2, "us-east-1", "gpu"),
+            resource(ResourceType::LoadBalancer, "ap-northeast-2", "alb"),
+            resource(ResourceType::LoadBalancer, "ap-northeast-2", "internal"),
+            resource(ResourceType::CloudFront, "", "cdn"),
+            resource(ResourceType::Eks, "ap-northeast-2", "platform"),
+        ];
+        let instance = |terminated: bool, spec: Option<(u32, u64)>| ResourceFacts {
+            instance: Some(InstanceUsage {
+                terminated,
+                vcpus: spec.map(|(vcpus, _)| vcpus),
+                memory_mib: spec.map(|(_, memory_mib)| memory_mib),
+            }),
+            ..ResourceFacts::default()
+        };
+        let public = |endpoint: PublicEndpoint| ResourceFacts {
+            public_endpoint: Some(endpoint),
+            ..ResourceFacts::default()
+        };
+        let facts = vec![
+            ResourceFacts {
+                public_endpoint: Some(PublicEndpoint::PublicIp("3.35.0.1".to_string())),
+                ..instance(false, Some((2, 1024)))
+            },
+            // c5n처럼 vCPU당 메모리가 계열 규칙과 다른 유형도 조회한 값 그대로
+            instance(false, Some((8, 21504))),
+            instance(true, Some((2, 16384))),
+            instance(false, None),
+            public(PublicEndpoint::InternetFacing),
+            ResourceFacts::default(),
+            public(PublicEndpoint::DomainName(
+                "d111.cloudfront.net".to_string(),
+            )),
+            public(PublicEndpoint::EksPublicAccess(vec![
+                "203.0.113.0/24".to_string(),
+            ])),
+        ];
+
+        let summary = summary_markdown(&resources, &facts, Language::English);
+        assert!(summary.starts_with("## Summary\n"));
+        assert!(summary.contains("| Resources | 8 |"));
+        assert!(summary.contains("| Regions | ap-northeast-2, us-east-1 |"));
+        assert!(summary.contains("| Total vCPU (EC2) | 10 (1 instance of unknown type) |"));
+        assert!(summary.contains("| Total Memory (EC2) | 22.0 GB (1 instance of unknown type) |"));
+        assert!(summary.contains("| Public Endpoints | 4 |"));
+        let ec2 = summary.find("| EC2 | 4 |").expect("ec2 count");
+        let lb = summary.find("| Load Balancer | 2 |").expect("lb count");
+        assert!(ec2 < lb);
+        assert!(summary.contains("| EC2 - web | Public IP: 3.35.0.1 |"));
+        assert!(summary.contains("| Load Balancer - alb | Scheme: internet-facing |"));
+        assert!(summary.contains("| CloudFront - cdn | Domain Name: d111.cloudfront.net |"));
+        assert!(summary.contains("| EKS - platform | Endpoint Access: Public (203.0.113.0/24) |"));
+        assert!(!summary.contains("Load Balancer - internal"));
+    }
+}
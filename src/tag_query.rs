@@ -0,0 +1,774 @@
+use crate::aws_cli::ssm::path_prefix;
+use crate::aws_cli::{Arn, TaggedResource};
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+
+/// Parse `Key=Value,Key2=A|B,Key3` into tag filters (values are OR-ed, keys are AND-ed).
+pub fn parse_tag_query(query: &str) -> Result<Vec<(String, Vec<String>)>, String> {
+    let mut filters = Vec::new();
+
+    for term in query.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let (key, values) = match term.split_once('=') {
+            Some((key, values)) => (
+                key.trim(),
+                values
+                    .split('|')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            ),
+            None => (term, Vec::new()),
+        };
+        if key.is_empty() {
+            return Err(format!("invalid tag filter: {}", term));
+        }
+        filters.push((key.to_string(), values));
+    }
+
+    if filters.is_empty() {
+        return Err("empty tag query".to_string());
+    }
+    Ok(filters)
+}
+
+fn to_blueprint_resource(resource: &TaggedResource) -> Option<BlueprintResource> {
+    let arn = Arn::parse(&resource.arn)?;
+    let path = arn.resource;
+
+    let (resource_type, resource_id) = match arn.service {
+        "ec2" => match path.split_once('/')?.0 {
+            "instance" => (ResourceType::Ec2, arn.resource_id().to_string()),
+            "vpc" => (ResourceType::Network, arn.resource_id().to_string()),
+            "security-group" => (ResourceType::SecurityGroup, arn.resource_id().to_string()),
+            "transit-gateway" => (ResourceType::TransitGateway, arn.resource_id().to_string()),
+            "vpc-peering-connection" => (ResourceType::VpcPeering, arn.resource_id().to_string()),
+            "vpc-endpoint" => (ResourceType::VpcEndpoint, arn.resource_id().to_string()),
+            "network-interface" => (
+                ResourceType::NetworkInterface,
+                arn.resource_id().to_string(),
+            ),
+            "launch-template" => (ResourceType::LaunchTemplate, arn.resource_id().to_string()),
+            "image" => (ResourceType::Ami, arn.resource_id().to_string()),
+            "volume" | "snapshot" => (ResourceType::Ebs, arn.resource_id().to_string()),
+            // 키 페어 ARN의 리소스 부분은 키 이름
+            "key-pair" => (ResourceType::KeyPair, arn.resource_id().to_string()),
+            "vpn-connection" | "customer-gateway" | "vpn-gateway" => {
+                (ResourceType::Vpn, arn.resource_id().to_string())
+            }
+            _ => return None,
+        },
+        // Classic ELB(loadbalancer/name)는 지원하지 않음
+        "elasticloadbalancing"
+            if path.starts_with("loadbalancer/app/") || path.starts_with("loadbalancer/net/") =>
+        {
+            (ResourceType::LoadBalancer, resource.arn.clone())
+        }
+        "ecr" if arn.resource_type() == "repository" => {
+            (ResourceType::Ecr, arn.resource_name().to_string())
+        }
+        "autoscaling" if path.contains("autoScalingGroupName/") => {
+            (ResourceType::Asg, arn.resource_name().to_string())
+        }
+        "rds" if matches!(arn.resource_type(), "db" | "cluster") => {
+            (ResourceType::Rds, arn.resource_name().to_string())
+        }
+        "lambda" if arn.resource_type() == "function" => {
+            (ResourceType::Lambda, arn.resource_name().to_string())
+        }
+        "dynamodb" if arn.resource_type() == "table" => {
+            (ResourceType::DynamoDb, arn.resource_name().to_string())
+        }
+        "cloudfront" if arn.resource_type() == "distribution" => {
+            (ResourceType::CloudFront, arn.resource_name().to_string())
+        }
+        "eks" if arn.resource_type() == "cluster" => {
+            (ResourceType::Eks, arn.resource_name().to_string())
+        }
+        "ecs" if arn.resource_type() == "cluster" => {
+            (ResourceType::Ecs, arn.resource_name().to_string())
+        }
+        // arn:aws:sqs:<region>:<account>:<queue-name>
+        "sqs" => (ResourceType::Sqs, arn.resource.to_string()),
+        // arn:aws:sns:<region>:<account>:<topic-name>, 구독 ARN은 제외
+        "sns" if !path.contains(':') => (ResourceType::Sns, resource.arn.clone()),
+        // arn:aws:elasticache:<region>:<account>:replicationgroup:<id> 또는 cluster:<id>
+        "elasticache" if matches!(arn.resource_type(), "replicationgroup" | "cluster") => {
+            (ResourceType::ElastiCache, arn.resource_name().to_string())
+        }
+        "cloudtrail" if arn.resource_type() == "trail" => {
+            (ResourceType::CloudTrail, resource.arn.clone())
+        }
+        "cloudwatch" if arn.resource_type() == "alarm" => (
+            ResourceType::CloudWatchAlarm,
+            arn.resource_name().to_string(),
+        ),
+        // arn:aws:kms:<region>:<account>:key/<key-id> (별칭 ARN은 제외)
+        "kms" if arn.resource_type() == "key" => (ResourceType::Kms, arn.resource_id().to_string()),
+        // arn:aws:secretsmanager:<region>:<account>:secret:<name>-<suffix>
+        "secretsmanager" if arn.resource_type() == "secret" => {
+            (ResourceType::Secret, resource.arn.clone())
+        }
+        // arn:aws:ssm:<region>:<account>:parameter/<path>, 경로 접두사 단위로 묶음
+        "ssm" if arn.resource_type() == "parameter" => (
+            ResourceType::SsmParameter,
+            path_prefix(&format!("/{}", arn.resource_id())),
+        ),
+        // arn:aws:elasticfilesystem:<region>:<account>:file-system/<fs-id>
+        "elasticfilesystem" if arn.resource_type() == "file-system" => {
+            (ResourceType::Efs, arn.resource_id().to_string())
+        }
+        // arn:aws:apigateway:<region>::/restapis/<api-id> 또는 /apis/<api-id> (스테이지 ARN은 제외)
+        "apigateway" => match path.trim_start_matches('/').split('/').collect::<Vec<_>>()[..] {
+            ["restapis" | "apis", api_id] => (ResourceType::ApiGateway, api_id.to_string()),
+            _ => return None,
+        },
+        // arn:aws:elasticbeanstalk:<region>:<account>:environment/<app>/<env-name>, 상세 조회는 환경 이름으로도 가능
+        "elasticbeanstalk" if arn.resource_type() == "environment" => (
+            ResourceType::ElasticBeanstalk,
+            arn.resource_suffix().to_string(),
+        ),
+        // arn:aws:states:<region>:<account>:stateMachine:<name> (실행/활동 ARN은 제외)
+        "states" if arn.resource_type() == "stateMachine" => {
+            (ResourceType::StepFunctions, resource.arn.clone())
+        }
+        // arn:aws:events:<region>:<account>:event-bus/<name> (규칙 ARN은 제외)
+        "events" if arn.resource_type() == "event-bus" => {
+            (ResourceType::EventBridge, arn.resource_id().to_string())
+        }
+        // arn:aws:cognito-idp:<region>:<account>:userpool/<pool-id>,
+        // arn:aws:cognito-identity:<region>:<account>:identitypool/<region>:<uuid>
+        "cognito-idp" if arn.resource_type() == "userpool" => {
+            (ResourceType::Cognito, arn.resource_id().to_string())
+        }
+        "cognito-identity" if arn.resource_type() == "identitypool" => {
+            (ResourceType::Cognito, arn.resource_id().to_string())
+        }
+        // arn:aws:glue:<region>:<account>:database/<name> 또는 crawler/<name> (테이블 ARN은 제외)
+        "glue" if matches!(arn.resource_type(), "database" | "crawler") => {
+            (ResourceType::Glue, arn.resource.to_string())
+        }
+        // arn:aws:athena:<region>:<account>:workgroup/<name>
+        "athena" if arn.resource_type() == "workgroup" => {
+            (ResourceType::Athena, arn.resource_id().to_string())
+        }
+        // arn:aws:backup:<region>:<account>:backup-plan:<plan-id> (볼트/복구 시점 ARN은 제외)
+        "backup" if arn.resource_type() == "backup-plan" => {
+            (ResourceType::Backup, arn.resource_id().to_string())
+        }
+        // arn:aws:kinesis:<region>:<account>:stream/<name> (소비자 ARN stream/<name>/consumer/...은 제외)
+        "kinesis" if arn.resource_type() == "stream" && !arn.resource_id().contains('/') => {
+            (ResourceType::Kinesis, arn.resource_id().to_string())
+        }
+        // arn:aws:kafka:<region>:<account>:cluster/<name>/<uuid> (토픽/구성 ARN은 제외)
+        "kafka" if arn.resource_type() == "cluster" => (ResourceType::Msk, resource.arn.clone()),
+        // arn:aws:es:<region>:<account>:domain/<name>
+        "es" if arn.resource_type() == "domain" => {
+            (ResourceType::OpenSearch, arn.resource_id().to_string())
+        }
+        // arn:aws:redshift:<region>:<account>:cluster:<identifier> (스냅샷/파라미터 그룹 ARN은 제외)
+        "redshift" if arn.resource_type() == "cluster" => {
+            (ResourceType::Redshift, arn.resource_name().to_string())
+        }
+        // arn:aws:directconnect:<region>:<account>:dxcon/<id> 또는 dxvif/<id> (LAG ARN은 제외)
+        "directconnect" if matches!(arn.resource_type(), "dxcon" | "dxvif") => {
+            (ResourceType::DirectConnect, arn.resource_id().to_string())
+        }
+        // arn:aws:wafv2:<region>:<account>:regional/webacl/<name>/<id> 또는 global/webacl/... (IP 세트/규칙 그룹 ARN은 제외)
+        "wafv2" if arn.resource_id().starts_with("webacl/") => {
+            (ResourceType::Waf, resource.arn.clone())
+        }
+        // arn:aws:acm:<region>:<account>:certificate/<id>
+        "acm" if arn.resource_type() == "certificate" => (ResourceType::Acm, resource.arn.clone()),
+        // arn:aws:cloudformation:<region>:<account>:stack/<name>/<id> (스택 세트 ARN은 제외)
+        "cloudformation" if arn.resource_type() == "stack" => (
+            ResourceType::CloudFormation,
+            arn.resource_name().to_string(),
+        ),
+        "route53" if arn.resource_type() == "hostedzone" => {
+            (ResourceType::Route53, arn.resource_name().to_string())
+        }
+        _ => return None,
+    };
+
+    // Name 태그가 없으면 ARN에서 읽을 수 있는 이름 사용 (LB: loadbalancer/app/<name>/<id>)
+    let fallback_name = match resource_type {
+        ResourceType::LoadBalancer
+        | ResourceType::Rds
+        | ResourceType::Lambda
+        | ResourceType::ElastiCache
+        | ResourceType::CloudWatchAlarm
+        | ResourceType::Secret
+        | ResourceType::StepFunctions
+        | ResourceType::Msk
+        | ResourceType::Waf
+        | ResourceType::CloudFormation => arn.resource_name(),
+        _ => arn.resource_suffix(),
+    };
+    // SSM 파라미터는 경로 단위로 묶으므로 개별 파라미터의 Name 태그 대신 경로 사용
+    let resource_name = if resource_type == ResourceType::SsmParameter {
+        resource_id.clone()
+    } else {
+        resource
+            .name
+            .clone()
+            .unwrap_or_else(|| fallback_name.to_string())
+    };
+
+    // CloudFront 같은 글로벌 서비스는 ARN에 리전이 없으므로 us-east-1에서 조회
+    let region = if arn.region.is_empty() {
+        "us-east-1"
+    } else {
+        arn.region
+    };
+
+    Some(BlueprintResource {
+        resource_type,
+        region: region.to_string(),
+        resource_id,
+        resource_name,
+    })
+}
+
+/// Build a blueprint named after the query from `get-resources` output.
+pub fn blueprint_from_tagged_resources(query: &str, resources: &[TaggedResource]) -> Blueprint {
+    let mut blueprint = Blueprint::new(query.trim().to_string());
+    for resource in resources.iter().filter_map(to_blueprint_resource) {
+        blueprint.add_resource(resource);
+    }
+    blueprint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blueprint_from_tagged_resources, parse_tag_query};
+    use crate::aws_cli::TaggedResource;
+    use crate::blueprint::ResourceType;
+
+    fn tagged(arn: &str, name: Option<&str>) -> TaggedResource {
+        TaggedResource {
+            arn: arn.to_string(),
+            name: name.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parse_tag_query_splits_keys_and_alternative_values() {
+        let filters = parse_tag_query("Project=checkout, Env=prod|stage ,Owner").expect("query");
+        assert_eq!(
+            filters,
+            vec![
+                ("Project".to_string(), vec!["checkout".to_string()]),
+                (
+                    "Env".to_string(),
+                    vec!["prod".to_string(), "stage".to_string()]
+                ),
+                ("Owner".to_string(), Vec::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_tag_query_rejects_empty_or_keyless_terms() {
+        assert!(parse_tag_query("  ").is_err());
+        assert!(parse_tag_query("=checkout").is_err());
+    }
+
+    #[test]
+    fn blueprint_from_tagged_resources_maps_supported_arns() {
+        let resources = vec![
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:instance/i-0123",
+                Some("checkout-web"),
+            ),
+            tagged("arn:aws:ec2:ap-northeast-2:123456789012:vpc/vpc-1", None),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:security-group/sg-1",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/app/checkout/abcd",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/legacy",
+                None,
+            ),
+            tagged(
+                "arn:aws:ecr:ap-northeast-2:123456789012:repository/team/checkout",
+                None,
+            ),
+            tagged(
+                "arn:aws:autoscaling:ap-northeast-2:123456789012:autoScalingGroup:uuid:autoScalingGroupName/checkout-asg",
+                None,
+            ),
+            tagged(
+                "arn:aws:rds:ap-northeast-2:123456789012:cluster:checkout-db",
+                None,
+            ),
+            tagged(
+                "arn:aws:lambda:ap-northeast-2:123456789012:function:checkout-api",
+                None,
+            ),
+            tagged(
+                "arn:aws:dynamodb:ap-northeast-2:123456789012:table/checkout-orders",
+                None,
+            ),
+            tagged(
+                "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE",
+                Some("checkout-cdn"),
+            ),
+            tagged("arn:aws:route53:::hostedzone/Z0123456789ABC", None),
+            tagged(
+                "arn:aws:eks:ap-northeast-2:123456789012:cluster/checkout",
+                None,
+            ),
+            tagged(
+                "arn:aws:ecs:ap-northeast-2:123456789012:cluster/checkout",
+                None,
+            ),
+            tagged(
+                "arn:aws:sqs:ap-northeast-2:123456789012:checkout-orders.fifo",
+                None,
+            ),
+            tagged(
+                "arn:aws:sns:ap-northeast-2:123456789012:checkout-alerts",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:checkout-cache",
+                None,
+            ),
+            tagged(
+                "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:checkout-5xx",
+                None,
+            ),
+            tagged(
+                "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/checkout-audit",
+                None,
+            ),
+            tagged(
+                "arn:aws:kms:ap-northeast-2:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+                Some("checkout-data"),
+            ),
+            tagged(
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:checkout/db-AbCdEf",
+                None,
+            ),
+            tagged(
+                "arn:aws:ssm:ap-northeast-2:123456789012:parameter/checkout/db/host",
+                Some("db-host"),
+            ),
+            tagged(
+                "arn:aws:ssm:ap-northeast-2:123456789012:parameter/checkout/api-url",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticfilesystem:ap-northeast-2:123456789012:file-system/fs-0123456789abcdef0",
+                Some("checkout-shared"),
+            ),
+            tagged(
+                "arn:aws:apigateway:ap-northeast-2::/restapis/a1b2c3d4e5",
+                Some("checkout-api"),
+            ),
+            tagged("arn:aws:apigateway:ap-northeast-2::/apis/f6g7h8i9j0", None),
+            tagged(
+                "arn:aws:apigateway:ap-northeast-2::/restapis/a1b2c3d4e5/stages/prod",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticbeanstalk:ap-northeast-2:123456789012:environment/checkout/checkout-prod",
+                None,
+            ),
+            tagged(
+                "arn:aws:elasticbeanstalk:ap-northeast-2:123456789012:application/checkout",
+                None,
+            ),
+            tagged(
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:checkout-flow",
+                None,
+            ),
+            tagged(
+                "arn:aws:states:ap-northeast-2:123456789012:execution:checkout-flow:run-1",
+                None,
+            ),
+            tagged(
+                "arn:aws:kinesis:ap-northeast-2:123456789012:stream/checkout-events",
+                None,
+            ),
+            tagged(
+                "arn:aws:kinesis:ap-northeast-2:123456789012:stream/checkout-events/consumer/audit:1767225600",
+                None,
+            ),
+            tagged(
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/checkout-events/abcd-1",
+                None,
+            ),
+            tagged(
+                "arn:aws:es:ap-northeast-2:123456789012:domain/checkout-search",
+                None,
+            ),
+            tagged(
+                "arn:aws:redshift:ap-northeast-2:123456789012:cluster:checkout-dw",
+                None,
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:transit-gateway/tgw-0123",
+                Some("checkout-tgw"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:vpc-peering-connection/pcx-0123",
+                Some("checkout-peer"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:customer-gateway/cgw-0123",
+                Some("checkout-hq"),
+            ),
+            tagged(
+                "arn:aws:directconnect:ap-northeast-2:123456789012:dxvif/dxvif-ffgh1234",
+                Some("checkout-onprem"),
+            ),
+            tagged(
+                "arn:aws:directconnect:ap-northeast-2:123456789012:dxlag/dxlag-ffab1234",
+                None,
+            ),
+            tagged(
+                "arn:aws:wafv2:us-east-1:123456789012:global/webacl/checkout-edge/a1b2c3d4",
+                None,
+            ),
+            tagged(
+                "arn:aws:wafv2:ap-northeast-2:123456789012:regional/ipset/office/e5f6a7b8",
+                None,
+            ),
+            tagged(
+                "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c",
+                Some("checkout-cert"),
+            ),
+            tagged(
+                "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/checkout-stack/1a2b3c4d",
+                None,
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:vpc-endpoint/vpce-0123",
+                Some("checkout-s3"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:network-interface/eni-0123",
+                Some("checkout-eni"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:launch-template/lt-0123",
+                Some("checkout-web"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2::image/ami-0123",
+                Some("checkout-golden"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:volume/vol-0123",
+                Some("checkout-data"),
+            ),
+            tagged(
+                "arn:aws:ec2:ap-northeast-2:123456789012:key-pair/checkout-deploy",
+                None,
+            ),
+            tagged(
+                "arn:aws:events:ap-northeast-2:123456789012:event-bus/checkout-events",
+                None,
+            ),
+            tagged(
+                "arn:aws:events:ap-northeast-2:123456789012:rule/checkout-events/order-created",
+                None,
+            ),
+            tagged("arn:aws:s3:::checkout-assets", None),
+            tagged(
+                "arn:aws:cognito-idp:ap-northeast-2:123456789012:userpool/ap-northeast-2_Checkout1",
+                Some("checkout-users"),
+            ),
+            tagged(
+                "arn:aws:cognito-identity:ap-northeast-2:123456789012:identitypool/ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666",
+                None,
+            ),
+            tagged(
+                "arn:aws:glue:ap-northeast-2:123456789012:crawler/checkout-raw",
+                None,
+            ),
+            tagged(
+                "arn:aws:glue:ap-northeast-2:123456789012:table/checkout/orders",
+                None,
+            ),
+            tagged(
+                "arn:aws:athena:ap-northeast-2:123456789012:workgroup/checkout",
+                Some("checkout-analytics"),
+            ),
+            tagged(
+                "arn:aws:backup:ap-northeast-2:123456789012:backup-vault:checkout",
+                None,
+            ),
+            tagged(
+                "arn:aws:backup:ap-northeast-2:123456789012:backup-plan:8a2f1c4e",
+                Some("checkout-daily"),
+            ),
+        ];
+
+        let blueprint = blueprint_from_tagged_resources(" Project=checkout ", &resources);
+        assert_eq!(blueprint.name, "Project=checkout");
+        let mapped: Vec<(ResourceType, &str, &str)> = blueprint
+            .resources
+            .iter()
+            .map(|r| {
+                (
+                    r.resource_type.clone(),
+                    r.resource_id.as_str(),
+                    r.resource_name.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(mapped.len(), 49);
+        assert_eq!(mapped[0], (ResourceType::Ec2, "i-0123", "checkout-web"));
+        assert_eq!(mapped[1], (ResourceType::Network, "vpc-1", "vpc-1"));
+        assert_eq!(mapped[2].0, ResourceType::SecurityGroup);
+        assert_eq!(mapped[3].0, ResourceType::LoadBalancer);
+        assert_eq!(mapped[3].2, "checkout");
+        assert_eq!(blueprint.resources[3].region, "us-east-1");
+        assert_eq!(mapped[4], (ResourceType::Ecr, "team/checkout", "checkout"));
+        assert_eq!(
+            mapped[5],
+            (ResourceType::Asg, "checkout-asg", "checkout-asg")
+        );
+        assert_eq!(mapped[6], (ResourceType::Rds, "checkout-db", "checkout-db"));
+        assert_eq!(
+            mapped[7],
+            (ResourceType::Lambda, "checkout-api", "checkout-api")
+        );
+        assert_eq!(
+            mapped[8],
+            (ResourceType::DynamoDb, "checkout-orders", "checkout-orders")
+        );
+        assert_eq!(
+            mapped[9],
+            (ResourceType::CloudFront, "E2EXAMPLE", "checkout-cdn")
+        );
+        assert_eq!(blueprint.resources[9].region, "us-east-1");
+        assert_eq!(
+            mapped[10],
+            (ResourceType::Route53, "Z0123456789ABC", "Z0123456789ABC")
+        );
+        assert_eq!(mapped[11], (ResourceType::Eks, "checkout", "checkout"));
+        assert_eq!(mapped[12], (ResourceType::Ecs, "checkout", "checkout"));
+        assert_eq!(
+            mapped[13],
+            (
+                ResourceType::Sqs,
+                "checkout-orders.fifo",
+                "checkout-orders.fifo"
+            )
+        );
+        assert_eq!(
+            mapped[14],
+            (
+                ResourceType::Sns,
+                "arn:aws:sns:ap-northeast-2:123456789012:checkout-alerts",
+                "checkout-alerts"
+            )
+        );
+        assert_eq!(
+            mapped[15],
+            (
+                ResourceType::ElastiCache,
+                "checkout-cache",
+                "checkout-cache"
+            )
+        );
+        assert_eq!(
+            mapped[16],
+            (
+                ResourceType::CloudWatchAlarm,
+                "checkout-5xx",
+                "checkout-5xx"
+            )
+        );
+        assert_eq!(
+            mapped[17],
+            (
+                ResourceType::CloudTrail,
+                "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/checkout-audit",
+                "checkout-audit"
+            )
+        );
+        assert_eq!(
+            mapped[18],
+            (
+                ResourceType::Kms,
+                "1234abcd-12ab-34cd-56ef-1234567890ab",
+                "checkout-data"
+            )
+        );
+        assert_eq!(
+            mapped[19],
+            (
+                ResourceType::Secret,
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:checkout/db-AbCdEf",
+                "checkout/db"
+            )
+        );
+        // 같은 경로 접두사의 파라미터는 하나로 묶임
+        assert_eq!(
+            mapped[20],
+            (ResourceType::SsmParameter, "/checkout", "/checkout")
+        );
+        assert_eq!(
+            mapped[21],
+            (ResourceType::Efs, "fs-0123456789abcdef0", "checkout-shared")
+        );
+        assert_eq!(
+            mapped[22],
+            (ResourceType::ApiGateway, "a1b2c3d4e5", "checkout-api")
+        );
+        assert_eq!(
+            mapped[23],
+            (ResourceType::ApiGateway, "f6g7h8i9j0", "f6g7h8i9j0")
+        );
+        assert_eq!(
+            mapped[24],
+            (
+                ResourceType::ElasticBeanstalk,
+                "checkout-prod",
+                "checkout-prod"
+            )
+        );
+        assert_eq!(
+            mapped[25],
+            (
+                ResourceType::StepFunctions,
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:checkout-flow",
+                "checkout-flow"
+            )
+        );
+        assert_eq!(
+            mapped[26],
+            (ResourceType::Kinesis, "checkout-events", "checkout-events")
+        );
+        assert_eq!(
+            mapped[27],
+            (
+                ResourceType::Msk,
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/checkout-events/abcd-1",
+                "checkout-events"
+            )
+        );
+        assert_eq!(
+            mapped[28],
+            (
+                ResourceType::OpenSearch,
+                "checkout-search",
+                "checkout-search"
+            )
+        );
+        assert_eq!(
+            mapped[29],
+            (ResourceType::Redshift, "checkout-dw", "checkout-dw")
+        );
+        assert_eq!(
+            mapped[30],
+            (ResourceType::TransitGateway, "tgw-0123", "checkout-tgw")
+        );
+        assert_eq!(
+            mapped[31],
+            (ResourceType::VpcPeering, "pcx-0123", "checkout-peer")
+        );
+        assert_eq!(mapped[32], (ResourceType::Vpn, "cgw-0123", "checkout-hq"));
+        assert_eq!(
+            mapped[33],
+            (
+                ResourceType::DirectConnect,
+                "dxvif-ffgh1234",
+                "checkout-onprem"
+            )
+        );
+        assert_eq!(
+            mapped[34],
+            (
+                ResourceType::Waf,
+                "arn:aws:wafv2:us-east-1:123456789012:global/webacl/checkout-edge/a1b2c3d4",
+                "checkout-edge"
+            )
+        );
+        assert_eq!(
+            mapped[35],
+            (
+                ResourceType::Acm,
+                "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c",
+                "checkout-cert"
+            )
+        );
+        assert_eq!(
+            mapped[36],
+            (
+                ResourceType::CloudFormation,
+                "checkout-stack",
+                "checkout-stack"
+            )
+        );
+        assert_eq!(
+            mapped[37],
+            (ResourceType::VpcEndpoint, "vpce-0123", "checkout-s3")
+        );
+        assert_eq!(
+            mapped[38],
+            (ResourceType::NetworkInterface, "eni-0123", "checkout-eni")
+        );
+        assert_eq!(
+            mapped[39],
+            (ResourceType::LaunchTemplate, "lt-0123", "checkout-web")
+        );
+        assert_eq!(
+            mapped[40],
+            (ResourceType::Ami, "ami-0123", "checkout-golden")
+        );
+        assert_eq!(mapped[41], (ResourceType::Ebs, "vol-0123", "checkout-data"));
+        assert_eq!(
+            mapped[42],
+            (ResourceType::KeyPair, "checkout-deploy", "checkout-deploy")
+        );
+        assert_eq!(
+            mapped[43],
+            (
+                ResourceType::EventBridge,
+                "checkout-events",
+                "checkout-events"
+            )
+        );
+        assert_eq!(
+            mapped[44],
+            (
+                ResourceType::Cognito,
+                "ap-northeast-2_Checkout1",
+                "checkout-users"
+            )
+        );
+        assert_eq!(
+            mapped[45],
+            (
+                ResourceType::Cognito,
+                "ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666",
+                "ap-northeast-2:0a1b2c3d-1111-2222-3333-444455556666"
+            )
+        );
+        assert_eq!(
+            mapped[46],
+            (
+                ResourceType::Glue,
+                "crawler/checkout-raw",
+                "crawler/checkout-raw"
+            )
+        );
+        assert_eq!(
+            mapped[47],
+            (ResourceType::Athena, "checkout", "checkout-analytics")
+        );
+        assert_eq!(
+            mapped[48],
+            (ResourceType::Backup, "8a2f1c4e", "checkout-daily")
+        );
+    }
+}
@@ -0,0 +1,132 @@
+// 로그 파일 기록과 선택적 OTLP(OpenTelemetry) 트레이스 내보내기
+use crate::settings::OtlpTracing;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing::Level;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::prelude::*;
+
+const SERVICE_NAME: &str = "emd";
+// OTLP/HTTP 수집기의 트레이스 경로
+const TRACES_PATH: &str = "/v1/traces";
+
+/// Keeps the log writer and the span exporter alive; flushes both on drop.
+pub struct TelemetryGuard {
+    _log_guard: WorkerGuard,
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        // 종료 전에 남은 스팬을 내보냄
+        if let Some(provider) = self.provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("Failed to flush traces: {}", e);
+        }
+    }
+}
+
+/// Log to `emd.log`, and export spans over OTLP/HTTP when an endpoint is configured.
+pub fn init(config: Option<&OtlpTracing>) -> TelemetryGuard {
+    let file_appender = tracing_appender::rolling::daily(".", "emd.log");
+    let (non_blocking, log_guard) = tracing_appender::non_blocking(file_appender);
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(non_blocking)
+        .with_ansi(false)
+        .with_filter(LevelFilter::INFO);
+
+    let provider = config
+        .map(|c| c.endpoint.trim())
+        .filter(|endpoint| !endpoint.is_empty())
+        .and_then(|endpoint| match build_provider(endpoint) {
+            Ok(provider) => Some(provider),
+            Err(e) => {
+                eprintln!("Failed to set up OTLP exporter for {}: {}", endpoint, e);
+                None
+            }
+        });
+    let otel_layer = provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer(SERVICE_NAME))
+            .with_filter(span_targets())
+    });
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer)
+        .init();
+
+    if let Some(config) = config.filter(|_| provider.is_some()) {
+        tracing::info!("Exporting traces to {}", traces_endpoint(&config.endpoint));
+    }
+
+    TelemetryGuard {
+        _log_guard: log_guard,
+        provider,
+    }
+}
+
+fn build_provider(endpoint: &str) -> Result<SdkTracerProvider, Box<dyn std::error::Error>> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(traces_endpoint(endpoint))
+        .build()?;
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .build())
+}
+
+// 앱의 스팬과 AWS SDK 호출 스팬(invoke)만 내보내고 HTTP/TLS 내부 스팬은 제외
+fn span_targets() -> Targets {
+    Targets::new()
+        .with_target("emd", Level::TRACE)
+        .with_target("aws_smithy_runtime", Level::DEBUG)
+        .with_target("aws_sdk", Level::DEBUG)
+}
+
+// 수집기 주소만 적어도 되도록 트레이스 경로를 붙임 (Jaeger: http://localhost:4318)
+fn traces_endpoint(endpoint: &str) -> String {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    if endpoint.ends_with(TRACES_PATH) {
+        endpoint.to_string()
+    } else {
+        format!("{}{}", endpoint, TRACES_PATH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{span_targets, traces_endpoint};
+    use tracing::Level;
+
+    #[test]
+    fn traces_endpoint_appends_path_once() {
+        assert_eq!(
+            traces_endpoint("http://localhost:4318"),
+            "http://localhost:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_endpoint(" http://localhost:4318/ "),
+            "http://localhost:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_endpoint("https://otel.example.com/v1/traces"),
+            "https://otel.example.com/v1/traces"
+        );
+    }
+
+    #[test]
+    fn span_targets_skip_transport_internals() {
+        let targets = span_targets();
+        assert!(targets.would_enable("emd::handler", &Level::INFO));
+        assert!(targets.would_enable("aws_smithy_runtime::client::orchestrator", &Level::DEBUG));
+        assert!(targets.would_enable("aws_sdk_ec2::operation", &Level::DEBUG));
+        assert!(!targets.would_enable("hyper_util::client", &Level::DEBUG));
+        assert!(!targets.would_enable("aws_smithy_runtime::client", &Level::TRACE));
+    }
+}
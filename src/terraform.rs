@@ -0,0 +1,951 @@
+use crate::aws_cli::Arn;
+use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+fn resource_type_for(tf_type: &str) -> Option<ResourceType> {
+    match tf_type {
+        "aws_instance" => Some(ResourceType::Ec2),
+        "aws_vpc" => Some(ResourceType::Network),
+        "aws_security_group" => Some(ResourceType::SecurityGroup),
+        "aws_lb" | "aws_alb" => Some(ResourceType::LoadBalancer),
+        "aws_ecr_repository" => Some(ResourceType::Ecr),
+        "aws_autoscaling_group" => Some(ResourceType::Asg),
+        "aws_db_instance" | "aws_rds_cluster" => Some(ResourceType::Rds),
+        "aws_lambda_function" => Some(ResourceType::Lambda),
+        "aws_dynamodb_table" => Some(ResourceType::DynamoDb),
+        "aws_cloudfront_distribution" => Some(ResourceType::CloudFront),
+        "aws_route53_zone" => Some(ResourceType::Route53),
+        "aws_eks_cluster" => Some(ResourceType::Eks),
+        "aws_ecs_cluster" => Some(ResourceType::Ecs),
+        "aws_sns_topic" => Some(ResourceType::Sns),
+        "aws_sqs_queue" => Some(ResourceType::Sqs),
+        "aws_elasticache_replication_group" | "aws_elasticache_cluster" => {
+            Some(ResourceType::ElastiCache)
+        }
+        "aws_cloudwatch_metric_alarm" | "aws_cloudwatch_composite_alarm" => {
+            Some(ResourceType::CloudWatchAlarm)
+        }
+        "aws_cloudtrail" => Some(ResourceType::CloudTrail),
+        "aws_kms_key" => Some(ResourceType::Kms),
+        "aws_secretsmanager_secret" => Some(ResourceType::Secret),
+        "aws_efs_file_system" => Some(ResourceType::Efs),
+        "aws_api_gateway_rest_api" | "aws_apigatewayv2_api" => Some(ResourceType::ApiGateway),
+        "aws_elastic_beanstalk_environment" => Some(ResourceType::ElasticBeanstalk),
+        "aws_sfn_state_machine" => Some(ResourceType::StepFunctions),
+        "aws_cloudwatch_event_bus" => Some(ResourceType::EventBridge),
+        "aws_cognito_user_pool" | "aws_cognito_identity_pool" => Some(ResourceType::Cognito),
+        "aws_glue_catalog_database" | "aws_glue_crawler" => Some(ResourceType::Glue),
+        "aws_athena_workgroup" => Some(ResourceType::Athena),
+        "aws_backup_plan" => Some(ResourceType::Backup),
+        "aws_kinesis_stream" => Some(ResourceType::Kinesis),
+        "aws_msk_cluster" => Some(ResourceType::Msk),
+        "aws_opensearch_domain" | "aws_elasticsearch_domain" => Some(ResourceType::OpenSearch),
+        "aws_redshift_cluster" => Some(ResourceType::Redshift),
+        "aws_ec2_transit_gateway" => Some(ResourceType::TransitGateway),
+        "aws_vpc_peering_connection" => Some(ResourceType::VpcPeering),
+        "aws_vpc_endpoint" => Some(ResourceType::VpcEndpoint),
+        "aws_network_interface" => Some(ResourceType::NetworkInterface),
+        "aws_launch_template" => Some(ResourceType::LaunchTemplate),
+        "aws_ami" | "aws_ami_copy" | "aws_ami_from_instance" => Some(ResourceType::Ami),
+        "aws_ebs_volume" | "aws_ebs_snapshot" => Some(ResourceType::Ebs),
+        "aws_key_pair" => Some(ResourceType::KeyPair),
+        "aws_vpn_connection" | "aws_customer_gateway" | "aws_vpn_gateway" => {
+            Some(ResourceType::Vpn)
+        }
+        "aws_dx_connection"
+        | "aws_dx_hosted_connection"
+        | "aws_dx_private_virtual_interface"
+        | "aws_dx_public_virtual_interface"
+        | "aws_dx_transit_virtual_interface" => Some(ResourceType::DirectConnect),
+        "aws_wafv2_web_acl" => Some(ResourceType::Waf),
+        "aws_acm_certificate" => Some(ResourceType::Acm),
+        "aws_cloudformation_stack" => Some(ResourceType::CloudFormation),
+        "aws_iam_role" | "aws_iam_user" | "aws_iam_group" | "aws_iam_policy" => {
+            Some(ResourceType::Iam)
+        }
+        _ => None,
+    }
+}
+
+fn attr<'a>(attributes: &'a Value, key: &str) -> Option<&'a str> {
+    attributes
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
+
+// ARN의 region 필드를 우선 사용하고, 없으면 기본 리전 사용
+fn region_from_attributes(attributes: &Value, default_region: &str) -> String {
+    attr(attributes, "arn")
+        .and_then(Arn::parse)
+        .map(|arn| arn.region)
+        .filter(|region| !region.is_empty())
+        .unwrap_or(default_region)
+        .to_string()
+}
+
+fn to_blueprint_resource(
+    tf_type: &str,
+    address: &str,
+    attributes: &Value,
+    default_region: &str,
+) -> Option<BlueprintResource> {
+    let resource_type = resource_type_for(tf_type)?;
+
+    // LB/SNS/CloudTrail/WAF/ACM/IAM은 ARN, ECR/ASG/Lambda/DynamoDB/EKS/ECS/SQS/Kinesis/CloudFormation 스택은 이름, RDS는 DB/클러스터 식별자,
+    // ElastiCache는 복제 그룹/클러스터 ID, CloudWatch 경보는 경보 이름, OpenSearch는 도메인 이름,
+    // Redshift는 클러스터 식별자, 키 페어는 key-로 시작하는 키 페어 ID가 EMD의 리소스 ID
+    let resource_id = match resource_type {
+        ResourceType::LoadBalancer
+        | ResourceType::Sns
+        | ResourceType::CloudTrail
+        | ResourceType::Waf
+        | ResourceType::Acm
+        | ResourceType::Iam => attr(attributes, "arn").or(attr(attributes, "id")),
+        ResourceType::Ecr
+        | ResourceType::Asg
+        | ResourceType::DynamoDb
+        | ResourceType::Eks
+        | ResourceType::Ecs
+        | ResourceType::Sqs
+        | ResourceType::Kinesis
+        | ResourceType::CloudFormation => attr(attributes, "name").or(attr(attributes, "id")),
+        // aws_db_instance의 id는 DbiResourceId(db-XXXX)이므로 identifier 우선
+        ResourceType::Rds => attr(attributes, "identifier")
+            .or(attr(attributes, "cluster_identifier"))
+            .or(attr(attributes, "id")),
+        ResourceType::Lambda => attr(attributes, "function_name").or(attr(attributes, "id")),
+        ResourceType::ElastiCache => attr(attributes, "replication_group_id")
+            .or(attr(attributes, "cluster_id"))
+            .or(attr(attributes, "id")),
+        ResourceType::CloudWatchAlarm => attr(attributes, "alarm_name").or(attr(attributes, "id")),
+        ResourceType::OpenSearch => attr(attributes, "domain_name").or(attr(attributes, "id")),
+        ResourceType::Redshift => attr(attributes, "cluster_identifier").or(attr(attributes, "id")),
+        ResourceType::KeyPair => attr(attributes, "key_pair_id").or(attr(attributes, "id")),
+        // aws_glue_catalog_database의 id는 "<카탈로그 ID>:<이름>"이므로 ARN의 database/<이름> 사용
+        ResourceType::Glue => attr(attributes, "arn")
+            .and_then(Arn::parse)
+            .map(|arn| arn.resource),
+        _ => attr(attributes, "id"),
+    }?;
+
+    let resource_name = attributes
+        .get("tags")
+        .and_then(|tags| attr(tags, "Name"))
+        .or(attr(attributes, "name"))
+        .or(attr(attributes, "cluster_name"))
+        .or(attr(attributes, "domain_name"))
+        .or(attr(attributes, "cluster_identifier"))
+        .or(attr(attributes, "key_name"))
+        .unwrap_or(address);
+
+    Some(BlueprintResource {
+        resource_type,
+        region: region_from_attributes(attributes, default_region),
+        resource_id: resource_id.to_string(),
+        resource_name: resource_name.to_string(),
+    })
+}
+
+// terraform.tfstate (version 4) 형식
+fn collect_from_state(state: &Value, default_region: &str, out: &mut Vec<BlueprintResource>) {
+    let Some(resources) = state.get("resources").and_then(Value::as_array) else {
+        return;
+    };
+
+    for resource in resources {
+        if resource.get("mode").and_then(Value::as_str) == Some("data") {
+            continue;
+        }
+        let tf_type = resource
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let name = resource
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let address = match resource.get("module").and_then(Value::as_str) {
+            Some(module) => format!("{}.{}.{}", module, tf_type, name),
+            None => format!("{}.{}", tf_type, name),
+        };
+
+        for instance in resource
+            .get("instances")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(attributes) = instance.get("attributes")
+                && let Some(res) =
+                    to_blueprint_resource(tf_type, &address, attributes, default_region)
+            {
+                out.push(res);
+            }
+        }
+    }
+}
+
+// `terraform show -json` 형식 (child_modules 재귀)
+fn collect_from_module(module: &Value, default_region: &str, out: &mut Vec<BlueprintResource>) {
+    for resource in module
+        .get("resources")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if resource.get("mode").and_then(Value::as_str) == Some("data") {
+            continue;
+        }
+        let tf_type = resource
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let address = resource
+            .get("address")
+            .and_then(Value::as_str)
+            .unwrap_or(tf_type);
+
+        if let Some(values) = resource.get("values")
+            && let Some(res) = to_blueprint_resource(tf_type, address, values, default_region)
+        {
+            out.push(res);
+        }
+    }
+
+    for child in module
+        .get("child_modules")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        collect_from_module(child, default_region, out);
+    }
+}
+
+/// Extract the resources EMD can document from a Terraform state document.
+///
+/// Accepts both the raw `terraform.tfstate` file and `terraform show -json` output.
+pub fn parse_state(content: &str, default_region: &str) -> Result<Vec<BlueprintResource>, String> {
+    let state: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let mut resources = Vec::new();
+
+    if let Some(root) = state.get("values").and_then(|v| v.get("root_module")) {
+        collect_from_module(root, default_region, &mut resources);
+    } else {
+        collect_from_state(&state, default_region, &mut resources);
+    }
+
+    Ok(resources)
+}
+
+fn blueprint_name_for(path: &Path) -> String {
+    // terraform.tfstate 파일명보다 상위 디렉터리 이름이 스택을 더 잘 나타냄
+    path.canonicalize()
+        .ok()
+        .as_deref()
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .or_else(|| path.file_stem())
+        .map(|name| name.to_string_lossy().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "terraform".to_string())
+}
+
+pub fn import_blueprint(path: &str, default_region: &str) -> Result<Blueprint, String> {
+    let path = Path::new(path);
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let resources = parse_state(&content, default_region)?;
+
+    let mut blueprint = Blueprint::new(blueprint_name_for(path));
+    for resource in resources {
+        blueprint.add_resource(resource);
+    }
+    Ok(blueprint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{import_blueprint, parse_state};
+    use crate::blueprint::ResourceType;
+    use std::fs;
+
+    #[test]
+    fn parse_state_reads_tfstate_v4_resources_and_skips_data_sources() {
+        let payload = r#"
+            {
+              "version": 4,
+              "resources": [
+                {
+                  "mode": "managed",
+                  "type": "aws_instance",
+                  "name": "web",
+                  "instances": [
+                    {
+                      "attributes": {
+                        "id": "i-0123",
+                        "arn": "arn:aws:ec2:us-west-2:123456789012:instance/i-0123",
+                        "tags": {"Name": "web-a"}
+                      }
+                    }
+                  ]
+                },
+                {
+                  "mode": "managed",
+                  "module": "module.network",
+                  "type": "aws_vpc",
+                  "name": "main",
+                  "instances": [{"attributes": {"id": "vpc-1", "tags": {}}}]
+                },
+                {
+                  "mode": "data",
+                  "type": "aws_instance",
+                  "name": "lookup",
+                  "instances": [{"attributes": {"id": "i-data"}}]
+                },
+                {
+                  "mode": "managed",
+                  "type": "aws_s3_bucket",
+                  "name": "logs",
+                  "instances": [{"attributes": {"id": "logs"}}]
+                }
+              ]
+            }
+        "#;
+
+        let resources = parse_state(payload, "ap-northeast-2").expect("parse tfstate");
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0].resource_type, ResourceType::Ec2);
+        assert_eq!(resources[0].resource_id, "i-0123");
+        assert_eq!(resources[0].resource_name, "web-a");
+        assert_eq!(resources[0].region, "us-west-2");
+        assert_eq!(resources[1].resource_type, ResourceType::Network);
+        assert_eq!(resources[1].resource_name, "module.network.aws_vpc.main");
+        assert_eq!(resources[1].region, "ap-northeast-2");
+    }
+
+    #[test]
+    fn parse_state_reads_show_json_modules_with_service_specific_ids() {
+        let payload = r#"
+            {
+              "format_version": "1.0",
+              "values": {
+                "root_module": {
+                  "resources": [
+                    {
+                      "address": "aws_lb.public",
+                      "mode": "managed",
+                      "type": "aws_lb",
+                      "values": {
+                        "id": "arn:aws:elasticloadbalancing:eu-west-1:123456789012:loadbalancer/app/public/abcd",
+                        "arn": "arn:aws:elasticloadbalancing:eu-west-1:123456789012:loadbalancer/app/public/abcd",
+                        "name": "public"
+                      }
+                    }
+                  ],
+                  "child_modules": [
+                    {
+                      "resources": [
+                        {
+                          "address": "module.app.aws_ecr_repository.api",
+                          "mode": "managed",
+                          "type": "aws_ecr_repository",
+                          "values": {"id": "api", "name": "api"}
+                        },
+                        {
+                          "address": "module.app.aws_autoscaling_group.api",
+                          "mode": "managed",
+                          "type": "aws_autoscaling_group",
+                          "values": {"id": "api-asg", "name": "api-asg"}
+                        },
+                        {
+                          "address": "module.app.aws_db_instance.api",
+                          "mode": "managed",
+                          "type": "aws_db_instance",
+                          "values": {"id": "db-ABCDEFGHIJ", "identifier": "api-db"}
+                        },
+                        {
+                          "address": "module.app.aws_lambda_function.api",
+                          "mode": "managed",
+                          "type": "aws_lambda_function",
+                          "values": {"id": "api-handler", "function_name": "api-handler"}
+                        },
+                        {
+                          "address": "module.app.aws_dynamodb_table.orders",
+                          "mode": "managed",
+                          "type": "aws_dynamodb_table",
+                          "values": {"id": "orders", "name": "orders"}
+                        },
+                        {
+                          "address": "module.app.aws_cloudfront_distribution.web",
+                          "mode": "managed",
+                          "type": "aws_cloudfront_distribution",
+                          "values": {
+                            "id": "E2EXAMPLE",
+                            "arn": "arn:aws:cloudfront::123456789012:distribution/E2EXAMPLE"
+                          }
+                        },
+                        {
+                          "address": "aws_route53_zone.main",
+                          "mode": "managed",
+                          "type": "aws_route53_zone",
+                          "values": {"id": "Z0123456789ABC", "name": "example.com"}
+                        },
+                        {
+                          "address": "aws_eks_cluster.main",
+                          "mode": "managed",
+                          "type": "aws_eks_cluster",
+                          "values": {"id": "checkout", "name": "checkout"}
+                        },
+                        {
+                          "address": "aws_ecs_cluster.main",
+                          "mode": "managed",
+                          "type": "aws_ecs_cluster",
+                          "values": {
+                            "id": "arn:aws:ecs:ap-northeast-2:123456789012:cluster/checkout",
+                            "arn": "arn:aws:ecs:ap-northeast-2:123456789012:cluster/checkout",
+                            "name": "checkout"
+                          }
+                        },
+                        {
+                          "address": "aws_sqs_queue.orders",
+                          "mode": "managed",
+                          "type": "aws_sqs_queue",
+                          "values": {
+                            "id": "https://sqs.ap-northeast-2.amazonaws.com/123456789012/orders",
+                            "name": "orders"
+                          }
+                        },
+                        {
+                          "address": "aws_sns_topic.alerts",
+                          "mode": "managed",
+                          "type": "aws_sns_topic",
+                          "values": {
+                            "id": "arn:aws:sns:ap-northeast-2:123456789012:alerts",
+                            "arn": "arn:aws:sns:ap-northeast-2:123456789012:alerts",
+                            "name": "alerts"
+                          }
+                        },
+                        {
+                          "address": "aws_elasticache_replication_group.sessions",
+                          "mode": "managed",
+                          "type": "aws_elasticache_replication_group",
+                          "values": {
+                            "id": "sessions",
+                            "arn": "arn:aws:elasticache:ap-northeast-2:123456789012:replicationgroup:sessions",
+                            "replication_group_id": "sessions"
+                          }
+                        },
+                        {
+                          "address": "aws_cloudwatch_metric_alarm.high_cpu",
+                          "mode": "managed",
+                          "type": "aws_cloudwatch_metric_alarm",
+                          "values": {
+                            "id": "api-high-cpu",
+                            "arn": "arn:aws:cloudwatch:ap-northeast-2:123456789012:alarm:api-high-cpu",
+                            "alarm_name": "api-high-cpu"
+                          }
+                        },
+                        {
+                          "address": "aws_cloudtrail.audit",
+                          "mode": "managed",
+                          "type": "aws_cloudtrail",
+                          "values": {
+                            "id": "org-audit",
+                            "arn": "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/org-audit",
+                            "name": "org-audit"
+                          }
+                        },
+                        {
+                          "address": "aws_kms_key.app_data",
+                          "mode": "managed",
+                          "type": "aws_kms_key",
+                          "values": {
+                            "id": "1234abcd-12ab-34cd-56ef-1234567890ab",
+                            "arn": "arn:aws:kms:ap-northeast-2:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+                            "key_id": "1234abcd-12ab-34cd-56ef-1234567890ab"
+                          }
+                        },
+                        {
+                          "address": "aws_secretsmanager_secret.checkout_db",
+                          "mode": "managed",
+                          "type": "aws_secretsmanager_secret",
+                          "values": {
+                            "id": "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf",
+                            "arn": "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:prod/checkout/db-AbCdEf",
+                            "name": "prod/checkout/db"
+                          }
+                        },
+                        {
+                          "address": "aws_efs_file_system.shared",
+                          "mode": "managed",
+                          "type": "aws_efs_file_system",
+                          "values": {
+                            "id": "fs-0123456789abcdef0",
+                            "arn": "arn:aws:elasticfilesystem:ap-northeast-2:123456789012:file-system/fs-0123456789abcdef0",
+                            "tags": { "Name": "shared-assets" }
+                          }
+                        },
+                        {
+                          "address": "aws_apigatewayv2_api.orders",
+                          "mode": "managed",
+                          "type": "aws_apigatewayv2_api",
+                          "values": {
+                            "id": "f6g7h8i9j0",
+                            "arn": "arn:aws:apigateway:ap-northeast-2::/apis/f6g7h8i9j0",
+                            "name": "orders-http"
+                          }
+                        },
+                        {
+                          "address": "aws_elastic_beanstalk_environment.orders",
+                          "mode": "managed",
+                          "type": "aws_elastic_beanstalk_environment",
+                          "values": {
+                            "id": "e-abc123xyz0",
+                            "arn": "arn:aws:elasticbeanstalk:ap-northeast-2:123456789012:environment/orders/orders-prod",
+                            "name": "orders-prod"
+                          }
+                        },
+                        {
+                          "address": "aws_sfn_state_machine.orders",
+                          "mode": "managed",
+                          "type": "aws_sfn_state_machine",
+                          "values": {
+                            "id": "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow",
+                            "arn": "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow",
+                            "name": "order-flow"
+                          }
+                        },
+                        {
+                          "address": "aws_kinesis_stream.clicks",
+                          "mode": "managed",
+                          "type": "aws_kinesis_stream",
+                          "values": {
+                            "id": "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream",
+                            "arn": "arn:aws:kinesis:ap-northeast-2:123456789012:stream/clickstream",
+                            "name": "clickstream"
+                          }
+                        },
+                        {
+                          "address": "aws_msk_cluster.events",
+                          "mode": "managed",
+                          "type": "aws_msk_cluster",
+                          "values": {
+                            "id": "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/abcd-1",
+                            "arn": "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/abcd-1",
+                            "cluster_name": "events"
+                          }
+                        },
+                        {
+                          "address": "aws_opensearch_domain.logs",
+                          "mode": "managed",
+                          "type": "aws_opensearch_domain",
+                          "values": {
+                            "id": "arn:aws:es:ap-northeast-2:123456789012:domain/logs",
+                            "arn": "arn:aws:es:ap-northeast-2:123456789012:domain/logs",
+                            "domain_name": "logs"
+                          }
+                        },
+                        {
+                          "address": "aws_redshift_cluster.analytics",
+                          "mode": "managed",
+                          "type": "aws_redshift_cluster",
+                          "values": {
+                            "id": "analytics",
+                            "arn": "arn:aws:redshift:ap-northeast-2:123456789012:cluster:analytics",
+                            "cluster_identifier": "analytics"
+                          }
+                        },
+                        {
+                          "address": "aws_ec2_transit_gateway.core",
+                          "mode": "managed",
+                          "type": "aws_ec2_transit_gateway",
+                          "values": {
+                            "id": "tgw-0123",
+                            "arn": "arn:aws:ec2:ap-northeast-2:123456789012:transit-gateway/tgw-0123",
+                            "amazon_side_asn": 64512,
+                            "tags": {"Name": "core-tgw"}
+                          }
+                        },
+                        {
+                          "address": "aws_vpc_peering_connection.shared",
+                          "mode": "managed",
+                          "type": "aws_vpc_peering_connection",
+                          "values": {
+                            "id": "pcx-0123",
+                            "vpc_id": "vpc-0123456789abcdef0",
+                            "peer_vpc_id": "vpc-0fedcba9876543210",
+                            "tags": {"Name": "app-to-shared"}
+                          }
+                        },
+                        {
+                          "address": "aws_vpn_connection.office",
+                          "mode": "managed",
+                          "type": "aws_vpn_connection",
+                          "values": {
+                            "id": "vpn-0123",
+                            "customer_gateway_id": "cgw-0123",
+                            "vpn_gateway_id": "vgw-0123",
+                            "tags": {"Name": "office"}
+                          }
+                        },
+                        {
+                          "address": "aws_dx_private_virtual_interface.onprem",
+                          "mode": "managed",
+                          "type": "aws_dx_private_virtual_interface",
+                          "values": {
+                            "id": "dxvif-ffgh1234",
+                            "connection_id": "dxcon-fgab1234",
+                            "name": "onprem-private",
+                            "vlan": 101
+                          }
+                        },
+                        {
+                          "address": "aws_wafv2_web_acl.edge",
+                          "mode": "managed",
+                          "type": "aws_wafv2_web_acl",
+                          "values": {
+                            "id": "a1b2c3d4-5678-90ab-cdef-EXAMPLE11111",
+                            "arn": "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/edge-acl/a1b2c3d4-5678-90ab-cdef-EXAMPLE11111",
+                            "name": "edge-acl",
+                            "scope": "REGIONAL"
+                          }
+                        },
+                        {
+                          "address": "aws_acm_certificate.api",
+                          "mode": "managed",
+                          "type": "aws_acm_certificate",
+                          "values": {
+                            "id": "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+                            "arn": "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+                            "domain_name": "api.example.com",
+                            "validation_method": "DNS"
+                          }
+                        },
+                        {
+                          "address": "aws_cloudformation_stack.legacy",
+                          "mode": "managed",
+                          "type": "aws_cloudformation_stack",
+                          "values": {
+                            "id": "arn:aws:cloudformation:ap-northeast-2:123456789012:stack/legacy-network/1a2b3c4d",
+                            "name": "legacy-network"
+                          }
+                        },
+                        {
+                          "address": "aws_iam_role.app",
+                          "mode": "managed",
+                          "type": "aws_iam_role",
+                          "values": {
+                            "arn": "arn:aws:iam::123456789012:role/app-role",
+                            "id": "app-role",
+                            "name": "app-role"
+                          }
+                        },
+                        {
+                          "address": "aws_vpc_endpoint.s3",
+                          "mode": "managed",
+                          "type": "aws_vpc_endpoint",
+                          "values": {
+                            "arn": "arn:aws:ec2:ap-northeast-2:123456789012:vpc-endpoint/vpce-0a1b2c3d",
+                            "id": "vpce-0a1b2c3d",
+                            "service_name": "com.amazonaws.ap-northeast-2.s3",
+                            "tags": {"Name": "s3-gateway"}
+                          }
+                        },
+                        {
+                          "address": "aws_network_interface.legacy",
+                          "mode": "managed",
+                          "type": "aws_network_interface",
+                          "values": {
+                            "arn": "arn:aws:ec2:ap-northeast-2:123456789012:network-interface/eni-0a1b2c3d",
+                            "id": "eni-0a1b2c3d",
+                            "tags": {"Name": "legacy-db"}
+                          }
+                        },
+                        {
+                          "address": "aws_launch_template.web",
+                          "mode": "managed",
+                          "type": "aws_launch_template",
+                          "values": {
+                            "arn": "arn:aws:ec2:ap-northeast-2:123456789012:launch-template/lt-0a1b2c3d",
+                            "id": "lt-0a1b2c3d",
+                            "name": "web"
+                          }
+                        },
+                        {
+                          "address": "aws_ami_from_instance.web",
+                          "mode": "managed",
+                          "type": "aws_ami_from_instance",
+                          "values": {
+                            "arn": "arn:aws:ec2:ap-northeast-2::image/ami-0a1b2c3d",
+                            "id": "ami-0a1b2c3d",
+                            "name": "web-2026-10"
+                          }
+                        },
+                        {
+                          "address": "aws_ebs_volume.data",
+                          "mode": "managed",
+                          "type": "aws_ebs_volume",
+                          "values": {
+                            "arn": "arn:aws:ec2:ap-northeast-2:123456789012:volume/vol-0a1b2c3d",
+                            "id": "vol-0a1b2c3d",
+                            "tags": {"Name": "data"}
+                          }
+                        },
+                        {
+                          "address": "aws_key_pair.deploy",
+                          "mode": "managed",
+                          "type": "aws_key_pair",
+                          "values": {
+                            "id": "deploy",
+                            "key_name": "deploy",
+                            "key_pair_id": "key-0a1b2c3d4e5f60718"
+                          }
+                        },
+                        {
+                          "address": "aws_cloudwatch_event_bus.orders",
+                          "mode": "managed",
+                          "type": "aws_cloudwatch_event_bus",
+                          "values": {
+                            "id": "orders",
+                            "arn": "arn:aws:events:ap-northeast-2:123456789012:event-bus/orders",
+                            "name": "orders"
+                          }
+                        },
+                        {
+                          "address": "aws_cognito_user_pool.customers",
+                          "mode": "managed",
+                          "type": "aws_cognito_user_pool",
+                          "values": {
+                            "id": "ap-northeast-2_AbCdEf123",
+                            "arn": "arn:aws:cognito-idp:ap-northeast-2:123456789012:userpool/ap-northeast-2_AbCdEf123",
+                            "name": "customers"
+                          }
+                        },
+                        {
+                          "address": "aws_glue_catalog_database.sales",
+                          "mode": "managed",
+                          "type": "aws_glue_catalog_database",
+                          "values": {
+                            "id": "123456789012:sales",
+                            "arn": "arn:aws:glue:ap-northeast-2:123456789012:database/sales",
+                            "name": "sales"
+                          }
+                        },
+                        {
+                          "address": "aws_athena_workgroup.analytics",
+                          "mode": "managed",
+                          "type": "aws_athena_workgroup",
+                          "values": {
+                            "id": "analytics",
+                            "arn": "arn:aws:athena:ap-northeast-2:123456789012:workgroup/analytics",
+                            "name": "analytics"
+                          }
+                        },
+                        {
+                          "address": "aws_backup_plan.daily",
+                          "mode": "managed",
+                          "type": "aws_backup_plan",
+                          "values": {
+                            "id": "8a2f1c4e-1111-2222-3333-444455556666",
+                            "arn": "arn:aws:backup:ap-northeast-2:123456789012:backup-plan:8a2f1c4e-1111-2222-3333-444455556666",
+                            "name": "daily-35d"
+                          }
+                        }
+                      ]
+                    }
+                  ]
+                }
+              }
+            }
+        "#;
+
+        let resources = parse_state(payload, "ap-northeast-2").expect("parse show json");
+        assert_eq!(resources.len(), 44);
+        assert_eq!(resources[0].resource_type, ResourceType::LoadBalancer);
+        assert!(
+            resources[0]
+                .resource_id
+                .starts_with("arn:aws:elasticloadbalancing")
+        );
+        assert_eq!(resources[0].region, "eu-west-1");
+        assert_eq!(resources[1].resource_type, ResourceType::Ecr);
+        assert_eq!(resources[1].resource_id, "api");
+        assert_eq!(resources[2].resource_type, ResourceType::Asg);
+        assert_eq!(resources[2].resource_name, "api-asg");
+        assert_eq!(resources[3].resource_type, ResourceType::Rds);
+        assert_eq!(resources[3].resource_id, "api-db");
+        assert_eq!(resources[4].resource_type, ResourceType::Lambda);
+        assert_eq!(resources[4].resource_id, "api-handler");
+        assert_eq!(resources[5].resource_type, ResourceType::DynamoDb);
+        assert_eq!(resources[5].resource_id, "orders");
+        assert_eq!(resources[6].resource_type, ResourceType::CloudFront);
+        assert_eq!(resources[6].resource_id, "E2EXAMPLE");
+        assert_eq!(resources[6].region, "ap-northeast-2");
+        assert_eq!(resources[7].resource_type, ResourceType::Route53);
+        assert_eq!(resources[7].resource_id, "Z0123456789ABC");
+        assert_eq!(resources[7].resource_name, "example.com");
+        assert_eq!(resources[8].resource_type, ResourceType::Eks);
+        assert_eq!(resources[8].resource_id, "checkout");
+        assert_eq!(resources[9].resource_type, ResourceType::Ecs);
+        assert_eq!(resources[9].resource_id, "checkout");
+        assert_eq!(resources[10].resource_type, ResourceType::Sqs);
+        assert_eq!(resources[10].resource_id, "orders");
+        assert_eq!(resources[11].resource_type, ResourceType::Sns);
+        assert_eq!(
+            resources[11].resource_id,
+            "arn:aws:sns:ap-northeast-2:123456789012:alerts"
+        );
+        assert_eq!(resources[11].resource_name, "alerts");
+        assert_eq!(resources[12].resource_type, ResourceType::ElastiCache);
+        assert_eq!(resources[12].resource_id, "sessions");
+        assert_eq!(resources[13].resource_type, ResourceType::CloudWatchAlarm);
+        assert_eq!(resources[13].resource_id, "api-high-cpu");
+        assert_eq!(resources[14].resource_type, ResourceType::CloudTrail);
+        assert_eq!(
+            resources[14].resource_id,
+            "arn:aws:cloudtrail:ap-northeast-2:123456789012:trail/org-audit"
+        );
+        assert_eq!(resources[15].resource_type, ResourceType::Kms);
+        assert_eq!(
+            resources[15].resource_id,
+            "1234abcd-12ab-34cd-56ef-1234567890ab"
+        );
+        assert_eq!(resources[16].resource_type, ResourceType::Secret);
+        assert!(
+            resources[16]
+                .resource_id
+                .ends_with(":secret:prod/checkout/db-AbCdEf")
+        );
+        assert_eq!(resources[16].resource_name, "prod/checkout/db");
+        assert_eq!(resources[17].resource_type, ResourceType::Efs);
+        assert_eq!(resources[17].resource_id, "fs-0123456789abcdef0");
+        assert_eq!(resources[17].resource_name, "shared-assets");
+        assert_eq!(resources[18].resource_type, ResourceType::ApiGateway);
+        assert_eq!(resources[18].resource_id, "f6g7h8i9j0");
+        assert_eq!(resources[18].resource_name, "orders-http");
+        assert_eq!(resources[19].resource_type, ResourceType::ElasticBeanstalk);
+        assert_eq!(resources[19].resource_id, "e-abc123xyz0");
+        assert_eq!(resources[19].resource_name, "orders-prod");
+        assert_eq!(resources[20].resource_type, ResourceType::StepFunctions);
+        assert_eq!(
+            resources[20].resource_id,
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:order-flow"
+        );
+        assert_eq!(resources[20].resource_name, "order-flow");
+        assert_eq!(resources[21].resource_type, ResourceType::Kinesis);
+        assert_eq!(resources[21].resource_id, "clickstream");
+        assert_eq!(resources[21].resource_name, "clickstream");
+        assert_eq!(resources[22].resource_type, ResourceType::Msk);
+        assert_eq!(
+            resources[22].resource_id,
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/events/abcd-1"
+        );
+        assert_eq!(resources[22].resource_name, "events");
+        assert_eq!(resources[23].resource_type, ResourceType::OpenSearch);
+        assert_eq!(resources[23].resource_id, "logs");
+        assert_eq!(resources[23].resource_name, "logs");
+        assert_eq!(resources[24].resource_type, ResourceType::Redshift);
+        assert_eq!(resources[24].resource_id, "analytics");
+        assert_eq!(resources[24].resource_name, "analytics");
+        assert_eq!(resources[25].resource_type, ResourceType::TransitGateway);
+        assert_eq!(resources[25].resource_id, "tgw-0123");
+        assert_eq!(resources[25].resource_name, "core-tgw");
+        assert_eq!(resources[26].resource_type, ResourceType::VpcPeering);
+        assert_eq!(resources[26].resource_id, "pcx-0123");
+        assert_eq!(resources[26].resource_name, "app-to-shared");
+        assert_eq!(resources[27].resource_type, ResourceType::Vpn);
+        assert_eq!(resources[27].resource_id, "vpn-0123");
+        assert_eq!(resources[27].resource_name, "office");
+        assert_eq!(resources[28].resource_type, ResourceType::DirectConnect);
+        assert_eq!(resources[28].resource_id, "dxvif-ffgh1234");
+        assert_eq!(resources[28].resource_name, "onprem-private");
+        assert_eq!(resources[29].resource_type, ResourceType::Waf);
+        assert!(
+            resources[29]
+                .resource_id
+                .ends_with("regional/webacl/edge-acl/a1b2c3d4-5678-90ab-cdef-EXAMPLE11111")
+        );
+        assert_eq!(resources[29].resource_name, "edge-acl");
+        assert_eq!(resources[30].resource_type, ResourceType::Acm);
+        assert!(resources[30].resource_id.starts_with("arn:aws:acm:"));
+        assert_eq!(resources[30].resource_name, "api.example.com");
+        assert_eq!(resources[31].resource_type, ResourceType::CloudFormation);
+        assert_eq!(resources[31].resource_id, "legacy-network");
+        assert_eq!(resources[32].resource_type, ResourceType::Iam);
+        assert_eq!(
+            resources[32].resource_id,
+            "arn:aws:iam::123456789012:role/app-role"
+        );
+        assert_eq!(resources[32].region, "ap-northeast-2");
+        assert_eq!(resources[33].resource_type, ResourceType::VpcEndpoint);
+        assert_eq!(resources[33].resource_id, "vpce-0a1b2c3d");
+        assert_eq!(resources[33].resource_name, "s3-gateway");
+        assert_eq!(resources[34].resource_type, ResourceType::NetworkInterface);
+        assert_eq!(resources[34].resource_id, "eni-0a1b2c3d");
+        assert_eq!(resources[34].resource_name, "legacy-db");
+        assert_eq!(resources[35].resource_type, ResourceType::LaunchTemplate);
+        assert_eq!(resources[35].resource_id, "lt-0a1b2c3d");
+        assert_eq!(resources[35].resource_name, "web");
+        assert_eq!(resources[36].resource_type, ResourceType::Ami);
+        assert_eq!(resources[36].resource_id, "ami-0a1b2c3d");
+        assert_eq!(resources[36].resource_name, "web-2026-10");
+        assert_eq!(resources[37].resource_type, ResourceType::Ebs);
+        assert_eq!(resources[37].resource_id, "vol-0a1b2c3d");
+        assert_eq!(resources[37].resource_name, "data");
+        assert_eq!(resources[38].resource_type, ResourceType::KeyPair);
+        assert_eq!(resources[38].resource_id, "key-0a1b2c3d4e5f60718");
+        assert_eq!(resources[38].resource_name, "deploy");
+        assert_eq!(resources[39].resource_type, ResourceType::EventBridge);
+        assert_eq!(resources[39].resource_id, "orders");
+        assert_eq!(resources[39].resource_name, "orders");
+        assert_eq!(resources[40].resource_type, ResourceType::Cognito);
+        assert_eq!(resources[40].resource_id, "ap-northeast-2_AbCdEf123");
+        assert_eq!(resources[40].resource_name, "customers");
+        assert_eq!(resources[41].resource_type, ResourceType::Glue);
+        assert_eq!(resources[41].resource_id, "database/sales");
+        assert_eq!(resources[41].resource_name, "sales");
+        assert_eq!(resources[42].resource_type, ResourceType::Athena);
+        assert_eq!(resources[42].resource_id, "analytics");
+        assert_eq!(resources[42].resource_name, "analytics");
+        assert_eq!(resources[43].resource_type, ResourceType::Backup);
+        assert_eq!(
+            resources[43].resource_id,
+            "8a2f1c4e-1111-2222-3333-444455556666"
+        );
+        assert_eq!(resources[43].resource_name, "daily-35d");
+    }
+
+    #[test]
+    fn parse_state_rejects_invalid_json() {
+        assert!(parse_state("not json", "ap-northeast-2").is_err());
+    }
+
+    #[test]
+    fn import_blueprint_names_blueprint_after_state_directory() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let dir =
+            std::env::temp_dir().join(format!("emd-tf-checkout-{}-{}", std::process::id(), nanos));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("terraform.tfstate");
+        fs::write(
+            &path,
+            r#"{"resources":[{"mode":"managed","type":"aws_security_group","name":"web","instances":[{"attributes":{"id":"sg-1","name":"web-sg"}}]}]}"#,
+        )
+        .expect("write state");
+
+        let blueprint =
+            import_blueprint(path.to_str().unwrap_or_default(), "ap-northeast-2").expect("import");
+        assert!(blueprint.name.starts_with("emd-tf-checkout-"));
+        assert_eq!(blueprint.resources.len(), 1);
+        assert_eq!(blueprint.resources[0].resource_name, "web-sg");
+
+        assert!(import_blueprint(dir.join("missing").to_str().unwrap_or_default(), "x").is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
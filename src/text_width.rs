@@ -0,0 +1,218 @@
+// 터미널 표시 폭 기준 문자열 배치: 한글/CJK와 이모지는 두 칸을 차지하므로 글자 수로 자르거나 맞추면 열이 어긋남
+use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const ELLIPSIS: char = '…';
+
+/// Number of terminal columns `text` occupies.
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Cut `text` to at most `max_width` columns, ending with `…` when it was shortened.
+pub fn truncate_to_width(text: &str, max_width: usize) -> Cow<'_, str> {
+    if display_width(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+    if max_width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // 말줄임표 한 칸을 남겨 두고, 두 칸 문자가 경계에 걸리면 그 문자는 넣지 않음
+    let mut truncated = String::new();
+    let mut width = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if width + ch_width > max_width - 1 {
+            break;
+        }
+        truncated.push(ch);
+        width += ch_width;
+    }
+    truncated.push(ELLIPSIS);
+    Cow::Owned(truncated)
+}
+
+/// Truncate or pad `text` with spaces to exactly `width` columns.
+pub fn fit_to_width(text: &str, width: usize) -> String {
+    let mut fitted = truncate_to_width(text, width).into_owned();
+    let padding = width.saturating_sub(display_width(&fitted));
+    fitted.extend(std::iter::repeat_n(' ', padding));
+    fitted
+}
+
+/// Width of the name column in a resource list: the longest name, at most half of
+/// the list's inner width so the state and details after it stay visible.
+pub fn name_column_width<'a>(names: impl IntoIterator<Item = &'a str>, area_width: u16) -> usize {
+    // 테두리 두 칸과 선택 표시("▶ ") 두 칸을 뺀 폭의 절반
+    let limit = (usize::from(area_width).saturating_sub(4) / 2).max(1);
+    names
+        .into_iter()
+        .map(display_width)
+        .max()
+        .unwrap_or(0)
+        .min(limit)
+}
+
+// "| a | b |" 형태의 줄만 표로 봄 (코드 블록 안의 줄도 같은 형태면 함께 맞춰짐)
+fn is_table_row(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 2 && line.starts_with('|') && line.ends_with('|')
+}
+
+// 셀 안의 이스케이프된 \| 는 구분자로 보지 않음
+fn split_cells(line: &str) -> Vec<&str> {
+    let inner = &line.trim()[1..line.trim().len() - 1];
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, ch) in inner.char_indices() {
+        match ch {
+            '\\' => escaped = !escaped,
+            '|' if !escaped => {
+                cells.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => escaped = false,
+        }
+    }
+    cells.push(inner[start..].trim());
+    cells
+}
+
+// |:---|, |---:|, |:---:| 구분 줄
+fn is_separator_cell(cell: &str) -> bool {
+    let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+    !dashes.is_empty() && dashes.chars().all(|ch| ch == '-')
+}
+
+fn separator_cell(cell: &str, width: usize) -> String {
+    let left = cell.starts_with(':');
+    let right = cell.len() > 1 && cell.ends_with(':');
+    let dashes = width.saturating_sub(usize::from(left) + usize::from(right));
+    format!(
+        "{}{}{}",
+        if left { ":" } else { "" },
+        "-".repeat(dashes),
+        if right { ":" } else { "" }
+    )
+}
+
+fn align_table(rows: &[&str], output: &mut Vec<String>) {
+    let rows: Vec<Vec<&str>> = rows.iter().map(|row| split_cells(row)).collect();
+    // 구분 줄은 머리글 바로 다음 줄만 인정 ("| - | - |" 같은 데이터 행과 구분)
+    let is_separator =
+        |row: usize| row == 1 && rows[row].iter().all(|cell| is_separator_cell(cell));
+
+    // 구분 줄은 폭 계산에서 빼되 최소 3칸(":--")은 확보
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut widths = vec![3; columns];
+    for (row, cells) in rows.iter().enumerate() {
+        if is_separator(row) {
+            continue;
+        }
+        for (column, cell) in cells.iter().enumerate() {
+            widths[column] = widths[column].max(display_width(cell));
+        }
+    }
+
+    for (row, cells) in rows.iter().enumerate() {
+        let separator = is_separator(row);
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| {
+                // 구분 줄은 데이터 행의 앞뒤 공백 두 칸까지 채움
+                if separator {
+                    separator_cell(cell, width + 2)
+                } else {
+                    fit_to_width(cell, width)
+                }
+            })
+            .collect();
+        let line = if separator {
+            format!("|{}|", padded.join("|"))
+        } else {
+            format!("| {} |", padded.join(" | "))
+        };
+        output.push(line);
+    }
+}
+
+/// Pad markdown table cells so every column lines up by display width.
+/// The number of lines is unchanged, so scroll offsets stay valid.
+pub fn align_markdown_tables(markdown: &str) -> Cow<'_, str> {
+    if !markdown.lines().any(is_table_row) {
+        return Cow::Borrowed(markdown);
+    }
+
+    let lines: Vec<&str> = markdown.split('\n').collect();
+    let mut output = Vec::with_capacity(lines.len());
+    let mut index = 0;
+    while index < lines.len() {
+        if !is_table_row(lines[index]) {
+            output.push(lines[index].to_string());
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < lines.len() && is_table_row(lines[index]) {
+            index += 1;
+        }
+        align_table(&lines[start..index], &mut output);
+    }
+    Cow::Owned(output.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        align_markdown_tables, display_width, fit_to_width, name_column_width, truncate_to_width,
+    };
+    use std::borrow::Cow;
+
+    #[test]
+    fn truncate_and_fit_count_hangul_as_two_columns() {
+        assert_eq!(display_width("웹 서버"), 7);
+        assert!(matches!(truncate_to_width("web-1", 5), Cow::Borrowed(_)));
+        assert_eq!(truncate_to_width("web-server-1", 6), "web-s…");
+        // 두 칸 문자가 경계에 걸리면 넣지 않음
+        assert_eq!(truncate_to_width("운영웹서버", 6), "운영…");
+        assert_eq!(truncate_to_width("운영웹서버", 0), "");
+        assert_eq!(fit_to_width("운영", 6), "운영  ");
+        assert_eq!(display_width(&fit_to_width("운영웹서버", 5)), 5);
+    }
+
+    #[test]
+    fn name_column_width_is_capped_at_half_of_the_list() {
+        let names = ["web-1", "운영-데이터베이스"];
+        assert_eq!(name_column_width(names, 80), 17);
+        assert_eq!(name_column_width(names, 24), 10);
+        assert_eq!(name_column_width(std::iter::empty(), 80), 0);
+    }
+
+    #[test]
+    fn align_markdown_tables_pads_cells_by_display_width() {
+        let markdown =
+            "## 제목\n\n| 항목 | 값 |\n|:---|:---|\n| 이름 | web-1 |\n| VPC | vpc-0123 |\n\n끝";
+        let aligned = align_markdown_tables(markdown);
+        assert_eq!(
+            aligned,
+            "## 제목\n\n| 항목 | 값       |\n|:-----|:---------|\n| 이름 | web-1    |\n| VPC  | vpc-0123 |\n\n끝"
+        );
+        assert_eq!(aligned.lines().count(), markdown.lines().count());
+
+        // 모든 행의 표시 폭이 같음
+        let widths: Vec<usize> = aligned.lines().skip(2).take(4).map(display_width).collect();
+        assert!(widths.windows(2).all(|pair| pair[0] == pair[1]));
+
+        assert!(matches!(
+            align_markdown_tables("no tables here"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            align_markdown_tables("| a \\| b | c |\n|---:|:-:|"),
+            "| a \\| b | c   |\n|-------:|:---:|"
+        );
+    }
+}
@@ -0,0 +1,376 @@
+// 색상 팔레트: 화면은 Color를 직접 쓰지 않고 Theme의 역할별 색상을 사용
+// 색각 이상용 팔레트는 Okabe-Ito 색상표 기반 (빨강/초록 대비에 의존하지 않음)
+use crate::blueprint::ResourceType;
+use crate::i18n::I18n;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Palette {
+    #[default]
+    Default,
+    HighContrast,
+    Deuteranopia,
+    Protanopia,
+    NoColor,
+}
+
+impl Palette {
+    const ALL: [Palette; 5] = [
+        Palette::Default,
+        Palette::HighContrast,
+        Palette::Deuteranopia,
+        Palette::Protanopia,
+        Palette::NoColor,
+    ];
+
+    /// Next palette in the settings cycle.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self, i18n: &I18n) -> &'static str {
+        match self {
+            Palette::Default => i18n.md_default(),
+            Palette::HighContrast => i18n.palette_high_contrast(),
+            Palette::Deuteranopia => i18n.palette_deuteranopia(),
+            Palette::Protanopia => i18n.palette_protanopia(),
+            Palette::NoColor => i18n.palette_no_color(),
+        }
+    }
+}
+
+/// Palette actually used: a non-empty `NO_COLOR` (https://no-color.org) overrides the setting.
+pub fn resolve_palette(configured: Palette) -> Palette {
+    palette_with_no_color(configured, std::env::var_os("NO_COLOR").as_deref())
+}
+
+fn palette_with_no_color(configured: Palette, no_color: Option<&OsStr>) -> Palette {
+    if no_color.is_some_and(|value| !value.is_empty()) {
+        Palette::NoColor
+    } else {
+        configured
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
+    // 안내 문구, 항목 레이블, 진행 중인 단계
+    pub accent: Color,
+    // 선택 항목, 강조 제목과 테두리
+    pub highlight: Color,
+    // 부가 정보, 이미 추가된 항목, 대기 중인 단계
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    // 설정 값처럼 강조해서 보여줄 본문
+    pub text: Color,
+    // 탭 바 (메인/설정) 배경과 글자색
+    tab_background: [Color; 2],
+    tab_text: Color,
+    // 리소스 분류별 색상: 컴퓨팅, 네트워크, 데이터, 메시징/스트리밍, 보안/운영
+    categories: Option<[Color; 5]>,
+}
+
+impl Theme {
+    pub fn new(palette: Palette) -> Self {
+        match palette {
+            Palette::Default => Self {
+                palette,
+                accent: Color::Cyan,
+                highlight: Color::Yellow,
+                muted: Color::DarkGray,
+                success: Color::Green,
+                warning: Color::Yellow,
+                error: Color::Red,
+                text: Color::White,
+                tab_background: [Color::Blue, Color::Magenta],
+                tab_text: Color::White,
+                // 기본 팔레트는 리소스 유형마다 고유 색상 사용
+                categories: None,
+            },
+            Palette::HighContrast => Self {
+                palette,
+                accent: Color::LightCyan,
+                highlight: Color::LightYellow,
+                muted: Color::Gray,
+                success: Color::LightGreen,
+                warning: Color::LightYellow,
+                error: Color::LightRed,
+                text: Color::White,
+                tab_background: [Color::White, Color::LightYellow],
+                tab_text: Color::Black,
+                categories: Some([
+                    Color::LightCyan,
+                    Color::LightGreen,
+                    Color::LightYellow,
+                    Color::LightMagenta,
+                    Color::White,
+                ]),
+            },
+            // 초록 대신 파랑, 빨강 대신 주황/주홍
+            Palette::Deuteranopia => Self {
+                palette,
+                accent: Color::Rgb(86, 180, 233),
+                highlight: Color::Rgb(240, 228, 66),
+                muted: Color::DarkGray,
+                success: Color::Rgb(0, 114, 178),
+                warning: Color::Rgb(230, 159, 0),
+                error: Color::Rgb(213, 94, 0),
+                text: Color::White,
+                tab_background: [Color::Rgb(0, 114, 178), Color::Rgb(204, 121, 167)],
+                tab_text: Color::White,
+                categories: Some([
+                    Color::Rgb(86, 180, 233),
+                    Color::Rgb(230, 159, 0),
+                    Color::Rgb(240, 228, 66),
+                    Color::Rgb(204, 121, 167),
+                    Color::Rgb(213, 94, 0),
+                ]),
+            },
+            // 제1색맹은 빨강이 어둡게 보이므로 주홍 대신 밝은 주황/노랑 사용
+            Palette::Protanopia => Self {
+                palette,
+                accent: Color::Rgb(86, 180, 233),
+                highlight: Color::Rgb(240, 228, 66),
+                muted: Color::DarkGray,
+                success: Color::Rgb(0, 114, 178),
+                warning: Color::Rgb(240, 228, 66),
+                error: Color::Rgb(230, 159, 0),
+                text: Color::White,
+                tab_background: [Color::Rgb(0, 114, 178), Color::Rgb(240, 228, 66)],
+                tab_text: Color::Black,
+                categories: Some([
+                    Color::Rgb(86, 180, 233),
+                    Color::Rgb(230, 159, 0),
+                    Color::Rgb(240, 228, 66),
+                    Color::Rgb(204, 121, 167),
+                    Color::Gray,
+                ]),
+            },
+            // 터미널 기본색만 사용 (선택 항목은 굵게, 탭은 반전으로 구분)
+            Palette::NoColor => Self {
+                palette,
+                accent: Color::Reset,
+                highlight: Color::Reset,
+                muted: Color::Reset,
+                success: Color::Reset,
+                warning: Color::Reset,
+                error: Color::Reset,
+                text: Color::Reset,
+                tab_background: [Color::Reset, Color::Reset],
+                tab_text: Color::Reset,
+                categories: Some([Color::Reset; 5]),
+            },
+        }
+    }
+
+    /// Bold highlight used for the selected row and emphasized headings.
+    pub fn emphasis(&self) -> Style {
+        Style::default()
+            .fg(self.highlight)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Style of the tab at `index` in the tab bar.
+    pub fn tab(&self, index: usize, selected: bool) -> Style {
+        if !selected {
+            Style::default().fg(self.muted)
+        } else if self.palette == Palette::NoColor {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default()
+                .fg(self.tab_text)
+                .bg(self.tab_background[index.min(1)])
+        }
+    }
+
+    pub fn resource_color(&self, resource_type: &ResourceType) -> Color {
+        match self.categories {
+            Some(categories) => categories[resource_category(resource_type)],
+            None => default_resource_color(resource_type),
+        }
+    }
+}
+
+fn resource_category(resource_type: &ResourceType) -> usize {
+    match resource_type {
+        ResourceType::Ec2
+        | ResourceType::Asg
+        | ResourceType::Lambda
+        | ResourceType::Eks
+        | ResourceType::Ecs
+        | ResourceType::Ecr
+        | ResourceType::ElasticBeanstalk
+        | ResourceType::LaunchTemplate
+        | ResourceType::Ami => 0,
+        ResourceType::Network
+        | ResourceType::SecurityGroup
+        | ResourceType::LoadBalancer
+        | ResourceType::CloudFront
+        | ResourceType::Route53
+        | ResourceType::ApiGateway
+        | ResourceType::TransitGateway
+        | ResourceType::VpcPeering
+        | ResourceType::Vpn
+        | ResourceType::DirectConnect
+        | ResourceType::VpcEndpoint
+        | ResourceType::NetworkInterface => 1,
+        ResourceType::Rds
+        | ResourceType::DynamoDb
+        | ResourceType::ElastiCache
+        | ResourceType::Efs
+        | ResourceType::OpenSearch
+        | ResourceType::Redshift
+        | ResourceType::Ebs
+        | ResourceType::Glue
+        | ResourceType::Athena
+        | ResourceType::Backup => 2,
+        ResourceType::Sqs
+        | ResourceType::Sns
+        | ResourceType::StepFunctions
+        | ResourceType::Kinesis
+        | ResourceType::Msk
+        | ResourceType::EventBridge => 3,
+        ResourceType::CloudWatchAlarm
+        | ResourceType::CloudTrail
+        | ResourceType::Kms
+        | ResourceType::Secret
+        | ResourceType::SsmParameter
+        | ResourceType::Waf
+        | ResourceType::Acm
+        | ResourceType::CloudFormation
+        | ResourceType::Iam
+        | ResourceType::KeyPair
+        | ResourceType::Cognito => 4,
+    }
+}
+
+fn default_resource_color(resource_type: &ResourceType) -> Color {
+    match resource_type {
+        ResourceType::Ec2 => Color::Cyan,
+        ResourceType::Network => Color::Green,
+        ResourceType::SecurityGroup => Color::Magenta,
+        ResourceType::LoadBalancer => Color::Blue,
+        ResourceType::Ecr => Color::LightRed,
+        ResourceType::Asg => Color::LightCyan,
+        ResourceType::Rds => Color::LightBlue,
+        ResourceType::Lambda => Color::LightYellow,
+        ResourceType::DynamoDb => Color::LightMagenta,
+        ResourceType::CloudFront => Color::LightGreen,
+        ResourceType::Route53 => Color::Yellow,
+        ResourceType::Eks => Color::Red,
+        ResourceType::Backup => Color::Rgb(122, 161, 22),
+        ResourceType::Athena => Color::Rgb(140, 79, 255),
+        ResourceType::Glue => Color::Rgb(140, 79, 255),
+        ResourceType::Cognito => Color::Rgb(221, 52, 76),
+        ResourceType::EventBridge => Color::Rgb(214, 36, 132),
+        ResourceType::KeyPair => Color::Rgb(190, 160, 60),
+        ResourceType::Ebs => Color::Rgb(210, 120, 50),
+        ResourceType::Ami => Color::Rgb(230, 150, 70),
+        ResourceType::LaunchTemplate => Color::Rgb(255, 170, 60),
+        ResourceType::NetworkInterface => Color::Rgb(95, 175, 175),
+        ResourceType::VpcEndpoint => Color::Rgb(120, 185, 95),
+        ResourceType::Iam => Color::Rgb(221, 52, 76),
+        ResourceType::CloudFormation => Color::Rgb(230, 160, 80),
+        ResourceType::Acm => Color::Rgb(110, 190, 160),
+        ResourceType::Waf => Color::Rgb(230, 110, 60),
+        ResourceType::DirectConnect => Color::Rgb(90, 140, 220),
+        ResourceType::Vpn => Color::Rgb(200, 160, 60),
+        ResourceType::VpcPeering => Color::Rgb(140, 200, 75),
+        ResourceType::TransitGateway => Color::Rgb(56, 190, 150),
+        ResourceType::Redshift => Color::Rgb(140, 79, 255),
+        ResourceType::OpenSearch => Color::Rgb(0, 169, 224),
+        ResourceType::Msk => Color::Rgb(78, 140, 255),
+        ResourceType::Kinesis => Color::Rgb(140, 79, 255),
+        ResourceType::StepFunctions => Color::Rgb(231, 21, 123),
+        ResourceType::ElasticBeanstalk => Color::Rgb(108, 180, 60),
+        ResourceType::ApiGateway => Color::Rgb(160, 92, 235),
+        ResourceType::Efs => Color::Rgb(122, 161, 22),
+        ResourceType::SsmParameter => Color::Rgb(100, 160, 230),
+        ResourceType::Secret => Color::Rgb(221, 52, 151),
+        ResourceType::Kms => Color::Rgb(221, 52, 76),
+        ResourceType::CloudTrail => Color::Rgb(120, 200, 80),
+        ResourceType::CloudWatchAlarm => Color::Rgb(230, 80, 60),
+        ResourceType::ElastiCache => Color::Rgb(0, 164, 166),
+        ResourceType::Sns => Color::Rgb(180, 110, 255),
+        ResourceType::Sqs => Color::Rgb(255, 79, 139),
+        ResourceType::Ecs => Color::Rgb(255, 153, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Palette, Theme, palette_with_no_color};
+    use crate::blueprint::ResourceType;
+    use ratatui::style::{Color, Modifier};
+    use std::ffi::OsStr;
+
+    #[test]
+    fn no_color_env_overrides_palette_only_when_non_empty() {
+        let set = Some(OsStr::new("1"));
+        let empty = Some(OsStr::new(""));
+        assert_eq!(
+            palette_with_no_color(Palette::Deuteranopia, set),
+            Palette::NoColor
+        );
+        assert_eq!(
+            palette_with_no_color(Palette::Deuteranopia, empty),
+            Palette::Deuteranopia
+        );
+        assert_eq!(
+            palette_with_no_color(Palette::HighContrast, None),
+            Palette::HighContrast
+        );
+    }
+
+    #[test]
+    fn palette_cycles_through_all_and_serializes_in_snake_case() {
+        let mut palette = Palette::default();
+        let mut seen = vec![palette];
+        for _ in 0..4 {
+            palette = palette.next();
+            assert!(!seen.contains(&palette));
+            seen.push(palette);
+        }
+        assert_eq!(palette.next(), Palette::Default);
+
+        let json = serde_json::to_string(&Palette::HighContrast).expect("serialize");
+        assert_eq!(json, r#""high_contrast""#);
+        let parsed: Palette = serde_json::from_str(r#""no_color""#).expect("parse");
+        assert_eq!(parsed, Palette::NoColor);
+    }
+
+    #[test]
+    fn colorblind_palettes_avoid_red_green_status_and_no_color_resets() {
+        for palette in [Palette::Deuteranopia, Palette::Protanopia] {
+            let theme = Theme::new(palette);
+            for color in [theme.success, theme.error] {
+                assert!(!matches!(
+                    color,
+                    Color::Red | Color::Green | Color::LightRed | Color::LightGreen
+                ));
+            }
+            assert_ne!(theme.success, theme.error);
+            assert_ne!(
+                theme.resource_color(&ResourceType::Ec2),
+                theme.resource_color(&ResourceType::Rds)
+            );
+        }
+
+        let theme = Theme::new(Palette::NoColor);
+        assert_eq!(theme.emphasis().fg, Some(Color::Reset));
+        assert!(theme.emphasis().add_modifier.contains(Modifier::BOLD));
+        assert!(theme.tab(0, true).add_modifier.contains(Modifier::REVERSED));
+        assert_eq!(theme.resource_color(&ResourceType::Msk), Color::Reset);
+        assert_eq!(
+            Theme::new(Palette::Default).resource_color(&ResourceType::Ec2),
+            Color::Cyan
+        );
+    }
+}
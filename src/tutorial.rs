@@ -0,0 +1,177 @@
+// 튜토리얼 모드: 실제 화면 위에 단계별 안내를 띄우고, 사용자가 다음 화면에 도달하면 진행
+use crate::app::Screen;
+use crate::i18n::I18n;
+use crossterm::event::KeyCode;
+
+// 어느 화면에서든 튜토리얼 시작/종료
+pub const TOGGLE_KEY: KeyCode = KeyCode::F(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    Login,
+    Region,
+    Service,
+    Preview,
+    Blueprint,
+}
+
+const STEPS: [TutorialStep; 5] = [
+    TutorialStep::Login,
+    TutorialStep::Region,
+    TutorialStep::Service,
+    TutorialStep::Preview,
+    TutorialStep::Blueprint,
+];
+
+impl TutorialStep {
+    fn next(self) -> Option<Self> {
+        let index = STEPS.iter().position(|step| *step == self)?;
+        STEPS.get(index + 1).copied()
+    }
+
+    // 이 화면에 도달하면 단계 완료
+    fn is_reached(self, screen: &Screen) -> bool {
+        match self {
+            TutorialStep::Login => *screen != Screen::Login,
+            TutorialStep::Region => *screen == Screen::ServiceSelect,
+            TutorialStep::Service => *screen == Screen::Preview,
+            TutorialStep::Preview => matches!(
+                screen,
+                Screen::BlueprintSelect | Screen::BlueprintNameInput | Screen::BlueprintDetail
+            ),
+            TutorialStep::Blueprint => *screen == Screen::BlueprintPreview,
+        }
+    }
+
+    fn title(self, i18n: &I18n) -> &'static str {
+        match self {
+            TutorialStep::Login => i18n.tutorial_login(),
+            TutorialStep::Region => i18n.tutorial_region(),
+            TutorialStep::Service => i18n.tutorial_service(),
+            TutorialStep::Preview => i18n.tutorial_preview(),
+            TutorialStep::Blueprint => i18n.tutorial_blueprint(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tutorial {
+    pub step: TutorialStep,
+}
+
+impl Tutorial {
+    pub fn new() -> Self {
+        Self {
+            step: TutorialStep::Login,
+        }
+    }
+
+    /// Advance past every step whose goal screen is `screen`; returns true once the last step is done.
+    pub fn observe(&mut self, screen: &Screen) -> bool {
+        while self.step.is_reached(screen) {
+            match self.step.next() {
+                Some(next) => self.step = next,
+                None => return true,
+            }
+        }
+        false
+    }
+
+    /// 1-based step number and step count, e.g. `(2, 5)`.
+    pub fn position(&self) -> (usize, usize) {
+        let index = STEPS
+            .iter()
+            .position(|step| *step == self.step)
+            .unwrap_or(0);
+        (index + 1, STEPS.len())
+    }
+
+    pub fn title(&self, i18n: &I18n) -> &'static str {
+        self.step.title(i18n)
+    }
+
+    /// Hint for the current step on the screen the user is looking at.
+    pub fn hint(&self, screen: &Screen, on_resource_list: bool, i18n: &I18n) -> &'static str {
+        match (self.step, screen) {
+            (TutorialStep::Login, _) => i18n.tutorial_login_hint(),
+            (TutorialStep::Region, Screen::BlueprintSelect) => i18n.tutorial_single_mode_hint(),
+            (TutorialStep::Region, _) => i18n.tutorial_region_hint(),
+            (TutorialStep::Service, _) if on_resource_list => i18n.tutorial_resource_hint(),
+            (TutorialStep::Service, _) => i18n.tutorial_service_hint(),
+            (TutorialStep::Preview, _) => i18n.tutorial_preview_hint(),
+            (TutorialStep::Blueprint, Screen::BlueprintSelect) => {
+                i18n.tutorial_new_blueprint_hint()
+            }
+            (TutorialStep::Blueprint, Screen::BlueprintNameInput) => {
+                i18n.tutorial_blueprint_name_hint()
+            }
+            (TutorialStep::Blueprint, Screen::BlueprintDetail) => {
+                i18n.tutorial_blueprint_detail_hint()
+            }
+            // 블루프린트 모드로 리전/서비스/리소스를 고르는 중
+            (TutorialStep::Blueprint, _) => i18n.tutorial_add_resource_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tutorial, TutorialStep};
+    use crate::app::Screen;
+    use crate::i18n::{I18n, Language};
+
+    #[test]
+    fn observe_follows_login_region_service_preview_blueprint() {
+        let mut tutorial = Tutorial::new();
+        assert!(!tutorial.observe(&Screen::Login));
+        assert_eq!(tutorial.step, TutorialStep::Login);
+
+        // 로그인 후 블루프린트 목록 → 단일 모드로 리전 선택
+        assert!(!tutorial.observe(&Screen::BlueprintSelect));
+        assert_eq!(tutorial.step, TutorialStep::Region);
+        assert!(!tutorial.observe(&Screen::RegionSelect));
+        assert_eq!(tutorial.step, TutorialStep::Region);
+        assert!(!tutorial.observe(&Screen::ServiceSelect));
+        assert_eq!(tutorial.step, TutorialStep::Service);
+        assert!(!tutorial.observe(&Screen::Ec2Select));
+        assert!(!tutorial.observe(&Screen::Preview));
+        assert_eq!(tutorial.step, TutorialStep::Preview);
+        assert_eq!(tutorial.position(), (4, 5));
+
+        // 뒤로 가는 중간 화면에서는 진행하지 않음
+        assert!(!tutorial.observe(&Screen::ServiceSelect));
+        assert_eq!(tutorial.step, TutorialStep::Preview);
+        assert!(!tutorial.observe(&Screen::BlueprintSelect));
+        assert_eq!(tutorial.step, TutorialStep::Blueprint);
+        assert!(!tutorial.observe(&Screen::BlueprintDetail));
+        assert!(tutorial.observe(&Screen::BlueprintPreview));
+    }
+
+    #[test]
+    fn hint_depends_on_current_screen() {
+        let i18n = I18n::new(Language::English);
+        let mut tutorial = Tutorial::new();
+        tutorial.step = TutorialStep::Region;
+        assert!(
+            tutorial
+                .hint(&Screen::BlueprintSelect, false, &i18n)
+                .contains("press s")
+        );
+        assert_eq!(
+            tutorial.hint(&Screen::RegionSelect, false, &i18n),
+            i18n.tutorial_region_hint()
+        );
+
+        tutorial.step = TutorialStep::Service;
+        assert_eq!(
+            tutorial.hint(&Screen::Ec2Select, true, &i18n),
+            i18n.tutorial_resource_hint()
+        );
+
+        tutorial.step = TutorialStep::Blueprint;
+        assert_eq!(
+            tutorial.hint(&Screen::Preview, false, &i18n),
+            i18n.tutorial_add_resource_hint()
+        );
+    }
+}
@@ -1,13 +1,18 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs, Wrap},
 };
 
-use crate::app::{App, LoadingTask, REGIONS, SERVICE_KEYS, Screen};
+use crate::app::{App, BlueprintImportKind, LoadingTask, SERVICE_KEYS, Screen};
+use crate::ascii::{self, AsciiMode};
 use crate::aws_cli::AwsAuthErrorCode;
+use crate::query_playground::QUERY_OPERATIONS;
+use crate::settings::next_concurrency_limit;
+use crate::text_width::{align_markdown_tables, fit_to_width, name_column_width};
+use crate::theme::Theme;
 
 const EMD_LOGO: &str = r#"
   ______ __  __ _____  
@@ -17,7 +22,7 @@ const EMD_LOGO: &str = r#"
  | |____| |  | | |__| |
  |______|_|  |_|_____/  AWS Markdown Template Generator 
 "#;
-use crate::blueprint::ResourceType;
+use crate::blueprint::{ResourceStatus, ResourceType};
 
 pub fn draw(frame: &mut Frame, app: &App) {
     let chunks = Layout::default()
@@ -31,26 +36,68 @@ pub fn draw(frame: &mut Frame, app: &App) {
         ])
         .split(frame.area());
 
-    draw_header(frame, chunks[0]);
+    draw_header(frame, app, chunks[0]);
     draw_tabs(frame, app, chunks[1]);
     draw_main(frame, app, chunks[2]);
     draw_footer(frame, app, chunks[3]);
+    draw_tutorial(frame, app, chunks[2]);
+
+    // 화면마다 따로 처리하지 않도록 다 그린 뒤 버퍼 전체를 변환
+    if app.ascii {
+        ascii::fold_buffer(frame.buffer_mut());
+    }
+}
+
+// 튜토리얼 안내: 본문 오른쪽 아래에 실제 화면을 가리지 않을 만큼 작게 표시
+fn draw_tutorial(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(tutorial) = &app.tutorial else {
+        return;
+    };
+    let i = &app.i18n;
+    let (step, total) = tutorial.position();
+    let progress: String = (1..=total)
+        .map(|n| if n <= step { '●' } else { '○' })
+        .collect();
+
+    let width = area.width.saturating_sub(2).min(60);
+    let height = area.height.min(7);
+    let overlay = Rect {
+        x: area.x + area.width.saturating_sub(width + 1),
+        y: area.y + area.height.saturating_sub(height),
+        width,
+        height,
+    };
+
+    let text = vec![
+        Line::from(tutorial.hint(&app.screen, app.current_resource_list().is_some(), i)),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("{}  F1: {}", progress, i.exit()),
+            Style::default().fg(app.theme.muted),
+        )),
+    ];
+    let paragraph = Paragraph::new(text).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.highlight))
+            .title(format!(
+                " {} {}/{} · {} ",
+                i.tutorial(),
+                step,
+                total,
+                tutorial.title(i)
+            )),
+    );
+    frame.render_widget(Clear, overlay);
+    frame.render_widget(paragraph, overlay);
 }
 
 fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
 
     let tab_titles = vec![
-        Line::from(format!("  {}  ", i.main_tab())).style(if app.selected_tab == 0 {
-            Style::default().fg(Color::White).bg(Color::Blue)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        }),
-        Line::from(format!("  {}  ", i.settings())).style(if app.selected_tab == 1 {
-            Style::default().fg(Color::White).bg(Color::Magenta)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        }),
+        Line::from(format!("  {}  ", i.main_tab())).style(app.theme.tab(0, app.selected_tab == 0)),
+        Line::from(format!("  {}  ", i.settings())).style(app.theme.tab(1, app.selected_tab == 1)),
     ];
 
     let tabs = Tabs::new(tab_titles)
@@ -61,14 +108,14 @@ fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(tabs, area);
 }
 
-fn draw_header(frame: &mut Frame, area: Rect) {
+fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
     let mut header_content = Vec::new();
     for line in EMD_LOGO.lines() {
         if !line.trim().is_empty() {
             header_content.push(Line::from(Span::styled(
                 line,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(app.theme.accent)
                     .add_modifier(Modifier::BOLD),
             )));
         }
@@ -82,38 +129,71 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
     let help = match &app.screen {
         Screen::Login => format!(
-            "↑↓/jk: {} | Enter: {} | r: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | r: {} | F1: {} | q: {}",
             i.move_cursor(),
             i.select(),
             i.refresh(),
+            i.tutorial(),
             i.exit()
         ),
         Screen::BlueprintSelect => format!(
-            "↑↓/jk: {} | Enter: {} | g: {} | d: {} | s: {} | ►: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | g: {} | p: {} | d: {} | s: {} | t: {} | c: {} | f: {} | r: {} | /: {} | ►: {} | q: {}",
             i.move_cursor(),
             i.select(),
             i.markdown_generate(),
+            i.promotion(),
             i.delete(),
             i.single_mode(),
+            i.import_terraform(),
+            i.import_cloudformation(),
+            i.import_tag_query(),
+            i.sync(),
+            i.search_documents(),
             i.settings(),
             i.exit()
         ),
         Screen::BlueprintDetail => format!(
-            "↑↓/jk: {} | Shift+↑↓/JK: {} | a: {} | d: {} | g/Enter: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | Shift+↑↓/JK: {} | a: {} | d: {} | g/Enter: {} | v: {} | m: {} | x: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.reorder(),
             i.add(),
             i.delete(),
             i.generate(),
+            i.validate(),
+            i.remap(),
+            i.remove_missing(),
+            i.back(),
+            i.exit()
+        ),
+        Screen::BlueprintNameInput | Screen::BlueprintImportInput | Screen::NameTagInput => {
+            format!("Enter: {} | Esc: {}", i.confirm(), i.cancel())
+        }
+        Screen::NameTagConfirm => format!("y: {} | n/Esc: {}", i.confirm(), i.cancel()),
+        Screen::DocumentSearch => format!(
+            "Enter: {} | ↑↓: {} | Esc: {}",
+            i.search_documents(),
+            i.move_cursor(),
+            i.back()
+        ),
+        Screen::Compare => format!(
+            "↑↓/jk: {} | PgUp/PgDn: {} | t: {} | Enter/s: {} | Esc: {} | q: {}",
+            i.scroll(),
+            i.page(),
+            i.compare_toggle_view(),
+            i.save(),
             i.back(),
             i.exit()
         ),
-        Screen::BlueprintNameInput => format!("Enter: {} | Esc: {}", i.confirm(), i.cancel()),
         Screen::BlueprintPreview => format!(
-            "↑↓/jk: {} | PgUp/PgDn: {} | Home/End | Enter/s: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | PgUp/PgDn: {} | Home/End | {} | Enter/s: {} | f: {} | n: {} | b: {} | i: {} | Esc: {} | q: {}",
             i.scroll(),
             i.page(),
+            preview_wrap_hint(app),
             i.save(),
+            i.save_per_resource(),
+            i.export_notion(),
+            i.export_backstage(),
+            i.export_inventory(),
             i.back(),
             i.exit()
         ),
@@ -125,43 +205,130 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
             i.exit()
         ),
         Screen::ServiceSelect => format!(
-            "↑↓/jk: {} | Enter: {} | ►: {} | Esc: {} | q: {}",
+            "↑↓/jk: {} | Enter: {} | f: {} | p: {} | d: {} | ►: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.select(),
+            i.pin_service(),
+            i.query_playground(),
+            i.document_region(),
             i.settings(),
             i.back(),
             i.exit()
         ),
+        Screen::Ec2Select | Screen::VpcSelect | Screen::SecurityGroupSelect if app.allow_write => {
+            format!(
+                "↑↓/jk: {} | Enter: {} | r: {} | c: {} | N: {} | Esc: {} | q: {}",
+                i.move_cursor(),
+                i.select(),
+                i.refresh(),
+                i.compare(),
+                i.edit_name_tag(),
+                i.back(),
+                i.exit()
+            )
+        }
         Screen::Ec2Select
         | Screen::VpcSelect
         | Screen::SecurityGroupSelect
         | Screen::LoadBalancerSelect
         | Screen::EcrSelect
-        | Screen::AsgSelect => format!(
-            "↑↓/jk: {} | Enter: {} | r: {} | Esc: {} | q: {}",
+        | Screen::AsgSelect
+        | Screen::RdsSelect
+        | Screen::LambdaSelect
+        | Screen::DynamoDbSelect
+        | Screen::CloudFrontSelect
+        | Screen::Route53Select
+        | Screen::EksSelect
+        | Screen::EcsSelect
+        | Screen::SqsSelect
+        | Screen::SnsSelect
+        | Screen::ElastiCacheSelect
+        | Screen::CloudWatchAlarmSelect
+        | Screen::CloudTrailSelect
+        | Screen::KmsSelect
+        | Screen::SecretSelect
+        | Screen::SsmParameterSelect
+        | Screen::EfsSelect
+        | Screen::ApiGatewaySelect
+        | Screen::ElasticBeanstalkSelect
+        | Screen::StepFunctionsSelect
+        | Screen::KinesisSelect
+        | Screen::MskSelect
+        | Screen::OpenSearchSelect
+        | Screen::RedshiftSelect
+        | Screen::TransitGatewaySelect
+        | Screen::VpcPeeringSelect
+        | Screen::VpnSelect
+        | Screen::DirectConnectSelect
+        | Screen::WafSelect
+        | Screen::AcmSelect
+        | Screen::CloudFormationSelect
+        | Screen::IamSelect
+        | Screen::VpcEndpointSelect
+        | Screen::NetworkInterfaceSelect
+        | Screen::LaunchTemplateSelect
+        | Screen::AmiSelect
+        | Screen::EbsSelect
+        | Screen::KeyPairSelect
+        | Screen::EventBridgeSelect
+        | Screen::CognitoSelect
+        | Screen::GlueSelect
+        | Screen::AthenaSelect
+        | Screen::BackupSelect => format!(
+            "↑↓/jk: {} | Enter: {} | r: {} | c: {} | Esc: {} | q: {}",
             i.move_cursor(),
             i.select(),
             i.refresh(),
+            i.compare(),
             i.back(),
             i.exit()
         ),
+        Screen::QueryPlayground => {
+            if app.query_output.is_none() {
+                format!(
+                    "↑↓/jk: {} | Enter: {} | Esc: {} | q: {}",
+                    i.move_cursor(),
+                    i.run_operation(),
+                    i.back(),
+                    i.exit()
+                )
+            } else {
+                format!(
+                    "↑↓: {} | PgUp/PgDn: {} | Enter: {} | Esc: {}",
+                    i.scroll(),
+                    i.page(),
+                    i.save(),
+                    i.back()
+                )
+            }
+        }
         Screen::Preview => {
             if app.blueprint_mode {
                 format!(
-                    "↑↓/jk: {} | Enter/s: {} | a: {} | r: {} | Esc: {} | q: {}",
+                    "↑↓/jk: {} | {} | Enter/s: {} | a: {} | r: {} | v: {} | n: {} | b: {} | i: {} | Esc: {} | q: {}",
                     i.scroll(),
+                    preview_wrap_hint(app),
                     i.save(),
                     i.add_to_blueprint(),
                     i.refresh(),
+                    i.raw_json(),
+                    i.export_notion(),
+                    i.export_backstage(),
+                    i.export_inventory(),
                     i.back(),
                     i.exit()
                 )
             } else {
                 format!(
-                    "↑↓/jk: {} | Enter/s: {} | r: {} | Esc: {} | q: {}",
+                    "↑↓/jk: {} | {} | Enter/s: {} | r: {} | v: {} | n: {} | b: {} | i: {} | Esc: {} | q: {}",
                     i.scroll(),
+                    preview_wrap_hint(app),
                     i.save(),
                     i.refresh(),
+                    i.raw_json(),
+                    i.export_notion(),
+                    i.export_backstage(),
+                    i.export_inventory(),
                     i.back(),
                     i.exit()
                 )
@@ -181,9 +348,14 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     } else {
         format!("{} | {}", app.message, help)
     };
+    // 매크로 기록 중 표시
+    let msg = match app.macros.recording_register() {
+        Some(register) => format!("● REC @{} | {}", register, msg),
+        None => msg,
+    };
 
     let footer = Paragraph::new(msg)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(app.theme.muted))
         .block(Block::default().borders(Borders::TOP));
     frame.render_widget(footer, area);
 }
@@ -200,6 +372,9 @@ fn draw_main(frame: &mut Frame, app: &App, area: Rect) {
         Screen::BlueprintSelect => draw_blueprint_select(frame, app, area),
         Screen::BlueprintDetail => draw_blueprint_detail(frame, app, area),
         Screen::BlueprintNameInput => draw_blueprint_name_input(frame, app, area),
+        Screen::BlueprintImportInput => draw_blueprint_import_input(frame, app, area),
+        Screen::DocumentSearch => draw_document_search(frame, app, area),
+        Screen::Compare => draw_compare(frame, app, area),
         Screen::BlueprintPreview => draw_blueprint_preview(frame, app, area),
         Screen::RegionSelect => draw_region_select(frame, app, area),
         Screen::ServiceSelect => draw_service_select(frame, app, area),
@@ -210,6 +385,51 @@ fn draw_main(frame: &mut Frame, app: &App, area: Rect) {
         Screen::LoadBalancerSelect => draw_load_balancer_select(frame, app, area),
         Screen::EcrSelect => draw_ecr_select(frame, app, area),
         Screen::AsgSelect => draw_asg_select(frame, app, area),
+        Screen::RdsSelect => draw_rds_select(frame, app, area),
+        Screen::LambdaSelect => draw_lambda_select(frame, app, area),
+        Screen::DynamoDbSelect => draw_dynamodb_select(frame, app, area),
+        Screen::CloudFrontSelect => draw_cloudfront_select(frame, app, area),
+        Screen::Route53Select => draw_route53_select(frame, app, area),
+        Screen::EksSelect => draw_eks_select(frame, app, area),
+        Screen::EcsSelect => draw_ecs_select(frame, app, area),
+        Screen::SqsSelect => draw_sqs_select(frame, app, area),
+        Screen::SnsSelect => draw_sns_select(frame, app, area),
+        Screen::ElastiCacheSelect => draw_elasticache_select(frame, app, area),
+        Screen::CloudWatchAlarmSelect => draw_cloudwatch_alarm_select(frame, app, area),
+        Screen::CloudTrailSelect => draw_cloudtrail_select(frame, app, area),
+        Screen::KmsSelect => draw_kms_select(frame, app, area),
+        Screen::SecretSelect => draw_secret_select(frame, app, area),
+        Screen::SsmParameterSelect => draw_ssm_parameter_select(frame, app, area),
+        Screen::EfsSelect => draw_efs_select(frame, app, area),
+        Screen::ApiGatewaySelect => draw_apigateway_select(frame, app, area),
+        Screen::ElasticBeanstalkSelect => draw_elasticbeanstalk_select(frame, app, area),
+        Screen::StepFunctionsSelect => draw_stepfunctions_select(frame, app, area),
+        Screen::KinesisSelect => draw_kinesis_select(frame, app, area),
+        Screen::MskSelect => draw_msk_select(frame, app, area),
+        Screen::OpenSearchSelect => draw_opensearch_select(frame, app, area),
+        Screen::RedshiftSelect => draw_redshift_select(frame, app, area),
+        Screen::TransitGatewaySelect => draw_transit_gateway_select(frame, app, area),
+        Screen::VpcPeeringSelect => draw_vpc_peering_select(frame, app, area),
+        Screen::VpnSelect => draw_vpn_select(frame, app, area),
+        Screen::DirectConnectSelect => draw_direct_connect_select(frame, app, area),
+        Screen::WafSelect => draw_waf_select(frame, app, area),
+        Screen::AcmSelect => draw_acm_select(frame, app, area),
+        Screen::CloudFormationSelect => draw_cloudformation_select(frame, app, area),
+        Screen::IamSelect => draw_iam_select(frame, app, area),
+        Screen::VpcEndpointSelect => draw_vpc_endpoint_select(frame, app, area),
+        Screen::NetworkInterfaceSelect => draw_network_interface_select(frame, app, area),
+        Screen::LaunchTemplateSelect => draw_launch_template_select(frame, app, area),
+        Screen::AmiSelect => draw_ami_select(frame, app, area),
+        Screen::EbsSelect => draw_ebs_select(frame, app, area),
+        Screen::KeyPairSelect => draw_key_pair_select(frame, app, area),
+        Screen::EventBridgeSelect => draw_eventbridge_select(frame, app, area),
+        Screen::CognitoSelect => draw_cognito_select(frame, app, area),
+        Screen::GlueSelect => draw_glue_select(frame, app, area),
+        Screen::AthenaSelect => draw_athena_select(frame, app, area),
+        Screen::BackupSelect => draw_backup_select(frame, app, area),
+        Screen::QueryPlayground => draw_query_playground(frame, app, area),
+        Screen::NameTagInput => draw_name_tag_input(frame, app, area),
+        Screen::NameTagConfirm => draw_name_tag_confirm(frame, app, area),
         Screen::Settings => draw_settings(frame, app, area),
     }
 }
@@ -235,6 +455,7 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         LoadingTask::RefreshVpc => i.refreshing_vpc_list(),
         LoadingTask::RefreshSecurityGroup => i.refreshing_sg_list(),
         LoadingTask::RefreshPreview => i.refreshing_preview(),
+        LoadingTask::DocumentRegion(_) => i.documenting_region(),
         LoadingTask::LoadEc2 => i.loading_ec2_list(),
         LoadingTask::LoadEc2Detail(_) => i.loading_ec2_detail(),
         LoadingTask::LoadVpc => i.loading_vpc_list(),
@@ -253,7 +474,182 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         LoadingTask::RefreshAsg => i.loading_asg_list(),
         LoadingTask::LoadAsg => i.loading_asg_list(),
         LoadingTask::LoadAsgDetail(_) => i.loading_asg_detail(),
+
+        LoadingTask::RefreshRds => i.loading_rds_list(),
+        LoadingTask::LoadRds => i.loading_rds_list(),
+        LoadingTask::LoadRdsDetail(_) => i.loading_rds_detail(),
+
+        LoadingTask::RefreshLambda => i.loading_lambda_list(),
+        LoadingTask::LoadLambda => i.loading_lambda_list(),
+        LoadingTask::LoadLambdaDetail(_) => i.loading_lambda_detail(),
+
+        LoadingTask::RefreshDynamoDb => i.loading_dynamodb_list(),
+        LoadingTask::LoadDynamoDb => i.loading_dynamodb_list(),
+        LoadingTask::LoadDynamoDbDetail(_) => i.loading_dynamodb_detail(),
+
+        LoadingTask::RefreshCloudFront => i.loading_cloudfront_list(),
+        LoadingTask::LoadCloudFront => i.loading_cloudfront_list(),
+        LoadingTask::LoadCloudFrontDetail(_) => i.loading_cloudfront_detail(),
+
+        LoadingTask::RefreshRoute53 => i.loading_route53_list(),
+        LoadingTask::LoadRoute53 => i.loading_route53_list(),
+        LoadingTask::LoadRoute53Detail(_) => i.loading_route53_detail(),
+
+        LoadingTask::RefreshEks => i.loading_eks_list(),
+        LoadingTask::LoadEks => i.loading_eks_list(),
+        LoadingTask::LoadEksDetail(_) => i.loading_eks_detail(),
+
+        LoadingTask::RefreshEcs => i.loading_ecs_list(),
+        LoadingTask::LoadEcs => i.loading_ecs_list(),
+        LoadingTask::LoadEcsDetail(_) => i.loading_ecs_detail(),
+
+        LoadingTask::RefreshSqs => i.loading_sqs_list(),
+        LoadingTask::LoadSqs => i.loading_sqs_list(),
+        LoadingTask::LoadSqsDetail(_) => i.loading_sqs_detail(),
+
+        LoadingTask::RefreshSns => i.loading_sns_list(),
+        LoadingTask::LoadSns => i.loading_sns_list(),
+        LoadingTask::LoadSnsDetail(_) => i.loading_sns_detail(),
+
+        LoadingTask::RefreshElastiCache => i.loading_elasticache_list(),
+        LoadingTask::LoadElastiCache => i.loading_elasticache_list(),
+        LoadingTask::LoadElastiCacheDetail(_) => i.loading_elasticache_detail(),
+
+        LoadingTask::RefreshCloudWatchAlarm => i.loading_cloudwatch_alarm_list(),
+        LoadingTask::LoadCloudWatchAlarm => i.loading_cloudwatch_alarm_list(),
+        LoadingTask::LoadCloudWatchAlarmDetail(_) => i.loading_cloudwatch_alarm_detail(),
+
+        LoadingTask::RefreshCloudTrail => i.loading_cloudtrail_list(),
+        LoadingTask::LoadCloudTrail => i.loading_cloudtrail_list(),
+        LoadingTask::LoadCloudTrailDetail(_) => i.loading_cloudtrail_detail(),
+
+        LoadingTask::RefreshKms => i.loading_kms_list(),
+        LoadingTask::LoadKms => i.loading_kms_list(),
+        LoadingTask::LoadKmsDetail(_) => i.loading_kms_detail(),
+
+        LoadingTask::RefreshSecret => i.loading_secret_list(),
+        LoadingTask::LoadSecret => i.loading_secret_list(),
+        LoadingTask::LoadSecretDetail(_) => i.loading_secret_detail(),
+
+        LoadingTask::RefreshSsmParameter => i.loading_ssm_parameter_list(),
+        LoadingTask::LoadSsmParameter => i.loading_ssm_parameter_list(),
+        LoadingTask::LoadSsmParameterDetail(_) => i.loading_ssm_parameter_detail(),
+
+        LoadingTask::RefreshEfs => i.loading_efs_list(),
+        LoadingTask::LoadEfs => i.loading_efs_list(),
+        LoadingTask::LoadEfsDetail(_) => i.loading_efs_detail(),
+
+        LoadingTask::RefreshApiGateway => i.loading_api_gateway_list(),
+        LoadingTask::LoadApiGateway => i.loading_api_gateway_list(),
+        LoadingTask::LoadApiGatewayDetail(_) => i.loading_api_gateway_detail(),
+
+        LoadingTask::RefreshElasticBeanstalk => i.loading_beanstalk_list(),
+        LoadingTask::LoadElasticBeanstalk => i.loading_beanstalk_list(),
+        LoadingTask::LoadElasticBeanstalkDetail(_) => i.loading_beanstalk_detail(),
+
+        LoadingTask::RefreshStepFunctions => i.loading_state_machine_list(),
+        LoadingTask::LoadStepFunctions => i.loading_state_machine_list(),
+        LoadingTask::LoadStepFunctionsDetail(_) => i.loading_state_machine_detail(),
+
+        LoadingTask::RefreshKinesis => i.loading_kinesis_stream_list(),
+        LoadingTask::LoadKinesis => i.loading_kinesis_stream_list(),
+        LoadingTask::LoadKinesisDetail(_) => i.loading_kinesis_stream_detail(),
+
+        LoadingTask::RefreshMsk => i.loading_msk_cluster_list(),
+        LoadingTask::LoadMsk => i.loading_msk_cluster_list(),
+        LoadingTask::LoadMskDetail(_) => i.loading_msk_cluster_detail(),
+
+        LoadingTask::RefreshOpenSearch => i.loading_opensearch_domain_list(),
+        LoadingTask::LoadOpenSearch => i.loading_opensearch_domain_list(),
+        LoadingTask::LoadOpenSearchDetail(_) => i.loading_opensearch_domain_detail(),
+
+        LoadingTask::RefreshRedshift => i.loading_redshift_cluster_list(),
+        LoadingTask::LoadRedshift => i.loading_redshift_cluster_list(),
+        LoadingTask::LoadRedshiftDetail(_) => i.loading_redshift_cluster_detail(),
+
+        LoadingTask::RefreshTransitGateway => i.loading_transit_gateway_list(),
+        LoadingTask::LoadTransitGateway => i.loading_transit_gateway_list(),
+        LoadingTask::LoadTransitGatewayDetail(_) => i.loading_transit_gateway_detail(),
+
+        LoadingTask::RefreshVpcPeering => i.loading_vpc_peering_list(),
+        LoadingTask::LoadVpcPeering => i.loading_vpc_peering_list(),
+        LoadingTask::LoadVpcPeeringDetail(_) => i.loading_vpc_peering_detail(),
+
+        LoadingTask::RefreshVpn => i.loading_vpn_list(),
+        LoadingTask::LoadVpn => i.loading_vpn_list(),
+        LoadingTask::LoadVpnDetail(_) => i.loading_vpn_detail(),
+
+        LoadingTask::RefreshDirectConnect => i.loading_direct_connect_list(),
+        LoadingTask::LoadDirectConnect => i.loading_direct_connect_list(),
+        LoadingTask::LoadDirectConnectDetail(_) => i.loading_direct_connect_detail(),
+
+        LoadingTask::RefreshWaf => i.loading_waf_list(),
+        LoadingTask::LoadWaf => i.loading_waf_list(),
+        LoadingTask::LoadWafDetail(_) => i.loading_waf_detail(),
+
+        LoadingTask::RefreshAcm => i.loading_acm_list(),
+        LoadingTask::LoadAcm => i.loading_acm_list(),
+        LoadingTask::LoadAcmDetail(_) => i.loading_acm_detail(),
+
+        LoadingTask::RefreshCloudFormation => i.loading_cloudformation_list(),
+        LoadingTask::LoadCloudFormation => i.loading_cloudformation_list(),
+        LoadingTask::LoadCloudFormationDetail(_) => i.loading_cloudformation_detail(),
+
+        LoadingTask::RefreshIam => i.loading_iam_list(),
+        LoadingTask::LoadIam => i.loading_iam_list(),
+        LoadingTask::LoadIamDetail(_) => i.loading_iam_detail(),
+
+        LoadingTask::RefreshVpcEndpoint => i.loading_vpc_endpoint_list(),
+        LoadingTask::LoadVpcEndpoint => i.loading_vpc_endpoint_list(),
+        LoadingTask::LoadVpcEndpointDetail(_) => i.loading_vpc_endpoint_detail(),
+
+        LoadingTask::RefreshNetworkInterface => i.loading_network_interface_list(),
+        LoadingTask::LoadNetworkInterface => i.loading_network_interface_list(),
+        LoadingTask::LoadNetworkInterfaceDetail(_) => i.loading_network_interface_detail(),
+
+        LoadingTask::RefreshLaunchTemplate => i.loading_launch_template_list(),
+        LoadingTask::LoadLaunchTemplate => i.loading_launch_template_list(),
+        LoadingTask::LoadLaunchTemplateDetail(_) => i.loading_launch_template_detail(),
+
+        LoadingTask::RefreshAmi => i.loading_ami_list(),
+        LoadingTask::LoadAmi => i.loading_ami_list(),
+        LoadingTask::LoadAmiDetail(_) => i.loading_ami_detail(),
+
+        LoadingTask::RefreshEbs => i.loading_ebs_list(),
+        LoadingTask::LoadEbs => i.loading_ebs_list(),
+        LoadingTask::LoadEbsDetail(_) => i.loading_ebs_detail(),
+
+        LoadingTask::RefreshKeyPair => i.loading_key_pair_list(),
+        LoadingTask::LoadKeyPair => i.loading_key_pair_list(),
+        LoadingTask::LoadKeyPairDetail(_) => i.loading_key_pair_detail(),
+
+        LoadingTask::RefreshEventBridge => i.loading_event_bus_list(),
+        LoadingTask::LoadEventBridge => i.loading_event_bus_list(),
+        LoadingTask::LoadEventBridgeDetail(_) => i.loading_event_bus_detail(),
+
+        LoadingTask::RefreshCognito => i.loading_cognito_pool_list(),
+        LoadingTask::LoadCognito => i.loading_cognito_pool_list(),
+        LoadingTask::LoadCognitoDetail(_) => i.loading_cognito_pool_detail(),
+
+        LoadingTask::RefreshGlue => i.loading_glue_list(),
+        LoadingTask::LoadGlue => i.loading_glue_list(),
+        LoadingTask::LoadGlueDetail(_) => i.loading_glue_detail(),
+
+        LoadingTask::RefreshAthena => i.loading_athena_workgroup_list(),
+        LoadingTask::LoadAthena => i.loading_athena_workgroup_list(),
+        LoadingTask::LoadAthenaDetail(_) => i.loading_athena_workgroup_detail(),
+
+        LoadingTask::RefreshBackup => i.loading_backup_plan_list(),
+        LoadingTask::LoadBackup => i.loading_backup_plan_list(),
+        LoadingTask::LoadBackupDetail(_) => i.loading_backup_plan_detail(),
         LoadingTask::LoadBlueprintResources(_) => i.loading_blueprint_resources(),
+        LoadingTask::ValidateBlueprintResources(_) => i.validating_blueprint_resources(),
+        LoadingTask::ImportCloudFormationStack(_) => i.loading_stack_resources(),
+        LoadingTask::ImportTaggedResources(_) => i.querying_tagged_resources(),
+        LoadingTask::LoadCompare => i.loading_comparison(),
+        LoadingTask::LoadPromotionChecklist(_, _) => i.loading_promotion_checklist(),
+        LoadingTask::RunQueryOperation(_) => i.running_query_operation(),
+        LoadingTask::SetNameTag => i.setting_name_tag(),
     };
 
     let content = vec![
@@ -261,19 +657,17 @@ fn draw_loading(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(Span::styled(
             format!("    ⏳ {}", i.loading_msg()),
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.emphasis(),
         )),
         Line::from(""),
         Line::from(Span::styled(
             format!("    {}", task_name),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(app.theme.accent),
         )),
         Line::from(""),
         Line::from(Span::styled(
             format!("    {}", i.aws_cli_waiting()),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(app.theme.muted),
         )),
     ];
 
@@ -299,32 +693,38 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
         4
     } else if !p.eips {
         5
-    } else if !p.dns_attrs {
+    } else if !p.peerings {
         6
-    } else {
+    } else if !p.endpoints {
         7
+    } else if !p.nacls {
+        8
+    } else if !p.dns_attrs {
+        9
+    } else {
+        10
     };
 
-    fn item(done: bool, loading: bool, text: &str) -> Line<'static> {
+    let item = |done: bool, loading: bool, text: &str| -> Line<'static> {
         let (check, color) = if done {
-            ("  ✓ ", Color::Green)
+            ("  ✓ ", app.theme.success)
         } else if loading {
-            ("  ▸ ", Color::Cyan)
+            ("  ▸ ", app.theme.accent)
         } else {
-            ("  ○ ", Color::DarkGray)
+            ("  ○ ", app.theme.muted)
         };
         let text_color = if done {
-            Color::Green
+            app.theme.success
         } else if loading {
-            Color::Cyan
+            app.theme.accent
         } else {
-            Color::DarkGray
+            app.theme.muted
         };
         Line::from(vec![
             Span::styled(check, Style::default().fg(color)),
             Span::styled(text.to_string(), Style::default().fg(text_color)),
         ])
-    }
+    };
 
     let steps = [
         i.vpc_basic_info(),
@@ -333,6 +733,9 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
         i.nat_gateway(),
         i.route_tables(),
         i.elastic_ip(),
+        i.vpc_peering(),
+        i.vpc_endpoint(),
+        i.network_acl(),
         i.dns_settings(),
     ];
 
@@ -346,9 +749,7 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(Span::styled(
             format!("  ⏳ {}...", i.loading_vpc_detail()),
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.emphasis(),
         )),
         Line::from(""),
         item(p.vpc_info, current_step == 0, steps[0]),
@@ -357,11 +758,14 @@ fn draw_vpc_loading_checklist(frame: &mut Frame, app: &App, area: Rect) {
         item(p.nats, current_step == 3, steps[3]),
         item(p.route_tables, current_step == 4, steps[4]),
         item(p.eips, current_step == 5, steps[5]),
-        item(p.dns_attrs, current_step == 6, steps[6]),
+        item(p.peerings, current_step == 6, steps[6]),
+        item(p.endpoints, current_step == 7, steps[7]),
+        item(p.nacls, current_step == 8, steps[8]),
+        item(p.dns_attrs, current_step == 9, steps[9]),
         Line::from(""),
         Line::from(Span::styled(
             i.current_loading(current_task),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(app.theme.accent),
         )),
     ];
 
@@ -377,7 +781,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
     if let Some(error) = app.login_error.as_ref() {
         content.push(Line::from(Span::styled(
             i.aws_login_required(),
-            Style::default().fg(Color::Red),
+            Style::default().fg(app.theme.error),
         )));
         content.push(Line::from(""));
         let display_error = match error.code {
@@ -388,7 +792,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
         };
         content.push(Line::from(Span::styled(
             display_error,
-            Style::default().fg(Color::Red),
+            Style::default().fg(app.theme.error),
         )));
         content.push(Line::from(""));
     }
@@ -402,7 +806,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
     } else {
         content.push(Line::from(Span::styled(
             i.aws_login_verified(),
-            Style::default().fg(Color::Green),
+            Style::default().fg(app.theme.success),
         )));
         content.push(Line::from(""));
         content.push(Line::from(i.profile_select_prompt()));
@@ -412,9 +816,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
             let selected = index == app.selected_profile_index;
             let prefix = if selected { "▶ " } else { "  " };
             let style = if selected {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -428,7 +830,7 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
                 info.as_str(),
-                Style::default().fg(Color::Green),
+                Style::default().fg(app.theme.success),
             )));
         }
     }
@@ -446,14 +848,13 @@ fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
 
 fn draw_region_select(frame: &mut Frame, app: &App, area: Rect) {
     let lang = app.settings.language;
-    let items: Vec<ListItem> = REGIONS
+    let items: Vec<ListItem> = app
+        .regions()
         .iter()
         .enumerate()
         .map(|(i, r)| {
             let style = if i == app.selected_region {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -474,7 +875,7 @@ fn draw_region_select(frame: &mut Frame, app: &App, area: Rect) {
 fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
     let lang = app.settings.language;
     let i = &app.i18n;
-    let region = &REGIONS[app.selected_region];
+    let region = app.region();
     let title = format!(
         " {} [{} - {}] ",
         i.service(),
@@ -482,9 +883,19 @@ fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
         region.name(lang)
     );
 
-    // Build service list: services + exit
-    let services: Vec<&str> = SERVICE_KEYS.to_vec();
-    let exit_label = i.exit();
+    // Build service list: pinned/usage-ordered services + exit
+    let services: Vec<String> = app
+        .service_order()
+        .into_iter()
+        .map(|service| {
+            if app.is_service_pinned(service) {
+                format!("{} ★", SERVICE_KEYS[service])
+            } else {
+                SERVICE_KEYS[service].to_string()
+            }
+        })
+        .collect();
+    let exit_label = i.exit().to_string();
 
     let items: Vec<ListItem> = services
         .iter()
@@ -492,9 +903,7 @@ fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(idx, s)| {
             let style = if idx == app.selected_service {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -511,10 +920,34 @@ fn draw_service_select(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(list, area);
 }
 
+// 목록 화면 제목: 서비스, 리전, 리소스 수, 마지막 조회 시각 (재조회 없이 보여주면 캐시 표시)
+fn list_title(app: &App, label: &str) -> String {
+    let region = app.region();
+    let mut title = format!(
+        " {} [{} - {}]",
+        label,
+        region.code,
+        region.name(app.settings.language)
+    );
+    if let Some((_, resources)) = app.current_resource_list() {
+        title.push_str(&format!(" ({})", resources.len()));
+    }
+    if let Some(refresh) = app.current_list_refresh() {
+        title.push_str(&format!(
+            " — {}",
+            app.i18n
+                .refreshed_ago(refresh.refreshed_at.elapsed().as_secs())
+        ));
+        if refresh.cached {
+            title.push_str(&format!(" ({})", app.i18n.cached()));
+        }
+    }
+    title.push(' ');
+    title
+}
+
 fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" EC2 [{} - {}] ", region.code, region.name(lang));
+    let title = list_title(app, "EC2");
 
     if app.instances.is_empty() {
         let para = Paragraph::new(app.i18n.no_instances())
@@ -535,9 +968,7 @@ fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
             });
 
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -560,18 +991,44 @@ fn draw_ec2_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_preview(frame: &mut Frame, app: &App, area: Rect) {
-    let title = format!(" {} - {} ", app.i18n.preview(), app.preview_filename);
-    let para = Paragraph::new(app.preview_content.as_str())
-        .wrap(Wrap { trim: false })
-        .scroll((app.preview_scroll, 0))
+    let title = if app.preview_raw.is_some() {
+        format!(
+            " {} - {} [{}] ",
+            app.i18n.preview(),
+            app.preview_filename,
+            app.i18n.raw_json()
+        )
+    } else {
+        format!(" {} - {} ", app.i18n.preview(), app.preview_filename)
+    };
+    // 원본 JSON 보기에는 표 형태의 줄이 없으므로 그대로 표시됨
+    frame.render_widget(preview_paragraph(app, app.preview_text(), title), area);
+}
+
+// 줄바꿈을 끈 상태에서만 좌우 스크롤 키 안내
+fn preview_wrap_hint(app: &App) -> String {
+    let i = &app.i18n;
+    if app.preview_wrap {
+        format!("w: {}", i.word_wrap())
+    } else {
+        format!("←→/hl: {} | w: {}", i.scroll_sideways(), i.word_wrap())
+    }
+}
+
+// 줄바꿈을 끄면 넓은 표가 잘리지 않고 가로 스크롤 위치부터 표시됨
+fn preview_paragraph<'a>(app: &App, text: &'a str, title: String) -> Paragraph<'a> {
+    let para = Paragraph::new(align_markdown_tables(text))
+        .scroll((app.preview_scroll, app.preview_hscroll))
         .block(Block::default().title(title).borders(Borders::ALL));
-    frame.render_widget(para, area);
+    if app.preview_wrap {
+        para.wrap(Wrap { trim: false })
+    } else {
+        para
+    }
 }
 
 fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Network [{} - {}] ", region.code, region.name(lang));
+    let title = list_title(app, "Network");
 
     if app.vpcs.is_empty() {
         let para = Paragraph::new(app.i18n.no_vpcs())
@@ -592,9 +1049,7 @@ fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
             });
 
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -617,9 +1072,7 @@ fn draw_vpc_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Security Group [{} - {}] ", region.code, region.name(lang));
+    let title = list_title(app, "Security Group");
 
     if app.security_groups.is_empty() {
         let para = Paragraph::new(app.i18n.no_security_groups())
@@ -640,9 +1093,7 @@ fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
             });
 
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -665,9 +1116,7 @@ fn draw_security_group_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" Load Balancer [{} - {}] ", region.code, region.name(lang));
+    let title = list_title(app, "Load Balancer");
 
     if app.load_balancers.is_empty() {
         let para = Paragraph::new(app.i18n.no_load_balancers())
@@ -688,9 +1137,7 @@ fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
             });
 
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -713,9 +1160,7 @@ fn draw_load_balancer_select(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_ecr_select(frame: &mut Frame, app: &App, area: Rect) {
-    let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
-    let title = format!(" ECR [{} - {}] ", region.code, region.name(lang));
+    let title = list_title(app, "ECR");
 
     if app.ecr_repositories.is_empty() {
         let para = Paragraph::new(app.i18n.no_ecr_repos())
@@ -736,9 +1181,7 @@ fn draw_ecr_select(frame: &mut Frame, app: &App, area: Rect) {
             });
 
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -768,11 +1211,9 @@ fn draw_blueprint_select(frame: &mut Frame, app: &App, area: Rect) {
 
     // "새 블루프린터" 항목
     let new_bp_style = if app.selected_blueprint_index == 0 {
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD)
+        app.theme.emphasis()
     } else {
-        Style::default().fg(Color::Green)
+        Style::default().fg(app.theme.success)
     };
     let new_bp_prefix = if app.selected_blueprint_index == 0 {
         "▶ "
@@ -785,14 +1226,15 @@ fn draw_blueprint_select(frame: &mut Frame, app: &App, area: Rect) {
     // 기존 블루프린터 목록
     for (idx, bp) in app.blueprint_store.blueprints.iter().enumerate() {
         let style = if idx + 1 == app.selected_blueprint_index {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
+            app.theme.emphasis()
         } else {
             Style::default()
         };
         let prefix = if idx + 1 == app.selected_blueprint_index {
             "▶ "
+        } else if app.promotion_source == Some(idx) {
+            // 승격 체크리스트 원본으로 지정된 블루프린트
+            "● "
         } else {
             "  "
         };
@@ -833,12 +1275,12 @@ fn draw_blueprint_detail(frame: &mut Frame, app: &App, area: Rect) {
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {}.", i.no_resources()),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(app.theme.muted),
             )),
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {}", i.press_a_to_add()),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(app.theme.accent),
             )),
         ];
         let para =
@@ -853,9 +1295,7 @@ fn draw_blueprint_detail(frame: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(i, res)| {
             let style = if i == app.blueprint_resource_index {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.emphasis()
             } else {
                 Style::default()
             };
@@ -865,23 +1305,37 @@ fn draw_blueprint_detail(frame: &mut Frame, app: &App, area: Rect) {
                 "  "
             };
 
-            let type_color = match res.resource_type {
-                ResourceType::Ec2 => Color::Cyan,
-                ResourceType::Network => Color::Green,
-                ResourceType::SecurityGroup => Color::Magenta,
-                ResourceType::LoadBalancer => Color::Blue,
-                ResourceType::Ecr => Color::LightRed,
-                ResourceType::Asg => Color::LightCyan,
-            };
+            let type_color = app.theme.resource_color(&res.resource_type);
 
-            ListItem::new(Line::from(vec![
+            let mut spans = vec![
                 Span::styled(prefix, style),
                 Span::styled(
                     format!("[{}] ", res.resource_type.display()),
                     Style::default().fg(type_color),
                 ),
                 Span::styled(format!("{} ({})", res.resource_name, res.region), style),
-            ]))
+            ];
+            match app.blueprint_validation.get(i) {
+                Some(ResourceStatus::Missing) => spans.push(Span::styled(
+                    format!("  ✗ {}", app.i18n.resource_missing()),
+                    Style::default().fg(app.theme.error),
+                )),
+                Some(ResourceStatus::Renamed(name)) => spans.push(Span::styled(
+                    format!("  ⚠ {}", app.i18n.resource_renamed(name)),
+                    Style::default().fg(app.theme.warning),
+                )),
+                Some(ResourceStatus::Moved(region)) => spans.push(Span::styled(
+                    format!("  ⚠ {}", app.i18n.resource_moved(region)),
+                    Style::default().fg(app.theme.warning),
+                )),
+                Some(ResourceStatus::Unknown) => spans.push(Span::styled(
+                    format!("  ? {}", app.i18n.resource_unverified()),
+                    Style::default().fg(app.theme.muted),
+                )),
+                _ => {}
+            }
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -897,7 +1351,7 @@ fn draw_blueprint_name_input(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(""),
         Line::from(Span::styled(
             format!("  {}", i.enter_blueprint_name()),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(app.theme.accent),
         )),
         Line::from(""),
         Line::from(format!("  > {}_", app.input_buffer)),
@@ -907,160 +1361,2873 @@ fn draw_blueprint_name_input(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(para, area);
 }
 
-fn draw_blueprint_preview(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_name_tag_input(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
-    let title = format!(
-        " {} {} - {} ",
-        i.blueprint(),
-        i.preview(),
-        app.preview_filename
-    );
-    let para = Paragraph::new(app.preview_content.as_str())
-        .wrap(Wrap { trim: false })
-        .scroll((app.preview_scroll, 0))
-        .block(Block::default().title(title).borders(Borders::ALL));
+    let Some(edit) = &app.name_tag_edit else {
+        return;
+    };
+    let title = format!(" {} ({}) ", i.edit_name_tag(), edit.resource_id);
+
+    let content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", i.enter_name_tag()),
+            Style::default().fg(app.theme.accent),
+        )),
+        Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
+    ];
+
+    let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
     frame.render_widget(para, area);
 }
 
-fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_index: usize) {
+fn draw_name_tag_confirm(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
-    let bp = match &app.current_blueprint {
-        Some(bp) => bp,
-        None => {
-            let title = format!(" {} ", i.loading());
-            let para = Paragraph::new(i.loading_msg())
-                .block(Block::default().title(title).borders(Borders::ALL));
-            frame.render_widget(para, area);
-            return;
-        }
+    let Some(edit) = &app.name_tag_edit else {
+        return;
     };
+    let title = format!(" {} ({}) ", i.edit_name_tag(), edit.resource_id);
+    let current = edit.current_name.as_deref().unwrap_or("-");
 
-    let total = bp.resources.len();
+    let content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", i.confirm_name_tag(&edit.resource_id)),
+            app.theme.emphasis(),
+        )),
+        Line::from(""),
+        Line::from(format!("  Name: {} → {}", current, app.input_buffer.trim())),
+        Line::from(""),
+        Line::from("  [y/N]"),
+    ];
 
-    fn item(done: bool, loading: bool, text: String) -> Line<'static> {
-        let (check, color) = if done {
-            ("  ✓ ", Color::Green)
-        } else if loading {
-            ("  ▸ ", Color::Cyan)
-        } else {
-            ("  ○ ", Color::DarkGray)
-        };
-        let text_color = if done {
-            Color::Green
-        } else if loading {
-            Color::Cyan
-        } else {
-            Color::DarkGray
-        };
-        Line::from(vec![
-            Span::styled(check, Style::default().fg(color)),
-            Span::styled(text, Style::default().fg(text_color)),
-        ])
-    }
+    let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, area);
+}
 
-    let mut content = vec![
+fn draw_blueprint_import_input(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(" {} ", i.import_blueprint());
+    let prompt = match app.blueprint_import_kind {
+        BlueprintImportKind::Terraform => i.enter_terraform_state_path(),
+        BlueprintImportKind::CloudFormation => i.enter_stack_name(),
+        BlueprintImportKind::TagQuery => i.enter_tag_query(),
+    };
+
+    let content = vec![
         Line::from(""),
         Line::from(Span::styled(
-            format!(
-                "  {}... ({}/{})",
-                i.loading_blueprint_resources(),
-                current_index,
-                total
-            ),
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            format!("  {}", prompt),
+            Style::default().fg(app.theme.accent),
         )),
         Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
     ];
 
-    for (idx, res) in bp.resources.iter().enumerate() {
-        let done = idx < current_index;
-        let loading = idx == current_index;
-        content.push(item(done, loading, res.display()));
-    }
-
-    let title = format!(" {} {} ", i.blueprint(), i.loading());
     let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
     frame.render_widget(para, area);
 }
 
-fn draw_settings(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_document_search(frame: &mut Frame, app: &App, area: Rect) {
     let i = &app.i18n;
-    let title = format!(" {} ", i.settings());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(3)])
+        .split(area);
 
-    let lang_current = app.settings.language.display();
-    let lang_next = app.settings.language.toggle().display();
+    let input = vec![
+        Line::from(Span::styled(
+            format!("  {}", i.enter_search_query()),
+            Style::default().fg(app.theme.accent),
+        )),
+        Line::from(""),
+        Line::from(format!("  > {}_", app.input_buffer)),
+    ];
+    let para = Paragraph::new(input).block(
+        Block::default()
+            .title(format!(" {} ", i.search_documents()))
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(para, chunks[0]);
 
-    let items: Vec<ListItem> = vec![ListItem::new(Line::from(vec![
-        Span::styled(
-            if app.selected_setting == 0 {
-                "▶ "
-            } else {
-                "  "
-            },
-            if app.selected_setting == 0 {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+    let items: Vec<ListItem> = app
+        .search_results
+        .iter()
+        .enumerate()
+        .map(|(idx, hit)| {
+            let selected = idx == app.selected_search_result;
+            let style = if selected {
+                app.theme.emphasis()
             } else {
                 Style::default()
-            },
-        ),
-        Span::styled(
-            format!("{}: ", i.language()),
-            Style::default().fg(Color::Cyan),
-        ),
-        Span::styled(
-            lang_current,
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::styled(
-            format!(" → {}", lang_next),
-            Style::default().fg(Color::DarkGray),
-        ),
-    ]))];
+            };
+            let prefix = if selected { "▶ " } else { "  " };
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(format!("{}{}", prefix, hit.path), style),
+                    Span::styled(
+                        // RFC 3339 색인 시각의 날짜 부분만 표시
+                        format!(
+                            "  ({})",
+                            hit.indexed_at.get(..10).unwrap_or(&hit.indexed_at)
+                        ),
+                        Style::default().fg(app.theme.muted),
+                    ),
+                ]),
+                Line::from(Span::styled(
+                    format!("    {}", hit.snippet),
+                    Style::default().fg(app.theme.muted),
+                )),
+            ])
+        })
+        .collect();
 
+    let title = if app.search_results.is_empty() {
+        String::new()
+    } else {
+        format!(" {} ", i.search_result_count(app.search_results.len()))
+    };
     let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
-    frame.render_widget(list, area);
+    frame.render_widget(list, chunks[1]);
 }
 
-fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_query_playground(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
     let lang = app.settings.language;
-    let region = &REGIONS[app.selected_region];
+    let region = app.region();
     let title = format!(
         " {} [{} - {}] ",
-        app.i18n.auto_scaling_group(),
+        i.query_playground(),
         region.code,
         region.name(lang)
     );
 
-    if app.auto_scaling_groups.is_empty() {
-        let para = Paragraph::new(app.i18n.no_asgs())
-            .block(Block::default().title(title).borders(Borders::ALL));
-        frame.render_widget(para, area);
+    // 작업 실행 전: 읽기 전용 작업 목록
+    if app.query_output.is_none() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)])
+            .split(area);
+        let header = Paragraph::new(Span::styled(
+            format!("  {}", i.select_query_operation()),
+            Style::default().fg(app.theme.accent),
+        ))
+        .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(header, chunks[0]);
+
+        let items: Vec<ListItem> = QUERY_OPERATIONS
+            .iter()
+            .enumerate()
+            .map(|(idx, (service, operation))| {
+                let selected = idx == app.selected_query_operation;
+                let style = if selected {
+                    app.theme.emphasis()
+                } else {
+                    Style::default()
+                };
+                let prefix = if selected { "▶ " } else { "  " };
+                ListItem::new(format!("{}aws {} {}", prefix, service, operation)).style(style)
+            })
+            .collect();
+        let list = List::new(items).block(Block::default().borders(Borders::ALL));
+        frame.render_widget(list, chunks[1]);
         return;
     }
 
-    let items: Vec<ListItem> = app
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(3)])
+        .split(area);
+
+    let input = vec![
+        Line::from(Span::styled(
+            format!("  {}", i.enter_jmespath_query()),
+            Style::default().fg(app.theme.accent),
+        )),
+        Line::from(format!("  > {}_", app.input_buffer)),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  $ {}", app.query_command()),
+            Style::default().fg(app.theme.muted),
+        )),
+    ];
+    let para = Paragraph::new(input).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, chunks[0]);
+
+    let (result_title, result, style) = match &app.query_result {
+        Ok(result) => (i.query_result(), result.as_str(), Style::default()),
+        Err(error) => (
+            i.query_error(),
+            error.as_str(),
+            Style::default().fg(app.theme.error),
+        ),
+    };
+    let result = Paragraph::new(result)
+        .style(style)
+        .block(
+            Block::default()
+                .title(format!(" {} ", result_title))
+                .borders(Borders::ALL),
+        )
+        .scroll((app.query_scroll, 0));
+    frame.render_widget(result, chunks[1]);
+}
+
+// 상대편 문서에 없는 줄을 강조해서 표시 (비교는 원래 줄로, 표시는 표 열을 맞춘 줄로)
+fn compare_pane_lines(theme: &Theme, markdown: &str, other: &str) -> Vec<Line<'static>> {
+    let other_lines: std::collections::HashSet<&str> = other.lines().map(str::trim).collect();
+    let aligned = align_markdown_tables(markdown);
+    markdown
+        .lines()
+        .zip(aligned.lines())
+        .map(|(line, shown)| {
+            if line.trim().is_empty() || other_lines.contains(line.trim()) {
+                Line::from(shown.to_string())
+            } else {
+                Line::from(Span::styled(
+                    shown.to_string(),
+                    Style::default().fg(theme.warning),
+                ))
+            }
+        })
+        .collect()
+}
+
+fn draw_compare(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let Some(comparison) = &app.comparison else {
+        return;
+    };
+
+    if app.compare_show_table {
+        let title = format!(" {} - {} ", i.compare(), app.preview_filename);
+        let para = Paragraph::new(align_markdown_tables(&app.preview_content))
+            .wrap(Wrap { trim: false })
+            .scroll((app.preview_scroll, 0))
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let panes = [
+        (
+            &comparison.left,
+            &comparison.left_markdown,
+            &comparison.right_markdown,
+        ),
+        (
+            &comparison.right,
+            &comparison.right_markdown,
+            &comparison.left_markdown,
+        ),
+    ];
+    for ((resource, markdown, other), chunk) in panes.into_iter().zip(chunks.iter()) {
+        let title = format!(" {} ({}) ", resource.resource_name, resource.resource_id);
+        let para = Paragraph::new(compare_pane_lines(&app.theme, markdown, other))
+            .wrap(Wrap { trim: false })
+            .scroll((app.preview_scroll, 0))
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, *chunk);
+    }
+}
+
+fn draw_blueprint_preview(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(
+        " {} {} - {} ",
+        i.blueprint(),
+        i.preview(),
+        app.preview_filename
+    );
+    frame.render_widget(preview_paragraph(app, &app.preview_content, title), area);
+}
+
+fn draw_blueprint_loading(frame: &mut Frame, app: &App, area: Rect, current_index: usize) {
+    let i = &app.i18n;
+    let bp = match app.documenting_blueprint() {
+        Some(bp) => bp,
+        None => {
+            let title = format!(" {} ", i.loading());
+            let para = Paragraph::new(i.loading_msg())
+                .block(Block::default().title(title).borders(Borders::ALL));
+            frame.render_widget(para, area);
+            return;
+        }
+    };
+
+    let total = bp.resources.len();
+
+    let item = |done: bool, loading: bool, text: String| -> Line<'static> {
+        let (check, color) = if done {
+            ("  ✓ ", app.theme.success)
+        } else if loading {
+            ("  ▸ ", app.theme.accent)
+        } else {
+            ("  ○ ", app.theme.muted)
+        };
+        let text_color = if done {
+            app.theme.success
+        } else if loading {
+            app.theme.accent
+        } else {
+            app.theme.muted
+        };
+        Line::from(vec![
+            Span::styled(check, Style::default().fg(color)),
+            Span::styled(text, Style::default().fg(text_color)),
+        ])
+    };
+
+    let mut content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "  {}... ({}/{})",
+                i.loading_blueprint_resources(),
+                current_index,
+                total
+            ),
+            app.theme.emphasis(),
+        )),
+        Line::from(""),
+    ];
+
+    for (idx, res) in bp.resources.iter().enumerate() {
+        let done = idx < current_index;
+        let loading = idx == current_index;
+        content.push(item(done, loading, res.display()));
+    }
+
+    let title = format!(" {} {} ", i.blueprint(), i.loading());
+    let para = Paragraph::new(content).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(para, area);
+}
+
+fn setting_item<'a>(
+    theme: &Theme,
+    selected: bool,
+    label: &str,
+    current: &'a str,
+    next: &str,
+) -> ListItem<'a> {
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            if selected { "▶ " } else { "  " },
+            if selected {
+                theme.emphasis()
+            } else {
+                Style::default()
+            },
+        ),
+        Span::styled(format!("{}: ", label), Style::default().fg(theme.accent)),
+        Span::styled(
+            current,
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!(" → {}", next), Style::default().fg(theme.muted)),
+    ]))
+}
+
+fn draw_settings(frame: &mut Frame, app: &App, area: Rect) {
+    let i = &app.i18n;
+    let title = format!(" {} ", i.settings());
+
+    let on_off = |enabled: bool| if enabled { i.on() } else { i.off() };
+    let encrypt = app.settings.encrypt_at_rest;
+    let hide_terminated = app.settings.hide_terminated_instances;
+    let sort_by_usage = app.settings.sort_services_by_usage;
+    let palette = app.settings.palette;
+    // NO_COLOR가 설정된 팔레트보다 우선하면 함께 표시
+    let current_palette = if app.theme.palette == palette {
+        palette.label(i).to_string()
+    } else {
+        format!("{} (NO_COLOR)", palette.label(i))
+    };
+    let ascii_mode = app.settings.ascii_mode;
+    let ascii_label = |mode: AsciiMode| match mode {
+        // 자동이면 감지 결과를 함께 표시
+        AsciiMode::Auto => format!("{} ({})", i.ascii_auto(), on_off(app.ascii)),
+        AsciiMode::On => i.on().to_string(),
+        AsciiMode::Off => i.off().to_string(),
+    };
+    let current_ascii = ascii_label(ascii_mode);
+    let concurrency = app.settings.concurrency_limit();
+    let number_locale = app.settings.number_locale;
+    let spell_out = app.settings.spell_out_schedules;
+    let normalize_rules = app.settings.normalize_security_group_rules;
+    let stamp_last_modified = app.settings.stamp_last_modified;
+    let show_age = app.settings.show_resource_age;
+
+    let items: Vec<ListItem> = vec![
+        setting_item(
+            &app.theme,
+            app.selected_setting == 0,
+            i.language(),
+            app.settings.language.display(),
+            app.settings.language.toggle().display(),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 1,
+            i.encrypt_at_rest(),
+            on_off(encrypt),
+            on_off(!encrypt),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 2,
+            i.hide_terminated_instances(),
+            on_off(hide_terminated),
+            on_off(!hide_terminated),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 3,
+            i.sort_services_by_usage(),
+            on_off(sort_by_usage),
+            on_off(!sort_by_usage),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 4,
+            i.color_palette(),
+            &current_palette,
+            palette.next().label(i),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 5,
+            i.ascii_mode(),
+            &current_ascii,
+            &ascii_label(ascii_mode.next()),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 6,
+            i.max_concurrent_requests(),
+            &concurrency.to_string(),
+            &next_concurrency_limit(concurrency).to_string(),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 7,
+            i.number_locale(),
+            &number_locale.label(i),
+            &number_locale.next().label(i),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 8,
+            i.spell_out_schedules(),
+            on_off(spell_out),
+            on_off(!spell_out),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 9,
+            i.normalize_security_group_rules(),
+            on_off(normalize_rules),
+            on_off(!normalize_rules),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 10,
+            i.stamp_last_modified(),
+            on_off(stamp_last_modified),
+            on_off(!stamp_last_modified),
+        ),
+        setting_item(
+            &app.theme,
+            app.selected_setting == 11,
+            i.show_resource_age(),
+            on_off(show_age),
+            on_off(!show_age),
+        ),
+    ];
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, app.i18n.auto_scaling_group());
+
+    if app.auto_scaling_groups.is_empty() {
+        let para = Paragraph::new(app.i18n.no_asgs())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
         .auto_scaling_groups
         .iter()
         .enumerate()
-        .map(|(i, asg)| {
+        .map(|(i, asg)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Asg && r.resource_id == asg.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let _state_style = if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default().fg(app.theme.accent)
+            };
+
+            let content = format!("{} [{}]", asg.display(), asg.state);
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_rds_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "RDS");
+
+    if app.rds_databases.is_empty() {
+        let para = Paragraph::new(app.i18n.no_rds_databases())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.rds_databases.iter().map(|db| db.id.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .rds_databases
+        .iter()
+        .enumerate()
+        .map(|(i, db)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Rds && r.resource_id == db.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // cidr 필드에 엔진 이름이 들어 있음 (클러스터는 "<engine> cluster")
+            let content = format!(
+                "{} ({}) [{}]",
+                fit_to_width(&db.id, name_width),
+                db.cidr,
+                db.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_lambda_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Lambda");
+
+    if app.lambda_functions.is_empty() {
+        let para = Paragraph::new(app.i18n.no_lambda_functions())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.lambda_functions
+            .iter()
+            .map(|function| function.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .lambda_functions
+        .iter()
+        .enumerate()
+        .map(|(i, function)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::Lambda && r.resource_id == function.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // state 필드에 런타임(또는 패키지 유형), cidr 필드에 메모리 크기가 들어 있음
+            let content = format!(
+                "{} ({}, {})",
+                fit_to_width(&function.name, name_width),
+                function.state,
+                function.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_dynamodb_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "DynamoDB");
+
+    if app.dynamodb_tables.is_empty() {
+        let para = Paragraph::new(app.i18n.no_dynamodb_tables())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .dynamodb_tables
+        .iter()
+        .enumerate()
+        .map(|(i, table)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::DynamoDb && r.resource_id == table.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = table.name.clone();
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_cloudfront_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "CloudFront");
+
+    if app.cloudfront_distributions.is_empty() {
+        let para = Paragraph::new(app.i18n.no_cloudfront_distributions())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.cloudfront_distributions
+            .iter()
+            .map(|distribution| distribution.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .cloudfront_distributions
+        .iter()
+        .enumerate()
+        .map(|(i, distribution)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::CloudFront && r.resource_id == distribution.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // state 필드에 배포 상태, cidr 필드에 CloudFront 도메인이 들어 있음
+            let content = format!(
+                "{} ({}, {})",
+                fit_to_width(&distribution.name, name_width),
+                distribution.id,
+                distribution.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_route53_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Route 53");
+
+    if app.route53_zones.is_empty() {
+        let para = Paragraph::new(app.i18n.no_route53_zones())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.route53_zones.iter().map(|zone| zone.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .route53_zones
+        .iter()
+        .enumerate()
+        .map(|(i, zone)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Route53 && r.resource_id == zone.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // state 필드에 public/private, cidr 필드에 레코드 수가 들어 있음
+            let content = format!(
+                "{} ({}, {})",
+                fit_to_width(&zone.name, name_width),
+                zone.id,
+                zone.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_eks_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "EKS");
+
+    if app.eks_clusters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_eks_clusters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .eks_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, cluster)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Eks && r.resource_id == cluster.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = cluster.name.clone();
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_ecs_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "ECS");
+
+    if app.ecs_clusters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_ecs_clusters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.ecs_clusters.iter().map(|cluster| cluster.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .ecs_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, cluster)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Ecs && r.resource_id == cluster.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({})",
+                fit_to_width(&cluster.name, name_width),
+                cluster.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_sqs_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "SQS");
+
+    if app.sqs_queues.is_empty() {
+        let para = Paragraph::new(app.i18n.no_sqs_queues())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.sqs_queues.iter().map(|queue| queue.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .sqs_queues
+        .iter()
+        .enumerate()
+        .map(|(i, queue)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Sqs && r.resource_id == queue.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({})",
+                fit_to_width(&queue.name, name_width),
+                queue.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_sns_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "SNS");
+
+    if app.sns_topics.is_empty() {
+        let para = Paragraph::new(app.i18n.no_sns_topics())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.sns_topics.iter().map(|topic| topic.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .sns_topics
+        .iter()
+        .enumerate()
+        .map(|(i, topic)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Sns && r.resource_id == topic.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({})",
+                fit_to_width(&topic.name, name_width),
+                topic.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_elasticache_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "ElastiCache");
+
+    if app.elasticache_clusters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_elasticache_clusters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.elasticache_clusters
+            .iter()
+            .map(|cache| cache.id.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .elasticache_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, cache)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::ElastiCache && r.resource_id == cache.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({}) [{}]",
+                fit_to_width(&cache.id, name_width),
+                cache.cidr,
+                cache.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_cloudwatch_alarm_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "CloudWatch Alarms");
+
+    if app.cloudwatch_alarms.is_empty() {
+        let para = Paragraph::new(app.i18n.no_cloudwatch_alarms())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.cloudwatch_alarms
+            .iter()
+            .map(|alarm| alarm.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .cloudwatch_alarms
+        .iter()
+        .enumerate()
+        .map(|(i, alarm)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::CloudWatchAlarm && r.resource_id == alarm.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} - {} [{}]",
+                fit_to_width(&alarm.name, name_width),
+                alarm.cidr,
+                alarm.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_cloudtrail_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "CloudTrail");
+
+    if app.cloudtrail_trails.is_empty() {
+        let para = Paragraph::new(app.i18n.no_cloudtrail_trails())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.cloudtrail_trails
+            .iter()
+            .map(|trail| trail.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .cloudtrail_trails
+        .iter()
+        .enumerate()
+        .map(|(i, trail)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::CloudTrail && r.resource_id == trail.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({})",
+                fit_to_width(&trail.name, name_width),
+                trail.state
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_kms_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "KMS");
+
+    if app.kms_keys.is_empty() {
+        let para = Paragraph::new(app.i18n.no_kms_keys())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width =
+        name_column_width(app.kms_keys.iter().map(|key| key.name.as_str()), area.width);
+    let items: Vec<ListItem> = app
+        .kms_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Kms && r.resource_id == key.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({}) - {}",
+                fit_to_width(&key.name, name_width),
+                key.state,
+                key.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_secret_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Secrets Manager");
+
+    if app.secrets.is_empty() {
+        let para = Paragraph::new(app.i18n.no_secrets())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .secrets
+        .iter()
+        .enumerate()
+        .map(|(i, secret)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Secret && r.resource_id == secret.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let last_accessed = if secret.state.is_empty() {
+                "-"
+            } else {
+                secret.state.as_str()
+            };
+            let mut content = format!(
+                "{} ({}: {})",
+                secret.name,
+                app.i18n.md_last_accessed(),
+                last_accessed
+            );
+            if !secret.cidr.is_empty() {
+                content.push_str(&format!(" - {}", secret.cidr));
+            }
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_ssm_parameter_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "SSM Parameter Store");
+
+    if app.ssm_parameter_paths.is_empty() {
+        let para = Paragraph::new(app.i18n.no_ssm_parameters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.ssm_parameter_paths
+            .iter()
+            .map(|path| path.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .ssm_parameter_paths
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::SsmParameter && r.resource_id == path.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} - {}: {} (SecureString: {})",
+                fit_to_width(&path.name, name_width),
+                app.i18n.md_parameters(),
+                path.state,
+                path.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_efs_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "EFS");
+
+    if app.efs_file_systems.is_empty() {
+        let para = Paragraph::new(app.i18n.no_efs_file_systems())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.efs_file_systems.iter().map(|fs| fs.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .efs_file_systems
+        .iter()
+        .enumerate()
+        .map(|(i, fs)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Efs && r.resource_id == fs.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({}) [{}] - {}",
+                fit_to_width(&fs.name, name_width),
+                fs.id,
+                fs.state,
+                fs.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_apigateway_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "API Gateway");
+
+    if app.api_gateways.is_empty() {
+        let para = Paragraph::new(app.i18n.no_api_gateway_apis())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.api_gateways.iter().map(|api| api.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .api_gateways
+        .iter()
+        .enumerate()
+        .map(|(i, api)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::ApiGateway && r.resource_id == api.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({}) [{}] - {}",
+                fit_to_width(&api.name, name_width),
+                api.id,
+                api.state,
+                api.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_elasticbeanstalk_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Elastic Beanstalk");
+
+    if app.beanstalk_environments.is_empty() {
+        let para = Paragraph::new(app.i18n.no_beanstalk_environments())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.beanstalk_environments
+            .iter()
+            .map(|env| env.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .beanstalk_environments
+        .iter()
+        .enumerate()
+        .map(|(i, env)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::ElasticBeanstalk && r.resource_id == env.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} ({}) [{}] - {}",
+                fit_to_width(&env.name, name_width),
+                env.id,
+                env.state,
+                env.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_stepfunctions_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Step Functions");
+
+    if app.state_machines.is_empty() {
+        let para = Paragraph::new(app.i18n.no_state_machines())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.state_machines
+            .iter()
+            .map(|machine| machine.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .state_machines
+        .iter()
+        .enumerate()
+        .map(|(i, machine)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::StepFunctions && r.resource_id == machine.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {}",
+                fit_to_width(&machine.name, name_width),
+                machine.state,
+                machine.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_kinesis_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Kinesis Data Streams");
+
+    if app.kinesis_streams.is_empty() {
+        let para = Paragraph::new(app.i18n.no_kinesis_streams())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.kinesis_streams
+            .iter()
+            .map(|stream| stream.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .kinesis_streams
+        .iter()
+        .enumerate()
+        .map(|(i, stream)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Kinesis && r.resource_id == stream.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {}",
+                fit_to_width(&stream.name, name_width),
+                stream.state,
+                stream.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_msk_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Amazon MSK");
+
+    if app.msk_clusters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_msk_clusters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.msk_clusters.iter().map(|cluster| cluster.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .msk_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, cluster)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Msk && r.resource_id == cluster.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {}",
+                fit_to_width(&cluster.name, name_width),
+                cluster.state,
+                cluster.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_opensearch_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Amazon OpenSearch Service");
+
+    if app.opensearch_domains.is_empty() {
+        let para = Paragraph::new(app.i18n.no_opensearch_domains())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.opensearch_domains
+            .iter()
+            .map(|domain| domain.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .opensearch_domains
+        .iter()
+        .enumerate()
+        .map(|(i, domain)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::OpenSearch && r.resource_id == domain.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {}",
+                fit_to_width(&domain.name, name_width),
+                domain.state,
+                domain.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_redshift_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Amazon Redshift");
+
+    if app.redshift_clusters.is_empty() {
+        let para = Paragraph::new(app.i18n.no_redshift_clusters())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.redshift_clusters
+            .iter()
+            .map(|cluster| cluster.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .redshift_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, cluster)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::Redshift && r.resource_id == cluster.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {}",
+                fit_to_width(&cluster.name, name_width),
+                cluster.state,
+                cluster.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_transit_gateway_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Transit Gateway");
+
+    if app.transit_gateways.is_empty() {
+        let para = Paragraph::new(app.i18n.no_transit_gateways())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.transit_gateways.iter().map(|tgw| tgw.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .transit_gateways
+        .iter()
+        .enumerate()
+        .map(|(i, tgw)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::TransitGateway && r.resource_id == tgw.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {} {}",
+                fit_to_width(&tgw.name, name_width),
+                tgw.state,
+                tgw.id,
+                tgw.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_vpc_peering_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "VPC Peering");
+
+    if app.vpc_peerings.is_empty() {
+        let para = Paragraph::new(app.i18n.no_vpc_peerings())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.vpc_peerings.iter().map(|peering| peering.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .vpc_peerings
+        .iter()
+        .enumerate()
+        .map(|(i, peering)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::VpcPeering && r.resource_id == peering.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {} {}",
+                fit_to_width(&peering.name, name_width),
+                peering.state,
+                peering.id,
+                peering.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_vpn_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Site-to-Site VPN");
+
+    if app.vpns.is_empty() {
+        let para = Paragraph::new(app.i18n.no_vpns())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(app.vpns.iter().map(|vpn| vpn.name.as_str()), area.width);
+    let items: Vec<ListItem> = app
+        .vpns
+        .iter()
+        .enumerate()
+        .map(|(i, vpn)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Vpn && r.resource_id == vpn.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {} {}",
+                fit_to_width(&vpn.name, name_width),
+                vpn.state,
+                vpn.id,
+                vpn.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_direct_connect_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Direct Connect");
+
+    if app.direct_connect_resources.is_empty() {
+        let para = Paragraph::new(app.i18n.no_direct_connect_resources())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.direct_connect_resources
+            .iter()
+            .map(|resource| resource.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .direct_connect_resources
+        .iter()
+        .enumerate()
+        .map(|(i, resource)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::DirectConnect && r.resource_id == resource.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {} {}",
+                fit_to_width(&resource.name, name_width),
+                resource.state,
+                resource.id,
+                resource.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_waf_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "WAF");
+
+    if app.waf_web_acls.is_empty() {
+        let para = Paragraph::new(app.i18n.no_waf_web_acls())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.waf_web_acls.iter().map(|acl| acl.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .waf_web_acls
+        .iter()
+        .enumerate()
+        .map(|(i, acl)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Waf && r.resource_id == acl.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {}",
+                fit_to_width(&acl.name, name_width),
+                acl.state,
+                acl.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_acm_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "ACM");
+
+    if app.acm_certificates.is_empty() {
+        let para = Paragraph::new(app.i18n.no_acm_certificates())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.acm_certificates
+            .iter()
+            .map(|certificate| certificate.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .acm_certificates
+        .iter()
+        .enumerate()
+        .map(|(i, certificate)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::Acm && r.resource_id == certificate.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} {}",
+                fit_to_width(&certificate.name, name_width),
+                certificate.state,
+                certificate.az,
+                certificate.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_cloudformation_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "CloudFormation");
+
+    if app.cloudformation_stacks.is_empty() {
+        let para = Paragraph::new(app.i18n.no_cloudformation_stacks())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.cloudformation_stacks
+            .iter()
+            .map(|stack| stack.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .cloudformation_stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::CloudFormation && r.resource_id == stack.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} {}",
+                fit_to_width(&stack.name, name_width),
+                stack.state,
+                stack.cidr,
+                stack.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_iam_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "IAM");
+
+    if app.iam_entities.is_empty() {
+        let para = Paragraph::new(app.i18n.no_iam_entities())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.iam_entities.iter().map(|entity| entity.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .iam_entities
+        .iter()
+        .enumerate()
+        .map(|(i, entity)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Iam && r.resource_id == entity.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} {}",
+                fit_to_width(&entity.name, name_width),
+                entity.state,
+                entity.cidr,
+                entity.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_vpc_endpoint_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "VPC Endpoint");
+
+    if app.vpc_endpoints.is_empty() {
+        let para = Paragraph::new(app.i18n.no_vpc_endpoints())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.vpc_endpoints
+            .iter()
+            .map(|endpoint| endpoint.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .vpc_endpoints
+        .iter()
+        .enumerate()
+        .map(|(i, endpoint)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::VpcEndpoint && r.resource_id == endpoint.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} {}",
+                fit_to_width(&endpoint.name, name_width),
+                endpoint.state,
+                endpoint.az,
+                endpoint.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_network_interface_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Network Interface");
+
+    if app.network_interfaces.is_empty() {
+        let para = Paragraph::new(app.i18n.no_network_interfaces())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.network_interfaces
+            .iter()
+            .map(|interface| interface.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .network_interfaces
+        .iter()
+        .enumerate()
+        .map(|(i, interface)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::NetworkInterface
+                        && r.resource_id == interface.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} {}",
+                fit_to_width(&interface.name, name_width),
+                interface.state,
+                interface.cidr,
+                interface.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_launch_template_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Launch Template");
+
+    if app.launch_templates.is_empty() {
+        let para = Paragraph::new(app.i18n.no_launch_templates())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.launch_templates
+            .iter()
+            .map(|template| template.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .launch_templates
+        .iter()
+        .enumerate()
+        .map(|(i, template)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::LaunchTemplate && r.resource_id == template.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} {} ({})",
+                fit_to_width(&template.name, name_width),
+                template.cidr,
+                template.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_ami_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "AMI");
+
+    if app.amis.is_empty() {
+        let para = Paragraph::new(app.i18n.no_amis())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width =
+        name_column_width(app.amis.iter().map(|image| image.name.as_str()), area.width);
+    let items: Vec<ListItem> = app
+        .amis
+        .iter()
+        .enumerate()
+        .map(|(i, image)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Ami && r.resource_id == image.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} {} ({})",
+                fit_to_width(&image.name, name_width),
+                image.cidr,
+                image.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_ebs_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "EBS");
+
+    if app.ebs_resources.is_empty() {
+        let para = Paragraph::new(app.i18n.no_ebs_resources())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.ebs_resources.iter().map(|ebs| ebs.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .ebs_resources
+        .iter()
+        .enumerate()
+        .map(|(i, ebs)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Ebs && r.resource_id == ebs.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] - {} {}",
+                fit_to_width(&ebs.name, name_width),
+                ebs.state,
+                ebs.id,
+                ebs.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_key_pair_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Key Pair");
+
+    if app.key_pairs.is_empty() {
+        let para = Paragraph::new(app.i18n.no_key_pairs())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.key_pairs.iter().map(|key_pair| key_pair.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .key_pairs
+        .iter()
+        .enumerate()
+        .map(|(i, key_pair)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::KeyPair && r.resource_id == key_pair.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            let content = format!(
+                "{} [{}] {} ({})",
+                fit_to_width(&key_pair.name, name_width),
+                key_pair.state,
+                key_pair.cidr,
+                key_pair.az
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_eventbridge_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "EventBridge");
+
+    if app.event_buses.is_empty() {
+        let para = Paragraph::new(app.i18n.no_event_buses())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.event_buses.iter().map(|bus| bus.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .event_buses
+        .iter()
+        .enumerate()
+        .map(|(i, bus)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::EventBridge && r.resource_id == bus.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // 설명이 있으면 이름 열 뒤에 표시
+            let content = format!("{} {}", fit_to_width(&bus.name, name_width), bus.cidr);
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_cognito_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Cognito");
+
+    if app.cognito_pools.is_empty() {
+        let para = Paragraph::new(app.i18n.no_cognito_pools())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.cognito_pools.iter().map(|pool| pool.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .cognito_pools
+        .iter()
+        .enumerate()
+        .map(|(i, pool)| {
             let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
                 bp.resources
                     .iter()
-                    .any(|r| r.resource_type == ResourceType::Asg && r.resource_id == asg.id)
+                    .any(|r| r.resource_type == ResourceType::Cognito && r.resource_id == pool.id)
             });
 
             let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
                 Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // 풀 종류와 생성일(사용자 풀만)을 이름 뒤에 표시
+            let content = format!(
+                "{} [{}] {}",
+                fit_to_width(&pool.name, name_width),
+                pool.state,
+                pool.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_glue_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Glue");
+
+    if app.glue_resources.is_empty() {
+        let para = Paragraph::new(app.i18n.no_glue_resources())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.glue_resources
+            .iter()
+            .map(|resource| resource.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .glue_resources
+        .iter()
+        .enumerate()
+        .map(|(i, resource)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Glue && r.resource_id == resource.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
             } else if is_in_blueprint {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(app.theme.muted)
             } else {
                 Style::default()
             };
@@ -1072,13 +4239,128 @@ fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
                 "  "
             };
 
-            let _state_style = if is_in_blueprint {
-                Style::default().fg(Color::DarkGray)
+            // 리소스 종류와 설명을 이름 뒤에 표시
+            let content = format!(
+                "{} [{}] {}",
+                fit_to_width(&resource.name, name_width),
+                resource.state,
+                resource.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_athena_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "Athena");
+
+    if app.athena_workgroups.is_empty() {
+        let para = Paragraph::new(app.i18n.no_athena_workgroups())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.athena_workgroups
+            .iter()
+            .map(|workgroup| workgroup.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .athena_workgroups
+        .iter()
+        .enumerate()
+        .map(|(i, workgroup)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources.iter().any(|r| {
+                    r.resource_type == ResourceType::Athena && r.resource_id == workgroup.id
+                })
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
+            };
+
+            // 상태와 엔진 버전을 이름 뒤에 표시
+            let content = format!(
+                "{} [{}] {}",
+                fit_to_width(&workgroup.name, name_width),
+                workgroup.state,
+                workgroup.cidr
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(content, style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(list, area);
+}
+
+fn draw_backup_select(frame: &mut Frame, app: &App, area: Rect) {
+    let title = list_title(app, "AWS Backup");
+
+    if app.backup_plans.is_empty() {
+        let para = Paragraph::new(app.i18n.no_backup_plans())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(para, area);
+        return;
+    }
+
+    let name_width = name_column_width(
+        app.backup_plans.iter().map(|plan| plan.name.as_str()),
+        area.width,
+    );
+    let items: Vec<ListItem> = app
+        .backup_plans
+        .iter()
+        .enumerate()
+        .map(|(i, plan)| {
+            let is_in_blueprint = app.current_blueprint.as_ref().is_some_and(|bp| {
+                bp.resources
+                    .iter()
+                    .any(|r| r.resource_type == ResourceType::Backup && r.resource_id == plan.id)
+            });
+
+            let style = if i == app.selected_index {
+                app.theme.emphasis()
+            } else if is_in_blueprint {
+                Style::default().fg(app.theme.muted)
             } else {
-                Style::default().fg(Color::LightCyan)
+                Style::default()
+            };
+            let prefix = if i == app.selected_index {
+                "▶ "
+            } else if is_in_blueprint {
+                "✓ "
+            } else {
+                "  "
             };
 
-            let content = format!("{} [{}]", asg.display(), asg.state);
+            // 마지막 실행 시각을 이름 뒤에 표시
+            let content = format!("{} {}", fit_to_width(&plan.name, name_width), plan.cidr);
 
             ListItem::new(Line::from(vec![
                 Span::styled(prefix, style),
@@ -1093,10 +4375,11 @@ fn draw_asg_select(frame: &mut Frame, app: &App, area: Rect) {
 
 #[cfg(test)]
 mod tests {
-    use super::draw;
-    use crate::app::{App, LoadingTask, Screen};
+    use super::{draw, list_title};
+    use crate::app::{App, LoadingTask, NameTagEdit, Screen};
     use crate::aws_cli::{AwsAuthError, AwsAuthErrorCode, AwsResource};
-    use crate::blueprint::{Blueprint, BlueprintResource, ResourceType};
+    use crate::blueprint::{Blueprint, BlueprintResource, ResourceStatus, ResourceType};
+    use crate::i18n::{I18n, Language};
     use chrono::Utc;
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
@@ -1108,6 +4391,7 @@ mod tests {
             state: "running".to_string(),
             az: "ap-northeast-2a".to_string(),
             cidr: "10.0.0.0/24".to_string(),
+            owner_id: String::new(),
         }
     }
 
@@ -1141,6 +4425,77 @@ mod tests {
         app.load_balancers = vec![resource("lb-1234", "alb-main")];
         app.ecr_repositories = vec![resource("repo-a", "repo-a")];
         app.auto_scaling_groups = vec![resource("asg-a", "asg-a")];
+        app.rds_databases = vec![resource("orders-db", "orders-db")];
+        app.lambda_functions = vec![resource("orders-api", "orders-api")];
+        app.dynamodb_tables = vec![resource("orders", "orders")];
+        app.cloudfront_distributions = vec![resource("E2EXAMPLE", "E2EXAMPLE")];
+        app.route53_zones = vec![resource("Z0123456789ABC", "Z0123456789ABC")];
+        app.eks_clusters = vec![resource("checkout", "checkout")];
+        app.ecs_clusters = vec![resource("checkout", "checkout")];
+        app.sqs_queues = vec![resource("orders", "orders")];
+        app.sns_topics = vec![resource("alerts", "alerts")];
+        app.elasticache_clusters = vec![resource("sessions", "sessions")];
+        app.cloudwatch_alarms = vec![resource("api-high-cpu", "api-high-cpu")];
+        app.cloudtrail_trails = vec![resource("org-audit", "org-audit")];
+        app.kms_keys = vec![resource(
+            "1234abcd-12ab-34cd-56ef-1234567890ab",
+            "1234abcd-12ab-34cd-56ef-1234567890ab",
+        )];
+        app.secrets = vec![resource(
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf",
+            "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf",
+        )];
+        app.ssm_parameter_paths = vec![resource("/ssm-test", "/ssm-test")];
+        app.efs_file_systems = vec![resource("fs-test", "fs-test")];
+        app.api_gateways = vec![resource("api-test", "api-test")];
+        app.beanstalk_environments = vec![resource("e-test", "e-test")];
+        app.state_machines = vec![resource(
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test",
+            "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test",
+        )];
+        app.kinesis_streams = vec![resource("kinesis-test", "kinesis-test")];
+        app.msk_clusters = vec![resource(
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1",
+            "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1",
+        )];
+        app.opensearch_domains = vec![resource("opensearch-test", "opensearch-test")];
+        app.redshift_clusters = vec![resource("redshift-test", "redshift-test")];
+        app.transit_gateways = vec![resource("tgw-test", "tgw-test")];
+        app.vpc_peerings = vec![resource("pcx-test", "pcx-test")];
+        app.vpns = vec![resource("vpn-test", "vpn-test")];
+        app.direct_connect_resources = vec![resource("dxcon-test", "dxcon-test")];
+        app.waf_web_acls = vec![resource(
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4",
+            "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4",
+        )];
+        app.acm_certificates = vec![resource(
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+        )];
+        app.cloudformation_stacks = vec![resource("checkout", "checkout")];
+        app.iam_entities = vec![resource(
+            "arn:aws:iam::123456789012:role/app-role",
+            "arn:aws:iam::123456789012:role/app-role",
+        )];
+        app.vpc_endpoints = vec![resource("vpce-0a1b2c3d4e5f60718", "vpce-0a1b2c3d4e5f60718")];
+        app.network_interfaces = vec![resource("eni-0a1b2c3d4e5f60718", "eni-0a1b2c3d4e5f60718")];
+        app.launch_templates = vec![resource("lt-0a1b2c3d4e5f60718", "lt-0a1b2c3d4e5f60718")];
+        app.amis = vec![resource("ami-0a1b2c3d4e5f60718", "ami-0a1b2c3d4e5f60718")];
+        app.ebs_resources = vec![resource("vol-0123", "vol-0123")];
+        app.key_pairs = vec![resource("key-0123", "key-0123")];
+        app.event_buses = vec![resource("default", "default")];
+        app.cognito_pools = vec![resource(
+            "ap-northeast-2_AbCdEf123",
+            "ap-northeast-2_AbCdEf123",
+        )];
+        app.glue_resources = vec![resource("database/sales", "database/sales")];
+        app.athena_workgroups = vec![resource("primary", "primary")];
+        app.backup_plans = vec![resource("8a2f1c4e", "8a2f1c4e")];
+        app.search_results = vec![crate::doc_index::SearchHit {
+            path: "/tmp/network.md".to_string(),
+            indexed_at: "2026-01-01T00:00:00Z".to_string(),
+            snippet: "| CIDR | 10.0.0.0/16 |".to_string(),
+        }];
         app.preview_filename = "preview.md".to_string();
         app.preview_content = "# hello\nworld\n".to_string();
         app.current_blueprint = Some(sample_blueprint());
@@ -1152,7 +4507,10 @@ mod tests {
             Screen::BlueprintSelect,
             Screen::BlueprintDetail,
             Screen::BlueprintNameInput,
+            Screen::BlueprintImportInput,
             Screen::BlueprintPreview,
+            Screen::DocumentSearch,
+            Screen::Compare,
             Screen::RegionSelect,
             Screen::ServiceSelect,
             Screen::Ec2Select,
@@ -1161,6 +4519,49 @@ mod tests {
             Screen::LoadBalancerSelect,
             Screen::EcrSelect,
             Screen::AsgSelect,
+            Screen::RdsSelect,
+            Screen::LambdaSelect,
+            Screen::DynamoDbSelect,
+            Screen::CloudFrontSelect,
+            Screen::Route53Select,
+            Screen::EksSelect,
+            Screen::EcsSelect,
+            Screen::SqsSelect,
+            Screen::SnsSelect,
+            Screen::ElastiCacheSelect,
+            Screen::CloudWatchAlarmSelect,
+            Screen::CloudTrailSelect,
+            Screen::KmsSelect,
+            Screen::SecretSelect,
+            Screen::SsmParameterSelect,
+            Screen::EfsSelect,
+            Screen::ApiGatewaySelect,
+            Screen::ElasticBeanstalkSelect,
+            Screen::StepFunctionsSelect,
+            Screen::KinesisSelect,
+            Screen::MskSelect,
+            Screen::OpenSearchSelect,
+            Screen::RedshiftSelect,
+            Screen::TransitGatewaySelect,
+            Screen::VpcPeeringSelect,
+            Screen::VpnSelect,
+            Screen::DirectConnectSelect,
+            Screen::WafSelect,
+            Screen::AcmSelect,
+            Screen::CloudFormationSelect,
+            Screen::IamSelect,
+            Screen::VpcEndpointSelect,
+            Screen::NetworkInterfaceSelect,
+            Screen::LaunchTemplateSelect,
+            Screen::AmiSelect,
+            Screen::EbsSelect,
+            Screen::KeyPairSelect,
+            Screen::EventBridgeSelect,
+            Screen::CognitoSelect,
+            Screen::GlueSelect,
+            Screen::AthenaSelect,
+            Screen::BackupSelect,
+            Screen::QueryPlayground,
             Screen::Preview,
             Screen::Settings,
         ];
@@ -1189,6 +4590,89 @@ mod tests {
 
         app.loading_task = LoadingTask::LoadBlueprintResources(0);
         render_app(&app);
+
+        app.loading_task = LoadingTask::ValidateBlueprintResources(0);
+        render_app(&app);
+
+        app.loading_task = LoadingTask::ImportCloudFormationStack("app-stack".to_string());
+        render_app(&app);
+
+        app.loading_task = LoadingTask::ImportTaggedResources("Project=checkout".to_string());
+        render_app(&app);
+
+        app.loading_task = LoadingTask::LoadCompare;
+        render_app(&app);
+
+        app.loading_task = LoadingTask::LoadPromotionChecklist(0, 1);
+        render_app(&app);
+
+        app.loading_task = LoadingTask::RunQueryOperation(0);
+        render_app(&app);
+
+        app.loading_task = LoadingTask::SetNameTag;
+        render_app(&app);
+    }
+
+    #[test]
+    fn draw_name_tag_screens_render_in_write_mode() {
+        let mut app = App::new();
+        app.allow_write = true;
+        app.screen = Screen::Ec2Select;
+        render_app(&app);
+
+        app.name_tag_edit = Some(NameTagEdit {
+            resource_type: ResourceType::Ec2,
+            resource_id: "i-0123456789abcdef0".to_string(),
+            current_name: None,
+            return_screen: Screen::Ec2Select,
+        });
+        app.input_buffer = "web-1".to_string();
+        app.screen = Screen::NameTagInput;
+        render_app(&app);
+        app.screen = Screen::NameTagConfirm;
+        render_app(&app);
+    }
+
+    #[test]
+    fn draw_query_playground_renders_result_and_error() {
+        let mut app = App::new();
+        app.screen = Screen::QueryPlayground;
+        app.query_output = Some(r#"{"Account":"123456789012"}"#.to_string());
+        app.input_buffer = "Account".to_string();
+        app.apply_query();
+        render_app(&app);
+
+        app.input_buffer = "Account[".to_string();
+        app.apply_query();
+        assert!(app.query_result.is_err());
+        render_app(&app);
+    }
+
+    #[test]
+    fn draw_compare_renders_split_and_table_views() {
+        let mut app = App::new();
+        let sg = |id: &str| BlueprintResource {
+            resource_type: ResourceType::SecurityGroup,
+            region: "ap-northeast-2".to_string(),
+            resource_id: id.to_string(),
+            resource_name: id.to_string(),
+        };
+        let comparison = crate::compare::Comparison::new(
+            sg("sg-prod"),
+            "## Security Group (sg-prod)\n| Item | Value |\n|:---|:---|\n| VPC | vpc-1 |\n"
+                .to_string(),
+            sg("sg-staging"),
+            "## Security Group (sg-staging)\n| Item | Value |\n|:---|:---|\n| VPC | vpc-2 |\n"
+                .to_string(),
+        );
+        app.preview_content = comparison.to_markdown(app.settings.language);
+        app.preview_filename = comparison.filename();
+        app.comparison = Some(comparison);
+        app.screen = Screen::Compare;
+        render_app(&app);
+
+        app.compare_show_table = true;
+        render_app(&app);
     }
 
     #[test]
@@ -1221,6 +4705,48 @@ mod tests {
             Screen::LoadBalancerSelect,
             Screen::EcrSelect,
             Screen::AsgSelect,
+            Screen::RdsSelect,
+            Screen::LambdaSelect,
+            Screen::DynamoDbSelect,
+            Screen::CloudFrontSelect,
+            Screen::Route53Select,
+            Screen::EksSelect,
+            Screen::EcsSelect,
+            Screen::SqsSelect,
+            Screen::SnsSelect,
+            Screen::ElastiCacheSelect,
+            Screen::CloudWatchAlarmSelect,
+            Screen::CloudTrailSelect,
+            Screen::KmsSelect,
+            Screen::SecretSelect,
+            Screen::SsmParameterSelect,
+            Screen::EfsSelect,
+            Screen::ApiGatewaySelect,
+            Screen::ElasticBeanstalkSelect,
+            Screen::StepFunctionsSelect,
+            Screen::KinesisSelect,
+            Screen::MskSelect,
+            Screen::OpenSearchSelect,
+            Screen::RedshiftSelect,
+            Screen::TransitGatewaySelect,
+            Screen::VpcPeeringSelect,
+            Screen::VpnSelect,
+            Screen::DirectConnectSelect,
+            Screen::WafSelect,
+            Screen::AcmSelect,
+            Screen::CloudFormationSelect,
+            Screen::IamSelect,
+            Screen::VpcEndpointSelect,
+            Screen::NetworkInterfaceSelect,
+            Screen::LaunchTemplateSelect,
+            Screen::AmiSelect,
+            Screen::EbsSelect,
+            Screen::KeyPairSelect,
+            Screen::EventBridgeSelect,
+            Screen::CognitoSelect,
+            Screen::GlueSelect,
+            Screen::AthenaSelect,
+            Screen::BackupSelect,
         ];
 
         for screen in screens {
@@ -1229,6 +4755,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn draw_blueprint_detail_with_validation_markers_without_panic() {
+        let mut app = App::new();
+        app.screen = Screen::BlueprintDetail;
+        let mut blueprint = sample_blueprint();
+        blueprint.resources.push(BlueprintResource {
+            resource_type: ResourceType::SecurityGroup,
+            region: "ap-northeast-2".to_string(),
+            resource_id: "sg-1234".to_string(),
+            resource_name: "sg-old".to_string(),
+        });
+        app.current_blueprint = Some(blueprint);
+
+        app.blueprint_validation = vec![ResourceStatus::Missing];
+        render_app(&app);
+
+        app.blueprint_validation = vec![
+            ResourceStatus::Valid,
+            ResourceStatus::Renamed("sg-new".to_string()),
+        ];
+        render_app(&app);
+
+        app.blueprint_validation = vec![
+            ResourceStatus::Unknown,
+            ResourceStatus::Moved("us-east-1".to_string()),
+        ];
+        render_app(&app);
+    }
+
     #[test]
     fn draw_blueprint_fallback_paths_without_panic() {
         let mut app = App::new();
@@ -1271,6 +4826,142 @@ mod tests {
             LoadingTask::RefreshAsg,
             LoadingTask::LoadAsg,
             LoadingTask::LoadAsgDetail("asg-a".to_string()),
+            LoadingTask::RefreshRds,
+            LoadingTask::LoadRds,
+            LoadingTask::LoadRdsDetail("orders-db".to_string()),
+            LoadingTask::RefreshLambda,
+            LoadingTask::LoadLambda,
+            LoadingTask::LoadLambdaDetail("orders-api".to_string()),
+            LoadingTask::RefreshDynamoDb,
+            LoadingTask::LoadDynamoDb,
+            LoadingTask::LoadDynamoDbDetail("orders".to_string()),
+            LoadingTask::RefreshCloudFront,
+            LoadingTask::LoadCloudFront,
+            LoadingTask::LoadCloudFrontDetail("E2EXAMPLE".to_string()),
+            LoadingTask::RefreshRoute53,
+            LoadingTask::LoadRoute53,
+            LoadingTask::LoadRoute53Detail("Z0123456789ABC".to_string()),
+            LoadingTask::RefreshEks,
+            LoadingTask::LoadEks,
+            LoadingTask::LoadEksDetail("checkout".to_string()),
+            LoadingTask::RefreshEcs,
+            LoadingTask::LoadEcs,
+            LoadingTask::LoadEcsDetail("checkout".to_string()),
+            LoadingTask::RefreshSqs,
+            LoadingTask::LoadSqs,
+            LoadingTask::LoadSqsDetail("orders".to_string()),
+            LoadingTask::RefreshSns,
+            LoadingTask::LoadSns,
+            LoadingTask::LoadSnsDetail("alerts".to_string()),
+            LoadingTask::RefreshElastiCache,
+            LoadingTask::LoadElastiCache,
+            LoadingTask::LoadElastiCacheDetail("sessions".to_string()),
+            LoadingTask::RefreshCloudWatchAlarm,
+            LoadingTask::LoadCloudWatchAlarm,
+            LoadingTask::LoadCloudWatchAlarmDetail("api-high-cpu".to_string()),
+            LoadingTask::RefreshCloudTrail,
+            LoadingTask::LoadCloudTrail,
+            LoadingTask::LoadCloudTrailDetail("org-audit".to_string()),
+            LoadingTask::RefreshKms,
+            LoadingTask::LoadKms,
+            LoadingTask::LoadKmsDetail("1234abcd-12ab-34cd-56ef-1234567890ab".to_string()),
+            LoadingTask::RefreshSecret,
+            LoadingTask::LoadSecret,
+            LoadingTask::LoadSecretDetail(
+                "arn:aws:secretsmanager:ap-northeast-2:123456789012:secret:secret-test-AbCdEf"
+                    .to_string(),
+            ),
+            LoadingTask::RefreshSsmParameter,
+            LoadingTask::LoadSsmParameter,
+            LoadingTask::LoadSsmParameterDetail("/ssm-test".to_string()),
+            LoadingTask::RefreshEfs,
+            LoadingTask::LoadEfs,
+            LoadingTask::LoadEfsDetail("fs-test".to_string()),
+            LoadingTask::RefreshApiGateway,
+            LoadingTask::LoadApiGateway,
+            LoadingTask::LoadApiGatewayDetail("api-test".to_string()),
+            LoadingTask::RefreshElasticBeanstalk,
+            LoadingTask::LoadElasticBeanstalk,
+            LoadingTask::LoadElasticBeanstalkDetail("e-test".to_string()),
+            LoadingTask::RefreshStepFunctions,
+            LoadingTask::LoadStepFunctions,
+            LoadingTask::LoadStepFunctionsDetail(
+                "arn:aws:states:ap-northeast-2:123456789012:stateMachine:sfn-test".to_string(),
+            ),
+            LoadingTask::RefreshKinesis,
+            LoadingTask::LoadKinesis,
+            LoadingTask::LoadKinesisDetail("kinesis-test".to_string()),
+            LoadingTask::RefreshMsk,
+            LoadingTask::LoadMsk,
+            LoadingTask::LoadMskDetail(
+                "arn:aws:kafka:ap-northeast-2:123456789012:cluster/msk-test/abcd-1".to_string(),
+            ),
+            LoadingTask::RefreshOpenSearch,
+            LoadingTask::LoadOpenSearch,
+            LoadingTask::LoadOpenSearchDetail("opensearch-test".to_string()),
+            LoadingTask::RefreshRedshift,
+            LoadingTask::LoadRedshift,
+            LoadingTask::LoadRedshiftDetail("redshift-test".to_string()),
+            LoadingTask::RefreshTransitGateway,
+            LoadingTask::LoadTransitGateway,
+            LoadingTask::LoadTransitGatewayDetail("tgw-test".to_string()),
+            LoadingTask::RefreshVpcPeering,
+            LoadingTask::LoadVpcPeering,
+            LoadingTask::LoadVpcPeeringDetail("pcx-test".to_string()),
+            LoadingTask::RefreshVpn,
+            LoadingTask::LoadVpn,
+            LoadingTask::LoadVpnDetail("vpn-test".to_string()),
+            LoadingTask::RefreshDirectConnect,
+            LoadingTask::LoadDirectConnect,
+            LoadingTask::LoadDirectConnectDetail("dxcon-test".to_string()),
+            LoadingTask::RefreshWaf,
+            LoadingTask::LoadWaf,
+            LoadingTask::LoadWafDetail(
+                "arn:aws:wafv2:ap-northeast-2:123456789012:regional/webacl/prod-web/a1b2c3d4"
+                    .to_string(),
+            ),
+            LoadingTask::RefreshAcm,
+            LoadingTask::LoadAcm,
+            LoadingTask::LoadAcmDetail("arn:aws:acm:ap-northeast-2:123456789012:certificate/0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0".to_string()),
+            LoadingTask::RefreshCloudFormation,
+            LoadingTask::LoadCloudFormation,
+            LoadingTask::LoadCloudFormationDetail("checkout".to_string()),
+            LoadingTask::RefreshIam,
+            LoadingTask::LoadIam,
+            LoadingTask::LoadIamDetail("arn:aws:iam::123456789012:role/app-role".to_string()),
+            LoadingTask::RefreshVpcEndpoint,
+            LoadingTask::LoadVpcEndpoint,
+            LoadingTask::LoadVpcEndpointDetail("vpce-0a1b2c3d4e5f60718".to_string()),
+            LoadingTask::RefreshNetworkInterface,
+            LoadingTask::LoadNetworkInterface,
+            LoadingTask::LoadNetworkInterfaceDetail("eni-0a1b2c3d4e5f60718".to_string()),
+            LoadingTask::RefreshLaunchTemplate,
+            LoadingTask::LoadLaunchTemplate,
+            LoadingTask::LoadLaunchTemplateDetail("lt-0a1b2c3d4e5f60718".to_string()),
+            LoadingTask::RefreshAmi,
+            LoadingTask::LoadAmi,
+            LoadingTask::LoadAmiDetail("ami-0a1b2c3d4e5f60718".to_string()),
+            LoadingTask::RefreshEbs,
+            LoadingTask::LoadEbs,
+            LoadingTask::LoadEbsDetail("vol-0123".to_string()),
+            LoadingTask::RefreshKeyPair,
+            LoadingTask::LoadKeyPair,
+            LoadingTask::LoadKeyPairDetail("key-0123".to_string()),
+            LoadingTask::RefreshEventBridge,
+            LoadingTask::LoadEventBridge,
+            LoadingTask::LoadEventBridgeDetail("default".to_string()),
+            LoadingTask::RefreshCognito,
+            LoadingTask::LoadCognito,
+            LoadingTask::LoadCognitoDetail("ap-northeast-2_AbCdEf123".to_string()),
+            LoadingTask::RefreshGlue,
+            LoadingTask::LoadGlue,
+            LoadingTask::LoadGlueDetail("database/sales".to_string()),
+            LoadingTask::RefreshAthena,
+            LoadingTask::LoadAthena,
+            LoadingTask::LoadAthenaDetail("primary".to_string()),
+            LoadingTask::RefreshBackup,
+            LoadingTask::LoadBackup,
+            LoadingTask::LoadBackupDetail("8a2f1c4e".to_string()),
         ];
 
         for task in tasks {
@@ -1278,4 +4969,84 @@ mod tests {
             render_app(&app);
         }
     }
+
+    #[test]
+    fn draw_ascii_mode_leaves_only_ascii_cells() {
+        let mut app = App::new();
+        app.settings.language = Language::English;
+        app.i18n = I18n::new(Language::English);
+        app.ascii = true;
+        app.instances = vec![resource("i-1234", "web-1")];
+        app.current_blueprint = Some(sample_blueprint());
+
+        for screen in [
+            Screen::ServiceSelect,
+            Screen::Ec2Select,
+            Screen::BlueprintDetail,
+        ] {
+            app.screen = screen;
+            let backend = TestBackend::new(160, 50);
+            let mut terminal = Terminal::new(backend).expect("create test terminal");
+            terminal.draw(|frame| draw(frame, &app)).expect("draw");
+            let buffer = terminal.backend().buffer();
+            assert!(buffer.content().iter().all(|cell| cell.symbol().is_ascii()));
+        }
+    }
+
+    #[test]
+    fn list_title_shows_count_and_cached_refresh() {
+        let mut app = App::new();
+        app.settings.language = Language::English;
+        app.settings.list_cache_seconds = None;
+        app.i18n = I18n::new(Language::English);
+        app.selected_region = 0;
+        app.screen = Screen::Ec2Select;
+        app.instances = vec![resource("i-1234", "web-1"), resource("i-5678", "web-2")];
+        assert_eq!(
+            list_title(&app, "EC2"),
+            " EC2 [ap-northeast-2 - Seoul] (2) "
+        );
+
+        app.record_list_refresh(Screen::Ec2Select);
+        assert_eq!(
+            list_title(&app, "EC2"),
+            " EC2 [ap-northeast-2 - Seoul] (2) — refreshed just now "
+        );
+
+        app.screen = Screen::ServiceSelect;
+        assert!(app.open_cached_list(Screen::Ec2Select));
+        assert!(list_title(&app, "EC2").ends_with(" — refreshed just now (cached) "));
+    }
+
+    #[test]
+    fn draw_preview_without_wrap_scrolls_wide_rows_sideways() {
+        let mut app = App::new();
+        app.screen = Screen::Preview;
+        app.preview_content = format!("{}END", "-".repeat(60));
+        let rendered = |app: &App| {
+            let backend = TestBackend::new(40, 12);
+            let mut terminal = Terminal::new(backend).expect("create test terminal");
+            terminal.draw(|frame| draw(frame, app)).expect("draw");
+            let buffer = terminal.backend().buffer().clone();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // 줄바꿈 상태에서는 끝부분이 다음 줄로 넘어감
+        assert!(rendered(&app).iter().any(|row| row.contains("END")));
+
+        app.toggle_preview_wrap();
+        assert!(!rendered(&app).iter().any(|row| row.contains("END")));
+
+        for _ in 0..4 {
+            app.scroll_preview_right();
+        }
+        assert_eq!(app.preview_hscroll, 32);
+        assert!(rendered(&app).iter().any(|row| row.contains("END")));
+    }
 }
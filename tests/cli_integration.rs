@@ -16,6 +16,18 @@ fn cli_help_runs_without_aws_account() {
     assert!(stdout.contains("Usage"));
 }
 
+#[test]
+fn cli_help_lists_audit_summary_flag() {
+    let output = Command::new(emd_bin())
+        .arg("--help")
+        .output()
+        .expect("run --help");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--audit-summary"));
+}
+
 #[test]
 fn cli_version_runs_without_aws_account() {
     let output = Command::new(emd_bin())
@@ -39,3 +51,29 @@ fn cli_update_help_is_accessible() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("update"));
 }
+
+#[test]
+fn cli_iam_policy_prints_read_only_policy_without_aws_account() {
+    let output = Command::new(emd_bin())
+        .args(["iam-policy", "--service", "ecr"])
+        .output()
+        .expect("run iam-policy");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"2012-10-17\""));
+    assert!(stdout.contains("ecr:DescribeRepositories"));
+    assert!(!stdout.contains("ec2:DescribeInstances"));
+}
+
+#[test]
+fn cli_iam_policy_rejects_unknown_service() {
+    let output = Command::new(emd_bin())
+        .args(["iam-policy", "--service", "unknown"])
+        .output()
+        .expect("run iam-policy");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("unknown service"));
+}